@@ -0,0 +1,98 @@
+# English messages; every other locale falls back to these.
+#
+# Server code writes these messages in English, and the server finds the
+# entry a message came from by its text. When changing wording here, change
+# the code that produces the message too, or translations stop applying.
+
+## API errors
+
+error-unauthorized = Unauthorized
+error-validation-failed = Validation failed
+error-resource-not-found = Resource not found
+error-admin-required = Admin permission required
+error-missing-permission = Missing permission: { $permission }
+error-requires-permission = Requires '{ $permission }' permission
+error-agent-not-found = Agent not found
+error-contact-not-found = Contact not found
+error-conversation-not-found = Conversation not found
+error-user-not-found = User not found
+error-team-not-found = Team not found
+error-message-not-found = Message not found
+error-attachment-not-found = Attachment not found
+error-notification-not-found = Notification not found
+error-macro-not-found = Macro not found
+error-invalid-credentials = Invalid email or password
+error-invalid-reset-token = Invalid or expired reset token
+error-invalid-invitation = Invalid or expired invitation
+error-too-many-login-attempts = Too many failed login attempts. Please try again in { $seconds } seconds.
+error-maintenance = Oxidesk is undergoing scheduled maintenance. Please try again shortly.
+
+## Field validation
+
+validation-required = is required
+validation-not-empty = must not be empty
+validation-not-negative = must not be negative
+validation-max-length = must be at most { $max } characters
+validation-min-length = must be at least { $min } characters
+validation-email = must be a valid email address
+validation-http-url = must be an HTTP or HTTPS URL
+validation-https = must use HTTPS
+validation-hex-color = must be a hex colour (#RRGGBB)
+validation-one-of = must be one of: { $allowed }
+validation-between = must be between { $min } and { $max }
+validation-min-entries = must have at least { $min } entries
+validation-max-entries = must have at most { $max } entries
+validation-timestamp = must be an RFC 3339 timestamp
+validation-date = must be a date in YYYY-MM-DD form
+validation-required-when-snoozing = is required when snoozing
+validation-locale = must be one of the available locales: { $locales }
+
+## Web interface
+
+nav-dashboard = Dashboard
+nav-inbox = Inbox
+nav-agents = Agents
+nav-contacts = Contacts
+nav-teams = Teams
+nav-roles = Roles
+nav-sign-out = Sign out
+login-page-title = Login
+login-heading = Login
+login-email = Email
+login-password = Password
+login-submit = Login
+login-submitting = Logging in...
+maintenance-page-title = Maintenance
+maintenance-heading = We'll be right back
+maintenance-refresh = This page will refresh automatically.
+web-load-agents-failed = Failed to load agents
+web-load-contacts-failed = Failed to load contacts
+web-load-roles-failed = Failed to load roles
+
+## Notification emails
+
+email-password-reset-subject = Password Reset Request
+email-password-reset-text =
+    You requested a password reset for your { $product } account.
+
+    Click the link below to reset your password:
+    { $link }
+
+    This link will expire in { $expires_in }.
+
+    If you did not request a password reset, please ignore this email.
+email-password-reset-requested = If an account exists with that email, you will receive a password reset link.
+email-invitation-subject = You have been invited to { $product }
+email-invitation-text =
+    { $inviter } invited you to join { $product } as an agent.
+
+    Click the link below to choose your password and activate your account:
+    { $link }
+
+    This link will expire in { $expires_in }.
+duration-minute = 1 minute
+duration-minutes = { $count } minutes
+duration-hour = 1 hour
+duration-hours = { $count } hours
+duration-day = 1 day
+duration-days = { $count } days
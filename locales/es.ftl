@@ -0,0 +1,94 @@
+# Spanish messages
+
+## API errors
+
+error-unauthorized = No autorizado
+error-validation-failed = La validación ha fallado
+error-resource-not-found = Recurso no encontrado
+error-admin-required = Se requiere permiso de administrador
+error-missing-permission = Falta el permiso: { $permission }
+error-requires-permission = Se requiere el permiso '{ $permission }'
+error-agent-not-found = Agente no encontrado
+error-contact-not-found = Contacto no encontrado
+error-conversation-not-found = Conversación no encontrada
+error-user-not-found = Usuario no encontrado
+error-team-not-found = Equipo no encontrado
+error-message-not-found = Mensaje no encontrado
+error-attachment-not-found = Archivo adjunto no encontrado
+error-notification-not-found = Notificación no encontrada
+error-macro-not-found = Macro no encontrada
+error-invalid-credentials = Correo electrónico o contraseña incorrectos
+error-invalid-reset-token = El enlace para restablecer la contraseña no es válido o ha caducado
+error-invalid-invitation = La invitación no es válida o ha caducado
+error-too-many-login-attempts = Demasiados intentos fallidos de inicio de sesión. Vuelve a intentarlo en { $seconds } segundos.
+error-maintenance = Oxidesk está en mantenimiento programado. Vuelve a intentarlo en breve.
+
+## Field validation
+
+validation-required = es obligatorio
+validation-not-empty = no puede estar vacío
+validation-not-negative = no puede ser negativo
+validation-max-length = debe tener como máximo { $max } caracteres
+validation-min-length = debe tener al menos { $min } caracteres
+validation-email = debe ser una dirección de correo electrónico válida
+validation-http-url = debe ser una URL HTTP o HTTPS
+validation-https = debe usar HTTPS
+validation-hex-color = debe ser un color hexadecimal (#RRGGBB)
+validation-one-of = debe ser uno de: { $allowed }
+validation-between = debe estar entre { $min } y { $max }
+validation-min-entries = debe tener al menos { $min } elementos
+validation-max-entries = debe tener como máximo { $max } elementos
+validation-timestamp = debe ser una marca de tiempo RFC 3339
+validation-date = debe ser una fecha con el formato AAAA-MM-DD
+validation-required-when-snoozing = es obligatorio al posponer
+validation-locale = debe ser uno de los idiomas disponibles: { $locales }
+
+## Web interface
+
+nav-dashboard = Panel
+nav-inbox = Bandeja de entrada
+nav-agents = Agentes
+nav-contacts = Contactos
+nav-teams = Equipos
+nav-roles = Roles
+nav-sign-out = Cerrar sesión
+login-page-title = Iniciar sesión
+login-heading = Iniciar sesión
+login-email = Correo electrónico
+login-password = Contraseña
+login-submit = Entrar
+login-submitting = Entrando...
+maintenance-page-title = Mantenimiento
+maintenance-heading = Volvemos enseguida
+maintenance-refresh = Esta página se actualizará automáticamente.
+web-load-agents-failed = No se pudieron cargar los agentes
+web-load-contacts-failed = No se pudieron cargar los contactos
+web-load-roles-failed = No se pudieron cargar los roles
+
+## Notification emails
+
+email-password-reset-subject = Solicitud para restablecer la contraseña
+email-password-reset-text =
+    Has solicitado restablecer la contraseña de tu cuenta de { $product }.
+
+    Haz clic en el siguiente enlace para restablecer tu contraseña:
+    { $link }
+
+    Este enlace caducará en { $expires_in }.
+
+    Si no has solicitado restablecer la contraseña, ignora este correo.
+email-password-reset-requested = Si existe una cuenta con ese correo electrónico, recibirás un enlace para restablecer la contraseña.
+email-invitation-subject = Te han invitado a { $product }
+email-invitation-text =
+    { $inviter } te ha invitado a unirte a { $product } como agente.
+
+    Haz clic en el siguiente enlace para elegir tu contraseña y activar tu cuenta:
+    { $link }
+
+    Este enlace caducará en { $expires_in }.
+duration-minute = 1 minuto
+duration-minutes = { $count } minutos
+duration-hour = 1 hora
+duration-hours = { $count } horas
+duration-day = 1 día
+duration-days = { $count } días
@@ -0,0 +1,22 @@
+-- Conversation auto-tagging via keyword rules
+-- Inbox-level rules that apply a tag when an inbound message matches a keyword or regex
+
+CREATE TABLE IF NOT EXISTS auto_tag_rules (
+    id TEXT PRIMARY KEY NOT NULL,
+    inbox_id TEXT NOT NULL,
+    tag_id TEXT NOT NULL,
+    match_field TEXT NOT NULL DEFAULT 'any', -- subject, body, any
+    match_type TEXT NOT NULL DEFAULT 'contains', -- contains, regex
+    pattern TEXT NOT NULL,
+    case_sensitive INTEGER NOT NULL DEFAULT 0,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    created_by TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES users(id)
+);
+
+-- Index for loading the enabled rules of an inbox at ingestion time
+CREATE INDEX IF NOT EXISTS idx_auto_tag_rules_inbox ON auto_tag_rules(inbox_id, enabled);
@@ -0,0 +1,16 @@
+-- Recent SSE notification events per user, kept so clients can resume with Last-Event-ID
+-- The autoincrement sequence doubles as the SSE event id
+
+CREATE TABLE IF NOT EXISTS notification_stream_events (
+    sequence INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id TEXT NOT NULL,
+    payload TEXT NOT NULL, -- Serialized NotificationEvent JSON
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- Index for replaying a user's events after a given sequence
+CREATE INDEX IF NOT EXISTS idx_notification_stream_events_user ON notification_stream_events(user_id, sequence);
+
+-- Index for garbage-collecting old events
+CREATE INDEX IF NOT EXISTS idx_notification_stream_events_created ON notification_stream_events(created_at);
@@ -0,0 +1,21 @@
+-- Contact-facing email address verification
+-- Channels created from inbound email are verified by construction (the contact wrote to us),
+-- so existing and inbound channels default to verified; manually created contacts start unverified.
+
+ALTER TABLE contact_channels ADD COLUMN email_verified INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE contact_channels ADD COLUMN email_verified_at TEXT;
+
+UPDATE contact_channels SET email_verified_at = created_at;
+
+-- Tokenized confirmation links sent to manually created contacts
+CREATE TABLE IF NOT EXISTS contact_email_verifications (
+    id TEXT PRIMARY KEY NOT NULL,
+    channel_id TEXT NOT NULL,
+    token TEXT NOT NULL UNIQUE,
+    expires_at TEXT NOT NULL,
+    used INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (channel_id) REFERENCES contact_channels(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_contact_email_verifications_channel ON contact_email_verifications(channel_id);
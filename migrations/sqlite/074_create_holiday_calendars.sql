@@ -0,0 +1,17 @@
+-- Per-team working-day calendars
+-- Holidays without a calendar stay global and apply to every team; holidays in a
+-- calendar only apply to teams linked to it.
+
+CREATE TABLE IF NOT EXISTS holiday_calendars (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL UNIQUE,
+    description TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+ALTER TABLE holidays ADD COLUMN calendar_id TEXT REFERENCES holiday_calendars(id) ON DELETE CASCADE;
+
+CREATE INDEX IF NOT EXISTS idx_holidays_calendar_date ON holidays(calendar_id, date);
+
+ALTER TABLE teams ADD COLUMN holiday_calendar_id TEXT REFERENCES holiday_calendars(id) ON DELETE SET NULL;
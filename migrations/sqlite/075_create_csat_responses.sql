@@ -0,0 +1,42 @@
+-- Migration 075: CSAT responses and team lead alert notifications
+-- Description: Stores customer satisfaction scores per conversation and allows
+-- automation rules to raise 'alert' notifications
+
+CREATE TABLE IF NOT EXISTS csat_responses (
+    id TEXT PRIMARY KEY,
+    conversation_id TEXT NOT NULL,
+    score INTEGER NOT NULL CHECK(score BETWEEN 1 AND 5),
+    comment TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_csat_responses_conversation ON csat_responses(conversation_id, created_at);
+
+-- SQLite doesn't support altering CHECK constraints, so recreate user_notifications
+CREATE TABLE user_notifications_new (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL,
+    type TEXT NOT NULL CHECK(type IN ('assignment', 'mention', 'alert')),
+    created_at TEXT NOT NULL,
+    is_read INTEGER NOT NULL DEFAULT 0,
+    conversation_id TEXT,
+    message_id TEXT,
+    actor_id TEXT,
+
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE SET NULL,
+    FOREIGN KEY (message_id) REFERENCES messages(id) ON DELETE SET NULL,
+    FOREIGN KEY (actor_id) REFERENCES users(id) ON DELETE SET NULL
+);
+
+INSERT INTO user_notifications_new SELECT * FROM user_notifications;
+
+DROP TABLE user_notifications;
+
+ALTER TABLE user_notifications_new RENAME TO user_notifications;
+
+CREATE INDEX idx_user_notifications_user_id ON user_notifications(user_id);
+CREATE INDEX idx_user_notifications_user_read ON user_notifications(user_id, is_read);
+CREATE INDEX idx_user_notifications_created_at ON user_notifications(created_at);
+CREATE INDEX idx_user_notifications_type ON user_notifications(type);
@@ -0,0 +1,14 @@
+-- Migration 076: Email ingestion backlog progress
+-- Description: One row per inbox tracking how far the polling worker has worked
+-- through the IMAP backlog. Updated after every polled batch.
+
+CREATE TABLE IF NOT EXISTS inbox_email_backlog (
+    inbox_id TEXT PRIMARY KEY NOT NULL,
+    messages_fetched INTEGER NOT NULL DEFAULT 0,
+    messages_processed INTEGER NOT NULL DEFAULT 0,
+    messages_failed INTEGER NOT NULL DEFAULT 0,
+    messages_remaining INTEGER NOT NULL DEFAULT 0,
+    total_processed INTEGER NOT NULL DEFAULT 0,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE CASCADE
+);
@@ -0,0 +1,25 @@
+-- Weekly shift schedules per team member
+-- Times are "HH:MM" in the team's business-hours timezone (UTC when unset);
+-- end_time may be "24:00" for shifts running until midnight.
+
+CREATE TABLE IF NOT EXISTS agent_shifts (
+    id TEXT PRIMARY KEY NOT NULL,
+    team_id TEXT NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    day TEXT NOT NULL CHECK (day IN ('Monday', 'Tuesday', 'Wednesday', 'Thursday', 'Friday', 'Saturday', 'Sunday')),
+    start_time TEXT NOT NULL,
+    end_time TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    CHECK (end_time > start_time)
+);
+
+CREATE INDEX IF NOT EXISTS idx_agent_shifts_team_day ON agent_shifts(team_id, day);
+CREATE INDEX IF NOT EXISTS idx_agent_shifts_user ON agent_shifts(user_id);
+
+-- Last observed on-shift state per agent, so availability only changes at boundaries
+CREATE TABLE IF NOT EXISTS agent_shift_states (
+    user_id TEXT PRIMARY KEY NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    on_shift INTEGER NOT NULL,
+    updated_at TEXT NOT NULL
+);
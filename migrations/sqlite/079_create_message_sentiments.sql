@@ -0,0 +1,17 @@
+-- Sentiment scores per inbound message and a rolling conversation-level trend
+
+CREATE TABLE IF NOT EXISTS message_sentiments (
+    message_id TEXT PRIMARY KEY NOT NULL REFERENCES messages(id) ON DELETE CASCADE,
+    conversation_id TEXT NOT NULL REFERENCES conversations(id) ON DELETE CASCADE,
+    score REAL NOT NULL CHECK(score BETWEEN -1.0 AND 1.0),
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_message_sentiments_conversation
+    ON message_sentiments(conversation_id, created_at);
+
+ALTER TABLE conversations ADD COLUMN sentiment_score REAL;
+ALTER TABLE conversations ADD COLUMN sentiment_trend TEXT
+    CHECK(sentiment_trend IN ('improving', 'stable', 'declining'));
+
+CREATE INDEX IF NOT EXISTS idx_conversations_sentiment ON conversations(sentiment_score);
@@ -0,0 +1,15 @@
+-- Audit trail of system configuration changes made through the admin API
+
+CREATE TABLE IF NOT EXISTS system_config_changes (
+    id TEXT PRIMARY KEY NOT NULL,
+    key TEXT NOT NULL,
+    old_value TEXT,
+    new_value TEXT NOT NULL,
+    changed_by TEXT NOT NULL,
+    changed_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_system_config_changes_changed_at
+    ON system_config_changes(changed_at);
+CREATE INDEX IF NOT EXISTS idx_system_config_changes_key
+    ON system_config_changes(key);
@@ -0,0 +1,25 @@
+-- External channel senders (phone numbers, widget visitors, ...) mapped to contacts,
+-- and channel message IDs already ingested so redelivered webhooks are ignored
+
+CREATE TABLE IF NOT EXISTS channel_identities (
+    inbox_id TEXT NOT NULL,
+    external_id TEXT NOT NULL,
+    contact_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (inbox_id, external_id),
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE CASCADE,
+    FOREIGN KEY (contact_id) REFERENCES contacts(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_channel_identities_contact
+    ON channel_identities(contact_id);
+
+CREATE TABLE IF NOT EXISTS channel_message_receipts (
+    inbox_id TEXT NOT NULL,
+    external_message_id TEXT NOT NULL,
+    message_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (inbox_id, external_message_id),
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE CASCADE,
+    FOREIGN KEY (message_id) REFERENCES messages(id) ON DELETE CASCADE
+);
@@ -0,0 +1,19 @@
+-- Twilio SMS configuration per inbox
+-- An inbox with an SMS configuration receives texts through the Twilio webhook
+-- and sends replies through Twilio; senders are tracked as channel identities
+
+CREATE TABLE IF NOT EXISTS inbox_sms_configs (
+    id TEXT PRIMARY KEY NOT NULL,
+    inbox_id TEXT UNIQUE NOT NULL,
+    account_sid TEXT NOT NULL,
+    auth_token TEXT NOT NULL,  -- Encrypted at rest when ENCRYPTION_KEY is set
+    phone_number TEXT NOT NULL,  -- E.164 number texts are sent from
+    max_segments INTEGER NOT NULL DEFAULT 3,  -- Longer replies are split into several texts
+    enabled INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_inbox_sms_configs_phone_number
+    ON inbox_sms_configs(phone_number);
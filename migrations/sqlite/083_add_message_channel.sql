@@ -0,0 +1,6 @@
+-- Source channel of each message
+-- channel is the channel the message came in through (chat, sms, telegram, ...);
+-- channel_metadata holds channel-specific details as JSON so the inbox can show the source
+
+ALTER TABLE messages ADD COLUMN channel TEXT;
+ALTER TABLE messages ADD COLUMN channel_metadata TEXT;
@@ -0,0 +1,15 @@
+-- Telegram bot configuration per inbox
+-- An inbox with a Telegram configuration receives updates through the bot webhook
+-- and sends replies through the Bot API; chats are tracked as channel identities
+
+CREATE TABLE IF NOT EXISTS inbox_telegram_configs (
+    id TEXT PRIMARY KEY NOT NULL,
+    inbox_id TEXT UNIQUE NOT NULL,
+    bot_token TEXT NOT NULL,  -- Encrypted at rest when ENCRYPTION_KEY is set
+    bot_username TEXT,  -- Filled in from getMe when the bot is connected
+    webhook_secret TEXT NOT NULL,  -- Sent back by Telegram in X-Telegram-Bot-Api-Secret-Token
+    enabled INTEGER NOT NULL DEFAULT 1,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE CASCADE
+);
@@ -0,0 +1,18 @@
+-- Indexes backing the sort options of the conversation list
+-- Each ends in the (created_at, id) tie-breakers the list query appends
+
+CREATE INDEX IF NOT EXISTS idx_conversations_created_at ON conversations(created_at, id);
+
+CREATE INDEX IF NOT EXISTS idx_conversations_last_message_at
+    ON conversations(last_message_at, created_at, id);
+
+-- Expression must match PRIORITY_RANK in persistence/conversations.rs
+CREATE INDEX IF NOT EXISTS idx_conversations_priority_rank ON conversations(
+    (CASE priority WHEN 'High' THEN 3 WHEN 'Medium' THEN 2 WHEN 'Low' THEN 1 ELSE 0 END),
+    created_at,
+    id
+);
+
+-- Earliest unmet deadline per applied SLA, for sorting by time to breach
+CREATE INDEX IF NOT EXISTS idx_sla_events_applied_sla_status_deadline
+    ON sla_events(applied_sla_id, status, deadline_at);
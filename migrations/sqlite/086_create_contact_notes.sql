@@ -0,0 +1,19 @@
+-- Notes agents keep about a contact, shown across all of the contact's conversations
+-- contact_id is the contact's user id, as in conversations.contact_id;
+-- pinned notes are listed first, most recently pinned on top
+
+CREATE TABLE IF NOT EXISTS contact_notes (
+    id TEXT PRIMARY KEY NOT NULL,
+    contact_id TEXT NOT NULL,
+    author_id TEXT,  -- Agent who wrote the note; NULL once the agent is deleted
+    content TEXT NOT NULL,
+    pinned INTEGER NOT NULL DEFAULT 0,
+    pinned_at TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (contact_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (author_id) REFERENCES users(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_contact_notes_contact
+    ON contact_notes(contact_id, pinned, created_at);
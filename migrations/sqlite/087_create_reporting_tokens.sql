@@ -0,0 +1,16 @@
+-- Workspace-level read-only tokens for reporting tools
+-- Separate from agent API keys: they only reach /api/reporting endpoints, which
+-- expose KPIs and conversation metadata but never message bodies.
+-- Only a SHA-256 hash of the token is stored; token_prefix is kept for display.
+
+CREATE TABLE IF NOT EXISTS reporting_tokens (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    token_prefix TEXT NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    created_by TEXT,  -- Admin who issued the token
+    last_used_at TEXT,
+    revoked_at TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (created_by) REFERENCES users(id) ON DELETE SET NULL
+);
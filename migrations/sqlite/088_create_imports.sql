@@ -0,0 +1,44 @@
+-- Imports of ticket exports from other helpdesks (Zendesk, Freshdesk)
+-- The export is stored with the import and processed by the task queue;
+-- data is cleared once the run finishes.
+
+CREATE TABLE IF NOT EXISTS imports (
+    id TEXT PRIMARY KEY NOT NULL,
+    source TEXT NOT NULL CHECK (source IN ('zendesk', 'freshdesk')),
+    format TEXT NOT NULL CHECK (format IN ('json', 'csv')),
+    inbox_id TEXT NOT NULL,
+    status TEXT NOT NULL CHECK (status IN ('pending', 'running', 'completed', 'failed')),
+    data TEXT NOT NULL,
+    total_records INTEGER NOT NULL DEFAULT 0,
+    processed_records INTEGER NOT NULL DEFAULT 0,
+    contacts_created INTEGER NOT NULL DEFAULT 0,
+    conversations_created INTEGER NOT NULL DEFAULT 0,
+    messages_created INTEGER NOT NULL DEFAULT 0,
+    tags_created INTEGER NOT NULL DEFAULT 0,
+    attachments_created INTEGER NOT NULL DEFAULT 0,
+    tickets_skipped INTEGER NOT NULL DEFAULT 0,
+    tickets_failed INTEGER NOT NULL DEFAULT 0,
+    errors TEXT NOT NULL DEFAULT '[]',  -- JSON array of per-ticket errors
+    created_by TEXT,
+    created_at TEXT NOT NULL,
+    started_at TEXT,
+    completed_at TEXT,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES users(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_imports_created_at ON imports(created_at);
+
+-- Maps records of the source helpdesk to what an import created for them,
+-- so running the same export again does not duplicate anything
+CREATE TABLE IF NOT EXISTS import_records (
+    source TEXT NOT NULL,
+    entity_type TEXT NOT NULL CHECK (entity_type IN ('conversation', 'message', 'attachment')),
+    external_id TEXT NOT NULL,
+    local_id TEXT NOT NULL,
+    import_id TEXT,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (source, entity_type, external_id),
+    FOREIGN KEY (import_id) REFERENCES imports(id) ON DELETE SET NULL
+);
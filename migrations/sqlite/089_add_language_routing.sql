@@ -0,0 +1,50 @@
+-- Migration 089: Language-based conversation routing
+-- Description: Stores the detected language of conversations, language skills
+-- on team memberships and the rules that route a language to a team. Rule-driven
+-- assignments are recorded in assignment_history with their source.
+
+ALTER TABLE conversations ADD COLUMN language TEXT;
+
+-- JSON array of ISO 639-1 codes the member can handle, e.g. '["en","es"]'
+ALTER TABLE team_memberships ADD COLUMN languages TEXT NOT NULL DEFAULT '[]';
+
+-- A NULL language marks the default team used when no language rule applies
+CREATE TABLE IF NOT EXISTS language_routing_rules (
+    id TEXT PRIMARY KEY,
+    language TEXT,
+    team_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (team_id) REFERENCES teams(id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX idx_language_routing_rules_language
+    ON language_routing_rules(COALESCE(language, ''));
+
+-- SQLite can't drop a foreign key, so recreate assignment_history. Rule-driven
+-- entries are made by "system", which is not a user.
+CREATE TABLE assignment_history_new (
+    id TEXT PRIMARY KEY NOT NULL,
+    conversation_id TEXT NOT NULL,
+    assigned_user_id TEXT,
+    assigned_team_id TEXT,
+    assigned_by TEXT NOT NULL,
+    assigned_at TEXT NOT NULL,
+    unassigned_at TEXT,
+    source TEXT NOT NULL DEFAULT 'manual' CHECK(source IN ('manual', 'rule')),
+    rule_id TEXT,
+    reason TEXT,
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE
+);
+
+INSERT INTO assignment_history_new (
+    id, conversation_id, assigned_user_id, assigned_team_id, assigned_by, assigned_at, unassigned_at
+)
+SELECT id, conversation_id, assigned_user_id, assigned_team_id, assigned_by, assigned_at, unassigned_at
+FROM assignment_history;
+
+DROP TABLE assignment_history;
+
+ALTER TABLE assignment_history_new RENAME TO assignment_history;
+
+CREATE INDEX idx_assignment_history_conversation ON assignment_history(conversation_id);
+CREATE INDEX idx_assignment_history_user ON assignment_history(assigned_user_id);
@@ -0,0 +1,19 @@
+-- Migration 090: Password reset token hygiene
+-- Feature: 017-password-reset
+-- Description: Reset tokens are stored as SHA-256 hashes and reset requests are
+-- rate limited per email address, whether or not an account exists for it.
+
+-- Outstanding plaintext tokens can't be hashed in SQL; drop them so affected
+-- users request a new link
+DELETE FROM password_reset_tokens;
+
+ALTER TABLE password_reset_tokens RENAME COLUMN token TO token_hash;
+
+CREATE TABLE IF NOT EXISTS password_reset_requests (
+    id TEXT PRIMARY KEY,
+    email TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_password_reset_requests_email_created
+ON password_reset_requests(email, created_at);
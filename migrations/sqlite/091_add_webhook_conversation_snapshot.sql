@@ -0,0 +1,6 @@
+-- Migration 091: Conversation snapshots in webhook payloads
+-- Description: Webhooks can opt in to receiving the current state of the
+-- conversation (status, priority, tags, assignee, contact) with each
+-- conversation event, so receivers don't have to call back for it.
+
+ALTER TABLE webhooks ADD COLUMN include_conversation_snapshot INTEGER NOT NULL DEFAULT 0;
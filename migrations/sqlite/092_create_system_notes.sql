@@ -0,0 +1,19 @@
+-- Migration 092: System notes for auto-generated emails
+-- Description: Out-of-office replies and bounce notifications received by email
+-- are kept as system notes on the conversation instead of contact messages, so
+-- they don't reopen it, start SLA clocks or trigger automations.
+
+CREATE TABLE IF NOT EXISTS system_notes (
+    id TEXT PRIMARY KEY NOT NULL,
+    conversation_id TEXT NOT NULL,
+    kind TEXT NOT NULL CHECK (kind IN ('auto_reply', 'bounce')),
+    from_address TEXT NOT NULL,
+    subject TEXT,
+    content TEXT NOT NULL,
+    email_message_id TEXT NOT NULL,  -- RFC 5322 Message-ID of the email
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_system_notes_conversation
+    ON system_notes(conversation_id, created_at);
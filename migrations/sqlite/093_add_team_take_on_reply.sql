@@ -0,0 +1,5 @@
+-- Migration 093: Take on reply
+-- Description: Teams can opt in to assigning an unassigned team conversation
+-- to the member who first replies to it.
+
+ALTER TABLE teams ADD COLUMN take_on_reply INTEGER NOT NULL DEFAULT 0;
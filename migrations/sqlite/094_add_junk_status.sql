@@ -0,0 +1,125 @@
+-- Migration 094: Junk conversations
+-- Description: Adds the 'junk' conversation status, per-sender junk counts used
+-- to junk new conversations automatically, and per-inbox sender blocklists.
+
+-- Allow 'junk' in the status CHECK constraint. SQLite can't alter a CHECK, so
+-- recreate conversations. Dropping the old table with foreign keys on would
+-- cascade-delete every message, tag and participant, and foreign keys can't be
+-- switched off inside the transaction sqlx runs migrations in. End that
+-- transaction, rebuild in one of our own with foreign keys off, and begin a new
+-- transaction for sqlx to commit.
+COMMIT;
+PRAGMA foreign_keys = OFF;
+BEGIN;
+
+CREATE TABLE conversations_new (
+    id TEXT PRIMARY KEY NOT NULL,
+    reference_number INTEGER NOT NULL UNIQUE,
+    status TEXT NOT NULL CHECK(status IN ('open', 'snoozed', 'resolved', 'closed', 'junk')) DEFAULT 'open',
+    inbox_id TEXT NOT NULL,
+    contact_id TEXT NOT NULL,
+    subject TEXT,
+    resolved_at TEXT, -- ISO 8601 timestamp
+    snoozed_until TEXT, -- ISO 8601 timestamp
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    version INTEGER NOT NULL DEFAULT 1,
+    last_message_id TEXT,
+    last_message_at TEXT,
+    last_reply_at TEXT,
+    assigned_user_id TEXT,
+    assigned_team_id TEXT,
+    assigned_at TEXT,
+    assigned_by TEXT,
+    priority TEXT,
+    closed_at TEXT,
+    sentiment_score REAL,
+    sentiment_trend TEXT CHECK(sentiment_trend IN ('improving', 'stable', 'declining')),
+    language TEXT,
+
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE RESTRICT,
+    FOREIGN KEY (contact_id) REFERENCES contacts(id) ON DELETE RESTRICT,
+    CHECK (status != 'snoozed' OR snoozed_until IS NOT NULL),
+    CHECK (status != 'resolved' OR resolved_at IS NOT NULL)
+);
+
+INSERT INTO conversations_new (
+    id, reference_number, status, inbox_id, contact_id, subject, resolved_at,
+    snoozed_until, created_at, updated_at, version, last_message_id,
+    last_message_at, last_reply_at, assigned_user_id, assigned_team_id,
+    assigned_at, assigned_by, priority, closed_at, sentiment_score,
+    sentiment_trend, language
+)
+SELECT
+    id, reference_number, status, inbox_id, contact_id, subject, resolved_at,
+    snoozed_until, created_at, updated_at, version, last_message_id,
+    last_message_at, last_reply_at, assigned_user_id, assigned_team_id,
+    assigned_at, assigned_by, priority, closed_at, sentiment_score,
+    sentiment_trend, language
+FROM conversations;
+
+DROP TABLE conversations;
+
+ALTER TABLE conversations_new RENAME TO conversations;
+
+-- Recreate indexes
+CREATE INDEX idx_conversations_status ON conversations(status);
+CREATE INDEX idx_conversations_inbox ON conversations(inbox_id);
+CREATE INDEX idx_conversations_contact ON conversations(contact_id);
+CREATE INDEX idx_conversations_snooze ON conversations(snoozed_until) WHERE status = 'snoozed';
+CREATE INDEX idx_conversations_assigned_user ON conversations(assigned_user_id);
+CREATE INDEX idx_conversations_assigned_team ON conversations(assigned_team_id);
+CREATE INDEX idx_conversations_priority ON conversations(priority);
+CREATE INDEX idx_conversations_closed_at ON conversations(closed_at);
+CREATE INDEX idx_conversations_sentiment ON conversations(sentiment_score);
+CREATE INDEX idx_conversations_created_at ON conversations(created_at, id);
+CREATE INDEX idx_conversations_last_message_at
+    ON conversations(last_message_at, created_at, id);
+CREATE INDEX idx_conversations_priority_rank ON conversations(
+    (CASE priority WHEN 'High' THEN 3 WHEN 'Medium' THEN 2 WHEN 'Low' THEN 1 ELSE 0 END),
+    created_at,
+    id
+);
+
+-- Recreate triggers
+CREATE TRIGGER conversations_ref_number_insert
+AFTER INSERT ON conversations
+WHEN NEW.reference_number IS NULL
+BEGIN
+    UPDATE conversations
+    SET reference_number = (SELECT COALESCE(MAX(reference_number), 99) + 1 FROM conversations)
+    WHERE rowid = NEW.rowid;
+END;
+
+CREATE TRIGGER conversations_updated_at_timestamp
+AFTER UPDATE ON conversations
+FOR EACH ROW
+BEGIN
+    UPDATE conversations SET updated_at = datetime('now') WHERE id = OLD.id;
+END;
+
+COMMIT;
+PRAGMA foreign_keys = ON;
+BEGIN;
+
+-- How often conversations from a sender were marked as junk (email, lowercased)
+CREATE TABLE IF NOT EXISTS sender_reputations (
+    email TEXT PRIMARY KEY NOT NULL,
+    junk_count INTEGER NOT NULL DEFAULT 0,
+    last_marked_at TEXT,
+    updated_at TEXT NOT NULL
+);
+
+-- Senders whose emails are dropped on ingestion for an inbox
+CREATE TABLE IF NOT EXISTS inbox_blocked_senders (
+    id TEXT PRIMARY KEY NOT NULL,
+    inbox_id TEXT NOT NULL,
+    email TEXT NOT NULL,
+    blocked_by TEXT,
+    conversation_id TEXT,
+    created_at TEXT NOT NULL,
+    UNIQUE(inbox_id, email),
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE CASCADE,
+    FOREIGN KEY (blocked_by) REFERENCES users(id) ON DELETE SET NULL,
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE SET NULL
+);
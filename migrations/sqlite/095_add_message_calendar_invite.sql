@@ -0,0 +1,5 @@
+-- Migration 095: Calendar invites on messages
+-- Description: Meeting invites parsed from inbound email (ICS) are stored as JSON
+-- so agents see the event details instead of a raw attachment.
+
+ALTER TABLE messages ADD COLUMN calendar_invite TEXT;
@@ -0,0 +1,21 @@
+-- Migration 096: API key usage
+-- Description: Hourly request and error counts per API key, so key owners
+-- can spot leaked or overused credentials. Rows older than the retention
+-- window are pruned by a background task.
+
+CREATE TABLE IF NOT EXISTS api_key_usage (
+    api_key TEXT NOT NULL,
+    agent_id TEXT NOT NULL,
+    -- Start of the hour the requests fall in (YYYY-MM-DDTHH:00:00Z)
+    hour TEXT NOT NULL,
+    request_count INTEGER NOT NULL DEFAULT 0,
+    error_count INTEGER NOT NULL DEFAULT 0,
+    last_seen_at TEXT NOT NULL,
+    last_status INTEGER NOT NULL,
+    last_ip TEXT,
+    PRIMARY KEY (api_key, hour),
+    FOREIGN KEY (agent_id) REFERENCES agents(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_api_key_usage_hour ON api_key_usage(hour);
+CREATE INDEX IF NOT EXISTS idx_api_key_usage_agent ON api_key_usage(agent_id);
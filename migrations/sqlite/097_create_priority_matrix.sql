@@ -0,0 +1,19 @@
+-- Migration 097: Priority matrix
+-- Description: Contact tiers and a tier × tag → priority matrix used to set
+-- conversation priority when a conversation is created or retagged.
+
+ALTER TABLE contacts ADD COLUMN tier TEXT;
+
+-- A NULL tier or tag matches any value
+CREATE TABLE IF NOT EXISTS priority_matrix_entries (
+    id TEXT PRIMARY KEY NOT NULL,
+    tier TEXT,
+    tag_id TEXT,
+    priority TEXT NOT NULL CHECK(priority IN ('Low', 'Medium', 'High')),
+    created_by TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_priority_matrix_entries_tag ON priority_matrix_entries(tag_id);
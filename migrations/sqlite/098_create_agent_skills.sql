@@ -0,0 +1,23 @@
+-- Migration 098: Agent skills
+-- Description: A catalog of skills (e.g. billing, api, german) and the agents
+-- who have them. A conversation tagged with a tag of the same name as a skill
+-- requires that skill when it is routed by skill match.
+
+CREATE TABLE IF NOT EXISTS skills (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL COLLATE NOCASE UNIQUE,
+    description TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS agent_skills (
+    user_id TEXT NOT NULL,
+    skill_id TEXT NOT NULL,
+    added_at TEXT NOT NULL,
+    PRIMARY KEY (user_id, skill_id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (skill_id) REFERENCES skills(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_agent_skills_skill ON agent_skills(skill_id);
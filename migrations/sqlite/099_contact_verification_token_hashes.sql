@@ -0,0 +1,9 @@
+-- Migration 099: Hash contact email verification tokens
+-- Description: Verification tokens are stored as SHA-256 hashes, like password
+-- reset tokens, so a database leak doesn't expose working confirmation links.
+
+-- Outstanding plaintext tokens can't be hashed in SQL; drop them so admins
+-- re-send the link to contacts still awaiting verification
+DELETE FROM contact_email_verifications;
+
+ALTER TABLE contact_email_verifications RENAME COLUMN token TO token_hash;
@@ -0,0 +1,20 @@
+-- Migration 100: Pending automation actions
+-- Description: Follow-up actions of Wait automation steps. Each row is run by a
+-- scheduled job once run_at passes, unless the rule condition stopped holding
+-- first, in which case it is cancelled.
+
+CREATE TABLE IF NOT EXISTS automation_pending_actions (
+    id TEXT PRIMARY KEY NOT NULL,
+    rule_id TEXT NOT NULL,
+    conversation_id TEXT NOT NULL,
+    action TEXT NOT NULL,
+    run_at TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'completed', 'cancelled')),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (rule_id) REFERENCES automation_rules(id) ON DELETE CASCADE,
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_automation_pending_actions_conversation
+    ON automation_pending_actions(conversation_id, status);
@@ -0,0 +1,15 @@
+-- Migration 101: Email participants per conversation
+-- Description: Addresses on a conversation's email thread (the contact and
+-- anyone CC'd). Suppressed participants are kept for history but left out of
+-- outbound replies.
+
+CREATE TABLE IF NOT EXISTS conversation_email_participants (
+    conversation_id TEXT NOT NULL,
+    email TEXT NOT NULL COLLATE NOCASE,
+    suppressed INTEGER NOT NULL DEFAULT 0,
+    suppressed_at TEXT,
+    suppressed_by TEXT,
+    added_at TEXT NOT NULL,
+    PRIMARY KEY (conversation_id, email),
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE
+);
@@ -0,0 +1,38 @@
+-- Replays of past events into a webhook
+-- Events are picked from stored deliveries when the backfill is created and
+-- handed to the delivery queue a batch at a time; they are dropped once the
+-- backfill finishes.
+
+CREATE TABLE IF NOT EXISTS webhook_backfills (
+    id TEXT PRIMARY KEY NOT NULL,
+    webhook_id TEXT NOT NULL,
+    event_types TEXT NOT NULL,  -- JSON array of event types
+    from_time TEXT NOT NULL,
+    to_time TEXT NOT NULL,
+    status TEXT NOT NULL CHECK (status IN ('pending', 'running', 'completed', 'failed')),
+    total_events INTEGER NOT NULL DEFAULT 0,
+    processed_events INTEGER NOT NULL DEFAULT 0,
+    error_message TEXT,
+    created_by TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    started_at TEXT,
+    completed_at TEXT,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (webhook_id) REFERENCES webhooks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_backfills_webhook_id ON webhook_backfills(webhook_id);
+
+CREATE TABLE IF NOT EXISTS webhook_backfill_events (
+    backfill_id TEXT NOT NULL,
+    sequence INTEGER NOT NULL,
+    event_type TEXT NOT NULL,
+    payload TEXT NOT NULL,
+    occurred_at TEXT NOT NULL,
+    PRIMARY KEY (backfill_id, sequence),
+    FOREIGN KEY (backfill_id) REFERENCES webhook_backfills(id) ON DELETE CASCADE
+);
+
+-- Stored deliveries are scanned by event type and time when picking events
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_event_type_attempted_at
+    ON webhook_deliveries(event_type, attempted_at);
@@ -0,0 +1,51 @@
+-- Migration 103: Conversation event history
+-- Description: Append-only log of conversation state changes (status,
+-- assignment, priority, tags and SLA), written in the same transaction as
+-- the change itself. Powers the conversation timeline and lets the current
+-- state be rebuilt from the log.
+
+CREATE TABLE IF NOT EXISTS conversation_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    conversation_id TEXT NOT NULL,
+    event_type TEXT NOT NULL CHECK(event_type IN (
+        'created', 'status_changed', 'assignee_changed', 'team_changed',
+        'priority_changed', 'tag_added', 'tag_removed',
+        'sla_applied', 'sla_status_changed', 'sla_met', 'sla_breached'
+    )),
+    actor_id TEXT,
+    data TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_conversation_events_conversation
+    ON conversation_events(conversation_id, id);
+
+-- History is never rewritten
+CREATE TRIGGER IF NOT EXISTS conversation_events_append_only
+BEFORE UPDATE ON conversation_events
+BEGIN
+    SELECT RAISE(ABORT, 'conversation_events is append-only');
+END;
+
+-- Baseline for existing conversations: their current state as the first event
+INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+SELECT id, 'created', NULL,
+       json_object(
+           'status', status,
+           'inbox_id', inbox_id,
+           'assigned_user_id', assigned_user_id,
+           'assigned_team_id', assigned_team_id,
+           'priority', priority
+       ),
+       created_at
+FROM conversations
+ORDER BY created_at, id;
+
+INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+SELECT ct.conversation_id, 'tag_added', ct.added_by,
+       json_object('tag_id', ct.tag_id, 'tag', t.name),
+       ct.added_at
+FROM conversation_tags ct
+LEFT JOIN tags t ON t.id = ct.tag_id
+ORDER BY ct.added_at;
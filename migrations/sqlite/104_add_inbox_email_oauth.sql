@@ -0,0 +1,41 @@
+-- Migration 104: OAuth2 (XOAUTH2) sign-in for inbox mailboxes
+-- Description: Lets an inbox authenticate to Gmail / Microsoft 365 over IMAP
+-- and SMTP with OAuth2 access tokens instead of a password. Tokens are
+-- obtained with the authorization-code flow and refreshed before they expire.
+
+ALTER TABLE inbox_email_configs
+    ADD COLUMN auth_method TEXT NOT NULL DEFAULT 'password'
+    CHECK(auth_method IN ('password', 'oauth2'));
+
+ALTER TABLE inbox_email_configs
+    ADD COLUMN oauth_provider TEXT
+    CHECK(oauth_provider IS NULL OR oauth_provider IN ('google', 'microsoft'));
+
+-- Tokens of an authorized mailbox; both tokens are encrypted at rest
+CREATE TABLE IF NOT EXISTS inbox_email_oauth_tokens (
+    inbox_id TEXT PRIMARY KEY NOT NULL,
+    provider TEXT NOT NULL CHECK(provider IN ('google', 'microsoft')),
+    access_token TEXT NOT NULL,
+    refresh_token TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    scope TEXT,
+    authorized_by TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE CASCADE
+);
+
+-- Pending authorizations: state and PKCE verifier, consumed by the callback
+CREATE TABLE IF NOT EXISTS inbox_email_oauth_states (
+    state TEXT PRIMARY KEY NOT NULL,
+    inbox_id TEXT NOT NULL,
+    provider TEXT NOT NULL CHECK(provider IN ('google', 'microsoft')),
+    pkce_verifier TEXT NOT NULL,
+    created_by TEXT,
+    created_at TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_inbox_email_oauth_states_expires_at
+    ON inbox_email_oauth_states(expires_at);
@@ -0,0 +1,78 @@
+-- Migration 105: Contact changes in conversation history
+-- Description: Records which contact a conversation belongs to, so merging
+-- contacts shows up in the history, and backfills history for conversations
+-- that were written without it (helpdesk imports).
+
+-- Allow 'contact_changed' in the event_type CHECK constraint. SQLite can't
+-- alter a CHECK, so recreate the table. Nothing references it, so this is
+-- safe with foreign keys on. Existing 'created' events get the conversation's
+-- current contact, since earlier merges weren't recorded.
+CREATE TABLE conversation_events_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    conversation_id TEXT NOT NULL,
+    event_type TEXT NOT NULL CHECK(event_type IN (
+        'created', 'status_changed', 'assignee_changed', 'team_changed',
+        'contact_changed', 'priority_changed', 'tag_added', 'tag_removed',
+        'sla_applied', 'sla_status_changed', 'sla_met', 'sla_breached'
+    )),
+    actor_id TEXT,
+    data TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE
+);
+
+INSERT INTO conversation_events_new (id, conversation_id, event_type, actor_id, data, created_at)
+SELECT e.id, e.conversation_id, e.event_type, e.actor_id,
+       CASE
+           WHEN e.event_type = 'created' THEN json_set(e.data, '$.contact_id', c.contact_id)
+           ELSE e.data
+       END,
+       e.created_at
+FROM conversation_events e
+LEFT JOIN conversations c ON c.id = e.conversation_id
+ORDER BY e.id;
+
+DROP TRIGGER IF EXISTS conversation_events_append_only;
+DROP TABLE conversation_events;
+ALTER TABLE conversation_events_new RENAME TO conversation_events;
+
+CREATE INDEX IF NOT EXISTS idx_conversation_events_conversation
+    ON conversation_events(conversation_id, id);
+
+-- History is never rewritten
+CREATE TRIGGER IF NOT EXISTS conversation_events_append_only
+BEFORE UPDATE ON conversation_events
+BEGIN
+    SELECT RAISE(ABORT, 'conversation_events is append-only');
+END;
+
+-- Imported conversations had no history: their current state as the first
+-- event, followed by their tags
+INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+SELECT c.id, 'created', NULL,
+       json_object(
+           'status', c.status,
+           'inbox_id', c.inbox_id,
+           'contact_id', c.contact_id,
+           'assigned_user_id', c.assigned_user_id,
+           'assigned_team_id', c.assigned_team_id,
+           'priority', c.priority
+       ),
+       c.created_at
+FROM conversations c
+WHERE NOT EXISTS (SELECT 1 FROM conversation_events e WHERE e.conversation_id = c.id)
+ORDER BY c.created_at, c.id;
+
+INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+SELECT ct.conversation_id, 'tag_added', ct.added_by,
+       json_object('tag_id', ct.tag_id, 'tag', t.name),
+       ct.added_at
+FROM conversation_tags ct
+LEFT JOIN tags t ON t.id = ct.tag_id
+WHERE NOT EXISTS (
+    SELECT 1 FROM conversation_events e
+    WHERE e.conversation_id = ct.conversation_id
+      AND e.event_type = 'tag_added'
+      AND json_extract(e.data, '$.tag_id') = ct.tag_id
+)
+ORDER BY ct.added_at;
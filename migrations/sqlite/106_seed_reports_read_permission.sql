@@ -0,0 +1,12 @@
+-- Migration 106: Seed reporting permission
+-- Description: Customer satisfaction answers are reporting data; reading them
+-- requires reports:read, which only admins have by default.
+
+INSERT INTO permissions (id, name, description, created_at, updated_at) VALUES
+    ('reports-perm-001', 'reports:read', 'Read reports and customer satisfaction responses', datetime('now'), datetime('now'));
+
+-- Assign permission to Admin role
+INSERT INTO role_permissions (role_id, permission_id, created_at)
+SELECT '00000000-0000-0000-0000-000000000001', id, datetime('now')
+FROM permissions
+WHERE name = 'reports:read';
@@ -0,0 +1,23 @@
+-- Conversation tagging and routing by attachment type
+-- Inbox-level rules that tag a conversation and/or route it to a team when an
+-- inbound message carries an attachment matching a MIME type or file extension
+
+CREATE TABLE IF NOT EXISTS attachment_rules (
+    id TEXT PRIMARY KEY NOT NULL,
+    inbox_id TEXT NOT NULL,
+    pattern TEXT NOT NULL, -- application/pdf, image/*, .zip
+    tag_id TEXT,
+    team_id TEXT,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    created_by TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (inbox_id) REFERENCES inboxes(id) ON DELETE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE,
+    FOREIGN KEY (team_id) REFERENCES teams(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by) REFERENCES users(id),
+    CHECK (tag_id IS NOT NULL OR team_id IS NOT NULL)
+);
+
+-- Index for loading the enabled rules of an inbox at ingestion time
+CREATE INDEX IF NOT EXISTS idx_attachment_rules_inbox ON attachment_rules(inbox_id, enabled);
@@ -0,0 +1,54 @@
+-- Response due reminders for assignees
+-- Agents opt in to be reminded when a conversation assigned to them has a
+-- contact message that has waited longer than their threshold, optionally
+-- repeating until someone replies
+
+CREATE TABLE IF NOT EXISTS response_reminder_settings (
+    user_id TEXT PRIMARY KEY NOT NULL,
+    enabled INTEGER NOT NULL DEFAULT 1,
+    remind_after_minutes INTEGER NOT NULL CHECK (remind_after_minutes > 0),
+    repeat_after_minutes INTEGER CHECK (repeat_after_minutes IS NULL OR repeat_after_minutes > 0),
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- Last reminder per conversation and assignee; message_id is the first
+-- unanswered contact message, so a reply starts a new cycle
+CREATE TABLE IF NOT EXISTS response_reminders (
+    conversation_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    message_id TEXT NOT NULL,
+    reminder_count INTEGER NOT NULL DEFAULT 1,
+    last_reminded_at TEXT NOT NULL,
+    PRIMARY KEY (conversation_id, user_id),
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- SQLite doesn't support altering CHECK constraints, so recreate user_notifications
+CREATE TABLE user_notifications_new (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL,
+    type TEXT NOT NULL CHECK(type IN ('assignment', 'mention', 'alert', 'reminder')),
+    created_at TEXT NOT NULL,
+    is_read INTEGER NOT NULL DEFAULT 0,
+    conversation_id TEXT,
+    message_id TEXT,
+    actor_id TEXT,
+
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE SET NULL,
+    FOREIGN KEY (message_id) REFERENCES messages(id) ON DELETE SET NULL,
+    FOREIGN KEY (actor_id) REFERENCES users(id) ON DELETE SET NULL
+);
+
+INSERT INTO user_notifications_new SELECT * FROM user_notifications;
+
+DROP TABLE user_notifications;
+
+ALTER TABLE user_notifications_new RENAME TO user_notifications;
+
+CREATE INDEX idx_user_notifications_user_id ON user_notifications(user_id);
+CREATE INDEX idx_user_notifications_user_read ON user_notifications(user_id, is_read);
+CREATE INDEX idx_user_notifications_created_at ON user_notifications(created_at);
+CREATE INDEX idx_user_notifications_type ON user_notifications(type);
@@ -0,0 +1,16 @@
+-- Saved conversation views
+-- Named conversation search queries an agent keeps for reuse, e.g.
+-- "status:open tag:billing assignee:me"
+
+CREATE TABLE IF NOT EXISTS saved_views (
+    id TEXT PRIMARY KEY NOT NULL,
+    user_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    query TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE (user_id, name)
+);
+
+CREATE INDEX idx_saved_views_user_id ON saved_views(user_id);
@@ -0,0 +1,24 @@
+-- GDPR data requests for contacts
+-- Audit record of every export and erasure of a contact's personal data.
+-- Erasures are confirmed in a second step: the first request stores a
+-- short-lived confirmation token hash and stays 'pending' until confirmed.
+-- contact_id is the contact's user id; there is no foreign key so the record
+-- outlives the contact.
+
+CREATE TABLE IF NOT EXISTS gdpr_requests (
+    id TEXT PRIMARY KEY NOT NULL,
+    contact_id TEXT NOT NULL,
+    request_type TEXT NOT NULL CHECK (request_type IN ('export', 'erasure')),
+    status TEXT NOT NULL CHECK (status IN ('pending', 'completed')),
+    message_redaction TEXT CHECK (message_redaction IN ('full', 'identifiers', 'keep')),
+    confirmation_token_hash TEXT,
+    requested_by TEXT NOT NULL,
+    confirmed_by TEXT,
+    summary TEXT,
+    created_at TEXT NOT NULL,
+    expires_at TEXT,
+    completed_at TEXT
+);
+
+CREATE INDEX idx_gdpr_requests_contact ON gdpr_requests(contact_id, created_at);
+CREATE UNIQUE INDEX idx_gdpr_requests_token ON gdpr_requests(confirmation_token_hash);
@@ -0,0 +1,59 @@
+-- Agent-to-agent conversation transfers with a handover note
+-- A transfer reassigns a conversation to an agent and/or team. When it
+-- requires acceptance it stays pending until the receiver accepts or declines.
+
+CREATE TABLE IF NOT EXISTS conversation_transfers (
+    id TEXT PRIMARY KEY NOT NULL,
+    conversation_id TEXT NOT NULL,
+    from_user_id TEXT,
+    from_team_id TEXT,
+    to_user_id TEXT,
+    to_team_id TEXT,
+    note TEXT NOT NULL,
+    requires_acceptance INTEGER NOT NULL DEFAULT 0,
+    status TEXT NOT NULL CHECK (status IN ('pending', 'completed', 'declined')),
+    requested_by TEXT NOT NULL,
+    responded_by TEXT,
+    created_at TEXT NOT NULL,
+    responded_at TEXT,
+    CHECK (to_user_id IS NOT NULL OR to_team_id IS NOT NULL),
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE,
+    FOREIGN KEY (to_user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (to_team_id) REFERENCES teams(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_conversation_transfers_conversation
+    ON conversation_transfers(conversation_id, created_at);
+
+-- At most one transfer awaiting acceptance per conversation
+CREATE UNIQUE INDEX IF NOT EXISTS idx_conversation_transfers_pending
+    ON conversation_transfers(conversation_id)
+    WHERE status = 'pending';
+
+-- SQLite doesn't support altering CHECK constraints, so recreate user_notifications
+CREATE TABLE user_notifications_new (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL,
+    type TEXT NOT NULL CHECK(type IN ('assignment', 'mention', 'alert', 'reminder', 'transfer')),
+    created_at TEXT NOT NULL,
+    is_read INTEGER NOT NULL DEFAULT 0,
+    conversation_id TEXT,
+    message_id TEXT,
+    actor_id TEXT,
+
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE SET NULL,
+    FOREIGN KEY (message_id) REFERENCES messages(id) ON DELETE SET NULL,
+    FOREIGN KEY (actor_id) REFERENCES users(id) ON DELETE SET NULL
+);
+
+INSERT INTO user_notifications_new SELECT * FROM user_notifications;
+
+DROP TABLE user_notifications;
+
+ALTER TABLE user_notifications_new RENAME TO user_notifications;
+
+CREATE INDEX idx_user_notifications_user_id ON user_notifications(user_id);
+CREATE INDEX idx_user_notifications_user_read ON user_notifications(user_id, is_read);
+CREATE INDEX idx_user_notifications_created_at ON user_notifications(created_at);
+CREATE INDEX idx_user_notifications_type ON user_notifications(type);
@@ -0,0 +1,13 @@
+-- Failure history and dead state for background jobs
+-- `failures` holds a JSON array of the most recent failed attempts
+-- ({attempt, kind, message, failed_at}). Jobs that use up their attempts
+-- become 'dead' and stay until an admin requeues them.
+
+ALTER TABLE jobs ADD COLUMN failures TEXT NOT NULL DEFAULT '[]';
+
+ALTER TABLE jobs ADD COLUMN dead_at DATETIME;
+
+-- Jobs that failed permanently before this migration are dead jobs now
+UPDATE jobs SET status = 'dead', dead_at = updated_at WHERE status = 'failed';
+
+CREATE INDEX IF NOT EXISTS idx_jobs_dead ON jobs(status, dead_at);
@@ -0,0 +1,18 @@
+-- Additional contacts taking part in a conversation
+-- The conversation's own contact_id stays the primary contact. Others are
+-- added when they reply on the thread (e.g. a CC'd colleague) or by an agent;
+-- `added_by` is the agent, or NULL when added from an inbound message.
+
+CREATE TABLE IF NOT EXISTS conversation_contacts (
+    conversation_id TEXT NOT NULL,
+    contact_id TEXT NOT NULL,
+    added_by TEXT,
+    added_at TEXT NOT NULL,
+    PRIMARY KEY (conversation_id, contact_id),
+    FOREIGN KEY (conversation_id) REFERENCES conversations(id) ON DELETE CASCADE,
+    FOREIGN KEY (contact_id) REFERENCES contacts(id) ON DELETE CASCADE,
+    FOREIGN KEY (added_by) REFERENCES users(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_conversation_contacts_contact
+    ON conversation_contacts(contact_id);
@@ -0,0 +1,11 @@
+-- Migration 114: Sender authentication of inbound email
+-- Description: SPF/DKIM/DMARC results from the receiving server's
+-- Authentication-Results header are stored on the message as JSON. Each
+-- inbox decides whether email failing authentication is only recorded,
+-- flagged for agents or quarantined.
+
+ALTER TABLE messages ADD COLUMN email_authentication TEXT;
+
+ALTER TABLE inbox_email_configs
+    ADD COLUMN auth_failure_policy TEXT NOT NULL DEFAULT 'accept'
+    CHECK(auth_failure_policy IN ('accept', 'flag', 'quarantine'));
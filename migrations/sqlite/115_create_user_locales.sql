@@ -0,0 +1,9 @@
+-- Locale a user chose for the web UI, API messages and notification emails
+-- Users without a row get the locale negotiated from Accept-Language.
+
+CREATE TABLE IF NOT EXISTS user_locales (
+    user_id TEXT PRIMARY KEY NOT NULL,
+    locale TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
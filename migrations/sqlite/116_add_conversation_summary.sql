@@ -0,0 +1,3 @@
+-- Short rolling summary shown as the conversation preview in inbox lists
+
+ALTER TABLE conversations ADD COLUMN summary TEXT;
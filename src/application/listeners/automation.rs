@@ -475,6 +475,73 @@ pub async fn run_automation_listener(
                             }
                         }
                     }
+                    SystemEvent::CsatReceived {
+                        csat_response_id,
+                        conversation_id,
+                        score,
+                        comment: _,
+                        timestamp,
+                    } => {
+                        tracing::info!(
+                            "Automation: CSAT {} received for conversation {} with score {} at {}",
+                            csat_response_id,
+                            conversation_id,
+                            score,
+                            timestamp
+                        );
+
+                        // Trigger automation rules for the CSAT response
+                        if let Ok(mut conversation) = automation_conversation_service
+                            .get_conversation(&conversation_id)
+                            .await
+                        {
+                            // Evaluate against the score that triggered this event
+                            conversation.csat_score = Some(score);
+                            if let Err(e) = automation_rule_service
+                                .handle_conversation_event("csat.received", &conversation, "system")
+                                .await
+                            {
+                                tracing::error!(
+                                    "Failed to execute automation rules for CSAT response: {}",
+                                    e
+                                );
+                            }
+                        }
+                    }
+                    SystemEvent::SentimentDropped {
+                        conversation_id,
+                        message_id,
+                        previous_score,
+                        current_score,
+                        timestamp: _,
+                    } => {
+                        tracing::info!(
+                            "Automation: Sentiment of conversation {} dropped from {:.2} to {:.2} (message {})",
+                            conversation_id,
+                            previous_score,
+                            current_score,
+                            message_id
+                        );
+
+                        if let Ok(conversation) = automation_conversation_service
+                            .get_conversation(&conversation_id)
+                            .await
+                        {
+                            if let Err(e) = automation_rule_service
+                                .handle_conversation_event(
+                                    "conversation.sentiment_dropped",
+                                    &conversation,
+                                    "system",
+                                )
+                                .await
+                            {
+                                tracing::error!(
+                                    "Failed to execute automation rules for sentiment drop: {}",
+                                    e
+                                );
+                            }
+                        }
+                    }
                     SystemEvent::ConversationPriorityChanged {
                         conversation_id,
                         previous_priority,
@@ -0,0 +1,45 @@
+use crate::application::services::ConversationSummaryService;
+use crate::domain::ports::event_bus::EventBus;
+use crate::SystemEvent;
+use std::sync::Arc;
+use tokio_stream::StreamExt;
+
+/// Refresh conversation summaries on events that change the issue or state
+pub async fn run_conversation_summary_listener(
+    event_bus: Arc<dyn EventBus>,
+    summary_service: ConversationSummaryService,
+) {
+    tracing::info!("Conversation summary listener started");
+
+    let mut receiver = event_bus.subscribe();
+
+    while let Some(msg) = receiver.next().await {
+        let conversation_id = match msg {
+            Ok(SystemEvent::ConversationCreated {
+                conversation_id, ..
+            })
+            | Ok(SystemEvent::ConversationStatusChanged {
+                conversation_id, ..
+            })
+            | Ok(SystemEvent::MessageReceived {
+                conversation_id, ..
+            })
+            | Ok(SystemEvent::MessageSent {
+                conversation_id, ..
+            }) => conversation_id,
+            Ok(_) => continue,
+            Err(e) => {
+                tracing::error!("Conversation summary listener error receiving event: {}", e);
+                continue;
+            }
+        };
+
+        if let Err(e) = summary_service.refresh(&conversation_id).await {
+            tracing::error!(
+                "Failed to refresh summary of conversation {}: {}",
+                conversation_id,
+                e
+            );
+        }
+    }
+}
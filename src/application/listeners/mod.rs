@@ -1 +1,4 @@
 pub mod automation;
+pub mod conversation_summary;
+pub mod priority_matrix;
+pub mod skill_routing;
@@ -0,0 +1,42 @@
+use crate::application::services::PriorityMatrixService;
+use crate::domain::ports::event_bus::EventBus;
+use crate::SystemEvent;
+use std::sync::Arc;
+use tokio_stream::StreamExt;
+
+/// Apply the priority matrix to new and retagged conversations
+pub async fn run_priority_matrix_listener(
+    event_bus: Arc<dyn EventBus>,
+    priority_matrix_service: PriorityMatrixService,
+) {
+    tracing::info!("Priority matrix listener started");
+
+    let mut receiver = event_bus.subscribe();
+
+    while let Some(msg) = receiver.next().await {
+        let conversation_id = match msg {
+            Ok(SystemEvent::ConversationCreated {
+                conversation_id, ..
+            })
+            | Ok(SystemEvent::ConversationTagsChanged {
+                conversation_id, ..
+            }) => conversation_id,
+            Ok(_) => continue,
+            Err(e) => {
+                tracing::error!("Priority matrix listener error receiving event: {}", e);
+                continue;
+            }
+        };
+
+        if let Err(e) = priority_matrix_service
+            .apply_to_conversation(&conversation_id)
+            .await
+        {
+            tracing::error!(
+                "Failed to apply priority matrix to conversation {}: {}",
+                conversation_id,
+                e
+            );
+        }
+    }
+}
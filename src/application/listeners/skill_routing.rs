@@ -0,0 +1,36 @@
+use crate::application::services::AssignmentService;
+use crate::domain::ports::event_bus::EventBus;
+use crate::SystemEvent;
+use std::sync::Arc;
+use tokio_stream::StreamExt;
+
+/// Route retagged conversations to agents with the skills their tags require
+pub async fn run_skill_routing_listener(
+    event_bus: Arc<dyn EventBus>,
+    assignment_service: AssignmentService,
+) {
+    tracing::info!("Skill routing listener started");
+
+    let mut receiver = event_bus.subscribe();
+
+    while let Some(msg) = receiver.next().await {
+        let conversation_id = match msg {
+            Ok(SystemEvent::ConversationTagsChanged {
+                conversation_id, ..
+            }) => conversation_id,
+            Ok(_) => continue,
+            Err(e) => {
+                tracing::error!("Skill routing listener error receiving event: {}", e);
+                continue;
+            }
+        };
+
+        if let Err(e) = assignment_service.route_by_skills(&conversation_id).await {
+            tracing::error!(
+                "Failed to route conversation {} by skills: {}",
+                conversation_id,
+                e
+            );
+        }
+    }
+}
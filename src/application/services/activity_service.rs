@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::entities::{ActivityFeedResponse, ActivityType},
+    domain::errors::{ActivityError, ActivityResult},
+    domain::ports::activity_repository::ActivityRepository,
+    infrastructure::http::middleware::AuthenticatedUser,
+};
+
+const MAX_ACTIVITY_PAGE: i64 = 200;
+
+/// Service behind the admin "what's happening" feed
+///
+/// Composes conversation creations, SLA breaches, automation rule runs and
+/// failed webhook deliveries into one newest-first timeline.
+#[derive(Clone)]
+pub struct ActivityService {
+    repo: Arc<dyn ActivityRepository>,
+}
+
+impl ActivityService {
+    pub fn new(repo: Arc<dyn ActivityRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// A page of the feed; `types` is a comma-separated filter, all types when absent
+    pub async fn list_activity(
+        &self,
+        auth_user: &AuthenticatedUser,
+        types: Option<&str>,
+        before: Option<&str>,
+        limit: i64,
+    ) -> ActivityResult<ActivityFeedResponse> {
+        if !auth_user.is_admin() {
+            return Err(ActivityError::Forbidden(
+                "Administrator role required".to_string(),
+            ));
+        }
+
+        let types = parse_activity_types(types)?;
+        let limit = limit.clamp(1, MAX_ACTIVITY_PAGE);
+        let items = self.repo.list_activity(&types, before, limit).await?;
+
+        let next_before = if items.len() as i64 == limit {
+            items.last().map(|item| item.occurred_at.clone())
+        } else {
+            None
+        };
+
+        Ok(ActivityFeedResponse { items, next_before })
+    }
+}
+
+fn parse_activity_types(types: Option<&str>) -> ActivityResult<Vec<ActivityType>> {
+    let Some(types) = types.filter(|t| !t.trim().is_empty()) else {
+        return Ok(ActivityType::ALL.to_vec());
+    };
+
+    let mut parsed = Vec::new();
+    for name in types.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        let activity_type = name.parse().map_err(ActivityError::Validation)?;
+        if !parsed.contains(&activity_type) {
+            parsed.push(activity_type);
+        }
+    }
+    Ok(parsed)
+}
@@ -0,0 +1,154 @@
+use crate::{
+    application::services::{AgentService, PasswordResetService, TeamService},
+    domain::entities::{
+        BulkAgentResult, BulkAgentStatus, BulkCreateAgentsRequest, BulkCreateAgentsResponse,
+        TeamMemberRole,
+    },
+    infrastructure::http::middleware::{ApiError, ApiResult, AuthenticatedUser},
+    shared::utils::email_validator::validate_and_normalize_email,
+};
+
+/// Bulk agent onboarding: create accounts, add them to a team and email
+/// invitations so each agent sets their own password
+#[derive(Clone)]
+pub struct AgentOnboardingService {
+    agent_service: AgentService,
+    team_service: TeamService,
+    password_reset_service: PasswordResetService,
+}
+
+impl AgentOnboardingService {
+    pub fn new(
+        agent_service: AgentService,
+        team_service: TeamService,
+        password_reset_service: PasswordResetService,
+    ) -> Self {
+        Self {
+            agent_service,
+            team_service,
+            password_reset_service,
+        }
+    }
+
+    /// Invite a list of agents with the same role and team
+    ///
+    /// The role and team are checked once up front; after that every address
+    /// succeeds or fails on its own, so one bad row does not block the rest.
+    pub async fn bulk_invite_agents(
+        &self,
+        auth_user: &AuthenticatedUser,
+        request: BulkCreateAgentsRequest,
+    ) -> ApiResult<BulkCreateAgentsResponse> {
+        if !auth_user.is_admin() {
+            return Err(ApiError::Forbidden(
+                "Requires 'agents:create' permission".to_string(),
+            ));
+        }
+
+        let role = self
+            .agent_service
+            .resolve_role(request.role_id.as_deref())
+            .await?;
+        if let Some(team_id) = &request.team_id {
+            self.team_service.get_team(team_id).await?;
+        }
+
+        let invited_by = match &auth_user.agent.last_name {
+            Some(last_name) => format!("{} {}", auth_user.agent.first_name, last_name),
+            None => auth_user.agent.first_name.clone(),
+        };
+        let mut seen: Vec<String> = Vec::new();
+        let mut results = Vec::with_capacity(request.emails.len());
+
+        for email in request.emails {
+            // Repeats are reported against the later row, not as a conflict
+            let normalized = validate_and_normalize_email(&email).ok();
+            if let Some(normalized) = &normalized {
+                if seen.contains(normalized) {
+                    results.push(BulkAgentResult::failed(
+                        email,
+                        "Duplicate email in request".to_string(),
+                    ));
+                    continue;
+                }
+                seen.push(normalized.clone());
+            }
+
+            let result = self
+                .invite_agent(&email, &role.id, request.team_id.as_deref(), &invited_by)
+                .await;
+            results.push(result);
+        }
+
+        let invited = results
+            .iter()
+            .filter(|r| r.status == BulkAgentStatus::Invited)
+            .count();
+        let failed = results.len() - invited;
+
+        tracing::info!(
+            "Bulk agent invitation by {}: {} invited, {} failed",
+            auth_user.user.id,
+            invited,
+            failed
+        );
+
+        Ok(BulkCreateAgentsResponse {
+            results,
+            invited,
+            failed,
+        })
+    }
+
+    async fn invite_agent(
+        &self,
+        email: &str,
+        role_id: &str,
+        team_id: Option<&str>,
+        invited_by: &str,
+    ) -> BulkAgentResult {
+        let (agent_id, user_id, email) = match self
+            .agent_service
+            .create_invited_agent(email, role_id)
+            .await
+        {
+            Ok(created) => created,
+            Err(e) => return BulkAgentResult::failed(email.to_string(), e.to_string()),
+        };
+
+        let mut result = BulkAgentResult {
+            email,
+            status: BulkAgentStatus::Invited,
+            agent_id: Some(agent_id),
+            user_id: Some(user_id.clone()),
+            invitation_sent: false,
+            error: None,
+        };
+
+        if let Some(team_id) = team_id {
+            if let Err(e) = self
+                .team_service
+                .add_member(team_id, &user_id, TeamMemberRole::Member)
+                .await
+            {
+                result.status = BulkAgentStatus::Failed;
+                result.error = Some(format!("Agent created but not added to team: {}", e));
+                return result;
+            }
+        }
+
+        match self
+            .password_reset_service
+            .send_invitation(&user_id, &result.email, invited_by)
+            .await
+        {
+            Ok(sent) => result.invitation_sent = sent,
+            Err(e) => {
+                result.status = BulkAgentStatus::Failed;
+                result.error = Some(format!("Agent created but invitation failed: {}", e));
+            }
+        }
+
+        result
+    }
+}
@@ -55,33 +55,14 @@ impl AgentService {
             ));
         }
 
-        // Validate email
-        let email = validate_and_normalize_email(&request.email)?;
-
-        // Check if email already exists for agents (per-type uniqueness)
-        if let Some(_) = self
-            .user_repo
-            .get_user_by_email_and_type(&email, &UserType::Agent)
-            .await?
-        {
-            return Err(ApiError::Conflict(
-                "Email already exists for this user type".to_string(),
-            ));
-        }
+        let email = self.normalize_new_agent_email(&request.email).await?;
 
         // Generate random password (16 characters with mixed complexity)
         let password = generate_random_password();
         let password_hash = hash_password(&password)?;
 
         // Use provided role_id or default to Agent role
-        let role_id = request.role_id.as_deref().unwrap_or(DEFAULT_AGENT_ROLE_ID);
-
-        // Verify role exists
-        let role = self
-            .role_repo
-            .get_role_by_id(role_id)
-            .await?
-            .ok_or_else(|| ApiError::BadRequest(format!("Role not found: {}", role_id)))?;
+        let role = self.resolve_role(request.role_id.as_deref()).await?;
 
         // Create agent with role in transaction
         let (agent_id, user_id) = self
@@ -91,7 +72,7 @@ impl AgentService {
                 &request.first_name,
                 request.last_name.as_deref(),
                 &password_hash,
-                role_id,
+                &role.id,
             )
             .await?;
 
@@ -125,6 +106,56 @@ impl AgentService {
         })
     }
 
+    /// Validate an email for a new agent and make sure no agent already uses it
+    async fn normalize_new_agent_email(&self, email: &str) -> ApiResult<String> {
+        let email = validate_and_normalize_email(email)?;
+
+        // Check if email already exists for agents (per-type uniqueness)
+        if self
+            .user_repo
+            .get_user_by_email_and_type(&email, &UserType::Agent)
+            .await?
+            .is_some()
+        {
+            return Err(ApiError::Conflict(
+                "Email already exists for this user type".to_string(),
+            ));
+        }
+
+        Ok(email)
+    }
+
+    /// Look up the role for a new agent, defaulting to the Agent role
+    pub async fn resolve_role(&self, role_id: Option<&str>) -> ApiResult<Role> {
+        let role_id = role_id.unwrap_or(DEFAULT_AGENT_ROLE_ID);
+        self.role_repo
+            .get_role_by_id(role_id)
+            .await?
+            .ok_or_else(|| ApiError::BadRequest(format!("Role not found: {}", role_id)))
+    }
+
+    /// Create an agent who will choose their own password from an invitation
+    ///
+    /// The account gets a random password that is never shown to anyone, and
+    /// the email's local part as first name until the agent updates it.
+    /// Returns `(agent_id, user_id, email)`.
+    pub async fn create_invited_agent(
+        &self,
+        email: &str,
+        role_id: &str,
+    ) -> ApiResult<(String, String, String)> {
+        let email = self.normalize_new_agent_email(email).await?;
+        let password_hash = hash_password(&generate_random_password())?;
+        let first_name = email.split('@').next().unwrap_or(&email).to_string();
+
+        let (agent_id, user_id) = self
+            .agent_repo
+            .create_agent_with_role(&email, &first_name, None, &password_hash, role_id)
+            .await?;
+
+        Ok((agent_id, user_id, email))
+    }
+
     /// Get an agent by ID
     pub async fn get_agent(&self, id: &str) -> ApiResult<AgentResponse> {
         // Get user
@@ -0,0 +1,166 @@
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+use crate::{
+    domain::entities::{
+        usage_error_rate, ApiKeyUsageOverview, ApiKeyUsageResponse, API_KEY_USAGE_RETENTION_DAYS,
+        DEFAULT_API_KEY_USAGE_WINDOW_HOURS,
+    },
+    domain::errors::{ApiKeyUsageError, ApiKeyUsageResult},
+    domain::ports::{
+        api_key_repository::ApiKeyRepository, api_key_usage_repository::ApiKeyUsageRepository,
+    },
+    infrastructure::http::middleware::AuthenticatedUser,
+};
+
+/// Service for per-key API usage counters
+///
+/// Every request authenticated with an API key is counted in an hourly
+/// bucket along with whether it failed, so owners can spot a key that is
+/// being used from somewhere it shouldn't be.
+#[derive(Clone)]
+pub struct ApiKeyUsageService {
+    repo: Arc<dyn ApiKeyUsageRepository>,
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+}
+
+impl ApiKeyUsageService {
+    pub fn new(
+        repo: Arc<dyn ApiKeyUsageRepository>,
+        api_key_repo: Arc<dyn ApiKeyRepository>,
+    ) -> Self {
+        Self { repo, api_key_repo }
+    }
+
+    /// Count a request made with the key and the status it was answered with
+    pub async fn record_request(
+        &self,
+        api_key: &str,
+        agent_id: &str,
+        status: u16,
+        ip: Option<&str>,
+    ) -> ApiKeyUsageResult<()> {
+        let now = Utc::now();
+        let ip = ip.filter(|ip| !ip.is_empty() && *ip != "unknown");
+        self.repo
+            .record_api_key_request(
+                api_key,
+                agent_id,
+                &hour_start(now),
+                status,
+                ip,
+                &now.to_rfc3339(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Usage of one key; visible to its owner and to user admins
+    pub async fn get_key_usage(
+        &self,
+        auth_user: &AuthenticatedUser,
+        api_key: &str,
+        hours: Option<i64>,
+    ) -> ApiKeyUsageResult<ApiKeyUsageResponse> {
+        let window_hours = validate_window(hours)?;
+
+        // Revoked keys keep their history, so fall back to the recorded owner
+        let owner = match self.api_key_repo.get_agent_by_api_key(api_key).await? {
+            Some(agent) => Some(agent.id),
+            None => self.repo.get_api_key_usage_owner(api_key).await?,
+        };
+        let agent_id =
+            owner.ok_or_else(|| ApiKeyUsageError::NotFound("API key not found".to_string()))?;
+        if agent_id != auth_user.agent.id && !can_view_all_usage(auth_user) {
+            return Err(ApiKeyUsageError::Forbidden(
+                "Only the key owner or an administrator can view its usage".to_string(),
+            ));
+        }
+
+        let hourly = self
+            .repo
+            .list_api_key_usage(api_key, &window_start(window_hours))
+            .await?;
+        let request_count = hourly.iter().map(|bucket| bucket.request_count).sum();
+        let error_count = hourly.iter().map(|bucket| bucket.error_count).sum();
+        let last = hourly.last();
+
+        Ok(ApiKeyUsageResponse {
+            api_key: api_key.to_string(),
+            agent_id,
+            window_hours,
+            request_count,
+            error_count,
+            error_rate: usage_error_rate(request_count, error_count),
+            last_seen_at: last.map(|bucket| bucket.last_seen_at.clone()),
+            last_status: last.map(|bucket| bucket.last_status),
+            last_ip: last.and_then(|bucket| bucket.last_ip.clone()),
+            hourly,
+        })
+    }
+
+    /// Usage totals across all keys (admin only)
+    pub async fn usage_overview(
+        &self,
+        auth_user: &AuthenticatedUser,
+        hours: Option<i64>,
+    ) -> ApiKeyUsageResult<ApiKeyUsageOverview> {
+        if !can_view_all_usage(auth_user) {
+            return Err(ApiKeyUsageError::Forbidden(
+                "Admin access required".to_string(),
+            ));
+        }
+        let window_hours = validate_window(hours)?;
+
+        let keys = self
+            .repo
+            .summarize_api_key_usage(&window_start(window_hours))
+            .await?;
+        Ok(ApiKeyUsageOverview {
+            window_hours,
+            request_count: keys.iter().map(|key| key.request_count).sum(),
+            error_count: keys.iter().map(|key| key.error_count).sum(),
+            keys,
+        })
+    }
+
+    /// Drop hourly buckets past the retention window
+    pub async fn prune_old_usage(&self) -> ApiKeyUsageResult<u64> {
+        let cutoff = Utc::now() - Duration::days(API_KEY_USAGE_RETENTION_DAYS);
+        let deleted = self
+            .repo
+            .delete_api_key_usage_before(&hour_start(cutoff))
+            .await?;
+        Ok(deleted)
+    }
+}
+
+/// Same check as the API key listing: user admins see every key
+fn can_view_all_usage(auth_user: &AuthenticatedUser) -> bool {
+    auth_user
+        .permissions
+        .iter()
+        .any(|p| p == "users:read" || p == "users:manage" || p == "*")
+}
+
+fn validate_window(hours: Option<i64>) -> ApiKeyUsageResult<i64> {
+    let hours = hours.unwrap_or(DEFAULT_API_KEY_USAGE_WINDOW_HOURS);
+    let max_hours = API_KEY_USAGE_RETENTION_DAYS * 24;
+    if !(1..=max_hours).contains(&hours) {
+        return Err(ApiKeyUsageError::Validation(format!(
+            "hours must be between 1 and {}",
+            max_hours
+        )));
+    }
+    Ok(hours)
+}
+
+/// Start of the hour `time` falls in, as stored in `api_key_usage.hour`
+fn hour_start(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%dT%H:00:00Z").to_string()
+}
+
+/// First hour bucket inside a window of `hours`, counting the current hour
+fn window_start(hours: i64) -> String {
+    hour_start(Utc::now() - Duration::hours(hours - 1))
+}
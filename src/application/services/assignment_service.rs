@@ -1,19 +1,25 @@
 use crate::domain::ports::{
     agent_repository::AgentRepository, assignment_repository::AssignmentRepository,
     availability_repository::AvailabilityRepository,
-    conversation_repository::ConversationRepository, role_repository::RoleRepository,
-    team_repository::TeamRepository, user_repository::UserRepository,
+    conversation_repository::ConversationRepository,
+    language_routing_repository::LanguageRoutingRepository, role_repository::RoleRepository,
+    skill_repository::SkillRepository, team_repository::TeamRepository,
+    user_repository::UserRepository,
 };
 use crate::{
     application::services::{NotificationService, SlaService},
     domain::entities::{
-        AgentAvailability, AssignmentHistory, Conversation, ConversationStatus, Permission,
-        UserNotification,
+        AgentAvailability, AssignmentHistory, Conversation, ConversationStatus,
+        CreateLanguageRoutingRuleRequest, CreateSkillRequest, LanguageRoutingRule,
+        MemberLanguagesResponse, Message, Permission, Skill, UpdateMemberLanguagesRequest,
+        UpdateSkillRequest, UserNotification,
     },
     domain::events::SystemEvent,
     domain::ports::event_bus::EventBus,
+    domain::services::detect_language,
     infrastructure::http::middleware::error::{ApiError, ApiResult},
     infrastructure::providers::connection_manager::ConnectionManager,
+    shared::validation::Validate,
 };
 use std::sync::Arc;
 
@@ -31,6 +37,8 @@ pub struct AssignmentService {
     notification_service: NotificationService,
     connection_manager: Arc<dyn ConnectionManager>,
     sla_service: Option<Arc<SlaService>>,
+    language_routing_repo: Option<Arc<dyn LanguageRoutingRepository>>,
+    skill_repo: Option<Arc<dyn SkillRepository>>,
 }
 
 impl AssignmentService {
@@ -58,6 +66,8 @@ impl AssignmentService {
             notification_service,
             connection_manager,
             sla_service: None,
+            language_routing_repo: None,
+            skill_repo: None,
         }
     }
 
@@ -66,6 +76,28 @@ impl AssignmentService {
         self.sla_service = Some(sla_service);
     }
 
+    /// Set the language routing repository (enables routing by detected language)
+    pub fn set_language_routing_repo(&mut self, repo: Arc<dyn LanguageRoutingRepository>) {
+        self.language_routing_repo = Some(repo);
+    }
+
+    fn language_routing_repo(&self) -> ApiResult<&Arc<dyn LanguageRoutingRepository>> {
+        self.language_routing_repo
+            .as_ref()
+            .ok_or_else(|| ApiError::Internal("Language routing is not configured".to_string()))
+    }
+
+    /// Set the skill repository (enables skill catalog and skill-match routing)
+    pub fn set_skill_repo(&mut self, repo: Arc<dyn SkillRepository>) {
+        self.skill_repo = Some(repo);
+    }
+
+    fn skill_repo(&self) -> ApiResult<&Arc<dyn SkillRepository>> {
+        self.skill_repo
+            .as_ref()
+            .ok_or_else(|| ApiError::Internal("Skill routing is not configured".to_string()))
+    }
+
     // Helper: Check if user has permission
     fn has_permission(&self, permissions: &[Permission], required: &str) -> bool {
         permissions.iter().any(|p| p.name == required)
@@ -408,6 +440,435 @@ impl AssignmentService {
             })
     }
 
+    /// Route an unassigned conversation to a team by the language of an incoming message
+    ///
+    /// Uses the rule for the detected language when its team has a member with
+    /// that language skill, otherwise the default-team rule. Returns the updated
+    /// conversation, or None when no rule applied.
+    #[tracing::instrument(skip(self, message), fields(message_id = %message.id))]
+    pub async fn route_by_language(&self, message: &Message) -> ApiResult<Option<Conversation>> {
+        let Some(repo) = &self.language_routing_repo else {
+            return Ok(None);
+        };
+
+        let conversation = self
+            .conversation_repo
+            .get_conversation_by_id(&message.conversation_id)
+            .await?
+            .ok_or_else(|| {
+                ApiError::NotFound(format!(
+                    "Conversation {} not found",
+                    message.conversation_id
+                ))
+            })?;
+        if conversation.assigned_team_id.is_some() || conversation.assigned_user_id.is_some() {
+            return Ok(None);
+        }
+
+        let language = detect_language(&message.content);
+        if let Some(language) = language {
+            repo.set_conversation_language(&conversation.id, language)
+                .await?;
+        }
+
+        let mut matched = None;
+        if let Some(language) = language {
+            if let Some(rule) = repo.find_language_rule(Some(language)).await? {
+                if repo
+                    .team_has_language_skill(&rule.team_id, language)
+                    .await?
+                {
+                    matched = Some((rule, format!("Detected language '{}'", language)));
+                }
+            }
+        }
+        if matched.is_none() {
+            if let Some(rule) = repo.find_language_rule(None).await? {
+                let reason = match language {
+                    Some(language) => format!(
+                        "No team with '{}' speakers; routed to the default team",
+                        language
+                    ),
+                    None => "Language not detected; routed to the default team".to_string(),
+                };
+                matched = Some((rule, reason));
+            }
+        }
+        let Some((rule, reason)) = matched else {
+            return Ok(None);
+        };
+
+        self.assign_team_by_rule(&conversation.id, &rule.team_id, &rule.id, reason)
+            .await?;
+
+        self.conversation_repo
+            .get_conversation_by_id(&conversation.id)
+            .await
+    }
+
+    /// Route a conversation to a team on behalf of a routing rule, unless it is
+    /// already assigned to a team or an agent
+    ///
+    /// Returns whether the conversation was routed.
+    pub async fn route_unassigned_to_team(
+        &self,
+        conversation_id: &str,
+        team_id: &str,
+        rule_id: &str,
+        reason: String,
+    ) -> ApiResult<bool> {
+        let conversation = self
+            .conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| {
+                ApiError::NotFound(format!("Conversation {} not found", conversation_id))
+            })?;
+        if conversation.assigned_team_id.is_some() || conversation.assigned_user_id.is_some() {
+            return Ok(false);
+        }
+
+        self.assign_team_by_rule(conversation_id, team_id, rule_id, reason)
+            .await?;
+        Ok(true)
+    }
+
+    /// Assign a conversation to a team as the system, recording the rule that did it
+    async fn assign_team_by_rule(
+        &self,
+        conversation_id: &str,
+        team_id: &str,
+        rule_id: &str,
+        reason: String,
+    ) -> ApiResult<()> {
+        self.conversation_repo
+            .assign_conversation_to_team(conversation_id, Some(team_id.to_string()), None)
+            .await?;
+        self.apply_team_sla(conversation_id, team_id).await?;
+
+        let history = AssignmentHistory::from_rule(
+            conversation_id.to_string(),
+            team_id.to_string(),
+            rule_id.to_string(),
+            reason,
+        );
+        self.assignment_repo.record_assignment(&history).await?;
+
+        tracing::info!(
+            "Routed conversation {} to team {} by rule {}",
+            conversation_id,
+            team_id,
+            rule_id
+        );
+
+        let _ = self.event_bus.publish(SystemEvent::ConversationAssigned {
+            conversation_id: conversation_id.to_string(),
+            assigned_user_id: None,
+            assigned_team_id: Some(team_id.to_string()),
+            assigned_by: "system".to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        });
+
+        Ok(())
+    }
+
+    /// Assign an unassigned team conversation to the agent replying to it
+    ///
+    /// Only applies when the team has take-on-reply enabled and the agent is a
+    /// member of it. Returns the updated conversation, or None when nothing changed.
+    #[tracing::instrument(skip(self, conversation), fields(conversation_id = %conversation.id))]
+    pub async fn take_on_reply(
+        &self,
+        conversation: &Conversation,
+        agent_id: &str,
+    ) -> ApiResult<Option<Conversation>> {
+        if conversation.assigned_user_id.is_some() {
+            return Ok(None);
+        }
+        let Some(team_id) = conversation.assigned_team_id.as_deref() else {
+            return Ok(None);
+        };
+
+        let Some(team) = self.team_repo.get_team_by_id(team_id).await? else {
+            return Ok(None);
+        };
+        if !team.take_on_reply || !self.team_repo.is_team_member(team_id, agent_id).await? {
+            return Ok(None);
+        }
+
+        // The snapshot may be stale: another agent replying at the same time
+        // can take the conversation first
+        let claimed = self
+            .conversation_repo
+            .claim_unassigned_conversation(&conversation.id, agent_id, Some(agent_id.to_string()))
+            .await?;
+        if !claimed {
+            return Ok(None);
+        }
+
+        let _ = self
+            .conversation_repo
+            .add_conversation_participant(&conversation.id, agent_id, "assignee")
+            .await;
+
+        let history = AssignmentHistory::on_reply(
+            conversation.id.clone(),
+            agent_id.to_string(),
+            team_id.to_string(),
+        );
+        self.assignment_repo.record_assignment(&history).await?;
+
+        tracing::info!(
+            "Conversation {} taken on reply by agent {} (team {})",
+            conversation.id,
+            agent_id,
+            team_id
+        );
+
+        let _ = self.event_bus.publish(SystemEvent::ConversationAssigned {
+            conversation_id: conversation.id.clone(),
+            assigned_user_id: Some(agent_id.to_string()),
+            assigned_team_id: Some(team_id.to_string()),
+            assigned_by: agent_id.to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        });
+
+        self.conversation_repo
+            .get_conversation_by_id(&conversation.id)
+            .await
+    }
+
+    /// Assign an open, unassigned conversation to an agent with the skills its tags require
+    ///
+    /// A tag requires the skill of the same name. The least loaded online agent
+    /// with every required skill gets the conversation; when it already belongs
+    /// to a team only that team's members are considered. Returns the updated
+    /// conversation, or None when nothing changed.
+    #[tracing::instrument(skip(self))]
+    pub async fn route_by_skills(&self, conversation_id: &str) -> ApiResult<Option<Conversation>> {
+        let Some(repo) = &self.skill_repo else {
+            return Ok(None);
+        };
+
+        let conversation = self
+            .conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| {
+                ApiError::NotFound(format!("Conversation {} not found", conversation_id))
+            })?;
+        if conversation.assigned_user_id.is_some()
+            || conversation.status != ConversationStatus::Open
+        {
+            return Ok(None);
+        }
+
+        let skills = repo
+            .get_conversation_required_skills(conversation_id)
+            .await?;
+        if skills.is_empty() {
+            return Ok(None);
+        }
+        let skill_ids: Vec<String> = skills.iter().map(|skill| skill.id.clone()).collect();
+        let skill_names: Vec<&str> = skills.iter().map(|skill| skill.name.as_str()).collect();
+
+        let team_id = conversation.assigned_team_id.clone();
+        let candidates = repo
+            .find_skilled_agents(&skill_ids, team_id.as_deref())
+            .await?;
+        let Some(agent_id) = candidates.into_iter().next() else {
+            tracing::info!(
+                "No available agent with skills [{}] for conversation {}",
+                skill_names.join(", "),
+                conversation_id
+            );
+            return Ok(None);
+        };
+
+        self.conversation_repo
+            .assign_conversation_to_user(conversation_id, Some(agent_id.clone()), None)
+            .await?;
+
+        let _ = self
+            .conversation_repo
+            .add_conversation_participant(conversation_id, &agent_id, "assignee")
+            .await;
+
+        let history = AssignmentHistory::from_skill_match(
+            conversation_id.to_string(),
+            agent_id.clone(),
+            team_id.clone(),
+            format!("Has required skills: {}", skill_names.join(", ")),
+        );
+        self.assignment_repo.record_assignment(&history).await?;
+
+        tracing::info!(
+            "Routed conversation {} to agent {} by skills [{}]",
+            conversation_id,
+            agent_id,
+            skill_names.join(", ")
+        );
+
+        let _ = self.event_bus.publish(SystemEvent::ConversationAssigned {
+            conversation_id: conversation_id.to_string(),
+            assigned_user_id: Some(agent_id),
+            assigned_team_id: team_id,
+            assigned_by: "system".to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        });
+
+        self.conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await
+    }
+
+    pub async fn list_skills(&self) -> ApiResult<Vec<Skill>> {
+        self.skill_repo()?.list_skills().await
+    }
+
+    pub async fn create_skill(&self, request: CreateSkillRequest) -> ApiResult<Skill> {
+        request.check().map_err(ApiError::Validation)?;
+        let skill = Skill::new(request.name.trim().to_string(), request.description);
+        self.skill_repo()?.create_skill(&skill).await?;
+        Ok(skill)
+    }
+
+    pub async fn update_skill(&self, id: &str, request: UpdateSkillRequest) -> ApiResult<Skill> {
+        request.check().map_err(ApiError::Validation)?;
+        let repo = self.skill_repo()?;
+        let mut skill = repo
+            .get_skill_by_id(id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("Skill {} not found", id)))?;
+
+        skill.description = request.description;
+        skill.updated_at = chrono::Utc::now().to_rfc3339();
+        repo.update_skill(&skill).await?;
+        Ok(skill)
+    }
+
+    pub async fn delete_skill(&self, id: &str) -> ApiResult<()> {
+        if !self.skill_repo()?.delete_skill(id).await? {
+            return Err(ApiError::NotFound(format!("Skill {} not found", id)));
+        }
+        Ok(())
+    }
+
+    pub async fn get_agent_skills(&self, user_id: &str) -> ApiResult<Vec<Skill>> {
+        self.agent_repo
+            .get_agent_by_user_id(user_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("Agent {} not found", user_id)))?;
+
+        self.skill_repo()?.get_agent_skills(user_id).await
+    }
+
+    /// Replace the skills an agent has
+    pub async fn set_agent_skills(
+        &self,
+        user_id: &str,
+        mut skill_ids: Vec<String>,
+    ) -> ApiResult<Vec<Skill>> {
+        let repo = self.skill_repo()?;
+        self.agent_repo
+            .get_agent_by_user_id(user_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("Agent {} not found", user_id)))?;
+
+        skill_ids.sort();
+        skill_ids.dedup();
+        for skill_id in &skill_ids {
+            if repo.get_skill_by_id(skill_id).await?.is_none() {
+                return Err(ApiError::BadRequest(format!(
+                    "Skill {} not found",
+                    skill_id
+                )));
+            }
+        }
+
+        repo.set_agent_skills(user_id, &skill_ids).await?;
+        repo.get_agent_skills(user_id).await
+    }
+
+    pub async fn list_language_rules(&self) -> ApiResult<Vec<LanguageRoutingRule>> {
+        self.language_routing_repo()?.list_language_rules().await
+    }
+
+    pub async fn create_language_rule(
+        &self,
+        request: CreateLanguageRoutingRuleRequest,
+    ) -> ApiResult<LanguageRoutingRule> {
+        let repo = self.language_routing_repo()?;
+        self.team_repo
+            .get_team_by_id(&request.team_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("Team {} not found", request.team_id)))?;
+
+        let rule = LanguageRoutingRule::new(request.language, request.team_id);
+        repo.create_language_rule(&rule).await?;
+        Ok(rule)
+    }
+
+    pub async fn delete_language_rule(&self, id: &str) -> ApiResult<()> {
+        if !self
+            .language_routing_repo()?
+            .delete_language_rule(id)
+            .await?
+        {
+            return Err(ApiError::NotFound(format!(
+                "Language routing rule {} not found",
+                id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Replace the languages a team member can handle
+    pub async fn set_member_languages(
+        &self,
+        team_id: &str,
+        user_id: &str,
+        request: UpdateMemberLanguagesRequest,
+    ) -> ApiResult<MemberLanguagesResponse> {
+        let mut languages = request.languages;
+        languages.sort();
+        languages.dedup();
+
+        if !self
+            .language_routing_repo()?
+            .set_member_languages(team_id, user_id, &languages)
+            .await?
+        {
+            return Err(ApiError::NotFound(format!(
+                "User {} is not a member of team {}",
+                user_id, team_id
+            )));
+        }
+
+        Ok(MemberLanguagesResponse {
+            team_id: team_id.to_string(),
+            user_id: user_id.to_string(),
+            languages,
+        })
+    }
+
+    /// Assignment changes of a conversation, newest first
+    pub async fn get_assignment_history(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<AssignmentHistory>> {
+        self.conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| {
+                ApiError::NotFound(format!("Conversation {} not found", conversation_id))
+            })?;
+
+        self.conversation_repo
+            .get_assignment_history(conversation_id)
+            .await
+    }
+
     /// Apply team's SLA policy to conversation
     async fn apply_team_sla(&self, conversation_id: &str, team_id: &str) -> ApiResult<()> {
         // Get the team
@@ -0,0 +1,298 @@
+use crate::{
+    application::services::AssignmentService,
+    domain::entities::{
+        AttachmentRule, AttachmentRuleOutcome, CreateAttachmentRuleRequest, MessageAttachment,
+        UpdateAttachmentRuleRequest,
+    },
+    domain::errors::{AttachmentRuleError, AttachmentRuleResult},
+    domain::events::SystemEvent,
+    domain::ports::attachment_rule_repository::AttachmentRuleRepository,
+    domain::ports::conversation_tag_repository::ConversationTagRepository,
+    domain::ports::event_bus::EventBus,
+    domain::ports::inbox_repository::InboxRepository,
+    domain::ports::tag_repository::TagRepository,
+    domain::ports::team_repository::TeamRepository,
+};
+use std::sync::Arc;
+
+/// Service for inbox-level tagging and routing by attachment type
+#[derive(Clone)]
+pub struct AttachmentRuleService {
+    attachment_rule_repo: Arc<dyn AttachmentRuleRepository>,
+    inbox_repo: Arc<dyn InboxRepository>,
+    tag_repo: TagRepository,
+    team_repo: Arc<dyn TeamRepository>,
+    conversation_tag_repo: Arc<dyn ConversationTagRepository>,
+    assignment_service: AssignmentService,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl AttachmentRuleService {
+    pub fn new(
+        attachment_rule_repo: Arc<dyn AttachmentRuleRepository>,
+        inbox_repo: Arc<dyn InboxRepository>,
+        tag_repo: TagRepository,
+        team_repo: Arc<dyn TeamRepository>,
+        conversation_tag_repo: Arc<dyn ConversationTagRepository>,
+        assignment_service: AssignmentService,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            attachment_rule_repo,
+            inbox_repo,
+            tag_repo,
+            team_repo,
+            conversation_tag_repo,
+            assignment_service,
+            event_bus,
+        }
+    }
+
+    async fn ensure_inbox_exists(&self, inbox_id: &str) -> AttachmentRuleResult<()> {
+        self.inbox_repo.get_inbox(inbox_id).await?.ok_or_else(|| {
+            AttachmentRuleError::NotFound(format!("Inbox {} not found", inbox_id))
+        })?;
+        Ok(())
+    }
+
+    async fn ensure_targets_exist(&self, rule: &AttachmentRule) -> AttachmentRuleResult<()> {
+        if let Some(tag_id) = &rule.tag_id {
+            self.tag_repo.get_tag_by_id(tag_id).await?.ok_or_else(|| {
+                AttachmentRuleError::NotFound(format!("Tag {} not found", tag_id))
+            })?;
+        }
+        if let Some(team_id) = &rule.team_id {
+            self.team_repo
+                .get_team_by_id(team_id)
+                .await?
+                .ok_or_else(|| {
+                    AttachmentRuleError::NotFound(format!("Team {} not found", team_id))
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Create an attachment rule for an inbox
+    pub async fn create_rule(
+        &self,
+        inbox_id: &str,
+        request: CreateAttachmentRuleRequest,
+        created_by: &str,
+    ) -> AttachmentRuleResult<AttachmentRule> {
+        self.ensure_inbox_exists(inbox_id).await?;
+
+        let rule = AttachmentRule::new(
+            inbox_id.to_string(),
+            request.pattern,
+            request.tag_id,
+            request.team_id,
+            created_by.to_string(),
+        );
+        rule.validate().map_err(AttachmentRuleError::Validation)?;
+        self.ensure_targets_exist(&rule).await?;
+
+        self.attachment_rule_repo
+            .create_attachment_rule(&rule)
+            .await?;
+
+        tracing::info!(
+            "Attachment rule {} ({}) created for inbox {} by {}",
+            rule.id,
+            rule.pattern,
+            inbox_id,
+            created_by
+        );
+
+        Ok(rule)
+    }
+
+    /// Get a rule by ID
+    pub async fn get_rule(&self, id: &str) -> AttachmentRuleResult<AttachmentRule> {
+        self.attachment_rule_repo
+            .get_attachment_rule(id)
+            .await?
+            .ok_or_else(|| {
+                AttachmentRuleError::NotFound(format!("Attachment rule {} not found", id))
+            })
+    }
+
+    /// List all rules (enabled and disabled) for an inbox
+    pub async fn list_rules(&self, inbox_id: &str) -> AttachmentRuleResult<Vec<AttachmentRule>> {
+        self.ensure_inbox_exists(inbox_id).await?;
+        Ok(self
+            .attachment_rule_repo
+            .list_attachment_rules(inbox_id)
+            .await?)
+    }
+
+    /// Update an existing rule
+    pub async fn update_rule(
+        &self,
+        id: &str,
+        request: UpdateAttachmentRuleRequest,
+    ) -> AttachmentRuleResult<AttachmentRule> {
+        let mut rule = self.get_rule(id).await?;
+
+        if let Some(pattern) = request.pattern {
+            rule.pattern = crate::domain::entities::normalize_attachment_pattern(&pattern);
+        }
+        if let Some(tag_id) = request.tag_id {
+            rule.tag_id = Some(tag_id).filter(|id| !id.is_empty());
+        }
+        if let Some(team_id) = request.team_id {
+            rule.team_id = Some(team_id).filter(|id| !id.is_empty());
+        }
+        if let Some(enabled) = request.enabled {
+            rule.enabled = enabled;
+        }
+        rule.validate().map_err(AttachmentRuleError::Validation)?;
+        self.ensure_targets_exist(&rule).await?;
+        rule.updated_at = chrono::Utc::now().to_rfc3339();
+
+        self.attachment_rule_repo
+            .update_attachment_rule(&rule)
+            .await?;
+
+        Ok(rule)
+    }
+
+    /// Delete a rule
+    pub async fn delete_rule(&self, id: &str) -> AttachmentRuleResult<()> {
+        self.get_rule(id).await?;
+        self.attachment_rule_repo.delete_attachment_rule(id).await?;
+        Ok(())
+    }
+
+    /// Apply the inbox's enabled rules to the attachments of an inbound message.
+    ///
+    /// Every matching rule adds its tag; the first matching rule with a team
+    /// routes the conversation there, unless it is already assigned to a team
+    /// or an agent.
+    pub async fn apply_to_attachments(
+        &self,
+        conversation_id: &str,
+        inbox_id: &str,
+        attachments: &[MessageAttachment],
+    ) -> AttachmentRuleResult<AttachmentRuleOutcome> {
+        if attachments.is_empty() {
+            return Ok(AttachmentRuleOutcome::default());
+        }
+
+        let rules = self
+            .attachment_rule_repo
+            .list_enabled_attachment_rules(inbox_id)
+            .await?;
+        let matched: Vec<(&AttachmentRule, &MessageAttachment)> = rules
+            .iter()
+            .filter_map(|rule| {
+                attachments
+                    .iter()
+                    .find(|a| rule.matches(&a.filename, a.content_type.as_deref()))
+                    .map(|attachment| (rule, attachment))
+            })
+            .collect();
+        if matched.is_empty() {
+            return Ok(AttachmentRuleOutcome::default());
+        }
+
+        let tags_applied = self.apply_tags(conversation_id, &matched).await?;
+        let routed_team_id = self.route(conversation_id, &matched).await?;
+
+        Ok(AttachmentRuleOutcome {
+            tags_applied,
+            routed_team_id,
+        })
+    }
+
+    async fn apply_tags(
+        &self,
+        conversation_id: &str,
+        matched: &[(&AttachmentRule, &MessageAttachment)],
+    ) -> AttachmentRuleResult<Vec<String>> {
+        let previous_tags: Vec<String> = self
+            .conversation_tag_repo
+            .get_conversation_tags(conversation_id)
+            .await?
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+
+        // Tags are recorded as added by the rule's creator, one event per creator
+        let mut applied: Vec<String> = Vec::new();
+        let mut applied_by: Vec<(String, Vec<String>)> = Vec::new();
+        for (rule, attachment) in matched {
+            let Some(tag_id) = &rule.tag_id else {
+                continue;
+            };
+            if previous_tags.contains(tag_id) || applied.contains(tag_id) {
+                continue;
+            }
+            self.conversation_tag_repo
+                .add_conversation_tag(conversation_id, tag_id, &rule.created_by)
+                .await?;
+            applied.push(tag_id.clone());
+            match applied_by
+                .iter_mut()
+                .find(|(actor, _)| *actor == rule.created_by)
+            {
+                Some((_, tags)) => tags.push(tag_id.clone()),
+                None => applied_by.push((rule.created_by.clone(), vec![tag_id.clone()])),
+            }
+
+            tracing::info!(
+                "Attachment rule {} applied tag {} to conversation {} for {}",
+                rule.id,
+                tag_id,
+                conversation_id,
+                attachment.filename
+            );
+        }
+
+        let mut current_tags = previous_tags;
+        for (actor, tags) in applied_by {
+            let mut new_tags = current_tags.clone();
+            new_tags.extend(tags);
+
+            let _ = self
+                .event_bus
+                .publish(SystemEvent::ConversationTagsChanged {
+                    conversation_id: conversation_id.to_string(),
+                    previous_tags: current_tags,
+                    new_tags: new_tags.clone(),
+                    changed_by: actor,
+                    timestamp: chrono::Utc::now().to_rfc3339(),
+                });
+            current_tags = new_tags;
+        }
+
+        Ok(applied)
+    }
+
+    async fn route(
+        &self,
+        conversation_id: &str,
+        matched: &[(&AttachmentRule, &MessageAttachment)],
+    ) -> AttachmentRuleResult<Option<String>> {
+        let Some((rule, team_id, attachment)) = matched
+            .iter()
+            .find_map(|(rule, attachment)| Some((rule, rule.team_id.as_ref()?, attachment)))
+        else {
+            return Ok(None);
+        };
+
+        let routed = self
+            .assignment_service
+            .route_unassigned_to_team(
+                conversation_id,
+                team_id,
+                &rule.id,
+                format!(
+                    "Attachment '{}' matched '{}'",
+                    attachment.filename, rule.pattern
+                ),
+            )
+            .await?;
+
+        Ok(routed.then(|| team_id.clone()))
+    }
+}
@@ -1,7 +1,13 @@
 use crate::domain::entities::MessageAttachment;
 use crate::domain::ports::attachment_repository::AttachmentRepository;
 use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
+use crate::shared::utils::generate_secret;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
 use std::sync::Arc;
+use std::time::Duration;
+
+type HmacSha256 = Hmac<Sha256>;
 
 /// Maximum attachment size in bytes (25 MB)
 const MAX_ATTACHMENT_SIZE: usize = 25 * 1024 * 1024;
@@ -37,11 +43,66 @@ const ALLOWED_CONTENT_TYPES: &[&str] = &[
 
 use crate::domain::ports::file_storage::FileStorage;
 
+/// Settings for signed attachment download links sent to contacts
+#[derive(Debug, Clone)]
+pub struct AttachmentLinkConfig {
+    /// HMAC key used to sign links
+    pub secret: String,
+    /// How long a link stays valid after it is issued
+    pub lifetime: Duration,
+    /// Public base URL that links are built on
+    pub base_url: String,
+}
+
+impl Default for AttachmentLinkConfig {
+    fn default() -> Self {
+        Self {
+            secret: generate_secret(),
+            lifetime: Duration::from_secs(7 * 24 * 60 * 60),
+            base_url: "http://localhost:3000".to_string(),
+        }
+    }
+}
+
+impl AttachmentLinkConfig {
+    /// Load settings from ATTACHMENT_LINK_* environment variables, falling back to defaults
+    ///
+    /// Without ATTACHMENT_LINK_SECRET a random key is used, so links stop working
+    /// after a restart and are only valid on the instance that issued them.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let secret = match std::env::var("ATTACHMENT_LINK_SECRET") {
+            Ok(secret) if !secret.is_empty() => secret,
+            _ => {
+                tracing::warn!(
+                    "ATTACHMENT_LINK_SECRET not set; signed attachment links will not survive a restart"
+                );
+                defaults.secret
+            }
+        };
+        let lifetime = std::env::var("ATTACHMENT_LINK_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(defaults.lifetime);
+        let base_url = std::env::var("ATTACHMENT_LINK_BASE_URL")
+            .map(|url| url.trim_end_matches('/').to_string())
+            .unwrap_or(defaults.base_url);
+
+        Self {
+            secret,
+            lifetime,
+            base_url,
+        }
+    }
+}
+
 /// Attachment service
 #[derive(Clone)]
 pub struct AttachmentService {
     attachment_repo: Arc<dyn AttachmentRepository>,
     storage: Arc<dyn FileStorage>,
+    links: AttachmentLinkConfig,
 }
 
 impl AttachmentService {
@@ -53,7 +114,66 @@ impl AttachmentService {
         Self {
             attachment_repo,
             storage,
+            links: AttachmentLinkConfig::default(),
+        }
+    }
+
+    /// Use the given settings for signed download links
+    pub fn with_link_config(mut self, links: AttachmentLinkConfig) -> Self {
+        self.links = links;
+        self
+    }
+
+    /// Build a time-limited download URL that works without authentication
+    pub fn signed_download_url(&self, attachment: &MessageAttachment) -> String {
+        let expires = chrono::Utc::now().timestamp() + self.links.lifetime.as_secs() as i64;
+        format!(
+            "{}/api/attachments/{}/download?expires={}&signature={}",
+            self.links.base_url,
+            attachment.id,
+            expires,
+            self.link_signature(&attachment.id, expires)
+        )
+    }
+
+    /// Load an attachment and its content for a signed download link
+    ///
+    /// Expired links and bad signatures are rejected before the attachment is looked up.
+    pub async fn open_signed_download(
+        &self,
+        attachment_id: &str,
+        expires: i64,
+        signature: &str,
+    ) -> ApiResult<(MessageAttachment, Vec<u8>)> {
+        if expires < chrono::Utc::now().timestamp() {
+            return Err(ApiError::Forbidden("Download link has expired".to_string()));
         }
+
+        let signature = hex::decode(signature)
+            .map_err(|_| ApiError::Forbidden("Invalid download link".to_string()))?;
+        self.link_mac(attachment_id, expires)
+            .verify_slice(&signature)
+            .map_err(|_| ApiError::Forbidden("Invalid download link".to_string()))?;
+
+        let attachment = self
+            .attachment_repo
+            .get_attachment_by_id(attachment_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("Attachment not found".to_string()))?;
+        let content = self.read_attachment(&attachment).await?;
+
+        Ok((attachment, content))
+    }
+
+    fn link_signature(&self, attachment_id: &str, expires: i64) -> String {
+        hex::encode(self.link_mac(attachment_id, expires).finalize().into_bytes())
+    }
+
+    fn link_mac(&self, attachment_id: &str, expires: i64) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(self.links.secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(format!("{}:{}", attachment_id, expires).as_bytes());
+        mac
     }
 
     /// Save attachment to disk and create database record
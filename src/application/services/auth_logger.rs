@@ -242,31 +242,6 @@ impl AuthLogger {
     }
 }
 
-/// Helper function to extract IP address from request
-///
-/// Checks X-Forwarded-For, X-Real-IP headers, or falls back to connection info.
-pub fn extract_ip_address(headers: &axum::http::HeaderMap) -> String {
-    // Check X-Forwarded-For (proxy/load balancer)
-    if let Some(forwarded) = headers.get("x-forwarded-for") {
-        if let Ok(value) = forwarded.to_str() {
-            // Take the first IP in the chain
-            if let Some(ip) = value.split(',').next() {
-                return ip.trim().to_string();
-            }
-        }
-    }
-
-    // Check X-Real-IP (nginx)
-    if let Some(real_ip) = headers.get("x-real-ip") {
-        if let Ok(value) = real_ip.to_str() {
-            return value.to_string();
-        }
-    }
-
-    // Fallback to unknown
-    "unknown".to_string()
-}
-
 /// Helper function to extract User-Agent from request
 pub fn extract_user_agent(headers: &axum::http::HeaderMap) -> Option<String> {
     headers
@@ -279,31 +254,6 @@ pub fn extract_user_agent(headers: &axum::http::HeaderMap) -> Option<String> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_extract_ip_from_forwarded_for() {
-        let mut headers = axum::http::HeaderMap::new();
-        headers.insert("x-forwarded-for", "192.168.1.1, 10.0.0.1".parse().unwrap());
-
-        let ip = extract_ip_address(&headers);
-        assert_eq!(ip, "192.168.1.1");
-    }
-
-    #[test]
-    fn test_extract_ip_from_real_ip() {
-        let mut headers = axum::http::HeaderMap::new();
-        headers.insert("x-real-ip", "192.168.1.100".parse().unwrap());
-
-        let ip = extract_ip_address(&headers);
-        assert_eq!(ip, "192.168.1.100");
-    }
-
-    #[test]
-    fn test_extract_ip_fallback() {
-        let headers = axum::http::HeaderMap::new();
-        let ip = extract_ip_address(&headers);
-        assert_eq!(ip, "unknown");
-    }
-
     #[test]
     fn test_extract_user_agent() {
         let mut headers = axum::http::HeaderMap::new();
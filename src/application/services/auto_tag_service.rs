@@ -0,0 +1,380 @@
+use crate::{
+    domain::entities::{
+        AutoTagBackfillRequest, AutoTagBackfillResponse, AutoTagMatchType, AutoTagRule,
+        ConversationListFilter, CreateAutoTagRuleRequest, MessageType, UpdateAutoTagRuleRequest,
+        MAX_AUTO_TAG_BACKFILL_BATCH,
+    },
+    domain::errors::{AutoTagError, AutoTagResult},
+    domain::events::SystemEvent,
+    domain::ports::auto_tag_repository::AutoTagRepository,
+    domain::ports::conversation_repository::ConversationRepository,
+    domain::ports::conversation_tag_repository::ConversationTagRepository,
+    domain::ports::event_bus::EventBus,
+    domain::ports::inbox_repository::InboxRepository,
+    domain::ports::message_repository::MessageRepository,
+    domain::ports::tag_repository::TagRepository,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// A rule's regex compiled for the pattern and case flag it was built from
+struct CompiledRule {
+    pattern: String,
+    case_sensitive: bool,
+    regex: Option<Arc<regex::Regex>>,
+}
+
+/// Service for inbox-level keyword auto-tagging
+#[derive(Clone)]
+pub struct AutoTagService {
+    auto_tag_repo: Arc<dyn AutoTagRepository>,
+    inbox_repo: Arc<dyn InboxRepository>,
+    tag_repo: TagRepository,
+    conversation_tag_repo: Arc<dyn ConversationTagRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    message_repo: Arc<dyn MessageRepository>,
+    event_bus: Arc<dyn EventBus>,
+    /// Map of rule id -> compiled regex, so regex rules aren't recompiled per message
+    regex_cache: Arc<RwLock<HashMap<String, CompiledRule>>>,
+}
+
+impl AutoTagService {
+    pub fn new(
+        auto_tag_repo: Arc<dyn AutoTagRepository>,
+        inbox_repo: Arc<dyn InboxRepository>,
+        tag_repo: TagRepository,
+        conversation_tag_repo: Arc<dyn ConversationTagRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+        message_repo: Arc<dyn MessageRepository>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            auto_tag_repo,
+            inbox_repo,
+            tag_repo,
+            conversation_tag_repo,
+            conversation_repo,
+            message_repo,
+            event_bus,
+            regex_cache: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Compiled regex for a regex rule, reusing the cached one while the
+    /// rule's pattern and case flag are unchanged
+    async fn compiled_regex(&self, rule: &AutoTagRule) -> Option<Arc<regex::Regex>> {
+        if rule.match_type != AutoTagMatchType::Regex {
+            return None;
+        }
+
+        if let Some(cached) = self.regex_cache.read().await.get(&rule.id) {
+            if cached.pattern == rule.pattern && cached.case_sensitive == rule.case_sensitive {
+                return cached.regex.clone();
+            }
+        }
+
+        let regex = rule.compile_regex().ok().map(Arc::new);
+        self.regex_cache.write().await.insert(
+            rule.id.clone(),
+            CompiledRule {
+                pattern: rule.pattern.clone(),
+                case_sensitive: rule.case_sensitive,
+                regex: regex.clone(),
+            },
+        );
+        regex
+    }
+
+    async fn ensure_inbox_exists(&self, inbox_id: &str) -> AutoTagResult<()> {
+        self.inbox_repo
+            .get_inbox(inbox_id)
+            .await?
+            .ok_or_else(|| AutoTagError::NotFound(format!("Inbox {} not found", inbox_id)))?;
+        Ok(())
+    }
+
+    async fn ensure_tag_exists(&self, tag_id: &str) -> AutoTagResult<()> {
+        self.tag_repo
+            .get_tag_by_id(tag_id)
+            .await?
+            .ok_or_else(|| AutoTagError::NotFound(format!("Tag {} not found", tag_id)))?;
+        Ok(())
+    }
+
+    /// Create a keyword rule for an inbox
+    pub async fn create_rule(
+        &self,
+        inbox_id: &str,
+        request: CreateAutoTagRuleRequest,
+        created_by: &str,
+    ) -> AutoTagResult<AutoTagRule> {
+        self.ensure_inbox_exists(inbox_id).await?;
+        self.ensure_tag_exists(&request.tag_id).await?;
+
+        let rule = AutoTagRule::new(
+            inbox_id.to_string(),
+            request.tag_id,
+            request.match_field,
+            request.match_type,
+            request.pattern,
+            request.case_sensitive,
+            created_by.to_string(),
+        );
+        rule.validate().map_err(AutoTagError::Validation)?;
+
+        self.auto_tag_repo.create_auto_tag_rule(&rule).await?;
+
+        tracing::info!(
+            "Auto-tag rule {} created for inbox {} by {}",
+            rule.id,
+            inbox_id,
+            created_by
+        );
+
+        Ok(rule)
+    }
+
+    /// Get a rule by ID
+    pub async fn get_rule(&self, id: &str) -> AutoTagResult<AutoTagRule> {
+        self.auto_tag_repo
+            .get_auto_tag_rule(id)
+            .await?
+            .ok_or_else(|| AutoTagError::NotFound(format!("Auto-tag rule {} not found", id)))
+    }
+
+    /// List all rules (enabled and disabled) for an inbox
+    pub async fn list_rules(&self, inbox_id: &str) -> AutoTagResult<Vec<AutoTagRule>> {
+        self.ensure_inbox_exists(inbox_id).await?;
+        Ok(self.auto_tag_repo.list_auto_tag_rules(inbox_id).await?)
+    }
+
+    /// Update an existing rule
+    pub async fn update_rule(
+        &self,
+        id: &str,
+        request: UpdateAutoTagRuleRequest,
+    ) -> AutoTagResult<AutoTagRule> {
+        let mut rule = self.get_rule(id).await?;
+
+        if let Some(tag_id) = request.tag_id {
+            self.ensure_tag_exists(&tag_id).await?;
+            rule.tag_id = tag_id;
+        }
+        if let Some(pattern) = request.pattern {
+            rule.pattern = pattern;
+        }
+        if let Some(match_field) = request.match_field {
+            rule.match_field = match_field;
+        }
+        if let Some(match_type) = request.match_type {
+            rule.match_type = match_type;
+        }
+        if let Some(case_sensitive) = request.case_sensitive {
+            rule.case_sensitive = case_sensitive;
+        }
+        if let Some(enabled) = request.enabled {
+            rule.enabled = enabled;
+        }
+        rule.validate().map_err(AutoTagError::Validation)?;
+        rule.updated_at = chrono::Utc::now().to_rfc3339();
+
+        self.auto_tag_repo.update_auto_tag_rule(&rule).await?;
+
+        Ok(rule)
+    }
+
+    /// Delete a rule
+    pub async fn delete_rule(&self, id: &str) -> AutoTagResult<()> {
+        self.get_rule(id).await?;
+        self.auto_tag_repo.delete_auto_tag_rule(id).await?;
+        self.regex_cache.write().await.remove(id);
+        Ok(())
+    }
+
+    /// Apply the inbox's enabled rules to an inbound message.
+    ///
+    /// Adds any matching tags the conversation doesn't already carry and emits
+    /// `ConversationTagsChanged` so downstream automation sees them. Returns the
+    /// IDs of newly applied tags.
+    pub async fn apply_to_message(
+        &self,
+        conversation_id: &str,
+        inbox_id: &str,
+        subject: Option<&str>,
+        body: &str,
+    ) -> AutoTagResult<Vec<String>> {
+        let rules = self
+            .auto_tag_repo
+            .list_enabled_auto_tag_rules(inbox_id)
+            .await?;
+        if rules.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut matched: Vec<&AutoTagRule> = Vec::new();
+        for rule in &rules {
+            let regex = self.compiled_regex(rule).await;
+            if rule.matches_with(regex.as_deref(), subject, body) {
+                matched.push(rule);
+            }
+        }
+        if matched.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.apply_matched_rules(conversation_id, &matched).await
+    }
+
+    async fn apply_matched_rules(
+        &self,
+        conversation_id: &str,
+        matched: &[&AutoTagRule],
+    ) -> AutoTagResult<Vec<String>> {
+        let previous_tags: Vec<String> = self
+            .conversation_tag_repo
+            .get_conversation_tags(conversation_id)
+            .await?
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+
+        // Tags are recorded as added by the rule's creator; group them so each
+        // event names the same actor as the tag rows it describes
+        let mut applied: Vec<String> = Vec::new();
+        let mut applied_by: Vec<(String, Vec<String>)> = Vec::new();
+        for rule in matched {
+            if previous_tags.contains(&rule.tag_id) || applied.contains(&rule.tag_id) {
+                continue;
+            }
+            self.conversation_tag_repo
+                .add_conversation_tag(conversation_id, &rule.tag_id, &rule.created_by)
+                .await?;
+            applied.push(rule.tag_id.clone());
+            match applied_by
+                .iter_mut()
+                .find(|(actor, _)| *actor == rule.created_by)
+            {
+                Some((_, tags)) => tags.push(rule.tag_id.clone()),
+                None => applied_by.push((rule.created_by.clone(), vec![rule.tag_id.clone()])),
+            }
+
+            tracing::info!(
+                "Auto-tag rule {} applied tag {} to conversation {}",
+                rule.id,
+                rule.tag_id,
+                conversation_id
+            );
+        }
+
+        let mut current_tags = previous_tags;
+        for (actor, tags) in applied_by {
+            let mut new_tags = current_tags.clone();
+            new_tags.extend(tags);
+
+            let _ = self
+                .event_bus
+                .publish(SystemEvent::ConversationTagsChanged {
+                    conversation_id: conversation_id.to_string(),
+                    previous_tags: current_tags,
+                    new_tags: new_tags.clone(),
+                    changed_by: actor,
+                    timestamp: chrono::Utc::now().to_rfc3339(),
+                });
+            current_tags = new_tags;
+        }
+
+        Ok(applied)
+    }
+
+    /// Run the inbox's rules over one batch of historical conversations.
+    ///
+    /// Each conversation's subject and inbound messages are matched against the
+    /// enabled rules; callers page through the inbox using `next_offset`.
+    pub async fn backfill(
+        &self,
+        inbox_id: &str,
+        request: AutoTagBackfillRequest,
+    ) -> AutoTagResult<AutoTagBackfillResponse> {
+        self.ensure_inbox_exists(inbox_id).await?;
+
+        if request.limit < 1 || request.limit > MAX_AUTO_TAG_BACKFILL_BATCH {
+            return Err(AutoTagError::Validation(format!(
+                "Limit must be between 1 and {}",
+                MAX_AUTO_TAG_BACKFILL_BATCH
+            )));
+        }
+        if request.offset < 0 {
+            return Err(AutoTagError::Validation(
+                "Offset must be non-negative".to_string(),
+            ));
+        }
+
+        let rules = self
+            .auto_tag_repo
+            .list_enabled_auto_tag_rules(inbox_id)
+            .await?;
+        let conversations = self
+            .conversation_repo
+            .list_conversations(
+                request.limit,
+                request.offset,
+                &ConversationListFilter {
+                    inbox_id: Some(inbox_id.to_string()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let mut response = AutoTagBackfillResponse {
+            conversations_scanned: conversations.len() as i64,
+            next_offset: if conversations.len() as i64 == request.limit {
+                Some(request.offset + request.limit)
+            } else {
+                None
+            },
+            ..Default::default()
+        };
+
+        if rules.is_empty() {
+            return Ok(response);
+        }
+
+        let mut compiled = Vec::with_capacity(rules.len());
+        for rule in &rules {
+            compiled.push((rule, self.compiled_regex(rule).await));
+        }
+
+        for conversation in conversations {
+            let total = self.message_repo.count_messages(&conversation.id).await?;
+            let (messages, _) = self
+                .message_repo
+                .list_messages(&conversation.id, total.max(1), 0)
+                .await?;
+
+            let subject = conversation.subject.as_deref();
+            let matched: Vec<&AutoTagRule> = compiled
+                .iter()
+                .filter(|(rule, regex)| {
+                    messages
+                        .iter()
+                        .filter(|m| m.message_type == MessageType::Incoming)
+                        .any(|m| rule.matches_with(regex.as_deref(), subject, &m.content))
+                        || rule.matches_with(regex.as_deref(), subject, "")
+                })
+                .map(|(rule, _)| *rule)
+                .collect();
+            if matched.is_empty() {
+                continue;
+            }
+
+            let applied = self.apply_matched_rules(&conversation.id, &matched).await?;
+            if !applied.is_empty() {
+                response.conversations_tagged += 1;
+                response.tags_applied += applied.len() as i64;
+            }
+        }
+
+        Ok(response)
+    }
+}
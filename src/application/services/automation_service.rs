@@ -1,15 +1,37 @@
+use crate::application::services::SlaService;
 use crate::domain::ports::automation_repository::AutomationRepository;
+use crate::domain::ports::conversation_repository::ConversationRepository;
+use crate::domain::ports::notification_repository::NotificationRepository;
+use crate::domain::ports::task_queue::TaskQueue;
+use crate::domain::ports::user_repository::UserRepository;
 use crate::domain::entities::{
-    ActionResult, AutomationRule, ConditionResult, Conversation, RuleEvaluationLog,
+    ActionResult, ActionType, AutomationRule, ConditionResult, Conversation, PendingRuleAction,
+    PendingRuleActionStatus, RuleEvaluationLog, UserNotification,
 };
 use crate::domain::services::action_executor::ActionExecutor;
 use crate::domain::services::condition_evaluator::ConditionEvaluator;
-use std::sync::Arc;
-use std::time::Instant;
+use std::collections::HashMap;
+use std::env;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Job that runs the follow-up of a Wait action once its delay has elapsed
+pub const RUN_AUTOMATION_WAIT_JOB: &str = "run_automation_wait";
+
+/// Event type recorded in evaluation logs when a Wait action's follow-up runs
+const WAIT_ELAPSED_EVENT: &str = "automation.wait_elapsed";
 
 #[derive(Debug, Clone)]
 pub struct AutomationConfig {
+    /// Deepest chain of rules triggering rules before further runs are suppressed
     pub cascade_max_depth: u32,
+    /// An event on a conversation this soon after a rule acted on it is treated
+    /// as caused by that rule and continues its cascade
+    pub cascade_window_secs: u64,
+    /// How often one rule may act on one conversation within `cycle_window_secs`
+    /// before it is treated as looping
+    pub cycle_max_runs: u32,
+    pub cycle_window_secs: u64,
     pub condition_timeout_secs: u64,
     pub action_timeout_secs: u64,
 }
@@ -18,18 +40,83 @@ impl Default for AutomationConfig {
     fn default() -> Self {
         Self {
             cascade_max_depth: 3,
+            cascade_window_secs: 10,
+            cycle_max_runs: 5,
+            cycle_window_secs: 300,
             condition_timeout_secs: 5,
             action_timeout_secs: 10,
         }
     }
 }
 
+impl AutomationConfig {
+    /// Read `AUTOMATION_CASCADE_MAX_DEPTH`, `AUTOMATION_CYCLE_MAX_RUNS` and
+    /// `AUTOMATION_CYCLE_WINDOW_SECONDS`, falling back to the defaults
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            cascade_max_depth: env::var("AUTOMATION_CASCADE_MAX_DEPTH")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.cascade_max_depth),
+            cycle_max_runs: env::var("AUTOMATION_CYCLE_MAX_RUNS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.cycle_max_runs),
+            cycle_window_secs: env::var("AUTOMATION_CYCLE_WINDOW_SECONDS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.cycle_window_secs),
+            ..defaults
+        }
+    }
+}
+
+/// Depth of the most recent rule-caused change to a conversation
+#[derive(Debug, Clone, Copy)]
+struct CascadeMark {
+    depth: u32,
+    at: Instant,
+}
+
+/// Why a rule was stopped from acting
+enum Suppression {
+    Depth(u32),
+    Cycle(i64),
+}
+
+impl Suppression {
+    fn describe(&self, config: &AutomationConfig) -> String {
+        match self {
+            Self::Depth(depth) => format!(
+                "Cascade suppressed: depth {} exceeds limit {}",
+                depth, config.cascade_max_depth
+            ),
+            Self::Cycle(runs) => format!(
+                "Cascade suppressed: rule already ran {} times on this conversation in the last {}s",
+                runs, config.cycle_window_secs
+            ),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct AutomationService {
     automation_repo: Arc<dyn AutomationRepository>,
     condition_evaluator: ConditionEvaluator,
     action_executor: ActionExecutor,
     config: AutomationConfig,
+    task_queue: Option<Arc<dyn TaskQueue>>,
+    conversation_repo: Option<Arc<dyn ConversationRepository>>,
+    sla_service: Option<SlaService>,
+    user_repo: Option<Arc<dyn UserRepository>>,
+    notification_repo: Option<Arc<dyn NotificationRepository>>,
+    /// Conversation ID -> depth of the last rule action on it
+    cascades: Arc<Mutex<HashMap<String, CascadeMark>>>,
+    /// (rule ID, conversation ID) -> when admins were last alerted about it
+    suppression_alerts: Arc<Mutex<HashMap<(String, String), Instant>>>,
 }
 
 impl AutomationService {
@@ -43,9 +130,41 @@ impl AutomationService {
             condition_evaluator: ConditionEvaluator::new(),
             action_executor,
             config,
+            task_queue: None,
+            conversation_repo: None,
+            sla_service: None,
+            user_repo: None,
+            notification_repo: None,
+            cascades: Arc::new(Mutex::new(HashMap::new())),
+            suppression_alerts: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
+    /// Schedule the follow-up of Wait actions as jobs on this queue
+    pub fn set_task_queue(&mut self, task_queue: Arc<dyn TaskQueue>) {
+        self.task_queue = Some(task_queue);
+    }
+
+    /// Reload conversations when a Wait action's follow-up comes due
+    pub fn set_conversation_repo(&mut self, conversation_repo: Arc<dyn ConversationRepository>) {
+        self.conversation_repo = Some(conversation_repo);
+    }
+
+    /// Count Wait delays marked `business_hours` in the assigned team's business hours
+    pub fn set_sla_service(&mut self, sla_service: SlaService) {
+        self.sla_service = Some(sla_service);
+    }
+
+    /// Notify admins when a rule is stopped for cascading too deep or looping
+    pub fn set_cascade_alerts(
+        &mut self,
+        user_repo: Arc<dyn UserRepository>,
+        notification_repo: Arc<dyn NotificationRepository>,
+    ) {
+        self.user_repo = Some(user_repo);
+        self.notification_repo = Some(notification_repo);
+    }
+
     /// Handle a conversation-related event
     pub async fn handle_conversation_event(
         &self,
@@ -65,15 +184,17 @@ impl AutomationService {
         executed_by: &str,
         cascade_depth: u32,
     ) -> Result<(), String> {
-        // Check cascade depth limit
-        if cascade_depth > self.config.cascade_max_depth {
-            tracing::warn!(
-                "Cascade depth {} exceeds limit {} for conversation {}, skipping automation",
-                cascade_depth,
-                self.config.cascade_max_depth,
-                conversation.id
+        // Events don't carry their cause, so a change shortly after a rule acted
+        // on the conversation is taken to continue that rule's cascade
+        let cascade_depth = cascade_depth.max(self.inherited_depth(&conversation.id));
+
+        // Waits whose rule no longer matches are dropped before new ones are scheduled
+        if let Err(e) = self.cancel_stale_waits(conversation).await {
+            tracing::error!(
+                "Failed to check pending waits for conversation {}: {}",
+                conversation.id,
+                e
             );
-            return Ok(());
         }
 
         tracing::info!(
@@ -105,6 +226,35 @@ impl AutomationService {
         let mut sorted_rules = rules;
         sorted_rules.sort_by_key(|r| std::cmp::Reverse(r.priority));
 
+        if cascade_depth > self.config.cascade_max_depth {
+            tracing::warn!(
+                "Cascade depth {} exceeds limit {} for conversation {}, suppressing automation",
+                cascade_depth,
+                self.config.cascade_max_depth,
+                conversation.id
+            );
+            for rule in &sorted_rules {
+                if let Err(e) = self
+                    .suppress_rule(
+                        rule,
+                        event_type,
+                        conversation,
+                        cascade_depth,
+                        Suppression::Depth(cascade_depth),
+                    )
+                    .await
+                {
+                    tracing::error!(
+                        "Error suppressing rule '{}' ({}): {}",
+                        rule.name,
+                        rule.id,
+                        e
+                    );
+                }
+            }
+            return Ok(());
+        }
+
         // Evaluate and execute each rule
         for rule in sorted_rules {
             if let Err(e) = self
@@ -157,6 +307,28 @@ impl AutomationService {
             }
         };
 
+        if condition_matched {
+            let runs = self.recent_runs(rule, conversation).await?;
+            if runs >= i64::from(self.config.cycle_max_runs) {
+                tracing::warn!(
+                    "Rule '{}' ran {} times on conversation {} within {}s, suppressing it",
+                    rule.name,
+                    runs,
+                    conversation.id,
+                    self.config.cycle_window_secs
+                );
+                return self
+                    .suppress_rule(
+                        rule,
+                        event_type,
+                        conversation,
+                        cascade_depth,
+                        Suppression::Cycle(runs),
+                    )
+                    .await;
+            }
+        }
+
         // Execute action if condition matched
         let (action_executed, action_result, action_error) = if condition_matched {
             tracing::info!(
@@ -165,22 +337,30 @@ impl AutomationService {
                 rule.action.action_type
             );
 
-            match self
-                .action_executor
-                .execute(&rule.action, &conversation.id, executed_by)
-                .await
-            {
+            let outcome = if rule.action.action_type == ActionType::Wait {
+                self.schedule_wait(rule, conversation).await
+            } else {
+                self.action_executor
+                    .execute(&rule.action, &conversation.id, executed_by)
+                    .await
+                    .map_err(|e| e.to_string())
+            };
+
+            match outcome {
                 Ok(()) => {
                     tracing::info!(
                         "Action {:?} executed successfully for rule '{}'",
                         rule.action.action_type,
                         rule.name
                     );
+                    if rule.action.action_type != ActionType::Wait {
+                        self.mark_cascade(&conversation.id, cascade_depth + 1);
+                    }
                     (true, ActionResult::Success, None)
                 }
                 Err(e) => {
                     tracing::error!("Action execution error for rule '{}': {}", rule.name, e);
-                    (false, ActionResult::Error, Some(e.to_string()))
+                    (false, ActionResult::Error, Some(e))
                 }
             }
         } else {
@@ -228,6 +408,345 @@ impl AutomationService {
         Ok(())
     }
 
+    /// Cascade depth carried over from a rule that recently acted on the conversation
+    fn inherited_depth(&self, conversation_id: &str) -> u32 {
+        let window = Duration::from_secs(self.config.cascade_window_secs);
+        let mut cascades = self.cascades.lock().unwrap();
+        cascades.retain(|_, mark| mark.at.elapsed() < window);
+        cascades.get(conversation_id).map_or(0, |mark| mark.depth)
+    }
+
+    /// Remember that a rule at `depth - 1` just changed the conversation
+    fn mark_cascade(&self, conversation_id: &str, depth: u32) {
+        let window = Duration::from_secs(self.config.cascade_window_secs);
+        let mut cascades = self.cascades.lock().unwrap();
+        let depth = match cascades.get(conversation_id) {
+            Some(mark) if mark.at.elapsed() < window => mark.depth.max(depth),
+            _ => depth,
+        };
+        cascades.insert(
+            conversation_id.to_string(),
+            CascadeMark {
+                depth,
+                at: Instant::now(),
+            },
+        );
+    }
+
+    /// How often the rule acted on the conversation within the cycle window
+    async fn recent_runs(
+        &self,
+        rule: &AutomationRule,
+        conversation: &Conversation,
+    ) -> Result<i64, String> {
+        let since =
+            chrono::Utc::now() - chrono::Duration::seconds(self.config.cycle_window_secs as i64);
+        self.automation_repo
+            .count_rule_executions_since(&rule.id, &conversation.id, &since.to_rfc3339())
+            .await
+            .map_err(|e| format!("Failed to count recent rule runs: {}", e))
+    }
+
+    /// Log a rule as suppressed instead of running it, and alert admins
+    async fn suppress_rule(
+        &self,
+        rule: &AutomationRule,
+        event_type: &str,
+        conversation: &Conversation,
+        cascade_depth: u32,
+        reason: Suppression,
+    ) -> Result<(), String> {
+        let log = RuleEvaluationLog {
+            id: uuid::Uuid::new_v4().to_string(),
+            rule_id: rule.id.clone(),
+            rule_name: rule.name.clone(),
+            event_type: event_type.to_string(),
+            conversation_id: Some(conversation.id.clone()),
+            matched: true,
+            condition_result: None,
+            action_executed: false,
+            action_result: Some(ActionResult::Skipped),
+            error_message: Some(reason.describe(&self.config)),
+            evaluation_time_ms: 0,
+            evaluated_at: chrono::Utc::now().to_rfc3339(),
+            cascade_depth,
+        };
+        self.automation_repo
+            .create_rule_evaluation_log(&log)
+            .await
+            .map_err(|e| format!("Failed to create evaluation log: {}", e))?;
+
+        self.alert_admins(rule, conversation).await;
+        Ok(())
+    }
+
+    /// Alert admins about a suppressed rule, at most once per rule and
+    /// conversation within the cycle window
+    async fn alert_admins(&self, rule: &AutomationRule, conversation: &Conversation) {
+        let (Some(user_repo), Some(notification_repo)) = (&self.user_repo, &self.notification_repo)
+        else {
+            return;
+        };
+
+        {
+            let window = Duration::from_secs(self.config.cycle_window_secs);
+            let mut alerts = self.suppression_alerts.lock().unwrap();
+            alerts.retain(|_, at| at.elapsed() < window);
+            let key = (rule.id.clone(), conversation.id.clone());
+            if alerts.contains_key(&key) {
+                return;
+            }
+            alerts.insert(key, Instant::now());
+        }
+
+        let admin_ids = match user_repo.list_admin_user_ids().await {
+            Ok(ids) => ids,
+            Err(e) => {
+                tracing::error!("Failed to list admins for cascade alert: {}", e);
+                return;
+            }
+        };
+        for admin_id in admin_ids {
+            let notification = UserNotification::new_alert(admin_id, conversation.id.clone());
+            if let Err(e) = notification_repo.create_notification(&notification).await {
+                tracing::error!(
+                    "Failed to alert admin about suppressed rule '{}': {}",
+                    rule.name,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Record a Wait action's follow-up and queue a job to run it once the delay elapses
+    async fn schedule_wait(
+        &self,
+        rule: &AutomationRule,
+        conversation: &Conversation,
+    ) -> Result<(), String> {
+        let task_queue = self
+            .task_queue
+            .as_ref()
+            .ok_or("Wait actions need a task queue")?;
+
+        // A rule waits at most once per conversation; later events don't restart the clock
+        let already_waiting = self
+            .automation_repo
+            .get_pending_rule_actions_for_conversation(&conversation.id)
+            .await
+            .map_err(|e| format!("Failed to fetch pending waits: {}", e))?
+            .iter()
+            .any(|pending| pending.rule_id == rule.id);
+        if already_waiting {
+            tracing::debug!(
+                "Rule '{}' is already waiting on conversation {}",
+                rule.name,
+                conversation.id
+            );
+            return Ok(());
+        }
+
+        let duration_seconds = rule.action.wait_duration_seconds()?;
+        let follow_up = rule.action.wait_follow_up()?;
+        let now = chrono::Utc::now();
+        let run_at = match &self.sla_service {
+            Some(sla_service) if rule.action.wait_in_business_hours() => {
+                let deadline = sla_service
+                    .calculate_deadline_for_conversation(
+                        conversation,
+                        &now.to_rfc3339(),
+                        duration_seconds,
+                    )
+                    .await
+                    .map_err(|e| format!("Failed to calculate wait deadline: {}", e))?;
+                chrono::DateTime::parse_from_rfc3339(&deadline)
+                    .map_err(|e| format!("Invalid wait deadline: {}", e))?
+                    .with_timezone(&chrono::Utc)
+            }
+            _ => now + chrono::Duration::seconds(duration_seconds),
+        };
+
+        let pending = PendingRuleAction::new(
+            rule.id.clone(),
+            conversation.id.clone(),
+            follow_up,
+            run_at.to_rfc3339(),
+        );
+        self.automation_repo
+            .create_pending_rule_action(&pending)
+            .await
+            .map_err(|e| format!("Failed to record pending action: {}", e))?;
+        task_queue
+            .enqueue_at(
+                RUN_AUTOMATION_WAIT_JOB,
+                serde_json::json!({ "pending_action_id": pending.id }),
+                run_at,
+                3,
+            )
+            .await
+            .map_err(|e| format!("Failed to schedule wait: {}", e))?;
+
+        tracing::info!(
+            "Rule '{}' waiting on conversation {} until {}",
+            rule.name,
+            conversation.id,
+            pending.run_at
+        );
+        Ok(())
+    }
+
+    /// Whether a waiting rule still applies to the conversation
+    async fn wait_still_applies(
+        &self,
+        rule: Option<&AutomationRule>,
+        conversation: &Conversation,
+    ) -> bool {
+        match rule {
+            Some(rule) if rule.enabled => matches!(
+                self.condition_evaluator
+                    .evaluate(&rule.condition, conversation)
+                    .await,
+                Ok(true)
+            ),
+            _ => false,
+        }
+    }
+
+    /// Cancel pending waits on a conversation whose rule condition no longer holds
+    async fn cancel_stale_waits(&self, conversation: &Conversation) -> Result<(), String> {
+        let pending_actions = self
+            .automation_repo
+            .get_pending_rule_actions_for_conversation(&conversation.id)
+            .await
+            .map_err(|e| format!("Failed to fetch pending waits: {}", e))?;
+
+        for pending in pending_actions {
+            let rule = self
+                .automation_repo
+                .get_automation_rule_by_id(&pending.rule_id)
+                .await
+                .map_err(|e| format!("Failed to fetch rule: {}", e))?;
+            if self.wait_still_applies(rule.as_ref(), conversation).await {
+                continue;
+            }
+
+            self.automation_repo
+                .finish_pending_rule_action(&pending.id, PendingRuleActionStatus::Cancelled)
+                .await
+                .map_err(|e| format!("Failed to cancel pending action: {}", e))?;
+            tracing::info!(
+                "Cancelled wait of rule {} on conversation {}: condition no longer holds",
+                pending.rule_id,
+                conversation.id
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Run a Wait action's follow-up if its rule condition still holds, otherwise cancel it
+    pub async fn run_pending_action(&self, pending_action_id: &str) -> Result<(), String> {
+        let Some(pending) = self
+            .automation_repo
+            .get_pending_rule_action(pending_action_id)
+            .await
+            .map_err(|e| format!("Failed to fetch pending action: {}", e))?
+        else {
+            tracing::warn!("Pending action {} no longer exists", pending_action_id);
+            return Ok(());
+        };
+        if pending.status != PendingRuleActionStatus::Pending {
+            tracing::debug!(
+                "Pending action {} is already {}",
+                pending.id,
+                pending.status
+            );
+            return Ok(());
+        }
+
+        let conversation_repo = self
+            .conversation_repo
+            .as_ref()
+            .ok_or("Conversation repository is not configured")?;
+        let conversation = conversation_repo
+            .get_conversation_by_id(&pending.conversation_id)
+            .await
+            .map_err(|e| format!("Failed to fetch conversation: {}", e))?;
+        let rule = self
+            .automation_repo
+            .get_automation_rule_by_id(&pending.rule_id)
+            .await
+            .map_err(|e| format!("Failed to fetch rule: {}", e))?;
+
+        let (Some(conversation), Some(rule)) = (conversation, rule) else {
+            self.automation_repo
+                .finish_pending_rule_action(&pending.id, PendingRuleActionStatus::Cancelled)
+                .await
+                .map_err(|e| format!("Failed to cancel pending action: {}", e))?;
+            return Ok(());
+        };
+
+        let start_time = Instant::now();
+        if !self.wait_still_applies(Some(&rule), &conversation).await {
+            self.automation_repo
+                .finish_pending_rule_action(&pending.id, PendingRuleActionStatus::Cancelled)
+                .await
+                .map_err(|e| format!("Failed to cancel pending action: {}", e))?;
+            tracing::info!(
+                "Wait of rule '{}' on conversation {} elapsed but its condition no longer holds",
+                rule.name,
+                conversation.id
+            );
+            return Ok(());
+        }
+
+        // Claim the action first so a concurrent cancellation can't race the follow-up
+        let claimed = self
+            .automation_repo
+            .finish_pending_rule_action(&pending.id, PendingRuleActionStatus::Completed)
+            .await
+            .map_err(|e| format!("Failed to complete pending action: {}", e))?;
+        if !claimed {
+            return Ok(());
+        }
+
+        let result = self
+            .action_executor
+            .execute(&pending.action, &conversation.id, "system")
+            .await;
+
+        let log = RuleEvaluationLog {
+            id: uuid::Uuid::new_v4().to_string(),
+            rule_id: rule.id.clone(),
+            rule_name: rule.name.clone(),
+            event_type: WAIT_ELAPSED_EVENT.to_string(),
+            conversation_id: Some(conversation.id.clone()),
+            matched: true,
+            condition_result: Some(ConditionResult::True),
+            action_executed: result.is_ok(),
+            action_result: Some(if result.is_ok() {
+                ActionResult::Success
+            } else {
+                ActionResult::Error
+            }),
+            error_message: result.as_ref().err().map(|e| e.to_string()),
+            evaluation_time_ms: start_time.elapsed().as_millis() as i64,
+            evaluated_at: chrono::Utc::now().to_rfc3339(),
+            cascade_depth: 0,
+        };
+        self.automation_repo
+            .create_rule_evaluation_log(&log)
+            .await
+            .map_err(|e| format!("Failed to create evaluation log: {}", e))?;
+
+        result.map_err(|e| {
+            format!(
+                "Follow-up {:?} of rule '{}' failed: {}",
+                pending.action.action_type, rule.name, e
+            )
+        })
+    }
+
     // Proxy methods for AutomationRepository
 
     pub async fn create_automation_rule(&self, rule: &AutomationRule) -> Result<(), String> {
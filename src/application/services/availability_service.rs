@@ -45,6 +45,25 @@ impl AvailabilityService {
             ));
         }
 
+        self.change_availability(agent_id, status, "manual").await
+    }
+
+    /// Apply a scheduled availability change (e.g. at a shift boundary)
+    pub async fn set_scheduled_availability(
+        &self,
+        agent_id: &str,
+        status: AgentAvailability,
+        reason: &str,
+    ) -> ApiResult<()> {
+        self.change_availability(agent_id, status, reason).await
+    }
+
+    async fn change_availability(
+        &self,
+        agent_id: &str,
+        status: AgentAvailability,
+        reason: &str,
+    ) -> ApiResult<()> {
         // Get current agent to check old status
         let agent = self
             .agent_repo
@@ -84,14 +103,15 @@ impl AvailabilityService {
                 old_status: old_status.to_string(),
                 new_status: status.to_string(),
                 timestamp: now,
-                reason: "manual".to_string(),
+                reason: reason.to_string(),
             });
 
         tracing::info!(
-            "Agent {} availability changed from {} to {} (manual)",
+            "Agent {} availability changed from {} to {} ({})",
             agent.id,
             old_status,
-            status
+            status,
+            reason
         );
 
         Ok(())
@@ -0,0 +1,80 @@
+use crate::{
+    domain::entities::{Branding, SystemConfigChange, UpdateBrandingRequest},
+    domain::errors::{DomainError, SystemConfigResult},
+    domain::ports::system_config_repository::SystemConfigRepository,
+    shared::validation::Validate,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Service for workspace branding (product name, logo, accent color, email footer)
+///
+/// Branding lives in `system_config` next to the other admin settings, so
+/// changes show up in the same change history. It is read from storage on
+/// each use; the web UI and outbound emails pick up changes immediately.
+#[derive(Clone)]
+pub struct BrandingService {
+    repo: Arc<dyn SystemConfigRepository>,
+}
+
+impl BrandingService {
+    pub fn new(repo: Arc<dyn SystemConfigRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Effective branding, with defaults for anything not configured
+    pub async fn get_branding(&self) -> SystemConfigResult<Branding> {
+        let stored: HashMap<String, String> = self
+            .repo
+            .list_config_entries()
+            .await?
+            .into_iter()
+            .map(|entry| (entry.key, entry.value))
+            .collect();
+        Ok(Branding::from_stored(&stored))
+    }
+
+    /// Branding for rendering, falling back to the defaults if storage fails
+    pub async fn current(&self) -> Branding {
+        match self.get_branding().await {
+            Ok(branding) => branding,
+            Err(e) => {
+                tracing::warn!("Failed to load branding, using defaults: {}", e);
+                Branding::default()
+            }
+        }
+    }
+
+    /// Validate and store a branding update, recording each changed field
+    pub async fn update_branding(
+        &self,
+        request: UpdateBrandingRequest,
+        changed_by: &str,
+    ) -> SystemConfigResult<Branding> {
+        request.check().map_err(DomainError::Validation)?;
+
+        let current = self.get_branding().await?;
+        let mut updated = current.clone();
+        updated.apply(&request);
+
+        for ((key, old_value), (_, new_value)) in current
+            .stored_values()
+            .into_iter()
+            .zip(updated.stored_values())
+        {
+            if old_value == new_value {
+                continue;
+            }
+
+            let stored = self.repo.get_config_value(key).await?;
+            let change =
+                SystemConfigChange::new(key.to_string(), stored, new_value, changed_by.to_string());
+            self.repo
+                .apply_config_change(&change, Some("Workspace branding"))
+                .await?;
+            tracing::info!("Branding {} changed by {}", key, changed_by);
+        }
+
+        self.get_branding().await
+    }
+}
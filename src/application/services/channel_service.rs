@@ -0,0 +1,282 @@
+use crate::{
+    application::services::MessageService,
+    domain::entities::{
+        ChannelMessageRequest, ChannelMessageResponse, ChannelSender, Contact, Conversation,
+        ConversationListFilter, ConversationStatus, CreateConversation, IncomingMessageRequest,
+    },
+    domain::errors::{ChannelError, ChannelResult},
+    domain::ports::channel_repository::ChannelRepository,
+    domain::ports::contact_repository::ContactRepository,
+    domain::ports::conversation_repository::ConversationRepository,
+    domain::ports::inbox_repository::InboxRepository,
+    domain::ports::message_repository::MessageRepository,
+};
+use std::sync::Arc;
+
+/// Service for ingesting messages pushed in by external channels
+///
+/// Resolves the sender to a contact and the message to a conversation, then
+/// hands it to `MessageService` so channel messages go through the same
+/// pipeline (events, auto-tags, sentiment) as email.
+#[derive(Clone)]
+pub struct ChannelService {
+    channel_repo: Arc<dyn ChannelRepository>,
+    inbox_repo: Arc<dyn InboxRepository>,
+    contact_repo: Arc<dyn ContactRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    message_repo: Arc<dyn MessageRepository>,
+    message_service: MessageService,
+}
+
+impl ChannelService {
+    pub fn new(
+        channel_repo: Arc<dyn ChannelRepository>,
+        inbox_repo: Arc<dyn InboxRepository>,
+        contact_repo: Arc<dyn ContactRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+        message_repo: Arc<dyn MessageRepository>,
+        message_service: MessageService,
+    ) -> Self {
+        Self {
+            channel_repo,
+            inbox_repo,
+            contact_repo,
+            conversation_repo,
+            message_repo,
+            message_service,
+        }
+    }
+
+    /// Ingest a message from an external channel into an inbox
+    ///
+    /// Without a `conversation_id` the message is appended to the sender's
+    /// latest open or snoozed conversation in the inbox, or starts a new one
+    /// (always, when `new_conversation` is set).
+    pub async fn receive_message(
+        &self,
+        inbox_id: &str,
+        request: ChannelMessageRequest,
+    ) -> ChannelResult<ChannelMessageResponse> {
+        request.validate().map_err(ChannelError::Validation)?;
+
+        let inbox = self
+            .inbox_repo
+            .get_inbox(inbox_id)
+            .await?
+            .ok_or_else(|| ChannelError::NotFound(format!("Inbox {} not found", inbox_id)))?;
+
+        let external_message_id = request
+            .external_message_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|id| !id.is_empty());
+        if let Some(external_message_id) = external_message_id {
+            if let Some(response) = self.find_duplicate(inbox_id, external_message_id).await? {
+                return Ok(response);
+            }
+        }
+
+        let contact = self.resolve_contact(inbox_id, &request.sender).await?;
+        let (conversation, created_conversation) = self
+            .resolve_conversation(inbox_id, &contact, &request)
+            .await?;
+
+        let message = self
+            .message_service
+            .create_incoming_message(IncomingMessageRequest {
+                conversation_id: conversation.id.clone(),
+                content: request.content,
+                contact_id: Some(contact.user_id.clone()),
+                inbox_id: inbox_id.to_string(),
+                from_header: None,
+                external_id: external_message_id.map(str::to_string),
+                received_at: None,
+                channel: request.channel.or(Some(inbox.channel_type)),
+                channel_metadata: request.channel_metadata,
+            })
+            .await?;
+
+        if let Some(external_message_id) = external_message_id {
+            self.channel_repo
+                .record_channel_message(inbox_id, external_message_id, &message.id)
+                .await?;
+        }
+
+        Ok(ChannelMessageResponse {
+            contact_id: contact.id,
+            conversation_id: conversation.id,
+            conversation_status: conversation.status,
+            message,
+            created_conversation,
+            duplicate: false,
+        })
+    }
+
+    /// Return the original result for a redelivered channel message
+    async fn find_duplicate(
+        &self,
+        inbox_id: &str,
+        external_message_id: &str,
+    ) -> ChannelResult<Option<ChannelMessageResponse>> {
+        let Some(message_id) = self
+            .channel_repo
+            .find_channel_message_id(inbox_id, external_message_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let Some(message) = self.message_repo.get_message_by_id(&message_id).await? else {
+            return Ok(None);
+        };
+        let conversation = self
+            .conversation_repo
+            .get_conversation_by_id(&message.conversation_id)
+            .await?
+            .ok_or_else(|| {
+                ChannelError::NotFound(format!(
+                    "Conversation {} not found",
+                    message.conversation_id
+                ))
+            })?;
+
+        Ok(Some(ChannelMessageResponse {
+            contact_id: conversation.contact_id,
+            conversation_id: conversation.id,
+            conversation_status: conversation.status,
+            message,
+            created_conversation: false,
+            duplicate: true,
+        }))
+    }
+
+    /// Find the sender's contact by channel identity, then email, creating it if needed
+    pub async fn resolve_contact(
+        &self,
+        inbox_id: &str,
+        sender: &ChannelSender,
+    ) -> ChannelResult<Contact> {
+        let external_id = sender
+            .external_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|id| !id.is_empty());
+        let email = sender
+            .email
+            .as_deref()
+            .map(str::trim)
+            .filter(|email| !email.is_empty())
+            .map(str::to_lowercase);
+
+        if let Some(external_id) = external_id {
+            if let Some(contact) = self
+                .channel_repo
+                .find_channel_contact(inbox_id, external_id)
+                .await?
+            {
+                return Ok(contact);
+            }
+        }
+
+        let email = match (email, external_id) {
+            (Some(email), _) => email,
+            (None, Some(external_id)) => ChannelSender::placeholder_email(inbox_id, external_id),
+            (None, None) => {
+                return Err(ChannelError::Validation(
+                    "Sender must have an external_id or email".to_string(),
+                ))
+            }
+        };
+
+        let contact = match self.contact_repo.get_contact_by_email(&email).await? {
+            Some(contact) => contact,
+            None => {
+                self.contact_repo
+                    .create_contact_from_message(&email, sender.name.as_deref(), inbox_id)
+                    .await?;
+                self.contact_repo
+                    .get_contact_by_email(&email)
+                    .await?
+                    .ok_or_else(|| {
+                        ChannelError::NotFound(format!("Contact for {} not found", email))
+                    })?
+            }
+        };
+
+        if let Some(external_id) = external_id {
+            self.channel_repo
+                .create_channel_identity(inbox_id, external_id, &contact.id)
+                .await?;
+        }
+
+        Ok(contact)
+    }
+
+    /// Pick the conversation for the message; returns whether it was newly created
+    async fn resolve_conversation(
+        &self,
+        inbox_id: &str,
+        contact: &Contact,
+        request: &ChannelMessageRequest,
+    ) -> ChannelResult<(Conversation, bool)> {
+        let existing = match request.conversation_id {
+            Some(ref conversation_id) => {
+                let conversation = self
+                    .conversation_repo
+                    .get_conversation_by_id(conversation_id)
+                    .await?
+                    .filter(|c| c.inbox_id == inbox_id && c.contact_id == contact.id)
+                    .ok_or_else(|| {
+                        ChannelError::NotFound(format!(
+                            "Conversation {} not found",
+                            conversation_id
+                        ))
+                    })?;
+                Some(conversation)
+            }
+            None if request.new_conversation => None,
+            None => {
+                let filter = ConversationListFilter {
+                    inbox_id: Some(inbox_id.to_string()),
+                    contact_id: Some(contact.id.clone()),
+                    unresolved: true,
+                    ..Default::default()
+                };
+                self.conversation_repo
+                    .list_conversations(1, 0, &filter)
+                    .await?
+                    .into_iter()
+                    .next()
+            }
+        };
+
+        if let Some(conversation) = existing {
+            if conversation.status == ConversationStatus::Open {
+                return Ok((conversation, false));
+            }
+
+            // Reopen conversation if it was snoozed or closed, as email replies do
+            self.conversation_repo
+                .update_conversation_status(&conversation.id, ConversationStatus::Open)
+                .await?;
+            let reopened = self
+                .conversation_repo
+                .get_conversation_by_id(&conversation.id)
+                .await?
+                .ok_or_else(|| {
+                    ChannelError::NotFound(format!("Conversation {} not found", conversation.id))
+                })?;
+            return Ok((reopened, false));
+        }
+
+        let conversation = self
+            .conversation_repo
+            .create_conversation(&CreateConversation {
+                inbox_id: inbox_id.to_string(),
+                contact_id: contact.id.clone(),
+                subject: request.subject.clone(),
+            })
+            .await?;
+
+        Ok((conversation, true))
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{
+    application::services::ChannelService,
+    domain::entities::{
+        ChannelMessageRequest, ChannelSender, Contact, Inbox, MessageType, WidgetConnectQuery,
+        WidgetMessage, WidgetSession, CHAT_CHANNEL_TYPE, WIDGET_HISTORY_LIMIT,
+    },
+    domain::errors::{ChannelError, ChannelResult},
+    domain::ports::channel_repository::ChannelRepository,
+    domain::ports::contact_repository::ContactRepository,
+    domain::ports::conversation_repository::ConversationRepository,
+    domain::ports::inbox_repository::InboxRepository,
+    domain::ports::message_repository::MessageRepository,
+    domain::services::verify_widget_identity,
+    infrastructure::providers::connection_manager::ConnectionManager,
+    shared::utils::email_validator::validate_and_normalize_email,
+};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+/// Visitor tokens are generated with this many characters; shorter ones are rejected
+const VISITOR_TOKEN_LENGTH: usize = 32;
+
+/// Service backing the web chat widget
+///
+/// Visitors are anonymous until they leave an email address. Each visitor is a
+/// channel identity derived from the token their browser keeps, so their
+/// messages go through `ChannelService` like any other channel. Agent replies
+/// reach connected visitors through `connections`, keyed by conversation ID.
+///
+/// An email only joins the visitor to an existing contact when the host site
+/// vouched for it with a signature made with `identity_secret`.
+#[derive(Clone)]
+pub struct ChatWidgetService {
+    inbox_repo: Arc<dyn InboxRepository>,
+    channel_repo: Arc<dyn ChannelRepository>,
+    contact_repo: Arc<dyn ContactRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    message_repo: Arc<dyn MessageRepository>,
+    channel_service: ChannelService,
+    connections: Arc<dyn ConnectionManager>,
+    identity_secret: Option<String>,
+}
+
+impl ChatWidgetService {
+    pub fn new(
+        inbox_repo: Arc<dyn InboxRepository>,
+        channel_repo: Arc<dyn ChannelRepository>,
+        contact_repo: Arc<dyn ContactRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+        message_repo: Arc<dyn MessageRepository>,
+        channel_service: ChannelService,
+        connections: Arc<dyn ConnectionManager>,
+    ) -> Self {
+        Self {
+            inbox_repo,
+            channel_repo,
+            contact_repo,
+            conversation_repo,
+            message_repo,
+            channel_service,
+            connections,
+            identity_secret: None,
+        }
+    }
+
+    /// Secret shared with the host site for signing the emails of its logged-in users
+    pub fn with_identity_secret(mut self, secret: &str) -> Self {
+        self.identity_secret = Some(secret.to_string()).filter(|s| !s.is_empty());
+        self
+    }
+
+    /// Live visitor connections, keyed by conversation ID
+    pub fn connections(&self) -> Arc<dyn ConnectionManager> {
+        self.connections.clone()
+    }
+
+    /// Get an inbox that serves the chat widget
+    pub async fn widget_inbox(&self, inbox_id: &str) -> ChannelResult<Inbox> {
+        self.inbox_repo
+            .get_inbox(inbox_id)
+            .await?
+            .filter(|inbox| inbox.channel_type == CHAT_CHANNEL_TYPE)
+            .ok_or_else(|| ChannelError::NotFound(format!("Chat inbox {} not found", inbox_id)))
+    }
+
+    /// Start a visitor session, resuming the visitor and conversation the widget stored
+    ///
+    /// Unknown or malformed tokens start a new anonymous visitor. A stored
+    /// conversation is only resumed if it belongs to the visitor.
+    pub async fn start_session(
+        &self,
+        inbox_id: &str,
+        query: &WidgetConnectQuery,
+    ) -> ChannelResult<WidgetSession> {
+        self.widget_inbox(inbox_id).await?;
+
+        let visitor_token = query
+            .visitor_token
+            .as_deref()
+            .filter(|token| is_valid_visitor_token(token))
+            .map(str::to_string)
+            .unwrap_or_else(generate_visitor_token);
+        let external_id = visitor_external_id(&visitor_token);
+
+        let contact = self
+            .channel_repo
+            .find_channel_contact(inbox_id, &external_id)
+            .await?;
+        let identified = match contact {
+            Some(ref contact) => !self.is_placeholder(inbox_id, &external_id, contact).await?,
+            None => false,
+        };
+
+        let mut conversation_id = None;
+        if let (Some(ref contact), Some(ref requested)) = (&contact, &query.conversation_id) {
+            conversation_id = self
+                .conversation_repo
+                .get_conversation_by_id(requested)
+                .await?
+                .filter(|c| c.inbox_id == inbox_id && c.contact_id == contact.id)
+                .map(|c| c.id);
+        }
+
+        Ok(WidgetSession {
+            inbox_id: inbox_id.to_string(),
+            visitor_token,
+            external_id,
+            contact,
+            conversation_id,
+            identified,
+        })
+    }
+
+    /// Most recent messages of the session's conversation, oldest first
+    pub async fn history(&self, session: &WidgetSession) -> ChannelResult<Vec<WidgetMessage>> {
+        let Some(ref conversation_id) = session.conversation_id else {
+            return Ok(Vec::new());
+        };
+
+        let (mut messages, _) = self
+            .message_repo
+            .list_messages(conversation_id, WIDGET_HISTORY_LIMIT, 0)
+            .await?;
+        messages.reverse();
+
+        Ok(messages.into_iter().map(WidgetMessage::from).collect())
+    }
+
+    /// Ingest a visitor message, starting a conversation on the first one
+    pub async fn send_message(
+        &self,
+        session: &mut WidgetSession,
+        content: String,
+    ) -> ChannelResult<WidgetMessage> {
+        let response = self
+            .channel_service
+            .receive_message(
+                &session.inbox_id,
+                ChannelMessageRequest {
+                    sender: ChannelSender {
+                        external_id: Some(session.external_id.clone()),
+                        email: None,
+                        name: None,
+                    },
+                    content,
+                    conversation_id: session.conversation_id.clone(),
+                    new_conversation: session.conversation_id.is_none(),
+                    subject: None,
+                    external_message_id: None,
+                    channel: None,
+                    channel_metadata: None,
+                },
+            )
+            .await?;
+
+        if session.contact.is_none() {
+            session.contact = self
+                .channel_repo
+                .find_channel_contact(&session.inbox_id, &session.external_id)
+                .await?;
+        }
+        session.conversation_id = Some(response.conversation_id);
+
+        Ok(WidgetMessage::from(response.message))
+    }
+
+    /// Promote the visitor to a known contact when they leave their email
+    ///
+    /// An anonymous visitor's placeholder contact takes the email. If a contact
+    /// with that email already exists, the visitor's conversations move to it,
+    /// but only when `identity_signature` proves the host site verified the
+    /// email; otherwise anyone could read that contact's conversations by typing
+    /// their address. Unverified visitors stay a separate anonymous contact.
+    pub async fn identify(
+        &self,
+        session: &mut WidgetSession,
+        email: &str,
+        name: Option<&str>,
+        identity_signature: Option<&str>,
+    ) -> ChannelResult<()> {
+        let email = validate_and_normalize_email(email)
+            .map_err(|_| ChannelError::Validation(format!("Invalid email: {}", email.trim())))?;
+        let name = name.map(str::trim).filter(|name| !name.is_empty());
+
+        let existing = self.contact_repo.get_contact_by_email(&email).await?;
+        let claims_other_contact = existing.as_ref().is_some_and(|existing| {
+            session
+                .contact
+                .as_ref()
+                .is_none_or(|current| current.id != existing.id)
+        });
+        if claims_other_contact && !self.is_verified(&email, identity_signature) {
+            tracing::info!(
+                "Chat visitor in inbox {} left an unverified email of an existing contact",
+                session.inbox_id
+            );
+            return Ok(());
+        }
+
+        let Some(current) = session.contact.clone() else {
+            let sender = ChannelSender {
+                external_id: Some(session.external_id.clone()),
+                email: Some(email),
+                name: name.map(str::to_string),
+            };
+            session.contact = Some(
+                self.channel_service
+                    .resolve_contact(&session.inbox_id, &sender)
+                    .await?,
+            );
+            session.identified = true;
+            return Ok(());
+        };
+
+        if existing.as_ref().is_some_and(|c| c.id == current.id) {
+            session.identified = true;
+            return Ok(());
+        }
+        if !self
+            .is_placeholder(&session.inbox_id, &session.external_id, &current)
+            .await?
+        {
+            return Err(ChannelError::Validation(
+                "Visitor is already identified as another contact".to_string(),
+            ));
+        }
+
+        match existing {
+            Some(existing) => {
+                self.channel_repo
+                    .merge_channel_contact(&current, &existing)
+                    .await?;
+                session.contact = Some(existing);
+            }
+            None => {
+                self.channel_repo
+                    .rename_channel_contact(&current, &email, name)
+                    .await?;
+            }
+        }
+        session.identified = true;
+
+        Ok(())
+    }
+
+    /// Agent reply to show a visitor, if the message belongs to their conversation
+    pub async fn reply_for_visitor(
+        &self,
+        session: &WidgetSession,
+        message_id: &str,
+    ) -> ChannelResult<Option<WidgetMessage>> {
+        let Some(message) = self.message_repo.get_message_by_id(message_id).await? else {
+            return Ok(None);
+        };
+        if message.message_type != MessageType::Outgoing
+            || session.conversation_id.as_deref() != Some(message.conversation_id.as_str())
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(WidgetMessage::from(message)))
+    }
+
+    /// Whether the host site signed `email` for this visitor
+    fn is_verified(&self, email: &str, signature: Option<&str>) -> bool {
+        match (&self.identity_secret, signature) {
+            (Some(secret), Some(signature)) => verify_widget_identity(email, signature, secret),
+            _ => false,
+        }
+    }
+
+    /// Whether the contact is still the anonymous placeholder created for the visitor
+    async fn is_placeholder(
+        &self,
+        inbox_id: &str,
+        external_id: &str,
+        contact: &Contact,
+    ) -> ChannelResult<bool> {
+        let placeholder = ChannelSender::placeholder_email(inbox_id, external_id);
+        Ok(self
+            .contact_repo
+            .get_contact_by_email(&placeholder)
+            .await?
+            .is_some_and(|c| c.id == contact.id))
+    }
+}
+
+fn generate_visitor_token() -> String {
+    use rand::{distributions::Alphanumeric, Rng};
+
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(VISITOR_TOKEN_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+fn is_valid_visitor_token(token: &str) -> bool {
+    (VISITOR_TOKEN_LENGTH..=128).contains(&token.len())
+        && token.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// Channel identity for a visitor; only a hash of the token is stored
+fn visitor_external_id(visitor_token: &str) -> String {
+    format!(
+        "visitor:{}",
+        hex::encode(Sha256::digest(visitor_token.as_bytes()))
+    )
+}
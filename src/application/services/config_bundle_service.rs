@@ -0,0 +1,729 @@
+use crate::{
+    domain::entities::{
+        parse_duration, AutomationRule, BundledAutomationRule, BundledMacro, BundledMacroAction,
+        BundledSlaPolicy, BundledTag, BundledWebhook, ConfigBundle, ConflictStrategy, ImportAction,
+        ImportChange, ImportConfigRequest, ImportConfigResponse, Macro, MacroAction, SlaPolicy,
+        Tag, Webhook, CONFIG_BUNDLE_VERSION,
+    },
+    domain::errors::{ConfigBundleError, ConfigBundleResult},
+    domain::ports::automation_repository::AutomationRepository,
+    domain::ports::macro_repository::MacroRepository,
+    domain::ports::sla_repository::SlaRepository,
+    domain::ports::tag_repository::TagRepository,
+    domain::ports::webhook_repository::WebhookRepository,
+    shared::utils::generate_secret,
+};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Page size used when reading paginated repositories for an export
+const EXPORT_PAGE_SIZE: i64 = 500;
+
+/// Service exporting and importing configuration bundles between environments
+#[derive(Clone)]
+pub struct ConfigBundleService {
+    automation_repo: Arc<dyn AutomationRepository>,
+    macro_repo: MacroRepository,
+    tag_repo: TagRepository,
+    sla_repo: Arc<dyn SlaRepository>,
+    webhook_repo: WebhookRepository,
+}
+
+impl ConfigBundleService {
+    pub fn new(
+        automation_repo: Arc<dyn AutomationRepository>,
+        macro_repo: MacroRepository,
+        tag_repo: TagRepository,
+        sla_repo: Arc<dyn SlaRepository>,
+        webhook_repo: WebhookRepository,
+    ) -> Self {
+        Self {
+            automation_repo,
+            macro_repo,
+            tag_repo,
+            sla_repo,
+            webhook_repo,
+        }
+    }
+
+    /// Export automation rules, macros, tags, SLA policies and webhooks (without secrets)
+    pub async fn export(&self) -> ConfigBundleResult<ConfigBundle> {
+        let automation_rules = self
+            .automation_repo
+            .get_automation_rules(false)
+            .await?
+            .iter()
+            .map(bundle_rule)
+            .collect();
+
+        let mut macros = Vec::new();
+        for macro_obj in self.macro_repo.list_macros().await? {
+            let actions = self.macro_repo.get_macro_actions(&macro_obj.id).await?;
+            macros.push(bundle_macro(&macro_obj, &actions));
+        }
+
+        Ok(ConfigBundle {
+            version: CONFIG_BUNDLE_VERSION,
+            exported_at: Some(chrono::Utc::now().to_rfc3339()),
+            automation_rules,
+            macros,
+            tags: self.all_tags().await?.iter().map(bundle_tag).collect(),
+            sla_policies: self
+                .all_sla_policies()
+                .await?
+                .iter()
+                .map(bundle_sla_policy)
+                .collect(),
+            webhooks: self
+                .all_webhooks()
+                .await?
+                .iter()
+                .map(bundle_webhook)
+                .collect(),
+        })
+    }
+
+    /// Import a bundle, matching existing items by name
+    ///
+    /// With `dry_run` nothing is written and the planned changes are returned.
+    /// Otherwise a `Fail` conflict strategy rejects the import before any write
+    /// when an existing item differs from the bundle.
+    pub async fn import(
+        &self,
+        request: ImportConfigRequest,
+        imported_by: &str,
+    ) -> ConfigBundleResult<ImportConfigResponse> {
+        let bundle = request.bundle;
+        let strategy = request.on_conflict;
+        validate_bundle(&bundle)?;
+
+        // Existing items by name
+        let rules: HashMap<String, AutomationRule> = self
+            .automation_repo
+            .get_automation_rules(false)
+            .await?
+            .into_iter()
+            .map(|rule| (rule.name.clone(), rule))
+            .collect();
+        let mut macros: HashMap<String, (Macro, Vec<MacroAction>)> = HashMap::new();
+        for macro_obj in self.macro_repo.list_macros().await? {
+            let actions = self.macro_repo.get_macro_actions(&macro_obj.id).await?;
+            macros.insert(macro_obj.name.clone(), (macro_obj, actions));
+        }
+        let tags: HashMap<String, Tag> = self
+            .all_tags()
+            .await?
+            .into_iter()
+            .map(|tag| (tag.name.clone(), tag))
+            .collect();
+        let policies: HashMap<String, SlaPolicy> = self
+            .all_sla_policies()
+            .await?
+            .into_iter()
+            .map(|policy| (policy.name.clone(), policy))
+            .collect();
+        let mut webhooks: HashMap<String, Webhook> = HashMap::new();
+        for webhook in self.all_webhooks().await? {
+            webhooks.entry(webhook.name.clone()).or_insert(webhook);
+        }
+
+        // Plan
+        let tag_changes = bundle
+            .tags
+            .iter()
+            .map(|tag| {
+                plan_change(
+                    "tag",
+                    &tag.name,
+                    tag,
+                    tags.get(&tag.name).map(bundle_tag),
+                    strategy,
+                )
+            })
+            .collect::<ConfigBundleResult<Vec<_>>>()?;
+        let policy_changes = bundle
+            .sla_policies
+            .iter()
+            .map(|policy| {
+                plan_change(
+                    "sla_policy",
+                    &policy.name,
+                    policy,
+                    policies.get(&policy.name).map(bundle_sla_policy),
+                    strategy,
+                )
+            })
+            .collect::<ConfigBundleResult<Vec<_>>>()?;
+        let macro_changes = bundle
+            .macros
+            .iter()
+            .map(|m| {
+                plan_change(
+                    "macro",
+                    &m.name,
+                    m,
+                    macros
+                        .get(&m.name)
+                        .map(|(existing, actions)| bundle_macro(existing, actions)),
+                    strategy,
+                )
+            })
+            .collect::<ConfigBundleResult<Vec<_>>>()?;
+        let rule_changes = bundle
+            .automation_rules
+            .iter()
+            .map(|rule| {
+                plan_change(
+                    "automation_rule",
+                    &rule.name,
+                    rule,
+                    rules.get(&rule.name).map(bundle_rule),
+                    strategy,
+                )
+            })
+            .collect::<ConfigBundleResult<Vec<_>>>()?;
+        let mut webhook_changes = bundle
+            .webhooks
+            .iter()
+            .map(|webhook| {
+                plan_change(
+                    "webhook",
+                    &webhook.name,
+                    webhook,
+                    webhooks.get(&webhook.name).map(bundle_webhook),
+                    strategy,
+                )
+            })
+            .collect::<ConfigBundleResult<Vec<_>>>()?;
+        for change in &mut webhook_changes {
+            if change.action == ImportAction::Create {
+                change.note = Some(
+                    "A new signing secret is generated; set it to match the receiver".to_string(),
+                );
+            }
+        }
+
+        let mut changes = Vec::new();
+        changes.extend(tag_changes.iter().cloned());
+        changes.extend(policy_changes.iter().cloned());
+        changes.extend(macro_changes.iter().cloned());
+        changes.extend(rule_changes.iter().cloned());
+        changes.extend(webhook_changes.iter().cloned());
+
+        let count = |action: ImportAction| changes.iter().filter(|c| c.action == action).count();
+        let conflicts = count(ImportAction::Conflict);
+
+        if !request.dry_run && conflicts > 0 {
+            let names: Vec<String> = changes
+                .iter()
+                .filter(|c| c.action == ImportAction::Conflict)
+                .map(|c| format!("{} '{}'", c.kind, c.name))
+                .collect();
+            return Err(ConfigBundleError::Conflict(format!(
+                "Bundle conflicts with existing configuration: {}",
+                names.join(", ")
+            )));
+        }
+
+        if !request.dry_run {
+            // Apply in dependency order: tags and SLA policies before what refers to them
+            for (tag, change) in bundle.tags.iter().zip(&tag_changes) {
+                self.apply_tag(tag, change.action, tags.get(&tag.name))
+                    .await?;
+            }
+            for (policy, change) in bundle.sla_policies.iter().zip(&policy_changes) {
+                self.apply_sla_policy(policy, change.action, policies.get(&policy.name))
+                    .await?;
+            }
+            for (m, change) in bundle.macros.iter().zip(&macro_changes) {
+                self.apply_macro(
+                    m,
+                    change.action,
+                    macros.get(&m.name).map(|(existing, _)| existing),
+                    imported_by,
+                )
+                .await?;
+            }
+            for (rule, change) in bundle.automation_rules.iter().zip(&rule_changes) {
+                self.apply_rule(rule, change.action, rules.get(&rule.name))
+                    .await?;
+            }
+            for (webhook, change) in bundle.webhooks.iter().zip(&webhook_changes) {
+                self.apply_webhook(
+                    webhook,
+                    change.action,
+                    webhooks.get(&webhook.name),
+                    imported_by,
+                )
+                .await?;
+            }
+
+            tracing::info!(
+                "Configuration bundle imported by {}: {} created, {} updated",
+                imported_by,
+                count(ImportAction::Create),
+                count(ImportAction::Update)
+            );
+        }
+
+        Ok(ImportConfigResponse {
+            dry_run: request.dry_run,
+            applied: !request.dry_run,
+            created: count(ImportAction::Create),
+            updated: count(ImportAction::Update),
+            unchanged: count(ImportAction::Unchanged),
+            skipped: count(ImportAction::Skip),
+            conflicts,
+            changes,
+        })
+    }
+
+    async fn apply_tag(
+        &self,
+        tag: &BundledTag,
+        action: ImportAction,
+        existing: Option<&Tag>,
+    ) -> ConfigBundleResult<()> {
+        match (action, existing) {
+            (ImportAction::Create, _) => {
+                let new_tag =
+                    Tag::new(tag.name.clone(), tag.description.clone(), tag.color.clone());
+                self.tag_repo.create_tag(&new_tag).await?;
+            }
+            (ImportAction::Update, Some(existing)) => {
+                self.tag_repo
+                    .update_tag(&existing.id, tag.description.clone(), tag.color.clone())
+                    .await?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn apply_sla_policy(
+        &self,
+        policy: &BundledSlaPolicy,
+        action: ImportAction,
+        existing: Option<&SlaPolicy>,
+    ) -> ConfigBundleResult<()> {
+        match (action, existing) {
+            (ImportAction::Create, _) => {
+                let new_policy = SlaPolicy::new(
+                    policy.name.clone(),
+                    policy.description.clone(),
+                    policy.first_response_time.clone(),
+                    policy.resolution_time.clone(),
+                    policy.next_response_time.clone(),
+                );
+                self.sla_repo.create_sla_policy(&new_policy).await?;
+            }
+            (ImportAction::Update, Some(existing)) => {
+                self.sla_repo
+                    .update_sla_policy(
+                        &existing.id,
+                        None,
+                        Some(policy.description.as_deref()),
+                        Some(&policy.first_response_time),
+                        Some(&policy.resolution_time),
+                        Some(&policy.next_response_time),
+                    )
+                    .await?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn apply_macro(
+        &self,
+        bundled: &BundledMacro,
+        action: ImportAction,
+        existing: Option<&Macro>,
+        imported_by: &str,
+    ) -> ConfigBundleResult<()> {
+        let macro_obj = match (action, existing) {
+            (ImportAction::Create, _) => {
+                let macro_obj = new_macro(bundled, imported_by);
+                self.macro_repo.create_macro(&macro_obj).await?;
+                macro_obj
+            }
+            (ImportAction::Update, Some(existing)) => {
+                let mut macro_obj = existing.clone();
+                macro_obj.message_content = bundled.message_content.clone();
+                macro_obj.access_control = bundled.access_control.clone();
+                macro_obj.updated_at = chrono::Utc::now().to_rfc3339();
+                self.macro_repo.update_macro(&macro_obj).await?;
+                self.macro_repo.delete_macro_actions(&macro_obj.id).await?;
+                macro_obj
+            }
+            _ => return Ok(()),
+        };
+
+        for action in macro_actions(bundled, &macro_obj.id) {
+            self.macro_repo.create_macro_action(&action).await?;
+        }
+        Ok(())
+    }
+
+    async fn apply_rule(
+        &self,
+        bundled: &BundledAutomationRule,
+        action: ImportAction,
+        existing: Option<&AutomationRule>,
+    ) -> ConfigBundleResult<()> {
+        match (action, existing) {
+            (ImportAction::Create, _) => {
+                self.automation_repo
+                    .create_automation_rule(&new_rule(bundled))
+                    .await?;
+            }
+            (ImportAction::Update, Some(existing)) => {
+                let mut rule = new_rule(bundled);
+                rule.id = existing.id.clone();
+                rule.created_at = existing.created_at.clone();
+                self.automation_repo.update_automation_rule(&rule).await?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn apply_webhook(
+        &self,
+        bundled: &BundledWebhook,
+        action: ImportAction,
+        existing: Option<&Webhook>,
+        imported_by: &str,
+    ) -> ConfigBundleResult<()> {
+        match (action, existing) {
+            (ImportAction::Create, _) => {
+                let mut webhook = Webhook::new(
+                    bundled.name.clone(),
+                    bundled.url.clone(),
+                    bundled.subscribed_events.clone(),
+                    generate_secret(),
+                    imported_by.to_string(),
+                );
+                webhook.is_active = bundled.is_active;
+                webhook.include_conversation_snapshot = bundled.include_conversation_snapshot;
+                self.webhook_repo.create_webhook(&webhook).await?;
+            }
+            (ImportAction::Update, Some(existing)) => {
+                // The existing secret is kept
+                let mut webhook = existing.clone();
+                webhook.url = bundled.url.clone();
+                webhook.subscribed_events = bundled.subscribed_events.clone();
+                webhook.is_active = bundled.is_active;
+                webhook.include_conversation_snapshot = bundled.include_conversation_snapshot;
+                webhook.touch();
+                self.webhook_repo.update_webhook(&webhook).await?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn all_tags(&self) -> ConfigBundleResult<Vec<Tag>> {
+        let mut tags = Vec::new();
+        loop {
+            let (page, total) = self
+                .tag_repo
+                .list_tags(EXPORT_PAGE_SIZE, tags.len() as i64)
+                .await?;
+            let done = page.is_empty();
+            tags.extend(page);
+            if done || tags.len() as i64 >= total {
+                return Ok(tags);
+            }
+        }
+    }
+
+    async fn all_sla_policies(&self) -> ConfigBundleResult<Vec<SlaPolicy>> {
+        let mut policies = Vec::new();
+        loop {
+            let (page, total) = self
+                .sla_repo
+                .list_sla_policies(EXPORT_PAGE_SIZE, policies.len() as i64)
+                .await?;
+            let done = page.is_empty();
+            policies.extend(page);
+            if done || policies.len() as i64 >= total {
+                return Ok(policies);
+            }
+        }
+    }
+
+    async fn all_webhooks(&self) -> ConfigBundleResult<Vec<Webhook>> {
+        let mut webhooks = Vec::new();
+        loop {
+            let page = self
+                .webhook_repo
+                .list_webhooks(EXPORT_PAGE_SIZE, webhooks.len() as i64)
+                .await?;
+            let done = (page.len() as i64) < EXPORT_PAGE_SIZE;
+            webhooks.extend(page);
+            if done {
+                return Ok(webhooks);
+            }
+        }
+    }
+}
+
+/// Decide what importing `desired` means given the existing item of the same name
+fn plan_change<T: Serialize>(
+    kind: &str,
+    name: &str,
+    desired: &T,
+    existing: Option<T>,
+    strategy: ConflictStrategy,
+) -> ConfigBundleResult<ImportChange> {
+    let (action, changed_fields) = match existing {
+        None => (ImportAction::Create, Vec::new()),
+        Some(existing) => {
+            let changed_fields = changed_fields(&to_json(desired)?, &to_json(&existing)?);
+            let action = if changed_fields.is_empty() {
+                ImportAction::Unchanged
+            } else {
+                match strategy {
+                    ConflictStrategy::Skip => ImportAction::Skip,
+                    ConflictStrategy::Overwrite => ImportAction::Update,
+                    ConflictStrategy::Fail => ImportAction::Conflict,
+                }
+            };
+            (action, changed_fields)
+        }
+    };
+
+    Ok(ImportChange {
+        kind: kind.to_string(),
+        name: name.to_string(),
+        action,
+        changed_fields,
+        note: None,
+    })
+}
+
+fn to_json<T: Serialize>(value: &T) -> ConfigBundleResult<serde_json::Value> {
+    serde_json::to_value(value)
+        .map_err(|e| ConfigBundleError::Validation(format!("Failed to serialize item: {}", e)))
+}
+
+/// Top-level object keys whose values differ
+fn changed_fields(desired: &serde_json::Value, existing: &serde_json::Value) -> Vec<String> {
+    let (Some(desired), Some(existing)) = (desired.as_object(), existing.as_object()) else {
+        return Vec::new();
+    };
+
+    let mut fields: Vec<String> = desired
+        .keys()
+        .chain(existing.keys())
+        .filter(|key| desired.get(*key) != existing.get(*key))
+        .cloned()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    fields.sort();
+    fields
+}
+
+/// Validate every item before anything is planned or written
+fn validate_bundle(bundle: &ConfigBundle) -> ConfigBundleResult<()> {
+    if bundle.version != CONFIG_BUNDLE_VERSION {
+        return Err(ConfigBundleError::Validation(format!(
+            "Unsupported bundle version {} (expected {})",
+            bundle.version, CONFIG_BUNDLE_VERSION
+        )));
+    }
+
+    let invalid = |kind: &str, name: &str, e: String| {
+        ConfigBundleError::Validation(format!("Invalid {} '{}': {}", kind, name, e))
+    };
+
+    check_unique_names(
+        "automation_rule",
+        bundle.automation_rules.iter().map(|r| &r.name),
+    )?;
+    for rule in &bundle.automation_rules {
+        new_rule(rule)
+            .validate()
+            .map_err(|e| invalid("automation_rule", &rule.name, e))?;
+    }
+
+    check_unique_names("macro", bundle.macros.iter().map(|m| &m.name))?;
+    for m in &bundle.macros {
+        let macro_obj = new_macro(m, "import");
+        macro_obj
+            .validate()
+            .map_err(|e| invalid("macro", &m.name, e))?;
+        for action in macro_actions(m, &macro_obj.id) {
+            action
+                .validate()
+                .map_err(|e| invalid("macro", &m.name, e))?;
+        }
+    }
+
+    check_unique_names("tag", bundle.tags.iter().map(|t| &t.name))?;
+    for tag in &bundle.tags {
+        if tag.name.trim().is_empty() || tag.name.len() > 50 {
+            return Err(invalid(
+                "tag",
+                &tag.name,
+                "name must be 1-50 characters".to_string(),
+            ));
+        }
+        if let Some(color) = &tag.color {
+            if !color.starts_with('#') || color.len() != 7 {
+                return Err(invalid(
+                    "tag",
+                    &tag.name,
+                    "color must be in hex format (#RRGGBB)".to_string(),
+                ));
+            }
+        }
+    }
+
+    check_unique_names("sla_policy", bundle.sla_policies.iter().map(|p| &p.name))?;
+    for policy in &bundle.sla_policies {
+        for duration in [
+            &policy.first_response_time,
+            &policy.resolution_time,
+            &policy.next_response_time,
+        ] {
+            parse_duration(duration).map_err(|e| invalid("sla_policy", &policy.name, e))?;
+        }
+    }
+
+    check_unique_names("webhook", bundle.webhooks.iter().map(|w| &w.name))?;
+    for webhook in &bundle.webhooks {
+        Webhook::new(
+            webhook.name.clone(),
+            webhook.url.clone(),
+            webhook.subscribed_events.clone(),
+            generate_secret(),
+            "import".to_string(),
+        )
+        .validate()
+        .map_err(|e| invalid("webhook", &webhook.name, e))?;
+    }
+
+    Ok(())
+}
+
+fn check_unique_names<'a>(
+    kind: &str,
+    names: impl Iterator<Item = &'a String>,
+) -> ConfigBundleResult<()> {
+    let mut seen = HashSet::new();
+    for name in names {
+        if !seen.insert(name) {
+            return Err(ConfigBundleError::Validation(format!(
+                "Duplicate {} name '{}' in bundle",
+                kind, name
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn new_rule(bundled: &BundledAutomationRule) -> AutomationRule {
+    let mut rule = AutomationRule::new(
+        bundled.name.clone(),
+        bundled.rule_type.clone(),
+        bundled.event_subscription.clone(),
+        bundled.condition.clone(),
+        bundled.action.clone(),
+    );
+    rule.description = bundled.description.clone();
+    rule.enabled = bundled.enabled;
+    rule.priority = bundled.priority;
+    rule
+}
+
+fn new_macro(bundled: &BundledMacro, created_by: &str) -> Macro {
+    let now = chrono::Utc::now().to_rfc3339();
+    Macro {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: bundled.name.clone(),
+        message_content: bundled.message_content.clone(),
+        created_by: created_by.to_string(),
+        created_at: now.clone(),
+        updated_at: now,
+        usage_count: 0,
+        access_control: bundled.access_control.clone(),
+        actions: None,
+    }
+}
+
+fn macro_actions(bundled: &BundledMacro, macro_id: &str) -> Vec<MacroAction> {
+    bundled
+        .actions
+        .iter()
+        .enumerate()
+        .map(|(order, action)| MacroAction {
+            id: uuid::Uuid::new_v4().to_string(),
+            macro_id: macro_id.to_string(),
+            action_type: action.action_type.clone(),
+            action_value: action.action_value.clone(),
+            action_order: order as i32,
+        })
+        .collect()
+}
+
+fn bundle_rule(rule: &AutomationRule) -> BundledAutomationRule {
+    BundledAutomationRule {
+        name: rule.name.clone(),
+        description: rule.description.clone(),
+        enabled: rule.enabled,
+        rule_type: rule.rule_type.clone(),
+        event_subscription: rule.event_subscription.clone(),
+        condition: rule.condition.clone(),
+        action: rule.action.clone(),
+        priority: rule.priority,
+    }
+}
+
+fn bundle_macro(macro_obj: &Macro, actions: &[MacroAction]) -> BundledMacro {
+    let mut actions = actions.to_vec();
+    actions.sort_by_key(|action| action.action_order);
+    BundledMacro {
+        name: macro_obj.name.clone(),
+        message_content: macro_obj.message_content.clone(),
+        access_control: macro_obj.access_control.clone(),
+        actions: actions
+            .into_iter()
+            .map(|action| BundledMacroAction {
+                action_type: action.action_type,
+                action_value: action.action_value,
+            })
+            .collect(),
+    }
+}
+
+fn bundle_tag(tag: &Tag) -> BundledTag {
+    BundledTag {
+        name: tag.name.clone(),
+        description: tag.description.clone(),
+        color: tag.color.clone(),
+    }
+}
+
+fn bundle_sla_policy(policy: &SlaPolicy) -> BundledSlaPolicy {
+    BundledSlaPolicy {
+        name: policy.name.clone(),
+        description: policy.description.clone(),
+        first_response_time: policy.first_response_time.clone(),
+        resolution_time: policy.resolution_time.clone(),
+        next_response_time: policy.next_response_time.clone(),
+    }
+}
+
+fn bundle_webhook(webhook: &Webhook) -> BundledWebhook {
+    BundledWebhook {
+        name: webhook.name.clone(),
+        url: webhook.url.clone(),
+        subscribed_events: webhook.subscribed_events.clone(),
+        is_active: webhook.is_active,
+        include_conversation_snapshot: webhook.include_conversation_snapshot,
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    domain::entities::{ContactNote, CreateContactNoteRequest, UpdateContactNoteRequest, UserType},
+    domain::errors::{ContactNoteError, ContactNoteResult},
+    domain::ports::contact_note_repository::ContactNoteRepository,
+    domain::ports::user_repository::UserRepository,
+    infrastructure::http::middleware::AuthenticatedUser,
+};
+use std::sync::Arc;
+
+/// Service for agents' notes about contacts
+///
+/// Any agent can add, pin and unpin notes; editing or deleting a note's
+/// content is left to its author and admins.
+#[derive(Clone)]
+pub struct ContactNoteService {
+    note_repo: Arc<dyn ContactNoteRepository>,
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl ContactNoteService {
+    pub fn new(
+        note_repo: Arc<dyn ContactNoteRepository>,
+        user_repo: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            note_repo,
+            user_repo,
+        }
+    }
+
+    /// A contact's notes, pinned first
+    pub async fn list_notes(&self, contact_id: &str) -> ContactNoteResult<Vec<ContactNote>> {
+        self.ensure_contact(contact_id).await?;
+        Ok(self.note_repo.list_contact_notes(contact_id).await?)
+    }
+
+    /// A contact's pinned notes
+    pub async fn list_pinned_notes(&self, contact_id: &str) -> ContactNoteResult<Vec<ContactNote>> {
+        let mut notes = self.note_repo.list_contact_notes(contact_id).await?;
+        notes.retain(|note| note.pinned);
+        Ok(notes)
+    }
+
+    /// Pinned notes of a conversation's contact, for the conversation detail panel
+    pub async fn list_conversation_pinned_notes(
+        &self,
+        conversation_id: &str,
+    ) -> ContactNoteResult<Vec<ContactNote>> {
+        Ok(self
+            .note_repo
+            .list_conversation_pinned_notes(conversation_id)
+            .await?)
+    }
+
+    pub async fn create_note(
+        &self,
+        auth_user: &AuthenticatedUser,
+        contact_id: &str,
+        request: CreateContactNoteRequest,
+    ) -> ContactNoteResult<ContactNote> {
+        self.ensure_contact(contact_id).await?;
+
+        let note = ContactNote::new(
+            contact_id.to_string(),
+            auth_user.user.id.clone(),
+            request.content,
+            request.pinned,
+        );
+        self.note_repo.create_contact_note(&note).await?;
+
+        self.get_note(contact_id, &note.id).await
+    }
+
+    pub async fn update_note(
+        &self,
+        auth_user: &AuthenticatedUser,
+        contact_id: &str,
+        note_id: &str,
+        request: UpdateContactNoteRequest,
+    ) -> ContactNoteResult<ContactNote> {
+        let mut note = self.get_note(contact_id, note_id).await?;
+
+        if let Some(content) = request.content {
+            ensure_can_edit(auth_user, &note)?;
+            note.content = content.trim().to_string();
+        }
+        if let Some(pinned) = request.pinned {
+            note.set_pinned(pinned);
+        }
+        note.updated_at = chrono::Utc::now().to_rfc3339();
+        self.note_repo.update_contact_note(&note).await?;
+
+        self.get_note(contact_id, note_id).await
+    }
+
+    pub async fn delete_note(
+        &self,
+        auth_user: &AuthenticatedUser,
+        contact_id: &str,
+        note_id: &str,
+    ) -> ContactNoteResult<()> {
+        let note = self.get_note(contact_id, note_id).await?;
+        ensure_can_edit(auth_user, &note)?;
+
+        Ok(self.note_repo.delete_contact_note(&note.id).await?)
+    }
+
+    async fn get_note(&self, contact_id: &str, note_id: &str) -> ContactNoteResult<ContactNote> {
+        self.note_repo
+            .get_contact_note(note_id)
+            .await?
+            .filter(|note| note.contact_id == contact_id)
+            .ok_or_else(|| ContactNoteError::NotFound(format!("Note {} not found", note_id)))
+    }
+
+    async fn ensure_contact(&self, contact_id: &str) -> ContactNoteResult<()> {
+        match self.user_repo.get_user_by_id(contact_id).await? {
+            Some(user) if matches!(user.user_type, UserType::Contact) => Ok(()),
+            _ => Err(ContactNoteError::NotFound("Contact not found".to_string())),
+        }
+    }
+}
+
+fn ensure_can_edit(auth_user: &AuthenticatedUser, note: &ContactNote) -> ContactNoteResult<()> {
+    if auth_user.is_admin() || note.author_id.as_deref() == Some(auth_user.user.id.as_str()) {
+        return Ok(());
+    }
+    Err(ContactNoteError::Forbidden(
+        "Only the note's author or an admin can change it".to_string(),
+    ))
+}
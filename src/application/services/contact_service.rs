@@ -1,8 +1,10 @@
 use crate::infrastructure::http::middleware::{ApiError, ApiResult, AuthenticatedUser};
 use crate::domain::ports::contact_repository::ContactRepository;
+use crate::domain::ports::email_sender::{EmailSender, OutgoingEmail};
 use crate::domain::ports::user_repository::UserRepository;
 use crate::domain::entities::*;
 use crate::shared::utils::email_validator::validate_and_normalize_email;
+use crate::shared::utils::generate_reset_token;
 use std::sync::Arc;
 use time;
 
@@ -10,6 +12,9 @@ use time;
 pub struct ContactService {
     contact_repo: Arc<dyn ContactRepository>,
     user_repo: Arc<dyn UserRepository>,
+    email_sender: Option<Arc<dyn EmailSender>>,
+    /// Base URL of the API; the confirmation link is built on top of it
+    verification_base_url: String,
 }
 
 impl ContactService {
@@ -20,9 +25,18 @@ impl ContactService {
         Self {
             contact_repo,
             user_repo,
+            email_sender: None,
+            verification_base_url: "http://localhost:3000".to_string(),
         }
     }
 
+    /// Deliver verification links through `email_sender`, pointing them at
+    /// `base_url`; without a sender, links are not sent
+    pub fn set_email_sender(&mut self, email_sender: Arc<dyn EmailSender>, base_url: &str) {
+        self.email_sender = Some(email_sender);
+        self.verification_base_url = base_url.trim_end_matches('/').to_string();
+    }
+
     /// Create a new contact
     pub async fn create_contact(
         &self,
@@ -58,14 +72,16 @@ impl ContactService {
         let contact = Contact::new(user.id.clone(), request.first_name.clone());
         self.contact_repo.create_contact(&contact).await?;
 
-        // Create contact channel if inbox_id is provided
+        // Create contact channel if inbox_id is provided. Manually entered
+        // addresses stay unverified until the contact confirms them.
         if !request.inbox_id.is_empty() {
-            let channel = ContactChannel::new(
+            let channel = ContactChannel::new_unverified(
                 contact.id.clone(),
                 request.inbox_id.clone(),
                 user.email.clone(),
             );
             self.contact_repo.create_contact_channel(&channel).await?;
+            self.issue_email_verification(&channel).await?;
         }
 
         // Get channels for response
@@ -79,9 +95,134 @@ impl ContactService {
             channels,
             created_at: user.created_at.clone(),
             updated_at: user.updated_at.clone(),
+            notes: None,
+            tier: None,
         })
     }
 
+    /// Confirm a contact channel's email address from a verification link
+    pub async fn verify_email(&self, token: &str) -> ApiResult<ContactChannel> {
+        let verification = self
+            .contact_repo
+            .get_email_verification_by_token_hash(&ContactEmailVerification::hash_token(token))
+            .await?
+            .ok_or_else(|| ApiError::BadRequest("Invalid verification token".to_string()))?;
+
+        if verification.used {
+            return Err(ApiError::BadRequest(
+                "Verification token has already been used".to_string(),
+            ));
+        }
+
+        if verification.is_expired() {
+            return Err(ApiError::BadRequest(
+                "Verification token has expired".to_string(),
+            ));
+        }
+
+        let now = time::OffsetDateTime::now_utc()
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap();
+
+        self.contact_repo
+            .mark_channel_email_verified(&verification.id, &verification.channel_id, &now)
+            .await?;
+
+        tracing::info!(
+            "Contact channel {} email verified",
+            verification.channel_id
+        );
+
+        self.contact_repo
+            .get_contact_channel_by_id(&verification.channel_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("Contact channel not found".to_string()))
+    }
+
+    /// Send a fresh verification link for one of a contact's channels
+    pub async fn resend_email_verification(
+        &self,
+        auth_user: &AuthenticatedUser,
+        id: &str,
+        channel_id: &str,
+    ) -> ApiResult<()> {
+        // Check permission (admin only)
+        if !auth_user.is_admin() {
+            return Err(ApiError::Forbidden(
+                "Requires 'contacts:update' permission".to_string(),
+            ));
+        }
+
+        let contact = self
+            .contact_repo
+            .find_contact_by_user_id(id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("Contact not found".to_string()))?;
+
+        let channel = self
+            .contact_repo
+            .get_contact_channel_by_id(channel_id)
+            .await?
+            .filter(|channel| channel.contact_id == contact.id)
+            .ok_or_else(|| ApiError::NotFound("Contact channel not found".to_string()))?;
+
+        if channel.email_verified {
+            return Err(ApiError::Conflict(
+                "Contact email address is already verified".to_string(),
+            ));
+        }
+
+        self.issue_email_verification(&channel).await
+    }
+
+    /// Store a verification token for the channel and email the link (best-effort)
+    async fn issue_email_verification(&self, channel: &ContactChannel) -> ApiResult<()> {
+        let token_value = generate_reset_token();
+        let verification = ContactEmailVerification::new(channel.id.clone(), &token_value);
+        self.contact_repo
+            .create_email_verification(&verification)
+            .await?;
+
+        let Some(email_sender) = self.email_sender.clone() else {
+            tracing::warn!(
+                "No email sender configured, skipping contact verification email to {}",
+                channel.email
+            );
+            return Ok(());
+        };
+
+        let verification_link = format!(
+            "{}/api/contacts/verify-email?token={}",
+            self.verification_base_url, token_value
+        );
+        let message = OutgoingEmail {
+            to: channel.email.clone(),
+            subject: "Please verify your email address".to_string(),
+            text_body: format!(
+                "Please confirm that this email address belongs to you.\n\n\
+                 Click the link below to verify your email address:\n\
+                 {}\n\n\
+                 This link will expire in {} hours.\n\n\
+                 If you were not expecting this email, please ignore it.",
+                verification_link, CONTACT_EMAIL_VERIFICATION_TTL_HOURS
+            ),
+            html_body: None,
+        };
+
+        // Send in the background to not block the response
+        tokio::spawn(async move {
+            if let Err(e) = email_sender.send(&message).await {
+                tracing::error!(
+                    "Failed to send contact verification email to {}: {}",
+                    message.to,
+                    e
+                );
+            }
+        });
+
+        Ok(())
+    }
+
     /// Get a contact by ID
     pub async fn get_contact(&self, id: &str) -> ApiResult<ContactResponse> {
         // Get user
@@ -114,6 +255,8 @@ impl ContactService {
             channels,
             created_at: user.created_at.clone(),
             updated_at: user.updated_at.clone(),
+            notes: None,
+            tier: None,
         })
     }
 
@@ -177,6 +320,8 @@ impl ContactService {
                 channels,
                 created_at: user.created_at.clone(),
                 updated_at: user.updated_at.clone(),
+                notes: None,
+                tier: None,
             });
         }
 
@@ -239,6 +384,8 @@ impl ContactService {
             channels,
             created_at: user.created_at.clone(),
             updated_at: user.updated_at.clone(),
+            notes: None,
+            tier: None,
         })
     }
 
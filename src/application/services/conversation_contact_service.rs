@@ -0,0 +1,224 @@
+use std::sync::Arc;
+
+use crate::{
+    application::services::PermissionService,
+    domain::entities::{AddConversationContactRequest, Conversation, ConversationContact},
+    domain::errors::{ConversationContactError, ConversationContactResult, DomainError},
+    domain::ports::{
+        contact_repository::ContactRepository,
+        conversation_contact_repository::ConversationContactRepository,
+        conversation_repository::ConversationRepository, email_repository::EmailRepository,
+        team_repository::TeamRepository,
+    },
+    infrastructure::http::middleware::AuthenticatedUser,
+    shared::validation::Validate,
+};
+
+/// Service for the contacts taking part in a conversation
+///
+/// On email threads, adding a contact also puts their address on the
+/// thread's email participants so outbound replies reach them; removing
+/// one suppresses the address again.
+#[derive(Clone)]
+pub struct ConversationContactService {
+    conversation_contact_repo: Arc<dyn ConversationContactRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    contact_repo: Arc<dyn ContactRepository>,
+    email_repo: Arc<dyn EmailRepository>,
+    team_repo: Arc<dyn TeamRepository>,
+}
+
+impl ConversationContactService {
+    pub fn new(
+        conversation_contact_repo: Arc<dyn ConversationContactRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+        contact_repo: Arc<dyn ContactRepository>,
+        email_repo: Arc<dyn EmailRepository>,
+        team_repo: Arc<dyn TeamRepository>,
+    ) -> Self {
+        Self {
+            conversation_contact_repo,
+            conversation_repo,
+            contact_repo,
+            email_repo,
+            team_repo,
+        }
+    }
+
+    pub async fn list_contacts(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+    ) -> ConversationContactResult<Vec<ConversationContact>> {
+        let conversation = self.get_conversation(conversation_id).await?;
+        self.require_access(auth_user, &conversation, "read")
+            .await?;
+        Ok(self
+            .conversation_contact_repo
+            .list_conversation_contacts(conversation_id)
+            .await?)
+    }
+
+    pub async fn add_contact(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+        request: AddConversationContactRequest,
+    ) -> ConversationContactResult<ConversationContact> {
+        request.check().map_err(DomainError::Validation)?;
+        let conversation = self.get_conversation(conversation_id).await?;
+        self.require_access(auth_user, &conversation, "update")
+            .await?;
+
+        let contact_id = request.contact_id.trim();
+        self.contact_repo
+            .find_contact_by_id(contact_id)
+            .await?
+            .ok_or_else(|| ConversationContactError::NotFound("Contact not found".to_string()))?;
+
+        let added = self
+            .conversation_contact_repo
+            .add_conversation_contact(conversation_id, contact_id, Some(&auth_user.user.id))
+            .await?;
+        if !added {
+            return Err(ConversationContactError::Conflict(
+                "Contact already takes part in this conversation".to_string(),
+            ));
+        }
+
+        let contact = self.find_participant(conversation_id, contact_id).await?;
+        self.sync_email_participant(conversation_id, &contact.email, false, &auth_user.user.id)
+            .await?;
+
+        tracing::info!(
+            "Contact {} added to conversation {} by {}",
+            contact_id,
+            conversation_id,
+            auth_user.user.id
+        );
+        Ok(contact)
+    }
+
+    /// Remove an additional contact; the primary contact can't be removed
+    pub async fn remove_contact(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+        contact_id: &str,
+    ) -> ConversationContactResult<()> {
+        let conversation = self.get_conversation(conversation_id).await?;
+        self.require_access(auth_user, &conversation, "update")
+            .await?;
+
+        if conversation.contact_id == contact_id {
+            return Err(ConversationContactError::Validation(
+                "The conversation's primary contact can't be removed".to_string(),
+            ));
+        }
+
+        let contact = self.find_participant(conversation_id, contact_id).await?;
+        self.conversation_contact_repo
+            .remove_conversation_contact(conversation_id, contact_id)
+            .await?;
+        self.sync_email_participant(conversation_id, &contact.email, true, &auth_user.user.id)
+            .await?;
+
+        tracing::info!(
+            "Contact {} removed from conversation {} by {}",
+            contact_id,
+            conversation_id,
+            auth_user.user.id
+        );
+        Ok(())
+    }
+
+    async fn find_participant(
+        &self,
+        conversation_id: &str,
+        contact_id: &str,
+    ) -> ConversationContactResult<ConversationContact> {
+        self.conversation_contact_repo
+            .list_conversation_contacts(conversation_id)
+            .await?
+            .into_iter()
+            .find(|contact| contact.contact_id == contact_id)
+            .ok_or_else(|| {
+                ConversationContactError::NotFound(
+                    "Contact is not a participant of this conversation".to_string(),
+                )
+            })
+    }
+
+    /// Keep outbound email in line with the contact list; only applies to
+    /// conversations that already have an email thread
+    async fn sync_email_participant(
+        &self,
+        conversation_id: &str,
+        email: &str,
+        suppressed: bool,
+        user_id: &str,
+    ) -> ConversationContactResult<()> {
+        let participants = self
+            .email_repo
+            .list_email_participants(conversation_id)
+            .await?;
+        if participants.is_empty() {
+            return Ok(());
+        }
+
+        if !suppressed {
+            self.email_repo
+                .add_email_participant(conversation_id, email)
+                .await?;
+        }
+        self.email_repo
+            .set_email_participant_suppressed(conversation_id, email, suppressed, Some(user_id))
+            .await?;
+        Ok(())
+    }
+
+    async fn get_conversation(
+        &self,
+        conversation_id: &str,
+    ) -> ConversationContactResult<Conversation> {
+        self.conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| ConversationContactError::NotFound("Conversation not found".to_string()))
+    }
+
+    /// `action` is "read" or "update"; the `_assigned` permission only
+    /// covers conversations assigned to the user or one of their teams
+    async fn require_access(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation: &Conversation,
+        action: &str,
+    ) -> ConversationContactResult<()> {
+        let all = format!("conversations:{}_all", action);
+        if PermissionService::has_permission(&auth_user.roles, &all) {
+            return Ok(());
+        }
+
+        let assigned = format!("conversations:{}_assigned", action);
+        if PermissionService::has_permission(&auth_user.roles, &assigned) {
+            if conversation.assigned_user_id.as_deref() == Some(auth_user.user.id.as_str()) {
+                return Ok(());
+            }
+            if let Some(team_id) = &conversation.assigned_team_id {
+                if self
+                    .team_repo
+                    .is_team_member(team_id, &auth_user.user.id)
+                    .await?
+                {
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(ConversationContactError::Forbidden(format!(
+            "Missing permission: {}",
+            all
+        )))
+    }
+}
@@ -3,7 +3,7 @@ use crate::domain::entities::Conversation;
 use crate::domain::events::SystemEvent;
 use crate::domain::ports::conversation_repository::ConversationRepository;
 use crate::domain::ports::event_bus::EventBus;
-use crate::infrastructure::http::middleware::{ApiError, ApiResult};
+use crate::domain::errors::{PriorityError, PriorityResult};
 use std::sync::Arc;
 
 /// Service for managing conversation priorities
@@ -36,35 +36,21 @@ impl ConversationPriorityService {
     /// Updated conversation
     ///
     /// # Errors
-    /// * `ApiError::NotFound` - Conversation not found
-    /// * `ApiError::BadRequest` - Invalid priority value
-    /// Update conversation priority
-    ///
-    /// # Arguments
-    /// * `conversation_id` - ID of the conversation to update
-    /// * `new_priority` - New priority value ("Low", "Medium", "High", or None to remove)
-    /// * `updated_by` - User ID performing the update
-    /// * `event_bus` - Optional event bus for triggering automation rules
-    ///
-    /// # Returns
-    /// Updated conversation
-    ///
-    /// # Errors
-    /// * `ApiError::NotFound` - Conversation not found
+    /// * `PriorityError::NotFound` - Conversation not found
     pub async fn update_conversation_priority(
         &self,
         conversation_id: &str,
         new_priority: Option<crate::domain::entities::Priority>,
         updated_by: &str,
         // event_bus: Option<&EventBus>, // Removed
-    ) -> ApiResult<Conversation> {
+    ) -> PriorityResult<Conversation> {
         // Get current conversation to check existing priority
         let current = self
             .conversation_repo
             .get_conversation_by_id(conversation_id)
             .await?
             .ok_or_else(|| {
-                ApiError::NotFound(format!("Conversation {} not found", conversation_id))
+                PriorityError::NotFound(format!("Conversation {} not found", conversation_id))
             })?;
 
         let previous_priority = current.priority.clone();
@@ -90,7 +76,7 @@ impl ConversationPriorityService {
             .get_conversation_by_id(conversation_id)
             .await?
             .ok_or_else(|| {
-                ApiError::NotFound(format!(
+                PriorityError::NotFound(format!(
                     "Conversation {} not found after update",
                     conversation_id
                 ))
@@ -1,6 +1,9 @@
+use crate::application::services::PermissionService;
 use crate::domain::entities::{
-    AssignmentHistory, Conversation, ConversationListResponse, ConversationStatus,
-    CreateConversation, UpdateStatusRequest,
+    AssignmentHistory, Conversation, ConversationCountScope, ConversationCounts,
+    ConversationListFilter, ConversationListResponse, ConversationStateSnapshot,
+    ConversationStatus, ConversationTimelineResponse, CreateConversation, UpdateStatusRequest,
+    BREACHING_SOON_MINUTES,
 };
 use crate::domain::events::SystemEvent;
 use crate::domain::ports::contact_repository::ContactRepository;
@@ -81,6 +84,18 @@ impl ConversationService {
             .await?
             .ok_or_else(|| ApiError::NotFound("Contact record not found".to_string()))?;
 
+        // Outbound conversations require the contact to have confirmed a
+        // manually entered email address for this inbox
+        let channels = self.contact_repo.find_contact_channels(&contact.id).await?;
+        if channels
+            .iter()
+            .any(|channel| channel.inbox_id == request.inbox_id && !channel.email_verified)
+        {
+            return Err(ApiError::BadRequest(
+                "Contact email address has not been verified".to_string(),
+            ));
+        }
+
         // Create conversation with contact.id
         let mut conversation_request = request.clone();
         conversation_request.contact_id = contact.id;
@@ -138,6 +153,15 @@ impl ConversationService {
             .await?
             .ok_or_else(|| ApiError::NotFound("Conversation not found".to_string()))?;
 
+        // Junk has its own endpoints so sender reputation stays in sync
+        if update_request.status == ConversationStatus::Junk
+            || current.status == ConversationStatus::Junk
+        {
+            return Err(ApiError::BadRequest(
+                "Use /api/conversations/:id/junk to mark or unmark junk".to_string(),
+            ));
+        }
+
         // Validating transition using state machine logic
         let context = TransitionContext {
             conversation_id: conversation_id.to_string(),
@@ -242,15 +266,12 @@ impl ConversationService {
             .ok_or_else(|| ApiError::NotFound("Conversation not found".to_string()))?;
 
         // Record history
-        let history = AssignmentHistory {
-            id: uuid::Uuid::new_v4().to_string(),
-            conversation_id: conversation_id.to_string(),
-            assigned_user_id: user_id.clone(),
-            assigned_team_id: team_id.clone(),
-            assigned_by: assigned_by.clone(),
-            assigned_at: chrono::Utc::now().to_rfc3339(),
-            unassigned_at: None,
-        };
+        let history = AssignmentHistory::new(
+            conversation_id.to_string(),
+            user_id.clone(),
+            team_id.clone(),
+            assigned_by.clone(),
+        );
         self.conversation_repo.record_assignment(&history).await?;
 
         // Update assignments
@@ -292,9 +313,7 @@ impl ConversationService {
         _auth_user: &AuthenticatedUser,
         page: i64,
         per_page: i64,
-        status: Option<ConversationStatus>,
-        inbox_id: Option<String>,
-        contact_id: Option<String>,
+        filter: ConversationListFilter,
     ) -> ApiResult<ConversationListResponse> {
         let page = if page < 1 { 1 } else { page };
         let per_page = if per_page < 1 {
@@ -312,18 +331,9 @@ impl ConversationService {
 
         let conversations = self
             .conversation_repo
-            .list_conversations(
-                per_page,
-                offset,
-                status.clone(),
-                inbox_id.clone(),
-                contact_id.clone(),
-            )
-            .await?;
-        let total_count = self
-            .conversation_repo
-            .count_conversations(status, inbox_id, contact_id)
+            .list_conversations(per_page, offset, &filter)
             .await?;
+        let total_count = self.conversation_repo.count_conversations(&filter).await?;
 
         let total_pages = (total_count + per_page - 1) / per_page;
 
@@ -354,6 +364,104 @@ impl ConversationService {
             .await?
             .ok_or_else(|| ApiError::NotFound("Conversation not found".to_string()))
     }
+
+    /// Sidebar counters (mine, unassigned, urgent, breaching soon and open per
+    /// inbox) over the open conversations the user may read
+    pub async fn get_sidebar_counts(
+        &self,
+        auth_user: &AuthenticatedUser,
+    ) -> ApiResult<ConversationCounts> {
+        let scope = if auth_user.is_admin()
+            || PermissionService::has_permission(&auth_user.roles, "conversations:read_all")
+        {
+            ConversationCountScope::All
+        } else if PermissionService::has_permission(
+            &auth_user.roles,
+            "conversations:read_assigned",
+        ) {
+            let team_ids = self
+                .team_repo
+                .get_user_teams(&auth_user.user.id)
+                .await?
+                .into_iter()
+                .map(|team| team.id)
+                .collect();
+            ConversationCountScope::Assigned { team_ids }
+        } else {
+            return Err(ApiError::Forbidden(
+                "Missing permission: conversations:read_all or conversations:read_assigned"
+                    .to_string(),
+            ));
+        };
+
+        let now = chrono::Utc::now();
+        let breaching_before = now + chrono::Duration::minutes(BREACHING_SOON_MINUTES);
+        self.conversation_repo
+            .count_open_conversations_for_sidebar(
+                &auth_user.user.id,
+                &scope,
+                &now.to_rfc3339(),
+                &breaching_before.to_rfc3339(),
+            )
+            .await
+    }
+
+    /// Get a conversation the user may read: all conversations with
+    /// `conversations:read_all`, or ones assigned to them or their team with
+    /// `conversations:read_assigned`
+    pub async fn get_readable_conversation(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+    ) -> ApiResult<Conversation> {
+        let conversation = self.get_conversation(conversation_id).await?;
+
+        if !PermissionService::has_permission(&auth_user.roles, "conversations:read_all") {
+            let user_id = auth_user.user.id.as_str();
+            let is_assigned = conversation.assigned_user_id.as_deref() == Some(user_id)
+                || match &conversation.assigned_team_id {
+                    Some(team_id) => self.team_repo.is_team_member(team_id, user_id).await?,
+                    None => false,
+                };
+            let can_read_assigned =
+                PermissionService::has_permission(&auth_user.roles, "conversations:read_assigned");
+            if !(can_read_assigned && is_assigned) {
+                return Err(ApiError::Forbidden(
+                    "Missing permission: conversations:read_all or conversations:read_assigned"
+                        .to_string(),
+                ));
+            }
+        }
+
+        Ok(conversation)
+    }
+
+    /// Status, assignment, priority, tag and SLA changes of a conversation,
+    /// oldest first, with the state they add up to
+    ///
+    /// Requires `conversations:read_all`, or `conversations:read_assigned`
+    /// for a conversation assigned to the user or one of their teams.
+    pub async fn get_timeline(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+    ) -> ApiResult<ConversationTimelineResponse> {
+        let conversation = self
+            .get_readable_conversation(auth_user, conversation_id)
+            .await?;
+
+        let events = self
+            .conversation_repo
+            .get_conversation_events(conversation_id)
+            .await?;
+        let state = ConversationStateSnapshot::replay(&events);
+
+        Ok(ConversationTimelineResponse {
+            conversation_id: conversation.id,
+            events,
+            state,
+        })
+    }
 }
 
 /// Helper: Calculate the snoozed_until timestamp based on duration string
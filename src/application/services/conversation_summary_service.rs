@@ -0,0 +1,67 @@
+use crate::{
+    domain::errors::{DomainError, DomainResult},
+    domain::ports::conversation_repository::ConversationRepository,
+    domain::ports::conversation_summary_repository::ConversationSummaryRepository,
+    domain::ports::message_repository::MessageRepository,
+    domain::services::summarize_conversation,
+};
+use std::sync::Arc;
+
+/// How many of the latest messages are scanned for an issue statement
+const SUMMARY_MESSAGE_WINDOW: i64 = 20;
+
+/// Service keeping each conversation's rolling summary up to date
+#[derive(Clone)]
+pub struct ConversationSummaryService {
+    summary_repo: Arc<dyn ConversationSummaryRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    message_repo: Arc<dyn MessageRepository>,
+}
+
+impl ConversationSummaryService {
+    pub fn new(
+        summary_repo: Arc<dyn ConversationSummaryRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+        message_repo: Arc<dyn MessageRepository>,
+    ) -> Self {
+        Self {
+            summary_repo,
+            conversation_repo,
+            message_repo,
+        }
+    }
+
+    /// Recompute and store a conversation's summary
+    ///
+    /// The write is skipped when the summary is unchanged.
+    pub async fn refresh(&self, conversation_id: &str) -> DomainResult<String> {
+        let conversation = self
+            .conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("Conversation {} not found", conversation_id))
+            })?;
+        let (messages, _) = self
+            .message_repo
+            .list_messages(conversation_id, SUMMARY_MESSAGE_WINDOW, 0)
+            .await?;
+
+        let summary = summarize_conversation(
+            conversation.status,
+            conversation.subject.as_deref(),
+            &messages,
+        );
+        if conversation.summary.as_deref() != Some(summary.as_str()) {
+            self.summary_repo
+                .update_conversation_summary(conversation_id, &summary)
+                .await?;
+            tracing::debug!(
+                "Conversation summary refreshed: conversation_id={}",
+                conversation_id
+            );
+        }
+
+        Ok(summary)
+    }
+}
@@ -0,0 +1,396 @@
+use crate::{
+    application::services::{NotificationService, PermissionService},
+    domain::entities::{
+        AssignmentHistory, Conversation, ConversationTransfer, TransferConversationRequest,
+        TransferStatus, UserNotification,
+    },
+    domain::errors::{DomainError, TransferError, TransferResult},
+    domain::events::SystemEvent,
+    domain::ports::{
+        agent_repository::AgentRepository, assignment_repository::AssignmentRepository,
+        conversation_repository::ConversationRepository,
+        conversation_transfer_repository::ConversationTransferRepository, event_bus::EventBus,
+        team_repository::TeamRepository,
+    },
+    infrastructure::http::middleware::{ApiError, AuthenticatedUser},
+    infrastructure::providers::connection_manager::ConnectionManager,
+    shared::validation::Validate,
+};
+use std::sync::Arc;
+
+/// Service for handing conversations over between agents and teams
+///
+/// A transfer carries a mandatory handover note, which is kept in the
+/// assignment history. Transfers that require acceptance leave the
+/// conversation where it is until the receiver accepts.
+#[derive(Clone)]
+pub struct ConversationTransferService {
+    transfer_repo: Arc<dyn ConversationTransferRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    agent_repo: Arc<dyn AgentRepository>,
+    team_repo: Arc<dyn TeamRepository>,
+    assignment_repo: Arc<dyn AssignmentRepository>,
+    event_bus: Arc<dyn EventBus>,
+    connection_manager: Arc<dyn ConnectionManager>,
+}
+
+impl ConversationTransferService {
+    pub fn new(
+        transfer_repo: Arc<dyn ConversationTransferRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+        agent_repo: Arc<dyn AgentRepository>,
+        team_repo: Arc<dyn TeamRepository>,
+        assignment_repo: Arc<dyn AssignmentRepository>,
+        event_bus: Arc<dyn EventBus>,
+        connection_manager: Arc<dyn ConnectionManager>,
+    ) -> Self {
+        Self {
+            transfer_repo,
+            conversation_repo,
+            agent_repo,
+            team_repo,
+            assignment_repo,
+            event_bus,
+            connection_manager,
+        }
+    }
+
+    /// Transfer a conversation to another agent and/or team
+    ///
+    /// The current assignee can always hand their conversation over; anyone
+    /// else needs the matching assignee permission.
+    pub async fn transfer(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+        request: TransferConversationRequest,
+    ) -> TransferResult<ConversationTransfer> {
+        request.check().map_err(DomainError::Validation)?;
+        let conversation = self.get_conversation(conversation_id).await?;
+        ensure_can_transfer(auth_user, &conversation, &request)?;
+
+        if let Some(user_id) = &request.to_user_id {
+            self.agent_repo
+                .get_agent_by_user_id(user_id)
+                .await?
+                .ok_or_else(|| TransferError::NotFound(format!("Agent {} not found", user_id)))?;
+        }
+        if let Some(team_id) = &request.to_team_id {
+            self.team_repo
+                .get_team_by_id(team_id)
+                .await?
+                .ok_or_else(|| TransferError::NotFound(format!("Team {} not found", team_id)))?;
+
+            if let Some(user_id) = &request.to_user_id {
+                if !self.team_repo.is_team_member(team_id, user_id).await? {
+                    return Err(TransferError::Validation(format!(
+                        "Agent {} is not a member of team {}",
+                        user_id, team_id
+                    )));
+                }
+            }
+        }
+
+        let same_user =
+            request.to_user_id.is_none() || request.to_user_id == conversation.assigned_user_id;
+        let same_team =
+            request.to_team_id.is_none() || request.to_team_id == conversation.assigned_team_id;
+        if same_user && same_team {
+            return Err(TransferError::Validation(
+                "Conversation is already assigned to the transfer target".to_string(),
+            ));
+        }
+
+        if self
+            .transfer_repo
+            .list_transfers(conversation_id)
+            .await?
+            .iter()
+            .any(|t| t.status == TransferStatus::Pending)
+        {
+            return Err(pending_conflict());
+        }
+
+        let transfer = ConversationTransfer::new(
+            conversation_id.to_string(),
+            conversation.assigned_user_id.clone(),
+            conversation.assigned_team_id.clone(),
+            &request,
+            auth_user.user.id.clone(),
+        );
+        match self.transfer_repo.create_transfer(&transfer).await {
+            Ok(()) => {}
+            // Lost the race against another transfer request
+            Err(ApiError::Conflict(_)) => return Err(pending_conflict()),
+            Err(e) => return Err(e.into()),
+        }
+
+        if transfer.status == TransferStatus::Completed {
+            self.apply(&transfer).await?;
+        }
+
+        self.notify_receiver(&transfer).await;
+
+        Ok(transfer)
+    }
+
+    /// Accept a pending transfer, reassigning the conversation
+    pub async fn accept(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+        transfer_id: &str,
+    ) -> TransferResult<ConversationTransfer> {
+        let transfer = self
+            .respond(
+                auth_user,
+                conversation_id,
+                transfer_id,
+                TransferStatus::Completed,
+            )
+            .await?;
+        self.apply(&transfer).await?;
+
+        Ok(transfer)
+    }
+
+    /// Decline a pending transfer; the conversation stays where it is
+    pub async fn decline(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+        transfer_id: &str,
+    ) -> TransferResult<ConversationTransfer> {
+        let transfer = self
+            .respond(
+                auth_user,
+                conversation_id,
+                transfer_id,
+                TransferStatus::Declined,
+            )
+            .await?;
+
+        if transfer.requested_by != auth_user.user.id {
+            let notification = UserNotification::new_transfer(
+                transfer.requested_by.clone(),
+                transfer.conversation_id.clone(),
+                auth_user.user.id.clone(),
+            );
+            self.notify(notification).await;
+        }
+
+        Ok(transfer)
+    }
+
+    /// A conversation's transfers, newest first
+    pub async fn list_transfers(
+        &self,
+        conversation_id: &str,
+    ) -> TransferResult<Vec<ConversationTransfer>> {
+        self.get_conversation(conversation_id).await?;
+        Ok(self.transfer_repo.list_transfers(conversation_id).await?)
+    }
+
+    async fn respond(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+        transfer_id: &str,
+        status: TransferStatus,
+    ) -> TransferResult<ConversationTransfer> {
+        let mut transfer = self
+            .transfer_repo
+            .get_transfer(transfer_id)
+            .await?
+            .filter(|t| t.conversation_id == conversation_id)
+            .ok_or_else(|| {
+                TransferError::NotFound(format!("Transfer {} not found", transfer_id))
+            })?;
+
+        if transfer.status != TransferStatus::Pending {
+            return Err(TransferError::Conflict(format!(
+                "Transfer was already {}",
+                transfer.status
+            )));
+        }
+        if !self.is_receiver(auth_user, &transfer).await? {
+            return Err(TransferError::Forbidden(
+                "Only the receiver can answer a transfer".to_string(),
+            ));
+        }
+
+        let responded_at = chrono::Utc::now().to_rfc3339();
+        let answered = self
+            .transfer_repo
+            .respond_to_transfer(transfer_id, status, &auth_user.user.id, &responded_at)
+            .await?;
+        if !answered {
+            return Err(TransferError::Conflict(
+                "Transfer was already answered".to_string(),
+            ));
+        }
+
+        transfer.status = status;
+        transfer.responded_by = Some(auth_user.user.id.clone());
+        transfer.responded_at = Some(responded_at);
+        Ok(transfer)
+    }
+
+    /// The agent a transfer names, or any member of its team when it only
+    /// names a team
+    async fn is_receiver(
+        &self,
+        auth_user: &AuthenticatedUser,
+        transfer: &ConversationTransfer,
+    ) -> TransferResult<bool> {
+        match (&transfer.to_user_id, &transfer.to_team_id) {
+            (Some(user_id), _) => Ok(*user_id == auth_user.user.id),
+            (None, Some(team_id)) => Ok(self
+                .team_repo
+                .is_team_member(team_id, &auth_user.user.id)
+                .await?),
+            (None, None) => Ok(false),
+        }
+    }
+
+    /// Reassign the conversation as the transfer describes
+    async fn apply(&self, transfer: &ConversationTransfer) -> TransferResult<()> {
+        let conversation_id = &transfer.conversation_id;
+        let assigned_by = Some(transfer.requested_by.clone());
+
+        if let Some(team_id) = &transfer.to_team_id {
+            self.conversation_repo
+                .assign_conversation_to_team(
+                    conversation_id,
+                    Some(team_id.clone()),
+                    assigned_by.clone(),
+                )
+                .await?;
+        }
+        // A transfer to a team alone puts the conversation back in the team's queue
+        self.conversation_repo
+            .assign_conversation_to_user(conversation_id, transfer.to_user_id.clone(), assigned_by)
+            .await?;
+
+        if let Some(user_id) = &transfer.to_user_id {
+            let _ = self
+                .conversation_repo
+                .add_conversation_participant(conversation_id, user_id, "assignee")
+                .await;
+        }
+
+        self.assignment_repo
+            .record_assignment(&AssignmentHistory::from_transfer(transfer))
+            .await?;
+
+        let _ = self.event_bus.publish(SystemEvent::ConversationAssigned {
+            conversation_id: conversation_id.clone(),
+            assigned_user_id: transfer.to_user_id.clone(),
+            assigned_team_id: transfer.to_team_id.clone(),
+            assigned_by: transfer.requested_by.clone(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        });
+
+        tracing::info!(
+            "Conversation {} transferred by {} (transfer {})",
+            conversation_id,
+            transfer.requested_by,
+            transfer.id
+        );
+
+        Ok(())
+    }
+
+    /// Let the receiver know about the transfer: the named agent, or the
+    /// team's members when only a team was given
+    async fn notify_receiver(&self, transfer: &ConversationTransfer) {
+        let recipients = match (&transfer.to_user_id, &transfer.to_team_id) {
+            (Some(user_id), _) => vec![user_id.clone()],
+            (None, Some(team_id)) => match self.team_repo.get_team_members(team_id).await {
+                Ok(members) => members.into_iter().map(|m| m.id).collect(),
+                Err(e) => {
+                    tracing::error!("Failed to load members of team {}: {}", team_id, e);
+                    Vec::new()
+                }
+            },
+            (None, None) => Vec::new(),
+        };
+
+        for user_id in recipients {
+            if user_id == transfer.requested_by {
+                continue;
+            }
+            let notification = UserNotification::new_transfer(
+                user_id,
+                transfer.conversation_id.clone(),
+                transfer.requested_by.clone(),
+            );
+            self.notify(notification).await;
+        }
+    }
+
+    async fn notify(&self, notification: UserNotification) {
+        if let Err(e) = self
+            .assignment_repo
+            .create_notification(&notification)
+            .await
+        {
+            // Notification failure shouldn't fail the transfer
+            tracing::error!("Failed to create transfer notification: {}", e);
+            return;
+        }
+
+        let connection_manager = self.connection_manager.clone();
+        tokio::spawn(async move {
+            if let Err(e) =
+                NotificationService::send_realtime_notification(&notification, &connection_manager)
+                    .await
+            {
+                tracing::debug!("Failed to send real-time notification: {}", e);
+            }
+        });
+    }
+
+    async fn get_conversation(&self, conversation_id: &str) -> TransferResult<Conversation> {
+        self.conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| {
+                TransferError::NotFound(format!("Conversation {} not found", conversation_id))
+            })
+    }
+}
+
+fn ensure_can_transfer(
+    auth_user: &AuthenticatedUser,
+    conversation: &Conversation,
+    request: &TransferConversationRequest,
+) -> TransferResult<()> {
+    if auth_user.is_admin()
+        || conversation.assigned_user_id.as_deref() == Some(auth_user.user.id.as_str())
+    {
+        return Ok(());
+    }
+
+    let mut required = Vec::new();
+    if request.to_user_id.is_some() {
+        required.push("conversations:update_user_assignee");
+    }
+    if request.to_team_id.is_some() {
+        required.push("conversations:update_team_assignee");
+    }
+    match required
+        .into_iter()
+        .find(|p| !PermissionService::has_permission(&auth_user.roles, p))
+    {
+        Some(missing) => Err(TransferError::Forbidden(format!(
+            "Missing permission: {}",
+            missing
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn pending_conflict() -> TransferError {
+    TransferError::Conflict("A transfer is already pending for this conversation".to_string())
+}
@@ -0,0 +1,135 @@
+use crate::{
+    domain::entities::{CsatResponse, SubmitCsatRequest},
+    domain::errors::{CsatError, CsatResult},
+    domain::events::SystemEvent,
+    domain::ports::conversation_repository::ConversationRepository,
+    domain::ports::csat_repository::CsatRepository,
+    domain::ports::event_bus::EventBus,
+    shared::utils::generate_secret,
+    shared::validation::Validate,
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::sync::Arc;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Service for recording customer satisfaction responses
+///
+/// Customers answer through a survey link signed for their conversation, so
+/// submitting needs no account but can't target other conversations.
+#[derive(Clone)]
+pub struct CsatService {
+    csat_repo: Arc<dyn CsatRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    event_bus: Arc<dyn EventBus>,
+    link_secret: String,
+    link_base_url: String,
+}
+
+impl CsatService {
+    pub fn new(
+        csat_repo: Arc<dyn CsatRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            csat_repo,
+            conversation_repo,
+            event_bus,
+            link_secret: generate_secret(),
+            link_base_url: "http://localhost:3000".to_string(),
+        }
+    }
+
+    /// Key that signs survey links, and the public base URL they are built on
+    pub fn with_survey_links(mut self, secret: &str, base_url: &str) -> Self {
+        self.link_secret = secret.to_string();
+        self.link_base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// URL a customer's survey answer for the conversation is posted to
+    pub fn survey_url(&self, conversation_id: &str) -> String {
+        format!(
+            "{}/api/csat/{}?signature={}",
+            self.link_base_url,
+            conversation_id,
+            self.survey_signature(conversation_id)
+        )
+    }
+
+    /// Signature of the conversation's survey link
+    pub fn survey_signature(&self, conversation_id: &str) -> String {
+        hex::encode(self.survey_mac(conversation_id).finalize().into_bytes())
+    }
+
+    fn survey_mac(&self, conversation_id: &str) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(self.link_secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(format!("csat:{}", conversation_id).as_bytes());
+        mac
+    }
+
+    async fn ensure_conversation_exists(&self, conversation_id: &str) -> CsatResult<()> {
+        self.conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| {
+                CsatError::NotFound(format!("Conversation {} not found", conversation_id))
+            })?;
+        Ok(())
+    }
+
+    /// Record a CSAT response and publish `csat.received` for automation rules
+    ///
+    /// `signature` comes from the conversation's survey link.
+    pub async fn submit_response(
+        &self,
+        conversation_id: &str,
+        signature: &str,
+        request: SubmitCsatRequest,
+    ) -> CsatResult<CsatResponse> {
+        let signature = hex::decode(signature)
+            .map_err(|_| CsatError::Forbidden("Invalid survey link".to_string()))?;
+        self.survey_mac(conversation_id)
+            .verify_slice(&signature)
+            .map_err(|_| CsatError::Forbidden("Invalid survey link".to_string()))?;
+
+        request
+            .check()
+            .map_err(|e| CsatError::Validation(e.to_string()))?;
+        let comment = request
+            .comment
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty());
+
+        self.ensure_conversation_exists(conversation_id).await?;
+
+        let response = CsatResponse::new(conversation_id.to_string(), request.score, comment);
+        self.csat_repo.create_csat_response(&response).await?;
+
+        tracing::info!(
+            "CSAT response recorded: conversation_id={}, score={}",
+            conversation_id,
+            response.score
+        );
+
+        if let Err(e) = self.event_bus.publish(SystemEvent::CsatReceived {
+            csat_response_id: response.id.clone(),
+            conversation_id: response.conversation_id.clone(),
+            score: response.score,
+            comment: response.comment.clone(),
+            timestamp: response.created_at.clone(),
+        }) {
+            tracing::error!("Failed to publish CsatReceived event: {}", e);
+        }
+
+        Ok(response)
+    }
+
+    pub async fn list_responses(&self, conversation_id: &str) -> CsatResult<Vec<CsatResponse>> {
+        self.ensure_conversation_exists(conversation_id).await?;
+        Ok(self.csat_repo.list_csat_responses(conversation_id).await?)
+    }
+}
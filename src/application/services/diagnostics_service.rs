@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::entities::SlowQueryReport, domain::ports::query_diagnostics::QueryDiagnostics,
+};
+
+/// Most statements a slow-query report returns
+pub const MAX_SLOW_QUERY_REPORT_LIMIT: usize = 100;
+
+/// Service exposing query diagnostics to administrators
+#[derive(Clone)]
+pub struct DiagnosticsService {
+    diagnostics: Arc<dyn QueryDiagnostics>,
+}
+
+impl DiagnosticsService {
+    pub fn new(diagnostics: Arc<dyn QueryDiagnostics>) -> Self {
+        Self { diagnostics }
+    }
+
+    /// Worst slow queries by total time, capped at `MAX_SLOW_QUERY_REPORT_LIMIT`
+    pub fn slow_queries(&self, limit: usize) -> SlowQueryReport {
+        self.diagnostics
+            .slow_query_report(limit.min(MAX_SLOW_QUERY_REPORT_LIMIT))
+    }
+}
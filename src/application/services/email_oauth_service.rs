@@ -0,0 +1,434 @@
+use crate::{
+    domain::entities::{
+        EmailAuthMethod, EmailOAuthAuthorization, EmailOAuthCallbackParams, EmailOAuthProvider,
+        EmailOAuthState, EmailOAuthStatus, EmailOAuthToken, InboxEmailConfig,
+    },
+    domain::errors::{EmailOAuthError, EmailOAuthResult},
+    domain::ports::email_oauth_client::EmailOAuthClient,
+    domain::ports::email_oauth_repository::EmailOAuthRepository,
+    domain::ports::email_repository::EmailRepository,
+};
+use openidconnect::{CsrfToken, PkceCodeChallenge};
+use std::sync::Arc;
+
+/// Access tokens are refreshed when they expire within this many seconds
+pub const EMAIL_OAUTH_REFRESH_MARGIN_SECS: i64 = 300;
+
+/// Time allowed between starting an authorization and the provider's callback
+pub const EMAIL_OAUTH_STATE_TTL_SECS: i64 = 600;
+
+/// Lifetime assumed when a token response has no `expires_in`
+const DEFAULT_ACCESS_TOKEN_LIFETIME_SECS: i64 = 3600;
+
+/// OAuth2 client registered with a mail provider
+#[derive(Debug, Clone)]
+pub struct EmailOAuthClientCredentials {
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+/// OAuth2 clients and callback URL for inbox mailbox authorization
+#[derive(Debug, Clone)]
+pub struct EmailOAuthSettings {
+    pub google: Option<EmailOAuthClientCredentials>,
+    pub microsoft: Option<EmailOAuthClientCredentials>,
+    /// Microsoft Entra tenant mailboxes sign in through; "common" accepts any
+    pub microsoft_tenant: String,
+    /// Callback URL registered with the providers
+    pub redirect_url: String,
+}
+
+impl Default for EmailOAuthSettings {
+    fn default() -> Self {
+        Self {
+            google: None,
+            microsoft: None,
+            microsoft_tenant: "common".to_string(),
+            redirect_url: "http://localhost:3000/api/email-oauth/callback".to_string(),
+        }
+    }
+}
+
+impl EmailOAuthSettings {
+    pub fn from_env() -> Self {
+        fn credentials(prefix: &str) -> Option<EmailOAuthClientCredentials> {
+            let client_id = std::env::var(format!("{}_EMAIL_OAUTH_CLIENT_ID", prefix)).ok()?;
+            let client_secret =
+                std::env::var(format!("{}_EMAIL_OAUTH_CLIENT_SECRET", prefix)).ok()?;
+            Some(EmailOAuthClientCredentials {
+                client_id,
+                client_secret,
+            })
+        }
+
+        let defaults = Self::default();
+        Self {
+            google: credentials("GOOGLE"),
+            microsoft: credentials("MICROSOFT"),
+            microsoft_tenant: std::env::var("MICROSOFT_EMAIL_OAUTH_TENANT")
+                .unwrap_or(defaults.microsoft_tenant),
+            redirect_url: std::env::var("EMAIL_OAUTH_REDIRECT_URL")
+                .unwrap_or(defaults.redirect_url),
+        }
+    }
+
+    pub fn authorize_endpoint(&self, provider: EmailOAuthProvider) -> String {
+        match provider {
+            EmailOAuthProvider::Google => {
+                "https://accounts.google.com/o/oauth2/v2/auth".to_string()
+            }
+            EmailOAuthProvider::Microsoft => format!(
+                "https://login.microsoftonline.com/{}/oauth2/v2.0/authorize",
+                self.microsoft_tenant
+            ),
+        }
+    }
+
+    pub fn token_endpoint(&self, provider: EmailOAuthProvider) -> String {
+        match provider {
+            EmailOAuthProvider::Google => "https://oauth2.googleapis.com/token".to_string(),
+            EmailOAuthProvider::Microsoft => format!(
+                "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
+                self.microsoft_tenant
+            ),
+        }
+    }
+
+    fn credentials(
+        &self,
+        provider: EmailOAuthProvider,
+    ) -> EmailOAuthResult<&EmailOAuthClientCredentials> {
+        match provider {
+            EmailOAuthProvider::Google => self.google.as_ref(),
+            EmailOAuthProvider::Microsoft => self.microsoft.as_ref(),
+        }
+        .ok_or_else(|| {
+            EmailOAuthError::Validation(format!("No OAuth2 client is configured for {}", provider))
+        })
+    }
+}
+
+/// Service for OAuth2 (XOAUTH2) sign-in of inbox mailboxes
+///
+/// A mailbox is authorized once with the authorization-code flow (with PKCE);
+/// the IMAP poller and SMTP sender then ask for an access token on every
+/// connection and get one refreshed shortly before it expires.
+#[derive(Clone)]
+pub struct EmailOAuthService {
+    email_repo: Arc<dyn EmailRepository>,
+    oauth_repo: Arc<dyn EmailOAuthRepository>,
+    client: Arc<dyn EmailOAuthClient>,
+    settings: EmailOAuthSettings,
+    /// Refreshes run one at a time, so the poller and the sender don't both
+    /// spend a refresh token the provider rotates
+    refresh_lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl EmailOAuthService {
+    pub fn new(
+        email_repo: Arc<dyn EmailRepository>,
+        oauth_repo: Arc<dyn EmailOAuthRepository>,
+        client: Arc<dyn EmailOAuthClient>,
+    ) -> Self {
+        Self {
+            email_repo,
+            oauth_repo,
+            client,
+            settings: EmailOAuthSettings::default(),
+            refresh_lock: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    pub fn with_settings(mut self, settings: EmailOAuthSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Start authorizing an inbox's mailbox
+    ///
+    /// Returns the provider consent page to send the mailbox owner to; the
+    /// provider redirects back to the callback with a one-time state.
+    pub async fn start_authorization(
+        &self,
+        inbox_id: &str,
+        user_id: &str,
+    ) -> EmailOAuthResult<EmailOAuthAuthorization> {
+        let config = self.get_config(inbox_id).await?;
+        let provider = oauth_provider(&config)?;
+        let credentials = self.settings.credentials(provider)?;
+
+        let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+        let now = chrono::Utc::now();
+        let state = EmailOAuthState {
+            state: CsrfToken::new_random().secret().clone(),
+            inbox_id: inbox_id.to_string(),
+            provider,
+            pkce_verifier: pkce_verifier.secret().clone(),
+            created_by: Some(user_id.to_string()),
+            created_at: now.to_rfc3339(),
+            expires_at: (now + chrono::Duration::seconds(EMAIL_OAUTH_STATE_TTL_SECS)).to_rfc3339(),
+        };
+
+        let scope = provider.scopes().join(" ");
+        let mut params = vec![
+            ("client_id", credentials.client_id.as_str()),
+            ("redirect_uri", self.settings.redirect_url.as_str()),
+            ("response_type", "code"),
+            ("scope", scope.as_str()),
+            ("state", state.state.as_str()),
+            ("code_challenge", pkce_challenge.as_str()),
+            ("code_challenge_method", "S256"),
+            ("login_hint", config.email_address.as_str()),
+        ];
+        if provider == EmailOAuthProvider::Google {
+            // Google only issues a refresh token for offline access, and only
+            // on first consent unless asked again
+            params.push(("access_type", "offline"));
+            params.push(("prompt", "consent"));
+        }
+        let authorize_url =
+            reqwest::Url::parse_with_params(&self.settings.authorize_endpoint(provider), &params)
+                .map_err(|e| {
+                EmailOAuthError::Validation(format!("Invalid authorization URL: {}", e))
+            })?;
+
+        self.oauth_repo.create_email_oauth_state(&state).await?;
+
+        Ok(EmailOAuthAuthorization {
+            authorize_url: authorize_url.to_string(),
+            provider,
+            expires_at: state.expires_at,
+        })
+    }
+
+    /// Finish an authorization from the provider's redirect: exchange the
+    /// code for tokens and store them
+    pub async fn complete_authorization(
+        &self,
+        params: EmailOAuthCallbackParams,
+    ) -> EmailOAuthResult<EmailOAuthStatus> {
+        let state_param = params.state.ok_or(EmailOAuthError::InvalidState)?;
+        let state = self
+            .oauth_repo
+            .consume_email_oauth_state(&state_param)
+            .await?
+            .ok_or(EmailOAuthError::InvalidState)?;
+        if state.is_expired() {
+            return Err(EmailOAuthError::InvalidState);
+        }
+
+        if let Some(error) = params.error {
+            return Err(EmailOAuthError::Provider(
+                params.error_description.unwrap_or(error),
+            ));
+        }
+        let code = params
+            .code
+            .ok_or_else(|| EmailOAuthError::Validation("Missing authorization code".to_string()))?;
+
+        let config = self.get_config(&state.inbox_id).await?;
+        if config.oauth_provider != Some(state.provider) {
+            return Err(EmailOAuthError::Validation(
+                "The inbox's OAuth provider changed during authorization".to_string(),
+            ));
+        }
+        let credentials = self.settings.credentials(state.provider)?;
+
+        let grant = self
+            .client
+            .request_token(
+                &self.settings.token_endpoint(state.provider),
+                &[
+                    ("grant_type", "authorization_code"),
+                    ("code", code.as_str()),
+                    ("redirect_uri", self.settings.redirect_url.as_str()),
+                    ("client_id", credentials.client_id.as_str()),
+                    ("client_secret", credentials.client_secret.as_str()),
+                    ("code_verifier", state.pkce_verifier.as_str()),
+                ],
+            )
+            .await
+            .map_err(EmailOAuthError::Provider)?;
+        let refresh_token = grant.refresh_token.ok_or_else(|| {
+            EmailOAuthError::Provider(
+                "No refresh token was issued; offline access must be granted".to_string(),
+            )
+        })?;
+
+        let now = chrono::Utc::now();
+        let token = EmailOAuthToken {
+            inbox_id: state.inbox_id,
+            provider: state.provider,
+            access_token: grant.access_token,
+            refresh_token,
+            expires_at: expires_at(now, grant.expires_in),
+            scope: grant.scope,
+            authorized_by: state.created_by,
+            created_at: now.to_rfc3339(),
+            updated_at: now.to_rfc3339(),
+        };
+        self.oauth_repo.save_email_oauth_token(&token).await?;
+
+        tracing::info!(
+            "Mailbox of inbox {} authorized with {}",
+            token.inbox_id,
+            token.provider
+        );
+        let inbox_id = token.inbox_id.clone();
+        Ok(status(&inbox_id, Some(token.provider), Some(token)))
+    }
+
+    /// OAuth2 authorization status of an inbox's mailbox
+    pub async fn get_status(&self, inbox_id: &str) -> EmailOAuthResult<EmailOAuthStatus> {
+        let config = self.get_config(inbox_id).await?;
+        let token = self.current_token(&config).await?;
+        Ok(status(inbox_id, config.oauth_provider, token))
+    }
+
+    /// Forget an inbox's tokens; the mailbox must be authorized again
+    pub async fn disconnect(&self, inbox_id: &str) -> EmailOAuthResult<()> {
+        self.oauth_repo.delete_email_oauth_token(inbox_id).await?;
+        Ok(())
+    }
+
+    /// Access token to sign in to the inbox's IMAP and SMTP servers with,
+    /// refreshed first if it's about to expire
+    pub async fn access_token(&self, config: &InboxEmailConfig) -> EmailOAuthResult<String> {
+        let margin = chrono::Duration::seconds(EMAIL_OAUTH_REFRESH_MARGIN_SECS);
+
+        let token = self.authorized_token(config).await?;
+        if !token.expires_within(margin) {
+            return Ok(token.access_token);
+        }
+
+        let _guard = self.refresh_lock.lock().await;
+        // Another connection may have refreshed it while we waited
+        let token = self.authorized_token(config).await?;
+        if !token.expires_within(margin) {
+            return Ok(token.access_token);
+        }
+
+        Ok(self.refresh(token).await?.access_token)
+    }
+
+    async fn refresh(&self, token: EmailOAuthToken) -> EmailOAuthResult<EmailOAuthToken> {
+        let credentials = self.settings.credentials(token.provider)?;
+        let scope = token.provider.scopes().join(" ");
+        let mut params = vec![
+            ("grant_type", "refresh_token"),
+            ("refresh_token", token.refresh_token.as_str()),
+            ("client_id", credentials.client_id.as_str()),
+            ("client_secret", credentials.client_secret.as_str()),
+        ];
+        if token.provider == EmailOAuthProvider::Microsoft {
+            params.push(("scope", scope.as_str()));
+        }
+
+        let grant = self
+            .client
+            .request_token(&self.settings.token_endpoint(token.provider), &params)
+            .await
+            .map_err(|e| {
+                if e.starts_with("invalid_grant") {
+                    EmailOAuthError::NotAuthorized(format!(
+                        "Authorization of the mailbox for inbox {} was revoked or has expired; \
+                         authorize it again ({})",
+                        token.inbox_id, e
+                    ))
+                } else {
+                    EmailOAuthError::Provider(e)
+                }
+            })?;
+
+        let now = chrono::Utc::now();
+        let refreshed = EmailOAuthToken {
+            access_token: grant.access_token,
+            // Providers that don't rotate refresh tokens leave it out
+            refresh_token: grant.refresh_token.unwrap_or(token.refresh_token),
+            expires_at: expires_at(now, grant.expires_in),
+            scope: grant.scope.or(token.scope),
+            updated_at: now.to_rfc3339(),
+            ..token
+        };
+        self.oauth_repo.save_email_oauth_token(&refreshed).await?;
+
+        tracing::debug!(
+            "Refreshed OAuth2 access token of inbox {}",
+            refreshed.inbox_id
+        );
+        Ok(refreshed)
+    }
+
+    async fn get_config(&self, inbox_id: &str) -> EmailOAuthResult<InboxEmailConfig> {
+        self.email_repo
+            .get_inbox_email_config(inbox_id)
+            .await?
+            .ok_or_else(|| {
+                EmailOAuthError::NotFound(format!(
+                    "Email configuration not found for inbox {}",
+                    inbox_id
+                ))
+            })
+    }
+
+    /// Stored tokens, if they were issued by the inbox's current provider
+    async fn current_token(
+        &self,
+        config: &InboxEmailConfig,
+    ) -> EmailOAuthResult<Option<EmailOAuthToken>> {
+        let token = self
+            .oauth_repo
+            .get_email_oauth_token(&config.inbox_id)
+            .await?;
+        Ok(token.filter(|token| Some(token.provider) == config.oauth_provider))
+    }
+
+    async fn authorized_token(
+        &self,
+        config: &InboxEmailConfig,
+    ) -> EmailOAuthResult<EmailOAuthToken> {
+        let provider = oauth_provider(config)?;
+        self.current_token(config).await?.ok_or_else(|| {
+            EmailOAuthError::NotAuthorized(format!(
+                "The mailbox of inbox {} has not been authorized with {}",
+                config.inbox_id, provider
+            ))
+        })
+    }
+}
+
+/// Provider of an inbox that signs in with OAuth2
+fn oauth_provider(config: &InboxEmailConfig) -> EmailOAuthResult<EmailOAuthProvider> {
+    match (config.auth_method, config.oauth_provider) {
+        (EmailAuthMethod::OAuth2, Some(provider)) => Ok(provider),
+        (EmailAuthMethod::OAuth2, None) => Err(EmailOAuthError::Validation(format!(
+            "Inbox {} has no OAuth provider",
+            config.inbox_id
+        ))),
+        (EmailAuthMethod::Password, _) => Err(EmailOAuthError::Validation(format!(
+            "Inbox {} does not use OAuth2 authentication",
+            config.inbox_id
+        ))),
+    }
+}
+
+fn expires_at(now: chrono::DateTime<chrono::Utc>, expires_in: Option<i64>) -> String {
+    let lifetime = expires_in.unwrap_or(DEFAULT_ACCESS_TOKEN_LIFETIME_SECS);
+    (now + chrono::Duration::seconds(lifetime)).to_rfc3339()
+}
+
+fn status(
+    inbox_id: &str,
+    provider: Option<EmailOAuthProvider>,
+    token: Option<EmailOAuthToken>,
+) -> EmailOAuthStatus {
+    EmailOAuthStatus {
+        inbox_id: inbox_id.to_string(),
+        provider,
+        authorized: token.is_some(),
+        expires_at: token.as_ref().map(|token| token.expires_at.clone()),
+        scope: token.as_ref().and_then(|token| token.scope.clone()),
+        authorized_by: token.as_ref().and_then(|token| token.authorized_by.clone()),
+        updated_at: token.map(|token| token.updated_at),
+    }
+}
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use crate::{
+    application::services::PermissionService,
+    domain::entities::{Conversation, EmailParticipant, UpdateEmailParticipantRequest},
+    domain::errors::{EmailParticipantError, EmailParticipantResult},
+    domain::ports::{
+        conversation_repository::ConversationRepository, email_repository::EmailRepository,
+        team_repository::TeamRepository,
+    },
+    infrastructure::http::middleware::AuthenticatedUser,
+};
+
+/// Service for the email addresses taking part in a conversation
+///
+/// Participants are recorded from the From and Cc headers of inbound mail.
+/// A suppressed participant stays on the thread's history but is left out
+/// of outbound replies.
+#[derive(Clone)]
+pub struct EmailParticipantService {
+    email_repo: Arc<dyn EmailRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    team_repo: Arc<dyn TeamRepository>,
+}
+
+impl EmailParticipantService {
+    pub fn new(
+        email_repo: Arc<dyn EmailRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+        team_repo: Arc<dyn TeamRepository>,
+    ) -> Self {
+        Self {
+            email_repo,
+            conversation_repo,
+            team_repo,
+        }
+    }
+
+    pub async fn list_participants(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+    ) -> EmailParticipantResult<Vec<EmailParticipant>> {
+        let conversation = self.get_conversation(conversation_id).await?;
+        self.require_access(auth_user, &conversation, "read")
+            .await?;
+        Ok(self
+            .email_repo
+            .list_email_participants(conversation_id)
+            .await?)
+    }
+
+    /// Suppress or restore outbound email to one participant of the thread
+    pub async fn update_participant(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+        email: &str,
+        request: UpdateEmailParticipantRequest,
+    ) -> EmailParticipantResult<EmailParticipant> {
+        let conversation = self.get_conversation(conversation_id).await?;
+        self.require_access(auth_user, &conversation, "update")
+            .await?;
+
+        let participant = self
+            .email_repo
+            .set_email_participant_suppressed(
+                conversation_id,
+                email,
+                request.suppressed,
+                Some(&auth_user.user.id),
+            )
+            .await?
+            .ok_or_else(|| {
+                EmailParticipantError::NotFound(format!(
+                    "{} is not a participant of this conversation",
+                    email
+                ))
+            })?;
+
+        tracing::info!(
+            "Email participant {} on conversation {} {} by {}",
+            participant.email,
+            conversation_id,
+            if participant.suppressed {
+                "suppressed"
+            } else {
+                "restored"
+            },
+            auth_user.user.id
+        );
+        Ok(participant)
+    }
+
+    async fn get_conversation(
+        &self,
+        conversation_id: &str,
+    ) -> EmailParticipantResult<Conversation> {
+        self.conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| EmailParticipantError::NotFound("Conversation not found".to_string()))
+    }
+
+    /// `action` is "read" or "update"; the `_assigned` permission only
+    /// covers conversations assigned to the user or one of their teams
+    async fn require_access(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation: &Conversation,
+        action: &str,
+    ) -> EmailParticipantResult<()> {
+        let all = format!("conversations:{}_all", action);
+        if PermissionService::has_permission(&auth_user.roles, &all) {
+            return Ok(());
+        }
+
+        let assigned = format!("conversations:{}_assigned", action);
+        if PermissionService::has_permission(&auth_user.roles, &assigned) {
+            if conversation.assigned_user_id.as_deref() == Some(auth_user.user.id.as_str()) {
+                return Ok(());
+            }
+            if let Some(team_id) = &conversation.assigned_team_id {
+                if self
+                    .team_repo
+                    .is_team_member(team_id, &auth_user.user.id)
+                    .await?
+                {
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(EmailParticipantError::Forbidden(format!(
+            "Missing permission: {}",
+            all
+        )))
+    }
+}
@@ -1,6 +1,8 @@
 use crate::infrastructure::http::middleware::error::ApiResult;
 use crate::domain::ports::email_repository::EmailRepository;
-use crate::domain::entities::{EmailProcessingLog, InboxEmailConfig, UpdateInboxEmailConfigRequest};
+use crate::domain::entities::{
+    EmailProcessingLog, InboxEmailBacklog, InboxEmailConfig, UpdateInboxEmailConfigRequest,
+};
 use std::sync::Arc;
 
 /// Service for managing email configurations and logs
@@ -64,4 +66,8 @@ impl EmailService {
             .check_email_processed(inbox_id, email_message_id)
             .await
     }
+
+    pub async fn get_email_backlog(&self, inbox_id: &str) -> ApiResult<Option<InboxEmailBacklog>> {
+        self.repo.get_email_backlog(inbox_id).await
+    }
 }
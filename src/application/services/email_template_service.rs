@@ -0,0 +1,154 @@
+use crate::{
+    application::services::BrandingService,
+    domain::entities::{
+        render_template, unresolved_placeholders, Branding, EmailTemplateDefinition,
+        EmailTemplatePreview, EmailTemplateSummary, PreviewEmailTemplateRequest,
+        TestSendEmailTemplateRequest, TestSendEmailTemplateResponse, EMAIL_TEMPLATES,
+    },
+    domain::errors::{DomainError, EmailTemplateError, EmailTemplateResult},
+    domain::ports::{
+        email_sender::{InboxEmailSender, OutgoingEmail},
+        template_repository::TemplateRepository,
+    },
+    infrastructure::http::middleware::AuthenticatedUser,
+    shared::validation::Validate,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Service for previewing HTML email templates and sending test copies
+///
+/// Lets admins check how a template renders in real mail clients before
+/// customers see it. Only templates in the catalog can be rendered.
+#[derive(Clone)]
+pub struct EmailTemplateService {
+    template_repo: Arc<dyn TemplateRepository>,
+    inbox_sender: Arc<dyn InboxEmailSender>,
+    branding_service: Option<BrandingService>,
+}
+
+impl EmailTemplateService {
+    pub fn new(
+        template_repo: Arc<dyn TemplateRepository>,
+        inbox_sender: Arc<dyn InboxEmailSender>,
+    ) -> Self {
+        Self {
+            template_repo,
+            inbox_sender,
+            branding_service: None,
+        }
+    }
+
+    /// Render branding placeholders from workspace settings instead of defaults
+    pub fn with_branding_service(mut self, branding_service: BrandingService) -> Self {
+        self.branding_service = Some(branding_service);
+        self
+    }
+
+    pub fn list_templates(
+        &self,
+        auth_user: &AuthenticatedUser,
+    ) -> EmailTemplateResult<Vec<EmailTemplateSummary>> {
+        ensure_admin(auth_user)?;
+        Ok(EMAIL_TEMPLATES.iter().map(Into::into).collect())
+    }
+
+    /// Render a template with the given variables, using samples for the rest
+    pub async fn preview(
+        &self,
+        auth_user: &AuthenticatedUser,
+        template_id: &str,
+        request: PreviewEmailTemplateRequest,
+    ) -> EmailTemplateResult<EmailTemplatePreview> {
+        ensure_admin(auth_user)?;
+        self.render(template_id, &request.variables).await
+    }
+
+    /// Render a template and email it from an inbox's mail server
+    pub async fn test_send(
+        &self,
+        auth_user: &AuthenticatedUser,
+        template_id: &str,
+        request: TestSendEmailTemplateRequest,
+    ) -> EmailTemplateResult<TestSendEmailTemplateResponse> {
+        ensure_admin(auth_user)?;
+        request.check().map_err(DomainError::Validation)?;
+
+        let preview = self.render(template_id, &request.variables).await?;
+        let email = OutgoingEmail {
+            to: request.to.trim().to_string(),
+            subject: format!("[Test] {}", preview.subject),
+            text_body: format!(
+                "This is a test of the {} email template. \
+                 Open it in an HTML-capable mail client to see the rendered version.",
+                preview.id
+            ),
+            html_body: Some(preview.html),
+        };
+        self.inbox_sender
+            .send_from_inbox(&request.inbox_id, &email)
+            .await
+            .map_err(EmailTemplateError::Delivery)?;
+
+        tracing::info!(
+            "Test email for template {} sent to {} from inbox {} by {}",
+            preview.id,
+            email.to,
+            request.inbox_id,
+            auth_user.user.id
+        );
+
+        Ok(TestSendEmailTemplateResponse {
+            id: preview.id,
+            inbox_id: request.inbox_id,
+            to: email.to,
+            subject: email.subject,
+        })
+    }
+
+    async fn render(
+        &self,
+        template_id: &str,
+        variables: &HashMap<String, String>,
+    ) -> EmailTemplateResult<EmailTemplatePreview> {
+        let definition = EmailTemplateDefinition::find(template_id).ok_or_else(|| {
+            EmailTemplateError::NotFound(format!("Template {} not found", template_id))
+        })?;
+        let template = self
+            .template_repo
+            .get_template(definition.id)
+            .await?
+            .ok_or_else(|| {
+                EmailTemplateError::NotFound(format!("Template file {} is missing", definition.id))
+            })?;
+
+        let branding = match &self.branding_service {
+            Some(branding_service) => branding_service.current().await,
+            None => Branding::default(),
+        };
+        let variables = definition.resolve_variables(variables);
+        let html = render_template(
+            &render_template(&template.body_html, &variables),
+            &branding.email_variables(),
+        );
+        Ok(EmailTemplatePreview {
+            id: definition.id.to_string(),
+            subject: definition
+                .subject
+                .replace("{{product_name}}", &branding.product_name),
+            unresolved: unresolved_placeholders(&html),
+            html,
+            variables,
+        })
+    }
+}
+
+fn ensure_admin(auth_user: &AuthenticatedUser) -> EmailTemplateResult<()> {
+    if auth_user.is_admin() {
+        Ok(())
+    } else {
+        Err(EmailTemplateError::Forbidden(
+            "Only admins can manage email templates".to_string(),
+        ))
+    }
+}
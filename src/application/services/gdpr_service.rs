@@ -0,0 +1,207 @@
+use crate::{
+    domain::entities::{
+        ContactDataExport, ContactErasurePlan, GdprErasureConfirmation, GdprRequest,
+        GdprRequestStatus, GdprRequestType, MessageRedaction,
+    },
+    domain::errors::{GdprError, GdprResult},
+    domain::ports::file_storage::FileStorage,
+    domain::ports::gdpr_repository::GdprRepository,
+    infrastructure::http::middleware::AuthenticatedUser,
+    shared::utils::generate_reset_token,
+};
+use std::sync::Arc;
+
+/// Service for contacts' GDPR data exports and erasures (admin only)
+///
+/// Every export and erasure leaves a `GdprRequest` audit record. Erasure
+/// anonymizes the contact rather than deleting it, so conversation history
+/// and reports stay intact, and takes two calls: the first returns a
+/// short-lived confirmation token, the second spends it.
+#[derive(Clone)]
+pub struct GdprService {
+    gdpr_repo: Arc<dyn GdprRepository>,
+    file_storage: Option<Arc<dyn FileStorage>>,
+}
+
+impl GdprService {
+    pub fn new(gdpr_repo: Arc<dyn GdprRepository>) -> Self {
+        Self {
+            gdpr_repo,
+            file_storage: None,
+        }
+    }
+
+    /// Delete the files of attachments removed by an erasure
+    pub fn set_file_storage(&mut self, file_storage: Arc<dyn FileStorage>) {
+        self.file_storage = Some(file_storage);
+    }
+
+    fn ensure_admin(auth_user: &AuthenticatedUser) -> GdprResult<()> {
+        if !auth_user.is_admin() {
+            return Err(GdprError::Forbidden(
+                "Only admins can export or erase contact data".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn load_export(&self, contact_id: &str) -> GdprResult<ContactDataExport> {
+        self.gdpr_repo
+            .get_contact_data_export(contact_id)
+            .await?
+            .ok_or_else(|| GdprError::NotFound("Contact not found".to_string()))
+    }
+
+    async fn ensure_not_erased(&self, contact_id: &str) -> GdprResult<()> {
+        let erased = self
+            .gdpr_repo
+            .list_gdpr_requests(contact_id)
+            .await?
+            .iter()
+            .any(|r| {
+                r.request_type == GdprRequestType::Erasure
+                    && r.status == GdprRequestStatus::Completed
+            });
+        if erased {
+            return Err(GdprError::AlreadyErased);
+        }
+        Ok(())
+    }
+
+    /// Everything stored about a contact, as a machine-readable document
+    pub async fn export_contact(
+        &self,
+        auth_user: &AuthenticatedUser,
+        contact_id: &str,
+    ) -> GdprResult<ContactDataExport> {
+        Self::ensure_admin(auth_user)?;
+        let export = self.load_export(contact_id).await?;
+
+        let record = GdprRequest::new_export(
+            contact_id.to_string(),
+            auth_user.user.id.clone(),
+            export.summary(),
+        );
+        self.gdpr_repo.create_gdpr_request(&record).await?;
+
+        tracing::info!(
+            "Contact {} data exported by {}",
+            contact_id,
+            auth_user.user.id
+        );
+        Ok(export)
+    }
+
+    /// Start an erasure; nothing changes until it is confirmed with the returned token
+    pub async fn request_erasure(
+        &self,
+        auth_user: &AuthenticatedUser,
+        contact_id: &str,
+        message_redaction: MessageRedaction,
+    ) -> GdprResult<GdprErasureConfirmation> {
+        Self::ensure_admin(auth_user)?;
+        let export = self.load_export(contact_id).await?;
+        self.ensure_not_erased(contact_id).await?;
+
+        let token = generate_reset_token();
+        let request = GdprRequest::new_erasure(
+            contact_id.to_string(),
+            auth_user.user.id.clone(),
+            message_redaction,
+            export.summary(),
+            &token,
+        );
+        self.gdpr_repo.create_gdpr_request(&request).await?;
+
+        tracing::info!(
+            "Erasure of contact {} requested by {} ({} redaction)",
+            contact_id,
+            auth_user.user.id,
+            message_redaction
+        );
+
+        Ok(GdprErasureConfirmation {
+            request_id: request.id,
+            confirmation_token: token,
+            expires_at: request.expires_at.unwrap_or_default(),
+            message_redaction,
+            summary: request.summary.unwrap_or_default(),
+        })
+    }
+
+    /// Carry out a requested erasure; this cannot be undone
+    pub async fn confirm_erasure(
+        &self,
+        auth_user: &AuthenticatedUser,
+        contact_id: &str,
+        token: &str,
+    ) -> GdprResult<GdprRequest> {
+        Self::ensure_admin(auth_user)?;
+        let mut request = self
+            .gdpr_repo
+            .get_gdpr_request_by_token_hash(&GdprRequest::hash_token(token))
+            .await?
+            .filter(|r| r.contact_id == contact_id && r.request_type == GdprRequestType::Erasure)
+            .ok_or_else(|| {
+                GdprError::InvalidConfirmation("Invalid confirmation token".to_string())
+            })?;
+        if request.status != GdprRequestStatus::Pending {
+            return Err(GdprError::InvalidConfirmation(
+                "Confirmation token has already been used".to_string(),
+            ));
+        }
+        if request.is_expired() {
+            return Err(GdprError::InvalidConfirmation(
+                "Confirmation token has expired; request the erasure again".to_string(),
+            ));
+        }
+        self.ensure_not_erased(contact_id).await?;
+
+        // Plan from current data, so messages that arrived since the request are covered
+        let export = self.load_export(contact_id).await?;
+        let message_redaction = request.message_redaction.unwrap_or_default();
+        let plan = ContactErasurePlan::new(&export, message_redaction);
+        let attachment_paths = self
+            .gdpr_repo
+            .erase_contact_data(&request.id, &auth_user.user.id, &plan)
+            .await?
+            .ok_or_else(|| {
+                GdprError::InvalidConfirmation(
+                    "Confirmation token has already been used".to_string(),
+                )
+            })?;
+
+        // Files are removed after the commit; a leftover file is logged, not fatal
+        if let Some(file_storage) = &self.file_storage {
+            for path in &attachment_paths {
+                if let Err(e) = file_storage.delete(path).await {
+                    tracing::error!("Failed to delete erased attachment {}: {}", path, e);
+                }
+            }
+        }
+
+        tracing::warn!(
+            "Contact {} erased by {} ({} redaction, {} messages and {} attachments changed)",
+            contact_id,
+            auth_user.user.id,
+            message_redaction,
+            plan.message_contents.len(),
+            attachment_paths.len()
+        );
+
+        request.status = GdprRequestStatus::Completed;
+        request.confirmed_by = Some(auth_user.user.id.clone());
+        request.completed_at = Some(chrono::Utc::now().to_rfc3339());
+        Ok(request)
+    }
+
+    /// A contact's export and erasure records, newest first
+    pub async fn list_requests(
+        &self,
+        auth_user: &AuthenticatedUser,
+        contact_id: &str,
+    ) -> GdprResult<Vec<GdprRequest>> {
+        Self::ensure_admin(auth_user)?;
+        Ok(self.gdpr_repo.list_gdpr_requests(contact_id).await?)
+    }
+}
@@ -0,0 +1,315 @@
+use crate::{
+    domain::entities::{
+        BusinessHours, CreateHolidayCalendarRequest, CreateHolidayRequest, Holiday,
+        HolidayCalendar, ImportHolidaysRequest, ImportHolidaysResponse, Team, TeamWorkingDay,
+        TeamWorkingDaysResponse, UpdateHolidayCalendarRequest,
+    },
+    domain::errors::{HolidayCalendarError, HolidayCalendarResult},
+    domain::ports::holiday_repository::HolidayRepository,
+    domain::ports::team_repository::TeamRepository,
+    domain::services::parse_ical_holidays,
+};
+use chrono::{Datelike, NaiveDate};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Longest date range returned by a single working-days query
+const MAX_WORKING_DAYS_RANGE: i64 = 366;
+
+/// Service for team holiday calendars and working-day lookups
+#[derive(Clone)]
+pub struct HolidayCalendarService {
+    holiday_repo: Arc<dyn HolidayRepository>,
+    team_repo: Arc<dyn TeamRepository>,
+}
+
+impl HolidayCalendarService {
+    pub fn new(
+        holiday_repo: Arc<dyn HolidayRepository>,
+        team_repo: Arc<dyn TeamRepository>,
+    ) -> Self {
+        Self {
+            holiday_repo,
+            team_repo,
+        }
+    }
+
+    async fn get_team(&self, team_id: &str) -> HolidayCalendarResult<Team> {
+        self.team_repo
+            .get_team_by_id(team_id)
+            .await?
+            .ok_or_else(|| HolidayCalendarError::NotFound(format!("Team {} not found", team_id)))
+    }
+
+    /// Create a holiday calendar
+    pub async fn create_calendar(
+        &self,
+        request: CreateHolidayCalendarRequest,
+    ) -> HolidayCalendarResult<HolidayCalendar> {
+        let name = validate_name(&request.name)?;
+        let calendar = HolidayCalendar::new(name, request.description);
+        self.holiday_repo.create_holiday_calendar(&calendar).await?;
+
+        tracing::info!("Holiday calendar created: id={}", calendar.id);
+        Ok(calendar)
+    }
+
+    pub async fn get_calendar(&self, id: &str) -> HolidayCalendarResult<HolidayCalendar> {
+        self.holiday_repo
+            .get_holiday_calendar(id)
+            .await?
+            .ok_or_else(|| {
+                HolidayCalendarError::NotFound(format!("Holiday calendar {} not found", id))
+            })
+    }
+
+    pub async fn list_calendars(&self) -> HolidayCalendarResult<Vec<HolidayCalendar>> {
+        Ok(self.holiday_repo.list_holiday_calendars().await?)
+    }
+
+    pub async fn update_calendar(
+        &self,
+        id: &str,
+        request: UpdateHolidayCalendarRequest,
+    ) -> HolidayCalendarResult<HolidayCalendar> {
+        let mut calendar = self.get_calendar(id).await?;
+
+        if let Some(name) = request.name {
+            calendar.name = validate_name(&name)?;
+        }
+        if let Some(description) = request.description {
+            calendar.description = Some(description);
+        }
+        calendar.updated_at = chrono::Utc::now().to_rfc3339();
+
+        self.holiday_repo.update_holiday_calendar(&calendar).await?;
+        Ok(calendar)
+    }
+
+    /// Delete a calendar along with its holidays; linked teams fall back to global holidays
+    pub async fn delete_calendar(&self, id: &str) -> HolidayCalendarResult<()> {
+        self.get_calendar(id).await?;
+        Ok(self.holiday_repo.delete_holiday_calendar(id).await?)
+    }
+
+    /// Add a single holiday to a calendar
+    pub async fn add_holiday(
+        &self,
+        calendar_id: &str,
+        request: CreateHolidayRequest,
+    ) -> HolidayCalendarResult<Holiday> {
+        self.get_calendar(calendar_id).await?;
+
+        let name = validate_name(&request.name)?;
+        let date = parse_date(&request.date)?.format("%Y-%m-%d").to_string();
+
+        let mut holiday = Holiday::new(name, date, request.recurring);
+        holiday.calendar_id = Some(calendar_id.to_string());
+        self.holiday_repo.create_holiday(&holiday).await?;
+
+        Ok(holiday)
+    }
+
+    pub async fn list_holidays(&self, calendar_id: &str) -> HolidayCalendarResult<Vec<Holiday>> {
+        self.get_calendar(calendar_id).await?;
+        Ok(self
+            .holiday_repo
+            .list_calendar_holidays(calendar_id)
+            .await?)
+    }
+
+    pub async fn delete_holiday(
+        &self,
+        calendar_id: &str,
+        holiday_id: &str,
+    ) -> HolidayCalendarResult<()> {
+        let holiday = self
+            .holiday_repo
+            .get_holiday(holiday_id)
+            .await?
+            .filter(|holiday| holiday.calendar_id.as_deref() == Some(calendar_id))
+            .ok_or_else(|| {
+                HolidayCalendarError::NotFound(format!("Holiday {} not found", holiday_id))
+            })?;
+
+        Ok(self.holiday_repo.delete_holiday(&holiday.id).await?)
+    }
+
+    /// Import holidays from an iCalendar document, skipping ones already present
+    pub async fn import_ical(
+        &self,
+        calendar_id: &str,
+        request: ImportHolidaysRequest,
+    ) -> HolidayCalendarResult<ImportHolidaysResponse> {
+        self.get_calendar(calendar_id).await?;
+
+        let parsed = parse_ical_holidays(&request.ics).map_err(HolidayCalendarError::Validation)?;
+
+        if request.replace {
+            self.holiday_repo
+                .delete_calendar_holidays(calendar_id)
+                .await?;
+        }
+
+        let mut existing: HashSet<(String, String)> = self
+            .holiday_repo
+            .list_calendar_holidays(calendar_id)
+            .await?
+            .into_iter()
+            .map(|holiday| (holiday.date, holiday.name))
+            .collect();
+
+        let mut imported = 0;
+        let mut skipped = parsed.invalid_events;
+        for parsed_holiday in parsed.holidays {
+            if !existing.insert((parsed_holiday.date.clone(), parsed_holiday.name.clone())) {
+                skipped += 1;
+                continue;
+            }
+
+            let mut holiday = Holiday::new(
+                parsed_holiday.name,
+                parsed_holiday.date,
+                parsed_holiday.recurring,
+            );
+            holiday.calendar_id = Some(calendar_id.to_string());
+            self.holiday_repo.create_holiday(&holiday).await?;
+            imported += 1;
+        }
+
+        tracing::info!(
+            "Imported {} holidays into calendar {} ({} skipped)",
+            imported,
+            calendar_id,
+            skipped
+        );
+
+        Ok(ImportHolidaysResponse { imported, skipped })
+    }
+
+    /// Link a team to a calendar, or unlink it with None
+    pub async fn set_team_calendar(
+        &self,
+        team_id: &str,
+        calendar_id: Option<&str>,
+    ) -> HolidayCalendarResult<()> {
+        self.get_team(team_id).await?;
+        if let Some(calendar_id) = calendar_id {
+            self.get_calendar(calendar_id).await?;
+        }
+
+        Ok(self
+            .team_repo
+            .update_team_holiday_calendar(team_id, calendar_id)
+            .await?)
+    }
+
+    /// Whether a team works on a date: the day must be in the team's business
+    /// hours schedule (every day when none is configured) and not a holiday
+    pub async fn is_team_working_day(
+        &self,
+        team_id: &str,
+        date: NaiveDate,
+    ) -> HolidayCalendarResult<bool> {
+        let team = self.get_team(team_id).await?;
+        Ok(self.working_day(&team, date).await?.is_working_day)
+    }
+
+    /// Per-day working status for a team over an inclusive date range
+    pub async fn team_working_days(
+        &self,
+        team_id: &str,
+        from: &str,
+        to: &str,
+    ) -> HolidayCalendarResult<TeamWorkingDaysResponse> {
+        let team = self.get_team(team_id).await?;
+        let from = parse_date(from)?;
+        let to = parse_date(to)?;
+
+        let days_in_range = (to - from).num_days() + 1;
+        if days_in_range < 1 {
+            return Err(HolidayCalendarError::Validation(
+                "'from' must not be after 'to'".to_string(),
+            ));
+        }
+        if days_in_range > MAX_WORKING_DAYS_RANGE {
+            return Err(HolidayCalendarError::Validation(format!(
+                "Date range cannot exceed {} days",
+                MAX_WORKING_DAYS_RANGE
+            )));
+        }
+
+        let mut days = Vec::with_capacity(days_in_range as usize);
+        for date in from.iter_days().take(days_in_range as usize) {
+            days.push(self.working_day(&team, date).await?);
+        }
+
+        Ok(TeamWorkingDaysResponse {
+            team_id: team.id,
+            holiday_calendar_id: team.holiday_calendar_id,
+            days,
+        })
+    }
+
+    async fn working_day(
+        &self,
+        team: &Team,
+        date: NaiveDate,
+    ) -> HolidayCalendarResult<TeamWorkingDay> {
+        let date_str = date.format("%Y-%m-%d").to_string();
+
+        let holiday = self
+            .holiday_repo
+            .find_holiday_on(&date_str, team.holiday_calendar_id.as_deref())
+            .await?;
+
+        let scheduled = match team
+            .business_hours
+            .as_deref()
+            .and_then(|json| BusinessHours::parse(json).ok())
+        {
+            Some(business_hours) => {
+                let day_name = weekday_name(date.weekday());
+                business_hours
+                    .schedule
+                    .iter()
+                    .any(|schedule| schedule.day == day_name)
+            }
+            None => true,
+        };
+
+        Ok(TeamWorkingDay {
+            date: date_str,
+            is_working_day: scheduled && holiday.is_none(),
+            holiday: holiday.map(|holiday| holiday.name),
+        })
+    }
+}
+
+fn validate_name(name: &str) -> HolidayCalendarResult<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(HolidayCalendarError::Validation(
+            "Name cannot be empty".to_string(),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+fn parse_date(date: &str) -> HolidayCalendarResult<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+        HolidayCalendarError::Validation(format!("Invalid date '{}', expected YYYY-MM-DD", date))
+    })
+}
+
+fn weekday_name(weekday: chrono::Weekday) -> &'static str {
+    match weekday {
+        chrono::Weekday::Mon => "Monday",
+        chrono::Weekday::Tue => "Tuesday",
+        chrono::Weekday::Wed => "Wednesday",
+        chrono::Weekday::Thu => "Thursday",
+        chrono::Weekday::Fri => "Friday",
+        chrono::Weekday::Sat => "Saturday",
+        chrono::Weekday::Sun => "Sunday",
+    }
+}
@@ -0,0 +1,554 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{
+    application::services::AttachmentService,
+    domain::entities::{
+        CreateImportRequest, Import, ImportStatus, ImportedConversation, ImportedEntity, Message,
+        MessageStatus, Tag, UserType,
+    },
+    domain::errors::{ImportError, ImportResult},
+    domain::ports::{
+        contact_repository::ContactRepository, file_downloader::FileDownloader,
+        import_repository::ImportRepository, inbox_repository::InboxRepository,
+        tag_repository::TagRepository, task_queue::TaskQueue, user_repository::UserRepository,
+    },
+    domain::services::{parse_helpdesk_export, ImportedMessage, ImportedTicket},
+    infrastructure::http::middleware::AuthenticatedUser,
+};
+
+/// Job type that runs an import on the task queue
+pub const RUN_IMPORT_JOB: &str = "run_import";
+
+/// Largest message body stored, in bytes (see `Message::validate_content`)
+const MAX_MESSAGE_BYTES: usize = 10_000;
+
+/// Per-ticket errors kept on an import; counts keep covering the rest
+const MAX_IMPORT_ERRORS: usize = 500;
+
+/// Service for importing ticket exports from other helpdesks
+///
+/// Admins upload an export; the task queue then recreates its tickets as
+/// conversations in the chosen inbox. Everything created is recorded in
+/// `import_records`, so running the same export again only fills in what
+/// is missing.
+#[derive(Clone)]
+pub struct ImportService {
+    import_repo: Arc<dyn ImportRepository>,
+    inbox_repo: Arc<dyn InboxRepository>,
+    contact_repo: Arc<dyn ContactRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    tag_repo: TagRepository,
+    task_queue: Arc<dyn TaskQueue>,
+    attachments: Option<(AttachmentService, Arc<dyn FileDownloader>)>,
+}
+
+/// Lookups cached for the length of one run
+#[derive(Default)]
+struct RunCache {
+    /// Agent user by email; `None` when no agent has that email
+    agents: HashMap<String, Option<String>>,
+    /// Tag id by name
+    tags: HashMap<String, String>,
+}
+
+impl ImportService {
+    pub fn new(
+        import_repo: Arc<dyn ImportRepository>,
+        inbox_repo: Arc<dyn InboxRepository>,
+        contact_repo: Arc<dyn ContactRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        tag_repo: TagRepository,
+        task_queue: Arc<dyn TaskQueue>,
+    ) -> Self {
+        Self {
+            import_repo,
+            inbox_repo,
+            contact_repo,
+            user_repo,
+            tag_repo,
+            task_queue,
+            attachments: None,
+        }
+    }
+
+    /// Download attachments of imported messages; without this they are left out
+    pub fn with_attachments(
+        mut self,
+        attachment_service: AttachmentService,
+        downloader: Arc<dyn FileDownloader>,
+    ) -> Self {
+        self.attachments = Some((attachment_service, downloader));
+        self
+    }
+
+    /// Store an export and queue it to be imported
+    ///
+    /// The export is read once up front so an unreadable file is rejected
+    /// right away rather than failing in the background.
+    pub async fn create_import(
+        &self,
+        auth_user: &AuthenticatedUser,
+        request: CreateImportRequest,
+    ) -> ImportResult<Import> {
+        ensure_admin(auth_user)?;
+
+        self.inbox_repo
+            .get_inbox(&request.inbox_id)
+            .await?
+            .ok_or_else(|| {
+                ImportError::NotFound(format!("Inbox {} not found", request.inbox_id))
+            })?;
+        let export = parse_helpdesk_export(request.source, request.format, &request.data)
+            .map_err(ImportError::Validation)?;
+
+        let mut import = Import::new(
+            request.source,
+            request.format,
+            request.inbox_id,
+            request.data,
+            auth_user.user.id.clone(),
+        );
+        import.total_records = (export.tickets.len() + export.invalid_records.len()) as i64;
+        self.import_repo.create_import(&import).await?;
+        self.task_queue
+            .enqueue(
+                RUN_IMPORT_JOB,
+                serde_json::json!({ "import_id": import.id }),
+                3,
+            )
+            .await?;
+
+        tracing::info!(
+            "Queued {} {} import {} of {} records",
+            import.source,
+            import.format,
+            import.id,
+            import.total_records
+        );
+
+        Ok(import)
+    }
+
+    pub async fn get_import(
+        &self,
+        auth_user: &AuthenticatedUser,
+        id: &str,
+    ) -> ImportResult<Import> {
+        ensure_admin(auth_user)?;
+        self.import_repo
+            .get_import(id)
+            .await?
+            .ok_or_else(|| ImportError::NotFound(format!("Import {} not found", id)))
+    }
+
+    pub async fn list_imports(&self, auth_user: &AuthenticatedUser) -> ImportResult<Vec<Import>> {
+        ensure_admin(auth_user)?;
+        Ok(self.import_repo.list_imports().await?)
+    }
+
+    /// Import every ticket of an export, saving progress after each one
+    ///
+    /// Safe to run again: tickets already imported are skipped, and only
+    /// their missing attachments are fetched.
+    pub async fn run_import(&self, import_id: &str) -> ImportResult<Import> {
+        let mut import = self
+            .import_repo
+            .get_import(import_id)
+            .await?
+            .ok_or_else(|| ImportError::NotFound(format!("Import {} not found", import_id)))?;
+        if matches!(
+            import.status,
+            ImportStatus::Completed | ImportStatus::Failed
+        ) {
+            return Ok(import);
+        }
+
+        let now = chrono::Utc::now().to_rfc3339();
+        import.status = ImportStatus::Running;
+        import.started_at.get_or_insert(now.clone());
+        import.updated_at = now;
+        import.counts = Default::default();
+        import.errors = Vec::new();
+        import.processed_records = 0;
+
+        let export = match parse_helpdesk_export(import.source, import.format, &import.data) {
+            Ok(export) => export,
+            Err(e) => {
+                import.errors.push(e);
+                return self.finish(import, ImportStatus::Failed).await;
+            }
+        };
+        import.total_records = (export.tickets.len() + export.invalid_records.len()) as i64;
+        import.processed_records = export.invalid_records.len() as i64;
+        import.counts.tickets_failed = export.invalid_records.len() as i64;
+        for error in export.invalid_records {
+            push_error(&mut import, error);
+        }
+        if export.private_notes_skipped > 0 {
+            tracing::info!(
+                "Import {} leaves out {} private notes",
+                import.id,
+                export.private_notes_skipped
+            );
+        }
+        self.import_repo.update_import_progress(&import).await?;
+
+        let mut cache = RunCache::default();
+        for ticket in &export.tickets {
+            if let Err(e) = self.import_ticket(&mut import, &mut cache, ticket).await {
+                import.counts.tickets_failed += 1;
+                push_error(&mut import, format!("Ticket {}: {}", ticket.external_id, e));
+            }
+            import.processed_records += 1;
+            import.updated_at = chrono::Utc::now().to_rfc3339();
+            self.import_repo.update_import_progress(&import).await?;
+        }
+
+        self.finish(import, ImportStatus::Completed).await
+    }
+
+    async fn finish(&self, mut import: Import, status: ImportStatus) -> ImportResult<Import> {
+        let now = chrono::Utc::now().to_rfc3339();
+        import.status = status;
+        import.completed_at = Some(now.clone());
+        import.updated_at = now;
+        self.import_repo.update_import_progress(&import).await?;
+        self.import_repo.clear_import_data(&import.id).await?;
+        import.data.clear();
+
+        tracing::info!(
+            "Import {} {}: {} conversations, {} messages, {} skipped, {} failed",
+            import.id,
+            import.status,
+            import.counts.conversations_created,
+            import.counts.messages_created,
+            import.counts.tickets_skipped,
+            import.counts.tickets_failed
+        );
+
+        Ok(import)
+    }
+
+    async fn import_ticket(
+        &self,
+        import: &mut Import,
+        cache: &mut RunCache,
+        ticket: &ImportedTicket,
+    ) -> Result<(), String> {
+        let existing = self
+            .import_repo
+            .find_imported_record(
+                import.source,
+                ImportedEntity::Conversation,
+                &ticket.external_id,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let message_ids = match existing {
+            Some(_) => {
+                import.counts.tickets_skipped += 1;
+                let mut message_ids = HashMap::new();
+                for message in ticket.messages.iter().filter(|m| !m.attachments.is_empty()) {
+                    if let Some(id) = self
+                        .import_repo
+                        .find_imported_record(
+                            import.source,
+                            ImportedEntity::Message,
+                            &message.external_id,
+                        )
+                        .await
+                        .map_err(|e| e.to_string())?
+                    {
+                        message_ids.insert(message.external_id.clone(), id);
+                    }
+                }
+                message_ids
+            }
+            None => self.create_conversation(import, cache, ticket).await?,
+        };
+
+        self.import_attachments(import, ticket, &message_ids).await;
+        Ok(())
+    }
+
+    /// Create the conversation for a ticket, returning local message ids by external id
+    async fn create_conversation(
+        &self,
+        import: &mut Import,
+        cache: &mut RunCache,
+        ticket: &ImportedTicket,
+    ) -> Result<HashMap<String, String>, String> {
+        let importer_id = import
+            .created_by
+            .clone()
+            .ok_or("the user who started the import no longer exists")?;
+        let (contact_id, contact_user_id) = self.resolve_contact(import, ticket).await?;
+
+        let mut tag_ids = Vec::new();
+        for name in &ticket.tags {
+            let tag_id = self.resolve_tag(import, cache, name).await?;
+            if !tag_ids.contains(&tag_id) {
+                tag_ids.push(tag_id);
+            }
+        }
+
+        let conversation_id = uuid::Uuid::new_v4().to_string();
+        let mut sorted: Vec<&ImportedMessage> = ticket.messages.iter().collect();
+        sorted.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+        let mut messages = Vec::new();
+        for imported in sorted {
+            let Some(content) = message_content(imported) else {
+                continue;
+            };
+            let mut message = if imported.from_agent {
+                let author_id = match &imported.author_email {
+                    Some(email) => self.find_agent(cache, email).await?,
+                    None => None,
+                };
+                let mut message = Message::new_outgoing(
+                    conversation_id.clone(),
+                    content,
+                    author_id.unwrap_or_else(|| importer_id.clone()),
+                );
+                message.status = MessageStatus::Sent;
+                message.sent_at = Some(imported.created_at.clone());
+                message.is_immutable = true;
+                message
+            } else {
+                Message::new_incoming(conversation_id.clone(), content, contact_user_id.clone())
+            };
+            message.created_at = imported.created_at.clone();
+            message.updated_at = imported.created_at.clone();
+            messages.push((imported.external_id.clone(), message));
+        }
+
+        let message_ids = messages
+            .iter()
+            .map(|(external_id, message)| (external_id.clone(), message.id.clone()))
+            .collect();
+        let message_count = messages.len() as i64;
+        let conversation = ImportedConversation {
+            import_id: import.id.clone(),
+            source: import.source,
+            external_id: ticket.external_id.clone(),
+            id: conversation_id,
+            inbox_id: import.inbox_id.clone(),
+            contact_id,
+            subject: ticket.subject.clone(),
+            status: ticket.status,
+            priority: ticket.priority,
+            created_at: ticket.created_at.clone(),
+            updated_at: ticket.updated_at.clone(),
+            messages,
+            tag_ids,
+            tagged_by: importer_id,
+        };
+        self.import_repo
+            .create_imported_conversation(&conversation)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        import.counts.conversations_created += 1;
+        import.counts.messages_created += message_count;
+        Ok(message_ids)
+    }
+
+    /// Contact id and user id of the ticket's requester, creating the contact if needed
+    async fn resolve_contact(
+        &self,
+        import: &mut Import,
+        ticket: &ImportedTicket,
+    ) -> Result<(String, String), String> {
+        let email = &ticket.requester.email;
+        if let Some(contact) = self
+            .contact_repo
+            .get_contact_by_email(email)
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            return Ok((contact.id, contact.user_id));
+        }
+
+        let contact_id = self
+            .contact_repo
+            .create_contact_from_message(email, ticket.requester.name.as_deref(), &import.inbox_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let contact = self
+            .contact_repo
+            .find_contact_by_id(&contact_id)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("contact {} was not created", email))?;
+        import.counts.contacts_created += 1;
+        Ok((contact.id, contact.user_id))
+    }
+
+    async fn resolve_tag(
+        &self,
+        import: &mut Import,
+        cache: &mut RunCache,
+        name: &str,
+    ) -> Result<String, String> {
+        if let Some(id) = cache.tags.get(name) {
+            return Ok(id.clone());
+        }
+
+        let id = match self
+            .tag_repo
+            .get_tag_by_name(name)
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            Some(tag) => tag.id,
+            None => {
+                let tag = Tag::new(name.to_string(), None, None);
+                self.tag_repo
+                    .create_tag(&tag)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                import.counts.tags_created += 1;
+                tag.id
+            }
+        };
+        cache.tags.insert(name.to_string(), id.clone());
+        Ok(id)
+    }
+
+    async fn find_agent(
+        &self,
+        cache: &mut RunCache,
+        email: &str,
+    ) -> Result<Option<String>, String> {
+        let email = email.to_lowercase();
+        if let Some(id) = cache.agents.get(&email) {
+            return Ok(id.clone());
+        }
+
+        let id = self
+            .user_repo
+            .get_user_by_email_and_type(&email, &UserType::Agent)
+            .await
+            .map_err(|e| e.to_string())?
+            .map(|user| user.id);
+        cache.agents.insert(email, id.clone());
+        Ok(id)
+    }
+
+    /// Fetch attachments not imported yet; failures are noted without failing the ticket
+    async fn import_attachments(
+        &self,
+        import: &mut Import,
+        ticket: &ImportedTicket,
+        message_ids: &HashMap<String, String>,
+    ) {
+        let Some((attachment_service, downloader)) = &self.attachments else {
+            return;
+        };
+
+        for message in &ticket.messages {
+            let Some(message_id) = message_ids.get(&message.external_id) else {
+                continue;
+            };
+            for attachment in &message.attachments {
+                let result: Result<(), String> = async {
+                    let existing = self
+                        .import_repo
+                        .find_imported_record(
+                            import.source,
+                            ImportedEntity::Attachment,
+                            &attachment.external_id,
+                        )
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    if existing.is_some() {
+                        return Ok(());
+                    }
+
+                    let file = downloader.download(&attachment.url).await?;
+                    let content_type = attachment
+                        .content_type
+                        .clone()
+                        .or(file.content_type)
+                        .unwrap_or_else(|| "application/octet-stream".to_string());
+                    let saved = attachment_service
+                        .save_attachment(
+                            message_id.clone(),
+                            attachment.file_name.clone(),
+                            content_type,
+                            file.content,
+                        )
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    self.import_repo
+                        .record_imported(
+                            &import.id,
+                            import.source,
+                            ImportedEntity::Attachment,
+                            &attachment.external_id,
+                            &saved.id,
+                        )
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    import.counts.attachments_created += 1;
+                    Ok(())
+                }
+                .await;
+
+                if let Err(e) = result {
+                    push_error(
+                        import,
+                        format!(
+                            "Ticket {}: attachment {}: {}",
+                            ticket.external_id, attachment.file_name, e
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Body to store for an imported message; `None` when there is nothing to keep
+fn message_content(message: &ImportedMessage) -> Option<String> {
+    let body = message.body.trim();
+    let content = if !body.is_empty() {
+        body.to_string()
+    } else if !message.attachments.is_empty() {
+        let names: Vec<&str> = message
+            .attachments
+            .iter()
+            .map(|a| a.file_name.as_str())
+            .collect();
+        format!("Attachments: {}", names.join(", "))
+    } else {
+        return None;
+    };
+
+    if content.len() <= MAX_MESSAGE_BYTES {
+        return Some(content);
+    }
+    let mut end = MAX_MESSAGE_BYTES;
+    while !content.is_char_boundary(end) {
+        end -= 1;
+    }
+    Some(content[..end].to_string())
+}
+
+fn push_error(import: &mut Import, error: String) {
+    if import.errors.len() < MAX_IMPORT_ERRORS {
+        import.errors.push(error);
+    }
+}
+
+fn ensure_admin(auth_user: &AuthenticatedUser) -> ImportResult<()> {
+    if auth_user.is_admin() {
+        return Ok(());
+    }
+    Err(ImportError::Forbidden(
+        "Administrator role required".to_string(),
+    ))
+}
@@ -1,4 +1,4 @@
-use crate::infrastructure::http::middleware::ApiResult;
+use crate::domain::errors::InboxResult;
 use crate::domain::ports::inbox_repository::InboxRepository;
 use crate::domain::entities::Inbox;
 use std::sync::Arc;
@@ -15,12 +15,12 @@ impl InboxService {
     }
 
     /// List all available inboxes
-    pub async fn list_inboxes(&self) -> ApiResult<Vec<Inbox>> {
-        self.repo.list_inboxes().await
+    pub async fn list_inboxes(&self) -> InboxResult<Vec<Inbox>> {
+        Ok(self.repo.list_inboxes().await?)
     }
 
     /// Get a default inbox ID (usually the first one available). Creates a default one if none exist.
-    pub async fn get_default_inbox_id(&self) -> ApiResult<String> {
+    pub async fn get_default_inbox_id(&self) -> InboxResult<String> {
         let inboxes = self.repo.list_inboxes().await?;
 
         if let Some(inbox) = inboxes.first() {
@@ -0,0 +1,66 @@
+use crate::{
+    domain::entities::{DeadJobsQuery, Job, JobStatus},
+    domain::errors::{JobError, JobResult},
+    domain::ports::task_queue::TaskQueue,
+};
+use std::sync::Arc;
+
+const MAX_DEAD_JOBS_PAGE: i64 = 200;
+
+/// Service for inspecting and requeueing dead background jobs
+///
+/// Jobs that fail on every attempt are parked as dead rather than retried
+/// forever; admins look at the recorded failures and requeue them once the
+/// cause is fixed.
+#[derive(Clone)]
+pub struct JobService {
+    queue: Arc<dyn TaskQueue>,
+}
+
+impl JobService {
+    pub fn new(queue: Arc<dyn TaskQueue>) -> Self {
+        Self { queue }
+    }
+
+    /// Dead jobs, most recently failed first
+    pub async fn list_dead_jobs(&self, query: DeadJobsQuery) -> JobResult<Vec<Job>> {
+        let limit = query.limit.clamp(1, MAX_DEAD_JOBS_PAGE);
+        let offset = query.offset.max(0);
+        Ok(self.queue.list_dead_jobs(limit, offset).await?)
+    }
+
+    pub async fn get_job(&self, job_id: &str) -> JobResult<Job> {
+        self.queue
+            .get_job(job_id)
+            .await?
+            .ok_or_else(|| JobError::NotFound(format!("Job {} not found", job_id)))
+    }
+
+    /// Give a dead job a fresh set of attempts, starting now
+    pub async fn requeue_job(&self, job_id: &str, requeued_by: &str) -> JobResult<Job> {
+        let job = self.get_job(job_id).await?;
+        if job.status != JobStatus::Dead {
+            return Err(JobError::NotDead(format!(
+                "Job {} is {}, only dead jobs can be requeued",
+                job_id,
+                job.status.to_string()
+            )));
+        }
+
+        if !self.queue.requeue_job(job_id).await? {
+            // Requeued by someone else in the meantime
+            return Err(JobError::NotDead(format!(
+                "Job {} is no longer dead",
+                job_id
+            )));
+        }
+        tracing::info!(
+            "Dead job {} (type: {}) requeued by {}",
+            job_id,
+            job.job_type,
+            requeued_by
+        );
+
+        self.get_job(job_id).await
+    }
+}
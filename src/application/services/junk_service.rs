@@ -0,0 +1,284 @@
+use std::sync::Arc;
+
+use crate::{
+    application::services::PermissionService,
+    domain::entities::{
+        normalize_sender_email, BlockedSender, Conversation, ConversationStatus, JunkResponse,
+        MarkJunkRequest, SystemConfigSetting, AUTO_JUNK_THRESHOLD_KEY,
+    },
+    domain::errors::{JunkError, JunkResult},
+    domain::ports::{
+        conversation_repository::ConversationRepository, event_bus::EventBus,
+        junk_repository::JunkRepository, system_config_repository::SystemConfigRepository,
+    },
+    domain::services::state_machine::{execute_transition, TransitionContext},
+    infrastructure::http::middleware::AuthenticatedUser,
+};
+
+/// Service for junk conversations and the sender reputation they feed
+///
+/// Every junk marking counts against the conversation's sender. Once a
+/// sender reaches the configured threshold, new conversations from them
+/// start out as junk. Confirmed markings also block the sender on the inbox.
+#[derive(Clone)]
+pub struct JunkService {
+    repo: Arc<dyn JunkRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    config_repo: Arc<dyn SystemConfigRepository>,
+    event_bus: Option<Arc<dyn EventBus>>,
+}
+
+impl JunkService {
+    pub fn new(
+        repo: Arc<dyn JunkRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+        config_repo: Arc<dyn SystemConfigRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            conversation_repo,
+            config_repo,
+            event_bus: None,
+        }
+    }
+
+    /// Publish status change events for junk markings
+    pub fn set_event_bus(&mut self, event_bus: Arc<dyn EventBus>) {
+        self.event_bus = Some(event_bus);
+    }
+
+    /// Mark a conversation as junk, counting it against the sender
+    pub async fn mark_junk(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+        request: MarkJunkRequest,
+    ) -> JunkResult<JunkResponse> {
+        require_update_all(auth_user)?;
+        let current = self.get_conversation(conversation_id).await?;
+        if current.status == ConversationStatus::Junk {
+            return Err(JunkError::Validation(
+                "Conversation is already marked as junk".to_string(),
+            ));
+        }
+
+        let conversation = self
+            .transition(&current, ConversationStatus::Junk, Some(&auth_user.user.id))
+            .await?;
+
+        let sender_email = self.sender_email(conversation_id).await?;
+        let mut junk_count = 0;
+        let mut blocked = false;
+        if let Some(email) = &sender_email {
+            junk_count = self.repo.increment_sender_junk_count(email).await?;
+            if request.confirm {
+                let sender = BlockedSender::new(
+                    conversation.inbox_id.clone(),
+                    email,
+                    Some(auth_user.user.id.clone()),
+                    Some(conversation.id.clone()),
+                );
+                self.repo.block_sender(&sender).await?;
+            }
+            blocked = self
+                .repo
+                .is_sender_blocked(&conversation.inbox_id, email)
+                .await?;
+        }
+
+        tracing::info!(
+            "Conversation {} marked as junk by {} (sender junk count {})",
+            conversation_id,
+            auth_user.user.id,
+            junk_count
+        );
+
+        Ok(JunkResponse {
+            conversation,
+            sender_email,
+            junk_count,
+            blocked,
+        })
+    }
+
+    /// Move a junk conversation back to open and take back its marking
+    ///
+    /// The sender stays on the inbox blocklist until an admin removes them.
+    pub async fn unmark_junk(
+        &self,
+        auth_user: &AuthenticatedUser,
+        conversation_id: &str,
+    ) -> JunkResult<JunkResponse> {
+        require_update_all(auth_user)?;
+        let current = self.get_conversation(conversation_id).await?;
+        if current.status != ConversationStatus::Junk {
+            return Err(JunkError::Validation(
+                "Conversation is not marked as junk".to_string(),
+            ));
+        }
+
+        let conversation = self
+            .transition(&current, ConversationStatus::Open, Some(&auth_user.user.id))
+            .await?;
+
+        let sender_email = self.sender_email(conversation_id).await?;
+        let mut junk_count = 0;
+        let mut blocked = false;
+        if let Some(email) = &sender_email {
+            junk_count = self.repo.decrement_sender_junk_count(email).await?;
+            blocked = self
+                .repo
+                .is_sender_blocked(&conversation.inbox_id, email)
+                .await?;
+        }
+
+        Ok(JunkResponse {
+            conversation,
+            sender_email,
+            junk_count,
+            blocked,
+        })
+    }
+
+    /// Whether emails from `email` are dropped by the inbox
+    pub async fn is_sender_blocked(&self, inbox_id: &str, email: &str) -> JunkResult<bool> {
+        Ok(self
+            .repo
+            .is_sender_blocked(inbox_id, &normalize_sender_email(email))
+            .await?)
+    }
+
+    /// Junk a newly created conversation when its sender has reached the
+    /// auto-junk threshold; returns whether it was junked
+    ///
+    /// Automatic junking doesn't count as another marking.
+    pub async fn apply_sender_reputation(
+        &self,
+        conversation: &Conversation,
+        email: &str,
+    ) -> JunkResult<bool> {
+        let junk_count = self
+            .repo
+            .get_sender_junk_count(&normalize_sender_email(email))
+            .await?;
+        if junk_count == 0 || junk_count < self.auto_junk_threshold().await? {
+            return Ok(false);
+        }
+
+        self.transition(conversation, ConversationStatus::Junk, None)
+            .await?;
+        tracing::info!(
+            "Conversation {} junked automatically: sender has {} junk markings",
+            conversation.id,
+            junk_count
+        );
+        Ok(true)
+    }
+
+    /// Junk a new conversation whose email failed sender authentication
+    /// under an inbox's quarantine policy
+    ///
+    /// Doesn't count against the sender: the email may not be theirs.
+    pub async fn quarantine(&self, conversation: &Conversation) -> JunkResult<()> {
+        self.transition(conversation, ConversationStatus::Junk, None)
+            .await?;
+        tracing::info!(
+            "Conversation {} quarantined: email failed sender authentication",
+            conversation.id
+        );
+        Ok(())
+    }
+
+    pub async fn list_blocked_senders(
+        &self,
+        auth_user: &AuthenticatedUser,
+        inbox_id: &str,
+    ) -> JunkResult<Vec<BlockedSender>> {
+        require_admin(auth_user)?;
+        Ok(self.repo.list_blocked_senders(inbox_id).await?)
+    }
+
+    pub async fn unblock_sender(
+        &self,
+        auth_user: &AuthenticatedUser,
+        inbox_id: &str,
+        id: &str,
+    ) -> JunkResult<()> {
+        require_admin(auth_user)?;
+        if !self.repo.delete_blocked_sender(inbox_id, id).await? {
+            return Err(JunkError::NotFound("Blocked sender not found".to_string()));
+        }
+        Ok(())
+    }
+
+    async fn get_conversation(&self, conversation_id: &str) -> JunkResult<Conversation> {
+        self.conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| JunkError::NotFound("Conversation not found".to_string()))
+    }
+
+    async fn sender_email(&self, conversation_id: &str) -> JunkResult<Option<String>> {
+        Ok(self
+            .repo
+            .get_conversation_sender_email(conversation_id)
+            .await?
+            .map(|email| normalize_sender_email(&email)))
+    }
+
+    async fn auto_junk_threshold(&self) -> JunkResult<i64> {
+        let stored = self
+            .config_repo
+            .get_config_value(AUTO_JUNK_THRESHOLD_KEY)
+            .await?;
+        Ok(SystemConfigSetting::find(AUTO_JUNK_THRESHOLD_KEY)
+            .map(|setting| setting.parse_stored(stored.as_deref()))
+            .unwrap_or(i64::MAX))
+    }
+
+    /// Move a conversation into or out of junk, keeping its resolved and
+    /// closed timestamps when junking so unmarking restores a clean open state
+    async fn transition(
+        &self,
+        current: &Conversation,
+        to_status: ConversationStatus,
+        agent_id: Option<&str>,
+    ) -> JunkResult<Conversation> {
+        let context = TransitionContext {
+            conversation_id: current.id.clone(),
+            from_status: current.status,
+            to_status,
+            agent_id: agent_id.map(str::to_string),
+            snooze_duration: None,
+        };
+        execute_transition(context, self.event_bus.as_deref())
+            .map_err(|e| JunkError::Validation(format!("Invalid transition: {}", e)))?;
+
+        let (resolved_at, closed_at) = match to_status {
+            ConversationStatus::Junk => (current.resolved_at.clone(), current.closed_at.clone()),
+            _ => (None, None),
+        };
+        Ok(self
+            .conversation_repo
+            .update_conversation_fields(&current.id, to_status, resolved_at, closed_at, None)
+            .await?)
+    }
+}
+
+fn require_update_all(auth_user: &AuthenticatedUser) -> JunkResult<()> {
+    if !PermissionService::has_permission(&auth_user.roles, "conversations:update_all") {
+        return Err(JunkError::Forbidden(
+            "Missing permission: conversations:update_all".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn require_admin(auth_user: &AuthenticatedUser) -> JunkResult<()> {
+    if !auth_user.is_admin() {
+        return Err(JunkError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::entities::{LocaleSettings, UpdateLocaleRequest},
+    domain::errors::{DomainError, DomainResult},
+    domain::ports::user_locale_repository::UserLocaleRepository,
+    shared::{i18n, validation::Validate},
+};
+
+/// Service for users' locale preferences
+#[derive(Clone)]
+pub struct LocaleService {
+    user_locale_repo: Arc<dyn UserLocaleRepository>,
+}
+
+impl LocaleService {
+    pub fn new(user_locale_repo: Arc<dyn UserLocaleRepository>) -> Self {
+        Self { user_locale_repo }
+    }
+
+    /// The user's chosen locale if it is still available
+    ///
+    /// Used on every authenticated request, so failures are logged and
+    /// treated as "no preference" rather than failing the request.
+    pub async fn preferred_locale(&self, user_id: &str) -> Option<String> {
+        match self.user_locale_repo.get_user_locale(user_id).await {
+            Ok(locale) => locale.and_then(|locale| i18n::catalog().resolve(&locale)),
+            Err(e) => {
+                tracing::warn!("Failed to load locale of user {}: {}", user_id, e);
+                None
+            }
+        }
+    }
+
+    pub async fn get_settings(&self, user_id: &str) -> DomainResult<LocaleSettings> {
+        let locale = self.user_locale_repo.get_user_locale(user_id).await?;
+        Ok(LocaleSettings {
+            locale,
+            effective_locale: i18n::current_locale(),
+            available_locales: i18n::catalog().locales(),
+        })
+    }
+
+    pub async fn update_settings(
+        &self,
+        user_id: &str,
+        request: UpdateLocaleRequest,
+    ) -> DomainResult<LocaleSettings> {
+        request.check().map_err(DomainError::Validation)?;
+        let locale = request
+            .locale
+            .as_deref()
+            .and_then(|locale| i18n::catalog().resolve(locale));
+        self.user_locale_repo
+            .set_user_locale(user_id, locale.as_deref())
+            .await?;
+
+        let effective_locale = locale.clone().unwrap_or_else(i18n::current_locale);
+        Ok(LocaleSettings {
+            locale,
+            effective_locale,
+            available_locales: i18n::catalog().locales(),
+        })
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    domain::entities::{
+        MaintenanceState, MaintenanceStatusResponse, MaintenanceWorkerStatus, ReadinessResponse,
+        SystemConfigChange, UpdateMaintenanceModeRequest, MAINTENANCE_MODE_KEY,
+    },
+    domain::errors::{MaintenanceError, MaintenanceResult},
+    domain::ports::system_config_repository::SystemConfigRepository,
+    shared::maintenance::MaintenanceMode,
+};
+use std::sync::Arc;
+
+/// Service for the admin maintenance switch
+///
+/// The switch is stored in `system_config` so it survives restarts, and each
+/// toggle is recorded in the config change history. The shared
+/// `MaintenanceMode` handle is what requests and workers actually check.
+#[derive(Clone)]
+pub struct MaintenanceService {
+    repo: Arc<dyn SystemConfigRepository>,
+    mode: MaintenanceMode,
+}
+
+impl MaintenanceService {
+    pub fn new(repo: Arc<dyn SystemConfigRepository>, mode: MaintenanceMode) -> Self {
+        Self { repo, mode }
+    }
+
+    /// Handle shared with the HTTP middleware and workers
+    pub fn mode(&self) -> &MaintenanceMode {
+        &self.mode
+    }
+
+    /// Load the stored switch into memory (called at startup)
+    pub async fn load(&self) -> MaintenanceResult<MaintenanceState> {
+        let state = self.stored_state().await?;
+        self.mode
+            .set(state.enabled, Some(state.display_message().to_string()));
+        if state.enabled {
+            tracing::warn!(
+                "Starting in maintenance mode (enabled by {:?} at {:?})",
+                state.changed_by,
+                state.changed_at
+            );
+        }
+        Ok(state)
+    }
+
+    /// Current switch and worker state
+    pub async fn status(&self) -> MaintenanceResult<MaintenanceStatusResponse> {
+        let state = self.stored_state().await?;
+        Ok(MaintenanceStatusResponse {
+            enabled: state.enabled,
+            message: state.message,
+            changed_by: state.changed_by,
+            changed_at: state.changed_at,
+            workers: self.worker_status(),
+        })
+    }
+
+    /// Turn maintenance on or off, recording who changed it
+    pub async fn set_mode(
+        &self,
+        request: UpdateMaintenanceModeRequest,
+        changed_by: &str,
+    ) -> MaintenanceResult<MaintenanceStatusResponse> {
+        let message = request
+            .message
+            .map(|m| m.trim().to_string())
+            .filter(|m| !m.is_empty());
+        let old_value = self.repo.get_config_value(MAINTENANCE_MODE_KEY).await?;
+
+        let state = MaintenanceState {
+            enabled: request.enabled,
+            message,
+            changed_by: Some(changed_by.to_string()),
+            changed_at: Some(chrono::Utc::now().to_rfc3339()),
+        };
+        let new_value = serde_json::to_string(&state)
+            .map_err(|e| MaintenanceError::Validation(e.to_string()))?;
+
+        let change = SystemConfigChange::new(
+            MAINTENANCE_MODE_KEY.to_string(),
+            old_value,
+            new_value,
+            changed_by.to_string(),
+        );
+        self.repo
+            .apply_config_change(&change, Some("Maintenance mode switch"))
+            .await?;
+
+        self.mode
+            .set(state.enabled, Some(state.display_message().to_string()));
+        if state.enabled {
+            tracing::warn!("Maintenance mode enabled by {}", changed_by);
+        } else {
+            tracing::info!("Maintenance mode disabled by {}", changed_by);
+        }
+
+        self.status().await
+    }
+
+    /// Readiness for load balancers: not ready during maintenance or when the
+    /// database can't be reached
+    pub async fn readiness(&self) -> ReadinessResponse {
+        let database = match self.repo.get_config_value(MAINTENANCE_MODE_KEY).await {
+            Ok(_) => true,
+            Err(e) => {
+                tracing::error!("Readiness check could not reach the database: {}", e);
+                false
+            }
+        };
+        let maintenance = self.mode.is_enabled();
+        let status = if !database {
+            "unavailable"
+        } else if maintenance {
+            "maintenance"
+        } else {
+            "ready"
+        };
+
+        ReadinessResponse {
+            status: status.to_string(),
+            database,
+            maintenance,
+            workers: self.worker_status(),
+        }
+    }
+
+    fn worker_status(&self) -> MaintenanceWorkerStatus {
+        let paused = self.mode.is_enabled();
+        let active_jobs = self.mode.active_jobs();
+        MaintenanceWorkerStatus {
+            paused,
+            active_jobs,
+            drained: paused && active_jobs == 0,
+        }
+    }
+
+    async fn stored_state(&self) -> MaintenanceResult<MaintenanceState> {
+        let stored = self.repo.get_config_value(MAINTENANCE_MODE_KEY).await?;
+        Ok(match stored {
+            Some(value) => serde_json::from_str(&value).unwrap_or_else(|e| {
+                tracing::error!("Ignoring invalid stored maintenance state: {}", e);
+                MaintenanceState::default()
+            }),
+            None => MaintenanceState::default(),
+        })
+    }
+}
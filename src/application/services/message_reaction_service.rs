@@ -0,0 +1,185 @@
+use crate::{
+    domain::entities::{
+        normalize_reaction, summarize_reactions, Message, MessageReaction, MessageType,
+        ReactionSummary,
+    },
+    domain::errors::{ReactionError, ReactionResult},
+    domain::ports::conversation_repository::ConversationRepository,
+    domain::ports::message_reaction_repository::MessageReactionRepository,
+    domain::ports::message_repository::MessageRepository,
+    infrastructure::providers::connection_manager::{ConnectionManager, NotificationEvent},
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Service for agent reactions (👍, ✅, ❓) on messages
+#[derive(Clone)]
+pub struct MessageReactionService {
+    reaction_repo: Arc<dyn MessageReactionRepository>,
+    message_repo: Arc<dyn MessageRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    connection_manager: Option<Arc<dyn ConnectionManager>>,
+}
+
+impl MessageReactionService {
+    pub fn new(
+        reaction_repo: Arc<dyn MessageReactionRepository>,
+        message_repo: Arc<dyn MessageRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+    ) -> Self {
+        Self {
+            reaction_repo,
+            message_repo,
+            conversation_repo,
+            connection_manager: None,
+        }
+    }
+
+    /// Push reaction changes to connected agents
+    pub fn with_connection_manager(
+        mut self,
+        connection_manager: Arc<dyn ConnectionManager>,
+    ) -> Self {
+        self.connection_manager = Some(connection_manager);
+        self
+    }
+
+    async fn get_message(&self, message_id: &str) -> ReactionResult<Message> {
+        self.message_repo
+            .get_message_by_id(message_id)
+            .await?
+            .ok_or_else(|| ReactionError::NotFound(format!("Message {} not found", message_id)))
+    }
+
+    /// React to a message; reacting twice with the same emoji is a no-op
+    pub async fn add_reaction(
+        &self,
+        message_id: &str,
+        user_id: &str,
+        emoji: &str,
+    ) -> ReactionResult<Vec<ReactionSummary>> {
+        let emoji = validate_emoji(emoji)?;
+        let message = self.get_message(message_id).await?;
+
+        let reaction =
+            MessageReaction::new(message.id.clone(), user_id.to_string(), emoji.to_string());
+        if self.reaction_repo.add_message_reaction(&reaction).await? {
+            self.broadcast(&message, user_id, "reaction_added", &reaction.id)
+                .await;
+        }
+
+        self.list_reactions(&message.id).await
+    }
+
+    /// Remove the user's reaction from a message
+    pub async fn remove_reaction(
+        &self,
+        message_id: &str,
+        user_id: &str,
+        emoji: &str,
+    ) -> ReactionResult<Vec<ReactionSummary>> {
+        let emoji = validate_emoji(emoji)?;
+        let message = self.get_message(message_id).await?;
+
+        if !self
+            .reaction_repo
+            .remove_message_reaction(&message.id, user_id, emoji)
+            .await?
+        {
+            return Err(ReactionError::NotFound(format!(
+                "Reaction {} not found on message {}",
+                emoji, message_id
+            )));
+        }
+        self.broadcast(
+            &message,
+            user_id,
+            "reaction_removed",
+            &uuid::Uuid::new_v4().to_string(),
+        )
+        .await;
+
+        self.list_reactions(&message.id).await
+    }
+
+    pub async fn list_reactions(&self, message_id: &str) -> ReactionResult<Vec<ReactionSummary>> {
+        let reactions = self
+            .reaction_repo
+            .list_message_reactions(&[message_id.to_string()])
+            .await?;
+        Ok(summarize_reactions(&reactions))
+    }
+
+    /// Fill in the reactions of messages being returned by the API
+    pub async fn attach_reactions(&self, messages: &mut [Message]) -> ReactionResult<()> {
+        let message_ids: Vec<String> = messages.iter().map(|m| m.id.clone()).collect();
+        let reactions = self
+            .reaction_repo
+            .list_message_reactions(&message_ids)
+            .await?;
+
+        let mut by_message: HashMap<String, Vec<MessageReaction>> = HashMap::new();
+        for reaction in reactions {
+            by_message
+                .entry(reaction.message_id.clone())
+                .or_default()
+                .push(reaction);
+        }
+
+        for message in messages.iter_mut() {
+            if let Some(reactions) = by_message.get(&message.id) {
+                message.reactions = summarize_reactions(reactions);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Notify the message's agent author and the assignee (best-effort)
+    async fn broadcast(&self, message: &Message, actor_id: &str, event_type: &str, event_id: &str) {
+        let Some(connection_manager) = &self.connection_manager else {
+            return;
+        };
+
+        let mut recipients = Vec::new();
+        if message.message_type == MessageType::Outgoing {
+            recipients.push(message.author_id.clone());
+        }
+        if let Ok(Some(conversation)) = self
+            .conversation_repo
+            .get_conversation_by_id(&message.conversation_id)
+            .await
+        {
+            if let Some(assignee) = conversation.assigned_user_id {
+                recipients.push(assignee);
+            }
+        }
+        recipients.retain(|user_id| user_id != actor_id);
+        recipients.dedup();
+
+        for user_id in recipients {
+            let event = NotificationEvent {
+                id: event_id.to_string(),
+                type_: event_type.to_string(),
+                created_at: chrono::Utc::now().to_rfc3339(),
+                is_read: false,
+                conversation_id: Some(message.conversation_id.clone()),
+                message_id: Some(message.id.clone()),
+                actor_id: Some(actor_id.to_string()),
+                sequence: None,
+            };
+            if let Err(e) = connection_manager.send_to_user(&user_id, event).await {
+                tracing::debug!("Reaction update not delivered to {}: {}", user_id, e);
+            }
+        }
+    }
+}
+
+fn validate_emoji(emoji: &str) -> ReactionResult<&'static str> {
+    normalize_reaction(emoji).ok_or_else(|| {
+        ReactionError::Validation(format!(
+            "Unsupported reaction '{}', expected one of 👍, ✅, ❓",
+            emoji
+        ))
+    })
+}
@@ -1,6 +1,11 @@
 use crate::{
-    application::services::{DeliveryService, NotificationService},
-    domain::entities::{IncomingMessageRequest, Message, SendMessageRequest, UserNotification},
+    application::services::{
+        AssignmentService, AutoTagService, ConversationSummaryService, DeliveryService,
+        NotificationService, SentimentService,
+    },
+    domain::entities::{
+        IncomingMessageRequest, Message, SendMessageRequest, SystemNote, UserNotification,
+    },
     domain::events::SystemEvent,
     domain::ports::conversation_repository::ConversationRepository,
     domain::ports::event_bus::EventBus,
@@ -17,6 +22,10 @@ pub struct MessageService {
     delivery_service: Option<DeliveryService>,
     event_bus: Option<Arc<dyn EventBus>>,
     connection_manager: Option<Arc<dyn ConnectionManager>>,
+    auto_tag_service: Option<AutoTagService>,
+    sentiment_service: Option<SentimentService>,
+    assignment_service: Option<AssignmentService>,
+    summary_service: Option<ConversationSummaryService>,
 }
 
 impl MessageService {
@@ -30,6 +39,10 @@ impl MessageService {
             delivery_service: None,
             event_bus: None,
             connection_manager: None,
+            auto_tag_service: None,
+            sentiment_service: None,
+            assignment_service: None,
+            summary_service: None,
         }
     }
 
@@ -44,6 +57,10 @@ impl MessageService {
             delivery_service: Some(delivery_service),
             event_bus: None,
             connection_manager: None,
+            auto_tag_service: None,
+            sentiment_service: None,
+            assignment_service: None,
+            summary_service: None,
         }
     }
 
@@ -60,9 +77,35 @@ impl MessageService {
             delivery_service: Some(delivery_service),
             event_bus: Some(event_bus),
             connection_manager: Some(connection_manager),
+            auto_tag_service: None,
+            sentiment_service: None,
+            assignment_service: None,
+            summary_service: None,
         }
     }
 
+    /// Set auto-tag service (for keyword tagging of incoming messages)
+    pub fn set_auto_tag_service(&mut self, auto_tag_service: AutoTagService) {
+        self.auto_tag_service = Some(auto_tag_service);
+    }
+
+    /// Set sentiment service (for scoring incoming messages)
+    pub fn set_sentiment_service(&mut self, sentiment_service: SentimentService) {
+        self.sentiment_service = Some(sentiment_service);
+    }
+
+    /// Set assignment service (for language-based routing of incoming messages
+    /// and take-on-reply assignment of outgoing ones)
+    pub fn set_assignment_service(&mut self, assignment_service: AssignmentService) {
+        self.assignment_service = Some(assignment_service);
+    }
+
+    /// Set conversation summary service (for refreshing the preview after
+    /// agent replies, which publish no event until delivered)
+    pub fn set_summary_service(&mut self, summary_service: ConversationSummaryService) {
+        self.summary_service = Some(summary_service);
+    }
+
     /// Create an incoming message from external source (webhook)
     pub async fn create_incoming_message(
         &self,
@@ -72,7 +115,7 @@ impl MessageService {
         Message::validate_content(&request.content).map_err(|e| ApiError::BadRequest(e))?;
 
         // Verify conversation exists
-        let _conversation = self
+        let conversation = self
             .conversation_repo
             .get_conversation_by_id(&request.conversation_id)
             .await?
@@ -89,8 +132,10 @@ impl MessageService {
         })?;
 
         // Create incoming message
-        let message =
+        let mut message =
             Message::new_incoming(request.conversation_id.clone(), request.content, contact_id);
+        message.channel = request.channel;
+        message.channel_metadata = request.channel_metadata;
 
         // Save to database
         self.message_repo.create_message(&message).await?;
@@ -121,6 +166,47 @@ impl MessageService {
             });
         }
 
+        // Apply inbox keyword auto-tag rules (best effort)
+        if let Some(ref auto_tag_service) = self.auto_tag_service {
+            if let Err(e) = auto_tag_service
+                .apply_to_message(
+                    &conversation.id,
+                    &conversation.inbox_id,
+                    conversation.subject.as_deref(),
+                    &message.content,
+                )
+                .await
+            {
+                tracing::warn!(
+                    "Failed to apply auto-tag rules to conversation {}: {}",
+                    conversation.id,
+                    e
+                );
+            }
+        }
+
+        // Score sentiment and update the conversation trend (best effort)
+        if let Some(ref sentiment_service) = self.sentiment_service {
+            if let Err(e) = sentiment_service.record_incoming_message(&message).await {
+                tracing::warn!(
+                    "Failed to record sentiment for message {}: {}",
+                    message.id,
+                    e
+                );
+            }
+        }
+
+        // Route unassigned conversations by the message's language (best effort)
+        if let Some(ref assignment_service) = self.assignment_service {
+            if let Err(e) = assignment_service.route_by_language(&message).await {
+                tracing::warn!(
+                    "Failed to route conversation {} by language: {}",
+                    message.conversation_id,
+                    e
+                );
+            }
+        }
+
         Ok(message)
     }
 
@@ -135,7 +221,7 @@ impl MessageService {
         Message::validate_content(&request.content).map_err(|e| ApiError::BadRequest(e))?;
 
         // Verify conversation exists
-        let _conversation = self
+        let conversation = self
             .conversation_repo
             .get_conversation_by_id(&conversation_id)
             .await?
@@ -160,6 +246,31 @@ impl MessageService {
             )
             .await?;
 
+        // Take on unassigned team conversations for the replying agent (best effort)
+        if let Some(ref assignment_service) = self.assignment_service {
+            if let Err(e) = assignment_service
+                .take_on_reply(&conversation, &agent_id)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to assign conversation {} on reply: {}",
+                    conversation_id,
+                    e
+                );
+            }
+        }
+
+        // The conversation now waits on the customer (best effort)
+        if let Some(ref summary_service) = self.summary_service {
+            if let Err(e) = summary_service.refresh(&conversation_id).await {
+                tracing::warn!(
+                    "Failed to refresh summary of conversation {}: {}",
+                    conversation_id,
+                    e
+                );
+            }
+        }
+
         // Queue message for delivery
         if let Some(ref delivery_service) = self.delivery_service {
             delivery_service.enqueue_message(message.id.clone()).await?;
@@ -245,6 +356,11 @@ impl MessageService {
             .await
     }
 
+    /// List the auto-generated emails recorded on a conversation
+    pub async fn list_system_notes(&self, conversation_id: &str) -> ApiResult<Vec<SystemNote>> {
+        self.message_repo.list_system_notes(conversation_id).await
+    }
+
     /// Check if a message is immutable (prevents updates to sent/received messages)
     async fn check_message_immutable(&self, message_id: &str) -> ApiResult<()> {
         let message = self.get_message(message_id).await?;
@@ -1,63 +1,141 @@
+pub mod activity_service;
+pub mod agent_onboarding_service;
 pub mod agent_service;
 pub mod api_key_service;
+pub mod api_key_usage_service;
 pub mod assignment_service;
+pub mod attachment_rule_service;
 pub mod attachment_service;
 pub mod auth;
 pub mod auth_logger;
 pub mod auth_logger_service;
+pub mod auto_tag_service;
 pub mod automation_service;
 pub mod availability_service;
+pub mod branding_service;
+pub mod channel_service;
+pub mod chat_widget_service;
+pub mod config_bundle_service;
+pub mod contact_note_service;
 pub mod contact_service;
+pub mod conversation_contact_service;
 pub mod conversation_priority_service;
 pub mod conversation_service;
+pub mod conversation_summary_service;
 pub mod conversation_tag_service;
+pub mod conversation_transfer_service;
+pub mod csat_service;
 pub mod delivery_service;
+pub mod diagnostics_service;
+pub mod email_oauth_service;
+pub mod email_participant_service;
+pub mod email_template_service;
 pub mod email_service;
+pub mod gdpr_service;
+pub mod holiday_calendar_service;
+pub mod import_service;
 pub mod inbox_service;
+pub mod job_service;
+pub mod junk_service;
+pub mod locale_service;
 pub mod macro_service;
+pub mod maintenance_service;
+pub mod message_reaction_service;
 pub mod message_service;
 pub mod notification_service;
 pub mod oidc_service;
 pub mod password_reset_email_service;
 pub mod password_reset_service;
 pub mod permission_service;
+pub mod priority_matrix_service;
+pub mod reporting_service;
+pub mod response_reminder_service;
 pub mod role_service;
+pub mod saved_view_service;
+pub mod sentiment_service;
 pub mod session_service;
+pub mod shift_service;
 pub mod sla_service;
+pub mod sms_service;
 pub mod snooze_service;
+pub mod system_config_service;
 pub mod tag_service;
 pub mod team_service;
+pub mod telegram_service;
 pub mod user_service;
 pub mod webhook_service;
 
+pub use crate::domain::errors::{
+    AutoTagError, AutoTagResult, ChannelError, ChannelResult, ConfigBundleError, ConfigBundleResult, CsatError, CsatResult,
+    HolidayCalendarError, HolidayCalendarResult, InboxError, InboxResult, PriorityError,
+    PriorityResult, ReactionError, ReactionResult, SentimentError, SentimentResult, ShiftError,
+    ShiftResult, SmsError, SmsResult, SystemConfigError, SystemConfigResult, TagError, TagResult, TeamError, TeamResult,
+    TelegramError, TelegramResult, WebhookError, WebhookResult,
+};
+
+pub use activity_service::*;
+pub use agent_onboarding_service::*;
 pub use agent_service::*;
 pub use api_key_service::*;
+pub use api_key_usage_service::*;
 pub use assignment_service::*;
+pub use attachment_rule_service::*;
 pub use attachment_service::*;
 pub use auth::*;
 pub use auth_logger::*;
 pub use auth_logger_service::*;
+pub use auto_tag_service::*;
 pub use automation_service::*;
 pub use availability_service::*;
+pub use branding_service::*;
+pub use channel_service::*;
+pub use chat_widget_service::*;
+pub use config_bundle_service::*;
+pub use contact_note_service::*;
 pub use contact_service::*;
+pub use conversation_contact_service::*;
 pub use conversation_priority_service::*;
 pub use conversation_service::*;
+pub use conversation_summary_service::*;
 pub use conversation_tag_service::*;
+pub use conversation_transfer_service::*;
+pub use csat_service::*;
 pub use delivery_service::*;
+pub use diagnostics_service::*;
+pub use email_oauth_service::*;
+pub use email_participant_service::*;
+pub use email_template_service::*;
 pub use email_service::*;
+pub use gdpr_service::*;
+pub use holiday_calendar_service::*;
+pub use import_service::*;
 pub use inbox_service::*;
+pub use job_service::*;
+pub use junk_service::*;
+pub use locale_service::*;
 pub use macro_service::*;
+pub use maintenance_service::*;
+pub use message_reaction_service::*;
 pub use message_service::*;
 pub use notification_service::*;
 pub use oidc_service::*;
 pub use password_reset_email_service::*;
 pub use password_reset_service::*;
 pub use permission_service::*;
+pub use priority_matrix_service::*;
+pub use reporting_service::*;
+pub use response_reminder_service::*;
 pub use role_service::*;
+pub use saved_view_service::*;
+pub use sentiment_service::*;
 pub use session_service::*;
+pub use shift_service::*;
 pub use sla_service::*;
+pub use sms_service::*;
+pub use system_config_service::*;
 
 pub use tag_service::*;
 pub use team_service::*;
+pub use telegram_service::*;
 pub use user_service::*;
 pub use webhook_service::*;
@@ -1,14 +1,21 @@
+use futures::stream::{self, Stream, StreamExt};
 use regex::Regex;
 use std::collections::HashSet;
 use std::sync::Arc;
 
-use crate::domain::entities::UserNotification;
+use crate::domain::entities::{NotificationStreamEvent, UserNotification};
 use crate::domain::ports::notification_repository::NotificationRepository;
 use crate::domain::ports::user_repository::UserRepository;
 use crate::infrastructure::http::middleware::error::ApiResult;
 use crate::infrastructure::persistence::Database;
 use crate::infrastructure::providers::connection_manager::{ConnectionManager, NotificationEvent};
 
+/// Number of missed events loaded per query when a stream resumes
+pub const STREAM_REPLAY_PAGE_SIZE: i64 = 500;
+
+/// How long stream events are kept for Last-Event-ID resume
+pub const STREAM_EVENT_RETENTION_HOURS: i64 = 24;
+
 /// Notification service for handling user notifications
 #[derive(Clone)]
 pub struct NotificationService {
@@ -66,6 +73,7 @@ impl NotificationService {
             conversation_id: notification.conversation_id.clone(),
             message_id: notification.message_id.clone(),
             actor_id: notification.actor_id.clone(),
+            sequence: None,
         };
 
         // Call connection_manager.send_to_user()
@@ -225,6 +233,87 @@ impl NotificationService {
         Ok(count)
     }
 
+    /// Cleanup stream events that are too old to be resumed from
+    /// Returns the count of events deleted
+    pub async fn cleanup_stream_events(
+        db: &Database,
+        retention_hours: Option<i64>,
+    ) -> Result<i32, String> {
+        let hours = retention_hours.unwrap_or(STREAM_EVENT_RETENTION_HOURS);
+
+        let count = db
+            .delete_old_stream_events(hours)
+            .await
+            .map_err(|e| format!("Failed to cleanup notification stream events: {}", e))?;
+
+        tracing::debug!(
+            "Cleaned up {} notification stream events (older than {} hours)",
+            count,
+            hours
+        );
+
+        Ok(count)
+    }
+
+    /// List one page of the stream events a user missed after `last_event_id`,
+    /// oldest first
+    pub async fn list_stream_events_after(
+        &self,
+        user_id: &str,
+        last_event_id: i64,
+    ) -> ApiResult<Vec<NotificationStreamEvent>> {
+        self.notification_repo
+            .as_ref()
+            .ok_or_else(|| {
+                crate::infrastructure::http::middleware::error::ApiError::Internal(
+                    "NotificationRepository not initialized".to_string(),
+                )
+            })?
+            .list_stream_events_after(user_id, last_event_id, STREAM_REPLAY_PAGE_SIZE)
+            .await
+    }
+
+    /// Stream every event a user missed after `last_event_id`, oldest first
+    ///
+    /// The backlog is read a page at a time until a short page comes back, so a
+    /// client resuming after a long disconnect is replayed everything still
+    /// retained (see `STREAM_EVENT_RETENTION_HOURS`), including events stored
+    /// while the replay runs. A failed page ends the replay early.
+    pub fn stream_events_after(
+        &self,
+        user_id: &str,
+        last_event_id: i64,
+    ) -> impl Stream<Item = NotificationStreamEvent> + Send + 'static {
+        let service = self.clone();
+        let user_id = user_id.to_string();
+
+        stream::unfold(Some(last_event_id), move |after| {
+            let service = service.clone();
+            let user_id = user_id.clone();
+            async move {
+                let after = after?;
+                let page = match service.list_stream_events_after(&user_id, after).await {
+                    Ok(page) => page,
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to load missed notifications for {}: {}",
+                            user_id,
+                            e
+                        );
+                        return None;
+                    }
+                };
+                let next = if page.len() as i64 == STREAM_REPLAY_PAGE_SIZE {
+                    page.last().map(|event| event.sequence)
+                } else {
+                    None
+                };
+                Some((stream::iter(page), next))
+            }
+        })
+        .flatten()
+    }
+
     /// Get unread notification count for a user
     pub async fn get_unread_count(&self, user_id: &str) -> ApiResult<i32> {
         self.notification_repo
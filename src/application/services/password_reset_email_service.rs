@@ -1,4 +1,4 @@
-/// Email service for sending password reset emails
+/// Email service for sending transactional emails such as password reset links
 /// Feature: 017-password-reset
 use lettre::{
     message::header::ContentType, transport::smtp::authentication::Credentials, Message,
@@ -7,6 +7,8 @@ use lettre::{
 use std::env;
 use thiserror::Error;
 
+use crate::domain::ports::email_sender::{EmailSender, OutgoingEmail};
+
 #[derive(Debug, Error)]
 pub enum EmailError {
     #[error("Failed to build email message: {0}")]
@@ -69,43 +71,42 @@ impl SmtpConfig {
     }
 }
 
-/// Send password reset email with token
-///
-/// # Arguments
-/// * `to_email` - Recipient email address
-/// * `reset_token` - 32-character reset token
-/// * `config` - SMTP configuration
-///
-/// # Returns
-/// * `Ok(())` if email sent successfully
-/// * `Err(EmailError)` if email failed to send
-pub async fn send_password_reset_email(
+/// Sends transactional emails (e.g. password reset links) over SMTP
+#[derive(Clone)]
+pub struct SmtpEmailSender {
+    config: SmtpConfig,
+}
+
+impl SmtpEmailSender {
+    pub fn new(config: SmtpConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailSender for SmtpEmailSender {
+    async fn send(&self, email: &OutgoingEmail) -> Result<(), String> {
+        let body = email
+            .html_body
+            .clone()
+            .unwrap_or_else(|| email.text_body.clone());
+        send_email(&email.to, &email.subject, body, &self.config)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        tracing::info!("Email '{}' sent successfully to {}", email.subject, email.to);
+
+        Ok(())
+    }
+}
+
+/// Build and send a single email over SMTP
+async fn send_email(
     to_email: &str,
-    reset_token: &str,
+    subject: &str,
+    email_body: String,
     config: &SmtpConfig,
 ) -> Result<(), EmailError> {
-    let reset_link = format!(
-        "{}/reset-password?token={}",
-        config.reset_base_url, reset_token
-    );
-
-    // Try to load HTML template, fallback to plain text if not found
-    let email_body = match std::fs::read_to_string("templates/password_reset_email.html") {
-        Ok(template) => template.replace("{{reset_link}}", &reset_link),
-        Err(_) => {
-            // Fallback to plain text if template not found
-            tracing::warn!("HTML email template not found, using plain text fallback");
-            format!(
-                "You requested a password reset for your Oxidesk account.\n\n\
-                 Click the link below to reset your password:\n\
-                 {}\n\n\
-                 This link will expire in 1 hour.\n\n\
-                 If you did not request a password reset, please ignore this email.",
-                reset_link
-            )
-        }
-    };
-
     let from_address = format!("{} <{}>", config.from_name, config.from_email);
 
     let content_type = if email_body.contains("<!DOCTYPE html") {
@@ -122,7 +123,7 @@ pub async fn send_password_reset_email(
             .to(to_email
                 .parse()
                 .map_err(|e| EmailError::MessageBuildError(format!("Invalid to address: {}", e)))?)
-            .subject("Password Reset Request")
+            .subject(subject)
             .header(content_type)
             .body(email_body)
             .map_err(|e| EmailError::MessageBuildError(e.to_string()))?;
@@ -141,8 +142,6 @@ pub async fn send_password_reset_email(
         .map_err(|e| EmailError::SendError(format!("Task join error: {}", e)))?
         .map_err(|e| EmailError::SendError(format!("SMTP send error: {}", e)))?;
 
-    tracing::info!("Password reset email sent successfully to {}", to_email);
-
     Ok(())
 }
 
@@ -2,24 +2,117 @@
 /// Feature: 017-password-reset
 ///
 /// Business logic for password reset functionality including:
-/// - Token generation and validation
-/// - Rate limiting
+/// - Token generation and validation (tokens are stored hashed)
+/// - Rate limiting per email address
 /// - Email enumeration prevention
+/// - Reset link delivery through the configured email sender
 /// - Session destruction
+/// - Agent invitations, which are longer-lived set-password links
 use crate::{
     infrastructure::http::middleware::error::{ApiError, ApiResult},
-    domain::ports::{password_reset_repository::PasswordResetRepository, user_repository::UserRepository},
+    domain::ports::{
+        email_sender::{EmailSender, OutgoingEmail},
+        password_reset_repository::PasswordResetRepository,
+        template_repository::TemplateRepository,
+        user_locale_repository::UserLocaleRepository,
+        user_repository::UserRepository,
+    },
     domain::entities::*,
     application::services::auth::{hash_password, validate_password_complexity},
-    application::services::password_reset_email_service::{send_password_reset_email, SmtpConfig},
-    shared::utils::generate_reset_token,
+    application::services::BrandingService,
+    shared::{i18n, utils::generate_reset_token},
 };
 use std::{env, sync::Arc};
 
+/// Template rendered into the HTML part of the reset email
+const RESET_EMAIL_TEMPLATE: &str = "password_reset_email.html";
+
+/// Template rendered into the HTML part of the invitation email
+const INVITATION_EMAIL_TEMPLATE: &str = "agent_invitation_email.html";
+
+/// Window for the per-email request limit
+const RATE_LIMIT_WINDOW_SECONDS: i64 = 3600;
+
+/// Password reset settings
+#[derive(Debug, Clone)]
+pub struct PasswordResetConfig {
+    /// How long a reset link stays valid
+    pub token_ttl_seconds: i64,
+    /// Requests accepted per email address per hour
+    pub max_requests_per_hour: i64,
+    /// Base URL of the reset page; the token is appended as `?token=`
+    pub reset_base_url: String,
+    /// How long an agent invitation link stays valid
+    pub invitation_ttl_seconds: i64,
+}
+
+impl Default for PasswordResetConfig {
+    fn default() -> Self {
+        Self {
+            token_ttl_seconds: 3600,
+            max_requests_per_hour: 5,
+            reset_base_url: "http://localhost:3000".to_string(),
+            invitation_ttl_seconds: 7 * 86400,
+        }
+    }
+}
+
+impl PasswordResetConfig {
+    /// Read `PASSWORD_RESET_TOKEN_EXPIRY`, `PASSWORD_RESET_RATE_LIMIT`,
+    /// `RESET_PASSWORD_BASE_URL` and `AGENT_INVITATION_EXPIRY`, falling back
+    /// to the defaults
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            token_ttl_seconds: env::var("PASSWORD_RESET_TOKEN_EXPIRY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.token_ttl_seconds),
+            max_requests_per_hour: env::var("PASSWORD_RESET_RATE_LIMIT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.max_requests_per_hour),
+            reset_base_url: env::var("RESET_PASSWORD_BASE_URL")
+                .unwrap_or(defaults.reset_base_url),
+            invitation_ttl_seconds: env::var("AGENT_INVITATION_EXPIRY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.invitation_ttl_seconds),
+        }
+    }
+}
+
+/// Human-readable token lifetime for the email, e.g. "1 hour" or "30 minutes"
+pub fn describe_token_ttl(locale: &str, seconds: i64) -> String {
+    let plural = |n: i64, unit: &str| {
+        let count = n.to_string();
+        let id = if n == 1 {
+            format!("duration-{}", unit)
+        } else {
+            format!("duration-{}s", unit)
+        };
+        i18n::catalog().translate(locale, &id, &[("count", &count)])
+    };
+    if seconds >= 86400 && seconds % 86400 == 0 {
+        plural(seconds / 86400, "day")
+    } else if seconds >= 3600 && seconds % 3600 == 0 {
+        plural(seconds / 3600, "hour")
+    } else {
+        plural((seconds + 59) / 60, "minute")
+    }
+}
+
 #[derive(Clone)]
 pub struct PasswordResetService {
     password_reset_repo: PasswordResetRepository,
     user_repo: Arc<dyn UserRepository>,
+    config: PasswordResetConfig,
+    email_sender: Option<Arc<dyn EmailSender>>,
+    template_repo: Option<Arc<dyn TemplateRepository>>,
+    branding_service: Option<BrandingService>,
+    user_locale_repo: Option<Arc<dyn UserLocaleRepository>>,
 }
 
 impl PasswordResetService {
@@ -27,6 +120,57 @@ impl PasswordResetService {
         Self {
             password_reset_repo,
             user_repo,
+            config: PasswordResetConfig::from_env(),
+            email_sender: None,
+            template_repo: None,
+            branding_service: None,
+            user_locale_repo: None,
+        }
+    }
+
+    /// Override the settings read from the environment
+    pub fn set_config(&mut self, config: PasswordResetConfig) {
+        self.config = config;
+    }
+
+    /// Deliver reset links through `email_sender`; without one, links are not sent
+    pub fn set_email_sender(&mut self, email_sender: Arc<dyn EmailSender>) {
+        self.email_sender = Some(email_sender);
+    }
+
+    /// Render the HTML email from the `password_reset_email.html` template
+    pub fn set_template_repo(&mut self, template_repo: Arc<dyn TemplateRepository>) {
+        self.template_repo = Some(template_repo);
+    }
+
+    /// Use the workspace product name, colors and footer in emails
+    pub fn set_branding_service(&mut self, branding_service: BrandingService) {
+        self.branding_service = Some(branding_service);
+    }
+
+    /// Write emails in the recipient's chosen locale
+    pub fn set_user_locale_repo(&mut self, user_locale_repo: Arc<dyn UserLocaleRepository>) {
+        self.user_locale_repo = Some(user_locale_repo);
+    }
+
+    /// The recipient's chosen locale, else the locale of the current request
+    async fn recipient_locale(&self, user_id: &str) -> String {
+        let preferred = match &self.user_locale_repo {
+            Some(repo) => repo.get_user_locale(user_id).await.unwrap_or_else(|e| {
+                tracing::warn!("Failed to load locale of user {}: {}", user_id, e);
+                None
+            }),
+            None => None,
+        };
+        preferred
+            .and_then(|locale| i18n::catalog().resolve(&locale))
+            .unwrap_or_else(i18n::current_locale)
+    }
+
+    async fn branding(&self) -> Branding {
+        match &self.branding_service {
+            Some(branding_service) => branding_service.current().await,
+            None => Branding::default(),
         }
     }
 
@@ -35,14 +179,27 @@ impl PasswordResetService {
     /// This implements email enumeration prevention by:
     /// - Always returning the same success message
     /// - Only sending email if user exists
-    /// - Maintaining consistent timing
+    /// - Applying the same rate limit to every address
+    /// - Sending the email in the background so timing stays consistent
     ///
     /// # Rate Limiting
-    /// Maximum 5 requests per hour per email
+    /// At most `max_requests_per_hour` requests per email address
     pub async fn request_password_reset(&self, email: &str) -> ApiResult<RequestPasswordResetResponse> {
         // Normalize email
         let email = email.trim().to_lowercase();
 
+        // Check rate limit before looking the address up
+        let recent_requests = self
+            .password_reset_repo
+            .count_recent_requests(&email, RATE_LIMIT_WINDOW_SECONDS)
+            .await?;
+        if recent_requests >= self.config.max_requests_per_hour {
+            return Err(ApiError::TooManyRequests(
+                "Too many password reset requests. Please try again later.".to_string(),
+            ));
+        }
+        self.password_reset_repo.record_request(&email).await?;
+
         // Try to find agent by email
         let user_option = self.user_repo
             .get_user_by_email_and_type(&email, &UserType::Agent)
@@ -50,23 +207,13 @@ impl PasswordResetService {
 
         // If user exists, proceed with reset flow
         if let Some(user) = user_option {
-            // Check rate limit (5 requests per hour)
-            let rate_limit_window = env::var("PASSWORD_RESET_RATE_LIMIT")
-                .unwrap_or_else(|_| "5".to_string())
-                .parse()
-                .unwrap_or(5);
-
-            let recent_requests = self.password_reset_repo.count_recent_requests(&user.id, 3600).await?;
-
-            if recent_requests >= rate_limit_window {
-                return Err(ApiError::TooManyRequests(
-                    "Too many password reset requests. Please try again later.".to_string(),
-                ));
-            }
-
-            // Generate reset token
+            // Generate reset token; only its hash is stored
             let token_value = generate_reset_token();
-            let reset_token = PasswordResetToken::new(user.id.clone(), token_value.clone());
+            let reset_token = PasswordResetToken::new(
+                user.id.clone(),
+                &token_value,
+                self.config.token_ttl_seconds,
+            );
 
             // Invalidate previous tokens for this user
             self.password_reset_repo.invalidate_user_tokens(&user.id).await?;
@@ -74,23 +221,27 @@ impl PasswordResetService {
             // Store new token
             self.password_reset_repo.create_token(&reset_token).await?;
 
-            // Send email (async, best-effort)
-            let smtp_config = SmtpConfig::from_env()
-                .map_err(|e| ApiError::Internal(format!("SMTP configuration error: {}", e)))?;
-
-            // Spawn email sending in background to not block response
-            let email_clone = email.clone();
-            tokio::spawn(async move {
-                if let Err(e) =
-                    send_password_reset_email(&email_clone, &token_value, &smtp_config).await
-                {
-                    tracing::error!(
-                        "Failed to send password reset email to {}: {}",
-                        email_clone,
-                        e
-                    );
+            // Send email in background to not block response (best-effort)
+            match &self.email_sender {
+                Some(email_sender) => {
+                    let locale = self.recipient_locale(&user.id).await;
+                    let message = self.render_reset_email(&email, &token_value, &locale).await;
+                    let email_sender = email_sender.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = email_sender.send(&message).await {
+                            tracing::error!(
+                                "Failed to send password reset email to {}: {}",
+                                message.to,
+                                e
+                            );
+                        }
+                    });
                 }
-            });
+                None => tracing::warn!(
+                    "No email sender configured; password reset link for {} was not sent",
+                    email
+                ),
+            }
 
             tracing::info!(
                 "Password reset requested for email: {} (user_id: {})",
@@ -104,8 +255,179 @@ impl PasswordResetService {
 
         // Always return the same generic message (email enumeration prevention)
         Ok(RequestPasswordResetResponse {
-            message: "If an account exists with that email, you will receive a password reset link."
-                .to_string(),
+            message: i18n::t("email-password-reset-requested"),
+        })
+    }
+
+    /// Build the reset email, using the HTML template when one is available
+    async fn render_reset_email(&self, to: &str, token: &str, locale: &str) -> OutgoingEmail {
+        let reset_link = format!("{}/reset-password?token={}", self.config.reset_base_url, token);
+        let expires_in = describe_token_ttl(locale, self.config.token_ttl_seconds);
+        let branding = self.branding().await;
+        let catalog = i18n::catalog();
+
+        let html_body = match &self.template_repo {
+            Some(template_repo) => match template_repo.get_template(RESET_EMAIL_TEMPLATE).await {
+                Ok(Some(template)) => Some(render_template(
+                    &template
+                        .body_html
+                        .replace("{{reset_link}}", &reset_link)
+                        .replace("{{expires_in}}", &expires_in),
+                    &branding.email_variables(),
+                )),
+                Ok(None) | Err(_) => {
+                    tracing::warn!("HTML email template not found, using plain text only");
+                    None
+                }
+            },
+            None => None,
+        };
+
+        OutgoingEmail {
+            to: to.to_string(),
+            subject: catalog.translate(locale, "email-password-reset-subject", &[]),
+            text_body: catalog.translate(
+                locale,
+                "email-password-reset-text",
+                &[
+                    ("product", &branding.product_name),
+                    ("link", &reset_link),
+                    ("expires_in", &expires_in),
+                ],
+            ),
+            html_body,
+        }
+    }
+
+    /// Email a new agent a link to choose their password
+    ///
+    /// Invitations are set-password tokens with the invitation lifetime, so
+    /// they share storage, hashing and single use with reset tokens. Earlier
+    /// links for the user stop working. Returns whether the email was handed
+    /// to the sender; without one configured the link is not sent.
+    pub async fn send_invitation(
+        &self,
+        user_id: &str,
+        email: &str,
+        invited_by: &str,
+    ) -> ApiResult<bool> {
+        let token_value = generate_reset_token();
+        let token = PasswordResetToken::new(
+            user_id.to_string(),
+            &token_value,
+            self.config.invitation_ttl_seconds,
+        );
+        self.password_reset_repo.invalidate_user_tokens(user_id).await?;
+        self.password_reset_repo.create_token(&token).await?;
+
+        let Some(email_sender) = &self.email_sender else {
+            tracing::warn!(
+                "No email sender configured; invitation for {} was not sent",
+                email
+            );
+            return Ok(false);
+        };
+
+        let locale = self.recipient_locale(user_id).await;
+        let message = self
+            .render_invitation_email(email, &token_value, invited_by, &locale)
+            .await;
+        let email_sender = email_sender.clone();
+        tokio::spawn(async move {
+            if let Err(e) = email_sender.send(&message).await {
+                tracing::error!("Failed to send invitation email to {}: {}", message.to, e);
+            }
+        });
+
+        tracing::info!("Invitation sent to {} (user_id: {})", email, user_id);
+        Ok(true)
+    }
+
+    /// Build the invitation email, using the HTML template when one is available
+    async fn render_invitation_email(
+        &self,
+        to: &str,
+        token: &str,
+        invited_by: &str,
+        locale: &str,
+    ) -> OutgoingEmail {
+        let invitation_link = format!(
+            "{}/accept-invitation?token={}",
+            self.config.reset_base_url, token
+        );
+        let expires_in = describe_token_ttl(locale, self.config.invitation_ttl_seconds);
+        let branding = self.branding().await;
+        let catalog = i18n::catalog();
+
+        let html_body = match &self.template_repo {
+            Some(template_repo) => match template_repo.get_template(INVITATION_EMAIL_TEMPLATE).await {
+                Ok(Some(template)) => Some(render_template(
+                    &template
+                        .body_html
+                        .replace("{{invitation_link}}", &invitation_link)
+                        .replace("{{expires_in}}", &expires_in),
+                    &branding.email_variables(),
+                )),
+                Ok(None) | Err(_) => {
+                    tracing::warn!("HTML email template not found, using plain text only");
+                    None
+                }
+            },
+            None => None,
+        };
+
+        OutgoingEmail {
+            to: to.to_string(),
+            subject: catalog.translate(
+                locale,
+                "email-invitation-subject",
+                &[("product", &branding.product_name)],
+            ),
+            text_body: catalog.translate(
+                locale,
+                "email-invitation-text",
+                &[
+                    ("inviter", invited_by),
+                    ("product", &branding.product_name),
+                    ("link", &invitation_link),
+                    ("expires_in", &expires_in),
+                ],
+            ),
+            html_body,
+        }
+    }
+
+    /// Accept an agent invitation by choosing a password
+    ///
+    /// Same checks and atomic update as [`Self::reset_password`].
+    pub async fn accept_invitation(
+        &self,
+        token: &str,
+        password: &str,
+    ) -> ApiResult<AcceptInvitationResponse> {
+        let invalid_invitation = |e: ApiError| match e {
+            ApiError::BadRequest(_) => {
+                ApiError::BadRequest("Invalid or expired invitation".to_string())
+            }
+            other => other,
+        };
+
+        let token_record = self
+            .validate_reset_token(token)
+            .await
+            .map_err(invalid_invitation)?;
+        validate_password_complexity(password)?;
+        let password_hash = hash_password(password)?;
+
+        self.password_reset_repo
+            .reset_password_atomic(&token_record.user_id, &token_record.id, &password_hash)
+            .await
+            .map_err(invalid_invitation)?;
+
+        tracing::info!("Invitation accepted for user_id: {}", token_record.user_id);
+
+        Ok(AcceptInvitationResponse {
+            message: "Your account is ready. Please log in with your new password.".to_string(),
         })
     }
 
@@ -155,7 +477,7 @@ impl PasswordResetService {
     /// 2. Validate password complexity
     /// 3. Hash new password
     /// 4. Update agent password
-    /// 5. Mark token as used
+    /// 5. Mark token as used (fails if another request used it first)
     /// 6. Delete all user sessions
     ///
     /// If any step fails, the entire transaction is rolled back
@@ -195,6 +517,7 @@ impl PasswordResetService {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
 
     #[test]
     fn test_validate_token_format_valid() {
@@ -215,6 +538,16 @@ mod tests {
         assert!(!invalid_token.chars().all(|c| c.is_alphanumeric()));
     }
 
+    #[test]
+    fn test_describe_token_ttl() {
+        assert_eq!(describe_token_ttl("en", 3600), "1 hour");
+        assert_eq!(describe_token_ttl("en", 7200), "2 hours");
+        assert_eq!(describe_token_ttl("en", 1800), "30 minutes");
+        assert_eq!(describe_token_ttl("en", 90), "2 minutes");
+        assert_eq!(describe_token_ttl("en", 86400), "1 day");
+        assert_eq!(describe_token_ttl("es", 7 * 86400), "7 días");
+    }
+
     #[test]
     fn test_email_normalization() {
         let email = "  Alice@Example.COM  ";
@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use crate::{
+    application::services::ConversationPriorityService,
+    domain::entities::{
+        normalize_tier, resolve_matrix_priority, ContactTierResponse,
+        CreatePriorityMatrixEntryRequest, Priority, PriorityMatrixEntry, SetContactTierRequest,
+    },
+    domain::errors::{PriorityError, PriorityMatrixError, PriorityMatrixResult},
+    domain::ports::{
+        conversation_repository::ConversationRepository,
+        conversation_tag_repository::ConversationTagRepository,
+        priority_matrix_repository::PriorityMatrixRepository, tag_repository::TagRepository,
+    },
+    infrastructure::http::middleware::AuthenticatedUser,
+};
+
+/// Service for the contact tier × tag priority matrix
+///
+/// The matrix is evaluated when a conversation is created or retagged. The
+/// highest matching priority is applied, but only when it raises the
+/// conversation's priority: a priority set by an agent is never lowered.
+#[derive(Clone)]
+pub struct PriorityMatrixService {
+    repo: Arc<dyn PriorityMatrixRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    conversation_tag_repo: Arc<dyn ConversationTagRepository>,
+    tag_repo: TagRepository,
+    priority_service: ConversationPriorityService,
+}
+
+impl PriorityMatrixService {
+    pub fn new(
+        repo: Arc<dyn PriorityMatrixRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+        conversation_tag_repo: Arc<dyn ConversationTagRepository>,
+        tag_repo: TagRepository,
+        priority_service: ConversationPriorityService,
+    ) -> Self {
+        Self {
+            repo,
+            conversation_repo,
+            conversation_tag_repo,
+            tag_repo,
+            priority_service,
+        }
+    }
+
+    pub async fn list_entries(
+        &self,
+        auth_user: &AuthenticatedUser,
+    ) -> PriorityMatrixResult<Vec<PriorityMatrixEntry>> {
+        require_admin(auth_user)?;
+        Ok(self.repo.list_priority_matrix_entries().await?)
+    }
+
+    pub async fn create_entry(
+        &self,
+        auth_user: &AuthenticatedUser,
+        request: CreatePriorityMatrixEntryRequest,
+    ) -> PriorityMatrixResult<PriorityMatrixEntry> {
+        require_admin(auth_user)?;
+
+        let tier = request
+            .tier
+            .as_deref()
+            .map(normalize_tier)
+            .filter(|tier| !tier.is_empty());
+        if tier.is_none() && request.tag_id.is_none() {
+            return Err(PriorityMatrixError::Validation(
+                "Either tier or tag_id is required".to_string(),
+            ));
+        }
+        if let Some(tag_id) = &request.tag_id {
+            if self.tag_repo.get_tag_by_id(tag_id).await?.is_none() {
+                return Err(PriorityMatrixError::Validation(format!(
+                    "Tag {} not found",
+                    tag_id
+                )));
+            }
+        }
+
+        let existing = self.repo.list_priority_matrix_entries().await?;
+        if existing
+            .iter()
+            .any(|entry| entry.tier == tier && entry.tag_id == request.tag_id)
+        {
+            return Err(PriorityMatrixError::Conflict(
+                "An entry for this tier and tag already exists".to_string(),
+            ));
+        }
+
+        let entry = PriorityMatrixEntry::new(
+            tier,
+            request.tag_id,
+            request.priority,
+            auth_user.user.id.clone(),
+        );
+        self.repo.create_priority_matrix_entry(&entry).await?;
+        Ok(entry)
+    }
+
+    pub async fn delete_entry(
+        &self,
+        auth_user: &AuthenticatedUser,
+        id: &str,
+    ) -> PriorityMatrixResult<()> {
+        require_admin(auth_user)?;
+        if !self.repo.delete_priority_matrix_entry(id).await? {
+            return Err(PriorityMatrixError::NotFound(
+                "Priority matrix entry not found".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Set or clear the tier of a contact (by the contact's user ID)
+    pub async fn set_contact_tier(
+        &self,
+        auth_user: &AuthenticatedUser,
+        contact_id: &str,
+        request: SetContactTierRequest,
+    ) -> PriorityMatrixResult<ContactTierResponse> {
+        require_admin(auth_user)?;
+        let tier = request
+            .tier
+            .as_deref()
+            .map(normalize_tier)
+            .filter(|tier| !tier.is_empty());
+
+        if !self
+            .repo
+            .set_contact_tier(contact_id, tier.as_deref())
+            .await?
+        {
+            return Err(PriorityMatrixError::NotFound(
+                "Contact not found".to_string(),
+            ));
+        }
+        Ok(ContactTierResponse {
+            contact_id: contact_id.to_string(),
+            tier,
+        })
+    }
+
+    pub async fn get_contact_tier(&self, contact_id: &str) -> PriorityMatrixResult<Option<String>> {
+        Ok(self.repo.get_contact_tier(contact_id).await?)
+    }
+
+    /// Raise the conversation's priority to what the matrix says, if higher
+    ///
+    /// Returns the priority that was applied, if any.
+    pub async fn apply_to_conversation(
+        &self,
+        conversation_id: &str,
+    ) -> PriorityMatrixResult<Option<Priority>> {
+        let entries = self.repo.list_priority_matrix_entries().await?;
+        if entries.is_empty() {
+            return Ok(None);
+        }
+
+        let conversation = self
+            .conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| {
+                PriorityMatrixError::NotFound(format!("Conversation {} not found", conversation_id))
+            })?;
+        let tier = self
+            .repo
+            .get_conversation_contact_tier(conversation_id)
+            .await?;
+        let tag_ids: Vec<String> = self
+            .conversation_tag_repo
+            .get_conversation_tags(conversation_id)
+            .await?
+            .into_iter()
+            .map(|tag| tag.id)
+            .collect();
+
+        let Some(priority) = resolve_matrix_priority(&entries, tier.as_deref(), &tag_ids) else {
+            return Ok(None);
+        };
+        if conversation.priority >= Some(priority) {
+            return Ok(None);
+        }
+
+        self.priority_service
+            .update_conversation_priority(conversation_id, Some(priority), "system")
+            .await
+            .map_err(|e| match e {
+                PriorityError::NotFound(msg) => PriorityMatrixError::NotFound(msg),
+                PriorityError::Repository(err) => PriorityMatrixError::Repository(err),
+            })?;
+        tracing::info!(
+            "Priority matrix set conversation {} to {} (tier: {:?})",
+            conversation_id,
+            priority,
+            tier
+        );
+        Ok(Some(priority))
+    }
+}
+
+fn require_admin(auth_user: &AuthenticatedUser) -> PriorityMatrixResult<()> {
+    if !auth_user.is_admin() {
+        return Err(PriorityMatrixError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+    Ok(())
+}
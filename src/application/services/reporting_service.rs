@@ -0,0 +1,152 @@
+use rand::distributions::Alphanumeric;
+use rand::{thread_rng, Rng};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+use crate::{
+    domain::entities::{
+        CreateReportingTokenRequest, CreateReportingTokenResponse,
+        ReportingConversationListResponse, ReportingSummary, ReportingToken,
+        REPORTING_TOKEN_PREFIX,
+    },
+    domain::errors::{ReportingError, ReportingResult},
+    domain::ports::reporting_repository::ReportingRepository,
+    infrastructure::http::middleware::AuthenticatedUser,
+};
+
+/// Random characters after the `oxr_` prefix
+const REPORTING_TOKEN_LENGTH: usize = 40;
+
+const MAX_CONVERSATIONS_PAGE: i64 = 500;
+
+/// Service for read-only reporting tokens and the data they can pull
+///
+/// Tokens are issued and revoked by admins. Only a SHA-256 hash is stored,
+/// so a lost token can't be recovered, only replaced.
+#[derive(Clone)]
+pub struct ReportingService {
+    repo: Arc<dyn ReportingRepository>,
+}
+
+impl ReportingService {
+    pub fn new(repo: Arc<dyn ReportingRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn create_token(
+        &self,
+        auth_user: &AuthenticatedUser,
+        request: CreateReportingTokenRequest,
+    ) -> ReportingResult<CreateReportingTokenResponse> {
+        ensure_admin(auth_user)?;
+
+        let plain_token = generate_reporting_token();
+        let token = ReportingToken::new(
+            request.name,
+            &plain_token,
+            hash_reporting_token(&plain_token),
+            auth_user.user.id.clone(),
+        );
+        self.repo.create_reporting_token(&token).await?;
+
+        Ok(CreateReportingTokenResponse { token, plain_token })
+    }
+
+    pub async fn list_tokens(
+        &self,
+        auth_user: &AuthenticatedUser,
+    ) -> ReportingResult<Vec<ReportingToken>> {
+        ensure_admin(auth_user)?;
+        Ok(self.repo.list_reporting_tokens().await?)
+    }
+
+    pub async fn revoke_token(
+        &self,
+        auth_user: &AuthenticatedUser,
+        id: &str,
+    ) -> ReportingResult<()> {
+        ensure_admin(auth_user)?;
+
+        self.repo
+            .get_reporting_token(id)
+            .await?
+            .ok_or_else(|| ReportingError::NotFound(format!("Reporting token {} not found", id)))?;
+        self.repo
+            .revoke_reporting_token(id, &chrono::Utc::now().to_rfc3339())
+            .await?;
+
+        Ok(())
+    }
+
+    /// Resolve a presented token; `None` for unknown or revoked tokens
+    pub async fn authenticate(&self, plain_token: &str) -> ReportingResult<Option<ReportingToken>> {
+        if !plain_token.starts_with(REPORTING_TOKEN_PREFIX) {
+            return Ok(None);
+        }
+
+        let token = match self
+            .repo
+            .get_reporting_token_by_hash(&hash_reporting_token(plain_token))
+            .await?
+        {
+            Some(token) if !token.is_revoked() => token,
+            _ => return Ok(None),
+        };
+
+        if let Err(e) = self
+            .repo
+            .touch_reporting_token(&token.id, &chrono::Utc::now().to_rfc3339())
+            .await
+        {
+            tracing::warn!("Failed to update reporting token last_used_at: {}", e);
+        }
+
+        Ok(Some(token))
+    }
+
+    pub async fn summary(&self, since: Option<&str>) -> ReportingResult<ReportingSummary> {
+        Ok(self.repo.reporting_summary(since).await?)
+    }
+
+    pub async fn list_conversations(
+        &self,
+        updated_since: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> ReportingResult<ReportingConversationListResponse> {
+        let limit = limit.clamp(1, MAX_CONVERSATIONS_PAGE);
+        let offset = offset.max(0);
+        let conversations = self
+            .repo
+            .list_reporting_conversations(updated_since, limit, offset)
+            .await?;
+
+        Ok(ReportingConversationListResponse {
+            conversations,
+            limit,
+            offset,
+        })
+    }
+}
+
+fn ensure_admin(auth_user: &AuthenticatedUser) -> ReportingResult<()> {
+    if auth_user.is_admin() {
+        return Ok(());
+    }
+    Err(ReportingError::Forbidden(
+        "Administrator role required".to_string(),
+    ))
+}
+
+fn generate_reporting_token() -> String {
+    let random: String = thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(REPORTING_TOKEN_LENGTH)
+        .map(char::from)
+        .collect();
+    format!("{}{}", REPORTING_TOKEN_PREFIX, random)
+}
+
+fn hash_reporting_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
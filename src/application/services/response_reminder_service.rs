@@ -0,0 +1,155 @@
+use crate::{
+    application::services::NotificationService,
+    domain::entities::{
+        ResponseReminderSettings, UpdateResponseReminderSettingsRequest, UserNotification,
+    },
+    domain::errors::{ResponseReminderError, ResponseReminderResult},
+    domain::ports::agent_repository::AgentRepository,
+    domain::ports::notification_repository::NotificationRepository,
+    domain::ports::response_reminder_repository::ResponseReminderRepository,
+    infrastructure::providers::connection_manager::ConnectionManager,
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+/// Job type of the recurring reminder check run by the job processor
+pub const CHECK_RESPONSE_REMINDERS_JOB: &str = "check_response_reminders";
+
+/// Service for per-agent reminders about assigned conversations waiting for a reply
+#[derive(Clone)]
+pub struct ResponseReminderService {
+    reminder_repo: Arc<dyn ResponseReminderRepository>,
+    agent_repo: Arc<dyn AgentRepository>,
+    notification_repo: Arc<dyn NotificationRepository>,
+    connection_manager: Arc<dyn ConnectionManager>,
+}
+
+impl ResponseReminderService {
+    pub fn new(
+        reminder_repo: Arc<dyn ResponseReminderRepository>,
+        agent_repo: Arc<dyn AgentRepository>,
+        notification_repo: Arc<dyn NotificationRepository>,
+        connection_manager: Arc<dyn ConnectionManager>,
+    ) -> Self {
+        Self {
+            reminder_repo,
+            agent_repo,
+            notification_repo,
+            connection_manager,
+        }
+    }
+
+    async fn agent_user_id(&self, agent_id: &str) -> ResponseReminderResult<String> {
+        let agent = self
+            .agent_repo
+            .get_agent_by_id(agent_id)
+            .await?
+            .ok_or_else(|| {
+                ResponseReminderError::NotFound(format!("Agent {} not found", agent_id))
+            })?;
+        Ok(agent.user_id)
+    }
+
+    /// Get an agent's reminder settings; agents who never opted in get disabled defaults
+    pub async fn get_settings(
+        &self,
+        agent_id: &str,
+    ) -> ResponseReminderResult<ResponseReminderSettings> {
+        let user_id = self.agent_user_id(agent_id).await?;
+        Ok(self
+            .reminder_repo
+            .get_response_reminder_settings(&user_id)
+            .await?
+            .unwrap_or_else(|| ResponseReminderSettings::disabled(user_id)))
+    }
+
+    /// Replace an agent's reminder settings
+    pub async fn update_settings(
+        &self,
+        agent_id: &str,
+        request: UpdateResponseReminderSettingsRequest,
+    ) -> ResponseReminderResult<ResponseReminderSettings> {
+        let user_id = self.agent_user_id(agent_id).await?;
+        let settings = ResponseReminderSettings {
+            user_id,
+            enabled: request.enabled,
+            remind_after_minutes: request.remind_after_minutes,
+            repeat_after_minutes: request.repeat_after_minutes,
+            updated_at: Utc::now().to_rfc3339(),
+        };
+        self.reminder_repo
+            .upsert_response_reminder_settings(&settings)
+            .await?;
+
+        tracing::info!(
+            "Response reminders for user {} set to enabled={} after={}m repeat={:?}m",
+            settings.user_id,
+            settings.enabled,
+            settings.remind_after_minutes,
+            settings.repeat_after_minutes
+        );
+
+        Ok(settings)
+    }
+
+    /// Notify assignees whose conversations have waited past their threshold
+    ///
+    /// Returns the number of reminders sent. A failed reminder is logged and
+    /// retried on the next run.
+    pub async fn send_due_reminders(&self, now: DateTime<Utc>) -> ResponseReminderResult<usize> {
+        let candidates = self
+            .reminder_repo
+            .list_response_reminder_candidates()
+            .await?;
+
+        let mut sent = 0;
+        for candidate in candidates.iter().filter(|c| c.is_due(now)) {
+            let notification = UserNotification::new_reminder(
+                candidate.user_id.clone(),
+                candidate.conversation_id.clone(),
+                candidate.message_id.clone(),
+            );
+            if let Err(e) = self
+                .notification_repo
+                .create_notification(&notification)
+                .await
+            {
+                tracing::error!(
+                    "Failed to create response reminder for conversation {}: {}",
+                    candidate.conversation_id,
+                    e
+                );
+                continue;
+            }
+            self.reminder_repo
+                .record_response_reminder(
+                    &candidate.conversation_id,
+                    &candidate.user_id,
+                    &candidate.message_id,
+                    &now.to_rfc3339(),
+                )
+                .await?;
+            sent += 1;
+
+            // Real-time delivery is best-effort; the notification is already stored
+            let connection_manager = self.connection_manager.clone();
+            tokio::spawn(async move {
+                if let Err(e) = NotificationService::send_realtime_notification(
+                    &notification,
+                    &connection_manager,
+                )
+                .await
+                {
+                    // Expected when the agent is not connected
+                    tracing::debug!("Failed to send real-time reminder: {}", e);
+                }
+            });
+        }
+
+        if sent > 0 {
+            tracing::info!("Sent {} response due reminder(s)", sent);
+        }
+
+        Ok(sent)
+    }
+}
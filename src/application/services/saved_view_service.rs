@@ -0,0 +1,124 @@
+use crate::{
+    domain::entities::{
+        compile_conversation_query, ConversationCondition, ConversationQueryContext,
+        CreateSavedViewRequest, SavedView, UpdateSavedViewRequest,
+    },
+    domain::errors::{SavedViewError, SavedViewResult},
+    domain::ports::saved_view_repository::SavedViewRepository,
+    infrastructure::http::middleware::AuthenticatedUser,
+};
+use std::sync::Arc;
+
+/// Service for agents' saved conversation views
+///
+/// Views are personal: only their owner can see, use, change or delete them.
+#[derive(Clone)]
+pub struct SavedViewService {
+    view_repo: Arc<dyn SavedViewRepository>,
+}
+
+impl SavedViewService {
+    pub fn new(view_repo: Arc<dyn SavedViewRepository>) -> Self {
+        Self { view_repo }
+    }
+
+    pub async fn list_views(
+        &self,
+        auth_user: &AuthenticatedUser,
+    ) -> SavedViewResult<Vec<SavedView>> {
+        Ok(self.view_repo.list_saved_views(&auth_user.user.id).await?)
+    }
+
+    pub async fn get_view(
+        &self,
+        auth_user: &AuthenticatedUser,
+        view_id: &str,
+    ) -> SavedViewResult<SavedView> {
+        self.view_repo
+            .get_saved_view(view_id)
+            .await?
+            .filter(|view| view.user_id == auth_user.user.id)
+            .ok_or_else(|| SavedViewError::NotFound(format!("View {} not found", view_id)))
+    }
+
+    pub async fn create_view(
+        &self,
+        auth_user: &AuthenticatedUser,
+        request: CreateSavedViewRequest,
+    ) -> SavedViewResult<SavedView> {
+        let view = SavedView::new(auth_user.user.id.clone(), request.name, request.query);
+        compile_conversation_query(&view.query, &ConversationQueryContext::new(&view.user_id))?;
+        self.ensure_name_available(&view.user_id, &view.name, None)
+            .await?;
+
+        self.view_repo.create_saved_view(&view).await?;
+        Ok(view)
+    }
+
+    pub async fn update_view(
+        &self,
+        auth_user: &AuthenticatedUser,
+        view_id: &str,
+        request: UpdateSavedViewRequest,
+    ) -> SavedViewResult<SavedView> {
+        let mut view = self.get_view(auth_user, view_id).await?;
+
+        if let Some(query) = request.query {
+            let query = query.trim().to_string();
+            compile_conversation_query(&query, &ConversationQueryContext::new(&view.user_id))?;
+            view.query = query;
+        }
+        if let Some(name) = request.name {
+            let name = name.trim().to_string();
+            self.ensure_name_available(&view.user_id, &name, Some(&view.id))
+                .await?;
+            view.name = name;
+        }
+        view.updated_at = chrono::Utc::now().to_rfc3339();
+
+        self.view_repo.update_saved_view(&view).await?;
+        Ok(view)
+    }
+
+    pub async fn delete_view(
+        &self,
+        auth_user: &AuthenticatedUser,
+        view_id: &str,
+    ) -> SavedViewResult<()> {
+        let view = self.get_view(auth_user, view_id).await?;
+        Ok(self.view_repo.delete_saved_view(&view.id).await?)
+    }
+
+    /// Compile a view's query for the current user and time
+    pub async fn view_conditions(
+        &self,
+        auth_user: &AuthenticatedUser,
+        view_id: &str,
+    ) -> SavedViewResult<Vec<ConversationCondition>> {
+        let view = self.get_view(auth_user, view_id).await?;
+        Ok(compile_conversation_query(
+            &view.query,
+            &ConversationQueryContext::new(&auth_user.user.id),
+        )?)
+    }
+
+    async fn ensure_name_available(
+        &self,
+        user_id: &str,
+        name: &str,
+        except_id: Option<&str>,
+    ) -> SavedViewResult<()> {
+        let taken = self
+            .view_repo
+            .list_saved_views(user_id)
+            .await?
+            .iter()
+            .any(|view| {
+                view.name.eq_ignore_ascii_case(name) && Some(view.id.as_str()) != except_id
+            });
+        if taken {
+            return Err(SavedViewError::AlreadyExists(name.to_string()));
+        }
+        Ok(())
+    }
+}
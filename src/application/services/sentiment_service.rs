@@ -0,0 +1,132 @@
+use crate::{
+    domain::entities::{
+        ConversationSentiment, ConversationSentimentResponse, Message, MessageSentiment,
+        MessageType,
+    },
+    domain::errors::{SentimentError, SentimentResult},
+    domain::events::SystemEvent,
+    domain::ports::conversation_repository::ConversationRepository,
+    domain::ports::event_bus::EventBus,
+    domain::ports::sentiment_analyzer::SentimentAnalyzer,
+    domain::ports::sentiment_repository::SentimentRepository,
+    domain::services::{is_sharp_drop, roll_sentiment, sentiment_trend},
+};
+use std::sync::Arc;
+
+/// Service for scoring inbound messages and tracking conversation sentiment
+#[derive(Clone)]
+pub struct SentimentService {
+    sentiment_repo: Arc<dyn SentimentRepository>,
+    conversation_repo: Arc<dyn ConversationRepository>,
+    analyzer: Arc<dyn SentimentAnalyzer>,
+    event_bus: Arc<dyn EventBus>,
+}
+
+impl SentimentService {
+    pub fn new(
+        sentiment_repo: Arc<dyn SentimentRepository>,
+        conversation_repo: Arc<dyn ConversationRepository>,
+        analyzer: Arc<dyn SentimentAnalyzer>,
+        event_bus: Arc<dyn EventBus>,
+    ) -> Self {
+        Self {
+            sentiment_repo,
+            conversation_repo,
+            analyzer,
+            event_bus,
+        }
+    }
+
+    /// Score an inbound message and roll it into the conversation's sentiment
+    ///
+    /// Publishes `SentimentDropped` when the rolling score falls sharply.
+    /// Outgoing messages are ignored and return `None`.
+    pub async fn record_incoming_message(
+        &self,
+        message: &Message,
+    ) -> SentimentResult<Option<ConversationSentiment>> {
+        if message.message_type != MessageType::Incoming {
+            return Ok(None);
+        }
+
+        let conversation = self
+            .conversation_repo
+            .get_conversation_by_id(&message.conversation_id)
+            .await?
+            .ok_or_else(|| {
+                SentimentError::NotFound(format!(
+                    "Conversation {} not found",
+                    message.conversation_id
+                ))
+            })?;
+
+        let score = self.analyzer.score(&message.content).await;
+        let message_sentiment = MessageSentiment::new(
+            message.id.clone(),
+            message.conversation_id.clone(),
+            score,
+        );
+        self.sentiment_repo
+            .create_message_sentiment(&message_sentiment)
+            .await?;
+
+        let previous_score = conversation.sentiment_score;
+        let current_score = roll_sentiment(previous_score, message_sentiment.score);
+        let trend = sentiment_trend(previous_score, current_score);
+        self.sentiment_repo
+            .update_conversation_sentiment(&conversation.id, current_score, trend)
+            .await?;
+
+        tracing::debug!(
+            "Sentiment recorded: conversation_id={}, message_score={:.2}, conversation_score={:.2}, trend={}",
+            conversation.id,
+            message_sentiment.score,
+            current_score,
+            trend
+        );
+
+        if is_sharp_drop(previous_score, current_score) {
+            if let Err(e) = self.event_bus.publish(SystemEvent::SentimentDropped {
+                conversation_id: conversation.id.clone(),
+                message_id: message.id.clone(),
+                previous_score: previous_score.unwrap_or_default(),
+                current_score,
+                timestamp: message_sentiment.created_at.clone(),
+            }) {
+                tracing::error!("Failed to publish SentimentDropped event: {}", e);
+            }
+        }
+
+        Ok(Some(ConversationSentiment {
+            conversation_id: conversation.id,
+            score: current_score,
+            trend,
+            previous_score,
+        }))
+    }
+
+    /// Conversation sentiment with per-message scores, oldest first
+    pub async fn get_conversation_sentiment(
+        &self,
+        conversation_id: &str,
+    ) -> SentimentResult<ConversationSentimentResponse> {
+        let conversation = self
+            .conversation_repo
+            .get_conversation_by_id(conversation_id)
+            .await?
+            .ok_or_else(|| {
+                SentimentError::NotFound(format!("Conversation {} not found", conversation_id))
+            })?;
+        let messages = self
+            .sentiment_repo
+            .list_message_sentiments(conversation_id)
+            .await?;
+
+        Ok(ConversationSentimentResponse {
+            conversation_id: conversation.id,
+            score: conversation.sentiment_score,
+            trend: conversation.sentiment_trend,
+            messages,
+        })
+    }
+}
@@ -0,0 +1,344 @@
+use crate::{
+    application::services::AvailabilityService,
+    domain::entities::{
+        AgentAvailability, AgentShift, BusinessHours, CreateShiftRequest, DayCoverage,
+        ShiftCoverageReport, Team, TimeRange, UpdateShiftRequest,
+    },
+    domain::errors::{ShiftError, ShiftResult},
+    domain::ports::shift_repository::ShiftRepository,
+    domain::ports::team_repository::TeamRepository,
+    domain::services::{
+        coverage_gaps, format_shift_time, normalize_weekday, parse_shift_time, weekday_name,
+        MINUTES_PER_DAY, WEEKDAYS,
+    },
+};
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use chrono_tz::Tz;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+/// Service for agent shift schedules, coverage reports and shift-driven availability
+#[derive(Clone)]
+pub struct ShiftService {
+    shift_repo: Arc<dyn ShiftRepository>,
+    team_repo: Arc<dyn TeamRepository>,
+    availability_service: AvailabilityService,
+}
+
+impl ShiftService {
+    pub fn new(
+        shift_repo: Arc<dyn ShiftRepository>,
+        team_repo: Arc<dyn TeamRepository>,
+        availability_service: AvailabilityService,
+    ) -> Self {
+        Self {
+            shift_repo,
+            team_repo,
+            availability_service,
+        }
+    }
+
+    async fn get_team(&self, team_id: &str) -> ShiftResult<Team> {
+        self.team_repo
+            .get_team_by_id(team_id)
+            .await?
+            .ok_or_else(|| ShiftError::NotFound(format!("Team {} not found", team_id)))
+    }
+
+    /// Add a shift for a team member
+    pub async fn create_shift(
+        &self,
+        team_id: &str,
+        request: CreateShiftRequest,
+    ) -> ShiftResult<AgentShift> {
+        self.get_team(team_id).await?;
+
+        if !self
+            .team_repo
+            .is_team_member(team_id, &request.user_id)
+            .await?
+        {
+            return Err(ShiftError::Validation(format!(
+                "User {} is not a member of team {}",
+                request.user_id, team_id
+            )));
+        }
+
+        let (day, start, end) =
+            validate_shift(&request.day, &request.start_time, &request.end_time)?;
+        let shift = AgentShift::new(
+            team_id.to_string(),
+            request.user_id,
+            day,
+            format_shift_time(start),
+            format_shift_time(end),
+        );
+        self.check_overlap(&shift).await?;
+
+        self.shift_repo.create_shift(&shift).await?;
+
+        tracing::info!(
+            "Shift created: id={} team={} user={}",
+            shift.id,
+            shift.team_id,
+            shift.user_id
+        );
+        Ok(shift)
+    }
+
+    /// List a team's shifts in weekday and start time order
+    pub async fn list_shifts(&self, team_id: &str) -> ShiftResult<Vec<AgentShift>> {
+        self.get_team(team_id).await?;
+
+        let mut shifts = self.shift_repo.list_team_shifts(team_id).await?;
+        shifts.sort_by(|a, b| {
+            (day_index(&a.day), &a.start_time).cmp(&(day_index(&b.day), &b.start_time))
+        });
+        Ok(shifts)
+    }
+
+    pub async fn get_shift(&self, team_id: &str, id: &str) -> ShiftResult<AgentShift> {
+        self.shift_repo
+            .get_shift(id)
+            .await?
+            .filter(|shift| shift.team_id == team_id)
+            .ok_or_else(|| ShiftError::NotFound(format!("Shift {} not found", id)))
+    }
+
+    pub async fn update_shift(
+        &self,
+        team_id: &str,
+        id: &str,
+        request: UpdateShiftRequest,
+    ) -> ShiftResult<AgentShift> {
+        let mut shift = self.get_shift(team_id, id).await?;
+
+        let (day, start, end) = validate_shift(
+            request.day.as_deref().unwrap_or(&shift.day),
+            request.start_time.as_deref().unwrap_or(&shift.start_time),
+            request.end_time.as_deref().unwrap_or(&shift.end_time),
+        )?;
+        shift.day = day;
+        shift.start_time = format_shift_time(start);
+        shift.end_time = format_shift_time(end);
+        self.check_overlap(&shift).await?;
+
+        shift.updated_at = Utc::now().to_rfc3339();
+        self.shift_repo.update_shift(&shift).await?;
+
+        Ok(shift)
+    }
+
+    pub async fn delete_shift(&self, team_id: &str, id: &str) -> ShiftResult<()> {
+        let shift = self.get_shift(team_id, id).await?;
+        Ok(self.shift_repo.delete_shift(&shift.id).await?)
+    }
+
+    /// Report the parts of each weekday's required hours with no agent scheduled
+    ///
+    /// Required hours are the team's business hours; teams without business
+    /// hours need coverage around the clock.
+    pub async fn coverage_report(&self, team_id: &str) -> ShiftResult<ShiftCoverageReport> {
+        let team = self.get_team(team_id).await?;
+        let business_hours = parse_business_hours(&team)?;
+        let shifts = self.shift_repo.list_team_shifts(team_id).await?;
+
+        let mut days = Vec::new();
+        for day in WEEKDAYS {
+            let required = match &business_hours {
+                Some(hours) => match hours.schedule.iter().find(|s| s.day == day) {
+                    Some(schedule) => Some((
+                        parse_shift_time(&schedule.start).map_err(ShiftError::Validation)?,
+                        parse_shift_time(&schedule.end).map_err(ShiftError::Validation)?,
+                    )),
+                    None => None,
+                },
+                None => Some((0, MINUTES_PER_DAY)),
+            };
+
+            let day_shifts: Vec<&AgentShift> = shifts.iter().filter(|s| s.day == day).collect();
+            let ranges: Vec<(u32, u32)> =
+                day_shifts.iter().filter_map(|s| shift_range(s)).collect();
+            let scheduled_agents: BTreeSet<String> =
+                day_shifts.iter().map(|s| s.user_id.clone()).collect();
+
+            let gaps = required
+                .map(|window| coverage_gaps(window, &ranges))
+                .unwrap_or_default();
+
+            days.push(DayCoverage {
+                day: day.to_string(),
+                required: required.map(|(start, end)| to_time_range(start, end)),
+                gaps: gaps
+                    .into_iter()
+                    .map(|(start, end)| to_time_range(start, end))
+                    .collect(),
+                scheduled_agents: scheduled_agents.into_iter().collect(),
+            });
+        }
+
+        let has_gaps = days.iter().any(|day| !day.gaps.is_empty());
+        Ok(ShiftCoverageReport {
+            team_id: team.id,
+            timezone: business_hours
+                .map(|hours| hours.timezone)
+                .unwrap_or_else(|| "UTC".to_string()),
+            days,
+            has_gaps,
+        })
+    }
+
+    /// Move agents online when a shift starts and offline when their last shift ends
+    ///
+    /// Only boundaries are acted on: the on-shift state seen on the previous run is
+    /// stored, and an agent's first observation is recorded without a change.
+    /// Returns the user IDs whose availability was changed.
+    pub async fn apply_shift_transitions(&self, now: DateTime<Utc>) -> ShiftResult<Vec<String>> {
+        let shifts = self.shift_repo.list_all_shifts().await?;
+        if shifts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let timezones: HashMap<String, Tz> = self
+            .team_repo
+            .list_teams()
+            .await?
+            .iter()
+            .map(|team| (team.id.clone(), team_timezone(team)))
+            .collect();
+
+        let mut on_shift_by_user: HashMap<String, bool> = HashMap::new();
+        for shift in &shifts {
+            let tz = timezones.get(&shift.team_id).copied().unwrap_or(Tz::UTC);
+            let active = is_shift_active(shift, now, tz);
+            *on_shift_by_user.entry(shift.user_id.clone()).or_default() |= active;
+        }
+
+        let mut changed = Vec::new();
+        for (user_id, on_shift) in on_shift_by_user {
+            let previous = self.shift_repo.get_shift_state(&user_id).await?;
+            if previous == Some(on_shift) {
+                continue;
+            }
+            self.shift_repo.set_shift_state(&user_id, on_shift).await?;
+            if previous.is_none() {
+                continue;
+            }
+
+            let status = match self.availability_service.get_availability(&user_id).await {
+                Ok(availability) => availability.availability_status,
+                Err(e) => {
+                    tracing::warn!("Skipping shift transition for {}: {}", user_id, e);
+                    continue;
+                }
+            };
+
+            let (target, reason) = if on_shift {
+                (AgentAvailability::Online, "shift_start")
+            } else {
+                (AgentAvailability::Offline, "shift_end")
+            };
+            let should_change = if on_shift {
+                status == AgentAvailability::Offline
+            } else {
+                status != AgentAvailability::Offline
+            };
+
+            if should_change {
+                self.availability_service
+                    .set_scheduled_availability(&user_id, target, reason)
+                    .await?;
+                changed.push(user_id);
+            }
+        }
+
+        Ok(changed)
+    }
+
+    /// Reject shifts overlapping another shift of the same agent on the same day
+    async fn check_overlap(&self, shift: &AgentShift) -> ShiftResult<()> {
+        let Some((start, end)) = shift_range(shift) else {
+            return Ok(());
+        };
+
+        let existing = self.shift_repo.list_team_shifts(&shift.team_id).await?;
+        let overlapping = existing
+            .iter()
+            .filter(|other| {
+                other.id != shift.id && other.user_id == shift.user_id && other.day == shift.day
+            })
+            .filter_map(shift_range)
+            .any(|(other_start, other_end)| start < other_end && other_start < end);
+
+        if overlapping {
+            return Err(ShiftError::Validation(
+                "Shift overlaps an existing shift for this agent".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Validate a shift's day and times, returning the canonical day and minute range
+fn validate_shift(day: &str, start: &str, end: &str) -> ShiftResult<(String, u32, u32)> {
+    let day = normalize_weekday(day).map_err(ShiftError::Validation)?;
+    let start = parse_shift_time(start).map_err(ShiftError::Validation)?;
+    let end = parse_shift_time(end).map_err(ShiftError::Validation)?;
+
+    if end <= start {
+        return Err(ShiftError::Validation(
+            "Shift end_time must be after start_time".to_string(),
+        ));
+    }
+
+    Ok((day, start, end))
+}
+
+fn shift_range(shift: &AgentShift) -> Option<(u32, u32)> {
+    Some((
+        parse_shift_time(&shift.start_time).ok()?,
+        parse_shift_time(&shift.end_time).ok()?,
+    ))
+}
+
+fn to_time_range(start: u32, end: u32) -> TimeRange {
+    TimeRange {
+        start: format_shift_time(start),
+        end: format_shift_time(end),
+    }
+}
+
+fn day_index(day: &str) -> usize {
+    WEEKDAYS
+        .iter()
+        .position(|d| *d == day)
+        .unwrap_or(WEEKDAYS.len())
+}
+
+fn parse_business_hours(team: &Team) -> ShiftResult<Option<BusinessHours>> {
+    team.business_hours
+        .as_deref()
+        .map(|json| BusinessHours::parse(json).map_err(ShiftError::Validation))
+        .transpose()
+}
+
+/// Timezone shift times of a team are expressed in (UTC without business hours)
+fn team_timezone(team: &Team) -> Tz {
+    team.business_hours
+        .as_deref()
+        .and_then(|json| BusinessHours::parse(json).ok())
+        .and_then(|hours| hours.timezone.parse().ok())
+        .unwrap_or(Tz::UTC)
+}
+
+fn is_shift_active(shift: &AgentShift, now: DateTime<Utc>, tz: Tz) -> bool {
+    let local = now.with_timezone(&tz);
+    if weekday_name(local.weekday()) != shift.day {
+        return false;
+    }
+
+    let minute = local.hour() * 60 + local.minute();
+    shift_range(shift)
+        .map(|(start, end)| start <= minute && minute < end)
+        .unwrap_or(false)
+}
@@ -134,6 +134,66 @@ impl SlaService {
         Ok(())
     }
 
+    /// Replay the conversations created in `[since, until)` against draft
+    /// policy targets to estimate how many would have breached
+    ///
+    /// Conversations are grouped under the team they are assigned to now.
+    #[tracing::instrument(skip(self))]
+    pub async fn simulate_policy(
+        &self,
+        first_response_time: &str,
+        resolution_time: &str,
+        next_response_time: &str,
+        since: chrono::DateTime<chrono::Utc>,
+        until: chrono::DateTime<chrono::Utc>,
+    ) -> ApiResult<SlaSimulation> {
+        use crate::domain::services::{simulate_sla_policy, SlaTargets, WorkingCalendar};
+
+        let targets = SlaTargets {
+            first_response: parse_duration(first_response_time)
+                .map_err(|e| ApiError::BadRequest(format!("Invalid first_response_time: {}", e)))?,
+            resolution: parse_duration(resolution_time)
+                .map_err(|e| ApiError::BadRequest(format!("Invalid resolution_time: {}", e)))?,
+            next_response: parse_duration(next_response_time)
+                .map_err(|e| ApiError::BadRequest(format!("Invalid next_response_time: {}", e)))?,
+        };
+
+        let holidays = self.sla_repo.list_holidays().await?;
+        let mut calendars = std::collections::HashMap::new();
+        for team in self.team_repo.list_teams().await? {
+            let Some(bh_json) = &team.business_hours else {
+                continue;
+            };
+            // Teams with unusable business hours fall back to 24/7, as live SLAs do
+            let calendar = crate::domain::entities::team::BusinessHours::parse(bh_json)
+                .and_then(|bh| {
+                    WorkingCalendar::new(&bh, team.holiday_calendar_id.as_deref(), &holidays)
+                });
+            match calendar {
+                Ok(calendar) => {
+                    calendars.insert(team.id, calendar);
+                }
+                Err(e) => {
+                    info!("Simulating team {} around the clock: {}", team.id, e);
+                }
+            }
+        }
+
+        let conversations = self
+            .sla_repo
+            .list_sla_replay_conversations(&since.to_rfc3339(), &until.to_rfc3339())
+            .await?;
+
+        Ok(simulate_sla_policy(
+            &conversations,
+            &targets,
+            &calendars,
+            since,
+            until,
+            chrono::Utc::now(),
+        ))
+    }
+
     // ========================================
     // Applied SLA Management
     // ========================================
@@ -235,8 +295,10 @@ impl SlaService {
         }
 
         // Check if conversation is assigned to a team with business hours
+        let mut holiday_calendar_id = None;
         let business_hours = if let Some(team_id) = &conversation.assigned_team_id {
             if let Some(team) = self.team_repo.get_team_by_id(team_id).await? {
+                holiday_calendar_id = team.holiday_calendar_id.clone();
                 if let Some(bh_json) = &team.business_hours {
                     // Parse business hours JSON
                     match crate::domain::entities::team::BusinessHours::parse(bh_json) {
@@ -266,8 +328,13 @@ impl SlaService {
         let first_response_deadline = if let Some(ref bh) = business_hours {
             let duration_seconds = crate::parse_duration(&policy.first_response_time)
                 .map_err(|e| ApiError::BadRequest(format!("Invalid first_response_time: {}", e)))?;
-            self.calculate_deadline_with_business_hours(base_timestamp, duration_seconds, bh)
-                .await?
+            self.calculate_deadline_with_business_hours(
+                base_timestamp,
+                duration_seconds,
+                bh,
+                holiday_calendar_id.as_deref(),
+            )
+            .await?
         } else {
             self.calculate_deadline(base_timestamp, &policy.first_response_time)?
         };
@@ -275,8 +342,13 @@ impl SlaService {
         let resolution_deadline = if let Some(ref bh) = business_hours {
             let duration_seconds = crate::parse_duration(&policy.resolution_time)
                 .map_err(|e| ApiError::BadRequest(format!("Invalid resolution_time: {}", e)))?;
-            self.calculate_deadline_with_business_hours(base_timestamp, duration_seconds, bh)
-                .await?
+            self.calculate_deadline_with_business_hours(
+                base_timestamp,
+                duration_seconds,
+                bh,
+                holiday_calendar_id.as_deref(),
+            )
+            .await?
         } else {
             self.calculate_deadline(base_timestamp, &policy.resolution_time)?
         };
@@ -590,6 +662,7 @@ impl SlaService {
         base_time: &str,
         duration_seconds: i64,
         business_hours: &crate::domain::entities::team::BusinessHours,
+        holiday_calendar_id: Option<&str>,
     ) -> ApiResult<String> {
         use chrono_tz::Tz;
 
@@ -614,7 +687,7 @@ impl SlaService {
             let current_in_tz = current.with_timezone(&tz);
 
             if self
-                .is_working_hour(&current_in_tz, business_hours, &tz)
+                .is_working_hour(&current_in_tz, business_hours, holiday_calendar_id)
                 .await?
             {
                 // We're in working hours, advance by 1 minute
@@ -623,7 +696,7 @@ impl SlaService {
             } else {
                 // We're outside working hours, jump to next working hour
                 current = self
-                    .next_working_hour(&current_in_tz, business_hours, &tz)
+                    .next_working_hour(&current_in_tz, business_hours, holiday_calendar_id, &tz)
                     .await?
                     .with_timezone(&chrono::Utc);
             }
@@ -632,12 +705,48 @@ impl SlaService {
         Ok(current.to_rfc3339())
     }
 
+    /// Calculate a deadline in the business hours of the conversation's assigned team,
+    /// or on the wall clock when the team has none
+    pub async fn calculate_deadline_for_conversation(
+        &self,
+        conversation: &Conversation,
+        base_time: &str,
+        duration_seconds: i64,
+    ) -> ApiResult<String> {
+        if let Some(team_id) = &conversation.assigned_team_id {
+            if let Some(team) = self.team_repo.get_team_by_id(team_id).await? {
+                if let Some(bh_json) = &team.business_hours {
+                    match crate::domain::entities::team::BusinessHours::parse(bh_json) {
+                        Ok(bh) => {
+                            return self
+                                .calculate_deadline_with_business_hours(
+                                    base_time,
+                                    duration_seconds,
+                                    &bh,
+                                    team.holiday_calendar_id.as_deref(),
+                                )
+                                .await;
+                        }
+                        Err(e) => {
+                            info!("Invalid business hours format for team {}: {}. Using 24/7 calculation.", team_id, e);
+                        }
+                    }
+                }
+            }
+        }
+
+        let base = chrono::DateTime::parse_from_rfc3339(base_time)
+            .map_err(|e| ApiError::BadRequest(format!("Invalid base time: {}", e)))?;
+        Ok((base + chrono::Duration::seconds(duration_seconds)).to_rfc3339())
+    }
+
     /// Check if a datetime falls within business hours and is not a holiday
+    /// (global, or in the team's holiday calendar)
     async fn is_working_hour(
         &self,
         datetime: &chrono::DateTime<impl chrono::TimeZone>,
         business_hours: &crate::domain::entities::team::BusinessHours,
-        _tz: &chrono_tz::Tz,
+        holiday_calendar_id: Option<&str>,
     ) -> ApiResult<bool> {
         use chrono::Datelike;
 
@@ -648,7 +757,11 @@ impl SlaService {
             datetime.month(),
             datetime.day()
         );
-        if self.sla_repo.is_holiday(&date_str).await? {
+        if self
+            .sla_repo
+            .is_holiday(&date_str, holiday_calendar_id)
+            .await?
+        {
             return Ok(false); // Holidays are non-working days
         }
 
@@ -682,6 +795,7 @@ impl SlaService {
         &self,
         datetime: &chrono::DateTime<impl chrono::TimeZone>,
         business_hours: &crate::domain::entities::team::BusinessHours,
+        holiday_calendar_id: Option<&str>,
         tz: &chrono_tz::Tz,
     ) -> ApiResult<chrono::DateTime<chrono_tz::Tz>> {
         let mut current = datetime.with_timezone(tz);
@@ -690,7 +804,10 @@ impl SlaService {
         for _ in 0..14 * 24 * 60 {
             current = current + chrono::Duration::minutes(1);
 
-            if self.is_working_hour(&current, business_hours, tz).await? {
+            if self
+                .is_working_hour(&current, business_hours, holiday_calendar_id)
+                .await?
+            {
                 return Ok(current);
             }
         }
@@ -0,0 +1,218 @@
+use crate::{
+    application::services::ChannelService,
+    domain::entities::{
+        ChannelMessageRequest, ChannelMessageResponse, ChannelSender, CreateInboxSmsConfigRequest,
+        InboxSmsConfig, UpdateInboxSmsConfigRequest, SMS_CHANNEL, SMS_INBOX_CHANNEL_TYPE,
+    },
+    domain::errors::{SmsError, SmsResult},
+    domain::ports::inbox_repository::InboxRepository,
+    domain::ports::sms_config_repository::SmsConfigRepository,
+    domain::services::sms::verify_twilio_signature,
+};
+use std::sync::Arc;
+
+/// Service for Twilio SMS inboxes
+///
+/// Inbound texts arrive on a signed webhook and are ingested through
+/// `ChannelService`, with the sender's phone number as channel identity.
+/// Replies go out through `TwilioDeliveryProvider`.
+#[derive(Clone)]
+pub struct SmsService {
+    sms_repo: Arc<dyn SmsConfigRepository>,
+    inbox_repo: Arc<dyn InboxRepository>,
+    channel_service: ChannelService,
+    webhook_base_url: String,
+}
+
+impl SmsService {
+    pub fn new(
+        sms_repo: Arc<dyn SmsConfigRepository>,
+        inbox_repo: Arc<dyn InboxRepository>,
+        channel_service: ChannelService,
+    ) -> Self {
+        Self {
+            sms_repo,
+            inbox_repo,
+            channel_service,
+            webhook_base_url: "http://localhost:3000".to_string(),
+        }
+    }
+
+    /// Public base URL Twilio calls the webhook on; signatures cover the full URL
+    pub fn with_webhook_base_url(mut self, base_url: &str) -> Self {
+        self.webhook_base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// URL to configure as the number's "A message comes in" webhook
+    pub fn webhook_url(&self, inbox_id: &str) -> String {
+        format!("{}/webhooks/twilio/{}", self.webhook_base_url, inbox_id)
+    }
+
+    pub async fn get_config(&self, inbox_id: &str) -> SmsResult<InboxSmsConfig> {
+        self.sms_repo
+            .get_inbox_sms_config(inbox_id)
+            .await?
+            .ok_or_else(|| {
+                SmsError::NotFound(format!(
+                    "SMS configuration not found for inbox {}",
+                    inbox_id
+                ))
+            })
+    }
+
+    pub async fn create_config(
+        &self,
+        inbox_id: &str,
+        request: CreateInboxSmsConfigRequest,
+    ) -> SmsResult<InboxSmsConfig> {
+        let inbox = self
+            .inbox_repo
+            .get_inbox(inbox_id)
+            .await?
+            .ok_or_else(|| SmsError::NotFound(format!("Inbox {} not found", inbox_id)))?;
+        if inbox.channel_type != SMS_INBOX_CHANNEL_TYPE {
+            return Err(SmsError::Validation(format!(
+                "SMS can only be configured on {} inboxes",
+                SMS_INBOX_CHANNEL_TYPE
+            )));
+        }
+        if self
+            .sms_repo
+            .get_inbox_sms_config(inbox_id)
+            .await?
+            .is_some()
+        {
+            return Err(SmsError::Conflict(
+                "SMS configuration already exists for this inbox".to_string(),
+            ));
+        }
+
+        let config = InboxSmsConfig::new(inbox_id.to_string(), request);
+        config.validate().map_err(SmsError::Validation)?;
+        self.sms_repo.create_inbox_sms_config(&config).await?;
+
+        Ok(config)
+    }
+
+    pub async fn update_config(
+        &self,
+        inbox_id: &str,
+        request: UpdateInboxSmsConfigRequest,
+    ) -> SmsResult<InboxSmsConfig> {
+        let mut config = self.get_config(inbox_id).await?;
+
+        if let Some(account_sid) = request.account_sid {
+            config.account_sid = account_sid.trim().to_string();
+        }
+        if let Some(auth_token) = request.auth_token {
+            config.auth_token = auth_token.trim().to_string();
+        }
+        if let Some(phone_number) = request.phone_number {
+            config.phone_number = phone_number.trim().to_string();
+        }
+        if let Some(max_segments) = request.max_segments {
+            config.max_segments = max_segments;
+        }
+        if let Some(enabled) = request.enabled {
+            config.enabled = enabled;
+        }
+        config.validate().map_err(SmsError::Validation)?;
+        config.updated_at = chrono::Utc::now().to_rfc3339();
+
+        self.sms_repo.update_inbox_sms_config(&config).await?;
+
+        Ok(config)
+    }
+
+    pub async fn delete_config(&self, inbox_id: &str) -> SmsResult<()> {
+        self.get_config(inbox_id).await?;
+        self.sms_repo.delete_inbox_sms_config(inbox_id).await?;
+        Ok(())
+    }
+
+    /// Ingest a text delivered to the inbox's Twilio webhook
+    ///
+    /// `params` are the form parameters exactly as posted; they are checked
+    /// against `signature` (the `X-Twilio-Signature` header) before anything
+    /// is stored. Redelivered texts (same `MessageSid`) are ignored.
+    pub async fn receive_twilio_message(
+        &self,
+        inbox_id: &str,
+        params: &[(String, String)],
+        signature: Option<&str>,
+    ) -> SmsResult<ChannelMessageResponse> {
+        let config = self
+            .sms_repo
+            .get_inbox_sms_config(inbox_id)
+            .await?
+            .filter(|config| config.enabled)
+            .ok_or_else(|| {
+                SmsError::NotFound(format!("SMS is not enabled for inbox {}", inbox_id))
+            })?;
+
+        let url = self.webhook_url(inbox_id);
+        let signature = signature.ok_or(SmsError::InvalidSignature)?;
+        if !verify_twilio_signature(&url, params, signature, &config.auth_token) {
+            return Err(SmsError::InvalidSignature);
+        }
+        if param(params, "AccountSid") != Some(config.account_sid.as_str()) {
+            return Err(SmsError::InvalidSignature);
+        }
+
+        let from = param(params, "From")
+            .filter(|from| !from.is_empty())
+            .ok_or_else(|| SmsError::Validation("Missing From parameter".to_string()))?;
+
+        self.channel_service
+            .receive_message(
+                inbox_id,
+                ChannelMessageRequest {
+                    sender: ChannelSender {
+                        external_id: Some(from.to_string()),
+                        email: None,
+                        name: None,
+                    },
+                    content: message_content(params),
+                    conversation_id: None,
+                    new_conversation: false,
+                    subject: None,
+                    external_message_id: param(params, "MessageSid").map(str::to_string),
+                    channel: Some(SMS_CHANNEL.to_string()),
+                    channel_metadata: Some(serde_json::json!({
+                        "from": from,
+                        "to": param(params, "To"),
+                        "message_sid": param(params, "MessageSid"),
+                    })),
+                },
+            )
+            .await
+            .map_err(SmsError::from)
+    }
+}
+
+fn param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Text body followed by the URLs of any MMS media, one per line
+fn message_content(params: &[(String, String)]) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    if let Some(body) = param(params, "Body").filter(|body| !body.trim().is_empty()) {
+        lines.push(body);
+    }
+
+    let media_count: usize = param(params, "NumMedia")
+        .and_then(|count| count.parse().ok())
+        .unwrap_or(0);
+    for index in 0..media_count {
+        if let Some(url) = param(params, &format!("MediaUrl{}", index)) {
+            lines.push(url);
+        }
+    }
+
+    lines.join("\n")
+}
@@ -0,0 +1,186 @@
+use crate::{
+    domain::entities::{
+        SystemConfigChange, SystemConfigChangeListResponse, SystemConfigResponse,
+        SystemConfigSetting, SystemConfigValue, UpdateSystemConfigRequest,
+        AUTH_RATE_LIMIT_ATTEMPTS_KEY, AUTH_RATE_LIMIT_WINDOW_KEY, INACTIVITY_TIMEOUT_KEY,
+        MAX_IDLE_THRESHOLD_KEY, SYSTEM_CONFIG_SETTINGS,
+    },
+    domain::errors::{SystemConfigError, SystemConfigResult},
+    domain::ports::system_config_repository::SystemConfigRepository,
+    shared::rate_limiter::AuthRateLimiter,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Largest page of change history returned at once
+const MAX_CHANGES_LIMIT: i64 = 500;
+
+/// Service for admin-editable system settings
+///
+/// Availability thresholds and retention periods are read from storage when
+/// used, so changes apply on the next check. The login rate limiter keeps its
+/// limits in memory and is reconfigured after each update.
+#[derive(Clone)]
+pub struct SystemConfigService {
+    repo: Arc<dyn SystemConfigRepository>,
+    rate_limiter: Option<AuthRateLimiter>,
+}
+
+impl SystemConfigService {
+    pub fn new(repo: Arc<dyn SystemConfigRepository>) -> Self {
+        Self {
+            repo,
+            rate_limiter: None,
+        }
+    }
+
+    /// Reconfigure this rate limiter when its settings change
+    pub fn with_rate_limiter(mut self, rate_limiter: AuthRateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Effective values of all editable settings
+    pub async fn get_settings(&self) -> SystemConfigResult<SystemConfigResponse> {
+        let stored: HashMap<String, (String, String)> = self
+            .repo
+            .list_config_entries()
+            .await?
+            .into_iter()
+            .map(|entry| (entry.key, (entry.value, entry.updated_at)))
+            .collect();
+
+        let settings = SYSTEM_CONFIG_SETTINGS
+            .iter()
+            .map(|setting| {
+                let entry = stored.get(setting.key);
+                SystemConfigValue {
+                    key: setting.key.to_string(),
+                    value: setting.parse_stored(entry.map(|(value, _)| value.as_str())),
+                    default_value: setting.default_value,
+                    min: setting.min,
+                    max: setting.max,
+                    description: setting.description.to_string(),
+                    updated_at: entry.map(|(_, updated_at)| updated_at.clone()),
+                }
+            })
+            .collect();
+
+        Ok(SystemConfigResponse { settings })
+    }
+
+    /// Effective value of one allow-listed setting
+    pub async fn value(&self, key: &str) -> SystemConfigResult<i64> {
+        let setting = SystemConfigSetting::find(key).ok_or_else(|| {
+            SystemConfigError::Validation(format!("Unknown configuration key: {}", key))
+        })?;
+        let stored = self.repo.get_config_value(key).await?;
+        Ok(setting.parse_stored(stored.as_deref()))
+    }
+
+    /// Validate and store settings, recording each change
+    ///
+    /// All values are validated before anything is written.
+    pub async fn update_settings(
+        &self,
+        request: UpdateSystemConfigRequest,
+        changed_by: &str,
+    ) -> SystemConfigResult<SystemConfigResponse> {
+        if request.settings.is_empty() {
+            return Err(SystemConfigError::Validation(
+                "No settings provided".to_string(),
+            ));
+        }
+
+        let mut updates = Vec::new();
+        for (key, value) in &request.settings {
+            let setting = SystemConfigSetting::find(key).ok_or_else(|| {
+                SystemConfigError::Validation(format!("Unknown configuration key: {}", key))
+            })?;
+            let value = setting
+                .validate(value)
+                .map_err(SystemConfigError::Validation)?;
+            updates.push((setting, value));
+        }
+
+        let current = self.get_settings().await?;
+        let effective = |key: &str| {
+            updates
+                .iter()
+                .find(|(setting, _)| setting.key == key)
+                .map(|(_, value)| *value)
+                .or_else(|| {
+                    current
+                        .settings
+                        .iter()
+                        .find(|s| s.key == key)
+                        .map(|s| s.value)
+                })
+                .unwrap_or_default()
+        };
+        if effective(MAX_IDLE_THRESHOLD_KEY) < effective(INACTIVITY_TIMEOUT_KEY) {
+            return Err(SystemConfigError::Validation(format!(
+                "{} must not be less than {}",
+                MAX_IDLE_THRESHOLD_KEY, INACTIVITY_TIMEOUT_KEY
+            )));
+        }
+
+        for (setting, value) in updates {
+            let old_value = self.repo.get_config_value(setting.key).await?;
+            let new_value = value.to_string();
+            if old_value.as_deref() == Some(new_value.as_str()) {
+                continue;
+            }
+
+            let change = SystemConfigChange::new(
+                setting.key.to_string(),
+                old_value,
+                new_value,
+                changed_by.to_string(),
+            );
+            self.repo
+                .apply_config_change(&change, Some(setting.description))
+                .await?;
+            tracing::info!(
+                "System config {} changed from {:?} to {} by {}",
+                change.key,
+                change.old_value,
+                change.new_value,
+                changed_by
+            );
+        }
+
+        self.apply_runtime_settings().await?;
+        self.get_settings().await
+    }
+
+    /// Push stored settings into in-memory components (called at startup and after updates)
+    pub async fn apply_runtime_settings(&self) -> SystemConfigResult<()> {
+        if let Some(ref rate_limiter) = self.rate_limiter {
+            let max_attempts = self.value(AUTH_RATE_LIMIT_ATTEMPTS_KEY).await?;
+            let window_minutes = self.value(AUTH_RATE_LIMIT_WINDOW_KEY).await?;
+            let limits = (max_attempts as u32, window_minutes as u64);
+            if rate_limiter.limits() != limits {
+                rate_limiter.reconfigure(limits.0, limits.1).await;
+                tracing::info!(
+                    "Login rate limit set to {} attempts per {} minutes",
+                    limits.0,
+                    limits.1
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Change history, newest first
+    pub async fn list_changes(
+        &self,
+        key: Option<&str>,
+        limit: i64,
+    ) -> SystemConfigResult<SystemConfigChangeListResponse> {
+        let limit = limit.clamp(1, MAX_CHANGES_LIMIT);
+        let changes = self.repo.list_config_changes(key, limit).await?;
+        let total = changes.len() as i64;
+        Ok(SystemConfigChangeListResponse { changes, total })
+    }
+}
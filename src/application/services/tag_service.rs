@@ -1,5 +1,5 @@
 use crate::{
-    infrastructure::http::middleware::error::{ApiError, ApiResult},
+    domain::errors::{DomainError, TagError, TagResult},
     domain::ports::tag_repository::TagRepository,
     domain::entities::*,
 };
@@ -25,37 +25,34 @@ impl TagService {
         &self,
         request: CreateTagRequest,
         permissions: &[Permission],
-    ) -> ApiResult<Tag> {
+    ) -> TagResult<Tag> {
         // 1. Check permission
         if !self.has_permission(permissions, "tags:create") {
-            return Err(ApiError::Forbidden(
+            return Err(TagError::Forbidden(
                 "Missing permission: tags:create".to_string(),
             ));
         }
 
         // 2. Validate tag name
         if request.name.trim().is_empty() {
-            return Err(ApiError::BadRequest("Tag name cannot be empty".to_string()));
+            return Err(TagError::Validation("Tag name cannot be empty".to_string()));
         }
 
         if request.name.len() > 50 {
-            return Err(ApiError::BadRequest(
+            return Err(TagError::Validation(
                 "Tag name cannot exceed 50 characters".to_string(),
             ));
         }
 
         // 3. Check if tag with same name already exists
         if let Some(_) = self.tag_repo.get_tag_by_name(&request.name).await? {
-            return Err(ApiError::BadRequest(format!(
-                "Tag with name '{}' already exists",
-                request.name
-            )));
+            return Err(TagError::AlreadyExists(request.name));
         }
 
         // 4. Validate color format if provided
         if let Some(ref color) = request.color {
             if !color.starts_with('#') || color.len() != 7 {
-                return Err(ApiError::BadRequest(
+                return Err(TagError::Validation(
                     "Color must be in hex format (#RRGGBB)".to_string(),
                 ));
             }
@@ -76,23 +73,23 @@ impl TagService {
         limit: i64,
         offset: i64,
         permissions: &[Permission],
-    ) -> ApiResult<(Vec<Tag>, i64)> {
+    ) -> TagResult<(Vec<Tag>, i64)> {
         // 1. Check permission
         if !self.has_permission(permissions, "tags:read") {
-            return Err(ApiError::Forbidden(
+            return Err(TagError::Forbidden(
                 "Missing permission: tags:read".to_string(),
             ));
         }
 
         // 2. Get tags from database
-        self.tag_repo.list_tags(limit, offset).await
+        Ok(self.tag_repo.list_tags(limit, offset).await?)
     }
 
     /// Get tag by ID (requires tags:read permission)
-    pub async fn get_tag(&self, tag_id: &str, permissions: &[Permission]) -> ApiResult<Tag> {
+    pub async fn get_tag(&self, tag_id: &str, permissions: &[Permission]) -> TagResult<Tag> {
         // 1. Check permission
         if !self.has_permission(permissions, "tags:read") {
-            return Err(ApiError::Forbidden(
+            return Err(TagError::Forbidden(
                 "Missing permission: tags:read".to_string(),
             ));
         }
@@ -101,7 +98,7 @@ impl TagService {
         self.tag_repo
             .get_tag_by_id(tag_id)
             .await?
-            .ok_or_else(|| ApiError::NotFound(format!("Tag {} not found", tag_id)))
+            .ok_or_else(|| TagError::NotFound(format!("Tag {} not found", tag_id)))
     }
 
     /// Update tag properties (requires tags:update permission)
@@ -110,10 +107,10 @@ impl TagService {
         tag_id: &str,
         request: UpdateTagRequest,
         permissions: &[Permission],
-    ) -> ApiResult<Tag> {
+    ) -> TagResult<Tag> {
         // 1. Check permission
         if !self.has_permission(permissions, "tags:update") {
-            return Err(ApiError::Forbidden(
+            return Err(TagError::Forbidden(
                 "Missing permission: tags:update".to_string(),
             ));
         }
@@ -123,12 +120,12 @@ impl TagService {
             .tag_repo
             .get_tag_by_id(tag_id)
             .await?
-            .ok_or_else(|| ApiError::NotFound(format!("Tag {} not found", tag_id)))?;
+            .ok_or_else(|| TagError::NotFound(format!("Tag {} not found", tag_id)))?;
 
         // 3. Validate color format if provided
         if let Some(ref color) = request.color {
             if !color.starts_with('#') || color.len() != 7 {
-                return Err(ApiError::BadRequest(
+                return Err(TagError::Validation(
                     "Color must be in hex format (#RRGGBB)".to_string(),
                 ));
             }
@@ -143,14 +140,18 @@ impl TagService {
         self.tag_repo
             .get_tag_by_id(tag_id)
             .await?
-            .ok_or_else(|| ApiError::Internal("Tag disappeared after update".to_string()))
+            .ok_or_else(|| {
+                TagError::Repository(DomainError::Internal(
+                    "Tag disappeared after update".to_string(),
+                ))
+            })
     }
 
     /// Delete tag (requires tags:delete permission)
-    pub async fn delete_tag(&self, tag_id: &str, permissions: &[Permission]) -> ApiResult<()> {
+    pub async fn delete_tag(&self, tag_id: &str, permissions: &[Permission]) -> TagResult<()> {
         // 1. Check permission
         if !self.has_permission(permissions, "tags:delete") {
-            return Err(ApiError::Forbidden(
+            return Err(TagError::Forbidden(
                 "Missing permission: tags:delete".to_string(),
             ));
         }
@@ -160,7 +161,7 @@ impl TagService {
             .tag_repo
             .get_tag_by_id(tag_id)
             .await?
-            .ok_or_else(|| ApiError::NotFound(format!("Tag {} not found", tag_id)))?;
+            .ok_or_else(|| TagError::NotFound(format!("Tag {} not found", tag_id)))?;
 
         // 3. Delete tag (cascades to conversation_tags)
         self.tag_repo.delete_tag(tag_id).await?;
@@ -169,7 +170,7 @@ impl TagService {
     }
 
     /// Get user permissions (helper for service layer)
-    pub async fn get_user_permissions(&self, user_id: &str) -> ApiResult<Vec<Permission>> {
-        self.tag_repo.get_user_permissions(user_id).await
+    pub async fn get_user_permissions(&self, user_id: &str) -> TagResult<Vec<Permission>> {
+        Ok(self.tag_repo.get_user_permissions(user_id).await?)
     }
 }
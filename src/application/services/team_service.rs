@@ -1,5 +1,5 @@
 use crate::{
-    infrastructure::http::middleware::error::ApiResult,
+    domain::errors::{TeamError, TeamResult},
     domain::ports::team_repository::TeamRepository,
     domain::entities::{Team, TeamMemberRole, User},
 };
@@ -15,19 +15,20 @@ impl TeamService {
         Self { team_repo }
     }
 
-    pub async fn create_team(&self, team: Team) -> ApiResult<Team> {
+    pub async fn create_team(&self, team: Team) -> TeamResult<Team> {
         self.team_repo.create_team(&team).await?;
         Ok(team)
     }
 
-    pub async fn get_team(&self, team_id: &str) -> ApiResult<Team> {
-        self.team_repo.get_team_by_id(team_id).await?.ok_or_else(|| {
-            crate::infrastructure::http::middleware::error::ApiError::NotFound(format!("Team {} not found", team_id))
-        })
+    pub async fn get_team(&self, team_id: &str) -> TeamResult<Team> {
+        self.team_repo
+            .get_team_by_id(team_id)
+            .await?
+            .ok_or_else(|| TeamError::NotFound(format!("Team {} not found", team_id)))
     }
 
-    pub async fn list_teams(&self) -> ApiResult<Vec<Team>> {
-        self.team_repo.list_teams().await
+    pub async fn list_teams(&self) -> TeamResult<Vec<Team>> {
+        Ok(self.team_repo.list_teams().await?)
     }
 
     pub async fn add_member(
@@ -35,34 +36,43 @@ impl TeamService {
         team_id: &str,
         user_id: &str,
         role: TeamMemberRole,
-    ) -> ApiResult<()> {
+    ) -> TeamResult<()> {
         // Verify team exists
         self.get_team(team_id).await?;
 
-        self.team_repo.add_team_member(team_id, user_id, role).await
+        Ok(self.team_repo.add_team_member(team_id, user_id, role).await?)
     }
 
-    pub async fn remove_member(&self, team_id: &str, user_id: &str) -> ApiResult<()> {
-        self.team_repo.remove_team_member(team_id, user_id).await
+    pub async fn remove_member(&self, team_id: &str, user_id: &str) -> TeamResult<()> {
+        Ok(self.team_repo.remove_team_member(team_id, user_id).await?)
     }
 
-    pub async fn get_members(&self, team_id: &str) -> ApiResult<Vec<User>> {
-        self.team_repo.get_team_members(team_id).await
+    pub async fn get_members(&self, team_id: &str) -> TeamResult<Vec<User>> {
+        Ok(self.team_repo.get_team_members(team_id).await?)
     }
 
-    pub async fn is_member(&self, team_id: &str, user_id: &str) -> ApiResult<bool> {
-        self.team_repo.is_team_member(team_id, user_id).await
+    pub async fn is_member(&self, team_id: &str, user_id: &str) -> TeamResult<bool> {
+        Ok(self.team_repo.is_team_member(team_id, user_id).await?)
     }
 
-    pub async fn get_user_teams(&self, user_id: &str) -> ApiResult<Vec<Team>> {
-        self.team_repo.get_user_teams(user_id).await
+    pub async fn get_user_teams(&self, user_id: &str) -> TeamResult<Vec<Team>> {
+        Ok(self.team_repo.get_user_teams(user_id).await?)
     }
 
     pub async fn update_team_sla_policy(
         &self,
         team_id: &str,
         sla_policy_id: Option<&str>,
-    ) -> ApiResult<()> {
-        self.team_repo.update_team_sla_policy(team_id, sla_policy_id).await
+    ) -> TeamResult<()> {
+        Ok(self.team_repo.update_team_sla_policy(team_id, sla_policy_id).await?)
+    }
+
+    /// Turn take-on-reply assignment on or off for a team
+    pub async fn set_take_on_reply(&self, team_id: &str, enabled: bool) -> TeamResult<Team> {
+        self.get_team(team_id).await?;
+        self.team_repo
+            .update_team_take_on_reply(team_id, enabled)
+            .await?;
+        self.get_team(team_id).await
     }
 }
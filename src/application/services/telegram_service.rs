@@ -0,0 +1,399 @@
+use crate::{
+    application::services::{AttachmentRuleService, AttachmentService, ChannelService},
+    domain::entities::{
+        ChannelMessageRequest, ChannelMessageResponse, ChannelSender,
+        CreateInboxTelegramConfigRequest, InboxTelegramConfig, MessageAttachment, TelegramMessage,
+        TelegramUpdate, UpdateInboxTelegramConfigRequest, TELEGRAM_CHANNEL,
+        TELEGRAM_INBOX_CHANNEL_TYPE,
+    },
+    domain::errors::{TelegramError, TelegramResult},
+    domain::ports::inbox_repository::InboxRepository,
+    domain::ports::telegram_bot_api::TelegramBotApi,
+    domain::ports::telegram_config_repository::TelegramConfigRepository,
+    domain::services::telegram::verify_telegram_secret,
+};
+use std::sync::Arc;
+
+/// File sent to the bot, to be stored as a message attachment
+struct TelegramFile {
+    file_id: String,
+    filename: String,
+    content_type: String,
+}
+
+/// Service for Telegram bot inboxes
+///
+/// Updates arrive on the bot webhook and are ingested through `ChannelService`,
+/// with the Telegram chat ID as channel identity. Replies go out through
+/// `TelegramDeliveryProvider`.
+#[derive(Clone)]
+pub struct TelegramService {
+    telegram_repo: Arc<dyn TelegramConfigRepository>,
+    inbox_repo: Arc<dyn InboxRepository>,
+    channel_service: ChannelService,
+    bot_api: Arc<dyn TelegramBotApi>,
+    attachment_service: Option<AttachmentService>,
+    attachment_rule_service: Option<AttachmentRuleService>,
+    webhook_base_url: String,
+}
+
+impl TelegramService {
+    pub fn new(
+        telegram_repo: Arc<dyn TelegramConfigRepository>,
+        inbox_repo: Arc<dyn InboxRepository>,
+        channel_service: ChannelService,
+        bot_api: Arc<dyn TelegramBotApi>,
+    ) -> Self {
+        Self {
+            telegram_repo,
+            inbox_repo,
+            channel_service,
+            bot_api,
+            attachment_service: None,
+            attachment_rule_service: None,
+            webhook_base_url: "http://localhost:3000".to_string(),
+        }
+    }
+
+    /// Store photos and files sent to the bot as message attachments
+    pub fn with_attachment_service(mut self, attachment_service: AttachmentService) -> Self {
+        self.attachment_service = Some(attachment_service);
+        self
+    }
+
+    /// Tag and route conversations by the type of files sent to the bot
+    pub fn with_attachment_rule_service(
+        mut self,
+        attachment_rule_service: AttachmentRuleService,
+    ) -> Self {
+        self.attachment_rule_service = Some(attachment_rule_service);
+        self
+    }
+
+    /// Public HTTPS base URL Telegram posts updates to
+    pub fn with_webhook_base_url(mut self, base_url: &str) -> Self {
+        self.webhook_base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// URL the bot's webhook is set to
+    pub fn webhook_url(&self, inbox_id: &str) -> String {
+        format!("{}/webhooks/telegram/{}", self.webhook_base_url, inbox_id)
+    }
+
+    pub async fn get_config(&self, inbox_id: &str) -> TelegramResult<InboxTelegramConfig> {
+        self.telegram_repo
+            .get_inbox_telegram_config(inbox_id)
+            .await?
+            .ok_or_else(|| {
+                TelegramError::NotFound(format!(
+                    "Telegram configuration not found for inbox {}",
+                    inbox_id
+                ))
+            })
+    }
+
+    /// Connect a bot to an inbox and point its webhook at this server
+    pub async fn create_config(
+        &self,
+        inbox_id: &str,
+        request: CreateInboxTelegramConfigRequest,
+    ) -> TelegramResult<InboxTelegramConfig> {
+        let inbox = self
+            .inbox_repo
+            .get_inbox(inbox_id)
+            .await?
+            .ok_or_else(|| TelegramError::NotFound(format!("Inbox {} not found", inbox_id)))?;
+        if inbox.channel_type != TELEGRAM_INBOX_CHANNEL_TYPE {
+            return Err(TelegramError::Validation(format!(
+                "Telegram can only be configured on {} inboxes",
+                TELEGRAM_INBOX_CHANNEL_TYPE
+            )));
+        }
+        if self
+            .telegram_repo
+            .get_inbox_telegram_config(inbox_id)
+            .await?
+            .is_some()
+        {
+            return Err(TelegramError::Conflict(
+                "Telegram configuration already exists for this inbox".to_string(),
+            ));
+        }
+
+        let mut config = InboxTelegramConfig::new(inbox_id.to_string(), request);
+        config.validate().map_err(TelegramError::Validation)?;
+        self.connect_bot(&mut config).await?;
+        self.telegram_repo
+            .create_inbox_telegram_config(&config)
+            .await?;
+
+        Ok(config)
+    }
+
+    pub async fn update_config(
+        &self,
+        inbox_id: &str,
+        request: UpdateInboxTelegramConfigRequest,
+    ) -> TelegramResult<InboxTelegramConfig> {
+        let mut config = self.get_config(inbox_id).await?;
+
+        if let Some(bot_token) = request.bot_token {
+            let bot_token = bot_token.trim().to_string();
+            if bot_token != config.bot_token {
+                config.bot_token = bot_token;
+                config.validate().map_err(TelegramError::Validation)?;
+                self.connect_bot(&mut config).await?;
+            }
+        }
+        if let Some(enabled) = request.enabled {
+            config.enabled = enabled;
+        }
+        config.updated_at = chrono::Utc::now().to_rfc3339();
+
+        self.telegram_repo
+            .update_inbox_telegram_config(&config)
+            .await?;
+
+        Ok(config)
+    }
+
+    /// Disconnect the bot; its webhook is removed on a best-effort basis
+    pub async fn delete_config(&self, inbox_id: &str) -> TelegramResult<()> {
+        let config = self.get_config(inbox_id).await?;
+        if let Err(e) = self.bot_api.delete_webhook(&config.bot_token).await {
+            tracing::warn!(
+                "Failed to delete Telegram webhook for inbox {}: {}",
+                inbox_id,
+                e
+            );
+        }
+        self.telegram_repo
+            .delete_inbox_telegram_config(inbox_id)
+            .await?;
+        Ok(())
+    }
+
+    /// Check the bot token and set the bot's webhook to this inbox
+    async fn connect_bot(&self, config: &mut InboxTelegramConfig) -> TelegramResult<()> {
+        let bot = self
+            .bot_api
+            .get_me(&config.bot_token)
+            .await
+            .map_err(TelegramError::Api)?;
+        self.bot_api
+            .set_webhook(
+                &config.bot_token,
+                &self.webhook_url(&config.inbox_id),
+                &config.webhook_secret,
+            )
+            .await
+            .map_err(TelegramError::Api)?;
+        config.bot_username = bot.username;
+        Ok(())
+    }
+
+    /// Ingest an update delivered to the inbox's bot webhook
+    ///
+    /// `secret_token` is the `X-Telegram-Bot-Api-Secret-Token` header. Updates
+    /// without a new message (edits, bot messages, ...) are acknowledged and
+    /// return `None`; redelivered messages are ignored.
+    pub async fn receive_update(
+        &self,
+        inbox_id: &str,
+        update: TelegramUpdate,
+        secret_token: Option<&str>,
+    ) -> TelegramResult<Option<ChannelMessageResponse>> {
+        let config = self
+            .telegram_repo
+            .get_inbox_telegram_config(inbox_id)
+            .await?
+            .filter(|config| config.enabled)
+            .ok_or_else(|| {
+                TelegramError::NotFound(format!("Telegram is not enabled for inbox {}", inbox_id))
+            })?;
+
+        let secret_token = secret_token.ok_or(TelegramError::InvalidSecret)?;
+        if !verify_telegram_secret(&config.webhook_secret, secret_token) {
+            return Err(TelegramError::InvalidSecret);
+        }
+
+        let Some(message) = update.message else {
+            return Ok(None);
+        };
+        if message.from.as_ref().is_some_and(|from| from.is_bot) {
+            return Ok(None);
+        }
+
+        let files = message_files(&message);
+        let content = message_content(&message, &files);
+        if content.is_empty() {
+            // Stickers, locations, service messages, ...
+            return Ok(None);
+        }
+
+        let response = self
+            .channel_service
+            .receive_message(
+                inbox_id,
+                ChannelMessageRequest {
+                    sender: ChannelSender {
+                        external_id: Some(message.chat.id.to_string()),
+                        email: None,
+                        name: message.from.as_ref().map(|from| from.full_name()),
+                    },
+                    content,
+                    conversation_id: None,
+                    new_conversation: false,
+                    subject: None,
+                    external_message_id: Some(format!(
+                        "{}:{}",
+                        message.chat.id, message.message_id
+                    )),
+                    channel: Some(TELEGRAM_CHANNEL.to_string()),
+                    channel_metadata: Some(serde_json::json!({
+                        "chat_id": message.chat.id,
+                        "chat_type": message.chat.chat_type,
+                        "message_id": message.message_id,
+                        "username": message.from.as_ref().and_then(|from| from.username.clone()),
+                        "bot_username": config.bot_username,
+                    })),
+                },
+            )
+            .await?;
+
+        if !response.duplicate {
+            let attachments = self.save_files(&config, &response.message.id, files).await;
+            self.apply_attachment_rules(inbox_id, &response.conversation_id, &attachments)
+                .await;
+        }
+
+        Ok(Some(response))
+    }
+
+    /// Run attachment type rules against the files of a message; failures are only logged
+    async fn apply_attachment_rules(
+        &self,
+        inbox_id: &str,
+        conversation_id: &str,
+        attachments: &[MessageAttachment],
+    ) {
+        let Some(attachment_rule_service) = &self.attachment_rule_service else {
+            return;
+        };
+
+        if let Err(e) = attachment_rule_service
+            .apply_to_attachments(conversation_id, inbox_id, attachments)
+            .await
+        {
+            tracing::warn!(
+                "Failed to apply attachment rules to conversation {}: {}",
+                conversation_id,
+                e
+            );
+        }
+    }
+
+    /// Download files sent with a message and attach them; failures are only logged
+    ///
+    /// Returns the attachments that were saved.
+    async fn save_files(
+        &self,
+        config: &InboxTelegramConfig,
+        message_id: &str,
+        files: Vec<TelegramFile>,
+    ) -> Vec<MessageAttachment> {
+        let mut saved = Vec::new();
+        let Some(attachment_service) = &self.attachment_service else {
+            return saved;
+        };
+
+        for file in files {
+            let content = match self
+                .bot_api
+                .download_file(&config.bot_token, &file.file_id)
+                .await
+            {
+                Ok(content) => content,
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to download Telegram file {} for message {}: {}",
+                        file.file_id,
+                        message_id,
+                        e
+                    );
+                    continue;
+                }
+            };
+
+            match attachment_service
+                .save_attachment(
+                    message_id.to_string(),
+                    file.filename.clone(),
+                    file.content_type,
+                    content,
+                )
+                .await
+            {
+                Ok(attachment) => saved.push(attachment),
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to save Telegram file {} for message {}: {}",
+                        file.filename,
+                        message_id,
+                        e
+                    );
+                }
+            }
+        }
+
+        saved
+    }
+}
+
+/// Photo (largest size) and document sent with a message
+fn message_files(message: &TelegramMessage) -> Vec<TelegramFile> {
+    let mut files = Vec::new();
+
+    if let Some(photo) = message.photo.as_ref().and_then(|sizes| sizes.last()) {
+        files.push(TelegramFile {
+            file_id: photo.file_id.clone(),
+            filename: format!("photo_{}.jpg", message.message_id),
+            content_type: "image/jpeg".to_string(),
+        });
+    }
+    if let Some(document) = &message.document {
+        files.push(TelegramFile {
+            file_id: document.file_id.clone(),
+            filename: document
+                .file_name
+                .clone()
+                .unwrap_or_else(|| format!("file_{}", message.message_id)),
+            content_type: document
+                .mime_type
+                .clone()
+                .unwrap_or_else(|| "application/octet-stream".to_string()),
+        });
+    }
+
+    files
+}
+
+/// Text or caption, or a line naming each file when the message has neither
+fn message_content(message: &TelegramMessage, files: &[TelegramFile]) -> String {
+    let text = message
+        .text
+        .as_deref()
+        .or(message.caption.as_deref())
+        .map(str::trim)
+        .unwrap_or_default();
+    if !text.is_empty() {
+        return text.to_string();
+    }
+
+    files
+        .iter()
+        .map(|file| format!("[Attachment: {}]", file.filename))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
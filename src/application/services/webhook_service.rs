@@ -1,22 +1,49 @@
 use crate::{
-    infrastructure::http::middleware::error::{ApiError, ApiResult},
-    domain::ports::webhook_repository::WebhookRepository,
+    domain::errors::{DomainError, WebhookError, WebhookResult},
+    domain::ports::{task_queue::TaskQueue, webhook_repository::WebhookRepository},
     domain::entities::{
-        CreateWebhookRequest, UpdateWebhookRequest, Webhook, WebhookListResponse, WebhookResponse,
+        CreateWebhookBackfillRequest, CreateWebhookRequest, UpdateWebhookRequest, Webhook,
+        WebhookBackfill, WebhookBackfillEvent, WebhookBackfillResponse, WebhookBackfillStatus,
+        WebhookDelivery, WebhookEventCatalogResponse, WebhookEventTypeResponse,
+        WebhookListResponse, WebhookResponse, WEBHOOK_EVENT_TYPES,
     },
+    domain::services::webhook_signature::sign_payload,
 };
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use std::collections::HashSet;
+use std::sync::Arc;
 use tracing::info;
 
+/// Job type that replays the next batch of a webhook backfill
+pub const RUN_WEBHOOK_BACKFILL_JOB: &str = "run_webhook_backfill";
+
+/// Events a backfill hands to the delivery queue per run
+const BACKFILL_BATCH_SIZE: i64 = 10;
+
+/// Pause between backfill runs, so a backfill replays at most
+/// `BACKFILL_BATCH_SIZE` events a second and live deliveries keep flowing
+const BACKFILL_BATCH_INTERVAL: chrono::Duration = chrono::Duration::seconds(1);
+
 /// Service for managing webhooks
 #[derive(Clone)]
 pub struct WebhookService {
     webhook_repo: WebhookRepository,
+    task_queue: Option<Arc<dyn TaskQueue>>,
 }
 
 impl WebhookService {
     /// Create a new webhook service
     pub fn new(webhook_repo: WebhookRepository) -> Self {
-        Self { webhook_repo }
+        Self {
+            webhook_repo,
+            task_queue: None,
+        }
+    }
+
+    /// Queue backfill runs and their deliveries; backfills are rejected without it
+    pub fn set_task_queue(&mut self, task_queue: Arc<dyn TaskQueue>) {
+        self.task_queue = Some(task_queue);
     }
 
     /// Create a new webhook
@@ -24,7 +51,7 @@ impl WebhookService {
         &self,
         request: CreateWebhookRequest,
         created_by: &str,
-    ) -> ApiResult<WebhookResponse> {
+    ) -> WebhookResult<WebhookResponse> {
         // Create webhook model
         let mut webhook = Webhook::new(
             request.name,
@@ -38,9 +65,12 @@ impl WebhookService {
         if let Some(is_active) = request.is_active {
             webhook.is_active = is_active;
         }
+        if let Some(include) = request.include_conversation_snapshot {
+            webhook.include_conversation_snapshot = include;
+        }
 
         // Validate webhook
-        webhook.validate().map_err(|e| ApiError::BadRequest(e))?;
+        webhook.validate().map_err(WebhookError::Validation)?;
 
         // Save to database
         self.webhook_repo.create_webhook(&webhook).await?;
@@ -55,13 +85,13 @@ impl WebhookService {
         &self,
         id: &str,
         request: UpdateWebhookRequest,
-    ) -> ApiResult<WebhookResponse> {
+    ) -> WebhookResult<WebhookResponse> {
         // Get existing webhook
         let mut webhook = self
             .webhook_repo
             .get_webhook_by_id(id)
             .await?
-            .ok_or_else(|| ApiError::NotFound(format!("Webhook {} not found", id)))?;
+            .ok_or_else(|| WebhookError::NotFound(format!("Webhook {} not found", id)))?;
 
         // Update fields if provided
         if let Some(name) = request.name {
@@ -79,12 +109,15 @@ impl WebhookService {
         if let Some(is_active) = request.is_active {
             webhook.is_active = is_active;
         }
+        if let Some(include) = request.include_conversation_snapshot {
+            webhook.include_conversation_snapshot = include;
+        }
 
         // Update timestamp
         webhook.touch();
 
         // Validate updated webhook
-        webhook.validate().map_err(|e| ApiError::BadRequest(e))?;
+        webhook.validate().map_err(WebhookError::Validation)?;
 
         // Save to database
         self.webhook_repo.update_webhook(&webhook).await?;
@@ -94,13 +127,23 @@ impl WebhookService {
         Ok(WebhookResponse::from(webhook))
     }
 
+    /// Event types webhooks can subscribe to, with their payload schemas
+    pub fn list_event_types(&self) -> WebhookEventCatalogResponse {
+        WebhookEventCatalogResponse {
+            events: WEBHOOK_EVENT_TYPES
+                .iter()
+                .map(WebhookEventTypeResponse::from)
+                .collect(),
+        }
+    }
+
     /// Delete a webhook
-    pub async fn delete_webhook(&self, id: &str) -> ApiResult<()> {
+    pub async fn delete_webhook(&self, id: &str) -> WebhookResult<()> {
         // Verify webhook exists
         self.webhook_repo
             .get_webhook_by_id(id)
             .await?
-            .ok_or_else(|| ApiError::NotFound(format!("Webhook {} not found", id)))?;
+            .ok_or_else(|| WebhookError::NotFound(format!("Webhook {} not found", id)))?;
 
         // Delete webhook (cascades to deliveries)
         self.webhook_repo.delete_webhook(id).await?;
@@ -111,13 +154,13 @@ impl WebhookService {
     }
 
     /// Toggle webhook active status
-    pub async fn toggle_webhook_status(&self, id: &str) -> ApiResult<WebhookResponse> {
+    pub async fn toggle_webhook_status(&self, id: &str) -> WebhookResult<WebhookResponse> {
         // Get existing webhook
         let mut webhook = self
             .webhook_repo
             .get_webhook_by_id(id)
             .await?
-            .ok_or_else(|| ApiError::NotFound(format!("Webhook {} not found", id)))?;
+            .ok_or_else(|| WebhookError::NotFound(format!("Webhook {} not found", id)))?;
 
         // Toggle is_active status
         webhook.is_active = !webhook.is_active;
@@ -140,34 +183,34 @@ impl WebhookService {
     }
 
     /// Get a webhook by ID
-    pub async fn get_webhook(&self, id: &str) -> ApiResult<WebhookResponse> {
+    pub async fn get_webhook(&self, id: &str) -> WebhookResult<WebhookResponse> {
         let webhook = self
             .webhook_repo
             .get_webhook_by_id(id)
             .await?
-            .ok_or_else(|| ApiError::NotFound(format!("Webhook {} not found", id)))?;
+            .ok_or_else(|| WebhookError::NotFound(format!("Webhook {} not found", id)))?;
 
         Ok(WebhookResponse::from(webhook))
     }
 
     /// Get full webhook by ID (including secret) - for internal use
-    pub async fn get_webhook_full(&self, id: &str) -> ApiResult<Webhook> {
+    pub async fn get_webhook_full(&self, id: &str) -> WebhookResult<Webhook> {
         self.webhook_repo
             .get_webhook_by_id(id)
             .await?
-            .ok_or_else(|| ApiError::NotFound(format!("Webhook {} not found", id)))
+            .ok_or_else(|| WebhookError::NotFound(format!("Webhook {} not found", id)))
     }
 
     /// List webhooks with pagination
-    pub async fn list_webhooks(&self, limit: i64, offset: i64) -> ApiResult<WebhookListResponse> {
+    pub async fn list_webhooks(&self, limit: i64, offset: i64) -> WebhookResult<WebhookListResponse> {
         // Validate pagination parameters
         if limit < 1 || limit > 100 {
-            return Err(ApiError::BadRequest(
+            return Err(WebhookError::Validation(
                 "Limit must be between 1 and 100".to_string(),
             ));
         }
         if offset < 0 {
-            return Err(ApiError::BadRequest(
+            return Err(WebhookError::Validation(
                 "Offset must be non-negative".to_string(),
             ));
         }
@@ -195,7 +238,7 @@ impl WebhookService {
         limit: i64,
         offset: i64,
         status_filter: Option<&str>,
-    ) -> ApiResult<crate::domain::entities::DeliveryListResponse> {
+    ) -> WebhookResult<crate::domain::entities::DeliveryListResponse> {
         // Get deliveries from repository
         let deliveries = self.webhook_repo
             .get_deliveries_for_webhook(webhook_id, limit, offset, status_filter)
@@ -217,6 +260,289 @@ impl WebhookService {
             total,
         })
     }
+
+    /// Replay past events into a webhook
+    ///
+    /// Events are taken from stored deliveries within the range, so only
+    /// events some webhook was subscribed to at the time can be replayed.
+    /// An event delivered to several webhooks, or retried, is replayed once.
+    pub async fn create_backfill(
+        &self,
+        webhook_id: &str,
+        request: CreateWebhookBackfillRequest,
+        created_by: &str,
+    ) -> WebhookResult<WebhookBackfillResponse> {
+        let task_queue = self.task_queue()?;
+        let webhook = self.get_webhook_full(webhook_id).await?;
+        if !webhook.is_active {
+            return Err(WebhookError::Validation(
+                "Webhook is inactive; activate it before backfilling".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let from = parse_backfill_time("from", &request.from)?;
+        let to = match &request.to {
+            Some(to) => parse_backfill_time("to", to)?.min(now),
+            None => now,
+        };
+        if from >= to {
+            return Err(WebhookError::Validation(
+                "'from' must be before 'to'".to_string(),
+            ));
+        }
+
+        let event_types = request
+            .event_types
+            .unwrap_or_else(|| webhook.subscribed_events.clone());
+        if let Some(event_type) = event_types
+            .iter()
+            .find(|event_type| !webhook.subscribed_events.contains(event_type))
+        {
+            return Err(WebhookError::Validation(format!(
+                "Webhook is not subscribed to {}",
+                event_type
+            )));
+        }
+
+        let deliveries = self
+            .webhook_repo
+            .get_deliveries_for_backfill(&event_types, &from.to_rfc3339(), &to.to_rfc3339())
+            .await?;
+        let events = backfill_events(deliveries);
+
+        let mut backfill = WebhookBackfill::new(
+            webhook.id.clone(),
+            event_types,
+            from.to_rfc3339(),
+            to.to_rfc3339(),
+            created_by.to_string(),
+        );
+        backfill.total_events = events.len() as i64;
+        if events.is_empty() {
+            backfill.status = WebhookBackfillStatus::Completed;
+            backfill.completed_at = Some(backfill.created_at.clone());
+        }
+        self.webhook_repo
+            .create_webhook_backfill(&backfill, &events)
+            .await?;
+
+        if !events.is_empty() {
+            task_queue
+                .enqueue(
+                    RUN_WEBHOOK_BACKFILL_JOB,
+                    json!({ "backfill_id": backfill.id }),
+                    3,
+                )
+                .await?;
+        }
+
+        info!(
+            "Queued backfill {} of {} events into webhook {} by user {}",
+            backfill.id, backfill.total_events, webhook.id, created_by
+        );
+
+        Ok(WebhookBackfillResponse::from(backfill))
+    }
+
+    /// Progress of a backfill
+    pub async fn get_backfill(
+        &self,
+        webhook_id: &str,
+        backfill_id: &str,
+    ) -> WebhookResult<WebhookBackfillResponse> {
+        self.webhook_repo
+            .get_webhook_backfill(backfill_id)
+            .await?
+            .filter(|backfill| backfill.webhook_id == webhook_id)
+            .map(WebhookBackfillResponse::from)
+            .ok_or_else(|| WebhookError::NotFound(format!("Backfill {} not found", backfill_id)))
+    }
+
+    /// Hand the next batch of a backfill to the delivery queue, then
+    /// schedule the following batch
+    ///
+    /// Progress is saved after every event, so a retried run carries on
+    /// where the last one stopped. A webhook deactivated mid-way fails the
+    /// backfill.
+    pub async fn run_backfill(&self, backfill_id: &str) -> WebhookResult<WebhookBackfill> {
+        let task_queue = self.task_queue()?;
+        let mut backfill = self
+            .webhook_repo
+            .get_webhook_backfill(backfill_id)
+            .await?
+            .ok_or_else(|| WebhookError::NotFound(format!("Backfill {} not found", backfill_id)))?;
+        if backfill.status.is_finished() {
+            return Ok(backfill);
+        }
+
+        let now = Utc::now().to_rfc3339();
+        backfill.status = WebhookBackfillStatus::Running;
+        backfill.started_at.get_or_insert(now.clone());
+        backfill.updated_at = now;
+
+        let webhook = match self
+            .webhook_repo
+            .get_webhook_by_id(&backfill.webhook_id)
+            .await?
+        {
+            Some(webhook) if webhook.is_active => webhook,
+            _ => {
+                backfill.error_message = Some("Webhook was deactivated".to_string());
+                return self
+                    .finish_backfill(backfill, WebhookBackfillStatus::Failed)
+                    .await;
+            }
+        };
+
+        let events = self
+            .webhook_repo
+            .get_webhook_backfill_events(
+                &backfill.id,
+                backfill.processed_events,
+                BACKFILL_BATCH_SIZE,
+            )
+            .await?;
+        for event in &events {
+            self.queue_replay(task_queue, &webhook, event).await?;
+            backfill.processed_events = event.sequence;
+            backfill.updated_at = Utc::now().to_rfc3339();
+            self.webhook_repo
+                .update_webhook_backfill_progress(&backfill)
+                .await?;
+        }
+
+        if events.is_empty() || backfill.processed_events >= backfill.total_events {
+            return self
+                .finish_backfill(backfill, WebhookBackfillStatus::Completed)
+                .await;
+        }
+
+        task_queue
+            .enqueue_at(
+                RUN_WEBHOOK_BACKFILL_JOB,
+                json!({ "backfill_id": backfill.id }),
+                Utc::now() + BACKFILL_BATCH_INTERVAL,
+                3,
+            )
+            .await?;
+        Ok(backfill)
+    }
+
+    async fn finish_backfill(
+        &self,
+        mut backfill: WebhookBackfill,
+        status: WebhookBackfillStatus,
+    ) -> WebhookResult<WebhookBackfill> {
+        let now = Utc::now().to_rfc3339();
+        backfill.status = status;
+        backfill.completed_at = Some(now.clone());
+        backfill.updated_at = now;
+        self.webhook_repo
+            .update_webhook_backfill_progress(&backfill)
+            .await?;
+        self.webhook_repo
+            .clear_webhook_backfill_events(&backfill.id)
+            .await?;
+
+        info!(
+            "Backfill {} into webhook {} {}: {}/{} events replayed",
+            backfill.id,
+            backfill.webhook_id,
+            backfill.status,
+            backfill.processed_events,
+            backfill.total_events
+        );
+
+        Ok(backfill)
+    }
+
+    /// Queue one replayed event as a regular webhook delivery
+    ///
+    /// The payload is marked `replayed` and signed with the webhook's
+    /// current secret; webhooks that include snapshots get the
+    /// conversation's current state.
+    async fn queue_replay(
+        &self,
+        task_queue: &Arc<dyn TaskQueue>,
+        webhook: &Webhook,
+        event: &WebhookBackfillEvent,
+    ) -> WebhookResult<()> {
+        let mut payload: serde_json::Value = serde_json::from_str(&event.payload)
+            .map_err(|e| DomainError::Internal(format!("Invalid stored payload: {}", e)))?;
+        payload["replayed"] = json!(true);
+        if webhook.include_conversation_snapshot {
+            if let Some(conversation_id) = payload["data"]["conversation_id"].as_str() {
+                if let Some(snapshot) = self
+                    .webhook_repo
+                    .get_conversation_snapshot(conversation_id)
+                    .await?
+                {
+                    payload["data"]["conversation"] = json!(snapshot);
+                }
+            }
+        }
+
+        let body = serde_json::to_string(&payload)
+            .map_err(|e| DomainError::Internal(format!("Failed to serialize payload: {}", e)))?;
+        let signature = sign_payload(&body, &webhook.secret);
+        task_queue
+            .enqueue(
+                "deliver_webhook",
+                json!({
+                    "webhook_id": webhook.id,
+                    "url": webhook.url,
+                    "event_type": event.event_type,
+                    "body": body,
+                    "signature": signature
+                }),
+                3,
+            )
+            .await?;
+        Ok(())
+    }
+
+    fn task_queue(&self) -> WebhookResult<&Arc<dyn TaskQueue>> {
+        self.task_queue.as_ref().ok_or_else(|| {
+            WebhookError::Repository(DomainError::Internal(
+                "Task queue is not configured".to_string(),
+            ))
+        })
+    }
+}
+
+fn parse_backfill_time(field: &str, value: &str) -> WebhookResult<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&Utc))
+        .map_err(|_| WebhookError::Validation(format!("'{}' must be an RFC 3339 timestamp", field)))
+}
+
+/// Distinct events among stored deliveries, in delivery order
+///
+/// Snapshots are dropped before comparing, since they differ per webhook;
+/// payloads that don't parse are skipped.
+fn backfill_events(deliveries: Vec<WebhookDelivery>) -> Vec<WebhookBackfillEvent> {
+    let mut seen = HashSet::new();
+    let mut events = Vec::new();
+    for delivery in deliveries {
+        let Ok(mut payload) = serde_json::from_str::<serde_json::Value>(&delivery.payload) else {
+            continue;
+        };
+        if let Some(data) = payload["data"].as_object_mut() {
+            data.remove("conversation");
+        }
+        let payload = payload.to_string();
+        if !seen.insert(payload.clone()) {
+            continue;
+        }
+        events.push(WebhookBackfillEvent {
+            sequence: events.len() as i64 + 1,
+            event_type: delivery.event_type,
+            payload,
+            occurred_at: delivery.attempted_at.unwrap_or_default(),
+        });
+    }
+    events
 }
 
 #[cfg(test)]
@@ -248,6 +574,7 @@ mod tests {
             subscribed_events: vec!["conversation.created".to_string()],
             secret: "secret123456789012".to_string(),
             is_active: Some(true),
+            include_conversation_snapshot: None,
         };
 
         let result = service.create_webhook(request, "admin-123").await;
@@ -271,4 +598,19 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Offset"));
     }
+
+    #[tokio::test]
+    async fn test_validation_errors_are_typed() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let service = WebhookService::new(WebhookRepository::new(db));
+
+        let err = service.list_webhooks(0, 0).await.unwrap_err();
+        assert!(matches!(err, WebhookError::Validation(_)));
+
+        let api_err: crate::infrastructure::http::middleware::ApiError = err.into();
+        assert!(matches!(
+            api_err,
+            crate::infrastructure::http::middleware::ApiError::BadRequest(_)
+        ));
+    }
 }
@@ -15,15 +15,18 @@ use crate::domain::ports::message_repository::MessageRepository;
 use crate::domain::ports::notification_repository::NotificationRepository;
 use crate::domain::ports::oidc_repository::OidcRepository;
 use crate::domain::ports::role_repository::RoleRepository;
+use crate::domain::ports::sms_config_repository::SmsConfigRepository;
 use crate::domain::ports::tag_repository::TagRepository;
 use crate::domain::ports::task_queue::TaskQueue;
 use crate::domain::ports::team_repository::TeamRepository;
+use crate::domain::ports::telegram_bot_api::TelegramBotApi;
+use crate::domain::ports::telegram_config_repository::TelegramConfigRepository;
 use crate::domain::ports::user_repository::UserRepository;
 use crate::domain::ports::webhook_repository::WebhookRepository;
 use crate::infrastructure::http::middleware::{ApiError, AppState};
 use crate::infrastructure::persistence::Database;
 use crate::infrastructure::providers::connection_manager::{
-    ConnectionManager, InMemoryConnectionManager,
+    ConnectionManager, InMemoryConnectionManager, ResumableConnectionManager,
 };
 use crate::shared::utils::email_validator::validate_and_normalize_email;
 use crate::LocalEventBus;
@@ -37,6 +40,21 @@ pub async fn build_app_state(
         std::env::var("ATTACHMENT_STORAGE_PATH").unwrap_or_else(|_| "./attachments".to_string());
     std::fs::create_dir_all(&attachment_storage_path)?;
 
+    // Initialize LocalFileStorage
+    let file_storage = std::sync::Arc::new(
+        crate::infrastructure::storage::local::LocalFileStorage::new(std::path::PathBuf::from(
+            &attachment_storage_path,
+        )),
+    );
+
+    // Initialize AttachmentService (signs download links used in outbound emails)
+    let attachment_service = crate::application::services::AttachmentService::new(
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::attachment_repository::AttachmentRepository>,
+        file_storage.clone(),
+    )
+    .with_link_config(crate::application::services::AttachmentLinkConfig::from_env());
+
     // Initialize TaskSpawner
     let task_spawner = Arc::new(crate::infrastructure::runtime::tokio::TokioTaskSpawner::new())
         as Arc<dyn crate::domain::ports::task_spawner::TaskSpawner>;
@@ -53,8 +71,26 @@ pub async fn build_app_state(
             ),
         );
 
-    // Initialize delivery service with mock provider
-    let delivery_provider = std::sync::Arc::new(
+    // Workspace branding for the web UI and outbound email layouts
+    let branding_service = crate::application::services::BrandingService::new(Arc::new(
+        db.clone(),
+    )
+        as Arc<dyn crate::domain::ports::system_config_repository::SystemConfigRepository>);
+
+    // Chat widget visitor connections, keyed by conversation ID
+    let widget_connections: Arc<dyn ConnectionManager> = Arc::new(InMemoryConnectionManager::new());
+
+    // OAuth2 (XOAUTH2) sign-in for Gmail / Microsoft 365 inbox mailboxes
+    let email_oauth_service = crate::application::services::EmailOAuthService::new(
+        Arc::new(db.clone()) as Arc<dyn crate::domain::ports::email_repository::EmailRepository>,
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::email_oauth_repository::EmailOAuthRepository>,
+        Arc::new(crate::infrastructure::providers::HttpEmailOAuthClient::new()),
+    )
+    .with_settings(crate::application::services::EmailOAuthSettings::from_env());
+
+    // Initialize delivery service, routing by inbox channel type (email by default)
+    let email_delivery_provider = std::sync::Arc::new(
         crate::infrastructure::providers::email_delivery_provider::EmailDeliveryProvider::new(
             Arc::new(db.clone()) as Arc<dyn ConversationRepository>,
             Arc::new(db.clone())
@@ -63,17 +99,72 @@ pub async fn build_app_state(
                 as Arc<dyn crate::domain::ports::email_repository::EmailRepository>,
             Arc::new(db.clone()) as Arc<dyn AgentRepository>,
             template_repo.clone(),
+        )
+        .with_attachment_service(attachment_service.clone())
+        .with_oauth_service(email_oauth_service.clone())
+        .with_branding_service(branding_service.clone())
+        .with_smtp_pool(Arc::new(
+            crate::infrastructure::providers::SmtpConnectionPool::new(
+                crate::infrastructure::providers::SmtpPoolConfig::from_env(),
+            ),
+        )),
+    );
+    // Initialize Email Template Service (previews and test sends through inbox mail servers)
+    let email_template_service = crate::application::services::EmailTemplateService::new(
+        template_repo.clone(),
+        email_delivery_provider.clone(),
+    )
+    .with_branding_service(branding_service.clone());
+    let telegram_bot_api: Arc<dyn TelegramBotApi> =
+        Arc::new(crate::infrastructure::providers::TelegramBotClient::new());
+    let delivery_provider = std::sync::Arc::new(
+        crate::infrastructure::providers::ChannelDeliveryProvider::new(
+            Arc::new(db.clone()) as Arc<dyn ConversationRepository>,
+            Arc::new(db.clone()) as Arc<dyn InboxRepository>,
+            email_delivery_provider.clone(),
+        )
+        .with_provider(
+            crate::domain::entities::CHAT_CHANNEL_TYPE,
+            Arc::new(
+                crate::infrastructure::providers::ChatWidgetDeliveryProvider::new(
+                    widget_connections.clone(),
+                ),
+            ),
+        )
+        .with_provider(
+            crate::domain::entities::SMS_INBOX_CHANNEL_TYPE,
+            Arc::new(
+                crate::infrastructure::providers::TwilioDeliveryProvider::new(
+                    Arc::new(db.clone()) as Arc<dyn ConversationRepository>,
+                    Arc::new(db.clone())
+                        as Arc<dyn crate::domain::ports::channel_repository::ChannelRepository>,
+                    Arc::new(db.clone()) as Arc<dyn SmsConfigRepository>,
+                    Arc::new(
+                        crate::infrastructure::providers::TelegramDeliveryProvider::new(
+                            Arc::new(db.clone()) as Arc<dyn ConversationRepository>,
+                            Arc::new(db.clone())
+                                as Arc<
+                                    dyn crate::domain::ports::channel_repository::ChannelRepository,
+                                >,
+                            Arc::new(db.clone()) as Arc<dyn TelegramConfigRepository>,
+                            telegram_bot_api.clone(),
+                            email_delivery_provider,
+                        )
+                        .with_attachment_service(attachment_service.clone()),
+                    ),
+                ),
+            ),
         ),
     );
     let delivery_service = crate::application::services::DeliveryService::new(
         Arc::new(db.clone()) as Arc<dyn MessageRepository>,
         delivery_provider,
     );
-    tracing::info!("Delivery service initialized with mock provider");
+    tracing::info!("Delivery service initialized");
 
     // Initialize notification service
     let notification_repo: Arc<dyn NotificationRepository> = Arc::new(db.clone());
-    let notification_service = crate::NotificationService::new(Some(notification_repo));
+    let notification_service = crate::NotificationService::new(Some(notification_repo.clone()));
     tracing::info!("Notification service initialized");
 
     // Initialize availability service
@@ -99,7 +190,7 @@ pub async fn build_app_state(
     let tag_repo = TagRepository::new(db.clone());
 
     // Initialize automation service
-    let action_executor = crate::domain::services::action_executor::ActionExecutor::new(
+    let mut action_executor = crate::domain::services::action_executor::ActionExecutor::new(
         std::sync::Arc::new(db.clone()) as std::sync::Arc<dyn ConversationRepository>,
         std::sync::Arc::new(db.clone()) as std::sync::Arc<dyn UserRepository>,
         std::sync::Arc::new(db.clone()) as std::sync::Arc<dyn AgentRepository>,
@@ -107,14 +198,10 @@ pub async fn build_app_state(
         tag_repo.clone(),
         std::sync::Arc::new(db.clone()) as std::sync::Arc<dyn ConversationTagRepository>,
     );
-    let automation_service = std::sync::Arc::new(crate::AutomationService::new(
-        std::sync::Arc::new(db.clone()) as std::sync::Arc<dyn AutomationRepository>,
-        action_executor,
-        AutomationConfig::default(),
-    ));
+    action_executor.set_notification_repo(notification_repo.clone());
     // Initialize webhook service
     let webhook_repo = WebhookRepository::new(db.clone());
-    let webhook_service = crate::WebhookService::new(webhook_repo);
+    let mut webhook_service = crate::WebhookService::new(webhook_repo);
 
     // Initialize Conversation Tag Service
     let conversation_tag_service = ConversationTagService::new(
@@ -136,18 +223,70 @@ pub async fn build_app_state(
     let tag_service = TagService::new(tag_repo.clone());
     let role_service = RoleService::new(Arc::new(db.clone()) as Arc<dyn RoleRepository>);
     tracing::info!("Automation service initialized");
-    let connection_manager: Arc<dyn ConnectionManager> = Arc::new(InMemoryConnectionManager::new());
+    let connection_manager: Arc<dyn ConnectionManager> = Arc::new(
+        ResumableConnectionManager::with_connections(
+            notification_repo.clone(),
+            agent_connections().await?,
+        ),
+    );
     tracing::info!("Connection manager initialized");
 
-    // Initialize rate limiter
+    // Initialize rate limiter (limits come from system config, defaulting to 5 per 15 minutes)
     let rate_limiter = crate::shared::rate_limiter::AuthRateLimiter::new();
-    tracing::info!("Rate limiter initialized (5 attempts per 15 minutes)");
+    let system_config_service = crate::application::services::SystemConfigService::new(
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::system_config_repository::SystemConfigRepository>,
+    )
+    .with_rate_limiter(rate_limiter.clone());
+    if let Err(e) = system_config_service.apply_runtime_settings().await {
+        tracing::warn!("Failed to apply stored system config: {}", e);
+    }
+
+    // Initialize MaintenanceService and restore the stored switch
+    let maintenance_mode = crate::shared::maintenance::MaintenanceMode::new();
+    let maintenance_service = crate::application::services::MaintenanceService::new(
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::system_config_repository::SystemConfigRepository>,
+        maintenance_mode.clone(),
+    );
+    if let Err(e) = maintenance_service.load().await {
+        tracing::error!("Failed to load maintenance mode: {}", e);
+    }
+    let (max_attempts, window_minutes) = rate_limiter.limits();
+    tracing::info!(
+        "Rate limiter initialized ({} attempts per {} minutes)",
+        max_attempts,
+        window_minutes
+    );
 
     // Initialize TaskQueue
     let task_queue = std::sync::Arc::new(crate::infrastructure::workers::SqliteTaskQueue::new(
         db.clone(),
     ));
 
+    // Automation rules schedule their Wait steps on the task queue
+    let mut automation_service = crate::AutomationService::new(
+        std::sync::Arc::new(db.clone()) as std::sync::Arc<dyn AutomationRepository>,
+        action_executor,
+        AutomationConfig::from_env(),
+    );
+    automation_service.set_task_queue(task_queue.clone());
+    automation_service.set_conversation_repo(
+        std::sync::Arc::new(db.clone()) as std::sync::Arc<dyn ConversationRepository>
+    );
+    automation_service.set_sla_service(sla_service.clone());
+    automation_service.set_cascade_alerts(
+        std::sync::Arc::new(db.clone()) as std::sync::Arc<dyn UserRepository>,
+        std::sync::Arc::new(db.clone()) as std::sync::Arc<dyn NotificationRepository>,
+    );
+    let automation_service = std::sync::Arc::new(automation_service);
+
+    // Dead job inspection and requeueing for admins
+    let job_service = crate::application::services::JobService::new(task_queue.clone());
+
+    // Webhook backfills queue their replays on the task queue
+    webhook_service.set_task_queue(task_queue.clone());
+
     // Enqueue initial maintenance jobs
     let q_init = task_queue.clone();
     task_spawner.spawn(Box::pin(async move {
@@ -181,6 +320,16 @@ pub async fn build_app_state(
         {
             tracing::error!("Failed to enqueue initial check_sla_breaches: {}", e);
         }
+        if let Err(e) = q_init
+            .enqueue(
+                crate::application::services::CHECK_RESPONSE_REMINDERS_JOB,
+                serde_json::Value::Null,
+                3,
+            )
+            .await
+        {
+            tracing::error!("Failed to enqueue initial check_response_reminders: {}", e);
+        }
     }));
 
     // Initialize Session Service
@@ -203,13 +352,126 @@ pub async fn build_app_state(
         crate::application::services::UserService::new(std::sync::Arc::new(db.clone()));
     tracing::info!("User service initialized");
 
+    // Transactional email (password reset and contact verification links)
+    let smtp_config = match crate::application::services::SmtpConfig::from_env() {
+        Ok(smtp_config) => Some(smtp_config),
+        Err(e) => {
+            tracing::warn!("SMTP not configured, password reset and contact verification emails will not be sent: {}", e);
+            None
+        }
+    };
+
     // Initialize Contact Service
-    let contact_service = crate::application::services::ContactService::new(
+    let mut contact_service = crate::application::services::ContactService::new(
         std::sync::Arc::new(db.clone()),
         std::sync::Arc::new(db.clone()),
     );
+    if let Some(ref smtp_config) = smtp_config {
+        contact_service.set_email_sender(
+            Arc::new(crate::application::services::SmtpEmailSender::new(
+                smtp_config.clone(),
+            )),
+            &smtp_config.reset_base_url,
+        );
+    }
     tracing::info!("Contact service initialized");
 
+    // Initialize Contact Note Service
+    let contact_note_service = crate::application::services::ContactNoteService::new(
+        std::sync::Arc::new(db.clone()),
+        std::sync::Arc::new(db.clone()),
+    );
+    tracing::info!("Contact note service initialized");
+
+    // Initialize GdprService (contact data exports and erasures)
+    let mut gdpr_service = crate::application::services::GdprService::new(Arc::new(db.clone()));
+    gdpr_service.set_file_storage(file_storage.clone());
+
+    // Initialize SavedViewService (saved conversation search queries)
+    let saved_view_service =
+        crate::application::services::SavedViewService::new(Arc::new(db.clone()));
+
+    // Initialize Reporting Service
+    let reporting_service =
+        crate::application::services::ReportingService::new(std::sync::Arc::new(db.clone()));
+    tracing::info!("Reporting service initialized");
+
+    // Initialize Activity Service (admin activity feed)
+    let activity_service =
+        crate::application::services::ActivityService::new(std::sync::Arc::new(db.clone()));
+    tracing::info!("Activity service initialized");
+
+    // Initialize Junk Service (junk status, sender reputation and blocklists)
+    let mut junk_service = crate::application::services::JunkService::new(
+        std::sync::Arc::new(db.clone()),
+        std::sync::Arc::new(db.clone()),
+        std::sync::Arc::new(db.clone()),
+    );
+    junk_service.set_event_bus(event_bus.clone());
+    tracing::info!("Junk service initialized");
+
+    // Initialize Locale Service (per-user locale preference)
+    let locale_service =
+        crate::application::services::LocaleService::new(std::sync::Arc::new(db.clone()));
+    tracing::info!(
+        "Locales available: {} (default {})",
+        crate::shared::i18n::catalog().locales().join(", "),
+        crate::shared::i18n::catalog().default_locale()
+    );
+
+    // Initialize Email Participant Service (per-thread addresses and suppression)
+    let email_participant_service = crate::application::services::EmailParticipantService::new(
+        std::sync::Arc::new(db.clone()),
+        std::sync::Arc::new(db.clone()),
+        std::sync::Arc::new(db.clone()),
+    );
+
+    // Initialize Conversation Contact Service (additional contacts per conversation)
+    let conversation_contact_service =
+        crate::application::services::ConversationContactService::new(
+            std::sync::Arc::new(db.clone()),
+            std::sync::Arc::new(db.clone()),
+            std::sync::Arc::new(db.clone()),
+            std::sync::Arc::new(db.clone()),
+            std::sync::Arc::new(db.clone()),
+        );
+
+    // Initialize Diagnostics Service (slow-query log, opt-in via SLOW_QUERY_LOG_ENABLED)
+    let diagnostics_service =
+        crate::application::services::DiagnosticsService::new(std::sync::Arc::new(db.clone()));
+
+    // Initialize API key usage service (hourly request/error counts per key)
+    let api_key_usage_service = crate::application::services::ApiKeyUsageService::new(
+        std::sync::Arc::new(db.clone()),
+        std::sync::Arc::new(db.clone()),
+    );
+    tracing::info!("API key usage service initialized");
+
+    // Initialize Priority Matrix Service (contact tier × tag → priority)
+    let priority_matrix_service = crate::application::services::PriorityMatrixService::new(
+        std::sync::Arc::new(db.clone()),
+        std::sync::Arc::new(db.clone()),
+        std::sync::Arc::new(db.clone()),
+        tag_repo.clone(),
+        conversation_priority_service.clone(),
+    );
+    tracing::info!("Priority matrix service initialized");
+
+    // Initialize Import Service (helpdesk migrations run on the task queue)
+    let import_service = crate::application::services::ImportService::new(
+        std::sync::Arc::new(db.clone()),
+        std::sync::Arc::new(db.clone()),
+        std::sync::Arc::new(db.clone()),
+        std::sync::Arc::new(db.clone()),
+        tag_repo.clone(),
+        task_queue.clone(),
+    )
+    .with_attachments(
+        attachment_service.clone(),
+        std::sync::Arc::new(crate::infrastructure::providers::HttpFileDownloader::new()),
+    );
+    tracing::info!("Import service initialized");
+
     // Initialize Repositories
     let email_repo: std::sync::Arc<dyn crate::domain::ports::email_repository::EmailRepository> =
         std::sync::Arc::new(db.clone());
@@ -225,6 +487,32 @@ pub async fn build_app_state(
     > = std::sync::Arc::new(db.clone());
     let team_repo: std::sync::Arc<dyn TeamRepository> = std::sync::Arc::new(db.clone());
     let team_service = crate::application::services::TeamService::new(team_repo.clone());
+    let holiday_calendar_service = crate::application::services::HolidayCalendarService::new(
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::holiday_repository::HolidayRepository>,
+        team_repo.clone(),
+    );
+    let shift_service = crate::application::services::ShiftService::new(
+        Arc::new(db.clone()) as Arc<dyn crate::domain::ports::shift_repository::ShiftRepository>,
+        team_repo.clone(),
+        availability_service.clone(),
+    );
+    let csat_service = crate::application::services::CsatService::new(
+        Arc::new(db.clone()) as Arc<dyn crate::domain::ports::csat_repository::CsatRepository>,
+        Arc::new(db.clone()) as Arc<dyn ConversationRepository>,
+        event_bus.clone(),
+    );
+    let csat_service = match std::env::var("CSAT_LINK_SECRET") {
+        Ok(secret) if !secret.is_empty() => csat_service.with_survey_links(
+            &secret,
+            &std::env::var("CSAT_LINK_BASE_URL")
+                .unwrap_or_else(|_| "http://localhost:3000".to_string()),
+        ),
+        _ => {
+            tracing::warn!("CSAT_LINK_SECRET not set; survey links will not survive a restart");
+            csat_service
+        }
+    };
 
     // Initialize Assignment Service
     let assignment_repo: Arc<dyn AssignmentRepository> = Arc::new(db.clone());
@@ -243,10 +531,29 @@ pub async fn build_app_state(
             connection_manager.clone(),
         );
         service.set_sla_service(Arc::new(sla_service.clone()));
+        service.set_language_routing_repo(Arc::new(db.clone())
+            as Arc<
+                dyn crate::domain::ports::language_routing_repository::LanguageRoutingRepository,
+            >);
+        service.set_skill_repo(Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::skill_repository::SkillRepository>);
         service
     };
     tracing::info!("Assignment service initialized");
 
+    // Initialize Conversation Transfer Service (handovers with notes)
+    let conversation_transfer_service =
+        crate::application::services::ConversationTransferService::new(
+            Arc::new(db.clone()),
+            conversation_repo.clone(),
+            Arc::new(db.clone()) as Arc<dyn AgentRepository>,
+            team_repo.clone(),
+            Arc::new(db.clone()) as Arc<dyn AssignmentRepository>,
+            event_bus.clone(),
+            connection_manager.clone(),
+        );
+    tracing::info!("Conversation transfer service initialized");
+
     // Initialize OIDC service
     let oidc_repository = OidcRepository::new(db.clone());
     let oidc_service = crate::application::services::OidcService::new(
@@ -259,18 +566,6 @@ pub async fn build_app_state(
 
     // Initialize Services (wrapping repositories)
     let email_service = crate::application::services::EmailService::new(email_repo.clone());
-    // Initialize LocalFileStorage
-    let file_storage = std::sync::Arc::new(
-        crate::infrastructure::storage::local::LocalFileStorage::new(std::path::PathBuf::from(
-            &attachment_storage_path,
-        )),
-    );
-
-    let attachment_service = crate::application::services::AttachmentService::new(
-        attachment_repo.clone(),
-        file_storage.clone(),
-    );
-
     let conversation_service = crate::application::services::ConversationService::new(
         conversation_repo.clone(),
         user_repo.clone(),
@@ -278,17 +573,122 @@ pub async fn build_app_state(
         team_repo.clone(),
     );
 
-    let message_service = crate::application::services::MessageService::with_all_services(
+    // Initialize AutoTagService (inbox keyword tagging)
+    let auto_tag_service = crate::application::services::AutoTagService::new(
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::auto_tag_repository::AutoTagRepository>,
+        Arc::new(db.clone()) as Arc<dyn InboxRepository>,
+        tag_repo.clone(),
+        Arc::new(db.clone()) as Arc<dyn ConversationTagRepository>,
+        conversation_repo.clone(),
+        message_repo.clone(),
+        event_bus.clone(),
+    );
+    tracing::info!("Auto-tag service initialized");
+
+    // Initialize AttachmentRuleService (inbox tagging and routing by attachment type)
+    let attachment_rule_service = crate::application::services::AttachmentRuleService::new(
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::attachment_rule_repository::AttachmentRuleRepository>,
+        Arc::new(db.clone()) as Arc<dyn InboxRepository>,
+        tag_repo.clone(),
+        team_repo.clone(),
+        Arc::new(db.clone()) as Arc<dyn ConversationTagRepository>,
+        assignment_service.clone(),
+        event_bus.clone(),
+    );
+
+    let mut message_service = crate::application::services::MessageService::with_all_services(
         message_repo.clone(),
         conversation_repo.clone(),
         delivery_service.clone(),
         event_bus.clone(),
         connection_manager.clone(),
     );
+    message_service.set_auto_tag_service(auto_tag_service.clone());
+
+    // Initialize SentimentService (rule-based scoring of inbound messages)
+    let sentiment_service = crate::application::services::SentimentService::new(
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::sentiment_repository::SentimentRepository>,
+        conversation_repo.clone(),
+        Arc::new(crate::domain::services::LexiconSentimentAnalyzer::new()),
+        event_bus.clone(),
+    );
+    message_service.set_sentiment_service(sentiment_service.clone());
+
+    // Initialize ConversationSummaryService (rolling list previews)
+    let conversation_summary_service = crate::application::services::ConversationSummaryService::new(
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::conversation_summary_repository::ConversationSummaryRepository>,
+        conversation_repo.clone(),
+        message_repo.clone(),
+    );
+    message_service.set_summary_service(conversation_summary_service.clone());
+    message_service.set_assignment_service(assignment_service.clone());
+
+    // Initialize ChannelService (messages pushed in by external channels)
+    let channel_service = crate::application::services::ChannelService::new(
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::channel_repository::ChannelRepository>,
+        Arc::new(db.clone()) as Arc<dyn InboxRepository>,
+        contact_repo.clone(),
+        conversation_repo.clone(),
+        message_repo.clone(),
+        message_service.clone(),
+    );
+    let chat_widget_service = crate::application::services::ChatWidgetService::new(
+        Arc::new(db.clone()) as Arc<dyn InboxRepository>,
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::channel_repository::ChannelRepository>,
+        contact_repo.clone(),
+        conversation_repo.clone(),
+        message_repo.clone(),
+        channel_service.clone(),
+        widget_connections,
+    )
+    .with_identity_secret(&std::env::var("CHAT_WIDGET_IDENTITY_SECRET").unwrap_or_default());
+    let sms_service = crate::application::services::SmsService::new(
+        Arc::new(db.clone()) as Arc<dyn SmsConfigRepository>,
+        Arc::new(db.clone()) as Arc<dyn InboxRepository>,
+        channel_service.clone(),
+    )
+    .with_webhook_base_url(
+        &std::env::var("SMS_WEBHOOK_BASE_URL")
+            .unwrap_or_else(|_| "http://localhost:3000".to_string()),
+    );
+    let telegram_service = crate::application::services::TelegramService::new(
+        Arc::new(db.clone()) as Arc<dyn TelegramConfigRepository>,
+        Arc::new(db.clone()) as Arc<dyn InboxRepository>,
+        channel_service.clone(),
+        telegram_bot_api,
+    )
+    .with_attachment_service(attachment_service.clone())
+    .with_attachment_rule_service(attachment_rule_service.clone())
+    .with_webhook_base_url(
+        &std::env::var("TELEGRAM_WEBHOOK_BASE_URL")
+            .unwrap_or_else(|_| "http://localhost:3000".to_string()),
+    );
+    let message_reaction_service = crate::application::services::MessageReactionService::new(
+        Arc::new(db.clone())
+            as Arc<dyn crate::domain::ports::message_reaction_repository::MessageReactionRepository>,
+        message_repo.clone(),
+        conversation_repo.clone(),
+    )
+    .with_connection_manager(connection_manager.clone());
 
     // Initialize MacroService
     let macro_repo = crate::domain::ports::macro_repository::MacroRepository::new(db.clone());
-    let macro_service = crate::application::services::MacroService::new(macro_repo);
+    let macro_service = crate::application::services::MacroService::new(macro_repo.clone());
+
+    // Initialize ConfigBundleService
+    let config_bundle_service = crate::application::services::ConfigBundleService::new(
+        Arc::new(db.clone()) as Arc<dyn AutomationRepository>,
+        macro_repo,
+        TagRepository::new(db.clone()),
+        Arc::new(db.clone()) as Arc<dyn crate::domain::ports::sla_repository::SlaRepository>,
+        WebhookRepository::new(db.clone()),
+    );
 
     // Initialize AuthService
     let auth_service = crate::application::services::AuthService::new(
@@ -301,12 +701,35 @@ pub async fn build_app_state(
     // Initialize PasswordResetService
     let password_reset_repo =
         crate::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone());
-    let password_reset_service = crate::application::services::PasswordResetService::new(
+    let mut password_reset_service = crate::application::services::PasswordResetService::new(
         password_reset_repo,
         Arc::new(db.clone()) as Arc<dyn UserRepository>,
     );
+    password_reset_service.set_template_repo(template_repo.clone());
+    password_reset_service.set_branding_service(branding_service.clone());
+    password_reset_service.set_user_locale_repo(Arc::new(db.clone()));
+    if let Some(smtp_config) = smtp_config {
+        password_reset_service.set_email_sender(Arc::new(
+            crate::application::services::SmtpEmailSender::new(smtp_config),
+        ));
+    }
     tracing::info!("Password reset service initialized");
 
+    // Initialize AgentOnboardingService (bulk agent invitations)
+    let agent_onboarding_service = crate::application::services::AgentOnboardingService::new(
+        agent_service.clone(),
+        team_service.clone(),
+        password_reset_service.clone(),
+    );
+
+    // Initialize ResponseReminderService (reminders about conversations waiting for a reply)
+    let response_reminder_service = crate::application::services::ResponseReminderService::new(
+        Arc::new(db.clone()),
+        Arc::new(db.clone()) as Arc<dyn AgentRepository>,
+        notification_repo.clone(),
+        connection_manager.clone(),
+    );
+
     // Initialize AuthLoggerService
     let auth_logger_service =
         crate::application::services::AuthLoggerService::new(Arc::new(db.clone()));
@@ -330,6 +753,45 @@ pub async fn build_app_state(
         .await;
     }));
 
+    // Start priority matrix listener background task
+    {
+        let matrix_event_bus = event_bus.clone();
+        let matrix_service = priority_matrix_service.clone();
+        task_spawner.spawn(Box::pin(async move {
+            crate::application::listeners::priority_matrix::run_priority_matrix_listener(
+                matrix_event_bus,
+                matrix_service,
+            )
+            .await;
+        }));
+    }
+
+    // Start conversation summary listener background task
+    {
+        let summary_event_bus = event_bus.clone();
+        let summary_service = conversation_summary_service.clone();
+        task_spawner.spawn(Box::pin(async move {
+            crate::application::listeners::conversation_summary::run_conversation_summary_listener(
+                summary_event_bus,
+                summary_service,
+            )
+            .await;
+        }));
+    }
+
+    // Start skill routing listener background task
+    {
+        let skill_event_bus = event_bus.clone();
+        let skill_assignment_service = assignment_service.clone();
+        task_spawner.spawn(Box::pin(async move {
+            crate::application::listeners::skill_routing::run_skill_routing_listener(
+                skill_event_bus,
+                skill_assignment_service,
+            )
+            .await;
+        }));
+    }
+
     // Start webhook worker background task
     let webhook_repo_for_worker = WebhookRepository::new(db.clone());
     let webhook_event_bus = event_bus.clone();
@@ -347,19 +809,26 @@ pub async fn build_app_state(
     // Start notification cleanup background task
     {
         let cleanup_db = db.clone();
+        let cleanup_config = system_config_service.clone();
         task_spawner.spawn(Box::pin(async move {
             use tokio::time::{interval, Duration};
             let mut cleanup_interval = interval(Duration::from_secs(24 * 60 * 60)); // 24 hours
 
-            tracing::info!(
-                "Notification cleanup task started (24-hour interval, 30-day retention)"
-            );
+            tracing::info!("Notification cleanup task started (24-hour interval)");
 
             loop {
                 cleanup_interval.tick().await;
 
-                match crate::NotificationService::cleanup_old_notifications(&cleanup_db, Some(30))
+                // Read on every run so admin changes apply without a restart
+                let retention_days = cleanup_config
+                    .value(crate::domain::entities::NOTIFICATION_RETENTION_DAYS_KEY)
                     .await
+                    .unwrap_or(30);
+                match crate::NotificationService::cleanup_old_notifications(
+                    &cleanup_db,
+                    Some(retention_days as i32),
+                )
+                .await
                 {
                     Ok(count) => {
                         tracing::info!(
@@ -375,6 +844,57 @@ pub async fn build_app_state(
         }));
     }
 
+    // Start notification stream cleanup background task
+    {
+        let cleanup_db = db.clone();
+        let cleanup_config = system_config_service.clone();
+        task_spawner.spawn(Box::pin(async move {
+            use tokio::time::{interval, Duration};
+            let mut cleanup_interval = interval(Duration::from_secs(60 * 60)); // 1 hour
+
+            tracing::info!("Notification stream cleanup task started (1-hour interval)");
+
+            loop {
+                cleanup_interval.tick().await;
+
+                let retention_hours = cleanup_config
+                    .value(crate::domain::entities::NOTIFICATION_STREAM_RETENTION_HOURS_KEY)
+                    .await
+                    .ok();
+                if let Err(e) = crate::NotificationService::cleanup_stream_events(
+                    &cleanup_db,
+                    retention_hours,
+                )
+                .await
+                {
+                    tracing::error!("Notification stream cleanup failed: {}", e);
+                }
+            }
+        }));
+    }
+
+    // Start API key usage cleanup background task
+    {
+        let usage_service = api_key_usage_service.clone();
+        task_spawner.spawn(Box::pin(async move {
+            use tokio::time::{interval, Duration};
+            let mut cleanup_interval = interval(Duration::from_secs(24 * 60 * 60)); // 24 hours
+
+            loop {
+                cleanup_interval.tick().await;
+
+                match usage_service.prune_old_usage().await {
+                    Ok(count) => {
+                        tracing::info!("API key usage cleanup removed {} hourly buckets", count);
+                    }
+                    Err(e) => {
+                        tracing::error!("API key usage cleanup failed: {}", e);
+                    }
+                }
+            }
+        }));
+    }
+
     // Initialize TimeService
     let time_service =
         std::sync::Arc::new(crate::infrastructure::runtime::tokio::TokioTimeService::new());
@@ -388,7 +908,7 @@ pub async fn build_app_state(
         ),
     );
 
-    let email_worker = crate::infrastructure::providers::email_receiver::EmailPollingWorker::new(
+    let mut email_worker = crate::infrastructure::providers::email_receiver::EmailPollingWorker::new(
         email_repo.clone(),
         conversation_repo.clone(),
         message_repo.clone(),
@@ -403,6 +923,18 @@ pub async fn build_app_state(
         distributed_lock.clone(),
         time_service.clone(),
     );
+    email_worker.set_auto_tag_service(auto_tag_service.clone());
+    email_worker.set_attachment_rule_service(attachment_rule_service.clone());
+    email_worker.set_sentiment_service(sentiment_service.clone());
+    email_worker.set_summary_service(conversation_summary_service.clone());
+    email_worker.set_junk_service(junk_service.clone());
+    email_worker.set_ingestion_limits(
+        crate::infrastructure::providers::email_receiver::EmailIngestionLimits::from_env(),
+    );
+    email_worker.set_event_bus(event_bus.clone());
+    email_worker.set_maintenance_mode(maintenance_mode.clone());
+    email_worker.set_oauth_service(email_oauth_service.clone());
+    email_worker.set_conversation_contact_repo(std::sync::Arc::new(db.clone()));
     task_spawner.spawn(Box::pin(async move {
         email_worker.run().await;
     }));
@@ -411,16 +943,22 @@ pub async fn build_app_state(
     // Spawn JobProcessor for background tasks
     let oidc_repo = OidcRepository::new(db.clone());
     let webhook_repo = WebhookRepository::new(db.clone());
-    let job_processor = crate::infrastructure::workers::job_worker::JobProcessor::new(
+    let mut job_processor = crate::infrastructure::workers::job_worker::JobProcessor::new(
         task_queue.clone(),
         oidc_repo.clone(),
         webhook_repo.clone(),
         rate_limiter.clone(),
         availability_service.clone(),
+        shift_service.clone(),
         sla_service.clone(),
         session_service.clone(),
         time_service.clone(),
     );
+    job_processor.set_import_service(import_service.clone());
+    job_processor.set_automation_service(automation_service.clone());
+    job_processor.set_webhook_service(webhook_service.clone());
+    job_processor.set_maintenance_mode(maintenance_mode.clone());
+    job_processor.set_response_reminder_service(response_reminder_service.clone());
     task_spawner.spawn(Box::pin(async move {
         job_processor.run().await;
     }));
@@ -437,29 +975,92 @@ pub async fn build_app_state(
         conversation_tag_service: conversation_tag_service.clone(),
         connection_manager,
         rate_limiter,
+        trusted_proxies: crate::shared::trusted_proxies::TrustedProxies::from_env(),
         webhook_service: webhook_service.clone(),
         tag_service: tag_service.clone(),
+        auto_tag_service,
+        attachment_rule_service,
         agent_service: agent_service.clone(),
+        agent_onboarding_service,
+        response_reminder_service,
         user_service: user_service.clone(),
         contact_service: contact_service.clone(),
+        contact_note_service,
+        gdpr_service,
+        conversation_transfer_service,
+        saved_view_service,
+        reporting_service,
+        activity_service,
+        junk_service,
+        locale_service,
+        api_key_usage_service,
+        priority_matrix_service,
+        import_service,
+        maintenance_service,
+        diagnostics_service,
+        job_service,
         session_service: session_service.clone(),
         email_service,
+        email_oauth_service,
+        email_participant_service,
+        conversation_contact_service,
+        email_template_service,
         attachment_service,
         conversation_service,
         message_service,
+        message_reaction_service,
+        channel_service,
+        chat_widget_service,
+        sms_service,
+        telegram_service,
         oidc_service,
         macro_service,
+        config_bundle_service,
         role_service,
         inbox_service,
         auth_service,
         password_reset_service,
         team_service,
+        holiday_calendar_service,
+        shift_service,
         conversation_priority_service,
+        csat_service,
+        sentiment_service,
+        system_config_service,
+        branding_service,
         assignment_service: assignment_service.clone(),
         auth_logger_service,
     })
 }
 
+/// Live agent connections: shared across instances through Redis when
+/// REDIS_URL is set, otherwise held in memory by this process only
+async fn agent_connections() -> Result<Arc<dyn ConnectionManager>, Box<dyn std::error::Error>> {
+    let Some(redis_url) = std::env::var("REDIS_URL").ok().filter(|url| !url.is_empty()) else {
+        return Ok(Arc::new(InMemoryConnectionManager::new()));
+    };
+
+    #[cfg(feature = "redis-presence")]
+    {
+        let manager = crate::infrastructure::providers::RedisConnectionManager::connect(
+            &redis_url,
+            crate::infrastructure::providers::RedisPresenceConfig::from_env(),
+        )
+        .await?;
+        Ok(Arc::new(manager))
+    }
+
+    #[cfg(not(feature = "redis-presence"))]
+    {
+        let _ = redis_url;
+        tracing::warn!(
+            "REDIS_URL is set but the redis-presence feature is disabled; \
+             real-time events will only reach agents connected to this instance"
+        );
+        Ok(Arc::new(InMemoryConnectionManager::new()))
+    }
+}
+
 pub async fn initialize_admin(db: &Database, config: &Config) -> Result<(), ApiError> {
     tracing::info!("Checking for admin user initialization");
 
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+/// Kind of system-wide action shown in the admin activity feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityType {
+    ConversationCreated,
+    SlaBreached,
+    RuleExecuted,
+    WebhookFailed,
+}
+
+impl ActivityType {
+    pub const ALL: [ActivityType; 4] = [
+        ActivityType::ConversationCreated,
+        ActivityType::SlaBreached,
+        ActivityType::RuleExecuted,
+        ActivityType::WebhookFailed,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActivityType::ConversationCreated => "conversation_created",
+            ActivityType::SlaBreached => "sla_breached",
+            ActivityType::RuleExecuted => "rule_executed",
+            ActivityType::WebhookFailed => "webhook_failed",
+        }
+    }
+}
+
+impl std::fmt::Display for ActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for ActivityType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ActivityType::ALL
+            .into_iter()
+            .find(|t| t.as_str() == s)
+            .ok_or_else(|| format!("Invalid activity type: {}", s))
+    }
+}
+
+/// One entry of the admin activity feed
+///
+/// Entries aren't stored on their own; they are read from the conversation,
+/// SLA, rule evaluation and webhook delivery records they describe.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActivityItem {
+    /// Id of the underlying record (conversation, SLA event, evaluation log or delivery)
+    pub id: String,
+    #[serde(rename = "type")]
+    pub activity_type: ActivityType,
+    pub occurred_at: String,
+    pub conversation_id: Option<String>,
+    pub summary: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActivityFeedResponse {
+    pub items: Vec<ActivityItem>,
+    /// Pass as `before` to fetch the next (older) page; absent on the last page
+    pub next_before: Option<String>,
+}
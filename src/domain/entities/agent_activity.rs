@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::shared::validation::{Validate, ValidationErrors};
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(type_name = "TEXT", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
@@ -71,6 +73,15 @@ pub struct SetAvailabilityRequest {
     pub status: crate::domain::entities::user::AgentAvailability,
 }
 
+impl Validate for SetAvailabilityRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        // Set by the inactivity reassignment, never by the agent
+        if self.status == crate::domain::entities::user::AgentAvailability::AwayAndReassigning {
+            errors.add("status", "away_and_reassigning can't be set manually");
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct AvailabilityResponse {
     pub agent_id: String,
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::shared::validation::{Validate, ValidationErrors};
+
 /// Request DTO for generating an API key
 #[derive(Debug, Deserialize)]
 pub struct GenerateApiKeyRequest {
@@ -7,6 +9,12 @@ pub struct GenerateApiKeyRequest {
     pub description: String,
 }
 
+impl Validate for GenerateApiKeyRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("description", &self.description, 3, 100);
+    }
+}
+
 /// Response DTO for API key generation (includes secret - returned only once)
 #[derive(Debug, Serialize)]
 pub struct ApiKeyResponse {
@@ -43,3 +51,68 @@ pub struct ApiKeyListResponse {
     /// Pagination metadata
     pub pagination: super::user::PaginationMetadata,
 }
+
+/// How long hourly API key usage is kept
+pub const API_KEY_USAGE_RETENTION_DAYS: i64 = 30;
+
+/// Default window for usage views (last 24 hours)
+pub const DEFAULT_API_KEY_USAGE_WINDOW_HOURS: i64 = 24;
+
+/// Requests made with an API key during one hour
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ApiKeyUsageBucket {
+    /// Start of the hour (YYYY-MM-DDTHH:00:00Z)
+    pub hour: String,
+    pub request_count: i64,
+    /// Requests answered with a 4xx or 5xx status
+    pub error_count: i64,
+    pub last_seen_at: String,
+    pub last_status: i64,
+    pub last_ip: Option<String>,
+}
+
+/// Usage of one API key over a window, with hourly detail
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiKeyUsageResponse {
+    pub api_key: String,
+    pub agent_id: String,
+    pub window_hours: i64,
+    pub request_count: i64,
+    pub error_count: i64,
+    /// Share of requests that failed, between 0 and 1
+    pub error_rate: f64,
+    pub last_seen_at: Option<String>,
+    pub last_status: Option<i64>,
+    pub last_ip: Option<String>,
+    /// Hours with at least one request, oldest first
+    pub hourly: Vec<ApiKeyUsageBucket>,
+}
+
+/// Totals for one API key in the admin usage view
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKeyUsageSummary {
+    pub api_key: String,
+    pub agent_id: String,
+    pub request_count: i64,
+    pub error_count: i64,
+    pub error_rate: f64,
+    pub last_seen_at: Option<String>,
+}
+
+/// Usage across all API keys over a window, busiest keys first
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiKeyUsageOverview {
+    pub window_hours: i64,
+    pub request_count: i64,
+    pub error_count: i64,
+    pub keys: Vec<ApiKeyUsageSummary>,
+}
+
+/// Share of failed requests, 0 when there were none
+pub fn usage_error_rate(request_count: i64, error_count: i64) -> f64 {
+    if request_count == 0 {
+        0.0
+    } else {
+        error_count as f64 / request_count as f64
+    }
+}
@@ -1,5 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+use crate::domain::entities::ConversationTransfer;
+use crate::domain::services::language_detection::{
+    detectable_languages, is_detectable_language, is_language_code,
+};
+use crate::shared::validation::{Validate, ValidationErrors};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConversationParticipant {
     pub id: String,
@@ -30,6 +36,13 @@ pub struct AssignmentHistory {
     pub assigned_by: String,
     pub assigned_at: String,
     pub unassigned_at: Option<String>,
+    /// Whether an agent or a routing rule made the assignment
+    pub source: AssignmentSource,
+    /// Routing rule that made the assignment, for rule-driven entries
+    pub rule_id: Option<String>,
+    /// Why this assignee was chosen, e.g. the detected language or a
+    /// transfer's handover note
+    pub reason: Option<String>,
 }
 
 impl AssignmentHistory {
@@ -47,6 +60,147 @@ impl AssignmentHistory {
             assigned_by,
             assigned_at: chrono::Utc::now().to_rfc3339(),
             unassigned_at: None,
+            source: AssignmentSource::Manual,
+            rule_id: None,
+            reason: None,
+        }
+    }
+
+    /// Agent assignment made when a member replies to an unassigned team conversation
+    pub fn on_reply(conversation_id: String, agent_id: String, team_id: String) -> Self {
+        Self {
+            reason: Some("Replied to an unassigned team conversation".to_string()),
+            ..Self::new(
+                conversation_id,
+                Some(agent_id.clone()),
+                Some(team_id),
+                agent_id,
+            )
+        }
+    }
+
+    /// Agent assignment made by skill-based routing on behalf of "system"
+    pub fn from_skill_match(
+        conversation_id: String,
+        assigned_user_id: String,
+        assigned_team_id: Option<String>,
+        reason: String,
+    ) -> Self {
+        Self {
+            source: AssignmentSource::Rule,
+            reason: Some(reason),
+            ..Self::new(
+                conversation_id,
+                Some(assigned_user_id),
+                assigned_team_id,
+                "system".to_string(),
+            )
+        }
+    }
+
+    /// Reassignment made by a completed transfer, with its handover note
+    pub fn from_transfer(transfer: &ConversationTransfer) -> Self {
+        Self {
+            reason: Some(transfer.note.clone()),
+            ..Self::new(
+                transfer.conversation_id.clone(),
+                transfer.to_user_id.clone(),
+                transfer.to_team_id.clone(),
+                transfer.requested_by.clone(),
+            )
+        }
+    }
+
+    /// Team assignment made by a routing rule on behalf of "system"
+    pub fn from_rule(
+        conversation_id: String,
+        assigned_team_id: String,
+        rule_id: String,
+        reason: String,
+    ) -> Self {
+        Self {
+            source: AssignmentSource::Rule,
+            rule_id: Some(rule_id),
+            reason: Some(reason),
+            ..Self::new(
+                conversation_id,
+                None,
+                Some(assigned_team_id),
+                "system".to_string(),
+            )
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AssignmentSource {
+    Manual,
+    Rule,
+}
+
+impl std::fmt::Display for AssignmentSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssignmentSource::Manual => write!(f, "manual"),
+            AssignmentSource::Rule => write!(f, "rule"),
+        }
+    }
+}
+
+impl std::str::FromStr for AssignmentSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "manual" => Ok(AssignmentSource::Manual),
+            "rule" => Ok(AssignmentSource::Rule),
+            _ => Err(format!("Invalid assignment source: {}", s)),
+        }
+    }
+}
+
+/// Routes conversations in `language` to `team_id`; a rule without a
+/// language names the default team
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageRoutingRule {
+    pub id: String,
+    pub language: Option<String>,
+    pub team_id: String,
+    pub created_at: String,
+}
+
+impl LanguageRoutingRule {
+    pub fn new(language: Option<String>, team_id: String) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            language,
+            team_id,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Something an agent is good at, e.g. billing or German; conversations
+/// tagged with a tag of the same name require it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Skill {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl Skill {
+    pub fn new(name: String, description: Option<String>) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name,
+            description,
+            created_at: now.clone(),
+            updated_at: now,
         }
     }
 }
@@ -58,7 +212,132 @@ pub struct AssignConversationRequest {
     pub assigned_team_id: Option<String>,
 }
 
+impl Validate for AssignConversationRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if self.assigned_user_id.is_none() && self.assigned_team_id.is_none() {
+            errors.add(
+                "assigned_user_id",
+                "either assigned_user_id or assigned_team_id is required",
+            );
+        }
+        errors.optional_length("assigned_user_id", self.assigned_user_id.as_deref(), 1, 255);
+        errors.optional_length("assigned_team_id", self.assigned_team_id.as_deref(), 1, 255);
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UpdateAvailabilityRequest {
     pub availability_status: crate::domain::entities::AgentAvailability,
 }
+
+impl Validate for UpdateAvailabilityRequest {
+    // The status value itself is checked when the body is deserialized
+    fn validate_fields(&self, _errors: &mut ValidationErrors) {}
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateLanguageRoutingRuleRequest {
+    /// ISO 639-1 code; omit to set the default team
+    pub language: Option<String>,
+    pub team_id: String,
+}
+
+impl Validate for CreateLanguageRoutingRuleRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("team_id", &self.team_id, 1, 255);
+        if let Some(language) = &self.language {
+            if !is_detectable_language(language) {
+                errors.add(
+                    "language",
+                    format!("must be one of: {}", detectable_languages().join(", ")),
+                );
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LanguageRoutingRuleListResponse {
+    pub rules: Vec<LanguageRoutingRule>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateMemberLanguagesRequest {
+    pub languages: Vec<String>,
+}
+
+impl Validate for UpdateMemberLanguagesRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        for (i, language) in self.languages.iter().enumerate() {
+            if !is_language_code(language) {
+                errors.add(
+                    format!("languages[{}]", i),
+                    "must be a lowercase ISO 639-1 code such as 'en'",
+                );
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemberLanguagesResponse {
+    pub team_id: String,
+    pub user_id: String,
+    pub languages: Vec<String>,
+}
+
+/// Request to add a skill to the catalog
+#[derive(Debug, Deserialize)]
+pub struct CreateSkillRequest {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl Validate for CreateSkillRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 50);
+        errors.max_length("description", self.description.as_deref(), 500);
+    }
+}
+
+/// Request to update a skill (name is immutable, like tag names it is matched on)
+#[derive(Debug, Deserialize)]
+pub struct UpdateSkillRequest {
+    pub description: Option<String>,
+}
+
+impl Validate for UpdateSkillRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.max_length("description", self.description.as_deref(), 500);
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SkillListResponse {
+    pub skills: Vec<Skill>,
+}
+
+/// Replace the skills an agent has
+#[derive(Debug, Deserialize)]
+pub struct UpdateAgentSkillsRequest {
+    pub skill_ids: Vec<String>,
+}
+
+impl Validate for UpdateAgentSkillsRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        for (i, skill_id) in self.skill_ids.iter().enumerate() {
+            errors.length(&format!("skill_ids[{}]", i), skill_id, 1, 255);
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct AgentSkillsResponse {
+    pub user_id: String,
+    pub skills: Vec<Skill>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssignmentHistoryResponse {
+    pub history: Vec<AssignmentHistory>,
+}
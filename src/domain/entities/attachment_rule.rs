@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Inbox-level rule that tags a conversation or routes it to a team when an
+/// inbound message carries an attachment of a given type
+///
+/// `pattern` is either a MIME type (`application/pdf`), a MIME type family
+/// (`image/*`) or a file extension (`.zip`). Extensions catch files that mail
+/// clients send as `application/octet-stream`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentRule {
+    pub id: String,
+    pub inbox_id: String,
+    pub pattern: String,
+    pub tag_id: Option<String>,
+    pub team_id: Option<String>,
+    pub enabled: bool,
+    pub created_by: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl AttachmentRule {
+    pub fn new(
+        inbox_id: String,
+        pattern: String,
+        tag_id: Option<String>,
+        team_id: Option<String>,
+        created_by: String,
+    ) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            inbox_id,
+            pattern: normalize_attachment_pattern(&pattern),
+            tag_id,
+            team_id,
+            enabled: true,
+            created_by,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /// Validate rule fields (pattern form and at least one action)
+    pub fn validate(&self) -> Result<(), String> {
+        validate_attachment_pattern(&self.pattern)?;
+
+        if self.tag_id.is_none() && self.team_id.is_none() {
+            return Err("Rule must apply a tag or route to a team".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Check whether an attachment matches this rule
+    pub fn matches(&self, filename: &str, content_type: Option<&str>) -> bool {
+        if let Some(extension) = self.pattern.strip_prefix('.') {
+            return filename
+                .rsplit_once('.')
+                .is_some_and(|(_, ext)| ext.eq_ignore_ascii_case(extension));
+        }
+
+        // Parameters such as "; name=invoice.pdf" are not part of the type
+        let Some(content_type) = content_type
+            .and_then(|ct| ct.split(';').next())
+            .map(|ct| ct.trim().to_ascii_lowercase())
+        else {
+            return false;
+        };
+
+        match self.pattern.strip_suffix("/*") {
+            Some(family) => content_type
+                .split_once('/')
+                .is_some_and(|(kind, _)| kind == family),
+            None => content_type == self.pattern,
+        }
+    }
+}
+
+/// Lowercase and trim a rule pattern so matching is case-insensitive
+pub fn normalize_attachment_pattern(pattern: &str) -> String {
+    pattern.trim().to_ascii_lowercase()
+}
+
+/// Check that a pattern is a MIME type, a MIME type family or a file extension
+pub fn validate_attachment_pattern(pattern: &str) -> Result<(), String> {
+    if pattern.is_empty() {
+        return Err("Pattern cannot be empty".to_string());
+    }
+    if pattern.len() > 255 {
+        return Err("Pattern cannot exceed 255 characters".to_string());
+    }
+
+    let token = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$&^_.+-".contains(c))
+    };
+
+    let valid = match pattern.strip_prefix('.') {
+        Some(extension) => token(extension) && !extension.contains('.'),
+        None => pattern
+            .split_once('/')
+            .is_some_and(|(kind, subtype)| token(kind) && (subtype == "*" || token(subtype))),
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid pattern '{}': expected a MIME type like application/pdf or image/*, or a file extension like .zip",
+            pattern
+        ))
+    }
+}
+
+// ========== DTOs ==========
+
+/// Request to create an attachment rule
+#[derive(Debug, Deserialize)]
+pub struct CreateAttachmentRuleRequest {
+    pub pattern: String,
+    pub tag_id: Option<String>,
+    pub team_id: Option<String>,
+}
+
+impl Validate for CreateAttachmentRuleRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("pattern", &self.pattern, 1, 255);
+        errors.optional_length("tag_id", self.tag_id.as_deref(), 1, 255);
+        errors.optional_length("team_id", self.team_id.as_deref(), 1, 255);
+        if self.tag_id.is_none() && self.team_id.is_none() {
+            errors.add("tag_id", "either tag_id or team_id is required");
+        }
+    }
+}
+
+/// Request to update an attachment rule
+///
+/// An empty `tag_id` or `team_id` removes that action from the rule.
+#[derive(Debug, Deserialize)]
+pub struct UpdateAttachmentRuleRequest {
+    pub pattern: Option<String>,
+    pub tag_id: Option<String>,
+    pub team_id: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+impl Validate for UpdateAttachmentRuleRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("pattern", self.pattern.as_deref(), 1, 255);
+        errors.max_length("tag_id", self.tag_id.as_deref(), 255);
+        errors.max_length("team_id", self.team_id.as_deref(), 255);
+    }
+}
+
+/// Response listing the attachment rules of an inbox
+#[derive(Debug, Serialize)]
+pub struct AttachmentRuleListResponse {
+    pub rules: Vec<AttachmentRule>,
+    pub total: i64,
+}
+
+/// What the attachment rules did to a conversation
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AttachmentRuleOutcome {
+    /// Tags newly added to the conversation
+    pub tags_applied: Vec<String>,
+    /// Team the conversation was routed to, if it was unassigned and a rule matched
+    pub routed_team_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str) -> AttachmentRule {
+        AttachmentRule::new(
+            "inbox-001".to_string(),
+            pattern.to_string(),
+            Some("tag-1".to_string()),
+            None,
+            "user-1".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_mime_type_matching() {
+        let pdf = rule("Application/PDF");
+        assert!(pdf.matches("invoice.bin", Some("application/pdf")));
+        assert!(pdf.matches("invoice", Some("APPLICATION/PDF; name=invoice")));
+        assert!(!pdf.matches("invoice.pdf", Some("application/zip")));
+        assert!(!pdf.matches("invoice.pdf", None));
+
+        let images = rule("image/*");
+        assert!(images.matches("shot.png", Some("image/png")));
+        assert!(!images.matches("shot.png", Some("imagex/png")));
+    }
+
+    #[test]
+    fn test_extension_matching() {
+        let zip = rule(".zip");
+        assert!(zip.matches("logs.ZIP", Some("application/octet-stream")));
+        assert!(zip.matches("logs.tar.zip", None));
+        assert!(!zip.matches("zip", None));
+        assert!(!zip.matches("logs.gzip", None));
+    }
+
+    #[test]
+    fn test_pattern_validation() {
+        for pattern in [
+            "application/pdf",
+            "image/*",
+            ".zip",
+            "application/vnd.ms-excel",
+        ] {
+            assert!(validate_attachment_pattern(pattern).is_ok(), "{}", pattern);
+        }
+        for pattern in ["", "pdf", ".", ".tar.gz", "*/*", "image/", "/pdf", "a b/c"] {
+            assert!(validate_attachment_pattern(pattern).is_err(), "{}", pattern);
+        }
+
+        let mut no_action = rule(".zip");
+        no_action.tag_id = None;
+        assert!(no_action.validate().is_err());
+    }
+}
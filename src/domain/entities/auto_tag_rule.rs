@@ -0,0 +1,319 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Which part of an inbound message a keyword rule inspects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutoTagMatchField {
+    Subject,
+    Body,
+    Any,
+}
+
+impl AutoTagMatchField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutoTagMatchField::Subject => "subject",
+            AutoTagMatchField::Body => "body",
+            AutoTagMatchField::Any => "any",
+        }
+    }
+}
+
+impl fmt::Display for AutoTagMatchField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<String> for AutoTagMatchField {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "subject" => AutoTagMatchField::Subject,
+            "body" => AutoTagMatchField::Body,
+            _ => AutoTagMatchField::Any,
+        }
+    }
+}
+
+/// How the rule pattern is compared against the message text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutoTagMatchType {
+    Contains,
+    Regex,
+}
+
+impl AutoTagMatchType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutoTagMatchType::Contains => "contains",
+            AutoTagMatchType::Regex => "regex",
+        }
+    }
+}
+
+impl fmt::Display for AutoTagMatchType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<String> for AutoTagMatchType {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "regex" => AutoTagMatchType::Regex,
+            _ => AutoTagMatchType::Contains,
+        }
+    }
+}
+
+/// Inbox-level keyword → tag rule applied at message ingestion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoTagRule {
+    pub id: String,
+    pub inbox_id: String,
+    pub tag_id: String,
+    pub match_field: AutoTagMatchField,
+    pub match_type: AutoTagMatchType,
+    pub pattern: String,
+    pub case_sensitive: bool,
+    pub enabled: bool,
+    pub created_by: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl AutoTagRule {
+    pub fn new(
+        inbox_id: String,
+        tag_id: String,
+        match_field: AutoTagMatchField,
+        match_type: AutoTagMatchType,
+        pattern: String,
+        case_sensitive: bool,
+        created_by: String,
+    ) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            inbox_id,
+            tag_id,
+            match_field,
+            match_type,
+            pattern,
+            case_sensitive,
+            enabled: true,
+            created_by,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /// Validate rule fields (pattern length and regex syntax)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.pattern.trim().is_empty() {
+            return Err("Pattern cannot be empty".to_string());
+        }
+
+        if self.pattern.len() > 500 {
+            return Err("Pattern cannot exceed 500 characters".to_string());
+        }
+
+        if self.match_type == AutoTagMatchType::Regex {
+            self.compile_regex()
+                .map_err(|e| format!("Invalid regex pattern: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Compile the rule's regex pattern
+    pub fn compile_regex(&self) -> Result<regex::Regex, regex::Error> {
+        regex::RegexBuilder::new(&self.pattern)
+            .case_insensitive(!self.case_sensitive)
+            .size_limit(1 << 20)
+            .build()
+    }
+
+    fn matches_text(&self, regex: Option<&regex::Regex>, text: &str) -> bool {
+        match self.match_type {
+            AutoTagMatchType::Contains => {
+                if self.case_sensitive {
+                    text.contains(&self.pattern)
+                } else {
+                    text.to_lowercase().contains(&self.pattern.to_lowercase())
+                }
+            }
+            AutoTagMatchType::Regex => regex.is_some_and(|re| re.is_match(text)),
+        }
+    }
+
+    /// Check whether an inbound message matches this rule
+    pub fn matches(&self, subject: Option<&str>, body: &str) -> bool {
+        let regex = match self.match_type {
+            AutoTagMatchType::Regex => self.compile_regex().ok(),
+            AutoTagMatchType::Contains => None,
+        };
+        self.matches_with(regex.as_ref(), subject, body)
+    }
+
+    /// Check whether an inbound message matches this rule, using a regex the
+    /// caller already compiled with `compile_regex`
+    pub fn matches_with(
+        &self,
+        regex: Option<&regex::Regex>,
+        subject: Option<&str>,
+        body: &str,
+    ) -> bool {
+        let subject_matches = || {
+            subject
+                .map(|s| self.matches_text(regex, s))
+                .unwrap_or(false)
+        };
+
+        match self.match_field {
+            AutoTagMatchField::Subject => subject_matches(),
+            AutoTagMatchField::Body => self.matches_text(regex, body),
+            AutoTagMatchField::Any => subject_matches() || self.matches_text(regex, body),
+        }
+    }
+}
+
+// ========== DTOs ==========
+
+/// Request to create an auto-tag rule
+#[derive(Debug, Deserialize)]
+pub struct CreateAutoTagRuleRequest {
+    pub tag_id: String,
+    pub pattern: String,
+    #[serde(default = "default_match_field")]
+    pub match_field: AutoTagMatchField,
+    #[serde(default = "default_match_type")]
+    pub match_type: AutoTagMatchType,
+    #[serde(default)]
+    pub case_sensitive: bool,
+}
+
+impl Validate for CreateAutoTagRuleRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("tag_id", &self.tag_id, 1, 255);
+        errors.length("pattern", &self.pattern, 1, 500);
+    }
+}
+
+fn default_match_field() -> AutoTagMatchField {
+    AutoTagMatchField::Any
+}
+
+fn default_match_type() -> AutoTagMatchType {
+    AutoTagMatchType::Contains
+}
+
+/// Request to update an auto-tag rule
+#[derive(Debug, Deserialize)]
+pub struct UpdateAutoTagRuleRequest {
+    pub tag_id: Option<String>,
+    pub pattern: Option<String>,
+    pub match_field: Option<AutoTagMatchField>,
+    pub match_type: Option<AutoTagMatchType>,
+    pub case_sensitive: Option<bool>,
+    pub enabled: Option<bool>,
+}
+
+impl Validate for UpdateAutoTagRuleRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("tag_id", self.tag_id.as_deref(), 1, 255);
+        errors.optional_length("pattern", self.pattern.as_deref(), 1, 500);
+    }
+}
+
+/// Response listing the auto-tag rules of an inbox
+#[derive(Debug, Serialize)]
+pub struct AutoTagRuleListResponse {
+    pub rules: Vec<AutoTagRule>,
+    pub total: i64,
+}
+
+/// Maximum number of conversations scanned in a single backfill batch
+pub const MAX_AUTO_TAG_BACKFILL_BATCH: i64 = 500;
+
+/// Request to backfill auto-tag rules over historical conversations
+#[derive(Debug, Deserialize)]
+pub struct AutoTagBackfillRequest {
+    #[serde(default = "default_backfill_limit")]
+    pub limit: i64,
+    #[serde(default)]
+    pub offset: i64,
+}
+
+impl Validate for AutoTagBackfillRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.range("limit", self.limit, 1, MAX_AUTO_TAG_BACKFILL_BATCH);
+        if self.offset < 0 {
+            errors.add("offset", "must not be negative");
+        }
+    }
+}
+
+fn default_backfill_limit() -> i64 {
+    100
+}
+
+/// Result of one backfill batch
+#[derive(Debug, Default, Serialize)]
+pub struct AutoTagBackfillResponse {
+    pub conversations_scanned: i64,
+    pub conversations_tagged: i64,
+    pub tags_applied: i64,
+    /// Offset to pass for the next batch, or None when the inbox is exhausted
+    pub next_offset: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(field: AutoTagMatchField, kind: AutoTagMatchType, pattern: &str) -> AutoTagRule {
+        AutoTagRule::new(
+            "inbox-001".to_string(),
+            "tag-1".to_string(),
+            field,
+            kind,
+            pattern.to_string(),
+            false,
+            "user-1".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_contains_is_case_insensitive_by_default() {
+        let r = rule(
+            AutoTagMatchField::Body,
+            AutoTagMatchType::Contains,
+            "Refund",
+        );
+        assert!(r.matches(None, "I want a REFUND please"));
+        assert!(!r.matches(Some("refund"), "hello"));
+    }
+
+    #[test]
+    fn test_regex_on_subject() {
+        let r = rule(
+            AutoTagMatchField::Subject,
+            AutoTagMatchType::Regex,
+            r"invoice\s+#\d+",
+        );
+        assert!(r.matches(Some("Invoice #123 overdue"), ""));
+        assert!(!r.matches(None, "invoice #123"));
+    }
+
+    #[test]
+    fn test_invalid_regex_fails_validation() {
+        let r = rule(AutoTagMatchField::Any, AutoTagMatchType::Regex, "(unclosed");
+        assert!(r.validate().is_err());
+    }
+}
@@ -77,7 +77,9 @@ pub enum ComparisonOperator {
     Equals,
     NotEquals,
     GreaterThan,
+    GreaterThanOrEqual,
     LessThan,
+    LessThanOrEqual,
     In,
     NotIn,
 }
@@ -99,6 +101,9 @@ pub enum ActionType {
     AddTag,
     RemoveTag,
     ChangeStatus,
+    NotifyTeamLead,
+    /// Wait for 'duration', then run the 'then' action if the rule condition still holds
+    Wait,
 }
 
 // Validation methods
@@ -170,6 +175,10 @@ impl RuleCondition {
                     "status",
                     "assigned_user_id",
                     "assigned_team_id",
+                    "contact_email_verified",
+                    "csat_score",
+                    "sentiment_score",
+                    "sentiment_trend",
                 ];
                 if !valid_attributes.contains(&attribute.as_str()) {
                     return Err(format!("Invalid attribute: {}", attribute));
@@ -227,6 +236,111 @@ impl RuleAction {
                 }
                 Ok(())
             }
+            // 'team_id' is optional and defaults to the conversation's assigned team
+            ActionType::NotifyTeamLead => Ok(()),
+            ActionType::Wait => {
+                self.wait_duration_seconds()?;
+                let follow_up = self.wait_follow_up()?;
+                if follow_up.action_type == ActionType::Wait {
+                    return Err("Wait action cannot be followed by another wait".to_string());
+                }
+                follow_up.validate()
+            }
+        }
+    }
+
+    /// Length of a Wait action's delay in seconds, from its 'duration' parameter ("4h", "30m", "1d")
+    pub fn wait_duration_seconds(&self) -> Result<i64, String> {
+        let duration = self
+            .parameters
+            .get("duration")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| "Wait action requires 'duration' parameter".to_string())?;
+        crate::domain::entities::parse_duration(duration)
+    }
+
+    /// Whether a Wait action counts its delay in the assigned team's business hours
+    pub fn wait_in_business_hours(&self) -> bool {
+        self.parameters
+            .get("business_hours")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false)
+    }
+
+    /// The action a Wait action runs once its delay has elapsed
+    pub fn wait_follow_up(&self) -> Result<RuleAction, String> {
+        let then = self
+            .parameters
+            .get("then")
+            .ok_or_else(|| "Wait action requires 'then' parameter".to_string())?;
+        serde_json::from_value(then.clone())
+            .map_err(|e| format!("Invalid 'then' action for Wait: {}", e))
+    }
+}
+
+/// A Wait action's follow-up, scheduled to run once the delay has elapsed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingRuleAction {
+    pub id: String,
+    pub rule_id: String,
+    pub conversation_id: String,
+    pub action: RuleAction,
+    pub run_at: String,
+    pub status: PendingRuleActionStatus,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl PendingRuleAction {
+    pub fn new(
+        rule_id: String,
+        conversation_id: String,
+        action: RuleAction,
+        run_at: String,
+    ) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            rule_id,
+            conversation_id,
+            action,
+            run_at,
+            status: PendingRuleActionStatus::Pending,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+}
+
+/// Lifecycle of a pending rule action
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PendingRuleActionStatus {
+    Pending,
+    Completed,
+    /// The rule condition stopped holding before the delay elapsed
+    Cancelled,
+}
+
+impl std::fmt::Display for PendingRuleActionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PendingRuleActionStatus::Pending => write!(f, "pending"),
+            PendingRuleActionStatus::Completed => write!(f, "completed"),
+            PendingRuleActionStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl std::str::FromStr for PendingRuleActionStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(PendingRuleActionStatus::Pending),
+            "completed" => Ok(PendingRuleActionStatus::Completed),
+            "cancelled" => Ok(PendingRuleActionStatus::Cancelled),
+            _ => Err(format!("Invalid pending action status: {}", s)),
         }
     }
 }
@@ -349,4 +463,58 @@ mod tests {
         );
         assert!(rule.validate().is_ok());
     }
+
+    #[test]
+    fn test_wait_action_validation() {
+        let wait = |parameters: serde_json::Value| RuleAction {
+            action_type: ActionType::Wait,
+            parameters: serde_json::from_value(parameters).unwrap(),
+        };
+
+        let action = wait(json!({
+            "duration": "4h",
+            "business_hours": true,
+            "then": {
+                "action_type": "assign_to_team",
+                "parameters": { "team_id": "escalations" }
+            }
+        }));
+        assert!(action.validate().is_ok());
+        assert_eq!(action.wait_duration_seconds().unwrap(), 4 * 60 * 60);
+        assert!(action.wait_in_business_hours());
+
+        // Missing or malformed duration
+        assert!(wait(json!({
+            "then": { "action_type": "set_priority", "parameters": { "priority": "High" } }
+        }))
+        .validate()
+        .is_err());
+        assert!(wait(json!({
+            "duration": "4 hours",
+            "then": { "action_type": "set_priority", "parameters": { "priority": "High" } }
+        }))
+        .validate()
+        .is_err());
+
+        // Follow-up is required, must be valid, and cannot wait again
+        assert!(wait(json!({ "duration": "30m" })).validate().is_err());
+        assert!(wait(json!({
+            "duration": "30m",
+            "then": { "action_type": "set_priority", "parameters": {} }
+        }))
+        .validate()
+        .is_err());
+        assert!(wait(json!({
+            "duration": "30m",
+            "then": {
+                "action_type": "wait",
+                "parameters": {
+                    "duration": "30m",
+                    "then": { "action_type": "set_priority", "parameters": { "priority": "High" } }
+                }
+            }
+        }))
+        .validate()
+        .is_err());
+    }
 }
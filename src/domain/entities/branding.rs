@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+pub const BRANDING_PRODUCT_NAME_KEY: &str = "branding.product_name";
+pub const BRANDING_LOGO_URL_KEY: &str = "branding.logo_url";
+pub const BRANDING_ACCENT_COLOR_KEY: &str = "branding.accent_color";
+pub const BRANDING_EMAIL_FOOTER_KEY: &str = "branding.email_footer";
+
+pub const DEFAULT_PRODUCT_NAME: &str = "Oxidesk";
+/// Matches `--oxi-accent` in the bundled stylesheet
+pub const DEFAULT_ACCENT_COLOR: &str = "#06b6d4";
+
+const MAX_PRODUCT_NAME_LENGTH: usize = 100;
+const MAX_LOGO_URL_LENGTH: usize = 2048;
+const MAX_EMAIL_FOOTER_LENGTH: usize = 2000;
+
+/// Workspace branding shown in the web UI and outbound emails
+///
+/// Stored as `branding.*` rows in `system_config`; missing rows use the
+/// built-in defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Branding {
+    pub product_name: String,
+    pub logo_url: Option<String>,
+    /// `#RRGGBB` color, e.g. `#06b6d4`
+    pub accent_color: String,
+    /// Plain text appended to outbound emails
+    pub email_footer: Option<String>,
+}
+
+impl Default for Branding {
+    fn default() -> Self {
+        Self {
+            product_name: DEFAULT_PRODUCT_NAME.to_string(),
+            logo_url: None,
+            accent_color: DEFAULT_ACCENT_COLOR.to_string(),
+            email_footer: None,
+        }
+    }
+}
+
+impl Branding {
+    /// Build from stored `system_config` values, ignoring blank or invalid ones
+    pub fn from_stored(stored: &HashMap<String, String>) -> Self {
+        let value = |key: &str| {
+            stored
+                .get(key)
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        let defaults = Self::default();
+
+        Self {
+            product_name: value(BRANDING_PRODUCT_NAME_KEY).unwrap_or(defaults.product_name),
+            logo_url: value(BRANDING_LOGO_URL_KEY),
+            accent_color: value(BRANDING_ACCENT_COLOR_KEY)
+                .filter(|color| is_hex_color(color))
+                .unwrap_or(defaults.accent_color),
+            email_footer: value(BRANDING_EMAIL_FOOTER_KEY),
+        }
+    }
+
+    /// The accent color as `rgba(r, g, b, alpha)`, for glows and shadows
+    pub fn accent_rgba(&self, alpha: f32) -> String {
+        let hex = self.accent_color.trim_start_matches('#');
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                .unwrap_or(0)
+        };
+        format!(
+            "rgba({}, {}, {}, {})",
+            channel(0),
+            channel(2),
+            channel(4),
+            alpha
+        )
+    }
+
+    /// Stylesheet overriding the web UI's accent variables
+    pub fn stylesheet(&self) -> String {
+        format!(
+            ":root {{\n    --oxi-accent: {};\n    --oxi-accent-glow: {};\n}}\n",
+            self.accent_color,
+            self.accent_rgba(0.3)
+        )
+    }
+
+    /// `{{product_name}}`, `{{logo_url}}`, `{{accent_color}}` and
+    /// `{{email_footer}}` values for HTML email templates, escaped
+    pub fn email_variables(&self) -> HashMap<String, String> {
+        HashMap::from([
+            ("product_name".to_string(), escape_html(&self.product_name)),
+            (
+                "logo_url".to_string(),
+                escape_html(self.logo_url.as_deref().unwrap_or_default()),
+            ),
+            ("accent_color".to_string(), self.accent_color.clone()),
+            (
+                "email_footer".to_string(),
+                escape_html(self.email_footer.as_deref().unwrap_or_default()).replace('\n', "<br>"),
+            ),
+        ])
+    }
+
+    /// Stored value for each branding key; cleared fields store an empty string
+    pub fn stored_values(&self) -> [(&'static str, String); 4] {
+        [
+            (BRANDING_PRODUCT_NAME_KEY, self.product_name.clone()),
+            (
+                BRANDING_LOGO_URL_KEY,
+                self.logo_url.clone().unwrap_or_default(),
+            ),
+            (BRANDING_ACCENT_COLOR_KEY, self.accent_color.clone()),
+            (
+                BRANDING_EMAIL_FOOTER_KEY,
+                self.email_footer.clone().unwrap_or_default(),
+            ),
+        ]
+    }
+
+    /// Apply an update; empty strings clear the optional fields
+    pub fn apply(&mut self, request: &UpdateBrandingRequest) {
+        if let Some(product_name) = &request.product_name {
+            self.product_name = product_name.trim().to_string();
+        }
+        if let Some(logo_url) = &request.logo_url {
+            self.logo_url = Some(logo_url.trim().to_string()).filter(|v| !v.is_empty());
+        }
+        if let Some(accent_color) = &request.accent_color {
+            self.accent_color = accent_color.trim().to_lowercase();
+        }
+        if let Some(email_footer) = &request.email_footer {
+            self.email_footer = Some(email_footer.trim().to_string()).filter(|v| !v.is_empty());
+        }
+    }
+}
+
+/// Request body for PUT /api/admin/branding; omitted fields are unchanged
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpdateBrandingRequest {
+    pub product_name: Option<String>,
+    /// Empty string removes the logo
+    pub logo_url: Option<String>,
+    pub accent_color: Option<String>,
+    /// Empty string removes the footer
+    pub email_footer: Option<String>,
+}
+
+impl Validate for UpdateBrandingRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if let Some(product_name) = &self.product_name {
+            errors.length(
+                "product_name",
+                product_name.trim(),
+                1,
+                MAX_PRODUCT_NAME_LENGTH,
+            );
+        }
+        if let Some(logo_url) = self.logo_url.as_deref().map(str::trim) {
+            if !logo_url.is_empty() {
+                errors.http_url("logo_url", logo_url, MAX_LOGO_URL_LENGTH);
+            }
+        }
+        if let Some(accent_color) = &self.accent_color {
+            errors.hex_color("accent_color", accent_color.trim());
+        }
+        errors.max_length(
+            "email_footer",
+            self.email_footer.as_deref(),
+            MAX_EMAIL_FOOTER_LENGTH,
+        );
+    }
+}
+
+fn is_hex_color(value: &str) -> bool {
+    let mut errors = ValidationErrors::new();
+    errors.hex_color("accent_color", value);
+    errors.is_empty()
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
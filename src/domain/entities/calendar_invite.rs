@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+
+/// iTIP method of a calendar payload (RFC 5546)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CalendarInviteMethod {
+    /// A new or updated invitation
+    Request,
+    /// An attendee accepting, declining or tentatively accepting
+    Reply,
+    /// The organizer called the event off
+    Cancel,
+    /// A plain event with no reply expected
+    Publish,
+}
+
+impl CalendarInviteMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CalendarInviteMethod::Request => "request",
+            CalendarInviteMethod::Reply => "reply",
+            CalendarInviteMethod::Cancel => "cancel",
+            CalendarInviteMethod::Publish => "publish",
+        }
+    }
+}
+
+impl std::str::FromStr for CalendarInviteMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "REQUEST" => Ok(CalendarInviteMethod::Request),
+            "REPLY" => Ok(CalendarInviteMethod::Reply),
+            "CANCEL" => Ok(CalendarInviteMethod::Cancel),
+            "PUBLISH" => Ok(CalendarInviteMethod::Publish),
+            other => Err(format!("Unsupported calendar method: {}", other)),
+        }
+    }
+}
+
+/// An attendee's participation status (PARTSTAT)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttendeeResponse {
+    #[default]
+    NeedsAction,
+    Accepted,
+    Declined,
+    Tentative,
+    Delegated,
+}
+
+impl AttendeeResponse {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttendeeResponse::NeedsAction => "needs_action",
+            AttendeeResponse::Accepted => "accepted",
+            AttendeeResponse::Declined => "declined",
+            AttendeeResponse::Tentative => "tentative",
+            AttendeeResponse::Delegated => "delegated",
+        }
+    }
+
+    /// Read a PARTSTAT value; unknown values count as not answered yet
+    pub fn from_partstat(value: &str) -> Self {
+        match value.trim().to_ascii_uppercase().as_str() {
+            "ACCEPTED" => AttendeeResponse::Accepted,
+            "DECLINED" => AttendeeResponse::Declined,
+            "TENTATIVE" => AttendeeResponse::Tentative,
+            "DELEGATED" => AttendeeResponse::Delegated,
+            _ => AttendeeResponse::NeedsAction,
+        }
+    }
+}
+
+/// The organizer of a calendar event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarOrganizer {
+    pub email: String,
+    pub name: Option<String>,
+}
+
+/// Someone invited to a calendar event, with their answer so far
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarAttendee {
+    pub email: String,
+    pub name: Option<String>,
+    pub response: AttendeeResponse,
+    /// The organizer asked for an answer
+    pub rsvp: bool,
+}
+
+/// A meeting invite (or reply to one) received with an email
+///
+/// Times are RFC 3339 in UTC when the invite gives a UTC time or a known
+/// time zone, `YYYY-MM-DD` for all-day events, and local times without an
+/// offset for floating events.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarInvite {
+    pub method: CalendarInviteMethod,
+    pub uid: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub starts_at: Option<String>,
+    pub ends_at: Option<String>,
+    pub all_day: bool,
+    /// Time zone the organizer scheduled the event in (TZID)
+    pub timezone: Option<String>,
+    pub organizer: Option<CalendarOrganizer>,
+    pub attendees: Vec<CalendarAttendee>,
+    /// Revision of the event; a higher sequence supersedes earlier invites
+    pub sequence: i64,
+    pub cancelled: bool,
+    /// Readable event summary shown on the message
+    pub summary: String,
+}
+
+impl CalendarInvite {
+    /// The answer an attendee gave, e.g. the sender of a reply
+    pub fn response_of(&self, email: &str) -> Option<AttendeeResponse> {
+        self.attendees
+            .iter()
+            .find(|attendee| attendee.email.eq_ignore_ascii_case(email.trim()))
+            .map(|attendee| attendee.response)
+    }
+
+    /// Build the readable summary from the event fields
+    pub fn render_summary(&self) -> String {
+        let title = self.title.as_deref().unwrap_or("Untitled event");
+        let heading = match self.method {
+            _ if self.cancelled => format!("Cancelled: {}", title),
+            CalendarInviteMethod::Reply => {
+                let answers: Vec<String> = self
+                    .attendees
+                    .iter()
+                    .map(|attendee| {
+                        format!(
+                            "{} {}",
+                            attendee.name.as_deref().unwrap_or(&attendee.email),
+                            attendee.response.as_str().replace('_', " ")
+                        )
+                    })
+                    .collect();
+                if answers.is_empty() {
+                    format!("Reply: {}", title)
+                } else {
+                    format!("Reply: {} ({})", title, answers.join(", "))
+                }
+            }
+            CalendarInviteMethod::Cancel => format!("Cancelled: {}", title),
+            CalendarInviteMethod::Request | CalendarInviteMethod::Publish => {
+                format!("Invitation: {}", title)
+            }
+        };
+
+        let mut lines = vec![heading];
+        if let Some(start) = &self.starts_at {
+            let when = match &self.ends_at {
+                Some(end) if end != start => format!("{} to {}", start, end),
+                _ => start.clone(),
+            };
+            let when = if self.all_day {
+                format!("{} (all day)", when)
+            } else {
+                when
+            };
+            lines.push(format!("When: {}", when));
+        }
+        if let Some(organizer) = &self.organizer {
+            let organizer = match &organizer.name {
+                Some(name) => format!("{} <{}>", name, organizer.email),
+                None => organizer.email.clone(),
+            };
+            lines.push(format!("Organizer: {}", organizer));
+        }
+        if let Some(location) = &self.location {
+            lines.push(format!("Location: {}", location));
+        }
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ConversationStatus, Message};
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Domain used for contacts created from senders that only have an external ID
+/// (`.invalid` is reserved, so these addresses can never receive mail)
+pub const CHANNEL_PLACEHOLDER_EMAIL_DOMAIN: &str = "channel.invalid";
+
+/// Longest external sender or message ID accepted from a channel
+pub const MAX_CHANNEL_EXTERNAL_ID_LENGTH: usize = 255;
+
+/// Sender of a message pushed in by an external channel
+///
+/// At least one of `external_id` (phone number, widget visitor ID, ...) or
+/// `email` identifies the contact.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChannelSender {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl ChannelSender {
+    /// Email address used for the contact when the sender has none
+    pub fn placeholder_email(inbox_id: &str, external_id: &str) -> String {
+        let local: String = external_id
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '+') {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        format!(
+            "{}@{}.{}",
+            local, inbox_id, CHANNEL_PLACEHOLDER_EMAIL_DOMAIN
+        )
+    }
+}
+
+/// Message pushed into an inbox by an external channel (chat widget, SMS gateway, ...)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelMessageRequest {
+    pub sender: ChannelSender,
+    pub content: String,
+    /// Append to this conversation instead of the sender's latest open one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conversation_id: Option<String>,
+    /// Start a new conversation instead of continuing the sender's open one
+    #[serde(default)]
+    pub new_conversation: bool,
+    /// Subject for a newly created conversation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Channel-side message ID; repeated deliveries with the same ID are ignored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_message_id: Option<String>,
+    /// Channel recorded on the message; defaults to the inbox's channel type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// Channel-specific details of the source, shown alongside the message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_metadata: Option<serde_json::Value>,
+}
+
+impl ChannelMessageRequest {
+    pub fn validate(&self) -> Result<(), String> {
+        let external_id = self.sender.external_id.as_deref().map(str::trim);
+        let email = self.sender.email.as_deref().map(str::trim);
+        if external_id.is_none_or(str::is_empty) && email.is_none_or(str::is_empty) {
+            return Err("Sender must have an external_id or email".to_string());
+        }
+        if let Some(email) = email.filter(|e| !e.is_empty()) {
+            if !email.contains('@') {
+                return Err(format!("Invalid sender email: {}", email));
+            }
+        }
+        for id in [external_id, self.external_message_id.as_deref()]
+            .into_iter()
+            .flatten()
+        {
+            if id.len() > MAX_CHANNEL_EXTERNAL_ID_LENGTH {
+                return Err(format!(
+                    "External IDs must be at most {} characters",
+                    MAX_CHANNEL_EXTERNAL_ID_LENGTH
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Validate for ChannelMessageRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        let external_id = self.sender.external_id.as_deref().map(str::trim);
+        let email = self.sender.email.as_deref().map(str::trim);
+        if external_id.is_none_or(str::is_empty) && email.is_none_or(str::is_empty) {
+            errors.add("sender", "must have an external_id or email");
+        }
+        if let Some(email) = email.filter(|e| !e.is_empty()) {
+            errors.email("sender.email", email);
+        }
+        errors.max_length(
+            "sender.external_id",
+            external_id,
+            MAX_CHANNEL_EXTERNAL_ID_LENGTH,
+        );
+        if let Err(e) = Message::validate_content(&self.content) {
+            errors.add("content", e);
+        }
+        errors.max_length(
+            "external_message_id",
+            self.external_message_id.as_deref(),
+            MAX_CHANNEL_EXTERNAL_ID_LENGTH,
+        );
+    }
+}
+
+/// Result of ingesting a channel message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelMessageResponse {
+    pub contact_id: String,
+    pub conversation_id: String,
+    /// Status after ingestion; snoozed and closed conversations are reopened
+    pub conversation_status: ConversationStatus,
+    pub message: Message,
+    /// A new conversation was opened for this message
+    pub created_conversation: bool,
+    /// The external message ID was already ingested; `message` is the original
+    pub duplicate: bool,
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Contact, Message, MessageType};
+
+/// Inbox channel type served by the web chat widget
+pub const CHAT_CHANNEL_TYPE: &str = "chat";
+
+/// Notification type pushed to a widget connection when an agent replies
+pub const CHAT_MESSAGE_EVENT_TYPE: &str = "chat_message";
+
+/// Most recent messages sent to a visitor when they reconnect
+pub const WIDGET_HISTORY_LIMIT: i64 = 50;
+
+/// Messages a visitor may send per minute on one connection
+pub const WIDGET_MESSAGES_PER_MINUTE: usize = 20;
+
+/// Query parameters of the visitor WebSocket
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct WidgetConnectQuery {
+    /// Token the widget stored from an earlier session
+    pub visitor_token: Option<String>,
+    /// Conversation the widget stored from an earlier session
+    pub conversation_id: Option<String>,
+}
+
+/// State of a connected visitor
+#[derive(Debug, Clone)]
+pub struct WidgetSession {
+    pub inbox_id: String,
+    pub visitor_token: String,
+    /// Channel identity of the visitor (derived from the token)
+    pub external_id: String,
+    /// Set once the visitor has sent a message or left their email
+    pub contact: Option<Contact>,
+    pub conversation_id: Option<String>,
+    pub identified: bool,
+}
+
+/// Frames sent by the widget
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WidgetClientFrame {
+    Message {
+        content: String,
+    },
+    /// Email capture: promote the visitor to a known contact
+    Identify {
+        email: String,
+        #[serde(default)]
+        name: Option<String>,
+        /// Signature of the email from the host site, for logged-in users
+        #[serde(default)]
+        identity_signature: Option<String>,
+    },
+}
+
+/// Frames sent to the widget
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WidgetServerFrame {
+    /// Sent on connect and whenever the session changes; the widget stores both IDs
+    Session {
+        visitor_token: String,
+        conversation_id: Option<String>,
+        identified: bool,
+    },
+    History {
+        messages: Vec<WidgetMessage>,
+    },
+    Message {
+        message: WidgetMessage,
+    },
+    Error {
+        error: String,
+    },
+}
+
+/// Conversation message as shown to a visitor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WidgetMessage {
+    pub id: String,
+    pub conversation_id: String,
+    pub content: String,
+    /// Sent by the visitor rather than an agent
+    pub from_visitor: bool,
+    pub created_at: String,
+}
+
+impl From<Message> for WidgetMessage {
+    fn from(message: Message) -> Self {
+        Self {
+            from_visitor: message.message_type == MessageType::Incoming,
+            id: message.id,
+            conversation_id: message.conversation_id,
+            content: message.content,
+            created_at: message.created_at,
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::{RuleAction, RuleCondition, RuleType};
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Version written to exported bundles; imports must match it
+pub const CONFIG_BUNDLE_VERSION: u32 = 1;
+
+/// Portable snapshot of workspace configuration
+///
+/// Items are matched by name on import. IDs, timestamps and webhook secrets are
+/// never included; IDs referenced inside rule parameters are carried as-is.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigBundle {
+    pub version: u32,
+    #[serde(default)]
+    pub exported_at: Option<String>,
+    #[serde(default)]
+    pub automation_rules: Vec<BundledAutomationRule>,
+    #[serde(default)]
+    pub macros: Vec<BundledMacro>,
+    #[serde(default)]
+    pub tags: Vec<BundledTag>,
+    #[serde(default)]
+    pub sla_policies: Vec<BundledSlaPolicy>,
+    #[serde(default)]
+    pub webhooks: Vec<BundledWebhook>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledAutomationRule {
+    pub name: String,
+    pub description: Option<String>,
+    pub enabled: bool,
+    pub rule_type: RuleType,
+    pub event_subscription: Vec<String>,
+    pub condition: RuleCondition,
+    pub action: RuleAction,
+    pub priority: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundledMacroAction {
+    pub action_type: String,
+    pub action_value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundledMacro {
+    pub name: String,
+    pub message_content: String,
+    pub access_control: String,
+    #[serde(default)]
+    pub actions: Vec<BundledMacroAction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundledTag {
+    pub name: String,
+    pub description: Option<String>,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundledSlaPolicy {
+    pub name: String,
+    pub description: Option<String>,
+    pub first_response_time: String,
+    pub resolution_time: String,
+    pub next_response_time: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundledWebhook {
+    pub name: String,
+    pub url: String,
+    pub subscribed_events: Vec<String>,
+    pub is_active: bool,
+    #[serde(default)]
+    pub include_conversation_snapshot: bool,
+}
+
+/// What to do with bundle items whose name exists with different settings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// Keep the existing item
+    #[default]
+    Skip,
+    /// Replace the existing item with the bundle's version
+    Overwrite,
+    /// Reject the whole import
+    Fail,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportConfigRequest {
+    pub bundle: ConfigBundle,
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default)]
+    pub on_conflict: ConflictStrategy,
+}
+
+impl Validate for ImportConfigRequest {
+    // Items are checked against each other and the target instance by the service
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if self.bundle.version != CONFIG_BUNDLE_VERSION {
+            errors.add(
+                "bundle.version",
+                format!("must be {}", CONFIG_BUNDLE_VERSION),
+            );
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportAction {
+    Create,
+    Update,
+    Unchanged,
+    Skip,
+    Conflict,
+}
+
+/// Planned (dry run) or applied change for one bundle item
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportChange {
+    pub kind: String, // "automation_rule", "macro", "tag", "sla_policy", "webhook"
+    pub name: String,
+    pub action: ImportAction,
+    /// Top-level fields that differ from the existing item
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changed_fields: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportConfigResponse {
+    pub dry_run: bool,
+    pub applied: bool,
+    pub created: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub skipped: usize,
+    pub conflicts: usize,
+    pub changes: Vec<ImportChange>,
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// How long a contact email confirmation link stays valid
+pub const CONTACT_EMAIL_VERIFICATION_TTL_HOURS: i64 = 72;
+
+/// Entity: Confirmation token sent to a manually created contact's email address
+///
+/// Only the SHA-256 hash of the token is kept; the token itself exists in the
+/// emailed link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContactEmailVerification {
+    pub id: String,
+    pub channel_id: String,
+    pub token_hash: String,
+    pub expires_at: String,
+    pub used: bool,
+    pub created_at: String,
+}
+
+/// DTO: Query parameters of the confirmation link
+#[derive(Debug, Clone, Deserialize)]
+pub struct VerifyContactEmailQuery {
+    pub token: String,
+}
+
+/// DTO: Response for a confirmed (or re-sent) verification
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactEmailVerificationResponse {
+    pub message: String,
+}
+
+impl ContactEmailVerification {
+    /// Record for `token`, valid for `CONTACT_EMAIL_VERIFICATION_TTL_HOURS`
+    pub fn new(channel_id: String, token: &str) -> Self {
+        let now = time::OffsetDateTime::now_utc();
+        let expires_at = now + time::Duration::hours(CONTACT_EMAIL_VERIFICATION_TTL_HOURS);
+
+        Self {
+            id: Uuid::new_v4().to_string(),
+            channel_id,
+            token_hash: Self::hash_token(token),
+            expires_at: expires_at
+                .format(&time::format_description::well_known::Rfc3339)
+                .unwrap(),
+            used: false,
+            created_at: now
+                .format(&time::format_description::well_known::Rfc3339)
+                .unwrap(),
+        }
+    }
+
+    /// Hex-encoded SHA-256 of a verification token, as stored in the database
+    pub fn hash_token(token: &str) -> String {
+        hex::encode(Sha256::digest(token.as_bytes()))
+    }
+
+    pub fn is_expired(&self) -> bool {
+        if let Ok(expires_at) = time::OffsetDateTime::parse(
+            &self.expires_at,
+            &time::format_description::well_known::Rfc3339,
+        ) {
+            expires_at < time::OffsetDateTime::now_utc()
+        } else {
+            true
+        }
+    }
+}
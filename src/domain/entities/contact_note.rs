@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Longest note content, in characters
+pub const CONTACT_NOTE_MAX_LENGTH: usize = 5000;
+
+/// Note an agent keeps about a contact
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContactNote {
+    pub id: String,
+    /// User id of the contact
+    pub contact_id: String,
+    /// Agent who wrote the note; `None` once that agent is deleted
+    pub author_id: Option<String>,
+    /// Author's display name, filled in when notes are read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    pub content: String,
+    pub pinned: bool,
+    pub pinned_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl ContactNote {
+    pub fn new(contact_id: String, author_id: String, content: String, pinned: bool) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            contact_id,
+            author_id: Some(author_id),
+            author_name: None,
+            content: content.trim().to_string(),
+            pinned,
+            pinned_at: pinned.then(|| now.clone()),
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /// Pin or unpin the note; pinning again keeps the original pin time
+    pub fn set_pinned(&mut self, pinned: bool) {
+        if pinned && !self.pinned {
+            self.pinned_at = Some(chrono::Utc::now().to_rfc3339());
+        } else if !pinned {
+            self.pinned_at = None;
+        }
+        self.pinned = pinned;
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateContactNoteRequest {
+    pub content: String,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+impl Validate for CreateContactNoteRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("content", &self.content, 1, CONTACT_NOTE_MAX_LENGTH);
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpdateContactNoteRequest {
+    pub content: Option<String>,
+    pub pinned: Option<bool>,
+}
+
+impl Validate for UpdateContactNoteRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length(
+            "content",
+            self.content.as_deref(),
+            1,
+            CONTACT_NOTE_MAX_LENGTH,
+        );
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContactNoteListResponse {
+    pub contact_id: String,
+    pub notes: Vec<ContactNote>,
+}
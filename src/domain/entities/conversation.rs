@@ -3,6 +3,10 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use std::fmt;
 
+use crate::domain::entities::{SentimentLabel, SentimentTrend};
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ConversationStatus {
@@ -10,6 +14,8 @@ pub enum ConversationStatus {
     Snoozed,
     Resolved,
     Closed,
+    /// Spam or junk; left out of default lists, reports and SLA tracking
+    Junk,
 }
 
 impl fmt::Display for ConversationStatus {
@@ -19,6 +25,7 @@ impl fmt::Display for ConversationStatus {
             ConversationStatus::Snoozed => write!(f, "snoozed"),
             ConversationStatus::Resolved => write!(f, "resolved"),
             ConversationStatus::Closed => write!(f, "closed"),
+            ConversationStatus::Junk => write!(f, "junk"),
         }
     }
 }
@@ -30,6 +37,7 @@ impl From<String> for ConversationStatus {
             "snoozed" => ConversationStatus::Snoozed,
             "resolved" => ConversationStatus::Resolved,
             "closed" => ConversationStatus::Closed,
+            "junk" => ConversationStatus::Junk,
             _ => ConversationStatus::Open,
         }
     }
@@ -151,6 +159,25 @@ pub struct Conversation {
     #[sqlx(skip)]
     pub tags: Option<Vec<String>>,
     pub priority: Option<Priority>,
+    /// Whether the contact's email for this inbox is verified (populated on single fetch)
+    #[sqlx(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact_email_verified: Option<bool>,
+    /// Score of the most recent CSAT response (populated on single fetch)
+    #[sqlx(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub csat_score: Option<i32>,
+    /// Rolling sentiment of inbound messages, from -1.0 to 1.0
+    #[sqlx(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sentiment_score: Option<f64>,
+    #[sqlx(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sentiment_trend: Option<SentimentTrend>,
+    /// Short rolling preview: the latest issue statement and current state
+    #[sqlx(skip)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -160,6 +187,14 @@ pub struct CreateConversation {
     pub subject: Option<String>,
 }
 
+impl Validate for CreateConversation {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("inbox_id", &self.inbox_id, 1, 255);
+        errors.length("contact_id", &self.contact_id, 1, 255);
+        errors.max_length("subject", self.subject.as_deref(), 998);
+    }
+}
+
 impl CreateConversation {
     /// Validate cardinality invariants for conversation creation
     /// Feature 023: Cardinality Invariants
@@ -223,6 +258,18 @@ pub struct UpdateStatusRequest {
     pub snooze_duration: Option<String>, // e.g. "2h", "30m"
 }
 
+impl Validate for UpdateStatusRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        match self.snooze_duration.as_deref() {
+            Some(duration) => errors.length("snooze_duration", duration, 1, 20),
+            None if self.status == ConversationStatus::Snoozed => {
+                errors.add("snooze_duration", "is required when snoozing")
+            }
+            None => {}
+        }
+    }
+}
+
 /// Request body for updating conversation priority (Feature 020)
 #[derive(Debug, Clone, Deserialize)]
 pub struct UpdatePriorityRequest {
@@ -230,12 +277,118 @@ pub struct UpdatePriorityRequest {
     pub priority: Option<Priority>,
 }
 
+impl Validate for UpdatePriorityRequest {
+    // The priority value itself is checked when the body is deserialized
+    fn validate_fields(&self, _errors: &mut ValidationErrors) {}
+}
+
+/// Filters for listing conversations
+#[derive(Debug, Clone, Default)]
+pub struct ConversationListFilter {
+    pub status: Option<ConversationStatus>,
+    pub inbox_id: Option<String>,
+    pub contact_id: Option<String>,
+    /// Only conversations whose rolling sentiment falls in this bucket
+    pub sentiment: Option<SentimentLabel>,
+    /// Only open or snoozed conversations
+    pub unresolved: bool,
+    /// Conditions compiled from a search query or saved view
+    pub conditions: Vec<super::ConversationCondition>,
+    /// Ordering of the listed conversations (not used when counting)
+    pub sort: ConversationSort,
+}
+
+/// Field a conversation list is ordered by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationSortField {
+    #[default]
+    CreatedAt,
+    LastMessageAt,
+    Priority,
+    /// Earliest unmet SLA deadline; conversations without one sort last
+    SlaTimeToBreach,
+    ReferenceNumber,
+}
+
+impl ConversationSortField {
+    /// Direction used when the caller gives none: newest, most urgent or
+    /// soonest to breach first
+    pub fn default_direction(self) -> SortDirection {
+        match self {
+            ConversationSortField::SlaTimeToBreach => SortDirection::Asc,
+            _ => SortDirection::Desc,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// Ordering of a conversation list
+///
+/// Ties are broken by creation time and id, newest first, so pages stay
+/// stable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConversationSort {
+    pub field: ConversationSortField,
+    pub direction: SortDirection,
+}
+
+impl ConversationSort {
+    pub fn new(field: ConversationSortField, direction: Option<SortDirection>) -> Self {
+        Self {
+            field,
+            direction: direction.unwrap_or_else(|| field.default_direction()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConversationListResponse {
     pub conversations: Vec<Conversation>,
     pub pagination: crate::domain::entities::PaginationMetadata,
 }
 
+/// Minutes ahead an open conversation's next SLA deadline counts as breaching soon
+pub const BREACHING_SOON_MINUTES: i64 = 60;
+
+/// Which conversations the sidebar counters cover
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversationCountScope {
+    /// Every conversation (`conversations:read_all`)
+    All,
+    /// Conversations assigned to the user or one of their teams
+    /// (`conversations:read_assigned`)
+    Assigned { team_ids: Vec<String> },
+}
+
+/// Open conversation counters for the inbox sidebar
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConversationCounts {
+    /// Assigned to the requesting user
+    pub mine: i64,
+    /// Without an assigned user
+    pub unassigned: i64,
+    /// High priority
+    pub urgent: i64,
+    /// With a pending SLA deadline within `BREACHING_SOON_MINUTES`
+    pub breaching_soon: i64,
+    /// Open conversations per inbox, for inboxes that have any
+    pub inboxes: Vec<InboxConversationCount>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InboxConversationCount {
+    pub inbox_id: String,
+    pub open: i64,
+}
+
 // Helper methods for timestamps (converting String <-> DateTime<Utc>)
 impl Conversation {
     pub fn resolved_at_datetime(&self) -> Option<DateTime<Utc>> {
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// A contact taking part in a conversation
+///
+/// The conversation's own contact is the primary one; others are added when
+/// they write in on the thread or by an agent. Inbound messages are
+/// attributed to whichever contact sent them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationContact {
+    pub conversation_id: String,
+    pub contact_id: String,
+    pub name: Option<String>,
+    pub email: String,
+    pub is_primary: bool,
+    /// Agent who added the contact; None for the primary contact and for
+    /// contacts added by an inbound message
+    pub added_by: Option<String>,
+    pub added_at: String,
+}
+
+/// Request to add a contact to a conversation
+#[derive(Debug, Clone, Deserialize)]
+pub struct AddConversationContactRequest {
+    pub contact_id: String,
+}
+
+impl Validate for AddConversationContactRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("contact_id", self.contact_id.trim(), 1, 255);
+    }
+}
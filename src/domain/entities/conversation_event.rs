@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::domain::entities::{ConversationStatus, Priority};
+
+/// Kind of state change recorded in a conversation's history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationEventType {
+    /// Data: `status`, `inbox_id`, `contact_id`, `assigned_user_id`,
+    /// `assigned_team_id`, `priority`
+    Created,
+    /// Data: `from`, `to`
+    StatusChanged,
+    /// Data: `from`, `to` (user ids, null when unassigned)
+    AssigneeChanged,
+    /// Data: `from`, `to` (team ids)
+    TeamChanged,
+    /// Data: `from`, `to` (contact ids, when contacts are merged)
+    ContactChanged,
+    /// Data: `from`, `to`
+    PriorityChanged,
+    /// Data: `tag_id`, `tag`
+    TagAdded,
+    /// Data: `tag_id`, `tag`
+    TagRemoved,
+    /// Data: `applied_sla_id`, `sla_policy_id`, `policy`, deadlines
+    SlaApplied,
+    /// Data: `applied_sla_id`, `from`, `to`
+    SlaStatusChanged,
+    /// Data: `sla_event_id`, `sla_event_type`, `deadline_at`, `met_at`
+    SlaMet,
+    /// Data: `sla_event_id`, `sla_event_type`, `deadline_at`, `breached_at`
+    SlaBreached,
+}
+
+impl ConversationEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConversationEventType::Created => "created",
+            ConversationEventType::StatusChanged => "status_changed",
+            ConversationEventType::AssigneeChanged => "assignee_changed",
+            ConversationEventType::TeamChanged => "team_changed",
+            ConversationEventType::ContactChanged => "contact_changed",
+            ConversationEventType::PriorityChanged => "priority_changed",
+            ConversationEventType::TagAdded => "tag_added",
+            ConversationEventType::TagRemoved => "tag_removed",
+            ConversationEventType::SlaApplied => "sla_applied",
+            ConversationEventType::SlaStatusChanged => "sla_status_changed",
+            ConversationEventType::SlaMet => "sla_met",
+            ConversationEventType::SlaBreached => "sla_breached",
+        }
+    }
+}
+
+impl fmt::Display for ConversationEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for ConversationEventType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created" => Ok(ConversationEventType::Created),
+            "status_changed" => Ok(ConversationEventType::StatusChanged),
+            "assignee_changed" => Ok(ConversationEventType::AssigneeChanged),
+            "team_changed" => Ok(ConversationEventType::TeamChanged),
+            "contact_changed" => Ok(ConversationEventType::ContactChanged),
+            "priority_changed" => Ok(ConversationEventType::PriorityChanged),
+            "tag_added" => Ok(ConversationEventType::TagAdded),
+            "tag_removed" => Ok(ConversationEventType::TagRemoved),
+            "sla_applied" => Ok(ConversationEventType::SlaApplied),
+            "sla_status_changed" => Ok(ConversationEventType::SlaStatusChanged),
+            "sla_met" => Ok(ConversationEventType::SlaMet),
+            "sla_breached" => Ok(ConversationEventType::SlaBreached),
+            _ => Err(format!("Unknown conversation event type: {}", s)),
+        }
+    }
+}
+
+/// One entry in a conversation's append-only history
+///
+/// Written by the repository in the same transaction as the change it
+/// records, so every code path (API, automation, SLA worker) is covered.
+/// `actor_id` is set when the change was attributed to a user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationEvent {
+    /// Increases with every event, so it orders a conversation's history
+    pub id: i64,
+    pub conversation_id: String,
+    pub event_type: ConversationEventType,
+    pub actor_id: Option<String>,
+    pub data: serde_json::Value,
+    pub created_at: String,
+}
+
+/// Conversation state rebuilt by replaying its events in order
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ConversationStateSnapshot {
+    pub status: Option<ConversationStatus>,
+    pub contact_id: Option<String>,
+    pub assigned_user_id: Option<String>,
+    pub assigned_team_id: Option<String>,
+    pub priority: Option<Priority>,
+    pub tag_ids: BTreeSet<String>,
+}
+
+impl ConversationStateSnapshot {
+    pub fn replay(events: &[ConversationEvent]) -> Self {
+        fn text(data: &serde_json::Value, key: &str) -> Option<String> {
+            data.get(key).and_then(|v| v.as_str()).map(str::to_string)
+        }
+
+        let mut state = Self::default();
+        for event in events {
+            let data = &event.data;
+            match event.event_type {
+                ConversationEventType::Created => {
+                    state.status = text(data, "status").map(ConversationStatus::from);
+                    state.contact_id = text(data, "contact_id");
+                    state.assigned_user_id = text(data, "assigned_user_id");
+                    state.assigned_team_id = text(data, "assigned_team_id");
+                    state.priority = text(data, "priority").map(Priority::from);
+                }
+                ConversationEventType::StatusChanged => {
+                    state.status = text(data, "to").map(ConversationStatus::from);
+                }
+                ConversationEventType::AssigneeChanged => {
+                    state.assigned_user_id = text(data, "to");
+                }
+                ConversationEventType::TeamChanged => {
+                    state.assigned_team_id = text(data, "to");
+                }
+                ConversationEventType::ContactChanged => {
+                    state.contact_id = text(data, "to");
+                }
+                ConversationEventType::PriorityChanged => {
+                    state.priority = text(data, "to").map(Priority::from);
+                }
+                ConversationEventType::TagAdded => {
+                    if let Some(tag_id) = text(data, "tag_id") {
+                        state.tag_ids.insert(tag_id);
+                    }
+                }
+                ConversationEventType::TagRemoved => {
+                    if let Some(tag_id) = text(data, "tag_id") {
+                        state.tag_ids.remove(&tag_id);
+                    }
+                }
+                ConversationEventType::SlaApplied
+                | ConversationEventType::SlaStatusChanged
+                | ConversationEventType::SlaMet
+                | ConversationEventType::SlaBreached => {}
+            }
+        }
+        state
+    }
+}
+
+/// Response for the conversation timeline
+#[derive(Debug, Clone, Serialize)]
+pub struct ConversationTimelineResponse {
+    pub conversation_id: String,
+    /// Oldest first
+    pub events: Vec<ConversationEvent>,
+    /// State as rebuilt from `events`, for comparing against the conversation
+    pub state: ConversationStateSnapshot,
+}
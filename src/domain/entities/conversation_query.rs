@@ -0,0 +1,535 @@
+use chrono::{DateTime, Duration, NaiveDate, SecondsFormat, Utc};
+use std::fmt;
+
+use super::conversation::ConversationStatus;
+
+/// Longest accepted search query, in characters
+pub const MAX_CONVERSATION_QUERY_LENGTH: usize = 500;
+
+/// Filter names understood by the conversation query language
+const QUERY_FIELDS: &str =
+    "status, priority, tag, assignee, team, inbox, contact, created, updated";
+
+/// Comparison in a query term such as `priority>=high`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            CompareOp::Eq => "=",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+        }
+    }
+
+    /// The same comparison with its operands swapped
+    fn flipped(self) -> Self {
+        match self {
+            CompareOp::Eq => CompareOp::Eq,
+            CompareOp::Lt => CompareOp::Gt,
+            CompareOp::Le => CompareOp::Ge,
+            CompareOp::Gt => CompareOp::Lt,
+            CompareOp::Ge => CompareOp::Le,
+        }
+    }
+}
+
+impl fmt::Display for CompareOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompareOp::Eq => write!(f, ":"),
+            op => write!(f, "{}", op.as_sql()),
+        }
+    }
+}
+
+/// Conversation timestamp a query can compare against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversationTimestamp {
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl ConversationTimestamp {
+    pub fn column(self) -> &'static str {
+        match self {
+            ConversationTimestamp::CreatedAt => "created_at",
+            ConversationTimestamp::UpdatedAt => "updated_at",
+        }
+    }
+}
+
+/// One condition compiled from a search query; a conversation must match
+/// every condition of its filter
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversationCondition {
+    /// Status is any of these
+    StatusIn(Vec<ConversationStatus>),
+    /// Priority rank (none 0, low 1, medium 2, high 3) compared to a value
+    PriorityRank(CompareOp, i64),
+    /// Tagged with any of these tag names, compared case-insensitively
+    TagIn(Vec<String>),
+    /// Assigned to this user; `None` for unassigned
+    AssignedUser(Option<String>),
+    /// Assigned to this team; `None` for no team
+    AssignedTeam(Option<String>),
+    Inbox(String),
+    Contact(String),
+    /// Timestamp compared to an RFC 3339 instant
+    Timestamp(ConversationTimestamp, CompareOp, String),
+    /// Subject or any message contains the text
+    Text(String),
+}
+
+/// What `me` and relative times in a query refer to
+#[derive(Debug, Clone)]
+pub struct ConversationQueryContext {
+    /// User id `assignee:me` resolves to
+    pub user_id: String,
+    pub now: DateTime<Utc>,
+}
+
+impl ConversationQueryContext {
+    pub fn new(user_id: impl Into<String>) -> Self {
+        Self {
+            user_id: user_id.into(),
+            now: Utc::now(),
+        }
+    }
+}
+
+/// A search query that could not be parsed, with the 1-based column of the
+/// offending term
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid query at column {column}: {message}")]
+pub struct QueryParseError {
+    pub column: usize,
+    pub message: String,
+}
+
+impl QueryParseError {
+    fn new(column: usize, message: impl Into<String>) -> Self {
+        Self {
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+/// A term of the query before it is checked against the known filters
+#[derive(Debug)]
+struct Term {
+    column: usize,
+    field: Option<String>,
+    op: CompareOp,
+    value: String,
+}
+
+/// Compile a conversation search query into filter conditions
+///
+/// A query is a list of whitespace-separated terms that must all match:
+///
+/// ```text
+/// status:open,snoozed tag:billing priority>=high assignee:me updated<2d "refund"
+/// ```
+///
+/// - `status:`, `tag:` take a comma-separated list and match any of it
+/// - `priority` compares with `:`, `<`, `<=`, `>` or `>=` against `none`,
+///   `low`, `medium` or `high`
+/// - `assignee:` takes `me`, `none` or a user id; `team:` takes `none` or a
+///   team id; `inbox:` and `contact:` take an id
+/// - `created` and `updated` compare against an age (`30m`, `12h`, `2d`,
+///   `1w`, so `updated<2d` is "updated in the last two days") or a date
+///   (`updated<2024-05-01` is "last updated before May 1st")
+/// - anything else, quoted or bare, is text matched against the subject and
+///   message contents
+pub fn compile_conversation_query(
+    query: &str,
+    context: &ConversationQueryContext,
+) -> Result<Vec<ConversationCondition>, QueryParseError> {
+    if query.chars().count() > MAX_CONVERSATION_QUERY_LENGTH {
+        return Err(QueryParseError::new(
+            MAX_CONVERSATION_QUERY_LENGTH + 1,
+            format!(
+                "query is longer than {} characters",
+                MAX_CONVERSATION_QUERY_LENGTH
+            ),
+        ));
+    }
+
+    tokenize(query)?
+        .into_iter()
+        .map(|term| compile_term(term, context))
+        .collect()
+}
+
+fn tokenize(query: &str) -> Result<Vec<Term>, QueryParseError> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut terms = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let column = i + 1;
+
+        if chars[i] == '"' {
+            let (value, next) = read_quoted(&chars, i)?;
+            terms.push(Term {
+                column,
+                field: None,
+                op: CompareOp::Eq,
+                value,
+            });
+            i = next;
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+            i += 1;
+        }
+        let name: String = chars[start..i].iter().collect();
+
+        let Some((op, op_len)) = read_operator(&chars, i) else {
+            // Plain word: read to the next space and search for it as text
+            while i < chars.len() && !chars[i].is_whitespace() {
+                i += 1;
+            }
+            terms.push(Term {
+                column,
+                field: None,
+                op: CompareOp::Eq,
+                value: chars[start..i].iter().collect(),
+            });
+            continue;
+        };
+        if name.is_empty() {
+            return Err(QueryParseError::new(
+                column,
+                format!("missing filter name before '{}'", op),
+            ));
+        }
+        i += op_len;
+
+        let value = if i < chars.len() && chars[i] == '"' {
+            let (value, next) = read_quoted(&chars, i)?;
+            i = next;
+            value
+        } else {
+            let value_start = i;
+            while i < chars.len() && !chars[i].is_whitespace() {
+                i += 1;
+            }
+            chars[value_start..i].iter().collect()
+        };
+        if value.is_empty() {
+            return Err(QueryParseError::new(
+                column,
+                format!("missing value after '{}{}'", name, op),
+            ));
+        }
+
+        terms.push(Term {
+            column,
+            field: Some(name.to_lowercase()),
+            op,
+            value,
+        });
+    }
+
+    Ok(terms)
+}
+
+/// Operator at `i`, with its length; `:` may be followed by a comparison
+/// (`priority:>=high`)
+fn read_operator(chars: &[char], i: usize) -> Option<(CompareOp, usize)> {
+    let (offset, first) = match chars.get(i)? {
+        ':' => match chars.get(i + 1) {
+            Some('<') | Some('>') => (1, chars[i + 1]),
+            _ => return Some((CompareOp::Eq, 1)),
+        },
+        '=' => return Some((CompareOp::Eq, 1)),
+        c @ ('<' | '>') => (0, *c),
+        _ => return None,
+    };
+    let or_equal = chars.get(i + offset + 1) == Some(&'=');
+    let op = match (first, or_equal) {
+        ('<', false) => CompareOp::Lt,
+        ('<', true) => CompareOp::Le,
+        ('>', false) => CompareOp::Gt,
+        _ => CompareOp::Ge,
+    };
+    Some((op, offset + 1 + usize::from(or_equal)))
+}
+
+/// Text between the quote at `start` and the next one, and the index after it
+fn read_quoted(chars: &[char], start: usize) -> Result<(String, usize), QueryParseError> {
+    let end = chars[start + 1..]
+        .iter()
+        .position(|c| *c == '"')
+        .map(|offset| start + 1 + offset)
+        .ok_or_else(|| QueryParseError::new(start + 1, "unclosed quote"))?;
+    Ok((chars[start + 1..end].iter().collect(), end + 1))
+}
+
+fn compile_term(
+    term: Term,
+    context: &ConversationQueryContext,
+) -> Result<ConversationCondition, QueryParseError> {
+    let column = term.column;
+    let error = |message: String| QueryParseError::new(column, message);
+
+    let Some(field) = term.field.as_deref() else {
+        return Ok(ConversationCondition::Text(term.value));
+    };
+
+    match field {
+        "created" | "updated" => {
+            let timestamp = if field == "created" {
+                ConversationTimestamp::CreatedAt
+            } else {
+                ConversationTimestamp::UpdatedAt
+            };
+            if term.op == CompareOp::Eq {
+                return Err(error(format!(
+                    "'{}' needs <, <=, > or >=, e.g. {}<2d",
+                    field, field
+                )));
+            }
+            compile_time(timestamp, term.op, &term.value, context).map_err(error)
+        }
+        "priority" => {
+            let rank = match term.value.to_lowercase().as_str() {
+                "none" => 0,
+                "low" => 1,
+                "medium" => 2,
+                "high" => 3,
+                _ => {
+                    return Err(error(format!(
+                        "unknown priority '{}'; expected none, low, medium or high",
+                        term.value
+                    )))
+                }
+            };
+            Ok(ConversationCondition::PriorityRank(term.op, rank))
+        }
+        "status" | "tag" | "assignee" | "team" | "inbox" | "contact"
+            if term.op != CompareOp::Eq =>
+        {
+            Err(error(format!(
+                "'{}' only supports ':', not '{}'",
+                field, term.op
+            )))
+        }
+        "status" => {
+            let statuses = split_list(&term.value)
+                .map(|value| parse_status(value).ok_or(value))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|value| {
+                    error(format!(
+                        "unknown status '{}'; expected open, snoozed, resolved, closed or junk",
+                        value
+                    ))
+                })?;
+            Ok(ConversationCondition::StatusIn(statuses))
+        }
+        "tag" => Ok(ConversationCondition::TagIn(
+            split_list(&term.value).map(str::to_lowercase).collect(),
+        )),
+        "assignee" => Ok(ConversationCondition::AssignedUser(
+            match term.value.as_str() {
+                "me" => Some(context.user_id.clone()),
+                "none" => None,
+                user_id => Some(user_id.to_string()),
+            },
+        )),
+        "team" => Ok(ConversationCondition::AssignedTeam(
+            (term.value != "none").then_some(term.value),
+        )),
+        "inbox" => Ok(ConversationCondition::Inbox(term.value)),
+        "contact" => Ok(ConversationCondition::Contact(term.value)),
+        _ => Err(error(format!(
+            "unknown filter '{}'; expected one of {}",
+            field, QUERY_FIELDS
+        ))),
+    }
+}
+
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|v| !v.is_empty())
+}
+
+fn parse_status(value: &str) -> Option<ConversationStatus> {
+    match value.to_lowercase().as_str() {
+        "open" => Some(ConversationStatus::Open),
+        "snoozed" => Some(ConversationStatus::Snoozed),
+        "resolved" => Some(ConversationStatus::Resolved),
+        "closed" => Some(ConversationStatus::Closed),
+        "junk" => Some(ConversationStatus::Junk),
+        _ => None,
+    }
+}
+
+/// Compile `created`/`updated` against an age (`2d`) or a date (`2024-05-01`)
+fn compile_time(
+    timestamp: ConversationTimestamp,
+    op: CompareOp,
+    value: &str,
+    context: &ConversationQueryContext,
+) -> Result<ConversationCondition, String> {
+    if let Some(age) = parse_age(value) {
+        // A younger age means a later timestamp
+        let instant = context.now - age;
+        return Ok(ConversationCondition::Timestamp(
+            timestamp,
+            op.flipped(),
+            instant.to_rfc3339_opts(SecondsFormat::Secs, true),
+        ));
+    }
+
+    let instant = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc())
+        .or_else(|| {
+            DateTime::parse_from_rfc3339(value)
+                .ok()
+                .map(|t| t.with_timezone(&Utc))
+        })
+        .ok_or_else(|| {
+            format!(
+                "invalid time '{}'; use an age like 30m, 12h, 2d or 1w, or a date like 2024-05-01",
+                value
+            )
+        })?;
+    Ok(ConversationCondition::Timestamp(
+        timestamp,
+        op,
+        instant.to_rfc3339_opts(SecondsFormat::Secs, true),
+    ))
+}
+
+fn parse_age(value: &str) -> Option<Duration> {
+    let unit = value.chars().last()?;
+    let amount: i64 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    if amount < 0 {
+        return None;
+    }
+    match unit {
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> ConversationQueryContext {
+        ConversationQueryContext {
+            user_id: "user-1".to_string(),
+            now: DateTime::parse_from_rfc3339("2024-05-10T12:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+        }
+    }
+
+    fn compile(query: &str) -> Result<Vec<ConversationCondition>, QueryParseError> {
+        compile_conversation_query(query, &context())
+    }
+
+    #[test]
+    fn test_compiles_power_user_query() {
+        let conditions =
+            compile("status:open tag:billing priority>=high assignee:me updated<2d").unwrap();
+        assert_eq!(
+            conditions,
+            vec![
+                ConversationCondition::StatusIn(vec![ConversationStatus::Open]),
+                ConversationCondition::TagIn(vec!["billing".to_string()]),
+                ConversationCondition::PriorityRank(CompareOp::Ge, 3),
+                ConversationCondition::AssignedUser(Some("user-1".to_string())),
+                ConversationCondition::Timestamp(
+                    ConversationTimestamp::UpdatedAt,
+                    CompareOp::Gt,
+                    "2024-05-08T12:00:00Z".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lists_dates_text_and_alternate_operators() {
+        let conditions = compile(
+            r#"status:open,Snoozed team:none priority:<=medium created>=2024-05-01 "late refund" order"#,
+        )
+        .unwrap();
+        assert_eq!(
+            conditions,
+            vec![
+                ConversationCondition::StatusIn(vec![
+                    ConversationStatus::Open,
+                    ConversationStatus::Snoozed
+                ]),
+                ConversationCondition::AssignedTeam(None),
+                ConversationCondition::PriorityRank(CompareOp::Le, 2),
+                ConversationCondition::Timestamp(
+                    ConversationTimestamp::CreatedAt,
+                    CompareOp::Ge,
+                    "2024-05-01T00:00:00Z".to_string()
+                ),
+                ConversationCondition::Text("late refund".to_string()),
+                ConversationCondition::Text("order".to_string()),
+            ]
+        );
+        assert!(compile("   ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors_point_at_the_term() {
+        let err = compile("status:open stauts:closed").unwrap_err();
+        assert_eq!(err.column, 13);
+        assert!(err.message.contains("unknown filter 'stauts'"));
+
+        let err = compile("status:pending").unwrap_err();
+        assert!(err.message.contains("unknown status 'pending'"));
+
+        let err = compile("tag>billing").unwrap_err();
+        assert_eq!(err.message, "'tag' only supports ':', not '>'");
+
+        let err = compile("updated:2d").unwrap_err();
+        assert!(err.message.contains("needs <, <=, > or >="));
+
+        let err = compile("updated<2x").unwrap_err();
+        assert!(err.message.contains("invalid time '2x'"));
+
+        let err = compile("priority>urgent").unwrap_err();
+        assert!(err.message.contains("expected none, low, medium or high"));
+
+        let err = compile("assignee:").unwrap_err();
+        assert_eq!(err.message, "missing value after 'assignee:'");
+
+        let err = compile(r#"tag:billing "refund"#).unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (13, "unclosed quote"));
+
+        assert_eq!(
+            compile(":open").unwrap_err().to_string(),
+            "Invalid query at column 1: missing filter name before ':'"
+        );
+    }
+}
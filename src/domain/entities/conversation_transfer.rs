@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Longest handover note accepted with a transfer
+pub const MAX_TRANSFER_NOTE_LENGTH: usize = 2000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferStatus {
+    /// Waiting for the receiver to accept or decline
+    Pending,
+    /// The conversation was reassigned
+    Completed,
+    /// The receiver declined; the assignment is unchanged
+    Declined,
+}
+
+impl fmt::Display for TransferStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransferStatus::Pending => write!(f, "pending"),
+            TransferStatus::Completed => write!(f, "completed"),
+            TransferStatus::Declined => write!(f, "declined"),
+        }
+    }
+}
+
+impl FromStr for TransferStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(TransferStatus::Pending),
+            "completed" => Ok(TransferStatus::Completed),
+            "declined" => Ok(TransferStatus::Declined),
+            _ => Err(format!("Invalid transfer status: {}", s)),
+        }
+    }
+}
+
+/// Handover of a conversation to another agent and/or team
+///
+/// `from_*` is the assignment at the time of the request. A transfer that
+/// requires acceptance is only applied once the receiver accepts it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationTransfer {
+    pub id: String,
+    pub conversation_id: String,
+    pub from_user_id: Option<String>,
+    pub from_team_id: Option<String>,
+    pub to_user_id: Option<String>,
+    pub to_team_id: Option<String>,
+    pub note: String,
+    pub requires_acceptance: bool,
+    pub status: TransferStatus,
+    pub requested_by: String,
+    pub responded_by: Option<String>,
+    pub created_at: String,
+    pub responded_at: Option<String>,
+}
+
+impl ConversationTransfer {
+    pub fn new(
+        conversation_id: String,
+        from_user_id: Option<String>,
+        from_team_id: Option<String>,
+        request: &TransferConversationRequest,
+        requested_by: String,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            conversation_id,
+            from_user_id,
+            from_team_id,
+            to_user_id: request.to_user_id.clone(),
+            to_team_id: request.to_team_id.clone(),
+            note: request.note.trim().to_string(),
+            requires_acceptance: request.require_acceptance,
+            status: if request.require_acceptance {
+                TransferStatus::Pending
+            } else {
+                TransferStatus::Completed
+            },
+            requested_by,
+            responded_by: None,
+            created_at: chrono::Utc::now().to_rfc3339(),
+            responded_at: None,
+        }
+    }
+}
+
+/// Request body for POST /api/conversations/:id/transfer
+#[derive(Debug, Clone, Deserialize)]
+pub struct TransferConversationRequest {
+    pub to_user_id: Option<String>,
+    pub to_team_id: Option<String>,
+    /// Handover context for the receiver
+    pub note: String,
+    /// Keep the current assignment until the receiver accepts
+    #[serde(default)]
+    pub require_acceptance: bool,
+}
+
+impl Validate for TransferConversationRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if self.to_user_id.is_none() && self.to_team_id.is_none() {
+            errors.add("to_user_id", "to_user_id or to_team_id is required");
+        }
+        errors.length("note", &self.note, 1, MAX_TRANSFER_NOTE_LENGTH);
+    }
+}
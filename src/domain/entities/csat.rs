@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Lowest accepted CSAT score
+pub const CSAT_MIN_SCORE: i32 = 1;
+
+/// Highest accepted CSAT score
+pub const CSAT_MAX_SCORE: i32 = 5;
+
+/// Longest CSAT comment accepted
+pub const CSAT_MAX_COMMENT_LENGTH: usize = 2000;
+
+/// A customer satisfaction rating left on a conversation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsatResponse {
+    pub id: String,
+    pub conversation_id: String,
+    pub score: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub created_at: String,
+}
+
+impl CsatResponse {
+    pub fn new(conversation_id: String, score: i32, comment: Option<String>) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            conversation_id,
+            score,
+            comment,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitCsatRequest {
+    pub score: i32,
+    pub comment: Option<String>,
+}
+
+impl Validate for SubmitCsatRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.range("score", self.score, CSAT_MIN_SCORE, CSAT_MAX_SCORE);
+        errors.max_length(
+            "comment",
+            self.comment.as_deref().map(str::trim),
+            CSAT_MAX_COMMENT_LENGTH,
+        );
+    }
+}
+
+/// Signature of a survey link, proving it was issued for the conversation
+#[derive(Debug, Deserialize)]
+pub struct CsatSurveyQuery {
+    pub signature: String,
+}
+
+/// Survey submission URL to send a customer
+#[derive(Debug, Serialize)]
+pub struct CsatSurveyLinkResponse {
+    pub url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CsatResponseListResponse {
+    pub responses: Vec<CsatResponse>,
+    pub total: i64,
+}
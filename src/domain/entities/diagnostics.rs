@@ -0,0 +1,28 @@
+use serde::Serialize;
+
+/// A statement that exceeded the slow-query threshold, aggregated across
+/// every time it was seen since startup
+#[derive(Debug, Clone, Serialize)]
+pub struct SlowQuery {
+    /// SQL with whitespace collapsed; parameters are placeholders, so each
+    /// distinct query shape is one entry
+    pub statement: String,
+    pub count: u64,
+    pub total_ms: u64,
+    pub max_ms: u64,
+    pub mean_ms: u64,
+    /// Output of `EXPLAIN QUERY PLAN`, captured the first time the statement
+    /// was slow (SQLite only)
+    pub plan: Option<String>,
+    pub first_seen_at: String,
+    pub last_seen_at: String,
+}
+
+/// Worst slow queries, by total time spent
+#[derive(Debug, Clone, Serialize)]
+pub struct SlowQueryReport {
+    /// False unless `SLOW_QUERY_LOG_ENABLED` is set
+    pub enabled: bool,
+    pub threshold_ms: u64,
+    pub queries: Vec<SlowQuery>,
+}
@@ -2,6 +2,13 @@
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
+use crate::domain::entities::{EmailAuthFailurePolicy, EmailAuthMethod, EmailOAuthProvider};
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Bounds of an inbox's IMAP poll interval, in seconds
+pub const MIN_POLL_INTERVAL_SECONDS: i32 = 10;
+pub const MAX_POLL_INTERVAL_SECONDS: i32 = 86_400;
+
 /// Email configuration for an inbox (IMAP receiving + SMTP sending)
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct InboxEmailConfig {
@@ -23,6 +30,13 @@ pub struct InboxEmailConfig {
     pub smtp_password: String, // Encrypted at rest using AES-256-GCM
     pub smtp_use_tls: bool,
 
+    // Authentication; passwords are unused with OAuth2
+    pub auth_method: EmailAuthMethod,
+    pub oauth_provider: Option<EmailOAuthProvider>,
+
+    /// What to do with email failing SPF/DKIM/DMARC
+    pub auth_failure_policy: EmailAuthFailurePolicy,
+
     // Email identity
     pub email_address: String,
     pub display_name: String,
@@ -68,6 +82,9 @@ impl InboxEmailConfig {
             smtp_username,
             smtp_password,
             smtp_use_tls: true,
+            auth_method: EmailAuthMethod::Password,
+            oauth_provider: None,
+            auth_failure_policy: EmailAuthFailurePolicy::default(),
             email_address,
             display_name,
             poll_interval_seconds: poll_interval_seconds.unwrap_or(30),
@@ -112,6 +129,13 @@ impl MessageAttachment {
     }
 }
 
+/// DTO: Query parameters of a signed attachment download link
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignedDownloadQuery {
+    pub expires: i64,
+    pub signature: String,
+}
+
 /// Processing status for email ingestion
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -199,6 +223,21 @@ impl EmailProcessingLog {
         self
     }
 
+    /// Mark an auto-generated email as handled: recorded as a system note on
+    /// `conversation_id`, or dropped when it matched no conversation
+    pub fn mark_auto_generated(mut self, conversation_id: Option<String>) -> Self {
+        self.processing_status = ProcessingStatus::Success.to_string();
+        self.conversation_id = conversation_id;
+        self
+    }
+
+    /// Mark an email from a blocked sender as handled without creating anything
+    pub fn mark_blocked(mut self) -> Self {
+        self.processing_status = ProcessingStatus::Success.to_string();
+        self.error_message = Some("Sender is blocked for this inbox".to_string());
+        self
+    }
+
     /// Mark as failed with error message
     pub fn mark_failed(mut self, error: String) -> Self {
         self.processing_status = ProcessingStatus::Failed.to_string();
@@ -218,20 +257,93 @@ impl EmailProcessingLog {
     }
 }
 
+/// Progress of an inbox's IMAP backlog, updated after each polled batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InboxEmailBacklog {
+    pub inbox_id: String,
+    /// Emails fetched in the last batch
+    pub messages_fetched: i64,
+    /// Emails from the last batch that were turned into messages
+    pub messages_processed: i64,
+    /// Emails from the last batch that failed to parse or process
+    pub messages_failed: i64,
+    /// Unseen emails still waiting on the server for later batches
+    pub messages_remaining: i64,
+    /// Emails processed across all batches
+    pub total_processed: i64,
+    pub updated_at: String, // ISO8601
+}
+
+impl InboxEmailBacklog {
+    pub fn new(inbox_id: String) -> Self {
+        Self {
+            inbox_id,
+            messages_fetched: 0,
+            messages_processed: 0,
+            messages_failed: 0,
+            messages_remaining: 0,
+            total_processed: 0,
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
 /// Request to create inbox email configuration
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateInboxEmailConfigRequest {
     pub imap_host: String,
     pub imap_port: i32,
     pub imap_username: String,
+    /// Not needed with OAuth2 authentication
+    #[serde(default)]
     pub imap_password: String,
     pub smtp_host: String,
     pub smtp_port: i32,
     pub smtp_username: String,
+    /// Not needed with OAuth2 authentication
+    #[serde(default)]
     pub smtp_password: String,
     pub email_address: String,
     pub display_name: String,
     pub poll_interval_seconds: Option<i32>,
+    #[serde(default)]
+    pub auth_method: EmailAuthMethod,
+    /// Required with OAuth2 authentication
+    pub oauth_provider: Option<EmailOAuthProvider>,
+    #[serde(default)]
+    pub auth_failure_policy: EmailAuthFailurePolicy,
+}
+
+impl Validate for CreateInboxEmailConfigRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("imap_host", &self.imap_host, 1, 255);
+        errors.range("imap_port", self.imap_port, 1, 65535);
+        errors.length("imap_username", &self.imap_username, 1, 255);
+        errors.length("smtp_host", &self.smtp_host, 1, 255);
+        errors.range("smtp_port", self.smtp_port, 1, 65535);
+        errors.length("smtp_username", &self.smtp_username, 1, 255);
+        match self.auth_method {
+            EmailAuthMethod::Password => {
+                errors.length("imap_password", &self.imap_password, 1, 1024);
+                errors.length("smtp_password", &self.smtp_password, 1, 1024);
+            }
+            EmailAuthMethod::OAuth2 => {
+                if self.oauth_provider.is_none() {
+                    errors.add("oauth_provider", "is required for OAuth2 authentication");
+                }
+            }
+        }
+        errors.email("email_address", &self.email_address);
+        errors.length("display_name", &self.display_name, 1, 255);
+        if let Some(poll_interval_seconds) = self.poll_interval_seconds {
+            errors.range(
+                "poll_interval_seconds",
+                poll_interval_seconds,
+                MIN_POLL_INTERVAL_SECONDS,
+                MAX_POLL_INTERVAL_SECONDS,
+            );
+        }
+    }
 }
 
 /// Request to update inbox email configuration
@@ -252,4 +364,94 @@ pub struct UpdateInboxEmailConfigRequest {
     pub display_name: Option<String>,
     pub poll_interval_seconds: Option<i32>,
     pub enabled: Option<bool>,
+    pub auth_method: Option<EmailAuthMethod>,
+    pub oauth_provider: Option<EmailOAuthProvider>,
+    pub auth_failure_policy: Option<EmailAuthFailurePolicy>,
+}
+
+impl Validate for UpdateInboxEmailConfigRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("imap_host", self.imap_host.as_deref(), 1, 255);
+        if let Some(imap_port) = self.imap_port {
+            errors.range("imap_port", imap_port, 1, 65535);
+        }
+        errors.optional_length("imap_username", self.imap_username.as_deref(), 1, 255);
+        errors.optional_length("imap_password", self.imap_password.as_deref(), 1, 1024);
+        errors.optional_length("imap_folder", self.imap_folder.as_deref(), 1, 255);
+        errors.optional_length("smtp_host", self.smtp_host.as_deref(), 1, 255);
+        if let Some(smtp_port) = self.smtp_port {
+            errors.range("smtp_port", smtp_port, 1, 65535);
+        }
+        errors.optional_length("smtp_username", self.smtp_username.as_deref(), 1, 255);
+        errors.optional_length("smtp_password", self.smtp_password.as_deref(), 1, 1024);
+        if let Some(email_address) = &self.email_address {
+            errors.email("email_address", email_address);
+        }
+        errors.optional_length("display_name", self.display_name.as_deref(), 1, 255);
+        if let Some(poll_interval_seconds) = self.poll_interval_seconds {
+            errors.range(
+                "poll_interval_seconds",
+                poll_interval_seconds,
+                MIN_POLL_INTERVAL_SECONDS,
+                MAX_POLL_INTERVAL_SECONDS,
+            );
+        }
+    }
+}
+
+/// An address on a conversation's email thread: the contact or someone CC'd
+///
+/// Suppressed participants stay on the thread history but are left out of
+/// outbound replies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmailParticipant {
+    pub conversation_id: String,
+    pub email: String,
+    pub suppressed: bool,
+    pub suppressed_at: Option<String>,
+    pub suppressed_by: Option<String>,
+    pub added_at: String,
+}
+
+/// Request to stop or resume emailing a participant
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateEmailParticipantRequest {
+    pub suppressed: bool,
+}
+
+impl Validate for UpdateEmailParticipantRequest {
+    // A single required flag: serde already rejects a missing or mistyped one
+    fn validate_fields(&self, _errors: &mut ValidationErrors) {}
+}
+
+/// Recipients of an outbound reply
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailRecipients {
+    pub to: Option<String>,
+    pub cc: Vec<String>,
+}
+
+impl EmailRecipients {
+    /// The contact goes in To and everyone else on the thread in Cc, leaving
+    /// out suppressed participants
+    pub fn for_reply(contact_email: &str, participants: &[EmailParticipant]) -> Self {
+        let contact_email = contact_email.trim().to_lowercase();
+        let is_suppressed = |email: &str| {
+            participants
+                .iter()
+                .any(|p| p.suppressed && p.email.eq_ignore_ascii_case(email))
+        };
+
+        let to = (!is_suppressed(&contact_email)).then(|| contact_email.clone());
+        let cc = participants
+            .iter()
+            .filter(|p| !p.suppressed && !p.email.eq_ignore_ascii_case(&contact_email))
+            .map(|p| p.email.clone())
+            .collect();
+        Self { to, cc }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.to.is_none() && self.cc.is_empty()
+    }
 }
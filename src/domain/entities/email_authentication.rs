@@ -0,0 +1,182 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Outcome of one sender authentication method (RFC 8601 `result`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailAuthResult {
+    Pass,
+    Fail,
+    /// SPF only: the domain says the host is probably not authorized
+    SoftFail,
+    Neutral,
+    /// The method had nothing to check (no record, no signature)
+    None,
+    TempError,
+    PermError,
+    Policy,
+}
+
+impl EmailAuthResult {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailAuthResult::Pass => "pass",
+            EmailAuthResult::Fail => "fail",
+            EmailAuthResult::SoftFail => "softfail",
+            EmailAuthResult::Neutral => "neutral",
+            EmailAuthResult::None => "none",
+            EmailAuthResult::TempError => "temperror",
+            EmailAuthResult::PermError => "permerror",
+            EmailAuthResult::Policy => "policy",
+        }
+    }
+}
+
+impl fmt::Display for EmailAuthResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for EmailAuthResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pass" => Ok(EmailAuthResult::Pass),
+            // "hardfail" is what some older SPF implementations report
+            "fail" | "hardfail" => Ok(EmailAuthResult::Fail),
+            "softfail" => Ok(EmailAuthResult::SoftFail),
+            "neutral" => Ok(EmailAuthResult::Neutral),
+            "none" => Ok(EmailAuthResult::None),
+            "temperror" => Ok(EmailAuthResult::TempError),
+            "permerror" => Ok(EmailAuthResult::PermError),
+            "policy" => Ok(EmailAuthResult::Policy),
+            _ => Err(format!("Unknown authentication result: {}", s)),
+        }
+    }
+}
+
+/// SPF, DKIM and DMARC outcomes of an inbound email, as reported by the
+/// receiving mail server in its `Authentication-Results` header
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmailAuthentication {
+    /// Server that evaluated the email (the header's authserv-id)
+    pub authserv_id: Option<String>,
+    pub spf: Option<EmailAuthResult>,
+    /// Best result over the email's DKIM signatures
+    pub dkim: Option<EmailAuthResult>,
+    pub dmarc: Option<EmailAuthResult>,
+    /// Set when the email failed authentication and the inbox policy flags
+    /// or quarantines such email
+    #[serde(default)]
+    pub flagged: bool,
+}
+
+impl EmailAuthentication {
+    /// Whether the sender could not be authenticated
+    ///
+    /// DMARC decides when the server evaluated it. Without a DMARC verdict,
+    /// a hard SPF failure that no valid DKIM signature makes up for counts.
+    pub fn is_failure(&self) -> bool {
+        match self.dmarc {
+            Some(EmailAuthResult::Fail) => true,
+            Some(EmailAuthResult::Pass) => false,
+            _ => {
+                self.spf == Some(EmailAuthResult::Fail) && self.dkim != Some(EmailAuthResult::Pass)
+            }
+        }
+    }
+}
+
+/// What an inbox does with email that fails sender authentication
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailAuthFailurePolicy {
+    /// Only record the results on the message
+    #[default]
+    Accept,
+    /// Flag the message so agents are warned about a possible spoof
+    Flag,
+    /// Flag the message; new conversations start out as junk and replies
+    /// don't reopen their conversation
+    Quarantine,
+}
+
+impl EmailAuthFailurePolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailAuthFailurePolicy::Accept => "accept",
+            EmailAuthFailurePolicy::Flag => "flag",
+            EmailAuthFailurePolicy::Quarantine => "quarantine",
+        }
+    }
+}
+
+impl fmt::Display for EmailAuthFailurePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for EmailAuthFailurePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "accept" => Ok(EmailAuthFailurePolicy::Accept),
+            "flag" => Ok(EmailAuthFailurePolicy::Flag),
+            "quarantine" => Ok(EmailAuthFailurePolicy::Quarantine),
+            _ => Err(format!("Unknown email authentication policy: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results(
+        spf: Option<EmailAuthResult>,
+        dkim: Option<EmailAuthResult>,
+        dmarc: Option<EmailAuthResult>,
+    ) -> EmailAuthentication {
+        EmailAuthentication {
+            spf,
+            dkim,
+            dmarc,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_dmarc_decides_failure() {
+        use EmailAuthResult::*;
+        assert!(results(Some(Pass), Some(Pass), Some(Fail)).is_failure());
+        assert!(!results(Some(Fail), Some(None), Some(Pass)).is_failure());
+    }
+
+    #[test]
+    fn test_spf_failure_without_dmarc() {
+        use EmailAuthResult::*;
+        assert!(results(Some(Fail), Some(None), Option::None).is_failure());
+        assert!(!results(Some(Fail), Some(Pass), Option::None).is_failure());
+        assert!(!results(Some(SoftFail), Option::None, Option::None).is_failure());
+        assert!(!EmailAuthentication::default().is_failure());
+    }
+
+    #[test]
+    fn test_policy_round_trip() {
+        for policy in [
+            EmailAuthFailurePolicy::Accept,
+            EmailAuthFailurePolicy::Flag,
+            EmailAuthFailurePolicy::Quarantine,
+        ] {
+            assert_eq!(
+                policy.as_str().parse::<EmailAuthFailurePolicy>(),
+                Ok(policy)
+            );
+        }
+        assert!("reject".parse::<EmailAuthFailurePolicy>().is_err());
+    }
+}
@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How an inbox signs in to its IMAP and SMTP servers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailAuthMethod {
+    /// Username and password (LOGIN / PLAIN)
+    #[default]
+    Password,
+    /// OAuth2 access token over SASL XOAUTH2
+    OAuth2,
+}
+
+impl EmailAuthMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailAuthMethod::Password => "password",
+            EmailAuthMethod::OAuth2 => "oauth2",
+        }
+    }
+}
+
+impl fmt::Display for EmailAuthMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for EmailAuthMethod {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "password" => Ok(EmailAuthMethod::Password),
+            "oauth2" => Ok(EmailAuthMethod::OAuth2),
+            _ => Err(format!("Unknown email auth method: {}", s)),
+        }
+    }
+}
+
+/// Mail provider an inbox authorizes with over OAuth2
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailOAuthProvider {
+    /// Gmail / Google Workspace
+    Google,
+    /// Microsoft 365 / Outlook.com
+    Microsoft,
+}
+
+impl EmailOAuthProvider {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailOAuthProvider::Google => "google",
+            EmailOAuthProvider::Microsoft => "microsoft",
+        }
+    }
+
+    /// Scopes granting IMAP and SMTP access, plus a refresh token
+    pub fn scopes(&self) -> &'static [&'static str] {
+        match self {
+            EmailOAuthProvider::Google => &["https://mail.google.com/"],
+            EmailOAuthProvider::Microsoft => &[
+                "offline_access",
+                "https://outlook.office.com/IMAP.AccessAsUser.All",
+                "https://outlook.office.com/SMTP.Send",
+            ],
+        }
+    }
+}
+
+impl fmt::Display for EmailOAuthProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for EmailOAuthProvider {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "google" => Ok(EmailOAuthProvider::Google),
+            "microsoft" => Ok(EmailOAuthProvider::Microsoft),
+            _ => Err(format!("Unknown email OAuth provider: {}", s)),
+        }
+    }
+}
+
+/// OAuth2 tokens of an authorized inbox mailbox
+#[derive(Debug, Clone)]
+pub struct EmailOAuthToken {
+    pub inbox_id: String,
+    pub provider: EmailOAuthProvider,
+    pub access_token: String,  // Encrypted at rest using AES-256-GCM
+    pub refresh_token: String, // Encrypted at rest using AES-256-GCM
+    pub expires_at: String,    // RFC3339
+    pub scope: Option<String>,
+    /// User who completed the authorization
+    pub authorized_by: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl EmailOAuthToken {
+    /// Whether the access token expires within `margin`; unparseable expiry
+    /// times count as expired
+    pub fn expires_within(&self, margin: chrono::Duration) -> bool {
+        match chrono::DateTime::parse_from_rfc3339(&self.expires_at) {
+            Ok(expires_at) => expires_at.with_timezone(&chrono::Utc) - margin <= chrono::Utc::now(),
+            Err(_) => true,
+        }
+    }
+}
+
+/// Pending authorization, from the authorize request until the callback
+#[derive(Debug, Clone)]
+pub struct EmailOAuthState {
+    pub state: String,
+    pub inbox_id: String,
+    pub provider: EmailOAuthProvider,
+    pub pkce_verifier: String,
+    pub created_by: Option<String>,
+    pub created_at: String,
+    pub expires_at: String, // RFC3339
+}
+
+impl EmailOAuthState {
+    pub fn is_expired(&self) -> bool {
+        chrono::DateTime::parse_from_rfc3339(&self.expires_at)
+            .map(|expires_at| expires_at.with_timezone(&chrono::Utc) <= chrono::Utc::now())
+            .unwrap_or(true)
+    }
+}
+
+/// Successful response of a provider's token endpoint
+#[derive(Debug, Clone, Deserialize)]
+pub struct OAuthTokenGrant {
+    pub access_token: String,
+    /// Absent on refreshes that don't rotate the refresh token
+    pub refresh_token: Option<String>,
+    /// Access token lifetime in seconds
+    pub expires_in: Option<i64>,
+    pub scope: Option<String>,
+}
+
+/// Response of starting an inbox's OAuth2 authorization
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailOAuthAuthorization {
+    /// Provider consent page the mailbox owner is sent to
+    pub authorize_url: String,
+    pub provider: EmailOAuthProvider,
+    pub expires_at: String,
+}
+
+/// Query parameters the provider redirects back with
+#[derive(Debug, Clone, Deserialize)]
+pub struct EmailOAuthCallbackParams {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+/// OAuth2 authorization status of an inbox mailbox; tokens are never returned
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailOAuthStatus {
+    pub inbox_id: String,
+    pub provider: Option<EmailOAuthProvider>,
+    pub authorized: bool,
+    /// When the current access token expires; it is refreshed before then
+    pub expires_at: Option<String>,
+    pub scope: Option<String>,
+    pub authorized_by: Option<String>,
+    pub updated_at: Option<String>,
+}
@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// An HTML email template the server renders, with sample values for each
+/// of its `{{variable}}` placeholders
+#[derive(Debug, Clone, Copy)]
+pub struct EmailTemplateDefinition {
+    /// File name under the templates directory, used as the template id
+    pub id: &'static str,
+    pub description: &'static str,
+    /// May contain `{{product_name}}`
+    pub subject: &'static str,
+    pub variables: &'static [(&'static str, &'static str)],
+}
+
+/// Email templates that can be previewed and test-sent
+pub const EMAIL_TEMPLATES: &[EmailTemplateDefinition] = &[
+    EmailTemplateDefinition {
+        id: "agent_reply_email.html",
+        description: "Agent replies, including the agent signature",
+        subject: "Re: Support Request [#1001]",
+        variables: &[
+            (
+                "message_content",
+                "Hi there,<br><br>Thanks for reaching out. Your refund has been processed.",
+            ),
+            ("agent_signature", "Alex<br>Support Team"),
+        ],
+    },
+    EmailTemplateDefinition {
+        id: "password_reset_email.html",
+        description: "Password reset links",
+        subject: "Password Reset Request",
+        variables: &[
+            (
+                "reset_link",
+                "https://support.example.com/reset-password?token=sample-token",
+            ),
+            ("expires_in", "1 hour"),
+        ],
+    },
+    EmailTemplateDefinition {
+        id: "agent_invitation_email.html",
+        description: "Invitations for new agents",
+        subject: "You have been invited to {{product_name}}",
+        variables: &[
+            (
+                "invitation_link",
+                "https://support.example.com/accept-invitation?token=sample-token",
+            ),
+            ("expires_in", "7 days"),
+        ],
+    },
+];
+
+impl EmailTemplateDefinition {
+    pub fn find(id: &str) -> Option<&'static EmailTemplateDefinition> {
+        EMAIL_TEMPLATES.iter().find(|template| template.id == id)
+    }
+
+    /// Sample values overridden by `variables`; unknown names are ignored
+    pub fn resolve_variables(
+        &self,
+        variables: &HashMap<String, String>,
+    ) -> HashMap<String, String> {
+        self.variables
+            .iter()
+            .map(|(name, sample)| {
+                let value = variables
+                    .get(*name)
+                    .cloned()
+                    .unwrap_or_else(|| sample.to_string());
+                (name.to_string(), value)
+            })
+            .collect()
+    }
+}
+
+/// Replace each `{{name}}` placeholder in `body` with its value
+pub fn render_template(body: &str, variables: &HashMap<String, String>) -> String {
+    variables
+        .iter()
+        .fold(body.to_string(), |rendered, (name, value)| {
+            rendered.replace(&format!("{{{{{}}}}}", name), value)
+        })
+}
+
+/// Placeholders left in rendered output, e.g. typos in the template
+pub fn unresolved_placeholders(rendered: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = rendered;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        let name = rest[start + 2..start + 2 + end].trim().to_string();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+        rest = &rest[start + 2 + end + 2..];
+    }
+    names
+}
+
+/// Catalog entry returned by GET /api/templates
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailTemplateSummary {
+    pub id: String,
+    pub description: String,
+    pub subject: String,
+    /// Placeholder names with their sample values
+    pub variables: HashMap<String, String>,
+}
+
+impl From<&EmailTemplateDefinition> for EmailTemplateSummary {
+    fn from(template: &EmailTemplateDefinition) -> Self {
+        Self {
+            id: template.id.to_string(),
+            description: template.description.to_string(),
+            subject: template.subject.to_string(),
+            variables: template.resolve_variables(&HashMap::new()),
+        }
+    }
+}
+
+/// Request body for POST /api/templates/:id/preview
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PreviewEmailTemplateRequest {
+    /// Values for placeholders; the rest use sample values
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+}
+
+/// A template rendered with sample or given variables
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailTemplatePreview {
+    pub id: String,
+    pub subject: String,
+    pub html: String,
+    pub variables: HashMap<String, String>,
+    /// Placeholders the template uses that have no value
+    pub unresolved: Vec<String>,
+}
+
+/// Request body for POST /api/templates/:id/test-send
+#[derive(Debug, Clone, Deserialize)]
+pub struct TestSendEmailTemplateRequest {
+    /// Inbox whose mail server sends the test
+    pub inbox_id: String,
+    pub to: String,
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+}
+
+impl Validate for TestSendEmailTemplateRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("inbox_id", &self.inbox_id, 1, 255);
+        errors.email("to", &self.to);
+    }
+}
+
+/// Result of a test send
+#[derive(Debug, Clone, Serialize)]
+pub struct TestSendEmailTemplateResponse {
+    pub id: String,
+    pub inbox_id: String,
+    pub to: String,
+    pub subject: String,
+}
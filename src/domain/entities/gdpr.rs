@@ -0,0 +1,594 @@
+use chrono::{DateTime, Duration, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// How long an erasure confirmation token stays valid
+pub const GDPR_ERASURE_CONFIRMATION_TTL_MINUTES: i64 = 15;
+
+/// Text that replaces erased content
+pub const REDACTED_TEXT: &str = "[redacted]";
+
+/// Version of the export document layout, bumped on incompatible changes
+pub const CONTACT_EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Erased contacts keep a placeholder address on this never-resolving domain
+const ERASED_EMAIL_DOMAIN: &str = "erased.invalid";
+
+/// Identifiers shorter than this are left alone when masking, to avoid
+/// redacting unrelated words
+const MIN_MASKED_IDENTIFIER_LENGTH: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GdprRequestType {
+    Export,
+    Erasure,
+}
+
+impl std::fmt::Display for GdprRequestType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GdprRequestType::Export => write!(f, "export"),
+            GdprRequestType::Erasure => write!(f, "erasure"),
+        }
+    }
+}
+
+impl std::str::FromStr for GdprRequestType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "export" => Ok(GdprRequestType::Export),
+            "erasure" => Ok(GdprRequestType::Erasure),
+            _ => Err(format!("Invalid GDPR request type: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GdprRequestStatus {
+    /// An erasure waiting for its confirmation token
+    Pending,
+    Completed,
+}
+
+impl std::fmt::Display for GdprRequestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GdprRequestStatus::Pending => write!(f, "pending"),
+            GdprRequestStatus::Completed => write!(f, "completed"),
+        }
+    }
+}
+
+impl std::str::FromStr for GdprRequestStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(GdprRequestStatus::Pending),
+            "completed" => Ok(GdprRequestStatus::Completed),
+            _ => Err(format!("Invalid GDPR request status: {}", s)),
+        }
+    }
+}
+
+/// What an erasure does to the content of the contact's conversations
+///
+/// The contact's profile, channels, channel identities and notes are erased
+/// in every mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageRedaction {
+    /// Replace the contact's messages, subjects and CSAT comments entirely,
+    /// remove their attachments, and mask the contact's identifiers in replies
+    #[default]
+    Full,
+    /// Mask the contact's name, addresses and channel identifiers wherever
+    /// they appear, keeping the rest of the text
+    Identifiers,
+    /// Leave conversation content untouched, e.g. under a legal hold
+    Keep,
+}
+
+impl std::fmt::Display for MessageRedaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessageRedaction::Full => write!(f, "full"),
+            MessageRedaction::Identifiers => write!(f, "identifiers"),
+            MessageRedaction::Keep => write!(f, "keep"),
+        }
+    }
+}
+
+impl std::str::FromStr for MessageRedaction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(MessageRedaction::Full),
+            "identifiers" => Ok(MessageRedaction::Identifiers),
+            "keep" => Ok(MessageRedaction::Keep),
+            _ => Err(format!("Invalid message redaction: {}", s)),
+        }
+    }
+}
+
+/// Audit record of an export or erasure of a contact's data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GdprRequest {
+    pub id: String,
+    /// The contact's user id
+    pub contact_id: String,
+    pub request_type: GdprRequestType,
+    pub status: GdprRequestStatus,
+    pub message_redaction: Option<MessageRedaction>,
+    #[serde(skip)]
+    pub confirmation_token_hash: Option<String>,
+    pub requested_by: String,
+    pub confirmed_by: Option<String>,
+    /// What was exported or erased, as counts
+    pub summary: Option<ContactDataSummary>,
+    pub created_at: String,
+    /// When a pending erasure's confirmation token stops working
+    pub expires_at: Option<String>,
+    pub completed_at: Option<String>,
+}
+
+impl GdprRequest {
+    /// A completed export
+    pub fn new_export(
+        contact_id: String,
+        requested_by: String,
+        summary: ContactDataSummary,
+    ) -> Self {
+        let now = Utc::now().to_rfc3339();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            contact_id,
+            request_type: GdprRequestType::Export,
+            status: GdprRequestStatus::Completed,
+            message_redaction: None,
+            confirmation_token_hash: None,
+            requested_by,
+            confirmed_by: None,
+            summary: Some(summary),
+            created_at: now.clone(),
+            expires_at: None,
+            completed_at: Some(now),
+        }
+    }
+
+    /// An erasure awaiting confirmation with `token`
+    pub fn new_erasure(
+        contact_id: String,
+        requested_by: String,
+        message_redaction: MessageRedaction,
+        summary: ContactDataSummary,
+        token: &str,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            contact_id,
+            request_type: GdprRequestType::Erasure,
+            status: GdprRequestStatus::Pending,
+            message_redaction: Some(message_redaction),
+            confirmation_token_hash: Some(Self::hash_token(token)),
+            requested_by,
+            confirmed_by: None,
+            summary: Some(summary),
+            created_at: now.to_rfc3339(),
+            expires_at: Some(
+                (now + Duration::minutes(GDPR_ERASURE_CONFIRMATION_TTL_MINUTES)).to_rfc3339(),
+            ),
+            completed_at: None,
+        }
+    }
+
+    /// Confirmation tokens are stored as SHA-256 hashes
+    pub fn hash_token(token: &str) -> String {
+        hex::encode(Sha256::digest(token.as_bytes()))
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .as_deref()
+            .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+            .is_some_and(|t| t < Utc::now())
+    }
+}
+
+/// Placeholder address given to an erased contact
+pub fn erased_contact_email(user_id: &str) -> String {
+    format!("erased-{}@{}", user_id, ERASED_EMAIL_DOMAIN)
+}
+
+// ========== Export ==========
+
+/// Machine-readable copy of everything stored about a contact
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContactDataExport {
+    pub format_version: u32,
+    pub exported_at: String,
+    pub contact: ContactProfileExport,
+    pub channels: Vec<ContactChannelExport>,
+    pub channel_identities: Vec<ChannelIdentityExport>,
+    pub conversations: Vec<ConversationExport>,
+    /// Notes agents keep about the contact
+    pub notes: Vec<ContactNoteExport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContactProfileExport {
+    pub user_id: String,
+    pub contact_id: String,
+    pub email: String,
+    pub first_name: Option<String>,
+    pub tier: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContactChannelExport {
+    pub id: String,
+    pub inbox_id: String,
+    pub email: String,
+    pub email_verified: bool,
+    pub created_at: String,
+}
+
+/// The contact's identifier on an external channel, e.g. a phone number
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelIdentityExport {
+    pub inbox_id: String,
+    pub external_id: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationExport {
+    pub id: String,
+    pub reference_number: i64,
+    pub inbox_id: String,
+    pub subject: Option<String>,
+    pub status: String,
+    pub created_at: String,
+    pub messages: Vec<MessageExport>,
+    pub csat_responses: Vec<CsatResponseExport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageExport {
+    pub id: String,
+    /// "incoming" for the contact's messages, "outgoing" for replies
+    pub direction: String,
+    pub content: String,
+    pub created_at: String,
+    pub attachments: Vec<AttachmentExport>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentExport {
+    pub id: String,
+    pub filename: String,
+    pub content_type: Option<String>,
+    pub file_size: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CsatResponseExport {
+    pub id: String,
+    pub score: i64,
+    pub comment: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContactNoteExport {
+    pub id: String,
+    pub content: String,
+    pub created_at: String,
+}
+
+/// Counts of a contact's exported or erased data
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContactDataSummary {
+    pub conversations: usize,
+    pub messages: usize,
+    pub attachments: usize,
+    pub notes: usize,
+    pub channel_identities: usize,
+}
+
+impl ContactDataExport {
+    pub fn summary(&self) -> ContactDataSummary {
+        let messages = self.conversations.iter().flat_map(|c| &c.messages);
+        ContactDataSummary {
+            conversations: self.conversations.len(),
+            messages: messages.clone().count(),
+            attachments: messages.map(|m| m.attachments.len()).sum(),
+            notes: self.notes.len(),
+            channel_identities: self.channel_identities.len(),
+        }
+    }
+
+    /// Name, addresses and channel identifiers the contact can be recognised by
+    fn identifiers(&self) -> Vec<String> {
+        let mut identifiers: Vec<String> = std::iter::once(self.contact.email.clone())
+            .chain(self.contact.first_name.clone())
+            .chain(self.channels.iter().map(|c| c.email.clone()))
+            .chain(
+                self.channel_identities
+                    .iter()
+                    .map(|i| i.external_id.clone()),
+            )
+            .map(|identifier| identifier.trim().to_string())
+            .filter(|identifier| identifier.chars().count() >= MIN_MASKED_IDENTIFIER_LENGTH)
+            .collect();
+        // Longest first, so an address is masked whole before the name inside it
+        identifiers.sort_by_key(|identifier| std::cmp::Reverse(identifier.len()));
+        identifiers.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        identifiers
+    }
+}
+
+// ========== Erasure ==========
+
+/// Changes an erasure makes, worked out from the contact's export
+#[derive(Debug, Clone)]
+pub struct ContactErasurePlan {
+    pub user_id: String,
+    pub contact_id: String,
+    pub anonymized_email: String,
+    /// Message id and its new content
+    pub message_contents: Vec<(String, String)>,
+    /// Messages whose attachments are removed
+    pub attachment_message_ids: Vec<String>,
+    /// Conversation id and its new subject
+    pub subjects: Vec<(String, Option<String>)>,
+    /// CSAT response id and its new comment
+    pub csat_comments: Vec<(String, Option<String>)>,
+}
+
+impl ContactErasurePlan {
+    pub fn new(export: &ContactDataExport, redaction: MessageRedaction) -> Self {
+        let mut plan = Self {
+            user_id: export.contact.user_id.clone(),
+            contact_id: export.contact.contact_id.clone(),
+            anonymized_email: erased_contact_email(&export.contact.user_id),
+            message_contents: Vec::new(),
+            attachment_message_ids: Vec::new(),
+            subjects: Vec::new(),
+            csat_comments: Vec::new(),
+        };
+        if redaction == MessageRedaction::Keep {
+            return plan;
+        }
+
+        let mask = IdentifierMask::new(&export.identifiers());
+        let full = redaction == MessageRedaction::Full;
+        for conversation in &export.conversations {
+            if let Some(subject) = &conversation.subject {
+                let redacted = if full {
+                    REDACTED_TEXT.to_string()
+                } else {
+                    mask.apply(subject)
+                };
+                if &redacted != subject {
+                    plan.subjects
+                        .push((conversation.id.clone(), Some(redacted)));
+                }
+            }
+
+            for message in &conversation.messages {
+                let from_contact = message.direction == "incoming";
+                let redacted = if full && from_contact {
+                    REDACTED_TEXT.to_string()
+                } else {
+                    mask.apply(&message.content)
+                };
+                if redacted != message.content {
+                    plan.message_contents.push((message.id.clone(), redacted));
+                }
+                if full && from_contact && !message.attachments.is_empty() {
+                    plan.attachment_message_ids.push(message.id.clone());
+                }
+            }
+
+            for response in &conversation.csat_responses {
+                let Some(comment) = &response.comment else {
+                    continue;
+                };
+                let redacted = if full {
+                    None
+                } else {
+                    Some(mask.apply(comment))
+                };
+                if redacted.as_ref() != Some(comment) {
+                    plan.csat_comments.push((response.id.clone(), redacted));
+                }
+            }
+        }
+        plan
+    }
+}
+
+/// Case-insensitive matcher for a contact's identifiers, respecting word
+/// boundaries so a name isn't masked inside longer words
+struct IdentifierMask(Option<Regex>);
+
+impl IdentifierMask {
+    fn new(identifiers: &[String]) -> Self {
+        if identifiers.is_empty() {
+            return Self(None);
+        }
+        let is_word = |c: char| c.is_alphanumeric() || c == '_';
+        let alternatives: Vec<String> = identifiers
+            .iter()
+            .map(|identifier| {
+                let start = if identifier.starts_with(is_word) {
+                    r"\b"
+                } else {
+                    ""
+                };
+                let end = if identifier.ends_with(is_word) {
+                    r"\b"
+                } else {
+                    ""
+                };
+                format!("{}{}{}", start, regex::escape(identifier), end)
+            })
+            .collect();
+        Self(Regex::new(&format!("(?i){}", alternatives.join("|"))).ok())
+    }
+
+    fn apply(&self, text: &str) -> String {
+        match &self.0 {
+            Some(regex) => regex.replace_all(text, REDACTED_TEXT).into_owned(),
+            None => text.to_string(),
+        }
+    }
+}
+
+// ========== DTOs ==========
+
+/// Request to erase a contact's data
+///
+/// Without a token this only starts the erasure and returns a confirmation
+/// token; sending that token back carries it out.
+#[derive(Debug, Default, Deserialize)]
+pub struct EraseContactRequest {
+    /// How conversation content is redacted; defaults to `full`
+    pub message_redaction: Option<MessageRedaction>,
+    pub confirmation_token: Option<String>,
+}
+
+impl Validate for EraseContactRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if self.confirmation_token.is_some() && self.message_redaction.is_some() {
+            errors.add(
+                "message_redaction",
+                "is chosen when the erasure is requested, not when it is confirmed",
+            );
+        }
+        errors.optional_length(
+            "confirmation_token",
+            self.confirmation_token.as_deref(),
+            1,
+            255,
+        );
+    }
+}
+
+/// A requested erasure that has not been carried out yet
+#[derive(Debug, Clone, Serialize)]
+pub struct GdprErasureConfirmation {
+    pub request_id: String,
+    /// Send back as `confirmation_token` to erase; shown only once
+    pub confirmation_token: String,
+    pub expires_at: String,
+    pub message_redaction: MessageRedaction,
+    /// What will be affected
+    pub summary: ContactDataSummary,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export() -> ContactDataExport {
+        let message = |id: &str, direction: &str, content: &str| MessageExport {
+            id: id.to_string(),
+            direction: direction.to_string(),
+            content: content.to_string(),
+            created_at: Utc::now().to_rfc3339(),
+            attachments: Vec::new(),
+        };
+        ContactDataExport {
+            format_version: CONTACT_EXPORT_FORMAT_VERSION,
+            exported_at: Utc::now().to_rfc3339(),
+            contact: ContactProfileExport {
+                user_id: "user-1".to_string(),
+                contact_id: "contact-1".to_string(),
+                email: "ann.lee@example.com".to_string(),
+                first_name: Some("Ann".to_string()),
+                tier: None,
+                created_at: Utc::now().to_rfc3339(),
+                updated_at: Utc::now().to_rfc3339(),
+            },
+            channels: Vec::new(),
+            channel_identities: vec![ChannelIdentityExport {
+                inbox_id: "inbox-1".to_string(),
+                external_id: "+15551234567".to_string(),
+                created_at: Utc::now().to_rfc3339(),
+            }],
+            conversations: vec![ConversationExport {
+                id: "conv-1".to_string(),
+                reference_number: 1,
+                inbox_id: "inbox-1".to_string(),
+                subject: Some("Refund for Ann".to_string()),
+                status: "open".to_string(),
+                created_at: Utc::now().to_rfc3339(),
+                messages: vec![
+                    message("msg-1", "incoming", "I'm Ann, call +15551234567"),
+                    message(
+                        "msg-2",
+                        "outgoing",
+                        "Hi ANN, we mailed ann.lee@example.com. Annual plan?",
+                    ),
+                    message("msg-3", "outgoing", "Thanks for waiting"),
+                ],
+                csat_responses: Vec::new(),
+            }],
+            notes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_identifiers_mode_masks_only_identifiers() {
+        let plan = ContactErasurePlan::new(&export(), MessageRedaction::Identifiers);
+        assert_eq!(
+            plan.message_contents,
+            vec![
+                (
+                    "msg-1".to_string(),
+                    "I'm [redacted], call [redacted]".to_string()
+                ),
+                (
+                    "msg-2".to_string(),
+                    "Hi [redacted], we mailed [redacted]. Annual plan?".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            plan.subjects,
+            vec![(
+                "conv-1".to_string(),
+                Some("Refund for [redacted]".to_string())
+            )]
+        );
+        assert_eq!(plan.anonymized_email, "erased-user-1@erased.invalid");
+    }
+
+    #[test]
+    fn test_full_mode_replaces_contact_messages() {
+        let plan = ContactErasurePlan::new(&export(), MessageRedaction::Full);
+        assert_eq!(
+            plan.message_contents[0],
+            ("msg-1".to_string(), REDACTED_TEXT.to_string())
+        );
+        assert_eq!(plan.message_contents.len(), 2);
+        assert_eq!(plan.subjects[0].1.as_deref(), Some(REDACTED_TEXT));
+
+        let plan = ContactErasurePlan::new(&export(), MessageRedaction::Keep);
+        assert!(plan.message_contents.is_empty() && plan.subjects.is_empty());
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::shared::validation::{Validate, ValidationErrors};
+
 /// Holiday calendar entry for SLA business hours calculation (Feature 029)
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Holiday {
@@ -7,6 +9,7 @@ pub struct Holiday {
     pub name: String,
     pub date: String, // Date in YYYY-MM-DD format
     pub recurring: bool, // If true, repeats annually on same month-day
+    pub calendar_id: Option<String>, // None = global holiday applying to every team
     pub created_at: String,
     pub updated_at: String,
 }
@@ -19,6 +22,30 @@ impl Holiday {
             name,
             date,
             recurring,
+            calendar_id: None,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+}
+
+/// Named set of holidays that can be linked to teams (e.g. "UK public holidays")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolidayCalendar {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl HolidayCalendar {
+    pub fn new(name: String, description: Option<String>) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name,
+            description,
             created_at: now.clone(),
             updated_at: now,
         }
@@ -33,6 +60,13 @@ pub struct CreateHolidayRequest {
     pub recurring: bool,
 }
 
+impl Validate for CreateHolidayRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 255);
+        validate_holiday_date(errors, &self.date);
+    }
+}
+
 /// DTO for updating a holiday
 #[derive(Debug, Deserialize)]
 pub struct UpdateHolidayRequest {
@@ -41,9 +75,123 @@ pub struct UpdateHolidayRequest {
     pub recurring: Option<bool>,
 }
 
+impl Validate for UpdateHolidayRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("name", self.name.as_deref(), 1, 255);
+        if let Some(date) = &self.date {
+            validate_holiday_date(errors, date);
+        }
+    }
+}
+
+fn validate_holiday_date(errors: &mut ValidationErrors, date: &str) {
+    if chrono::NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").is_err() {
+        errors.add("date", "must be a date in YYYY-MM-DD form");
+    }
+}
+
 /// DTO for holiday list response
 #[derive(Debug, Serialize)]
 pub struct HolidayListResponse {
     pub holidays: Vec<Holiday>,
     pub count: i64,
 }
+
+/// DTO for creating a holiday calendar
+#[derive(Debug, Deserialize)]
+pub struct CreateHolidayCalendarRequest {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl Validate for CreateHolidayCalendarRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 255);
+        errors.max_length("description", self.description.as_deref(), 1000);
+    }
+}
+
+/// DTO for updating a holiday calendar
+#[derive(Debug, Deserialize)]
+pub struct UpdateHolidayCalendarRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+impl Validate for UpdateHolidayCalendarRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("name", self.name.as_deref(), 1, 255);
+        errors.max_length("description", self.description.as_deref(), 1000);
+    }
+}
+
+/// DTO for holiday calendar list response
+#[derive(Debug, Serialize)]
+pub struct HolidayCalendarListResponse {
+    pub calendars: Vec<HolidayCalendar>,
+    pub count: i64,
+}
+
+/// DTO for importing holidays from an iCalendar (.ics) document
+#[derive(Debug, Deserialize)]
+pub struct ImportHolidaysRequest {
+    pub ics: String,
+    /// Remove the calendar's existing holidays before importing
+    #[serde(default)]
+    pub replace: bool,
+}
+
+impl Validate for ImportHolidaysRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if self.ics.trim().is_empty() {
+            errors.add("ics", "is required");
+        }
+    }
+}
+
+/// DTO for iCalendar import result
+#[derive(Debug, Serialize)]
+pub struct ImportHolidaysResponse {
+    pub imported: usize,
+    pub skipped: usize,
+}
+
+/// DTO for linking a team to a holiday calendar (None unlinks)
+#[derive(Debug, Deserialize)]
+pub struct SetTeamHolidayCalendarRequest {
+    pub holiday_calendar_id: Option<String>,
+}
+
+impl Validate for SetTeamHolidayCalendarRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length(
+            "holiday_calendar_id",
+            self.holiday_calendar_id.as_deref(),
+            1,
+            255,
+        );
+    }
+}
+
+/// Query for a team's working days between two dates (inclusive, YYYY-MM-DD)
+#[derive(Debug, Deserialize)]
+pub struct WorkingDaysQuery {
+    pub from: String,
+    pub to: String,
+}
+
+/// A single day in a team's working-day calendar
+#[derive(Debug, Serialize)]
+pub struct TeamWorkingDay {
+    pub date: String,
+    pub is_working_day: bool,
+    pub holiday: Option<String>,
+}
+
+/// DTO for a team's working days over a date range
+#[derive(Debug, Serialize)]
+pub struct TeamWorkingDaysResponse {
+    pub team_id: String,
+    pub holiday_calendar_id: Option<String>,
+    pub days: Vec<TeamWorkingDay>,
+}
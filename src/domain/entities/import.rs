@@ -0,0 +1,280 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+use crate::domain::entities::{ConversationStatus, Message, Priority};
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Largest export accepted in one import, in bytes
+pub const IMPORT_MAX_DATA_BYTES: usize = 50 * 1024 * 1024;
+
+/// Helpdesk an export comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportSource {
+    Zendesk,
+    Freshdesk,
+}
+
+impl ImportSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportSource::Zendesk => "zendesk",
+            ImportSource::Freshdesk => "freshdesk",
+        }
+    }
+}
+
+impl fmt::Display for ImportSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<String> for ImportSource {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "freshdesk" => ImportSource::Freshdesk,
+            _ => ImportSource::Zendesk,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    Json,
+    Csv,
+}
+
+impl ImportFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportFormat::Json => "json",
+            ImportFormat::Csv => "csv",
+        }
+    }
+}
+
+impl fmt::Display for ImportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<String> for ImportFormat {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "csv" => ImportFormat::Csv,
+            _ => ImportFormat::Json,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl ImportStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportStatus::Pending => "pending",
+            ImportStatus::Running => "running",
+            ImportStatus::Completed => "completed",
+            ImportStatus::Failed => "failed",
+        }
+    }
+}
+
+impl fmt::Display for ImportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<String> for ImportStatus {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "running" => ImportStatus::Running,
+            "completed" => ImportStatus::Completed,
+            "failed" => ImportStatus::Failed,
+            _ => ImportStatus::Pending,
+        }
+    }
+}
+
+/// Kind of record tracked in `import_records` so re-runs skip what already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportedEntity {
+    Conversation,
+    Message,
+    Attachment,
+}
+
+impl ImportedEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportedEntity::Conversation => "conversation",
+            ImportedEntity::Message => "message",
+            ImportedEntity::Attachment => "attachment",
+        }
+    }
+}
+
+/// Counts of what an import run created or skipped
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportCounts {
+    pub contacts_created: i64,
+    pub conversations_created: i64,
+    pub messages_created: i64,
+    pub tags_created: i64,
+    pub attachments_created: i64,
+    /// Tickets already imported by an earlier run
+    pub tickets_skipped: i64,
+    /// Tickets that could not be imported; see `errors`
+    pub tickets_failed: i64,
+}
+
+/// An import of another helpdesk's export into an inbox
+///
+/// The export itself is kept with the import until it has run, and is never
+/// returned through the API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Import {
+    pub id: String,
+    pub source: ImportSource,
+    pub format: ImportFormat,
+    pub inbox_id: String,
+    pub status: ImportStatus,
+    #[serde(skip)]
+    pub data: String,
+    pub total_records: i64,
+    pub processed_records: i64,
+    #[serde(flatten)]
+    pub counts: ImportCounts,
+    /// Per-ticket problems, e.g. a ticket without a requester email
+    pub errors: Vec<String>,
+    pub created_by: Option<String>,
+    pub created_at: String,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub updated_at: String,
+}
+
+impl Import {
+    pub fn new(
+        source: ImportSource,
+        format: ImportFormat,
+        inbox_id: String,
+        data: String,
+        created_by: String,
+    ) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            source,
+            format,
+            inbox_id,
+            status: ImportStatus::Pending,
+            data,
+            total_records: 0,
+            processed_records: 0,
+            counts: ImportCounts::default(),
+            errors: Vec::new(),
+            created_by: Some(created_by),
+            created_at: now.clone(),
+            started_at: None,
+            completed_at: None,
+            updated_at: now,
+        }
+    }
+
+    /// Share of records processed, from 0 to 100
+    pub fn progress_percent(&self) -> i64 {
+        if self.total_records == 0 {
+            return if self.status == ImportStatus::Completed {
+                100
+            } else {
+                0
+            };
+        }
+        self.processed_records * 100 / self.total_records
+    }
+}
+
+/// An imported ticket ready to be stored as a conversation
+///
+/// Written in one go, with its original timestamps, so an interrupted run
+/// never leaves a half-imported ticket behind.
+#[derive(Debug, Clone)]
+pub struct ImportedConversation {
+    pub import_id: String,
+    pub source: ImportSource,
+    /// Ticket id in the source helpdesk
+    pub external_id: String,
+    pub id: String,
+    pub inbox_id: String,
+    pub contact_id: String,
+    pub subject: Option<String>,
+    pub status: ConversationStatus,
+    pub priority: Option<Priority>,
+    pub created_at: String,
+    pub updated_at: String,
+    /// Messages with their id in the source helpdesk, oldest first
+    pub messages: Vec<(String, Message)>,
+    pub tag_ids: Vec<String>,
+    /// User recorded as having added the tags
+    pub tagged_by: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateImportRequest {
+    pub source: ImportSource,
+    pub format: ImportFormat,
+    pub inbox_id: String,
+    /// Content of the export file (JSON, newline-delimited JSON or CSV)
+    pub data: String,
+}
+
+impl Validate for CreateImportRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("inbox_id", &self.inbox_id, 1, 255);
+        if self.data.trim().is_empty() {
+            errors.add("data", "is required");
+        } else if self.data.len() > IMPORT_MAX_DATA_BYTES {
+            errors.add(
+                "data",
+                format!(
+                    "must be at most {} MB",
+                    IMPORT_MAX_DATA_BYTES / (1024 * 1024)
+                ),
+            );
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportResponse {
+    #[serde(flatten)]
+    pub import: Import,
+    pub progress_percent: i64,
+}
+
+impl From<Import> for ImportResponse {
+    fn from(import: Import) -> Self {
+        Self {
+            progress_percent: import.progress_percent(),
+            import,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportListResponse {
+    pub imports: Vec<ImportResponse>,
+}
@@ -2,13 +2,22 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// Failed attempts kept on a job row; older entries are dropped
+pub const MAX_RECORDED_JOB_FAILURES: usize = 20;
+
+/// Delay before the first retry; doubles with each further attempt
+const BASE_RETRY_DELAY_SECS: i64 = 30;
+/// Longest delay between retries
+const MAX_RETRY_DELAY_SECS: i64 = 60 * 60;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum JobStatus {
     Pending,
     Processing,
     Completed,
-    Failed,
+    /// Used up its attempts; waits for an admin to requeue it
+    Dead,
 }
 
 impl ToString for JobStatus {
@@ -17,7 +26,7 @@ impl ToString for JobStatus {
             JobStatus::Pending => "pending".to_string(),
             JobStatus::Processing => "processing".to_string(),
             JobStatus::Completed => "completed".to_string(),
-            JobStatus::Failed => "failed".to_string(),
+            JobStatus::Dead => "dead".to_string(),
         }
     }
 }
@@ -28,12 +37,34 @@ impl From<String> for JobStatus {
             "pending" => JobStatus::Pending,
             "processing" => JobStatus::Processing,
             "completed" => JobStatus::Completed,
-            "failed" => JobStatus::Failed,
+            // 'failed' was the terminal state before dead jobs were tracked
+            "dead" | "failed" => JobStatus::Dead,
             _ => JobStatus::Pending, // Default fallback
         }
     }
 }
 
+/// Why a job attempt failed
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum JobFailureKind {
+    /// The handler returned an error
+    Error,
+    /// The handler panicked
+    Panic,
+    /// The worker stopped renewing its lock (crashed or was killed)
+    LockExpired,
+}
+
+/// One failed attempt, stored on the job row
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JobFailure {
+    pub attempt: i32,
+    pub kind: JobFailureKind,
+    pub message: String,
+    pub failed_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Job {
     pub id: String,
@@ -46,4 +77,30 @@ pub struct Job {
     pub attempts: i32,
     pub max_attempts: i32,
     pub last_error: Option<String>,
+    /// Most recent failed attempts, oldest first
+    pub failures: Vec<JobFailure>,
+    pub dead_at: Option<DateTime<Utc>>,
+}
+
+impl Job {
+    /// Delay before retrying after the given number of failed attempts
+    /// (30s, 1m, 2m, ... capped at an hour)
+    pub fn retry_delay(failed_attempts: i32) -> chrono::Duration {
+        let exponent = failed_attempts.saturating_sub(1).clamp(0, 20) as u32;
+        let seconds = BASE_RETRY_DELAY_SECS.saturating_mul(1 << exponent);
+        chrono::Duration::seconds(seconds.min(MAX_RETRY_DELAY_SECS))
+    }
+}
+
+/// Query parameters for GET /api/admin/jobs/dead
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeadJobsQuery {
+    #[serde(default = "default_dead_jobs_limit")]
+    pub limit: i64,
+    #[serde(default)]
+    pub offset: i64,
+}
+
+fn default_dead_jobs_limit() -> i64 {
+    50
 }
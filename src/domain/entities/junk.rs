@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+
+use super::Conversation;
+
+/// Request to mark a conversation as junk
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MarkJunkRequest {
+    /// The agent is sure this is spam: also block the sender on the inbox
+    #[serde(default)]
+    pub confirm: bool,
+}
+
+/// Outcome of marking or unmarking a conversation as junk
+#[derive(Debug, Clone, Serialize)]
+pub struct JunkResponse {
+    pub conversation: Conversation,
+    pub sender_email: Option<String>,
+    /// Conversations from this sender currently marked as junk
+    pub junk_count: i64,
+    /// Whether the sender is on the inbox blocklist
+    pub blocked: bool,
+}
+
+/// A sender whose emails are dropped by an inbox
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockedSender {
+    pub id: String,
+    pub inbox_id: String,
+    pub email: String,
+    pub blocked_by: Option<String>,
+    /// Conversation whose confirmed junk marking blocked the sender
+    pub conversation_id: Option<String>,
+    pub created_at: String,
+}
+
+impl BlockedSender {
+    pub fn new(
+        inbox_id: String,
+        email: &str,
+        blocked_by: Option<String>,
+        conversation_id: Option<String>,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            inbox_id,
+            email: normalize_sender_email(email),
+            blocked_by,
+            conversation_id,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Sender addresses are compared case-insensitively
+pub fn normalize_sender_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
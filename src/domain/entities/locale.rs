@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::shared::i18n;
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// A user's language setting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocaleSettings {
+    /// Locale the user chose; None follows the browser's Accept-Language
+    pub locale: Option<String>,
+    /// Locale the current request is answered in
+    pub effective_locale: String,
+    pub available_locales: Vec<String>,
+}
+
+/// Request to choose (or, with null, clear) the caller's locale
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateLocaleRequest {
+    pub locale: Option<String>,
+}
+
+impl Validate for UpdateLocaleRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if let Some(locale) = &self.locale {
+            let catalog = i18n::catalog();
+            if catalog.resolve(locale).is_none() {
+                errors.add(
+                    "locale",
+                    format!(
+                        "must be one of the available locales: {}",
+                        catalog.locales().join(", ")
+                    ),
+                );
+            }
+        }
+    }
+}
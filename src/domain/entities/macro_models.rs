@@ -43,6 +43,15 @@ impl Macro {
     }
 }
 
+/// Action types a macro can run
+pub const MACRO_ACTION_TYPES: &[&str] = &[
+    "set_status",
+    "assign_to_user",
+    "assign_to_team",
+    "add_tag",
+    "set_priority",
+];
+
 /// Action to execute when macro is applied
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MacroAction {
@@ -57,19 +66,11 @@ impl MacroAction {
     /// Validate action type and value
     pub fn validate(&self) -> Result<(), String> {
         // Validate action type
-        let valid_types = vec![
-            "set_status",
-            "assign_to_user",
-            "assign_to_team",
-            "add_tag",
-            "set_priority",
-        ];
-
-        if !valid_types.contains(&self.action_type.as_str()) {
+        if !MACRO_ACTION_TYPES.contains(&self.action_type.as_str()) {
             return Err(format!(
                 "Invalid action type '{}'. Must be one of: {}",
                 self.action_type,
-                valid_types.join(", ")
+                MACRO_ACTION_TYPES.join(", ")
             ));
         }
 
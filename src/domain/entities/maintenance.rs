@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// `system_config` key holding the maintenance state as JSON
+pub const MAINTENANCE_MODE_KEY: &str = "maintenance.mode";
+
+/// Shown when maintenance is enabled without a message
+pub const DEFAULT_MAINTENANCE_MESSAGE: &str =
+    "Oxidesk is undergoing scheduled maintenance. Please try again shortly.";
+
+pub const MAINTENANCE_MESSAGE_MAX_LENGTH: usize = 500;
+
+/// Persisted maintenance switch
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaintenanceState {
+    pub enabled: bool,
+    pub message: Option<String>,
+    pub changed_by: Option<String>,
+    pub changed_at: Option<String>,
+}
+
+impl MaintenanceState {
+    /// Message to show users, falling back to the default
+    pub fn display_message(&self) -> &str {
+        self.message
+            .as_deref()
+            .unwrap_or(DEFAULT_MAINTENANCE_MESSAGE)
+    }
+}
+
+/// DTO: Turn maintenance mode on or off
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateMaintenanceModeRequest {
+    pub enabled: bool,
+    /// Replaces the default message shown to users
+    pub message: Option<String>,
+}
+
+impl Validate for UpdateMaintenanceModeRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length(
+            "message",
+            self.message.as_deref(),
+            1,
+            MAINTENANCE_MESSAGE_MAX_LENGTH,
+        );
+    }
+}
+
+/// Background worker state while maintenance is on
+#[derive(Debug, Clone, Serialize)]
+pub struct MaintenanceWorkerStatus {
+    /// Workers stop picking up new jobs while maintenance is on
+    pub paused: bool,
+    /// Jobs started before maintenance that are still running
+    pub active_jobs: usize,
+    /// True once paused workers have no running jobs left
+    pub drained: bool,
+}
+
+/// DTO: Maintenance switch and worker state
+#[derive(Debug, Clone, Serialize)]
+pub struct MaintenanceStatusResponse {
+    pub enabled: bool,
+    pub message: Option<String>,
+    pub changed_by: Option<String>,
+    pub changed_at: Option<String>,
+    pub workers: MaintenanceWorkerStatus,
+}
+
+/// DTO: Body of `/readyz`
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadinessResponse {
+    /// "ready", "maintenance" or "unavailable"
+    pub status: String,
+    pub database: bool,
+    pub maintenance: bool,
+    pub workers: MaintenanceWorkerStatus,
+}
+
+impl ReadinessResponse {
+    pub fn is_ready(&self) -> bool {
+        self.status == "ready"
+    }
+}
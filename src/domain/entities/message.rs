@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::shared::validation::{Validate, ValidationErrors};
+
 /// Message type indicating direction of communication
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -93,6 +95,21 @@ pub struct Message {
     pub created_at: String,      // ISO 8601 timestamp
     pub sent_at: Option<String>, // ISO 8601 timestamp
     pub updated_at: String,      // ISO 8601 timestamp
+    /// Channel the message came in through (`chat`, `sms`, `telegram`, ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// Channel-specific details of the source (chat ID, phone number, ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_metadata: Option<serde_json::Value>,
+    /// Meeting invite sent with the email, parsed from its ICS payload
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calendar_invite: Option<crate::domain::entities::CalendarInvite>,
+    /// SPF/DKIM/DMARC results of an inbound email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_authentication: Option<crate::domain::entities::EmailAuthentication>,
+    /// Agent reactions, filled in when the message is read through the API
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<crate::domain::entities::ReactionSummary>,
 }
 
 impl Message {
@@ -114,6 +131,11 @@ impl Message {
             created_at: now.clone(),
             sent_at: None,
             updated_at: now,
+            channel: None,
+            channel_metadata: None,
+            calendar_invite: None,
+            email_authentication: None,
+            reactions: Vec::new(),
         }
     }
 
@@ -135,6 +157,11 @@ impl Message {
             created_at: now.clone(),
             sent_at: None,
             updated_at: now,
+            channel: None,
+            channel_metadata: None,
+            calendar_invite: None,
+            email_authentication: None,
+            reactions: Vec::new(),
         }
     }
 
@@ -169,6 +196,14 @@ pub struct SendMessageRequest {
     pub content: String,
 }
 
+impl Validate for SendMessageRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if let Err(e) = Message::validate_content(&self.content) {
+            errors.add("content", e);
+        }
+    }
+}
+
 /// Request to receive an incoming message (webhook)
 ///
 /// Feature 016: Supports automatic contact creation via from_header field.
@@ -189,6 +224,27 @@ pub struct IncomingMessageRequest {
     pub external_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub received_at: Option<String>,
+    /// Source channel recorded on the message (see `Message::channel`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_metadata: Option<serde_json::Value>,
+}
+
+impl Validate for IncomingMessageRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("conversation_id", &self.conversation_id, 1, 255);
+        errors.length("inbox_id", &self.inbox_id, 1, 255);
+        if let Err(e) = Message::validate_content(&self.content) {
+            errors.add("content", e);
+        }
+        let present =
+            |value: &Option<String>| value.as_deref().is_some_and(|v| !v.trim().is_empty());
+        if !present(&self.contact_id) && !present(&self.from_header) {
+            errors.add("contact_id", "either contact_id or from_header is required");
+        }
+        errors.max_length("external_id", self.external_id.as_deref(), 255);
+    }
 }
 
 /// Response containing message list with pagination
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Reactions agents can leave on a message, in display order
+pub const REACTION_EMOJIS: [&str; 3] = ["👍", "✅", "❓"];
+
+/// A single agent's reaction on a message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReaction {
+    pub id: String,
+    pub message_id: String,
+    pub user_id: String,
+    pub emoji: String,
+    pub created_at: String,
+}
+
+impl MessageReaction {
+    pub fn new(message_id: String, user_id: String, emoji: String) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            message_id,
+            user_id,
+            emoji,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Reactions of one kind on a message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReactionSummary {
+    pub emoji: String,
+    pub count: i64,
+    pub user_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddReactionRequest {
+    pub emoji: String,
+}
+
+impl Validate for AddReactionRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if normalize_reaction(&self.emoji).is_none() {
+            errors.add("emoji", "must be one of 👍, ✅, ❓");
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReactionListResponse {
+    pub message_id: String,
+    pub reactions: Vec<ReactionSummary>,
+}
+
+/// Map an emoji or its name ("thumbs_up", "check", "question") to a supported reaction
+pub fn normalize_reaction(value: &str) -> Option<&'static str> {
+    match value.trim() {
+        "👍" | "thumbs_up" | "+1" => Some("👍"),
+        "✅" | "check" | "white_check_mark" => Some("✅"),
+        "❓" | "question" => Some("❓"),
+        _ => None,
+    }
+}
+
+/// Group a message's reactions by emoji, in `REACTION_EMOJIS` order
+pub fn summarize_reactions(reactions: &[MessageReaction]) -> Vec<ReactionSummary> {
+    REACTION_EMOJIS
+        .iter()
+        .filter_map(|emoji| {
+            let user_ids: Vec<String> = reactions
+                .iter()
+                .filter(|r| r.emoji == *emoji)
+                .map(|r| r.user_id.clone())
+                .collect();
+            if user_ids.is_empty() {
+                return None;
+            }
+            Some(ReactionSummary {
+                emoji: emoji.to_string(),
+                count: user_ids.len() as i64,
+                user_ids,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_reaction() {
+        assert_eq!(normalize_reaction("👍"), Some("👍"));
+        assert_eq!(normalize_reaction("check"), Some("✅"));
+        assert_eq!(normalize_reaction(" question "), Some("❓"));
+        assert_eq!(normalize_reaction("🎉"), None);
+    }
+
+    #[test]
+    fn test_summarize_reactions() {
+        let reactions = vec![
+            MessageReaction::new("m1".to_string(), "u1".to_string(), "❓".to_string()),
+            MessageReaction::new("m1".to_string(), "u1".to_string(), "👍".to_string()),
+            MessageReaction::new("m1".to_string(), "u2".to_string(), "👍".to_string()),
+        ];
+
+        let summary = summarize_reactions(&reactions);
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].emoji, "👍");
+        assert_eq!(summary[0].count, 2);
+        assert_eq!(summary[1].user_ids, vec!["u1".to_string()]);
+    }
+}
@@ -1,51 +1,121 @@
+pub mod activity;
 pub mod agent_activity;
 pub mod api_key;
 pub mod assignment;
+pub mod attachment_rule;
 pub mod auth_event;
+pub mod auto_tag_rule;
 pub mod automation_rule;
+pub mod branding;
+pub mod calendar_invite;
+pub mod channel;
+pub mod chat_widget;
 pub mod config;
+pub mod config_bundle;
+pub mod contact_email_verification;
+pub mod contact_note;
 pub mod conversation;
+pub mod conversation_contact;
+pub mod conversation_event;
+pub mod conversation_query;
+pub mod conversation_transfer;
+pub mod csat;
+pub mod diagnostics;
 pub mod email;
+pub mod email_authentication;
+pub mod email_oauth;
+pub mod email_template;
+pub mod gdpr;
 pub mod holiday;
+pub mod import;
 pub mod inbox;
 pub mod job;
+pub mod junk;
+pub mod locale;
 pub mod macro_models;
+pub mod maintenance;
 pub mod message;
+pub mod message_reaction;
 pub mod notification;
 pub mod oidc_provider;
 pub mod oidc_state;
 pub mod password_reset;
+pub mod priority_matrix;
+pub mod reporting;
+pub mod response_reminder;
 pub mod role;
 pub mod rule_evaluation_log;
+pub mod saved_view;
+pub mod sentiment;
 pub mod session;
+pub mod shift;
 pub mod sla;
+pub mod sms;
+pub mod system_config;
+pub mod system_note;
 pub mod tag;
 pub mod team;
+pub mod telegram;
 pub mod user;
 pub mod webhook;
 
+pub use activity::*;
 pub use agent_activity::*;
 pub use api_key::*;
 pub use assignment::*;
+pub use attachment_rule::*;
 pub use auth_event::*;
+pub use auto_tag_rule::*;
 pub use automation_rule::*;
+pub use branding::*;
+pub use calendar_invite::*;
+pub use channel::*;
+pub use chat_widget::*;
 pub use config::*;
+pub use config_bundle::*;
+pub use contact_email_verification::*;
+pub use contact_note::*;
 pub use conversation::*;
+pub use conversation_event::*;
+pub use conversation_query::*;
+pub use conversation_contact::*;
+pub use conversation_transfer::*;
+pub use csat::*;
+pub use diagnostics::*;
 pub use email::*;
+pub use email_authentication::*;
+pub use email_oauth::*;
+pub use email_template::*;
+pub use gdpr::*;
 pub use holiday::*;
+pub use import::*;
 pub use inbox::*;
 pub use job::*;
+pub use junk::*;
+pub use locale::*;
 pub use macro_models::*;
+pub use maintenance::*;
 pub use message::*;
+pub use message_reaction::*;
 pub use notification::*;
 pub use oidc_provider::*;
 pub use oidc_state::*;
 pub use password_reset::*;
+pub use priority_matrix::*;
+pub use reporting::*;
+pub use response_reminder::*;
 pub use role::*;
 pub use rule_evaluation_log::*;
+pub use saved_view::*;
+pub use sentiment::*;
 pub use session::*;
+pub use shift::*;
 pub use sla::*;
+pub use sms::*;
+pub use system_config::*;
+pub use system_note::*;
 pub use tag::*;
 pub use team::*;
+pub use telegram::*;
 pub use user::*;
 pub use webhook::*;
@@ -7,6 +7,11 @@ use uuid::Uuid;
 pub enum NotificationType {
     Assignment,
     Mention,
+    Alert,
+    /// A conversation assigned to the user is waiting for a reply
+    Reminder,
+    /// A conversation was transferred to the user, or their transfer was answered
+    Transfer,
 }
 
 impl NotificationType {
@@ -14,6 +19,9 @@ impl NotificationType {
         match self {
             NotificationType::Assignment => "assignment",
             NotificationType::Mention => "mention",
+            NotificationType::Alert => "alert",
+            NotificationType::Reminder => "reminder",
+            NotificationType::Transfer => "transfer",
         }
     }
 }
@@ -29,6 +37,9 @@ impl From<String> for NotificationType {
         match s.to_lowercase().as_str() {
             "assignment" => NotificationType::Assignment,
             "mention" => NotificationType::Mention,
+            "alert" => NotificationType::Alert,
+            "reminder" => NotificationType::Reminder,
+            "transfer" => NotificationType::Transfer,
             _ => NotificationType::Assignment, // Default fallback
         }
     }
@@ -93,6 +104,62 @@ impl UserNotification {
         }
     }
 
+    /// Create a new alert notification raised by an automation rule
+    pub fn new_alert(user_id: String, conversation_id: String) -> Self {
+        let now = time::OffsetDateTime::now_utc()
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap();
+
+        Self {
+            id: Uuid::new_v4().to_string(),
+            user_id,
+            notification_type: NotificationType::Alert,
+            created_at: now,
+            is_read: false,
+            conversation_id: Some(conversation_id),
+            message_id: None,
+            actor_id: None,
+        }
+    }
+
+    /// Create a reminder that `message_id` in a conversation assigned to the
+    /// user is still waiting for a reply
+    pub fn new_reminder(user_id: String, conversation_id: String, message_id: String) -> Self {
+        let now = time::OffsetDateTime::now_utc()
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap();
+
+        Self {
+            id: Uuid::new_v4().to_string(),
+            user_id,
+            notification_type: NotificationType::Reminder,
+            created_at: now,
+            is_read: false,
+            conversation_id: Some(conversation_id),
+            message_id: Some(message_id),
+            actor_id: None,
+        }
+    }
+
+    /// Create a transfer notification: a conversation handed over to the user
+    /// by `actor_id`, or the receiver's answer to the user's transfer
+    pub fn new_transfer(user_id: String, conversation_id: String, actor_id: String) -> Self {
+        let now = time::OffsetDateTime::now_utc()
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap();
+
+        Self {
+            id: Uuid::new_v4().to_string(),
+            user_id,
+            notification_type: NotificationType::Transfer,
+            created_at: now,
+            is_read: false,
+            conversation_id: Some(conversation_id),
+            message_id: None,
+            actor_id: Some(actor_id),
+        }
+    }
+
     /// Validate notification fields based on type
     pub fn validate(&self) -> Result<(), String> {
         match self.notification_type {
@@ -114,11 +181,45 @@ impl UserNotification {
                     return Err("Mention notification must have actor_id".to_string());
                 }
             }
+            NotificationType::Alert => {
+                // Alert notifications MUST have conversation_id
+                if self.conversation_id.is_none() {
+                    return Err("Alert notification must have conversation_id".to_string());
+                }
+            }
+            NotificationType::Reminder => {
+                // Reminder notifications MUST have conversation_id AND message_id
+                if self.conversation_id.is_none() {
+                    return Err("Reminder notification must have conversation_id".to_string());
+                }
+                if self.message_id.is_none() {
+                    return Err("Reminder notification must have message_id".to_string());
+                }
+            }
+            NotificationType::Transfer => {
+                // Transfer notifications MUST have conversation_id AND actor_id
+                if self.conversation_id.is_none() {
+                    return Err("Transfer notification must have conversation_id".to_string());
+                }
+                if self.actor_id.is_none() {
+                    return Err("Transfer notification must have actor_id".to_string());
+                }
+            }
         }
         Ok(())
     }
 }
 
+/// A notification event recorded for SSE replay (resume via Last-Event-ID)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationStreamEvent {
+    pub sequence: i64,
+    pub user_id: String,
+    /// Serialized event payload, sent verbatim as the SSE data field
+    pub payload: String,
+    pub created_at: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::shared::validation::{Validate, ValidationErrors};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OidcProvider {
     pub id: String,
@@ -28,79 +30,14 @@ pub struct CreateOidcProviderRequest {
     pub enabled: bool,
 }
 
-impl CreateOidcProviderRequest {
-    pub fn validate(&self) -> Result<(), crate::infrastructure::http::middleware::ApiError> {
-        use crate::infrastructure::http::middleware::ApiError;
-
-        if self.name.trim().is_empty() {
-            return Err(ApiError::BadRequest(
-                "Provider name cannot be empty".to_string(),
-            ));
-        }
-
-        if self.name.len() > 100 {
-            return Err(ApiError::BadRequest(
-                "Provider name cannot exceed 100 characters".to_string(),
-            ));
-        }
-
-        if self.issuer_url.trim().is_empty() {
-            return Err(ApiError::BadRequest(
-                "Issuer URL cannot be empty".to_string(),
-            ));
-        }
-
-        if !self.issuer_url.starts_with("https://") {
-            return Err(ApiError::BadRequest(
-                "Issuer URL must use HTTPS".to_string(),
-            ));
-        }
-
-        if self.client_id.trim().is_empty() {
-            return Err(ApiError::BadRequest(
-                "Client ID cannot be empty".to_string(),
-            ));
-        }
-
-        if self.client_secret.trim().is_empty() {
-            return Err(ApiError::BadRequest(
-                "Client secret cannot be empty".to_string(),
-            ));
-        }
-
-        if self.redirect_uri.trim().is_empty() {
-            return Err(ApiError::BadRequest(
-                "Redirect URI cannot be empty".to_string(),
-            ));
-        }
-
-        if !self.redirect_uri.starts_with("https://")
-            && !self.redirect_uri.starts_with("http://localhost")
-        {
-            return Err(ApiError::BadRequest(
-                "Redirect URI must use HTTPS (or http://localhost for development)".to_string(),
-            ));
-        }
-
-        if self.scopes.is_empty() {
-            return Err(ApiError::BadRequest(
-                "At least one scope must be specified".to_string(),
-            ));
-        }
-
-        if !self.scopes.contains(&"openid".to_string()) {
-            return Err(ApiError::BadRequest(
-                "Scopes must include 'openid' for OIDC".to_string(),
-            ));
-        }
-
-        if !self.scopes.contains(&"email".to_string()) {
-            return Err(ApiError::BadRequest(
-                "Scopes must include 'email' to identify users".to_string(),
-            ));
-        }
-
-        Ok(())
+impl Validate for CreateOidcProviderRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 100);
+        validate_issuer_url(errors, &self.issuer_url);
+        errors.length("client_id", &self.client_id, 1, 255);
+        errors.length("client_secret", &self.client_secret, 1, 1024);
+        validate_redirect_uri(errors, &self.redirect_uri);
+        validate_scopes(errors, &self.scopes);
     }
 }
 
@@ -115,6 +52,55 @@ pub struct UpdateOidcProviderRequest {
     pub enabled: Option<bool>,
 }
 
+impl Validate for UpdateOidcProviderRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("name", self.name.as_deref(), 1, 100);
+        if let Some(issuer_url) = &self.issuer_url {
+            validate_issuer_url(errors, issuer_url);
+        }
+        errors.optional_length("client_id", self.client_id.as_deref(), 1, 255);
+        errors.optional_length("client_secret", self.client_secret.as_deref(), 1, 1024);
+        if let Some(redirect_uri) = &self.redirect_uri {
+            validate_redirect_uri(errors, redirect_uri);
+        }
+        if let Some(scopes) = &self.scopes {
+            validate_scopes(errors, scopes);
+        }
+    }
+}
+
+fn validate_issuer_url(errors: &mut ValidationErrors, issuer_url: &str) {
+    if issuer_url.trim().is_empty() {
+        errors.add("issuer_url", "is required");
+    } else if !issuer_url.starts_with("https://") {
+        errors.add("issuer_url", "must use HTTPS");
+    }
+}
+
+fn validate_redirect_uri(errors: &mut ValidationErrors, redirect_uri: &str) {
+    if redirect_uri.trim().is_empty() {
+        errors.add("redirect_uri", "is required");
+    } else if !redirect_uri.starts_with("https://") && !redirect_uri.starts_with("http://localhost")
+    {
+        errors.add(
+            "redirect_uri",
+            "must use HTTPS (or http://localhost for development)",
+        );
+    }
+}
+
+fn validate_scopes(errors: &mut ValidationErrors, scopes: &[String]) {
+    if scopes.is_empty() {
+        errors.add("scopes", "must not be empty");
+        return;
+    }
+    for required in ["openid", "email"] {
+        if !scopes.iter().any(|scope| scope == required) {
+            errors.add("scopes", format!("must include '{}'", required));
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct OidcProviderResponse {
     pub id: String,
@@ -1,12 +1,18 @@
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
+use crate::shared::validation::{Validate, ValidationErrors};
+
 /// Entity: Password reset token stored in database
+///
+/// Only the SHA-256 hash of the token is kept; the token itself exists in the
+/// emailed link.
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct PasswordResetToken {
     pub id: String,
     pub user_id: String,
-    pub token: String,
+    pub token_hash: String,
     pub expires_at: String,
     pub used: bool,
     pub created_at: String,
@@ -37,15 +43,36 @@ pub struct ResetPasswordResponse {
     pub message: String,
 }
 
+/// DTO: Request to accept an agent invitation by choosing a password
+#[derive(Debug, Clone, Deserialize)]
+pub struct AcceptInvitationRequest {
+    pub token: String,
+    pub password: String,
+}
+
+impl Validate for AcceptInvitationRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("token", &self.token, 1, 64);
+        errors.length("password", &self.password, 1, 128);
+    }
+}
+
+/// DTO: Response for an accepted invitation
+#[derive(Debug, Clone, Serialize)]
+pub struct AcceptInvitationResponse {
+    pub message: String,
+}
+
 impl PasswordResetToken {
-    pub fn new(user_id: String, token: String) -> Self {
+    /// Record for `token`, valid for `ttl_seconds`
+    pub fn new(user_id: String, token: &str, ttl_seconds: i64) -> Self {
         let now = time::OffsetDateTime::now_utc();
-        let expires_at = now + time::Duration::hours(1); // 1 hour expiration
+        let expires_at = now + time::Duration::seconds(ttl_seconds);
 
         Self {
             id: Uuid::new_v4().to_string(),
             user_id,
-            token,
+            token_hash: Self::hash_token(token),
             expires_at: expires_at
                 .format(&time::format_description::well_known::Rfc3339)
                 .unwrap(),
@@ -56,6 +83,11 @@ impl PasswordResetToken {
         }
     }
 
+    /// Hex-encoded SHA-256 of a reset token, as stored in the database
+    pub fn hash_token(token: &str) -> String {
+        hex::encode(Sha256::digest(token.as_bytes()))
+    }
+
     pub fn is_expired(&self) -> bool {
         if let Ok(expires_at) = time::OffsetDateTime::parse(
             &self.expires_at,
@@ -67,3 +99,20 @@ impl PasswordResetToken {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_is_stored_hashed() {
+        let record = PasswordResetToken::new("user-1".to_string(), "abc123", 600);
+        assert_ne!(record.token_hash, "abc123");
+        assert_eq!(record.token_hash.len(), 64);
+        assert_eq!(record.token_hash, PasswordResetToken::hash_token("abc123"));
+        assert!(!record.is_expired());
+
+        let expired = PasswordResetToken::new("user-1".to_string(), "abc123", -1);
+        assert!(expired.is_expired());
+    }
+}
@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::Priority;
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// One cell of the priority matrix: contact tier × tag → priority
+///
+/// A missing tier or tag matches any value, so an entry can set a priority
+/// for every conversation from a tier, or for a tag whoever the contact is.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriorityMatrixEntry {
+    pub id: String,
+    pub tier: Option<String>,
+    pub tag_id: Option<String>,
+    pub priority: Priority,
+    pub created_by: String,
+    pub created_at: String,
+}
+
+impl PriorityMatrixEntry {
+    pub fn new(
+        tier: Option<String>,
+        tag_id: Option<String>,
+        priority: Priority,
+        created_by: String,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            tier: tier.as_deref().map(normalize_tier),
+            tag_id,
+            priority,
+            created_by,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Whether the entry applies to a contact tier and set of conversation tags
+    pub fn matches(&self, tier: Option<&str>, tag_ids: &[String]) -> bool {
+        let tier_matches = match &self.tier {
+            Some(entry_tier) => tier == Some(entry_tier.as_str()),
+            None => true,
+        };
+        let tag_matches = match &self.tag_id {
+            Some(entry_tag) => tag_ids.contains(entry_tag),
+            None => true,
+        };
+        tier_matches && tag_matches
+    }
+}
+
+/// Highest priority among the entries matching a conversation
+pub fn resolve_matrix_priority(
+    entries: &[PriorityMatrixEntry],
+    tier: Option<&str>,
+    tag_ids: &[String],
+) -> Option<Priority> {
+    entries
+        .iter()
+        .filter(|entry| entry.matches(tier, tag_ids))
+        .map(|entry| entry.priority)
+        .max()
+}
+
+/// Tiers are compared case-insensitively
+pub fn normalize_tier(tier: &str) -> String {
+    tier.trim().to_lowercase()
+}
+
+// ========== DTOs ==========
+
+/// Request to add a priority matrix entry
+#[derive(Debug, Deserialize)]
+pub struct CreatePriorityMatrixEntryRequest {
+    pub tier: Option<String>,
+    pub tag_id: Option<String>,
+    pub priority: Priority,
+}
+
+impl Validate for CreatePriorityMatrixEntryRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("tier", self.tier.as_deref(), 1, 50);
+        errors.optional_length("tag_id", self.tag_id.as_deref(), 1, 255);
+        if self.tier.is_none() && self.tag_id.is_none() {
+            errors.add("tier", "Either tier or tag_id is required");
+        }
+    }
+}
+
+/// Request to set (or, with `null`, clear) a contact's tier
+#[derive(Debug, Deserialize)]
+pub struct SetContactTierRequest {
+    pub tier: Option<String>,
+}
+
+impl Validate for SetContactTierRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("tier", self.tier.as_deref(), 1, 50);
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContactTierResponse {
+    pub contact_id: String,
+    pub tier: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(tier: Option<&str>, tag_id: Option<&str>, priority: Priority) -> PriorityMatrixEntry {
+        PriorityMatrixEntry::new(
+            tier.map(str::to_string),
+            tag_id.map(str::to_string),
+            priority,
+            "admin".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_highest_matching_priority_wins() {
+        let entries = vec![
+            entry(Some("Enterprise"), None, Priority::Medium),
+            entry(None, Some("outage"), Priority::Medium),
+            entry(Some("enterprise"), Some("outage"), Priority::High),
+            entry(Some("free"), Some("billing"), Priority::Low),
+        ];
+        let tags = vec!["outage".to_string()];
+
+        assert_eq!(
+            resolve_matrix_priority(&entries, Some("enterprise"), &tags),
+            Some(Priority::High)
+        );
+        assert_eq!(
+            resolve_matrix_priority(&entries, Some("enterprise"), &[]),
+            Some(Priority::Medium)
+        );
+        assert_eq!(
+            resolve_matrix_priority(&entries, None, &tags),
+            Some(Priority::Medium)
+        );
+        assert_eq!(resolve_matrix_priority(&entries, Some("free"), &[]), None);
+    }
+}
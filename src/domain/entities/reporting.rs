@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Prefix of every reporting token, so leaked tokens are easy to recognise
+pub const REPORTING_TOKEN_PREFIX: &str = "oxr_";
+
+/// Characters of a token kept in `token_prefix` for display
+pub const REPORTING_TOKEN_DISPLAY_LENGTH: usize = 12;
+
+/// Workspace-level read-only token for BI and reporting tools
+///
+/// Unlike agent API keys these are not tied to an agent and only grant
+/// access to the `/api/reporting` endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportingToken {
+    pub id: String,
+    pub name: String,
+    /// Leading characters of the token, e.g. `oxr_AbC12345`
+    pub token_prefix: String,
+    #[serde(skip)]
+    pub token_hash: String,
+    /// Admin who issued the token; `None` once that user is deleted
+    pub created_by: Option<String>,
+    pub last_used_at: Option<String>,
+    pub revoked_at: Option<String>,
+    pub created_at: String,
+}
+
+impl ReportingToken {
+    pub fn new(name: String, token: &str, token_hash: String, created_by: String) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            name: name.trim().to_string(),
+            token_prefix: token.chars().take(REPORTING_TOKEN_DISPLAY_LENGTH).collect(),
+            token_hash,
+            created_by: Some(created_by),
+            last_used_at: None,
+            revoked_at: None,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateReportingTokenRequest {
+    pub name: String,
+}
+
+impl Validate for CreateReportingTokenRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 100);
+    }
+}
+
+/// Newly issued token; the plain token is only ever returned here
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateReportingTokenResponse {
+    #[serde(flatten)]
+    pub token: ReportingToken,
+    #[serde(rename = "token")]
+    pub plain_token: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportingTokenListResponse {
+    pub tokens: Vec<ReportingToken>,
+}
+
+/// Workspace KPIs, optionally limited to records created since a point in time
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportingSummary {
+    pub since: Option<String>,
+    pub conversations: ConversationVolume,
+    pub csat: CsatSummary,
+    pub sla: SlaSummary,
+    pub generated_at: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConversationVolume {
+    pub total: i64,
+    pub by_status: BTreeMap<String, i64>,
+    /// Conversations without a priority are counted under `none`
+    pub by_priority: BTreeMap<String, i64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CsatSummary {
+    pub responses: i64,
+    pub average_score: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SlaSummary {
+    pub applied: i64,
+    pub pending: i64,
+    pub met: i64,
+    pub breached: i64,
+}
+
+/// Conversation metadata exposed to reporting tools
+///
+/// Deliberately leaves out the subject and anything else written by the
+/// contact or agents.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportingConversation {
+    pub id: String,
+    pub reference_number: i64,
+    pub status: String,
+    pub priority: Option<String>,
+    pub inbox_id: String,
+    pub assigned_user_id: Option<String>,
+    pub assigned_team_id: Option<String>,
+    pub tags: Vec<String>,
+    pub csat_score: Option<i32>,
+    pub sentiment_score: Option<f64>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub resolved_at: Option<String>,
+    pub closed_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportingConversationListResponse {
+    pub conversations: Vec<ReportingConversation>,
+    pub limit: i64,
+    pub offset: i64,
+}
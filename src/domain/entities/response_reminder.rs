@@ -0,0 +1,159 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Threshold used when an agent has not configured reminders yet
+pub const DEFAULT_REMIND_AFTER_MINUTES: i64 = 60;
+
+/// Longest configurable wait or repeat interval (one week)
+pub const MAX_REMINDER_MINUTES: i64 = 7 * 24 * 60;
+
+/// Per-agent response due reminder settings
+///
+/// When enabled, the agent is notified once a contact message in a
+/// conversation assigned to them has waited `remind_after_minutes` without a
+/// reply, and again every `repeat_after_minutes` (if set) until someone replies.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseReminderSettings {
+    pub user_id: String,
+    pub enabled: bool,
+    pub remind_after_minutes: i64,
+    pub repeat_after_minutes: Option<i64>,
+    pub updated_at: String,
+}
+
+impl ResponseReminderSettings {
+    /// Settings reported for an agent who has not opted in
+    pub fn disabled(user_id: String) -> Self {
+        Self {
+            user_id,
+            enabled: false,
+            remind_after_minutes: DEFAULT_REMIND_AFTER_MINUTES,
+            repeat_after_minutes: None,
+            updated_at: Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// An assigned open conversation waiting for a reply, with the reminder
+/// state of its assignee
+#[derive(Debug, Clone)]
+pub struct ResponseReminderCandidate {
+    pub conversation_id: String,
+    pub user_id: String,
+    pub remind_after_minutes: i64,
+    pub repeat_after_minutes: Option<i64>,
+    /// First contact message since the last reply
+    pub message_id: String,
+    pub waiting_since: String,
+    /// Message the last reminder was about, if any
+    pub reminded_message_id: Option<String>,
+    pub last_reminded_at: Option<String>,
+}
+
+impl ResponseReminderCandidate {
+    /// Whether a reminder should be sent at `now`
+    ///
+    /// The first reminder for a waiting message goes out once it is older than
+    /// the threshold; later ones only when repeats are on and the interval has
+    /// passed since the previous reminder.
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        let Some(waiting_since) = parse_timestamp(&self.waiting_since) else {
+            return false;
+        };
+        if now - waiting_since < Duration::minutes(self.remind_after_minutes) {
+            return false;
+        }
+
+        if self.reminded_message_id.as_deref() != Some(self.message_id.as_str()) {
+            return true;
+        }
+
+        match (
+            self.repeat_after_minutes,
+            self.last_reminded_at.as_deref().and_then(parse_timestamp),
+        ) {
+            (Some(repeat), Some(last)) => now - last >= Duration::minutes(repeat),
+            _ => false,
+        }
+    }
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+// ========== DTOs ==========
+
+/// Request to change an agent's reminder settings
+#[derive(Debug, Deserialize)]
+pub struct UpdateResponseReminderSettingsRequest {
+    pub enabled: bool,
+    pub remind_after_minutes: i64,
+    /// Repeat interval; omit or null to remind only once per waiting message
+    pub repeat_after_minutes: Option<i64>,
+}
+
+impl Validate for UpdateResponseReminderSettingsRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.range(
+            "remind_after_minutes",
+            self.remind_after_minutes,
+            1,
+            MAX_REMINDER_MINUTES,
+        );
+        if let Some(repeat) = self.repeat_after_minutes {
+            errors.range("repeat_after_minutes", repeat, 1, MAX_REMINDER_MINUTES);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(waiting_minutes: i64) -> (ResponseReminderCandidate, DateTime<Utc>) {
+        let now = Utc::now();
+        let candidate = ResponseReminderCandidate {
+            conversation_id: "conv-1".to_string(),
+            user_id: "user-1".to_string(),
+            remind_after_minutes: 30,
+            repeat_after_minutes: None,
+            message_id: "msg-1".to_string(),
+            waiting_since: (now - Duration::minutes(waiting_minutes)).to_rfc3339(),
+            reminded_message_id: None,
+            last_reminded_at: None,
+        };
+        (candidate, now)
+    }
+
+    #[test]
+    fn test_first_reminder_after_threshold() {
+        let (fresh, now) = candidate(10);
+        assert!(!fresh.is_due(now));
+
+        let (waiting, now) = candidate(45);
+        assert!(waiting.is_due(now));
+    }
+
+    #[test]
+    fn test_repeat_reminders() {
+        let (mut reminded, now) = candidate(300);
+        reminded.reminded_message_id = Some("msg-1".to_string());
+        reminded.last_reminded_at = Some((now - Duration::minutes(90)).to_rfc3339());
+        assert!(!reminded.is_due(now), "no repeat configured");
+
+        reminded.repeat_after_minutes = Some(120);
+        assert!(!reminded.is_due(now));
+        reminded.repeat_after_minutes = Some(60);
+        assert!(reminded.is_due(now));
+
+        // A reminder about an earlier, since-answered message starts a new cycle
+        reminded.repeat_after_minutes = None;
+        reminded.reminded_message_id = Some("msg-0".to_string());
+        assert!(reminded.is_due(now));
+    }
+}
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::shared::validation::{Validate, ValidationErrors};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Role {
     pub id: String,
@@ -63,6 +65,14 @@ pub struct CreateRoleRequest {
     pub permissions: Vec<String>, // Permission strings like "conversations:read_assigned"
 }
 
+impl Validate for CreateRoleRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 100);
+        errors.max_length("description", self.description.as_deref(), 500);
+        validate_permission_names(errors, &self.permissions);
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UpdateRoleRequest {
     pub name: Option<String>,
@@ -70,6 +80,31 @@ pub struct UpdateRoleRequest {
     pub permissions: Option<Vec<String>>, // Permission strings
 }
 
+impl Validate for UpdateRoleRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("name", self.name.as_deref(), 1, 100);
+        errors.max_length("description", self.description.as_deref(), 500);
+        if let Some(permissions) = &self.permissions {
+            validate_permission_names(errors, permissions);
+        }
+    }
+}
+
+/// Permissions are `resource:action` strings
+fn validate_permission_names(errors: &mut ValidationErrors, permissions: &[String]) {
+    for (index, permission) in permissions.iter().enumerate() {
+        let valid = permission
+            .split_once(':')
+            .is_some_and(|(resource, action)| !resource.is_empty() && !action.is_empty());
+        if !valid {
+            errors.add(
+                format!("permissions[{}]", index),
+                "must be a permission name like resource:action",
+            );
+        }
+    }
+}
+
 impl Role {
     pub fn new(name: String, description: Option<String>, permissions: Vec<String>) -> Self {
         let now = time::OffsetDateTime::now_utc()
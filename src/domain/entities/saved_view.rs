@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::conversation_query::MAX_CONVERSATION_QUERY_LENGTH;
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Longest view name, in characters
+pub const SAVED_VIEW_NAME_MAX_LENGTH: usize = 100;
+
+/// A named conversation search query kept by an agent
+///
+/// The query is stored as written and compiled on every use, so `assignee:me`
+/// and relative times like `updated<2d` stay current.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedView {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub query: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl SavedView {
+    pub fn new(user_id: String, name: String, query: String) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            user_id,
+            name: name.trim().to_string(),
+            query: query.trim().to_string(),
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateSavedViewRequest {
+    pub name: String,
+    pub query: String,
+}
+
+impl Validate for CreateSavedViewRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, SAVED_VIEW_NAME_MAX_LENGTH);
+        errors.length("query", &self.query, 1, MAX_CONVERSATION_QUERY_LENGTH);
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpdateSavedViewRequest {
+    pub name: Option<String>,
+    pub query: Option<String>,
+}
+
+impl Validate for UpdateSavedViewRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("name", self.name.as_deref(), 1, SAVED_VIEW_NAME_MAX_LENGTH);
+        errors.optional_length(
+            "query",
+            self.query.as_deref(),
+            1,
+            MAX_CONVERSATION_QUERY_LENGTH,
+        );
+    }
+}
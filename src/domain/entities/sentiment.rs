@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+/// Scores at or below this are labelled negative
+pub const NEGATIVE_SENTIMENT_THRESHOLD: f64 = -0.25;
+
+/// Scores at or above this are labelled positive
+pub const POSITIVE_SENTIMENT_THRESHOLD: f64 = 0.25;
+
+/// Sentiment score of a single inbound message, from -1.0 (negative) to 1.0 (positive)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageSentiment {
+    pub message_id: String,
+    pub conversation_id: String,
+    pub score: f64,
+    pub created_at: String,
+}
+
+impl MessageSentiment {
+    pub fn new(message_id: String, conversation_id: String, score: f64) -> Self {
+        Self {
+            message_id,
+            conversation_id,
+            score: score.clamp(-1.0, 1.0),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Direction the conversation's sentiment moved with the latest message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SentimentTrend {
+    Improving,
+    Stable,
+    Declining,
+}
+
+impl SentimentTrend {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SentimentTrend::Improving => "improving",
+            SentimentTrend::Stable => "stable",
+            SentimentTrend::Declining => "declining",
+        }
+    }
+}
+
+impl From<String> for SentimentTrend {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "improving" => SentimentTrend::Improving,
+            "declining" => SentimentTrend::Declining,
+            _ => SentimentTrend::Stable,
+        }
+    }
+}
+
+impl std::fmt::Display for SentimentTrend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Coarse sentiment bucket used for list filters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SentimentLabel {
+    Negative,
+    Neutral,
+    Positive,
+}
+
+impl SentimentLabel {
+    pub fn from_score(score: f64) -> Self {
+        if score <= NEGATIVE_SENTIMENT_THRESHOLD {
+            SentimentLabel::Negative
+        } else if score >= POSITIVE_SENTIMENT_THRESHOLD {
+            SentimentLabel::Positive
+        } else {
+            SentimentLabel::Neutral
+        }
+    }
+}
+
+/// Rolling sentiment of a conversation after an inbound message was scored
+#[derive(Debug, Clone, Serialize)]
+pub struct ConversationSentiment {
+    pub conversation_id: String,
+    pub score: f64,
+    pub trend: SentimentTrend,
+    /// Conversation score before this message, if it had one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_score: Option<f64>,
+}
+
+/// DTO: Sentiment history of a conversation
+#[derive(Debug, Clone, Serialize)]
+pub struct ConversationSentimentResponse {
+    pub conversation_id: String,
+    pub score: Option<f64>,
+    pub trend: Option<SentimentTrend>,
+    pub messages: Vec<MessageSentiment>,
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::services::shift_schedule::{normalize_weekday, parse_shift_time};
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// A weekly shift worked by a team member
+///
+/// Times are "HH:MM" in the team's business-hours timezone (UTC when the team
+/// has none); `end_time` may be "24:00".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentShift {
+    pub id: String,
+    pub team_id: String,
+    pub user_id: String,
+    pub day: String, // "Monday", "Tuesday", etc.
+    pub start_time: String,
+    pub end_time: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl AgentShift {
+    pub fn new(
+        team_id: String,
+        user_id: String,
+        day: String,
+        start_time: String,
+        end_time: String,
+    ) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            team_id,
+            user_id,
+            day,
+            start_time,
+            end_time,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateShiftRequest {
+    pub user_id: String,
+    pub day: String,
+    pub start_time: String,
+    pub end_time: String,
+}
+
+impl Validate for CreateShiftRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("user_id", &self.user_id, 1, 255);
+        validate_shift_fields(
+            errors,
+            Some(&self.day),
+            Some(&self.start_time),
+            Some(&self.end_time),
+        );
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateShiftRequest {
+    pub day: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+}
+
+impl Validate for UpdateShiftRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        validate_shift_fields(
+            errors,
+            self.day.as_deref(),
+            self.start_time.as_deref(),
+            self.end_time.as_deref(),
+        );
+    }
+}
+
+fn validate_shift_fields(
+    errors: &mut ValidationErrors,
+    day: Option<&str>,
+    start_time: Option<&str>,
+    end_time: Option<&str>,
+) {
+    if let Some(Err(e)) = day.map(normalize_weekday) {
+        errors.add("day", e);
+    }
+    let start = start_time.map(parse_shift_time);
+    let end = end_time.map(parse_shift_time);
+    if let Some(Err(e)) = &start {
+        errors.add("start_time", e.clone());
+    }
+    if let Some(Err(e)) = &end {
+        errors.add("end_time", e.clone());
+    }
+    if let (Some(Ok(start)), Some(Ok(end))) = (start, end) {
+        if end <= start {
+            errors.add("end_time", "must be after start_time");
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShiftListResponse {
+    pub shifts: Vec<AgentShift>,
+    pub total: i64,
+}
+
+/// A "HH:MM" time range within a single day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimeRange {
+    pub start: String,
+    pub end: String,
+}
+
+/// Scheduled coverage for one weekday
+#[derive(Debug, Clone, Serialize)]
+pub struct DayCoverage {
+    pub day: String,
+    /// Hours that need coverage: business hours, or the whole day without them
+    pub required: Option<TimeRange>,
+    /// Parts of `required` with no agent scheduled
+    pub gaps: Vec<TimeRange>,
+    pub scheduled_agents: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShiftCoverageReport {
+    pub team_id: String,
+    pub timezone: String,
+    pub days: Vec<DayCoverage>,
+    pub has_gaps: bool,
+}
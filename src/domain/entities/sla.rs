@@ -231,6 +231,71 @@ impl std::str::FromStr for SlaEventStatus {
     }
 }
 
+// ===== Policy Simulation =====
+
+/// Timing of a past conversation, replayed by the SLA simulator
+#[derive(Debug, Clone)]
+pub struct SlaReplayConversation {
+    pub id: String,
+    pub inbox_id: String,
+    pub inbox_name: String,
+    /// Team the conversation is assigned to now
+    pub team_id: Option<String>,
+    pub team_name: Option<String>,
+    pub created_at: String,
+    /// When it was resolved, or closed if it never was
+    pub resolved_at: Option<String>,
+    /// Messages in the order they were written
+    pub messages: Vec<SlaReplayMessage>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SlaReplayMessage {
+    pub created_at: String,
+    pub from_agent: bool,
+}
+
+/// Expected outcome of a draft SLA policy over past conversations
+#[derive(Debug, Clone, Serialize)]
+pub struct SlaSimulation {
+    pub since: String,
+    pub until: String,
+    pub overall: SlaSimulationStats,
+    pub by_team: Vec<SlaSimulationGroup>,
+    pub by_inbox: Vec<SlaSimulationGroup>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SlaSimulationStats {
+    pub conversations: i64,
+    /// Conversations that would have breached at least one target
+    pub breached_conversations: i64,
+    /// Share of conversations with a breach, from 0 to 1
+    pub breach_rate: f64,
+    pub first_response: SlaTargetStats,
+    pub resolution: SlaTargetStats,
+    pub next_response: SlaTargetStats,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SlaTargetStats {
+    pub met: i64,
+    pub breached: i64,
+    /// Deadlines that had not passed yet when the simulation ran
+    pub pending: i64,
+    /// Share of decided deadlines that were breached, from 0 to 1
+    pub breach_rate: f64,
+}
+
+/// Stats for one team or inbox; conversations without a team have no `id`
+#[derive(Debug, Clone, Serialize)]
+pub struct SlaSimulationGroup {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub stats: SlaSimulationStats,
+}
+
 // ===== Duration Parsing Utility =====
 
 use regex::Regex;
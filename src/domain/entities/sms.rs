@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Channel type of SMS inboxes: texts arrive through the Twilio webhook, so SMS
+/// inboxes are API inboxes with an `InboxSmsConfig`
+pub const SMS_INBOX_CHANNEL_TYPE: &str = "api";
+
+/// Channel recorded on messages received by text
+pub const SMS_CHANNEL: &str = "sms";
+
+/// Header carrying Twilio's webhook signature
+pub const TWILIO_SIGNATURE_HEADER: &str = "x-twilio-signature";
+
+/// Segments per text when an inbox doesn't configure a limit
+pub const DEFAULT_SMS_MAX_SEGMENTS: i32 = 3;
+
+/// Twilio rejects messages of more than 10 segments
+pub const MAX_SMS_SEGMENTS: i32 = 10;
+
+/// Twilio SMS configuration of an inbox
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InboxSmsConfig {
+    pub id: String,
+    pub inbox_id: String,
+    pub account_sid: String,
+    pub auth_token: String, // Encrypted at rest using AES-256-GCM
+    /// E.164 number texts are received on and sent from
+    pub phone_number: String,
+    /// Longer replies are split into several texts of at most this many segments
+    pub max_segments: i32,
+    pub enabled: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl InboxSmsConfig {
+    pub fn new(inbox_id: String, request: CreateInboxSmsConfigRequest) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            inbox_id,
+            account_sid: request.account_sid.trim().to_string(),
+            auth_token: request.auth_token.trim().to_string(),
+            phone_number: request.phone_number.trim().to_string(),
+            max_segments: request.max_segments.unwrap_or(DEFAULT_SMS_MAX_SEGMENTS),
+            enabled: request.enabled.unwrap_or(true),
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        validate_account_sid(&self.account_sid)?;
+        if self.auth_token.is_empty() {
+            return Err("Auth token is required".to_string());
+        }
+        validate_phone_number(&self.phone_number)?;
+        if !(1..=MAX_SMS_SEGMENTS).contains(&self.max_segments) {
+            return Err(format!(
+                "max_segments must be between 1 and {}",
+                MAX_SMS_SEGMENTS
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// DTO: Attach a Twilio number to an inbox
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateInboxSmsConfigRequest {
+    pub account_sid: String,
+    pub auth_token: String,
+    pub phone_number: String,
+    pub max_segments: Option<i32>,
+    pub enabled: Option<bool>,
+}
+
+impl Validate for CreateInboxSmsConfigRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if let Err(e) = validate_account_sid(self.account_sid.trim()) {
+            errors.add("account_sid", e);
+        }
+        errors.length("auth_token", &self.auth_token, 1, 255);
+        if let Err(e) = validate_phone_number(self.phone_number.trim()) {
+            errors.add("phone_number", e);
+        }
+        if let Some(max_segments) = self.max_segments {
+            errors.range("max_segments", max_segments, 1, MAX_SMS_SEGMENTS);
+        }
+    }
+}
+
+/// DTO: Update an inbox's Twilio configuration
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpdateInboxSmsConfigRequest {
+    pub account_sid: Option<String>,
+    pub auth_token: Option<String>,
+    pub phone_number: Option<String>,
+    pub max_segments: Option<i32>,
+    pub enabled: Option<bool>,
+}
+
+impl Validate for UpdateInboxSmsConfigRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if let Some(Err(e)) = self
+            .account_sid
+            .as_deref()
+            .map(|account_sid| validate_account_sid(account_sid.trim()))
+        {
+            errors.add("account_sid", e);
+        }
+        errors.optional_length("auth_token", self.auth_token.as_deref(), 1, 255);
+        if let Some(Err(e)) = self
+            .phone_number
+            .as_deref()
+            .map(|phone_number| validate_phone_number(phone_number.trim()))
+        {
+            errors.add("phone_number", e);
+        }
+        if let Some(max_segments) = self.max_segments {
+            errors.range("max_segments", max_segments, 1, MAX_SMS_SEGMENTS);
+        }
+    }
+}
+
+fn validate_account_sid(account_sid: &str) -> Result<(), String> {
+    if account_sid.len() == 34
+        && account_sid.starts_with("AC")
+        && account_sid[2..].chars().all(|c| c.is_ascii_hexdigit())
+    {
+        Ok(())
+    } else {
+        Err(format!("Invalid Twilio account SID: {}", account_sid))
+    }
+}
+
+/// Phone numbers are stored in E.164 format (`+` and 7 to 15 digits)
+pub fn validate_phone_number(phone_number: &str) -> Result<(), String> {
+    let digits = phone_number.strip_prefix('+').unwrap_or("");
+    if (7..=15).contains(&digits.len())
+        && !digits.starts_with('0')
+        && digits.chars().all(|c| c.is_ascii_digit())
+    {
+        Ok(())
+    } else {
+        Err(format!(
+            "Phone number must be in E.164 format (e.g. +14155550123): {}",
+            phone_number
+        ))
+    }
+}
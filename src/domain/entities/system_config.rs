@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::shared::validation::{Validate, ValidationErrors};
+
+pub const INACTIVITY_TIMEOUT_KEY: &str = "availability.inactivity_timeout_seconds";
+pub const MAX_IDLE_THRESHOLD_KEY: &str = "availability.max_idle_threshold_seconds";
+pub const NOTIFICATION_RETENTION_DAYS_KEY: &str = "retention.notification_days";
+pub const NOTIFICATION_STREAM_RETENTION_HOURS_KEY: &str = "retention.notification_stream_hours";
+pub const AUTH_RATE_LIMIT_ATTEMPTS_KEY: &str = "rate_limit.auth_max_attempts";
+pub const AUTH_RATE_LIMIT_WINDOW_KEY: &str = "rate_limit.auth_window_minutes";
+pub const AUTO_JUNK_THRESHOLD_KEY: &str = "junk.auto_junk_threshold";
+
+/// An integer setting that admins may change through the API
+#[derive(Debug, Clone, Copy)]
+pub struct SystemConfigSetting {
+    pub key: &'static str,
+    pub description: &'static str,
+    pub default_value: i64,
+    pub min: i64,
+    pub max: i64,
+}
+
+/// Allow-list of editable settings; any other key is rejected
+pub const SYSTEM_CONFIG_SETTINGS: &[SystemConfigSetting] = &[
+    SystemConfigSetting {
+        key: INACTIVITY_TIMEOUT_KEY,
+        description: "Seconds before an online agent goes away",
+        default_value: 300,
+        min: 60,
+        max: 86_400,
+    },
+    SystemConfigSetting {
+        key: MAX_IDLE_THRESHOLD_KEY,
+        description: "Seconds before an away agent's conversations are reassigned",
+        default_value: 1800,
+        min: 60,
+        max: 604_800,
+    },
+    SystemConfigSetting {
+        key: NOTIFICATION_RETENTION_DAYS_KEY,
+        description: "Days to keep notifications",
+        default_value: 30,
+        min: 1,
+        max: 3650,
+    },
+    SystemConfigSetting {
+        key: NOTIFICATION_STREAM_RETENTION_HOURS_KEY,
+        description: "Hours to keep notification stream events for resumable connections",
+        default_value: 24,
+        min: 1,
+        max: 720,
+    },
+    SystemConfigSetting {
+        key: AUTH_RATE_LIMIT_ATTEMPTS_KEY,
+        description: "Failed login attempts allowed per email within the window",
+        default_value: 5,
+        min: 1,
+        max: 100,
+    },
+    SystemConfigSetting {
+        key: AUTH_RATE_LIMIT_WINDOW_KEY,
+        description: "Login rate limit window in minutes",
+        default_value: 15,
+        min: 1,
+        max: 1440,
+    },
+    SystemConfigSetting {
+        key: AUTO_JUNK_THRESHOLD_KEY,
+        description: "Junk markings after which new conversations from a sender start as junk",
+        default_value: 3,
+        min: 1,
+        max: 100,
+    },
+];
+
+impl SystemConfigSetting {
+    pub fn find(key: &str) -> Option<&'static SystemConfigSetting> {
+        SYSTEM_CONFIG_SETTINGS.iter().find(|s| s.key == key)
+    }
+
+    /// Parse a submitted value (a JSON integer or numeric string) and check its range
+    pub fn validate(&self, value: &serde_json::Value) -> Result<i64, String> {
+        let parsed = match value {
+            serde_json::Value::Number(n) => n.as_i64(),
+            serde_json::Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+        .ok_or_else(|| format!("{} must be an integer", self.key))?;
+
+        if !(self.min..=self.max).contains(&parsed) {
+            return Err(format!(
+                "{} must be between {} and {}",
+                self.key, self.min, self.max
+            ));
+        }
+        Ok(parsed)
+    }
+
+    /// Parse a stored value, falling back to the default when missing or invalid
+    pub fn parse_stored(&self, stored: Option<&str>) -> i64 {
+        stored
+            .and_then(|v| v.trim().parse().ok())
+            .filter(|v| (self.min..=self.max).contains(v))
+            .unwrap_or(self.default_value)
+    }
+}
+
+/// Raw row from `system_config`
+#[derive(Debug, Clone)]
+pub struct SystemConfigEntry {
+    pub key: String,
+    pub value: String,
+    pub updated_at: String,
+}
+
+/// Audit record of one setting change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemConfigChange {
+    pub id: String,
+    pub key: String,
+    pub old_value: Option<String>,
+    pub new_value: String,
+    pub changed_by: String,
+    pub changed_at: String,
+}
+
+impl SystemConfigChange {
+    pub fn new(
+        key: String,
+        old_value: Option<String>,
+        new_value: String,
+        changed_by: String,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            key,
+            old_value,
+            new_value,
+            changed_by,
+            changed_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// DTO: Effective value of a setting
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemConfigValue {
+    pub key: String,
+    pub value: i64,
+    pub default_value: i64,
+    pub min: i64,
+    pub max: i64,
+    pub description: String,
+    /// When the value was last stored; absent while the default applies
+    pub updated_at: Option<String>,
+}
+
+/// DTO: All editable settings
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemConfigResponse {
+    pub settings: Vec<SystemConfigValue>,
+}
+
+/// DTO: Update one or more settings by key
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateSystemConfigRequest {
+    pub settings: BTreeMap<String, serde_json::Value>,
+}
+
+impl Validate for UpdateSystemConfigRequest {
+    // Values are checked against each setting's definition by the service
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if self.settings.is_empty() {
+            errors.add("settings", "must not be empty");
+        }
+        for key in self.settings.keys() {
+            if key.trim().is_empty() || key.len() > 100 {
+                errors.add(format!("settings.{}", key), "is not a valid setting key");
+            }
+        }
+    }
+}
+
+/// Query parameters for the change history
+#[derive(Debug, Clone, Deserialize)]
+pub struct SystemConfigChangesQuery {
+    pub key: Option<String>,
+    #[serde(default = "default_changes_limit")]
+    pub limit: i64,
+}
+
+fn default_changes_limit() -> i64 {
+    50
+}
+
+/// DTO: Change history, newest first
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemConfigChangeListResponse {
+    pub changes: Vec<SystemConfigChange>,
+    pub total: i64,
+}
@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+/// Longest email body kept on a system note, in characters
+pub const SYSTEM_NOTE_MAX_CONTENT_LENGTH: usize = 2000;
+
+/// Kind of machine-generated email that was recorded as a system note
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoGeneratedEmailKind {
+    /// Out-of-office and other automatic replies (`Auto-Submitted`, `X-Autoreply`)
+    AutoReply,
+    /// Delivery status notification for a message that could not be delivered
+    Bounce,
+}
+
+impl AutoGeneratedEmailKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutoGeneratedEmailKind::AutoReply => "auto_reply",
+            AutoGeneratedEmailKind::Bounce => "bounce",
+        }
+    }
+}
+
+impl fmt::Display for AutoGeneratedEmailKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<String> for AutoGeneratedEmailKind {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "bounce" => AutoGeneratedEmailKind::Bounce,
+            _ => AutoGeneratedEmailKind::AutoReply,
+        }
+    }
+}
+
+/// Automatic email recorded on a conversation instead of a contact message
+///
+/// System notes don't reopen the conversation, start SLA clocks or trigger
+/// automations; they are only there so agents can see what came back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemNote {
+    pub id: String,
+    pub conversation_id: String,
+    pub kind: AutoGeneratedEmailKind,
+    pub from_address: String,
+    pub subject: Option<String>,
+    /// Email body, cut to `SYSTEM_NOTE_MAX_CONTENT_LENGTH` characters
+    pub content: String,
+    /// RFC 5322 Message-ID of the email
+    pub email_message_id: String,
+    pub created_at: String,
+}
+
+impl SystemNote {
+    pub fn new(
+        conversation_id: String,
+        kind: AutoGeneratedEmailKind,
+        from_address: String,
+        subject: Option<String>,
+        content: &str,
+        email_message_id: String,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            conversation_id,
+            kind,
+            from_address,
+            subject,
+            content: content
+                .trim()
+                .chars()
+                .take(SYSTEM_NOTE_MAX_CONTENT_LENGTH)
+                .collect(),
+            email_message_id,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// DTO: A conversation's system notes, oldest first
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemNoteListResponse {
+    pub conversation_id: String,
+    pub notes: Vec<SystemNote>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_is_trimmed_and_truncated() {
+        let long_body = format!("  {}  ", "a".repeat(SYSTEM_NOTE_MAX_CONTENT_LENGTH + 10));
+        let note = SystemNote::new(
+            "conv-1".to_string(),
+            AutoGeneratedEmailKind::Bounce,
+            "mailer-daemon@example.com".to_string(),
+            None,
+            &long_body,
+            "<bounce-1@example.com>".to_string(),
+        );
+        assert_eq!(note.content.len(), SYSTEM_NOTE_MAX_CONTENT_LENGTH);
+        assert!(note.content.starts_with('a'));
+    }
+
+    #[test]
+    fn test_kind_round_trips_through_strings() {
+        for kind in [
+            AutoGeneratedEmailKind::AutoReply,
+            AutoGeneratedEmailKind::Bounce,
+        ] {
+            assert_eq!(AutoGeneratedEmailKind::from(kind.to_string()), kind);
+        }
+    }
+}
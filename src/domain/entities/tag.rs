@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::shared::validation::{Validate, ValidationErrors};
+
 /// Tag entity for conversation classification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tag {
@@ -58,6 +60,16 @@ pub struct CreateTagRequest {
     pub color: Option<String>,
 }
 
+impl Validate for CreateTagRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 50);
+        errors.max_length("description", self.description.as_deref(), 500);
+        if let Some(color) = &self.color {
+            errors.hex_color("color", color);
+        }
+    }
+}
+
 /// Request to update tag properties (name is immutable)
 #[derive(Debug, Deserialize)]
 pub struct UpdateTagRequest {
@@ -65,6 +77,15 @@ pub struct UpdateTagRequest {
     pub color: Option<String>,
 }
 
+impl Validate for UpdateTagRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.max_length("description", self.description.as_deref(), 500);
+        if let Some(color) = &self.color {
+            errors.hex_color("color", color);
+        }
+    }
+}
+
 /// Response containing full tag data
 #[derive(Debug, Serialize)]
 pub struct TagResponse {
@@ -102,12 +123,25 @@ pub struct AddTagsRequest {
     pub tag_ids: Vec<String>,
 }
 
+impl Validate for AddTagsRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.items("tag_ids", &self.tag_ids, 1, 255);
+    }
+}
+
 /// Request to replace all conversation tags
 #[derive(Debug, Deserialize)]
 pub struct ReplaceTagsRequest {
     pub tag_ids: Vec<String>,
 }
 
+impl Validate for ReplaceTagsRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        // An empty list clears the conversation's tags
+        errors.items("tag_ids", &self.tag_ids, 0, 255);
+    }
+}
+
 /// Response containing conversation's tags
 #[derive(Debug, Serialize)]
 pub struct ConversationTagsResponse {
@@ -7,6 +7,9 @@ pub struct Team {
     pub description: Option<String>,
     pub sla_policy_id: Option<String>,
     pub business_hours: Option<String>, // JSON format: {"timezone": "America/New_York", "schedule": [...]}
+    pub holiday_calendar_id: Option<String>,
+    /// Assign unassigned team conversations to the member who replies first
+    pub take_on_reply: bool,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -20,6 +23,8 @@ impl Team {
             description,
             sla_policy_id: None,
             business_hours: None,
+            holiday_calendar_id: None,
+            take_on_reply: false,
             created_at: now.clone(),
             updated_at: now,
         }
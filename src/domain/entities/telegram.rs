@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+
+use crate::shared::utils::generate_secret;
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Channel type of Telegram inboxes: updates arrive through the bot webhook, so
+/// Telegram inboxes are API inboxes with an `InboxTelegramConfig`
+pub const TELEGRAM_INBOX_CHANNEL_TYPE: &str = "api";
+
+/// Channel recorded on messages received from Telegram
+pub const TELEGRAM_CHANNEL: &str = "telegram";
+
+/// Header carrying the secret token set with `setWebhook`
+pub const TELEGRAM_SECRET_HEADER: &str = "x-telegram-bot-api-secret-token";
+
+/// Longest text `sendMessage` accepts, in characters
+pub const TELEGRAM_MAX_MESSAGE_LENGTH: usize = 4096;
+
+/// Telegram bot configuration of an inbox
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InboxTelegramConfig {
+    pub id: String,
+    pub inbox_id: String,
+    pub bot_token: String, // Encrypted at rest using AES-256-GCM
+    /// Bot's @username, filled in from `getMe` when the bot is connected
+    pub bot_username: Option<String>,
+    /// Secret Telegram sends back with every webhook request
+    pub webhook_secret: String,
+    pub enabled: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl InboxTelegramConfig {
+    pub fn new(inbox_id: String, request: CreateInboxTelegramConfigRequest) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            inbox_id,
+            bot_token: request.bot_token.trim().to_string(),
+            bot_username: None,
+            webhook_secret: generate_secret(),
+            enabled: request.enabled.unwrap_or(true),
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        validate_bot_token(&self.bot_token)
+    }
+}
+
+/// Webhook secret token: Telegram allows up to 256 characters of `A-Za-z0-9_-`
+/// Bot tokens issued by @BotFather look like `123456789:AAH...`
+pub fn validate_bot_token(token: &str) -> Result<(), String> {
+    let valid = token.split_once(':').is_some_and(|(bot_id, secret)| {
+        !bot_id.is_empty()
+            && bot_id.chars().all(|c| c.is_ascii_digit())
+            && !secret.is_empty()
+            && secret
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    });
+    if !valid {
+        return Err("Invalid Telegram bot token".to_string());
+    }
+    Ok(())
+}
+
+/// DTO: Connect a Telegram bot to an inbox
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateInboxTelegramConfigRequest {
+    pub bot_token: String,
+    pub enabled: Option<bool>,
+}
+
+impl Validate for CreateInboxTelegramConfigRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if let Err(e) = validate_bot_token(self.bot_token.trim()) {
+            errors.add("bot_token", e);
+        }
+    }
+}
+
+/// DTO: Update an inbox's Telegram configuration
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpdateInboxTelegramConfigRequest {
+    pub bot_token: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+impl Validate for UpdateInboxTelegramConfigRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if let Some(Err(e)) = self
+            .bot_token
+            .as_deref()
+            .map(|bot_token| validate_bot_token(bot_token.trim()))
+        {
+            errors.add("bot_token", e);
+        }
+    }
+}
+
+/// Incoming update posted to the bot webhook
+///
+/// Only the fields the inbox uses are modelled; other update kinds (edits,
+/// callback queries, ...) deserialize with `message` unset and are ignored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelegramUpdate {
+    pub update_id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<TelegramMessage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelegramMessage {
+    pub message_id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<TelegramUser>,
+    pub chat: TelegramChat,
+    pub date: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    /// Available sizes of a photo, smallest first
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub photo: Option<Vec<TelegramPhotoSize>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document: Option<TelegramDocument>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelegramUser {
+    pub id: i64,
+    #[serde(default)]
+    pub is_bot: bool,
+    pub first_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+impl TelegramUser {
+    pub fn full_name(&self) -> String {
+        match &self.last_name {
+            Some(last_name) => format!("{} {}", self.first_name, last_name),
+            None => self.first_name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelegramChat {
+    pub id: i64,
+    /// `private`, `group`, `supergroup` or `channel`
+    #[serde(rename = "type")]
+    pub chat_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelegramPhotoSize {
+    pub file_id: String,
+    pub file_unique_id: String,
+    pub width: i64,
+    pub height: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelegramDocument {
+    pub file_id: String,
+    pub file_unique_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_size: Option<i64>,
+}
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::domain::entities::ContactNote;
+use crate::shared::validation::{Validate, ValidationErrors};
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(type_name = "TEXT", rename_all = "lowercase")]
 pub enum UserType {
@@ -98,6 +101,8 @@ pub struct ContactChannel {
     pub contact_id: String,
     pub inbox_id: String,
     pub email: String,
+    pub email_verified: bool,
+    pub email_verified_at: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -114,6 +119,15 @@ pub struct CreateAgentRequest {
     pub role_id: Option<String>, // Optional, defaults to "Agent" role if not provided
 }
 
+impl Validate for CreateAgentRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.email("email", &self.email);
+        errors.length("first_name", &self.first_name, 1, 100);
+        errors.max_length("last_name", self.last_name.as_deref(), 100);
+        errors.optional_length("role_id", self.role_id.as_deref(), 1, 255);
+    }
+}
+
 /// Create Agent Response (Feature 016: User Creation)
 /// Returns agent details with plaintext password (shown only once)
 #[derive(Debug, Serialize)]
@@ -131,6 +145,76 @@ pub struct CreateAgentResponse {
     pub created_at: String,
 }
 
+/// Most addresses accepted by one bulk agent request
+pub const MAX_BULK_AGENT_EMAILS: usize = 100;
+
+/// Bulk agent invitation request
+///
+/// Every address becomes an agent with `role_id` (the Agent role when
+/// omitted), joins `team_id` when given, and is emailed an invitation to
+/// choose a password.
+#[derive(Debug, Deserialize)]
+pub struct BulkCreateAgentsRequest {
+    pub emails: Vec<String>,
+    pub role_id: Option<String>,
+    pub team_id: Option<String>,
+}
+
+impl Validate for BulkCreateAgentsRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.items("emails", &self.emails, 1, 255);
+        if self.emails.len() > MAX_BULK_AGENT_EMAILS {
+            errors.add(
+                "emails",
+                format!("must have at most {} entries", MAX_BULK_AGENT_EMAILS),
+            );
+        }
+        errors.optional_length("role_id", self.role_id.as_deref(), 1, 255);
+        errors.optional_length("team_id", self.team_id.as_deref(), 1, 255);
+    }
+}
+
+/// Outcome of one address in a bulk agent request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkAgentStatus {
+    Invited,
+    Failed,
+}
+
+/// Per-address result of a bulk agent request
+#[derive(Debug, Serialize)]
+pub struct BulkAgentResult {
+    pub email: String,
+    pub status: BulkAgentStatus,
+    pub agent_id: Option<String>,
+    pub user_id: Option<String>,
+    /// Whether the invitation email was handed to the mail sender
+    pub invitation_sent: bool,
+    pub error: Option<String>,
+}
+
+impl BulkAgentResult {
+    pub fn failed(email: String, error: String) -> Self {
+        Self {
+            email,
+            status: BulkAgentStatus::Failed,
+            agent_id: None,
+            user_id: None,
+            invitation_sent: false,
+            error: Some(error),
+        }
+    }
+}
+
+/// Bulk agent response, one result per submitted address in request order
+#[derive(Debug, Serialize)]
+pub struct BulkCreateAgentsResponse {
+    pub results: Vec<BulkAgentResult>,
+    pub invited: usize,
+    pub failed: usize,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateContactRequest {
     pub email: String,
@@ -138,17 +222,40 @@ pub struct CreateContactRequest {
     pub inbox_id: String,
 }
 
+impl Validate for CreateContactRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.email("email", &self.email);
+        errors.max_length("first_name", self.first_name.as_deref(), 100);
+        errors.length("inbox_id", &self.inbox_id, 1, 255);
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UpdateAgentRequest {
     pub first_name: String,
     pub role_ids: Option<Vec<String>>,
 }
 
+impl Validate for UpdateAgentRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("first_name", &self.first_name, 1, 100);
+        if let Some(role_ids) = &self.role_ids {
+            errors.items("role_ids", role_ids, 0, 255);
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct UpdateContactRequest {
     pub first_name: Option<String>,
 }
 
+impl Validate for UpdateContactRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.max_length("first_name", self.first_name.as_deref(), 100);
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ChangePasswordRequest {
     pub new_password: String,
@@ -188,6 +295,12 @@ pub struct ContactResponse {
     pub channels: Vec<ContactChannel>,
     pub created_at: String,
     pub updated_at: String,
+    /// Agents' notes about the contact, pinned first (single-contact responses only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<Vec<ContactNote>>,
+    /// Customer tier used by the priority matrix (single-contact responses only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -296,6 +409,8 @@ impl Contact {
 }
 
 impl ContactChannel {
+    /// Create a channel whose email is known to belong to the contact
+    /// (e.g. the contact wrote to the inbox from it)
     pub fn new(contact_id: String, inbox_id: String, email: String) -> Self {
         let now = time::OffsetDateTime::now_utc()
             .format(&time::format_description::well_known::Rfc3339)
@@ -306,8 +421,19 @@ impl ContactChannel {
             contact_id,
             inbox_id,
             email,
+            email_verified: true,
+            email_verified_at: Some(now.clone()),
             created_at: now.clone(),
             updated_at: now,
         }
     }
+
+    /// Create a channel for a manually entered email that still needs confirmation
+    pub fn new_unverified(contact_id: String, inbox_id: String, email: String) -> Self {
+        Self {
+            email_verified: false,
+            email_verified_at: None,
+            ..Self::new(contact_id, inbox_id, email)
+        }
+    }
 }
@@ -3,6 +3,8 @@ use sqlx::FromRow;
 use std::fmt;
 use uuid::Uuid;
 
+use crate::shared::validation::{Validate, ValidationErrors};
+
 // ============================================================================
 // DeliveryStatus Enum
 // ============================================================================
@@ -74,6 +76,8 @@ pub struct Webhook {
     #[serde(skip_serializing)] // Don't expose secret in API responses
     pub secret: String,
     pub is_active: bool,
+    /// Add a `conversation` snapshot to the data of conversation events
+    pub include_conversation_snapshot: bool,
     pub created_at: String, // ISO 8601
     pub updated_at: String, // ISO 8601
     pub created_by: String,
@@ -96,6 +100,7 @@ impl Webhook {
             subscribed_events,
             secret,
             is_active: true, // Default active
+            include_conversation_snapshot: false,
             created_at: now.clone(),
             updated_at: now,
             created_by,
@@ -129,6 +134,10 @@ impl Webhook {
                 return Err("Event type must be 1-100 characters".to_string());
             }
         }
+        let unknown = unknown_webhook_events(&self.subscribed_events);
+        if !unknown.is_empty() {
+            return Err(format!("Unknown event types: {}", unknown.join(", ")));
+        }
 
         // Secret validation
         if self.secret.len() < 16 || self.secret.len() > 255 {
@@ -149,6 +158,315 @@ impl Webhook {
     }
 }
 
+// ============================================================================
+// Event Catalog
+// ============================================================================
+
+/// An event type webhooks can subscribe to
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookEventType {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Fields of the payload's `data` object with their type: `string`,
+    /// `date-time`, `integer`, `number` or `string[]`; a trailing `?` marks
+    /// fields that can be null
+    pub data: &'static [(&'static str, &'static str)],
+}
+
+/// Every event type delivered to webhooks, in the order they are documented
+///
+/// Subscriptions are checked against this list, so it must name each event
+/// the webhook worker emits.
+pub const WEBHOOK_EVENT_TYPES: &[WebhookEventType] = &[
+    WebhookEventType {
+        name: "conversation.created",
+        description: "A conversation was started",
+        data: &[
+            ("conversation_id", "string"),
+            ("inbox_id", "string"),
+            ("contact_id", "string"),
+            ("status", "string"),
+            ("created_at", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "conversation.status_changed",
+        description: "A conversation moved to another status",
+        data: &[
+            ("conversation_id", "string"),
+            ("old_status", "string"),
+            ("new_status", "string"),
+            ("agent_id", "string?"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "conversation.assigned",
+        description: "A conversation was assigned to an agent or team",
+        data: &[
+            ("conversation_id", "string"),
+            ("assigned_user_id", "string?"),
+            ("assigned_team_id", "string?"),
+            ("assigned_by", "string"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "conversation.unassigned",
+        description: "A conversation's assignee was removed",
+        data: &[
+            ("conversation_id", "string"),
+            ("previous_assigned_user_id", "string?"),
+            ("previous_assigned_team_id", "string?"),
+            ("unassigned_by", "string"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "conversation.tags_changed",
+        description: "Tags were added to or removed from a conversation",
+        data: &[
+            ("conversation_id", "string"),
+            ("previous_tags", "string[]"),
+            ("new_tags", "string[]"),
+            ("changed_by", "string"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "conversation.priority_changed",
+        description: "A conversation's priority was set or cleared",
+        data: &[
+            ("conversation_id", "string"),
+            ("previous_priority", "string?"),
+            ("new_priority", "string?"),
+            ("updated_by", "string"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "conversation.sentiment_dropped",
+        description: "A contact's messages turned markedly more negative",
+        data: &[
+            ("conversation_id", "string"),
+            ("message_id", "string"),
+            ("previous_score", "number"),
+            ("current_score", "number"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "message.received",
+        description: "A contact sent a message",
+        data: &[
+            ("message_id", "string"),
+            ("conversation_id", "string"),
+            ("contact_id", "string"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "message.sent",
+        description: "An agent's reply was delivered",
+        data: &[
+            ("message_id", "string"),
+            ("conversation_id", "string"),
+            ("agent_id", "string"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "message.failed",
+        description: "An agent's reply could not be delivered",
+        data: &[
+            ("message_id", "string"),
+            ("conversation_id", "string"),
+            ("retry_count", "integer"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "sla.breached",
+        description: "An SLA deadline passed without being met",
+        data: &[
+            ("event_id", "string"),
+            ("applied_sla_id", "string"),
+            ("conversation_id", "string"),
+            ("event_type", "string"),
+            ("deadline_at", "date-time"),
+            ("breached_at", "date-time"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "csat.received",
+        description: "A contact answered a satisfaction survey",
+        data: &[
+            ("csat_response_id", "string"),
+            ("conversation_id", "string"),
+            ("score", "integer"),
+            ("comment", "string?"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "agent.availability_changed",
+        description: "An agent went online, away or offline",
+        data: &[
+            ("agent_id", "string"),
+            ("old_status", "string"),
+            ("new_status", "string"),
+            ("reason", "string"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "agent.logged_in",
+        description: "An agent logged in",
+        data: &[
+            ("agent_id", "string"),
+            ("user_id", "string"),
+            ("timestamp", "date-time"),
+        ],
+    },
+    WebhookEventType {
+        name: "agent.logged_out",
+        description: "An agent logged out",
+        data: &[
+            ("agent_id", "string"),
+            ("user_id", "string"),
+            ("timestamp", "date-time"),
+        ],
+    },
+];
+
+/// Look up an event type in the catalog
+pub fn find_webhook_event_type(name: &str) -> Option<&'static WebhookEventType> {
+    WEBHOOK_EVENT_TYPES.iter().find(|event| event.name == name)
+}
+
+/// Subscribed events that are not in the catalog
+pub fn unknown_webhook_events(events: &[String]) -> Vec<&str> {
+    events
+        .iter()
+        .map(String::as_str)
+        .filter(|event| find_webhook_event_type(event).is_none())
+        .collect()
+}
+
+impl WebhookEventType {
+    /// Whether the event is about a conversation, so its payload can carry a
+    /// conversation snapshot
+    pub fn has_conversation(&self) -> bool {
+        self.data
+            .iter()
+            .any(|(field, _)| *field == "conversation_id")
+    }
+
+    /// JSON Schema of the payload delivered for this event
+    pub fn payload_schema(&self) -> serde_json::Value {
+        let mut properties: serde_json::Map<String, serde_json::Value> = self
+            .data
+            .iter()
+            .map(|(field, kind)| (field.to_string(), field_schema(kind)))
+            .collect();
+        if self.has_conversation() {
+            properties.insert("conversation".to_string(), ConversationSnapshot::schema());
+        }
+        let required: Vec<&str> = self.data.iter().map(|(field, _)| *field).collect();
+
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "event_type": { "const": self.name },
+                "timestamp": { "type": "string", "format": "date-time" },
+                "data": {
+                    "type": "object",
+                    "properties": properties,
+                    "required": required,
+                },
+            },
+            "required": ["event_type", "timestamp", "data"],
+        })
+    }
+}
+
+fn field_schema(kind: &str) -> serde_json::Value {
+    let (kind, nullable) = match kind.strip_suffix('?') {
+        Some(kind) => (kind, true),
+        None => (kind, false),
+    };
+    let mut schema = match kind {
+        "date-time" => serde_json::json!({ "type": "string", "format": "date-time" }),
+        "string[]" => serde_json::json!({ "type": "array", "items": { "type": "string" } }),
+        other => serde_json::json!({ "type": other }),
+    };
+    if nullable {
+        let kind = schema["type"].clone();
+        schema["type"] = serde_json::json!([kind, "null"]);
+    }
+    schema
+}
+
+// ============================================================================
+// Conversation Snapshot
+// ============================================================================
+
+/// Contact details included in a conversation snapshot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotContact {
+    pub id: String,
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+/// Current state of a conversation, added to the payload of webhooks that
+/// opt in so receivers don't have to call back for it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationSnapshot {
+    pub id: String,
+    pub reference_number: i64,
+    pub status: String,
+    pub priority: Option<String>,
+    /// Tag names, sorted
+    pub tags: Vec<String>,
+    pub assigned_user_id: Option<String>,
+    pub assigned_user_name: Option<String>,
+    pub assigned_team_id: Option<String>,
+    pub contact: SnapshotContact,
+}
+
+impl ConversationSnapshot {
+    /// JSON Schema of the `conversation` field (only sent to webhooks with
+    /// `include_conversation_snapshot`, so it is never required)
+    fn schema() -> serde_json::Value {
+        let contact: serde_json::Map<String, serde_json::Value> =
+            [("id", "string"), ("name", "string?"), ("email", "string?")]
+                .iter()
+                .map(|(field, kind)| (field.to_string(), field_schema(kind)))
+                .collect();
+        let mut properties: serde_json::Map<String, serde_json::Value> = [
+            ("id", "string"),
+            ("reference_number", "integer"),
+            ("status", "string"),
+            ("priority", "string?"),
+            ("tags", "string[]"),
+            ("assigned_user_id", "string?"),
+            ("assigned_user_name", "string?"),
+            ("assigned_team_id", "string?"),
+        ]
+        .iter()
+        .map(|(field, kind)| (field.to_string(), field_schema(kind)))
+        .collect();
+        properties.insert(
+            "contact".to_string(),
+            serde_json::json!({ "type": "object", "properties": contact }),
+        );
+
+        serde_json::json!({ "type": "object", "properties": properties })
+    }
+}
+
 // ============================================================================
 // WebhookDelivery Model
 // ============================================================================
@@ -250,6 +568,127 @@ impl WebhookDelivery {
     }
 }
 
+// ============================================================================
+// WebhookBackfill Model
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookBackfillStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl WebhookBackfillStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookBackfillStatus::Pending => "pending",
+            WebhookBackfillStatus::Running => "running",
+            WebhookBackfillStatus::Completed => "completed",
+            WebhookBackfillStatus::Failed => "failed",
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            WebhookBackfillStatus::Completed | WebhookBackfillStatus::Failed
+        )
+    }
+}
+
+impl fmt::Display for WebhookBackfillStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<String> for WebhookBackfillStatus {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "running" => WebhookBackfillStatus::Running,
+            "completed" => WebhookBackfillStatus::Completed,
+            "failed" => WebhookBackfillStatus::Failed,
+            _ => WebhookBackfillStatus::Pending,
+        }
+    }
+}
+
+/// Replay of past events into one webhook
+///
+/// The events are picked from stored deliveries when the backfill is
+/// created and handed to the delivery queue a batch at a time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookBackfill {
+    pub id: String,
+    pub webhook_id: String,
+    pub event_types: Vec<String>,
+    pub from_time: String, // ISO 8601
+    pub to_time: String,   // ISO 8601
+    pub status: WebhookBackfillStatus,
+    pub total_events: i64,
+    /// Events handed to the delivery queue so far
+    pub processed_events: i64,
+    pub error_message: Option<String>,
+    pub created_by: String,
+    pub created_at: String,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub updated_at: String,
+}
+
+impl WebhookBackfill {
+    pub fn new(
+        webhook_id: String,
+        event_types: Vec<String>,
+        from_time: String,
+        to_time: String,
+        created_by: String,
+    ) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            webhook_id,
+            event_types,
+            from_time,
+            to_time,
+            status: WebhookBackfillStatus::Pending,
+            total_events: 0,
+            processed_events: 0,
+            error_message: None,
+            created_by,
+            created_at: now.clone(),
+            started_at: None,
+            completed_at: None,
+            updated_at: now,
+        }
+    }
+
+    /// Share of events replayed, from 0 to 100
+    pub fn progress_percent(&self) -> i64 {
+        if self.total_events == 0 {
+            return if self.status == WebhookBackfillStatus::Completed {
+                100
+            } else {
+                0
+            };
+        }
+        self.processed_events * 100 / self.total_events
+    }
+}
+
+/// A past event waiting to be replayed by a backfill, in delivery order
+#[derive(Debug, Clone)]
+pub struct WebhookBackfillEvent {
+    pub sequence: i64,
+    pub event_type: String,
+    /// Stored payload envelope, without any conversation snapshot
+    pub payload: String,
+    pub occurred_at: String,
+}
+
 // ============================================================================
 // Request/Response DTOs
 // ============================================================================
@@ -262,6 +701,33 @@ pub struct CreateWebhookRequest {
     pub secret: String,
     #[serde(default)]
     pub is_active: Option<bool>,
+    #[serde(default)]
+    pub include_conversation_snapshot: Option<bool>,
+}
+
+impl Validate for CreateWebhookRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 255);
+        errors.http_url("url", &self.url, 2048);
+        errors.items("subscribed_events", &self.subscribed_events, 1, 100);
+        validate_event_types(errors, "subscribed_events", &self.subscribed_events);
+        errors.length("secret", &self.secret, 16, 255);
+    }
+}
+
+/// Typos would otherwise subscribe to an event that never fires
+fn validate_event_types(errors: &mut ValidationErrors, field: &str, events: &[String]) {
+    for event in unknown_webhook_events(events) {
+        if !event.is_empty() {
+            errors.add(
+                field,
+                format!(
+                    "unknown event type '{}', see GET /api/webhooks/events",
+                    event
+                ),
+            );
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -271,6 +737,22 @@ pub struct UpdateWebhookRequest {
     pub subscribed_events: Option<Vec<String>>,
     pub secret: Option<String>,
     pub is_active: Option<bool>,
+    #[serde(default)]
+    pub include_conversation_snapshot: Option<bool>,
+}
+
+impl Validate for UpdateWebhookRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("name", self.name.as_deref(), 1, 255);
+        if let Some(url) = &self.url {
+            errors.http_url("url", url, 2048);
+        }
+        if let Some(subscribed_events) = &self.subscribed_events {
+            errors.items("subscribed_events", subscribed_events, 1, 100);
+            validate_event_types(errors, "subscribed_events", subscribed_events);
+        }
+        errors.optional_length("secret", self.secret.as_deref(), 16, 255);
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -280,6 +762,7 @@ pub struct WebhookResponse {
     pub url: String,
     pub subscribed_events: Vec<String>,
     pub is_active: bool,
+    pub include_conversation_snapshot: bool,
     pub created_at: String,
     pub updated_at: String,
     pub created_by: String,
@@ -294,6 +777,7 @@ impl From<Webhook> for WebhookResponse {
             url: webhook.url,
             subscribed_events: webhook.subscribed_events,
             is_active: webhook.is_active,
+            include_conversation_snapshot: webhook.include_conversation_snapshot,
             created_at: webhook.created_at,
             updated_at: webhook.updated_at,
             created_by: webhook.created_by,
@@ -351,3 +835,64 @@ pub struct DeliveryListResponse {
     pub deliveries: Vec<DeliveryResponse>,
     pub total: i64,
 }
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookEventTypeResponse {
+    pub name: String,
+    pub description: String,
+    /// JSON Schema of the delivered payload
+    pub schema: serde_json::Value,
+}
+
+impl From<&WebhookEventType> for WebhookEventTypeResponse {
+    fn from(event: &WebhookEventType) -> Self {
+        Self {
+            name: event.name.to_string(),
+            description: event.description.to_string(),
+            schema: event.payload_schema(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookEventCatalogResponse {
+    pub events: Vec<WebhookEventTypeResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookBackfillRequest {
+    /// Start of the range to replay (RFC 3339)
+    pub from: String,
+    /// End of the range (RFC 3339); defaults to now
+    #[serde(default)]
+    pub to: Option<String>,
+    /// Defaults to every event the webhook subscribes to
+    #[serde(default)]
+    pub event_types: Option<Vec<String>>,
+}
+
+impl Validate for CreateWebhookBackfillRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("from", &self.from, 1, 64);
+        if let Some(event_types) = &self.event_types {
+            errors.items("event_types", event_types, 1, 100);
+            validate_event_types(errors, "event_types", event_types);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookBackfillResponse {
+    #[serde(flatten)]
+    pub backfill: WebhookBackfill,
+    pub progress_percent: i64,
+}
+
+impl From<WebhookBackfill> for WebhookBackfillResponse {
+    fn from(backfill: WebhookBackfill) -> Self {
+        Self {
+            progress_percent: backfill.progress_percent(),
+            backfill,
+        }
+    }
+}
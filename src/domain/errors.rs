@@ -1,17 +1,485 @@
 use thiserror::Error;
 
+use crate::domain::entities::QueryParseError;
+use crate::shared::validation::ValidationErrors;
+
 #[derive(Error, Debug)]
 pub enum DomainError {
     #[error("Entity not found: {0}")]
     NotFound(String),
     #[error("Validation error: {0}")]
     ValidationError(String),
+    /// Per-field failures, kept apart so they reach the client field by field
+    #[error("Validation failed: {0}")]
+    Validation(ValidationErrors),
     #[error("Conflict: {0}")]
     Conflict(String),
     #[error("Internal error: {0}")]
     Internal(String),
     #[error("Forbidden: {0}")]
     Forbidden(String),
+    #[error("Unauthorized")]
+    Unauthorized,
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
 }
 
 pub type DomainResult<T> = Result<T, DomainError>;
+
+/// Errors returned by `TagService`
+#[derive(Error, Debug)]
+pub enum TagError {
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error("Tag with name '{0}' already exists")]
+    AlreadyExists(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `TeamService`
+#[derive(Error, Debug)]
+pub enum TeamError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `InboxService`
+#[derive(Error, Debug)]
+pub enum InboxError {
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `WebhookService`
+#[derive(Error, Debug)]
+pub enum WebhookError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ConversationPriorityService`
+#[derive(Error, Debug)]
+pub enum PriorityError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `AutoTagService`
+#[derive(Error, Debug)]
+pub enum AutoTagError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `AttachmentRuleService`
+#[derive(Error, Debug)]
+pub enum AttachmentRuleError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `HolidayCalendarService`
+#[derive(Error, Debug)]
+pub enum HolidayCalendarError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `CsatService`
+#[derive(Error, Debug)]
+pub enum CsatError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ShiftService`
+#[derive(Error, Debug)]
+pub enum ShiftError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ResponseReminderService`
+#[derive(Error, Debug)]
+pub enum ResponseReminderError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `SavedViewService`
+#[derive(Error, Debug)]
+pub enum SavedViewError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("A view named '{0}' already exists")]
+    AlreadyExists(String),
+    #[error(transparent)]
+    InvalidQuery(#[from] QueryParseError),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ConversationTransferService`
+#[derive(Error, Debug)]
+pub enum TransferError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Validation(String),
+    /// The transfer was already answered, or another one is pending
+    #[error("{0}")]
+    Conflict(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ConversationContactService`
+#[derive(Error, Debug)]
+pub enum ConversationContactError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Validation(String),
+    /// The contact already takes part in the conversation
+    #[error("{0}")]
+    Conflict(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `MessageReactionService`
+#[derive(Error, Debug)]
+pub enum ReactionError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ConfigBundleService`
+#[derive(Error, Debug)]
+pub enum ConfigBundleError {
+    #[error("{0}")]
+    Validation(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `SentimentService`
+#[derive(Error, Debug)]
+pub enum SentimentError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `SystemConfigService`
+#[derive(Error, Debug)]
+pub enum SystemConfigError {
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ChannelService`
+#[derive(Error, Debug)]
+pub enum ChannelError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `SmsService`
+#[derive(Error, Debug)]
+pub enum SmsError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("Invalid Twilio signature")]
+    InvalidSignature,
+    #[error(transparent)]
+    Channel(#[from] ChannelError),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `TelegramService`
+#[derive(Error, Debug)]
+pub enum TelegramError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error("Invalid Telegram secret token")]
+    InvalidSecret,
+    /// The Bot API rejected a call made while configuring the bot
+    #[error("Telegram API error: {0}")]
+    Api(String),
+    #[error(transparent)]
+    Channel(#[from] ChannelError),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ContactNoteService`
+#[derive(Error, Debug)]
+pub enum ContactNoteError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `GdprService`
+#[derive(Error, Debug)]
+pub enum GdprError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("Contact data has already been erased")]
+    AlreadyErased,
+    #[error("{0}")]
+    InvalidConfirmation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ReportingService`
+#[derive(Error, Debug)]
+pub enum ReportingError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ImportService`
+#[derive(Error, Debug)]
+pub enum ImportError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `MaintenanceService`
+#[derive(Error, Debug)]
+pub enum MaintenanceError {
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `JobService`
+#[derive(Error, Debug)]
+pub enum JobError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    NotDead(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ActivityService`
+#[derive(Error, Debug)]
+pub enum ActivityError {
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `JunkService`
+#[derive(Error, Debug)]
+pub enum JunkError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `ApiKeyUsageService`
+#[derive(Error, Debug)]
+pub enum ApiKeyUsageError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `PriorityMatrixService`
+#[derive(Error, Debug)]
+pub enum PriorityMatrixError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Validation(String),
+    #[error("{0}")]
+    Conflict(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `EmailParticipantService`
+#[derive(Error, Debug)]
+pub enum EmailParticipantError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `EmailOAuthService`
+#[derive(Error, Debug)]
+pub enum EmailOAuthError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Validation(String),
+    /// Unknown, used or expired authorization state
+    #[error("Invalid or expired authorization state")]
+    InvalidState,
+    /// The mailbox has no usable tokens and must be authorized again
+    #[error("{0}")]
+    NotAuthorized(String),
+    /// The provider's token endpoint rejected a request
+    #[error("OAuth provider error: {0}")]
+    Provider(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+/// Errors returned by `EmailTemplateService`
+#[derive(Error, Debug)]
+pub enum EmailTemplateError {
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    Forbidden(String),
+    #[error("{0}")]
+    Validation(String),
+    /// The inbox's mail server did not take the test email
+    #[error("Test send failed: {0}")]
+    Delivery(String),
+    #[error(transparent)]
+    Repository(#[from] DomainError),
+}
+
+pub type TagResult<T> = Result<T, TagError>;
+pub type TeamResult<T> = Result<T, TeamError>;
+pub type InboxResult<T> = Result<T, InboxError>;
+pub type WebhookResult<T> = Result<T, WebhookError>;
+pub type PriorityResult<T> = Result<T, PriorityError>;
+pub type AutoTagResult<T> = Result<T, AutoTagError>;
+pub type AttachmentRuleResult<T> = Result<T, AttachmentRuleError>;
+pub type HolidayCalendarResult<T> = Result<T, HolidayCalendarError>;
+pub type CsatResult<T> = Result<T, CsatError>;
+pub type ShiftResult<T> = Result<T, ShiftError>;
+pub type ResponseReminderResult<T> = Result<T, ResponseReminderError>;
+pub type SavedViewResult<T> = Result<T, SavedViewError>;
+pub type TransferResult<T> = Result<T, TransferError>;
+pub type ConversationContactResult<T> = Result<T, ConversationContactError>;
+pub type ReactionResult<T> = Result<T, ReactionError>;
+pub type ConfigBundleResult<T> = Result<T, ConfigBundleError>;
+pub type SentimentResult<T> = Result<T, SentimentError>;
+pub type SystemConfigResult<T> = Result<T, SystemConfigError>;
+pub type ChannelResult<T> = Result<T, ChannelError>;
+pub type SmsResult<T> = Result<T, SmsError>;
+pub type TelegramResult<T> = Result<T, TelegramError>;
+pub type ContactNoteResult<T> = Result<T, ContactNoteError>;
+pub type GdprResult<T> = Result<T, GdprError>;
+pub type ReportingResult<T> = Result<T, ReportingError>;
+pub type ImportResult<T> = Result<T, ImportError>;
+pub type MaintenanceResult<T> = Result<T, MaintenanceError>;
+pub type JobResult<T> = Result<T, JobError>;
+pub type ActivityResult<T> = Result<T, ActivityError>;
+pub type JunkResult<T> = Result<T, JunkError>;
+pub type ApiKeyUsageResult<T> = Result<T, ApiKeyUsageError>;
+pub type PriorityMatrixResult<T> = Result<T, PriorityMatrixError>;
+pub type EmailParticipantResult<T> = Result<T, EmailParticipantError>;
+pub type EmailOAuthResult<T> = Result<T, EmailOAuthError>;
+pub type EmailTemplateResult<T> = Result<T, EmailTemplateError>;
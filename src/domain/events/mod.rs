@@ -89,4 +89,18 @@ pub enum SystemEvent {
         breached_at: String, // ISO 8601
         timestamp: String,   // ISO 8601
     },
+    CsatReceived {
+        csat_response_id: String,
+        conversation_id: String,
+        score: i32,
+        comment: Option<String>,
+        timestamp: String, // ISO 8601
+    },
+    SentimentDropped {
+        conversation_id: String,
+        message_id: String,
+        previous_score: f64,
+        current_score: f64,
+        timestamp: String, // ISO 8601
+    },
 }
@@ -0,0 +1,15 @@
+use crate::domain::entities::{ActivityItem, ActivityType};
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Read-only view over the records that make up the admin activity feed
+#[async_trait::async_trait]
+pub trait ActivityRepository: Send + Sync {
+    /// Entries of the given types that happened before `before` (or at any
+    /// time), newest first
+    async fn list_activity(
+        &self,
+        types: &[ActivityType],
+        before: Option<&str>,
+        limit: i64,
+    ) -> ApiResult<Vec<ActivityItem>>;
+}
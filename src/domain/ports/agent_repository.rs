@@ -18,6 +18,7 @@ pub trait AgentRepository: Send + Sync {
         role_id: &str,
     ) -> ApiResult<(String, String)>;
     async fn get_agent_by_user_id(&self, user_id: &str) -> ApiResult<Option<Agent>>;
+    async fn get_agent_by_id(&self, agent_id: &str) -> ApiResult<Option<Agent>>;
     // List agents with pagination (348-401 in view, originally 451)
     async fn list_agents(&self, limit: i64, offset: i64) -> ApiResult<Vec<(User, Agent)>>;
     // Count total agents
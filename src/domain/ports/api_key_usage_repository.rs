@@ -0,0 +1,34 @@
+use crate::domain::entities::{ApiKeyUsageBucket, ApiKeyUsageSummary};
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Rolling hourly usage counters per API key
+#[async_trait::async_trait]
+pub trait ApiKeyUsageRepository: Send + Sync {
+    /// Count one request against the key's bucket for `hour`
+    async fn record_api_key_request(
+        &self,
+        api_key: &str,
+        agent_id: &str,
+        hour: &str,
+        status: u16,
+        ip: Option<&str>,
+        seen_at: &str,
+    ) -> ApiResult<()>;
+
+    /// Hourly buckets for a key from `since_hour` on, oldest first
+    async fn list_api_key_usage(
+        &self,
+        api_key: &str,
+        since_hour: &str,
+    ) -> ApiResult<Vec<ApiKeyUsageBucket>>;
+
+    /// Agent the key's usage was recorded for, if it was ever used
+    async fn get_api_key_usage_owner(&self, api_key: &str) -> ApiResult<Option<String>>;
+
+    /// Per-key totals from `since_hour` on, busiest keys first
+    async fn summarize_api_key_usage(&self, since_hour: &str)
+        -> ApiResult<Vec<ApiKeyUsageSummary>>;
+
+    /// Drop buckets older than `before_hour`, returning how many were removed
+    async fn delete_api_key_usage_before(&self, before_hour: &str) -> ApiResult<u64>;
+}
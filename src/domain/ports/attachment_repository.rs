@@ -8,6 +8,10 @@ pub trait AttachmentRepository: Send + Sync {
         attachment: &MessageAttachment,
     ) -> ApiResult<MessageAttachment>;
     async fn get_message_attachments(&self, message_id: &str) -> ApiResult<Vec<MessageAttachment>>;
+    async fn get_attachment_by_id(
+        &self,
+        attachment_id: &str,
+    ) -> ApiResult<Option<MessageAttachment>>;
     // Defined in implementation but maybe should be part of trait if we want full abstraction for AttachmentService?
     // AttachmentService uses: create_message_attachment, get_message_attachments
 }
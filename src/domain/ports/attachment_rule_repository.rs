@@ -0,0 +1,22 @@
+use crate::domain::entities::AttachmentRule;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for inbox-level attachment type rules
+#[async_trait::async_trait]
+pub trait AttachmentRuleRepository: Send + Sync {
+    async fn create_attachment_rule(&self, rule: &AttachmentRule) -> ApiResult<()>;
+
+    async fn get_attachment_rule(&self, id: &str) -> ApiResult<Option<AttachmentRule>>;
+
+    async fn list_attachment_rules(&self, inbox_id: &str) -> ApiResult<Vec<AttachmentRule>>;
+
+    /// List only enabled rules for an inbox (used at ingestion time)
+    async fn list_enabled_attachment_rules(
+        &self,
+        inbox_id: &str,
+    ) -> ApiResult<Vec<AttachmentRule>>;
+
+    async fn update_attachment_rule(&self, rule: &AttachmentRule) -> ApiResult<()>;
+
+    async fn delete_attachment_rule(&self, id: &str) -> ApiResult<()>;
+}
@@ -0,0 +1,19 @@
+use crate::domain::entities::AutoTagRule;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for inbox-level auto-tag keyword rules
+#[async_trait::async_trait]
+pub trait AutoTagRepository: Send + Sync {
+    async fn create_auto_tag_rule(&self, rule: &AutoTagRule) -> ApiResult<()>;
+
+    async fn get_auto_tag_rule(&self, id: &str) -> ApiResult<Option<AutoTagRule>>;
+
+    async fn list_auto_tag_rules(&self, inbox_id: &str) -> ApiResult<Vec<AutoTagRule>>;
+
+    /// List only enabled rules for an inbox (used at ingestion time)
+    async fn list_enabled_auto_tag_rules(&self, inbox_id: &str) -> ApiResult<Vec<AutoTagRule>>;
+
+    async fn update_auto_tag_rule(&self, rule: &AutoTagRule) -> ApiResult<()>;
+
+    async fn delete_auto_tag_rule(&self, id: &str) -> ApiResult<()>;
+}
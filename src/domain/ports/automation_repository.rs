@@ -1,5 +1,7 @@
 use crate::infrastructure::http::middleware::error::ApiResult;
-use crate::domain::entities::{AutomationRule, RuleEvaluationLog};
+use crate::domain::entities::{
+    AutomationRule, PendingRuleAction, PendingRuleActionStatus, RuleEvaluationLog,
+};
 
 /// Repository for automation rule operations
 #[async_trait::async_trait]
@@ -40,4 +42,31 @@ pub trait AutomationRepository: Send + Sync {
         limit: Option<i32>,
         offset: Option<i32>,
     ) -> ApiResult<Vec<RuleEvaluationLog>>;
+
+    /// Count how often a rule executed its action on a conversation since a timestamp
+    async fn count_rule_executions_since(
+        &self,
+        rule_id: &str,
+        conversation_id: &str,
+        since: &str,
+    ) -> ApiResult<i64>;
+
+    /// Record a Wait action's follow-up to run later
+    async fn create_pending_rule_action(&self, pending: &PendingRuleAction) -> ApiResult<()>;
+
+    /// Get pending rule action by ID
+    async fn get_pending_rule_action(&self, id: &str) -> ApiResult<Option<PendingRuleAction>>;
+
+    /// Get rule actions still waiting to run on a conversation
+    async fn get_pending_rule_actions_for_conversation(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<PendingRuleAction>>;
+
+    /// Complete or cancel a pending rule action; false if it was no longer pending
+    async fn finish_pending_rule_action(
+        &self,
+        id: &str,
+        status: PendingRuleActionStatus,
+    ) -> ApiResult<bool>;
 }
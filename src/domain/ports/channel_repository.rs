@@ -0,0 +1,53 @@
+use crate::domain::entities::Contact;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for external channel sender identities and ingested message IDs
+#[async_trait::async_trait]
+pub trait ChannelRepository: Send + Sync {
+    /// Find the contact mapped to a channel sender in an inbox
+    async fn find_channel_contact(
+        &self,
+        inbox_id: &str,
+        external_id: &str,
+    ) -> ApiResult<Option<Contact>>;
+
+    /// Find the most recently mapped channel sender ID of a contact in an inbox
+    async fn find_channel_external_id(
+        &self,
+        inbox_id: &str,
+        contact_id: &str,
+    ) -> ApiResult<Option<String>>;
+
+    async fn create_channel_identity(
+        &self,
+        inbox_id: &str,
+        external_id: &str,
+        contact_id: &str,
+    ) -> ApiResult<()>;
+
+    /// Give a placeholder contact its real email (and name, if provided)
+    async fn rename_channel_contact(
+        &self,
+        contact: &Contact,
+        email: &str,
+        name: Option<&str>,
+    ) -> ApiResult<()>;
+
+    /// Move a placeholder contact's identities, conversations and messages to
+    /// `into`, then delete the placeholder
+    async fn merge_channel_contact(&self, from: &Contact, into: &Contact) -> ApiResult<()>;
+
+    /// Find the message created for a channel message ID, if already ingested
+    async fn find_channel_message_id(
+        &self,
+        inbox_id: &str,
+        external_message_id: &str,
+    ) -> ApiResult<Option<String>>;
+
+    async fn record_channel_message(
+        &self,
+        inbox_id: &str,
+        external_message_id: &str,
+        message_id: &str,
+    ) -> ApiResult<()>;
+}
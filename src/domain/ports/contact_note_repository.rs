@@ -0,0 +1,23 @@
+use crate::domain::entities::ContactNote;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for agents' notes about contacts
+#[async_trait::async_trait]
+pub trait ContactNoteRepository: Send + Sync {
+    async fn create_contact_note(&self, note: &ContactNote) -> ApiResult<()>;
+
+    async fn get_contact_note(&self, id: &str) -> ApiResult<Option<ContactNote>>;
+
+    async fn update_contact_note(&self, note: &ContactNote) -> ApiResult<()>;
+
+    async fn delete_contact_note(&self, id: &str) -> ApiResult<()>;
+
+    /// A contact's notes, pinned first (most recently pinned on top), then newest first
+    async fn list_contact_notes(&self, contact_id: &str) -> ApiResult<Vec<ContactNote>>;
+
+    /// Pinned notes of the contact a conversation belongs to, most recently pinned first
+    async fn list_conversation_pinned_notes(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<ContactNote>>;
+}
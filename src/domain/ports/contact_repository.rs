@@ -1,10 +1,12 @@
 use crate::infrastructure::http::middleware::error::ApiResult;
-use crate::domain::entities::{Contact, ContactChannel, User};
+use crate::domain::entities::{Contact, ContactChannel, ContactEmailVerification, User};
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait ContactRepository: Send + Sync {
     async fn create_contact(&self, contact: &Contact) -> ApiResult<()>;
+    /// Look up a contact by its contacts-table id, as stored on conversations
+    async fn find_contact_by_id(&self, id: &str) -> ApiResult<Option<Contact>>;
     async fn find_contact_by_user_id(&self, user_id: &str) -> ApiResult<Option<Contact>>;
     async fn create_contact_channel(&self, channel: &ContactChannel) -> ApiResult<()>;
     async fn get_contact_by_email(&self, email: &str) -> ApiResult<Option<Contact>>;
@@ -19,4 +21,22 @@ pub trait ContactRepository: Send + Sync {
     async fn delete_contact(&self, contact_id: &str) -> ApiResult<()>;
     async fn list_contacts(&self, limit: i64, offset: i64) -> ApiResult<Vec<(User, Contact)>>;
     async fn count_contacts(&self) -> ApiResult<i64>;
+
+    // Contact email verification
+    async fn get_contact_channel_by_id(&self, channel_id: &str) -> ApiResult<Option<ContactChannel>>;
+    async fn create_email_verification(
+        &self,
+        verification: &ContactEmailVerification,
+    ) -> ApiResult<()>;
+    async fn get_email_verification_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> ApiResult<Option<ContactEmailVerification>>;
+    /// Consume the token and mark its channel verified in one transaction
+    async fn mark_channel_email_verified(
+        &self,
+        verification_id: &str,
+        channel_id: &str,
+        verified_at: &str,
+    ) -> ApiResult<()>;
 }
@@ -0,0 +1,28 @@
+use crate::domain::entities::ConversationContact;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for the contacts taking part in a conversation
+#[async_trait::async_trait]
+pub trait ConversationContactRepository: Send + Sync {
+    /// Add a participant; returns false if the contact already takes part
+    /// (as primary or additional contact)
+    async fn add_conversation_contact(
+        &self,
+        conversation_id: &str,
+        contact_id: &str,
+        added_by: Option<&str>,
+    ) -> ApiResult<bool>;
+
+    /// Remove an additional participant; returns false if it wasn't one
+    async fn remove_conversation_contact(
+        &self,
+        conversation_id: &str,
+        contact_id: &str,
+    ) -> ApiResult<bool>;
+
+    /// The primary contact followed by the others, oldest first
+    async fn list_conversation_contacts(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<ConversationContact>>;
+}
@@ -1,6 +1,7 @@
 use crate::infrastructure::http::middleware::error::ApiResult;
 use crate::domain::entities::{
-    AssignmentHistory, Conversation, ConversationStatus, CreateConversation, Priority,
+    AssignmentHistory, Conversation, ConversationCountScope, ConversationCounts, ConversationEvent,
+    ConversationListFilter, ConversationStatus, CreateConversation, Priority,
 };
 
 #[async_trait::async_trait]
@@ -33,17 +34,20 @@ pub trait ConversationRepository: Send + Sync {
         &self,
         limit: i64,
         offset: i64,
-        status: Option<ConversationStatus>,
-        inbox_id: Option<String>,
-        contact_id: Option<String>,
+        filter: &ConversationListFilter,
     ) -> ApiResult<Vec<Conversation>>;
 
-    async fn count_conversations(
+    async fn count_conversations(&self, filter: &ConversationListFilter) -> ApiResult<i64>;
+
+    /// Sidebar counters over open conversations in `scope`, in one query;
+    /// SLA deadlines between `now` and `breaching_before` count as breaching soon
+    async fn count_open_conversations_for_sidebar(
         &self,
-        status: Option<ConversationStatus>,
-        inbox_id: Option<String>,
-        contact_id: Option<String>,
-    ) -> ApiResult<i64>;
+        user_id: &str,
+        scope: &ConversationCountScope,
+        now: &str,
+        breaching_before: &str,
+    ) -> ApiResult<ConversationCounts>;
 
     async fn set_conversation_priority(
         &self,
@@ -61,6 +65,15 @@ pub trait ConversationRepository: Send + Sync {
         assigned_by: Option<String>,
     ) -> ApiResult<()>;
 
+    /// Assign to `user_id` only if the conversation has no assignee yet;
+    /// returns whether this call assigned it
+    async fn claim_unassigned_conversation(
+        &self,
+        conversation_id: &str,
+        user_id: &str,
+        assigned_by: Option<String>,
+    ) -> ApiResult<bool>;
+
     async fn assign_conversation_to_team(
         &self,
         conversation_id: &str,
@@ -106,6 +119,12 @@ pub trait ConversationRepository: Send + Sync {
         conversation_id: &str,
     ) -> ApiResult<Vec<AssignmentHistory>>;
 
+    /// Append-only history of state changes, oldest first
+    async fn get_conversation_events(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<ConversationEvent>>;
+
     async fn find_contact_by_user_id(
         &self,
         user_id: &str,
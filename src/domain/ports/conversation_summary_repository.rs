@@ -0,0 +1,11 @@
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for the rolling summary shown as a conversation's list preview
+#[async_trait::async_trait]
+pub trait ConversationSummaryRepository: Send + Sync {
+    async fn update_conversation_summary(
+        &self,
+        conversation_id: &str,
+        summary: &str,
+    ) -> ApiResult<()>;
+}
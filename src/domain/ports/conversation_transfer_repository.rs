@@ -0,0 +1,24 @@
+use crate::domain::entities::{ConversationTransfer, TransferStatus};
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for conversation transfers between agents and teams
+#[async_trait::async_trait]
+pub trait ConversationTransferRepository: Send + Sync {
+    /// Insert a transfer; a second pending transfer for the same
+    /// conversation is rejected with `ApiError::Conflict`
+    async fn create_transfer(&self, transfer: &ConversationTransfer) -> ApiResult<()>;
+
+    async fn get_transfer(&self, id: &str) -> ApiResult<Option<ConversationTransfer>>;
+
+    /// A conversation's transfers, newest first
+    async fn list_transfers(&self, conversation_id: &str) -> ApiResult<Vec<ConversationTransfer>>;
+
+    /// Answer a pending transfer; returns false if it was no longer pending
+    async fn respond_to_transfer(
+        &self,
+        id: &str,
+        status: TransferStatus,
+        responded_by: &str,
+        responded_at: &str,
+    ) -> ApiResult<bool>;
+}
@@ -0,0 +1,11 @@
+use crate::domain::entities::CsatResponse;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for conversation CSAT responses
+#[async_trait::async_trait]
+pub trait CsatRepository: Send + Sync {
+    async fn create_csat_response(&self, response: &CsatResponse) -> ApiResult<()>;
+
+    /// List a conversation's responses, newest first
+    async fn list_csat_responses(&self, conversation_id: &str) -> ApiResult<Vec<CsatResponse>>;
+}
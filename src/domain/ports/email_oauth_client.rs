@@ -0,0 +1,15 @@
+use crate::domain::entities::OAuthTokenGrant;
+
+/// Calls to a mail provider's OAuth2 token endpoint
+///
+/// Errors are the provider's description of the failure.
+#[async_trait::async_trait]
+pub trait EmailOAuthClient: Send + Sync {
+    /// POST a form-encoded grant (`authorization_code` or `refresh_token`)
+    /// to `token_url`
+    async fn request_token(
+        &self,
+        token_url: &str,
+        params: &[(&str, &str)],
+    ) -> Result<OAuthTokenGrant, String>;
+}
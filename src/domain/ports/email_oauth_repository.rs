@@ -0,0 +1,19 @@
+use crate::domain::entities::{EmailOAuthState, EmailOAuthToken};
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for inbox mailbox OAuth2 tokens and pending authorizations
+#[async_trait::async_trait]
+pub trait EmailOAuthRepository: Send + Sync {
+    async fn create_email_oauth_state(&self, state: &EmailOAuthState) -> ApiResult<()>;
+
+    /// Retrieve and delete a pending authorization, so it can be used once
+    async fn consume_email_oauth_state(&self, state: &str) -> ApiResult<Option<EmailOAuthState>>;
+
+    /// Get an inbox's tokens, decrypted
+    async fn get_email_oauth_token(&self, inbox_id: &str) -> ApiResult<Option<EmailOAuthToken>>;
+
+    /// Insert or replace an inbox's tokens
+    async fn save_email_oauth_token(&self, token: &EmailOAuthToken) -> ApiResult<()>;
+
+    async fn delete_email_oauth_token(&self, inbox_id: &str) -> ApiResult<()>;
+}
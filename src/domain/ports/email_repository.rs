@@ -1,5 +1,8 @@
 use crate::infrastructure::http::middleware::error::ApiResult;
-use crate::domain::entities::{EmailProcessingLog, InboxEmailConfig, UpdateInboxEmailConfigRequest};
+use crate::domain::entities::{
+    EmailParticipant, EmailProcessingLog, InboxEmailBacklog, InboxEmailConfig,
+    UpdateInboxEmailConfigRequest,
+};
 
 #[async_trait::async_trait]
 pub trait EmailRepository: Send + Sync {
@@ -23,4 +26,20 @@ pub trait EmailRepository: Send + Sync {
         inbox_id: &str,
         email_message_id: &str,
     ) -> ApiResult<bool>;
+    async fn get_email_backlog(&self, inbox_id: &str) -> ApiResult<Option<InboxEmailBacklog>>;
+    async fn save_email_backlog(&self, backlog: &InboxEmailBacklog) -> ApiResult<()>;
+    /// Add an address to a conversation's thread; existing participants are left as they are
+    async fn add_email_participant(&self, conversation_id: &str, email: &str) -> ApiResult<()>;
+    async fn list_email_participants(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<EmailParticipant>>;
+    /// Returns None if the address is not on the conversation's thread
+    async fn set_email_participant_suppressed(
+        &self,
+        conversation_id: &str,
+        email: &str,
+        suppressed: bool,
+        suppressed_by: Option<&str>,
+    ) -> ApiResult<Option<EmailParticipant>>;
 }
@@ -0,0 +1,21 @@
+/// An email sent outside any conversation, e.g. a password reset link
+#[derive(Debug, Clone)]
+pub struct OutgoingEmail {
+    pub to: String,
+    pub subject: String,
+    pub text_body: String,
+    /// HTML alternative to `text_body`, when a template is available
+    pub html_body: Option<String>,
+}
+
+/// Sends transactional emails
+#[async_trait::async_trait]
+pub trait EmailSender: Send + Sync {
+    async fn send(&self, email: &OutgoingEmail) -> Result<(), String>;
+}
+
+/// Sends emails through an inbox's own mail server
+#[async_trait::async_trait]
+pub trait InboxEmailSender: Send + Sync {
+    async fn send_from_inbox(&self, inbox_id: &str, email: &OutgoingEmail) -> Result<(), String>;
+}
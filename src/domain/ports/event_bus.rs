@@ -12,4 +12,10 @@ pub trait EventBus: Send + Sync {
     /// Subscribe to events
     /// Returns a stream of events, abstracting away underlying transport errors
     fn subscribe(&self) -> Pin<Box<dyn Stream<Item = Result<SystemEvent, String>> + Send>>;
+
+    /// Number of published events not yet received by every subscriber
+    /// Used by producers that need to back off while listeners catch up
+    fn pending_events(&self) -> usize {
+        0
+    }
 }
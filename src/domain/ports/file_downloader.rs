@@ -0,0 +1,15 @@
+/// A file fetched from a URL
+#[derive(Debug, Clone)]
+pub struct DownloadedFile {
+    pub content: Vec<u8>,
+    /// Content type reported by the server, if any
+    pub content_type: Option<String>,
+}
+
+/// Fetches files from other services, e.g. attachments of imported tickets
+///
+/// Errors describe the failure without the URL, which may carry credentials.
+#[async_trait::async_trait]
+pub trait FileDownloader: Send + Sync {
+    async fn download(&self, url: &str) -> Result<DownloadedFile, String>;
+}
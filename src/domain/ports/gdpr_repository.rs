@@ -0,0 +1,30 @@
+use crate::domain::entities::{ContactDataExport, ContactErasurePlan, GdprRequest};
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for exporting and erasing contacts' personal data
+#[async_trait::async_trait]
+pub trait GdprRepository: Send + Sync {
+    /// Everything stored about a contact, by the contact's user id
+    async fn get_contact_data_export(&self, user_id: &str) -> ApiResult<Option<ContactDataExport>>;
+
+    /// Complete a pending erasure request and apply its plan in one transaction
+    ///
+    /// Returns the storage paths of removed attachments, or `None` if the
+    /// request was no longer pending.
+    async fn erase_contact_data(
+        &self,
+        request_id: &str,
+        confirmed_by: &str,
+        plan: &ContactErasurePlan,
+    ) -> ApiResult<Option<Vec<String>>>;
+
+    async fn create_gdpr_request(&self, request: &GdprRequest) -> ApiResult<()>;
+
+    async fn get_gdpr_request_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> ApiResult<Option<GdprRequest>>;
+
+    /// A contact's export and erasure records, newest first
+    async fn list_gdpr_requests(&self, contact_id: &str) -> ApiResult<Vec<GdprRequest>>;
+}
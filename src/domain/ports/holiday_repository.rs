@@ -0,0 +1,35 @@
+use crate::domain::entities::{Holiday, HolidayCalendar};
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for holiday calendars and the holidays they contain
+#[async_trait::async_trait]
+pub trait HolidayRepository: Send + Sync {
+    async fn create_holiday_calendar(&self, calendar: &HolidayCalendar) -> ApiResult<()>;
+
+    async fn get_holiday_calendar(&self, id: &str) -> ApiResult<Option<HolidayCalendar>>;
+
+    async fn list_holiday_calendars(&self) -> ApiResult<Vec<HolidayCalendar>>;
+
+    async fn update_holiday_calendar(&self, calendar: &HolidayCalendar) -> ApiResult<()>;
+
+    /// Delete a calendar; its holidays are removed and linked teams are unlinked
+    async fn delete_holiday_calendar(&self, id: &str) -> ApiResult<()>;
+
+    async fn create_holiday(&self, holiday: &Holiday) -> ApiResult<()>;
+
+    async fn get_holiday(&self, id: &str) -> ApiResult<Option<Holiday>>;
+
+    async fn list_calendar_holidays(&self, calendar_id: &str) -> ApiResult<Vec<Holiday>>;
+
+    async fn delete_holiday(&self, id: &str) -> ApiResult<()>;
+
+    async fn delete_calendar_holidays(&self, calendar_id: &str) -> ApiResult<u64>;
+
+    /// Find a holiday on the given YYYY-MM-DD date, checking global holidays
+    /// and (when provided) the holidays of one calendar
+    async fn find_holiday_on(
+        &self,
+        date: &str,
+        calendar_id: Option<&str>,
+    ) -> ApiResult<Option<Holiday>>;
+}
@@ -0,0 +1,43 @@
+use crate::domain::entities::{Import, ImportSource, ImportedConversation, ImportedEntity};
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for helpdesk imports and the records they created
+#[async_trait::async_trait]
+pub trait ImportRepository: Send + Sync {
+    async fn create_import(&self, import: &Import) -> ApiResult<()>;
+
+    /// The import including its export data
+    async fn get_import(&self, id: &str) -> ApiResult<Option<Import>>;
+
+    /// All imports without their export data, newest first
+    async fn list_imports(&self) -> ApiResult<Vec<Import>>;
+
+    /// Store status, progress, counts and errors; the export data is left untouched
+    async fn update_import_progress(&self, import: &Import) -> ApiResult<()>;
+
+    /// Drop the export data once an import has finished
+    async fn clear_import_data(&self, id: &str) -> ApiResult<()>;
+
+    /// Local id of a record created from `external_id` by an earlier import
+    async fn find_imported_record(
+        &self,
+        source: ImportSource,
+        entity: ImportedEntity,
+        external_id: &str,
+    ) -> ApiResult<Option<String>>;
+
+    async fn record_imported(
+        &self,
+        import_id: &str,
+        source: ImportSource,
+        entity: ImportedEntity,
+        external_id: &str,
+        local_id: &str,
+    ) -> ApiResult<()>;
+
+    /// Store a conversation with its messages and tags in one transaction
+    async fn create_imported_conversation(
+        &self,
+        conversation: &ImportedConversation,
+    ) -> ApiResult<()>;
+}
@@ -0,0 +1,32 @@
+use crate::domain::entities::BlockedSender;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Sender reputation and per-inbox blocklists behind junk handling
+///
+/// Sender emails are expected to be normalized with `normalize_sender_email`.
+#[async_trait::async_trait]
+pub trait JunkRepository: Send + Sync {
+    /// Email address of the contact who started the conversation
+    async fn get_conversation_sender_email(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Option<String>>;
+
+    /// Record one more junk marking for the sender, returning the new count
+    async fn increment_sender_junk_count(&self, email: &str) -> ApiResult<i64>;
+
+    /// Take back one junk marking for the sender, returning the new count
+    async fn decrement_sender_junk_count(&self, email: &str) -> ApiResult<i64>;
+
+    async fn get_sender_junk_count(&self, email: &str) -> ApiResult<i64>;
+
+    /// Add the sender to the inbox blocklist; blocking twice is a no-op
+    async fn block_sender(&self, sender: &BlockedSender) -> ApiResult<()>;
+
+    async fn is_sender_blocked(&self, inbox_id: &str, email: &str) -> ApiResult<bool>;
+
+    async fn list_blocked_senders(&self, inbox_id: &str) -> ApiResult<Vec<BlockedSender>>;
+
+    /// Remove a blocklist entry, returning whether it existed
+    async fn delete_blocked_sender(&self, inbox_id: &str, id: &str) -> ApiResult<bool>;
+}
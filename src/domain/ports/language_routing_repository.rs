@@ -0,0 +1,37 @@
+use crate::domain::entities::LanguageRoutingRule;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for language routing rules and member language skills
+#[async_trait::async_trait]
+pub trait LanguageRoutingRepository: Send + Sync {
+    async fn create_language_rule(&self, rule: &LanguageRoutingRule) -> ApiResult<()>;
+
+    async fn list_language_rules(&self) -> ApiResult<Vec<LanguageRoutingRule>>;
+
+    /// Returns false when no rule has this id
+    async fn delete_language_rule(&self, id: &str) -> ApiResult<bool>;
+
+    /// Rule for `language`, or the default-team rule when `language` is None
+    async fn find_language_rule(
+        &self,
+        language: Option<&str>,
+    ) -> ApiResult<Option<LanguageRoutingRule>>;
+
+    /// Whether any member of the team lists `language` among their skills
+    async fn team_has_language_skill(&self, team_id: &str, language: &str) -> ApiResult<bool>;
+
+    /// Replace a member's language skills; returns false when the user is not
+    /// a member of the team
+    async fn set_member_languages(
+        &self,
+        team_id: &str,
+        user_id: &str,
+        languages: &[String],
+    ) -> ApiResult<bool>;
+
+    async fn set_conversation_language(
+        &self,
+        conversation_id: &str,
+        language: &str,
+    ) -> ApiResult<()>;
+}
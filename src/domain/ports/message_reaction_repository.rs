@@ -0,0 +1,23 @@
+use crate::domain::entities::MessageReaction;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for agent reactions on messages
+#[async_trait::async_trait]
+pub trait MessageReactionRepository: Send + Sync {
+    /// Add a reaction; returns false when the user already left it
+    async fn add_message_reaction(&self, reaction: &MessageReaction) -> ApiResult<bool>;
+
+    /// Remove a reaction; returns false when it did not exist
+    async fn remove_message_reaction(
+        &self,
+        message_id: &str,
+        user_id: &str,
+        emoji: &str,
+    ) -> ApiResult<bool>;
+
+    /// List the reactions on a set of messages, oldest first
+    async fn list_message_reactions(
+        &self,
+        message_ids: &[String],
+    ) -> ApiResult<Vec<MessageReaction>>;
+}
@@ -31,6 +31,18 @@ pub trait MessageRepository: Send + Sync {
 
     async fn count_messages(&self, conversation_id: &str) -> ApiResult<i64>;
 
+    // System notes (auto-generated emails kept apart from contact messages)
+    async fn create_system_note(
+        &self,
+        note: &crate::domain::entities::SystemNote,
+    ) -> ApiResult<()>;
+
+    /// A conversation's system notes, oldest first
+    async fn list_system_notes(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<crate::domain::entities::SystemNote>>;
+
     // Notifications (related to messaging)
     async fn create_notification(
         &self,
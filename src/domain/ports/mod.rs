@@ -1,30 +1,64 @@
+pub mod activity_repository;
 pub mod agent_repository;
 pub mod api_key_repository;
+pub mod api_key_usage_repository;
 pub mod assignment_repository;
 pub mod attachment_repository;
+pub mod attachment_rule_repository;
+pub mod auto_tag_repository;
 pub mod automation_repository;
 pub mod availability_repository;
+pub mod channel_repository;
+pub mod contact_note_repository;
 pub mod contact_repository;
+pub mod conversation_contact_repository;
 pub mod conversation_repository;
+pub mod conversation_summary_repository;
 pub mod conversation_tag_repository;
+pub mod conversation_transfer_repository;
+pub mod csat_repository;
 pub mod distributed_lock;
+pub mod email_oauth_client;
+pub mod email_oauth_repository;
 pub mod email_repository;
+pub mod email_sender;
 pub mod event_bus;
+pub mod holiday_repository;
+pub mod file_downloader;
 pub mod file_storage;
+pub mod gdpr_repository;
+pub mod import_repository;
+pub mod junk_repository;
 pub mod inbox_repository;
+pub mod language_routing_repository;
 pub mod macro_repository;
+pub mod message_reaction_repository;
 pub mod message_repository;
 pub mod notification_repository;
 pub mod oidc_repository;
 pub mod password_reset_repository;
+pub mod priority_matrix_repository;
+pub mod query_diagnostics;
+pub mod reporting_repository;
+pub mod response_reminder_repository;
 pub mod role_repository;
+pub mod saved_view_repository;
+pub mod sentiment_analyzer;
+pub mod sentiment_repository;
 pub mod session_repository;
+pub mod shift_repository;
+pub mod skill_repository;
 pub mod sla_repository;
+pub mod sms_config_repository;
+pub mod system_config_repository;
 pub mod tag_repository;
 pub mod task_queue;
 pub mod task_spawner;
 pub mod team_repository;
+pub mod telegram_bot_api;
+pub mod telegram_config_repository;
 pub mod template_repository;
 pub mod time_service;
+pub mod user_locale_repository;
 pub mod user_repository;
 pub mod webhook_repository;
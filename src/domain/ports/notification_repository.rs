@@ -1,9 +1,12 @@
 use crate::infrastructure::http::middleware::error::ApiResult;
-use crate::domain::entities::UserNotification;
+use crate::domain::entities::{NotificationStreamEvent, UserNotification};
 
 /// Repository for notification operations
 #[async_trait::async_trait]
 pub trait NotificationRepository: Send + Sync {
+    /// Persist a new notification
+    async fn create_notification(&self, notification: &UserNotification) -> ApiResult<()>;
+
     /// Get unread notification count for a user
     async fn get_unread_count(&self, user_id: &str) -> ApiResult<i32>;
 
@@ -18,4 +21,15 @@ pub trait NotificationRepository: Send + Sync {
 
     /// Mark all notifications as read for a user
     async fn mark_all_notifications_as_read(&self, user_id: &str) -> ApiResult<i32>;
+
+    /// Record a stream event for a user, returning its sequence number
+    async fn append_stream_event(&self, user_id: &str, payload: &str) -> ApiResult<i64>;
+
+    /// List a user's stream events with a sequence greater than `after_sequence`
+    async fn list_stream_events_after(
+        &self,
+        user_id: &str,
+        after_sequence: i64,
+        limit: i64,
+    ) -> ApiResult<Vec<NotificationStreamEvent>>;
 }
@@ -14,9 +14,14 @@ impl PasswordResetRepository {
         Self { db }
     }
 
-    /// Count recent password reset requests for a user within a time window
-    pub async fn count_recent_requests(&self, user_id: &str, seconds: i64) -> ApiResult<i64> {
-        self.db.count_recent_reset_requests(user_id, seconds).await
+    /// Count password reset requests for an email address within a time window
+    pub async fn count_recent_requests(&self, email: &str, seconds: i64) -> ApiResult<i64> {
+        self.db.count_recent_reset_requests(email, seconds).await
+    }
+
+    /// Record a password reset request for an email address
+    pub async fn record_request(&self, email: &str) -> ApiResult<()> {
+        self.db.record_password_reset_request(email).await
     }
 
     /// Invalidate all existing reset tokens for a user (marks them as used)
@@ -29,7 +34,7 @@ impl PasswordResetRepository {
         self.db.create_password_reset_token(token).await
     }
 
-    /// Get a password reset token by its value
+    /// Get a password reset token by its value (looked up by hash)
     pub async fn get_token(&self, token: &str) -> ApiResult<Option<PasswordResetToken>> {
         self.db.get_password_reset_token(token).await
     }
@@ -39,8 +44,8 @@ impl PasswordResetRepository {
         self.db.delete_password_reset_token(token_id).await
     }
 
-    /// Atomically reset password and destroy sessions
-    /// Returns the number of sessions destroyed
+    /// Atomically claim the token, reset password and destroy sessions
+    /// Returns the number of sessions destroyed; fails if the token was already used
     pub async fn reset_password_atomic(
         &self,
         user_id: &str,
@@ -0,0 +1,26 @@
+use crate::domain::entities::PriorityMatrixEntry;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Priority matrix entries and the contact tiers they are matched against
+///
+/// Tiers are expected to be normalized with `normalize_tier`.
+#[async_trait::async_trait]
+pub trait PriorityMatrixRepository: Send + Sync {
+    async fn create_priority_matrix_entry(&self, entry: &PriorityMatrixEntry) -> ApiResult<()>;
+
+    async fn list_priority_matrix_entries(&self) -> ApiResult<Vec<PriorityMatrixEntry>>;
+
+    /// Remove an entry, returning whether it existed
+    async fn delete_priority_matrix_entry(&self, id: &str) -> ApiResult<bool>;
+
+    /// Set the tier of the contact with this user ID, returning whether it exists
+    async fn set_contact_tier(&self, user_id: &str, tier: Option<&str>) -> ApiResult<bool>;
+
+    async fn get_contact_tier(&self, user_id: &str) -> ApiResult<Option<String>>;
+
+    /// Tier of the contact who started the conversation
+    async fn get_conversation_contact_tier(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Option<String>>;
+}
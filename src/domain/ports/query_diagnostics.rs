@@ -0,0 +1,7 @@
+use crate::domain::entities::SlowQueryReport;
+
+/// Query performance diagnostics collected by the persistence layer
+pub trait QueryDiagnostics: Send + Sync {
+    /// The `limit` slowest statements seen since startup
+    fn slow_query_report(&self, limit: usize) -> SlowQueryReport;
+}
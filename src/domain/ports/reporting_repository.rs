@@ -0,0 +1,33 @@
+use crate::domain::entities::{ReportingConversation, ReportingSummary, ReportingToken};
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for reporting tokens and the read-only data they expose
+#[async_trait::async_trait]
+pub trait ReportingRepository: Send + Sync {
+    async fn create_reporting_token(&self, token: &ReportingToken) -> ApiResult<()>;
+
+    async fn get_reporting_token(&self, id: &str) -> ApiResult<Option<ReportingToken>>;
+
+    async fn get_reporting_token_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> ApiResult<Option<ReportingToken>>;
+
+    /// All tokens, revoked ones included, newest first
+    async fn list_reporting_tokens(&self) -> ApiResult<Vec<ReportingToken>>;
+
+    async fn revoke_reporting_token(&self, id: &str, revoked_at: &str) -> ApiResult<()>;
+
+    async fn touch_reporting_token(&self, id: &str, used_at: &str) -> ApiResult<()>;
+
+    /// KPIs over records created at or after `since`, or over everything
+    async fn reporting_summary(&self, since: Option<&str>) -> ApiResult<ReportingSummary>;
+
+    /// Conversation metadata ordered by last update, oldest first, for incremental pulls
+    async fn list_reporting_conversations(
+        &self,
+        updated_since: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> ApiResult<Vec<ReportingConversation>>;
+}
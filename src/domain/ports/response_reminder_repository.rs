@@ -0,0 +1,30 @@
+use crate::domain::entities::{ResponseReminderCandidate, ResponseReminderSettings};
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for per-agent response due reminders
+#[async_trait::async_trait]
+pub trait ResponseReminderRepository: Send + Sync {
+    async fn get_response_reminder_settings(
+        &self,
+        user_id: &str,
+    ) -> ApiResult<Option<ResponseReminderSettings>>;
+
+    /// Insert or replace an agent's settings
+    async fn upsert_response_reminder_settings(
+        &self,
+        settings: &ResponseReminderSettings,
+    ) -> ApiResult<()>;
+
+    /// Open conversations assigned to agents with reminders enabled whose
+    /// latest contact message has no reply after it
+    async fn list_response_reminder_candidates(&self) -> ApiResult<Vec<ResponseReminderCandidate>>;
+
+    /// Record a reminder about `message_id`, counting repeats for the same message
+    async fn record_response_reminder(
+        &self,
+        conversation_id: &str,
+        user_id: &str,
+        message_id: &str,
+        reminded_at: &str,
+    ) -> ApiResult<()>;
+}
@@ -0,0 +1,17 @@
+use crate::domain::entities::SavedView;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for agents' saved conversation views
+#[async_trait::async_trait]
+pub trait SavedViewRepository: Send + Sync {
+    async fn create_saved_view(&self, view: &SavedView) -> ApiResult<()>;
+
+    async fn get_saved_view(&self, id: &str) -> ApiResult<Option<SavedView>>;
+
+    async fn update_saved_view(&self, view: &SavedView) -> ApiResult<()>;
+
+    async fn delete_saved_view(&self, id: &str) -> ApiResult<()>;
+
+    /// A user's views, by name
+    async fn list_saved_views(&self, user_id: &str) -> ApiResult<Vec<SavedView>>;
+}
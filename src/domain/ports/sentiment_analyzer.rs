@@ -0,0 +1,9 @@
+/// Scores the sentiment of message text
+///
+/// The default implementation is the rule-based `LexiconSentimentAnalyzer`;
+/// model-backed analyzers can be plugged in through this trait.
+#[async_trait::async_trait]
+pub trait SentimentAnalyzer: Send + Sync {
+    /// Score `text` from -1.0 (very negative) to 1.0 (very positive)
+    async fn score(&self, text: &str) -> f64;
+}
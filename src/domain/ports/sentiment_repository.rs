@@ -0,0 +1,19 @@
+use crate::domain::entities::{MessageSentiment, SentimentTrend};
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for message sentiment scores and the conversation-level rollup
+#[async_trait::async_trait]
+pub trait SentimentRepository: Send + Sync {
+    async fn create_message_sentiment(&self, sentiment: &MessageSentiment) -> ApiResult<()>;
+
+    /// List a conversation's message scores, oldest first
+    async fn list_message_sentiments(&self, conversation_id: &str)
+        -> ApiResult<Vec<MessageSentiment>>;
+
+    async fn update_conversation_sentiment(
+        &self,
+        conversation_id: &str,
+        score: f64,
+        trend: SentimentTrend,
+    ) -> ApiResult<()>;
+}
@@ -0,0 +1,23 @@
+use crate::domain::entities::AgentShift;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for agent shift schedules
+#[async_trait::async_trait]
+pub trait ShiftRepository: Send + Sync {
+    async fn create_shift(&self, shift: &AgentShift) -> ApiResult<()>;
+
+    async fn get_shift(&self, id: &str) -> ApiResult<Option<AgentShift>>;
+
+    async fn list_team_shifts(&self, team_id: &str) -> ApiResult<Vec<AgentShift>>;
+
+    async fn list_all_shifts(&self) -> ApiResult<Vec<AgentShift>>;
+
+    async fn update_shift(&self, shift: &AgentShift) -> ApiResult<()>;
+
+    async fn delete_shift(&self, id: &str) -> ApiResult<()>;
+
+    /// Last recorded on-shift state of an agent, if any
+    async fn get_shift_state(&self, user_id: &str) -> ApiResult<Option<bool>>;
+
+    async fn set_shift_state(&self, user_id: &str, on_shift: bool) -> ApiResult<()>;
+}
@@ -0,0 +1,38 @@
+use crate::domain::entities::Skill;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for the skill catalog and the skills agents have
+#[async_trait::async_trait]
+pub trait SkillRepository: Send + Sync {
+    async fn create_skill(&self, skill: &Skill) -> ApiResult<()>;
+
+    async fn get_skill_by_id(&self, id: &str) -> ApiResult<Option<Skill>>;
+
+    async fn list_skills(&self) -> ApiResult<Vec<Skill>>;
+
+    async fn update_skill(&self, skill: &Skill) -> ApiResult<()>;
+
+    /// Returns false when no skill has this id
+    async fn delete_skill(&self, id: &str) -> ApiResult<bool>;
+
+    async fn get_agent_skills(&self, user_id: &str) -> ApiResult<Vec<Skill>>;
+
+    /// Replace the skills an agent has
+    async fn set_agent_skills(&self, user_id: &str, skill_ids: &[String]) -> ApiResult<()>;
+
+    /// Skills required by the conversation's tags (tags named after a skill)
+    async fn get_conversation_required_skills(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<Skill>>;
+
+    /// Online agents that have every one of `skill_ids`, least loaded first
+    ///
+    /// Load is the number of open or snoozed conversations assigned to the
+    /// agent. When `team_id` is set only members of that team are returned.
+    async fn find_skilled_agents(
+        &self,
+        skill_ids: &[String],
+        team_id: Option<&str>,
+    ) -> ApiResult<Vec<String>>;
+}
@@ -1,4 +1,6 @@
-use crate::domain::entities::{AppliedSla, AppliedSlaStatus, SlaEvent, SlaEventType, SlaPolicy};
+use crate::domain::entities::{
+    AppliedSla, AppliedSlaStatus, Holiday, SlaEvent, SlaEventType, SlaPolicy, SlaReplayConversation,
+};
 use crate::infrastructure::http::middleware::error::ApiResult;
 
 /// Repository for SLA operations
@@ -55,5 +57,16 @@ pub trait SlaRepository: Send + Sync {
     async fn mark_sla_event_breached(&self, event_id: &str, breached_at: &str) -> ApiResult<()>;
 
     // Holiday operations
-    async fn is_holiday(&self, date: &str) -> ApiResult<bool>;
+    /// Whether a date is a global holiday or a holiday in the given calendar
+    async fn is_holiday(&self, date: &str, calendar_id: Option<&str>) -> ApiResult<bool>;
+    async fn list_holidays(&self) -> ApiResult<Vec<Holiday>>;
+
+    // Policy simulation
+    /// Timing of the conversations created between two RFC 3339 times,
+    /// matched by day so the caller can narrow them further
+    async fn list_sla_replay_conversations(
+        &self,
+        since: &str,
+        until: &str,
+    ) -> ApiResult<Vec<SlaReplayConversation>>;
 }
@@ -0,0 +1,15 @@
+use crate::domain::entities::InboxSmsConfig;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for per-inbox Twilio SMS configurations
+#[async_trait::async_trait]
+pub trait SmsConfigRepository: Send + Sync {
+    /// Get an inbox's SMS configuration, with the auth token decrypted
+    async fn get_inbox_sms_config(&self, inbox_id: &str) -> ApiResult<Option<InboxSmsConfig>>;
+
+    async fn create_inbox_sms_config(&self, config: &InboxSmsConfig) -> ApiResult<()>;
+
+    async fn update_inbox_sms_config(&self, config: &InboxSmsConfig) -> ApiResult<()>;
+
+    async fn delete_inbox_sms_config(&self, inbox_id: &str) -> ApiResult<()>;
+}
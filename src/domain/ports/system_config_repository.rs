@@ -0,0 +1,24 @@
+use crate::domain::entities::{SystemConfigChange, SystemConfigEntry};
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for admin-editable system settings and their change history
+#[async_trait::async_trait]
+pub trait SystemConfigRepository: Send + Sync {
+    async fn list_config_entries(&self) -> ApiResult<Vec<SystemConfigEntry>>;
+
+    async fn get_config_value(&self, key: &str) -> ApiResult<Option<String>>;
+
+    /// Store `change.new_value` for `change.key` and record the change, atomically
+    async fn apply_config_change(
+        &self,
+        change: &SystemConfigChange,
+        description: Option<&str>,
+    ) -> ApiResult<()>;
+
+    /// List changes, newest first, optionally for a single key
+    async fn list_config_changes(
+        &self,
+        key: Option<&str>,
+        limit: i64,
+    ) -> ApiResult<Vec<SystemConfigChange>>;
+}
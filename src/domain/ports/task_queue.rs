@@ -1,4 +1,4 @@
-use crate::domain::entities::Job;
+use crate::domain::entities::{Job, JobFailureKind, JobStatus};
 use crate::infrastructure::http::middleware::error::ApiResult;
 use async_trait::async_trait;
 use serde_json::Value;
@@ -14,6 +14,21 @@ pub trait TaskQueue: Send + Sync {
         max_retries: i32,
     ) -> ApiResult<String>;
     async fn fetch_next_job(&self) -> ApiResult<Option<Job>>;
+    /// Keep a running job locked so other workers don't reclaim it
+    async fn extend_lock(&self, job_id: &str) -> ApiResult<()>;
     async fn complete_job(&self, job_id: &str) -> ApiResult<()>;
-    async fn fail_job(&self, job_id: &str, error: &str) -> ApiResult<()>;
+    /// Record a failed attempt; returns `Pending` if the job will be retried
+    /// or `Dead` if it has used up its attempts
+    async fn fail_job(
+        &self,
+        job_id: &str,
+        kind: JobFailureKind,
+        error: &str,
+    ) -> ApiResult<JobStatus>;
+    async fn get_job(&self, job_id: &str) -> ApiResult<Option<Job>>;
+    /// Dead jobs, most recently failed first
+    async fn list_dead_jobs(&self, limit: i64, offset: i64) -> ApiResult<Vec<Job>>;
+    /// Put a dead job back in the queue with fresh attempts; returns false if
+    /// the job isn't dead
+    async fn requeue_job(&self, job_id: &str) -> ApiResult<bool>;
 }
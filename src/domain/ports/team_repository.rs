@@ -19,6 +19,8 @@ pub trait TeamRepository: Send + Sync {
 
     async fn get_team_members(&self, team_id: &str) -> ApiResult<Vec<User>>;
 
+    async fn get_team_lead_ids(&self, team_id: &str) -> ApiResult<Vec<String>>;
+
     async fn is_team_member(&self, team_id: &str, user_id: &str) -> ApiResult<bool>;
 
     async fn get_user_teams(&self, user_id: &str) -> ApiResult<Vec<Team>>;
@@ -28,4 +30,12 @@ pub trait TeamRepository: Send + Sync {
         team_id: &str,
         sla_policy_id: Option<&str>,
     ) -> ApiResult<()>;
+
+    async fn update_team_holiday_calendar(
+        &self,
+        team_id: &str,
+        holiday_calendar_id: Option<&str>,
+    ) -> ApiResult<()>;
+
+    async fn update_team_take_on_reply(&self, team_id: &str, enabled: bool) -> ApiResult<()>;
 }
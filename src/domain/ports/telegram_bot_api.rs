@@ -0,0 +1,30 @@
+use crate::domain::entities::TelegramUser;
+
+/// Calls to the Telegram Bot API, made with an inbox's bot token
+///
+/// Errors are the API's description of the failure.
+#[async_trait::async_trait]
+pub trait TelegramBotApi: Send + Sync {
+    /// The bot the token belongs to (`getMe`)
+    async fn get_me(&self, bot_token: &str) -> Result<TelegramUser, String>;
+
+    /// Point the bot's updates at `url`, signed with `secret_token`
+    async fn set_webhook(&self, bot_token: &str, url: &str, secret_token: &str)
+        -> Result<(), String>;
+
+    async fn delete_webhook(&self, bot_token: &str) -> Result<(), String>;
+
+    async fn send_message(&self, bot_token: &str, chat_id: &str, text: &str) -> Result<(), String>;
+
+    /// Send a file Telegram downloads from `document_url`
+    async fn send_document(
+        &self,
+        bot_token: &str,
+        chat_id: &str,
+        document_url: &str,
+        caption: Option<&str>,
+    ) -> Result<(), String>;
+
+    /// Content of a file sent to the bot
+    async fn download_file(&self, bot_token: &str, file_id: &str) -> Result<Vec<u8>, String>;
+}
@@ -0,0 +1,18 @@
+use crate::domain::entities::InboxTelegramConfig;
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for per-inbox Telegram bot configurations
+#[async_trait::async_trait]
+pub trait TelegramConfigRepository: Send + Sync {
+    /// Get an inbox's Telegram configuration, with the bot token decrypted
+    async fn get_inbox_telegram_config(
+        &self,
+        inbox_id: &str,
+    ) -> ApiResult<Option<InboxTelegramConfig>>;
+
+    async fn create_inbox_telegram_config(&self, config: &InboxTelegramConfig) -> ApiResult<()>;
+
+    async fn update_inbox_telegram_config(&self, config: &InboxTelegramConfig) -> ApiResult<()>;
+
+    async fn delete_inbox_telegram_config(&self, inbox_id: &str) -> ApiResult<()>;
+}
@@ -0,0 +1,11 @@
+use crate::infrastructure::http::middleware::error::ApiResult;
+
+/// Repository for the locale users chose for the UI, API messages and
+/// notification emails
+#[async_trait::async_trait]
+pub trait UserLocaleRepository: Send + Sync {
+    async fn get_user_locale(&self, user_id: &str) -> ApiResult<Option<String>>;
+
+    /// Store the user's locale; None clears it
+    async fn set_user_locale(&self, user_id: &str, locale: Option<&str>) -> ApiResult<()>;
+}
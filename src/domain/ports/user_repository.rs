@@ -23,5 +23,6 @@ pub trait UserRepository: Send + Sync {
     async fn get_users_by_usernames(&self, usernames: &[String]) -> ApiResult<Vec<User>>;
     async fn get_users_by_ids(&self, ids: &[String]) -> ApiResult<Vec<User>>;
     async fn count_admin_users(&self) -> ApiResult<i64>;
+    async fn list_admin_user_ids(&self) -> ApiResult<Vec<String>>;
     async fn delete_user(&self, user_id: &str) -> ApiResult<()>;
 }
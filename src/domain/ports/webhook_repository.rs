@@ -1,7 +1,9 @@
 use crate::{
     infrastructure::http::middleware::error::ApiResult,
     infrastructure::persistence::Database,
-    domain::entities::Webhook,
+    domain::entities::{
+        ConversationSnapshot, Webhook, WebhookBackfill, WebhookBackfillEvent, WebhookDelivery,
+    },
 };
 
 #[derive(Clone)]
@@ -69,8 +71,68 @@ impl WebhookRepository {
         self.db.get_active_webhooks_for_event(event_type).await
     }
 
+    /// Current state of a conversation, for webhooks that include snapshots
+    pub async fn get_conversation_snapshot(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Option<ConversationSnapshot>> {
+        self.db.get_conversation_snapshot(conversation_id).await
+    }
+
     /// Create a webhook delivery record
     pub async fn create_webhook_delivery(&self, delivery: &crate::domain::entities::WebhookDelivery) -> ApiResult<()> {
         self.db.create_webhook_delivery(delivery).await
     }
+
+    /// Stored deliveries of the given event types attempted within `[from, to)`,
+    /// oldest first
+    pub async fn get_deliveries_for_backfill(
+        &self,
+        event_types: &[String],
+        from: &str,
+        to: &str,
+    ) -> ApiResult<Vec<WebhookDelivery>> {
+        self.db
+            .get_deliveries_for_backfill(event_types, from, to)
+            .await
+    }
+
+    /// Create a backfill together with the events it will replay
+    pub async fn create_webhook_backfill(
+        &self,
+        backfill: &WebhookBackfill,
+        events: &[WebhookBackfillEvent],
+    ) -> ApiResult<()> {
+        self.db.create_webhook_backfill(backfill, events).await
+    }
+
+    /// Get backfill by ID
+    pub async fn get_webhook_backfill(&self, id: &str) -> ApiResult<Option<WebhookBackfill>> {
+        self.db.get_webhook_backfill(id).await
+    }
+
+    /// Next events of a backfill after `after_sequence`
+    pub async fn get_webhook_backfill_events(
+        &self,
+        backfill_id: &str,
+        after_sequence: i64,
+        limit: i64,
+    ) -> ApiResult<Vec<WebhookBackfillEvent>> {
+        self.db
+            .get_webhook_backfill_events(backfill_id, after_sequence, limit)
+            .await
+    }
+
+    /// Store backfill status and progress
+    pub async fn update_webhook_backfill_progress(
+        &self,
+        backfill: &WebhookBackfill,
+    ) -> ApiResult<()> {
+        self.db.update_webhook_backfill_progress(backfill).await
+    }
+
+    /// Drop the events of a finished backfill
+    pub async fn clear_webhook_backfill_events(&self, backfill_id: &str) -> ApiResult<()> {
+        self.db.clear_webhook_backfill_events(backfill_id).await
+    }
 }
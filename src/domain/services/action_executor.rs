@@ -2,10 +2,13 @@ use crate::infrastructure::http::middleware::ApiError;
 use crate::domain::ports::agent_repository::AgentRepository;
 use crate::domain::ports::conversation_repository::ConversationRepository;
 use crate::domain::ports::conversation_tag_repository::ConversationTagRepository;
+use crate::domain::ports::notification_repository::NotificationRepository;
 use crate::domain::ports::tag_repository::TagRepository;
 use crate::domain::ports::team_repository::TeamRepository;
 use crate::domain::ports::user_repository::UserRepository;
-use crate::domain::entities::{ActionType, ConversationStatus, RuleAction};
+use crate::domain::entities::{
+    ActionType, Conversation, ConversationStatus, RuleAction, UserNotification,
+};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -50,6 +53,7 @@ pub struct ActionExecutor {
     team_repo: Arc<dyn TeamRepository>,
     tag_repo: TagRepository,
     conversation_tag_repo: Arc<dyn ConversationTagRepository>,
+    notification_repo: Option<Arc<dyn NotificationRepository>>,
     timeout: Duration,
 }
 
@@ -69,6 +73,7 @@ impl ActionExecutor {
             team_repo,
             tag_repo,
             conversation_tag_repo,
+            notification_repo: None,
             timeout: Duration::from_secs(10),
         }
     }
@@ -89,10 +94,16 @@ impl ActionExecutor {
             team_repo,
             tag_repo,
             conversation_tag_repo,
+            notification_repo: None,
             timeout,
         }
     }
 
+    /// Enable notification actions such as NotifyTeamLead
+    pub fn set_notification_repo(&mut self, notification_repo: Arc<dyn NotificationRepository>) {
+        self.notification_repo = Some(notification_repo);
+    }
+
     /// Execute an action on a conversation
     pub async fn execute(
         &self,
@@ -116,7 +127,7 @@ impl ActionExecutor {
         executed_by: &str,
     ) -> Result<(), ActionError> {
         // Verify conversation exists
-        let _conversation = self
+        let conversation = self
             .conversation_repo
             .get_conversation_by_id(conversation_id)
             .await?
@@ -154,6 +165,14 @@ impl ActionExecutor {
                 self.execute_change_status(conversation_id, &action.parameters)
                     .await
             }
+            ActionType::NotifyTeamLead => {
+                self.execute_notify_team_lead(&conversation, &action.parameters)
+                    .await
+            }
+            // Waits are scheduled by AutomationService; only their follow-up runs here
+            ActionType::Wait => Err(ActionError::InvalidParameters(
+                "Wait actions cannot be executed directly".to_string(),
+            )),
         }
     }
 
@@ -387,6 +406,58 @@ impl ActionExecutor {
 
         Ok(())
     }
+
+    async fn execute_notify_team_lead(
+        &self,
+        conversation: &Conversation,
+        parameters: &std::collections::HashMap<String, serde_json::Value>,
+    ) -> Result<(), ActionError> {
+        let notification_repo = self.notification_repo.as_ref().ok_or_else(|| {
+            ActionError::ExecutionFailed("Notifications are not configured".to_string())
+        })?;
+
+        // Default to the team the conversation is assigned to
+        let team_id = match parameters.get("team_id") {
+            Some(value) => value.as_str().map(|s| s.to_string()).ok_or_else(|| {
+                ActionError::InvalidParameters("'team_id' must be a string".to_string())
+            })?,
+            None => conversation.assigned_team_id.clone().ok_or_else(|| {
+                ActionError::InvalidParameters(
+                    "Missing 'team_id' parameter and conversation has no assigned team"
+                        .to_string(),
+                )
+            })?,
+        };
+
+        self.team_repo
+            .get_team_by_id(&team_id)
+            .await?
+            .ok_or(ActionError::TeamNotFound)?;
+
+        let lead_ids = self.team_repo.get_team_lead_ids(&team_id).await?;
+        if lead_ids.is_empty() {
+            tracing::warn!(
+                "Team {} has no lead to notify about conversation {}",
+                team_id,
+                conversation.id
+            );
+            return Ok(());
+        }
+
+        for lead_id in &lead_ids {
+            let notification = UserNotification::new_alert(lead_id.clone(), conversation.id.clone());
+            notification_repo.create_notification(&notification).await?;
+        }
+
+        tracing::info!(
+            "Notified {} lead(s) of team {} about conversation {}",
+            lead_ids.len(),
+            team_id,
+            conversation.id
+        );
+
+        Ok(())
+    }
 }
 
 impl Default for ActionExecutor {
@@ -0,0 +1,29 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Identity signature the host site computes for its logged-in user
+///
+/// Hex HMAC-SHA256 of the normalized (lowercased) email, keyed with the widget
+/// identity secret. The host site passes it to the widget with the email.
+pub fn sign_widget_identity(email: &str, secret: &str) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(email.as_bytes());
+
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Verify the identity signature a visitor sent along with their email
+pub fn verify_widget_identity(email: &str, signature: &str, secret: &str) -> bool {
+    let Ok(signature) = hex::decode(signature.trim()) else {
+        return false;
+    };
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(email.as_bytes());
+
+    // Constant-time comparison
+    mac.verify_slice(&signature).is_ok()
+}
@@ -103,7 +103,13 @@ impl ConditionEvaluator {
             ComparisonOperator::GreaterThan => {
                 self.evaluate_greater_than(&attr_value, expected_value)
             }
+            ComparisonOperator::GreaterThanOrEqual => {
+                Ok(!self.evaluate_less_than(&attr_value, expected_value)?)
+            }
             ComparisonOperator::LessThan => self.evaluate_less_than(&attr_value, expected_value),
+            ComparisonOperator::LessThanOrEqual => {
+                Ok(!self.evaluate_greater_than(&attr_value, expected_value)?)
+            }
             ComparisonOperator::In => self.evaluate_in(&attr_value, expected_value),
             ComparisonOperator::NotIn => Ok(!self.evaluate_in(&attr_value, expected_value)?),
         }
@@ -128,6 +134,7 @@ impl ConditionEvaluator {
                 ConversationStatus::Snoozed => "snoozed".to_string(),
                 ConversationStatus::Resolved => "resolved".to_string(),
                 ConversationStatus::Closed => "closed".to_string(),
+                ConversationStatus::Junk => "junk".to_string(),
             })),
             "assigned_user_id" => Ok(match &conversation.assigned_user_id {
                 Some(id) => Value::String(id.clone()),
@@ -137,6 +144,22 @@ impl ConditionEvaluator {
                 Some(id) => Value::String(id.clone()),
                 None => Value::Null,
             }),
+            "contact_email_verified" => Ok(match conversation.contact_email_verified {
+                Some(verified) => Value::Bool(verified),
+                None => Value::Null,
+            }),
+            "csat_score" => Ok(match conversation.csat_score {
+                Some(score) => Value::from(score),
+                None => Value::Null,
+            }),
+            "sentiment_score" => Ok(match conversation.sentiment_score {
+                Some(score) => Value::from(score),
+                None => Value::Null,
+            }),
+            "sentiment_trend" => Ok(match conversation.sentiment_trend {
+                Some(trend) => Value::String(trend.as_str().to_string()),
+                None => Value::Null,
+            }),
             _ => Err(ConditionError::InvalidAttribute(attribute.to_string())),
         }
     }
@@ -287,6 +310,11 @@ mod tests {
             version: 1,
             tags: Some(vec!["Bug".to_string()]),
             priority: Some(crate::domain::entities::Priority::High),
+            contact_email_verified: Some(false),
+            csat_score: None,
+            sentiment_score: None,
+            sentiment_trend: None,
+            summary: None,
         }
     }
 
@@ -322,6 +350,49 @@ mod tests {
         assert_eq!(result.unwrap(), true);
     }
 
+    #[tokio::test]
+    async fn test_evaluate_contact_email_verified() {
+        let evaluator = ConditionEvaluator::new();
+        let conversation = create_test_conversation();
+
+        let condition = RuleCondition::Simple {
+            attribute: "contact_email_verified".to_string(),
+            comparison: ComparisonOperator::Equals,
+            value: json!(false),
+        };
+
+        let result = evaluator.evaluate(&condition, &conversation).await;
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_csat_score_thresholds() {
+        let evaluator = ConditionEvaluator::new();
+        let mut conversation = create_test_conversation();
+        conversation.csat_score = Some(2);
+
+        let at_most_two = RuleCondition::Simple {
+            attribute: "csat_score".to_string(),
+            comparison: ComparisonOperator::LessThanOrEqual,
+            value: json!(2),
+        };
+        let at_least_three = RuleCondition::Simple {
+            attribute: "csat_score".to_string(),
+            comparison: ComparisonOperator::GreaterThanOrEqual,
+            value: json!(3),
+        };
+
+        assert!(evaluator
+            .evaluate(&at_most_two, &conversation)
+            .await
+            .unwrap());
+        assert!(!evaluator
+            .evaluate(&at_least_three, &conversation)
+            .await
+            .unwrap());
+    }
+
     #[tokio::test]
     async fn test_invalid_attribute_error() {
         let evaluator = ConditionEvaluator::new();
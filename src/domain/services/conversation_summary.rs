@@ -0,0 +1,257 @@
+use crate::domain::entities::{ConversationStatus, Message, MessageType};
+
+/// Longest issue statement kept in a summary, in characters
+pub const MAX_ISSUE_CHARS: usize = 140;
+
+/// Sentences are added to the issue statement until it reaches this length
+const MIN_ISSUE_CHARS: usize = 40;
+
+const GREETINGS: &[&str] = &[
+    "hi",
+    "hello",
+    "hey",
+    "dear",
+    "greetings",
+    "good morning",
+    "good afternoon",
+    "good evening",
+];
+
+const SIGN_OFFS: &[&str] = &[
+    "thanks",
+    "thank you",
+    "thanks in advance",
+    "many thanks",
+    "regards",
+    "best regards",
+    "kind regards",
+    "best",
+    "cheers",
+    "sincerely",
+];
+
+/// Build a conversation's rolling summary from its status and recent messages
+///
+/// `messages` are newest first. The issue statement comes from the latest
+/// inbound message with meaningful text, falling back to the subject; the
+/// current state is appended so the preview reads e.g.
+/// "Invoice shows the wrong VAT rate. — Waiting on agent".
+pub fn summarize_conversation(
+    status: ConversationStatus,
+    subject: Option<&str>,
+    messages: &[Message],
+) -> String {
+    let issue = messages
+        .iter()
+        .filter(|m| m.message_type == MessageType::Incoming)
+        .find_map(|m| issue_statement(&m.content))
+        .or_else(|| subject.and_then(issue_statement));
+    let state = conversation_state(status, messages.first().map(|m| m.message_type));
+
+    match issue {
+        Some(issue) => format!("{} — {}", issue, state),
+        None => state.to_string(),
+    }
+}
+
+/// Who the conversation is waiting on, or where it ended up
+pub fn conversation_state(
+    status: ConversationStatus,
+    last_message_type: Option<MessageType>,
+) -> &'static str {
+    match status {
+        ConversationStatus::Open => match last_message_type {
+            Some(MessageType::Outgoing) => "Waiting on customer",
+            _ => "Waiting on agent",
+        },
+        ConversationStatus::Snoozed => "Snoozed",
+        ConversationStatus::Resolved => "Resolved",
+        ConversationStatus::Closed => "Closed",
+        ConversationStatus::Junk => "Marked as junk",
+    }
+}
+
+/// Extract the opening sentences of a message, without greetings, quoted
+/// replies or signatures
+///
+/// Returns `None` when nothing meaningful is left.
+pub fn issue_statement(content: &str) -> Option<String> {
+    let text = strip_tags(content);
+    let mut lines = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line == "--" || line == "-- " || is_reply_header(line) || is_sign_off(line) {
+            break;
+        }
+        if line.is_empty() || line.starts_with('>') {
+            continue;
+        }
+        if lines.is_empty() && is_greeting(line) {
+            continue;
+        }
+        lines.push(line);
+    }
+
+    let text = lines.join(" ");
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return None;
+    }
+
+    let mut statement = String::new();
+    for sentence in sentences(&text) {
+        if !statement.is_empty() {
+            statement.push(' ');
+        }
+        statement.push_str(sentence);
+        if statement.chars().count() >= MIN_ISSUE_CHARS {
+            break;
+        }
+    }
+
+    Some(truncate(&statement, MAX_ISSUE_CHARS))
+}
+
+/// Drop HTML tags so rich email bodies read as plain text
+fn strip_tags(content: &str) -> String {
+    if !content.contains('<') {
+        return content.to_string();
+    }
+
+    let mut text = String::with_capacity(content.len());
+    let mut in_tag = false;
+    for c in content.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push('\n');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// "On Mon, 3 Jun 2024 at 10:00, Ana <ana@example.com> wrote:"
+fn is_reply_header(line: &str) -> bool {
+    let lower = line.to_lowercase();
+    (lower.starts_with("on ") && lower.ends_with("wrote:"))
+        || lower.starts_with("-----original message")
+}
+
+fn is_greeting(line: &str) -> bool {
+    let lower = line.to_lowercase();
+    let short = line.split_whitespace().count() <= 3 || line.ends_with(',');
+    short
+        && GREETINGS.iter().any(|greeting| {
+            lower
+                .strip_prefix(greeting)
+                .is_some_and(|rest| rest.is_empty() || !rest.starts_with(char::is_alphanumeric))
+        })
+}
+
+fn is_sign_off(line: &str) -> bool {
+    let lower = line.trim_end_matches([',', '!', '.']).to_lowercase();
+    SIGN_OFFS.contains(&lower.as_str())
+}
+
+/// Split text after sentence-ending punctuation
+fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if matches!(c, '.' | '?' | '!') && chars.peek().is_some_and(|(_, next)| *next == ' ') {
+            sentences.push(text[start..=i].trim());
+            start = i + 1;
+        }
+    }
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+    sentences
+}
+
+/// Cut text to `max` characters at a word boundary, marking the cut
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+
+    let cut: String = text.chars().take(max - 1).collect();
+    let cut = match cut.rfind(' ') {
+        Some(i) if i > 0 => &cut[..i],
+        _ => cut.as_str(),
+    };
+    format!("{}…", cut.trim_end_matches([',', ' ']))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn incoming(content: &str) -> Message {
+        Message::new_incoming("c1".to_string(), content.to_string(), "u1".to_string())
+    }
+
+    fn outgoing(content: &str) -> Message {
+        Message::new_outgoing("c1".to_string(), content.to_string(), "a1".to_string())
+    }
+
+    #[test]
+    fn test_issue_statement_skips_greetings_quotes_and_signatures() {
+        let content = "Hi team,\n\nMy invoice shows the wrong VAT rate. Can you fix it? \
+                       It is due Friday.\n\nThanks,\nAna\n\nOn Mon, Ana wrote:\n> old text";
+        assert_eq!(
+            issue_statement(content).as_deref(),
+            Some("My invoice shows the wrong VAT rate. Can you fix it?")
+        );
+
+        assert_eq!(
+            issue_statement("<p>Hello!</p><p>The export button does nothing.</p>").as_deref(),
+            Some("The export button does nothing.")
+        );
+        assert_eq!(issue_statement("Hi,\n\nThanks!"), None);
+        // Greetings only match whole words
+        assert_eq!(
+            issue_statement("Heyday sale codes are not applying").as_deref(),
+            Some("Heyday sale codes are not applying")
+        );
+    }
+
+    #[test]
+    fn test_issue_statement_is_truncated_at_a_word() {
+        let long = "word ".repeat(60);
+        let statement = issue_statement(&long).unwrap();
+        assert!(statement.chars().count() <= MAX_ISSUE_CHARS);
+        assert!(statement.ends_with("word…"));
+    }
+
+    #[test]
+    fn test_summary_combines_latest_issue_and_state() {
+        let messages = vec![
+            outgoing("Could you send a screenshot?"),
+            incoming("Hello,\nThe app crashes when I upload a photo."),
+            incoming("Login is slow."),
+        ];
+        assert_eq!(
+            summarize_conversation(ConversationStatus::Open, Some("Crash"), &messages),
+            "The app crashes when I upload a photo. — Waiting on customer"
+        );
+        assert_eq!(
+            summarize_conversation(ConversationStatus::Open, Some("Crash"), &messages[1..]),
+            "The app crashes when I upload a photo. — Waiting on agent"
+        );
+        assert_eq!(
+            summarize_conversation(ConversationStatus::Resolved, Some("Refund request"), &[]),
+            "Refund request — Resolved"
+        );
+        assert_eq!(
+            summarize_conversation(ConversationStatus::Snoozed, None, &[]),
+            "Snoozed"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! `Authentication-Results` header (RFC 8601) of inbound email
+//!
+//! Only the topmost header is read: it is the one added by the receiving
+//! server, while any further down could have been written by the sender.
+
+use crate::domain::entities::{EmailAuthResult, EmailAuthentication};
+
+/// Parse the SPF, DKIM and DMARC results out of an `Authentication-Results`
+/// header value
+///
+/// Returns `None` when the header carries no result for any of the three
+/// methods (e.g. `example.com; none`).
+pub fn parse_authentication_results(header: &str) -> Option<EmailAuthentication> {
+    let header = strip_comments(header);
+    let mut statements = header.split(';');
+
+    // The authserv-id may be followed by a version number
+    let authserv_id = statements
+        .next()
+        .and_then(|id| id.split_whitespace().next())
+        .map(str::to_string);
+
+    let mut authentication = EmailAuthentication {
+        authserv_id,
+        ..Default::default()
+    };
+    for statement in statements {
+        let Some((method, result)) = statement
+            .split_whitespace()
+            .next()
+            .and_then(|result| result.split_once('='))
+        else {
+            continue;
+        };
+        let Ok(result) = result.parse::<EmailAuthResult>() else {
+            continue;
+        };
+
+        let slot = match method.to_ascii_lowercase().as_str() {
+            "spf" => &mut authentication.spf,
+            "dkim" => &mut authentication.dkim,
+            "dmarc" => &mut authentication.dmarc,
+            _ => continue,
+        };
+        // An email may carry several DKIM signatures; one passing is enough
+        if slot.is_none() || result == EmailAuthResult::Pass {
+            *slot = Some(result);
+        }
+    }
+
+    if authentication.spf.is_none()
+        && authentication.dkim.is_none()
+        && authentication.dmarc.is_none()
+    {
+        return None;
+    }
+    Some(authentication)
+}
+
+/// Drop `(comments)`, which may contain semicolons and `=` signs
+fn strip_comments(header: &str) -> String {
+    let mut depth = 0usize;
+    let mut in_quotes = false;
+    header
+        .chars()
+        .filter(|c| match c {
+            '"' if depth == 0 => {
+                in_quotes = !in_quotes;
+                true
+            }
+            '(' if !in_quotes => {
+                depth += 1;
+                false
+            }
+            ')' if !in_quotes && depth > 0 => {
+                depth -= 1;
+                false
+            }
+            _ => depth == 0,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_all_methods() {
+        let header = "mx.google.com;\r\n       dkim=pass header.i=@example.com header.s=sel1;\r\n       \
+                      spf=pass (google.com: domain of a@example.com designates 1.2.3.4 as permitted sender) smtp.mailfrom=a@example.com;\r\n       \
+                      dmarc=pass (p=REJECT sp=REJECT dis=NONE) header.from=example.com";
+        let results = parse_authentication_results(header).unwrap();
+        assert_eq!(results.authserv_id.as_deref(), Some("mx.google.com"));
+        assert_eq!(results.spf, Some(EmailAuthResult::Pass));
+        assert_eq!(results.dkim, Some(EmailAuthResult::Pass));
+        assert_eq!(results.dmarc, Some(EmailAuthResult::Pass));
+        assert!(!results.is_failure());
+    }
+
+    #[test]
+    fn test_parse_failures_and_multiple_signatures() {
+        let header = "mx.example.net 1; spf=softfail smtp.mailfrom=ceo@example.com; \
+                      dkim=fail (bad signature) header.d=example.com; \
+                      dkim=pass header.d=mailer.example.org; \
+                      dmarc=fail (p=quarantine) header.from=example.com";
+        let results = parse_authentication_results(header).unwrap();
+        assert_eq!(results.authserv_id.as_deref(), Some("mx.example.net"));
+        assert_eq!(results.spf, Some(EmailAuthResult::SoftFail));
+        assert_eq!(results.dkim, Some(EmailAuthResult::Pass));
+        assert_eq!(results.dmarc, Some(EmailAuthResult::Fail));
+        assert!(results.is_failure());
+    }
+
+    #[test]
+    fn test_no_results() {
+        assert!(parse_authentication_results("mx.example.net; none").is_none());
+        assert!(parse_authentication_results("").is_none());
+        assert!(parse_authentication_results("mx.example.net; arc=pass").is_none());
+    }
+}
@@ -0,0 +1,7 @@
+/// Initial client response of the SASL XOAUTH2 mechanism, before base64
+///
+/// Used by Gmail and Microsoft 365 for IMAP and SMTP sign-in with an OAuth2
+/// access token in place of a password.
+pub fn xoauth2_sasl_response(username: &str, access_token: &str) -> String {
+    format!("user={}\x01auth=Bearer {}\x01\x01", username, access_token)
+}
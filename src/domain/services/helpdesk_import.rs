@@ -0,0 +1,744 @@
+//! Readers for ticket exports from other helpdesks
+//!
+//! Zendesk and Freshdesk exports, as JSON (a document, a bare array or
+//! newline-delimited tickets) or CSV, are normalized into `ImportedTicket`s.
+//! CSV exports only carry ticket fields, so each ticket becomes a single
+//! message built from its description.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde_json::Value;
+
+use crate::domain::entities::{ConversationStatus, ImportFormat, ImportSource, Priority};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedContact {
+    pub email: String,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedAttachment {
+    pub external_id: String,
+    pub file_name: String,
+    pub content_type: Option<String>,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedMessage {
+    pub external_id: String,
+    pub body: String,
+    /// Written by an agent of the old helpdesk rather than the requester
+    pub from_agent: bool,
+    pub author_email: Option<String>,
+    pub created_at: String,
+    pub attachments: Vec<ImportedAttachment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedTicket {
+    pub external_id: String,
+    pub subject: Option<String>,
+    pub status: ConversationStatus,
+    pub priority: Option<Priority>,
+    pub requester: ImportedContact,
+    pub tags: Vec<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub messages: Vec<ImportedMessage>,
+}
+
+/// Result of reading an export
+#[derive(Debug, Default)]
+pub struct ParsedExport {
+    pub tickets: Vec<ImportedTicket>,
+    /// Records that could not be read, with the reason
+    pub invalid_records: Vec<String>,
+    /// Private notes left out; Oxidesk has no internal note messages
+    pub private_notes_skipped: usize,
+}
+
+/// Parse a helpdesk export into tickets
+///
+/// Fails only when the document as a whole can't be read; problems with
+/// individual tickets are reported in `invalid_records`.
+pub fn parse_helpdesk_export(
+    source: ImportSource,
+    format: ImportFormat,
+    data: &str,
+) -> Result<ParsedExport, String> {
+    match (source, format) {
+        (ImportSource::Zendesk, ImportFormat::Json) => {
+            let (tickets, users) = read_json_records(data)?;
+            Ok(parse_json_tickets(tickets, |t, r| {
+                zendesk_json_ticket(t, &users, r)
+            }))
+        }
+        (ImportSource::Freshdesk, ImportFormat::Json) => {
+            let (tickets, _) = read_json_records(data)?;
+            Ok(parse_json_tickets(tickets, freshdesk_json_ticket))
+        }
+        (ImportSource::Zendesk, ImportFormat::Csv) => parse_csv(data, &ZENDESK_CSV_COLUMNS),
+        (ImportSource::Freshdesk, ImportFormat::Csv) => parse_csv(data, &FRESHDESK_CSV_COLUMNS),
+    }
+}
+
+/// Read the tickets (and Zendesk side-loaded users) out of a JSON export
+fn read_json_records(data: &str) -> Result<(Vec<Value>, HashMap<String, Value>), String> {
+    let records = match serde_json::from_str::<Value>(data) {
+        Ok(Value::Array(tickets)) => (tickets, Vec::new()),
+        Ok(Value::Object(mut document)) => match document.remove("tickets") {
+            Some(Value::Array(tickets)) => {
+                let users = match document.remove("users") {
+                    Some(Value::Array(users)) => users,
+                    _ => Vec::new(),
+                };
+                (tickets, users)
+            }
+            // A single ticket object (one line of a newline-delimited export)
+            _ => (vec![Value::Object(document)], Vec::new()),
+        },
+        Ok(_) => return Err("Export must be a JSON object or array".to_string()),
+        Err(_) => {
+            let mut tickets = Vec::new();
+            for (index, line) in data.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let ticket = serde_json::from_str(line)
+                    .map_err(|e| format!("Invalid JSON on line {}: {}", index + 1, e))?;
+                tickets.push(ticket);
+            }
+            (tickets, Vec::new())
+        }
+    };
+
+    let (tickets, users) = records;
+    let users = users
+        .into_iter()
+        .filter_map(|user| Some((id_string(user.get("id")?)?, user)))
+        .collect();
+    Ok((tickets, users))
+}
+
+fn parse_json_tickets<F>(tickets: Vec<Value>, mut parse: F) -> ParsedExport
+where
+    F: FnMut(&Value, &mut ParsedExport) -> Result<ImportedTicket, String>,
+{
+    let mut export = ParsedExport::default();
+    for (index, ticket) in tickets.iter().enumerate() {
+        match parse(ticket, &mut export) {
+            Ok(ticket) => export.tickets.push(ticket),
+            Err(e) => {
+                let id = ticket
+                    .get("id")
+                    .and_then(id_string)
+                    .unwrap_or_else(|| format!("#{}", index + 1));
+                export.invalid_records.push(format!("Ticket {}: {}", id, e));
+            }
+        }
+    }
+    export
+}
+
+fn zendesk_json_ticket(
+    ticket: &Value,
+    users: &HashMap<String, Value>,
+    export: &mut ParsedExport,
+) -> Result<ImportedTicket, String> {
+    let external_id = ticket.get("id").and_then(id_string).ok_or("missing id")?;
+    let requester_id = ticket.get("requester_id").and_then(id_string);
+    let requester = ticket
+        .get("requester")
+        .or_else(|| users.get(requester_id.as_deref()?))
+        .and_then(contact_from_json)
+        .ok_or("missing requester email")?;
+    let created_at = timestamp_field(ticket, "created_at")?;
+    let updated_at = optional_timestamp(ticket, "updated_at").unwrap_or_else(|| created_at.clone());
+
+    let mut messages = Vec::new();
+    for comment in array_field(ticket, "comments") {
+        if comment.get("public").and_then(Value::as_bool) == Some(false) {
+            export.private_notes_skipped += 1;
+            continue;
+        }
+        let id = comment
+            .get("id")
+            .and_then(id_string)
+            .ok_or("comment without id")?;
+        let author_id = comment.get("author_id").and_then(id_string);
+        let author = author_id.as_deref().and_then(|id| users.get(id));
+        let from_agent = match author.and_then(|u| u.get("role")).and_then(Value::as_str) {
+            Some(role) => role != "end-user",
+            None => author_id.is_some() && author_id != requester_id,
+        };
+        messages.push(ImportedMessage {
+            external_id: id,
+            body: string_field(comment, "plain_body")
+                .or_else(|| string_field(comment, "body"))
+                .unwrap_or_default(),
+            from_agent,
+            author_email: author.and_then(|u| string_field(u, "email")),
+            created_at: optional_timestamp(comment, "created_at")
+                .unwrap_or_else(|| created_at.clone()),
+            attachments: array_field(comment, "attachments")
+                .iter()
+                .filter_map(|a| attachment_from_json(a, "file_name", "content_url", "content_type"))
+                .collect(),
+        });
+    }
+    if messages.is_empty() {
+        messages.extend(description_message(
+            ticket,
+            "description",
+            &external_id,
+            &created_at,
+        ));
+    }
+
+    Ok(ImportedTicket {
+        subject: string_field(ticket, "subject"),
+        status: zendesk_status(string_field(ticket, "status").as_deref()),
+        priority: zendesk_priority(string_field(ticket, "priority").as_deref()),
+        requester,
+        tags: tags_field(ticket),
+        created_at,
+        updated_at,
+        messages,
+        external_id,
+    })
+}
+
+fn freshdesk_json_ticket(
+    ticket: &Value,
+    export: &mut ParsedExport,
+) -> Result<ImportedTicket, String> {
+    let external_id = ticket.get("id").and_then(id_string).ok_or("missing id")?;
+    let requester = ticket
+        .get("requester")
+        .and_then(contact_from_json)
+        .or_else(|| {
+            Some(ImportedContact {
+                email: normalize_email(&string_field(ticket, "email")?)?,
+                name: string_field(ticket, "name"),
+            })
+        })
+        .ok_or("missing requester email")?;
+    let created_at = timestamp_field(ticket, "created_at")?;
+    let updated_at = optional_timestamp(ticket, "updated_at").unwrap_or_else(|| created_at.clone());
+
+    // The ticket description is the first message; replies and notes follow
+    let mut messages = Vec::new();
+    if let Some(mut first) =
+        description_message(ticket, "description_text", &external_id, &created_at)
+            .or_else(|| description_message(ticket, "description", &external_id, &created_at))
+    {
+        first.attachments = array_field(ticket, "attachments")
+            .iter()
+            .filter_map(|a| attachment_from_json(a, "name", "attachment_url", "content_type"))
+            .collect();
+        messages.push(first);
+    }
+    for reply in array_field(ticket, "conversations") {
+        if reply.get("private").and_then(Value::as_bool) == Some(true) {
+            export.private_notes_skipped += 1;
+            continue;
+        }
+        let id = reply
+            .get("id")
+            .and_then(id_string)
+            .ok_or("conversation without id")?;
+        messages.push(ImportedMessage {
+            external_id: id,
+            body: string_field(reply, "body_text")
+                .or_else(|| string_field(reply, "body"))
+                .unwrap_or_default(),
+            from_agent: !reply
+                .get("incoming")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+            author_email: string_field(reply, "from_email"),
+            created_at: optional_timestamp(reply, "created_at")
+                .unwrap_or_else(|| created_at.clone()),
+            attachments: array_field(reply, "attachments")
+                .iter()
+                .filter_map(|a| attachment_from_json(a, "name", "attachment_url", "content_type"))
+                .collect(),
+        });
+    }
+
+    Ok(ImportedTicket {
+        subject: string_field(ticket, "subject"),
+        status: freshdesk_status(ticket.get("status")),
+        priority: freshdesk_priority(ticket.get("priority")),
+        requester,
+        tags: tags_field(ticket),
+        created_at,
+        updated_at,
+        messages,
+        external_id,
+    })
+}
+
+/// Header names of the columns read from a CSV export
+struct CsvColumns {
+    id: &'static str,
+    subject: &'static str,
+    description: &'static str,
+    status: &'static str,
+    priority: &'static str,
+    requester_name: &'static str,
+    requester_email: &'static str,
+    created_at: &'static str,
+    updated_at: &'static str,
+    tags: &'static str,
+    source: ImportSource,
+}
+
+const ZENDESK_CSV_COLUMNS: CsvColumns = CsvColumns {
+    id: "Id",
+    subject: "Subject",
+    description: "Description",
+    status: "Status",
+    priority: "Priority",
+    requester_name: "Requester",
+    requester_email: "Requester email",
+    created_at: "Created at",
+    updated_at: "Updated at",
+    tags: "Tags",
+    source: ImportSource::Zendesk,
+};
+
+const FRESHDESK_CSV_COLUMNS: CsvColumns = CsvColumns {
+    id: "Ticket ID",
+    subject: "Subject",
+    description: "Description",
+    status: "Status",
+    priority: "Priority",
+    requester_name: "Full name",
+    requester_email: "Email",
+    created_at: "Created time",
+    updated_at: "Last updated time",
+    tags: "Tags",
+    source: ImportSource::Freshdesk,
+};
+
+fn parse_csv(data: &str, columns: &CsvColumns) -> Result<ParsedExport, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(data.trim_start_matches('\u{feff}').as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| format!("Invalid CSV header: {}", e))?
+        .clone();
+    let column = |name: &str| {
+        headers
+            .iter()
+            .position(|header| header.trim().eq_ignore_ascii_case(name))
+    };
+    let id_column =
+        column(columns.id).ok_or_else(|| format!("CSV export has no \"{}\" column", columns.id))?;
+
+    let mut export = ParsedExport::default();
+    for (index, record) in reader.records().enumerate() {
+        let row = index + 2;
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                export.invalid_records.push(format!("Row {}: {}", row, e));
+                continue;
+            }
+        };
+        let field = |name: &str| {
+            column(name)
+                .and_then(|i| record.get(i))
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+
+        let external_id = match record.get(id_column).map(str::trim) {
+            Some(id) if !id.is_empty() => id.to_string(),
+            _ => {
+                export
+                    .invalid_records
+                    .push(format!("Row {}: missing ticket id", row));
+                continue;
+            }
+        };
+        let Some(email) = field(columns.requester_email).and_then(|e| normalize_email(&e)) else {
+            export
+                .invalid_records
+                .push(format!("Ticket {}: missing requester email", external_id));
+            continue;
+        };
+        let Some(created_at) = field(columns.created_at).and_then(|t| normalize_timestamp(&t))
+        else {
+            export.invalid_records.push(format!(
+                "Ticket {}: missing or invalid created time",
+                external_id
+            ));
+            continue;
+        };
+        let updated_at = field(columns.updated_at)
+            .and_then(|t| normalize_timestamp(&t))
+            .unwrap_or_else(|| created_at.clone());
+
+        let (status, priority) = match columns.source {
+            ImportSource::Zendesk => (
+                zendesk_status(field(columns.status).as_deref()),
+                zendesk_priority(field(columns.priority).as_deref()),
+            ),
+            ImportSource::Freshdesk => (
+                freshdesk_status(field(columns.status).map(Value::String).as_ref()),
+                freshdesk_priority(field(columns.priority).map(Value::String).as_ref()),
+            ),
+        };
+
+        let messages = field(columns.description)
+            .map(|body| ImportedMessage {
+                external_id: format!("{}-description", external_id),
+                body,
+                from_agent: false,
+                author_email: Some(email.clone()),
+                created_at: created_at.clone(),
+                attachments: Vec::new(),
+            })
+            .into_iter()
+            .collect();
+
+        export.tickets.push(ImportedTicket {
+            subject: field(columns.subject),
+            status,
+            priority,
+            requester: ImportedContact {
+                email,
+                name: field(columns.requester_name),
+            },
+            tags: field(columns.tags)
+                .map(|tags| split_tags(&tags))
+                .unwrap_or_default(),
+            created_at,
+            updated_at,
+            messages,
+            external_id,
+        });
+    }
+
+    Ok(export)
+}
+
+fn zendesk_status(status: Option<&str>) -> ConversationStatus {
+    match status.map(str::to_lowercase).as_deref() {
+        Some("solved") => ConversationStatus::Resolved,
+        Some("closed") => ConversationStatus::Closed,
+        _ => ConversationStatus::Open,
+    }
+}
+
+fn zendesk_priority(priority: Option<&str>) -> Option<Priority> {
+    match priority?.to_lowercase().as_str() {
+        "low" => Some(Priority::Low),
+        "normal" => Some(Priority::Medium),
+        "high" | "urgent" => Some(Priority::High),
+        _ => None,
+    }
+}
+
+/// Freshdesk statuses are numbers in the API (2 open, 3 pending, 4 resolved, 5 closed)
+/// and names in CSV exports
+fn freshdesk_status(status: Option<&Value>) -> ConversationStatus {
+    let status = match status {
+        Some(Value::Number(n)) => n.to_string(),
+        Some(Value::String(s)) => s.to_lowercase(),
+        _ => return ConversationStatus::Open,
+    };
+    match status.as_str() {
+        "4" | "resolved" => ConversationStatus::Resolved,
+        "5" | "closed" => ConversationStatus::Closed,
+        _ => ConversationStatus::Open,
+    }
+}
+
+/// Freshdesk priorities are numbers in the API (1 low to 4 urgent) and names in CSV exports
+fn freshdesk_priority(priority: Option<&Value>) -> Option<Priority> {
+    let priority = match priority? {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.to_lowercase(),
+        _ => return None,
+    };
+    match priority.as_str() {
+        "1" | "low" => Some(Priority::Low),
+        "2" | "medium" => Some(Priority::Medium),
+        "3" | "4" | "high" | "urgent" => Some(Priority::High),
+        _ => None,
+    }
+}
+
+fn description_message(
+    ticket: &Value,
+    field: &str,
+    ticket_id: &str,
+    created_at: &str,
+) -> Option<ImportedMessage> {
+    Some(ImportedMessage {
+        external_id: format!("{}-description", ticket_id),
+        body: string_field(ticket, field)?,
+        from_agent: false,
+        author_email: None,
+        created_at: created_at.to_string(),
+        attachments: Vec::new(),
+    })
+}
+
+fn contact_from_json(value: &Value) -> Option<ImportedContact> {
+    Some(ImportedContact {
+        email: normalize_email(&string_field(value, "email")?)?,
+        name: string_field(value, "name"),
+    })
+}
+
+fn attachment_from_json(
+    value: &Value,
+    name_field: &str,
+    url_field: &str,
+    type_field: &str,
+) -> Option<ImportedAttachment> {
+    let url = string_field(value, url_field)?;
+    let file_name = string_field(value, name_field)?;
+    Some(ImportedAttachment {
+        external_id: value
+            .get("id")
+            .and_then(id_string)
+            .unwrap_or_else(|| url.clone()),
+        file_name,
+        content_type: string_field(value, type_field),
+        url,
+    })
+}
+
+fn tags_field(value: &Value) -> Vec<String> {
+    match value.get("tags") {
+        Some(Value::Array(tags)) => tags
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Some(Value::String(tags)) => split_tags(tags),
+        _ => Vec::new(),
+    }
+}
+
+/// Tags in CSV exports are separated by spaces (Zendesk) or commas (Freshdesk)
+fn split_tags(tags: &str) -> Vec<String> {
+    let separator: &[char] = if tags.contains(',') { &[','] } else { &[' '] };
+    tags.split(separator)
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn array_field<'a>(value: &'a Value, field: &str) -> &'a [Value] {
+    value
+        .get(field)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn string_field(value: &Value, field: &str) -> Option<String> {
+    value
+        .get(field)?
+        .as_str()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// Ids are numbers in Zendesk and Freshdesk exports but may be strings elsewhere
+fn id_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        _ => None,
+    }
+}
+
+fn timestamp_field(value: &Value, field: &str) -> Result<String, String> {
+    optional_timestamp(value, field).ok_or_else(|| format!("missing or invalid {}", field))
+}
+
+fn optional_timestamp(value: &Value, field: &str) -> Option<String> {
+    normalize_timestamp(&string_field(value, field)?)
+}
+
+fn normalize_email(email: &str) -> Option<String> {
+    let email = email.trim().to_lowercase();
+    email.contains('@').then_some(email)
+}
+
+/// Convert an export timestamp to RFC 3339 in UTC
+///
+/// Accepts RFC 3339 and the `YYYY-MM-DD HH:MM:SS` forms used by CSV exports,
+/// with or without a numeric offset; timestamps without an offset are UTC.
+pub fn normalize_timestamp(value: &str) -> Option<String> {
+    let value = value.trim();
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(value) {
+        return Some(parsed.with_timezone(&Utc).to_rfc3339());
+    }
+    for format in ["%Y-%m-%d %H:%M:%S %z", "%Y-%m-%d %H:%M %z"] {
+        if let Ok(parsed) = DateTime::parse_from_str(value, format) {
+            return Some(parsed.with_timezone(&Utc).to_rfc3339());
+        }
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S"] {
+        if let Ok(parsed) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(parsed.and_utc().to_rfc3339());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zendesk_json_with_side_loaded_users() {
+        let data = r#"{
+            "tickets": [{
+                "id": 101,
+                "subject": "Refund",
+                "status": "solved",
+                "priority": "urgent",
+                "requester_id": 1,
+                "tags": ["billing", "vip"],
+                "created_at": "2023-03-01T09:00:00Z",
+                "updated_at": "2023-03-02T10:00:00Z",
+                "comments": [
+                    {"id": 1001, "author_id": 1, "public": true, "plain_body": "I want a refund",
+                     "created_at": "2023-03-01T09:00:00Z",
+                     "attachments": [{"id": 5, "file_name": "invoice.pdf",
+                                      "content_type": "application/pdf",
+                                      "content_url": "https://example.zendesk.com/a/5"}]},
+                    {"id": 1002, "author_id": 2, "public": false, "plain_body": "Check plan",
+                     "created_at": "2023-03-01T09:30:00Z"},
+                    {"id": 1003, "author_id": 2, "public": true, "plain_body": "Done",
+                     "created_at": "2023-03-01T10:00:00Z"}
+                ]
+            }],
+            "users": [
+                {"id": 1, "name": "Ada", "email": "Ada@Example.com", "role": "end-user"},
+                {"id": 2, "name": "Sam", "email": "sam@support.example.com", "role": "agent"}
+            ]
+        }"#;
+
+        let export =
+            parse_helpdesk_export(ImportSource::Zendesk, ImportFormat::Json, data).unwrap();
+        assert!(export.invalid_records.is_empty());
+        assert_eq!(export.private_notes_skipped, 1);
+
+        let ticket = &export.tickets[0];
+        assert_eq!(ticket.external_id, "101");
+        assert_eq!(ticket.status, ConversationStatus::Resolved);
+        assert_eq!(ticket.priority, Some(Priority::High));
+        assert_eq!(ticket.requester.email, "ada@example.com");
+        assert_eq!(ticket.tags, vec!["billing", "vip"]);
+        assert_eq!(ticket.messages.len(), 2);
+        assert!(!ticket.messages[0].from_agent);
+        assert_eq!(ticket.messages[0].attachments[0].file_name, "invoice.pdf");
+        assert!(ticket.messages[1].from_agent);
+        assert_eq!(
+            ticket.messages[1].author_email.as_deref(),
+            Some("sam@support.example.com")
+        );
+        assert_eq!(ticket.messages[1].created_at, "2023-03-01T10:00:00+00:00");
+    }
+
+    #[test]
+    fn test_newline_delimited_json_reports_bad_tickets() {
+        let data = concat!(
+            r#"{"id": 1, "requester": {"email": "a@example.com"}, "created_at": "2023-01-01T00:00:00Z", "description": "Hi"}"#,
+            "\n",
+            r#"{"id": 2, "created_at": "2023-01-01T00:00:00Z"}"#,
+            "\n"
+        );
+
+        let export =
+            parse_helpdesk_export(ImportSource::Zendesk, ImportFormat::Json, data).unwrap();
+        assert_eq!(export.tickets.len(), 1);
+        assert_eq!(export.tickets[0].messages[0].body, "Hi");
+        assert_eq!(
+            export.invalid_records,
+            vec!["Ticket 2: missing requester email"]
+        );
+    }
+
+    #[test]
+    fn test_freshdesk_json_numeric_fields() {
+        let data = r#"[{
+            "id": 7,
+            "subject": "Login",
+            "description_text": "Can't log in",
+            "status": 5,
+            "priority": 2,
+            "requester": {"email": "bo@example.com", "name": "Bo"},
+            "created_at": "2023-05-01T08:00:00Z",
+            "updated_at": "2023-05-01T09:00:00Z",
+            "conversations": [
+                {"id": 70, "body_text": "Reset sent", "incoming": false, "private": false,
+                 "from_email": "help@example.com", "created_at": "2023-05-01T08:30:00Z"}
+            ]
+        }]"#;
+
+        let export =
+            parse_helpdesk_export(ImportSource::Freshdesk, ImportFormat::Json, data).unwrap();
+        let ticket = &export.tickets[0];
+        assert_eq!(ticket.status, ConversationStatus::Closed);
+        assert_eq!(ticket.priority, Some(Priority::Medium));
+        assert_eq!(ticket.messages.len(), 2);
+        assert_eq!(ticket.messages[0].external_id, "7-description");
+        assert!(ticket.messages[1].from_agent);
+    }
+
+    #[test]
+    fn test_csv_exports() {
+        let zendesk = "Id,Subject,Status,Priority,Requester,Requester email,Created at,Updated at,Tags,Description\n\
+                       12,Shipping,open,normal,Cy,cy@example.com,2023-02-01 10:00:00 +0100,,late shipping,\"Where is it, please?\"\n\
+                       13,No email,open,low,Di,,2023-02-01 10:00:00,,,Hello\n";
+        let export =
+            parse_helpdesk_export(ImportSource::Zendesk, ImportFormat::Csv, zendesk).unwrap();
+        assert_eq!(export.tickets.len(), 1);
+        assert_eq!(
+            export.invalid_records,
+            vec!["Ticket 13: missing requester email"]
+        );
+        let ticket = &export.tickets[0];
+        assert_eq!(ticket.created_at, "2023-02-01T09:00:00+00:00");
+        assert_eq!(ticket.priority, Some(Priority::Medium));
+        assert_eq!(ticket.tags, vec!["late", "shipping"]);
+        assert_eq!(ticket.messages[0].body, "Where is it, please?");
+
+        let freshdesk = "Ticket ID,Subject,Description,Status,Priority,Full name,Email,Created time,Last updated time,Tags\n\
+                         9,Invoice,Send invoice,Resolved,Urgent,Ed,ed@example.com,2023-04-01 12:00:00,2023-04-02 12:00:00,\"billing,invoices\"\n";
+        let export =
+            parse_helpdesk_export(ImportSource::Freshdesk, ImportFormat::Csv, freshdesk).unwrap();
+        let ticket = &export.tickets[0];
+        assert_eq!(ticket.status, ConversationStatus::Resolved);
+        assert_eq!(ticket.priority, Some(Priority::High));
+        assert_eq!(ticket.tags, vec!["billing", "invoices"]);
+        assert_eq!(ticket.updated_at, "2023-04-02T12:00:00+00:00");
+    }
+
+    #[test]
+    fn test_csv_without_id_column_is_rejected() {
+        let result =
+            parse_helpdesk_export(ImportSource::Freshdesk, ImportFormat::Csv, "Subject\nHi\n");
+        assert!(result.is_err());
+    }
+}
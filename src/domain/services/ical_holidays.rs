@@ -0,0 +1,227 @@
+//! Minimal iCalendar (RFC 5545) reader for importing holiday calendars
+//!
+//! Only the parts used by public holiday feeds are supported: VEVENT blocks
+//! with SUMMARY, DTSTART, an optional all-day DTEND and a yearly RRULE.
+
+use chrono::NaiveDate;
+
+/// Longest multi-day event expanded into individual holidays
+const MAX_EVENT_DAYS: i64 = 31;
+
+/// A holiday read from an iCalendar VEVENT
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedHoliday {
+    pub name: String,
+    pub date: String, // YYYY-MM-DD
+    pub recurring: bool,
+}
+
+/// Result of reading an iCalendar document
+#[derive(Debug, Default)]
+pub struct ParsedHolidays {
+    pub holidays: Vec<ParsedHoliday>,
+    /// Events skipped because they had no usable start date
+    pub invalid_events: usize,
+}
+
+#[derive(Default)]
+struct EventFields {
+    summary: Option<String>,
+    dtstart: Option<String>,
+    dtend: Option<String>,
+    rrule: Option<String>,
+}
+
+/// Parse the holidays out of an iCalendar document
+pub fn parse_ical_holidays(ics: &str) -> Result<ParsedHolidays, String> {
+    let lines = unfold_lines(ics);
+
+    if !lines
+        .iter()
+        .any(|line| line.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err("Not an iCalendar document (missing BEGIN:VCALENDAR)".to_string());
+    }
+
+    let mut result = ParsedHolidays::default();
+    let mut event: Option<EventFields> = None;
+
+    for line in &lines {
+        if line.eq_ignore_ascii_case("BEGIN:VEVENT") {
+            event = Some(EventFields::default());
+            continue;
+        }
+
+        if line.eq_ignore_ascii_case("END:VEVENT") {
+            if let Some(fields) = event.take() {
+                match event_to_holidays(fields) {
+                    Some(holidays) => result.holidays.extend(holidays),
+                    None => result.invalid_events += 1,
+                }
+            }
+            continue;
+        }
+
+        let Some(fields) = event.as_mut() else {
+            continue;
+        };
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        // Property parameters (e.g. DTSTART;VALUE=DATE) are not needed
+        let property = name
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_uppercase();
+        let value = value.trim().to_string();
+
+        match property.as_str() {
+            "SUMMARY" => fields.summary = Some(unescape_text(&value)),
+            "DTSTART" => fields.dtstart = Some(value),
+            "DTEND" => fields.dtend = Some(value),
+            "RRULE" => fields.rrule = Some(value.to_ascii_uppercase()),
+            _ => {}
+        }
+    }
+
+    Ok(result)
+}
+
+/// Join folded continuation lines (lines starting with a space or tab)
+pub(crate) fn unfold_lines(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for raw in ics.lines() {
+        let raw = raw.trim_end_matches('\r');
+        if let Some(continuation) = raw.strip_prefix([' ', '\t']) {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(continuation);
+                continue;
+            }
+        }
+        if !raw.trim().is_empty() {
+            lines.push(raw.to_string());
+        }
+    }
+    lines
+}
+
+pub(crate) fn unescape_text(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => result.push(' '),
+                Some(other) => result.push(other),
+                None => {}
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result.trim().to_string()
+}
+
+/// Read the date part of a DATE (20250101) or DATE-TIME (20250101T090000Z) value
+fn parse_ical_date(value: &str) -> Option<NaiveDate> {
+    let digits = value.get(..8)?;
+    NaiveDate::parse_from_str(digits, "%Y%m%d").ok()
+}
+
+fn event_to_holidays(fields: EventFields) -> Option<Vec<ParsedHoliday>> {
+    let start = parse_ical_date(fields.dtstart.as_deref()?)?;
+    let name = fields
+        .summary
+        .filter(|summary| !summary.is_empty())
+        .unwrap_or_else(|| "Holiday".to_string());
+    let recurring = fields
+        .rrule
+        .as_deref()
+        .is_some_and(|rrule| rrule.contains("FREQ=YEARLY"));
+
+    // All-day events have an exclusive DTEND; expand multi-day ranges
+    let days = match fields.dtend.as_deref() {
+        Some(end) if end.len() == 8 => parse_ical_date(end)
+            .map(|end| (end - start).num_days().clamp(1, MAX_EVENT_DAYS))
+            .unwrap_or(1),
+        _ => 1,
+    };
+
+    Some(
+        (0..days)
+            .map(|offset| ParsedHoliday {
+                name: name.clone(),
+                date: (start + chrono::Duration::days(offset))
+                    .format("%Y-%m-%d")
+                    .to_string(),
+                recurring,
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_all_day_and_recurring_events() {
+        let ics = "BEGIN:VCALENDAR\r\n\
+                   VERSION:2.0\r\n\
+                   BEGIN:VEVENT\r\n\
+                   DTSTART;VALUE=DATE:20251225\r\n\
+                   DTEND;VALUE=DATE:20251226\r\n\
+                   SUMMARY:Christmas Day\r\n\
+                   RRULE:FREQ=YEARLY\r\n\
+                   END:VEVENT\r\n\
+                   BEGIN:VEVENT\r\n\
+                   DTSTART:20250418T000000Z\r\n\
+                   SUMMARY:Good Friday\\, observed\r\n\
+                   END:VEVENT\r\n\
+                   END:VCALENDAR\r\n";
+
+        let parsed = parse_ical_holidays(ics).unwrap();
+        assert_eq!(parsed.invalid_events, 0);
+        assert_eq!(
+            parsed.holidays,
+            vec![
+                ParsedHoliday {
+                    name: "Christmas Day".to_string(),
+                    date: "2025-12-25".to_string(),
+                    recurring: true,
+                },
+                ParsedHoliday {
+                    name: "Good Friday, observed".to_string(),
+                    date: "2025-04-18".to_string(),
+                    recurring: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multi_day_events_and_folded_lines() {
+        let ics = "BEGIN:VCALENDAR\n\
+                   BEGIN:VEVENT\n\
+                   DTSTART;VALUE=DATE:20250501\n\
+                   DTEND;VALUE=DATE:20250503\n\
+                   SUMMARY:Golden\n  Week\n\
+                   END:VEVENT\n\
+                   BEGIN:VEVENT\n\
+                   SUMMARY:No start date\n\
+                   END:VEVENT\n\
+                   END:VCALENDAR\n";
+
+        let parsed = parse_ical_holidays(ics).unwrap();
+        assert_eq!(parsed.invalid_events, 1);
+        let dates: Vec<&str> = parsed.holidays.iter().map(|h| h.date.as_str()).collect();
+        assert_eq!(dates, vec!["2025-05-01", "2025-05-02"]);
+        assert_eq!(parsed.holidays[0].name, "Golden Week");
+    }
+
+    #[test]
+    fn test_rejects_non_calendar_input() {
+        assert!(parse_ical_holidays("not a calendar").is_err());
+    }
+}
@@ -0,0 +1,320 @@
+//! iCalendar (RFC 5545) meeting invites carried by inbound email
+//!
+//! Reads the first VEVENT of an iTIP payload (RFC 5546): its method, times,
+//! organizer, location and attendees with their participation status.
+
+use chrono::{NaiveDate, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
+
+use super::ical_holidays::{unescape_text, unfold_lines};
+use crate::domain::entities::{
+    AttendeeResponse, CalendarAttendee, CalendarInvite, CalendarInviteMethod, CalendarOrganizer,
+};
+
+/// One content line: `NAME;PARAM=value:VALUE`
+struct ContentLine {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl ContentLine {
+    fn parse(line: &str) -> Option<Self> {
+        // The value starts at the first colon outside a quoted parameter
+        let mut in_quotes = false;
+        let split = line.char_indices().find_map(|(i, c)| match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                None
+            }
+            ':' if !in_quotes => Some(i),
+            _ => None,
+        })?;
+        let (head, value) = (&line[..split], &line[split + 1..]);
+
+        let mut parts = head.split(';');
+        let name = parts.next()?.trim().to_ascii_uppercase();
+        let params = parts
+            .filter_map(|param| param.split_once('='))
+            .map(|(key, value)| {
+                (
+                    key.trim().to_ascii_uppercase(),
+                    value.trim().trim_matches('"').to_string(),
+                )
+            })
+            .collect();
+
+        Some(Self {
+            name,
+            params,
+            value: value.trim().to_string(),
+        })
+    }
+
+    fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[derive(Default)]
+struct EventFields {
+    uid: Option<String>,
+    title: Option<String>,
+    description: Option<String>,
+    location: Option<String>,
+    start: Option<ContentLine>,
+    end: Option<ContentLine>,
+    organizer: Option<CalendarOrganizer>,
+    attendees: Vec<CalendarAttendee>,
+    sequence: i64,
+    cancelled: bool,
+}
+
+/// Parse the meeting invite out of an iCalendar payload
+///
+/// Returns `None` when the payload has no VEVENT. A missing METHOD is read
+/// as PUBLISH.
+pub fn parse_calendar_invite(ics: &str) -> Option<CalendarInvite> {
+    let lines = unfold_lines(ics);
+    if !lines
+        .iter()
+        .any(|line| line.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return None;
+    }
+
+    let mut method = CalendarInviteMethod::Publish;
+    let mut event: Option<EventFields> = None;
+    // Components nested inside the event (VALARM) have their own properties
+    let mut nested_depth = 0usize;
+
+    for line in &lines {
+        let Some(line) = ContentLine::parse(line) else {
+            continue;
+        };
+
+        match (line.name.as_str(), event.as_mut()) {
+            ("METHOD", None) => {
+                method = line.value.parse().unwrap_or(CalendarInviteMethod::Publish);
+            }
+            ("BEGIN", None) if line.value.eq_ignore_ascii_case("VEVENT") => {
+                event = Some(EventFields::default());
+            }
+            ("BEGIN", Some(_)) => nested_depth += 1,
+            ("END", Some(_)) if nested_depth > 0 => nested_depth -= 1,
+            ("END", Some(_)) if line.value.eq_ignore_ascii_case("VEVENT") => break,
+            (_, Some(fields)) if nested_depth == 0 => read_event_property(fields, line),
+            _ => {}
+        }
+    }
+
+    let fields = event?;
+    let tz = fields
+        .start
+        .as_ref()
+        .and_then(|start| start.param("TZID"))
+        .map(str::to_string);
+    let (starts_at, start_all_day) = fields.start.as_ref().map(parse_time).unzip();
+    let (ends_at, _) = fields.end.as_ref().map(parse_time).unzip();
+
+    let mut invite = CalendarInvite {
+        method,
+        uid: fields.uid,
+        title: fields.title,
+        description: fields.description,
+        location: fields.location,
+        starts_at: starts_at.flatten(),
+        ends_at: ends_at.flatten(),
+        all_day: start_all_day.unwrap_or(false),
+        timezone: tz,
+        organizer: fields.organizer,
+        attendees: fields.attendees,
+        sequence: fields.sequence,
+        cancelled: fields.cancelled || method == CalendarInviteMethod::Cancel,
+        summary: String::new(),
+    };
+    invite.summary = invite.render_summary();
+    Some(invite)
+}
+
+fn read_event_property(fields: &mut EventFields, line: ContentLine) {
+    let text = || Some(unescape_text(&line.value)).filter(|text| !text.is_empty());
+    match line.name.as_str() {
+        "UID" => fields.uid = text(),
+        "SUMMARY" => fields.title = text(),
+        "DESCRIPTION" => fields.description = text(),
+        "LOCATION" => fields.location = text(),
+        "SEQUENCE" => fields.sequence = line.value.parse().unwrap_or(0),
+        "STATUS" => fields.cancelled = line.value.eq_ignore_ascii_case("CANCELLED"),
+        "ORGANIZER" => {
+            if let Some(email) = calendar_address(&line.value) {
+                fields.organizer = Some(CalendarOrganizer {
+                    email,
+                    name: line.param("CN").map(str::to_string),
+                });
+            }
+        }
+        "ATTENDEE" => {
+            if let Some(email) = calendar_address(&line.value) {
+                fields.attendees.push(CalendarAttendee {
+                    email,
+                    name: line.param("CN").map(str::to_string),
+                    response: line
+                        .param("PARTSTAT")
+                        .map(AttendeeResponse::from_partstat)
+                        .unwrap_or_default(),
+                    rsvp: line
+                        .param("RSVP")
+                        .is_some_and(|rsvp| rsvp.eq_ignore_ascii_case("TRUE")),
+                });
+            }
+        }
+        "DTSTART" => fields.start = Some(line),
+        "DTEND" => fields.end = Some(line),
+        _ => {}
+    }
+}
+
+/// Email address of a CAL-ADDRESS value (`mailto:someone@example.com`)
+fn calendar_address(value: &str) -> Option<String> {
+    let address = value
+        .get(..7)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+        .map(|_| &value[7..])
+        .unwrap_or(value)
+        .trim();
+    (!address.is_empty() && address.contains('@')).then(|| address.to_lowercase())
+}
+
+/// Read a DTSTART/DTEND value, returning the formatted time and whether it's all-day
+fn parse_time(line: &ContentLine) -> (Option<String>, bool) {
+    let value = line.value.as_str();
+    let is_date = line
+        .param("VALUE")
+        .is_some_and(|kind| kind.eq_ignore_ascii_case("DATE"))
+        || value.len() == 8;
+    if is_date {
+        let date = NaiveDate::parse_from_str(value.get(..8).unwrap_or(value), "%Y%m%d").ok();
+        return (date.map(|d| d.format("%Y-%m-%d").to_string()), true);
+    }
+
+    let Ok(local) = NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), "%Y%m%dT%H%M%S")
+    else {
+        return (None, false);
+    };
+    if value.ends_with('Z') {
+        return (Some(local.and_utc().to_rfc3339()), false);
+    }
+    let zoned = line
+        .param("TZID")
+        .and_then(|tzid| tzid.parse::<Tz>().ok())
+        .and_then(|tz| tz.from_local_datetime(&local).earliest())
+        .map(|time| time.with_timezone(&chrono::Utc).to_rfc3339());
+    match zoned {
+        Some(time) => (Some(time), false),
+        // Floating time, or a time zone we don't know: keep the wall-clock time
+        None => (Some(local.format("%Y-%m-%dT%H:%M:%S").to_string()), false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_meeting_request() {
+        let ics = "BEGIN:VCALENDAR\r\n\
+                   METHOD:REQUEST\r\n\
+                   BEGIN:VEVENT\r\n\
+                   UID:abc-123@example.com\r\n\
+                   SEQUENCE:2\r\n\
+                   SUMMARY:Renewal call\r\n\
+                   LOCATION:Room 4\\, 2nd floor\r\n\
+                   DTSTART;TZID=Europe/Berlin:20261020T140000\r\n\
+                   DTEND;TZID=Europe/Berlin:20261020T150000\r\n\
+                   ORGANIZER;CN=\"Doe: Jane\":mailto:Jane@Example.com\r\n\
+                   ATTENDEE;CN=Support;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:support@oxidesk.io\r\n\
+                   ATTENDEE;PARTSTAT=ACCEPTED:mailto:jane@example.com\r\n\
+                   BEGIN:VALARM\r\n\
+                   DESCRIPTION:Reminder\r\n\
+                   END:VALARM\r\n\
+                   END:VEVENT\r\n\
+                   END:VCALENDAR\r\n";
+
+        let invite = parse_calendar_invite(ics).unwrap();
+        assert_eq!(invite.method, CalendarInviteMethod::Request);
+        assert_eq!(invite.uid.as_deref(), Some("abc-123@example.com"));
+        assert_eq!(invite.sequence, 2);
+        assert_eq!(invite.location.as_deref(), Some("Room 4, 2nd floor"));
+        assert_eq!(invite.description, None);
+        assert_eq!(
+            invite.starts_at.as_deref(),
+            Some("2026-10-20T12:00:00+00:00")
+        );
+        assert_eq!(invite.ends_at.as_deref(), Some("2026-10-20T13:00:00+00:00"));
+        assert_eq!(invite.timezone.as_deref(), Some("Europe/Berlin"));
+        assert!(!invite.all_day);
+        let organizer = invite.organizer.as_ref().unwrap();
+        assert_eq!(organizer.email, "jane@example.com");
+        assert_eq!(organizer.name.as_deref(), Some("Doe: Jane"));
+        assert_eq!(invite.attendees.len(), 2);
+        assert!(invite.attendees[0].rsvp);
+        assert_eq!(
+            invite.response_of("support@oxidesk.io"),
+            Some(AttendeeResponse::NeedsAction)
+        );
+        assert_eq!(
+            invite.summary,
+            "Invitation: Renewal call\n\
+             When: 2026-10-20T12:00:00+00:00 to 2026-10-20T13:00:00+00:00\n\
+             Organizer: Doe: Jane <jane@example.com>\n\
+             Location: Room 4, 2nd floor"
+        );
+    }
+
+    #[test]
+    fn test_parse_reply_and_all_day_event() {
+        let ics = "BEGIN:VCALENDAR\n\
+                   METHOD:REPLY\n\
+                   BEGIN:VEVENT\n\
+                   SUMMARY:Onboarding\n\
+                   DTSTART;VALUE=DATE:20261102\n\
+                   ATTENDEE;CN=Sam;PARTSTAT=DECLINED:mailto:sam@example.com\n\
+                   END:VEVENT\n\
+                   END:VCALENDAR\n";
+
+        let invite = parse_calendar_invite(ics).unwrap();
+        assert_eq!(invite.method, CalendarInviteMethod::Reply);
+        assert!(invite.all_day);
+        assert_eq!(invite.starts_at.as_deref(), Some("2026-11-02"));
+        assert_eq!(
+            invite.response_of("SAM@example.com"),
+            Some(AttendeeResponse::Declined)
+        );
+        assert_eq!(
+            invite.summary,
+            "Reply: Onboarding (Sam declined)\nWhen: 2026-11-02 (all day)"
+        );
+    }
+
+    #[test]
+    fn test_cancelled_and_invalid_payloads() {
+        let ics = "BEGIN:VCALENDAR\n\
+                   METHOD:CANCEL\n\
+                   BEGIN:VEVENT\n\
+                   SUMMARY:Renewal call\n\
+                   DTSTART:20261020T120000Z\n\
+                   STATUS:CANCELLED\n\
+                   END:VEVENT\n\
+                   END:VCALENDAR\n";
+        let invite = parse_calendar_invite(ics).unwrap();
+        assert!(invite.cancelled);
+        assert!(invite.summary.starts_with("Cancelled: Renewal call"));
+
+        assert!(parse_calendar_invite("BEGIN:VCALENDAR\nEND:VCALENDAR\n").is_none());
+        assert!(parse_calendar_invite("not a calendar").is_none());
+    }
+}
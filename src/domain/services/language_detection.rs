@@ -0,0 +1,168 @@
+/// Fewest stopword matches needed before a language is reported
+const MIN_MATCHES: usize = 2;
+
+/// Common function words per ISO 639-1 code. Words shared by several
+/// languages count for each of them; the distinctive ones decide.
+const STOPWORDS: &[(&str, &[&str])] = &[
+    (
+        "de",
+        &[
+            "der", "die", "das", "und", "ist", "nicht", "ich", "sie", "es", "ein", "eine", "mit",
+            "für", "auf", "mein", "meine", "meinem", "hallo", "danke", "bitte", "habe", "wie",
+            "zu", "den", "dem", "von", "kann", "mich", "mir",
+        ],
+    ),
+    (
+        "en",
+        &[
+            "the", "and", "is", "are", "was", "to", "of", "it", "that", "this", "you", "my",
+            "have", "with", "for", "not", "please", "can", "i", "be", "what", "how", "me", "on",
+            "hello", "thanks", "hi",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el", "la", "los", "las", "de", "que", "y", "en", "es", "un", "una", "por", "para",
+            "con", "no", "mi", "hola", "gracias", "está", "pero", "cómo", "qué", "tengo", "favor",
+            "su", "del", "al", "puedo",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le", "la", "les", "de", "des", "du", "et", "est", "un", "une", "je", "vous", "que",
+            "pas", "pour", "avec", "mon", "ma", "bonjour", "merci", "ce", "il", "ne", "sur", "au",
+            "suis", "peux",
+        ],
+    ),
+    (
+        "it",
+        &[
+            "il", "lo", "la", "gli", "le", "di", "che", "e", "è", "un", "una", "non", "per", "con",
+            "mio", "mia", "ciao", "grazie", "sono", "ho", "del", "della", "come", "questo", "ma",
+            "riesco",
+        ],
+    ),
+    (
+        "nl",
+        &[
+            "de", "het", "een", "en", "is", "van", "dat", "niet", "ik", "je", "met", "voor", "op",
+            "mijn", "hallo", "bedankt", "dank", "zijn", "heb", "wat", "hoe", "maar", "ook",
+            "graag", "kan",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "o", "a", "os", "as", "de", "que", "e", "é", "um", "uma", "não", "para", "com", "meu",
+            "minha", "olá", "obrigado", "obrigada", "do", "da", "no", "na", "em", "por", "você",
+            "está", "consigo",
+        ],
+    ),
+];
+
+/// Languages `detect_language` can report, as ISO 639-1 codes
+pub fn detectable_languages() -> Vec<&'static str> {
+    STOPWORDS.iter().map(|(code, _)| *code).collect()
+}
+
+/// Whether `code` is a language `detect_language` can report
+pub fn is_detectable_language(code: &str) -> bool {
+    STOPWORDS.iter().any(|(c, _)| *c == code)
+}
+
+/// Guess the language of a message from its function words
+///
+/// Returns the ISO 639-1 code of the language with the most stopword matches,
+/// or `None` when the text is too short or two languages tie.
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .collect();
+
+    let mut scores: Vec<(&'static str, usize)> = STOPWORDS
+        .iter()
+        .map(|(code, stopwords)| {
+            let matches = words
+                .iter()
+                .filter(|w| stopwords.contains(&w.as_str()))
+                .count();
+            (*code, matches)
+        })
+        .collect();
+    scores.sort_by_key(|(_, matches)| std::cmp::Reverse(*matches));
+
+    match scores.as_slice() {
+        [(code, best), (_, runner_up), ..] if *best >= MIN_MATCHES && best > runner_up => {
+            Some(code)
+        }
+        _ => None,
+    }
+}
+
+/// Whether `code` looks like an ISO 639-1 language code
+pub fn is_language_code(code: &str) -> bool {
+    code.len() == 2 && code.chars().all(|c| c.is_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detects_supported_languages() {
+        let samples = [
+            (
+                "en",
+                "Hello, I can't log in to my account and the reset link is not working",
+            ),
+            (
+                "es",
+                "Hola, no puedo acceder a mi cuenta y el enlace de recuperación no funciona",
+            ),
+            ("fr", "Bonjour, je ne peux pas me connecter à mon compte"),
+            (
+                "de",
+                "Hallo, ich kann mich nicht bei meinem Konto anmelden, bitte helfen Sie mir",
+            ),
+            (
+                "pt",
+                "Olá, não consigo acessar minha conta, o link não funciona",
+            ),
+            (
+                "it",
+                "Ciao, non riesco ad accedere al mio account, il link non funziona",
+            ),
+            (
+                "nl",
+                "Hallo, ik kan niet inloggen op mijn account, de link werkt niet",
+            ),
+        ];
+        for (expected, text) in samples {
+            assert_eq!(detect_language(text), Some(expected), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_short_or_ambiguous_text_is_undetected() {
+        assert_eq!(detect_language(""), None);
+        assert_eq!(detect_language("Thanks!"), None);
+        assert_eq!(detect_language("Order #12345 / SKU 998-AB"), None);
+        // "de" and "la" count for Spanish and French alike
+        assert_eq!(detect_language("de la"), None);
+    }
+
+    #[test]
+    fn test_language_codes() {
+        assert!(is_language_code("en"));
+        assert!(is_language_code("ja"));
+        assert!(!is_language_code("EN"));
+        assert!(!is_language_code("eng"));
+        assert!(is_detectable_language("pt"));
+        assert!(!is_detectable_language("ja"));
+        assert_eq!(detectable_languages().len(), 7);
+    }
+}
@@ -1,11 +1,37 @@
 pub mod action_executor;
+pub mod chat_widget;
 pub mod condition_evaluator;
+pub mod conversation_summary;
+pub mod email_authentication;
+pub mod email_oauth;
+pub mod helpdesk_import;
+pub mod ical_holidays;
+pub mod ical_invites;
+pub mod language_detection;
 pub mod password_service;
+pub mod sentiment;
+pub mod shift_schedule;
+pub mod sla_simulation;
+pub mod sms;
 pub mod state_machine;
+pub mod telegram;
 pub mod webhook_signature;
 
 pub use action_executor::*;
+pub use chat_widget::*;
 pub use condition_evaluator::*;
+pub use conversation_summary::*;
+pub use email_authentication::*;
+pub use email_oauth::*;
+pub use helpdesk_import::*;
+pub use ical_holidays::*;
+pub use ical_invites::*;
+pub use language_detection::*;
 pub use password_service::*;
+pub use sentiment::*;
+pub use shift_schedule::*;
+pub use sla_simulation::*;
+pub use sms::*;
 pub use state_machine::*;
+pub use telegram::*;
 pub use webhook_signature::*;
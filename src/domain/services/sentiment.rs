@@ -0,0 +1,223 @@
+use crate::domain::entities::SentimentTrend;
+use crate::domain::ports::sentiment_analyzer::SentimentAnalyzer;
+
+/// Weight of the newest message in the conversation's rolling score
+const ROLLING_WEIGHT: f64 = 0.5;
+
+/// Smallest change in the rolling score reported as improving or declining
+const TREND_THRESHOLD: f64 = 0.1;
+
+/// Fall in the rolling score from one message that counts as a sharp drop
+pub const SHARP_DROP_THRESHOLD: f64 = 0.4;
+
+/// Normalization constant mapping raw lexicon totals into (-1, 1)
+const NORMALIZATION_ALPHA: f64 = 15.0;
+
+/// How many following words a negation applies to
+const NEGATION_WINDOW: usize = 3;
+
+const POSITIVE_WORDS: &[(&str, f64)] = &[
+    ("thanks", 2.0),
+    ("thank", 2.0),
+    ("great", 3.0),
+    ("excellent", 3.0),
+    ("awesome", 3.0),
+    ("perfect", 3.0),
+    ("amazing", 3.0),
+    ("love", 3.0),
+    ("happy", 2.5),
+    ("glad", 2.0),
+    ("good", 2.0),
+    ("helpful", 2.0),
+    ("appreciate", 2.0),
+    ("resolved", 1.5),
+    ("works", 1.5),
+    ("working", 1.0),
+    ("fixed", 1.5),
+    ("quick", 1.0),
+    ("fast", 1.0),
+    ("nice", 2.0),
+    ("pleased", 2.0),
+];
+
+const NEGATIVE_WORDS: &[(&str, f64)] = &[
+    ("angry", 3.0),
+    ("furious", 3.5),
+    ("terrible", 3.0),
+    ("awful", 3.0),
+    ("horrible", 3.0),
+    ("worst", 3.5),
+    ("hate", 3.0),
+    ("unacceptable", 3.0),
+    ("ridiculous", 2.5),
+    ("disappointed", 2.5),
+    ("frustrated", 2.5),
+    ("frustrating", 2.5),
+    ("annoyed", 2.0),
+    ("useless", 2.5),
+    ("bad", 2.0),
+    ("broken", 2.0),
+    ("problem", 1.0),
+    ("issue", 0.5),
+    ("error", 1.0),
+    ("slow", 1.5),
+    ("refund", 1.5),
+    ("cancel", 2.0),
+    ("complaint", 2.0),
+    ("wrong", 1.5),
+    ("still", 0.5),
+    ("again", 0.5),
+    ("waiting", 1.0),
+    ("never", 1.0),
+];
+
+const NEGATIONS: &[&str] = &[
+    "not", "no", "never", "dont", "don't", "doesnt", "doesn't", "didnt", "didn't", "isnt",
+    "isn't", "wasnt", "wasn't", "cant", "can't", "cannot", "wont", "won't",
+];
+
+const INTENSIFIERS: &[&str] = &["very", "really", "extremely", "so", "totally", "absolutely"];
+
+/// Rule-based analyzer using a small support-oriented word list
+///
+/// Handles simple negation ("not happy") and intensifiers ("very slow").
+#[derive(Debug, Clone, Default)]
+pub struct LexiconSentimentAnalyzer;
+
+impl LexiconSentimentAnalyzer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Score text synchronously; see `SentimentAnalyzer::score`
+    pub fn score_text(&self, text: &str) -> f64 {
+        let words: Vec<String> = text
+            .split(|c: char| !c.is_alphanumeric() && c != '\'')
+            .filter(|w| !w.is_empty())
+            .map(|w| w.to_lowercase())
+            .collect();
+
+        let mut total = 0.0;
+        let mut negated_for = 0;
+        let mut intensity = 1.0;
+        for word in &words {
+            if NEGATIONS.contains(&word.as_str()) {
+                // "never" is also a negative word on its own
+                if word != "never" {
+                    negated_for = NEGATION_WINDOW;
+                    continue;
+                }
+            }
+            if INTENSIFIERS.contains(&word.as_str()) {
+                intensity = 1.5;
+                continue;
+            }
+
+            let valence = POSITIVE_WORDS
+                .iter()
+                .find(|(w, _)| w == word)
+                .map(|(_, v)| *v)
+                .or_else(|| {
+                    NEGATIVE_WORDS
+                        .iter()
+                        .find(|(w, _)| w == word)
+                        .map(|(_, v)| -*v)
+                });
+
+            if let Some(valence) = valence {
+                let mut valence = valence * intensity;
+                if negated_for > 0 {
+                    // Negated praise reads as a complaint; negated complaints are only mildly positive
+                    valence = if valence > 0.0 {
+                        -valence
+                    } else {
+                        -valence * 0.5
+                    };
+                    negated_for = 0;
+                }
+                total += valence;
+            }
+
+            intensity = 1.0;
+            negated_for = negated_for.saturating_sub(1);
+        }
+
+        if text.contains("!!") && total != 0.0 {
+            total *= 1.2;
+        }
+
+        normalize(total)
+    }
+}
+
+#[async_trait::async_trait]
+impl SentimentAnalyzer for LexiconSentimentAnalyzer {
+    async fn score(&self, text: &str) -> f64 {
+        self.score_text(text)
+    }
+}
+
+fn normalize(total: f64) -> f64 {
+    if total == 0.0 {
+        return 0.0;
+    }
+    (total / (total * total + NORMALIZATION_ALPHA).sqrt()).clamp(-1.0, 1.0)
+}
+
+/// Fold a new message score into the conversation's rolling score
+pub fn roll_sentiment(previous: Option<f64>, message_score: f64) -> f64 {
+    match previous {
+        Some(previous) => previous * (1.0 - ROLLING_WEIGHT) + message_score * ROLLING_WEIGHT,
+        None => message_score,
+    }
+}
+
+/// Trend between the previous and updated rolling scores
+pub fn sentiment_trend(previous: Option<f64>, current: f64) -> SentimentTrend {
+    match previous {
+        Some(previous) if current - previous >= TREND_THRESHOLD => SentimentTrend::Improving,
+        Some(previous) if previous - current >= TREND_THRESHOLD => SentimentTrend::Declining,
+        _ => SentimentTrend::Stable,
+    }
+}
+
+/// Whether the rolling score fell sharply with the latest message
+pub fn is_sharp_drop(previous: Option<f64>, current: f64) -> bool {
+    previous.is_some_and(|previous| previous - current >= SHARP_DROP_THRESHOLD)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lexicon_scores_polarity() {
+        let analyzer = LexiconSentimentAnalyzer::new();
+        assert!(analyzer.score_text("Thanks, that was really helpful!") > 0.25);
+        assert!(analyzer.score_text("This is unacceptable, I am furious") < -0.25);
+        assert_eq!(analyzer.score_text("My order number is 1234"), 0.0);
+    }
+
+    #[test]
+    fn test_lexicon_handles_negation_and_intensifiers() {
+        let analyzer = LexiconSentimentAnalyzer::new();
+        assert!(analyzer.score_text("I am not happy with this") < 0.0);
+        assert!(analyzer.score_text("very slow") < analyzer.score_text("slow"));
+        assert!(analyzer.score_text("not bad") > 0.0);
+    }
+
+    #[test]
+    fn test_rolling_score_and_trend() {
+        assert_eq!(roll_sentiment(None, 0.6), 0.6);
+        assert_eq!(roll_sentiment(Some(0.6), -0.6), 0.0);
+
+        assert_eq!(sentiment_trend(None, -0.5), SentimentTrend::Stable);
+        assert_eq!(sentiment_trend(Some(0.0), 0.3), SentimentTrend::Improving);
+        assert_eq!(sentiment_trend(Some(0.0), -0.3), SentimentTrend::Declining);
+        assert_eq!(sentiment_trend(Some(0.2), 0.25), SentimentTrend::Stable);
+
+        assert!(is_sharp_drop(Some(0.3), -0.2));
+        assert!(!is_sharp_drop(Some(0.3), 0.0));
+        assert!(!is_sharp_drop(None, -0.9));
+    }
+}
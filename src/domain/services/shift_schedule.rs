@@ -0,0 +1,139 @@
+//! Time arithmetic for weekly agent shifts
+//!
+//! Shift times are minutes since midnight; "24:00" (1440) is allowed as an end
+//! time so a shift can run until midnight.
+
+use chrono::Weekday;
+
+/// Minutes in a day, also the value of "24:00"
+pub const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// Weekday names in schedule order
+pub const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Parse an "HH:MM" shift time into minutes since midnight
+pub fn parse_shift_time(value: &str) -> Result<u32, String> {
+    let invalid = || format!("Invalid time '{}', expected HH:MM", value);
+
+    let (hours, minutes) = value.trim().split_once(':').ok_or_else(invalid)?;
+    if hours.len() != 2 || minutes.len() != 2 {
+        return Err(invalid());
+    }
+    let hours: u32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: u32 = minutes.parse().map_err(|_| invalid())?;
+
+    let total = hours * 60 + minutes;
+    if minutes >= 60 || total > MINUTES_PER_DAY {
+        return Err(invalid());
+    }
+
+    Ok(total)
+}
+
+/// Format minutes since midnight as "HH:MM"
+pub fn format_shift_time(minutes: u32) -> String {
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// Canonical weekday name for a full or three-letter name, in any case
+pub fn normalize_weekday(value: &str) -> Result<String, String> {
+    let lower = value.trim().to_lowercase();
+    WEEKDAYS
+        .iter()
+        .find(|day| {
+            let day = day.to_lowercase();
+            day == lower || (lower.len() == 3 && day.starts_with(&lower))
+        })
+        .map(|day| day.to_string())
+        .ok_or_else(|| format!("Invalid day '{}'", value))
+}
+
+/// Schedule name of a chrono weekday
+pub fn weekday_name(weekday: Weekday) -> &'static str {
+    WEEKDAYS[weekday.num_days_from_monday() as usize]
+}
+
+/// Parts of `window` not covered by any of `shifts`
+///
+/// Ranges are half-open `(start, end)` minute pairs; the result is ordered.
+pub fn coverage_gaps(window: (u32, u32), shifts: &[(u32, u32)]) -> Vec<(u32, u32)> {
+    let mut shifts: Vec<(u32, u32)> = shifts
+        .iter()
+        .filter(|(start, end)| start < end)
+        .copied()
+        .collect();
+    shifts.sort();
+
+    let (window_start, window_end) = window;
+    let mut gaps = Vec::new();
+    let mut cursor = window_start;
+
+    for (start, end) in shifts {
+        if cursor >= window_end {
+            break;
+        }
+        if start > cursor {
+            gaps.push((cursor, start.min(window_end)));
+        }
+        cursor = cursor.max(end);
+    }
+    if cursor < window_end {
+        gaps.push((cursor, window_end));
+    }
+
+    gaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format_shift_times() {
+        assert_eq!(parse_shift_time("09:30"), Ok(570));
+        assert_eq!(parse_shift_time("00:00"), Ok(0));
+        assert_eq!(parse_shift_time("24:00"), Ok(MINUTES_PER_DAY));
+        assert!(parse_shift_time("24:30").is_err());
+        assert!(parse_shift_time("9:30").is_err());
+        assert!(parse_shift_time("12:60").is_err());
+        assert!(parse_shift_time("noon").is_err());
+
+        assert_eq!(format_shift_time(570), "09:30");
+        assert_eq!(format_shift_time(MINUTES_PER_DAY), "24:00");
+    }
+
+    #[test]
+    fn test_normalize_weekday() {
+        assert_eq!(normalize_weekday("monday"), Ok("Monday".to_string()));
+        assert_eq!(normalize_weekday("SAT"), Ok("Saturday".to_string()));
+        assert!(normalize_weekday("Mo").is_err());
+        assert!(normalize_weekday("Funday").is_err());
+        assert_eq!(weekday_name(Weekday::Sun), "Sunday");
+    }
+
+    #[test]
+    fn test_coverage_gaps() {
+        let window = (9 * 60, 17 * 60);
+
+        // Nothing scheduled: the whole window is a gap
+        assert_eq!(coverage_gaps(window, &[]), vec![window]);
+
+        // Overlapping shifts with a hole at lunch and an uncovered end of day
+        let shifts = [(8 * 60, 12 * 60), (11 * 60, 12 * 60), (13 * 60, 16 * 60)];
+        assert_eq!(
+            coverage_gaps(window, &shifts),
+            vec![(12 * 60, 13 * 60), (16 * 60, 17 * 60)]
+        );
+
+        // Fully covered
+        assert!(coverage_gaps(window, &[(0, MINUTES_PER_DAY)]).is_empty());
+    }
+}
@@ -0,0 +1,680 @@
+//! Replays past conversations against a draft SLA policy
+//!
+//! Deadlines follow the live SLA rules: first response and resolution run on
+//! the assigned team's business hours (skipping holidays) when it has them,
+//! next response always runs around the clock.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::domain::entities::{
+    BusinessHours, Holiday, SlaReplayConversation, SlaSimulation, SlaSimulationGroup,
+    SlaSimulationStats, SlaTargetStats,
+};
+use crate::domain::services::parse_shift_time;
+
+/// Days searched for working time before giving up on a calendar
+const MAX_CALENDAR_DAYS: i64 = 3 * 366;
+
+/// Targets of the draft policy, in seconds
+#[derive(Debug, Clone, Copy)]
+pub struct SlaTargets {
+    pub first_response: i64,
+    pub resolution: i64,
+    pub next_response: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlaOutcome {
+    Met,
+    Breached,
+    /// The deadline had not passed yet at simulation time
+    Pending,
+}
+
+/// How one conversation would have fared against the targets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversationReplay {
+    pub first_response: SlaOutcome,
+    pub resolution: SlaOutcome,
+    pub next_responses: Vec<SlaOutcome>,
+}
+
+impl ConversationReplay {
+    pub fn breached(&self) -> bool {
+        self.first_response == SlaOutcome::Breached
+            || self.resolution == SlaOutcome::Breached
+            || self.next_responses.contains(&SlaOutcome::Breached)
+    }
+}
+
+/// A team's business hours with its holidays preloaded, so deadlines can be
+/// computed for many conversations without going back to the database
+#[derive(Debug, Clone)]
+pub struct WorkingCalendar {
+    timezone: Tz,
+    /// Working window per weekday, in minutes since midnight
+    days: HashMap<Weekday, (u32, u32)>,
+    dates: HashSet<NaiveDate>,
+    /// Recurring holidays as (month, day)
+    recurring: HashSet<(u32, u32)>,
+}
+
+impl WorkingCalendar {
+    /// Build a calendar from business hours and the holidays that apply to
+    /// it: global ones and those in `holiday_calendar_id`
+    pub fn new(
+        business_hours: &BusinessHours,
+        holiday_calendar_id: Option<&str>,
+        holidays: &[Holiday],
+    ) -> Result<Self, String> {
+        let timezone: Tz = business_hours
+            .timezone
+            .parse()
+            .map_err(|_| format!("Invalid timezone: {}", business_hours.timezone))?;
+
+        let mut days = HashMap::new();
+        for schedule in &business_hours.schedule {
+            let Some(day) = parse_day_name(&schedule.day) else {
+                continue;
+            };
+            let start = parse_shift_time(&schedule.start)?;
+            let end = parse_shift_time(&schedule.end)?;
+            if start < end {
+                // Like the live check, the first entry for a day wins
+                days.entry(day).or_insert((start, end));
+            }
+        }
+        if days.is_empty() {
+            return Err("Business hours have no working days".to_string());
+        }
+
+        let mut dates = HashSet::new();
+        let mut recurring = HashSet::new();
+        let applicable = holidays.iter().filter(|holiday| {
+            holiday.calendar_id.is_none() || holiday.calendar_id.as_deref() == holiday_calendar_id
+        });
+        for holiday in applicable {
+            let Ok(date) = NaiveDate::parse_from_str(&holiday.date, "%Y-%m-%d") else {
+                continue;
+            };
+            if holiday.recurring {
+                recurring.insert((date.month(), date.day()));
+            } else {
+                dates.insert(date);
+            }
+        }
+
+        Ok(Self {
+            timezone,
+            days,
+            dates,
+            recurring,
+        })
+    }
+
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.dates.contains(&date) || self.recurring.contains(&(date.month(), date.day()))
+    }
+
+    /// The moment `seconds` of working time have elapsed after `start`
+    ///
+    /// Working time is counted in whole minutes, as the live calculation does.
+    /// Returns `None` when the calendar has no working time left within a few
+    /// years, e.g. because every working day is a holiday.
+    pub fn add_working_time(&self, start: DateTime<Utc>, seconds: i64) -> Option<DateTime<Utc>> {
+        let mut remaining = Duration::minutes((seconds.max(0) + 59) / 60);
+        let mut cursor = start.with_timezone(&self.timezone).naive_local();
+        if remaining.is_zero() {
+            return Some(start);
+        }
+
+        let first_day = cursor.date();
+        for offset in 0..MAX_CALENDAR_DAYS {
+            let date = first_day + Duration::days(offset);
+            if self.is_holiday(date) {
+                continue;
+            }
+            let Some(&(start_minute, end_minute)) = self.days.get(&date.weekday()) else {
+                continue;
+            };
+
+            let midnight = date.and_hms_opt(0, 0, 0)?;
+            let window_start = midnight + Duration::minutes(start_minute as i64);
+            let window_end = midnight + Duration::minutes(end_minute as i64);
+            let begin = cursor.max(window_start);
+            if begin >= window_end {
+                continue;
+            }
+
+            let available = window_end - begin;
+            if remaining <= available {
+                return self.to_utc(begin + remaining);
+            }
+            remaining -= available;
+            cursor = window_end;
+        }
+
+        None
+    }
+
+    fn to_utc(&self, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+        // A local time skipped by a DST change lands an hour later
+        self.timezone
+            .from_local_datetime(&local)
+            .earliest()
+            .or_else(|| {
+                self.timezone
+                    .from_local_datetime(&(local + Duration::hours(1)))
+                    .earliest()
+            })
+            .map(|time| time.with_timezone(&Utc))
+    }
+}
+
+fn parse_day_name(day: &str) -> Option<Weekday> {
+    match day {
+        "Monday" => Some(Weekday::Mon),
+        "Tuesday" => Some(Weekday::Tue),
+        "Wednesday" => Some(Weekday::Wed),
+        "Thursday" => Some(Weekday::Thu),
+        "Friday" => Some(Weekday::Fri),
+        "Saturday" => Some(Weekday::Sat),
+        "Sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Parse a stored timestamp, either RFC 3339 or SQLite's `datetime('now')`
+pub fn parse_replay_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(time.with_timezone(&Utc));
+    }
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|time| Utc.from_utc_datetime(&time))
+}
+
+fn outcome(
+    deadline: DateTime<Utc>,
+    done_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> SlaOutcome {
+    match done_at {
+        Some(done_at) if done_at <= deadline => SlaOutcome::Met,
+        Some(_) => SlaOutcome::Breached,
+        None if now > deadline => SlaOutcome::Breached,
+        None => SlaOutcome::Pending,
+    }
+}
+
+/// Replay one conversation against the targets
+///
+/// A conversation resolved before anyone replied owes no reply, so
+/// resolution also stops the response clocks. Returns `None` when its
+/// creation time can't be read.
+pub fn replay_conversation(
+    conversation: &SlaReplayConversation,
+    targets: &SlaTargets,
+    calendar: Option<&WorkingCalendar>,
+    now: DateTime<Utc>,
+) -> Option<ConversationReplay> {
+    let created_at = parse_replay_timestamp(&conversation.created_at)?;
+    let resolved_at = conversation
+        .resolved_at
+        .as_deref()
+        .and_then(parse_replay_timestamp);
+
+    let mut messages: Vec<(DateTime<Utc>, bool)> = conversation
+        .messages
+        .iter()
+        .filter_map(|message| {
+            parse_replay_timestamp(&message.created_at).map(|at| (at, message.from_agent))
+        })
+        .collect();
+    messages.sort_by_key(|(at, _)| *at);
+
+    let business_deadline = |seconds: i64| {
+        calendar
+            .and_then(|calendar| calendar.add_working_time(created_at, seconds))
+            .unwrap_or(created_at + Duration::seconds(seconds))
+    };
+
+    let first_reply = messages
+        .iter()
+        .find(|(_, from_agent)| *from_agent)
+        .map(|(at, _)| *at);
+    let first_response = outcome(
+        business_deadline(targets.first_response),
+        first_reply.or(resolved_at),
+        now,
+    );
+    let resolution = outcome(business_deadline(targets.resolution), resolved_at, now);
+
+    // A contact message after an agent reply starts the clock; the next agent
+    // reply stops it
+    let mut next_responses = Vec::new();
+    let mut replied = false;
+    let mut waiting_since: Option<DateTime<Utc>> = None;
+    for (at, from_agent) in &messages {
+        if *from_agent {
+            if let Some(since) = waiting_since.take() {
+                let deadline = since + Duration::seconds(targets.next_response);
+                next_responses.push(outcome(deadline, Some(*at), now));
+            }
+            replied = true;
+        } else if replied && waiting_since.is_none() {
+            waiting_since = Some(*at);
+        }
+    }
+    if let Some(since) = waiting_since {
+        let deadline = since + Duration::seconds(targets.next_response);
+        let done_at = resolved_at.filter(|resolved_at| *resolved_at >= since);
+        next_responses.push(outcome(deadline, done_at, now));
+    }
+
+    Some(ConversationReplay {
+        first_response,
+        resolution,
+        next_responses,
+    })
+}
+
+fn record_target(stats: &mut SlaTargetStats, outcome: SlaOutcome) {
+    match outcome {
+        SlaOutcome::Met => stats.met += 1,
+        SlaOutcome::Breached => stats.breached += 1,
+        SlaOutcome::Pending => stats.pending += 1,
+    }
+}
+
+fn record(stats: &mut SlaSimulationStats, replay: &ConversationReplay) {
+    stats.conversations += 1;
+    if replay.breached() {
+        stats.breached_conversations += 1;
+    }
+    record_target(&mut stats.first_response, replay.first_response);
+    record_target(&mut stats.resolution, replay.resolution);
+    for outcome in &replay.next_responses {
+        record_target(&mut stats.next_response, *outcome);
+    }
+}
+
+fn rate(part: i64, total: i64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    (part as f64 / total as f64 * 10_000.0).round() / 10_000.0
+}
+
+fn finish(mut stats: SlaSimulationStats) -> SlaSimulationStats {
+    stats.breach_rate = rate(stats.breached_conversations, stats.conversations);
+    for target in [
+        &mut stats.first_response,
+        &mut stats.resolution,
+        &mut stats.next_response,
+    ] {
+        target.breach_rate = rate(target.breached, target.met + target.breached);
+    }
+    stats
+}
+
+fn finish_groups(
+    groups: BTreeMap<Option<String>, (Option<String>, SlaSimulationStats)>,
+) -> Vec<SlaSimulationGroup> {
+    let mut groups: Vec<SlaSimulationGroup> = groups
+        .into_iter()
+        .map(|(id, (name, stats))| SlaSimulationGroup {
+            id,
+            name,
+            stats: finish(stats),
+        })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.stats
+            .conversations
+            .cmp(&a.stats.conversations)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    groups
+}
+
+/// Replay the conversations created in `[since, until)` and aggregate the
+/// outcomes overall, by team and by inbox
+///
+/// `calendars` holds the working calendar of each team that has business
+/// hours, keyed by team id.
+pub fn simulate_sla_policy(
+    conversations: &[SlaReplayConversation],
+    targets: &SlaTargets,
+    calendars: &HashMap<String, WorkingCalendar>,
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> SlaSimulation {
+    let mut overall = SlaSimulationStats::default();
+    let mut by_team = BTreeMap::new();
+    let mut by_inbox = BTreeMap::new();
+
+    for conversation in conversations {
+        let in_window = parse_replay_timestamp(&conversation.created_at)
+            .is_some_and(|created_at| created_at >= since && created_at < until);
+        if !in_window {
+            continue;
+        }
+
+        let calendar = conversation
+            .team_id
+            .as_ref()
+            .and_then(|team_id| calendars.get(team_id));
+        let Some(replay) = replay_conversation(conversation, targets, calendar, now) else {
+            continue;
+        };
+
+        record(&mut overall, &replay);
+        let (_, team) = by_team
+            .entry(conversation.team_id.clone())
+            .or_insert_with(|| {
+                (
+                    conversation.team_name.clone(),
+                    SlaSimulationStats::default(),
+                )
+            });
+        record(team, &replay);
+        let (_, inbox) = by_inbox
+            .entry(Some(conversation.inbox_id.clone()))
+            .or_insert_with(|| {
+                (
+                    Some(conversation.inbox_name.clone()),
+                    SlaSimulationStats::default(),
+                )
+            });
+        record(inbox, &replay);
+    }
+
+    SlaSimulation {
+        since: since.to_rfc3339(),
+        until: until.to_rfc3339(),
+        overall: finish(overall),
+        by_team: finish_groups(by_team),
+        by_inbox: finish_groups(by_inbox),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::{DaySchedule, SlaReplayMessage};
+
+    const HOUR: i64 = 3600;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        parse_replay_timestamp(value).unwrap()
+    }
+
+    fn targets() -> SlaTargets {
+        SlaTargets {
+            first_response: HOUR,
+            resolution: 24 * HOUR,
+            next_response: 2 * HOUR,
+        }
+    }
+
+    fn conversation(
+        created_at: &str,
+        resolved_at: Option<&str>,
+        messages: &[(&str, bool)],
+    ) -> SlaReplayConversation {
+        SlaReplayConversation {
+            id: "conv".to_string(),
+            inbox_id: "inbox-1".to_string(),
+            inbox_name: "Support".to_string(),
+            team_id: None,
+            team_name: None,
+            created_at: created_at.to_string(),
+            resolved_at: resolved_at.map(str::to_string),
+            messages: messages
+                .iter()
+                .map(|(created_at, from_agent)| SlaReplayMessage {
+                    created_at: created_at.to_string(),
+                    from_agent: *from_agent,
+                })
+                .collect(),
+        }
+    }
+
+    fn weekday_hours() -> BusinessHours {
+        BusinessHours {
+            timezone: "UTC".to_string(),
+            schedule: ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday"]
+                .iter()
+                .map(|day| DaySchedule {
+                    day: day.to_string(),
+                    start: "09:00".to_string(),
+                    end: "17:00".to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn holiday(date: &str, recurring: bool, calendar_id: Option<&str>) -> Holiday {
+        Holiday {
+            id: date.to_string(),
+            name: "Holiday".to_string(),
+            date: date.to_string(),
+            recurring,
+            calendar_id: calendar_id.map(str::to_string),
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_working_time_skips_nights_and_weekends() {
+        let calendar = WorkingCalendar::new(&weekday_hours(), None, &[]).unwrap();
+
+        // Friday 16:00 + 2h of working time lands on Monday 10:00
+        let deadline = calendar
+            .add_working_time(at("2024-03-01T16:00:00Z"), 2 * HOUR)
+            .unwrap();
+        assert_eq!(deadline, at("2024-03-04T10:00:00Z"));
+
+        // Saturday evening starts counting from Monday morning
+        let deadline = calendar
+            .add_working_time(at("2024-03-02T20:00:00Z"), HOUR)
+            .unwrap();
+        assert_eq!(deadline, at("2024-03-04T10:00:00Z"));
+    }
+
+    #[test]
+    fn test_working_time_skips_applicable_holidays() {
+        let holidays = vec![
+            holiday("2024-03-04", false, None),
+            holiday("2020-03-05", true, Some("cal-1")),
+            holiday("2024-03-06", false, Some("cal-2")),
+        ];
+        let calendar = WorkingCalendar::new(&weekday_hours(), Some("cal-1"), &holidays).unwrap();
+
+        // Monday is a global holiday and Tuesday a recurring one in this
+        // calendar; Wednesday's holiday belongs to another calendar
+        let deadline = calendar
+            .add_working_time(at("2024-03-04T09:00:00Z"), HOUR)
+            .unwrap();
+        assert_eq!(deadline, at("2024-03-06T10:00:00Z"));
+    }
+
+    #[test]
+    fn test_working_calendar_rejects_empty_schedule() {
+        let hours = BusinessHours {
+            timezone: "UTC".to_string(),
+            schedule: vec![],
+        };
+        assert!(WorkingCalendar::new(&hours, None, &[]).is_err());
+    }
+
+    #[test]
+    fn test_replay_first_response_and_resolution() {
+        let now = at("2024-06-01T00:00:00Z");
+
+        let fast = conversation(
+            "2024-03-01T10:00:00Z",
+            Some("2024-03-01T12:00:00Z"),
+            &[
+                ("2024-03-01T10:00:00Z", false),
+                ("2024-03-01T10:30:00Z", true),
+            ],
+        );
+        let replay = replay_conversation(&fast, &targets(), None, now).unwrap();
+        assert_eq!(replay.first_response, SlaOutcome::Met);
+        assert_eq!(replay.resolution, SlaOutcome::Met);
+        assert!(!replay.breached());
+
+        let slow = conversation(
+            "2024-03-01 10:00:00",
+            None,
+            &[
+                ("2024-03-01T10:00:00Z", false),
+                ("2024-03-01T13:00:00Z", true),
+            ],
+        );
+        let replay = replay_conversation(&slow, &targets(), None, now).unwrap();
+        assert_eq!(replay.first_response, SlaOutcome::Breached);
+        assert_eq!(replay.resolution, SlaOutcome::Breached);
+        assert!(replay.breached());
+    }
+
+    #[test]
+    fn test_replay_open_deadlines_are_pending() {
+        let now = at("2024-03-01T10:30:00Z");
+        let open = conversation(
+            "2024-03-01T10:00:00Z",
+            None,
+            &[("2024-03-01T10:00:00Z", false)],
+        );
+
+        let replay = replay_conversation(&open, &targets(), None, now).unwrap();
+        assert_eq!(replay.first_response, SlaOutcome::Pending);
+        assert_eq!(replay.resolution, SlaOutcome::Pending);
+        assert!(!replay.breached());
+    }
+
+    #[test]
+    fn test_replay_next_responses() {
+        let now = at("2024-06-01T00:00:00Z");
+        let conv = conversation(
+            "2024-03-01T10:00:00Z",
+            None,
+            &[
+                ("2024-03-01T10:00:00Z", false),
+                ("2024-03-01T10:10:00Z", true),
+                // Answered within 2h
+                ("2024-03-01T11:00:00Z", false),
+                ("2024-03-01T11:05:00Z", false),
+                ("2024-03-01T12:00:00Z", true),
+                // Answered after 3h
+                ("2024-03-01T13:00:00Z", false),
+                ("2024-03-01T16:00:00Z", true),
+                // Never answered
+                ("2024-03-01T17:00:00Z", false),
+            ],
+        );
+
+        let replay = replay_conversation(&conv, &targets(), None, now).unwrap();
+        assert_eq!(
+            replay.next_responses,
+            vec![SlaOutcome::Met, SlaOutcome::Breached, SlaOutcome::Breached]
+        );
+    }
+
+    #[test]
+    fn test_replay_resolution_stops_response_clocks() {
+        let now = at("2024-06-01T00:00:00Z");
+        let conv = conversation(
+            "2024-03-01T10:00:00Z",
+            Some("2024-03-01T10:20:00Z"),
+            &[("2024-03-01T10:00:00Z", false)],
+        );
+
+        let replay = replay_conversation(&conv, &targets(), None, now).unwrap();
+        assert_eq!(replay.first_response, SlaOutcome::Met);
+        assert!(replay.next_responses.is_empty());
+    }
+
+    #[test]
+    fn test_replay_uses_team_business_hours() {
+        let calendar = WorkingCalendar::new(&weekday_hours(), None, &[]).unwrap();
+        let now = at("2024-06-01T00:00:00Z");
+        // Arrives Friday evening, answered Monday 09:30
+        let conv = conversation(
+            "2024-03-01T18:00:00Z",
+            None,
+            &[
+                ("2024-03-01T18:00:00Z", false),
+                ("2024-03-04T09:30:00Z", true),
+            ],
+        );
+
+        let business = replay_conversation(&conv, &targets(), Some(&calendar), now).unwrap();
+        assert_eq!(business.first_response, SlaOutcome::Met);
+
+        let around_the_clock = replay_conversation(&conv, &targets(), None, now).unwrap();
+        assert_eq!(around_the_clock.first_response, SlaOutcome::Breached);
+    }
+
+    #[test]
+    fn test_simulate_groups_by_team_and_inbox() {
+        let now = at("2024-06-01T00:00:00Z");
+        let replied = &[
+            ("2024-03-01T10:00:00Z", false),
+            ("2024-03-01T10:30:00Z", true),
+        ];
+        let ignored = &[("2024-03-01T10:00:00Z", false)];
+
+        let mut a = conversation(
+            "2024-03-01T10:00:00Z",
+            Some("2024-03-01T11:00:00Z"),
+            replied,
+        );
+        a.team_id = Some("team-1".to_string());
+        a.team_name = Some("Billing".to_string());
+        let mut b = conversation("2024-03-01T10:00:00Z", None, ignored);
+        b.team_id = Some("team-1".to_string());
+        b.team_name = Some("Billing".to_string());
+        let mut c = conversation("2024-03-01T10:00:00Z", None, ignored);
+        c.inbox_id = "inbox-2".to_string();
+        c.inbox_name = "Sales".to_string();
+        // Outside the window
+        let d = conversation("2024-01-01T10:00:00Z", None, ignored);
+
+        let result = simulate_sla_policy(
+            &[a, b, c, d],
+            &targets(),
+            &HashMap::new(),
+            at("2024-02-01T00:00:00Z"),
+            at("2024-04-01T00:00:00Z"),
+            now,
+        );
+
+        assert_eq!(result.overall.conversations, 3);
+        assert_eq!(result.overall.breached_conversations, 2);
+        assert_eq!(result.overall.breach_rate, 0.6667);
+        assert_eq!(result.overall.first_response.met, 1);
+        assert_eq!(result.overall.first_response.breached, 2);
+
+        assert_eq!(result.by_team.len(), 2);
+        assert_eq!(result.by_team[0].id.as_deref(), Some("team-1"));
+        assert_eq!(result.by_team[0].name.as_deref(), Some("Billing"));
+        assert_eq!(result.by_team[0].stats.conversations, 2);
+        assert_eq!(result.by_team[0].stats.breach_rate, 0.5);
+        assert_eq!(result.by_team[1].id, None);
+        assert_eq!(result.by_team[1].stats.breach_rate, 1.0);
+
+        assert_eq!(result.by_inbox.len(), 2);
+        assert_eq!(result.by_inbox[0].name.as_deref(), Some("Support"));
+        assert_eq!(result.by_inbox[0].stats.conversations, 2);
+        assert_eq!(result.by_inbox[1].name.as_deref(), Some("Sales"));
+        assert_eq!(result.by_inbox[1].stats.breached_conversations, 1);
+    }
+}
@@ -0,0 +1,303 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+
+type HmacSha1 = Hmac<Sha1>;
+
+/// Characters of the GSM 03.38 basic character set (one septet each)
+const GSM7_BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?\
+¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+
+/// Characters of the GSM 03.38 extension table (escape + septet)
+const GSM7_EXTENSION: &str = "^{}\\[~]|€\u{0C}";
+
+/// Character encoding an SMS is sent with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmsEncoding {
+    Gsm7,
+    Ucs2,
+}
+
+impl SmsEncoding {
+    /// Encoding needed for `text`: GSM-7 unless it has characters outside the GSM alphabet
+    pub fn for_text(text: &str) -> Self {
+        if text
+            .chars()
+            .all(|c| GSM7_BASIC.contains(c) || GSM7_EXTENSION.contains(c))
+        {
+            SmsEncoding::Gsm7
+        } else {
+            SmsEncoding::Ucs2
+        }
+    }
+
+    /// Capacity of a message sent as a single segment
+    fn single_segment_units(self) -> usize {
+        match self {
+            SmsEncoding::Gsm7 => 160,
+            SmsEncoding::Ucs2 => 70,
+        }
+    }
+
+    /// Capacity of each segment of a concatenated message (the rest is the UDH)
+    fn multipart_segment_units(self) -> usize {
+        match self {
+            SmsEncoding::Gsm7 => 153,
+            SmsEncoding::Ucs2 => 67,
+        }
+    }
+
+    /// Septets (GSM-7) or UTF-16 code units (UCS-2) a character takes
+    fn char_units(self, c: char) -> usize {
+        match self {
+            SmsEncoding::Gsm7 if GSM7_EXTENSION.contains(c) => 2,
+            SmsEncoding::Gsm7 => 1,
+            SmsEncoding::Ucs2 => c.len_utf16(),
+        }
+    }
+
+    /// Capacity of a message of at most `max_segments` segments
+    fn capacity(self, max_segments: usize) -> usize {
+        if max_segments <= 1 {
+            self.single_segment_units()
+        } else {
+            max_segments * self.multipart_segment_units()
+        }
+    }
+}
+
+/// Number of segments carriers bill `text` as
+///
+/// # Example
+/// ```
+/// use oxidesk::domain::services::sms::sms_segment_count;
+/// assert_eq!(sms_segment_count(&"a".repeat(160)), 1);
+/// assert_eq!(sms_segment_count(&"a".repeat(161)), 2);
+/// assert_eq!(sms_segment_count("Ça va? 👍"), 1);
+/// ```
+pub fn sms_segment_count(text: &str) -> usize {
+    let encoding = SmsEncoding::for_text(text);
+    let units: usize = text.chars().map(|c| encoding.char_units(c)).sum();
+
+    if units <= encoding.single_segment_units() {
+        1
+    } else {
+        units.div_ceil(encoding.multipart_segment_units())
+    }
+}
+
+/// Split `text` into messages of at most `max_segments` segments each
+///
+/// Messages are broken at whitespace where possible, so words are not cut
+/// across two texts.
+pub fn split_sms(text: &str, max_segments: usize) -> Vec<String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Vec::new();
+    }
+
+    let encoding = SmsEncoding::for_text(text);
+    let capacity = encoding.capacity(max_segments);
+    let chars: Vec<char> = text.chars().collect();
+    let mut parts = Vec::new();
+    let mut start = 0;
+
+    while start < chars.len() {
+        let mut end = start;
+        let mut units = 0;
+        while end < chars.len() && units + encoding.char_units(chars[end]) <= capacity {
+            units += encoding.char_units(chars[end]);
+            end += 1;
+        }
+
+        // Break at the last whitespace, unless that would leave a tiny part
+        if end < chars.len() {
+            if let Some(space) = chars[start..end].iter().rposition(|c| c.is_whitespace()) {
+                if space > (end - start) / 2 {
+                    end = start + space;
+                }
+            }
+        }
+
+        let part: String = chars[start..end].iter().collect();
+        let part = part.trim();
+        if !part.is_empty() {
+            parts.push(part.to_string());
+        }
+        start = end;
+    }
+
+    parts
+}
+
+/// Compute the `X-Twilio-Signature` of a webhook request
+///
+/// Twilio signs the full request URL followed by each POST parameter name and
+/// value, sorted by name, with HMAC-SHA1 keyed by the account's auth token.
+pub fn twilio_signature(url: &str, params: &[(String, String)], auth_token: &str) -> String {
+    let mut mac =
+        HmacSha1::new_from_slice(auth_token.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(signed_twilio_payload(url, params).as_bytes());
+
+    STANDARD.encode(mac.finalize().into_bytes())
+}
+
+/// Verify the `X-Twilio-Signature` of a webhook request
+pub fn verify_twilio_signature(
+    url: &str,
+    params: &[(String, String)],
+    signature: &str,
+    auth_token: &str,
+) -> bool {
+    let Ok(signature) = STANDARD.decode(signature.trim()) else {
+        return false;
+    };
+    let mut mac =
+        HmacSha1::new_from_slice(auth_token.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(signed_twilio_payload(url, params).as_bytes());
+
+    // Constant-time comparison
+    mac.verify_slice(&signature).is_ok()
+}
+
+fn signed_twilio_payload(url: &str, params: &[(String, String)]) -> String {
+    let mut sorted: Vec<&(String, String)> = params.iter().collect();
+    sorted.sort();
+
+    let mut payload = url.to_string();
+    for (name, value) in sorted {
+        payload.push_str(name);
+        payload.push_str(value);
+    }
+    payload
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_twilio_signature_matches_documented_example() {
+        // Example from Twilio's webhook security documentation
+        let url = "https://mycompany.com/myapp.php?foo=1&bar=2";
+        let params = params(&[
+            ("CallSid", "CA1234567890ABCDE"),
+            ("Caller", "+12349013030"),
+            ("Digits", "1234"),
+            ("From", "+12349013030"),
+            ("To", "+18005551212"),
+        ]);
+
+        let signature = twilio_signature(url, &params, "12345");
+        assert_eq!(signature, "0/KCTR6DLpKmkAf8muzZqo1nDgQ=");
+        assert!(verify_twilio_signature(url, &params, &signature, "12345"));
+    }
+
+    #[test]
+    fn test_twilio_signature_sorts_parameters() {
+        let url = "https://example.com/webhooks/twilio/inbox-1";
+        let forward = params(&[("Body", "Hi"), ("From", "+15550001111")]);
+        let reverse = params(&[("From", "+15550001111"), ("Body", "Hi")]);
+
+        assert_eq!(
+            twilio_signature(url, &forward, "token"),
+            twilio_signature(url, &reverse, "token")
+        );
+    }
+
+    #[test]
+    fn test_verify_twilio_signature_rejects_tampering() {
+        let url = "https://example.com/webhooks/twilio/inbox-1";
+        let original = params(&[("Body", "Hi"), ("From", "+15550001111")]);
+        let signature = twilio_signature(url, &original, "token");
+
+        let tampered = params(&[("Body", "Hi!"), ("From", "+15550001111")]);
+        assert!(!verify_twilio_signature(
+            url, &tampered, &signature, "token"
+        ));
+        assert!(!verify_twilio_signature(
+            url, &original, &signature, "other"
+        ));
+        assert!(!verify_twilio_signature(
+            "https://example.com/other",
+            &original,
+            &signature,
+            "token"
+        ));
+        assert!(!verify_twilio_signature(
+            url,
+            &original,
+            "not base64!",
+            "token"
+        ));
+    }
+
+    #[test]
+    fn test_encoding_detection() {
+        assert_eq!(SmsEncoding::for_text("Hello {world} €5"), SmsEncoding::Gsm7);
+        assert_eq!(SmsEncoding::for_text("Ça coûte 5€"), SmsEncoding::Ucs2);
+        assert_eq!(SmsEncoding::for_text("Thanks 🙂"), SmsEncoding::Ucs2);
+    }
+
+    #[test]
+    fn test_segment_count() {
+        assert_eq!(sms_segment_count(&"a".repeat(160)), 1);
+        assert_eq!(sms_segment_count(&"a".repeat(161)), 2);
+        assert_eq!(sms_segment_count(&"a".repeat(306)), 2);
+        assert_eq!(sms_segment_count(&"a".repeat(307)), 3);
+        // Extension characters take two septets
+        assert_eq!(sms_segment_count(&"€".repeat(80)), 1);
+        assert_eq!(sms_segment_count(&"€".repeat(81)), 2);
+        // UCS-2 messages hold fewer characters
+        assert_eq!(sms_segment_count(&"ç".repeat(70)), 1);
+        assert_eq!(sms_segment_count(&"ç".repeat(71)), 2);
+    }
+
+    #[test]
+    fn test_split_sms_keeps_short_text_whole() {
+        assert_eq!(split_sms("  Hello there  ", 1), vec!["Hello there"]);
+        assert!(split_sms("   ", 3).is_empty());
+    }
+
+    #[test]
+    fn test_split_sms_respects_segment_limit() {
+        let text = "word ".repeat(200);
+        let parts = split_sms(&text, 2);
+
+        assert!(parts.len() > 1);
+        for part in &parts {
+            assert!(sms_segment_count(part) <= 2);
+            assert!(!part.starts_with(' ') && !part.ends_with(' '));
+        }
+        assert_eq!(parts.join(" "), text.trim());
+    }
+
+    #[test]
+    fn test_split_sms_without_whitespace() {
+        let text = "x".repeat(400);
+        let parts = split_sms(&text, 1);
+
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0].len(), 160);
+        assert_eq!(parts.concat(), text);
+    }
+
+    #[test]
+    fn test_split_sms_unicode() {
+        let text = "é".repeat(100) + "🙂";
+        let parts = split_sms(&text, 1);
+
+        assert_eq!(parts.len(), 2);
+        for part in &parts {
+            assert_eq!(sms_segment_count(part), 1);
+        }
+        assert_eq!(parts.concat(), text);
+    }
+}
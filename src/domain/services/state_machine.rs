@@ -49,6 +49,8 @@ pub fn validate_transition(
         (Snoozed, Open) => Ok(()),
         (Resolved, Open) => Ok(()),
         (Resolved, Closed) => Ok(()), // Feature 019: Allow closing resolved conversations
+        (_, Junk) => Ok(()),
+        (Junk, Open) => Ok(()),
 
         // All other transitions are invalid
         _ => Err(TransitionError::InvalidTransition { from, to }),
@@ -148,4 +150,13 @@ mod tests {
         let result = validate_transition(ConversationStatus::Resolved, ConversationStatus::Snoozed);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_junk_transitions() {
+        assert!(validate_transition(ConversationStatus::Closed, ConversationStatus::Junk).is_ok());
+        assert!(validate_transition(ConversationStatus::Junk, ConversationStatus::Open).is_ok());
+        assert!(
+            validate_transition(ConversationStatus::Junk, ConversationStatus::Resolved).is_err()
+        );
+    }
 }
@@ -0,0 +1,111 @@
+/// Check the secret token of a webhook request against the inbox's secret
+///
+/// The comparison takes the same time wherever the first difference is.
+pub fn verify_telegram_secret(expected: &str, provided: &str) -> bool {
+    let expected = expected.as_bytes();
+    let provided = provided.as_bytes();
+    if expected.len() != provided.len() {
+        return false;
+    }
+
+    expected
+        .iter()
+        .zip(provided)
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+/// Split `text` into messages of at most `max_chars` characters each
+///
+/// Messages are broken at a newline, or failing that at whitespace, in the
+/// second half of a message, so paragraphs and words stay together.
+pub fn split_telegram_text(text: &str, max_chars: usize) -> Vec<String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Vec::new();
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut parts = Vec::new();
+    let mut start = 0;
+
+    while start < chars.len() {
+        let mut end = (start + max_chars).min(chars.len());
+
+        if end < chars.len() {
+            let window = &chars[start..end];
+            let half = (end - start) / 2;
+            let break_at = window
+                .iter()
+                .rposition(|c| *c == '\n')
+                .filter(|position| *position > half)
+                .or_else(|| {
+                    window
+                        .iter()
+                        .rposition(|c| c.is_whitespace())
+                        .filter(|position| *position > half)
+                });
+            if let Some(position) = break_at {
+                end = start + position;
+            }
+        }
+
+        let part: String = chars[start..end].iter().collect();
+        let part = part.trim();
+        if !part.is_empty() {
+            parts.push(part.to_string());
+        }
+        start = end;
+    }
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_telegram_secret() {
+        assert!(verify_telegram_secret("s3cret", "s3cret"));
+        assert!(!verify_telegram_secret("s3cret", "s3creT"));
+        assert!(!verify_telegram_secret("s3cret", "s3cret2"));
+        assert!(!verify_telegram_secret("s3cret", ""));
+    }
+
+    #[test]
+    fn test_split_telegram_text_keeps_short_text_whole() {
+        assert_eq!(split_telegram_text("  Hi there \n", 4096), vec!["Hi there"]);
+        assert!(split_telegram_text(" \n ", 4096).is_empty());
+    }
+
+    #[test]
+    fn test_split_telegram_text_prefers_newlines() {
+        let text = format!("{}\n{}", "a ".repeat(30).trim(), "b ".repeat(30).trim());
+        let parts = split_telegram_text(&text, 80);
+
+        assert_eq!(parts.len(), 2);
+        assert!(parts[0].chars().all(|c| c == 'a' || c == ' '));
+        assert!(parts[1].chars().all(|c| c == 'b' || c == ' '));
+    }
+
+    #[test]
+    fn test_split_telegram_text_respects_limit() {
+        let text = "word ".repeat(2000);
+        let parts = split_telegram_text(&text, 4096);
+
+        assert_eq!(parts.len(), 3);
+        for part in &parts {
+            assert!(part.chars().count() <= 4096);
+        }
+        assert_eq!(parts.join(" "), text.trim());
+    }
+
+    #[test]
+    fn test_split_telegram_text_counts_characters() {
+        let text = "ü".repeat(10);
+        let parts = split_telegram_text(&text, 4);
+
+        assert_eq!(parts, vec!["üüüü", "üüüü", "üü"]);
+    }
+}
@@ -0,0 +1,42 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::entities::ActivityFeedResponse,
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ActivityFeedParams {
+    /// Comma-separated activity types, e.g. `sla_breached,webhook_failed`
+    pub types: Option<String>,
+    /// Only return entries older than this timestamp (`next_before` of the previous page)
+    pub before: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
+
+fn default_limit() -> i64 {
+    50
+}
+
+/// Recent system-wide activity, newest first (admin only)
+pub async fn list_activity(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Query(params): Query<ActivityFeedParams>,
+) -> ApiResult<Json<ActivityFeedResponse>> {
+    let feed = state
+        .activity_service
+        .list_activity(
+            &auth_user,
+            params.types.as_deref(),
+            params.before.as_deref(),
+            params.limit,
+        )
+        .await?;
+    Ok(Json(feed))
+}
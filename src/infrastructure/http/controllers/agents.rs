@@ -1,5 +1,5 @@
 use crate::{
-    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
     domain::entities::*,
 };
 use axum::{
@@ -12,7 +12,7 @@ use serde::Deserialize;
 pub async fn create_agent(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
-    Json(request): Json<CreateAgentRequest>,
+    ValidatedJson(request): ValidatedJson<CreateAgentRequest>,
 ) -> ApiResult<(StatusCode, Json<CreateAgentResponse>)> {
     let response = state
         .agent_service
@@ -21,6 +21,22 @@ pub async fn create_agent(
     Ok((StatusCode::CREATED, Json(response)))
 }
 
+/// POST /api/agents/bulk
+///
+/// Create agents from a list of emails and email each an invitation;
+/// returns one result per address
+pub async fn bulk_create_agents(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(request): ValidatedJson<BulkCreateAgentsRequest>,
+) -> ApiResult<Json<BulkCreateAgentsResponse>> {
+    let response = state
+        .agent_onboarding_service
+        .bulk_invite_agents(&auth_user, request)
+        .await?;
+    Ok(Json(response))
+}
+
 pub async fn get_agent(
     State(state): State<AppState>,
     axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
@@ -71,7 +87,7 @@ pub async fn update_agent(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
     Path(id): Path<String>,
-    Json(request): Json<UpdateAgentRequest>,
+    ValidatedJson(request): ValidatedJson<UpdateAgentRequest>,
 ) -> ApiResult<Json<AgentResponse>> {
     let response = state
         .agent_service
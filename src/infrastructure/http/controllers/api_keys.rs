@@ -8,8 +8,10 @@ use serde::Deserialize;
 
 use crate::infrastructure::http::middleware::auth::{AppState, AuthenticatedUser};
 use crate::infrastructure::http::middleware::error::ApiError;
+use crate::infrastructure::http::middleware::ValidatedJson;
 use crate::domain::entities::{
-    ApiKeyListItem, ApiKeyListResponse, ApiKeyResponse, GenerateApiKeyRequest, PaginationMetadata,
+    ApiKeyListItem, ApiKeyListResponse, ApiKeyResponse, ApiKeyUsageOverview, ApiKeyUsageResponse,
+    GenerateApiKeyRequest, PaginationMetadata,
 };
 use crate::application::services::api_key_service::{generate_api_key, generate_api_secret, hash_api_secret};
 
@@ -26,6 +28,13 @@ pub struct ListApiKeysQuery {
     pub sort_order: String,
 }
 
+/// Query parameters for API key usage views
+#[derive(Debug, Deserialize)]
+pub struct ApiKeyUsageQuery {
+    /// Window in hours, counting the current hour (default 24)
+    pub hours: Option<i64>,
+}
+
 fn default_page() -> i64 {
     1
 }
@@ -45,7 +54,7 @@ pub async fn generate_api_key_handler(
     State(state): State<AppState>,
     Path(agent_id): Path<String>,
     axum::Extension(authenticated_user): axum::Extension<AuthenticatedUser>,
-    Json(request): Json<GenerateApiKeyRequest>,
+    ValidatedJson(request): ValidatedJson<GenerateApiKeyRequest>,
 ) -> Result<(StatusCode, Json<ApiKeyResponse>), ApiError> {
     // Authorization: Admin or self
     let is_admin = authenticated_user
@@ -219,3 +228,32 @@ pub async fn list_api_keys_handler(
         },
     }))
 }
+
+/// Usage of one API key (owner or admin)
+/// GET /api-keys/:id/usage
+pub async fn get_api_key_usage_handler(
+    State(state): State<AppState>,
+    Path(api_key): Path<String>,
+    Query(query): Query<ApiKeyUsageQuery>,
+    axum::Extension(authenticated_user): axum::Extension<AuthenticatedUser>,
+) -> Result<Json<ApiKeyUsageResponse>, ApiError> {
+    let usage = state
+        .api_key_usage_service
+        .get_key_usage(&authenticated_user, &api_key, query.hours)
+        .await?;
+    Ok(Json(usage))
+}
+
+/// Usage totals across all API keys (admin only)
+/// GET /api-keys/usage
+pub async fn get_api_key_usage_overview_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ApiKeyUsageQuery>,
+    axum::Extension(authenticated_user): axum::Extension<AuthenticatedUser>,
+) -> Result<Json<ApiKeyUsageOverview>, ApiError> {
+    let overview = state
+        .api_key_usage_service
+        .usage_overview(&authenticated_user, query.hours)
+        .await?;
+    Ok(Json(overview))
+}
@@ -7,7 +7,9 @@ use serde::Deserialize;
 
 use crate::{
     domain::entities::*,
-    infrastructure::http::middleware::{ApiError, ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
 };
 
 // POST /api/conversations/:id/assign - Assign conversation
@@ -15,7 +17,7 @@ pub async fn assign_conversation(
     State(state): State<AppState>,
     axum::Extension(user): axum::Extension<AuthenticatedUser>,
     Path(conversation_id): Path<String>,
-    Json(req): Json<AssignConversationRequest>,
+    ValidatedJson(req): ValidatedJson<AssignConversationRequest>,
 ) -> ApiResult<Json<ConversationResponse>> {
     // Get user's permissions via service
     let permissions = state
@@ -77,7 +79,7 @@ pub async fn update_agent_availability(
     State(state): State<AppState>,
     axum::Extension(user): axum::Extension<AuthenticatedUser>,
     Path(agent_id): Path<String>,
-    Json(req): Json<UpdateAvailabilityRequest>,
+    ValidatedJson(req): ValidatedJson<UpdateAvailabilityRequest>,
 ) -> ApiResult<StatusCode> {
     // Verify the user is updating their own availability
     if agent_id != user.user.id {
@@ -232,3 +234,182 @@ pub async fn get_team_conversations(
         },
     }))
 }
+
+// GET /api/conversations/:id/assignment-history - Assignment changes, newest first
+pub async fn get_assignment_history(
+    State(state): State<AppState>,
+    axum::Extension(_user): axum::Extension<AuthenticatedUser>,
+    Path(conversation_id): Path<String>,
+) -> ApiResult<Json<AssignmentHistoryResponse>> {
+    let history = state
+        .assignment_service
+        .get_assignment_history(&conversation_id)
+        .await?;
+
+    Ok(Json(AssignmentHistoryResponse { history }))
+}
+
+// GET /api/routing/language-rules - List language routing rules
+pub async fn list_language_rules(
+    State(state): State<AppState>,
+    axum::Extension(_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<Json<LanguageRoutingRuleListResponse>> {
+    let rules = state.assignment_service.list_language_rules().await?;
+
+    Ok(Json(LanguageRoutingRuleListResponse { rules }))
+}
+
+// POST /api/routing/language-rules - Route a language (or the default) to a team (admin only)
+pub async fn create_language_rule(
+    State(state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(req): ValidatedJson<CreateLanguageRoutingRuleRequest>,
+) -> ApiResult<(StatusCode, Json<LanguageRoutingRule>)> {
+    if !user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let rule = state.assignment_service.create_language_rule(req).await?;
+
+    Ok((StatusCode::CREATED, Json(rule)))
+}
+
+// DELETE /api/routing/language-rules/:id - Remove a language routing rule (admin only)
+pub async fn delete_language_rule(
+    State(state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path(rule_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    if !user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    state
+        .assignment_service
+        .delete_language_rule(&rule_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// PUT /api/teams/:id/members/:user_id/languages - Set a member's language skills (admin only)
+pub async fn set_member_languages(
+    State(state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path((team_id, user_id)): Path<(String, String)>,
+    ValidatedJson(req): ValidatedJson<UpdateMemberLanguagesRequest>,
+) -> ApiResult<Json<MemberLanguagesResponse>> {
+    if !user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let languages = state
+        .assignment_service
+        .set_member_languages(&team_id, &user_id, req)
+        .await?;
+
+    Ok(Json(languages))
+}
+
+// GET /api/skills - List the skill catalog
+pub async fn list_skills(
+    State(state): State<AppState>,
+    axum::Extension(_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<Json<SkillListResponse>> {
+    let skills = state.assignment_service.list_skills().await?;
+
+    Ok(Json(SkillListResponse { skills }))
+}
+
+// POST /api/skills - Add a skill to the catalog (admin only)
+pub async fn create_skill(
+    State(state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(req): ValidatedJson<CreateSkillRequest>,
+) -> ApiResult<(StatusCode, Json<Skill>)> {
+    if !user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let skill = state.assignment_service.create_skill(req).await?;
+
+    Ok((StatusCode::CREATED, Json(skill)))
+}
+
+// PUT /api/skills/:id - Update a skill's description (admin only)
+pub async fn update_skill(
+    State(state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path(skill_id): Path<String>,
+    ValidatedJson(req): ValidatedJson<UpdateSkillRequest>,
+) -> ApiResult<Json<Skill>> {
+    if !user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let skill = state
+        .assignment_service
+        .update_skill(&skill_id, req)
+        .await?;
+
+    Ok(Json(skill))
+}
+
+// DELETE /api/skills/:id - Remove a skill from the catalog and all agents (admin only)
+pub async fn delete_skill(
+    State(state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path(skill_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    if !user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    state.assignment_service.delete_skill(&skill_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// GET /api/agents/:id/skills - List an agent's skills
+pub async fn get_agent_skills(
+    State(state): State<AppState>,
+    axum::Extension(_user): axum::Extension<AuthenticatedUser>,
+    Path(user_id): Path<String>,
+) -> ApiResult<Json<AgentSkillsResponse>> {
+    let skills = state.assignment_service.get_agent_skills(&user_id).await?;
+
+    Ok(Json(AgentSkillsResponse { user_id, skills }))
+}
+
+// PUT /api/agents/:id/skills - Replace an agent's skills (admin only)
+pub async fn set_agent_skills(
+    State(state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path(user_id): Path<String>,
+    ValidatedJson(req): ValidatedJson<UpdateAgentSkillsRequest>,
+) -> ApiResult<Json<AgentSkillsResponse>> {
+    if !user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let skills = state
+        .assignment_service
+        .set_agent_skills(&user_id, req.skill_ids)
+        .await?;
+
+    Ok(Json(AgentSkillsResponse { user_id, skills }))
+}
@@ -0,0 +1,113 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    domain::entities::{
+        AttachmentRuleListResponse, CreateAttachmentRuleRequest, UpdateAttachmentRuleRequest,
+    },
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
+};
+
+/// Create an attachment rule for an inbox (admin only)
+pub async fn create_attachment_rule(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<CreateAttachmentRuleRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let rule = state
+        .attachment_rule_service
+        .create_rule(&inbox_id, request, &auth_user.user.id)
+        .await?;
+
+    Ok((axum::http::StatusCode::CREATED, Json(rule)))
+}
+
+/// List the attachment rules of an inbox (admin only)
+pub async fn list_attachment_rules(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let rules = state.attachment_rule_service.list_rules(&inbox_id).await?;
+    let total = rules.len() as i64;
+
+    Ok(Json(AttachmentRuleListResponse { rules, total }))
+}
+
+/// Get a specific attachment rule (admin only)
+pub async fn get_attachment_rule(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let rule = state.attachment_rule_service.get_rule(&id).await?;
+
+    Ok(Json(rule))
+}
+
+/// Update an attachment rule (admin only)
+pub async fn update_attachment_rule(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateAttachmentRuleRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let rule = state
+        .attachment_rule_service
+        .update_rule(&id, request)
+        .await?;
+
+    Ok(Json(rule))
+}
+
+/// Delete an attachment rule (admin only)
+pub async fn delete_attachment_rule(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    state.attachment_rule_service.delete_rule(&id).await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+};
+
+use crate::{
+    domain::entities::SignedDownloadQuery,
+    infrastructure::http::middleware::{ApiResult, AppState},
+};
+
+/// Download an attachment through a signed link (public, reached from outbound emails)
+pub async fn download_signed_attachment(
+    State(state): State<AppState>,
+    Path(attachment_id): Path<String>,
+    Query(query): Query<SignedDownloadQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let (attachment, content) = state
+        .attachment_service
+        .open_signed_download(&attachment_id, query.expires, &query.signature)
+        .await?;
+
+    let content_type = attachment
+        .content_type
+        .unwrap_or_else(|| "application/octet-stream".to_string());
+    let disposition = format!(
+        "attachment; filename=\"{}\"",
+        attachment.filename.replace(['"', '\\', '\r', '\n'], "_")
+    );
+
+    // The content type comes from the uploader: always download, never render
+    // or sniff, so an uploaded HTML or SVG file can't run script on our origin
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::CONTENT_DISPOSITION, disposition),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+        ],
+        content,
+    ))
+}
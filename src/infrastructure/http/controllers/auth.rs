@@ -1,25 +1,30 @@
 use crate::{
-    infrastructure::http::middleware::{ApiError, ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ClientInfo,
+    },
     domain::entities::*,
-    application::services::auth_logger,
 };
 use axum::{
     extract::{Path, State},
-    http::{HeaderMap, StatusCode},
+    http::StatusCode,
     Json,
 };
 
 pub async fn login(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    client: ClientInfo,
     Json(request): Json<LoginRequest>,
 ) -> ApiResult<Json<LoginResponse>> {
-    // Extract IP address and user agent for logging
-    let ip_address = auth_logger::extract_ip_address(&headers);
-    let user_agent = auth_logger::extract_user_agent(&headers);
-
-    // Check rate limit
-    if let Err(wait_duration) = state.rate_limiter.check(&request.email).await {
+    // Client address and user agent for logging
+    let ip_address = client.ip_address();
+    let user_agent = client.user_agent;
+
+    // Check rate limits, per email and per client address
+    let mut rate_limit = state.rate_limiter.check(&request.email).await;
+    if let (Ok(()), Some(ip)) = (&rate_limit, client.ip) {
+        rate_limit = state.rate_limiter.check_client(ip).await;
+    }
+    if let Err(wait_duration) = rate_limit {
         // Log rate limit exceeded event
         let _ = state.auth_logger_service.log_rate_limit_exceeded(
             request.email.clone(),
@@ -66,6 +71,9 @@ pub async fn login(
         Err(e) => {
             // Record failed attempt for rate limiting
             let _ = state.rate_limiter.record_failure(&request.email).await;
+            if let Some(ip) = client.ip {
+                let _ = state.rate_limiter.record_client_failure(ip).await;
+            }
 
             // Log failed login attempt
             let error_reason = match &e {
@@ -122,12 +130,12 @@ pub async fn login(
 
 pub async fn logout(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    client: ClientInfo,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
 ) -> ApiResult<StatusCode> {
-    // Extract IP address and user agent for logging
-    let ip_address = auth_logger::extract_ip_address(&headers);
-    let user_agent = auth_logger::extract_user_agent(&headers);
+    // Client address and user agent for logging
+    let ip_address = client.ip_address();
+    let user_agent = client.user_agent;
 
     // Log logout event
     let _ = state.auth_logger_service.log_logout(
@@ -270,12 +278,12 @@ pub async fn oidc_login(
 /// validates the ID token, and creates a session.
 pub async fn oidc_callback(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    client: ClientInfo,
     axum::extract::Query(params): axum::extract::Query<OidcCallbackParams>,
 ) -> ApiResult<Json<LoginResponse>> {
-    // Extract IP address and user agent for logging
-    let ip_address = auth_logger::extract_ip_address(&headers);
-    let user_agent = auth_logger::extract_user_agent(&headers);
+    // Client address and user agent for logging
+    let ip_address = client.ip_address();
+    let user_agent = client.user_agent;
 
     // Check for error from provider
     if let Some(error) = params.error {
@@ -0,0 +1,130 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    domain::entities::{
+        AutoTagBackfillRequest, AutoTagRuleListResponse, CreateAutoTagRuleRequest,
+        UpdateAutoTagRuleRequest,
+    },
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
+};
+
+/// Create an auto-tag rule for an inbox (admin only)
+pub async fn create_auto_tag_rule(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<CreateAutoTagRuleRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let rule = state
+        .auto_tag_service
+        .create_rule(&inbox_id, request, &auth_user.user.id)
+        .await?;
+
+    Ok((axum::http::StatusCode::CREATED, Json(rule)))
+}
+
+/// List the auto-tag rules of an inbox (admin only)
+pub async fn list_auto_tag_rules(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let rules = state.auto_tag_service.list_rules(&inbox_id).await?;
+    let total = rules.len() as i64;
+
+    Ok(Json(AutoTagRuleListResponse { rules, total }))
+}
+
+/// Get a specific auto-tag rule (admin only)
+pub async fn get_auto_tag_rule(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let rule = state.auto_tag_service.get_rule(&id).await?;
+
+    Ok(Json(rule))
+}
+
+/// Update an auto-tag rule (admin only)
+pub async fn update_auto_tag_rule(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateAutoTagRuleRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let rule = state.auto_tag_service.update_rule(&id, request).await?;
+
+    Ok(Json(rule))
+}
+
+/// Delete an auto-tag rule (admin only)
+pub async fn delete_auto_tag_rule(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    state.auto_tag_service.delete_rule(&id).await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// Run an inbox's auto-tag rules over one batch of existing conversations (admin only)
+pub async fn backfill_auto_tag_rules(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<AutoTagBackfillRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let response = state.auto_tag_service.backfill(&inbox_id, request).await?;
+
+    Ok(Json(response))
+}
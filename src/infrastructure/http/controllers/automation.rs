@@ -7,8 +7,11 @@ use axum::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    infrastructure::http::middleware::{ApiError, ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
     domain::entities::{AutomationRule, RuleAction, RuleCondition, RuleEvaluationLog, RuleType},
+    shared::validation::{Validate, ValidationErrors},
 };
 
 // Request DTOs
@@ -33,6 +36,40 @@ pub struct UpdateAutomationRuleRequest {
     pub priority: Option<i32>,
 }
 
+impl Validate for CreateAutomationRuleRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 200);
+        errors.items("event_subscription", &self.event_subscription, 1, 100);
+        if let Err(e) = self.condition.validate() {
+            errors.add("condition", e);
+        }
+        if let Err(e) = self.action.validate() {
+            errors.add("action", e);
+        }
+        if let Some(priority) = self.priority {
+            errors.range("priority", priority, 1, 1000);
+        }
+    }
+}
+
+impl Validate for UpdateAutomationRuleRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("name", self.name.as_deref(), 1, 200);
+        if let Some(event_subscription) = &self.event_subscription {
+            errors.items("event_subscription", event_subscription, 1, 100);
+        }
+        if let Some(Err(e)) = self.condition.as_ref().map(RuleCondition::validate) {
+            errors.add("condition", e);
+        }
+        if let Some(Err(e)) = self.action.as_ref().map(RuleAction::validate) {
+            errors.add("action", e);
+        }
+        if let Some(priority) = self.priority {
+            errors.range("priority", priority, 1, 1000);
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RuleFilters {
     pub enabled: Option<bool>,
@@ -139,7 +176,7 @@ pub struct LogListResponse {
 pub async fn create_automation_rule(
     State(state): State<AppState>,
     Extension(user): Extension<AuthenticatedUser>,
-    Json(request): Json<CreateAutomationRuleRequest>,
+    ValidatedJson(request): ValidatedJson<CreateAutomationRuleRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Check permission
     if !user.has_permission("automation:manage").await {
@@ -265,7 +302,7 @@ pub async fn update_automation_rule(
     State(state): State<AppState>,
     Extension(user): Extension<AuthenticatedUser>,
     Path(rule_id): Path<String>,
-    Json(request): Json<UpdateAutomationRuleRequest>,
+    ValidatedJson(request): ValidatedJson<UpdateAutomationRuleRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Check permission
     if !user.has_permission("automation:manage").await {
@@ -1,5 +1,7 @@
 use crate::{
-    infrastructure::http::middleware::{ApiError, ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
     domain::entities::*,
 };
 use axum::{
@@ -13,7 +15,7 @@ pub async fn set_availability(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
     Path(agent_id): Path<String>,
-    Json(request): Json<SetAvailabilityRequest>,
+    ValidatedJson(request): ValidatedJson<SetAvailabilityRequest>,
 ) -> ApiResult<Json<AvailabilityResponse>> {
     // Verify the agent is changing their own status (or has admin permission)
     let has_admin = auth_user.roles.iter().any(|r| r.name == "Admin");
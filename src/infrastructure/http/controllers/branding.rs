@@ -0,0 +1,46 @@
+use axum::{extract::State, http::header, response::IntoResponse, Json};
+
+use crate::{
+    domain::entities::{Branding, UpdateBrandingRequest},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
+};
+
+/// Get the workspace branding (public, used by the login page and web UI)
+pub async fn get_branding(State(state): State<AppState>) -> ApiResult<Json<Branding>> {
+    let branding = state.branding_service.get_branding().await?;
+    Ok(Json(branding))
+}
+
+/// Stylesheet setting the web UI's accent color from the branding (public)
+pub async fn branding_stylesheet(State(state): State<AppState>) -> impl IntoResponse {
+    let branding = state.branding_service.current().await;
+    (
+        [
+            (header::CONTENT_TYPE, "text/css; charset=utf-8"),
+            (header::CACHE_CONTROL, "public, max-age=60"),
+        ],
+        branding.stylesheet(),
+    )
+}
+
+/// Update the workspace branding (admin only)
+pub async fn update_branding(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(request): ValidatedJson<UpdateBrandingRequest>,
+) -> ApiResult<Json<Branding>> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let branding = state
+        .branding_service
+        .update_branding(request, &auth_user.user.id)
+        .await?;
+    Ok(Json(branding))
+}
@@ -0,0 +1,44 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::domain::entities::ChannelMessageRequest;
+use crate::infrastructure::http::middleware::{
+    ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+};
+
+/// Push a message from an external channel (chat widget, SMS gateway, ...) into an inbox
+///
+/// Returns 201 when a message was created and 200 when the external message ID
+/// was already ingested.
+pub async fn receive_channel_message(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<ChannelMessageRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Channel messages may open conversations
+    if !crate::application::services::PermissionService::has_permission(
+        &auth_user.roles,
+        "conversations:create",
+    ) {
+        return Err(ApiError::Forbidden(
+            "Missing permission: conversations:create".to_string(),
+        ));
+    }
+
+    let response = state
+        .channel_service
+        .receive_message(&inbox_id, request)
+        .await?;
+    let status = if response.duplicate {
+        StatusCode::OK
+    } else {
+        StatusCode::CREATED
+    };
+
+    Ok((status, Json(response)))
+}
@@ -0,0 +1,215 @@
+use axum::{
+    extract::{
+        ws::{Message as WsMessage, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
+    http::header,
+    response::IntoResponse,
+};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+use crate::application::services::ChatWidgetService;
+use crate::domain::entities::{
+    WidgetClientFrame, WidgetConnectQuery, WidgetServerFrame, WidgetSession,
+    CHAT_MESSAGE_EVENT_TYPE, WIDGET_MESSAGES_PER_MINUTE,
+};
+use crate::infrastructure::http::middleware::{ApiResult, AppState};
+use crate::infrastructure::providers::connection_manager::NotificationEvent;
+
+/// Widget script template; the inbox ID placeholder is filled in per inbox
+const WIDGET_SCRIPT: &str = include_str!("../../web/static/chat-widget.js");
+
+/// Serve the embeddable chat widget script for a chat inbox
+pub async fn widget_script(
+    State(state): State<AppState>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let inbox = state.chat_widget_service.widget_inbox(&inbox_id).await?;
+    let inbox_id_literal = serde_json::to_string(&inbox.id).unwrap_or_else(|_| "\"\"".to_string());
+    let script = WIDGET_SCRIPT.replace("\"__OXIDESK_INBOX_ID__\"", &inbox_id_literal);
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                "application/javascript; charset=utf-8",
+            ),
+            (header::CACHE_CONTROL, "public, max-age=300"),
+        ],
+        script,
+    ))
+}
+
+/// Public WebSocket for chat widget visitors
+pub async fn widget_socket(
+    State(state): State<AppState>,
+    Path(inbox_id): Path<String>,
+    Query(query): Query<WidgetConnectQuery>,
+    ws: WebSocketUpgrade,
+) -> ApiResult<impl IntoResponse> {
+    let service = state.chat_widget_service.clone();
+    let session = service.start_session(&inbox_id, &query).await?;
+
+    Ok(ws.on_upgrade(move |socket| handle_widget_socket(socket, service, session)))
+}
+
+async fn handle_widget_socket(
+    mut socket: WebSocket,
+    service: ChatWidgetService,
+    mut session: WidgetSession,
+) {
+    let connections = service.connections();
+    let (tx, mut rx) = mpsc::channel::<NotificationEvent>(100);
+    if let Some(ref conversation_id) = session.conversation_id {
+        connections
+            .add_connection(conversation_id, tx.clone())
+            .await;
+    }
+
+    let history = match service.history(&session).await {
+        Ok(messages) => messages,
+        Err(e) => {
+            tracing::warn!("Failed to load chat history: {}", e);
+            Vec::new()
+        }
+    };
+    if send_frame(&mut socket, &session_frame(&session))
+        .await
+        .is_err()
+        || send_frame(
+            &mut socket,
+            &WidgetServerFrame::History { messages: history },
+        )
+        .await
+        .is_err()
+    {
+        return;
+    }
+
+    let mut recent_messages: VecDeque<Instant> = VecDeque::new();
+    loop {
+        tokio::select! {
+            incoming = socket.recv() => {
+                let text = match incoming {
+                    Some(Ok(WsMessage::Text(text))) => text,
+                    Some(Ok(WsMessage::Close(_))) | None | Some(Err(_)) => break,
+                    Some(Ok(_)) => continue,
+                };
+
+                let previous_conversation = session.conversation_id.clone();
+                let reply =
+                    handle_client_frame(&service, &mut session, &mut recent_messages, &text).await;
+
+                // The first message starts a conversation: listen for agent replies on it
+                if session.conversation_id != previous_conversation {
+                    if let Some(ref conversation_id) = session.conversation_id {
+                        connections.add_connection(conversation_id, tx.clone()).await;
+                    }
+                    if send_frame(&mut socket, &session_frame(&session)).await.is_err() {
+                        break;
+                    }
+                }
+                if send_frame(&mut socket, &reply).await.is_err() {
+                    break;
+                }
+            }
+            Some(event) = rx.recv() => {
+                let Some(reply) = agent_reply(&service, &session, event).await else {
+                    continue;
+                };
+                if send_frame(&mut socket, &reply).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    if let Some(ref conversation_id) = session.conversation_id {
+        connections.remove_connection(conversation_id).await;
+    }
+}
+
+/// Handle a frame from the visitor and build the response frame
+async fn handle_client_frame(
+    service: &ChatWidgetService,
+    session: &mut WidgetSession,
+    recent_messages: &mut VecDeque<Instant>,
+    text: &str,
+) -> WidgetServerFrame {
+    let error = |error: String| WidgetServerFrame::Error { error };
+
+    match serde_json::from_str::<WidgetClientFrame>(text) {
+        Ok(WidgetClientFrame::Message { content }) => {
+            let now = Instant::now();
+            while recent_messages
+                .front()
+                .is_some_and(|sent| now.duration_since(*sent) > Duration::from_secs(60))
+            {
+                recent_messages.pop_front();
+            }
+            if recent_messages.len() >= WIDGET_MESSAGES_PER_MINUTE {
+                return error("Too many messages, please wait a moment".to_string());
+            }
+            recent_messages.push_back(now);
+
+            match service.send_message(session, content).await {
+                Ok(message) => WidgetServerFrame::Message { message },
+                Err(e) => error(e.to_string()),
+            }
+        }
+        Ok(WidgetClientFrame::Identify {
+            email,
+            name,
+            identity_signature,
+        }) => {
+            match service
+                .identify(
+                    session,
+                    &email,
+                    name.as_deref(),
+                    identity_signature.as_deref(),
+                )
+                .await
+            {
+                Ok(()) => session_frame(session),
+                Err(e) => error(e.to_string()),
+            }
+        }
+        Err(_) => error("Invalid frame".to_string()),
+    }
+}
+
+/// Frame for an agent reply pushed through the connection manager, if it is for this visitor
+async fn agent_reply(
+    service: &ChatWidgetService,
+    session: &WidgetSession,
+    event: NotificationEvent,
+) -> Option<WidgetServerFrame> {
+    if event.type_ != CHAT_MESSAGE_EVENT_TYPE {
+        return None;
+    }
+    let message_id = event.message_id?;
+
+    match service.reply_for_visitor(session, &message_id).await {
+        Ok(message) => message.map(|message| WidgetServerFrame::Message { message }),
+        Err(e) => {
+            tracing::warn!("Failed to load chat reply {}: {}", message_id, e);
+            None
+        }
+    }
+}
+
+fn session_frame(session: &WidgetSession) -> WidgetServerFrame {
+    WidgetServerFrame::Session {
+        visitor_token: session.visitor_token.clone(),
+        conversation_id: session.conversation_id.clone(),
+        identified: session.identified,
+    }
+}
+
+async fn send_frame(socket: &mut WebSocket, frame: &WidgetServerFrame) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(frame).unwrap_or_else(|_| "{}".to_string());
+    socket.send(WsMessage::Text(text)).await
+}
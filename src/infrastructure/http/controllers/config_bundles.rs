@@ -0,0 +1,46 @@
+use axum::{extract::State, response::IntoResponse, Json};
+
+use crate::{
+    domain::entities::ImportConfigRequest,
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
+};
+
+/// Export automation rules, macros, tags, SLA policies and webhooks as a bundle (admin only)
+pub async fn export_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let bundle = state.config_bundle_service.export().await?;
+
+    Ok(Json(bundle))
+}
+
+/// Import a configuration bundle, or preview it with `dry_run` (admin only)
+pub async fn import_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(request): ValidatedJson<ImportConfigRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let response = state
+        .config_bundle_service
+        .import(request, &auth_user.user.id)
+        .await?;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,63 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+use crate::{
+    domain::entities::{
+        ContactNote, ContactNoteListResponse, CreateContactNoteRequest, UpdateContactNoteRequest,
+    },
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+/// List a contact's notes, pinned first
+pub async fn list_contact_notes(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(contact_id): Path<String>,
+) -> ApiResult<Json<ContactNoteListResponse>> {
+    let notes = state.contact_note_service.list_notes(&contact_id).await?;
+    Ok(Json(ContactNoteListResponse { contact_id, notes }))
+}
+
+/// Add a note about a contact as the current agent
+pub async fn create_contact_note(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(contact_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<CreateContactNoteRequest>,
+) -> ApiResult<(StatusCode, Json<ContactNote>)> {
+    let note = state
+        .contact_note_service
+        .create_note(&auth_user, &contact_id, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(note)))
+}
+
+/// Edit a note's content (author or admin) or pin/unpin it (any agent)
+pub async fn update_contact_note(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((contact_id, note_id)): Path<(String, String)>,
+    ValidatedJson(request): ValidatedJson<UpdateContactNoteRequest>,
+) -> ApiResult<Json<ContactNote>> {
+    let note = state
+        .contact_note_service
+        .update_note(&auth_user, &contact_id, &note_id, request)
+        .await?;
+    Ok(Json(note))
+}
+
+/// Delete a note (author or admin)
+pub async fn delete_contact_note(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((contact_id, note_id)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    state
+        .contact_note_service
+        .delete_note(&auth_user, &contact_id, &note_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
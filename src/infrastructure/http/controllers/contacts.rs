@@ -1,5 +1,5 @@
 use crate::{
-    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
     domain::entities::*,
 };
 use axum::{
@@ -12,7 +12,7 @@ use serde::Deserialize;
 pub async fn create_contact(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
-    Json(request): Json<CreateContactRequest>,
+    ValidatedJson(request): ValidatedJson<CreateContactRequest>,
 ) -> ApiResult<(StatusCode, Json<ContactResponse>)> {
     let response = state
         .contact_service
@@ -26,7 +26,9 @@ pub async fn get_contact(
     axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
     Path(id): Path<String>,
 ) -> ApiResult<Json<ContactResponse>> {
-    let response = state.contact_service.get_contact(&id).await?;
+    let mut response = state.contact_service.get_contact(&id).await?;
+    response.notes = Some(state.contact_note_service.list_notes(&id).await?);
+    response.tier = state.priority_matrix_service.get_contact_tier(&id).await?;
     Ok(Json(response))
 }
 
@@ -62,7 +64,7 @@ pub async fn update_contact(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
     Path(id): Path<String>,
-    Json(request): Json<UpdateContactRequest>,
+    ValidatedJson(request): ValidatedJson<UpdateContactRequest>,
 ) -> ApiResult<Json<ContactResponse>> {
     let response = state
         .contact_service
@@ -79,3 +81,32 @@ pub async fn delete_contact(
     state.contact_service.delete(&auth_user, &id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Re-send the email verification link for one of a contact's channels (admin only)
+pub async fn resend_contact_email_verification(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((id, channel_id)): Path<(String, String)>,
+) -> ApiResult<(StatusCode, Json<ContactEmailVerificationResponse>)> {
+    state
+        .contact_service
+        .resend_email_verification(&auth_user, &id, &channel_id)
+        .await?;
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(ContactEmailVerificationResponse {
+            message: "Verification email sent".to_string(),
+        }),
+    ))
+}
+
+/// Confirm a contact's email address (public, reached from the emailed link)
+pub async fn verify_contact_email(
+    State(state): State<AppState>,
+    Query(query): Query<VerifyContactEmailQuery>,
+) -> ApiResult<Json<ContactEmailVerificationResponse>> {
+    state.contact_service.verify_email(&query.token).await?;
+    Ok(Json(ContactEmailVerificationResponse {
+        message: "Email address verified".to_string(),
+    }))
+}
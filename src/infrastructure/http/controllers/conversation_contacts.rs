@@ -0,0 +1,50 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+use crate::{
+    domain::entities::{AddConversationContactRequest, ConversationContact},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+/// Contacts taking part in a conversation, primary contact first
+pub async fn list_contacts(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<ConversationContact>>> {
+    let contacts = state
+        .conversation_contact_service
+        .list_contacts(&auth_user, &id)
+        .await?;
+    Ok(Json(contacts))
+}
+
+/// Add a contact to the conversation (and to outbound email on the thread)
+pub async fn add_contact(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<AddConversationContactRequest>,
+) -> ApiResult<(StatusCode, Json<ConversationContact>)> {
+    let contact = state
+        .conversation_contact_service
+        .add_contact(&auth_user, &id, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(contact)))
+}
+
+/// Remove an additional contact from the conversation
+pub async fn remove_contact(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((id, contact_id)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    state
+        .conversation_contact_service
+        .remove_contact(&auth_user, &id, &contact_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -4,7 +4,7 @@ use axum::{
 };
 
 use crate::{
-    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
     domain::entities::*,
 };
 
@@ -33,7 +33,7 @@ pub async fn add_tags_to_conversation(
     State(state): State<AppState>,
     axum::Extension(user): axum::Extension<AuthenticatedUser>,
     Path(conversation_id): Path<String>,
-    Json(req): Json<AddTagsRequest>,
+    ValidatedJson(req): ValidatedJson<AddTagsRequest>,
 ) -> ApiResult<Json<ConversationTagsResponse>> {
     // Get user permissions
     let permissions = state
@@ -86,7 +86,7 @@ pub async fn replace_conversation_tags(
     State(state): State<AppState>,
     axum::Extension(user): axum::Extension<AuthenticatedUser>,
     Path(conversation_id): Path<String>,
-    Json(req): Json<ReplaceTagsRequest>,
+    ValidatedJson(req): ValidatedJson<ReplaceTagsRequest>,
 ) -> ApiResult<Json<ConversationTagsResponse>> {
     // Get user permissions
     let permissions = state
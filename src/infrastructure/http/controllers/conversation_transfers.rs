@@ -0,0 +1,63 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+use crate::{
+    domain::entities::{ConversationTransfer, TransferConversationRequest},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+/// Transfer a conversation to another agent and/or team with a handover note
+pub async fn transfer_conversation(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(conversation_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<TransferConversationRequest>,
+) -> ApiResult<(StatusCode, Json<ConversationTransfer>)> {
+    let transfer = state
+        .conversation_transfer_service
+        .transfer(&auth_user, &conversation_id, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(transfer)))
+}
+
+/// List a conversation's transfers, newest first
+pub async fn list_conversation_transfers(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(conversation_id): Path<String>,
+) -> ApiResult<Json<Vec<ConversationTransfer>>> {
+    let transfers = state
+        .conversation_transfer_service
+        .list_transfers(&conversation_id)
+        .await?;
+    Ok(Json(transfers))
+}
+
+/// Accept a pending transfer (receiver only)
+pub async fn accept_conversation_transfer(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((conversation_id, transfer_id)): Path<(String, String)>,
+) -> ApiResult<Json<ConversationTransfer>> {
+    let transfer = state
+        .conversation_transfer_service
+        .accept(&auth_user, &conversation_id, &transfer_id)
+        .await?;
+    Ok(Json(transfer))
+}
+
+/// Decline a pending transfer (receiver only)
+pub async fn decline_conversation_transfer(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((conversation_id, transfer_id)): Path<(String, String)>,
+) -> ApiResult<Json<ConversationTransfer>> {
+    let transfer = state
+        .conversation_transfer_service
+        .decline(&auth_user, &conversation_id, &transfer_id)
+        .await?;
+    Ok(Json(transfer))
+}
@@ -1,7 +1,11 @@
-use crate::infrastructure::http::middleware::{ApiError, ApiResult, AppState, AuthenticatedUser};
+use crate::infrastructure::http::middleware::{
+    ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+};
 use crate::domain::entities::{
-    ConversationListResponse, ConversationStatus, CreateConversation, PaginationMetadata,
-    UpdatePriorityRequest, UpdateStatusRequest,
+    compile_conversation_query, ConversationListFilter, ConversationListResponse,
+    ConversationQueryContext, ConversationSort, ConversationSortField, ConversationStatus,
+    CreateConversation, PaginationMetadata, SentimentLabel, SortDirection, UpdatePriorityRequest,
+    UpdateStatusRequest,
 };
 
 use axum::{
@@ -16,7 +20,7 @@ use serde::Deserialize;
 pub async fn create_conversation(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
-    Json(request): Json<CreateConversation>,
+    ValidatedJson(request): ValidatedJson<CreateConversation>,
 ) -> ApiResult<impl IntoResponse> {
     // Check if user has conversations:create permission
     let has_create = crate::application::services::PermissionService::has_permission(
@@ -42,7 +46,7 @@ pub async fn update_conversation_status(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
     Path(id): Path<String>,
-    Json(request): Json<UpdateStatusRequest>,
+    ValidatedJson(request): ValidatedJson<UpdateStatusRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Check if user has conversations:update_all (admin access)
     let has_update_all = crate::application::services::PermissionService::has_permission(
@@ -150,6 +154,20 @@ pub async fn get_conversation(
     Ok(Json(conversation))
 }
 
+/// History of status, assignment, priority, tag and SLA changes
+pub async fn get_conversation_timeline(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let timeline = state
+        .conversation_service
+        .get_timeline(&auth_user, &id)
+        .await?;
+
+    Ok(Json(timeline))
+}
+
 /// Get conversation by Reference Number
 pub async fn get_conversation_by_reference(
     State(state): State<AppState>,
@@ -181,6 +199,53 @@ pub struct ListConversationsParams {
     pub status: Option<ConversationStatus>,
     pub inbox_id: Option<String>,
     pub contact_id: Option<String>,
+    /// Filter by sentiment bucket (negative, neutral, positive)
+    pub sentiment: Option<SentimentLabel>,
+    /// Only open or snoozed conversations
+    #[serde(default)]
+    pub unresolved: bool,
+    /// created_at (default), last_message_at, priority, sla_time_to_breach or
+    /// reference_number
+    #[serde(default)]
+    pub sort_by: ConversationSortField,
+    /// asc or desc; defaults to the field's natural order
+    pub sort_order: Option<SortDirection>,
+    /// Search query, e.g. `status:open tag:billing priority>=high assignee:me`
+    pub q: Option<String>,
+    /// Saved view whose query is applied along with `q`
+    pub view: Option<String>,
+}
+
+impl ListConversationsParams {
+    async fn filter(
+        &self,
+        state: &AppState,
+        auth_user: &AuthenticatedUser,
+    ) -> ApiResult<ConversationListFilter> {
+        let mut conditions = match &self.view {
+            Some(view_id) => {
+                state
+                    .saved_view_service
+                    .view_conditions(auth_user, view_id)
+                    .await?
+            }
+            None => Vec::new(),
+        };
+        if let Some(q) = &self.q {
+            let context = ConversationQueryContext::new(&auth_user.user.id);
+            conditions.extend(compile_conversation_query(q, &context)?);
+        }
+
+        Ok(ConversationListFilter {
+            status: self.status,
+            inbox_id: self.inbox_id.clone(),
+            contact_id: self.contact_id.clone(),
+            sentiment: self.sentiment,
+            unresolved: self.unresolved,
+            conditions,
+            sort: ConversationSort::new(self.sort_by, self.sort_order),
+        })
+    }
 }
 
 fn default_page() -> i64 {
@@ -216,18 +281,13 @@ pub async fn list_conversations(
         ));
     }
 
+    let filter = params.filter(&state, &auth_user).await?;
+
     // If user has read_all, show all conversations
     if has_read_all {
         let response = state
             .conversation_service
-            .list_conversations(
-                &auth_user,
-                params.page,
-                params.per_page,
-                params.status,
-                params.inbox_id,
-                params.contact_id,
-            )
+            .list_conversations(&auth_user, params.page, params.per_page, filter)
             .await?;
         return Ok(Json(response));
     }
@@ -237,14 +297,7 @@ pub async fn list_conversations(
     // A more efficient approach would be to add a database query filter
     let all_response = state
         .conversation_service
-        .list_conversations(
-            &auth_user,
-            params.page,
-            params.per_page,
-            params.status,
-            params.inbox_id,
-            params.contact_id,
-        )
+        .list_conversations(&auth_user, params.page, params.per_page, filter)
         .await?;
 
     // Get user's teams
@@ -282,12 +335,25 @@ pub async fn list_conversations(
     Ok(Json(response))
 }
 
+/// Sidebar counters over the open conversations the user may read
+pub async fn get_conversation_counts(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<impl IntoResponse> {
+    let counts = state
+        .conversation_service
+        .get_sidebar_counts(&auth_user)
+        .await?;
+
+    Ok(Json(counts))
+}
+
 /// Update conversation priority (Feature 020)
 pub async fn update_conversation_priority(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
     Path(id): Path<String>,
-    Json(request): Json<UpdatePriorityRequest>,
+    ValidatedJson(request): ValidatedJson<UpdatePriorityRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Check if user has conversations:update_priority permission
     let has_permission = crate::application::services::PermissionService::has_permission(
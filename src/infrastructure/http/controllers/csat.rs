@@ -0,0 +1,63 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    domain::entities::{
+        CsatResponseListResponse, CsatSurveyLinkResponse, CsatSurveyQuery, SubmitCsatRequest,
+    },
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
+};
+
+/// Record a customer's CSAT response (public, reached through a signed survey link)
+pub async fn submit_csat_response(
+    State(state): State<AppState>,
+    Path(conversation_id): Path<String>,
+    Query(query): Query<CsatSurveyQuery>,
+    ValidatedJson(request): ValidatedJson<SubmitCsatRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let response = state
+        .csat_service
+        .submit_response(&conversation_id, &query.signature, request)
+        .await?;
+
+    Ok((axum::http::StatusCode::CREATED, Json(response)))
+}
+
+/// Survey link to send the conversation's customer (needs read access to the conversation)
+pub async fn get_csat_survey_link(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(conversation_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    state
+        .conversation_service
+        .get_readable_conversation(&auth_user, &conversation_id)
+        .await?;
+
+    Ok(Json(CsatSurveyLinkResponse {
+        url: state.csat_service.survey_url(&conversation_id),
+    }))
+}
+
+/// List the CSAT responses of a conversation, newest first (requires `reports:read`)
+pub async fn list_csat_responses(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(conversation_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    if !auth_user.has_permission("reports:read").await {
+        return Err(ApiError::Forbidden(
+            "Missing permission: reports:read".to_string(),
+        ));
+    }
+
+    let responses = state.csat_service.list_responses(&conversation_id).await?;
+    let total = responses.len() as i64;
+
+    Ok(Json(CsatResponseListResponse { responses, total }))
+}
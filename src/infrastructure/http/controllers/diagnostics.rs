@@ -0,0 +1,36 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::entities::SlowQueryReport,
+    infrastructure::http::middleware::{ApiError, ApiResult, AppState, AuthenticatedUser},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SlowQueryParams {
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+fn default_limit() -> usize {
+    20
+}
+
+/// Worst slow queries since startup with their plans (admin only)
+pub async fn list_slow_queries(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Query(params): Query<SlowQueryParams>,
+) -> ApiResult<Json<SlowQueryReport>> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    Ok(Json(state.diagnostics_service.slow_queries(params.limit)))
+}
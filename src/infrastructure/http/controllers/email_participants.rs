@@ -0,0 +1,36 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+
+use crate::{
+    domain::entities::{EmailParticipant, UpdateEmailParticipantRequest},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+/// Email addresses on a conversation's thread, including suppressed ones
+pub async fn list_participants(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<EmailParticipant>>> {
+    let participants = state
+        .email_participant_service
+        .list_participants(&auth_user, &id)
+        .await?;
+    Ok(Json(participants))
+}
+
+/// Suppress or restore outbound email to a participant
+pub async fn update_participant(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((id, email)): Path<(String, String)>,
+    ValidatedJson(request): ValidatedJson<UpdateEmailParticipantRequest>,
+) -> ApiResult<Json<EmailParticipant>> {
+    let participant = state
+        .email_participant_service
+        .update_participant(&auth_user, &id, &email, request)
+        .await?;
+    Ok(Json(participant))
+}
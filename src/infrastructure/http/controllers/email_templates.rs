@@ -0,0 +1,49 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+
+use crate::{
+    domain::entities::{
+        EmailTemplatePreview, EmailTemplateSummary, PreviewEmailTemplateRequest,
+        TestSendEmailTemplateRequest, TestSendEmailTemplateResponse,
+    },
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+/// List the email templates that can be previewed (admin only)
+pub async fn list_email_templates(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<Json<Vec<EmailTemplateSummary>>> {
+    let templates = state.email_template_service.list_templates(&auth_user)?;
+    Ok(Json(templates))
+}
+
+/// Render a template with sample or given variables (admin only)
+pub async fn preview_email_template(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(template_id): Path<String>,
+    Json(request): Json<PreviewEmailTemplateRequest>,
+) -> ApiResult<Json<EmailTemplatePreview>> {
+    let preview = state
+        .email_template_service
+        .preview(&auth_user, &template_id, request)
+        .await?;
+    Ok(Json(preview))
+}
+
+/// Email a rendered template through an inbox's mail server (admin only)
+pub async fn test_send_email_template(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(template_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<TestSendEmailTemplateRequest>,
+) -> ApiResult<Json<TestSendEmailTemplateResponse>> {
+    let response = state
+        .email_template_service
+        .test_send(&auth_user, &template_id, request)
+        .await?;
+    Ok(Json(response))
+}
@@ -0,0 +1,64 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::{
+    domain::entities::{EraseContactRequest, GdprRequest},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+/// Download everything stored about a contact as a JSON document (admin only)
+pub async fn export_contact_data(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let export = state.gdpr_service.export_contact(&auth_user, &id).await?;
+    let disposition = format!("attachment; filename=\"contact-{}-export.json\"", id);
+    Ok(([(header::CONTENT_DISPOSITION, disposition)], Json(export)))
+}
+
+/// Erase a contact's personal data (admin only)
+///
+/// Without `confirmation_token` this returns 202 with a token; sending it
+/// back within its lifetime performs the erasure.
+pub async fn erase_contact_data(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<EraseContactRequest>,
+) -> ApiResult<Response> {
+    match request.confirmation_token {
+        Some(token) => {
+            let record = state
+                .gdpr_service
+                .confirm_erasure(&auth_user, &id, &token)
+                .await?;
+            Ok(Json(record).into_response())
+        }
+        None => {
+            let confirmation = state
+                .gdpr_service
+                .request_erasure(
+                    &auth_user,
+                    &id,
+                    request.message_redaction.unwrap_or_default(),
+                )
+                .await?;
+            Ok((StatusCode::ACCEPTED, Json(confirmation)).into_response())
+        }
+    }
+}
+
+/// Audit trail of a contact's exports and erasures (admin only)
+pub async fn list_gdpr_requests(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<GdprRequest>>> {
+    let requests = state.gdpr_service.list_requests(&auth_user, &id).await?;
+    Ok(Json(requests))
+}
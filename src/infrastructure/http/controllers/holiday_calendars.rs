@@ -0,0 +1,213 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    domain::entities::{
+        CreateHolidayCalendarRequest, CreateHolidayRequest, HolidayCalendarListResponse,
+        HolidayListResponse, ImportHolidaysRequest, SetTeamHolidayCalendarRequest,
+        UpdateHolidayCalendarRequest, WorkingDaysQuery,
+    },
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
+};
+
+/// Create a holiday calendar (admin only)
+pub async fn create_holiday_calendar(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(request): ValidatedJson<CreateHolidayCalendarRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let calendar = state
+        .holiday_calendar_service
+        .create_calendar(request)
+        .await?;
+
+    Ok((axum::http::StatusCode::CREATED, Json(calendar)))
+}
+
+/// List holiday calendars
+pub async fn list_holiday_calendars(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<impl IntoResponse> {
+    let calendars = state.holiday_calendar_service.list_calendars().await?;
+    let count = calendars.len() as i64;
+
+    Ok(Json(HolidayCalendarListResponse { calendars, count }))
+}
+
+/// Get a holiday calendar
+pub async fn get_holiday_calendar(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let calendar = state.holiday_calendar_service.get_calendar(&id).await?;
+
+    Ok(Json(calendar))
+}
+
+/// Update a holiday calendar (admin only)
+pub async fn update_holiday_calendar(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateHolidayCalendarRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let calendar = state
+        .holiday_calendar_service
+        .update_calendar(&id, request)
+        .await?;
+
+    Ok(Json(calendar))
+}
+
+/// Delete a holiday calendar and its holidays (admin only)
+pub async fn delete_holiday_calendar(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    state.holiday_calendar_service.delete_calendar(&id).await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// List the holidays in a calendar
+pub async fn list_calendar_holidays(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let holidays = state.holiday_calendar_service.list_holidays(&id).await?;
+    let count = holidays.len() as i64;
+
+    Ok(Json(HolidayListResponse { holidays, count }))
+}
+
+/// Add a holiday to a calendar (admin only)
+pub async fn create_calendar_holiday(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<CreateHolidayRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let holiday = state
+        .holiday_calendar_service
+        .add_holiday(&id, request)
+        .await?;
+
+    Ok((axum::http::StatusCode::CREATED, Json(holiday)))
+}
+
+/// Remove a holiday from a calendar (admin only)
+pub async fn delete_calendar_holiday(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((id, holiday_id)): Path<(String, String)>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    state
+        .holiday_calendar_service
+        .delete_holiday(&id, &holiday_id)
+        .await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// Import holidays from an iCalendar (.ics) document (admin only)
+pub async fn import_calendar_holidays(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<ImportHolidaysRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let response = state
+        .holiday_calendar_service
+        .import_ical(&id, request)
+        .await?;
+
+    Ok(Json(response))
+}
+
+/// Link a team to a holiday calendar, or unlink it (admin only)
+pub async fn set_team_holiday_calendar(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(team_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<SetTeamHolidayCalendarRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    state
+        .holiday_calendar_service
+        .set_team_calendar(&team_id, request.holiday_calendar_id.as_deref())
+        .await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// Get a team's working days over a date range
+pub async fn get_team_working_days(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(team_id): Path<String>,
+    Query(query): Query<WorkingDaysQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let response = state
+        .holiday_calendar_service
+        .team_working_days(&team_id, &query.from, &query.to)
+        .await?;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,44 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+use crate::{
+    domain::entities::{CreateImportRequest, ImportListResponse, ImportResponse},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+/// Upload a Zendesk or Freshdesk export to import in the background (admin only)
+pub async fn create_import(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(request): ValidatedJson<CreateImportRequest>,
+) -> ApiResult<(StatusCode, Json<ImportResponse>)> {
+    let import = state
+        .import_service
+        .create_import(&auth_user, request)
+        .await?;
+    Ok((StatusCode::ACCEPTED, Json(import.into())))
+}
+
+/// List imports, newest first (admin only)
+pub async fn list_imports(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<Json<ImportListResponse>> {
+    let imports = state.import_service.list_imports(&auth_user).await?;
+    Ok(Json(ImportListResponse {
+        imports: imports.into_iter().map(ImportResponse::from).collect(),
+    }))
+}
+
+/// Progress and results of an import (admin only)
+pub async fn get_import(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<ImportResponse>> {
+    let import = state.import_service.get_import(&auth_user, &id).await?;
+    Ok(Json(import.into()))
+}
@@ -1,10 +1,16 @@
 use crate::{
-    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ApiError},
-    domain::entities::{CreateInboxEmailConfigRequest, InboxEmailConfig, UpdateInboxEmailConfigRequest},
+    infrastructure::http::middleware::{
+        ApiResult, AppState, AuthenticatedUser, ApiError, ValidatedJson,
+    },
+    domain::entities::{
+        CreateInboxEmailConfigRequest, EmailAuthFailurePolicy, EmailAuthMethod, EmailOAuthAuthorization,
+        EmailOAuthCallbackParams, EmailOAuthProvider, EmailOAuthStatus, InboxEmailBacklog,
+        InboxEmailConfig, UpdateInboxEmailConfigRequest,
+    },
 };
 /// API handlers for inbox email configurations (Feature 021)
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     Json,
 };
@@ -26,6 +32,9 @@ pub struct InboxEmailConfigResponse {
     pub smtp_username: String,
     pub smtp_password: String, // Will be masked
     pub smtp_use_tls: bool,
+    pub auth_method: EmailAuthMethod,
+    pub oauth_provider: Option<EmailOAuthProvider>,
+    pub auth_failure_policy: EmailAuthFailurePolicy,
     pub email_address: String,
     pub display_name: String,
     pub poll_interval_seconds: i32,
@@ -51,6 +60,9 @@ impl From<InboxEmailConfig> for InboxEmailConfigResponse {
             smtp_username: config.smtp_username,
             smtp_password: "********".to_string(), // Mask password
             smtp_use_tls: config.smtp_use_tls,
+            auth_method: config.auth_method,
+            oauth_provider: config.oauth_provider,
+            auth_failure_policy: config.auth_failure_policy,
             email_address: config.email_address,
             display_name: config.display_name,
             poll_interval_seconds: config.poll_interval_seconds,
@@ -62,12 +74,22 @@ impl From<InboxEmailConfig> for InboxEmailConfigResponse {
     }
 }
 
+/// Email ingestion status of an inbox
+#[derive(Debug, Clone, Serialize)]
+pub struct InboxStatusResponse {
+    pub inbox_id: String,
+    pub email_enabled: bool,
+    pub last_poll_at: Option<String>,
+    /// Progress of the IMAP backlog, once the inbox has been polled
+    pub backlog: Option<InboxEmailBacklog>,
+}
+
 /// Create inbox email configuration
 pub async fn create_inbox_email_config(
     State(state): State<AppState>,
     axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
     Path(inbox_id): Path<String>,
-    Json(request): Json<CreateInboxEmailConfigRequest>,
+    ValidatedJson(request): ValidatedJson<CreateInboxEmailConfigRequest>,
 ) -> ApiResult<(StatusCode, Json<InboxEmailConfigResponse>)> {
     // Check if config already exists
     if let Some(_) = state
@@ -81,7 +103,7 @@ pub async fn create_inbox_email_config(
     }
 
     // Create configuration
-    let config = InboxEmailConfig::new(
+    let mut config = InboxEmailConfig::new(
         inbox_id.clone(),
         request.imap_host,
         request.imap_port,
@@ -95,6 +117,9 @@ pub async fn create_inbox_email_config(
         request.display_name,
         request.poll_interval_seconds,
     );
+    config.auth_method = request.auth_method;
+    config.oauth_provider = request.oauth_provider;
+    config.auth_failure_policy = request.auth_failure_policy;
 
     let created = state
         .email_service
@@ -132,7 +157,7 @@ pub async fn update_inbox_email_config(
     State(state): State<AppState>,
     axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
     Path(inbox_id): Path<String>,
-    Json(request): Json<UpdateInboxEmailConfigRequest>,
+    ValidatedJson(request): ValidatedJson<UpdateInboxEmailConfigRequest>,
 ) -> ApiResult<Json<InboxEmailConfigResponse>> {
     // Get existing config
     let existing = state
@@ -173,11 +198,12 @@ pub async fn delete_inbox_email_config(
             ))
         })?;
 
-    // Delete configuration
+    // Delete configuration, and any OAuth2 tokens of the mailbox
     state
         .email_service
         .delete_inbox_email_config(&existing.id)
         .await?;
+    state.email_oauth_service.disconnect(&inbox_id).await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -220,3 +246,96 @@ pub async fn test_inbox_email_config(
         smtp_error: None,
     }))
 }
+
+/// Get inbox email ingestion status, including backlog progress
+pub async fn get_inbox_status(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<Json<InboxStatusResponse>> {
+    let config = state
+        .email_service
+        .get_inbox_email_config(&inbox_id)
+        .await?
+        .ok_or_else(|| {
+            ApiError::NotFound(format!(
+                "Email configuration not found for inbox {}",
+                inbox_id
+            ))
+        })?;
+
+    let backlog = state.email_service.get_email_backlog(&inbox_id).await?;
+
+    Ok(Json(InboxStatusResponse {
+        inbox_id,
+        email_enabled: config.enabled,
+        last_poll_at: config.last_poll_at,
+        backlog,
+    }))
+}
+
+/// Start OAuth2 authorization of an inbox's mailbox
+///
+/// Returns the provider consent page to open; the provider redirects back to
+/// `/api/email-oauth/callback`.
+pub async fn start_email_oauth(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<Json<EmailOAuthAuthorization>> {
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let authorization = state
+        .email_oauth_service
+        .start_authorization(&inbox_id, &auth_user.user.id)
+        .await?;
+
+    Ok(Json(authorization))
+}
+
+/// Get OAuth2 authorization status of an inbox's mailbox
+pub async fn get_email_oauth_status(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<Json<EmailOAuthStatus>> {
+    let status = state.email_oauth_service.get_status(&inbox_id).await?;
+
+    Ok(Json(status))
+}
+
+/// Forget an inbox mailbox's OAuth2 tokens
+pub async fn disconnect_email_oauth(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    state.email_oauth_service.disconnect(&inbox_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Handle the provider's redirect after the mailbox owner consented
+///
+/// Public: the one-time state issued by `start_email_oauth` authenticates it.
+pub async fn email_oauth_callback(
+    State(state): State<AppState>,
+    Query(params): Query<EmailOAuthCallbackParams>,
+) -> ApiResult<Json<EmailOAuthStatus>> {
+    let status = state
+        .email_oauth_service
+        .complete_authorization(params)
+        .await?;
+
+    Ok(Json(status))
+}
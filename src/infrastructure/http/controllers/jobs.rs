@@ -0,0 +1,58 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+
+use crate::{
+    domain::entities::{DeadJobsQuery, Job},
+    infrastructure::http::middleware::{ApiError, ApiResult, AppState, AuthenticatedUser},
+};
+
+fn ensure_admin(auth_user: &AuthenticatedUser) -> ApiResult<()> {
+    if auth_user.is_admin() {
+        Ok(())
+    } else {
+        Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ))
+    }
+}
+
+/// List dead jobs with their recorded failures (admin only)
+pub async fn list_dead_jobs(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Query(query): Query<DeadJobsQuery>,
+) -> ApiResult<Json<Vec<Job>>> {
+    ensure_admin(&auth_user)?;
+
+    let jobs = state.job_service.list_dead_jobs(query).await?;
+    Ok(Json(jobs))
+}
+
+/// Get a background job with its attempts and failures (admin only)
+pub async fn get_job(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Job>> {
+    ensure_admin(&auth_user)?;
+
+    let job = state.job_service.get_job(&id).await?;
+    Ok(Json(job))
+}
+
+/// Requeue a dead job with a fresh set of attempts (admin only)
+pub async fn requeue_job(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Job>> {
+    ensure_admin(&auth_user)?;
+
+    let job = state
+        .job_service
+        .requeue_job(&id, &auth_user.user.id)
+        .await?;
+    Ok(Json(job))
+}
@@ -0,0 +1,61 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+use crate::{
+    domain::entities::{BlockedSender, JunkResponse, MarkJunkRequest},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser},
+};
+
+/// Mark a conversation as junk; `confirm` also blocks the sender on the inbox
+pub async fn mark_junk(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    request: Option<Json<MarkJunkRequest>>,
+) -> ApiResult<Json<JunkResponse>> {
+    let request = request.map(|Json(r)| r).unwrap_or_default();
+    let response = state
+        .junk_service
+        .mark_junk(&auth_user, &id, request)
+        .await?;
+    Ok(Json(response))
+}
+
+/// Move a junk conversation back to open
+pub async fn unmark_junk(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<JunkResponse>> {
+    let response = state.junk_service.unmark_junk(&auth_user, &id).await?;
+    Ok(Json(response))
+}
+
+/// Senders blocked on an inbox (admin only)
+pub async fn list_blocked_senders(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<Json<Vec<BlockedSender>>> {
+    let senders = state
+        .junk_service
+        .list_blocked_senders(&auth_user, &inbox_id)
+        .await?;
+    Ok(Json(senders))
+}
+
+/// Remove a sender from an inbox blocklist (admin only)
+pub async fn unblock_sender(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((inbox_id, id)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    state
+        .junk_service
+        .unblock_sender(&auth_user, &inbox_id, &id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,28 @@
+use axum::{extract::State, Json};
+
+use crate::{
+    domain::entities::{LocaleSettings, UpdateLocaleRequest},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+/// The caller's locale and the locales they can choose from
+pub async fn get_locale(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<Json<LocaleSettings>> {
+    let settings = state.locale_service.get_settings(&auth_user.user.id).await?;
+    Ok(Json(settings))
+}
+
+/// Choose the caller's locale; null goes back to following Accept-Language
+pub async fn update_locale(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(request): ValidatedJson<UpdateLocaleRequest>,
+) -> ApiResult<Json<LocaleSettings>> {
+    let settings = state
+        .locale_service
+        .update_settings(&auth_user.user.id, request)
+        .await?;
+    Ok(Json(settings))
+}
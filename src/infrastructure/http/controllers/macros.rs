@@ -7,8 +7,11 @@ use axum::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    infrastructure::http::middleware::{error::ApiError, ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{
+        error::ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
     domain::entities::*,
+    shared::validation::{Validate, ValidationErrors},
 };
 
 // ===== Request DTOs =====
@@ -18,6 +21,12 @@ pub struct ApplyMacroRequest {
     pub conversation_id: String,
 }
 
+impl Validate for ApplyMacroRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("conversation_id", &self.conversation_id, 1, 255);
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateMacroRequest {
     pub name: String,
@@ -47,12 +56,62 @@ pub struct UpdateMacroRequest {
     pub access_control: Option<String>,
 }
 
+const MACRO_ACCESS_CONTROLS: &[&str] = &["all", "restricted"];
+
+impl Validate for CreateMacroRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 255);
+        errors.length("message_content", &self.message_content, 1, 10000);
+        validate_macro_actions(errors, &self.actions);
+        errors.one_of(
+            "access_control",
+            &self.access_control,
+            MACRO_ACCESS_CONTROLS,
+        );
+    }
+}
+
+impl Validate for UpdateMacroRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("message_content", self.message_content.as_deref(), 1, 10000);
+        if let Some(actions) = &self.actions {
+            validate_macro_actions(errors, actions);
+        }
+        if let Some(access_control) = &self.access_control {
+            errors.one_of("access_control", access_control, MACRO_ACCESS_CONTROLS);
+        }
+    }
+}
+
+impl Validate for MacroActionRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.one_of("action_type", &self.action_type, MACRO_ACTION_TYPES);
+        errors.length("action_value", &self.action_value, 1, 255);
+        if self.action_order < 0 {
+            errors.add("action_order", "must not be negative");
+        }
+    }
+}
+
+fn validate_macro_actions(errors: &mut ValidationErrors, actions: &[MacroActionRequest]) {
+    for (index, action) in actions.iter().enumerate() {
+        errors.nested(&format!("actions[{}]", index), action);
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct GrantAccessRequest {
     pub entity_type: String,
     pub entity_id: String,
 }
 
+impl Validate for GrantAccessRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.one_of("entity_type", &self.entity_type, &["user", "team"]);
+        errors.length("entity_id", &self.entity_id, 1, 255);
+    }
+}
+
 // ===== Response DTOs =====
 
 #[derive(Debug, Serialize)]
@@ -117,7 +176,7 @@ pub async fn apply_macro(
     State(state): State<AppState>,
     Extension(user): Extension<AuthenticatedUser>,
     Path(macro_id): Path<String>,
-    Json(req): Json<ApplyMacroRequest>,
+    ValidatedJson(req): ValidatedJson<ApplyMacroRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Apply macro
     let result = state
@@ -148,7 +207,7 @@ pub async fn apply_macro(
 pub async fn create_macro(
     State(state): State<AppState>,
     Extension(user): Extension<AuthenticatedUser>,
-    Json(req): Json<CreateMacroRequest>,
+    ValidatedJson(req): ValidatedJson<CreateMacroRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Check admin permission
     if !user.has_permission("automation:manage").await {
@@ -240,7 +299,7 @@ pub async fn update_macro(
     State(state): State<AppState>,
     Extension(user): Extension<AuthenticatedUser>,
     Path(macro_id): Path<String>,
-    Json(req): Json<UpdateMacroRequest>,
+    ValidatedJson(req): ValidatedJson<UpdateMacroRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Check admin permission
     if !user.has_permission("automation:manage").await {
@@ -295,7 +354,7 @@ pub async fn grant_macro_access(
     State(state): State<AppState>,
     Extension(user): Extension<AuthenticatedUser>,
     Path(macro_id): Path<String>,
-    Json(req): Json<GrantAccessRequest>,
+    ValidatedJson(req): ValidatedJson<GrantAccessRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Check admin permission
     if !user.has_permission("automation:manage").await {
@@ -0,0 +1,59 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use crate::{
+    domain::entities::UpdateMaintenanceModeRequest,
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
+};
+
+/// Get the maintenance switch and background worker state (admin only)
+pub async fn get_maintenance_status(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let status = state.maintenance_service.status().await?;
+
+    Ok(Json(status))
+}
+
+/// Turn maintenance mode on or off (admin only)
+pub async fn update_maintenance_mode(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(request): ValidatedJson<UpdateMaintenanceModeRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let status = state
+        .maintenance_service
+        .set_mode(request, &auth_user.user.id)
+        .await?;
+
+    Ok(Json(status))
+}
+
+/// Readiness probe: 200 when serving traffic, 503 during maintenance or when
+/// the database is unreachable
+pub async fn readiness(State(state): State<AppState>) -> impl IntoResponse {
+    let readiness = state.maintenance_service.readiness().await;
+    let status = if readiness.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(readiness))
+}
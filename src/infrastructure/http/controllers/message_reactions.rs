@@ -0,0 +1,65 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    domain::entities::{AddReactionRequest, ReactionListResponse},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+/// List the reactions on a message
+pub async fn list_message_reactions(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(message_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let reactions = state
+        .message_reaction_service
+        .list_reactions(&message_id)
+        .await?;
+
+    Ok(Json(ReactionListResponse {
+        message_id,
+        reactions,
+    }))
+}
+
+/// React to a message as the current agent
+pub async fn add_message_reaction(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(message_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<AddReactionRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let reactions = state
+        .message_reaction_service
+        .add_reaction(&message_id, &auth_user.user.id, &request.emoji)
+        .await?;
+
+    Ok((
+        axum::http::StatusCode::CREATED,
+        Json(ReactionListResponse {
+            message_id,
+            reactions,
+        }),
+    ))
+}
+
+/// Remove the current agent's reaction (emoji or name, e.g. "thumbs_up")
+pub async fn remove_message_reaction(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((message_id, emoji)): Path<(String, String)>,
+) -> ApiResult<impl IntoResponse> {
+    let reactions = state
+        .message_reaction_service
+        .remove_reaction(&message_id, &auth_user.user.id, &emoji)
+        .await?;
+
+    Ok(Json(ReactionListResponse {
+        message_id,
+        reactions,
+    }))
+}
@@ -8,8 +8,11 @@ use axum::{
 use serde::Deserialize;
 
 use crate::{
-    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser},
-    domain::entities::{IncomingMessageRequest, MessageListResponse, PaginationMetadata, SendMessageRequest},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+    domain::entities::{
+        IncomingMessageRequest, MessageListResponse, PaginationMetadata, SendMessageRequest,
+        SystemNoteListResponse,
+    },
 };
 
 /// Webhook endpoint for receiving incoming messages from external sources
@@ -18,7 +21,7 @@ use crate::{
 /// Either contact_id or from_header must be provided.
 pub async fn receive_incoming_message(
     State(state): State<AppState>,
-    Json(mut request): Json<IncomingMessageRequest>,
+    ValidatedJson(mut request): ValidatedJson<IncomingMessageRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Feature 016: Automatic contact creation from from_header
     if request.contact_id.is_none() {
@@ -62,7 +65,7 @@ pub async fn send_message(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
     Path(conversation_id): Path<String>,
-    Json(request): Json<SendMessageRequest>,
+    ValidatedJson(request): ValidatedJson<SendMessageRequest>,
 ) -> ApiResult<impl IntoResponse> {
     let message = state
         .message_service
@@ -78,7 +81,11 @@ pub async fn get_message(
     axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
     Path(message_id): Path<String>,
 ) -> ApiResult<impl IntoResponse> {
-    let message = state.message_service.get_message(&message_id).await?;
+    let mut message = state.message_service.get_message(&message_id).await?;
+    state
+        .message_reaction_service
+        .attach_reactions(std::slice::from_mut(&mut message))
+        .await?;
 
     Ok(Json(message))
 }
@@ -106,10 +113,14 @@ pub async fn list_messages(
     Path(conversation_id): Path<String>,
     Query(query): Query<MessageListQuery>,
 ) -> ApiResult<impl IntoResponse> {
-    let (messages, total) = state
+    let (mut messages, total) = state
         .message_service
         .list_messages(&conversation_id, query.page, query.per_page)
         .await?;
+    state
+        .message_reaction_service
+        .attach_reactions(&mut messages)
+        .await?;
 
     let response = MessageListResponse {
         messages,
@@ -124,6 +135,22 @@ pub async fn list_messages(
     Ok(Json(response))
 }
 
+/// List out-of-office replies and bounces received for a conversation
+pub async fn list_system_notes(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(conversation_id): Path<String>,
+) -> ApiResult<Json<SystemNoteListResponse>> {
+    let notes = state
+        .message_service
+        .list_system_notes(&conversation_id)
+        .await?;
+    Ok(Json(SystemNoteListResponse {
+        conversation_id,
+        notes,
+    }))
+}
+
 pub fn routes() -> Router<AppState> {
     Router::new()
         // Webhook endpoint (no auth required - external systems)
@@ -141,4 +168,8 @@ pub fn routes() -> Router<AppState> {
             "/api/conversations/:conversation_id/messages",
             post(send_message),
         )
+        .route(
+            "/api/conversations/:conversation_id/system-notes",
+            get(list_system_notes),
+        )
 }
@@ -1,21 +1,53 @@
+pub mod activity_feed;
 pub mod agents;
 pub mod api_keys;
 pub mod assignments;
+pub mod attachment_rules;
+pub mod attachments;
 pub mod auth;
+pub mod auto_tag_rules;
 pub mod automation;
 pub mod availability;
+pub mod branding;
+pub mod channels;
+pub mod chat_widget;
+pub mod config_bundles;
+pub mod contact_notes;
 pub mod contacts;
+pub mod conversation_contacts;
 pub mod conversation_tags;
+pub mod conversation_transfers;
 pub mod conversations;
+pub mod csat;
+pub mod diagnostics;
+pub mod email_participants;
+pub mod email_templates;
+pub mod gdpr;
+pub mod holiday_calendars;
+pub mod imports;
 pub mod inbox_email_configs;
+pub mod jobs;
+pub mod junk;
+pub mod locales;
 pub mod macros;
+pub mod maintenance;
+pub mod message_reactions;
 pub mod messages;
 pub mod notifications;
 pub mod oidc_providers;
 pub mod password_reset;
+pub mod priority_matrix;
+pub mod reporting;
+pub mod response_reminders;
 pub mod roles;
+pub mod saved_views;
+pub mod sentiment;
+pub mod shifts;
 pub mod sla;
+pub mod sms;
+pub mod system_config;
 pub mod tags;
 pub mod teams;
+pub mod telegram;
 pub mod users;
 pub mod webhooks;
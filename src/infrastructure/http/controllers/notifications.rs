@@ -1,5 +1,6 @@
 use axum::{
     extract::{Path, Query, State},
+    http::HeaderMap,
     response::{
         sse::{Event, KeepAlive, Sse},
         IntoResponse,
@@ -9,13 +10,16 @@ use axum::{
 use futures::stream::Stream;
 use serde::{Deserialize, Serialize};
 use std::convert::Infallible;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tokio_stream::StreamExt as _;
 
 use crate::{
     infrastructure::http::middleware::{ApiError, ApiResult, AppState, AuthenticatedUser},
-    domain::entities::UserNotification,
+    domain::entities::{NotificationStreamEvent, UserNotification},
     infrastructure::providers::connection_manager::NotificationEvent,
 };
 
@@ -32,6 +36,12 @@ fn default_limit() -> i32 {
     50
 }
 
+#[derive(Debug, Deserialize)]
+pub struct NotificationStreamQuery {
+    /// Fallback for clients that cannot set the Last-Event-ID header
+    pub last_event_id: Option<i64>,
+}
+
 // Response DTOs
 #[derive(Debug, Serialize)]
 pub struct NotificationResponse {
@@ -137,34 +147,83 @@ pub async fn get_unread_count(
 }
 
 /// SSE endpoint for real-time notification streaming
+///
+/// Clients reconnecting with `Last-Event-ID` (or `?last_event_id=`) are first
+/// replayed every missed event still retained, a page at a time, then switched
+/// to the live stream. Events older than the retention window are gone; a
+/// client away longer than that should reload its notifications list.
 pub async fn notification_stream(
     State(state): State<AppState>,
     Extension(user): Extension<AuthenticatedUser>,
+    Query(query): Query<NotificationStreamQuery>,
+    headers: HeaderMap,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
     // Create a channel for this connection
     let (tx, rx) = mpsc::channel::<NotificationEvent>(100);
 
     // Register the connection with the connection manager
+    // (before loading the backlog so no event falls between the two)
     let user_id = user.user.id.clone();
     state.connection_manager.add_connection(&user_id, tx).await;
 
+    let last_event_id = headers
+        .get("last-event-id")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<i64>().ok())
+        .or(query.last_event_id);
+
+    // Replay the events missed since the client's last event, remembering the
+    // newest one so the live stream doesn't deliver it a second time
+    let replayed_through = Arc::new(AtomicI64::new(last_event_id.unwrap_or(0)));
+    let replay_cursor = replayed_through.clone();
+    let backlog: Pin<Box<dyn Stream<Item = NotificationStreamEvent> + Send>> = match last_event_id {
+        Some(last_event_id) => Box::pin(
+            state
+                .notification_service
+                .stream_events_after(&user_id, last_event_id),
+        ),
+        None => Box::pin(futures::stream::empty()),
+    };
+
     // Log the new SSE connection
-    tracing::info!("SSE connection established for user {}", user_id);
-
-    // Create a stream from the receiver
-    let stream = ReceiverStream::new(rx).map(|event| {
-        // Serialize the notification event to JSON
-        let json_data = serde_json::to_string(&event).unwrap_or_else(|e| {
-            tracing::error!("Failed to serialize notification event: {}", e);
-            "{}".to_string()
-        });
+    tracing::info!(
+        "SSE connection established for user {} (resuming after {:?})",
+        user_id,
+        last_event_id
+    );
 
-        // Create an SSE event with the JSON data
-        Ok(Event::default().event("notification").data(json_data))
+    let replay = backlog.map(move |event| {
+        replay_cursor.store(event.sequence, Ordering::SeqCst);
+        Ok(Event::default()
+            .event("notification")
+            .id(event.sequence.to_string())
+            .data(event.payload))
     });
 
+    // Create a stream from the receiver, skipping events already replayed
+    let live = ReceiverStream::new(rx)
+        .filter(move |event| {
+            event
+                .sequence
+                .is_none_or(|seq| seq > replayed_through.load(Ordering::SeqCst))
+        })
+        .map(|event| {
+            // Serialize the notification event to JSON
+            let json_data = serde_json::to_string(&event).unwrap_or_else(|e| {
+                tracing::error!("Failed to serialize notification event: {}", e);
+                "{}".to_string()
+            });
+
+            // Create an SSE event with the JSON data
+            let sse_event = Event::default().event("notification").data(json_data);
+            match event.sequence {
+                Some(sequence) => Ok(sse_event.id(sequence.to_string())),
+                None => Ok(sse_event),
+            }
+        });
+
     // Create the SSE response with keep-alive
-    Sse::new(stream).keep_alive(KeepAlive::default())
+    Sse::new(replay.chain(live)).keep_alive(KeepAlive::default())
 }
 
 /// Mark a notification as read
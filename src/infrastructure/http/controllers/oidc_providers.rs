@@ -1,5 +1,7 @@
 use crate::{
-    infrastructure::http::middleware::{ApiError, ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
     domain::entities::*,
 };
 use axum::{
@@ -52,16 +54,13 @@ pub async fn get_oidc_provider(
 pub async fn create_oidc_provider(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
-    Json(request): Json<CreateOidcProviderRequest>,
+    ValidatedJson(request): ValidatedJson<CreateOidcProviderRequest>,
 ) -> ApiResult<Json<OidcProviderResponse>> {
     // Only admins can create OIDC providers
     if !auth_user.is_admin() {
         return Err(ApiError::Forbidden("Admin permission required".to_string()));
     }
 
-    // Validate the request
-    request.validate()?;
-
     // Check if provider with this name already exists
     if state
         .oidc_service
@@ -86,7 +85,7 @@ pub async fn update_oidc_provider(
     State(state): State<AppState>,
     Path(id): Path<String>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
-    Json(request): Json<UpdateOidcProviderRequest>,
+    ValidatedJson(request): ValidatedJson<UpdateOidcProviderRequest>,
 ) -> ApiResult<Json<OidcProviderResponse>> {
     // Only admins can update OIDC providers
     if !auth_user.is_admin() {
@@ -1,5 +1,5 @@
 use crate::{
-    infrastructure::http::middleware::{ApiResult, AppState},
+    infrastructure::http::middleware::{ApiResult, AppState, ValidatedJson},
     domain::entities::*,
 };
 /// Password Reset API Handlers
@@ -41,3 +41,20 @@ pub async fn reset_password(
 
     Ok(Json(response))
 }
+
+/// POST /api/invitations/accept
+///
+/// Accept an agent invitation by choosing a password
+///
+/// Uses the same single-use token checks as a password reset.
+pub async fn accept_invitation(
+    State(state): State<AppState>,
+    ValidatedJson(request): ValidatedJson<AcceptInvitationRequest>,
+) -> ApiResult<Json<AcceptInvitationResponse>> {
+    let response = state
+        .password_reset_service
+        .accept_invitation(&request.token, &request.password)
+        .await?;
+
+    Ok(Json(response))
+}
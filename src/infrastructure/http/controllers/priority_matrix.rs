@@ -0,0 +1,65 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+use crate::{
+    domain::entities::{
+        ContactTierResponse, CreatePriorityMatrixEntryRequest, PriorityMatrixEntry,
+        SetContactTierRequest,
+    },
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+/// List priority matrix entries (admin only)
+pub async fn list_priority_matrix(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<Json<Vec<PriorityMatrixEntry>>> {
+    let entries = state
+        .priority_matrix_service
+        .list_entries(&auth_user)
+        .await?;
+    Ok(Json(entries))
+}
+
+/// Add a tier × tag → priority entry (admin only)
+pub async fn create_priority_matrix_entry(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(request): ValidatedJson<CreatePriorityMatrixEntryRequest>,
+) -> ApiResult<(StatusCode, Json<PriorityMatrixEntry>)> {
+    let entry = state
+        .priority_matrix_service
+        .create_entry(&auth_user, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(entry)))
+}
+
+/// Remove a priority matrix entry (admin only)
+pub async fn delete_priority_matrix_entry(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .priority_matrix_service
+        .delete_entry(&auth_user, &id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Set or clear a contact's tier (admin only)
+pub async fn set_contact_tier(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<SetContactTierRequest>,
+) -> ApiResult<Json<ContactTierResponse>> {
+    let response = state
+        .priority_matrix_service
+        .set_contact_tier(&auth_user, &id, request)
+        .await?;
+    Ok(Json(response))
+}
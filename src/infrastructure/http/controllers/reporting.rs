@@ -0,0 +1,93 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::entities::{
+        CreateReportingTokenRequest, CreateReportingTokenResponse,
+        ReportingConversationListResponse, ReportingSummary, ReportingTokenListResponse,
+    },
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ReportingSummaryParams {
+    /// Only count records created at or after this RFC 3339 timestamp or date
+    pub since: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReportingConversationsParams {
+    /// Only return conversations updated at or after this timestamp
+    pub updated_since: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    #[serde(default)]
+    pub offset: i64,
+}
+
+fn default_limit() -> i64 {
+    100
+}
+
+/// Issue a reporting token (admin only); the token is only shown in this response
+pub async fn create_reporting_token(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(request): ValidatedJson<CreateReportingTokenRequest>,
+) -> ApiResult<(StatusCode, Json<CreateReportingTokenResponse>)> {
+    let created = state
+        .reporting_service
+        .create_token(&auth_user, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// List reporting tokens, revoked ones included (admin only)
+pub async fn list_reporting_tokens(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<Json<ReportingTokenListResponse>> {
+    let tokens = state.reporting_service.list_tokens(&auth_user).await?;
+    Ok(Json(ReportingTokenListResponse { tokens }))
+}
+
+/// Revoke a reporting token (admin only)
+pub async fn revoke_reporting_token(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .reporting_service
+        .revoke_token(&auth_user, &id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Workspace KPIs (reporting token)
+pub async fn get_reporting_summary(
+    State(state): State<AppState>,
+    Query(params): Query<ReportingSummaryParams>,
+) -> ApiResult<Json<ReportingSummary>> {
+    let summary = state
+        .reporting_service
+        .summary(params.since.as_deref())
+        .await?;
+    Ok(Json(summary))
+}
+
+/// Conversation metadata without message content (reporting token)
+pub async fn list_reporting_conversations(
+    State(state): State<AppState>,
+    Query(params): Query<ReportingConversationsParams>,
+) -> ApiResult<Json<ReportingConversationListResponse>> {
+    let response = state
+        .reporting_service
+        .list_conversations(params.updated_since.as_deref(), params.limit, params.offset)
+        .await?;
+    Ok(Json(response))
+}
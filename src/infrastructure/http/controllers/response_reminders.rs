@@ -0,0 +1,50 @@
+use crate::{
+    domain::entities::{ResponseReminderSettings, UpdateResponseReminderSettingsRequest},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
+};
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+
+/// GET /api/agents/:id/response-reminders - Get an agent's reminder settings
+pub async fn get_response_reminder_settings(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(agent_id): Path<String>,
+) -> ApiResult<Json<ResponseReminderSettings>> {
+    // Agents can view their own settings, admins can view any
+    if auth_user.agent.id != agent_id && !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "You can only view your own reminder settings".to_string(),
+        ));
+    }
+
+    let settings = state
+        .response_reminder_service
+        .get_settings(&agent_id)
+        .await?;
+    Ok(Json(settings))
+}
+
+/// PUT /api/agents/:id/response-reminders - Replace an agent's reminder settings
+pub async fn update_response_reminder_settings(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(agent_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateResponseReminderSettingsRequest>,
+) -> ApiResult<Json<ResponseReminderSettings>> {
+    if auth_user.agent.id != agent_id && !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "You can only change your own reminder settings".to_string(),
+        ));
+    }
+
+    let settings = state
+        .response_reminder_service
+        .update_settings(&agent_id, request)
+        .await?;
+    Ok(Json(settings))
+}
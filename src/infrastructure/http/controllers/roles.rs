@@ -1,5 +1,5 @@
 use crate::{
-    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
     domain::entities::*,
 };
 use axum::{
@@ -29,7 +29,7 @@ pub async fn get_role(
 pub async fn create_role(
     State(state): State<AppState>,
     axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
-    Json(request): Json<CreateRoleRequest>,
+    ValidatedJson(request): ValidatedJson<CreateRoleRequest>,
 ) -> ApiResult<(StatusCode, Json<RoleResponse>)> {
     let role = state.role_service.create_role(
         request.name,
@@ -43,7 +43,7 @@ pub async fn update_role(
     State(state): State<AppState>,
     axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
     Path(id): Path<String>,
-    Json(request): Json<UpdateRoleRequest>,
+    ValidatedJson(request): ValidatedJson<UpdateRoleRequest>,
 ) -> ApiResult<Json<RoleResponse>> {
     let role = state
         .role_service
@@ -0,0 +1,70 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+use crate::{
+    domain::entities::{CreateSavedViewRequest, SavedView, UpdateSavedViewRequest},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
+};
+
+/// List the current agent's saved views
+pub async fn list_saved_views(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<Json<Vec<SavedView>>> {
+    let views = state.saved_view_service.list_views(&auth_user).await?;
+    Ok(Json(views))
+}
+
+/// Save a conversation search query under a name
+pub async fn create_saved_view(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(request): ValidatedJson<CreateSavedViewRequest>,
+) -> ApiResult<(StatusCode, Json<SavedView>)> {
+    let view = state
+        .saved_view_service
+        .create_view(&auth_user, request)
+        .await?;
+    Ok((StatusCode::CREATED, Json(view)))
+}
+
+pub async fn get_saved_view(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(view_id): Path<String>,
+) -> ApiResult<Json<SavedView>> {
+    let view = state
+        .saved_view_service
+        .get_view(&auth_user, &view_id)
+        .await?;
+    Ok(Json(view))
+}
+
+/// Rename a view or change its query
+pub async fn update_saved_view(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(view_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateSavedViewRequest>,
+) -> ApiResult<Json<SavedView>> {
+    let view = state
+        .saved_view_service
+        .update_view(&auth_user, &view_id, request)
+        .await?;
+    Ok(Json(view))
+}
+
+pub async fn delete_saved_view(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(view_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    state
+        .saved_view_service
+        .delete_view(&auth_user, &view_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,21 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser};
+
+/// Get a conversation's rolling sentiment and per-message scores
+pub async fn get_conversation_sentiment(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(conversation_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let sentiment = state
+        .sentiment_service
+        .get_conversation_sentiment(&conversation_id)
+        .await?;
+
+    Ok(Json(sentiment))
+}
@@ -0,0 +1,97 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    domain::entities::{CreateShiftRequest, ShiftListResponse, UpdateShiftRequest},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
+};
+
+/// Add a shift to a team's schedule (admin only)
+pub async fn create_shift(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(team_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<CreateShiftRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let shift = state.shift_service.create_shift(&team_id, request).await?;
+
+    Ok((axum::http::StatusCode::CREATED, Json(shift)))
+}
+
+/// List a team's shifts
+pub async fn list_shifts(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(team_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let shifts = state.shift_service.list_shifts(&team_id).await?;
+    let total = shifts.len() as i64;
+
+    Ok(Json(ShiftListResponse { shifts, total }))
+}
+
+/// Update a shift (admin only)
+pub async fn update_shift(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((team_id, shift_id)): Path<(String, String)>,
+    ValidatedJson(request): ValidatedJson<UpdateShiftRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let shift = state
+        .shift_service
+        .update_shift(&team_id, &shift_id, request)
+        .await?;
+
+    Ok(Json(shift))
+}
+
+/// Remove a shift (admin only)
+pub async fn delete_shift(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((team_id, shift_id)): Path<(String, String)>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    state
+        .shift_service
+        .delete_shift(&team_id, &shift_id)
+        .await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT)
+}
+
+/// Report the hours of each weekday with no agent scheduled for a team
+pub async fn get_shift_coverage(
+    State(state): State<AppState>,
+    axum::Extension(_auth_user): axum::Extension<AuthenticatedUser>,
+    Path(team_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let report = state.shift_service.coverage_report(&team_id).await?;
+
+    Ok(Json(report))
+}
@@ -6,8 +6,11 @@ use axum::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    infrastructure::http::middleware::{ApiError, ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
     domain::entities::*,
+    shared::validation::{Validate, ValidationErrors},
 };
 
 // ========================================
@@ -32,6 +35,106 @@ pub struct UpdateSlaPolicyRequest {
     pub next_response_time: Option<String>,
 }
 
+impl Validate for CreateSlaPolicyRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 255);
+        errors.max_length("description", self.description.as_deref(), 1000);
+        validate_sla_duration(errors, "first_response_time", &self.first_response_time);
+        validate_sla_duration(errors, "resolution_time", &self.resolution_time);
+        validate_sla_duration(errors, "next_response_time", &self.next_response_time);
+    }
+}
+
+impl Validate for UpdateSlaPolicyRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("name", self.name.as_deref(), 1, 255);
+        errors.max_length(
+            "description",
+            self.description.as_ref().and_then(Option::as_deref),
+            1000,
+        );
+        let durations = [
+            ("first_response_time", &self.first_response_time),
+            ("resolution_time", &self.resolution_time),
+            ("next_response_time", &self.next_response_time),
+        ];
+        for (field, value) in durations {
+            if let Some(value) = value {
+                validate_sla_duration(errors, field, value);
+            }
+        }
+    }
+}
+
+/// Draft policy targets to replay past conversations against
+#[derive(Debug, Deserialize)]
+pub struct SimulateSlaPolicyRequest {
+    pub first_response_time: String,
+    pub resolution_time: String,
+    pub next_response_time: String,
+    /// Start of the window (RFC 3339), defaults to 90 days before `until`
+    pub since: Option<String>,
+    /// End of the window (RFC 3339), defaults to now
+    pub until: Option<String>,
+}
+
+/// Longest window a simulation replays
+const SIMULATION_MAX_DAYS: i64 = 366;
+const SIMULATION_DEFAULT_DAYS: i64 = 90;
+
+impl SimulateSlaPolicyRequest {
+    /// The window to replay; `None` when a bound isn't a valid timestamp
+    fn window(&self) -> Option<(chrono::DateTime<chrono::Utc>, chrono::DateTime<chrono::Utc>)> {
+        let parse = |value: &str| {
+            chrono::DateTime::parse_from_rfc3339(value)
+                .ok()
+                .map(|time| time.with_timezone(&chrono::Utc))
+        };
+        let until = match &self.until {
+            Some(until) => parse(until)?,
+            None => chrono::Utc::now(),
+        };
+        let since = match &self.since {
+            Some(since) => parse(since)?,
+            None => until - chrono::Duration::days(SIMULATION_DEFAULT_DAYS),
+        };
+        Some((since, until))
+    }
+}
+
+impl Validate for SimulateSlaPolicyRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        validate_sla_duration(errors, "first_response_time", &self.first_response_time);
+        validate_sla_duration(errors, "resolution_time", &self.resolution_time);
+        validate_sla_duration(errors, "next_response_time", &self.next_response_time);
+
+        for (field, value) in [("since", &self.since), ("until", &self.until)] {
+            if let Some(value) = value {
+                if chrono::DateTime::parse_from_rfc3339(value).is_err() {
+                    errors.add(field, "must be an RFC 3339 timestamp");
+                }
+            }
+        }
+        if let Some((since, until)) = self.window() {
+            if since >= until {
+                errors.add("since", "must be before until");
+            } else if until - since > chrono::Duration::days(SIMULATION_MAX_DAYS) {
+                errors.add(
+                    "since",
+                    format!("window must be at most {} days", SIMULATION_MAX_DAYS),
+                );
+            }
+        }
+    }
+}
+
+/// Durations are written like "30m", "2h" or "1d"
+fn validate_sla_duration(errors: &mut ValidationErrors, field: &str, value: &str) {
+    if let Err(e) = parse_duration(value) {
+        errors.add(field, e);
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct SlaPolicyResponse {
     pub id: String,
@@ -84,6 +187,12 @@ pub struct AssignSlaPolicyToTeamRequest {
     pub sla_policy_id: Option<String>, // None to remove SLA policy
 }
 
+impl Validate for AssignSlaPolicyToTeamRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.optional_length("sla_policy_id", self.sla_policy_id.as_deref(), 1, 255);
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct AppliedSlaResponse {
     pub id: String,
@@ -134,6 +243,13 @@ pub struct ApplySlaRequest {
     pub sla_policy_id: String,
 }
 
+impl Validate for ApplySlaRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("conversation_id", &self.conversation_id, 1, 255);
+        errors.length("sla_policy_id", &self.sla_policy_id, 1, 255);
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct SlaEventResponse {
     pub id: String,
@@ -177,7 +293,7 @@ pub struct SlaEventListResponse {
 pub async fn create_sla_policy(
     State(state): State<AppState>,
     axum::Extension(_user): axum::Extension<AuthenticatedUser>,
-    Json(req): Json<CreateSlaPolicyRequest>,
+    ValidatedJson(req): ValidatedJson<CreateSlaPolicyRequest>,
 ) -> ApiResult<(StatusCode, Json<SlaPolicyResponse>)> {
     let policy = state
         .sla_service
@@ -237,7 +353,7 @@ pub async fn update_sla_policy(
     State(state): State<AppState>,
     axum::Extension(_user): axum::Extension<AuthenticatedUser>,
     Path(id): Path<String>,
-    Json(req): Json<UpdateSlaPolicyRequest>,
+    ValidatedJson(req): ValidatedJson<UpdateSlaPolicyRequest>,
 ) -> ApiResult<StatusCode> {
     state
         .sla_service
@@ -265,6 +381,36 @@ pub async fn delete_sla_policy(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// Estimate how many past conversations a draft policy would have breached
+/// POST /api/sla/policies/simulate
+pub async fn simulate_sla_policy(
+    State(state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(req): ValidatedJson<SimulateSlaPolicyRequest>,
+) -> ApiResult<Json<SlaSimulation>> {
+    if !user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let (since, until) = req
+        .window()
+        .ok_or_else(|| ApiError::BadRequest("Invalid simulation window".to_string()))?;
+    let simulation = state
+        .sla_service
+        .simulate_policy(
+            &req.first_response_time,
+            &req.resolution_time,
+            &req.next_response_time,
+            since,
+            until,
+        )
+        .await?;
+
+    Ok(Json(simulation))
+}
+
 // ========================================
 // Applied SLA Endpoints
 // ========================================
@@ -330,7 +476,7 @@ pub async fn list_applied_slas(
 pub async fn apply_sla(
     State(state): State<AppState>,
     axum::Extension(user): axum::Extension<AuthenticatedUser>,
-    Json(req): Json<ApplySlaRequest>,
+    ValidatedJson(req): ValidatedJson<ApplySlaRequest>,
 ) -> ApiResult<(StatusCode, Json<AppliedSlaResponse>)> {
     // Check for "sla:manage" permission
     if !user.has_permission("sla:manage").await {
@@ -387,7 +533,7 @@ pub async fn assign_sla_policy_to_team(
     State(state): State<AppState>,
     axum::Extension(_user): axum::Extension<AuthenticatedUser>,
     Path(team_id): Path<String>,
-    Json(req): Json<AssignSlaPolicyToTeamRequest>,
+    ValidatedJson(req): ValidatedJson<AssignSlaPolicyToTeamRequest>,
 ) -> ApiResult<StatusCode> {
     // Verify team exists
     state
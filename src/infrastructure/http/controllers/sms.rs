@@ -0,0 +1,142 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::IntoResponse,
+    Form, Json,
+};
+use serde::Serialize;
+
+use crate::domain::entities::{
+    CreateInboxSmsConfigRequest, InboxSmsConfig, UpdateInboxSmsConfigRequest,
+    TWILIO_SIGNATURE_HEADER,
+};
+use crate::infrastructure::http::middleware::{
+    ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+};
+
+/// Empty TwiML reply: replies are sent later through the REST API
+const EMPTY_TWIML: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Response></Response>";
+
+/// Response DTO with the auth token masked
+#[derive(Debug, Clone, Serialize)]
+pub struct InboxSmsConfigResponse {
+    pub id: String,
+    pub inbox_id: String,
+    pub account_sid: String,
+    pub auth_token: String, // Masked
+    pub phone_number: String,
+    pub max_segments: i32,
+    pub enabled: bool,
+    /// URL to set as the number's incoming message webhook in Twilio
+    pub webhook_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl InboxSmsConfigResponse {
+    fn new(config: InboxSmsConfig, webhook_url: String) -> Self {
+        Self {
+            id: config.id,
+            inbox_id: config.inbox_id,
+            account_sid: config.account_sid,
+            auth_token: "********".to_string(),
+            phone_number: config.phone_number,
+            max_segments: config.max_segments,
+            enabled: config.enabled,
+            webhook_url,
+            created_at: config.created_at,
+            updated_at: config.updated_at,
+        }
+    }
+}
+
+fn require_admin(auth_user: &AuthenticatedUser) -> ApiResult<()> {
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Attach a Twilio number to an inbox (admin only)
+pub async fn create_inbox_sms_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<CreateInboxSmsConfigRequest>,
+) -> ApiResult<impl IntoResponse> {
+    require_admin(&auth_user)?;
+
+    let config = state.sms_service.create_config(&inbox_id, request).await?;
+    let webhook_url = state.sms_service.webhook_url(&inbox_id);
+
+    Ok((
+        StatusCode::CREATED,
+        Json(InboxSmsConfigResponse::new(config, webhook_url)),
+    ))
+}
+
+/// Get an inbox's Twilio configuration (admin only)
+pub async fn get_inbox_sms_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    require_admin(&auth_user)?;
+
+    let config = state.sms_service.get_config(&inbox_id).await?;
+    let webhook_url = state.sms_service.webhook_url(&inbox_id);
+
+    Ok(Json(InboxSmsConfigResponse::new(config, webhook_url)))
+}
+
+/// Update an inbox's Twilio configuration (admin only)
+pub async fn update_inbox_sms_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateInboxSmsConfigRequest>,
+) -> ApiResult<impl IntoResponse> {
+    require_admin(&auth_user)?;
+
+    let config = state.sms_service.update_config(&inbox_id, request).await?;
+    let webhook_url = state.sms_service.webhook_url(&inbox_id);
+
+    Ok(Json(InboxSmsConfigResponse::new(config, webhook_url)))
+}
+
+/// Remove an inbox's Twilio configuration (admin only)
+pub async fn delete_inbox_sms_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    require_admin(&auth_user)?;
+
+    state.sms_service.delete_config(&inbox_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Twilio incoming message webhook - Public, authenticated by the request signature
+pub async fn twilio_webhook(
+    State(state): State<AppState>,
+    Path(inbox_id): Path<String>,
+    headers: HeaderMap,
+    Form(params): Form<Vec<(String, String)>>,
+) -> ApiResult<impl IntoResponse> {
+    let signature = headers
+        .get(TWILIO_SIGNATURE_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    state
+        .sms_service
+        .receive_twilio_message(&inbox_id, &params, signature)
+        .await?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/xml; charset=utf-8")],
+        EMPTY_TWIML,
+    ))
+}
@@ -0,0 +1,71 @@
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    domain::entities::{SystemConfigChangesQuery, UpdateSystemConfigRequest},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
+};
+
+/// Get the editable system settings with their current values (admin only)
+pub async fn get_system_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let settings = state.system_config_service.get_settings().await?;
+
+    Ok(Json(settings))
+}
+
+/// Update one or more system settings (admin only)
+pub async fn update_system_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    ValidatedJson(request): ValidatedJson<UpdateSystemConfigRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let settings = state
+        .system_config_service
+        .update_settings(request, &auth_user.user.id)
+        .await?;
+
+    Ok(Json(settings))
+}
+
+/// List recent system setting changes, newest first (admin only)
+pub async fn list_system_config_changes(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Query(query): Query<SystemConfigChangesQuery>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let changes = state
+        .system_config_service
+        .list_changes(query.key.as_deref(), query.limit)
+        .await?;
+
+    Ok(Json(changes))
+}
@@ -6,7 +6,7 @@ use axum::{
 use serde::Deserialize;
 
 use crate::{
-    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser, ValidatedJson},
     domain::entities::*,
 };
 
@@ -30,7 +30,7 @@ fn default_per_page() -> i64 {
 pub async fn create_tag(
     State(state): State<AppState>,
     axum::Extension(user): axum::Extension<AuthenticatedUser>,
-    Json(req): Json<CreateTagRequest>,
+    ValidatedJson(req): ValidatedJson<CreateTagRequest>,
 ) -> ApiResult<Json<TagResponse>> {
     // Get user permissions
     let permissions = state
@@ -101,7 +101,7 @@ pub async fn update_tag(
     State(state): State<AppState>,
     axum::Extension(user): axum::Extension<AuthenticatedUser>,
     Path(tag_id): Path<String>,
-    Json(req): Json<UpdateTagRequest>,
+    ValidatedJson(req): ValidatedJson<UpdateTagRequest>,
 ) -> ApiResult<Json<TagResponse>> {
     // Get user permissions
     let permissions = state
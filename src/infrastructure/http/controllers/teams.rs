@@ -7,7 +7,10 @@ use serde::Deserialize;
 
 use crate::{
     domain::entities::*,
-    infrastructure::http::middleware::{ApiResult, AppState, AuthenticatedUser},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
+    shared::validation::{Validate, ValidationErrors},
 };
 
 #[derive(Debug, Deserialize)]
@@ -16,17 +19,35 @@ pub struct CreateTeamRequest {
     pub description: Option<String>,
 }
 
+impl Validate for CreateTeamRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("name", &self.name, 1, 255);
+        errors.max_length("description", self.description.as_deref(), 1000);
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct AddTeamMemberRequest {
     pub user_id: String,
     pub role: TeamMemberRole,
 }
 
+impl Validate for AddTeamMemberRequest {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        errors.length("user_id", &self.user_id, 1, 255);
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetTakeOnReplyRequest {
+    pub enabled: bool,
+}
+
 // POST /api/teams - Create a new team
 pub async fn create_team(
     State(state): State<AppState>,
     axum::Extension(_user): axum::Extension<AuthenticatedUser>,
-    Json(req): Json<CreateTeamRequest>,
+    ValidatedJson(req): ValidatedJson<CreateTeamRequest>,
 ) -> ApiResult<(StatusCode, Json<Team>)> {
     let team = Team::new(req.name, req.description);
     // Use state.team_service
@@ -65,7 +86,7 @@ pub async fn add_team_member(
     State(state): State<AppState>,
     axum::Extension(_user): axum::Extension<AuthenticatedUser>,
     Path(team_id): Path<String>,
-    Json(req): Json<AddTeamMemberRequest>,
+    ValidatedJson(req): ValidatedJson<AddTeamMemberRequest>,
 ) -> ApiResult<StatusCode> {
     // Use state.team_service
     state
@@ -101,3 +122,25 @@ pub async fn get_team_members(
 
     Ok(Json(members))
 }
+
+// PUT /api/teams/:id/take-on-reply - Toggle assigning conversations to the first replier (admin only)
+pub async fn set_take_on_reply(
+    State(state): State<AppState>,
+    axum::Extension(user): axum::Extension<AuthenticatedUser>,
+    Path(team_id): Path<String>,
+    Json(req): Json<SetTakeOnReplyRequest>,
+) -> ApiResult<Json<Team>> {
+    if !user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let team = state
+        .team_service
+        .set_take_on_reply(&team_id, req.enabled)
+        .await?;
+
+    tracing::info!("Take on reply set to {} for team {}", req.enabled, team_id);
+    Ok(Json(team))
+}
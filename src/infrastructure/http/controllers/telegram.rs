@@ -0,0 +1,138 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::Serialize;
+
+use crate::domain::entities::{
+    CreateInboxTelegramConfigRequest, InboxTelegramConfig, TelegramUpdate,
+    UpdateInboxTelegramConfigRequest, TELEGRAM_SECRET_HEADER,
+};
+use crate::infrastructure::http::middleware::{
+    ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+};
+
+/// Response DTO with the bot token and webhook secret left out
+#[derive(Debug, Clone, Serialize)]
+pub struct InboxTelegramConfigResponse {
+    pub id: String,
+    pub inbox_id: String,
+    pub bot_token: String, // Masked
+    pub bot_username: Option<String>,
+    pub enabled: bool,
+    /// URL the bot's webhook was set to
+    pub webhook_url: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl InboxTelegramConfigResponse {
+    fn new(config: InboxTelegramConfig, webhook_url: String) -> Self {
+        Self {
+            id: config.id,
+            inbox_id: config.inbox_id,
+            bot_token: "********".to_string(),
+            bot_username: config.bot_username,
+            enabled: config.enabled,
+            webhook_url,
+            created_at: config.created_at,
+            updated_at: config.updated_at,
+        }
+    }
+}
+
+fn require_admin(auth_user: &AuthenticatedUser) -> ApiResult<()> {
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Connect a Telegram bot to an inbox (admin only)
+pub async fn create_inbox_telegram_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<CreateInboxTelegramConfigRequest>,
+) -> ApiResult<impl IntoResponse> {
+    require_admin(&auth_user)?;
+
+    let config = state
+        .telegram_service
+        .create_config(&inbox_id, request)
+        .await?;
+    let webhook_url = state.telegram_service.webhook_url(&inbox_id);
+
+    Ok((
+        StatusCode::CREATED,
+        Json(InboxTelegramConfigResponse::new(config, webhook_url)),
+    ))
+}
+
+/// Get an inbox's Telegram configuration (admin only)
+pub async fn get_inbox_telegram_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    require_admin(&auth_user)?;
+
+    let config = state.telegram_service.get_config(&inbox_id).await?;
+    let webhook_url = state.telegram_service.webhook_url(&inbox_id);
+
+    Ok(Json(InboxTelegramConfigResponse::new(config, webhook_url)))
+}
+
+/// Update an inbox's Telegram configuration (admin only)
+pub async fn update_inbox_telegram_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+    ValidatedJson(request): ValidatedJson<UpdateInboxTelegramConfigRequest>,
+) -> ApiResult<impl IntoResponse> {
+    require_admin(&auth_user)?;
+
+    let config = state
+        .telegram_service
+        .update_config(&inbox_id, request)
+        .await?;
+    let webhook_url = state.telegram_service.webhook_url(&inbox_id);
+
+    Ok(Json(InboxTelegramConfigResponse::new(config, webhook_url)))
+}
+
+/// Disconnect an inbox's Telegram bot (admin only)
+pub async fn delete_inbox_telegram_config(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(inbox_id): Path<String>,
+) -> ApiResult<StatusCode> {
+    require_admin(&auth_user)?;
+
+    state.telegram_service.delete_config(&inbox_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Telegram bot webhook - Public, authenticated by the secret token header
+pub async fn telegram_webhook(
+    State(state): State<AppState>,
+    Path(inbox_id): Path<String>,
+    headers: HeaderMap,
+    Json(update): Json<TelegramUpdate>,
+) -> ApiResult<StatusCode> {
+    let secret_token = headers
+        .get(TELEGRAM_SECRET_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    state
+        .telegram_service
+        .receive_update(&inbox_id, update, secret_token)
+        .await?;
+
+    Ok(StatusCode::OK)
+}
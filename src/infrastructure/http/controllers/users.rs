@@ -198,6 +198,8 @@ pub async fn get_user(
                 channels,
                 created_at: user.created_at.clone(),
                 updated_at: user.updated_at.clone(),
+                notes: None,
+                tier: None,
             })))
         }
     }
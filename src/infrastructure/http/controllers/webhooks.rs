@@ -6,15 +6,17 @@ use axum::{
 use serde::Deserialize;
 
 use crate::{
-    domain::entities::{CreateWebhookRequest, UpdateWebhookRequest},
-    infrastructure::http::middleware::{ApiError, ApiResult, AppState, AuthenticatedUser},
+    domain::entities::{CreateWebhookBackfillRequest, CreateWebhookRequest, UpdateWebhookRequest},
+    infrastructure::http::middleware::{
+        ApiError, ApiResult, AppState, AuthenticatedUser, ValidatedJson,
+    },
 };
 
 /// Create a new webhook (admin only)
 pub async fn create_webhook(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
-    Json(request): Json<CreateWebhookRequest>,
+    ValidatedJson(request): ValidatedJson<CreateWebhookRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Verify admin role
     if !auth_user.is_admin() {
@@ -52,6 +54,21 @@ pub async fn list_webhooks(
     Ok(Json(response))
 }
 
+/// List the event types webhooks can subscribe to (admin only)
+pub async fn list_webhook_event_types(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    Ok(Json(state.webhook_service.list_event_types()))
+}
+
 /// Get a specific webhook by ID (admin only)
 pub async fn get_webhook(
     State(state): State<AppState>,
@@ -75,7 +92,7 @@ pub async fn update_webhook(
     State(state): State<AppState>,
     axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
     Path(id): Path<String>,
-    Json(request): Json<UpdateWebhookRequest>,
+    ValidatedJson(request): ValidatedJson<UpdateWebhookRequest>,
 ) -> ApiResult<impl IntoResponse> {
     // Verify admin role
     if !auth_user.is_admin() {
@@ -225,6 +242,49 @@ pub async fn list_webhook_deliveries(
     Ok(Json(response))
 }
 
+/// Replay past events into a webhook (admin only)
+pub async fn create_webhook_backfill(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path(id): Path<String>,
+    ValidatedJson(request): ValidatedJson<CreateWebhookBackfillRequest>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let backfill = state
+        .webhook_service
+        .create_backfill(&id, request, &auth_user.user.id)
+        .await?;
+
+    Ok((axum::http::StatusCode::ACCEPTED, Json(backfill)))
+}
+
+/// Progress of a webhook backfill (admin only)
+pub async fn get_webhook_backfill(
+    State(state): State<AppState>,
+    axum::Extension(auth_user): axum::Extension<AuthenticatedUser>,
+    Path((id, backfill_id)): Path<(String, String)>,
+) -> ApiResult<impl IntoResponse> {
+    // Verify admin role
+    if !auth_user.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Administrator role required".to_string(),
+        ));
+    }
+
+    let backfill = state
+        .webhook_service
+        .get_backfill(&id, &backfill_id)
+        .await?;
+
+    Ok(Json(backfill))
+}
+
 // Query parameters for listing webhooks
 #[derive(Deserialize)]
 pub struct ListWebhooksParams {
@@ -10,6 +10,7 @@ use base64::{engine::general_purpose, Engine as _};
 use crate::application::services::api_key_service::verify_api_secret;
 use crate::domain::entities::Agent;
 use crate::infrastructure::http::middleware::auth::AppState;
+use crate::infrastructure::http::middleware::client_info::ClientInfo;
 use crate::infrastructure::http::middleware::error::ApiError;
 
 /// Extract API key credentials from request headers
@@ -135,8 +136,28 @@ pub async fn api_key_auth_middleware(
             Some(agent) => {
                 // Authentication successful - store agent in request extensions
                 // The require_auth middleware will use this to build AuthenticatedUser
+                let agent_id = agent.id.clone();
+                let ip = request
+                    .extensions()
+                    .get::<ClientInfo>()
+                    .and_then(|client| client.ip)
+                    .map(|ip| ip.to_string());
                 request.extensions_mut().insert(agent);
-                return Ok(next.run(request).await);
+                let response = next.run(request).await;
+
+                // Count the request against the key (fire and forget)
+                let usage_service = state.api_key_usage_service.clone();
+                let status = response.status().as_u16();
+                tokio::spawn(async move {
+                    if let Err(e) = usage_service
+                        .record_request(&api_key, &agent_id, status, ip.as_deref())
+                        .await
+                    {
+                        tracing::error!("Failed to record API key usage: {}", e);
+                    }
+                });
+
+                return Ok(response);
             }
             None => {
                 // Authentication failed
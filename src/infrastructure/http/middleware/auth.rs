@@ -2,9 +2,10 @@ use crate::{
     application::services,
     domain::entities::*,
     infrastructure::{
-        http::middleware::error::ApiError, providers::connection_manager::ConnectionManager,
+        http::middleware::{error::ApiError, locale::respond_in_locale},
+        providers::connection_manager::ConnectionManager,
     },
-    shared::rate_limiter::AuthRateLimiter,
+    shared::{rate_limiter::AuthRateLimiter, trusted_proxies::TrustedProxies},
 };
 use axum::{
     extract::{Request, State},
@@ -25,24 +26,59 @@ pub struct AppState {
     pub conversation_tag_service: services::ConversationTagService,
     pub connection_manager: Arc<dyn ConnectionManager>,
     pub rate_limiter: AuthRateLimiter,
+    pub trusted_proxies: TrustedProxies,
     pub webhook_service: services::WebhookService,
     pub tag_service: services::TagService,
+    pub auto_tag_service: services::AutoTagService,
+    pub attachment_rule_service: services::AttachmentRuleService,
     pub agent_service: services::AgentService,
+    pub agent_onboarding_service: services::AgentOnboardingService,
+    pub response_reminder_service: services::ResponseReminderService,
     pub user_service: services::UserService,
     pub contact_service: services::ContactService,
+    pub contact_note_service: services::ContactNoteService,
+    pub gdpr_service: services::GdprService,
+    pub conversation_transfer_service: services::ConversationTransferService,
+    pub saved_view_service: services::SavedViewService,
+    pub reporting_service: services::ReportingService,
+    pub activity_service: services::ActivityService,
+    pub junk_service: services::JunkService,
+    pub locale_service: services::LocaleService,
+    pub api_key_usage_service: services::ApiKeyUsageService,
+    pub priority_matrix_service: services::PriorityMatrixService,
+    pub import_service: services::ImportService,
+    pub maintenance_service: services::MaintenanceService,
+    pub diagnostics_service: services::DiagnosticsService,
+    pub job_service: services::JobService,
     pub session_service: services::SessionService,
     pub oidc_service: services::OidcService,
     pub email_service: services::EmailService,
+    pub email_oauth_service: services::EmailOAuthService,
+    pub email_participant_service: services::EmailParticipantService,
+    pub conversation_contact_service: services::ConversationContactService,
+    pub email_template_service: services::EmailTemplateService,
     pub attachment_service: services::AttachmentService,
     pub conversation_service: services::ConversationService,
     pub message_service: services::MessageService,
+    pub message_reaction_service: services::MessageReactionService,
+    pub channel_service: services::ChannelService,
+    pub chat_widget_service: services::ChatWidgetService,
+    pub sms_service: services::SmsService,
+    pub telegram_service: services::TelegramService,
     pub macro_service: services::MacroService,
+    pub config_bundle_service: services::ConfigBundleService,
     pub role_service: services::RoleService,
     pub inbox_service: services::InboxService,
     pub auth_service: services::AuthService,
     pub password_reset_service: services::PasswordResetService,
     pub team_service: services::TeamService,
+    pub holiday_calendar_service: services::HolidayCalendarService,
+    pub shift_service: services::ShiftService,
     pub conversation_priority_service: services::ConversationPriorityService,
+    pub csat_service: services::CsatService,
+    pub sentiment_service: services::SentimentService,
+    pub system_config_service: services::SystemConfigService,
+    pub branding_service: services::BrandingService,
     pub assignment_service: services::AssignmentService,
     pub auth_logger_service: services::AuthLoggerService,
 }
@@ -79,6 +115,7 @@ pub async fn require_auth(
             None,
         );
 
+        let user_id = user.id.clone();
         request.extensions_mut().insert(AuthenticatedUser {
             user,
             agent,
@@ -88,7 +125,7 @@ pub async fn require_auth(
             token: "api-key-auth".to_string(),
         });
 
-        return Ok(next.run(request).await);
+        return Ok(run_in_user_locale(&state, &user_id, request, next).await);
     }
 
     // Fall back to session-based auth
@@ -155,6 +192,7 @@ pub async fn require_auth(
     let token_owned = token.to_string();
 
     // Store authenticated user in request extensions
+    let user_id = user.id.clone();
     request.extensions_mut().insert(AuthenticatedUser {
         user,
         agent,
@@ -164,7 +202,20 @@ pub async fn require_auth(
         token: token_owned,
     });
 
-    Ok(next.run(request).await)
+    Ok(run_in_user_locale(&state, &user_id, request, next).await)
+}
+
+/// Continue in the locale the user chose, if they chose one
+async fn run_in_user_locale(
+    state: &AppState,
+    user_id: &str,
+    request: Request,
+    next: Next,
+) -> Response {
+    match state.locale_service.preferred_locale(user_id).await {
+        Some(locale) => respond_in_locale(locale, next.run(request)).await,
+        None => next.run(request).await,
+    }
 }
 
 /// Compute unique permissions from all roles
@@ -301,6 +352,7 @@ pub async fn web_auth_middleware(
     let permissions: Vec<String> = permissions.into_iter().collect();
 
     // Store authenticated user in request extensions
+    let user_id = user.id.clone();
     request.extensions_mut().insert(AuthenticatedUser {
         user,
         agent,
@@ -310,5 +362,5 @@ pub async fn web_auth_middleware(
         token,
     });
 
-    Ok(next.run(request).await)
+    Ok(run_in_user_locale(&state, &user_id, request, next).await)
 }
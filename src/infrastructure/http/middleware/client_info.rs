@@ -0,0 +1,90 @@
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts, Request, State},
+    http::{request::Parts, Extensions, HeaderMap},
+    middleware::Next,
+    response::Response,
+};
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+
+use crate::application::services::auth_logger::extract_user_agent;
+use crate::shared::trusted_proxies::TrustedProxies;
+
+/// Who is on the other end of a request, as seen through trusted proxies
+///
+/// Put in the request extensions by [`client_info_middleware`]; handlers can
+/// take it as an extractor, which falls back to the bare peer address when
+/// the middleware did not run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientInfo {
+    pub ip: Option<IpAddr>,
+    pub user_agent: Option<String>,
+}
+
+impl ClientInfo {
+    pub fn resolve(
+        trusted_proxies: &TrustedProxies,
+        extensions: &Extensions,
+        headers: &HeaderMap,
+    ) -> Self {
+        let peer = extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| *addr);
+        Self {
+            ip: trusted_proxies.client_ip(peer, headers),
+            user_agent: extract_user_agent(headers),
+        }
+    }
+
+    /// Client address for logs and audit records ("unknown" when not known)
+    pub fn ip_address(&self) -> String {
+        self.ip
+            .map(|ip| ip.to_string())
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+
+    /// User agent for logs and audit records ("unknown" when not sent)
+    pub fn user_agent_or_unknown(&self) -> String {
+        self.user_agent
+            .clone()
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+}
+
+/// Resolve the real client address of every request
+pub async fn client_info_middleware(
+    State(trusted_proxies): State<TrustedProxies>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let client_info = ClientInfo::resolve(
+        &trusted_proxies,
+        request.extensions(),
+        request.headers(),
+    );
+    request.extensions_mut().insert(client_info);
+    next.run(request).await
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ClientInfo
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<ClientInfo>()
+            .cloned()
+            .unwrap_or_else(|| {
+                ClientInfo::resolve(
+                    &TrustedProxies::default(),
+                    &parts.extensions,
+                    &parts.headers,
+                )
+            }))
+    }
+}
@@ -6,6 +6,8 @@ use axum::{
 use serde_json::json;
 use std::fmt;
 
+use crate::shared::{i18n, validation::FieldError};
+
 #[derive(Debug)]
 pub enum ApiError {
     NotFound(String),
@@ -15,6 +17,8 @@ pub enum ApiError {
     Internal(String),
     Conflict(String),
     TooManyRequests(String),
+    /// Request body broke field constraints (422 with per-field details)
+    Validation(crate::shared::validation::ValidationErrors),
 }
 
 impl fmt::Display for ApiError {
@@ -27,6 +31,7 @@ impl fmt::Display for ApiError {
             ApiError::Internal(msg) => write!(f, "Internal error: {}", msg),
             ApiError::Conflict(msg) => write!(f, "Conflict: {}", msg),
             ApiError::TooManyRequests(msg) => write!(f, "Too many requests: {}", msg),
+            ApiError::Validation(errors) => write!(f, "Validation failed: {}", errors),
         }
     }
 }
@@ -43,10 +48,25 @@ impl IntoResponse for ApiError {
             ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
             ApiError::Conflict(msg) => (StatusCode::CONFLICT, msg),
             ApiError::TooManyRequests(msg) => (StatusCode::TOO_MANY_REQUESTS, msg),
+            ApiError::Validation(errors) => {
+                let fields: Vec<FieldError> = errors
+                    .errors()
+                    .iter()
+                    .map(|error| FieldError {
+                        field: error.field.clone(),
+                        message: i18n::localize(&error.message),
+                    })
+                    .collect();
+                let body = Json(json!({
+                    "error": i18n::localize("Validation failed"),
+                    "fields": fields,
+                }));
+                return (StatusCode::UNPROCESSABLE_ENTITY, body).into_response();
+            }
         };
 
         let body = Json(json!({
-            "error": message
+            "error": i18n::localize(&message)
         }));
 
         (status, body).into_response()
@@ -96,11 +116,492 @@ impl From<crate::domain::errors::DomainError> for ApiError {
         match err {
             crate::domain::errors::DomainError::NotFound(msg) => ApiError::NotFound(msg),
             crate::domain::errors::DomainError::ValidationError(msg) => ApiError::BadRequest(msg),
+            crate::domain::errors::DomainError::Validation(errors) => ApiError::Validation(errors),
             crate::domain::errors::DomainError::Conflict(msg) => ApiError::Conflict(msg),
             crate::domain::errors::DomainError::Forbidden(msg) => ApiError::Forbidden(msg),
+            crate::domain::errors::DomainError::Unauthorized => ApiError::Unauthorized,
+            crate::domain::errors::DomainError::RateLimited(msg) => ApiError::TooManyRequests(msg),
             crate::domain::errors::DomainError::Internal(msg) => ApiError::Internal(msg),
         }
     }
 }
 
+// Repository ports still speak ApiError; lift those into the domain layer so
+// services can stay free of HTTP concerns. Every ApiError has a DomainError
+// counterpart, so converting back gives the same response.
+impl From<ApiError> for crate::domain::errors::DomainError {
+    fn from(err: ApiError) -> Self {
+        use crate::domain::errors::DomainError;
+        match err {
+            ApiError::NotFound(msg) => DomainError::NotFound(msg),
+            ApiError::BadRequest(msg) => DomainError::ValidationError(msg),
+            ApiError::Conflict(msg) => DomainError::Conflict(msg),
+            ApiError::Forbidden(msg) => DomainError::Forbidden(msg),
+            ApiError::Unauthorized => DomainError::Unauthorized,
+            ApiError::Validation(errors) => DomainError::Validation(errors),
+            ApiError::TooManyRequests(msg) => DomainError::RateLimited(msg),
+            ApiError::Internal(msg) => DomainError::Internal(msg),
+        }
+    }
+}
+
+macro_rules! impl_from_api_error {
+    ($($service_error:ty),* $(,)?) => {
+        $(
+            impl From<ApiError> for $service_error {
+                fn from(err: ApiError) -> Self {
+                    crate::domain::errors::DomainError::from(err).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_api_error!(
+    crate::domain::errors::TagError,
+    crate::domain::errors::TeamError,
+    crate::domain::errors::InboxError,
+    crate::domain::errors::WebhookError,
+    crate::domain::errors::PriorityError,
+    crate::domain::errors::AutoTagError,
+    crate::domain::errors::AttachmentRuleError,
+    crate::domain::errors::HolidayCalendarError,
+    crate::domain::errors::CsatError,
+    crate::domain::errors::ShiftError,
+    crate::domain::errors::ResponseReminderError,
+    crate::domain::errors::SavedViewError,
+    crate::domain::errors::TransferError,
+    crate::domain::errors::ConversationContactError,
+    crate::domain::errors::ReactionError,
+    crate::domain::errors::ConfigBundleError,
+    crate::domain::errors::SentimentError,
+    crate::domain::errors::SystemConfigError,
+    crate::domain::errors::ChannelError,
+    crate::domain::errors::SmsError,
+    crate::domain::errors::TelegramError,
+    crate::domain::errors::ContactNoteError,
+    crate::domain::errors::GdprError,
+    crate::domain::errors::ReportingError,
+    crate::domain::errors::ImportError,
+    crate::domain::errors::MaintenanceError,
+    crate::domain::errors::JobError,
+    crate::domain::errors::ActivityError,
+    crate::domain::errors::JunkError,
+    crate::domain::errors::ApiKeyUsageError,
+    crate::domain::errors::PriorityMatrixError,
+    crate::domain::errors::EmailParticipantError,
+    crate::domain::errors::EmailOAuthError,
+    crate::domain::errors::EmailTemplateError,
+);
+
+// Convert per-service errors at the handler boundary
+impl From<crate::domain::errors::TagError> for ApiError {
+    fn from(err: crate::domain::errors::TagError) -> Self {
+        use crate::domain::errors::TagError;
+        match err {
+            TagError::Forbidden(msg) => ApiError::Forbidden(msg),
+            TagError::NotFound(msg) => ApiError::NotFound(msg),
+            TagError::Validation(msg) => ApiError::BadRequest(msg),
+            err @ TagError::AlreadyExists(_) => ApiError::BadRequest(err.to_string()),
+            TagError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::TeamError> for ApiError {
+    fn from(err: crate::domain::errors::TeamError) -> Self {
+        use crate::domain::errors::TeamError;
+        match err {
+            TeamError::NotFound(msg) => ApiError::NotFound(msg),
+            TeamError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::InboxError> for ApiError {
+    fn from(err: crate::domain::errors::InboxError) -> Self {
+        use crate::domain::errors::InboxError;
+        match err {
+            InboxError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::WebhookError> for ApiError {
+    fn from(err: crate::domain::errors::WebhookError) -> Self {
+        use crate::domain::errors::WebhookError;
+        match err {
+            WebhookError::NotFound(msg) => ApiError::NotFound(msg),
+            WebhookError::Validation(msg) => ApiError::BadRequest(msg),
+            WebhookError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::PriorityError> for ApiError {
+    fn from(err: crate::domain::errors::PriorityError) -> Self {
+        use crate::domain::errors::PriorityError;
+        match err {
+            PriorityError::NotFound(msg) => ApiError::NotFound(msg),
+            PriorityError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::AutoTagError> for ApiError {
+    fn from(err: crate::domain::errors::AutoTagError) -> Self {
+        use crate::domain::errors::AutoTagError;
+        match err {
+            AutoTagError::NotFound(msg) => ApiError::NotFound(msg),
+            AutoTagError::Validation(msg) => ApiError::BadRequest(msg),
+            AutoTagError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::AttachmentRuleError> for ApiError {
+    fn from(err: crate::domain::errors::AttachmentRuleError) -> Self {
+        use crate::domain::errors::AttachmentRuleError;
+        match err {
+            AttachmentRuleError::NotFound(msg) => ApiError::NotFound(msg),
+            AttachmentRuleError::Validation(msg) => ApiError::BadRequest(msg),
+            AttachmentRuleError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::HolidayCalendarError> for ApiError {
+    fn from(err: crate::domain::errors::HolidayCalendarError) -> Self {
+        use crate::domain::errors::HolidayCalendarError;
+        match err {
+            HolidayCalendarError::NotFound(msg) => ApiError::NotFound(msg),
+            HolidayCalendarError::Validation(msg) => ApiError::BadRequest(msg),
+            HolidayCalendarError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::CsatError> for ApiError {
+    fn from(err: crate::domain::errors::CsatError) -> Self {
+        use crate::domain::errors::CsatError;
+        match err {
+            CsatError::NotFound(msg) => ApiError::NotFound(msg),
+            CsatError::Validation(msg) => ApiError::BadRequest(msg),
+            CsatError::Forbidden(msg) => ApiError::Forbidden(msg),
+            CsatError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::ShiftError> for ApiError {
+    fn from(err: crate::domain::errors::ShiftError) -> Self {
+        use crate::domain::errors::ShiftError;
+        match err {
+            ShiftError::NotFound(msg) => ApiError::NotFound(msg),
+            ShiftError::Validation(msg) => ApiError::BadRequest(msg),
+            ShiftError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::ResponseReminderError> for ApiError {
+    fn from(err: crate::domain::errors::ResponseReminderError) -> Self {
+        use crate::domain::errors::ResponseReminderError;
+        match err {
+            ResponseReminderError::NotFound(msg) => ApiError::NotFound(msg),
+            ResponseReminderError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::entities::QueryParseError> for ApiError {
+    fn from(err: crate::domain::entities::QueryParseError) -> Self {
+        ApiError::BadRequest(err.to_string())
+    }
+}
+
+impl From<crate::domain::errors::SavedViewError> for ApiError {
+    fn from(err: crate::domain::errors::SavedViewError) -> Self {
+        use crate::domain::errors::SavedViewError;
+        match err {
+            SavedViewError::NotFound(msg) => ApiError::NotFound(msg),
+            err @ SavedViewError::AlreadyExists(_) => ApiError::Conflict(err.to_string()),
+            SavedViewError::InvalidQuery(err) => err.into(),
+            SavedViewError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::ConversationContactError> for ApiError {
+    fn from(err: crate::domain::errors::ConversationContactError) -> Self {
+        use crate::domain::errors::ConversationContactError;
+        match err {
+            ConversationContactError::NotFound(msg) => ApiError::NotFound(msg),
+            ConversationContactError::Forbidden(msg) => ApiError::Forbidden(msg),
+            ConversationContactError::Validation(msg) => ApiError::BadRequest(msg),
+            ConversationContactError::Conflict(msg) => ApiError::Conflict(msg),
+            ConversationContactError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::TransferError> for ApiError {
+    fn from(err: crate::domain::errors::TransferError) -> Self {
+        use crate::domain::errors::TransferError;
+        match err {
+            TransferError::NotFound(msg) => ApiError::NotFound(msg),
+            TransferError::Forbidden(msg) => ApiError::Forbidden(msg),
+            TransferError::Validation(msg) => ApiError::BadRequest(msg),
+            TransferError::Conflict(msg) => ApiError::Conflict(msg),
+            TransferError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::ReactionError> for ApiError {
+    fn from(err: crate::domain::errors::ReactionError) -> Self {
+        use crate::domain::errors::ReactionError;
+        match err {
+            ReactionError::NotFound(msg) => ApiError::NotFound(msg),
+            ReactionError::Validation(msg) => ApiError::BadRequest(msg),
+            ReactionError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::ConfigBundleError> for ApiError {
+    fn from(err: crate::domain::errors::ConfigBundleError) -> Self {
+        use crate::domain::errors::ConfigBundleError;
+        match err {
+            ConfigBundleError::Validation(msg) => ApiError::BadRequest(msg),
+            ConfigBundleError::Conflict(msg) => ApiError::Conflict(msg),
+            ConfigBundleError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::SentimentError> for ApiError {
+    fn from(err: crate::domain::errors::SentimentError) -> Self {
+        use crate::domain::errors::SentimentError;
+        match err {
+            SentimentError::NotFound(msg) => ApiError::NotFound(msg),
+            SentimentError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::SystemConfigError> for ApiError {
+    fn from(err: crate::domain::errors::SystemConfigError) -> Self {
+        use crate::domain::errors::SystemConfigError;
+        match err {
+            SystemConfigError::Validation(msg) => ApiError::BadRequest(msg),
+            SystemConfigError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::ChannelError> for ApiError {
+    fn from(err: crate::domain::errors::ChannelError) -> Self {
+        use crate::domain::errors::ChannelError;
+        match err {
+            ChannelError::NotFound(msg) => ApiError::NotFound(msg),
+            ChannelError::Validation(msg) => ApiError::BadRequest(msg),
+            ChannelError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::SmsError> for ApiError {
+    fn from(err: crate::domain::errors::SmsError) -> Self {
+        use crate::domain::errors::SmsError;
+        match err {
+            SmsError::NotFound(msg) => ApiError::NotFound(msg),
+            SmsError::Validation(msg) => ApiError::BadRequest(msg),
+            SmsError::Conflict(msg) => ApiError::Conflict(msg),
+            SmsError::InvalidSignature => {
+                ApiError::Forbidden("Invalid Twilio signature".to_string())
+            }
+            SmsError::Channel(err) => err.into(),
+            SmsError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::TelegramError> for ApiError {
+    fn from(err: crate::domain::errors::TelegramError) -> Self {
+        use crate::domain::errors::TelegramError;
+        match err {
+            TelegramError::NotFound(msg) => ApiError::NotFound(msg),
+            TelegramError::Validation(msg) => ApiError::BadRequest(msg),
+            TelegramError::Conflict(msg) => ApiError::Conflict(msg),
+            TelegramError::InvalidSecret => {
+                ApiError::Forbidden("Invalid Telegram secret token".to_string())
+            }
+            TelegramError::Api(msg) => ApiError::BadRequest(format!("Telegram API error: {}", msg)),
+            TelegramError::Channel(err) => err.into(),
+            TelegramError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::ContactNoteError> for ApiError {
+    fn from(err: crate::domain::errors::ContactNoteError) -> Self {
+        use crate::domain::errors::ContactNoteError;
+        match err {
+            ContactNoteError::NotFound(msg) => ApiError::NotFound(msg),
+            ContactNoteError::Forbidden(msg) => ApiError::Forbidden(msg),
+            ContactNoteError::Validation(msg) => ApiError::BadRequest(msg),
+            ContactNoteError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::GdprError> for ApiError {
+    fn from(err: crate::domain::errors::GdprError) -> Self {
+        use crate::domain::errors::GdprError;
+        match err {
+            GdprError::NotFound(msg) => ApiError::NotFound(msg),
+            GdprError::Forbidden(msg) => ApiError::Forbidden(msg),
+            err @ GdprError::AlreadyErased => ApiError::Conflict(err.to_string()),
+            GdprError::InvalidConfirmation(msg) => ApiError::BadRequest(msg),
+            GdprError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::ReportingError> for ApiError {
+    fn from(err: crate::domain::errors::ReportingError) -> Self {
+        use crate::domain::errors::ReportingError;
+        match err {
+            ReportingError::NotFound(msg) => ApiError::NotFound(msg),
+            ReportingError::Forbidden(msg) => ApiError::Forbidden(msg),
+            ReportingError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::ImportError> for ApiError {
+    fn from(err: crate::domain::errors::ImportError) -> Self {
+        use crate::domain::errors::ImportError;
+        match err {
+            ImportError::NotFound(msg) => ApiError::NotFound(msg),
+            ImportError::Forbidden(msg) => ApiError::Forbidden(msg),
+            ImportError::Validation(msg) => ApiError::BadRequest(msg),
+            ImportError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::MaintenanceError> for ApiError {
+    fn from(err: crate::domain::errors::MaintenanceError) -> Self {
+        use crate::domain::errors::MaintenanceError;
+        match err {
+            MaintenanceError::Validation(msg) => ApiError::BadRequest(msg),
+            MaintenanceError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::JobError> for ApiError {
+    fn from(err: crate::domain::errors::JobError) -> Self {
+        use crate::domain::errors::JobError;
+        match err {
+            JobError::NotFound(msg) => ApiError::NotFound(msg),
+            JobError::NotDead(msg) => ApiError::Conflict(msg),
+            JobError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::ActivityError> for ApiError {
+    fn from(err: crate::domain::errors::ActivityError) -> Self {
+        use crate::domain::errors::ActivityError;
+        match err {
+            ActivityError::Forbidden(msg) => ApiError::Forbidden(msg),
+            ActivityError::Validation(msg) => ApiError::BadRequest(msg),
+            ActivityError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::JunkError> for ApiError {
+    fn from(err: crate::domain::errors::JunkError) -> Self {
+        use crate::domain::errors::JunkError;
+        match err {
+            JunkError::NotFound(msg) => ApiError::NotFound(msg),
+            JunkError::Forbidden(msg) => ApiError::Forbidden(msg),
+            JunkError::Validation(msg) => ApiError::BadRequest(msg),
+            JunkError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::ApiKeyUsageError> for ApiError {
+    fn from(err: crate::domain::errors::ApiKeyUsageError) -> Self {
+        use crate::domain::errors::ApiKeyUsageError;
+        match err {
+            ApiKeyUsageError::NotFound(msg) => ApiError::NotFound(msg),
+            ApiKeyUsageError::Forbidden(msg) => ApiError::Forbidden(msg),
+            ApiKeyUsageError::Validation(msg) => ApiError::BadRequest(msg),
+            ApiKeyUsageError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::PriorityMatrixError> for ApiError {
+    fn from(err: crate::domain::errors::PriorityMatrixError) -> Self {
+        use crate::domain::errors::PriorityMatrixError;
+        match err {
+            PriorityMatrixError::NotFound(msg) => ApiError::NotFound(msg),
+            PriorityMatrixError::Forbidden(msg) => ApiError::Forbidden(msg),
+            PriorityMatrixError::Validation(msg) => ApiError::BadRequest(msg),
+            PriorityMatrixError::Conflict(msg) => ApiError::Conflict(msg),
+            PriorityMatrixError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::EmailParticipantError> for ApiError {
+    fn from(err: crate::domain::errors::EmailParticipantError) -> Self {
+        use crate::domain::errors::EmailParticipantError;
+        match err {
+            EmailParticipantError::NotFound(msg) => ApiError::NotFound(msg),
+            EmailParticipantError::Forbidden(msg) => ApiError::Forbidden(msg),
+            EmailParticipantError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::EmailOAuthError> for ApiError {
+    fn from(err: crate::domain::errors::EmailOAuthError) -> Self {
+        use crate::domain::errors::EmailOAuthError;
+        match err {
+            EmailOAuthError::NotFound(msg) => ApiError::NotFound(msg),
+            EmailOAuthError::Forbidden(msg) => ApiError::Forbidden(msg),
+            EmailOAuthError::Validation(msg) => ApiError::BadRequest(msg),
+            EmailOAuthError::InvalidState => {
+                ApiError::BadRequest("Invalid or expired authorization state".to_string())
+            }
+            EmailOAuthError::NotAuthorized(msg) => ApiError::Conflict(msg),
+            EmailOAuthError::Provider(msg) => {
+                ApiError::BadRequest(format!("OAuth provider error: {}", msg))
+            }
+            EmailOAuthError::Repository(err) => err.into(),
+        }
+    }
+}
+
+impl From<crate::domain::errors::EmailTemplateError> for ApiError {
+    fn from(err: crate::domain::errors::EmailTemplateError) -> Self {
+        use crate::domain::errors::EmailTemplateError;
+        match err {
+            EmailTemplateError::NotFound(msg) => ApiError::NotFound(msg),
+            EmailTemplateError::Forbidden(msg) => ApiError::Forbidden(msg),
+            EmailTemplateError::Validation(msg) => ApiError::BadRequest(msg),
+            err @ EmailTemplateError::Delivery(_) => ApiError::BadRequest(err.to_string()),
+            EmailTemplateError::Repository(err) => err.into(),
+        }
+    }
+}
+
 pub type ApiResult<T> = Result<T, ApiError>;
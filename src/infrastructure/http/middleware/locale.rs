@@ -0,0 +1,43 @@
+use axum::{
+    extract::Request,
+    http::{
+        header::{ACCEPT_LANGUAGE, CONTENT_LANGUAGE},
+        HeaderValue,
+    },
+    middleware::Next,
+    response::Response,
+};
+use std::future::Future;
+
+use crate::shared::i18n;
+
+/// Answer in the locale negotiated from `Accept-Language`
+///
+/// Runs in front of every route. Authentication middleware narrows this down
+/// to the user's chosen locale with [`respond_in_locale`] once it knows who
+/// is asking.
+pub async fn locale_middleware(request: Request, next: Next) -> Response {
+    let accept_language = request
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|h| h.to_str().ok());
+    let locale = i18n::catalog().negotiate(accept_language, None);
+    respond_in_locale(locale, next.run(request)).await
+}
+
+/// Run a handler with `locale` as the request locale and label the
+/// response with it; the innermost (most specific) label wins
+pub async fn respond_in_locale<F>(locale: String, response: F) -> Response
+where
+    F: Future<Output = Response>,
+{
+    let header = HeaderValue::from_str(&locale).ok();
+    let mut response = i18n::with_locale(locale, response).await;
+    if let Some(header) = header {
+        response
+            .headers_mut()
+            .entry(CONTENT_LANGUAGE)
+            .or_insert(header);
+    }
+    response
+}
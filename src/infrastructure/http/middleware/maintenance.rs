@@ -0,0 +1,188 @@
+use askama::Template;
+use axum::{
+    extract::{Request, State},
+    http::{
+        header::{HeaderMap, ACCEPT, AUTHORIZATION, COOKIE, RETRY_AFTER},
+        HeaderValue, StatusCode,
+    },
+    middleware::Next,
+    response::{Html, IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+
+use crate::domain::entities::DEFAULT_MAINTENANCE_MESSAGE;
+use crate::infrastructure::http::middleware::auth::AppState;
+use crate::shared::i18n;
+
+/// Seconds clients are told to wait before retrying
+const RETRY_AFTER_SECONDS: u32 = 60;
+
+/// Paths that stay reachable during maintenance: probes, static assets and
+/// login (with its branding), so administrators can still sign in
+const EXEMPT_PATHS: &[&str] = &[
+    "/health",
+    "/readyz",
+    "/metrics",
+    "/login",
+    "/api/auth/login",
+    "/api/branding",
+    "/branding.css",
+];
+const EXEMPT_PREFIXES: &[&str] = &["/static/", "/api/auth/oidc/"];
+
+#[derive(Template)]
+#[template(path = "maintenance.html")]
+struct MaintenancePageTemplate {
+    message: String,
+    retry_after_seconds: u32,
+}
+
+fn is_exempt_path(path: &str) -> bool {
+    EXEMPT_PATHS.contains(&path) || EXEMPT_PREFIXES.iter().any(|p| path.starts_with(p))
+}
+
+/// Session token from a bearer header (API) or the session cookie (web UI)
+fn extract_session_token(headers: &HeaderMap) -> Option<String> {
+    let bearer = headers
+        .get(AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .map(str::trim)
+        .filter(|token| !token.is_empty());
+    if let Some(token) = bearer {
+        return Some(token.to_string());
+    }
+
+    headers
+        .get(COOKIE)
+        .and_then(|h| h.to_str().ok())?
+        .split(';')
+        .find_map(|cookie| cookie.trim().strip_prefix("session_token="))
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+}
+
+/// Browsers get the HTML page; API clients get JSON
+fn wants_html(path: &str, headers: &HeaderMap) -> bool {
+    !path.starts_with("/api/")
+        && headers
+            .get(ACCEPT)
+            .and_then(|h| h.to_str().ok())
+            .is_some_and(|accept| accept.contains("text/html"))
+}
+
+/// Whether the request carries a valid session belonging to an administrator
+async fn is_admin_request(state: &AppState, headers: &HeaderMap) -> bool {
+    let Some(token) = extract_session_token(headers) else {
+        return false;
+    };
+    let session = match state.session_service.get_session_by_token(&token).await {
+        Ok(Some(session)) if !session.is_expired() => session,
+        _ => return false,
+    };
+    match state.role_service.get_user_roles(&session.user_id).await {
+        Ok(roles) => roles.iter().any(|r| r.name == "Admin"),
+        Err(_) => false,
+    }
+}
+
+fn maintenance_response(message: &str, html: bool) -> Response {
+    let mut response = if html {
+        let page = MaintenancePageTemplate {
+            message: message.to_string(),
+            retry_after_seconds: RETRY_AFTER_SECONDS,
+        };
+        match page.render() {
+            Ok(body) => (StatusCode::SERVICE_UNAVAILABLE, Html(body)).into_response(),
+            Err(e) => {
+                tracing::error!("Failed to render maintenance page: {}", e);
+                (StatusCode::SERVICE_UNAVAILABLE, message.to_string()).into_response()
+            }
+        }
+    } else {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({
+                "error": message,
+                "maintenance": true,
+            })),
+        )
+            .into_response()
+    };
+    response
+        .headers_mut()
+        .insert(RETRY_AFTER, HeaderValue::from(RETRY_AFTER_SECONDS));
+    response
+}
+
+/// Answer 503 to everyone but administrators while maintenance mode is on
+///
+/// Runs in front of every route. Administrators are recognised by their
+/// session (bearer token or cookie); API keys and reporting tokens are
+/// refused along with everything else, as are inbound webhooks, which
+/// providers retry.
+pub async fn maintenance_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let mode = state.maintenance_service.mode();
+    if !mode.is_enabled() || is_exempt_path(request.uri().path()) {
+        return next.run(request).await;
+    }
+    if is_admin_request(&state, request.headers()).await {
+        return next.run(request).await;
+    }
+
+    let message = i18n::localize(
+        &mode
+            .message()
+            .unwrap_or_else(|| DEFAULT_MAINTENANCE_MESSAGE.to_string()),
+    );
+    maintenance_response(
+        &message,
+        wants_html(request.uri().path(), request.headers()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exempt_paths() {
+        assert!(is_exempt_path("/readyz"));
+        assert!(is_exempt_path("/api/auth/login"));
+        assert!(is_exempt_path("/api/auth/oidc/google/login"));
+        assert!(is_exempt_path("/static/css/modern.css"));
+        assert!(!is_exempt_path("/api/conversations"));
+        assert!(!is_exempt_path("/dashboard"));
+        assert!(!is_exempt_path("/login/other"));
+    }
+
+    #[test]
+    fn test_extract_session_token() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(extract_session_token(&headers), None);
+
+        headers.insert(COOKIE, "theme=dark; session_token=abc123".parse().unwrap());
+        assert_eq!(extract_session_token(&headers).as_deref(), Some("abc123"));
+
+        headers.insert(AUTHORIZATION, "Bearer xyz789".parse().unwrap());
+        assert_eq!(extract_session_token(&headers).as_deref(), Some("xyz789"));
+    }
+
+    #[test]
+    fn test_response_format() {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, "text/html,application/xhtml+xml".parse().unwrap());
+        assert!(wants_html("/inbox", &headers));
+        assert!(!wants_html("/api/conversations", &headers));
+        assert!(!wants_html("/inbox", &HeaderMap::new()));
+
+        let response = maintenance_response("Back soon", true);
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[RETRY_AFTER], "60");
+    }
+}
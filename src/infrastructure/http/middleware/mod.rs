@@ -1,11 +1,21 @@
 pub mod activity;
 pub mod api_key_auth;
 pub mod auth;
+pub mod client_info;
 pub mod error;
+pub mod locale;
+pub mod maintenance;
 pub mod permission;
+pub mod reporting_auth;
+pub mod validation;
 
 pub use activity::*;
 pub use api_key_auth::*;
 pub use auth::*;
+pub use client_info::*;
 pub use error::*;
+pub use locale::*;
+pub use maintenance::*;
 pub use permission::*;
+pub use reporting_auth::*;
+pub use validation::*;
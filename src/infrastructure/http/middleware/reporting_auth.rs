@@ -0,0 +1,72 @@
+use axum::{
+    extract::{Request, State},
+    http::header::{HeaderMap, AUTHORIZATION},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::infrastructure::http::middleware::auth::AppState;
+use crate::infrastructure::http::middleware::error::ApiError;
+
+/// Extract a bearer token from the Authorization header
+fn extract_bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+}
+
+/// Middleware for the read-only reporting API
+///
+/// Only workspace reporting tokens are accepted here; sessions and agent API
+/// keys are not, so the reporting routes can't be used to widen an agent's
+/// access and a reporting token can't reach any other route. The resolved
+/// `ReportingToken` is stored in the request extensions.
+pub async fn reporting_token_auth_middleware(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let token = match extract_bearer_token(request.headers()) {
+        Some(token) => token.to_string(),
+        None => return Err(ApiError::Unauthorized),
+    };
+
+    match state.reporting_service.authenticate(&token).await? {
+        Some(reporting_token) => {
+            request.extensions_mut().insert(reporting_token);
+            Ok(next.run(request).await)
+        }
+        None => {
+            tracing::warn!("Rejected reporting request with an invalid or revoked token");
+            Err(ApiError::Unauthorized)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_bearer_token() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, "Bearer oxr_abc123".parse().unwrap());
+        assert_eq!(extract_bearer_token(&headers), Some("oxr_abc123"));
+    }
+
+    #[test]
+    fn test_extract_bearer_token_rejects_other_schemes() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, "Basic a2V5OnNlY3JldA==".parse().unwrap());
+        assert_eq!(extract_bearer_token(&headers), None);
+
+        headers.insert(AUTHORIZATION, "Bearer ".parse().unwrap());
+        assert_eq!(extract_bearer_token(&headers), None);
+
+        assert_eq!(extract_bearer_token(&HeaderMap::new()), None);
+    }
+}
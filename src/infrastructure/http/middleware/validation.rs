@@ -0,0 +1,105 @@
+use axum::{
+    async_trait,
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    Json,
+};
+use serde::de::DeserializeOwned;
+
+use crate::infrastructure::http::middleware::error::ApiError;
+use crate::shared::validation::{Validate, ValidationErrors};
+
+/// Prefix axum puts in front of serde's message for bodies of the wrong shape
+const JSON_DATA_ERROR_PREFIX: &str = "Failed to deserialize the JSON body into the target type: ";
+
+/// JSON body extractor that checks the DTO's field constraints
+///
+/// Bodies that parse but break constraints, or have missing or mistyped
+/// fields, are rejected with `ApiError::Validation` (422 with per-field
+/// details); malformed JSON is a plain 400.
+pub struct ValidatedJson<T>(pub T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(json_rejection)?;
+        value.check().map_err(ApiError::Validation)?;
+
+        Ok(Self(value))
+    }
+}
+
+fn json_rejection(rejection: JsonRejection) -> ApiError {
+    match rejection {
+        JsonRejection::JsonDataError(err) => ApiError::Validation(data_error(&err.body_text())),
+        other => ApiError::BadRequest(other.body_text()),
+    }
+}
+
+/// Turn serde's message for a mistyped body into a field error
+///
+/// Messages look like "missing field `name` at line 1 column 2" or
+/// "color: invalid type: integer `5`, expected a string at line 1 column 10".
+fn data_error(text: &str) -> ValidationErrors {
+    let detail = text.strip_prefix(JSON_DATA_ERROR_PREFIX).unwrap_or(text);
+    let detail = match detail.rfind(" at line ") {
+        Some(position) => &detail[..position],
+        None => detail,
+    };
+
+    if let Some(field) = detail
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.split('`').next())
+    {
+        return ValidationErrors::field(field, "is required");
+    }
+    if let Some((path, message)) = detail.split_once(": ") {
+        if !path.is_empty() && !path.contains(char::is_whitespace) {
+            return ValidationErrors::field(path, message);
+        }
+    }
+
+    ValidationErrors::field("body", detail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_error_missing_field() {
+        let errors = data_error(
+            "Failed to deserialize the JSON body into the target type: missing field `name` at line 1 column 2",
+        );
+        assert_eq!(errors.messages_for("name"), vec!["is required"]);
+    }
+
+    #[test]
+    fn test_data_error_field_path() {
+        let errors = data_error(
+            "Failed to deserialize the JSON body into the target type: actions[0].action_type: invalid type: integer `5`, expected a string at line 1 column 30",
+        );
+        assert_eq!(
+            errors.messages_for("actions[0].action_type"),
+            vec!["invalid type: integer `5`, expected a string"]
+        );
+    }
+
+    #[test]
+    fn test_data_error_without_path() {
+        let errors = data_error(
+            "Failed to deserialize the JSON body into the target type: invalid type: sequence, expected struct Foo at line 1 column 0",
+        );
+        assert_eq!(
+            errors.messages_for("body"),
+            vec!["invalid type: sequence, expected struct Foo"]
+        );
+    }
+}
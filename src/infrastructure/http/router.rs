@@ -1,14 +1,21 @@
 use crate::infrastructure::http as api;
 use crate::infrastructure::http::middleware::{
-    api_key_auth_middleware, require_auth, track_activity_middleware, web_auth_middleware, AppState,
+    api_key_auth_middleware, client_info_middleware, locale_middleware, maintenance_middleware,
+    reporting_token_auth_middleware, require_auth, track_activity_middleware, web_auth_middleware,
+    AppState,
 };
 use crate::infrastructure::web;
 use axum::{
+    extract::DefaultBodyLimit,
     routing::{delete, get, patch, post, put},
     Router,
 };
 use tower_http::{services::ServeDir, trace::TraceLayer};
 
+/// Request body limit for helpdesk imports; escaping an export as a JSON
+/// string can double its size
+const IMPORT_BODY_LIMIT: usize = 2 * crate::domain::entities::IMPORT_MAX_DATA_BYTES;
+
 pub fn build_router(state: AppState) -> Router {
     // Build static file router
     let static_router =
@@ -29,6 +36,8 @@ pub fn build_router(state: AppState) -> Router {
             "/api/auth/events/recent",
             get(api::controllers::auth::get_recent_auth_events),
         )
+        .route("/api/auth/locale", get(api::locales::get_locale))
+        .route("/api/auth/locale", put(api::locales::update_locale))
         .route(
             "/api/oidc-providers",
             get(api::oidc_providers::list_oidc_providers),
@@ -50,6 +59,7 @@ pub fn build_router(state: AppState) -> Router {
             delete(api::oidc_providers::delete_oidc_provider),
         )
         // API Routes
+        .route("/api/agents/bulk", post(api::agents::bulk_create_agents))
         .route("/api/agents/:id", get(api::agents::get_agent))
         .route("/api/agents/:id", patch(api::agents::update_agent))
         .route("/api/agents/:id", delete(api::agents::delete_agent))
@@ -67,11 +77,95 @@ pub fn build_router(state: AppState) -> Router {
             delete(api::api_keys::revoke_api_key_handler),
         )
         .route("/api/api-keys", get(api::api_keys::list_api_keys_handler))
+        .route(
+            "/api/api-keys/usage",
+            get(api::api_keys::get_api_key_usage_overview_handler),
+        )
+        .route(
+            "/api/api-keys/:id/usage",
+            get(api::api_keys::get_api_key_usage_handler),
+        )
+        // Admin activity feed
+        .route("/api/activity", get(api::activity_feed::list_activity))
+        // Workspace reporting tokens (admin only)
+        .route(
+            "/api/reporting-tokens",
+            post(api::reporting::create_reporting_token),
+        )
+        .route(
+            "/api/reporting-tokens",
+            get(api::reporting::list_reporting_tokens),
+        )
+        .route(
+            "/api/reporting-tokens/:id",
+            delete(api::reporting::revoke_reporting_token),
+        )
+        // Helpdesk imports (admin only); exports are embedded in the JSON body
+        .route(
+            "/api/imports",
+            post(api::imports::create_import).layer(DefaultBodyLimit::max(IMPORT_BODY_LIMIT)),
+        )
+        .route("/api/imports", get(api::imports::list_imports))
+        .route("/api/imports/:id", get(api::imports::get_import))
+        // External channel ingestion (authenticate with an agent API key)
+        .route(
+            "/api/channels/:inbox_id/messages",
+            post(api::channels::receive_channel_message),
+        )
         .route("/api/contacts", get(api::contacts::list_contacts))
         .route("/api/contacts", post(api::contacts::create_contact))
         .route("/api/contacts/:id", get(api::contacts::get_contact))
         .route("/api/contacts/:id", patch(api::contacts::update_contact))
         .route("/api/contacts/:id", delete(api::contacts::delete_contact))
+        .route(
+            "/api/contacts/:id/tier",
+            put(api::priority_matrix::set_contact_tier),
+        )
+        .route(
+            "/api/contacts/:id/channels/:channel_id/verification",
+            post(api::contacts::resend_contact_email_verification),
+        )
+        .route(
+            "/api/contacts/:id/notes",
+            get(api::contact_notes::list_contact_notes),
+        )
+        .route(
+            "/api/contacts/:id/notes",
+            post(api::contact_notes::create_contact_note),
+        )
+        .route(
+            "/api/contacts/:id/notes/:note_id",
+            patch(api::contact_notes::update_contact_note),
+        )
+        .route(
+            "/api/contacts/:id/notes/:note_id",
+            delete(api::contact_notes::delete_contact_note),
+        )
+        // GDPR data requests
+        .route(
+            "/api/contacts/:id/gdpr/export",
+            post(api::gdpr::export_contact_data),
+        )
+        .route(
+            "/api/contacts/:id/gdpr/erase",
+            post(api::gdpr::erase_contact_data),
+        )
+        .route(
+            "/api/contacts/:id/gdpr/requests",
+            get(api::gdpr::list_gdpr_requests),
+        )
+        // Saved conversation views
+        .route("/api/saved-views", get(api::saved_views::list_saved_views))
+        .route("/api/saved-views", post(api::saved_views::create_saved_view))
+        .route("/api/saved-views/:id", get(api::saved_views::get_saved_view))
+        .route(
+            "/api/saved-views/:id",
+            patch(api::saved_views::update_saved_view),
+        )
+        .route(
+            "/api/saved-views/:id",
+            delete(api::saved_views::delete_saved_view),
+        )
         .route(
             "/api/conversations",
             get(api::conversations::list_conversations),
@@ -80,10 +174,18 @@ pub fn build_router(state: AppState) -> Router {
             "/api/conversations",
             post(api::conversations::create_conversation),
         )
+        .route(
+            "/api/conversations/counts",
+            get(api::conversations::get_conversation_counts),
+        )
         .route(
             "/api/conversations/:id",
             get(api::conversations::get_conversation),
         )
+        .route(
+            "/api/conversations/:id/timeline",
+            get(api::conversations::get_conversation_timeline),
+        )
         .route(
             "/api/conversations/:id/status",
             patch(api::conversations::update_conversation_status),
@@ -92,6 +194,62 @@ pub fn build_router(state: AppState) -> Router {
             "/api/conversations/:id/priority",
             patch(api::conversations::update_conversation_priority),
         )
+        .route(
+            "/api/conversations/:id/csat",
+            get(api::csat::list_csat_responses),
+        )
+        .route(
+            "/api/conversations/:id/csat/link",
+            get(api::csat::get_csat_survey_link),
+        )
+        .route(
+            "/api/conversations/:id/sentiment",
+            get(api::sentiment::get_conversation_sentiment),
+        )
+        // Junk conversations and inbox sender blocklists
+        .route("/api/conversations/:id/junk", post(api::junk::mark_junk))
+        .route("/api/conversations/:id/junk", delete(api::junk::unmark_junk))
+        .route(
+            "/api/conversations/:id/participants",
+            get(api::email_participants::list_participants),
+        )
+        .route(
+            "/api/conversations/:id/participants/:email",
+            patch(api::email_participants::update_participant),
+        )
+        .route(
+            "/api/conversations/:id/contacts",
+            get(api::conversation_contacts::list_contacts),
+        )
+        .route(
+            "/api/conversations/:id/contacts",
+            post(api::conversation_contacts::add_contact),
+        )
+        .route(
+            "/api/conversations/:id/contacts/:contact_id",
+            delete(api::conversation_contacts::remove_contact),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/blocked-senders",
+            get(api::junk::list_blocked_senders),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/blocked-senders/:id",
+            delete(api::junk::unblock_sender),
+        )
+        // Message reaction endpoints
+        .route(
+            "/api/messages/:id/reactions",
+            get(api::message_reactions::list_message_reactions),
+        )
+        .route(
+            "/api/messages/:id/reactions",
+            post(api::message_reactions::add_message_reaction),
+        )
+        .route(
+            "/api/messages/:id/reactions/:emoji",
+            delete(api::message_reactions::remove_message_reaction),
+        )
         .route(
             "/api/conversations/ref/:reference_number",
             get(api::conversations::get_conversation_by_reference),
@@ -115,6 +273,10 @@ pub fn build_router(state: AppState) -> Router {
             "/api/teams/:id/members/:user_id",
             delete(api::teams::remove_team_member),
         )
+        .route(
+            "/api/teams/:id/take-on-reply",
+            put(api::teams::set_take_on_reply),
+        )
         // Assignment routes
         .route(
             "/api/conversations/:id/assign",
@@ -124,6 +286,27 @@ pub fn build_router(state: AppState) -> Router {
             "/api/conversations/:id/unassign",
             post(api::assignments::unassign_conversation),
         )
+        .route(
+            "/api/conversations/:id/assignment-history",
+            get(api::assignments::get_assignment_history),
+        )
+        // Transfers with handover notes
+        .route(
+            "/api/conversations/:id/transfer",
+            post(api::conversation_transfers::transfer_conversation),
+        )
+        .route(
+            "/api/conversations/:id/transfers",
+            get(api::conversation_transfers::list_conversation_transfers),
+        )
+        .route(
+            "/api/conversations/:id/transfers/:transfer_id/accept",
+            post(api::conversation_transfers::accept_conversation_transfer),
+        )
+        .route(
+            "/api/conversations/:id/transfers/:transfer_id/decline",
+            post(api::conversation_transfers::decline_conversation_transfer),
+        )
         .route(
             "/api/conversations/unassigned",
             get(api::assignments::get_unassigned_conversations),
@@ -136,6 +319,36 @@ pub fn build_router(state: AppState) -> Router {
             "/api/teams/:id/conversations",
             get(api::assignments::get_team_conversations),
         )
+        // Language routing (rules and member language skills)
+        .route(
+            "/api/routing/language-rules",
+            get(api::assignments::list_language_rules),
+        )
+        .route(
+            "/api/routing/language-rules",
+            post(api::assignments::create_language_rule),
+        )
+        .route(
+            "/api/routing/language-rules/:id",
+            delete(api::assignments::delete_language_rule),
+        )
+        .route(
+            "/api/teams/:id/members/:user_id/languages",
+            put(api::assignments::set_member_languages),
+        )
+        // Skill catalog and agent skills (skill-based routing)
+        .route("/api/skills", get(api::assignments::list_skills))
+        .route("/api/skills", post(api::assignments::create_skill))
+        .route("/api/skills/:id", put(api::assignments::update_skill))
+        .route("/api/skills/:id", delete(api::assignments::delete_skill))
+        .route(
+            "/api/agents/:id/skills",
+            get(api::assignments::get_agent_skills),
+        )
+        .route(
+            "/api/agents/:id/skills",
+            put(api::assignments::set_agent_skills),
+        )
         .route(
             "/api/agents/:id/availability",
             put(api::assignments::update_agent_availability),
@@ -176,9 +389,21 @@ pub fn build_router(state: AppState) -> Router {
             "/api/agents/:id/activity",
             get(api::availability::get_activity_log),
         )
+        .route(
+            "/api/agents/:id/response-reminders",
+            get(api::response_reminders::get_response_reminder_settings),
+        )
+        .route(
+            "/api/agents/:id/response-reminders",
+            put(api::response_reminders::update_response_reminder_settings),
+        )
         // SLA routes
         .route("/api/sla/policies", post(api::sla::create_sla_policy))
         .route("/api/sla/policies", get(api::sla::list_sla_policies))
+        .route(
+            "/api/sla/policies/simulate",
+            post(api::sla::simulate_sla_policy),
+        )
         .route("/api/sla/policies/:id", get(api::sla::get_sla_policy))
         .route("/api/sla/policies/:id", put(api::sla::update_sla_policy))
         .route("/api/sla/policies/:id", delete(api::sla::delete_sla_policy))
@@ -196,6 +421,110 @@ pub fn build_router(state: AppState) -> Router {
             "/api/teams/:id/sla-policy",
             put(api::sla::assign_sla_policy_to_team),
         )
+        // Holiday calendar routes (Feature 029: per-team working-day calendars)
+        .route(
+            "/api/holiday-calendars",
+            post(api::holiday_calendars::create_holiday_calendar),
+        )
+        .route(
+            "/api/holiday-calendars",
+            get(api::holiday_calendars::list_holiday_calendars),
+        )
+        .route(
+            "/api/holiday-calendars/:id",
+            get(api::holiday_calendars::get_holiday_calendar),
+        )
+        .route(
+            "/api/holiday-calendars/:id",
+            put(api::holiday_calendars::update_holiday_calendar),
+        )
+        .route(
+            "/api/holiday-calendars/:id",
+            delete(api::holiday_calendars::delete_holiday_calendar),
+        )
+        .route(
+            "/api/holiday-calendars/:id/holidays",
+            get(api::holiday_calendars::list_calendar_holidays),
+        )
+        .route(
+            "/api/holiday-calendars/:id/holidays",
+            post(api::holiday_calendars::create_calendar_holiday),
+        )
+        .route(
+            "/api/holiday-calendars/:id/holidays/:holiday_id",
+            delete(api::holiday_calendars::delete_calendar_holiday),
+        )
+        .route(
+            "/api/holiday-calendars/:id/import",
+            post(api::holiday_calendars::import_calendar_holidays),
+        )
+        .route(
+            "/api/teams/:id/holiday-calendar",
+            put(api::holiday_calendars::set_team_holiday_calendar),
+        )
+        .route(
+            "/api/teams/:id/working-days",
+            get(api::holiday_calendars::get_team_working_days),
+        )
+        // Agent shift schedule endpoints
+        .route("/api/teams/:id/shifts", get(api::shifts::list_shifts))
+        .route("/api/teams/:id/shifts", post(api::shifts::create_shift))
+        .route(
+            "/api/teams/:id/shifts/:shift_id",
+            put(api::shifts::update_shift),
+        )
+        .route(
+            "/api/teams/:id/shifts/:shift_id",
+            delete(api::shifts::delete_shift),
+        )
+        .route(
+            "/api/teams/:id/shift-coverage",
+            get(api::shifts::get_shift_coverage),
+        )
+        // Configuration bundle endpoints
+        .route(
+            "/api/config/export",
+            get(api::config_bundles::export_config),
+        )
+        .route(
+            "/api/config/import",
+            post(api::config_bundles::import_config),
+        )
+        // System configuration endpoints
+        .route(
+            "/api/admin/config",
+            get(api::system_config::get_system_config),
+        )
+        .route(
+            "/api/admin/config",
+            put(api::system_config::update_system_config),
+        )
+        .route(
+            "/api/admin/config/changes",
+            get(api::system_config::list_system_config_changes),
+        )
+        .route("/api/admin/branding", put(api::branding::update_branding))
+        // Maintenance mode endpoints (admin only)
+        .route(
+            "/api/admin/maintenance",
+            get(api::controllers::maintenance::get_maintenance_status)
+                .put(api::controllers::maintenance::update_maintenance_mode),
+        )
+        // Diagnostics endpoints (admin only)
+        .route(
+            "/api/admin/diagnostics/slow-queries",
+            get(api::controllers::diagnostics::list_slow_queries),
+        )
+        // Background job endpoints (admin only)
+        .route(
+            "/api/admin/jobs/dead",
+            get(api::controllers::jobs::list_dead_jobs),
+        )
+        .route("/api/admin/jobs/:id", get(api::controllers::jobs::get_job))
+        .route(
+            "/api/admin/jobs/:id/requeue",
+            post(api::controllers::jobs::requeue_job),
+        )
         // Automation rules endpoints
         .route(
             "/api/automation/rules",
@@ -287,13 +616,139 @@ pub fn build_router(state: AppState) -> Router {
             "/api/inboxes/:inbox_id/email-config",
             delete(api::inbox_email_configs::delete_inbox_email_config),
         )
+        .route(
+            "/api/inboxes/:inbox_id/status",
+            get(api::inbox_email_configs::get_inbox_status),
+        )
         .route(
             "/api/inboxes/email-config/test",
             post(api::inbox_email_configs::test_inbox_email_config),
         )
+        .route(
+            "/api/inboxes/:inbox_id/email-config/oauth",
+            get(api::inbox_email_configs::get_email_oauth_status),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/email-config/oauth",
+            delete(api::inbox_email_configs::disconnect_email_oauth),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/email-config/oauth/authorize",
+            post(api::inbox_email_configs::start_email_oauth),
+        )
+        // Email template previews and test sends (admin only)
+        .route(
+            "/api/templates",
+            get(api::email_templates::list_email_templates),
+        )
+        .route(
+            "/api/templates/:id/preview",
+            post(api::email_templates::preview_email_template),
+        )
+        .route(
+            "/api/templates/:id/test-send",
+            post(api::email_templates::test_send_email_template),
+        )
+        // Inbox SMS configuration routes (admin only)
+        .route(
+            "/api/inboxes/:inbox_id/sms-config",
+            post(api::sms::create_inbox_sms_config),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/sms-config",
+            get(api::sms::get_inbox_sms_config),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/sms-config",
+            put(api::sms::update_inbox_sms_config),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/sms-config",
+            delete(api::sms::delete_inbox_sms_config),
+        )
+        // Inbox Telegram configuration routes (admin only)
+        .route(
+            "/api/inboxes/:inbox_id/telegram-config",
+            post(api::telegram::create_inbox_telegram_config),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/telegram-config",
+            get(api::telegram::get_inbox_telegram_config),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/telegram-config",
+            put(api::telegram::update_inbox_telegram_config),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/telegram-config",
+            delete(api::telegram::delete_inbox_telegram_config),
+        )
+        // Priority matrix routes (admin only)
+        .route(
+            "/api/priority-matrix",
+            get(api::priority_matrix::list_priority_matrix),
+        )
+        .route(
+            "/api/priority-matrix",
+            post(api::priority_matrix::create_priority_matrix_entry),
+        )
+        .route(
+            "/api/priority-matrix/:id",
+            delete(api::priority_matrix::delete_priority_matrix_entry),
+        )
+        // Inbox auto-tag rule routes (admin only)
+        .route(
+            "/api/inboxes/:inbox_id/auto-tag-rules",
+            post(api::auto_tag_rules::create_auto_tag_rule),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/auto-tag-rules",
+            get(api::auto_tag_rules::list_auto_tag_rules),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/auto-tag-rules/backfill",
+            post(api::auto_tag_rules::backfill_auto_tag_rules),
+        )
+        .route(
+            "/api/auto-tag-rules/:id",
+            get(api::auto_tag_rules::get_auto_tag_rule),
+        )
+        .route(
+            "/api/auto-tag-rules/:id",
+            put(api::auto_tag_rules::update_auto_tag_rule),
+        )
+        .route(
+            "/api/auto-tag-rules/:id",
+            delete(api::auto_tag_rules::delete_auto_tag_rule),
+        )
+        // Inbox attachment rule routes (admin only)
+        .route(
+            "/api/inboxes/:inbox_id/attachment-rules",
+            post(api::attachment_rules::create_attachment_rule),
+        )
+        .route(
+            "/api/inboxes/:inbox_id/attachment-rules",
+            get(api::attachment_rules::list_attachment_rules),
+        )
+        .route(
+            "/api/attachment-rules/:id",
+            get(api::attachment_rules::get_attachment_rule),
+        )
+        .route(
+            "/api/attachment-rules/:id",
+            put(api::attachment_rules::update_attachment_rule),
+        )
+        .route(
+            "/api/attachment-rules/:id",
+            delete(api::attachment_rules::delete_attachment_rule),
+        )
         // Webhook routes (admin only)
         .route("/api/webhooks", post(api::webhooks::create_webhook))
         .route("/api/webhooks", get(api::webhooks::list_webhooks))
+        .route(
+            "/api/webhooks/events",
+            get(api::webhooks::list_webhook_event_types),
+        )
         .route("/api/webhooks/:id", get(api::webhooks::get_webhook))
         .route("/api/webhooks/:id", put(api::webhooks::update_webhook))
         .route("/api/webhooks/:id", delete(api::webhooks::delete_webhook))
@@ -306,6 +761,14 @@ pub fn build_router(state: AppState) -> Router {
             "/api/webhooks/:id/deliveries",
             get(api::webhooks::list_webhook_deliveries),
         )
+        .route(
+            "/api/webhooks/:id/backfill",
+            post(api::webhooks::create_webhook_backfill),
+        )
+        .route(
+            "/api/webhooks/:id/backfill/:backfill_id",
+            get(api::webhooks::get_webhook_backfill),
+        )
         // Add activity tracking middleware (before auth middleware)
         .layer(axum::middleware::from_fn_with_state(
             state.clone(),
@@ -320,6 +783,21 @@ pub fn build_router(state: AppState) -> Router {
             api_key_auth_middleware,
         ));
 
+    // Build read-only reporting routes (require a reporting token)
+    let reporting = Router::new()
+        .route(
+            "/api/reporting/summary",
+            get(api::reporting::get_reporting_summary),
+        )
+        .route(
+            "/api/reporting/conversations",
+            get(api::reporting::list_reporting_conversations),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            reporting_token_auth_middleware,
+        ));
+
     // Build web routes (require auth via cookie)
     let web_protected = Router::new()
         .route("/dashboard", get(web::show_dashboard))
@@ -367,6 +845,7 @@ pub fn build_router(state: AppState) -> Router {
     Router::new()
         .route("/", get(root_handler))
         .route("/health", get(health_handler))
+        .route("/readyz", get(api::controllers::maintenance::readiness))
         .route("/login", get(web::show_login_page))
         .route("/login", post(web::handle_login))
         .route(
@@ -374,6 +853,9 @@ pub fn build_router(state: AppState) -> Router {
             get(web::show_public_conversation),
         )
         .route("/api/auth/login", post(api::controllers::auth::login))
+        // Workspace branding - Public endpoints (login page and web UI)
+        .route("/api/branding", get(api::branding::get_branding))
+        .route("/branding.css", get(api::branding::branding_stylesheet))
         .route(
             "/api/auth/oidc/providers",
             get(api::oidc_providers::list_enabled_oidc_providers),
@@ -386,16 +868,64 @@ pub fn build_router(state: AppState) -> Router {
             "/api/auth/oidc/callback",
             get(api::controllers::auth::oidc_callback),
         )
+        // Inbox mailbox OAuth2 redirect - Public endpoint (verified by one-time state)
+        .route(
+            "/api/email-oauth/callback",
+            get(api::inbox_email_configs::email_oauth_callback),
+        )
         // Password Reset routes (Feature 017) - Public endpoints
         .route(
             "/api/password-reset/reset",
             post(api::password_reset::reset_password),
         )
+        // Agent invitation acceptance - Public endpoint (verified by one-time token)
+        .route(
+            "/api/invitations/accept",
+            post(api::password_reset::accept_invitation),
+        )
+        // Contact email verification link - Public endpoint
+        .route(
+            "/api/contacts/verify-email",
+            get(api::contacts::verify_contact_email),
+        )
+        // Signed attachment download link - Public endpoint
+        .route(
+            "/api/attachments/:id/download",
+            get(api::attachments::download_signed_attachment),
+        )
+        // CSAT survey answers - Public endpoint (verified by link signature)
+        .route(
+            "/api/csat/:conversation_id",
+            post(api::csat::submit_csat_response),
+        )
+        // Web chat widget - Public endpoints
+        .route(
+            "/widget/:inbox_id/widget.js",
+            get(api::chat_widget::widget_script),
+        )
+        .route("/widget/:inbox_id/ws", get(api::chat_widget::widget_socket))
+        // Twilio SMS webhook - Public endpoint (verified by request signature)
+        .route("/webhooks/twilio/:inbox_id", post(api::sms::twilio_webhook))
+        // Telegram bot webhook - Public endpoint (verified by secret token header)
+        .route(
+            "/webhooks/telegram/:inbox_id",
+            post(api::telegram::telegram_webhook),
+        )
         .route("/metrics", get(metrics_handler))
         .merge(static_router)
         .merge(protected)
+        .merge(reporting)
         .merge(web_protected)
         .merge(api::messages::routes())
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            maintenance_middleware,
+        ))
+        .layer(axum::middleware::from_fn(locale_middleware))
+        .layer(axum::middleware::from_fn_with_state(
+            state.trusted_proxies.clone(),
+            client_info_middleware,
+        ))
         .layer(TraceLayer::new_for_http())
         .with_state(state)
 }
@@ -1,4 +1,5 @@
 use crate::config::Config;
+use crate::infrastructure::persistence::diagnostics::SlowQueryLayer;
 use opentelemetry::{global, KeyValue};
 use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_sdk::{runtime, trace as sdktrace, Resource};
@@ -47,9 +48,14 @@ fn init_tracing(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
             .with(env_filter)
             .with(fmt_layer)
             .with(otel_layer)
+            .with(SlowQueryLayer)
             .init();
     } else {
-        Registry::default().with(env_filter).with(fmt_layer).init();
+        Registry::default()
+            .with(env_filter)
+            .with(fmt_layer)
+            .with(SlowQueryLayer)
+            .init();
     }
 
     Ok(())
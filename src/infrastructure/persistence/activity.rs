@@ -0,0 +1,126 @@
+use sqlx::Row;
+
+use crate::domain::entities::{ActivityItem, ActivityType};
+use crate::domain::ports::activity_repository::ActivityRepository;
+use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
+use crate::infrastructure::persistence::Database;
+
+/// Rows of one activity type, each with a `label` and `detail` used to build the summary
+fn activity_source(activity_type: ActivityType) -> &'static str {
+    match activity_type {
+        ActivityType::ConversationCreated => {
+            "SELECT c.id AS id, 'conversation_created' AS activity_type,
+                    c.created_at AS occurred_at, c.id AS conversation_id,
+                    CAST(c.reference_number AS TEXT) AS label, c.subject AS detail
+             FROM conversations c"
+        }
+        ActivityType::SlaBreached => {
+            "SELECT e.id AS id, 'sla_breached' AS activity_type,
+                    e.breached_at AS occurred_at, a.conversation_id AS conversation_id,
+                    e.event_type AS label, p.name AS detail
+             FROM sla_events e
+             INNER JOIN applied_slas a ON a.id = e.applied_sla_id
+             LEFT JOIN sla_policies p ON p.id = a.sla_policy_id
+             WHERE e.status = 'breached' AND e.breached_at IS NOT NULL"
+        }
+        ActivityType::RuleExecuted => {
+            "SELECT l.id AS id, 'rule_executed' AS activity_type,
+                    l.evaluated_at AS occurred_at, l.conversation_id AS conversation_id,
+                    l.rule_name AS label, l.action_result AS detail
+             FROM rule_evaluation_logs l
+             WHERE l.action_executed = 1"
+        }
+        ActivityType::WebhookFailed => {
+            "SELECT d.id AS id, 'webhook_failed' AS activity_type,
+                    COALESCE(d.completed_at, d.attempted_at) AS occurred_at,
+                    NULL AS conversation_id,
+                    w.name || ' (' || d.event_type || ')' AS label, d.error_message AS detail
+             FROM webhook_deliveries d
+             INNER JOIN webhooks w ON w.id = d.webhook_id
+             WHERE d.status = 'failed' AND COALESCE(d.completed_at, d.attempted_at) IS NOT NULL"
+        }
+    }
+}
+
+fn activity_summary(activity_type: ActivityType, label: &str, detail: Option<&str>) -> String {
+    match activity_type {
+        ActivityType::ConversationCreated => match detail {
+            Some(subject) => format!("Conversation #{} created: {}", label, subject),
+            None => format!("Conversation #{} created", label),
+        },
+        ActivityType::SlaBreached => {
+            let target = label.replace('_', " ");
+            match detail {
+                Some(policy) => format!("SLA {} target breached ({})", target, policy),
+                None => format!("SLA {} target breached", target),
+            }
+        }
+        ActivityType::RuleExecuted => format!(
+            "Automation rule '{}' ran: {}",
+            label,
+            detail.unwrap_or("unknown")
+        ),
+        ActivityType::WebhookFailed => match detail {
+            Some(error) => format!("Webhook {} delivery failed: {}", label, error),
+            None => format!("Webhook {} delivery failed", label),
+        },
+    }
+}
+
+fn row_to_activity_item(row: &sqlx::any::AnyRow) -> ApiResult<ActivityItem> {
+    let activity_type: String = row.try_get("activity_type")?;
+    let activity_type: ActivityType = activity_type.parse().map_err(ApiError::Internal)?;
+    let label: String = row.try_get("label")?;
+    let detail: Option<String> = row.try_get("detail").ok().flatten();
+    Ok(ActivityItem {
+        id: row.try_get("id")?,
+        activity_type,
+        occurred_at: row.try_get("occurred_at")?,
+        conversation_id: row.try_get("conversation_id").ok().flatten(),
+        summary: activity_summary(activity_type, &label, detail.as_deref()),
+    })
+}
+
+impl Database {
+    pub async fn list_activity(
+        &self,
+        types: &[ActivityType],
+        before: Option<&str>,
+        limit: i64,
+    ) -> ApiResult<Vec<ActivityItem>> {
+        if types.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let sources: Vec<&str> = types.iter().map(|t| activity_source(*t)).collect();
+        let query = format!(
+            "SELECT id, activity_type, occurred_at, conversation_id, label, detail
+             FROM ({}) activity
+             WHERE (? IS NULL OR occurred_at < ?)
+             ORDER BY occurred_at DESC, id DESC
+             LIMIT ?",
+            sources.join(" UNION ALL ")
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(before)
+            .bind(before)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.iter().map(row_to_activity_item).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl ActivityRepository for Database {
+    async fn list_activity(
+        &self,
+        types: &[ActivityType],
+        before: Option<&str>,
+        limit: i64,
+    ) -> ApiResult<Vec<ActivityItem>> {
+        Database::list_activity(self, types, before, limit).await
+    }
+}
@@ -79,6 +79,7 @@ impl AgentRepository for Database {
         .await?;
 
         tx.commit().await?;
+        self.cache.invalidate_user_roles(&user.id).await;
 
         Ok((agent.id, user.id))
     }
@@ -122,6 +123,10 @@ impl AgentRepository for Database {
             Ok(None)
         }
     }
+
+    async fn get_agent_by_id(&self, agent_id: &str) -> ApiResult<Option<Agent>> {
+        Database::get_agent_by_id(self, agent_id).await
+    }
     // List agents with pagination (348-401 in view, originally 451)
     async fn list_agents(&self, limit: i64, offset: i64) -> ApiResult<Vec<(User, Agent)>> {
         let rows = sqlx::query(
@@ -0,0 +1,165 @@
+use sqlx::Row;
+
+use crate::domain::entities::{usage_error_rate, ApiKeyUsageBucket, ApiKeyUsageSummary};
+use crate::domain::ports::api_key_usage_repository::ApiKeyUsageRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+impl Database {
+    pub async fn record_api_key_request(
+        &self,
+        api_key: &str,
+        agent_id: &str,
+        hour: &str,
+        status: u16,
+        ip: Option<&str>,
+        seen_at: &str,
+    ) -> ApiResult<()> {
+        let error = i64::from(status >= 400);
+        sqlx::query(
+            "INSERT INTO api_key_usage
+             (api_key, agent_id, hour, request_count, error_count, last_seen_at, last_status, last_ip)
+             VALUES (?, ?, ?, 1, ?, ?, ?, ?)
+             ON CONFLICT(api_key, hour) DO UPDATE SET
+                request_count = request_count + 1,
+                error_count = error_count + excluded.error_count,
+                last_seen_at = excluded.last_seen_at,
+                last_status = excluded.last_status,
+                last_ip = excluded.last_ip",
+        )
+        .bind(api_key)
+        .bind(agent_id)
+        .bind(hour)
+        .bind(error)
+        .bind(seen_at)
+        .bind(i64::from(status))
+        .bind(ip.map(str::to_string))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_api_key_usage(
+        &self,
+        api_key: &str,
+        since_hour: &str,
+    ) -> ApiResult<Vec<ApiKeyUsageBucket>> {
+        let rows = sqlx::query(
+            "SELECT hour, request_count, error_count, last_seen_at, last_status, last_ip
+             FROM api_key_usage
+             WHERE api_key = ? AND hour >= ?
+             ORDER BY hour ASC",
+        )
+        .bind(api_key)
+        .bind(since_hour)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(ApiKeyUsageBucket {
+                    hour: row.try_get("hour")?,
+                    request_count: row.try_get("request_count")?,
+                    error_count: row.try_get("error_count")?,
+                    last_seen_at: row.try_get("last_seen_at")?,
+                    last_status: row.try_get("last_status")?,
+                    last_ip: row.try_get("last_ip").ok().flatten(),
+                })
+            })
+            .collect()
+    }
+
+    pub async fn get_api_key_usage_owner(&self, api_key: &str) -> ApiResult<Option<String>> {
+        let agent_id: Option<String> = sqlx::query_scalar(
+            "SELECT agent_id FROM api_key_usage WHERE api_key = ? ORDER BY hour DESC LIMIT 1",
+        )
+        .bind(api_key)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(agent_id)
+    }
+
+    pub async fn summarize_api_key_usage(
+        &self,
+        since_hour: &str,
+    ) -> ApiResult<Vec<ApiKeyUsageSummary>> {
+        let rows = sqlx::query(
+            "SELECT api_key, MAX(agent_id) AS agent_id,
+                    SUM(request_count) AS request_count,
+                    SUM(error_count) AS error_count,
+                    MAX(last_seen_at) AS last_seen_at
+             FROM api_key_usage
+             WHERE hour >= ?
+             GROUP BY api_key
+             ORDER BY request_count DESC, api_key ASC",
+        )
+        .bind(since_hour)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                let request_count: i64 = row.try_get("request_count")?;
+                let error_count: i64 = row.try_get("error_count")?;
+                Ok(ApiKeyUsageSummary {
+                    api_key: row.try_get("api_key")?,
+                    agent_id: row.try_get("agent_id")?,
+                    request_count,
+                    error_count,
+                    error_rate: usage_error_rate(request_count, error_count),
+                    last_seen_at: row.try_get("last_seen_at").ok().flatten(),
+                })
+            })
+            .collect()
+    }
+
+    pub async fn delete_api_key_usage_before(&self, before_hour: &str) -> ApiResult<u64> {
+        let result = sqlx::query("DELETE FROM api_key_usage WHERE hour < ?")
+            .bind(before_hour)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiKeyUsageRepository for Database {
+    async fn record_api_key_request(
+        &self,
+        api_key: &str,
+        agent_id: &str,
+        hour: &str,
+        status: u16,
+        ip: Option<&str>,
+        seen_at: &str,
+    ) -> ApiResult<()> {
+        self.record_api_key_request(api_key, agent_id, hour, status, ip, seen_at)
+            .await
+    }
+
+    async fn list_api_key_usage(
+        &self,
+        api_key: &str,
+        since_hour: &str,
+    ) -> ApiResult<Vec<ApiKeyUsageBucket>> {
+        self.list_api_key_usage(api_key, since_hour).await
+    }
+
+    async fn get_api_key_usage_owner(&self, api_key: &str) -> ApiResult<Option<String>> {
+        self.get_api_key_usage_owner(api_key).await
+    }
+
+    async fn summarize_api_key_usage(
+        &self,
+        since_hour: &str,
+    ) -> ApiResult<Vec<ApiKeyUsageSummary>> {
+        self.summarize_api_key_usage(since_hour).await
+    }
+
+    async fn delete_api_key_usage_before(&self, before_hour: &str) -> ApiResult<u64> {
+        self.delete_api_key_usage_before(before_hour).await
+    }
+}
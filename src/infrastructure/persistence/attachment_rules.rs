@@ -0,0 +1,112 @@
+use crate::domain::entities::AttachmentRule;
+use crate::domain::ports::attachment_rule_repository::AttachmentRuleRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+use sqlx::Row;
+
+const ATTACHMENT_RULE_COLUMNS: &str =
+    "id, inbox_id, pattern, tag_id, team_id, enabled, created_by, created_at, updated_at";
+
+fn row_to_attachment_rule(row: &sqlx::any::AnyRow) -> ApiResult<AttachmentRule> {
+    Ok(AttachmentRule {
+        id: row.try_get("id")?,
+        inbox_id: row.try_get("inbox_id")?,
+        pattern: row.try_get("pattern")?,
+        tag_id: row.try_get("tag_id").ok().flatten(),
+        team_id: row.try_get("team_id").ok().flatten(),
+        enabled: row.try_get::<i32, _>("enabled")? != 0,
+        created_by: row.try_get("created_by")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[async_trait::async_trait]
+impl AttachmentRuleRepository for Database {
+    async fn create_attachment_rule(&self, rule: &AttachmentRule) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO attachment_rules (id, inbox_id, pattern, tag_id, team_id, enabled,
+                                           created_by, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&rule.id)
+        .bind(&rule.inbox_id)
+        .bind(&rule.pattern)
+        .bind(&rule.tag_id)
+        .bind(&rule.team_id)
+        .bind(rule.enabled)
+        .bind(&rule.created_by)
+        .bind(&rule.created_at)
+        .bind(&rule.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_attachment_rule(&self, id: &str) -> ApiResult<Option<AttachmentRule>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM attachment_rules WHERE id = ?",
+            ATTACHMENT_RULE_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_attachment_rule).transpose()
+    }
+
+    async fn list_attachment_rules(&self, inbox_id: &str) -> ApiResult<Vec<AttachmentRule>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM attachment_rules WHERE inbox_id = ? ORDER BY created_at ASC",
+            ATTACHMENT_RULE_COLUMNS
+        ))
+        .bind(inbox_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_attachment_rule).collect()
+    }
+
+    async fn list_enabled_attachment_rules(
+        &self,
+        inbox_id: &str,
+    ) -> ApiResult<Vec<AttachmentRule>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM attachment_rules WHERE inbox_id = ? AND enabled = 1 ORDER BY created_at ASC",
+            ATTACHMENT_RULE_COLUMNS
+        ))
+        .bind(inbox_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_attachment_rule).collect()
+    }
+
+    async fn update_attachment_rule(&self, rule: &AttachmentRule) -> ApiResult<()> {
+        sqlx::query(
+            "UPDATE attachment_rules
+             SET pattern = ?, tag_id = ?, team_id = ?, enabled = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&rule.pattern)
+        .bind(&rule.tag_id)
+        .bind(&rule.team_id)
+        .bind(rule.enabled)
+        .bind(&rule.updated_at)
+        .bind(&rule.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_attachment_rule(&self, id: &str) -> ApiResult<()> {
+        sqlx::query("DELETE FROM attachment_rules WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
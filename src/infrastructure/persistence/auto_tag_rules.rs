@@ -0,0 +1,116 @@
+use crate::domain::entities::AutoTagRule;
+use crate::domain::ports::auto_tag_repository::AutoTagRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+use sqlx::Row;
+
+const AUTO_TAG_RULE_COLUMNS: &str = "id, inbox_id, tag_id, match_field, match_type, pattern,
+     case_sensitive, enabled, created_by, created_at, updated_at";
+
+fn row_to_auto_tag_rule(row: &sqlx::any::AnyRow) -> ApiResult<AutoTagRule> {
+    Ok(AutoTagRule {
+        id: row.try_get("id")?,
+        inbox_id: row.try_get("inbox_id")?,
+        tag_id: row.try_get("tag_id")?,
+        match_field: row.try_get::<String, _>("match_field")?.into(),
+        match_type: row.try_get::<String, _>("match_type")?.into(),
+        pattern: row.try_get("pattern")?,
+        case_sensitive: row.try_get::<i32, _>("case_sensitive")? != 0,
+        enabled: row.try_get::<i32, _>("enabled")? != 0,
+        created_by: row.try_get("created_by")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[async_trait::async_trait]
+impl AutoTagRepository for Database {
+    async fn create_auto_tag_rule(&self, rule: &AutoTagRule) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO auto_tag_rules (id, inbox_id, tag_id, match_field, match_type, pattern,
+                                         case_sensitive, enabled, created_by, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&rule.id)
+        .bind(&rule.inbox_id)
+        .bind(&rule.tag_id)
+        .bind(rule.match_field.as_str())
+        .bind(rule.match_type.as_str())
+        .bind(&rule.pattern)
+        .bind(rule.case_sensitive)
+        .bind(rule.enabled)
+        .bind(&rule.created_by)
+        .bind(&rule.created_at)
+        .bind(&rule.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_auto_tag_rule(&self, id: &str) -> ApiResult<Option<AutoTagRule>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM auto_tag_rules WHERE id = ?",
+            AUTO_TAG_RULE_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_auto_tag_rule).transpose()
+    }
+
+    async fn list_auto_tag_rules(&self, inbox_id: &str) -> ApiResult<Vec<AutoTagRule>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM auto_tag_rules WHERE inbox_id = ? ORDER BY created_at ASC",
+            AUTO_TAG_RULE_COLUMNS
+        ))
+        .bind(inbox_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_auto_tag_rule).collect()
+    }
+
+    async fn list_enabled_auto_tag_rules(&self, inbox_id: &str) -> ApiResult<Vec<AutoTagRule>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM auto_tag_rules WHERE inbox_id = ? AND enabled = 1 ORDER BY created_at ASC",
+            AUTO_TAG_RULE_COLUMNS
+        ))
+        .bind(inbox_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_auto_tag_rule).collect()
+    }
+
+    async fn update_auto_tag_rule(&self, rule: &AutoTagRule) -> ApiResult<()> {
+        sqlx::query(
+            "UPDATE auto_tag_rules
+             SET tag_id = ?, match_field = ?, match_type = ?, pattern = ?,
+                 case_sensitive = ?, enabled = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&rule.tag_id)
+        .bind(rule.match_field.as_str())
+        .bind(rule.match_type.as_str())
+        .bind(&rule.pattern)
+        .bind(rule.case_sensitive)
+        .bind(rule.enabled)
+        .bind(&rule.updated_at)
+        .bind(&rule.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_auto_tag_rule(&self, id: &str) -> ApiResult<()> {
+        sqlx::query("DELETE FROM auto_tag_rules WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
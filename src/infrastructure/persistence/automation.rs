@@ -2,7 +2,9 @@ use crate::infrastructure::http::middleware::error::ApiResult;
 use crate::infrastructure::persistence::automation_rules::AutomationRulesRepository;
 use crate::infrastructure::persistence::Database;
 use crate::domain::ports::automation_repository::AutomationRepository;
-use crate::domain::entities::{AutomationRule, RuleEvaluationLog};
+use crate::domain::entities::{
+    AutomationRule, PendingRuleAction, PendingRuleActionStatus, RuleEvaluationLog,
+};
 
 /// Implement AutomationRepository trait for Database by delegating to AutomationRulesRepository
 #[async_trait::async_trait]
@@ -61,4 +63,46 @@ impl AutomationRepository for Database {
         )
         .await
     }
+
+    async fn count_rule_executions_since(
+        &self,
+        rule_id: &str,
+        conversation_id: &str,
+        since: &str,
+    ) -> ApiResult<i64> {
+        <Self as AutomationRulesRepository>::count_rule_executions_since(
+            self,
+            rule_id,
+            conversation_id,
+            since,
+        )
+        .await
+    }
+
+    async fn create_pending_rule_action(&self, pending: &PendingRuleAction) -> ApiResult<()> {
+        <Self as AutomationRulesRepository>::create_pending_rule_action(self, pending).await
+    }
+
+    async fn get_pending_rule_action(&self, id: &str) -> ApiResult<Option<PendingRuleAction>> {
+        <Self as AutomationRulesRepository>::get_pending_rule_action(self, id).await
+    }
+
+    async fn get_pending_rule_actions_for_conversation(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<PendingRuleAction>> {
+        <Self as AutomationRulesRepository>::get_pending_rule_actions_for_conversation(
+            self,
+            conversation_id,
+        )
+        .await
+    }
+
+    async fn finish_pending_rule_action(
+        &self,
+        id: &str,
+        status: PendingRuleActionStatus,
+    ) -> ApiResult<bool> {
+        <Self as AutomationRulesRepository>::finish_pending_rule_action(self, id, status).await
+    }
 }
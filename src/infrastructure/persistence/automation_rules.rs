@@ -2,8 +2,8 @@ use async_trait::async_trait;
 use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
 use crate::infrastructure::persistence::Database;
 use crate::domain::entities::{
-    ActionResult, AutomationRule, ConditionResult, RuleAction, RuleCondition, RuleEvaluationLog,
-    RuleType,
+    ActionResult, AutomationRule, ConditionResult, PendingRuleAction, PendingRuleActionStatus,
+    RuleAction, RuleCondition, RuleEvaluationLog, RuleType,
 };
 use sqlx::Row;
 
@@ -384,11 +384,12 @@ impl AutomationRulesRepository for Database {
 
         let mut logs = Vec::new();
         for row in rows {
-            let condition_result_str: Option<String> = row.try_get("condition_result")?;
+            let condition_result_str: Option<String> =
+                row.try_get("condition_result").ok().flatten();
             let condition_result =
                 condition_result_str.and_then(|s| s.parse::<ConditionResult>().ok());
 
-            let action_result_str: Option<String> = row.try_get("action_result")?;
+            let action_result_str: Option<String> = row.try_get("action_result").ok().flatten();
             let action_result = action_result_str.and_then(|s| s.parse::<ActionResult>().ok());
 
             let cascade_depth: i32 = row.try_get("cascade_depth")?;
@@ -451,6 +452,119 @@ impl AutomationRulesRepository for Database {
         self.get_rule_evaluation_logs(Some(rule_id), None, None, Some(limit), Some(offset))
             .await
     }
+    /// Count how often a rule executed its action on a conversation since a timestamp
+    async fn count_rule_executions_since(
+        &self,
+        rule_id: &str,
+        conversation_id: &str,
+        since: &str,
+    ) -> ApiResult<i64> {
+        let row = sqlx::query(
+            "SELECT COUNT(*) as count
+             FROM rule_evaluation_logs
+             WHERE rule_id = ? AND conversation_id = ? AND action_executed = 1
+               AND evaluated_at >= ?",
+        )
+        .bind(rule_id)
+        .bind(conversation_id)
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.try_get("count")?)
+    }
+    /// Create pending rule action
+    async fn create_pending_rule_action(&self, pending: &PendingRuleAction) -> ApiResult<()> {
+        let action_json = serde_json::to_string(&pending.action)
+            .map_err(|e| ApiError::Internal(format!("Failed to serialize action: {}", e)))?;
+
+        sqlx::query(
+            "INSERT INTO automation_pending_actions (id, rule_id, conversation_id, action, run_at, status, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(&pending.id)
+            .bind(&pending.rule_id)
+            .bind(&pending.conversation_id)
+            .bind(&action_json)
+            .bind(&pending.run_at)
+            .bind(pending.status.to_string())
+            .bind(&pending.created_at)
+            .bind(&pending.updated_at)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+    /// Get pending rule action by ID
+    async fn get_pending_rule_action(&self, id: &str) -> ApiResult<Option<PendingRuleAction>> {
+        let row = sqlx::query(
+            "SELECT id, rule_id, conversation_id, action, run_at, status, created_at, updated_at
+             FROM automation_pending_actions
+             WHERE id = ?",
+        )
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.map(|row| row_to_pending_rule_action(&row)).transpose()
+    }
+    /// Get rule actions still waiting to run on a conversation
+    async fn get_pending_rule_actions_for_conversation(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<PendingRuleAction>> {
+        let rows = sqlx::query(
+            "SELECT id, rule_id, conversation_id, action, run_at, status, created_at, updated_at
+             FROM automation_pending_actions
+             WHERE conversation_id = ? AND status = 'pending'
+             ORDER BY run_at ASC",
+        )
+            .bind(conversation_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.iter().map(row_to_pending_rule_action).collect()
+    }
+    /// Move a pending rule action to a final status; false if it was no longer pending
+    async fn finish_pending_rule_action(
+        &self,
+        id: &str,
+        status: PendingRuleActionStatus,
+    ) -> ApiResult<bool> {
+        let updated_at = chrono::Utc::now().to_rfc3339();
+        let result = sqlx::query(
+            "UPDATE automation_pending_actions SET status = ?, updated_at = ?
+             WHERE id = ? AND status = 'pending'",
+        )
+            .bind(status.to_string())
+            .bind(&updated_at)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+fn row_to_pending_rule_action(row: &sqlx::any::AnyRow) -> ApiResult<PendingRuleAction> {
+    let action_str: String = row.try_get("action")?;
+    let action: RuleAction = serde_json::from_str(&action_str)
+        .map_err(|e| ApiError::Internal(format!("Failed to deserialize action: {}", e)))?;
+    let status_str: String = row.try_get("status")?;
+    let status = status_str
+        .parse::<PendingRuleActionStatus>()
+        .map_err(ApiError::Internal)?;
+
+    Ok(PendingRuleAction {
+        id: row.try_get("id")?,
+        rule_id: row.try_get("rule_id")?,
+        conversation_id: row.try_get("conversation_id")?,
+        action,
+        run_at: row.try_get("run_at")?,
+        status,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
 }
 
 #[async_trait]
@@ -507,4 +621,26 @@ pub trait AutomationRulesRepository : Send + Sync {
         limit: i32,
         offset: i32,
     ) -> ApiResult<Vec<RuleEvaluationLog>>;
+    /// Count how often a rule executed its action on a conversation since a timestamp
+    async fn count_rule_executions_since(
+        &self,
+        rule_id: &str,
+        conversation_id: &str,
+        since: &str,
+    ) -> ApiResult<i64>;
+    /// Create pending rule action
+    async fn create_pending_rule_action(&self, pending: &PendingRuleAction) -> ApiResult<()>;
+    /// Get pending rule action by ID
+    async fn get_pending_rule_action(&self, id: &str) -> ApiResult<Option<PendingRuleAction>>;
+    /// Get rule actions still waiting to run on a conversation
+    async fn get_pending_rule_actions_for_conversation(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<PendingRuleAction>>;
+    /// Move a pending rule action to a final status; false if it was no longer pending
+    async fn finish_pending_rule_action(
+        &self,
+        id: &str,
+        status: PendingRuleActionStatus,
+    ) -> ApiResult<bool>;
 }
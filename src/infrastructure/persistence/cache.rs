@@ -0,0 +1,173 @@
+/// Row cache for hot repository lookups
+///
+/// Caches conversations by id, roles by name and role lists by user id. Entries
+/// expire after a TTL and are invalidated explicitly by the repository writes
+/// that touch them, so stale reads are bounded by the TTL only for changes made
+/// by other processes. Compiled in with the `row-cache` feature; without it
+/// every lookup is a miss and invalidation is a no-op.
+use crate::domain::entities::{Conversation, Role};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct RowCacheConfig {
+    /// Maximum number of entries kept per cache
+    pub max_entries: u64,
+    /// How long an entry is served before it is re-read from the database
+    pub ttl: Duration,
+}
+
+impl Default for RowCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 10_000,
+            ttl: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RowCacheConfig {
+    /// Load settings from ROW_CACHE_* environment variables, falling back to defaults
+    pub fn from_env() -> Self {
+        fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default)
+        }
+
+        let defaults = Self::default();
+        Self {
+            max_entries: env_or("ROW_CACHE_MAX_ENTRIES", defaults.max_entries),
+            ttl: Duration::from_secs(env_or("ROW_CACHE_TTL_SECS", defaults.ttl.as_secs())),
+        }
+    }
+}
+
+#[cfg(feature = "row-cache")]
+fn record_lookup(cache: &'static str, hit: bool) {
+    if hit {
+        metrics::counter!("row_cache_hits_total", "cache" => cache).increment(1);
+    } else {
+        metrics::counter!("row_cache_misses_total", "cache" => cache).increment(1);
+    }
+}
+
+#[cfg(feature = "row-cache")]
+#[derive(Clone)]
+pub struct RowCache {
+    conversations: moka::future::Cache<String, Conversation>,
+    roles_by_name: moka::future::Cache<String, Role>,
+    user_roles: moka::future::Cache<String, Vec<Role>>,
+}
+
+#[cfg(feature = "row-cache")]
+impl RowCache {
+    pub fn new(config: &RowCacheConfig) -> Self {
+        fn build<V: Clone + Send + Sync + 'static>(
+            config: &RowCacheConfig,
+        ) -> moka::future::Cache<String, V> {
+            moka::future::Cache::builder()
+                .max_capacity(config.max_entries)
+                .time_to_live(config.ttl)
+                .build()
+        }
+
+        Self {
+            conversations: build(config),
+            roles_by_name: build(config),
+            user_roles: build(config),
+        }
+    }
+
+    pub async fn conversation(&self, id: &str) -> Option<Conversation> {
+        let cached = self.conversations.get(id).await;
+        record_lookup("conversation", cached.is_some());
+        cached
+    }
+
+    pub async fn put_conversation(&self, conversation: &Conversation) {
+        self.conversations
+            .insert(conversation.id.clone(), conversation.clone())
+            .await;
+    }
+
+    pub async fn invalidate_conversation(&self, id: &str) {
+        self.conversations.invalidate(id).await;
+    }
+
+    /// Drop every cached conversation, for writes that touch many rows
+    pub fn invalidate_conversations(&self) {
+        self.conversations.invalidate_all();
+    }
+
+    pub async fn role_by_name(&self, name: &str) -> Option<Role> {
+        let cached = self.roles_by_name.get(name).await;
+        record_lookup("role_by_name", cached.is_some());
+        cached
+    }
+
+    pub async fn put_role_by_name(&self, role: &Role) {
+        self.roles_by_name
+            .insert(role.name.clone(), role.clone())
+            .await;
+    }
+
+    pub async fn user_roles(&self, user_id: &str) -> Option<Vec<Role>> {
+        let cached = self.user_roles.get(user_id).await;
+        record_lookup("user_roles", cached.is_some());
+        cached
+    }
+
+    pub async fn put_user_roles(&self, user_id: &str, roles: &[Role]) {
+        self.user_roles
+            .insert(user_id.to_string(), roles.to_vec())
+            .await;
+    }
+
+    pub async fn invalidate_user_roles(&self, user_id: &str) {
+        self.user_roles.invalidate(user_id).await;
+    }
+
+    /// Drop every cached role, for role writes that may be embedded in any user's roles
+    pub fn invalidate_roles(&self) {
+        self.roles_by_name.invalidate_all();
+        self.user_roles.invalidate_all();
+    }
+}
+
+#[cfg(not(feature = "row-cache"))]
+#[derive(Clone)]
+pub struct RowCache;
+
+#[cfg(not(feature = "row-cache"))]
+impl RowCache {
+    pub fn new(_config: &RowCacheConfig) -> Self {
+        Self
+    }
+
+    pub async fn conversation(&self, _id: &str) -> Option<Conversation> {
+        None
+    }
+
+    pub async fn put_conversation(&self, _conversation: &Conversation) {}
+
+    pub async fn invalidate_conversation(&self, _id: &str) {}
+
+    pub fn invalidate_conversations(&self) {}
+
+    pub async fn role_by_name(&self, _name: &str) -> Option<Role> {
+        None
+    }
+
+    pub async fn put_role_by_name(&self, _role: &Role) {}
+
+    pub async fn user_roles(&self, _user_id: &str) -> Option<Vec<Role>> {
+        None
+    }
+
+    pub async fn put_user_roles(&self, _user_id: &str, _roles: &[Role]) {}
+
+    pub async fn invalidate_user_roles(&self, _user_id: &str) {}
+
+    pub fn invalidate_roles(&self) {}
+}
@@ -0,0 +1,278 @@
+use sqlx::Row;
+
+use crate::domain::entities::{Contact, ConversationEventType};
+use crate::domain::ports::channel_repository::ChannelRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::conversation_events::record_column_change;
+use crate::infrastructure::persistence::Database;
+
+impl Database {
+    pub async fn find_channel_contact(
+        &self,
+        inbox_id: &str,
+        external_id: &str,
+    ) -> ApiResult<Option<Contact>> {
+        let row = sqlx::query(
+            "SELECT c.id, c.user_id, c.first_name
+             FROM channel_identities ci
+             JOIN contacts c ON c.id = ci.contact_id
+             WHERE ci.inbox_id = ? AND ci.external_id = ?",
+        )
+        .bind(inbox_id)
+        .bind(external_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if let Some(row) = row {
+            Ok(Some(Contact {
+                id: row.try_get("id")?,
+                user_id: row.try_get("user_id")?,
+                first_name: row.try_get("first_name").ok(),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub async fn find_channel_external_id(
+        &self,
+        inbox_id: &str,
+        contact_id: &str,
+    ) -> ApiResult<Option<String>> {
+        let row = sqlx::query(
+            "SELECT external_id
+             FROM channel_identities
+             WHERE inbox_id = ? AND contact_id = ?
+             ORDER BY created_at DESC
+             LIMIT 1",
+        )
+        .bind(inbox_id)
+        .bind(contact_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(Some(row.try_get("external_id")?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn create_channel_identity(
+        &self,
+        inbox_id: &str,
+        external_id: &str,
+        contact_id: &str,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO channel_identities (inbox_id, external_id, contact_id, created_at)
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(inbox_id)
+        .bind(external_id)
+        .bind(contact_id)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn rename_channel_contact(
+        &self,
+        contact: &Contact,
+        email: &str,
+        name: Option<&str>,
+    ) -> ApiResult<()> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("UPDATE users SET email = ?, updated_at = ? WHERE id = ?")
+            .bind(email)
+            .bind(&now)
+            .bind(&contact.user_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("UPDATE contact_channels SET email = ?, updated_at = ? WHERE contact_id = ?")
+            .bind(email)
+            .bind(&now)
+            .bind(&contact.id)
+            .execute(&mut *tx)
+            .await?;
+        if let Some(name) = name {
+            sqlx::query("UPDATE contacts SET first_name = ? WHERE id = ?")
+                .bind(name)
+                .bind(&contact.id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn merge_channel_contact(&self, from: &Contact, into: &Contact) -> ApiResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let rows = sqlx::query("SELECT id FROM conversations WHERE contact_id = ?")
+            .bind(&from.id)
+            .fetch_all(&mut *tx)
+            .await?;
+        let conversation_ids = rows
+            .iter()
+            .map(|row| row.try_get::<String, _>("id"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Identities the target already has win over the placeholder's
+        sqlx::query(
+            "DELETE FROM channel_identities
+             WHERE contact_id = ?
+               AND EXISTS (
+                   SELECT 1 FROM channel_identities other
+                   WHERE other.contact_id = ?
+                     AND other.inbox_id = channel_identities.inbox_id
+                     AND other.external_id = channel_identities.external_id
+               )",
+        )
+        .bind(&from.id)
+        .bind(&into.id)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("UPDATE channel_identities SET contact_id = ? WHERE contact_id = ?")
+            .bind(&into.id)
+            .bind(&from.id)
+            .execute(&mut *tx)
+            .await?;
+        for conversation_id in &conversation_ids {
+            record_column_change(
+                &mut tx,
+                conversation_id,
+                ConversationEventType::ContactChanged,
+                "contact_id",
+                Some(&into.id),
+                None,
+            )
+            .await?;
+        }
+        sqlx::query("UPDATE conversations SET contact_id = ? WHERE contact_id = ?")
+            .bind(&into.id)
+            .bind(&from.id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("UPDATE messages SET author_id = ? WHERE author_id = ?")
+            .bind(&into.user_id)
+            .bind(&from.user_id)
+            .execute(&mut *tx)
+            .await?;
+        // Cascades to the placeholder contact and its channels
+        sqlx::query("DELETE FROM users WHERE id = ?")
+            .bind(&from.user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        for conversation_id in &conversation_ids {
+            self.cache.invalidate_conversation(conversation_id).await;
+        }
+
+        Ok(())
+    }
+
+    pub async fn find_channel_message_id(
+        &self,
+        inbox_id: &str,
+        external_message_id: &str,
+    ) -> ApiResult<Option<String>> {
+        let row = sqlx::query(
+            "SELECT message_id
+             FROM channel_message_receipts
+             WHERE inbox_id = ? AND external_message_id = ?",
+        )
+        .bind(inbox_id)
+        .bind(external_message_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(Some(row.try_get("message_id")?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn record_channel_message(
+        &self,
+        inbox_id: &str,
+        external_message_id: &str,
+        message_id: &str,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO channel_message_receipts (inbox_id, external_message_id, message_id, created_at)
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(inbox_id)
+        .bind(external_message_id)
+        .bind(message_id)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ChannelRepository for Database {
+    async fn find_channel_contact(
+        &self,
+        inbox_id: &str,
+        external_id: &str,
+    ) -> ApiResult<Option<Contact>> {
+        Database::find_channel_contact(self, inbox_id, external_id).await
+    }
+
+    async fn find_channel_external_id(
+        &self,
+        inbox_id: &str,
+        contact_id: &str,
+    ) -> ApiResult<Option<String>> {
+        Database::find_channel_external_id(self, inbox_id, contact_id).await
+    }
+
+    async fn create_channel_identity(
+        &self,
+        inbox_id: &str,
+        external_id: &str,
+        contact_id: &str,
+    ) -> ApiResult<()> {
+        Database::create_channel_identity(self, inbox_id, external_id, contact_id).await
+    }
+
+    async fn rename_channel_contact(
+        &self,
+        contact: &Contact,
+        email: &str,
+        name: Option<&str>,
+    ) -> ApiResult<()> {
+        Database::rename_channel_contact(self, contact, email, name).await
+    }
+
+    async fn merge_channel_contact(&self, from: &Contact, into: &Contact) -> ApiResult<()> {
+        Database::merge_channel_contact(self, from, into).await
+    }
+
+    async fn find_channel_message_id(
+        &self,
+        inbox_id: &str,
+        external_message_id: &str,
+    ) -> ApiResult<Option<String>> {
+        Database::find_channel_message_id(self, inbox_id, external_message_id).await
+    }
+
+    async fn record_channel_message(
+        &self,
+        inbox_id: &str,
+        external_message_id: &str,
+        message_id: &str,
+    ) -> ApiResult<()> {
+        Database::record_channel_message(self, inbox_id, external_message_id, message_id).await
+    }
+}
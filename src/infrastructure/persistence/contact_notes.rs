@@ -0,0 +1,149 @@
+use sqlx::Row;
+
+use crate::domain::entities::ContactNote;
+use crate::domain::ports::contact_note_repository::ContactNoteRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+/// Note columns plus the author's display name: full name for agents, else email
+const CONTACT_NOTE_SELECT: &str = "SELECT n.id, n.contact_id, n.author_id, n.content, n.pinned,
+            n.pinned_at, n.created_at, n.updated_at,
+            COALESCE(a.first_name || ' ' || a.last_name, a.first_name, u.email) AS author_name
+     FROM contact_notes n
+     LEFT JOIN users u ON u.id = n.author_id
+     LEFT JOIN agents a ON a.user_id = n.author_id";
+
+fn row_to_contact_note(row: &sqlx::any::AnyRow) -> ApiResult<ContactNote> {
+    let pinned: i64 = row.try_get("pinned")?;
+    Ok(ContactNote {
+        id: row.try_get("id")?,
+        contact_id: row.try_get("contact_id")?,
+        author_id: row.try_get("author_id").ok(),
+        author_name: row.try_get("author_name").ok(),
+        content: row.try_get("content")?,
+        pinned: pinned != 0,
+        pinned_at: row.try_get("pinned_at").ok(),
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+impl Database {
+    pub async fn create_contact_note(&self, note: &ContactNote) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO contact_notes (
+                id, contact_id, author_id, content, pinned, pinned_at, created_at, updated_at
+             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&note.id)
+        .bind(&note.contact_id)
+        .bind(&note.author_id)
+        .bind(&note.content)
+        .bind(if note.pinned { 1 } else { 0 })
+        .bind(&note.pinned_at)
+        .bind(&note.created_at)
+        .bind(&note.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_contact_note(&self, id: &str) -> ApiResult<Option<ContactNote>> {
+        let row = sqlx::query(&format!("{} WHERE n.id = ?", CONTACT_NOTE_SELECT))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.as_ref().map(row_to_contact_note).transpose()
+    }
+
+    pub async fn update_contact_note(&self, note: &ContactNote) -> ApiResult<()> {
+        sqlx::query(
+            "UPDATE contact_notes
+             SET content = ?, pinned = ?, pinned_at = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&note.content)
+        .bind(if note.pinned { 1 } else { 0 })
+        .bind(&note.pinned_at)
+        .bind(&note.updated_at)
+        .bind(&note.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_contact_note(&self, id: &str) -> ApiResult<()> {
+        sqlx::query("DELETE FROM contact_notes WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_contact_notes(&self, contact_id: &str) -> ApiResult<Vec<ContactNote>> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE n.contact_id = ?
+             ORDER BY n.pinned DESC, n.pinned_at DESC, n.created_at DESC, n.id DESC",
+            CONTACT_NOTE_SELECT
+        ))
+        .bind(contact_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_contact_note).collect()
+    }
+
+    pub async fn list_conversation_pinned_notes(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<ContactNote>> {
+        // Conversations store the contact record id, notes the contact's user id
+        let rows = sqlx::query(&format!(
+            "{}
+             JOIN contacts c ON c.user_id = n.contact_id
+             JOIN conversations cv ON cv.contact_id = c.id
+             WHERE cv.id = ? AND n.pinned = 1
+             ORDER BY n.pinned_at DESC, n.created_at DESC, n.id DESC",
+            CONTACT_NOTE_SELECT
+        ))
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_contact_note).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl ContactNoteRepository for Database {
+    async fn create_contact_note(&self, note: &ContactNote) -> ApiResult<()> {
+        Database::create_contact_note(self, note).await
+    }
+
+    async fn get_contact_note(&self, id: &str) -> ApiResult<Option<ContactNote>> {
+        Database::get_contact_note(self, id).await
+    }
+
+    async fn update_contact_note(&self, note: &ContactNote) -> ApiResult<()> {
+        Database::update_contact_note(self, note).await
+    }
+
+    async fn delete_contact_note(&self, id: &str) -> ApiResult<()> {
+        Database::delete_contact_note(self, id).await
+    }
+
+    async fn list_contact_notes(&self, contact_id: &str) -> ApiResult<Vec<ContactNote>> {
+        Database::list_contact_notes(self, contact_id).await
+    }
+
+    async fn list_conversation_pinned_notes(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<ContactNote>> {
+        Database::list_conversation_pinned_notes(self, conversation_id).await
+    }
+}
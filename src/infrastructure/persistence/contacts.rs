@@ -1,6 +1,6 @@
 use crate::infrastructure::http::middleware::error::ApiResult;
 use crate::infrastructure::persistence::Database;
-use crate::domain::entities::{Contact, ContactChannel, User, UserType};
+use crate::domain::entities::{Contact, ContactChannel, ContactEmailVerification, User, UserType};
 use sqlx::Row;
 
 use crate::domain::ports::contact_repository::ContactRepository;
@@ -26,23 +26,31 @@ impl ContactRepository for Database {
         Ok(())
     }
 
+    async fn find_contact_by_id(&self, id: &str) -> ApiResult<Option<Contact>> {
+        Database::find_contact_by_id(self, id).await
+    }
+
     async fn find_contact_by_user_id(&self, user_id: &str) -> ApiResult<Option<Contact>> {
         Database::find_contact_by_user_id(self, user_id).await
     }
 
     async fn create_contact_channel(&self, channel: &ContactChannel) -> ApiResult<()> {
         sqlx::query(
-            "INSERT INTO contact_channels (id, contact_id, inbox_id, email, created_at, updated_at)
-             VALUES (?, ?, ?, ?, ?, ?)",
+            "INSERT INTO contact_channels (id, contact_id, inbox_id, email, email_verified, email_verified_at, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&channel.id)
         .bind(&channel.contact_id)
         .bind(&channel.inbox_id)
         .bind(&channel.email)
+        .bind(channel.email_verified)
+        .bind(&channel.email_verified_at)
         .bind(&channel.created_at)
         .bind(&channel.updated_at)
         .execute(&self.pool)
         .await?;
+        // Conversations carry the verification state of their contact's channel
+        self.cache.invalidate_conversations();
 
         Ok(())
     }
@@ -102,13 +110,15 @@ impl ContactRepository for Database {
         let channel =
             ContactChannel::new(contact.id.clone(), inbox_id.to_string(), email.to_string());
         sqlx::query(
-            "INSERT INTO contact_channels (id, contact_id, inbox_id, email, created_at, updated_at)
-             VALUES (?, ?, ?, ?, ?, ?)",
+            "INSERT INTO contact_channels (id, contact_id, inbox_id, email, email_verified, email_verified_at, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&channel.id)
         .bind(&channel.contact_id)
         .bind(&channel.inbox_id)
         .bind(&channel.email)
+        .bind(channel.email_verified)
+        .bind(&channel.email_verified_at)
         .bind(&channel.created_at)
         .bind(&channel.updated_at)
         .execute(&mut *tx)
@@ -138,7 +148,7 @@ impl ContactRepository for Database {
 
     async fn find_contact_channels(&self, contact_id: &str) -> ApiResult<Vec<ContactChannel>> {
         let rows = sqlx::query(
-            "SELECT id, contact_id, inbox_id, email, created_at, updated_at
+            "SELECT id, contact_id, inbox_id, email, email_verified, email_verified_at, created_at, updated_at
              FROM contact_channels
              WHERE contact_id = ?",
         )
@@ -148,14 +158,7 @@ impl ContactRepository for Database {
 
         let mut channels = Vec::new();
         for row in rows {
-            channels.push(ContactChannel {
-                id: row.try_get("id")?,
-                contact_id: row.try_get("contact_id")?,
-                inbox_id: row.try_get("inbox_id")?,
-                email: row.try_get("email")?,
-                created_at: row.try_get("created_at")?,
-                updated_at: row.try_get("updated_at")?,
-            });
+            channels.push(row_to_contact_channel(&row)?);
         }
 
         Ok(channels)
@@ -221,9 +224,115 @@ impl ContactRepository for Database {
             .bind(contact_id)
             .execute(&self.pool)
             .await?;
+        self.cache.invalidate_user_roles(contact_id).await;
 
         Ok(())
     }
+
+    async fn get_contact_channel_by_id(&self, channel_id: &str) -> ApiResult<Option<ContactChannel>> {
+        let row = sqlx::query(
+            "SELECT id, contact_id, inbox_id, email, email_verified, email_verified_at, created_at, updated_at
+             FROM contact_channels
+             WHERE id = ?",
+        )
+        .bind(channel_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| row_to_contact_channel(&row)).transpose()
+    }
+
+    async fn create_email_verification(
+        &self,
+        verification: &ContactEmailVerification,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO contact_email_verifications (id, channel_id, token_hash, expires_at, used, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&verification.id)
+        .bind(&verification.channel_id)
+        .bind(&verification.token_hash)
+        .bind(&verification.expires_at)
+        .bind(verification.used)
+        .bind(&verification.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_email_verification_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> ApiResult<Option<ContactEmailVerification>> {
+        let row = sqlx::query(
+            "SELECT id, channel_id, token_hash, expires_at, used, created_at
+             FROM contact_email_verifications
+             WHERE token_hash = ?",
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        if let Some(row) = row {
+            Ok(Some(ContactEmailVerification {
+                id: row.try_get("id")?,
+                channel_id: row.try_get("channel_id")?,
+                token_hash: row.try_get("token_hash")?,
+                expires_at: row.try_get("expires_at")?,
+                used: row.try_get::<i32, _>("used")? != 0,
+                created_at: row.try_get("created_at")?,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn mark_channel_email_verified(
+        &self,
+        verification_id: &str,
+        channel_id: &str,
+        verified_at: &str,
+    ) -> ApiResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        // Invalidate every outstanding link for the channel, not just the one used
+        sqlx::query("UPDATE contact_email_verifications SET used = 1 WHERE channel_id = ? OR id = ?")
+            .bind(channel_id)
+            .bind(verification_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(
+            "UPDATE contact_channels
+             SET email_verified = 1, email_verified_at = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(verified_at)
+        .bind(verified_at)
+        .bind(channel_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        self.cache.invalidate_conversations();
+
+        Ok(())
+    }
+}
+
+fn row_to_contact_channel(row: &sqlx::any::AnyRow) -> ApiResult<ContactChannel> {
+    Ok(ContactChannel {
+        id: row.try_get("id")?,
+        contact_id: row.try_get("contact_id")?,
+        inbox_id: row.try_get("inbox_id")?,
+        email: row.try_get("email")?,
+        email_verified: row.try_get::<i32, _>("email_verified")? != 0,
+        email_verified_at: row.try_get("email_verified_at").ok(),
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
 }
 
 impl Database {
@@ -232,6 +341,7 @@ impl Database {
             .bind(user_id)
             .execute(&self.pool)
             .await?;
+        self.cache.invalidate_user_roles(user_id).await;
 
         Ok(())
     }
@@ -246,6 +356,26 @@ impl Database {
         Ok(())
     }
 
+    pub async fn find_contact_by_id(&self, id: &str) -> ApiResult<Option<Contact>> {
+        let row = sqlx::query(
+            "SELECT id, user_id, first_name
+             FROM contacts
+             WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| {
+            Ok(Contact {
+                id: row.try_get("id")?,
+                user_id: row.try_get("user_id")?,
+                first_name: row.try_get("first_name").ok(),
+            })
+        })
+        .transpose()
+    }
+
     pub async fn find_contact_by_user_id(&self, user_id: &str) -> ApiResult<Option<Contact>> {
         let row = sqlx::query(
             "SELECT id, user_id, first_name
@@ -0,0 +1,122 @@
+use sqlx::Row;
+
+use crate::domain::entities::ConversationContact;
+use crate::domain::ports::conversation_contact_repository::ConversationContactRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+fn row_to_conversation_contact(row: &sqlx::any::AnyRow) -> ApiResult<ConversationContact> {
+    let optional = |column: &str| row.try_get::<Option<String>, _>(column).ok().flatten();
+
+    Ok(ConversationContact {
+        conversation_id: row.try_get("conversation_id")?,
+        contact_id: row.try_get("contact_id")?,
+        name: optional("name"),
+        email: row.try_get("email")?,
+        is_primary: row.try_get::<i64, _>("is_primary")? != 0,
+        added_by: optional("added_by"),
+        added_at: row.try_get("added_at")?,
+    })
+}
+
+impl Database {
+    pub async fn add_conversation_contact(
+        &self,
+        conversation_id: &str,
+        contact_id: &str,
+        added_by: Option<&str>,
+    ) -> ApiResult<bool> {
+        // The primary contact is never stored as an additional one
+        let result = sqlx::query(
+            "INSERT INTO conversation_contacts (conversation_id, contact_id, added_by, added_at)
+             SELECT ?, ?, ?, ?
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM conversations WHERE id = ? AND contact_id = ?
+             )
+             ON CONFLICT (conversation_id, contact_id) DO NOTHING",
+        )
+        .bind(conversation_id)
+        .bind(contact_id)
+        .bind(added_by)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .bind(conversation_id)
+        .bind(contact_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn remove_conversation_contact(
+        &self,
+        conversation_id: &str,
+        contact_id: &str,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query(
+            "DELETE FROM conversation_contacts WHERE conversation_id = ? AND contact_id = ?",
+        )
+        .bind(conversation_id)
+        .bind(contact_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn list_conversation_contacts(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<ConversationContact>> {
+        let rows = sqlx::query(
+            "SELECT conv.id AS conversation_id, conv.contact_id, c.first_name AS name, u.email,
+                    1 AS is_primary, NULL AS added_by, conv.created_at AS added_at
+             FROM conversations conv
+             JOIN contacts c ON c.id = conv.contact_id
+             JOIN users u ON u.id = c.user_id
+             WHERE conv.id = ?
+             UNION ALL
+             SELECT cc.conversation_id, cc.contact_id, c.first_name AS name, u.email,
+                    0 AS is_primary, cc.added_by, cc.added_at
+             FROM conversation_contacts cc
+             JOIN contacts c ON c.id = cc.contact_id
+             JOIN users u ON u.id = c.user_id
+             WHERE cc.conversation_id = ?
+             ORDER BY is_primary DESC, added_at ASC, contact_id ASC",
+        )
+        .bind(conversation_id)
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_conversation_contact).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl ConversationContactRepository for Database {
+    async fn add_conversation_contact(
+        &self,
+        conversation_id: &str,
+        contact_id: &str,
+        added_by: Option<&str>,
+    ) -> ApiResult<bool> {
+        self.add_conversation_contact(conversation_id, contact_id, added_by)
+            .await
+    }
+
+    async fn remove_conversation_contact(
+        &self,
+        conversation_id: &str,
+        contact_id: &str,
+    ) -> ApiResult<bool> {
+        self.remove_conversation_contact(conversation_id, contact_id)
+            .await
+    }
+
+    async fn list_conversation_contacts(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<ConversationContact>> {
+        self.list_conversation_contacts(conversation_id).await
+    }
+}
@@ -0,0 +1,124 @@
+use crate::domain::entities::{ConversationEvent, ConversationEventType};
+use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
+use crate::infrastructure::persistence::Database;
+use sqlx::{AnyConnection, Row};
+
+// Conversation history is written from inside the transaction of each
+// mutation. Events are inserted with INSERT ... SELECT before the change is
+// applied, so the previous value is read and the write lock taken in the
+// same statement.
+
+/// Record a change of one `conversations` column; nothing is written when
+/// the value is unchanged or the conversation doesn't exist
+pub(super) async fn record_column_change(
+    conn: &mut AnyConnection,
+    conversation_id: &str,
+    event_type: ConversationEventType,
+    column: &str,
+    value: Option<&str>,
+    actor_id: Option<&str>,
+) -> ApiResult<()> {
+    let sql = format!(
+        "INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+         SELECT id, ?, ?, json_object('from', {column}, 'to', ?), ?
+         FROM conversations
+         WHERE id = ? AND {column} IS NOT ?",
+        column = column
+    );
+    sqlx::query(&sql)
+        .bind(event_type.as_str())
+        .bind(actor_id)
+        .bind(value)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .bind(conversation_id)
+        .bind(value)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Record a tag being added to or removed from a conversation
+pub(super) async fn record_tag_event(
+    conn: &mut AnyConnection,
+    conversation_id: &str,
+    event_type: ConversationEventType,
+    tag_id: &str,
+    actor_id: Option<&str>,
+) -> ApiResult<()> {
+    sqlx::query(
+        "INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+         VALUES (?, ?, ?, json_object('tag_id', ?, 'tag', (SELECT name FROM tags WHERE id = ?)), ?)",
+    )
+    .bind(conversation_id)
+    .bind(event_type.as_str())
+    .bind(actor_id)
+    .bind(tag_id)
+    .bind(tag_id)
+    .bind(chrono::Utc::now().to_rfc3339())
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Record an event whose data was built by the caller
+pub(super) async fn record_conversation_event(
+    conn: &mut AnyConnection,
+    conversation_id: &str,
+    event_type: ConversationEventType,
+    actor_id: Option<&str>,
+    data: &serde_json::Value,
+) -> ApiResult<()> {
+    sqlx::query(
+        "INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+         VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(conversation_id)
+    .bind(event_type.as_str())
+    .bind(actor_id)
+    .bind(data.to_string())
+    .bind(chrono::Utc::now().to_rfc3339())
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+impl Database {
+    /// A conversation's history, oldest first
+    pub async fn get_conversation_events(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<ConversationEvent>> {
+        let rows = sqlx::query(
+            "SELECT id, conversation_id, event_type, actor_id, data, created_at
+             FROM conversation_events
+             WHERE conversation_id = ?
+             ORDER BY id",
+        )
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                let event_type: String = row.try_get("event_type")?;
+                let data: String = row.try_get("data")?;
+                Ok(ConversationEvent {
+                    id: row.try_get("id")?,
+                    conversation_id: row.try_get("conversation_id")?,
+                    event_type: event_type.parse().map_err(ApiError::Internal)?,
+                    actor_id: row
+                        .try_get::<Option<String>, _>("actor_id")
+                        .ok()
+                        .flatten(),
+                    data: serde_json::from_str(&data).map_err(|e| {
+                        ApiError::Internal(format!("Invalid conversation event data: {}", e))
+                    })?,
+                    created_at: row.try_get("created_at")?,
+                })
+            })
+            .collect()
+    }
+}
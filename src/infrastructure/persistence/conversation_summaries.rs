@@ -0,0 +1,33 @@
+use crate::domain::ports::conversation_summary_repository::ConversationSummaryRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+impl Database {
+    /// Store a conversation's summary without touching `updated_at`, so
+    /// refreshing it does not reorder the inbox
+    pub async fn update_conversation_summary(
+        &self,
+        conversation_id: &str,
+        summary: &str,
+    ) -> ApiResult<()> {
+        sqlx::query("UPDATE conversations SET summary = ? WHERE id = ?")
+            .bind(summary)
+            .bind(conversation_id)
+            .execute(&self.pool)
+            .await?;
+        self.cache.invalidate_conversation(conversation_id).await;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ConversationSummaryRepository for Database {
+    async fn update_conversation_summary(
+        &self,
+        conversation_id: &str,
+        summary: &str,
+    ) -> ApiResult<()> {
+        Database::update_conversation_summary(self, conversation_id, summary).await
+    }
+}
@@ -0,0 +1,142 @@
+use sqlx::Row;
+
+use crate::domain::entities::{ConversationTransfer, TransferStatus};
+use crate::domain::ports::conversation_transfer_repository::ConversationTransferRepository;
+use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
+use crate::infrastructure::persistence::Database;
+
+const TRANSFER_COLUMNS: &str = "id, conversation_id, from_user_id, from_team_id, to_user_id, \
+     to_team_id, note, requires_acceptance, status, requested_by, responded_by, created_at, \
+     responded_at";
+
+fn row_to_transfer(row: &sqlx::any::AnyRow) -> ApiResult<ConversationTransfer> {
+    let status: String = row.try_get("status")?;
+    let optional = |column: &str| row.try_get::<Option<String>, _>(column).ok().flatten();
+
+    Ok(ConversationTransfer {
+        id: row.try_get("id")?,
+        conversation_id: row.try_get("conversation_id")?,
+        from_user_id: optional("from_user_id"),
+        from_team_id: optional("from_team_id"),
+        to_user_id: optional("to_user_id"),
+        to_team_id: optional("to_team_id"),
+        note: row.try_get("note")?,
+        requires_acceptance: row.try_get::<i64, _>("requires_acceptance")? != 0,
+        status: status.parse().map_err(ApiError::Internal)?,
+        requested_by: row.try_get("requested_by")?,
+        responded_by: optional("responded_by"),
+        created_at: row.try_get("created_at")?,
+        responded_at: optional("responded_at"),
+    })
+}
+
+impl Database {
+    pub async fn create_conversation_transfer(
+        &self,
+        transfer: &ConversationTransfer,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO conversation_transfers (id, conversation_id, from_user_id, from_team_id,
+                 to_user_id, to_team_id, note, requires_acceptance, status, requested_by,
+                 responded_by, created_at, responded_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&transfer.id)
+        .bind(&transfer.conversation_id)
+        .bind(&transfer.from_user_id)
+        .bind(&transfer.from_team_id)
+        .bind(&transfer.to_user_id)
+        .bind(&transfer.to_team_id)
+        .bind(&transfer.note)
+        .bind(transfer.requires_acceptance as i64)
+        .bind(transfer.status.to_string())
+        .bind(&transfer.requested_by)
+        .bind(&transfer.responded_by)
+        .bind(&transfer.created_at)
+        .bind(&transfer.responded_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_conversation_transfer(
+        &self,
+        id: &str,
+    ) -> ApiResult<Option<ConversationTransfer>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM conversation_transfers WHERE id = ?",
+            TRANSFER_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_transfer).transpose()
+    }
+
+    pub async fn list_conversation_transfers(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<ConversationTransfer>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM conversation_transfers
+             WHERE conversation_id = ?
+             ORDER BY created_at DESC, id DESC",
+            TRANSFER_COLUMNS
+        ))
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_transfer).collect()
+    }
+
+    pub async fn respond_to_conversation_transfer(
+        &self,
+        id: &str,
+        status: TransferStatus,
+        responded_by: &str,
+        responded_at: &str,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query(
+            "UPDATE conversation_transfers
+             SET status = ?, responded_by = ?, responded_at = ?
+             WHERE id = ? AND status = 'pending'",
+        )
+        .bind(status.to_string())
+        .bind(responded_by)
+        .bind(responded_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[async_trait::async_trait]
+impl ConversationTransferRepository for Database {
+    async fn create_transfer(&self, transfer: &ConversationTransfer) -> ApiResult<()> {
+        self.create_conversation_transfer(transfer).await
+    }
+
+    async fn get_transfer(&self, id: &str) -> ApiResult<Option<ConversationTransfer>> {
+        self.get_conversation_transfer(id).await
+    }
+
+    async fn list_transfers(&self, conversation_id: &str) -> ApiResult<Vec<ConversationTransfer>> {
+        self.list_conversation_transfers(conversation_id).await
+    }
+
+    async fn respond_to_transfer(
+        &self,
+        id: &str,
+        status: TransferStatus,
+        responded_by: &str,
+        responded_at: &str,
+    ) -> ApiResult<bool> {
+        self.respond_to_conversation_transfer(id, status, responded_by, responded_at)
+            .await
+    }
+}
@@ -1,7 +1,14 @@
 use crate::domain::entities::{
-    AssignmentHistory, Conversation, ConversationStatus, CreateConversation, Priority,
+    AssignmentHistory, Conversation, ConversationCondition, ConversationCountScope,
+    ConversationCounts, ConversationEventType, ConversationListFilter, ConversationSort,
+    ConversationSortField, ConversationStatus, CreateConversation, InboxConversationCount,
+    Priority, SentimentLabel, SentimentTrend, SortDirection, NEGATIVE_SENTIMENT_THRESHOLD,
+    POSITIVE_SENTIMENT_THRESHOLD,
 };
 use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
+use crate::infrastructure::persistence::conversation_events::{
+    record_column_change, record_conversation_event,
+};
 use crate::infrastructure::persistence::Database;
 
 use sqlx::Row;
@@ -9,6 +16,194 @@ use time;
 use tracing;
 use uuid;
 
+type AnyQuery<'q> = sqlx::query::Query<'q, sqlx::Any, sqlx::any::AnyArguments<'q>>;
+
+/// Append WHERE clauses for a conversation list filter
+fn push_filter_clauses(query: &mut String, filter: &ConversationListFilter) {
+    let query_has_status = filter
+        .conditions
+        .iter()
+        .any(|c| matches!(c, ConversationCondition::StatusIn(_)));
+    if filter.status.is_some() {
+        query.push_str(" AND status = ?");
+    } else if !query_has_status {
+        // Junk only shows up when asked for by status
+        query.push_str(" AND status <> 'junk'");
+    }
+    if filter.unresolved {
+        query.push_str(" AND status IN ('open', 'snoozed')");
+    }
+    if filter.inbox_id.is_some() {
+        query.push_str(" AND inbox_id = ?");
+    }
+    if filter.contact_id.is_some() {
+        query.push_str(" AND contact_id = ?");
+    }
+    match filter.sentiment {
+        Some(SentimentLabel::Negative) => query.push_str(" AND sentiment_score <= ?"),
+        Some(SentimentLabel::Positive) => query.push_str(" AND sentiment_score >= ?"),
+        Some(SentimentLabel::Neutral) => {
+            query.push_str(" AND sentiment_score > ? AND sentiment_score < ?")
+        }
+        None => {}
+    }
+    for condition in &filter.conditions {
+        push_condition_clause(query, condition);
+    }
+}
+
+/// Append the WHERE clause of a search query condition
+fn push_condition_clause(query: &mut String, condition: &ConversationCondition) {
+    match condition {
+        ConversationCondition::StatusIn(statuses) => query.push_str(&format!(
+            " AND status IN ({})",
+            placeholders(statuses.len())
+        )),
+        ConversationCondition::PriorityRank(op, _) => {
+            query.push_str(&format!(" AND {} {} ?", PRIORITY_RANK, op.as_sql()))
+        }
+        ConversationCondition::TagIn(names) => query.push_str(&format!(
+            " AND EXISTS (
+                SELECT 1 FROM conversation_tags ct
+                JOIN tags t ON t.id = ct.tag_id
+                WHERE ct.conversation_id = conversations.id AND LOWER(t.name) IN ({})
+            )",
+            placeholders(names.len())
+        )),
+        ConversationCondition::AssignedUser(Some(_)) => query.push_str(" AND assigned_user_id = ?"),
+        ConversationCondition::AssignedUser(None) => {
+            query.push_str(" AND assigned_user_id IS NULL")
+        }
+        ConversationCondition::AssignedTeam(Some(_)) => query.push_str(" AND assigned_team_id = ?"),
+        ConversationCondition::AssignedTeam(None) => {
+            query.push_str(" AND assigned_team_id IS NULL")
+        }
+        ConversationCondition::Inbox(_) => query.push_str(" AND inbox_id = ?"),
+        ConversationCondition::Contact(_) => query.push_str(" AND contact_id = ?"),
+        // The updated_at trigger writes `YYYY-MM-DD HH:MM:SS` while the app writes
+        // RFC 3339, so compare normalized values rather than raw strings
+        ConversationCondition::Timestamp(timestamp, op, _) => query.push_str(&format!(
+            " AND datetime(conversations.{}) {} datetime(?)",
+            timestamp.column(),
+            op.as_sql()
+        )),
+        ConversationCondition::Text(_) => query.push_str(
+            " AND (subject LIKE ? ESCAPE '\\' OR EXISTS (
+                SELECT 1 FROM messages m
+                WHERE m.conversation_id = conversations.id AND m.content LIKE ? ESCAPE '\\'
+            ))",
+        ),
+    }
+}
+
+fn placeholders(count: usize) -> String {
+    vec!["?"; count].join(", ")
+}
+
+/// Rank of a conversation's priority, highest first when sorted descending
+///
+/// Must match the expression of the `idx_conversations_priority_rank` index.
+const PRIORITY_RANK: &str =
+    "CASE priority WHEN 'High' THEN 3 WHEN 'Medium' THEN 2 WHEN 'Low' THEN 1 ELSE 0 END";
+
+/// Earliest unmet SLA deadline of each conversation
+const NEXT_SLA_DEADLINE_JOIN: &str = " LEFT JOIN (
+        SELECT a.conversation_id, MIN(e.deadline_at) AS next_deadline_at
+        FROM applied_slas a
+        JOIN sla_events e ON e.applied_sla_id = a.id
+        WHERE e.status <> 'met'
+        GROUP BY a.conversation_id
+    ) sla ON sla.conversation_id = conversations.id";
+
+/// ORDER BY clause for a conversation list, ending in a unique key so that
+/// pagination is stable
+fn order_clause(sort: ConversationSort) -> String {
+    let direction = match sort.direction {
+        SortDirection::Asc => "ASC",
+        SortDirection::Desc => "DESC",
+    };
+    match sort.field {
+        ConversationSortField::CreatedAt => {
+            format!(" ORDER BY created_at {0}, id {0}", direction)
+        }
+        ConversationSortField::LastMessageAt => format!(
+            " ORDER BY last_message_at {} NULLS LAST, created_at DESC, id DESC",
+            direction
+        ),
+        ConversationSortField::Priority => format!(
+            " ORDER BY {} {}, created_at DESC, id DESC",
+            PRIORITY_RANK, direction
+        ),
+        ConversationSortField::SlaTimeToBreach => format!(
+            " ORDER BY sla.next_deadline_at {} NULLS LAST, created_at DESC, id DESC",
+            direction
+        ),
+        ConversationSortField::ReferenceNumber => {
+            format!(" ORDER BY reference_number {}", direction)
+        }
+    }
+}
+
+/// Bind parameters in the order `push_filter_clauses` added them
+fn bind_filter<'q>(mut query: AnyQuery<'q>, filter: &ConversationListFilter) -> AnyQuery<'q> {
+    if let Some(ref status) = filter.status {
+        query = query.bind(status.to_string());
+    }
+    if let Some(ref inbox_id) = filter.inbox_id {
+        query = query.bind(inbox_id.clone());
+    }
+    if let Some(ref contact_id) = filter.contact_id {
+        query = query.bind(contact_id.clone());
+    }
+    match filter.sentiment {
+        Some(SentimentLabel::Negative) => query = query.bind(NEGATIVE_SENTIMENT_THRESHOLD),
+        Some(SentimentLabel::Positive) => query = query.bind(POSITIVE_SENTIMENT_THRESHOLD),
+        Some(SentimentLabel::Neutral) => {
+            query = query
+                .bind(NEGATIVE_SENTIMENT_THRESHOLD)
+                .bind(POSITIVE_SENTIMENT_THRESHOLD)
+        }
+        None => {}
+    }
+    for condition in &filter.conditions {
+        query = bind_condition(query, condition);
+    }
+    query
+}
+
+/// Bind parameters in the order `push_condition_clause` added them
+fn bind_condition<'q>(mut query: AnyQuery<'q>, condition: &ConversationCondition) -> AnyQuery<'q> {
+    match condition {
+        ConversationCondition::StatusIn(statuses) => {
+            for status in statuses {
+                query = query.bind(status.to_string());
+            }
+        }
+        ConversationCondition::PriorityRank(_, rank) => query = query.bind(*rank),
+        ConversationCondition::TagIn(names) => {
+            for name in names {
+                query = query.bind(name.to_lowercase());
+            }
+        }
+        ConversationCondition::AssignedUser(Some(id))
+        | ConversationCondition::AssignedTeam(Some(id))
+        | ConversationCondition::Inbox(id)
+        | ConversationCondition::Contact(id) => query = query.bind(id.clone()),
+        ConversationCondition::AssignedUser(None) | ConversationCondition::AssignedTeam(None) => {}
+        ConversationCondition::Timestamp(_, _, instant) => query = query.bind(instant.clone()),
+        ConversationCondition::Text(text) => {
+            let pattern = format!(
+                "%{}%",
+                text.replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_")
+            );
+            query = query.bind(pattern.clone()).bind(pattern);
+        }
+    }
+    query
+}
+
 impl Database {
     // Conversation operations
     #[tracing::instrument(skip(self))]
@@ -29,6 +224,7 @@ impl Database {
         let conversation_id = uuid::Uuid::new_v4().to_string();
 
         // Insert the conversation
+        let mut tx = self.pool.begin().await?;
         sqlx::query(
             "INSERT INTO conversations (id, reference_number, status, inbox_id, contact_id, subject, created_at, updated_at)
              VALUES (?, (SELECT COALESCE(MAX(reference_number), 99) + 1 FROM conversations), 'open', ?, ?, ?, datetime('now'), datetime('now'))",
@@ -37,8 +233,24 @@ impl Database {
         .bind(&create.inbox_id)
         .bind(&create.contact_id)
         .bind(subject_value)
-        .execute(&self.pool)
+        .execute(&mut *tx)
+        .await?;
+        record_conversation_event(
+            &mut tx,
+            &conversation_id,
+            ConversationEventType::Created,
+            None,
+            &serde_json::json!({
+                "status": ConversationStatus::Open.to_string(),
+                "inbox_id": create.inbox_id,
+                "contact_id": create.contact_id,
+                "assigned_user_id": null,
+                "assigned_team_id": null,
+                "priority": null,
+            }),
+        )
         .await?;
+        tx.commit().await?;
 
         // Fetch the created conversation using the generated ID
         let row = sqlx::query(
@@ -71,6 +283,11 @@ impl Database {
             version: row.try_get("version")?,
             tags: None,
             priority: None,
+            contact_email_verified: None,
+            csat_score: None,
+            sentiment_score: None,
+            sentiment_trend: None,
+            summary: None,
         };
 
         tracing::info!(
@@ -85,10 +302,21 @@ impl Database {
 
     #[tracing::instrument(skip(self))]
     pub async fn get_conversation_by_id(&self, id: &str) -> ApiResult<Option<Conversation>> {
+        if let Some(conversation) = self.cache.conversation(id).await {
+            return Ok(Some(conversation));
+        }
+
         let row = sqlx::query(
             "SELECT id, reference_number, status, inbox_id, contact_id, subject,
                     resolved_at, closed_at, snoozed_until, assigned_user_id, assigned_team_id,
-                    assigned_at, assigned_by, created_at, updated_at, version, priority
+                    assigned_at, assigned_by, created_at, updated_at, version, priority,
+                    (SELECT MIN(cc.email_verified) FROM contact_channels cc
+                     WHERE cc.contact_id = conversations.contact_id
+                       AND cc.inbox_id = conversations.inbox_id) AS contact_email_verified,
+                    (SELECT cr.score FROM csat_responses cr
+                     WHERE cr.conversation_id = conversations.id
+                     ORDER BY cr.created_at DESC LIMIT 1) AS csat_score,
+                    sentiment_score, sentiment_trend, summary
              FROM conversations
              WHERE id = ?",
         )
@@ -120,7 +348,24 @@ impl Database {
                     .ok()
                     .flatten()
                     .map(Priority::from),
+                contact_email_verified: row
+                    .try_get::<Option<i32>, _>("contact_email_verified")
+                    .ok()
+                    .flatten()
+                    .map(|verified| verified != 0),
+                csat_score: row
+                    .try_get::<Option<i32>, _>("csat_score")
+                    .ok()
+                    .flatten(),
+                sentiment_score: row.try_get::<Option<f64>, _>("sentiment_score").ok().flatten(),
+                sentiment_trend: row
+                    .try_get::<Option<String>, _>("sentiment_trend")
+                    .ok()
+                    .flatten()
+                    .map(SentimentTrend::from),
+                summary: row.try_get::<Option<String>, _>("summary").ok().flatten(),
             };
+            self.cache.put_conversation(&conversation).await;
             Ok(Some(conversation))
         } else {
             Ok(None)
@@ -165,6 +410,11 @@ impl Database {
                     .ok()
                     .flatten()
                     .map(Priority::from),
+                contact_email_verified: None,
+                csat_score: None,
+                sentiment_score: None,
+                sentiment_trend: None,
+                summary: None,
             };
             Ok(Some(conversation))
         } else {
@@ -185,6 +435,16 @@ impl Database {
         // but can be added if we pass expected_version.
         // For now, simple update.
 
+        let mut tx = self.pool.begin().await?;
+        record_column_change(
+            &mut tx,
+            id,
+            ConversationEventType::StatusChanged,
+            "status",
+            Some(&status.to_string()),
+            None,
+        )
+        .await?;
         sqlx::query(
             "UPDATE conversations
              SET status = ?, resolved_at = ?, closed_at = ?, snoozed_until = ?, version = version + 1
@@ -195,8 +455,10 @@ impl Database {
         .bind(closed_at)
         .bind(snoozed_until)
         .bind(id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
+        self.cache.invalidate_conversation(id).await;
 
         self.get_conversation_by_id(id)
             .await?
@@ -237,6 +499,11 @@ impl Database {
                     .ok()
                     .flatten()
                     .map(Priority::from),
+                contact_email_verified: None,
+                csat_score: None,
+                sentiment_score: None,
+                sentiment_trend: None,
+                summary: None,
             };
             Ok(Some(conversation))
         } else {
@@ -250,45 +517,26 @@ impl Database {
         &self,
         limit: i64,
         offset: i64,
-        status: Option<ConversationStatus>,
-        inbox_id: Option<String>,
-        contact_id: Option<String>,
+        filter: &ConversationListFilter,
     ) -> ApiResult<Vec<Conversation>> {
         let mut query = String::from(
             "SELECT id, reference_number, status, inbox_id, contact_id, subject,
-                    resolved_at, snoozed_until, created_at, updated_at, version, priority
-             FROM conversations
-             WHERE 1=1",
+                    resolved_at, snoozed_until, created_at, updated_at, version, priority,
+                    sentiment_score, sentiment_trend, summary
+             FROM conversations",
         );
-
-        // Add filters
-        if status.is_some() {
-            query.push_str(" AND status = ?");
-        }
-        if inbox_id.is_some() {
-            query.push_str(" AND inbox_id = ?");
-        }
-        if contact_id.is_some() {
-            query.push_str(" AND contact_id = ?");
+        if filter.sort.field == ConversationSortField::SlaTimeToBreach {
+            query.push_str(NEXT_SLA_DEADLINE_JOIN);
         }
+        query.push_str(" WHERE 1=1");
+        push_filter_clauses(&mut query, filter);
+        query.push_str(&order_clause(filter.sort));
+        query.push_str(" LIMIT ? OFFSET ?");
 
-        query.push_str(" ORDER BY created_at DESC LIMIT ? OFFSET ?");
-
-        let mut sql_query = sqlx::query(&query);
-
-        // Bind filter parameters
-        if let Some(s) = status {
-            sql_query = sql_query.bind(s.to_string());
-        }
-        if let Some(inbox) = inbox_id {
-            sql_query = sql_query.bind(inbox);
-        }
-        if let Some(contact) = contact_id {
-            sql_query = sql_query.bind(contact);
-        }
-
-        // Bind pagination parameters
-        sql_query = sql_query.bind(limit).bind(offset);
+        // Bind filter parameters, then pagination
+        let sql_query = bind_filter(sqlx::query(&query), filter)
+            .bind(limit)
+            .bind(offset);
 
         let rows = sql_query.fetch_all(&self.pool).await?;
 
@@ -319,6 +567,15 @@ impl Database {
                     .ok()
                     .flatten()
                     .map(Priority::from),
+                contact_email_verified: None,
+                csat_score: None,
+                sentiment_score: row.try_get::<Option<f64>, _>("sentiment_score").ok().flatten(),
+                sentiment_trend: row
+                    .try_get::<Option<String>, _>("sentiment_trend")
+                    .ok()
+                    .flatten()
+                    .map(SentimentTrend::from),
+                summary: row.try_get::<Option<String>, _>("summary").ok().flatten(),
             };
             conversations.push(conversation);
         }
@@ -327,41 +584,78 @@ impl Database {
     }
 
     /// Count total conversations with optional filters
-    pub async fn count_conversations(
-        &self,
-        status: Option<ConversationStatus>,
-        inbox_id: Option<String>,
-        contact_id: Option<String>,
-    ) -> ApiResult<i64> {
+    pub async fn count_conversations(&self, filter: &ConversationListFilter) -> ApiResult<i64> {
         let mut query = String::from("SELECT COUNT(*) as count FROM conversations WHERE 1=1");
+        push_filter_clauses(&mut query, filter);
 
-        if status.is_some() {
-            query.push_str(" AND status = ?");
-        }
-        if inbox_id.is_some() {
-            query.push_str(" AND inbox_id = ?");
-        }
-        if contact_id.is_some() {
-            query.push_str(" AND contact_id = ?");
-        }
+        let row = bind_filter(sqlx::query(&query), filter)
+            .fetch_one(&self.pool)
+            .await?;
+        use sqlx::Row;
+        let count: i64 = row.try_get("count")?;
 
-        let mut sql_query = sqlx::query(&query);
+        Ok(count)
+    }
 
-        if let Some(s) = status {
-            sql_query = sql_query.bind(s.to_string());
-        }
-        if let Some(inbox) = inbox_id {
-            sql_query = sql_query.bind(inbox);
+    /// Sidebar counters over open conversations, aggregated per inbox in one query
+    pub async fn count_open_conversations_for_sidebar(
+        &self,
+        user_id: &str,
+        scope: &ConversationCountScope,
+        now: &str,
+        breaching_before: &str,
+    ) -> ApiResult<ConversationCounts> {
+        let mut query = String::from(
+            "SELECT c.inbox_id,
+                    COUNT(*) AS open_count,
+                    SUM(CASE WHEN c.assigned_user_id = ? THEN 1 ELSE 0 END) AS mine,
+                    SUM(CASE WHEN c.assigned_user_id IS NULL THEN 1 ELSE 0 END) AS unassigned,
+                    SUM(CASE WHEN c.priority = 'High' THEN 1 ELSE 0 END) AS urgent,
+                    SUM(CASE WHEN sla.next_deadline_at > ? AND sla.next_deadline_at <= ?
+                        THEN 1 ELSE 0 END) AS breaching_soon
+             FROM conversations c
+             LEFT JOIN (
+                 SELECT a.conversation_id, MIN(e.deadline_at) AS next_deadline_at
+                 FROM applied_slas a
+                 JOIN sla_events e ON e.applied_sla_id = a.id
+                 WHERE e.status = 'pending'
+                 GROUP BY a.conversation_id
+             ) sla ON sla.conversation_id = c.id
+             WHERE c.status = 'open'",
+        );
+        if let ConversationCountScope::Assigned { team_ids } = scope {
+            query.push_str(" AND (c.assigned_user_id = ?");
+            if !team_ids.is_empty() {
+                let placeholders = vec!["?"; team_ids.len()].join(", ");
+                query.push_str(&format!(" OR c.assigned_team_id IN ({})", placeholders));
+            }
+            query.push(')');
         }
-        if let Some(contact) = contact_id {
-            sql_query = sql_query.bind(contact);
+        query.push_str(" GROUP BY c.inbox_id ORDER BY c.inbox_id");
+
+        let mut sql_query = sqlx::query(&query)
+            .bind(user_id)
+            .bind(now)
+            .bind(breaching_before);
+        if let ConversationCountScope::Assigned { team_ids } = scope {
+            sql_query = sql_query.bind(user_id);
+            for team_id in team_ids {
+                sql_query = sql_query.bind(team_id.as_str());
+            }
         }
 
-        let row = sql_query.fetch_one(&self.pool).await?;
-        use sqlx::Row;
-        let count: i64 = row.try_get("count")?;
-
-        Ok(count)
+        let mut counts = ConversationCounts::default();
+        for row in sql_query.fetch_all(&self.pool).await? {
+            counts.mine += row.try_get::<i64, _>("mine")?;
+            counts.unassigned += row.try_get::<i64, _>("unassigned")?;
+            counts.urgent += row.try_get::<i64, _>("urgent")?;
+            counts.breaching_soon += row.try_get::<i64, _>("breaching_soon")?;
+            counts.inboxes.push(InboxConversationCount {
+                inbox_id: row.try_get("inbox_id")?,
+                open: row.try_get("open_count")?,
+            });
+        }
+        Ok(counts)
     }
 
     /// Set conversation priority (for automation rules)
@@ -374,6 +668,16 @@ impl Database {
             .format(&time::format_description::well_known::Rfc3339)
             .unwrap();
 
+        let mut tx = self.pool.begin().await?;
+        record_column_change(
+            &mut tx,
+            conversation_id,
+            ConversationEventType::PriorityChanged,
+            "priority",
+            Some(&priority.to_string()),
+            None,
+        )
+        .await?;
         sqlx::query(
             "UPDATE conversations
              SET priority = ?, updated_at = ?
@@ -382,12 +686,14 @@ impl Database {
         .bind(priority.to_string())
         .bind(&now)
         .bind(conversation_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| {
             eprintln!("Database error setting conversation priority: {:?}", e);
             ApiError::Internal(format!("Database error: {}", e))
         })?;
+        tx.commit().await?;
+        self.cache.invalidate_conversation(conversation_id).await;
 
         tracing::info!(
             "Set priority to '{}' for conversation {}",
@@ -404,6 +710,16 @@ impl Database {
             .format(&time::format_description::well_known::Rfc3339)
             .unwrap();
 
+        let mut tx = self.pool.begin().await?;
+        record_column_change(
+            &mut tx,
+            conversation_id,
+            ConversationEventType::PriorityChanged,
+            "priority",
+            None,
+            None,
+        )
+        .await?;
         sqlx::query(
             "UPDATE conversations
              SET priority = NULL, updated_at = ?
@@ -411,12 +727,14 @@ impl Database {
         )
         .bind(&now)
         .bind(conversation_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| {
             eprintln!("Database error clearing conversation priority: {:?}", e);
             ApiError::Internal(format!("Database error: {}", e))
         })?;
+        tx.commit().await?;
+        self.cache.invalidate_conversation(conversation_id).await;
 
         tracing::info!("Cleared priority for conversation {}", conversation_id);
 
@@ -441,6 +759,17 @@ impl Database {
             None
         };
 
+        let mut tx = self.pool.begin().await?;
+        record_column_change(
+            &mut tx,
+            conversation_id,
+            ConversationEventType::StatusChanged,
+            "status",
+            Some(&status.to_string()),
+            None,
+        )
+        .await?;
+
         // Clear resolved_at if not resolved
         if status != ConversationStatus::Resolved {
             sqlx::query(
@@ -451,7 +780,7 @@ impl Database {
             .bind(status.to_string())
             .bind(&now)
             .bind(conversation_id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await
             .map_err(|e| {
                 eprintln!("Database error updating conversation status: {:?}", e);
@@ -467,13 +796,15 @@ impl Database {
             .bind(&resolved_at)
             .bind(&now)
             .bind(conversation_id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await
             .map_err(|e| {
                 eprintln!("Database error updating conversation status: {:?}", e);
                 ApiError::Internal(format!("Database error: {}", e))
             })?;
         }
+        tx.commit().await?;
+        self.cache.invalidate_conversation(conversation_id).await;
 
         tracing::info!(
             "Updated status to {:?} for conversation {}",
@@ -496,6 +827,16 @@ impl Database {
             .format(&time::format_description::well_known::Rfc3339)
             .unwrap();
 
+        let mut tx = self.pool.begin().await?;
+        record_column_change(
+            &mut tx,
+            conversation_id,
+            ConversationEventType::AssigneeChanged,
+            "assigned_user_id",
+            user_id.as_deref(),
+            assigned_by.as_deref(),
+        )
+        .await?;
         sqlx::query(
             "UPDATE conversations
              SET assigned_user_id = ?, assigned_by = ?, assigned_at = ?, updated_at = ?
@@ -506,12 +847,61 @@ impl Database {
         .bind(&now)
         .bind(&now)
         .bind(conversation_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
+        self.cache.invalidate_conversation(conversation_id).await;
 
         Ok(())
     }
 
+    /// Assign the conversation to `user_id` only while nobody holds it; of two
+    /// concurrent claims only one succeeds. Returns whether this call assigned it
+    pub async fn claim_unassigned_conversation(
+        &self,
+        conversation_id: &str,
+        user_id: &str,
+        assigned_by: Option<String>,
+    ) -> ApiResult<bool> {
+        let now = time::OffsetDateTime::now_utc()
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap();
+
+        let mut tx = self.pool.begin().await?;
+        record_column_change(
+            &mut tx,
+            conversation_id,
+            ConversationEventType::AssigneeChanged,
+            "assigned_user_id",
+            Some(user_id),
+            assigned_by.as_deref(),
+        )
+        .await?;
+        let claimed = sqlx::query(
+            "UPDATE conversations
+             SET assigned_user_id = ?, assigned_by = ?, assigned_at = ?, updated_at = ?
+             WHERE id = ? AND assigned_user_id IS NULL",
+        )
+        .bind(user_id)
+        .bind(assigned_by)
+        .bind(&now)
+        .bind(&now)
+        .bind(conversation_id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected()
+            == 1;
+        if !claimed {
+            // Someone else got there first; drop the event recorded above
+            tx.rollback().await?;
+            return Ok(false);
+        }
+        tx.commit().await?;
+        self.cache.invalidate_conversation(conversation_id).await;
+
+        Ok(true)
+    }
+
     pub async fn assign_conversation_to_team(
         &self,
         conversation_id: &str,
@@ -522,6 +912,16 @@ impl Database {
             .format(&time::format_description::well_known::Rfc3339)
             .unwrap();
 
+        let mut tx = self.pool.begin().await?;
+        record_column_change(
+            &mut tx,
+            conversation_id,
+            ConversationEventType::TeamChanged,
+            "assigned_team_id",
+            team_id.as_deref(),
+            assigned_by.as_deref(),
+        )
+        .await?;
         sqlx::query(
             "UPDATE conversations
              SET assigned_team_id = ?, assigned_by = ?, assigned_at = ?, updated_at = ?
@@ -532,8 +932,10 @@ impl Database {
         .bind(&now)
         .bind(&now)
         .bind(conversation_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
+        self.cache.invalidate_conversation(conversation_id).await;
 
         Ok(())
     }
@@ -615,6 +1017,11 @@ impl Database {
                     .ok()
                     .flatten()
                     .map(Priority::from),
+                contact_email_verified: None,
+                csat_score: None,
+                sentiment_score: None,
+                sentiment_trend: None,
+                summary: row.try_get::<Option<String>, _>("summary").ok().flatten(),
             };
             conversations.push(conversation);
         }
@@ -675,6 +1082,11 @@ impl Database {
                     .ok()
                     .flatten()
                     .map(Priority::from),
+                contact_email_verified: None,
+                csat_score: None,
+                sentiment_score: None,
+                sentiment_trend: None,
+                summary: row.try_get::<Option<String>, _>("summary").ok().flatten(),
             };
             conversations.push(conversation);
         }
@@ -735,6 +1147,11 @@ impl Database {
                     .ok()
                     .flatten()
                     .map(Priority::from),
+                contact_email_verified: None,
+                csat_score: None,
+                sentiment_score: None,
+                sentiment_trend: None,
+                summary: row.try_get::<Option<String>, _>("summary").ok().flatten(),
             };
             conversations.push(conversation);
         }
@@ -746,6 +1163,18 @@ impl Database {
             .format(&time::format_description::well_known::Rfc3339)
             .unwrap();
 
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+             SELECT id, ?, NULL, json_object('from', assigned_user_id, 'to', NULL), ?
+             FROM conversations
+             WHERE assigned_user_id = ? AND status = 'open'",
+        )
+        .bind(ConversationEventType::AssigneeChanged.as_str())
+        .bind(&now)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
         let result = sqlx::query(
             "UPDATE conversations
              SET assigned_user_id = NULL, assigned_at = NULL, updated_at = ?
@@ -753,8 +1182,10 @@ impl Database {
         )
         .bind(&now)
         .bind(user_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
+        self.cache.invalidate_conversations();
 
         Ok(result.rows_affected())
     }
@@ -764,6 +1195,16 @@ impl Database {
             .format(&time::format_description::well_known::Rfc3339)
             .unwrap();
 
+        let mut tx = self.pool.begin().await?;
+        record_column_change(
+            &mut tx,
+            conversation_id,
+            ConversationEventType::AssigneeChanged,
+            "assigned_user_id",
+            None,
+            None,
+        )
+        .await?;
         sqlx::query(
             "UPDATE conversations
              SET assigned_user_id = NULL, assigned_at = NULL, updated_at = ?
@@ -771,8 +1212,10 @@ impl Database {
         )
         .bind(&now)
         .bind(conversation_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
+        self.cache.invalidate_conversation(conversation_id).await;
 
         Ok(())
     }
@@ -783,8 +1226,8 @@ impl Database {
             .unwrap();
 
         sqlx::query(
-            "INSERT INTO assignment_history (id, conversation_id, assigned_user_id, assigned_team_id, assigned_by, assigned_at)
-             VALUES (?, ?, ?, ?, ?, ?)",
+            "INSERT INTO assignment_history (id, conversation_id, assigned_user_id, assigned_team_id, assigned_by, assigned_at, source, rule_id, reason)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&history.id)
         .bind(&history.conversation_id)
@@ -792,6 +1235,9 @@ impl Database {
         .bind(&history.assigned_team_id)
         .bind(&history.assigned_by)
         .bind(&history.assigned_at)
+        .bind(history.source.to_string())
+        .bind(&history.rule_id)
+        .bind(&history.reason)
         .execute(&self.pool)
         .await?;
 
@@ -822,6 +1268,12 @@ impl Database {
                 assigned_by: row.try_get("assigned_by")?,
                 assigned_at: row.try_get("assigned_at")?,
                 unassigned_at: row.try_get("unassigned_at").ok(),
+                source: row
+                    .try_get::<String, _>("source")?
+                    .parse()
+                    .map_err(ApiError::Internal)?,
+                rule_id: row.try_get("rule_id").ok(),
+                reason: row.try_get("reason").ok(),
             });
         }
         Ok(history)
@@ -897,20 +1349,24 @@ impl ConversationRepository for Database {
         &self,
         limit: i64,
         offset: i64,
-        status: Option<ConversationStatus>,
-        inbox_id: Option<String>,
-        contact_id: Option<String>,
+        filter: &ConversationListFilter,
     ) -> ApiResult<Vec<Conversation>> {
-        Database::list_conversations(self, limit, offset, status, inbox_id, contact_id).await
+        Database::list_conversations(self, limit, offset, filter).await
     }
 
-    async fn count_conversations(
+    async fn count_conversations(&self, filter: &ConversationListFilter) -> ApiResult<i64> {
+        Database::count_conversations(self, filter).await
+    }
+
+    async fn count_open_conversations_for_sidebar(
         &self,
-        status: Option<ConversationStatus>,
-        inbox_id: Option<String>,
-        contact_id: Option<String>,
-    ) -> ApiResult<i64> {
-        Database::count_conversations(self, status, inbox_id, contact_id).await
+        user_id: &str,
+        scope: &ConversationCountScope,
+        now: &str,
+        breaching_before: &str,
+    ) -> ApiResult<ConversationCounts> {
+        Database::count_open_conversations_for_sidebar(self, user_id, scope, now, breaching_before)
+            .await
     }
 
     async fn set_conversation_priority(
@@ -935,6 +1391,15 @@ impl ConversationRepository for Database {
         Database::assign_conversation_to_user(self, conversation_id, user_id, assigned_by).await
     }
 
+    async fn claim_unassigned_conversation(
+        &self,
+        conversation_id: &str,
+        user_id: &str,
+        assigned_by: Option<String>,
+    ) -> ApiResult<bool> {
+        Database::claim_unassigned_conversation(self, conversation_id, user_id, assigned_by).await
+    }
+
     async fn assign_conversation_to_team(
         &self,
         conversation_id: &str,
@@ -998,6 +1463,13 @@ impl ConversationRepository for Database {
         Database::get_assignment_history(self, conversation_id).await
     }
 
+    async fn get_conversation_events(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<crate::domain::entities::ConversationEvent>> {
+        Database::get_conversation_events(self, conversation_id).await
+    }
+
     async fn find_contact_by_user_id(
         &self,
         user_id: &str,
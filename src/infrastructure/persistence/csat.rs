@@ -0,0 +1,62 @@
+use sqlx::Row;
+
+use crate::domain::entities::CsatResponse;
+use crate::domain::ports::csat_repository::CsatRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+fn row_to_csat_response(row: &sqlx::any::AnyRow) -> ApiResult<CsatResponse> {
+    Ok(CsatResponse {
+        id: row.try_get("id")?,
+        conversation_id: row.try_get("conversation_id")?,
+        score: row.try_get("score")?,
+        comment: row.try_get("comment").ok(),
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+impl Database {
+    pub async fn create_csat_response(&self, response: &CsatResponse) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO csat_responses (id, conversation_id, score, comment, created_at)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&response.id)
+        .bind(&response.conversation_id)
+        .bind(response.score)
+        .bind(&response.comment)
+        .bind(&response.created_at)
+        .execute(&self.pool)
+        .await?;
+        self.cache
+            .invalidate_conversation(&response.conversation_id)
+            .await;
+
+        Ok(())
+    }
+
+    pub async fn list_csat_responses(&self, conversation_id: &str) -> ApiResult<Vec<CsatResponse>> {
+        let rows = sqlx::query(
+            "SELECT id, conversation_id, score, comment, created_at
+             FROM csat_responses
+             WHERE conversation_id = ?
+             ORDER BY created_at DESC",
+        )
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_csat_response).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl CsatRepository for Database {
+    async fn create_csat_response(&self, response: &CsatResponse) -> ApiResult<()> {
+        Database::create_csat_response(self, response).await
+    }
+
+    async fn list_csat_responses(&self, conversation_id: &str) -> ApiResult<Vec<CsatResponse>> {
+        Database::list_csat_responses(self, conversation_id).await
+    }
+}
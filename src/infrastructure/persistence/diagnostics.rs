@@ -0,0 +1,333 @@
+/// Slow-query diagnostics
+///
+/// sqlx reports statements that run longer than the connection's slow
+/// threshold as `sqlx::query` tracing events. `SlowQueryLayer` picks those
+/// events up and forwards them to the `SlowQueryLog` of the connected
+/// database, which aggregates them per statement in memory. The first time a
+/// statement is seen its `EXPLAIN QUERY PLAN` output is captured and logged
+/// alongside it, so a missing index shows up as a `SCAN` in the log.
+///
+/// Disabled unless `SLOW_QUERY_LOG_ENABLED` is set; nothing is recorded and
+/// the slow threshold stays at sqlx's one second warning.
+use crate::domain::entities::{SlowQuery, SlowQueryReport};
+use crate::domain::ports::query_diagnostics::QueryDiagnostics;
+use crate::infrastructure::persistence::Database;
+use chrono::Utc;
+use sqlx::{AnyPool, Row};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+/// Where sqlx reports slow statements that the layer forwards to; replaced
+/// by each `SlowQueryLog::watch` so the most recent database wins
+static SLOW_STATEMENTS: RwLock<Option<mpsc::UnboundedSender<SlowStatement>>> = RwLock::new(None);
+
+#[derive(Debug, Clone)]
+pub struct SlowQueryConfig {
+    pub enabled: bool,
+    /// Statements taking at least this long are recorded
+    pub threshold: Duration,
+    /// Distinct statements kept; the cheapest is evicted when full
+    pub max_entries: usize,
+}
+
+impl Default for SlowQueryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold: Duration::from_millis(200),
+            max_entries: 100,
+        }
+    }
+}
+
+impl SlowQueryConfig {
+    /// Load settings from SLOW_QUERY_* environment variables, falling back to defaults
+    pub fn from_env() -> Self {
+        fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default)
+        }
+
+        let defaults = Self::default();
+        Self {
+            enabled: env_or("SLOW_QUERY_LOG_ENABLED", defaults.enabled),
+            threshold: Duration::from_millis(env_or(
+                "SLOW_QUERY_THRESHOLD_MS",
+                defaults.threshold.as_millis() as u64,
+            )),
+            max_entries: env_or("SLOW_QUERY_MAX_ENTRIES", defaults.max_entries),
+        }
+    }
+}
+
+/// A single slow execution reported by sqlx
+#[derive(Debug)]
+struct SlowStatement {
+    sql: String,
+    elapsed: Duration,
+}
+
+#[derive(Debug)]
+struct SlowQueryEntry {
+    count: u64,
+    total: Duration,
+    max: Duration,
+    plan: Option<String>,
+    first_seen_at: String,
+    last_seen_at: String,
+}
+
+/// In-memory aggregate of slow statements
+#[derive(Clone)]
+pub struct SlowQueryLog {
+    config: SlowQueryConfig,
+    entries: Arc<Mutex<HashMap<String, SlowQueryEntry>>>,
+}
+
+impl SlowQueryLog {
+    pub fn new(config: SlowQueryConfig) -> Self {
+        Self {
+            config,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn config(&self) -> &SlowQueryConfig {
+        &self.config
+    }
+
+    /// Record one slow execution; returns true the first time the statement
+    /// is seen
+    pub fn record(&self, sql: &str, elapsed: Duration) -> bool {
+        let statement = normalize_statement(sql);
+        let now = Utc::now().to_rfc3339();
+        let mut entries = self.entries.lock().unwrap();
+
+        if let Some(entry) = entries.get_mut(&statement) {
+            entry.count += 1;
+            entry.total += elapsed;
+            entry.max = entry.max.max(elapsed);
+            entry.last_seen_at = now;
+            return false;
+        }
+
+        if entries.len() >= self.config.max_entries {
+            let cheapest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.total)
+                .map(|(statement, _)| statement.clone());
+            match cheapest {
+                Some(cheapest) => {
+                    entries.remove(&cheapest);
+                }
+                None => return false,
+            }
+        }
+
+        entries.insert(
+            statement,
+            SlowQueryEntry {
+                count: 1,
+                total: elapsed,
+                max: elapsed,
+                plan: None,
+                first_seen_at: now.clone(),
+                last_seen_at: now,
+            },
+        );
+        true
+    }
+
+    pub fn set_plan(&self, sql: &str, plan: String) {
+        let statement = normalize_statement(sql);
+        if let Some(entry) = self.entries.lock().unwrap().get_mut(&statement) {
+            entry.plan = Some(plan);
+        }
+    }
+
+    /// The `limit` statements with the most total time spent
+    pub fn report(&self, limit: usize) -> SlowQueryReport {
+        let entries = self.entries.lock().unwrap();
+        let mut queries: Vec<SlowQuery> = entries
+            .iter()
+            .map(|(statement, entry)| SlowQuery {
+                statement: statement.clone(),
+                count: entry.count,
+                total_ms: entry.total.as_millis() as u64,
+                max_ms: entry.max.as_millis() as u64,
+                mean_ms: (entry.total / entry.count as u32).as_millis() as u64,
+                plan: entry.plan.clone(),
+                first_seen_at: entry.first_seen_at.clone(),
+                last_seen_at: entry.last_seen_at.clone(),
+            })
+            .collect();
+        queries.sort_by(|a, b| {
+            b.total_ms
+                .cmp(&a.total_ms)
+                .then(b.max_ms.cmp(&a.max_ms))
+                .then(a.statement.cmp(&b.statement))
+        });
+        queries.truncate(limit);
+
+        SlowQueryReport {
+            enabled: self.config.enabled,
+            threshold_ms: self.config.threshold.as_millis() as u64,
+            queries,
+        }
+    }
+
+    /// Start receiving slow statements from `SlowQueryLayer`, capturing the
+    /// plan of each new statement with `pool`
+    pub fn watch(&self, pool: AnyPool, explain: bool) {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        *SLOW_STATEMENTS.write().unwrap() = Some(sender);
+
+        let log = self.clone();
+        tokio::spawn(async move {
+            while let Some(slow) = receiver.recv().await {
+                // Our own EXPLAIN runs are reported too
+                if slow.sql.trim_start().to_uppercase().starts_with("EXPLAIN") {
+                    continue;
+                }
+                if !log.record(&slow.sql, slow.elapsed) {
+                    continue;
+                }
+
+                let plan = if explain && is_explainable(&slow.sql) {
+                    match explain_query_plan(&pool, &slow.sql).await {
+                        Ok(plan) => {
+                            log.set_plan(&slow.sql, plan.clone());
+                            plan
+                        }
+                        Err(e) => format!("unavailable: {}", e),
+                    }
+                } else {
+                    "unavailable".to_string()
+                };
+                tracing::warn!(
+                    "Slow query ({} ms): {}\nPlan:\n{}",
+                    slow.elapsed.as_millis(),
+                    normalize_statement(&slow.sql),
+                    plan
+                );
+            }
+        });
+    }
+}
+
+/// Collapse whitespace so the same statement formatted differently
+/// aggregates into one entry
+fn normalize_statement(sql: &str) -> String {
+    sql.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Only queries have a plan; explaining DDL or PRAGMAs can contend with
+/// migrations for the schema lock
+fn is_explainable(sql: &str) -> bool {
+    let keyword = sql
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_uppercase();
+    matches!(
+        keyword.as_str(),
+        "SELECT" | "WITH" | "INSERT" | "UPDATE" | "DELETE" | "REPLACE"
+    )
+}
+
+/// SQLite's `EXPLAIN QUERY PLAN`, one step per line and indented by depth.
+/// Unbound parameters are treated as NULL, which doesn't change the plan.
+async fn explain_query_plan(pool: &AnyPool, sql: &str) -> Result<String, sqlx::Error> {
+    let rows = sqlx::query(&format!("EXPLAIN QUERY PLAN {}", sql))
+        .fetch_all(pool)
+        .await?;
+
+    let mut depths: HashMap<i64, usize> = HashMap::new();
+    let mut lines = Vec::with_capacity(rows.len());
+    for row in rows {
+        let id: i64 = row.try_get("id")?;
+        let parent: i64 = row.try_get("parent")?;
+        let detail: String = row.try_get("detail")?;
+        let depth = depths.get(&parent).map_or(0, |depth| depth + 1);
+        depths.insert(id, depth);
+        lines.push(format!("{}{}", "  ".repeat(depth), detail));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Tracing layer forwarding sqlx's slow statement events to the
+/// `SlowQueryLog` being watched
+pub struct SlowQueryLayer;
+
+impl<S: Subscriber> Layer<S> for SlowQueryLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if event.metadata().target() != "sqlx::query" {
+            return;
+        }
+        let sender = SLOW_STATEMENTS.read().unwrap();
+        let Some(sender) = sender.as_ref() else {
+            return;
+        };
+
+        let mut visitor = SlowStatementVisitor::default();
+        event.record(&mut visitor);
+        if !visitor.slow {
+            return;
+        }
+        // `db.statement` holds the full SQL when the summary is truncated
+        let sql = visitor
+            .statement
+            .filter(|statement| !statement.trim().is_empty())
+            .or(visitor.summary);
+        if let (Some(sql), Some(elapsed_secs)) = (sql, visitor.elapsed_secs) {
+            let _ = sender.send(SlowStatement {
+                sql,
+                elapsed: Duration::from_secs_f64(elapsed_secs),
+            });
+        }
+    }
+}
+
+#[derive(Default)]
+struct SlowStatementVisitor {
+    summary: Option<String>,
+    statement: Option<String>,
+    elapsed_secs: Option<f64>,
+    slow: bool,
+}
+
+impl Visit for SlowStatementVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "summary" => self.summary = Some(value.to_string()),
+            "db.statement" => self.statement = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if field.name() == "elapsed_secs" {
+            self.elapsed_secs = Some(value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, _value: &dyn std::fmt::Debug) {
+        // Only present on slow statement events
+        if field.name() == "slow_threshold" {
+            self.slow = true;
+        }
+    }
+}
+
+impl QueryDiagnostics for Database {
+    fn slow_query_report(&self, limit: usize) -> SlowQueryReport {
+        self.slow_queries.report(limit)
+    }
+}
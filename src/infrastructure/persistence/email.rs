@@ -1,7 +1,9 @@
 use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
 use crate::infrastructure::persistence::Database;
 use crate::domain::entities::{
-    EmailProcessingLog, InboxEmailConfig, MessageAttachment, UpdateInboxEmailConfigRequest,
+    normalize_sender_email, EmailAuthFailurePolicy, EmailAuthMethod, EmailOAuthProvider, EmailParticipant,
+    EmailProcessingLog, InboxEmailBacklog, InboxEmailConfig, MessageAttachment,
+    UpdateInboxEmailConfigRequest,
 };
 use sqlx::Row;
 use time;
@@ -15,7 +17,7 @@ impl Database {
     // ========================================
 
     /// Decrypt password if encryption is enabled, otherwise return as-is
-    fn decrypt_password_field(&self, encrypted: &str) -> String {
+    pub(super) fn decrypt_password_field(&self, encrypted: &str) -> String {
         use crate::shared::utils::encryption::{decrypt_password, is_encryption_enabled};
 
         if !is_encryption_enabled() {
@@ -38,7 +40,7 @@ impl Database {
     }
 
     /// Encrypt password if encryption is enabled, otherwise return as-is
-    fn encrypt_password_field(&self, plaintext: &str) -> ApiResult<String> {
+    pub(super) fn encrypt_password_field(&self, plaintext: &str) -> ApiResult<String> {
         use crate::shared::utils::encryption::{encrypt_password, is_encryption_enabled};
 
         if !is_encryption_enabled() {
@@ -60,6 +62,7 @@ impl Database {
         let row = sqlx::query(
             "SELECT id, inbox_id, imap_host, imap_port, imap_username, imap_password, imap_use_tls, imap_folder,
                     smtp_host, smtp_port, smtp_username, smtp_password, smtp_use_tls,
+                    auth_method, oauth_provider, auth_failure_policy,
                     email_address, display_name, poll_interval_seconds, enabled,
                     CAST(last_poll_at AS TEXT) as last_poll_at,
                     CAST(created_at AS TEXT) as created_at,
@@ -101,6 +104,9 @@ impl Database {
                     smtp_username: row.try_get("smtp_username")?,
                     smtp_password: self.decrypt_password_field(&smtp_password_encrypted),
                     smtp_use_tls: smtp_use_tls != 0,
+                    auth_method: row_auth_method(&row)?,
+                    oauth_provider: row_oauth_provider(&row),
+                    auth_failure_policy: row_auth_failure_policy(&row)?,
                     email_address: row.try_get("email_address")?,
                     display_name: row.try_get("display_name")?,
                     poll_interval_seconds: row.try_get("poll_interval_seconds")?,
@@ -119,6 +125,7 @@ impl Database {
         let rows = sqlx::query(
             "SELECT c.id, c.inbox_id, c.imap_host, c.imap_port, c.imap_username, c.imap_password, c.imap_use_tls, c.imap_folder,
                     c.smtp_host, c.smtp_port, c.smtp_username, c.smtp_password, c.smtp_use_tls,
+                    c.auth_method, c.oauth_provider, c.auth_failure_policy,
                     c.email_address, c.display_name, c.poll_interval_seconds, c.enabled,
                     CAST(c.last_poll_at AS TEXT) as last_poll_at,
                     CAST(c.created_at AS TEXT) as created_at,
@@ -152,6 +159,9 @@ impl Database {
                 smtp_username: row.try_get("smtp_username")?,
                 smtp_password: row.try_get("smtp_password")?,
                 smtp_use_tls: smtp_use_tls != 0,
+                auth_method: row_auth_method(&row)?,
+                oauth_provider: row_oauth_provider(&row),
+                auth_failure_policy: row_auth_failure_policy(&row)?,
                 email_address: row.try_get("email_address")?,
                 display_name: row.try_get("display_name")?,
                 poll_interval_seconds: row.try_get("poll_interval_seconds")?,
@@ -177,9 +187,9 @@ impl Database {
         sqlx::query(
             "INSERT INTO inbox_email_configs (
                 id, inbox_id, imap_host, imap_port, imap_username, imap_password, imap_use_tls, imap_folder,
-                smtp_host, smtp_port, smtp_username, smtp_password, smtp_use_tls,
+                smtp_host, smtp_port, smtp_username, smtp_password, smtp_use_tls, auth_method, oauth_provider, auth_failure_policy,
                 email_address, display_name, poll_interval_seconds, enabled, last_poll_at, created_at, updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
         )
         .bind(&config.id)
         .bind(&config.inbox_id)
@@ -194,6 +204,9 @@ impl Database {
         .bind(&config.smtp_username)
         .bind(&smtp_password_encrypted)
         .bind(if config.smtp_use_tls { 1 } else { 0 })
+        .bind(config.auth_method.as_str())
+        .bind(config.oauth_provider.map(|provider| provider.as_str()))
+        .bind(config.auth_failure_policy.as_str())
         .bind(&config.email_address)
         .bind(&config.display_name)
         .bind(config.poll_interval_seconds)
@@ -251,6 +264,11 @@ impl Database {
                 .clone()
                 .unwrap_or(existing.smtp_password),
             smtp_use_tls: updates.smtp_use_tls.unwrap_or(existing.smtp_use_tls),
+            auth_method: updates.auth_method.unwrap_or(existing.auth_method),
+            oauth_provider: updates.oauth_provider.or(existing.oauth_provider),
+            auth_failure_policy: updates
+                .auth_failure_policy
+                .unwrap_or(existing.auth_failure_policy),
             email_address: updates
                 .email_address
                 .clone()
@@ -268,6 +286,12 @@ impl Database {
             updated_at: now.clone(),
         };
 
+        if updated.auth_method == EmailAuthMethod::OAuth2 && updated.oauth_provider.is_none() {
+            return Err(ApiError::BadRequest(
+                "oauth_provider is required for OAuth2 authentication".to_string(),
+            ));
+        }
+
         // Encrypt passwords before storing
         let imap_password_encrypted = self.encrypt_password_field(&updated.imap_password)?;
         let smtp_password_encrypted = self.encrypt_password_field(&updated.smtp_password)?;
@@ -276,6 +300,7 @@ impl Database {
             "UPDATE inbox_email_configs SET
                 imap_host = ?, imap_port = ?, imap_username = ?, imap_password = ?, imap_use_tls = ?, imap_folder = ?,
                 smtp_host = ?, smtp_port = ?, smtp_username = ?, smtp_password = ?, smtp_use_tls = ?,
+                auth_method = ?, oauth_provider = ?, auth_failure_policy = ?,
                 email_address = ?, display_name = ?, poll_interval_seconds = ?, enabled = ?, updated_at = ?
              WHERE id = ?"
         )
@@ -290,6 +315,9 @@ impl Database {
         .bind(&updated.smtp_username)
         .bind(&smtp_password_encrypted)
         .bind(if updated.smtp_use_tls { 1 } else { 0 })
+        .bind(updated.auth_method.as_str())
+        .bind(updated.oauth_provider.map(|provider| provider.as_str()))
+        .bind(updated.auth_failure_policy.as_str())
         .bind(&updated.email_address)
         .bind(&updated.display_name)
         .bind(updated.poll_interval_seconds)
@@ -308,6 +336,7 @@ impl Database {
         let row = sqlx::query(
             "SELECT id, inbox_id, imap_host, imap_port, imap_username, imap_password, imap_use_tls, imap_folder,
                     smtp_host, smtp_port, smtp_username, smtp_password, smtp_use_tls,
+                    auth_method, oauth_provider, auth_failure_policy,
                     email_address, display_name, poll_interval_seconds, enabled,
                     CAST(last_poll_at AS TEXT) as last_poll_at,
                     CAST(created_at AS TEXT) as created_at,
@@ -343,6 +372,9 @@ impl Database {
                     smtp_username: row.try_get("smtp_username")?,
                     smtp_password: self.decrypt_password_field(&smtp_password_encrypted),
                     smtp_use_tls: smtp_use_tls != 0,
+                    auth_method: row_auth_method(&row)?,
+                    oauth_provider: row_oauth_provider(&row),
+                    auth_failure_policy: row_auth_failure_policy(&row)?,
                     email_address: row.try_get("email_address")?,
                     display_name: row.try_get("display_name")?,
                     poll_interval_seconds: row.try_get("poll_interval_seconds")?,
@@ -372,6 +404,139 @@ impl Database {
         Ok(())
     }
 
+    /// Get the backlog progress of an inbox
+    pub async fn get_email_backlog(&self, inbox_id: &str) -> ApiResult<Option<InboxEmailBacklog>> {
+        let row = sqlx::query(
+            "SELECT inbox_id, messages_fetched, messages_processed, messages_failed,
+                    messages_remaining, total_processed, updated_at
+             FROM inbox_email_backlog WHERE inbox_id = ?",
+        )
+        .bind(inbox_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(Some(InboxEmailBacklog {
+                inbox_id: row.try_get("inbox_id")?,
+                messages_fetched: row.try_get("messages_fetched")?,
+                messages_processed: row.try_get("messages_processed")?,
+                messages_failed: row.try_get("messages_failed")?,
+                messages_remaining: row.try_get("messages_remaining")?,
+                total_processed: row.try_get("total_processed")?,
+                updated_at: row.try_get("updated_at")?,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    /// Insert or replace the backlog progress of an inbox
+    pub async fn save_email_backlog(&self, backlog: &InboxEmailBacklog) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO inbox_email_backlog (inbox_id, messages_fetched, messages_processed,
+                    messages_failed, messages_remaining, total_processed, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(inbox_id) DO UPDATE SET
+                    messages_fetched = excluded.messages_fetched,
+                    messages_processed = excluded.messages_processed,
+                    messages_failed = excluded.messages_failed,
+                    messages_remaining = excluded.messages_remaining,
+                    total_processed = excluded.total_processed,
+                    updated_at = excluded.updated_at",
+        )
+        .bind(&backlog.inbox_id)
+        .bind(backlog.messages_fetched)
+        .bind(backlog.messages_processed)
+        .bind(backlog.messages_failed)
+        .bind(backlog.messages_remaining)
+        .bind(backlog.total_processed)
+        .bind(&backlog.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Add an address to a conversation's email thread
+    pub async fn add_email_participant(&self, conversation_id: &str, email: &str) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO conversation_email_participants (conversation_id, email, suppressed, added_at)
+             VALUES (?, ?, 0, ?)
+             ON CONFLICT(conversation_id, email) DO NOTHING",
+        )
+        .bind(conversation_id)
+        .bind(normalize_sender_email(email))
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Addresses on a conversation's email thread, oldest first
+    pub async fn list_email_participants(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<EmailParticipant>> {
+        let rows = sqlx::query(
+            "SELECT conversation_id, email, suppressed, suppressed_at, suppressed_by, added_at
+             FROM conversation_email_participants
+             WHERE conversation_id = ?
+             ORDER BY added_at ASC, email ASC",
+        )
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_email_participant).collect()
+    }
+
+    /// Stop or resume emailing a participant
+    pub async fn set_email_participant_suppressed(
+        &self,
+        conversation_id: &str,
+        email: &str,
+        suppressed: bool,
+        suppressed_by: Option<&str>,
+    ) -> ApiResult<Option<EmailParticipant>> {
+        let email = normalize_sender_email(email);
+        let (suppressed_at, suppressed_by) = if suppressed {
+            (
+                Some(chrono::Utc::now().to_rfc3339()),
+                suppressed_by.map(str::to_string),
+            )
+        } else {
+            (None, None)
+        };
+
+        let result = sqlx::query(
+            "UPDATE conversation_email_participants
+             SET suppressed = ?, suppressed_at = ?, suppressed_by = ?
+             WHERE conversation_id = ? AND email = ?",
+        )
+        .bind(if suppressed { 1 } else { 0 })
+        .bind(suppressed_at)
+        .bind(suppressed_by)
+        .bind(conversation_id)
+        .bind(&email)
+        .execute(&self.pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let row = sqlx::query(
+            "SELECT conversation_id, email, suppressed, suppressed_at, suppressed_by, added_at
+             FROM conversation_email_participants
+             WHERE conversation_id = ? AND email = ?",
+        )
+        .bind(conversation_id)
+        .bind(&email)
+        .fetch_one(&self.pool)
+        .await?;
+
+        row_to_email_participant(&row).map(Some)
+    }
+
     /// Create message attachment
     pub async fn create_message_attachment(
         &self,
@@ -426,6 +591,35 @@ impl Database {
         Ok(attachments)
     }
 
+    /// Get a single attachment by ID
+    pub async fn get_attachment_by_id(
+        &self,
+        attachment_id: &str,
+    ) -> ApiResult<Option<MessageAttachment>> {
+        let row = sqlx::query(
+            "SELECT id, message_id, filename, content_type, file_size, file_path,
+                    CAST(created_at AS TEXT) as created_at
+             FROM message_attachments WHERE id = ?",
+        )
+        .bind(attachment_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ApiError::Internal(format!("Database error: {}", e)))?;
+
+        match row {
+            Some(row) => Ok(Some(MessageAttachment {
+                id: row.try_get("id")?,
+                message_id: row.try_get("message_id")?,
+                filename: row.try_get("filename")?,
+                content_type: row.try_get("content_type").ok(),
+                file_size: row.try_get("file_size")?,
+                file_path: row.try_get("file_path")?,
+                created_at: row.try_get("created_at")?,
+            })),
+            None => Ok(None),
+        }
+    }
+
     /// Log email processing result
     pub async fn log_email_processing(
         &self,
@@ -486,6 +680,41 @@ impl Database {
     }
 }
 
+fn row_auth_method(row: &sqlx::any::AnyRow) -> ApiResult<EmailAuthMethod> {
+    let auth_method: String = row.try_get("auth_method")?;
+    auth_method.parse().map_err(ApiError::Internal)
+}
+
+fn row_auth_failure_policy(row: &sqlx::any::AnyRow) -> ApiResult<EmailAuthFailurePolicy> {
+    let policy: String = row.try_get("auth_failure_policy")?;
+    policy.parse().map_err(ApiError::Internal)
+}
+
+fn row_oauth_provider(row: &sqlx::any::AnyRow) -> Option<EmailOAuthProvider> {
+    row.try_get::<Option<String>, _>("oauth_provider")
+        .ok()
+        .flatten()
+        .and_then(|provider| provider.parse().ok())
+}
+
+fn row_to_email_participant(row: &sqlx::any::AnyRow) -> ApiResult<EmailParticipant> {
+    let suppressed: i32 = row.try_get("suppressed")?;
+    Ok(EmailParticipant {
+        conversation_id: row.try_get("conversation_id")?,
+        email: row.try_get("email")?,
+        suppressed: suppressed != 0,
+        suppressed_at: row
+            .try_get::<Option<String>, _>("suppressed_at")
+            .ok()
+            .flatten(),
+        suppressed_by: row
+            .try_get::<Option<String>, _>("suppressed_by")
+            .ok()
+            .flatten(),
+        added_at: row.try_get("added_at")?,
+    })
+}
+
 #[async_trait::async_trait]
 impl EmailRepository for Database {
     async fn get_inbox_email_config(&self, inbox_id: &str) -> ApiResult<Option<InboxEmailConfig>> {
@@ -533,6 +762,36 @@ impl EmailRepository for Database {
     ) -> ApiResult<bool> {
         self.check_email_processed(inbox_id, email_message_id).await
     }
+
+    async fn get_email_backlog(&self, inbox_id: &str) -> ApiResult<Option<InboxEmailBacklog>> {
+        self.get_email_backlog(inbox_id).await
+    }
+
+    async fn save_email_backlog(&self, backlog: &InboxEmailBacklog) -> ApiResult<()> {
+        self.save_email_backlog(backlog).await
+    }
+
+    async fn add_email_participant(&self, conversation_id: &str, email: &str) -> ApiResult<()> {
+        self.add_email_participant(conversation_id, email).await
+    }
+
+    async fn list_email_participants(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<EmailParticipant>> {
+        self.list_email_participants(conversation_id).await
+    }
+
+    async fn set_email_participant_suppressed(
+        &self,
+        conversation_id: &str,
+        email: &str,
+        suppressed: bool,
+        suppressed_by: Option<&str>,
+    ) -> ApiResult<Option<EmailParticipant>> {
+        self.set_email_participant_suppressed(conversation_id, email, suppressed, suppressed_by)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -547,4 +806,11 @@ impl AttachmentRepository for Database {
     async fn get_message_attachments(&self, message_id: &str) -> ApiResult<Vec<MessageAttachment>> {
         self.get_message_attachments(message_id).await
     }
+
+    async fn get_attachment_by_id(
+        &self,
+        attachment_id: &str,
+    ) -> ApiResult<Option<MessageAttachment>> {
+        self.get_attachment_by_id(attachment_id).await
+    }
 }
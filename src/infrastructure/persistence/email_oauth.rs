@@ -0,0 +1,178 @@
+use sqlx::Row;
+
+use crate::domain::entities::{EmailOAuthState, EmailOAuthToken};
+use crate::domain::ports::email_oauth_repository::EmailOAuthRepository;
+use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
+use crate::infrastructure::persistence::Database;
+
+impl Database {
+    pub async fn create_email_oauth_state(&self, state: &EmailOAuthState) -> ApiResult<()> {
+        // Drop abandoned authorizations while we're here
+        sqlx::query("DELETE FROM inbox_email_oauth_states WHERE expires_at < ?")
+            .bind(chrono::Utc::now().to_rfc3339())
+            .execute(&self.pool)
+            .await?;
+
+        sqlx::query(
+            "INSERT INTO inbox_email_oauth_states (
+                state, inbox_id, provider, pkce_verifier, created_by, created_at, expires_at
+             ) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&state.state)
+        .bind(&state.inbox_id)
+        .bind(state.provider.as_str())
+        .bind(&state.pkce_verifier)
+        .bind(&state.created_by)
+        .bind(&state.created_at)
+        .bind(&state.expires_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Retrieve and delete a pending authorization; of two concurrent
+    /// callbacks with the same state only one gets it
+    pub async fn consume_email_oauth_state(
+        &self,
+        state: &str,
+    ) -> ApiResult<Option<EmailOAuthState>> {
+        let row = sqlx::query(
+            "SELECT state, inbox_id, provider, pkce_verifier, created_by, created_at, expires_at
+             FROM inbox_email_oauth_states WHERE state = ?",
+        )
+        .bind(state)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let deleted = sqlx::query("DELETE FROM inbox_email_oauth_states WHERE state = ?")
+            .bind(state)
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
+        if deleted == 0 {
+            return Ok(None);
+        }
+
+        let provider: String = row.try_get("provider")?;
+        Ok(Some(EmailOAuthState {
+            state: row.try_get("state")?,
+            inbox_id: row.try_get("inbox_id")?,
+            provider: provider.parse().map_err(ApiError::Internal)?,
+            pkce_verifier: row.try_get("pkce_verifier")?,
+            created_by: row
+                .try_get::<Option<String>, _>("created_by")
+                .ok()
+                .flatten(),
+            created_at: row.try_get("created_at")?,
+            expires_at: row.try_get("expires_at")?,
+        }))
+    }
+
+    /// Get an inbox's OAuth2 tokens, decrypted
+    pub async fn get_email_oauth_token(
+        &self,
+        inbox_id: &str,
+    ) -> ApiResult<Option<EmailOAuthToken>> {
+        let row = sqlx::query(
+            "SELECT inbox_id, provider, access_token, refresh_token, expires_at, scope,
+                    authorized_by, created_at, updated_at
+             FROM inbox_email_oauth_tokens WHERE inbox_id = ?",
+        )
+        .bind(inbox_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let provider: String = row.try_get("provider")?;
+        let access_token: String = row.try_get("access_token")?;
+        let refresh_token: String = row.try_get("refresh_token")?;
+
+        Ok(Some(EmailOAuthToken {
+            inbox_id: row.try_get("inbox_id")?,
+            provider: provider.parse().map_err(ApiError::Internal)?,
+            access_token: self.decrypt_password_field(&access_token),
+            refresh_token: self.decrypt_password_field(&refresh_token),
+            expires_at: row.try_get("expires_at")?,
+            scope: row.try_get::<Option<String>, _>("scope").ok().flatten(),
+            authorized_by: row
+                .try_get::<Option<String>, _>("authorized_by")
+                .ok()
+                .flatten(),
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        }))
+    }
+
+    /// Insert or replace an inbox's OAuth2 tokens
+    pub async fn save_email_oauth_token(&self, token: &EmailOAuthToken) -> ApiResult<()> {
+        let access_token = self.encrypt_password_field(&token.access_token)?;
+        let refresh_token = self.encrypt_password_field(&token.refresh_token)?;
+
+        sqlx::query(
+            "INSERT INTO inbox_email_oauth_tokens (
+                inbox_id, provider, access_token, refresh_token, expires_at, scope,
+                authorized_by, created_at, updated_at
+             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(inbox_id) DO UPDATE SET
+                provider = excluded.provider,
+                access_token = excluded.access_token,
+                refresh_token = excluded.refresh_token,
+                expires_at = excluded.expires_at,
+                scope = excluded.scope,
+                authorized_by = excluded.authorized_by,
+                updated_at = excluded.updated_at",
+        )
+        .bind(&token.inbox_id)
+        .bind(token.provider.as_str())
+        .bind(&access_token)
+        .bind(&refresh_token)
+        .bind(&token.expires_at)
+        .bind(&token.scope)
+        .bind(&token.authorized_by)
+        .bind(&token.created_at)
+        .bind(&token.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_email_oauth_token(&self, inbox_id: &str) -> ApiResult<()> {
+        sqlx::query("DELETE FROM inbox_email_oauth_tokens WHERE inbox_id = ?")
+            .bind(inbox_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailOAuthRepository for Database {
+    async fn create_email_oauth_state(&self, state: &EmailOAuthState) -> ApiResult<()> {
+        Database::create_email_oauth_state(self, state).await
+    }
+
+    async fn consume_email_oauth_state(&self, state: &str) -> ApiResult<Option<EmailOAuthState>> {
+        Database::consume_email_oauth_state(self, state).await
+    }
+
+    async fn get_email_oauth_token(&self, inbox_id: &str) -> ApiResult<Option<EmailOAuthToken>> {
+        Database::get_email_oauth_token(self, inbox_id).await
+    }
+
+    async fn save_email_oauth_token(&self, token: &EmailOAuthToken) -> ApiResult<()> {
+        Database::save_email_oauth_token(self, token).await
+    }
+
+    async fn delete_email_oauth_token(&self, inbox_id: &str) -> ApiResult<()> {
+        Database::delete_email_oauth_token(self, inbox_id).await
+    }
+}
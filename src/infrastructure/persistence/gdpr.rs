@@ -0,0 +1,457 @@
+use std::collections::HashMap;
+
+use sqlx::Row;
+
+use crate::domain::entities::{
+    AttachmentExport, ChannelIdentityExport, ContactChannelExport, ContactDataExport,
+    ContactDataSummary, ContactErasurePlan, ContactNoteExport, ContactProfileExport,
+    ConversationExport, CsatResponseExport, GdprRequest, MessageExport,
+    CONTACT_EXPORT_FORMAT_VERSION,
+};
+use crate::domain::ports::gdpr_repository::GdprRepository;
+use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
+use crate::infrastructure::persistence::Database;
+
+fn optional_string(row: &sqlx::any::AnyRow, column: &str) -> Option<String> {
+    row.try_get::<Option<String>, _>(column).ok().flatten()
+}
+
+fn row_to_gdpr_request(row: &sqlx::any::AnyRow) -> ApiResult<GdprRequest> {
+    let parse_err = |e: String| ApiError::Internal(e);
+    let request_type: String = row.try_get("request_type")?;
+    let status: String = row.try_get("status")?;
+    let summary = optional_string(row, "summary")
+        .map(|s| serde_json::from_str::<ContactDataSummary>(&s))
+        .transpose()
+        .map_err(|e| ApiError::Internal(format!("Invalid GDPR request summary: {}", e)))?;
+
+    Ok(GdprRequest {
+        id: row.try_get("id")?,
+        contact_id: row.try_get("contact_id")?,
+        request_type: request_type.parse().map_err(parse_err)?,
+        status: status.parse().map_err(parse_err)?,
+        message_redaction: optional_string(row, "message_redaction")
+            .map(|s| s.parse())
+            .transpose()
+            .map_err(parse_err)?,
+        confirmation_token_hash: optional_string(row, "confirmation_token_hash"),
+        requested_by: row.try_get("requested_by")?,
+        confirmed_by: optional_string(row, "confirmed_by"),
+        summary,
+        created_at: row.try_get("created_at")?,
+        expires_at: optional_string(row, "expires_at"),
+        completed_at: optional_string(row, "completed_at"),
+    })
+}
+
+const GDPR_REQUEST_COLUMNS: &str = "id, contact_id, request_type, status, message_redaction, \
+     confirmation_token_hash, requested_by, confirmed_by, summary, created_at, expires_at, \
+     completed_at";
+
+impl Database {
+    pub async fn get_contact_data_export(
+        &self,
+        user_id: &str,
+    ) -> ApiResult<Option<ContactDataExport>> {
+        let Some(row) = sqlx::query(
+            "SELECT u.id AS user_id, u.email, u.created_at, u.updated_at,
+                    c.id AS contact_id, c.first_name, c.tier
+             FROM users u
+             INNER JOIN contacts c ON c.user_id = u.id
+             WHERE u.id = ? AND u.user_type = 'contact'",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?
+        else {
+            return Ok(None);
+        };
+        let contact = ContactProfileExport {
+            user_id: row.try_get("user_id")?,
+            contact_id: row.try_get("contact_id")?,
+            email: row.try_get("email")?,
+            first_name: optional_string(&row, "first_name"),
+            tier: optional_string(&row, "tier"),
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        };
+
+        let channels = sqlx::query(
+            "SELECT id, inbox_id, email, CAST(email_verified AS INTEGER) AS email_verified, created_at
+             FROM contact_channels WHERE contact_id = ?
+             ORDER BY created_at, id",
+        )
+        .bind(&contact.contact_id)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            Ok(ContactChannelExport {
+                id: row.try_get("id")?,
+                inbox_id: row.try_get("inbox_id")?,
+                email: row.try_get("email")?,
+                email_verified: row.try_get::<i64, _>("email_verified")? != 0,
+                created_at: row.try_get("created_at")?,
+            })
+        })
+        .collect::<ApiResult<Vec<_>>>()?;
+
+        let channel_identities = sqlx::query(
+            "SELECT inbox_id, external_id, created_at
+             FROM channel_identities WHERE contact_id = ?
+             ORDER BY created_at, inbox_id",
+        )
+        .bind(&contact.contact_id)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            Ok(ChannelIdentityExport {
+                inbox_id: row.try_get("inbox_id")?,
+                external_id: row.try_get("external_id")?,
+                created_at: row.try_get("created_at")?,
+            })
+        })
+        .collect::<ApiResult<Vec<_>>>()?;
+
+        let mut attachments: HashMap<String, Vec<AttachmentExport>> = HashMap::new();
+        for row in sqlx::query(
+            "SELECT a.id, a.message_id, a.filename, a.content_type, a.file_size
+             FROM message_attachments a
+             INNER JOIN messages m ON m.id = a.message_id
+             INNER JOIN conversations c ON c.id = m.conversation_id
+             WHERE c.contact_id = ?
+             ORDER BY a.created_at, a.id",
+        )
+        .bind(&contact.contact_id)
+        .fetch_all(&self.pool)
+        .await?
+        {
+            attachments
+                .entry(row.try_get("message_id")?)
+                .or_default()
+                .push(AttachmentExport {
+                    id: row.try_get("id")?,
+                    filename: row.try_get("filename")?,
+                    content_type: optional_string(&row, "content_type"),
+                    file_size: row.try_get("file_size")?,
+                });
+        }
+
+        let mut messages: HashMap<String, Vec<MessageExport>> = HashMap::new();
+        for row in sqlx::query(
+            "SELECT m.id, m.conversation_id, m.type, m.content, m.created_at
+             FROM messages m
+             INNER JOIN conversations c ON c.id = m.conversation_id
+             WHERE c.contact_id = ?
+             ORDER BY m.created_at, m.id",
+        )
+        .bind(&contact.contact_id)
+        .fetch_all(&self.pool)
+        .await?
+        {
+            let id: String = row.try_get("id")?;
+            messages
+                .entry(row.try_get("conversation_id")?)
+                .or_default()
+                .push(MessageExport {
+                    attachments: attachments.remove(&id).unwrap_or_default(),
+                    id,
+                    direction: row.try_get("type")?,
+                    content: row.try_get("content")?,
+                    created_at: row.try_get("created_at")?,
+                });
+        }
+
+        let mut csat_responses: HashMap<String, Vec<CsatResponseExport>> = HashMap::new();
+        for row in sqlx::query(
+            "SELECT r.id, r.conversation_id, r.score, r.comment, r.created_at
+             FROM csat_responses r
+             INNER JOIN conversations c ON c.id = r.conversation_id
+             WHERE c.contact_id = ?
+             ORDER BY r.created_at, r.id",
+        )
+        .bind(&contact.contact_id)
+        .fetch_all(&self.pool)
+        .await?
+        {
+            csat_responses
+                .entry(row.try_get("conversation_id")?)
+                .or_default()
+                .push(CsatResponseExport {
+                    id: row.try_get("id")?,
+                    score: row.try_get("score")?,
+                    comment: optional_string(&row, "comment"),
+                    created_at: row.try_get("created_at")?,
+                });
+        }
+
+        let conversations = sqlx::query(
+            "SELECT id, reference_number, inbox_id, subject, status, created_at
+             FROM conversations WHERE contact_id = ?
+             ORDER BY created_at, id",
+        )
+        .bind(&contact.contact_id)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            let id: String = row.try_get("id")?;
+            Ok(ConversationExport {
+                reference_number: row.try_get("reference_number")?,
+                inbox_id: row.try_get("inbox_id")?,
+                subject: optional_string(row, "subject"),
+                status: row.try_get("status")?,
+                created_at: row.try_get("created_at")?,
+                messages: messages.remove(&id).unwrap_or_default(),
+                csat_responses: csat_responses.remove(&id).unwrap_or_default(),
+                id,
+            })
+        })
+        .collect::<ApiResult<Vec<_>>>()?;
+
+        let notes = sqlx::query(
+            "SELECT id, content, created_at
+             FROM contact_notes WHERE contact_id = ?
+             ORDER BY created_at, id",
+        )
+        .bind(&contact.user_id)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            Ok(ContactNoteExport {
+                id: row.try_get("id")?,
+                content: row.try_get("content")?,
+                created_at: row.try_get("created_at")?,
+            })
+        })
+        .collect::<ApiResult<Vec<_>>>()?;
+
+        Ok(Some(ContactDataExport {
+            format_version: CONTACT_EXPORT_FORMAT_VERSION,
+            exported_at: chrono::Utc::now().to_rfc3339(),
+            contact,
+            channels,
+            channel_identities,
+            conversations,
+            notes,
+        }))
+    }
+
+    pub async fn erase_contact_data(
+        &self,
+        request_id: &str,
+        confirmed_by: &str,
+        plan: &ContactErasurePlan,
+    ) -> ApiResult<Option<Vec<String>>> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut tx = self.pool.begin().await?;
+
+        // Claim the request first so a second confirmation can't erase twice
+        let claimed = sqlx::query(
+            "UPDATE gdpr_requests
+             SET status = 'completed', confirmed_by = ?, completed_at = ?
+             WHERE id = ? AND status = 'pending'",
+        )
+        .bind(confirmed_by)
+        .bind(&now)
+        .bind(request_id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if claimed == 0 {
+            tx.rollback().await?;
+            return Ok(None);
+        }
+
+        sqlx::query("UPDATE users SET email = ?, updated_at = ? WHERE id = ?")
+            .bind(&plan.anonymized_email)
+            .bind(&now)
+            .bind(&plan.user_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("UPDATE contacts SET first_name = NULL WHERE id = ?")
+            .bind(&plan.contact_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "DELETE FROM contact_email_verifications
+             WHERE channel_id IN (SELECT id FROM contact_channels WHERE contact_id = ?)",
+        )
+        .bind(&plan.contact_id)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("UPDATE contact_channels SET email = ?, updated_at = ? WHERE contact_id = ?")
+            .bind(&plan.anonymized_email)
+            .bind(&now)
+            .bind(&plan.contact_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM channel_identities WHERE contact_id = ?")
+            .bind(&plan.contact_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM contact_notes WHERE contact_id = ?")
+            .bind(&plan.user_id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM sessions WHERE user_id = ?")
+            .bind(&plan.user_id)
+            .execute(&mut *tx)
+            .await?;
+        // Raw channel headers and invites carry the contact's addresses
+        sqlx::query(
+            "UPDATE messages SET channel_metadata = NULL, calendar_invite = NULL
+             WHERE author_id = ?",
+        )
+        .bind(&plan.user_id)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query(
+            "UPDATE email_processing_log SET from_address = ?, subject = NULL
+             WHERE conversation_id IN (SELECT id FROM conversations WHERE contact_id = ?)",
+        )
+        .bind(&plan.anonymized_email)
+        .bind(&plan.contact_id)
+        .execute(&mut *tx)
+        .await?;
+
+        for (message_id, content) in &plan.message_contents {
+            sqlx::query("UPDATE messages SET content = ?, updated_at = ? WHERE id = ?")
+                .bind(content)
+                .bind(&now)
+                .bind(message_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        for (conversation_id, subject) in &plan.subjects {
+            sqlx::query("UPDATE conversations SET subject = ? WHERE id = ?")
+                .bind(subject)
+                .bind(conversation_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        for (response_id, comment) in &plan.csat_comments {
+            sqlx::query("UPDATE csat_responses SET comment = ? WHERE id = ?")
+                .bind(comment)
+                .bind(response_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        let mut attachment_paths = Vec::new();
+        for message_id in &plan.attachment_message_ids {
+            let rows =
+                sqlx::query("SELECT file_path FROM message_attachments WHERE message_id = ?")
+                    .bind(message_id)
+                    .fetch_all(&mut *tx)
+                    .await?;
+            for row in rows {
+                attachment_paths.push(row.try_get("file_path")?);
+            }
+            sqlx::query("DELETE FROM message_attachments WHERE message_id = ?")
+                .bind(message_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+        if !plan.subjects.is_empty() {
+            self.cache.invalidate_conversations();
+        }
+
+        Ok(Some(attachment_paths))
+    }
+
+    pub async fn create_gdpr_request(&self, request: &GdprRequest) -> ApiResult<()> {
+        let summary = request
+            .summary
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .map_err(|e| ApiError::Internal(format!("Failed to serialize summary: {}", e)))?;
+
+        sqlx::query(
+            "INSERT INTO gdpr_requests (id, contact_id, request_type, status, message_redaction,
+                 confirmation_token_hash, requested_by, confirmed_by, summary, created_at,
+                 expires_at, completed_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&request.id)
+        .bind(&request.contact_id)
+        .bind(request.request_type.to_string())
+        .bind(request.status.to_string())
+        .bind(request.message_redaction.map(|r| r.to_string()))
+        .bind(&request.confirmation_token_hash)
+        .bind(&request.requested_by)
+        .bind(&request.confirmed_by)
+        .bind(summary)
+        .bind(&request.created_at)
+        .bind(&request.expires_at)
+        .bind(&request.completed_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_gdpr_request_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> ApiResult<Option<GdprRequest>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM gdpr_requests WHERE confirmation_token_hash = ?",
+            GDPR_REQUEST_COLUMNS
+        ))
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_gdpr_request).transpose()
+    }
+
+    pub async fn list_gdpr_requests(&self, contact_id: &str) -> ApiResult<Vec<GdprRequest>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM gdpr_requests WHERE contact_id = ?
+             ORDER BY created_at DESC, rowid DESC",
+            GDPR_REQUEST_COLUMNS
+        ))
+        .bind(contact_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_gdpr_request).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl GdprRepository for Database {
+    async fn get_contact_data_export(&self, user_id: &str) -> ApiResult<Option<ContactDataExport>> {
+        Database::get_contact_data_export(self, user_id).await
+    }
+
+    async fn erase_contact_data(
+        &self,
+        request_id: &str,
+        confirmed_by: &str,
+        plan: &ContactErasurePlan,
+    ) -> ApiResult<Option<Vec<String>>> {
+        Database::erase_contact_data(self, request_id, confirmed_by, plan).await
+    }
+
+    async fn create_gdpr_request(&self, request: &GdprRequest) -> ApiResult<()> {
+        Database::create_gdpr_request(self, request).await
+    }
+
+    async fn get_gdpr_request_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> ApiResult<Option<GdprRequest>> {
+        Database::get_gdpr_request_by_token_hash(self, token_hash).await
+    }
+
+    async fn list_gdpr_requests(&self, contact_id: &str) -> ApiResult<Vec<GdprRequest>> {
+        Database::list_gdpr_requests(self, contact_id).await
+    }
+}
@@ -1,16 +1,44 @@
 use crate::{infrastructure::persistence::Database, infrastructure::http::middleware::error::{ApiError, ApiResult}};
+use crate::domain::entities::{Holiday, HolidayCalendar};
+use crate::domain::ports::holiday_repository::HolidayRepository;
+use sqlx::Row;
+
+const HOLIDAY_COLUMNS: &str = "id, name, date, recurring, calendar_id, created_at, updated_at";
+
+fn row_to_holiday(row: &sqlx::any::AnyRow) -> ApiResult<Holiday> {
+    Ok(Holiday {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        date: row.try_get("date")?,
+        recurring: row.try_get::<i32, _>("recurring")? != 0,
+        calendar_id: row.try_get("calendar_id").ok(),
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+fn row_to_holiday_calendar(row: &sqlx::any::AnyRow) -> ApiResult<HolidayCalendar> {
+    Ok(HolidayCalendar {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        description: row.try_get("description").ok(),
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
 
 impl Database {
     /// Create a new holiday
-    pub async fn create_holiday(&self, holiday: &crate::domain::entities::Holiday) -> ApiResult<()> {
+    pub async fn create_holiday(&self, holiday: &Holiday) -> ApiResult<()> {
         sqlx::query(
-            "INSERT INTO holidays (id, name, date, recurring, created_at, updated_at)
-             VALUES (?, ?, ?, ?, ?, ?)",
+            "INSERT INTO holidays (id, name, date, recurring, calendar_id, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&holiday.id)
         .bind(&holiday.name)
         .bind(&holiday.date)
         .bind(holiday.recurring)
+        .bind(&holiday.calendar_id)
         .bind(&holiday.created_at)
         .bind(&holiday.updated_at)
         .execute(&self.pool)
@@ -20,52 +48,54 @@ impl Database {
     }
 
     /// Get a holiday by ID
-    pub async fn get_holiday(&self, id: &str) -> ApiResult<Option<crate::domain::entities::Holiday>> {
-        let holiday =
-            sqlx::query_as::<_, crate::domain::entities::Holiday>("SELECT * FROM holidays WHERE id = ?")
-                .bind(id)
-                .fetch_optional(&self.pool)
-                .await?;
+    pub async fn get_holiday(&self, id: &str) -> ApiResult<Option<Holiday>> {
+        let row = sqlx::query(&format!("SELECT {} FROM holidays WHERE id = ?", HOLIDAY_COLUMNS))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
 
-        Ok(holiday)
+        row.map(|row| row_to_holiday(&row)).transpose()
     }
 
     /// Get all holidays
-    pub async fn list_holidays(&self) -> ApiResult<Vec<crate::domain::entities::Holiday>> {
-        let holidays =
-            sqlx::query_as::<_, crate::domain::entities::Holiday>("SELECT * FROM holidays ORDER BY date ASC")
-                .fetch_all(&self.pool)
-                .await?;
+    pub async fn list_holidays(&self) -> ApiResult<Vec<Holiday>> {
+        let rows = sqlx::query(&format!("SELECT {} FROM holidays ORDER BY date ASC", HOLIDAY_COLUMNS))
+            .fetch_all(&self.pool)
+            .await?;
 
-        Ok(holidays)
+        rows.iter().map(row_to_holiday).collect()
     }
 
-    /// Check if a specific date is a holiday
-    pub async fn is_holiday(&self, date: &str) -> ApiResult<bool> {
-        // Check for exact date match
-        let exact_match = sqlx::query_scalar::<_, i64>(
-            "SELECT COUNT(*) FROM holidays WHERE date = ? AND recurring = 0",
-        )
-        .bind(date)
-        .fetch_one(&self.pool)
-        .await?;
-
-        if exact_match > 0 {
-            return Ok(true);
-        }
+    /// Check if a specific date is a holiday, either globally or in the given calendar
+    pub async fn is_holiday(&self, date: &str, calendar_id: Option<&str>) -> ApiResult<bool> {
+        Ok(self.find_holiday_on(date, calendar_id).await?.is_some())
+    }
 
-        // Check for recurring holidays (same month-day)
-        // Extract month-day from date (YYYY-MM-DD -> MM-DD)
-        let month_day = &date[5..]; // Skip "YYYY-"
+    /// Find the holiday on a specific date, either global or in the given calendar
+    pub async fn find_holiday_on(
+        &self,
+        date: &str,
+        calendar_id: Option<&str>,
+    ) -> ApiResult<Option<Holiday>> {
+        // Exact dates match as-is; recurring holidays match on month-day
+        // (YYYY-MM-DD -> MM-DD)
+        let month_day = date.get(5..).unwrap_or_default();
 
-        let recurring_match = sqlx::query_scalar::<_, i64>(
-            "SELECT COUNT(*) FROM holidays WHERE substr(date, 6) = ? AND recurring = 1",
-        )
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM holidays
+             WHERE (calendar_id IS NULL OR calendar_id = ?)
+               AND ((recurring = 0 AND date = ?) OR (recurring = 1 AND substr(date, 6) = ?))
+             ORDER BY calendar_id IS NULL, name ASC
+             LIMIT 1",
+            HOLIDAY_COLUMNS
+        ))
+        .bind(calendar_id)
+        .bind(date)
         .bind(month_day)
-        .fetch_one(&self.pool)
+        .fetch_optional(&self.pool)
         .await?;
 
-        Ok(recurring_match > 0)
+        row.map(|row| row_to_holiday(&row)).transpose()
     }
 
     /// Update a holiday
@@ -112,3 +142,144 @@ impl Database {
         Ok(())
     }
 }
+
+#[async_trait::async_trait]
+impl HolidayRepository for Database {
+    async fn create_holiday_calendar(&self, calendar: &HolidayCalendar) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO holiday_calendars (id, name, description, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&calendar.id)
+        .bind(&calendar.name)
+        .bind(&calendar.description)
+        .bind(&calendar.created_at)
+        .bind(&calendar.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if e.to_string().contains("UNIQUE") {
+                ApiError::Conflict(format!(
+                    "Holiday calendar with name '{}' already exists",
+                    calendar.name
+                ))
+            } else {
+                ApiError::Internal(e.to_string())
+            }
+        })?;
+
+        Ok(())
+    }
+
+    async fn get_holiday_calendar(&self, id: &str) -> ApiResult<Option<HolidayCalendar>> {
+        let row = sqlx::query(
+            "SELECT id, name, description, created_at, updated_at
+             FROM holiday_calendars WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| row_to_holiday_calendar(&row)).transpose()
+    }
+
+    async fn list_holiday_calendars(&self) -> ApiResult<Vec<HolidayCalendar>> {
+        let rows = sqlx::query(
+            "SELECT id, name, description, created_at, updated_at
+             FROM holiday_calendars ORDER BY name ASC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_holiday_calendar).collect()
+    }
+
+    async fn update_holiday_calendar(&self, calendar: &HolidayCalendar) -> ApiResult<()> {
+        sqlx::query(
+            "UPDATE holiday_calendars SET name = ?, description = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(&calendar.name)
+        .bind(&calendar.description)
+        .bind(&calendar.updated_at)
+        .bind(&calendar.id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if e.to_string().contains("UNIQUE") {
+                ApiError::Conflict(format!(
+                    "Holiday calendar with name '{}' already exists",
+                    calendar.name
+                ))
+            } else {
+                ApiError::Internal(e.to_string())
+            }
+        })?;
+
+        Ok(())
+    }
+
+    async fn delete_holiday_calendar(&self, id: &str) -> ApiResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        // Explicit cleanup so the result doesn't depend on foreign key enforcement
+        sqlx::query("UPDATE teams SET holiday_calendar_id = NULL WHERE holiday_calendar_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM holidays WHERE calendar_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query("DELETE FROM holiday_calendars WHERE id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn create_holiday(&self, holiday: &Holiday) -> ApiResult<()> {
+        Database::create_holiday(self, holiday).await
+    }
+
+    async fn get_holiday(&self, id: &str) -> ApiResult<Option<Holiday>> {
+        Database::get_holiday(self, id).await
+    }
+
+    async fn list_calendar_holidays(&self, calendar_id: &str) -> ApiResult<Vec<Holiday>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM holidays WHERE calendar_id = ? ORDER BY date ASC",
+            HOLIDAY_COLUMNS
+        ))
+        .bind(calendar_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_holiday).collect()
+    }
+
+    async fn delete_holiday(&self, id: &str) -> ApiResult<()> {
+        Database::delete_holiday(self, id).await
+    }
+
+    async fn delete_calendar_holidays(&self, calendar_id: &str) -> ApiResult<u64> {
+        let result = sqlx::query("DELETE FROM holidays WHERE calendar_id = ?")
+            .bind(calendar_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn find_holiday_on(
+        &self,
+        date: &str,
+        calendar_id: Option<&str>,
+    ) -> ApiResult<Option<Holiday>> {
+        Database::find_holiday_on(self, date, calendar_id).await
+    }
+}
@@ -0,0 +1,372 @@
+use sqlx::Row;
+
+use crate::domain::entities::{
+    ConversationEventType, ConversationStatus, Import, ImportCounts, ImportSource,
+    ImportedConversation, ImportedEntity, MessageType,
+};
+use crate::domain::ports::import_repository::ImportRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::conversation_events::{
+    record_conversation_event, record_tag_event,
+};
+use crate::infrastructure::persistence::Database;
+
+const IMPORT_COLUMNS: &str = "id, source, format, inbox_id, status, total_records,
+            processed_records, contacts_created, conversations_created, messages_created,
+            tags_created, attachments_created, tickets_skipped, tickets_failed, errors,
+            created_by, created_at, started_at, completed_at, updated_at";
+
+fn row_to_import(row: &sqlx::any::AnyRow) -> ApiResult<Import> {
+    let errors: String = row.try_get("errors")?;
+
+    Ok(Import {
+        id: row.try_get("id")?,
+        source: ImportSource::from(row.try_get::<String, _>("source")?),
+        format: row.try_get::<String, _>("format")?.into(),
+        inbox_id: row.try_get("inbox_id")?,
+        status: row.try_get::<String, _>("status")?.into(),
+        data: row.try_get("data").unwrap_or_default(),
+        total_records: row.try_get("total_records")?,
+        processed_records: row.try_get("processed_records")?,
+        counts: ImportCounts {
+            contacts_created: row.try_get("contacts_created")?,
+            conversations_created: row.try_get("conversations_created")?,
+            messages_created: row.try_get("messages_created")?,
+            tags_created: row.try_get("tags_created")?,
+            attachments_created: row.try_get("attachments_created")?,
+            tickets_skipped: row.try_get("tickets_skipped")?,
+            tickets_failed: row.try_get("tickets_failed")?,
+        },
+        errors: serde_json::from_str(&errors).unwrap_or_default(),
+        created_by: row.try_get("created_by").ok(),
+        created_at: row.try_get("created_at")?,
+        started_at: row.try_get("started_at").ok(),
+        completed_at: row.try_get("completed_at").ok(),
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+impl Database {
+    pub async fn create_import(&self, import: &Import) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO imports (
+                id, source, format, inbox_id, status, data, total_records, processed_records,
+                errors, created_by, created_at, updated_at
+             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&import.id)
+        .bind(import.source.as_str())
+        .bind(import.format.as_str())
+        .bind(&import.inbox_id)
+        .bind(import.status.as_str())
+        .bind(&import.data)
+        .bind(import.total_records)
+        .bind(import.processed_records)
+        .bind(serde_json::to_string(&import.errors).unwrap_or_else(|_| "[]".to_string()))
+        .bind(&import.created_by)
+        .bind(&import.created_at)
+        .bind(&import.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_import(&self, id: &str) -> ApiResult<Option<Import>> {
+        let row = sqlx::query(&format!(
+            "SELECT {}, data FROM imports WHERE id = ?",
+            IMPORT_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_import).transpose()
+    }
+
+    pub async fn list_imports(&self) -> ApiResult<Vec<Import>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM imports ORDER BY created_at DESC, id DESC",
+            IMPORT_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_import).collect()
+    }
+
+    pub async fn update_import_progress(&self, import: &Import) -> ApiResult<()> {
+        sqlx::query(
+            "UPDATE imports
+             SET status = ?, total_records = ?, processed_records = ?, contacts_created = ?,
+                 conversations_created = ?, messages_created = ?, tags_created = ?,
+                 attachments_created = ?, tickets_skipped = ?, tickets_failed = ?, errors = ?,
+                 started_at = ?, completed_at = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(import.status.as_str())
+        .bind(import.total_records)
+        .bind(import.processed_records)
+        .bind(import.counts.contacts_created)
+        .bind(import.counts.conversations_created)
+        .bind(import.counts.messages_created)
+        .bind(import.counts.tags_created)
+        .bind(import.counts.attachments_created)
+        .bind(import.counts.tickets_skipped)
+        .bind(import.counts.tickets_failed)
+        .bind(serde_json::to_string(&import.errors).unwrap_or_else(|_| "[]".to_string()))
+        .bind(&import.started_at)
+        .bind(&import.completed_at)
+        .bind(&import.updated_at)
+        .bind(&import.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn clear_import_data(&self, id: &str) -> ApiResult<()> {
+        sqlx::query("UPDATE imports SET data = '' WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_imported_record(
+        &self,
+        source: ImportSource,
+        entity: ImportedEntity,
+        external_id: &str,
+    ) -> ApiResult<Option<String>> {
+        let row = sqlx::query(
+            "SELECT local_id FROM import_records
+             WHERE source = ? AND entity_type = ? AND external_id = ?",
+        )
+        .bind(source.as_str())
+        .bind(entity.as_str())
+        .bind(external_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|row| row.try_get("local_id")).transpose()?)
+    }
+
+    pub async fn record_imported(
+        &self,
+        import_id: &str,
+        source: ImportSource,
+        entity: ImportedEntity,
+        external_id: &str,
+        local_id: &str,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO import_records (source, entity_type, external_id, local_id, import_id, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(source.as_str())
+        .bind(entity.as_str())
+        .bind(external_id)
+        .bind(local_id)
+        .bind(import_id)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn create_imported_conversation(
+        &self,
+        conversation: &ImportedConversation,
+    ) -> ApiResult<()> {
+        let last_message = conversation.messages.last().map(|(_, m)| m);
+        let last_reply_at = conversation
+            .messages
+            .iter()
+            .rev()
+            .find(|(_, m)| m.message_type == MessageType::Outgoing)
+            .map(|(_, m)| m.created_at.as_str());
+        // Exports don't say when a ticket was solved; its last update is the closest we have
+        let resolved_at = matches!(
+            conversation.status,
+            ConversationStatus::Resolved | ConversationStatus::Closed
+        )
+        .then_some(conversation.updated_at.as_str());
+        let closed_at = (conversation.status == ConversationStatus::Closed)
+            .then_some(conversation.updated_at.as_str());
+        let now = chrono::Utc::now().to_rfc3339();
+
+        let mut tx = self.pool.begin().await?;
+
+        // Written with its final state in one insert: updating a conversation
+        // afterwards would reset updated_at through the trigger
+        sqlx::query(
+            "INSERT INTO conversations (
+                id, reference_number, status, inbox_id, contact_id, subject, priority,
+                resolved_at, closed_at, last_message_id, last_message_at, last_reply_at,
+                created_at, updated_at
+             ) VALUES (
+                ?, (SELECT COALESCE(MAX(reference_number), 99) + 1 FROM conversations),
+                ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+             )",
+        )
+        .bind(&conversation.id)
+        .bind(conversation.status.to_string())
+        .bind(&conversation.inbox_id)
+        .bind(&conversation.contact_id)
+        .bind(&conversation.subject)
+        .bind(conversation.priority.map(|p| p.to_string()))
+        .bind(resolved_at)
+        .bind(closed_at)
+        .bind(last_message.map(|m| m.id.as_str()))
+        .bind(last_message.map(|m| m.created_at.as_str()))
+        .bind(last_reply_at)
+        .bind(&conversation.created_at)
+        .bind(&conversation.updated_at)
+        .execute(&mut *tx)
+        .await?;
+        record_conversation_event(
+            &mut tx,
+            &conversation.id,
+            ConversationEventType::Created,
+            None,
+            &serde_json::json!({
+                "status": conversation.status.to_string(),
+                "inbox_id": conversation.inbox_id,
+                "contact_id": conversation.contact_id,
+                "assigned_user_id": null,
+                "assigned_team_id": null,
+                "priority": conversation.priority.map(|p| p.to_string()),
+            }),
+        )
+        .await?;
+
+        for (_, message) in &conversation.messages {
+            sqlx::query(
+                "INSERT INTO messages (id, conversation_id, type, status, content, author_id, is_immutable, retry_count, created_at, sent_at, updated_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&message.id)
+            .bind(&message.conversation_id)
+            .bind(message.message_type.as_str())
+            .bind(message.status.as_str())
+            .bind(&message.content)
+            .bind(&message.author_id)
+            .bind(message.is_immutable)
+            .bind(message.retry_count)
+            .bind(&message.created_at)
+            .bind(&message.sent_at)
+            .bind(&message.updated_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        for tag_id in &conversation.tag_ids {
+            let added = sqlx::query(
+                "INSERT OR IGNORE INTO conversation_tags (conversation_id, tag_id, added_by, added_at)
+                 VALUES (?, ?, ?, ?)",
+            )
+            .bind(&conversation.id)
+            .bind(tag_id)
+            .bind(&conversation.tagged_by)
+            .bind(&conversation.created_at)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+            if added > 0 {
+                record_tag_event(
+                    &mut tx,
+                    &conversation.id,
+                    ConversationEventType::TagAdded,
+                    tag_id,
+                    Some(conversation.tagged_by.as_str()),
+                )
+                .await?;
+            }
+        }
+
+        let records = std::iter::once((
+            ImportedEntity::Conversation,
+            conversation.external_id.as_str(),
+            conversation.id.as_str(),
+        ))
+        .chain(conversation.messages.iter().map(|(external_id, message)| {
+            (
+                ImportedEntity::Message,
+                external_id.as_str(),
+                message.id.as_str(),
+            )
+        }));
+        for (entity, external_id, local_id) in records {
+            sqlx::query(
+                "INSERT INTO import_records (source, entity_type, external_id, local_id, import_id, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(conversation.source.as_str())
+            .bind(entity.as_str())
+            .bind(external_id)
+            .bind(local_id)
+            .bind(&conversation.import_id)
+            .bind(&now)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ImportRepository for Database {
+    async fn create_import(&self, import: &Import) -> ApiResult<()> {
+        self.create_import(import).await
+    }
+
+    async fn get_import(&self, id: &str) -> ApiResult<Option<Import>> {
+        self.get_import(id).await
+    }
+
+    async fn list_imports(&self) -> ApiResult<Vec<Import>> {
+        self.list_imports().await
+    }
+
+    async fn update_import_progress(&self, import: &Import) -> ApiResult<()> {
+        self.update_import_progress(import).await
+    }
+
+    async fn clear_import_data(&self, id: &str) -> ApiResult<()> {
+        self.clear_import_data(id).await
+    }
+
+    async fn find_imported_record(
+        &self,
+        source: ImportSource,
+        entity: ImportedEntity,
+        external_id: &str,
+    ) -> ApiResult<Option<String>> {
+        self.find_imported_record(source, entity, external_id).await
+    }
+
+    async fn record_imported(
+        &self,
+        import_id: &str,
+        source: ImportSource,
+        entity: ImportedEntity,
+        external_id: &str,
+        local_id: &str,
+    ) -> ApiResult<()> {
+        self.record_imported(import_id, source, entity, external_id, local_id)
+            .await
+    }
+
+    async fn create_imported_conversation(
+        &self,
+        conversation: &ImportedConversation,
+    ) -> ApiResult<()> {
+        self.create_imported_conversation(conversation).await
+    }
+}
@@ -94,6 +94,30 @@ impl InboxRepository for Database {
         }
         Ok(inboxes)
     }
+
+    async fn get_inbox(&self, inbox_id: &str) -> ApiResult<Option<Inbox>> {
+        let row = sqlx::query(
+            "SELECT id, name, channel_type, created_at, updated_at, deleted_at, deleted_by
+             FROM inboxes
+             WHERE id = ? AND deleted_at IS NULL",
+        )
+        .bind(inbox_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(Some(Inbox {
+                id: row.try_get("id")?,
+                name: row.try_get("name")?,
+                channel_type: row.try_get("channel_type")?,
+                created_at: row.try_get("created_at")?,
+                updated_at: row.try_get("updated_at")?,
+                deleted_at: row.try_get("deleted_at").ok(),
+                deleted_by: row.try_get("deleted_by").ok(),
+            })),
+            None => Ok(None),
+        }
+    }
 }
 
 // Legacy Inherent Implementation (delegating to trait impl if needed, or removing if safe)
@@ -114,4 +138,8 @@ impl Database {
     pub async fn list_inboxes(&self) -> ApiResult<Vec<Inbox>> {
         <Self as InboxRepository>::list_inboxes(self).await
     }
+
+    pub async fn get_inbox(&self, inbox_id: &str) -> ApiResult<Option<Inbox>> {
+        <Self as InboxRepository>::get_inbox(self, inbox_id).await
+    }
 }
@@ -0,0 +1,172 @@
+use sqlx::Row;
+
+use crate::domain::entities::BlockedSender;
+use crate::domain::ports::junk_repository::JunkRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+fn row_to_blocked_sender(row: &sqlx::any::AnyRow) -> ApiResult<BlockedSender> {
+    Ok(BlockedSender {
+        id: row.try_get("id")?,
+        inbox_id: row.try_get("inbox_id")?,
+        email: row.try_get("email")?,
+        blocked_by: row.try_get("blocked_by").ok().flatten(),
+        conversation_id: row.try_get("conversation_id").ok().flatten(),
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+impl Database {
+    pub async fn get_conversation_sender_email(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Option<String>> {
+        let email: Option<String> = sqlx::query_scalar(
+            "SELECT u.email
+             FROM conversations c
+             JOIN contacts ct ON ct.id = c.contact_id
+             JOIN users u ON u.id = ct.user_id
+             WHERE c.id = ?",
+        )
+        .bind(conversation_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(email)
+    }
+
+    pub async fn increment_sender_junk_count(&self, email: &str) -> ApiResult<i64> {
+        let now = chrono::Utc::now().to_rfc3339();
+        sqlx::query(
+            "INSERT INTO sender_reputations (email, junk_count, last_marked_at, updated_at)
+             VALUES (?, 1, ?, ?)
+             ON CONFLICT(email) DO UPDATE SET
+                junk_count = junk_count + 1,
+                last_marked_at = excluded.last_marked_at,
+                updated_at = excluded.updated_at",
+        )
+        .bind(email)
+        .bind(&now)
+        .bind(&now)
+        .execute(&self.pool)
+        .await?;
+
+        self.get_sender_junk_count(email).await
+    }
+
+    pub async fn decrement_sender_junk_count(&self, email: &str) -> ApiResult<i64> {
+        sqlx::query(
+            "UPDATE sender_reputations
+             SET junk_count = MAX(junk_count - 1, 0), updated_at = ?
+             WHERE email = ?",
+        )
+        .bind(chrono::Utc::now().to_rfc3339())
+        .bind(email)
+        .execute(&self.pool)
+        .await?;
+
+        self.get_sender_junk_count(email).await
+    }
+
+    pub async fn get_sender_junk_count(&self, email: &str) -> ApiResult<i64> {
+        let count: Option<i64> =
+            sqlx::query_scalar("SELECT junk_count FROM sender_reputations WHERE email = ?")
+                .bind(email)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(count.unwrap_or(0))
+    }
+
+    pub async fn block_sender(&self, sender: &BlockedSender) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT OR IGNORE INTO inbox_blocked_senders
+             (id, inbox_id, email, blocked_by, conversation_id, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&sender.id)
+        .bind(&sender.inbox_id)
+        .bind(&sender.email)
+        .bind(&sender.blocked_by)
+        .bind(&sender.conversation_id)
+        .bind(&sender.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn is_sender_blocked(&self, inbox_id: &str, email: &str) -> ApiResult<bool> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM inbox_blocked_senders WHERE inbox_id = ? AND email = ?",
+        )
+        .bind(inbox_id)
+        .bind(email)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count > 0)
+    }
+
+    pub async fn list_blocked_senders(&self, inbox_id: &str) -> ApiResult<Vec<BlockedSender>> {
+        let rows = sqlx::query(
+            "SELECT id, inbox_id, email, blocked_by, conversation_id, created_at
+             FROM inbox_blocked_senders
+             WHERE inbox_id = ?
+             ORDER BY created_at DESC",
+        )
+        .bind(inbox_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_blocked_sender).collect()
+    }
+
+    pub async fn delete_blocked_sender(&self, inbox_id: &str, id: &str) -> ApiResult<bool> {
+        let result = sqlx::query("DELETE FROM inbox_blocked_senders WHERE inbox_id = ? AND id = ?")
+            .bind(inbox_id)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[async_trait::async_trait]
+impl JunkRepository for Database {
+    async fn get_conversation_sender_email(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Option<String>> {
+        self.get_conversation_sender_email(conversation_id).await
+    }
+
+    async fn increment_sender_junk_count(&self, email: &str) -> ApiResult<i64> {
+        self.increment_sender_junk_count(email).await
+    }
+
+    async fn decrement_sender_junk_count(&self, email: &str) -> ApiResult<i64> {
+        self.decrement_sender_junk_count(email).await
+    }
+
+    async fn get_sender_junk_count(&self, email: &str) -> ApiResult<i64> {
+        self.get_sender_junk_count(email).await
+    }
+
+    async fn block_sender(&self, sender: &BlockedSender) -> ApiResult<()> {
+        self.block_sender(sender).await
+    }
+
+    async fn is_sender_blocked(&self, inbox_id: &str, email: &str) -> ApiResult<bool> {
+        self.is_sender_blocked(inbox_id, email).await
+    }
+
+    async fn list_blocked_senders(&self, inbox_id: &str) -> ApiResult<Vec<BlockedSender>> {
+        self.list_blocked_senders(inbox_id).await
+    }
+
+    async fn delete_blocked_sender(&self, inbox_id: &str, id: &str) -> ApiResult<bool> {
+        self.delete_blocked_sender(inbox_id, id).await
+    }
+}
@@ -0,0 +1,171 @@
+use sqlx::Row;
+
+use crate::domain::entities::LanguageRoutingRule;
+use crate::domain::ports::language_routing_repository::LanguageRoutingRepository;
+use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
+use crate::infrastructure::persistence::Database;
+
+fn row_to_rule(row: &sqlx::any::AnyRow) -> ApiResult<LanguageRoutingRule> {
+    Ok(LanguageRoutingRule {
+        id: row.try_get("id")?,
+        language: row.try_get("language").ok(),
+        team_id: row.try_get("team_id")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+impl Database {
+    pub async fn create_language_rule(&self, rule: &LanguageRoutingRule) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO language_routing_rules (id, language, team_id, created_at)
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(&rule.id)
+        .bind(&rule.language)
+        .bind(&rule.team_id)
+        .bind(&rule.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if e.to_string().contains("UNIQUE") {
+                ApiError::Conflict(match &rule.language {
+                    Some(language) => format!("A routing rule for '{}' already exists", language),
+                    None => "A default team is already set".to_string(),
+                })
+            } else {
+                ApiError::Internal(e.to_string())
+            }
+        })?;
+
+        Ok(())
+    }
+
+    pub async fn list_language_rules(&self) -> ApiResult<Vec<LanguageRoutingRule>> {
+        // Default-team rule last
+        let rows = sqlx::query(
+            "SELECT id, language, team_id, created_at FROM language_routing_rules
+             ORDER BY language IS NULL, language",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_rule).collect()
+    }
+
+    pub async fn delete_language_rule(&self, id: &str) -> ApiResult<bool> {
+        let result = sqlx::query("DELETE FROM language_routing_rules WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn find_language_rule(
+        &self,
+        language: Option<&str>,
+    ) -> ApiResult<Option<LanguageRoutingRule>> {
+        let row = sqlx::query(
+            "SELECT id, language, team_id, created_at FROM language_routing_rules
+             WHERE COALESCE(language, '') = ?",
+        )
+        .bind(language.unwrap_or(""))
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_rule).transpose()
+    }
+
+    pub async fn team_has_language_skill(&self, team_id: &str, language: &str) -> ApiResult<bool> {
+        let row = sqlx::query(
+            "SELECT COUNT(*) AS count
+             FROM team_memberships tm, json_each(tm.languages) skill
+             WHERE tm.team_id = ? AND skill.value = ?",
+        )
+        .bind(team_id)
+        .bind(language)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let count: i64 = row.try_get("count")?;
+        Ok(count > 0)
+    }
+
+    pub async fn set_member_languages(
+        &self,
+        team_id: &str,
+        user_id: &str,
+        languages: &[String],
+    ) -> ApiResult<bool> {
+        let languages = serde_json::to_string(languages)
+            .map_err(|e| ApiError::Internal(format!("Failed to encode languages: {}", e)))?;
+
+        let result = sqlx::query(
+            "UPDATE team_memberships SET languages = ? WHERE team_id = ? AND user_id = ?",
+        )
+        .bind(languages)
+        .bind(team_id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn set_conversation_language(
+        &self,
+        conversation_id: &str,
+        language: &str,
+    ) -> ApiResult<()> {
+        sqlx::query("UPDATE conversations SET language = ? WHERE id = ?")
+            .bind(language)
+            .bind(conversation_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl LanguageRoutingRepository for Database {
+    async fn create_language_rule(&self, rule: &LanguageRoutingRule) -> ApiResult<()> {
+        Database::create_language_rule(self, rule).await
+    }
+
+    async fn list_language_rules(&self) -> ApiResult<Vec<LanguageRoutingRule>> {
+        Database::list_language_rules(self).await
+    }
+
+    async fn delete_language_rule(&self, id: &str) -> ApiResult<bool> {
+        Database::delete_language_rule(self, id).await
+    }
+
+    async fn find_language_rule(
+        &self,
+        language: Option<&str>,
+    ) -> ApiResult<Option<LanguageRoutingRule>> {
+        Database::find_language_rule(self, language).await
+    }
+
+    async fn team_has_language_skill(&self, team_id: &str, language: &str) -> ApiResult<bool> {
+        Database::team_has_language_skill(self, team_id, language).await
+    }
+
+    async fn set_member_languages(
+        &self,
+        team_id: &str,
+        user_id: &str,
+        languages: &[String],
+    ) -> ApiResult<bool> {
+        Database::set_member_languages(self, team_id, user_id, languages).await
+    }
+
+    async fn set_conversation_language(
+        &self,
+        conversation_id: &str,
+        language: &str,
+    ) -> ApiResult<()> {
+        Database::set_conversation_language(self, conversation_id, language).await
+    }
+}
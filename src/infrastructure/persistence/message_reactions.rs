@@ -0,0 +1,106 @@
+use sqlx::Row;
+
+use crate::domain::entities::MessageReaction;
+use crate::domain::ports::message_reaction_repository::MessageReactionRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+impl Database {
+    pub async fn add_message_reaction(&self, reaction: &MessageReaction) -> ApiResult<bool> {
+        let result = sqlx::query(
+            "INSERT OR IGNORE INTO message_reactions (id, message_id, user_id, emoji, created_at)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&reaction.id)
+        .bind(&reaction.message_id)
+        .bind(&reaction.user_id)
+        .bind(&reaction.emoji)
+        .bind(&reaction.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn remove_message_reaction(
+        &self,
+        message_id: &str,
+        user_id: &str,
+        emoji: &str,
+    ) -> ApiResult<bool> {
+        let result = sqlx::query(
+            "DELETE FROM message_reactions WHERE message_id = ? AND user_id = ? AND emoji = ?",
+        )
+        .bind(message_id)
+        .bind(user_id)
+        .bind(emoji)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn list_message_reactions(
+        &self,
+        message_ids: &[String],
+    ) -> ApiResult<Vec<MessageReaction>> {
+        if message_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = message_ids
+            .iter()
+            .map(|_| "?")
+            .collect::<Vec<_>>()
+            .join(", ");
+        let query = format!(
+            "SELECT id, message_id, user_id, emoji, created_at
+             FROM message_reactions
+             WHERE message_id IN ({})
+             ORDER BY created_at ASC",
+            placeholders
+        );
+
+        let mut query_builder = sqlx::query(&query);
+        for message_id in message_ids {
+            query_builder = query_builder.bind(message_id);
+        }
+        let rows = query_builder.fetch_all(&self.pool).await?;
+
+        let mut reactions = Vec::new();
+        for row in rows {
+            reactions.push(MessageReaction {
+                id: row.try_get("id")?,
+                message_id: row.try_get("message_id")?,
+                user_id: row.try_get("user_id")?,
+                emoji: row.try_get("emoji")?,
+                created_at: row.try_get("created_at")?,
+            });
+        }
+
+        Ok(reactions)
+    }
+}
+
+#[async_trait::async_trait]
+impl MessageReactionRepository for Database {
+    async fn add_message_reaction(&self, reaction: &MessageReaction) -> ApiResult<bool> {
+        Database::add_message_reaction(self, reaction).await
+    }
+
+    async fn remove_message_reaction(
+        &self,
+        message_id: &str,
+        user_id: &str,
+        emoji: &str,
+    ) -> ApiResult<bool> {
+        Database::remove_message_reaction(self, message_id, user_id, emoji).await
+    }
+
+    async fn list_message_reactions(
+        &self,
+        message_ids: &[String],
+    ) -> ApiResult<Vec<MessageReaction>> {
+        Database::list_message_reactions(self, message_ids).await
+    }
+}
@@ -1,4 +1,6 @@
-use crate::domain::entities::{Message, MessageStatus, MessageType};
+use crate::domain::entities::{
+    AutoGeneratedEmailKind, Message, MessageStatus, MessageType, SystemNote,
+};
 use crate::infrastructure::http::middleware::error::ApiResult;
 use crate::infrastructure::persistence::Database;
 use sqlx::Row;
@@ -11,8 +13,8 @@ impl MessageRepository for Database {
     #[tracing::instrument(skip(self))]
     async fn create_message(&self, message: &Message) -> ApiResult<()> {
         sqlx::query(
-            "INSERT INTO messages (id, conversation_id, type, status, content, author_id, is_immutable, retry_count, created_at, sent_at, updated_at)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO messages (id, conversation_id, type, status, content, author_id, is_immutable, retry_count, created_at, sent_at, updated_at, channel, channel_metadata, calendar_invite, email_authentication)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
             .bind(&message.id)
             .bind(&message.conversation_id)
@@ -25,6 +27,25 @@ impl MessageRepository for Database {
             .bind(&message.created_at)
             .bind(&message.sent_at)
             .bind(&message.updated_at)
+            .bind(&message.channel)
+            .bind(
+                message
+                    .channel_metadata
+                    .as_ref()
+                    .map(|metadata| metadata.to_string()),
+            )
+            .bind(
+                message
+                    .calendar_invite
+                    .as_ref()
+                    .and_then(|invite| serde_json::to_string(invite).ok()),
+            )
+            .bind(
+                message
+                    .email_authentication
+                    .as_ref()
+                    .and_then(|results| serde_json::to_string(results).ok()),
+            )
             .execute(&self.pool)
             .await?;
 
@@ -34,7 +55,7 @@ impl MessageRepository for Database {
     #[tracing::instrument(skip(self))]
     async fn get_message_by_id(&self, message_id: &str) -> ApiResult<Option<Message>> {
         let row = sqlx::query(
-            "SELECT id, conversation_id, type, status, content, author_id, is_immutable, retry_count, created_at, sent_at, updated_at
+            "SELECT id, conversation_id, type, status, content, author_id, is_immutable, retry_count, created_at, sent_at, updated_at, channel, channel_metadata, calendar_invite, email_authentication
              FROM messages
              WHERE id = ?",
         )
@@ -53,11 +74,25 @@ impl MessageRepository for Database {
                 status: MessageStatus::from(status_str),
                 content: row.try_get("content")?,
                 author_id: row.try_get("author_id")?,
-                is_immutable: row.try_get("is_immutable")?,
+                is_immutable: row.try_get::<i32, _>("is_immutable")? != 0,
                 retry_count: row.try_get("retry_count")?,
                 created_at: row.try_get("created_at")?,
                 sent_at: row.try_get("sent_at").ok(),
                 updated_at: row.try_get("updated_at")?,
+                channel: row.try_get("channel").ok(),
+                channel_metadata: row
+                    .try_get::<String, _>("channel_metadata")
+                    .ok()
+                    .and_then(|metadata| serde_json::from_str(&metadata).ok()),
+                calendar_invite: row
+                    .try_get::<String, _>("calendar_invite")
+                    .ok()
+                    .and_then(|invite| serde_json::from_str(&invite).ok()),
+                email_authentication: row
+                    .try_get::<String, _>("email_authentication")
+                    .ok()
+                    .and_then(|results| serde_json::from_str(&results).ok()),
+                reactions: Vec::new(),
             }))
         } else {
             Ok(None)
@@ -80,7 +115,7 @@ impl MessageRepository for Database {
 
         // Get messages
         let rows = sqlx::query(
-            "SELECT id, conversation_id, type, status, content, author_id, is_immutable, retry_count, created_at, sent_at, updated_at
+            "SELECT id, conversation_id, type, status, content, author_id, is_immutable, retry_count, created_at, sent_at, updated_at, channel, channel_metadata, calendar_invite, email_authentication
              FROM messages
              WHERE conversation_id = ?
              ORDER BY created_at DESC
@@ -109,6 +144,20 @@ impl MessageRepository for Database {
                 created_at: row.try_get("created_at")?,
                 sent_at: row.try_get("sent_at").ok(),
                 updated_at: row.try_get("updated_at")?,
+                channel: row.try_get("channel").ok(),
+                channel_metadata: row
+                    .try_get::<String, _>("channel_metadata")
+                    .ok()
+                    .and_then(|metadata| serde_json::from_str(&metadata).ok()),
+                calendar_invite: row
+                    .try_get::<String, _>("calendar_invite")
+                    .ok()
+                    .and_then(|invite| serde_json::from_str(&invite).ok()),
+                email_authentication: row
+                    .try_get::<String, _>("email_authentication")
+                    .ok()
+                    .and_then(|results| serde_json::from_str(&results).ok()),
+                reactions: Vec::new(),
             });
         }
 
@@ -189,6 +238,7 @@ impl MessageRepository for Database {
             .execute(&self.pool)
             .await?;
         }
+        self.cache.invalidate_conversation(conversation_id).await;
 
         Ok(())
     }
@@ -203,6 +253,54 @@ impl MessageRepository for Database {
         Ok(count)
     }
 
+    async fn create_system_note(&self, note: &SystemNote) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO system_notes (id, conversation_id, kind, from_address, subject, content,
+                                       email_message_id, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&note.id)
+        .bind(&note.conversation_id)
+        .bind(note.kind.as_str())
+        .bind(&note.from_address)
+        .bind(&note.subject)
+        .bind(&note.content)
+        .bind(&note.email_message_id)
+        .bind(&note.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list_system_notes(&self, conversation_id: &str) -> ApiResult<Vec<SystemNote>> {
+        let rows = sqlx::query(
+            "SELECT id, conversation_id, kind, from_address, subject, content,
+                    email_message_id, created_at
+             FROM system_notes
+             WHERE conversation_id = ?
+             ORDER BY created_at ASC",
+        )
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(SystemNote {
+                    id: row.try_get("id")?,
+                    conversation_id: row.try_get("conversation_id")?,
+                    kind: AutoGeneratedEmailKind::from(row.try_get::<String, _>("kind")?),
+                    from_address: row.try_get("from_address")?,
+                    subject: row.try_get("subject").ok(),
+                    content: row.try_get("content")?,
+                    email_message_id: row.try_get("email_message_id")?,
+                    created_at: row.try_get("created_at")?,
+                })
+            })
+            .collect()
+    }
+
     async fn create_notification(
         &self,
         notification: &crate::domain::entities::UserNotification,
@@ -5,33 +5,64 @@ use sqlx::{
 use std::str::FromStr;
 use tracing::log::LevelFilter;
 
+mod activity;
 pub mod agents;
 pub mod api_key;
+mod api_key_usage;
+mod attachment_rules;
 pub mod auth_event;
+mod auto_tag_rules;
 mod automation;
 pub mod automation_rules;
+pub mod cache;
+mod channels;
+mod contact_notes;
 mod contacts;
+mod conversation_contacts;
+mod conversation_events;
+mod conversation_summaries;
+mod conversation_transfers;
 mod conversations;
+mod csat;
+pub mod diagnostics;
 pub mod distributed_lock;
 mod email;
+mod email_oauth;
+mod gdpr;
 mod holiday;
+mod imports;
 mod inboxes;
+mod junk;
+mod language_routing;
 mod macros;
+mod message_reactions;
 mod messages;
 mod notification;
 mod oidc;
 mod password_reset;
+mod priority_matrix;
+mod reporting;
+mod response_reminders;
 mod roles;
+mod saved_views;
+mod sentiment;
 mod sessions;
+mod shifts;
+mod skills;
 mod sla;
+mod sms;
 mod system_config;
 mod tags;
 mod teams;
+mod telegram;
 pub mod templates;
+mod user_locales;
 mod users;
 mod webhook;
 pub struct Database {
     pub(crate) pool: AnyPool,
+    pub(crate) cache: cache::RowCache,
+    pub(crate) slow_queries: diagnostics::SlowQueryLog,
 }
 
 #[cfg(test)]
@@ -41,21 +72,37 @@ impl Database {
         let pool = AnyPoolOptions::new()
             .connect_lazy("sqlite::memory:")
             .expect("Failed to create lazy pool");
-        Self { pool }
+        Self {
+            pool,
+            cache: cache::RowCache::new(&cache::RowCacheConfig::default()),
+            slow_queries: diagnostics::SlowQueryLog::new(Default::default()),
+        }
     }
 }
 
 impl Database {
     pub async fn connect(database_url: &str) -> Result<Self, sqlx::Error> {
+        Self::connect_with_diagnostics(database_url, diagnostics::SlowQueryConfig::from_env()).await
+    }
+
+    pub async fn connect_with_diagnostics(
+        database_url: &str,
+        slow_query_config: diagnostics::SlowQueryConfig,
+    ) -> Result<Self, sqlx::Error> {
         // Ensure drivers are installed for AnyPool
         sqlx::any::install_default_drivers();
 
         let mut connect_options = AnyConnectOptions::from_str(database_url)?;
 
-        // Configure logging
+        // Configure logging; diagnostics mode lowers the slow threshold
+        let slow_threshold = if slow_query_config.enabled {
+            slow_query_config.threshold
+        } else {
+            std::time::Duration::from_secs(1)
+        };
         connect_options = connect_options
             .log_statements(LevelFilter::Info)
-            .log_slow_statements(LevelFilter::Warn, std::time::Duration::from_secs(1));
+            .log_slow_statements(LevelFilter::Warn, slow_threshold);
 
         tracing::info!("Database connection options configured with LevelFilter::Info");
 
@@ -81,7 +128,20 @@ impl Database {
                 .await?;
         }
 
-        Ok(Self { pool })
+        let slow_queries = diagnostics::SlowQueryLog::new(slow_query_config);
+        if slow_queries.config().enabled {
+            slow_queries.watch(pool.clone(), database_url.starts_with("sqlite"));
+            tracing::info!(
+                "Slow query log enabled (threshold {} ms)",
+                slow_queries.config().threshold.as_millis()
+            );
+        }
+
+        Ok(Self {
+            pool,
+            cache: cache::RowCache::new(&cache::RowCacheConfig::from_env()),
+            slow_queries,
+        })
     }
 
     pub async fn run_migrations(&self) -> Result<(), sqlx::Error> {
@@ -98,6 +158,8 @@ impl Clone for Database {
     fn clone(&self) -> Self {
         Self {
             pool: self.pool.clone(),
+            cache: self.cache.clone(),
+            slow_queries: self.slow_queries.clone(),
         }
     }
 }
@@ -1,6 +1,6 @@
 use sqlx::Row;
 
-use crate::{ApiResult, Database, NotificationType, UserNotification};
+use crate::{ApiResult, Database, NotificationStreamEvent, NotificationType, UserNotification};
 
 impl Database {
     pub async fn create_notification(&self, notification: &UserNotification) -> ApiResult<()> {
@@ -147,11 +147,81 @@ impl Database {
 
         Ok(result.rows_affected() as i32)
     }
+
+    pub async fn append_stream_event(&self, user_id: &str, payload: &str) -> ApiResult<i64> {
+        // fetch_all (not fetch_one) so SQLite steps the INSERT to completion
+        let rows = sqlx::query(
+            "INSERT INTO notification_stream_events (user_id, payload, created_at)
+             VALUES (?, ?, ?)
+             RETURNING sequence",
+        )
+        .bind(user_id)
+        .bind(payload)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .fetch_all(&self.pool)
+        .await?;
+
+        let row = rows.first().ok_or_else(|| {
+            crate::ApiError::Internal("Failed to read notification stream sequence".to_string())
+        })?;
+
+        Ok(row.try_get("sequence")?)
+    }
+
+    pub async fn list_stream_events_after(
+        &self,
+        user_id: &str,
+        after_sequence: i64,
+        limit: i64,
+    ) -> ApiResult<Vec<NotificationStreamEvent>> {
+        let rows = sqlx::query(
+            "SELECT sequence, user_id, payload, created_at
+             FROM notification_stream_events
+             WHERE user_id = ? AND sequence > ?
+             ORDER BY sequence ASC
+             LIMIT ?",
+        )
+        .bind(user_id)
+        .bind(after_sequence)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut events = Vec::new();
+        for row in rows {
+            events.push(NotificationStreamEvent {
+                sequence: row.try_get("sequence")?,
+                user_id: row.try_get("user_id")?,
+                payload: row.try_get("payload")?,
+                created_at: row.try_get("created_at")?,
+            });
+        }
+
+        Ok(events)
+    }
+
+    pub async fn delete_old_stream_events(&self, older_than_hours: i64) -> ApiResult<i32> {
+        let cutoff = (chrono::Utc::now() - chrono::Duration::hours(older_than_hours)).to_rfc3339();
+
+        let result = sqlx::query(
+            "DELETE FROM notification_stream_events
+             WHERE created_at < ?",
+        )
+        .bind(&cutoff)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() as i32)
+    }
 }
 
 // Implement NotificationRepository trait for Database
 #[async_trait::async_trait]
 impl crate::domain::ports::notification_repository::NotificationRepository for Database {
+    async fn create_notification(&self, notification: &UserNotification) -> ApiResult<()> {
+        self.create_notification(notification).await
+    }
+
     async fn get_unread_count(&self, user_id: &str) -> ApiResult<i32> {
         self.get_unread_count(user_id).await
     }
@@ -171,4 +241,18 @@ impl crate::domain::ports::notification_repository::NotificationRepository for D
     async fn mark_all_notifications_as_read(&self, user_id: &str) -> ApiResult<i32> {
         self.mark_all_notifications_as_read(user_id).await
     }
+
+    async fn append_stream_event(&self, user_id: &str, payload: &str) -> ApiResult<i64> {
+        self.append_stream_event(user_id, payload).await
+    }
+
+    async fn list_stream_events_after(
+        &self,
+        user_id: &str,
+        after_sequence: i64,
+        limit: i64,
+    ) -> ApiResult<Vec<NotificationStreamEvent>> {
+        self.list_stream_events_after(user_id, after_sequence, limit)
+            .await
+    }
 }
@@ -1,4 +1,4 @@
-use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
 use crate::infrastructure::persistence::Database;
 use crate::domain::entities::PasswordResetToken;
 use sqlx::Row;
@@ -10,12 +10,12 @@ impl Database {
     /// Create a password reset token
     pub async fn create_password_reset_token(&self, token: &PasswordResetToken) -> ApiResult<()> {
         sqlx::query(
-            "INSERT INTO password_reset_tokens (id, user_id, token, expires_at, used, created_at)
+            "INSERT INTO password_reset_tokens (id, user_id, token_hash, expires_at, used, created_at)
              VALUES (?, ?, ?, ?, ?, ?)",
         )
         .bind(&token.id)
         .bind(&token.user_id)
-        .bind(&token.token)
+        .bind(&token.token_hash)
         .bind(&token.expires_at)
         .bind(if token.used { 1 } else { 0 })
         .bind(&token.created_at)
@@ -25,17 +25,17 @@ impl Database {
         Ok(())
     }
 
-    /// Get password reset token by token value
+    /// Get password reset token by token value (looked up by its hash)
     pub async fn get_password_reset_token(
         &self,
         token: &str,
     ) -> ApiResult<Option<PasswordResetToken>> {
         let row = sqlx::query(
-            "SELECT id, user_id, token, expires_at, used, created_at
+            "SELECT id, user_id, token_hash, expires_at, used, created_at
              FROM password_reset_tokens
-             WHERE token = ?",
+             WHERE token_hash = ?",
         )
-        .bind(token)
+        .bind(PasswordResetToken::hash_token(token))
         .fetch_optional(&self.pool)
         .await?;
 
@@ -43,7 +43,7 @@ impl Database {
             Ok(Some(PasswordResetToken {
                 id: row.try_get("id")?,
                 user_id: row.try_get("user_id")?,
-                token: row.try_get("token")?,
+                token_hash: row.try_get("token_hash")?,
                 expires_at: row.try_get("expires_at")?,
                 used: row.try_get::<i32, _>("used")? == 1,
                 created_at: row.try_get("created_at")?,
@@ -53,12 +53,12 @@ impl Database {
         }
     }
 
-    /// Count recent password reset requests for a user (for rate limiting)
-    /// Returns count of ALL tokens created in the last hour (used or unused)
-    /// Rate limiting counts all requests to prevent abuse, regardless of token status
+    /// Count password reset requests for an email address (for rate limiting)
+    /// Counts every accepted request in the window, whether or not an account
+    /// exists for the address
     pub async fn count_recent_reset_requests(
         &self,
-        user_id: &str,
+        email: &str,
         window_seconds: i64,
     ) -> ApiResult<i64> {
         let now = time::OffsetDateTime::now_utc();
@@ -69,10 +69,10 @@ impl Database {
 
         let row = sqlx::query(
             "SELECT COUNT(*) as count
-             FROM password_reset_tokens
-             WHERE user_id = ? AND created_at > ?",
+             FROM password_reset_requests
+             WHERE email = ? AND created_at > ?",
         )
-        .bind(user_id)
+        .bind(email)
         .bind(&window_start_str)
         .fetch_one(&self.pool)
         .await?;
@@ -80,6 +80,30 @@ impl Database {
         Ok(row.try_get("count")?)
     }
 
+    /// Record a password reset request, dropping requests older than a day
+    pub async fn record_password_reset_request(&self, email: &str) -> ApiResult<()> {
+        let now = time::OffsetDateTime::now_utc();
+        let format = &time::format_description::well_known::Rfc3339;
+        let cutoff = (now - time::Duration::days(1)).format(format).unwrap();
+
+        sqlx::query("DELETE FROM password_reset_requests WHERE created_at < ?")
+            .bind(&cutoff)
+            .execute(&self.pool)
+            .await?;
+
+        sqlx::query(
+            "INSERT INTO password_reset_requests (id, email, created_at)
+             VALUES (?, ?, ?)",
+        )
+        .bind(uuid::Uuid::new_v4().to_string())
+        .bind(email)
+        .bind(now.format(format).unwrap())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
     /// Mark password reset token as used
     pub async fn mark_token_as_used(&self, token_id: &str) -> ApiResult<()> {
         sqlx::query(
@@ -124,11 +148,12 @@ impl Database {
 
     /// Reset password with transaction (Feature 017: Password Reset)
     /// Performs all password reset operations atomically:
-    /// 1. Update agent password
-    /// 2. Mark token as used
+    /// 1. Claim the token (mark it used if it still is unused)
+    /// 2. Update agent password
     /// 3. Delete all user sessions
     ///
-    /// If any step fails, the entire transaction is rolled back
+    /// If any step fails, or another request claimed the token first, the
+    /// entire transaction is rolled back
     pub async fn reset_password_atomic(
         &self,
         user_id: &str,
@@ -137,7 +162,23 @@ impl Database {
     ) -> ApiResult<u64> {
         let mut tx = self.pool.begin().await?;
 
-        // 1. Update agent password
+        // 1. Claim the token; a concurrent reset with the same token finds it used
+        let claimed = sqlx::query(
+            "UPDATE password_reset_tokens
+             SET used = 1
+             WHERE id = ? AND used = 0",
+        )
+        .bind(token_id)
+        .execute(&mut *tx)
+        .await?;
+        if claimed.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Err(ApiError::BadRequest(
+                "Invalid or expired reset token".to_string(),
+            ));
+        }
+
+        // 2. Update agent password
         sqlx::query(
             "UPDATE agents
              SET password_hash = ?
@@ -148,16 +189,6 @@ impl Database {
         .execute(&mut *tx)
         .await?;
 
-        // 2. Mark token as used
-        sqlx::query(
-            "UPDATE password_reset_tokens
-             SET used = 1
-             WHERE id = ?",
-        )
-        .bind(token_id)
-        .execute(&mut *tx)
-        .await?;
-
         // 3. Delete all user sessions
         let result = sqlx::query(
             "DELETE FROM sessions
@@ -179,7 +210,7 @@ impl Database {
         user_id: &str,
     ) -> ApiResult<Vec<PasswordResetToken>> {
         let rows = sqlx::query(
-            "SELECT id, user_id, token, expires_at, used, created_at
+            "SELECT id, user_id, token_hash, expires_at, used, created_at
              FROM password_reset_tokens WHERE user_id = ? ORDER BY created_at DESC",
         )
         .bind(user_id)
@@ -192,7 +223,7 @@ impl Database {
             tokens.push(PasswordResetToken {
                 id: row.try_get("id")?,
                 user_id: row.try_get("user_id")?,
-                token: row.try_get("token")?,
+                token_hash: row.try_get("token_hash")?,
                 expires_at: row.try_get("expires_at")?,
                 used: used_int != 0,
                 created_at: row.try_get("created_at")?,
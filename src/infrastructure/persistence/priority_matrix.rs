@@ -0,0 +1,123 @@
+use sqlx::Row;
+
+use crate::domain::entities::{Priority, PriorityMatrixEntry};
+use crate::domain::ports::priority_matrix_repository::PriorityMatrixRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+fn row_to_priority_matrix_entry(row: &sqlx::any::AnyRow) -> ApiResult<PriorityMatrixEntry> {
+    Ok(PriorityMatrixEntry {
+        id: row.try_get("id")?,
+        tier: row.try_get("tier").ok().flatten(),
+        tag_id: row.try_get("tag_id").ok().flatten(),
+        priority: Priority::from(row.try_get::<String, _>("priority")?),
+        created_by: row.try_get("created_by")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+impl Database {
+    pub async fn create_priority_matrix_entry(&self, entry: &PriorityMatrixEntry) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO priority_matrix_entries (id, tier, tag_id, priority, created_by, created_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&entry.id)
+        .bind(&entry.tier)
+        .bind(&entry.tag_id)
+        .bind(entry.priority.to_string())
+        .bind(&entry.created_by)
+        .bind(&entry.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_priority_matrix_entries(&self) -> ApiResult<Vec<PriorityMatrixEntry>> {
+        let rows = sqlx::query(
+            "SELECT id, tier, tag_id, priority, created_by, created_at
+             FROM priority_matrix_entries
+             ORDER BY created_at ASC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_priority_matrix_entry).collect()
+    }
+
+    pub async fn delete_priority_matrix_entry(&self, id: &str) -> ApiResult<bool> {
+        let result = sqlx::query("DELETE FROM priority_matrix_entries WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn set_contact_tier(&self, user_id: &str, tier: Option<&str>) -> ApiResult<bool> {
+        let result = sqlx::query("UPDATE contacts SET tier = ? WHERE user_id = ?")
+            .bind(tier.map(str::to_string))
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn get_contact_tier(&self, user_id: &str) -> ApiResult<Option<String>> {
+        let row = sqlx::query("SELECT tier FROM contacts WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.and_then(|row| row.try_get("tier").ok().flatten()))
+    }
+
+    pub async fn get_conversation_contact_tier(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Option<String>> {
+        let row = sqlx::query(
+            "SELECT ct.tier
+             FROM conversations c
+             JOIN contacts ct ON ct.id = c.contact_id
+             WHERE c.id = ?",
+        )
+        .bind(conversation_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.and_then(|row| row.try_get("tier").ok().flatten()))
+    }
+}
+
+#[async_trait::async_trait]
+impl PriorityMatrixRepository for Database {
+    async fn create_priority_matrix_entry(&self, entry: &PriorityMatrixEntry) -> ApiResult<()> {
+        self.create_priority_matrix_entry(entry).await
+    }
+
+    async fn list_priority_matrix_entries(&self) -> ApiResult<Vec<PriorityMatrixEntry>> {
+        self.list_priority_matrix_entries().await
+    }
+
+    async fn delete_priority_matrix_entry(&self, id: &str) -> ApiResult<bool> {
+        self.delete_priority_matrix_entry(id).await
+    }
+
+    async fn set_contact_tier(&self, user_id: &str, tier: Option<&str>) -> ApiResult<bool> {
+        self.set_contact_tier(user_id, tier).await
+    }
+
+    async fn get_contact_tier(&self, user_id: &str) -> ApiResult<Option<String>> {
+        self.get_contact_tier(user_id).await
+    }
+
+    async fn get_conversation_contact_tier(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Option<String>> {
+        self.get_conversation_contact_tier(conversation_id).await
+    }
+}
@@ -0,0 +1,290 @@
+use sqlx::Row;
+
+use crate::domain::entities::{
+    ConversationVolume, CsatSummary, ReportingConversation, ReportingSummary, ReportingToken,
+    SlaSummary,
+};
+use crate::domain::ports::reporting_repository::ReportingRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+const REPORTING_TOKEN_SELECT: &str = "SELECT id, name, token_prefix, token_hash, created_by,
+            last_used_at, revoked_at, created_at
+     FROM reporting_tokens";
+
+/// Separator for tag names aggregated with GROUP_CONCAT (ASCII unit separator)
+pub(super) const TAG_SEPARATOR: char = '\u{1f}';
+
+fn row_to_reporting_token(row: &sqlx::any::AnyRow) -> ApiResult<ReportingToken> {
+    Ok(ReportingToken {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        token_prefix: row.try_get("token_prefix")?,
+        token_hash: row.try_get("token_hash")?,
+        created_by: row.try_get("created_by").ok(),
+        last_used_at: row.try_get("last_used_at").ok(),
+        revoked_at: row.try_get("revoked_at").ok(),
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+fn row_to_reporting_conversation(row: &sqlx::any::AnyRow) -> ApiResult<ReportingConversation> {
+    let mut tags: Vec<String> = row
+        .try_get::<Option<String>, _>("tags")
+        .ok()
+        .flatten()
+        .map(|tags| tags.split(TAG_SEPARATOR).map(str::to_string).collect())
+        .unwrap_or_default();
+    tags.sort();
+
+    Ok(ReportingConversation {
+        id: row.try_get("id")?,
+        reference_number: row.try_get("reference_number")?,
+        status: row.try_get("status")?,
+        priority: row
+            .try_get::<Option<String>, _>("priority")
+            .ok()
+            .flatten()
+            .map(|p| p.to_lowercase()),
+        inbox_id: row.try_get("inbox_id")?,
+        assigned_user_id: row.try_get("assigned_user_id").ok(),
+        assigned_team_id: row.try_get("assigned_team_id").ok(),
+        tags,
+        csat_score: row
+            .try_get::<Option<i64>, _>("csat_score")
+            .ok()
+            .flatten()
+            .map(|score| score as i32),
+        sentiment_score: row
+            .try_get::<Option<f64>, _>("sentiment_score")
+            .ok()
+            .flatten(),
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+        resolved_at: row.try_get("resolved_at").ok(),
+        closed_at: row.try_get("closed_at").ok(),
+    })
+}
+
+impl Database {
+    pub async fn create_reporting_token(&self, token: &ReportingToken) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO reporting_tokens (
+                id, name, token_prefix, token_hash, created_by, last_used_at, revoked_at, created_at
+             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&token.id)
+        .bind(&token.name)
+        .bind(&token.token_prefix)
+        .bind(&token.token_hash)
+        .bind(&token.created_by)
+        .bind(&token.last_used_at)
+        .bind(&token.revoked_at)
+        .bind(&token.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_reporting_token(&self, id: &str) -> ApiResult<Option<ReportingToken>> {
+        let row = sqlx::query(&format!("{} WHERE id = ?", REPORTING_TOKEN_SELECT))
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.as_ref().map(row_to_reporting_token).transpose()
+    }
+
+    pub async fn get_reporting_token_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> ApiResult<Option<ReportingToken>> {
+        let row = sqlx::query(&format!("{} WHERE token_hash = ?", REPORTING_TOKEN_SELECT))
+            .bind(token_hash)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        row.as_ref().map(row_to_reporting_token).transpose()
+    }
+
+    pub async fn list_reporting_tokens(&self) -> ApiResult<Vec<ReportingToken>> {
+        let rows = sqlx::query(&format!(
+            "{} ORDER BY created_at DESC, id DESC",
+            REPORTING_TOKEN_SELECT
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_reporting_token).collect()
+    }
+
+    pub async fn revoke_reporting_token(&self, id: &str, revoked_at: &str) -> ApiResult<()> {
+        sqlx::query(
+            "UPDATE reporting_tokens SET revoked_at = ? WHERE id = ? AND revoked_at IS NULL",
+        )
+        .bind(revoked_at)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn touch_reporting_token(&self, id: &str, used_at: &str) -> ApiResult<()> {
+        sqlx::query("UPDATE reporting_tokens SET last_used_at = ? WHERE id = ?")
+            .bind(used_at)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn reporting_summary(&self, since: Option<&str>) -> ApiResult<ReportingSummary> {
+        let mut conversations = ConversationVolume::default();
+        let rows = sqlx::query(
+            "SELECT status, COALESCE(priority, 'none') AS priority, COUNT(*) AS count
+             FROM conversations
+             WHERE status <> 'junk' AND (? IS NULL OR created_at >= ?)
+             GROUP BY status, priority",
+        )
+        .bind(since)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+        for row in &rows {
+            let status: String = row.try_get("status")?;
+            let priority: String = row.try_get("priority")?;
+            let count: i64 = row.try_get("count")?;
+            conversations.total += count;
+            *conversations.by_status.entry(status).or_default() += count;
+            *conversations
+                .by_priority
+                .entry(priority.to_lowercase())
+                .or_default() += count;
+        }
+
+        let row = sqlx::query(
+            "SELECT COUNT(*) AS count, AVG(score) AS average
+             FROM csat_responses
+             WHERE conversation_id NOT IN (SELECT id FROM conversations WHERE status = 'junk')
+               AND (? IS NULL OR created_at >= ?)",
+        )
+        .bind(since)
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await?;
+        let csat = CsatSummary {
+            responses: row.try_get("count")?,
+            average_score: row.try_get::<Option<f64>, _>("average").ok().flatten(),
+        };
+
+        let mut sla = SlaSummary::default();
+        let rows = sqlx::query(
+            "SELECT status, COUNT(*) AS count
+             FROM applied_slas
+             WHERE conversation_id NOT IN (SELECT id FROM conversations WHERE status = 'junk')
+               AND (? IS NULL OR applied_at >= ?)
+             GROUP BY status",
+        )
+        .bind(since)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+        for row in &rows {
+            let status: String = row.try_get("status")?;
+            let count: i64 = row.try_get("count")?;
+            sla.applied += count;
+            match status.as_str() {
+                "pending" => sla.pending += count,
+                "met" => sla.met += count,
+                "breached" => sla.breached += count,
+                _ => {}
+            }
+        }
+
+        Ok(ReportingSummary {
+            since: since.map(str::to_string),
+            conversations,
+            csat,
+            sla,
+            generated_at: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+
+    pub async fn list_reporting_conversations(
+        &self,
+        updated_since: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> ApiResult<Vec<ReportingConversation>> {
+        let rows = sqlx::query(
+            "SELECT c.id, c.reference_number, c.status, c.priority, c.inbox_id,
+                    c.assigned_user_id, c.assigned_team_id, c.sentiment_score,
+                    c.created_at, c.updated_at, c.resolved_at, c.closed_at,
+                    (SELECT r.score FROM csat_responses r
+                     WHERE r.conversation_id = c.id
+                     ORDER BY r.created_at DESC LIMIT 1) AS csat_score,
+                    (SELECT GROUP_CONCAT(t.name, char(31)) FROM conversation_tags ct
+                     JOIN tags t ON t.id = ct.tag_id
+                     WHERE ct.conversation_id = c.id) AS tags
+             FROM conversations c
+             WHERE c.status <> 'junk' AND (? IS NULL OR c.updated_at >= ?)
+             ORDER BY c.updated_at ASC, c.id ASC
+             LIMIT ? OFFSET ?",
+        )
+        .bind(updated_since)
+        .bind(updated_since)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_reporting_conversation).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportingRepository for Database {
+    async fn create_reporting_token(&self, token: &ReportingToken) -> ApiResult<()> {
+        self.create_reporting_token(token).await
+    }
+
+    async fn get_reporting_token(&self, id: &str) -> ApiResult<Option<ReportingToken>> {
+        self.get_reporting_token(id).await
+    }
+
+    async fn get_reporting_token_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> ApiResult<Option<ReportingToken>> {
+        self.get_reporting_token_by_hash(token_hash).await
+    }
+
+    async fn list_reporting_tokens(&self) -> ApiResult<Vec<ReportingToken>> {
+        self.list_reporting_tokens().await
+    }
+
+    async fn revoke_reporting_token(&self, id: &str, revoked_at: &str) -> ApiResult<()> {
+        self.revoke_reporting_token(id, revoked_at).await
+    }
+
+    async fn touch_reporting_token(&self, id: &str, used_at: &str) -> ApiResult<()> {
+        self.touch_reporting_token(id, used_at).await
+    }
+
+    async fn reporting_summary(&self, since: Option<&str>) -> ApiResult<ReportingSummary> {
+        self.reporting_summary(since).await
+    }
+
+    async fn list_reporting_conversations(
+        &self,
+        updated_since: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> ApiResult<Vec<ReportingConversation>> {
+        self.list_reporting_conversations(updated_since, limit, offset)
+            .await
+    }
+}
@@ -0,0 +1,132 @@
+use crate::domain::entities::{ResponseReminderCandidate, ResponseReminderSettings};
+use crate::domain::ports::response_reminder_repository::ResponseReminderRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+use sqlx::Row;
+
+#[async_trait::async_trait]
+impl ResponseReminderRepository for Database {
+    async fn get_response_reminder_settings(
+        &self,
+        user_id: &str,
+    ) -> ApiResult<Option<ResponseReminderSettings>> {
+        let row = sqlx::query(
+            "SELECT user_id, enabled, remind_after_minutes, repeat_after_minutes, updated_at
+             FROM response_reminder_settings
+             WHERE user_id = ?",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row {
+            Some(row) => Ok(Some(ResponseReminderSettings {
+                user_id: row.try_get("user_id")?,
+                enabled: row.try_get::<i32, _>("enabled")? != 0,
+                remind_after_minutes: row.try_get("remind_after_minutes")?,
+                repeat_after_minutes: row.try_get("repeat_after_minutes").ok().flatten(),
+                updated_at: row.try_get("updated_at")?,
+            })),
+            None => Ok(None),
+        }
+    }
+
+    async fn upsert_response_reminder_settings(
+        &self,
+        settings: &ResponseReminderSettings,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO response_reminder_settings
+                (user_id, enabled, remind_after_minutes, repeat_after_minutes, updated_at)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(user_id) DO UPDATE SET
+                enabled = excluded.enabled,
+                remind_after_minutes = excluded.remind_after_minutes,
+                repeat_after_minutes = excluded.repeat_after_minutes,
+                updated_at = excluded.updated_at",
+        )
+        .bind(&settings.user_id)
+        .bind(if settings.enabled { 1 } else { 0 })
+        .bind(settings.remind_after_minutes)
+        .bind(settings.repeat_after_minutes)
+        .bind(&settings.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list_response_reminder_candidates(&self) -> ApiResult<Vec<ResponseReminderCandidate>> {
+        // The waiting message is the first contact message after the most
+        // recent reply; conversations whose last message is a reply have none
+        let rows = sqlx::query(
+            "SELECT c.id AS conversation_id, s.user_id, s.remind_after_minutes,
+                    s.repeat_after_minutes, m.id AS message_id, m.created_at AS waiting_since,
+                    r.message_id AS reminded_message_id, r.last_reminded_at
+             FROM response_reminder_settings s
+             JOIN conversations c ON c.assigned_user_id = s.user_id AND c.status = 'open'
+             JOIN messages m ON m.id = (
+                 SELECT mi.id FROM messages mi
+                 WHERE mi.conversation_id = c.id
+                   AND mi.type = 'incoming'
+                   AND mi.created_at > COALESCE((
+                       SELECT MAX(mo.created_at) FROM messages mo
+                       WHERE mo.conversation_id = c.id AND mo.type = 'outgoing'
+                   ), '')
+                 ORDER BY mi.created_at ASC
+                 LIMIT 1
+             )
+             LEFT JOIN response_reminders r
+                 ON r.conversation_id = c.id AND r.user_id = s.user_id
+             WHERE s.enabled = 1
+             ORDER BY m.created_at ASC",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(ResponseReminderCandidate {
+                    conversation_id: row.try_get("conversation_id")?,
+                    user_id: row.try_get("user_id")?,
+                    remind_after_minutes: row.try_get("remind_after_minutes")?,
+                    repeat_after_minutes: row.try_get("repeat_after_minutes").ok().flatten(),
+                    message_id: row.try_get("message_id")?,
+                    waiting_since: row.try_get("waiting_since")?,
+                    reminded_message_id: row.try_get("reminded_message_id").ok().flatten(),
+                    last_reminded_at: row.try_get("last_reminded_at").ok().flatten(),
+                })
+            })
+            .collect()
+    }
+
+    async fn record_response_reminder(
+        &self,
+        conversation_id: &str,
+        user_id: &str,
+        message_id: &str,
+        reminded_at: &str,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO response_reminders
+                (conversation_id, user_id, message_id, reminder_count, last_reminded_at)
+             VALUES (?, ?, ?, 1, ?)
+             ON CONFLICT(conversation_id, user_id) DO UPDATE SET
+                reminder_count = CASE
+                    WHEN response_reminders.message_id = excluded.message_id
+                    THEN response_reminders.reminder_count + 1
+                    ELSE 1
+                END,
+                message_id = excluded.message_id,
+                last_reminded_at = excluded.last_reminded_at",
+        )
+        .bind(conversation_id)
+        .bind(user_id)
+        .bind(message_id)
+        .bind(reminded_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
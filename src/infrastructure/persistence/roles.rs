@@ -97,6 +97,10 @@ impl RoleRepository for Database {
     }
 
     async fn get_role_by_name(&self, name: &str) -> DomainResult<Option<DomainRole>> {
+        if let Some(role) = self.cache.role_by_name(name).await {
+            return Ok(Some(role));
+        }
+
         let row = sqlx::query(
             "SELECT id, name, description, permissions, CAST(is_protected AS INTEGER) as is_protected, created_at, updated_at
              FROM roles
@@ -114,7 +118,7 @@ impl RoleRepository for Database {
             let permissions: Vec<String> =
                 serde_json::from_str(&permissions_json).unwrap_or_else(|_| Vec::new());
 
-            Ok(Some(DomainRole {
+            let role = DomainRole {
                 id: row
                     .try_get("id")
                     .map_err(|e| DomainError::Internal(e.to_string()))?,
@@ -133,7 +137,9 @@ impl RoleRepository for Database {
                 updated_at: row
                     .try_get("updated_at")
                     .map_err(|e| DomainError::Internal(e.to_string()))?,
-            }))
+            };
+            self.cache.put_role_by_name(&role).await;
+            Ok(Some(role))
         } else {
             Ok(None)
         }
@@ -157,6 +163,7 @@ impl RoleRepository for Database {
         .execute(&self.pool)
         .await
         .map_err(|e| DomainError::Internal(e.to_string()))?;
+        self.cache.invalidate_roles();
 
         Ok(())
     }
@@ -202,6 +209,7 @@ impl RoleRepository for Database {
             .execute(&self.pool)
             .await
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+        self.cache.invalidate_roles();
 
         Ok(())
     }
@@ -212,6 +220,7 @@ impl RoleRepository for Database {
             .execute(&self.pool)
             .await
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+        self.cache.invalidate_roles();
 
         Ok(())
     }
@@ -266,6 +275,10 @@ impl RoleRepository for Database {
     }
 
     async fn get_user_roles(&self, user_id: &str) -> DomainResult<Vec<DomainRole>> {
+        if let Some(roles) = self.cache.user_roles(user_id).await {
+            return Ok(roles);
+        }
+
         let rows = sqlx::query(
             "SELECT r.id, r.name, r.description, r.permissions, CAST(r.is_protected AS INTEGER) as is_protected, r.created_at, r.updated_at
              FROM roles r
@@ -306,6 +319,7 @@ impl RoleRepository for Database {
                     .map_err(|e| DomainError::Internal(e.to_string()))?,
             });
         }
+        self.cache.put_user_roles(user_id, &roles).await;
 
         Ok(roles)
     }
@@ -316,6 +330,7 @@ impl RoleRepository for Database {
             .execute(&self.pool)
             .await
             .map_err(|e| DomainError::Internal(e.to_string()))?;
+        self.cache.invalidate_user_roles(user_id).await;
 
         Ok(())
     }
@@ -331,6 +346,7 @@ impl RoleRepository for Database {
         .execute(&self.pool)
         .await
         .map_err(|e| DomainError::Internal(e.to_string()))?;
+        self.cache.invalidate_user_roles(&user_role.user_id).await;
 
         Ok(())
     }
@@ -454,42 +470,15 @@ impl Database {
         .bind(&user_role.created_at)
         .execute(&self.pool)
         .await?;
+        self.cache.invalidate_user_roles(&user_role.user_id).await;
 
         Ok(())
     }
 
     pub async fn get_user_roles(&self, user_id: &str) -> ApiResult<Vec<Role>> {
-        let rows = sqlx::query(
-            "SELECT r.id, r.name, r.description, r.permissions, CAST(r.is_protected AS INTEGER) as is_protected, r.created_at, r.updated_at
-             FROM roles r
-             INNER JOIN user_roles ur ON r.id = ur.role_id
-             WHERE ur.user_id = ?",
-        )
-        .bind(user_id)
-        .fetch_all(&self.pool)
-        .await?;
-
-        let mut roles = Vec::new();
-        for row in rows {
-            let permissions_json: String = row.try_get("permissions")?;
-            let permissions: Vec<String> =
-                serde_json::from_str(&permissions_json).unwrap_or_else(|_| Vec::new());
-
-            roles.push(Role {
-                id: row.try_get("id")?,
-                name: row.try_get("name")?,
-                description: row
-                    .try_get::<Option<String>, _>("description")
-                    .ok()
-                    .flatten(),
-                permissions,
-                is_protected: row.try_get::<i32, _>("is_protected")? != 0,
-                created_at: row.try_get("created_at")?,
-                updated_at: row.try_get("updated_at")?,
-            });
-        }
-
-        Ok(roles)
+        <Self as RoleRepository>::get_user_roles(self, user_id)
+            .await
+            .map_err(|e| ApiError::Internal(e.to_string()))
     }
 
     // Permission operations
@@ -570,6 +559,7 @@ impl Database {
             .bind(user_id)
             .execute(&self.pool)
             .await?;
+        self.cache.invalidate_user_roles(user_id).await;
 
         Ok(())
     }
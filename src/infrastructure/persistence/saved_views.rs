@@ -0,0 +1,105 @@
+use sqlx::Row;
+
+use crate::domain::entities::SavedView;
+use crate::domain::ports::saved_view_repository::SavedViewRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+fn row_to_saved_view(row: &sqlx::any::AnyRow) -> ApiResult<SavedView> {
+    Ok(SavedView {
+        id: row.try_get("id")?,
+        user_id: row.try_get("user_id")?,
+        name: row.try_get("name")?,
+        query: row.try_get("query")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+impl Database {
+    pub async fn create_saved_view(&self, view: &SavedView) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO saved_views (id, user_id, name, query, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&view.id)
+        .bind(&view.user_id)
+        .bind(&view.name)
+        .bind(&view.query)
+        .bind(&view.created_at)
+        .bind(&view.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_saved_view(&self, id: &str) -> ApiResult<Option<SavedView>> {
+        let row = sqlx::query(
+            "SELECT id, user_id, name, query, created_at, updated_at
+             FROM saved_views WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_saved_view).transpose()
+    }
+
+    pub async fn update_saved_view(&self, view: &SavedView) -> ApiResult<()> {
+        sqlx::query("UPDATE saved_views SET name = ?, query = ?, updated_at = ? WHERE id = ?")
+            .bind(&view.name)
+            .bind(&view.query)
+            .bind(&view.updated_at)
+            .bind(&view.id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_saved_view(&self, id: &str) -> ApiResult<()> {
+        sqlx::query("DELETE FROM saved_views WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_saved_views(&self, user_id: &str) -> ApiResult<Vec<SavedView>> {
+        let rows = sqlx::query(
+            "SELECT id, user_id, name, query, created_at, updated_at
+             FROM saved_views WHERE user_id = ?
+             ORDER BY name, id",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_saved_view).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl SavedViewRepository for Database {
+    async fn create_saved_view(&self, view: &SavedView) -> ApiResult<()> {
+        Database::create_saved_view(self, view).await
+    }
+
+    async fn get_saved_view(&self, id: &str) -> ApiResult<Option<SavedView>> {
+        Database::get_saved_view(self, id).await
+    }
+
+    async fn update_saved_view(&self, view: &SavedView) -> ApiResult<()> {
+        Database::update_saved_view(self, view).await
+    }
+
+    async fn delete_saved_view(&self, id: &str) -> ApiResult<()> {
+        Database::delete_saved_view(self, id).await
+    }
+
+    async fn list_saved_views(&self, user_id: &str) -> ApiResult<Vec<SavedView>> {
+        Database::list_saved_views(self, user_id).await
+    }
+}
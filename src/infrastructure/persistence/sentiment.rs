@@ -0,0 +1,93 @@
+use sqlx::Row;
+
+use crate::domain::entities::{MessageSentiment, SentimentTrend};
+use crate::domain::ports::sentiment_repository::SentimentRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+fn row_to_message_sentiment(row: &sqlx::any::AnyRow) -> ApiResult<MessageSentiment> {
+    Ok(MessageSentiment {
+        message_id: row.try_get("message_id")?,
+        conversation_id: row.try_get("conversation_id")?,
+        score: row.try_get("score")?,
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+impl Database {
+    pub async fn create_message_sentiment(&self, sentiment: &MessageSentiment) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO message_sentiments (message_id, conversation_id, score, created_at)
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(&sentiment.message_id)
+        .bind(&sentiment.conversation_id)
+        .bind(sentiment.score)
+        .bind(&sentiment.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_message_sentiments(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<MessageSentiment>> {
+        let rows = sqlx::query(
+            "SELECT message_id, conversation_id, score, created_at
+             FROM message_sentiments
+             WHERE conversation_id = ?
+             ORDER BY created_at ASC",
+        )
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_message_sentiment).collect()
+    }
+
+    pub async fn update_conversation_sentiment(
+        &self,
+        conversation_id: &str,
+        score: f64,
+        trend: SentimentTrend,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            "UPDATE conversations
+             SET sentiment_score = ?, sentiment_trend = ?
+             WHERE id = ?",
+        )
+        .bind(score)
+        .bind(trend.as_str())
+        .bind(conversation_id)
+        .execute(&self.pool)
+        .await?;
+        self.cache.invalidate_conversation(conversation_id).await;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl SentimentRepository for Database {
+    async fn create_message_sentiment(&self, sentiment: &MessageSentiment) -> ApiResult<()> {
+        Database::create_message_sentiment(self, sentiment).await
+    }
+
+    async fn list_message_sentiments(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<MessageSentiment>> {
+        Database::list_message_sentiments(self, conversation_id).await
+    }
+
+    async fn update_conversation_sentiment(
+        &self,
+        conversation_id: &str,
+        score: f64,
+        trend: SentimentTrend,
+    ) -> ApiResult<()> {
+        Database::update_conversation_sentiment(self, conversation_id, score, trend).await
+    }
+}
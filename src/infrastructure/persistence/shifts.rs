@@ -0,0 +1,167 @@
+use sqlx::Row;
+
+use crate::domain::entities::AgentShift;
+use crate::domain::ports::shift_repository::ShiftRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+const SHIFT_COLUMNS: &str =
+    "id, team_id, user_id, day, start_time, end_time, created_at, updated_at";
+
+fn row_to_shift(row: &sqlx::any::AnyRow) -> ApiResult<AgentShift> {
+    Ok(AgentShift {
+        id: row.try_get("id")?,
+        team_id: row.try_get("team_id")?,
+        user_id: row.try_get("user_id")?,
+        day: row.try_get("day")?,
+        start_time: row.try_get("start_time")?,
+        end_time: row.try_get("end_time")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+impl Database {
+    pub async fn create_shift(&self, shift: &AgentShift) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO agent_shifts (id, team_id, user_id, day, start_time, end_time, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&shift.id)
+        .bind(&shift.team_id)
+        .bind(&shift.user_id)
+        .bind(&shift.day)
+        .bind(&shift.start_time)
+        .bind(&shift.end_time)
+        .bind(&shift.created_at)
+        .bind(&shift.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_shift(&self, id: &str) -> ApiResult<Option<AgentShift>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM agent_shifts WHERE id = ?",
+            SHIFT_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_shift).transpose()
+    }
+
+    pub async fn list_team_shifts(&self, team_id: &str) -> ApiResult<Vec<AgentShift>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM agent_shifts WHERE team_id = ? ORDER BY day, start_time",
+            SHIFT_COLUMNS
+        ))
+        .bind(team_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_shift).collect()
+    }
+
+    pub async fn list_all_shifts(&self) -> ApiResult<Vec<AgentShift>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM agent_shifts ORDER BY user_id",
+            SHIFT_COLUMNS
+        ))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_shift).collect()
+    }
+
+    pub async fn update_shift(&self, shift: &AgentShift) -> ApiResult<()> {
+        sqlx::query(
+            "UPDATE agent_shifts SET day = ?, start_time = ?, end_time = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&shift.day)
+        .bind(&shift.start_time)
+        .bind(&shift.end_time)
+        .bind(&shift.updated_at)
+        .bind(&shift.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_shift(&self, id: &str) -> ApiResult<()> {
+        sqlx::query("DELETE FROM agent_shifts WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_shift_state(&self, user_id: &str) -> ApiResult<Option<bool>> {
+        let row = sqlx::query("SELECT on_shift FROM agent_shift_states WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        match row {
+            Some(row) => Ok(Some(row.try_get::<i32, _>("on_shift")? != 0)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn set_shift_state(&self, user_id: &str, on_shift: bool) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO agent_shift_states (user_id, on_shift, updated_at)
+             VALUES (?, ?, ?)
+             ON CONFLICT(user_id) DO UPDATE SET
+                on_shift = excluded.on_shift,
+                updated_at = excluded.updated_at",
+        )
+        .bind(user_id)
+        .bind(if on_shift { 1 } else { 0 })
+        .bind(chrono::Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ShiftRepository for Database {
+    async fn create_shift(&self, shift: &AgentShift) -> ApiResult<()> {
+        Database::create_shift(self, shift).await
+    }
+
+    async fn get_shift(&self, id: &str) -> ApiResult<Option<AgentShift>> {
+        Database::get_shift(self, id).await
+    }
+
+    async fn list_team_shifts(&self, team_id: &str) -> ApiResult<Vec<AgentShift>> {
+        Database::list_team_shifts(self, team_id).await
+    }
+
+    async fn list_all_shifts(&self) -> ApiResult<Vec<AgentShift>> {
+        Database::list_all_shifts(self).await
+    }
+
+    async fn update_shift(&self, shift: &AgentShift) -> ApiResult<()> {
+        Database::update_shift(self, shift).await
+    }
+
+    async fn delete_shift(&self, id: &str) -> ApiResult<()> {
+        Database::delete_shift(self, id).await
+    }
+
+    async fn get_shift_state(&self, user_id: &str) -> ApiResult<Option<bool>> {
+        Database::get_shift_state(self, user_id).await
+    }
+
+    async fn set_shift_state(&self, user_id: &str, on_shift: bool) -> ApiResult<()> {
+        Database::set_shift_state(self, user_id, on_shift).await
+    }
+}
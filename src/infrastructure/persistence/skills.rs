@@ -0,0 +1,233 @@
+use sqlx::Row;
+
+use crate::domain::entities::Skill;
+use crate::domain::ports::skill_repository::SkillRepository;
+use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
+use crate::infrastructure::persistence::Database;
+
+fn row_to_skill(row: &sqlx::any::AnyRow) -> ApiResult<Skill> {
+    Ok(Skill {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        description: row.try_get("description").ok().flatten(),
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+impl Database {
+    pub async fn create_skill(&self, skill: &Skill) -> ApiResult<()> {
+        sqlx::query(
+            "INSERT INTO skills (id, name, description, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&skill.id)
+        .bind(&skill.name)
+        .bind(&skill.description)
+        .bind(&skill.created_at)
+        .bind(&skill.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if e.to_string().contains("UNIQUE") {
+                ApiError::Conflict(format!("A skill named '{}' already exists", skill.name))
+            } else {
+                ApiError::Internal(e.to_string())
+            }
+        })?;
+
+        Ok(())
+    }
+
+    pub async fn get_skill_by_id(&self, id: &str) -> ApiResult<Option<Skill>> {
+        let row = sqlx::query(
+            "SELECT id, name, description, created_at, updated_at FROM skills WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_skill).transpose()
+    }
+
+    pub async fn list_skills(&self) -> ApiResult<Vec<Skill>> {
+        let rows = sqlx::query(
+            "SELECT id, name, description, created_at, updated_at FROM skills ORDER BY name",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_skill).collect()
+    }
+
+    pub async fn update_skill(&self, skill: &Skill) -> ApiResult<()> {
+        sqlx::query("UPDATE skills SET description = ?, updated_at = ? WHERE id = ?")
+            .bind(&skill.description)
+            .bind(&skill.updated_at)
+            .bind(&skill.id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_skill(&self, id: &str) -> ApiResult<bool> {
+        let result = sqlx::query("DELETE FROM skills WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn get_agent_skills(&self, user_id: &str) -> ApiResult<Vec<Skill>> {
+        let rows = sqlx::query(
+            "SELECT s.id, s.name, s.description, s.created_at, s.updated_at
+             FROM agent_skills ags
+             JOIN skills s ON s.id = ags.skill_id
+             WHERE ags.user_id = ?
+             ORDER BY s.name",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_skill).collect()
+    }
+
+    pub async fn set_agent_skills(&self, user_id: &str, skill_ids: &[String]) -> ApiResult<()> {
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM agent_skills WHERE user_id = ?")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        for skill_id in skill_ids {
+            sqlx::query("INSERT INTO agent_skills (user_id, skill_id, added_at) VALUES (?, ?, ?)")
+                .bind(user_id)
+                .bind(skill_id)
+                .bind(&now)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_conversation_required_skills(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<Skill>> {
+        let rows = sqlx::query(
+            "SELECT DISTINCT s.id, s.name, s.description, s.created_at, s.updated_at
+             FROM conversation_tags ct
+             JOIN tags t ON t.id = ct.tag_id
+             JOIN skills s ON s.name = t.name COLLATE NOCASE
+             WHERE ct.conversation_id = ?
+             ORDER BY s.name",
+        )
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_skill).collect()
+    }
+
+    pub async fn find_skilled_agents(
+        &self,
+        skill_ids: &[String],
+        team_id: Option<&str>,
+    ) -> ApiResult<Vec<String>> {
+        if skill_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; skill_ids.len()].join(", ");
+        let team_filter = if team_id.is_some() {
+            "AND EXISTS (SELECT 1 FROM team_memberships tm
+                         WHERE tm.user_id = a.user_id AND tm.team_id = ?)"
+        } else {
+            ""
+        };
+        let sql = format!(
+            "SELECT a.user_id,
+                    (SELECT COUNT(*) FROM conversations c
+                     WHERE c.assigned_user_id = a.user_id
+                       AND c.status IN ('open', 'snoozed')) AS open_count
+             FROM agents a
+             JOIN users u ON u.id = a.user_id
+             JOIN agent_skills ags ON ags.user_id = a.user_id
+             WHERE a.availability_status = 'online'
+               AND u.deleted_at IS NULL
+               AND ags.skill_id IN ({})
+               {}
+             GROUP BY a.user_id
+             HAVING COUNT(DISTINCT ags.skill_id) = ?
+             ORDER BY open_count ASC, a.user_id ASC",
+            placeholders, team_filter
+        );
+
+        let mut query = sqlx::query(&sql);
+        for skill_id in skill_ids {
+            query = query.bind(skill_id);
+        }
+        if let Some(team_id) = team_id {
+            query = query.bind(team_id);
+        }
+        let rows = query
+            .bind(skill_ids.len() as i64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.iter().map(|row| Ok(row.try_get("user_id")?)).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl SkillRepository for Database {
+    async fn create_skill(&self, skill: &Skill) -> ApiResult<()> {
+        self.create_skill(skill).await
+    }
+
+    async fn get_skill_by_id(&self, id: &str) -> ApiResult<Option<Skill>> {
+        self.get_skill_by_id(id).await
+    }
+
+    async fn list_skills(&self) -> ApiResult<Vec<Skill>> {
+        self.list_skills().await
+    }
+
+    async fn update_skill(&self, skill: &Skill) -> ApiResult<()> {
+        self.update_skill(skill).await
+    }
+
+    async fn delete_skill(&self, id: &str) -> ApiResult<bool> {
+        self.delete_skill(id).await
+    }
+
+    async fn get_agent_skills(&self, user_id: &str) -> ApiResult<Vec<Skill>> {
+        self.get_agent_skills(user_id).await
+    }
+
+    async fn set_agent_skills(&self, user_id: &str, skill_ids: &[String]) -> ApiResult<()> {
+        self.set_agent_skills(user_id, skill_ids).await
+    }
+
+    async fn get_conversation_required_skills(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Vec<Skill>> {
+        self.get_conversation_required_skills(conversation_id).await
+    }
+
+    async fn find_skilled_agents(
+        &self,
+        skill_ids: &[String],
+        team_id: Option<&str>,
+    ) -> ApiResult<Vec<String>> {
+        self.find_skilled_agents(skill_ids, team_id).await
+    }
+}
@@ -1,4 +1,6 @@
+use crate::domain::entities::ConversationEventType;
 use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
+use crate::infrastructure::persistence::conversation_events::record_conversation_event;
 use crate::infrastructure::persistence::Database;
 use sqlx::Row;
 use time;
@@ -217,6 +219,7 @@ impl Database {
         &self,
         applied_sla: &crate::domain::entities::AppliedSla,
     ) -> ApiResult<()> {
+        let mut tx = self.pool.begin().await?;
         sqlx::query(
             "INSERT INTO applied_slas (id, conversation_id, sla_policy_id, status, first_response_deadline_at, resolution_deadline_at, applied_at, updated_at)
              VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
@@ -229,8 +232,22 @@ impl Database {
         .bind(&applied_sla.resolution_deadline_at)
         .bind(&applied_sla.applied_at)
         .bind(&applied_sla.updated_at)
-        .execute(&self.pool)
+        .execute(&mut *tx)
+        .await?;
+        record_conversation_event(
+            &mut tx,
+            &applied_sla.conversation_id,
+            ConversationEventType::SlaApplied,
+            None,
+            &serde_json::json!({
+                "applied_sla_id": applied_sla.id,
+                "sla_policy_id": applied_sla.sla_policy_id,
+                "first_response_deadline_at": applied_sla.first_response_deadline_at,
+                "resolution_deadline_at": applied_sla.resolution_deadline_at,
+            }),
+        )
         .await?;
+        tx.commit().await?;
 
         Ok(())
     }
@@ -419,12 +436,27 @@ impl Database {
             .format(&time::format_description::well_known::Rfc3339)
             .unwrap();
 
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+             SELECT conversation_id, ?, NULL, json_object('applied_sla_id', id, 'from', status, 'to', ?), ?
+             FROM applied_slas
+             WHERE id = ? AND status <> ?",
+        )
+        .bind(ConversationEventType::SlaStatusChanged.as_str())
+        .bind(status.to_string())
+        .bind(&now)
+        .bind(id)
+        .bind(status.to_string())
+        .execute(&mut *tx)
+        .await?;
         sqlx::query("UPDATE applied_slas SET status = ?, updated_at = ? WHERE id = ?")
             .bind(status.to_string())
             .bind(now)
             .bind(id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
             .await?;
+        tx.commit().await?;
 
         Ok(())
     }
@@ -644,7 +676,14 @@ impl Database {
 
         let rows = sqlx::query(
             "SELECT id, applied_sla_id, event_type, status, deadline_at, met_at, breached_at, created_at, updated_at
-             FROM sla_events WHERE status = 'pending' AND deadline_at < ? ORDER BY deadline_at ASC"
+             FROM sla_events
+             WHERE status = 'pending' AND deadline_at < ?
+               AND applied_sla_id NOT IN (
+                   SELECT a.id FROM applied_slas a
+                   JOIN conversations c ON c.id = a.conversation_id
+                   WHERE c.status = 'junk'
+               )
+             ORDER BY deadline_at ASC"
         )
         .bind(now)
         .fetch_all(&self.pool)
@@ -703,14 +742,31 @@ impl Database {
             .format(&time::format_description::well_known::Rfc3339)
             .unwrap();
 
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+             SELECT a.conversation_id, ?, NULL,
+                    json_object('sla_event_id', e.id, 'sla_event_type', e.event_type,
+                                'deadline_at', e.deadline_at, 'met_at', ?), ?
+             FROM sla_events e
+             JOIN applied_slas a ON a.id = e.applied_sla_id
+             WHERE e.id = ? AND e.status <> 'met'",
+        )
+        .bind(ConversationEventType::SlaMet.as_str())
+        .bind(met_at)
+        .bind(&now)
+        .bind(event_id)
+        .execute(&mut *tx)
+        .await?;
         sqlx::query(
             "UPDATE sla_events SET status = 'met', met_at = ?, updated_at = ? WHERE id = ?",
         )
         .bind(met_at)
         .bind(now)
         .bind(event_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
 
         Ok(())
     }
@@ -738,14 +794,31 @@ impl Database {
             .format(&time::format_description::well_known::Rfc3339)
             .unwrap();
 
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+             SELECT a.conversation_id, ?, NULL,
+                    json_object('sla_event_id', e.id, 'sla_event_type', e.event_type,
+                                'deadline_at', e.deadline_at, 'breached_at', ?), ?
+             FROM sla_events e
+             JOIN applied_slas a ON a.id = e.applied_sla_id
+             WHERE e.id = ? AND e.status <> 'breached'",
+        )
+        .bind(ConversationEventType::SlaBreached.as_str())
+        .bind(breached_at)
+        .bind(&now)
+        .bind(event_id)
+        .execute(&mut *tx)
+        .await?;
         sqlx::query(
             "UPDATE sla_events SET status = 'breached', breached_at = ?, updated_at = ? WHERE id = ?"
         )
         .bind(breached_at)
         .bind(now)
         .bind(event_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        tx.commit().await?;
 
         Ok(())
     }
@@ -759,6 +832,84 @@ impl Database {
 
         Ok(())
     }
+
+    // ========================================
+    // Policy Simulation
+    // ========================================
+
+    /// Timing of the conversations created between two RFC 3339 times
+    ///
+    /// Timestamps are stored in more than one format, so rows are matched by
+    /// day and the caller narrows them to the exact window.
+    pub async fn list_sla_replay_conversations(
+        &self,
+        since: &str,
+        until: &str,
+    ) -> ApiResult<Vec<crate::domain::entities::SlaReplayConversation>> {
+        let since_day = since.get(..10).unwrap_or(since);
+        let until_day = until.get(..10).unwrap_or(until);
+
+        let rows = sqlx::query(
+            "SELECT c.id, c.inbox_id, i.name AS inbox_name, c.assigned_team_id,
+                    t.name AS team_name, c.created_at,
+                    COALESCE(c.resolved_at, c.closed_at) AS resolved_at
+             FROM conversations c
+             JOIN inboxes i ON i.id = c.inbox_id
+             LEFT JOIN teams t ON t.id = c.assigned_team_id
+             WHERE c.status <> 'junk'
+               AND substr(c.created_at, 1, 10) >= ? AND substr(c.created_at, 1, 10) <= ?
+             ORDER BY c.created_at ASC",
+        )
+        .bind(since_day)
+        .bind(until_day)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut conversations = Vec::with_capacity(rows.len());
+        let mut positions = std::collections::HashMap::new();
+        for row in &rows {
+            let id: String = row.try_get("id")?;
+            positions.insert(id.clone(), conversations.len());
+            conversations.push(crate::domain::entities::SlaReplayConversation {
+                id,
+                inbox_id: row.try_get("inbox_id")?,
+                inbox_name: row.try_get("inbox_name")?,
+                team_id: row.try_get("assigned_team_id").ok(),
+                team_name: row.try_get("team_name").ok(),
+                created_at: row.try_get("created_at")?,
+                resolved_at: row.try_get("resolved_at").ok(),
+                messages: Vec::new(),
+            });
+        }
+
+        let rows = sqlx::query(
+            "SELECT m.conversation_id, m.type, m.created_at
+             FROM messages m
+             JOIN conversations c ON c.id = m.conversation_id
+             WHERE substr(c.created_at, 1, 10) >= ? AND substr(c.created_at, 1, 10) <= ?
+             ORDER BY m.created_at ASC",
+        )
+        .bind(since_day)
+        .bind(until_day)
+        .fetch_all(&self.pool)
+        .await?;
+
+        for row in &rows {
+            let conversation_id: String = row.try_get("conversation_id")?;
+            let Some(&position) = positions.get(&conversation_id) else {
+                continue;
+            };
+            let message_type: String = row.try_get("type")?;
+            conversations[position]
+                .messages
+                .push(crate::domain::entities::SlaReplayMessage {
+                    created_at: row.try_get("created_at")?,
+                    from_agent: message_type == "outgoing",
+                });
+        }
+
+        Ok(conversations)
+    }
 }
 
 // Implement SlaRepository trait for Database
@@ -864,7 +1015,19 @@ impl crate::domain::ports::sla_repository::SlaRepository for Database {
         self.mark_sla_event_breached(event_id, breached_at).await
     }
 
-    async fn is_holiday(&self, date: &str) -> ApiResult<bool> {
-        self.is_holiday(date).await
+    async fn is_holiday(&self, date: &str, calendar_id: Option<&str>) -> ApiResult<bool> {
+        self.is_holiday(date, calendar_id).await
+    }
+
+    async fn list_holidays(&self) -> ApiResult<Vec<crate::domain::entities::Holiday>> {
+        self.list_holidays().await
+    }
+
+    async fn list_sla_replay_conversations(
+        &self,
+        since: &str,
+        until: &str,
+    ) -> ApiResult<Vec<crate::domain::entities::SlaReplayConversation>> {
+        self.list_sla_replay_conversations(since, until).await
     }
 }
@@ -0,0 +1,114 @@
+use sqlx::Row;
+
+use crate::domain::entities::InboxSmsConfig;
+use crate::domain::ports::sms_config_repository::SmsConfigRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+impl Database {
+    /// Get an inbox's Twilio configuration, with the auth token decrypted
+    pub async fn get_inbox_sms_config(&self, inbox_id: &str) -> ApiResult<Option<InboxSmsConfig>> {
+        let row = sqlx::query(
+            "SELECT id, inbox_id, account_sid, auth_token, phone_number, max_segments, enabled,
+                    CAST(created_at AS TEXT) as created_at,
+                    CAST(updated_at AS TEXT) as updated_at
+             FROM inbox_sms_configs WHERE inbox_id = ?",
+        )
+        .bind(inbox_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let auth_token: String = row.try_get("auth_token")?;
+        let max_segments: i64 = row.try_get("max_segments")?;
+        let enabled: i64 = row.try_get("enabled")?;
+
+        Ok(Some(InboxSmsConfig {
+            id: row.try_get("id")?,
+            inbox_id: row.try_get("inbox_id")?,
+            account_sid: row.try_get("account_sid")?,
+            auth_token: self.decrypt_password_field(&auth_token),
+            phone_number: row.try_get("phone_number")?,
+            max_segments: max_segments as i32,
+            enabled: enabled != 0,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        }))
+    }
+
+    pub async fn create_inbox_sms_config(&self, config: &InboxSmsConfig) -> ApiResult<()> {
+        let auth_token = self.encrypt_password_field(&config.auth_token)?;
+
+        sqlx::query(
+            "INSERT INTO inbox_sms_configs (
+                id, inbox_id, account_sid, auth_token, phone_number, max_segments, enabled,
+                created_at, updated_at
+             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&config.id)
+        .bind(&config.inbox_id)
+        .bind(&config.account_sid)
+        .bind(&auth_token)
+        .bind(&config.phone_number)
+        .bind(config.max_segments)
+        .bind(if config.enabled { 1 } else { 0 })
+        .bind(&config.created_at)
+        .bind(&config.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_inbox_sms_config(&self, config: &InboxSmsConfig) -> ApiResult<()> {
+        let auth_token = self.encrypt_password_field(&config.auth_token)?;
+
+        sqlx::query(
+            "UPDATE inbox_sms_configs
+             SET account_sid = ?, auth_token = ?, phone_number = ?, max_segments = ?,
+                 enabled = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&config.account_sid)
+        .bind(&auth_token)
+        .bind(&config.phone_number)
+        .bind(config.max_segments)
+        .bind(if config.enabled { 1 } else { 0 })
+        .bind(&config.updated_at)
+        .bind(&config.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_inbox_sms_config(&self, inbox_id: &str) -> ApiResult<()> {
+        sqlx::query("DELETE FROM inbox_sms_configs WHERE inbox_id = ?")
+            .bind(inbox_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl SmsConfigRepository for Database {
+    async fn get_inbox_sms_config(&self, inbox_id: &str) -> ApiResult<Option<InboxSmsConfig>> {
+        Database::get_inbox_sms_config(self, inbox_id).await
+    }
+
+    async fn create_inbox_sms_config(&self, config: &InboxSmsConfig) -> ApiResult<()> {
+        Database::create_inbox_sms_config(self, config).await
+    }
+
+    async fn update_inbox_sms_config(&self, config: &InboxSmsConfig) -> ApiResult<()> {
+        Database::update_inbox_sms_config(self, config).await
+    }
+
+    async fn delete_inbox_sms_config(&self, inbox_id: &str) -> ApiResult<()> {
+        Database::delete_inbox_sms_config(self, inbox_id).await
+    }
+}
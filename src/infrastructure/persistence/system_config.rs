@@ -1,3 +1,5 @@
+use crate::domain::entities::{SystemConfigChange, SystemConfigEntry};
+use crate::domain::ports::system_config_repository::SystemConfigRepository;
 use crate::infrastructure::http::middleware::error::ApiResult;
 use crate::infrastructure::persistence::Database;
 use sqlx::Row;
@@ -47,4 +49,123 @@ impl Database {
 
         Ok(())
     }
+
+    /// List all stored configuration values
+    pub async fn list_config_entries(&self) -> ApiResult<Vec<SystemConfigEntry>> {
+        let rows = sqlx::query("SELECT key, value, updated_at FROM system_config ORDER BY key")
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(SystemConfigEntry {
+                    key: row.try_get("key")?,
+                    value: row.try_get("value")?,
+                    updated_at: row.try_get("updated_at")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Store a new value and its audit record in one transaction
+    pub async fn apply_config_change(
+        &self,
+        change: &SystemConfigChange,
+        description: Option<&str>,
+    ) -> ApiResult<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            "INSERT INTO system_config (key, value, description, updated_at)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(key) DO UPDATE SET
+                 value = excluded.value,
+                 description = COALESCE(excluded.description, description),
+                 updated_at = excluded.updated_at",
+        )
+        .bind(&change.key)
+        .bind(&change.new_value)
+        .bind(description)
+        .bind(&change.changed_at)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO system_config_changes (id, key, old_value, new_value, changed_by, changed_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&change.id)
+        .bind(&change.key)
+        .bind(&change.old_value)
+        .bind(&change.new_value)
+        .bind(&change.changed_by)
+        .bind(&change.changed_at)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// List configuration changes, newest first
+    pub async fn list_config_changes(
+        &self,
+        key: Option<&str>,
+        limit: i64,
+    ) -> ApiResult<Vec<SystemConfigChange>> {
+        let mut query = String::from(
+            "SELECT id, key, old_value, new_value, changed_by, changed_at
+             FROM system_config_changes",
+        );
+        if key.is_some() {
+            query.push_str(" WHERE key = ?");
+        }
+        query.push_str(" ORDER BY changed_at DESC, rowid DESC LIMIT ?");
+
+        let mut sql_query = sqlx::query(&query);
+        if let Some(key) = key {
+            sql_query = sql_query.bind(key);
+        }
+        let rows = sql_query.bind(limit).fetch_all(&self.pool).await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(SystemConfigChange {
+                    id: row.try_get("id")?,
+                    key: row.try_get("key")?,
+                    old_value: row.try_get("old_value").ok(),
+                    new_value: row.try_get("new_value")?,
+                    changed_by: row.try_get("changed_by")?,
+                    changed_at: row.try_get("changed_at")?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl SystemConfigRepository for Database {
+    async fn list_config_entries(&self) -> ApiResult<Vec<SystemConfigEntry>> {
+        Database::list_config_entries(self).await
+    }
+
+    async fn get_config_value(&self, key: &str) -> ApiResult<Option<String>> {
+        Database::get_config_value(self, key).await
+    }
+
+    async fn apply_config_change(
+        &self,
+        change: &SystemConfigChange,
+        description: Option<&str>,
+    ) -> ApiResult<()> {
+        Database::apply_config_change(self, change, description).await
+    }
+
+    async fn list_config_changes(
+        &self,
+        key: Option<&str>,
+        limit: i64,
+    ) -> ApiResult<Vec<SystemConfigChange>> {
+        Database::list_config_changes(self, key, limit).await
+    }
 }
@@ -1,6 +1,7 @@
 use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
+use crate::infrastructure::persistence::conversation_events::record_tag_event;
 use crate::infrastructure::persistence::Database;
-use crate::domain::entities::{Conversation, ConversationStatus, Tag};
+use crate::domain::entities::{Conversation, ConversationEventType, ConversationStatus, Tag};
 use chrono;
 use sqlx::Row;
 
@@ -192,6 +193,8 @@ impl Database {
     ) -> ApiResult<()> {
         let now = chrono::Utc::now().to_rfc3339();
 
+        let mut tx = self.pool.begin().await?;
+
         // Use INSERT OR IGNORE for SQLite idempotency
         // This will silently ignore if the tag is already associated
         let result = sqlx::query(
@@ -202,13 +205,25 @@ impl Database {
         .bind(tag_id)
         .bind(added_by)
         .bind(&now)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await;
 
         // For databases that don't support INSERT OR IGNORE, we can check if it exists first
         // But for now, we'll handle the error gracefully
         match result {
-            Ok(_) => {
+            Ok(result) => {
+                // Only a new association goes into the history
+                if result.rows_affected() > 0 {
+                    record_tag_event(
+                        &mut tx,
+                        conversation_id,
+                        ConversationEventType::TagAdded,
+                        tag_id,
+                        Some(added_by),
+                    )
+                    .await?;
+                }
+                tx.commit().await?;
                 tracing::debug!("Tag {} added to conversation {}", tag_id, conversation_id);
                 Ok(())
             }
@@ -234,14 +249,26 @@ impl Database {
         conversation_id: &str,
         tag_id: &str,
     ) -> ApiResult<()> {
-        sqlx::query(
+        let mut tx = self.pool.begin().await?;
+        let result = sqlx::query(
             "DELETE FROM conversation_tags
              WHERE conversation_id = ? AND tag_id = ?",
         )
         .bind(conversation_id)
         .bind(tag_id)
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
+        if result.rows_affected() > 0 {
+            record_tag_event(
+                &mut tx,
+                conversation_id,
+                ConversationEventType::TagRemoved,
+                tag_id,
+                None,
+            )
+            .await?;
+        }
+        tx.commit().await?;
 
         tracing::debug!(
             "Tag {} removed from conversation {}",
@@ -263,6 +290,49 @@ impl Database {
         // Start transaction
         let mut tx = self.pool.begin().await?;
 
+        // History: tags dropped by the replacement, then tags it adds
+        let mut removed_query = String::from(
+            "INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+             SELECT ct.conversation_id, ?, ?, json_object('tag_id', ct.tag_id, 'tag', t.name), ?
+             FROM conversation_tags ct
+             LEFT JOIN tags t ON t.id = ct.tag_id
+             WHERE ct.conversation_id = ?",
+        );
+        if !tag_ids.is_empty() {
+            let placeholders = vec!["?"; tag_ids.len()].join(", ");
+            removed_query.push_str(&format!(" AND ct.tag_id NOT IN ({})", placeholders));
+        }
+        removed_query.push_str(" ORDER BY ct.added_at");
+        let mut removed = sqlx::query(&removed_query)
+            .bind(ConversationEventType::TagRemoved.as_str())
+            .bind(added_by)
+            .bind(&now)
+            .bind(conversation_id);
+        for tag_id in tag_ids {
+            removed = removed.bind(tag_id);
+        }
+        removed.execute(&mut *tx).await?;
+
+        for tag_id in tag_ids {
+            sqlx::query(
+                "INSERT INTO conversation_events (conversation_id, event_type, actor_id, data, created_at)
+                 SELECT ?, ?, ?, json_object('tag_id', ?, 'tag', (SELECT name FROM tags WHERE id = ?)), ?
+                 WHERE NOT EXISTS (
+                     SELECT 1 FROM conversation_tags WHERE conversation_id = ? AND tag_id = ?
+                 )",
+            )
+            .bind(conversation_id)
+            .bind(ConversationEventType::TagAdded.as_str())
+            .bind(added_by)
+            .bind(tag_id)
+            .bind(tag_id)
+            .bind(&now)
+            .bind(conversation_id)
+            .bind(tag_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
         // Delete all existing tags
         sqlx::query("DELETE FROM conversation_tags WHERE conversation_id = ?")
             .bind(conversation_id)
@@ -350,6 +420,11 @@ impl Database {
                 version: row.try_get("version")?,
                 tags: None,
                 priority: None,
+                contact_email_verified: None,
+                csat_score: None,
+                sentiment_score: None,
+                sentiment_trend: None,
+                summary: None,
             });
         }
 
@@ -438,6 +513,11 @@ impl Database {
                     version: row.try_get("version")?,
                     tags: None,
                     priority: None,
+                    contact_email_verified: None,
+                    csat_score: None,
+                    sentiment_score: None,
+                    sentiment_trend: None,
+                    summary: None,
                 });
             }
 
@@ -502,6 +582,11 @@ impl Database {
                     version: row.try_get("version")?,
                     tags: None,
                     priority: None,
+                    contact_email_verified: None,
+                    csat_score: None,
+                    sentiment_score: None,
+                    sentiment_trend: None,
+                    summary: None,
                 });
             }
 
@@ -9,14 +9,16 @@ impl Database {
 
     pub async fn create_team(&self, team: &Team) -> ApiResult<()> {
         sqlx::query(
-            "INSERT INTO teams (id, name, description, sla_policy_id, business_hours, created_at, updated_at)
-             VALUES (?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO teams (id, name, description, sla_policy_id, business_hours, holiday_calendar_id, take_on_reply, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&team.id)
         .bind(&team.name)
         .bind(&team.description)
         .bind(&team.sla_policy_id)
         .bind(&team.business_hours)
+        .bind(&team.holiday_calendar_id)
+        .bind(team.take_on_reply)
         .bind(&team.created_at)
         .bind(&team.updated_at)
         .execute(&self.pool)
@@ -35,7 +37,7 @@ impl Database {
 
     pub async fn get_team_by_id(&self, id: &str) -> ApiResult<Option<Team>> {
         let row = sqlx::query(
-            "SELECT id, name, description, sla_policy_id, business_hours, created_at, updated_at
+            "SELECT id, name, description, sla_policy_id, business_hours, holiday_calendar_id, take_on_reply, created_at, updated_at
              FROM teams WHERE id = ?",
         )
         .bind(id)
@@ -49,6 +51,8 @@ impl Database {
                 description: row.try_get("description").ok(),
                 sla_policy_id: row.try_get("sla_policy_id").ok(),
                 business_hours: row.try_get("business_hours").ok(),
+                holiday_calendar_id: row.try_get("holiday_calendar_id").ok(),
+                take_on_reply: row.try_get::<i32, _>("take_on_reply")? != 0,
                 created_at: row.try_get("created_at")?,
                 updated_at: row.try_get("updated_at")?,
             }))
@@ -59,7 +63,7 @@ impl Database {
 
     pub async fn list_teams(&self) -> ApiResult<Vec<Team>> {
         let rows = sqlx::query(
-            "SELECT id, name, description, sla_policy_id, business_hours, created_at, updated_at
+            "SELECT id, name, description, sla_policy_id, business_hours, holiday_calendar_id, take_on_reply, created_at, updated_at
              FROM teams ORDER BY name ASC",
         )
         .fetch_all(&self.pool)
@@ -73,6 +77,8 @@ impl Database {
                 description: row.try_get("description").ok(),
                 sla_policy_id: row.try_get("sla_policy_id").ok(),
                 business_hours: row.try_get("business_hours").ok(),
+                holiday_calendar_id: row.try_get("holiday_calendar_id").ok(),
+                take_on_reply: row.try_get::<i32, _>("take_on_reply")? != 0,
                 created_at: row.try_get("created_at")?,
                 updated_at: row.try_get("updated_at")?,
             });
@@ -157,6 +163,22 @@ impl Database {
         Ok(users)
     }
 
+    pub async fn get_team_lead_ids(&self, team_id: &str) -> ApiResult<Vec<String>> {
+        let rows = sqlx::query(
+            "SELECT user_id FROM team_memberships WHERE team_id = ? AND role = 'lead' ORDER BY joined_at ASC",
+        )
+        .bind(team_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let user_ids = rows
+            .iter()
+            .map(|row| row.try_get("user_id"))
+            .collect::<Result<Vec<String>, _>>()?;
+
+        Ok(user_ids)
+    }
+
     pub async fn is_team_member(&self, team_id: &str, user_id: &str) -> ApiResult<bool> {
         let row = sqlx::query(
             "SELECT COUNT(*) as count FROM team_memberships WHERE team_id = ? AND user_id = ?",
@@ -172,7 +194,7 @@ impl Database {
 
     pub async fn get_user_teams(&self, user_id: &str) -> ApiResult<Vec<Team>> {
         let rows = sqlx::query(
-            "SELECT t.id, t.name, t.description, t.sla_policy_id, t.business_hours, t.created_at, t.updated_at
+            "SELECT t.id, t.name, t.description, t.sla_policy_id, t.business_hours, t.holiday_calendar_id, t.take_on_reply, t.created_at, t.updated_at
              FROM teams t
              INNER JOIN team_memberships tm ON t.id = tm.team_id
              WHERE tm.user_id = ?
@@ -190,6 +212,8 @@ impl Database {
                 description: row.try_get("description").ok(),
                 sla_policy_id: row.try_get("sla_policy_id").ok(),
                 business_hours: row.try_get("business_hours").ok(),
+                holiday_calendar_id: row.try_get("holiday_calendar_id").ok(),
+                take_on_reply: row.try_get::<i32, _>("take_on_reply")? != 0,
                 created_at: row.try_get("created_at")?,
                 updated_at: row.try_get("updated_at")?,
             });
@@ -215,6 +239,38 @@ impl Database {
 
         Ok(())
     }
+
+    /// Link a team to a holiday calendar (None unlinks it)
+    pub async fn update_team_holiday_calendar(
+        &self,
+        team_id: &str,
+        holiday_calendar_id: Option<&str>,
+    ) -> ApiResult<()> {
+        let now = chrono::Utc::now().to_rfc3339();
+
+        sqlx::query("UPDATE teams SET holiday_calendar_id = ?, updated_at = ? WHERE id = ?")
+            .bind(holiday_calendar_id)
+            .bind(now)
+            .bind(team_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Turn take-on-reply assignment on or off for a team
+    pub async fn update_team_take_on_reply(&self, team_id: &str, enabled: bool) -> ApiResult<()> {
+        let now = chrono::Utc::now().to_rfc3339();
+
+        sqlx::query("UPDATE teams SET take_on_reply = ?, updated_at = ? WHERE id = ?")
+            .bind(enabled)
+            .bind(now)
+            .bind(team_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
 }
 
 use crate::domain::ports::team_repository::TeamRepository;
@@ -250,6 +306,10 @@ impl TeamRepository for Database {
         Database::get_team_members(self, team_id).await
     }
 
+    async fn get_team_lead_ids(&self, team_id: &str) -> ApiResult<Vec<String>> {
+        Database::get_team_lead_ids(self, team_id).await
+    }
+
     async fn is_team_member(&self, team_id: &str, user_id: &str) -> ApiResult<bool> {
         Database::is_team_member(self, team_id, user_id).await
     }
@@ -265,4 +325,16 @@ impl TeamRepository for Database {
     ) -> ApiResult<()> {
         Database::update_team_sla_policy(self, team_id, sla_policy_id).await
     }
+
+    async fn update_team_holiday_calendar(
+        &self,
+        team_id: &str,
+        holiday_calendar_id: Option<&str>,
+    ) -> ApiResult<()> {
+        Database::update_team_holiday_calendar(self, team_id, holiday_calendar_id).await
+    }
+
+    async fn update_team_take_on_reply(&self, team_id: &str, enabled: bool) -> ApiResult<()> {
+        Database::update_team_take_on_reply(self, team_id, enabled).await
+    }
 }
@@ -0,0 +1,122 @@
+use sqlx::Row;
+
+use crate::domain::entities::InboxTelegramConfig;
+use crate::domain::ports::telegram_config_repository::TelegramConfigRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+impl Database {
+    /// Get an inbox's Telegram configuration, with the bot token decrypted
+    pub async fn get_inbox_telegram_config(
+        &self,
+        inbox_id: &str,
+    ) -> ApiResult<Option<InboxTelegramConfig>> {
+        let row = sqlx::query(
+            "SELECT id, inbox_id, bot_token, bot_username, webhook_secret, enabled,
+                    CAST(created_at AS TEXT) as created_at,
+                    CAST(updated_at AS TEXT) as updated_at
+             FROM inbox_telegram_configs WHERE inbox_id = ?",
+        )
+        .bind(inbox_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let bot_token: String = row.try_get("bot_token")?;
+        let enabled: i64 = row.try_get("enabled")?;
+
+        Ok(Some(InboxTelegramConfig {
+            id: row.try_get("id")?,
+            inbox_id: row.try_get("inbox_id")?,
+            bot_token: self.decrypt_password_field(&bot_token),
+            bot_username: row.try_get("bot_username").ok(),
+            webhook_secret: row.try_get("webhook_secret")?,
+            enabled: enabled != 0,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        }))
+    }
+
+    pub async fn create_inbox_telegram_config(
+        &self,
+        config: &InboxTelegramConfig,
+    ) -> ApiResult<()> {
+        let bot_token = self.encrypt_password_field(&config.bot_token)?;
+
+        sqlx::query(
+            "INSERT INTO inbox_telegram_configs (
+                id, inbox_id, bot_token, bot_username, webhook_secret, enabled,
+                created_at, updated_at
+             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&config.id)
+        .bind(&config.inbox_id)
+        .bind(&bot_token)
+        .bind(&config.bot_username)
+        .bind(&config.webhook_secret)
+        .bind(if config.enabled { 1 } else { 0 })
+        .bind(&config.created_at)
+        .bind(&config.updated_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn update_inbox_telegram_config(
+        &self,
+        config: &InboxTelegramConfig,
+    ) -> ApiResult<()> {
+        let bot_token = self.encrypt_password_field(&config.bot_token)?;
+
+        sqlx::query(
+            "UPDATE inbox_telegram_configs
+             SET bot_token = ?, bot_username = ?, webhook_secret = ?, enabled = ?,
+                 updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&bot_token)
+        .bind(&config.bot_username)
+        .bind(&config.webhook_secret)
+        .bind(if config.enabled { 1 } else { 0 })
+        .bind(&config.updated_at)
+        .bind(&config.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_inbox_telegram_config(&self, inbox_id: &str) -> ApiResult<()> {
+        sqlx::query("DELETE FROM inbox_telegram_configs WHERE inbox_id = ?")
+            .bind(inbox_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl TelegramConfigRepository for Database {
+    async fn get_inbox_telegram_config(
+        &self,
+        inbox_id: &str,
+    ) -> ApiResult<Option<InboxTelegramConfig>> {
+        Database::get_inbox_telegram_config(self, inbox_id).await
+    }
+
+    async fn create_inbox_telegram_config(&self, config: &InboxTelegramConfig) -> ApiResult<()> {
+        Database::create_inbox_telegram_config(self, config).await
+    }
+
+    async fn update_inbox_telegram_config(&self, config: &InboxTelegramConfig) -> ApiResult<()> {
+        Database::update_inbox_telegram_config(self, config).await
+    }
+
+    async fn delete_inbox_telegram_config(&self, inbox_id: &str) -> ApiResult<()> {
+        Database::delete_inbox_telegram_config(self, inbox_id).await
+    }
+}
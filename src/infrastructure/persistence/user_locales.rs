@@ -0,0 +1,53 @@
+use sqlx::Row;
+
+use crate::domain::ports::user_locale_repository::UserLocaleRepository;
+use crate::infrastructure::http::middleware::error::ApiResult;
+use crate::infrastructure::persistence::Database;
+
+impl Database {
+    pub async fn get_user_locale(&self, user_id: &str) -> ApiResult<Option<String>> {
+        let row = sqlx::query("SELECT locale FROM user_locales WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(|row| row.try_get("locale")).transpose()?)
+    }
+
+    pub async fn set_user_locale(&self, user_id: &str, locale: Option<&str>) -> ApiResult<()> {
+        match locale {
+            Some(locale) => {
+                sqlx::query(
+                    "INSERT INTO user_locales (user_id, locale, updated_at)
+                     VALUES (?, ?, ?)
+                     ON CONFLICT (user_id) DO UPDATE SET
+                         locale = excluded.locale,
+                         updated_at = excluded.updated_at",
+                )
+                .bind(user_id)
+                .bind(locale)
+                .bind(chrono::Utc::now().to_rfc3339())
+                .execute(&self.pool)
+                .await?;
+            }
+            None => {
+                sqlx::query("DELETE FROM user_locales WHERE user_id = ?")
+                    .bind(user_id)
+                    .execute(&self.pool)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl UserLocaleRepository for Database {
+    async fn get_user_locale(&self, user_id: &str) -> ApiResult<Option<String>> {
+        self.get_user_locale(user_id).await
+    }
+
+    async fn set_user_locale(&self, user_id: &str, locale: Option<&str>) -> ApiResult<()> {
+        self.set_user_locale(user_id, locale).await
+    }
+}
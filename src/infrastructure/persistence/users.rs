@@ -338,11 +338,26 @@ impl UserRepository for Database {
         Database::count_admin_users(self).await
     }
 
+    async fn list_admin_user_ids(&self) -> ApiResult<Vec<String>> {
+        let rows = sqlx::query(
+            "SELECT DISTINCT u.id
+             FROM users u
+             INNER JOIN user_roles ur ON ur.user_id = u.id
+             INNER JOIN roles r ON r.id = ur.role_id
+             WHERE r.name = 'Admin' AND u.deleted_at IS NULL",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(|row| Ok(row.try_get("id")?)).collect()
+    }
+
     async fn delete_user(&self, user_id: &str) -> ApiResult<()> {
         sqlx::query("DELETE FROM users WHERE id = ?")
             .bind(user_id)
             .execute(&self.pool)
             .await?;
+        self.cache.invalidate_user_roles(user_id).await;
         Ok(())
     }
 }
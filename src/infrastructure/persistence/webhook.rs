@@ -1,6 +1,10 @@
 use sqlx::Row;
 
-use crate::{ApiError, ApiResult, Database, DeliveryStatus, Webhook, WebhookDelivery};
+use super::reporting::TAG_SEPARATOR;
+use crate::{
+    ApiError, ApiResult, ConversationSnapshot, Database, DeliveryStatus, SnapshotContact, Webhook,
+    WebhookBackfill, WebhookBackfillEvent, WebhookBackfillStatus, WebhookDelivery,
+};
 
 impl Database {
     pub async fn create_webhook(&self, webhook: &Webhook) -> ApiResult<()> {
@@ -8,8 +12,9 @@ impl Database {
             .map_err(|e| ApiError::Internal(format!("Failed to serialize events: {}", e)))?;
 
         sqlx::query(
-            "INSERT INTO webhooks (id, name, url, subscribed_events, secret, is_active, created_at, updated_at, created_by)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            "INSERT INTO webhooks (id, name, url, subscribed_events, secret, is_active,
+                                   include_conversation_snapshot, created_at, updated_at, created_by)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )
         .bind(&webhook.id)
         .bind(&webhook.name)
@@ -17,6 +22,7 @@ impl Database {
         .bind(&subscribed_events_json)
         .bind(&webhook.secret)
         .bind(webhook.is_active)
+        .bind(webhook.include_conversation_snapshot)
         .bind(&webhook.created_at)
         .bind(&webhook.updated_at)
         .bind(&webhook.created_by)
@@ -29,7 +35,8 @@ impl Database {
     /// Get a webhook by ID
     pub async fn get_webhook_by_id(&self, id: &str) -> ApiResult<Option<Webhook>> {
         let row = sqlx::query(
-            "SELECT id, name, url, subscribed_events, secret, is_active, created_at, updated_at, created_by
+            "SELECT id, name, url, subscribed_events, secret, is_active, include_conversation_snapshot,
+                    created_at, updated_at, created_by
              FROM webhooks
              WHERE id = ?",
         )
@@ -48,7 +55,10 @@ impl Database {
                 url: row.try_get("url")?,
                 subscribed_events,
                 secret: row.try_get("secret")?,
-                is_active: row.try_get("is_active")?,
+                is_active: row.try_get::<i32, _>("is_active")? != 0,
+                include_conversation_snapshot: row
+                    .try_get::<i32, _>("include_conversation_snapshot")?
+                    != 0,
                 created_at: row.try_get("created_at")?,
                 updated_at: row.try_get("updated_at")?,
                 created_by: row.try_get("created_by")?,
@@ -61,7 +71,8 @@ impl Database {
     /// List all webhooks with pagination
     pub async fn list_webhooks(&self, limit: i64, offset: i64) -> ApiResult<Vec<Webhook>> {
         let rows = sqlx::query(
-            "SELECT id, name, url, subscribed_events, secret, is_active, created_at, updated_at, created_by
+            "SELECT id, name, url, subscribed_events, secret, is_active, include_conversation_snapshot,
+                    created_at, updated_at, created_by
              FROM webhooks
              ORDER BY created_at DESC
              LIMIT ? OFFSET ?",
@@ -83,7 +94,10 @@ impl Database {
                 url: row.try_get("url")?,
                 subscribed_events,
                 secret: row.try_get("secret")?,
-                is_active: row.try_get("is_active")?,
+                is_active: row.try_get::<i32, _>("is_active")? != 0,
+                include_conversation_snapshot: row
+                    .try_get::<i32, _>("include_conversation_snapshot")?
+                    != 0,
                 created_at: row.try_get("created_at")?,
                 updated_at: row.try_get("updated_at")?,
                 created_by: row.try_get("created_by")?,
@@ -96,7 +110,8 @@ impl Database {
     /// Get active webhooks that subscribe to a specific event type
     pub async fn get_active_webhooks_for_event(&self, event_type: &str) -> ApiResult<Vec<Webhook>> {
         let rows = sqlx::query(
-            "SELECT id, name, url, subscribed_events, secret, is_active, created_at, updated_at, created_by
+            "SELECT id, name, url, subscribed_events, secret, is_active, include_conversation_snapshot,
+                    created_at, updated_at, created_by
              FROM webhooks
              WHERE is_active = ?",
         )
@@ -118,7 +133,10 @@ impl Database {
                     url: row.try_get("url")?,
                     subscribed_events,
                     secret: row.try_get("secret")?,
-                    is_active: row.try_get("is_active")?,
+                    is_active: row.try_get::<i32, _>("is_active")? != 0,
+                    include_conversation_snapshot: row
+                        .try_get::<i32, _>("include_conversation_snapshot")?
+                        != 0,
                     created_at: row.try_get("created_at")?,
                     updated_at: row.try_get("updated_at")?,
                     created_by: row.try_get("created_by")?,
@@ -136,7 +154,8 @@ impl Database {
 
         sqlx::query(
             "UPDATE webhooks
-             SET name = ?, url = ?, subscribed_events = ?, secret = ?, is_active = ?, updated_at = ?
+             SET name = ?, url = ?, subscribed_events = ?, secret = ?, is_active = ?,
+                 include_conversation_snapshot = ?, updated_at = ?
              WHERE id = ?",
         )
         .bind(&webhook.name)
@@ -144,6 +163,7 @@ impl Database {
         .bind(&subscribed_events_json)
         .bind(&webhook.secret)
         .bind(webhook.is_active)
+        .bind(webhook.include_conversation_snapshot)
         .bind(&webhook.updated_at)
         .bind(&webhook.id)
         .execute(&self.pool)
@@ -171,6 +191,57 @@ impl Database {
         Ok(row.try_get("count")?)
     }
 
+    /// Current state of a conversation for webhook payloads, in one query
+    pub async fn get_conversation_snapshot(
+        &self,
+        conversation_id: &str,
+    ) -> ApiResult<Option<ConversationSnapshot>> {
+        let row = sqlx::query(
+            "SELECT c.id, c.reference_number, c.status, c.priority,
+                    c.assigned_user_id, c.assigned_team_id, a.first_name AS assigned_user_name,
+                    c.contact_id, ct.first_name AS contact_name, u.email AS contact_email,
+                    (SELECT GROUP_CONCAT(t.name, char(31)) FROM conversation_tags cta
+                     JOIN tags t ON t.id = cta.tag_id
+                     WHERE cta.conversation_id = c.id) AS tags
+             FROM conversations c
+             LEFT JOIN agents a ON a.user_id = c.assigned_user_id
+             LEFT JOIN contacts ct ON ct.id = c.contact_id
+             LEFT JOIN users u ON u.id = ct.user_id
+             WHERE c.id = ?",
+        )
+        .bind(conversation_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let mut tags: Vec<String> = row
+            .try_get::<Option<String>, _>("tags")
+            .ok()
+            .flatten()
+            .map(|tags| tags.split(TAG_SEPARATOR).map(str::to_string).collect())
+            .unwrap_or_default();
+        tags.sort();
+
+        Ok(Some(ConversationSnapshot {
+            id: row.try_get("id")?,
+            reference_number: row.try_get("reference_number")?,
+            status: row.try_get("status")?,
+            priority: row.try_get("priority").ok(),
+            tags,
+            assigned_user_id: row.try_get("assigned_user_id").ok(),
+            assigned_user_name: row.try_get("assigned_user_name").ok(),
+            assigned_team_id: row.try_get("assigned_team_id").ok(),
+            contact: SnapshotContact {
+                id: row.try_get("contact_id")?,
+                name: row.try_get("contact_name").ok(),
+                email: row.try_get("contact_email").ok(),
+            },
+        }))
+    }
+
     // ========================================================================
     // Webhook Delivery Operations
     // ========================================================================
@@ -341,4 +412,237 @@ impl Database {
 
         Ok(row.try_get("count")?)
     }
+
+    /// Deliveries of the given event types attempted within `[from, to)`,
+    /// oldest first
+    pub async fn get_deliveries_for_backfill(
+        &self,
+        event_types: &[String],
+        from: &str,
+        to: &str,
+    ) -> ApiResult<Vec<WebhookDelivery>> {
+        if event_types.is_empty() {
+            return Ok(Vec::new());
+        }
+        let placeholders = vec!["?"; event_types.len()].join(", ");
+        let sql = format!(
+            "SELECT id, webhook_id, event_type, payload, signature, status,
+                    http_status_code, retry_count, next_retry_at, attempted_at, completed_at, error_message
+             FROM webhook_deliveries
+             WHERE event_type IN ({}) AND attempted_at >= ? AND attempted_at < ?
+             ORDER BY attempted_at ASC, id ASC",
+            placeholders
+        );
+        let mut query = sqlx::query(&sql);
+        for event_type in event_types {
+            query = query.bind(event_type);
+        }
+        let rows = query.bind(from).bind(to).fetch_all(&self.pool).await?;
+
+        rows.iter().map(row_to_webhook_delivery).collect()
+    }
+
+    // ========================================================================
+    // Webhook Backfill Operations
+    // ========================================================================
+
+    /// Store a backfill together with the events it will replay
+    pub async fn create_webhook_backfill(
+        &self,
+        backfill: &WebhookBackfill,
+        events: &[WebhookBackfillEvent],
+    ) -> ApiResult<()> {
+        let event_types_json = serde_json::to_string(&backfill.event_types)
+            .map_err(|e| ApiError::Internal(format!("Failed to serialize events: {}", e)))?;
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            "INSERT INTO webhook_backfills
+             (id, webhook_id, event_types, from_time, to_time, status, total_events,
+              processed_events, error_message, created_by, created_at, started_at,
+              completed_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&backfill.id)
+        .bind(&backfill.webhook_id)
+        .bind(&event_types_json)
+        .bind(&backfill.from_time)
+        .bind(&backfill.to_time)
+        .bind(backfill.status.as_str())
+        .bind(backfill.total_events)
+        .bind(backfill.processed_events)
+        .bind(&backfill.error_message)
+        .bind(&backfill.created_by)
+        .bind(&backfill.created_at)
+        .bind(&backfill.started_at)
+        .bind(&backfill.completed_at)
+        .bind(&backfill.updated_at)
+        .execute(&mut *tx)
+        .await?;
+
+        for event in events {
+            sqlx::query(
+                "INSERT INTO webhook_backfill_events
+                 (backfill_id, sequence, event_type, payload, occurred_at)
+                 VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(&backfill.id)
+            .bind(event.sequence)
+            .bind(&event.event_type)
+            .bind(&event.payload)
+            .bind(&event.occurred_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_webhook_backfill(&self, id: &str) -> ApiResult<Option<WebhookBackfill>> {
+        let row = sqlx::query(
+            "SELECT id, webhook_id, event_types, from_time, to_time, status, total_events,
+                    processed_events, error_message, created_by, created_at, started_at,
+                    completed_at, updated_at
+             FROM webhook_backfills
+             WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_webhook_backfill).transpose()
+    }
+
+    /// Events of a backfill after `after_sequence`, in order
+    pub async fn get_webhook_backfill_events(
+        &self,
+        backfill_id: &str,
+        after_sequence: i64,
+        limit: i64,
+    ) -> ApiResult<Vec<WebhookBackfillEvent>> {
+        let rows = sqlx::query(
+            "SELECT sequence, event_type, payload, occurred_at
+             FROM webhook_backfill_events
+             WHERE backfill_id = ? AND sequence > ?
+             ORDER BY sequence ASC
+             LIMIT ?",
+        )
+        .bind(backfill_id)
+        .bind(after_sequence)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut events = Vec::new();
+        for row in rows {
+            events.push(WebhookBackfillEvent {
+                sequence: row.try_get("sequence")?,
+                event_type: row.try_get("event_type")?,
+                payload: row.try_get("payload")?,
+                occurred_at: row.try_get("occurred_at")?,
+            });
+        }
+
+        Ok(events)
+    }
+
+    /// Store status and progress of a backfill
+    pub async fn update_webhook_backfill_progress(
+        &self,
+        backfill: &WebhookBackfill,
+    ) -> ApiResult<()> {
+        sqlx::query(
+            "UPDATE webhook_backfills
+             SET status = ?, total_events = ?, processed_events = ?, error_message = ?,
+                 started_at = ?, completed_at = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(backfill.status.as_str())
+        .bind(backfill.total_events)
+        .bind(backfill.processed_events)
+        .bind(&backfill.error_message)
+        .bind(&backfill.started_at)
+        .bind(&backfill.completed_at)
+        .bind(&backfill.updated_at)
+        .bind(&backfill.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Drop the events of a finished backfill
+    pub async fn clear_webhook_backfill_events(&self, backfill_id: &str) -> ApiResult<()> {
+        sqlx::query("DELETE FROM webhook_backfill_events WHERE backfill_id = ?")
+            .bind(backfill_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn row_to_webhook_delivery(row: &sqlx::any::AnyRow) -> ApiResult<WebhookDelivery> {
+    Ok(WebhookDelivery {
+        id: row.try_get("id")?,
+        webhook_id: row.try_get("webhook_id")?,
+        event_type: row.try_get("event_type")?,
+        payload: row.try_get("payload")?,
+        signature: row.try_get("signature")?,
+        status: DeliveryStatus::from(row.try_get::<String, _>("status")?),
+        http_status_code: row
+            .try_get::<Option<i32>, _>("http_status_code")
+            .ok()
+            .flatten(),
+        retry_count: row.try_get("retry_count")?,
+        next_retry_at: row
+            .try_get::<Option<String>, _>("next_retry_at")
+            .ok()
+            .flatten(),
+        attempted_at: row
+            .try_get::<Option<String>, _>("attempted_at")
+            .ok()
+            .flatten(),
+        completed_at: row
+            .try_get::<Option<String>, _>("completed_at")
+            .ok()
+            .flatten(),
+        error_message: row
+            .try_get::<Option<String>, _>("error_message")
+            .ok()
+            .flatten(),
+    })
+}
+
+fn row_to_webhook_backfill(row: &sqlx::any::AnyRow) -> ApiResult<WebhookBackfill> {
+    let event_types_json: String = row.try_get("event_types")?;
+    let event_types = serde_json::from_str(&event_types_json)
+        .map_err(|e| ApiError::Internal(format!("Failed to parse event types: {}", e)))?;
+
+    Ok(WebhookBackfill {
+        id: row.try_get("id")?,
+        webhook_id: row.try_get("webhook_id")?,
+        event_types,
+        from_time: row.try_get("from_time")?,
+        to_time: row.try_get("to_time")?,
+        status: WebhookBackfillStatus::from(row.try_get::<String, _>("status")?),
+        total_events: row.try_get("total_events")?,
+        processed_events: row.try_get("processed_events")?,
+        error_message: row
+            .try_get::<Option<String>, _>("error_message")
+            .ok()
+            .flatten(),
+        created_by: row.try_get("created_by")?,
+        created_at: row.try_get("created_at")?,
+        started_at: row
+            .try_get::<Option<String>, _>("started_at")
+            .ok()
+            .flatten(),
+        completed_at: row
+            .try_get::<Option<String>, _>("completed_at")
+            .ok()
+            .flatten(),
+        updated_at: row.try_get("updated_at")?,
+    })
 }
@@ -0,0 +1,70 @@
+use crate::domain::entities::Message;
+use crate::domain::ports::conversation_repository::ConversationRepository;
+use crate::domain::ports::inbox_repository::InboxRepository;
+use crate::MessageDeliveryProvider;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Delivery provider that routes each message by its inbox's channel type
+///
+/// Channel types without a registered provider use the default (email).
+pub struct ChannelDeliveryProvider {
+    conversation_repo: Arc<dyn ConversationRepository>,
+    inbox_repo: Arc<dyn InboxRepository>,
+    default_provider: Arc<dyn MessageDeliveryProvider>,
+    providers: HashMap<String, Arc<dyn MessageDeliveryProvider>>,
+}
+
+impl ChannelDeliveryProvider {
+    pub fn new(
+        conversation_repo: Arc<dyn ConversationRepository>,
+        inbox_repo: Arc<dyn InboxRepository>,
+        default_provider: Arc<dyn MessageDeliveryProvider>,
+    ) -> Self {
+        Self {
+            conversation_repo,
+            inbox_repo,
+            default_provider,
+            providers: HashMap::new(),
+        }
+    }
+
+    /// Deliver messages of inboxes with this channel type through `provider`
+    pub fn with_provider(
+        mut self,
+        channel_type: &str,
+        provider: Arc<dyn MessageDeliveryProvider>,
+    ) -> Self {
+        self.providers.insert(channel_type.to_string(), provider);
+        self
+    }
+}
+
+#[async_trait]
+impl MessageDeliveryProvider for ChannelDeliveryProvider {
+    async fn deliver(&self, message: &Message) -> Result<(), String> {
+        let conversation = self
+            .conversation_repo
+            .get_conversation_by_id(&message.conversation_id)
+            .await
+            .map_err(|e| format!("Failed to load conversation: {}", e))?
+            .ok_or_else(|| format!("Conversation {} not found", message.conversation_id))?;
+        let inbox = self
+            .inbox_repo
+            .get_inbox(&conversation.inbox_id)
+            .await
+            .map_err(|e| format!("Failed to load inbox: {}", e))?
+            .ok_or_else(|| format!("Inbox {} not found", conversation.inbox_id))?;
+
+        let provider = self
+            .providers
+            .get(&inbox.channel_type)
+            .unwrap_or(&self.default_provider);
+        provider.deliver(message).await
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "channel"
+    }
+}
@@ -0,0 +1,62 @@
+use crate::domain::entities::{Message, CHAT_MESSAGE_EVENT_TYPE};
+use crate::infrastructure::providers::connection_manager::{ConnectionManager, NotificationEvent};
+use crate::MessageDeliveryProvider;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Delivery provider for chat widget inboxes
+///
+/// Pushes agent replies to the visitor connected to the conversation. Visitors
+/// who are offline see the reply in the history sent when they reconnect, so
+/// delivery succeeds either way.
+pub struct ChatWidgetDeliveryProvider {
+    connections: Arc<dyn ConnectionManager>,
+}
+
+impl ChatWidgetDeliveryProvider {
+    pub fn new(connections: Arc<dyn ConnectionManager>) -> Self {
+        Self { connections }
+    }
+}
+
+#[async_trait]
+impl MessageDeliveryProvider for ChatWidgetDeliveryProvider {
+    async fn deliver(&self, message: &Message) -> Result<(), String> {
+        if !self
+            .connections
+            .is_connected(&message.conversation_id)
+            .await
+        {
+            tracing::debug!(
+                "No visitor connected to conversation {}, reply kept for history",
+                message.conversation_id
+            );
+            return Ok(());
+        }
+
+        let event = NotificationEvent {
+            id: message.id.clone(),
+            type_: CHAT_MESSAGE_EVENT_TYPE.to_string(),
+            created_at: message.created_at.clone(),
+            is_read: false,
+            conversation_id: Some(message.conversation_id.clone()),
+            message_id: Some(message.id.clone()),
+            actor_id: Some(message.author_id.clone()),
+            sequence: None,
+        };
+        if let Err(e) = self
+            .connections
+            .send_to_user(&message.conversation_id, event)
+            .await
+        {
+            // The visitor disconnected in between; the reply is in their history
+            tracing::debug!("Failed to push chat reply {}: {}", message.id, e);
+        }
+
+        Ok(())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "chat_widget"
+    }
+}
@@ -1,11 +1,13 @@
 use async_trait::async_trait;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{mpsc::Sender, Mutex};
 
+use crate::domain::ports::notification_repository::NotificationRepository;
+
 /// Represents a notification event to be sent to a connected user
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NotificationEvent {
     pub id: String,
     #[serde(rename = "type")]
@@ -15,6 +17,9 @@ pub struct NotificationEvent {
     pub conversation_id: Option<String>,
     pub message_id: Option<String>,
     pub actor_id: Option<String>,
+    /// Stream sequence number (sent as the SSE event id, not in the payload)
+    #[serde(skip)]
+    pub sequence: Option<i64>,
 }
 
 /// Trait for managing real-time connections and delivering notifications
@@ -34,6 +39,7 @@ pub trait ConnectionManager: Send + Sync {
 }
 
 /// In-memory implementation of ConnectionManager using a HashMap
+#[derive(Clone)]
 pub struct InMemoryConnectionManager {
     connections: Arc<Mutex<HashMap<String, Sender<NotificationEvent>>>>,
 }
@@ -45,6 +51,14 @@ impl InMemoryConnectionManager {
             connections: Arc::new(Mutex::new(HashMap::new())),
         }
     }
+
+    /// Drop connections whose receiver has gone away, returning the users
+    /// that are still connected
+    pub async fn retain_open_connections(&self) -> Vec<String> {
+        let mut connections = self.connections.lock().await;
+        connections.retain(|_, sender| !sender.is_closed());
+        connections.keys().cloned().collect()
+    }
 }
 
 impl Default for InMemoryConnectionManager {
@@ -84,6 +98,72 @@ impl ConnectionManager for InMemoryConnectionManager {
     }
 }
 
+/// ConnectionManager that records every event before delivering it
+///
+/// Each event is stored with a per-stream sequence number so a client that
+/// reconnects with `Last-Event-ID` can be replayed whatever it missed. Events
+/// for users who are offline are stored and reported as delivered.
+pub struct ResumableConnectionManager {
+    inner: Arc<dyn ConnectionManager>,
+    notification_repo: Arc<dyn NotificationRepository>,
+}
+
+impl ResumableConnectionManager {
+    /// Create a new ResumableConnectionManager backed by the given repository
+    pub fn new(notification_repo: Arc<dyn NotificationRepository>) -> Self {
+        Self::with_connections(notification_repo, Arc::new(InMemoryConnectionManager::new()))
+    }
+
+    /// Record events before handing them to another ConnectionManager
+    /// (e.g. one that routes across instances)
+    pub fn with_connections(
+        notification_repo: Arc<dyn NotificationRepository>,
+        inner: Arc<dyn ConnectionManager>,
+    ) -> Self {
+        Self {
+            inner,
+            notification_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl ConnectionManager for ResumableConnectionManager {
+    async fn add_connection(&self, user_id: &str, sender: Sender<NotificationEvent>) {
+        self.inner.add_connection(user_id, sender).await;
+    }
+
+    async fn remove_connection(&self, user_id: &str) {
+        self.inner.remove_connection(user_id).await;
+    }
+
+    async fn send_to_user(
+        &self,
+        user_id: &str,
+        mut event: NotificationEvent,
+    ) -> Result<(), String> {
+        let payload = serde_json::to_string(&event)
+            .map_err(|e| format!("Failed to serialize notification: {}", e))?;
+        let sequence = self
+            .notification_repo
+            .append_stream_event(user_id, &payload)
+            .await
+            .map_err(|e| format!("Failed to record notification event: {}", e))?;
+        event.sequence = Some(sequence);
+
+        if !self.inner.is_connected(user_id).await {
+            // Queued: delivered on the next resume from an earlier event id
+            return Ok(());
+        }
+
+        self.inner.send_to_user(user_id, event).await
+    }
+
+    async fn is_connected(&self, user_id: &str) -> bool {
+        self.inner.is_connected(user_id).await
+    }
+}
+
 /// Mock implementation of ConnectionManager for testing
 /// Records all notifications sent instead of actually sending them
 pub struct MockConnectionManager {
@@ -175,6 +255,7 @@ mod tests {
             conversation_id: Some("conv1".to_string()),
             message_id: Some("msg1".to_string()),
             actor_id: Some("user2".to_string()),
+            sequence: None,
         };
 
         let result = manager.send_to_user("user1", event.clone()).await;
@@ -197,6 +278,7 @@ mod tests {
             conversation_id: None,
             message_id: None,
             actor_id: None,
+            sequence: None,
         };
 
         let result = manager.send_to_user("user1", event).await;
@@ -204,6 +286,20 @@ mod tests {
         assert!(result.unwrap_err().contains("not connected"));
     }
 
+    #[tokio::test]
+    async fn test_in_memory_retain_open_connections() {
+        let manager = InMemoryConnectionManager::new();
+        let (tx1, _rx1) = mpsc::channel(10);
+        let (tx2, rx2) = mpsc::channel(10);
+
+        manager.add_connection("user1", tx1).await;
+        manager.add_connection("user2", tx2).await;
+        drop(rx2);
+
+        assert_eq!(manager.retain_open_connections().await, vec!["user1"]);
+        assert!(!manager.is_connected("user2").await);
+    }
+
     #[tokio::test]
     async fn test_mock_records_notifications() {
         let manager = MockConnectionManager::new();
@@ -216,6 +312,7 @@ mod tests {
             conversation_id: Some("conv1".to_string()),
             message_id: Some("msg1".to_string()),
             actor_id: Some("user2".to_string()),
+            sequence: None,
         };
 
         let event2 = NotificationEvent {
@@ -226,6 +323,7 @@ mod tests {
             conversation_id: Some("conv2".to_string()),
             message_id: None,
             actor_id: Some("user3".to_string()),
+            sequence: None,
         };
 
         manager.send_to_user("user1", event1).await.unwrap();
@@ -266,6 +364,7 @@ mod tests {
             conversation_id: None,
             message_id: None,
             actor_id: None,
+            sequence: None,
         };
 
         let event2 = NotificationEvent {
@@ -276,6 +375,7 @@ mod tests {
             conversation_id: None,
             message_id: None,
             actor_id: None,
+            sequence: None,
         };
 
         manager.send_to_user("user1", event1).await.unwrap();
@@ -1,18 +1,25 @@
-use crate::domain::entities::Message;
+use crate::application::services::{AttachmentService, BrandingService, EmailOAuthService};
+use crate::domain::entities::{
+    render_template, Branding, EmailAuthMethod, EmailRecipients, InboxEmailConfig, Message,
+};
 use crate::domain::ports::agent_repository::AgentRepository;
 use crate::domain::ports::contact_repository::ContactRepository;
 use crate::domain::ports::conversation_repository::ConversationRepository;
 use crate::domain::ports::email_repository::EmailRepository;
+use crate::domain::ports::email_sender::{InboxEmailSender, OutgoingEmail};
 /// Email Delivery Provider (Feature 021)
 ///
 /// Implements MessageDeliveryProvider trait for sending agent replies via SMTP.
 /// Formats emails with reference numbers and sends using lettre.
 use crate::domain::ports::template_repository::TemplateRepository;
-use crate::infrastructure::providers::EmailParserService;
+use crate::infrastructure::providers::{
+    EmailParserService, SmtpConnectionPool, SmtpPoolConfig, SmtpServer,
+};
 use crate::MessageDeliveryProvider;
 use lettre::{
-    message::header::ContentType, transport::smtp::authentication::Credentials,
-    Message as LettreMessage, SmtpTransport, Transport,
+    message::header::ContentType,
+    transport::smtp::authentication::{Credentials, Mechanism},
+    Message as LettreMessage,
 };
 use std::sync::Arc;
 
@@ -24,6 +31,10 @@ pub struct EmailDeliveryProvider {
     agent_repo: Arc<dyn AgentRepository>,
     template_repo: Arc<dyn TemplateRepository>,
     parser: EmailParserService,
+    attachment_service: Option<AttachmentService>,
+    oauth_service: Option<EmailOAuthService>,
+    branding_service: Option<BrandingService>,
+    smtp_pool: Arc<SmtpConnectionPool>,
 }
 
 impl EmailDeliveryProvider {
@@ -42,7 +53,67 @@ impl EmailDeliveryProvider {
             agent_repo,
             template_repo,
             parser: EmailParserService::new(),
+            attachment_service: None,
+            oauth_service: None,
+            branding_service: None,
+            smtp_pool: Arc::new(SmtpConnectionPool::new(SmtpPoolConfig::default())),
+        }
+    }
+
+    /// Link message attachments in outbound emails using signed download URLs
+    pub fn with_attachment_service(mut self, attachment_service: AttachmentService) -> Self {
+        self.attachment_service = Some(attachment_service);
+        self
+    }
+
+    /// Sign in to SMTP servers of mailboxes set up for OAuth2 with XOAUTH2
+    pub fn with_oauth_service(mut self, oauth_service: EmailOAuthService) -> Self {
+        self.oauth_service = Some(oauth_service);
+        self
+    }
+
+    /// Fill the reply template's branding placeholders from workspace settings
+    pub fn with_branding_service(mut self, branding_service: BrandingService) -> Self {
+        self.branding_service = Some(branding_service);
+        self
+    }
+
+    /// Send through a shared SMTP connection pool instead of the default one
+    pub fn with_smtp_pool(mut self, smtp_pool: Arc<SmtpConnectionPool>) -> Self {
+        self.smtp_pool = smtp_pool;
+        self
+    }
+
+    /// Append signed download links for the message's attachments to its content
+    async fn content_with_attachment_links(&self, message: &Message) -> String {
+        let Some(attachment_service) = &self.attachment_service else {
+            return message.content.clone();
+        };
+
+        let attachments = match attachment_service.get_message_attachments(&message.id).await {
+            Ok(attachments) => attachments,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load attachments for message {}: {}",
+                    message.id,
+                    e
+                );
+                return message.content.clone();
+            }
+        };
+        if attachments.is_empty() {
+            return message.content.clone();
+        }
+
+        let mut content = format!("{}\n\nAttachments:", message.content);
+        for attachment in &attachments {
+            content.push_str(&format!(
+                "\n{}: {}",
+                attachment.filename,
+                attachment_service.signed_download_url(attachment)
+            ));
         }
+        content
     }
 
     /// Render email body from message content with HTML template
@@ -63,10 +134,17 @@ impl EmailDeliveryProvider {
         match self.template_repo.get_template(template_name).await {
             Ok(Some(template)) => {
                 let html_content = content.replace("\n", "<br>");
-                let rendered = template
-                    .body_html
-                    .replace("{{message_content}}", &html_content)
-                    .replace("{{agent_signature}}", &signature);
+                let branding = match &self.branding_service {
+                    Some(branding_service) => branding_service.current().await,
+                    None => Branding::default(),
+                };
+                let rendered = render_template(
+                    &template
+                        .body_html
+                        .replace("{{message_content}}", &html_content)
+                        .replace("{{agent_signature}}", &signature),
+                    &branding.email_variables(),
+                );
 
                 (rendered, true) // true = is HTML
             }
@@ -78,6 +156,49 @@ impl EmailDeliveryProvider {
         }
     }
 
+    /// SMTP server and sign-in for an inbox; OAuth2 mailboxes send the
+    /// access token with XOAUTH2
+    async fn smtp_server(&self, email_config: &InboxEmailConfig) -> Result<SmtpServer, String> {
+        let (creds, mechanisms) = match email_config.auth_method {
+            EmailAuthMethod::Password => (
+                Credentials::new(
+                    email_config.smtp_username.clone(),
+                    email_config.smtp_password.clone(),
+                ),
+                vec![Mechanism::Plain, Mechanism::Login],
+            ),
+            EmailAuthMethod::OAuth2 => {
+                let oauth_service = self
+                    .oauth_service
+                    .as_ref()
+                    .ok_or("OAuth2 mailbox sign-in is not available")?;
+                let access_token = oauth_service
+                    .access_token(email_config)
+                    .await
+                    .map_err(|e| format!("Failed to get OAuth2 access token: {}", e))?;
+                (
+                    Credentials::new(email_config.smtp_username.clone(), access_token),
+                    vec![Mechanism::Xoauth2],
+                )
+            }
+        };
+
+        Ok(SmtpServer {
+            fingerprint: format!(
+                "{}:{}:{}:{}:{}",
+                email_config.smtp_host,
+                email_config.smtp_port,
+                email_config.smtp_use_tls,
+                email_config.smtp_username,
+                email_config.auth_method
+            ),
+            host: email_config.smtp_host.clone(),
+            port: email_config.smtp_port as u16,
+            use_tls: email_config.smtp_use_tls,
+            credentials: Some((creds, mechanisms)),
+        })
+    }
+
     /// Format subject with reference number
     fn format_subject_with_reference(
         &self,
@@ -121,6 +242,21 @@ impl MessageDeliveryProvider for EmailDeliveryProvider {
                 )
             })?;
 
+        // Everyone on the thread except suppressed participants
+        let participants = self
+            .email_repo
+            .list_email_participants(&conversation.id)
+            .await
+            .map_err(|e| format!("Failed to load email participants: {}", e))?;
+        let recipients = EmailRecipients::for_reply(&email_channel.email, &participants);
+        if recipients.is_empty() {
+            tracing::info!(
+                "Not emailing reply for conversation {}: every participant is suppressed",
+                conversation.id
+            );
+            return Ok(());
+        }
+
         // Get inbox email configuration
         let email_config = self
             .email_repo
@@ -150,8 +286,9 @@ impl MessageDeliveryProvider for EmailDeliveryProvider {
         );
 
         // Render email body
+        let content = self.content_with_attachment_links(message).await;
         let (body, is_html) = self
-            .render_email_body(&message.content, agent_name.as_deref())
+            .render_email_body(&content, agent_name.as_deref())
             .await;
 
         // Build email message
@@ -166,49 +303,47 @@ impl MessageDeliveryProvider for EmailDeliveryProvider {
             ContentType::TEXT_PLAIN
         };
 
-        let email = LettreMessage::builder()
-            .from(
-                from_address
-                    .parse()
-                    .map_err(|e| format!("Invalid from address: {}", e))?,
-            )
-            .to(email_channel
-                .email
+        let mut builder = LettreMessage::builder().from(
+            from_address
                 .parse()
-                .map_err(|e| format!("Invalid to address: {}", e))?)
+                .map_err(|e| format!("Invalid from address: {}", e))?,
+        );
+        if let Some(to) = &recipients.to {
+            builder = builder.to(to
+                .parse()
+                .map_err(|e| format!("Invalid to address: {}", e))?);
+        }
+        for cc in &recipients.cc {
+            builder = builder.cc(cc
+                .parse()
+                .map_err(|e| format!("Invalid cc address: {}", e))?);
+        }
+        let email = builder
             .subject(&subject)
             .header(content_type)
             .body(body)
             .map_err(|e| format!("Failed to build email: {}", e))?;
 
-        // Create SMTP transport
-        let creds = Credentials::new(
-            email_config.smtp_username.clone(),
-            email_config.smtp_password.clone(),
-        );
-
-        let mailer = if email_config.smtp_use_tls {
-            SmtpTransport::starttls_relay(&email_config.smtp_host)
-                .map_err(|e| format!("Failed to create SMTP transport: {}", e))?
-                .port(email_config.smtp_port as u16)
-                .credentials(creds)
-                .build()
-        } else {
-            SmtpTransport::builder_dangerous(&email_config.smtp_host)
-                .port(email_config.smtp_port as u16)
-                .credentials(creds)
-                .build()
-        };
-
-        // Send email asynchronously
-        tokio::task::spawn_blocking(move || mailer.send(&email))
-            .await
-            .map_err(|e| format!("Task join error: {}", e))?
-            .map_err(|e| format!("SMTP send error: {}", e))?;
+        // Reuse a pooled connection for the inbox when one is open
+        let server = self.smtp_server(&email_config).await?;
+        self.smtp_pool
+            .send(
+                &conversation.inbox_id,
+                server,
+                email.envelope().clone(),
+                email.formatted(),
+            )
+            .await?;
 
         tracing::info!(
             "Email sent successfully to {} for conversation {} [#{}]",
-            email_channel.email,
+            recipients
+                .to
+                .iter()
+                .chain(recipients.cc.iter())
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", "),
             conversation.id,
             conversation.reference_number
         );
@@ -221,6 +356,56 @@ impl MessageDeliveryProvider for EmailDeliveryProvider {
     }
 }
 
+#[async_trait::async_trait]
+impl InboxEmailSender for EmailDeliveryProvider {
+    #[tracing::instrument(skip(self, email), fields(to = %email.to))]
+    async fn send_from_inbox(&self, inbox_id: &str, email: &OutgoingEmail) -> Result<(), String> {
+        let email_config = self
+            .email_repo
+            .get_inbox_email_config(inbox_id)
+            .await
+            .map_err(|e| format!("Failed to load inbox email config: {}", e))?
+            .ok_or_else(|| format!("No email configuration found for inbox {}", inbox_id))?;
+
+        let from_address = format!(
+            "{} <{}>",
+            email_config.display_name, email_config.email_address
+        );
+        let (content_type, body) = match &email.html_body {
+            Some(html) => (ContentType::TEXT_HTML, html.clone()),
+            None => (ContentType::TEXT_PLAIN, email.text_body.clone()),
+        };
+        let message = LettreMessage::builder()
+            .from(
+                from_address
+                    .parse()
+                    .map_err(|e| format!("Invalid from address: {}", e))?,
+            )
+            .to(email
+                .to
+                .parse()
+                .map_err(|e| format!("Invalid to address: {}", e))?)
+            .subject(&email.subject)
+            .header(content_type)
+            .body(body)
+            .map_err(|e| format!("Failed to build email: {}", e))?;
+
+        let server = self.smtp_server(&email_config).await?;
+        self.smtp_pool
+            .send(
+                inbox_id,
+                server,
+                message.envelope().clone(),
+                message.formatted(),
+            )
+            .await?;
+
+        tracing::info!("Email '{}' sent from inbox {}", email.subject, inbox_id);
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,70 @@
+use crate::domain::entities::OAuthTokenGrant;
+use crate::domain::ports::email_oauth_client::EmailOAuthClient;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Error body of an OAuth2 token endpoint (RFC 6749 section 5.2)
+#[derive(Debug, Deserialize)]
+struct TokenErrorResponse {
+    error: String,
+    error_description: Option<String>,
+}
+
+/// OAuth2 token endpoint client over HTTPS
+pub struct HttpEmailOAuthClient {
+    client: reqwest::Client,
+}
+
+impl HttpEmailOAuthClient {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .unwrap_or_default();
+
+        Self { client }
+    }
+}
+
+impl Default for HttpEmailOAuthClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl EmailOAuthClient for HttpEmailOAuthClient {
+    async fn request_token(
+        &self,
+        token_url: &str,
+        params: &[(&str, &str)],
+    ) -> Result<OAuthTokenGrant, String> {
+        let response = self
+            .client
+            .post(token_url)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .form(params)
+            .send()
+            .await
+            .map_err(|e| format!("Token request failed: {}", e))?;
+
+        let status = response.status();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| format!("Failed to read token response: {}", e))?;
+
+        if !status.is_success() {
+            return Err(match serde_json::from_slice::<TokenErrorResponse>(&body) {
+                Ok(error) => match error.error_description {
+                    Some(description) => format!("{}: {}", error.error, description),
+                    None => error.error,
+                },
+                Err(_) => format!("Token endpoint returned {}", status),
+            });
+        }
+
+        serde_json::from_slice(&body).map_err(|e| format!("Invalid token response: {}", e))
+    }
+}
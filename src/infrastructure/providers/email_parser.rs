@@ -2,8 +2,10 @@
 ///
 /// Handles parsing of incoming emails using mail-parser crate.
 /// Extracts headers, body content, and attachments.
+use crate::domain::entities::{AutoGeneratedEmailKind, CalendarInvite, EmailAuthentication};
+use crate::domain::services::{parse_authentication_results, parse_calendar_invite};
 use crate::infrastructure::http::middleware::error::ApiResult;
-use mail_parser::{MessageParser, MimeHeaders};
+use mail_parser::{Message, MessageParser, MessagePart, MimeHeaders};
 
 /// Parsed email data structure
 #[derive(Debug, Clone)]
@@ -17,6 +19,9 @@ pub struct ParsedEmail {
     /// Email sender display name
     pub from_name: Option<String>,
 
+    /// Addresses in the Cc header
+    pub cc_addresses: Vec<String>,
+
     /// Email subject
     pub subject: Option<String>,
 
@@ -34,6 +39,17 @@ pub struct ParsedEmail {
 
     /// Parsed attachments
     pub attachments: Vec<EmailAttachment>,
+
+    /// Set for out-of-office replies and bounces, which are not written by
+    /// the contact
+    pub auto_generated: Option<AutoGeneratedEmailKind>,
+
+    /// Meeting invite from a `text/calendar` part or `.ics` attachment
+    pub calendar_invite: Option<CalendarInvite>,
+
+    /// SPF/DKIM/DMARC results from the topmost `Authentication-Results`
+    /// header, added by the receiving server
+    pub authentication: Option<EmailAuthentication>,
 }
 
 /// Email attachment data
@@ -90,11 +106,31 @@ impl EmailParserService {
 
         let from_name = from.name().map(|s| s.to_string());
 
+        let cc_addresses = message
+            .cc()
+            .map(|cc| {
+                cc.iter()
+                    .filter_map(|addr| addr.address())
+                    .map(|address| address.to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
         // Extract subject (optional)
         let subject = message.subject().map(|s| s.to_string());
 
-        // Extract body content
-        let text_body = message.body_text(0).map(|s| s.to_string());
+        // Meeting invites are shown as event details rather than a raw attachment
+        let calendar_invite = message
+            .parts
+            .iter()
+            .filter(|part| is_calendar_part(part))
+            .find_map(|part| part.text_contents().and_then(parse_calendar_invite));
+
+        // Extract body content; an invite with no text part must not show up as raw ICS
+        let text_body = message
+            .body_text(0)
+            .map(|s| s.to_string())
+            .filter(|body| calendar_invite.is_none() || !is_calendar_text(body));
         let html_body = message.body_html(0).map(|s| s.to_string());
 
         // Extract threading headers
@@ -109,6 +145,9 @@ impl EmailParserService {
         // Extract attachments
         let mut attachments = Vec::new();
         for attachment in message.attachments() {
+            if calendar_invite.is_some() && is_calendar_part(attachment) {
+                continue;
+            }
             let body = attachment.contents();
 
             let filename = attachment
@@ -133,12 +172,17 @@ impl EmailParserService {
             message_id,
             from_address,
             from_name,
+            cc_addresses,
             subject,
             text_body,
             html_body,
             references,
             in_reply_to,
             attachments,
+            auto_generated: detect_auto_generated(&message),
+            calendar_invite,
+            authentication: first_header_raw(&message, "Authentication-Results")
+                .and_then(parse_authentication_results),
         })
     }
 
@@ -178,6 +222,85 @@ impl EmailParserService {
     }
 }
 
+/// Raw value of the first (topmost) occurrence of a header
+///
+/// `Message::header_raw` returns the last one, which for trace headers is
+/// the one furthest from our server.
+fn first_header_raw<'a>(message: &'a Message, name: &str) -> Option<&'a str> {
+    message
+        .headers()
+        .iter()
+        .find(|header| header.name().eq_ignore_ascii_case(name))
+        .and_then(|header| {
+            let raw = message
+                .raw_message()
+                .get(header.offset_start..header.offset_end)?;
+            std::str::from_utf8(raw).ok()
+        })
+}
+
+/// Whether a MIME part carries an iCalendar payload
+fn is_calendar_part(part: &MessagePart) -> bool {
+    let calendar_type = part.content_type().is_some_and(|ct| {
+        let subtype = ct.subtype().unwrap_or_default();
+        (ct.ctype().eq_ignore_ascii_case("text") && subtype.eq_ignore_ascii_case("calendar"))
+            || (ct.ctype().eq_ignore_ascii_case("application")
+                && subtype.eq_ignore_ascii_case("ics"))
+    });
+    let ics_name = part
+        .attachment_name()
+        .is_some_and(|name| name.to_ascii_lowercase().ends_with(".ics"));
+    calendar_type || ics_name
+}
+
+fn is_calendar_text(body: &str) -> bool {
+    body.trim_start()
+        .get(..15)
+        .is_some_and(|start| start.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+}
+
+/// Recognise machine-generated email from its headers
+///
+/// Bounces are delivery status notifications (RFC 3464: a `multipart/report`
+/// with `report-type=delivery-status`, or any `message/delivery-status` part).
+/// Automatic replies carry `Auto-Submitted` with a value other than `no`
+/// (RFC 3834) or one of the non-standard `X-Autoreply`/`X-Autorespond` headers.
+fn detect_auto_generated(message: &Message) -> Option<AutoGeneratedEmailKind> {
+    let is_report = message.content_type().is_some_and(|ct| {
+        ct.ctype().eq_ignore_ascii_case("multipart")
+            && ct
+                .subtype()
+                .is_some_and(|sub| sub.eq_ignore_ascii_case("report"))
+            && ct
+                .attribute("report-type")
+                .is_some_and(|report| report.eq_ignore_ascii_case("delivery-status"))
+    });
+    let has_delivery_status = message.parts.iter().any(|part| {
+        part.content_type().is_some_and(|ct| {
+            ct.ctype().eq_ignore_ascii_case("message")
+                && ct
+                    .subtype()
+                    .is_some_and(|sub| sub.eq_ignore_ascii_case("delivery-status"))
+        })
+    });
+    if is_report || has_delivery_status {
+        return Some(AutoGeneratedEmailKind::Bounce);
+    }
+
+    let auto_submitted = message
+        .header_raw("Auto-Submitted")
+        .map(|value| value.trim().to_ascii_lowercase())
+        .is_some_and(|value| !value.is_empty() && value != "no");
+    let autoreply_header = ["X-Autoreply", "X-Autorespond"]
+        .iter()
+        .any(|name| message.header_raw(*name).is_some());
+    if auto_submitted || autoreply_header {
+        return Some(AutoGeneratedEmailKind::AutoReply);
+    }
+
+    None
+}
+
 impl Default for EmailParserService {
     fn default() -> Self {
         Self::new()
@@ -208,6 +331,77 @@ mod tests {
         assert_eq!(parser.extract_reference_number("Invalid [#abc]"), None);
     }
 
+    fn parse(raw: &str) -> ParsedEmail {
+        EmailParserService::new()
+            .parse_email(raw.replace('\n', "\r\n").as_bytes())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_detects_automatic_replies() {
+        let out_of_office = parse(
+            "From: customer@example.com\n\
+             Message-ID: <ooo-1@example.com>\n\
+             Subject: Automatic reply: Re: Billing [#123]\n\
+             Auto-Submitted: auto-replied\n\
+             \n\
+             I'm out of the office until Monday.\n",
+        );
+        assert_eq!(
+            out_of_office.auto_generated,
+            Some(AutoGeneratedEmailKind::AutoReply)
+        );
+
+        let x_autoreply = parse(
+            "From: customer@example.com\n\
+             Message-ID: <ooo-2@example.com>\n\
+             Subject: Away\n\
+             X-Autoreply: yes\n\
+             \n\
+             Away.\n",
+        );
+        assert_eq!(
+            x_autoreply.auto_generated,
+            Some(AutoGeneratedEmailKind::AutoReply)
+        );
+
+        // Explicitly marked as written by a person
+        let human = parse(
+            "From: customer@example.com\n\
+             Message-ID: <human-1@example.com>\n\
+             Subject: Re: Billing [#123]\n\
+             Auto-Submitted: no\n\
+             \n\
+             Thanks!\n",
+        );
+        assert_eq!(human.auto_generated, None);
+    }
+
+    #[test]
+    fn test_detects_bounces() {
+        let bounce = parse(
+            "From: MAILER-DAEMON@mail.example.com\n\
+             Message-ID: <dsn-1@mail.example.com>\n\
+             Subject: Undelivered Mail Returned to Sender\n\
+             Auto-Submitted: auto-replied\n\
+             MIME-Version: 1.0\n\
+             Content-Type: multipart/report; report-type=delivery-status; boundary=\"b1\"\n\
+             \n\
+             --b1\n\
+             Content-Type: text/plain\n\
+             \n\
+             Your message could not be delivered.\n\
+             --b1\n\
+             Content-Type: message/delivery-status\n\
+             \n\
+             Final-Recipient: rfc822; nobody@example.com\n\
+             Action: failed\n\
+             Status: 5.1.1\n\
+             --b1--\n",
+        );
+        assert_eq!(bounce.auto_generated, Some(AutoGeneratedEmailKind::Bounce));
+    }
+
     #[test]
     fn test_format_subject_with_reference() {
         let parser = EmailParserService::new();
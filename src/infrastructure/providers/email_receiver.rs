@@ -1,24 +1,158 @@
-use crate::application::services::AttachmentService;
+use crate::application::services::{
+    AttachmentRuleService, AttachmentService, AutoTagService, ConversationSummaryService,
+    EmailOAuthService, JunkService, SentimentService,
+};
 use crate::domain::entities::{
-    ConversationStatus, CreateConversation, EmailProcessingLog, InboxEmailConfig, Message,
+    AutoGeneratedEmailKind, Contact, Conversation, ConversationStatus, CreateConversation,
+    EmailAuthFailurePolicy, EmailAuthMethod, EmailAuthentication, EmailProcessingLog,
+    InboxEmailBacklog, InboxEmailConfig, Message, MessageAttachment, SystemNote,
 };
 /// Email Receiver Service (Feature 021)
 ///
 /// Handles receiving and processing incoming emails via IMAP.
 /// Creates conversations, messages, contacts, and attachments from emails.
 use crate::domain::ports::attachment_repository::AttachmentRepository;
+use crate::domain::ports::conversation_contact_repository::ConversationContactRepository;
 use crate::domain::ports::conversation_repository::ConversationRepository;
 use crate::domain::ports::email_repository::EmailRepository;
+use crate::domain::ports::event_bus::EventBus;
 use crate::domain::ports::message_repository::MessageRepository;
+use crate::domain::services::xoauth2_sasl_response;
 use crate::infrastructure::http::middleware::error::{ApiError, ApiResult};
 use crate::infrastructure::providers::{EmailParserService, ParsedEmail};
+use crate::shared::maintenance::MaintenanceMode;
 use async_imap::Session;
 use async_native_tls::{TlsConnector, TlsStream};
 use futures::StreamExt;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpStream;
 use tokio_util::compat::{Compat, TokioAsyncReadCompatExt};
 
+/// How often the event bus queue depth is re-checked while ingestion is paused
+const BACKPRESSURE_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Longest ingestion pauses for the event bus before carrying on regardless
+const MAX_BACKPRESSURE_WAIT: Duration = Duration::from_secs(30);
+
+/// SASL XOAUTH2 exchange for IMAP `AUTHENTICATE`
+///
+/// The first challenge is answered with the token; on failure the server
+/// sends an error challenge, which must be answered with an empty response.
+struct XOAuth2Authenticator {
+    response: Option<String>,
+}
+
+impl XOAuth2Authenticator {
+    fn new(username: &str, access_token: &str) -> Self {
+        Self {
+            response: Some(xoauth2_sasl_response(username, access_token)),
+        }
+    }
+}
+
+impl async_imap::Authenticator for XOAuth2Authenticator {
+    type Response = String;
+
+    fn process(&mut self, challenge: &[u8]) -> Self::Response {
+        match self.response.take() {
+            Some(response) => response,
+            None => {
+                tracing::warn!(
+                    "IMAP XOAUTH2 authentication failed: {}",
+                    String::from_utf8_lossy(challenge)
+                );
+                String::new()
+            }
+        }
+    }
+}
+
+/// Limits that keep a large mailbox backlog from flooding the event bus and automations
+#[derive(Debug, Clone)]
+pub struct EmailIngestionLimits {
+    /// Maximum number of emails fetched from IMAP per poll
+    pub batch_size: usize,
+    /// Pause between processed emails
+    pub message_delay: Duration,
+    /// Pause ingestion while more events than this are queued on the event bus
+    /// (keep below the event bus capacity)
+    pub max_pending_events: usize,
+    /// Poll interval used while an inbox still has unseen emails left
+    pub backlog_poll_interval: Duration,
+}
+
+impl Default for EmailIngestionLimits {
+    fn default() -> Self {
+        Self {
+            batch_size: 50,
+            message_delay: Duration::from_millis(100),
+            max_pending_events: 50,
+            backlog_poll_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+impl EmailIngestionLimits {
+    /// Load limits from EMAIL_POLL_* environment variables, falling back to defaults
+    pub fn from_env() -> Self {
+        fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default)
+        }
+
+        let defaults = Self::default();
+        Self {
+            batch_size: env_or("EMAIL_POLL_BATCH_SIZE", defaults.batch_size).max(1),
+            message_delay: Duration::from_millis(env_or(
+                "EMAIL_POLL_MESSAGE_DELAY_MS",
+                defaults.message_delay.as_millis() as u64,
+            )),
+            max_pending_events: env_or(
+                "EMAIL_POLL_MAX_PENDING_EVENTS",
+                defaults.max_pending_events,
+            ),
+            backlog_poll_interval: Duration::from_secs(env_or(
+                "EMAIL_POLL_BACKLOG_INTERVAL_SECS",
+                defaults.backlog_poll_interval.as_secs(),
+            )),
+        }
+    }
+}
+
+/// Pick the oldest `batch_size` UIDs to fetch and report how many are left over
+pub fn select_email_batch(mut uids: Vec<u32>, batch_size: usize) -> (Vec<u32>, usize) {
+    uids.sort_unstable();
+    let remaining = uids.len().saturating_sub(batch_size);
+    uids.truncate(batch_size);
+    (uids, remaining)
+}
+
+/// Build the message for a contact's email, authored by the contact's user
+///
+/// A meeting invite is kept on the message; when the email has no body of
+/// its own, the invite summary becomes the message content.
+fn incoming_message(conversation_id: &str, author_id: &str, parsed_email: &ParsedEmail) -> Message {
+    let content = parsed_email
+        .text_body
+        .clone()
+        .or_else(|| parsed_email.html_body.clone())
+        .filter(|body| !body.trim().is_empty())
+        .or_else(|| {
+            parsed_email
+                .calendar_invite
+                .as_ref()
+                .map(|invite| invite.summary.clone())
+        })
+        .unwrap_or_default();
+    let mut message =
+        Message::new_incoming(conversation_id.to_string(), content, author_id.to_string());
+    message.calendar_invite = parsed_email.calendar_invite.clone();
+    message
+}
+
 /// Email receiver service
 pub struct EmailReceiverService {
     email_repo: Arc<dyn EmailRepository>,
@@ -27,6 +161,15 @@ pub struct EmailReceiverService {
     contact_service: crate::application::services::ContactService,
     parser: EmailParserService,
     attachment_service: AttachmentService,
+    auto_tag_service: Option<AutoTagService>,
+    attachment_rule_service: Option<AttachmentRuleService>,
+    sentiment_service: Option<SentimentService>,
+    summary_service: Option<ConversationSummaryService>,
+    junk_service: Option<JunkService>,
+    limits: EmailIngestionLimits,
+    event_bus: Option<Arc<dyn EventBus>>,
+    oauth_service: Option<EmailOAuthService>,
+    conversation_contact_repo: Option<Arc<dyn ConversationContactRepository>>,
 }
 
 impl EmailReceiverService {
@@ -45,6 +188,227 @@ impl EmailReceiverService {
             contact_service,
             parser: EmailParserService::new(),
             attachment_service,
+            auto_tag_service: None,
+            attachment_rule_service: None,
+            sentiment_service: None,
+            summary_service: None,
+            junk_service: None,
+            limits: EmailIngestionLimits::default(),
+            event_bus: None,
+            oauth_service: None,
+            conversation_contact_repo: None,
+        }
+    }
+
+    /// Apply inbox keyword auto-tag rules to messages created by this receiver
+    pub fn set_auto_tag_service(&mut self, auto_tag_service: AutoTagService) {
+        self.auto_tag_service = Some(auto_tag_service);
+    }
+
+    /// Tag and route conversations by the type of attachments received
+    pub fn set_attachment_rule_service(&mut self, attachment_rule_service: AttachmentRuleService) {
+        self.attachment_rule_service = Some(attachment_rule_service);
+    }
+
+    /// Score sentiment of messages created by this receiver
+    pub fn set_sentiment_service(&mut self, sentiment_service: SentimentService) {
+        self.sentiment_service = Some(sentiment_service);
+    }
+
+    /// Refresh the summary of conversations receiving emails
+    pub fn set_summary_service(&mut self, summary_service: ConversationSummaryService) {
+        self.summary_service = Some(summary_service);
+    }
+
+    /// Drop emails from blocked senders and junk new conversations from
+    /// senders with a bad reputation
+    pub fn set_junk_service(&mut self, junk_service: JunkService) {
+        self.junk_service = Some(junk_service);
+    }
+
+    /// Override the default batch size and pacing
+    pub fn set_ingestion_limits(&mut self, limits: EmailIngestionLimits) {
+        self.limits = limits;
+    }
+
+    /// Pause ingestion while the event bus has too many undelivered events
+    pub fn set_event_bus(&mut self, event_bus: Arc<dyn EventBus>) {
+        self.event_bus = Some(event_bus);
+    }
+
+    /// Sign in to mailboxes set up for OAuth2 with XOAUTH2
+    pub fn set_oauth_service(&mut self, oauth_service: EmailOAuthService) {
+        self.oauth_service = Some(oauth_service);
+    }
+
+    /// Add other contacts who reply on a thread to its conversation
+    pub fn set_conversation_contact_repo(
+        &mut self,
+        conversation_contact_repo: Arc<dyn ConversationContactRepository>,
+    ) {
+        self.conversation_contact_repo = Some(conversation_contact_repo);
+    }
+
+    /// Wait until event listeners have caught up (bounded by MAX_BACKPRESSURE_WAIT)
+    async fn wait_for_event_bus(&self) {
+        let Some(ref event_bus) = self.event_bus else {
+            return;
+        };
+
+        let mut waited = Duration::ZERO;
+        while event_bus.pending_events() > self.limits.max_pending_events {
+            if waited >= MAX_BACKPRESSURE_WAIT {
+                tracing::warn!(
+                    "Event bus still has {} pending events after {:?}, resuming email ingestion",
+                    event_bus.pending_events(),
+                    waited
+                );
+                return;
+            }
+            tokio::time::sleep(BACKPRESSURE_CHECK_INTERVAL).await;
+            waited += BACKPRESSURE_CHECK_INTERVAL;
+        }
+    }
+
+    /// Run auto-tag rules against a newly received email (best effort)
+    async fn apply_auto_tags(
+        &self,
+        conversation_id: &str,
+        inbox_id: &str,
+        parsed_email: &ParsedEmail,
+        content: &str,
+    ) {
+        if let Some(ref auto_tag_service) = self.auto_tag_service {
+            if let Err(e) = auto_tag_service
+                .apply_to_message(
+                    conversation_id,
+                    inbox_id,
+                    parsed_email.subject.as_deref(),
+                    content,
+                )
+                .await
+            {
+                tracing::warn!(
+                    "Failed to apply auto-tag rules to conversation {}: {}",
+                    conversation_id,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Run attachment type rules against the attachments of a new email (best effort)
+    async fn apply_attachment_rules(
+        &self,
+        conversation_id: &str,
+        inbox_id: &str,
+        attachments: &[MessageAttachment],
+    ) {
+        if let Some(ref attachment_rule_service) = self.attachment_rule_service {
+            if let Err(e) = attachment_rule_service
+                .apply_to_attachments(conversation_id, inbox_id, attachments)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to apply attachment rules to conversation {}: {}",
+                    conversation_id,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Record the sender and CC'd addresses on the conversation's thread (best effort)
+    ///
+    /// The inbox's own address is left out so replies aren't CC'd back to it.
+    /// A reply from someone other than the conversation's contact (e.g. a
+    /// colleague who was CC'd) makes them a participant of the conversation
+    async fn record_contact_participant(&self, conversation: &Conversation, contact_id: &str) {
+        let Some(ref repo) = self.conversation_contact_repo else {
+            return;
+        };
+        if conversation.contact_id == contact_id {
+            return;
+        }
+
+        match repo
+            .add_conversation_contact(&conversation.id, contact_id, None)
+            .await
+        {
+            Ok(true) => tracing::info!(
+                "Contact {} joined conversation {} by replying",
+                contact_id,
+                conversation.id
+            ),
+            Ok(false) => {}
+            Err(e) => tracing::warn!(
+                "Failed to add contact {} to conversation {}: {}",
+                contact_id,
+                conversation.id,
+                e
+            ),
+        }
+    }
+
+    async fn record_email_participants(
+        &self,
+        conversation_id: &str,
+        inbox_id: &str,
+        parsed_email: &ParsedEmail,
+    ) {
+        let inbox_address = match self.email_repo.get_inbox_email_config(inbox_id).await {
+            Ok(config) => config.map(|config| config.email_address),
+            Err(e) => {
+                tracing::warn!("Failed to load email config for inbox {}: {}", inbox_id, e);
+                None
+            }
+        };
+
+        let addresses = std::iter::once(&parsed_email.from_address)
+            .chain(parsed_email.cc_addresses.iter())
+            .filter(|address| {
+                inbox_address
+                    .as_deref()
+                    .is_none_or(|inbox| !inbox.eq_ignore_ascii_case(address))
+            });
+        for address in addresses {
+            if let Err(e) = self
+                .email_repo
+                .add_email_participant(conversation_id, address)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to record email participant on conversation {}: {}",
+                    conversation_id,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Score sentiment of a newly received email (best effort)
+    async fn record_sentiment(&self, message: &Message) {
+        if let Some(ref sentiment_service) = self.sentiment_service {
+            if let Err(e) = sentiment_service.record_incoming_message(message).await {
+                tracing::warn!(
+                    "Failed to record sentiment for message {}: {}",
+                    message.id,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Refresh the summary of a conversation that received an email (best effort)
+    async fn refresh_summary(&self, conversation_id: &str) {
+        if let Some(ref summary_service) = self.summary_service {
+            if let Err(e) = summary_service.refresh(conversation_id).await {
+                tracing::warn!(
+                    "Failed to refresh summary of conversation {}: {}",
+                    conversation_id,
+                    e
+                );
+            }
         }
     }
 
@@ -81,22 +445,39 @@ impl EmailReceiverService {
         let client = async_imap::Client::new(tls_stream);
 
         // Login
-        let session = client
-            .login(&config.imap_username, &config.imap_password)
-            .await
-            .map_err(|e| {
-                ApiError::Internal(format!("Failed to authenticate with IMAP server: {:?}", e))
-            })?;
+        let session = match config.auth_method {
+            EmailAuthMethod::Password => client
+                .login(&config.imap_username, &config.imap_password)
+                .await
+                .map_err(|(e, _)| e),
+            EmailAuthMethod::OAuth2 => {
+                let oauth_service = self.oauth_service.as_ref().ok_or_else(|| {
+                    ApiError::Internal("OAuth2 mailbox sign-in is not available".to_string())
+                })?;
+                let access_token = oauth_service.access_token(config).await?;
+                client
+                    .authenticate(
+                        "XOAUTH2",
+                        XOAuth2Authenticator::new(&config.imap_username, &access_token),
+                    )
+                    .await
+                    .map_err(|(e, _)| e)
+            }
+        }
+        .map_err(|e| {
+            ApiError::Internal(format!("Failed to authenticate with IMAP server: {:?}", e))
+        })?;
 
         Ok(session)
     }
 
-    /// Fetch new (UNSEEN) emails from inbox
+    /// Fetch the next batch of new (UNSEEN) emails from inbox
+    /// Returns the fetched emails and the number of unseen emails left for later polls
     async fn fetch_new_emails(
         &self,
         session: &mut Session<TlsStream<Compat<TcpStream>>>,
         folder: &str,
-    ) -> ApiResult<Vec<(u32, Vec<u8>)>> {
+    ) -> ApiResult<(Vec<(u32, Vec<u8>)>, usize)> {
         // Select mailbox
         session
             .select(folder)
@@ -109,12 +490,16 @@ impl EmailReceiverService {
         })?;
 
         if unseen_uids.is_empty() {
-            return Ok(Vec::new());
+            return Ok((Vec::new(), 0));
         }
 
+        // Only take the oldest batch; the rest is left for later polls
+        let (batch, remaining) =
+            select_email_batch(unseen_uids.into_iter().collect(), self.limits.batch_size);
+
         // Fetch email bodies
         let mut emails = Vec::new();
-        for uid in unseen_uids {
+        for uid in batch {
             // Fetch full RFC822 message
             let mut messages = session
                 .uid_fetch(uid.to_string(), "RFC822")
@@ -136,7 +521,7 @@ impl EmailReceiverService {
             }
         }
 
-        Ok(emails)
+        Ok((emails, remaining))
     }
 
     /// Process a new incoming email and create conversation
@@ -147,7 +532,7 @@ impl EmailReceiverService {
         parsed_email: &ParsedEmail,
     ) -> ApiResult<(String, String)> {
         // Get or create contact from email sender
-        let contact_id = self
+        let contact = self
             .get_or_create_contact(
                 inbox_id,
                 &parsed_email.from_address,
@@ -158,7 +543,7 @@ impl EmailReceiverService {
         // Create conversation
         let create_conv = CreateConversation {
             inbox_id: inbox_id.to_string(),
-            contact_id: contact_id.clone(),
+            contact_id: contact.id.clone(),
             subject: parsed_email.subject.clone(),
         };
         let conversation = self
@@ -167,18 +552,24 @@ impl EmailReceiverService {
             .await?;
 
         // Create incoming message
-        let content = parsed_email
-            .text_body
-            .clone()
-            .or_else(|| parsed_email.html_body.clone())
-            .unwrap_or_default();
-        let message = Message::new_incoming(conversation.id.clone(), content, contact_id.clone());
+        let (authentication, quarantine) = self.verify_sender(inbox_id, parsed_email).await;
+        let mut message = incoming_message(&conversation.id, &contact.user_id, parsed_email);
+        message.email_authentication = authentication;
         let message_id = message.id.clone();
         self.message_repo.create_message(&message).await?;
 
+        self.apply_auto_tags(&conversation.id, inbox_id, parsed_email, &message.content)
+            .await;
+        self.record_sentiment(&message).await;
+        self.refresh_summary(&conversation.id).await;
+        self.record_email_participants(&conversation.id, inbox_id, parsed_email)
+            .await;
+
         // Store attachments
+        let mut attachments = Vec::with_capacity(parsed_email.attachments.len());
         for attachment in &parsed_email.attachments {
-            self.attachment_service
+            let saved = self
+                .attachment_service
                 .save_attachment(
                     message_id.clone(),
                     attachment.filename.clone(),
@@ -186,6 +577,16 @@ impl EmailReceiverService {
                     attachment.content.clone(),
                 )
                 .await?;
+            attachments.push(saved);
+        }
+        self.apply_attachment_rules(&conversation.id, inbox_id, &attachments)
+            .await;
+
+        if quarantine {
+            self.quarantine(&conversation).await;
+        } else {
+            self.apply_sender_reputation(&conversation, &parsed_email.from_address)
+                .await;
         }
 
         Ok((conversation.id, message_id))
@@ -197,28 +598,33 @@ impl EmailReceiverService {
         inbox_id: &str,
         email_address: &str,
         name: Option<&str>,
-    ) -> ApiResult<String> {
+    ) -> ApiResult<Contact> {
         // Try to find existing contact by email
         if let Some(contact) = self
             .contact_service
             .get_contact_by_email(email_address)
             .await?
         {
-            return Ok(contact.id);
+            return Ok(contact);
         }
 
         // Create new contact using the service method
-        let contact_id = self
-            .contact_service
+        self.contact_service
             .create_contact_from_message(email_address, name, inbox_id)
             .await?;
 
-        Ok(contact_id)
+        self.contact_service
+            .get_contact_by_email(email_address)
+            .await?
+            .ok_or_else(|| {
+                ApiError::Internal(format!("Contact for {} was not created", email_address))
+            })
     }
 
-    /// Process inbox - fetch and process all new emails
+    /// Process inbox - fetch and process the next batch of new emails
+    /// Returns the inbox's backlog progress after the batch
     #[tracing::instrument(skip(self))]
-    pub async fn process_inbox(&self, inbox_id: &str) -> ApiResult<u32> {
+    pub async fn process_inbox(&self, inbox_id: &str) -> ApiResult<InboxEmailBacklog> {
         // Get inbox email configuration
         let config = self
             .email_repo
@@ -226,26 +632,45 @@ impl EmailReceiverService {
             .await?
             .ok_or_else(|| ApiError::NotFound("Inbox email configuration not found".to_string()))?;
 
+        let mut backlog = self
+            .email_repo
+            .get_email_backlog(inbox_id)
+            .await?
+            .unwrap_or_else(|| InboxEmailBacklog::new(inbox_id.to_string()));
+
         if !config.enabled {
-            return Ok(0);
+            return Ok(backlog);
         }
 
         // Connect to IMAP
         let mut session = self.connect_imap(&config).await?;
 
-        // Fetch new emails
-        let emails = self
+        // Fetch the next batch of new emails
+        let (emails, remaining) = self
             .fetch_new_emails(&mut session, &config.imap_folder)
             .await?;
 
-        let mut processed_count = 0;
+        backlog.messages_fetched = emails.len() as i64;
+        backlog.messages_processed = 0;
+        backlog.messages_failed = 0;
+        backlog.messages_remaining = remaining as i64;
+
+        for (index, (uid, raw_email)) in emails.into_iter().enumerate() {
+            if index > 0 && !self.limits.message_delay.is_zero() {
+                tokio::time::sleep(self.limits.message_delay).await;
+            }
+            self.wait_for_event_bus().await;
 
-        for (uid, raw_email) in emails {
             // Parse email
             let parsed_email = match self.parser.parse_email(&raw_email) {
                 Ok(email) => email,
                 Err(e) => {
                     tracing::warn!("Failed to parse email UID {}: {:?}", uid, e);
+                    backlog.messages_failed += 1;
+                    // Mark as SEEN so an unparseable email can't hold up later batches
+                    let _ = session
+                        .uid_store(format!("{}", uid), "+FLAGS (\\Seen)")
+                        .await;
                     continue;
                 }
             };
@@ -260,6 +685,10 @@ impl EmailReceiverService {
                     "Email {} already processed, skipping",
                     parsed_email.message_id
                 );
+                // Mark as SEEN so it isn't fetched again in the next batch
+                let _ = session
+                    .uid_store(format!("{}", uid), "+FLAGS (\\Seen)")
+                    .await;
                 continue;
             }
 
@@ -271,16 +700,20 @@ impl EmailReceiverService {
                 parsed_email.subject.clone(),
             );
 
-            let log = match self.process_reply_email(inbox_id, uid, &parsed_email).await {
-                Ok((conversation_id, message_id)) => {
-                    processed_count += 1;
+            let result = self
+                .ingest_email(inbox_id, uid, &parsed_email, log.clone())
+                .await;
+
+            let log = match result {
+                Ok(log) => {
+                    backlog.messages_processed += 1;
 
                     // Mark as SEEN
                     let _ = session
                         .uid_store(format!("{}", uid), "+FLAGS (\\Seen)")
                         .await;
 
-                    log.mark_success(conversation_id, message_id)
+                    log
                 }
                 Err(e) => {
                     tracing::error!(
@@ -288,6 +721,7 @@ impl EmailReceiverService {
                         parsed_email.message_id,
                         e
                     );
+                    backlog.messages_failed += 1;
                     log.mark_failed(e.to_string())
                 }
             };
@@ -299,10 +733,191 @@ impl EmailReceiverService {
         // Logout from IMAP
         let _ = session.logout().await;
 
-        // Update last poll time
+        // Record backlog progress and update last poll time
+        backlog.total_processed += backlog.messages_processed;
+        backlog.updated_at = chrono::Utc::now().to_rfc3339();
+        self.email_repo.save_email_backlog(&backlog).await?;
         self.email_repo.update_last_poll_time(inbox_id).await?;
 
-        Ok(processed_count)
+        Ok(backlog)
+    }
+
+    /// Turn one parsed email into a note, a message or a new conversation
+    ///
+    /// Emails from senders on the inbox blocklist are dropped.
+    pub async fn ingest_email(
+        &self,
+        inbox_id: &str,
+        email_uid: u32,
+        parsed_email: &ParsedEmail,
+        log: EmailProcessingLog,
+    ) -> ApiResult<EmailProcessingLog> {
+        if self
+            .is_sender_blocked(inbox_id, &parsed_email.from_address)
+            .await?
+        {
+            tracing::info!(
+                "Dropping email {} from blocked sender {}",
+                parsed_email.message_id,
+                parsed_email.from_address
+            );
+            return Ok(log.mark_blocked());
+        }
+
+        match parsed_email.auto_generated {
+            Some(kind) => self
+                .record_auto_generated_email(kind, parsed_email)
+                .await
+                .map(|note| log.mark_auto_generated(note.map(|n| n.conversation_id))),
+            None => self
+                .process_reply_email(inbox_id, email_uid, parsed_email)
+                .await
+                .map(|(conversation_id, message_id)| log.mark_success(conversation_id, message_id)),
+        }
+    }
+
+    async fn is_sender_blocked(&self, inbox_id: &str, email: &str) -> ApiResult<bool> {
+        match self.junk_service {
+            Some(ref junk_service) => Ok(junk_service.is_sender_blocked(inbox_id, email).await?),
+            None => Ok(false),
+        }
+    }
+
+    /// Sender authentication results of an email, flagged when it failed
+    /// and the inbox flags or quarantines such email; also returns whether
+    /// the email is to be quarantined
+    async fn verify_sender(
+        &self,
+        inbox_id: &str,
+        parsed_email: &ParsedEmail,
+    ) -> (Option<EmailAuthentication>, bool) {
+        let Some(mut authentication) = parsed_email.authentication.clone() else {
+            return (None, false);
+        };
+        if !authentication.is_failure() {
+            return (Some(authentication), false);
+        }
+
+        let policy = match self.email_repo.get_inbox_email_config(inbox_id).await {
+            Ok(config) => config.map(|config| config.auth_failure_policy),
+            Err(e) => {
+                tracing::warn!("Failed to load email config for inbox {}: {}", inbox_id, e);
+                None
+            }
+        }
+        .unwrap_or_default();
+        tracing::info!(
+            "Email {} from {} failed sender authentication (spf={:?}, dkim={:?}, dmarc={:?}); inbox policy {}",
+            parsed_email.message_id,
+            parsed_email.from_address,
+            authentication.spf,
+            authentication.dkim,
+            authentication.dmarc,
+            policy
+        );
+
+        authentication.flagged = policy != EmailAuthFailurePolicy::Accept;
+        (
+            Some(authentication),
+            policy == EmailAuthFailurePolicy::Quarantine,
+        )
+    }
+
+    /// Junk a new conversation started by an email that failed sender
+    /// authentication (best effort)
+    async fn quarantine(&self, conversation: &Conversation) {
+        let Some(ref junk_service) = self.junk_service else {
+            tracing::warn!(
+                "Cannot quarantine conversation {}: junk handling is not configured",
+                conversation.id
+            );
+            return;
+        };
+        if let Err(e) = junk_service.quarantine(conversation).await {
+            tracing::warn!(
+                "Failed to quarantine conversation {}: {}",
+                conversation.id,
+                e
+            );
+        }
+    }
+
+    /// Junk a new conversation whose sender has a bad reputation (best effort)
+    async fn apply_sender_reputation(&self, conversation: &Conversation, email: &str) {
+        if let Some(ref junk_service) = self.junk_service {
+            if let Err(e) = junk_service
+                .apply_sender_reputation(conversation, email)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to apply sender reputation to conversation {}: {}",
+                    conversation.id,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Record an out-of-office reply or bounce as a system note
+    ///
+    /// The email is matched to a conversation by the reference number in its
+    /// subject or, for bounces that quote the original message, in its body.
+    /// Unlike a contact message it doesn't reopen the conversation, update
+    /// its last message, run auto-tagging or sentiment scoring, or publish an
+    /// event, so SLA clocks and automations are left alone. Emails that match
+    /// no conversation are dropped rather than starting a new one.
+    pub async fn record_auto_generated_email(
+        &self,
+        kind: AutoGeneratedEmailKind,
+        parsed_email: &ParsedEmail,
+    ) -> ApiResult<Option<SystemNote>> {
+        let content = parsed_email
+            .text_body
+            .clone()
+            .or_else(|| parsed_email.html_body.clone())
+            .unwrap_or_default();
+
+        let ref_number = parsed_email
+            .subject
+            .as_deref()
+            .and_then(|s| self.parser.extract_reference_number(s))
+            .or_else(|| self.parser.extract_reference_number(&content));
+        let conversation = match ref_number {
+            Some(ref_number) => {
+                self.conversation_repo
+                    .get_conversation_by_reference_number(ref_number as i64)
+                    .await?
+            }
+            None => None,
+        };
+        let Some(conversation) = conversation else {
+            tracing::info!(
+                "Ignoring {} email {} from {}: no matching conversation",
+                kind,
+                parsed_email.message_id,
+                parsed_email.from_address
+            );
+            return Ok(None);
+        };
+
+        let note = SystemNote::new(
+            conversation.id,
+            kind,
+            parsed_email.from_address.clone(),
+            parsed_email.subject.clone(),
+            &content,
+            parsed_email.message_id.clone(),
+        );
+        self.message_repo.create_system_note(&note).await?;
+
+        tracing::info!(
+            "Recorded {} email {} as a system note on conversation {}",
+            kind,
+            parsed_email.message_id,
+            note.conversation_id
+        );
+
+        Ok(Some(note))
     }
 
     /// Process email reply (with reference number matching)
@@ -332,7 +947,7 @@ impl EmailReceiverService {
                 );
 
                 // Get or create contact
-                let contact_id = self
+                let contact = self
                     .get_or_create_contact(
                         inbox_id,
                         &parsed_email.from_address,
@@ -341,19 +956,27 @@ impl EmailReceiverService {
                     .await?;
 
                 // Create incoming message on existing conversation
-                let content = parsed_email
-                    .text_body
-                    .clone()
-                    .or_else(|| parsed_email.html_body.clone())
-                    .unwrap_or_default();
-                let message =
-                    Message::new_incoming(conversation.id.clone(), content, contact_id.clone());
+                let (authentication, quarantine) = self.verify_sender(inbox_id, parsed_email).await;
+                let mut message =
+                    incoming_message(&conversation.id, &contact.user_id, parsed_email);
+                message.email_authentication = authentication;
                 let message_id = message.id.clone();
                 self.message_repo.create_message(&message).await?;
 
+                self.apply_auto_tags(&conversation.id, inbox_id, parsed_email, &message.content)
+                    .await;
+                self.record_sentiment(&message).await;
+                self.refresh_summary(&conversation.id).await;
+                self.record_email_participants(&conversation.id, inbox_id, parsed_email)
+                    .await;
+                self.record_contact_participant(&conversation, &contact.id)
+                    .await;
+
                 // Store attachments
+                let mut attachments = Vec::with_capacity(parsed_email.attachments.len());
                 for attachment in &parsed_email.attachments {
-                    self.attachment_service
+                    let saved = self
+                        .attachment_service
                         .save_attachment(
                             message_id.clone(),
                             attachment.filename.clone(),
@@ -361,10 +984,17 @@ impl EmailReceiverService {
                             attachment.content.clone(),
                         )
                         .await?;
+                    attachments.push(saved);
                 }
+                self.apply_attachment_rules(&conversation.id, inbox_id, &attachments)
+                    .await;
 
-                // Reopen conversation if it was closed
-                if conversation.status != ConversationStatus::Open {
+                // Reopen conversation if it was closed; junk stays junk, and a
+                // quarantined reply doesn't reopen anything
+                if conversation.status != ConversationStatus::Open
+                    && conversation.status != ConversationStatus::Junk
+                    && !quarantine
+                {
                     self.conversation_repo
                         .update_conversation_status(&conversation.id, ConversationStatus::Open)
                         .await?;
@@ -400,6 +1030,16 @@ where
     file_storage: Arc<dyn crate::domain::ports::file_storage::FileStorage>,
     distributed_lock: Arc<dyn crate::domain::ports::distributed_lock::DistributedLock>,
     time_service: Arc<dyn TimeService>,
+    auto_tag_service: Option<AutoTagService>,
+    attachment_rule_service: Option<AttachmentRuleService>,
+    sentiment_service: Option<SentimentService>,
+    summary_service: Option<ConversationSummaryService>,
+    junk_service: Option<JunkService>,
+    limits: EmailIngestionLimits,
+    event_bus: Option<Arc<dyn EventBus>>,
+    maintenance_mode: Option<MaintenanceMode>,
+    oauth_service: Option<EmailOAuthService>,
+    conversation_contact_repo: Option<Arc<dyn ConversationContactRepository>>,
 }
 
 impl<F> EmailPollingWorker<F>
@@ -425,13 +1065,90 @@ where
             file_storage,
             distributed_lock,
             time_service,
+            auto_tag_service: None,
+            attachment_rule_service: None,
+            sentiment_service: None,
+            summary_service: None,
+            junk_service: None,
+            limits: EmailIngestionLimits::default(),
+            event_bus: None,
+            maintenance_mode: None,
+            oauth_service: None,
+            conversation_contact_repo: None,
         }
     }
 
+    /// Apply inbox keyword auto-tag rules to polled emails
+    pub fn set_auto_tag_service(&mut self, auto_tag_service: AutoTagService) {
+        self.auto_tag_service = Some(auto_tag_service);
+    }
+
+    /// Tag and route conversations by the type of polled attachments
+    pub fn set_attachment_rule_service(&mut self, attachment_rule_service: AttachmentRuleService) {
+        self.attachment_rule_service = Some(attachment_rule_service);
+    }
+
+    /// Score sentiment of polled emails
+    pub fn set_sentiment_service(&mut self, sentiment_service: SentimentService) {
+        self.sentiment_service = Some(sentiment_service);
+    }
+
+    /// Refresh the summary of conversations receiving polled emails
+    pub fn set_summary_service(&mut self, summary_service: ConversationSummaryService) {
+        self.summary_service = Some(summary_service);
+    }
+
+    /// Drop emails from blocked senders and auto-junk by sender reputation
+    pub fn set_junk_service(&mut self, junk_service: JunkService) {
+        self.junk_service = Some(junk_service);
+    }
+
+    /// Override the default batch size and pacing
+    pub fn set_ingestion_limits(&mut self, limits: EmailIngestionLimits) {
+        self.limits = limits;
+    }
+
+    /// Pause ingestion while the event bus has too many undelivered events
+    pub fn set_event_bus(&mut self, event_bus: Arc<dyn EventBus>) {
+        self.event_bus = Some(event_bus);
+    }
+
+    /// Stop polling while maintenance mode is on; a poll in progress finishes
+    pub fn set_maintenance_mode(&mut self, maintenance_mode: MaintenanceMode) {
+        self.maintenance_mode = Some(maintenance_mode);
+    }
+
+    /// Sign in to mailboxes set up for OAuth2 with XOAUTH2
+    pub fn set_oauth_service(&mut self, oauth_service: EmailOAuthService) {
+        self.oauth_service = Some(oauth_service);
+    }
+
+    /// Add other contacts who reply on a thread to its conversation
+    pub fn set_conversation_contact_repo(
+        &mut self,
+        conversation_contact_repo: Arc<dyn ConversationContactRepository>,
+    ) {
+        self.conversation_contact_repo = Some(conversation_contact_repo);
+    }
+
     pub async fn run(&self) {
         tracing::info!("Email polling worker started");
 
         loop {
+            if self
+                .maintenance_mode
+                .as_ref()
+                .is_some_and(|mode| mode.is_enabled())
+            {
+                // Paused for maintenance; unseen emails stay on the server
+                self.time_service.sleep(Duration::from_secs(5)).await;
+                continue;
+            }
+            let active_job = self.maintenance_mode.as_ref().map(|mode| mode.begin_job());
+
+            // Set when any inbox still has unseen emails after its batch
+            let mut has_backlog = false;
+
             // Get all enabled email configurations
             match self.email_repo.get_enabled_email_configs().await {
                 Ok(configs) => {
@@ -446,7 +1163,7 @@ where
 
                     for config in configs {
                         let contact_service = (self.contact_service_factory)();
-                        let receiver = EmailReceiverService::new(
+                        let mut receiver = EmailReceiverService::new(
                             self.email_repo.clone(),
                             self.conversation_repo.clone(),
                             self.message_repo.clone(),
@@ -456,6 +1173,33 @@ where
                                 self.file_storage.clone(),
                             ),
                         );
+                        receiver.set_ingestion_limits(self.limits.clone());
+                        if let Some(ref auto_tag_service) = self.auto_tag_service {
+                            receiver.set_auto_tag_service(auto_tag_service.clone());
+                        }
+                        if let Some(ref attachment_rule_service) = self.attachment_rule_service {
+                            receiver.set_attachment_rule_service(attachment_rule_service.clone());
+                        }
+                        if let Some(ref sentiment_service) = self.sentiment_service {
+                            receiver.set_sentiment_service(sentiment_service.clone());
+                        }
+                        if let Some(ref summary_service) = self.summary_service {
+                            receiver.set_summary_service(summary_service.clone());
+                        }
+                        if let Some(ref junk_service) = self.junk_service {
+                            receiver.set_junk_service(junk_service.clone());
+                        }
+                        if let Some(ref event_bus) = self.event_bus {
+                            receiver.set_event_bus(event_bus.clone());
+                        }
+                        if let Some(ref oauth_service) = self.oauth_service {
+                            receiver.set_oauth_service(oauth_service.clone());
+                        }
+                        if let Some(ref conversation_contact_repo) = self.conversation_contact_repo
+                        {
+                            receiver
+                                .set_conversation_contact_repo(conversation_contact_repo.clone());
+                        }
                         let inbox_id = config.inbox_id.clone();
                         let distributed_lock = self.distributed_lock.clone();
 
@@ -474,15 +1218,19 @@ where
                                 // 50s TTL (slightly less than 60s poll interval)
                                 Ok(true) => {
                                     // Got lock, process
+                                    let mut has_backlog = false;
                                     match receiver.process_inbox(&inbox_id).await {
-                                        Ok(count) => {
-                                            if count > 0 {
+                                        Ok(backlog) => {
+                                            if backlog.messages_fetched > 0 {
                                                 tracing::info!(
-                                                    "Processed {} emails for inbox {}",
-                                                    count,
-                                                    inbox_id
+                                                    "Processed {} of {} emails for inbox {} ({} remaining)",
+                                                    backlog.messages_processed,
+                                                    backlog.messages_fetched,
+                                                    inbox_id,
+                                                    backlog.messages_remaining
                                                 );
                                             }
+                                            has_backlog = backlog.messages_remaining > 0;
                                         }
                                         Err(e) => {
                                             tracing::error!(
@@ -503,12 +1251,15 @@ where
                                             e
                                         );
                                     }
+
+                                    has_backlog
                                 }
                                 Ok(false) => {
                                     tracing::debug!(
                                         "Could not acquire lock for inbox {}, skipping",
                                         inbox_id
                                     );
+                                    false
                                 }
                                 Err(e) => {
                                     tracing::error!(
@@ -516,23 +1267,48 @@ where
                                         inbox_id,
                                         e
                                     );
+                                    false
                                 }
                             }
                         });
                     }
 
                     // Wait for all inbox processing to complete
-                    futures::future::join_all(futures).await;
+                    has_backlog = futures::future::join_all(futures)
+                        .await
+                        .into_iter()
+                        .any(|inbox_has_backlog| inbox_has_backlog);
                 }
                 Err(e) => {
                     tracing::error!("Failed to get enabled email configs: {:?}", e);
                 }
             }
 
-            // Wait 60 seconds before next poll
-            self.time_service
-                .sleep(std::time::Duration::from_secs(60))
-                .await;
+            drop(active_job);
+
+            // Wait 60 seconds before next poll, or less while working through a backlog
+            let poll_interval = if has_backlog {
+                self.limits.backlog_poll_interval
+            } else {
+                Duration::from_secs(60)
+            };
+            self.time_service.sleep(poll_interval).await;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_email_batch_takes_oldest_uids() {
+        let (batch, remaining) = select_email_batch(vec![42, 7, 19, 3, 100], 3);
+        assert_eq!(batch, vec![3, 7, 19]);
+        assert_eq!(remaining, 2);
+
+        let (batch, remaining) = select_email_batch(vec![5, 1], 10);
+        assert_eq!(batch, vec![1, 5]);
+        assert_eq!(remaining, 0);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::domain::ports::file_downloader::{DownloadedFile, FileDownloader};
+use async_trait::async_trait;
+use std::time::Duration;
+
+/// Matches the largest attachment Oxidesk stores
+const MAX_DOWNLOAD_SIZE: u64 = 25 * 1024 * 1024;
+
+/// Downloads files over HTTP(S)
+pub struct HttpFileDownloader {
+    client: reqwest::Client,
+}
+
+impl HttpFileDownloader {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(60))
+            .build()
+            .unwrap_or_default();
+
+        Self { client }
+    }
+}
+
+impl Default for HttpFileDownloader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl FileDownloader for HttpFileDownloader {
+    async fn download(&self, url: &str) -> Result<DownloadedFile, String> {
+        // Export URLs are often signed, so keep them out of errors
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| format!("Download failed: {}", e.without_url()))?;
+        if !response.status().is_success() {
+            return Err(format!("Download returned {}", response.status()));
+        }
+        if response.content_length().unwrap_or(0) > MAX_DOWNLOAD_SIZE {
+            return Err(format!(
+                "File is larger than {} MB",
+                MAX_DOWNLOAD_SIZE / (1024 * 1024)
+            ));
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.split(';').next().unwrap_or(value).trim().to_string());
+        let content = response
+            .bytes()
+            .await
+            .map_err(|e| format!("Download failed: {}", e.without_url()))?
+            .to_vec();
+
+        Ok(DownloadedFile {
+            content,
+            content_type,
+        })
+    }
+}
@@ -1,9 +1,29 @@
+pub mod channel_delivery_provider;
+pub mod chat_widget_delivery_provider;
 pub mod connection_manager;
 pub mod email_delivery_provider;
+pub mod email_oauth_client;
 pub mod email_parser;
 pub mod email_receiver;
+pub mod http_file_downloader;
+#[cfg(feature = "redis-presence")]
+pub mod redis_connection_manager;
+pub mod smtp_pool;
+pub mod telegram_bot_client;
+pub mod telegram_delivery_provider;
+pub mod twilio_delivery_provider;
 
+pub use channel_delivery_provider::*;
+pub use chat_widget_delivery_provider::*;
 pub use connection_manager::*;
 pub use email_delivery_provider::*;
+pub use email_oauth_client::*;
 pub use email_parser::*;
 pub use email_receiver::*;
+pub use http_file_downloader::*;
+#[cfg(feature = "redis-presence")]
+pub use redis_connection_manager::*;
+pub use smtp_pool::*;
+pub use telegram_bot_client::*;
+pub use telegram_delivery_provider::*;
+pub use twilio_delivery_provider::*;
@@ -0,0 +1,371 @@
+//! Redis-backed ConnectionManager for running several instances
+//!
+//! Connections stay in memory on the instance that accepted them. Each
+//! instance records which users it holds in a per-user presence set in Redis
+//! and subscribes to its own pub/sub channel; events for a user connected
+//! elsewhere are published to the channels of the instances holding them.
+//! Presence entries carry an expiry that is refreshed by a heartbeat, so an
+//! instance that dies stops receiving events once its entries lapse.
+use async_trait::async_trait;
+use futures::StreamExt;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::sync::mpsc::Sender;
+
+use crate::infrastructure::providers::connection_manager::{
+    ConnectionManager, InMemoryConnectionManager, NotificationEvent,
+};
+
+/// Settings for cross-instance presence
+#[derive(Debug, Clone)]
+pub struct RedisPresenceConfig {
+    /// Prefix for presence keys and pub/sub channels
+    pub key_prefix: String,
+    /// How long a presence entry lives without a heartbeat
+    pub presence_ttl: Duration,
+}
+
+impl Default for RedisPresenceConfig {
+    fn default() -> Self {
+        Self {
+            key_prefix: "oxidesk".to_string(),
+            presence_ttl: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RedisPresenceConfig {
+    /// Load settings from REDIS_* environment variables, falling back to defaults
+    pub fn from_env() -> Self {
+        fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default)
+        }
+
+        let defaults = Self::default();
+        Self {
+            key_prefix: env_or("REDIS_KEY_PREFIX", defaults.key_prefix),
+            presence_ttl: Duration::from_secs(
+                env_or("REDIS_PRESENCE_TTL_SECS", defaults.presence_ttl.as_secs()).max(3),
+            ),
+        }
+    }
+
+    fn presence_key(&self, user_id: &str) -> String {
+        format!("{}:presence:{}", self.key_prefix, user_id)
+    }
+
+    fn node_channel(&self, node_id: &str) -> String {
+        format!("{}:node:{}", self.key_prefix, node_id)
+    }
+}
+
+/// An event published to another instance's channel
+#[derive(Debug, Serialize, Deserialize)]
+struct RoutedEvent {
+    user_id: String,
+    /// Carried separately because it is not part of the event payload
+    sequence: Option<i64>,
+    event: NotificationEvent,
+}
+
+/// ConnectionManager that routes events between instances through Redis
+pub struct RedisConnectionManager {
+    local: InMemoryConnectionManager,
+    redis: redis::aio::ConnectionManager,
+    config: RedisPresenceConfig,
+    node_id: String,
+}
+
+impl RedisConnectionManager {
+    /// Connect to Redis, subscribe to this instance's channel and start the
+    /// presence heartbeat
+    pub async fn connect(redis_url: &str, config: RedisPresenceConfig) -> Result<Self, String> {
+        let client =
+            redis::Client::open(redis_url).map_err(|e| format!("Invalid Redis URL: {}", e))?;
+        let redis = client
+            .get_connection_manager()
+            .await
+            .map_err(|e| format!("Failed to connect to Redis: {}", e))?;
+
+        let manager = Self {
+            local: InMemoryConnectionManager::new(),
+            redis,
+            config,
+            node_id: uuid::Uuid::new_v4().to_string(),
+        };
+
+        // Subscribe before returning so no event published to us is missed
+        let channel = manager.config.node_channel(&manager.node_id);
+        let pubsub = subscribe_channel(&client, &channel).await?;
+        tokio::spawn(Self::run_subscriber(
+            client,
+            pubsub,
+            channel,
+            manager.local.clone(),
+        ));
+        tokio::spawn(Self::run_heartbeat(
+            manager.redis.clone(),
+            manager.config.clone(),
+            manager.node_id.clone(),
+            manager.local.clone(),
+        ));
+
+        tracing::info!(
+            "Redis connection manager started (instance {})",
+            manager.node_id
+        );
+        Ok(manager)
+    }
+
+    /// Deliver events published to this instance, resubscribing after errors
+    async fn run_subscriber(
+        client: redis::Client,
+        pubsub: redis::aio::PubSub,
+        channel: String,
+        local: InMemoryConnectionManager,
+    ) {
+        let mut pubsub = Some(pubsub);
+        loop {
+            let current = match pubsub.take() {
+                Some(current) => current,
+                None => match subscribe_channel(&client, &channel).await {
+                    Ok(current) => current,
+                    Err(e) => {
+                        tracing::error!("{}; retrying", e);
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        continue;
+                    }
+                },
+            };
+
+            let mut messages = current.into_on_message();
+            while let Some(message) = messages.next().await {
+                let payload: String = match message.get_payload() {
+                    Ok(payload) => payload,
+                    Err(e) => {
+                        tracing::warn!("Ignoring unreadable routed event: {}", e);
+                        continue;
+                    }
+                };
+                let routed: RoutedEvent = match serde_json::from_str(&payload) {
+                    Ok(routed) => routed,
+                    Err(e) => {
+                        tracing::warn!("Ignoring malformed routed event: {}", e);
+                        continue;
+                    }
+                };
+
+                let mut event = routed.event;
+                event.sequence = routed.sequence;
+                if let Err(e) = local.send_to_user(&routed.user_id, event).await {
+                    tracing::debug!("Routed event not delivered: {}", e);
+                }
+            }
+
+            tracing::warn!("Redis subscription on {} closed; resubscribing", channel);
+        }
+    }
+
+    /// Refresh presence for every open local connection
+    async fn run_heartbeat(
+        mut redis: redis::aio::ConnectionManager,
+        config: RedisPresenceConfig,
+        node_id: String,
+        local: InMemoryConnectionManager,
+    ) {
+        let mut interval = tokio::time::interval(config.presence_ttl / 3);
+        loop {
+            interval.tick().await;
+            for user_id in local.retain_open_connections().await {
+                if let Err(e) = mark_present(&mut redis, &config, &node_id, &user_id).await {
+                    tracing::warn!("Failed to refresh presence for {}: {}", user_id, e);
+                }
+            }
+        }
+    }
+
+    /// Instances currently holding a connection for the user
+    async fn present_nodes(&self, user_id: &str) -> Result<Vec<String>, String> {
+        let mut redis = self.redis.clone();
+        redis
+            .zrangebyscore(self.config.presence_key(user_id), unix_now(), "+inf")
+            .await
+            .map_err(|e| format!("Failed to look up presence: {}", e))
+    }
+
+    async fn clear_presence(&self, user_id: &str) {
+        let mut redis = self.redis.clone();
+        let result: redis::RedisResult<()> = redis
+            .zrem(self.config.presence_key(user_id), &self.node_id)
+            .await;
+        if let Err(e) = result {
+            tracing::warn!("Failed to clear presence for {}: {}", user_id, e);
+        }
+    }
+}
+
+#[async_trait]
+impl ConnectionManager for RedisConnectionManager {
+    async fn add_connection(&self, user_id: &str, sender: Sender<NotificationEvent>) {
+        self.local.add_connection(user_id, sender).await;
+
+        let mut redis = self.redis.clone();
+        if let Err(e) = mark_present(&mut redis, &self.config, &self.node_id, user_id).await {
+            tracing::warn!("Failed to record presence for {}: {}", user_id, e);
+        }
+    }
+
+    async fn remove_connection(&self, user_id: &str) {
+        self.local.remove_connection(user_id).await;
+        self.clear_presence(user_id).await;
+    }
+
+    async fn send_to_user(&self, user_id: &str, event: NotificationEvent) -> Result<(), String> {
+        let mut delivered = false;
+        let mut local_error = None;
+
+        if self.local.is_connected(user_id).await {
+            match self.local.send_to_user(user_id, event.clone()).await {
+                Ok(()) => delivered = true,
+                Err(e) => {
+                    // The receiver has gone away; stop advertising it
+                    self.local.remove_connection(user_id).await;
+                    self.clear_presence(user_id).await;
+                    local_error = Some(e);
+                }
+            }
+        }
+
+        let remote_nodes: Vec<String> = self
+            .present_nodes(user_id)
+            .await?
+            .into_iter()
+            .filter(|node_id| *node_id != self.node_id)
+            .collect();
+        if !remote_nodes.is_empty() {
+            let payload = serde_json::to_string(&RoutedEvent {
+                user_id: user_id.to_string(),
+                sequence: event.sequence,
+                event,
+            })
+            .map_err(|e| format!("Failed to serialize routed event: {}", e))?;
+
+            let mut redis = self.redis.clone();
+            for node_id in remote_nodes {
+                let receivers: i64 = redis
+                    .publish(self.config.node_channel(&node_id), &payload)
+                    .await
+                    .map_err(|e| format!("Failed to route notification: {}", e))?;
+                delivered |= receivers > 0;
+            }
+        }
+
+        if delivered {
+            Ok(())
+        } else {
+            Err(local_error.unwrap_or_else(|| format!("User {} is not connected", user_id)))
+        }
+    }
+
+    async fn is_connected(&self, user_id: &str) -> bool {
+        if self.local.is_connected(user_id).await {
+            return true;
+        }
+        match self.present_nodes(user_id).await {
+            Ok(nodes) => !nodes.is_empty(),
+            Err(e) => {
+                tracing::warn!("{}", e);
+                false
+            }
+        }
+    }
+}
+
+async fn subscribe_channel(
+    client: &redis::Client,
+    channel: &str,
+) -> Result<redis::aio::PubSub, String> {
+    let mut pubsub = client
+        .get_async_pubsub()
+        .await
+        .map_err(|e| format!("Failed to open Redis subscription: {}", e))?;
+    pubsub
+        .subscribe(channel)
+        .await
+        .map_err(|e| format!("Failed to subscribe to {}: {}", channel, e))?;
+    Ok(pubsub)
+}
+
+/// Record (or refresh) that this instance holds a connection for the user
+async fn mark_present(
+    redis: &mut redis::aio::ConnectionManager,
+    config: &RedisPresenceConfig,
+    node_id: &str,
+    user_id: &str,
+) -> redis::RedisResult<()> {
+    let key = config.presence_key(user_id);
+    let ttl = config.presence_ttl.as_secs() as i64;
+    let now = unix_now();
+    redis::pipe()
+        .atomic()
+        .zrembyscore(&key, "-inf", format!("({}", now))
+        .ignore()
+        .zadd(&key, node_id, now + ttl)
+        .ignore()
+        .expire(&key, ttl)
+        .ignore()
+        .query_async(redis)
+        .await
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_are_namespaced() {
+        let config = RedisPresenceConfig {
+            key_prefix: "acme".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(config.presence_key("user1"), "acme:presence:user1");
+        assert_eq!(config.node_channel("node1"), "acme:node:node1");
+    }
+
+    #[test]
+    fn test_routed_event_keeps_sequence() {
+        let routed = RoutedEvent {
+            user_id: "user1".to_string(),
+            sequence: Some(42),
+            event: NotificationEvent {
+                id: "notif1".to_string(),
+                type_: "message_received".to_string(),
+                created_at: "2026-01-13T00:00:00Z".to_string(),
+                is_read: false,
+                conversation_id: Some("conv1".to_string()),
+                message_id: None,
+                actor_id: None,
+                sequence: Some(42),
+            },
+        };
+
+        let decoded: RoutedEvent =
+            serde_json::from_str(&serde_json::to_string(&routed).unwrap()).unwrap();
+        assert_eq!(decoded.user_id, "user1");
+        assert_eq!(decoded.sequence, Some(42));
+        assert_eq!(decoded.event.type_, "message_received");
+        // The sequence is not part of the event payload itself
+        assert_eq!(decoded.event.sequence, None);
+    }
+}
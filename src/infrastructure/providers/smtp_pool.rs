@@ -0,0 +1,403 @@
+//! SMTP connection pool for outbound email
+//!
+//! Keeps authenticated SMTP connections open per inbox so replies don't pay
+//! for a TCP/TLS handshake and login each time. Idle connections are checked
+//! with NOOP before reuse and closed once they have been idle too long.
+use lettre::{
+    address::Envelope,
+    transport::smtp::{
+        authentication::{Credentials, Mechanism},
+        client::{SmtpConnection, TlsParameters},
+        extension::ClientId,
+    },
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Limits for pooled SMTP connections
+#[derive(Debug, Clone)]
+pub struct SmtpPoolConfig {
+    /// Idle connections kept open per inbox; extra connections are closed after sending
+    pub max_idle_per_inbox: usize,
+    /// Idle connections older than this are closed instead of reused
+    pub max_idle_time: Duration,
+    /// Network timeout for connecting and for each SMTP command
+    pub timeout: Duration,
+}
+
+impl Default for SmtpPoolConfig {
+    fn default() -> Self {
+        Self {
+            max_idle_per_inbox: 4,
+            max_idle_time: Duration::from_secs(60),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl SmtpPoolConfig {
+    /// Load limits from SMTP_POOL_* environment variables, falling back to defaults
+    pub fn from_env() -> Self {
+        fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(default)
+        }
+
+        let defaults = Self::default();
+        Self {
+            max_idle_per_inbox: env_or("SMTP_POOL_MAX_IDLE_PER_INBOX", defaults.max_idle_per_inbox),
+            max_idle_time: Duration::from_secs(env_or(
+                "SMTP_POOL_MAX_IDLE_SECS",
+                defaults.max_idle_time.as_secs(),
+            )),
+            timeout: Duration::from_secs(env_or("SMTP_TIMEOUT_SECS", defaults.timeout.as_secs())),
+        }
+    }
+}
+
+/// Where and how to sign in to an inbox's SMTP server
+#[derive(Clone)]
+pub struct SmtpServer {
+    pub host: String,
+    pub port: u16,
+    /// Upgrade the connection with STARTTLS
+    pub use_tls: bool,
+    pub credentials: Option<(Credentials, Vec<Mechanism>)>,
+    /// Identifies the settings a pooled connection was opened with; connections
+    /// opened with other settings are closed instead of reused
+    pub fingerprint: String,
+}
+
+struct IdleConnection {
+    connection: SmtpConnection,
+    idle_since: Instant,
+}
+
+struct InboxConnections {
+    fingerprint: String,
+    idle: Vec<IdleConnection>,
+}
+
+/// Pooled, kept-alive SMTP connections keyed by inbox
+///
+/// Exposes `smtp_connect_duration_seconds` and `smtp_send_duration_seconds`
+/// histograms, `smtp_connections_opened_total` / `smtp_connections_reused_total`
+/// counters and the `smtp_pool_idle_connections` gauge, all labelled by inbox.
+pub struct SmtpConnectionPool {
+    config: SmtpPoolConfig,
+    inboxes: Mutex<HashMap<String, InboxConnections>>,
+}
+
+impl SmtpConnectionPool {
+    pub fn new(config: SmtpPoolConfig) -> Self {
+        Self {
+            config,
+            inboxes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Send an email over a pooled connection for the inbox
+    pub async fn send(
+        self: &Arc<Self>,
+        inbox_id: &str,
+        server: SmtpServer,
+        envelope: Envelope,
+        email: Vec<u8>,
+    ) -> Result<(), String> {
+        let pool = self.clone();
+        let inbox_id = inbox_id.to_string();
+        tokio::task::spawn_blocking(move || {
+            pool.send_blocking(&inbox_id, &server, &envelope, &email)
+        })
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+    }
+
+    /// Number of idle connections currently kept for the inbox
+    pub fn idle_connections(&self, inbox_id: &str) -> usize {
+        self.inboxes
+            .lock()
+            .unwrap()
+            .get(inbox_id)
+            .map_or(0, |inbox| inbox.idle.len())
+    }
+
+    /// Close idle connections that exceeded the idle time, across all inboxes
+    pub fn evict_idle(&self) {
+        let expired = {
+            let mut inboxes = self.inboxes.lock().unwrap();
+            let mut expired = Vec::new();
+            for (inbox_id, inbox) in inboxes.iter_mut() {
+                let (keep, old): (Vec<_>, Vec<_>) = inbox
+                    .idle
+                    .drain(..)
+                    .partition(|c| c.idle_since.elapsed() < self.config.max_idle_time);
+                inbox.idle = keep;
+                if !old.is_empty() {
+                    metrics::gauge!("smtp_pool_idle_connections", "inbox" => inbox_id.clone())
+                        .set(inbox.idle.len() as f64);
+                }
+                expired.extend(old);
+            }
+            inboxes.retain(|_, inbox| !inbox.idle.is_empty());
+            expired
+        };
+        for mut idle in expired {
+            let _ = idle.connection.quit();
+        }
+    }
+
+    fn send_blocking(
+        &self,
+        inbox_id: &str,
+        server: &SmtpServer,
+        envelope: &Envelope,
+        email: &[u8],
+    ) -> Result<(), String> {
+        self.evict_idle();
+
+        let mut connection = match self.checkout_healthy(inbox_id, &server.fingerprint) {
+            Some(connection) => {
+                metrics::counter!("smtp_connections_reused_total", "inbox" => inbox_id.to_string())
+                    .increment(1);
+                connection
+            }
+            None => {
+                let started = Instant::now();
+                let connection = self.connect(server)?;
+                metrics::histogram!("smtp_connect_duration_seconds", "inbox" => inbox_id.to_string())
+                    .record(started.elapsed().as_secs_f64());
+                metrics::counter!("smtp_connections_opened_total", "inbox" => inbox_id.to_string())
+                    .increment(1);
+                connection
+            }
+        };
+
+        let started = Instant::now();
+        let result = connection.send(envelope, email);
+        metrics::histogram!("smtp_send_duration_seconds", "inbox" => inbox_id.to_string())
+            .record(started.elapsed().as_secs_f64());
+
+        match result {
+            Ok(_) => {
+                self.checkin(inbox_id, &server.fingerprint, connection);
+                Ok(())
+            }
+            Err(e) => {
+                // The transaction state is unknown after a failure; don't reuse it
+                connection.abort();
+                Err(format!("SMTP send error: {}", e))
+            }
+        }
+    }
+
+    /// Take the most recently used idle connection that still answers NOOP
+    fn checkout_healthy(&self, inbox_id: &str, fingerprint: &str) -> Option<SmtpConnection> {
+        loop {
+            let mut idle = {
+                let mut inboxes = self.inboxes.lock().unwrap();
+                let inbox = inboxes.get_mut(inbox_id)?;
+                if inbox.fingerprint != fingerprint {
+                    // Settings changed; the stale connections are dropped (closing their sockets)
+                    inboxes.remove(inbox_id);
+                    return None;
+                }
+                let idle = inbox.idle.pop()?;
+                metrics::gauge!("smtp_pool_idle_connections", "inbox" => inbox_id.to_string())
+                    .set(inbox.idle.len() as f64);
+                idle
+            };
+
+            if idle.connection.test_connected() {
+                return Some(idle.connection);
+            }
+            tracing::debug!(
+                "Discarding dead pooled SMTP connection for inbox {}",
+                inbox_id
+            );
+            idle.connection.abort();
+        }
+    }
+
+    fn checkin(&self, inbox_id: &str, fingerprint: &str, mut connection: SmtpConnection) {
+        if connection.has_broken() {
+            return;
+        }
+        {
+            let mut inboxes = self.inboxes.lock().unwrap();
+            let inbox = inboxes
+                .entry(inbox_id.to_string())
+                .or_insert_with(|| InboxConnections {
+                    fingerprint: fingerprint.to_string(),
+                    idle: Vec::new(),
+                });
+            if inbox.fingerprint != fingerprint {
+                inbox.fingerprint = fingerprint.to_string();
+                inbox.idle.clear();
+            }
+            if inbox.idle.len() < self.config.max_idle_per_inbox {
+                inbox.idle.push(IdleConnection {
+                    connection,
+                    idle_since: Instant::now(),
+                });
+                metrics::gauge!("smtp_pool_idle_connections", "inbox" => inbox_id.to_string())
+                    .set(inbox.idle.len() as f64);
+                return;
+            }
+        }
+        let _ = connection.quit();
+    }
+
+    fn connect(&self, server: &SmtpServer) -> Result<SmtpConnection, String> {
+        let hello_name = ClientId::default();
+        let mut connection = SmtpConnection::connect(
+            (server.host.as_str(), server.port),
+            Some(self.config.timeout),
+            &hello_name,
+            None,
+            None,
+        )
+        .map_err(|e| format!("Failed to connect to SMTP server: {}", e))?;
+
+        if server.use_tls {
+            let tls_parameters = TlsParameters::new(server.host.clone())
+                .map_err(|e| format!("Failed to create SMTP transport: {}", e))?;
+            connection
+                .starttls(&tls_parameters, &hello_name)
+                .map_err(|e| format!("SMTP STARTTLS failed: {}", e))?;
+        }
+
+        if let Some((credentials, mechanisms)) = &server.credentials {
+            connection
+                .auth(mechanisms, credentials)
+                .map_err(|e| format!("SMTP authentication failed: {}", e))?;
+        }
+        Ok(connection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Minimal SMTP server accepting any mail; returns its port and a connection counter
+    fn fake_smtp_server() -> (u16, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = connections.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                counter.fetch_add(1, Ordering::SeqCst);
+                std::thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let _ = stream.write_all(b"220 localhost ESMTP\r\n");
+                    let mut in_data = false;
+                    let mut line = String::new();
+                    loop {
+                        line.clear();
+                        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                            break;
+                        }
+                        let reply: &[u8] = if in_data {
+                            if line != ".\r\n" {
+                                continue;
+                            }
+                            in_data = false;
+                            b"250 queued\r\n"
+                        } else {
+                            match line.get(..4).unwrap_or("").to_ascii_uppercase().as_str() {
+                                "EHLO" => b"250 localhost\r\n",
+                                "DATA" => {
+                                    in_data = true;
+                                    b"354 go ahead\r\n"
+                                }
+                                "QUIT" => {
+                                    let _ = stream.write_all(b"221 bye\r\n");
+                                    break;
+                                }
+                                _ => b"250 ok\r\n",
+                            }
+                        };
+                        let _ = stream.write_all(reply);
+                    }
+                });
+            }
+        });
+        (port, connections)
+    }
+
+    fn server(port: u16, fingerprint: &str) -> SmtpServer {
+        SmtpServer {
+            host: "127.0.0.1".to_string(),
+            port,
+            use_tls: false,
+            credentials: None,
+            fingerprint: fingerprint.to_string(),
+        }
+    }
+
+    fn envelope() -> Envelope {
+        Envelope::new(
+            Some("support@example.com".parse().unwrap()),
+            vec!["customer@example.com".parse().unwrap()],
+        )
+        .unwrap()
+    }
+
+    const EMAIL: &[u8] = b"Subject: Hi\r\n\r\nHello\r\n";
+
+    #[tokio::test]
+    async fn test_connections_are_reused_per_inbox() {
+        let (port, connections) = fake_smtp_server();
+        let pool = Arc::new(SmtpConnectionPool::new(SmtpPoolConfig::default()));
+
+        for _ in 0..3 {
+            pool.send("inbox-1", server(port, "a"), envelope(), EMAIL.to_vec())
+                .await
+                .unwrap();
+        }
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+        assert_eq!(pool.idle_connections("inbox-1"), 1);
+
+        pool.send("inbox-2", server(port, "a"), envelope(), EMAIL.to_vec())
+            .await
+            .unwrap();
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+
+        // Changed settings open a fresh connection
+        pool.send("inbox-1", server(port, "b"), envelope(), EMAIL.to_vec())
+            .await
+            .unwrap();
+        assert_eq!(connections.load(Ordering::SeqCst), 3);
+        assert_eq!(pool.idle_connections("inbox-1"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_idle_connections_are_evicted() {
+        let (port, connections) = fake_smtp_server();
+        let pool = Arc::new(SmtpConnectionPool::new(SmtpPoolConfig {
+            max_idle_time: Duration::ZERO,
+            ..Default::default()
+        }));
+
+        pool.send("inbox-1", server(port, "a"), envelope(), EMAIL.to_vec())
+            .await
+            .unwrap();
+        assert_eq!(pool.idle_connections("inbox-1"), 1);
+        pool.evict_idle();
+        assert_eq!(pool.idle_connections("inbox-1"), 0);
+
+        pool.send("inbox-1", server(port, "a"), envelope(), EMAIL.to_vec())
+            .await
+            .unwrap();
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    }
+}
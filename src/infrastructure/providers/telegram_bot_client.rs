@@ -0,0 +1,180 @@
+use crate::domain::entities::TelegramUser;
+use crate::domain::ports::telegram_bot_api::TelegramBotApi;
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::time::Duration;
+
+const TELEGRAM_API_BASE_URL: &str = "https://api.telegram.org";
+
+/// Envelope of every Bot API response
+#[derive(Debug, Deserialize)]
+struct BotApiResponse<T> {
+    ok: bool,
+    result: Option<T>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TelegramFileInfo {
+    file_path: Option<String>,
+}
+
+/// Telegram Bot API client over HTTPS
+pub struct TelegramBotClient {
+    api_base_url: String,
+    client: reqwest::Client,
+}
+
+impl TelegramBotClient {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            api_base_url: TELEGRAM_API_BASE_URL.to_string(),
+            client,
+        }
+    }
+
+    /// Call another Bot API server (local Bot API server, test server)
+    pub fn with_api_base_url(mut self, api_base_url: &str) -> Self {
+        self.api_base_url = api_base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    async fn call<T: DeserializeOwned>(
+        &self,
+        bot_token: &str,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<T, String> {
+        let url = format!("{}/bot{}/{}", self.api_base_url, bot_token, method);
+        let response = self
+            .client
+            .post(&url)
+            .json(&params)
+            .send()
+            .await
+            // The URL holds the bot token, so keep it out of the error
+            .map_err(|e| format!("Telegram {} request failed: {}", method, e.without_url()))?;
+
+        let status = response.status();
+        let body: BotApiResponse<T> = response
+            .json()
+            .await
+            .map_err(|_| format!("Telegram {} returned {}", method, status))?;
+        if !body.ok {
+            return Err(format!(
+                "Telegram {} failed: {}",
+                method,
+                body.description.unwrap_or_else(|| status.to_string())
+            ));
+        }
+        body.result
+            .ok_or_else(|| format!("Telegram {} returned no result", method))
+    }
+}
+
+impl Default for TelegramBotClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TelegramBotApi for TelegramBotClient {
+    async fn get_me(&self, bot_token: &str) -> Result<TelegramUser, String> {
+        self.call(bot_token, "getMe", serde_json::json!({})).await
+    }
+
+    async fn set_webhook(
+        &self,
+        bot_token: &str,
+        url: &str,
+        secret_token: &str,
+    ) -> Result<(), String> {
+        self.call::<bool>(
+            bot_token,
+            "setWebhook",
+            serde_json::json!({
+                "url": url,
+                "secret_token": secret_token,
+                "allowed_updates": ["message"],
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_webhook(&self, bot_token: &str) -> Result<(), String> {
+        self.call::<bool>(bot_token, "deleteWebhook", serde_json::json!({}))
+            .await?;
+        Ok(())
+    }
+
+    async fn send_message(&self, bot_token: &str, chat_id: &str, text: &str) -> Result<(), String> {
+        self.call::<serde_json::Value>(
+            bot_token,
+            "sendMessage",
+            serde_json::json!({ "chat_id": chat_id, "text": text }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn send_document(
+        &self,
+        bot_token: &str,
+        chat_id: &str,
+        document_url: &str,
+        caption: Option<&str>,
+    ) -> Result<(), String> {
+        self.call::<serde_json::Value>(
+            bot_token,
+            "sendDocument",
+            serde_json::json!({
+                "chat_id": chat_id,
+                "document": document_url,
+                "caption": caption,
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn download_file(&self, bot_token: &str, file_id: &str) -> Result<Vec<u8>, String> {
+        let file: TelegramFileInfo = self
+            .call(
+                bot_token,
+                "getFile",
+                serde_json::json!({ "file_id": file_id }),
+            )
+            .await?;
+        let file_path = file
+            .file_path
+            .ok_or_else(|| format!("Telegram file {} is not available", file_id))?;
+
+        let url = format!("{}/file/bot{}/{}", self.api_base_url, bot_token, file_path);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("Telegram file download failed: {}", e.without_url()))?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "Telegram file download returned {}",
+                response.status()
+            ));
+        }
+
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| format!("Telegram file download failed: {}", e.without_url()))
+    }
+}
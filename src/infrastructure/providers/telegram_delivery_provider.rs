@@ -0,0 +1,122 @@
+use crate::application::services::AttachmentService;
+use crate::domain::entities::{Message, TELEGRAM_MAX_MESSAGE_LENGTH};
+use crate::domain::ports::channel_repository::ChannelRepository;
+use crate::domain::ports::conversation_repository::ConversationRepository;
+use crate::domain::ports::telegram_bot_api::TelegramBotApi;
+use crate::domain::ports::telegram_config_repository::TelegramConfigRepository;
+use crate::domain::services::telegram::split_telegram_text;
+use crate::MessageDeliveryProvider;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Delivery provider for Telegram inboxes, sending replies through the Bot API
+///
+/// Replies longer than Telegram allows are split into several messages, and
+/// attachments are sent as documents Telegram fetches from signed download
+/// links. Inboxes without a Telegram configuration are delivered through
+/// `fallback`, so this provider can serve every inbox of the Telegram channel type.
+pub struct TelegramDeliveryProvider {
+    conversation_repo: Arc<dyn ConversationRepository>,
+    channel_repo: Arc<dyn ChannelRepository>,
+    telegram_repo: Arc<dyn TelegramConfigRepository>,
+    bot_api: Arc<dyn TelegramBotApi>,
+    fallback: Arc<dyn MessageDeliveryProvider>,
+    attachment_service: Option<AttachmentService>,
+}
+
+impl TelegramDeliveryProvider {
+    pub fn new(
+        conversation_repo: Arc<dyn ConversationRepository>,
+        channel_repo: Arc<dyn ChannelRepository>,
+        telegram_repo: Arc<dyn TelegramConfigRepository>,
+        bot_api: Arc<dyn TelegramBotApi>,
+        fallback: Arc<dyn MessageDeliveryProvider>,
+    ) -> Self {
+        Self {
+            conversation_repo,
+            channel_repo,
+            telegram_repo,
+            bot_api,
+            fallback,
+            attachment_service: None,
+        }
+    }
+
+    /// Send message attachments as documents using signed download URLs
+    pub fn with_attachment_service(mut self, attachment_service: AttachmentService) -> Self {
+        self.attachment_service = Some(attachment_service);
+        self
+    }
+}
+
+#[async_trait]
+impl MessageDeliveryProvider for TelegramDeliveryProvider {
+    async fn deliver(&self, message: &Message) -> Result<(), String> {
+        let conversation = self
+            .conversation_repo
+            .get_conversation_by_id(&message.conversation_id)
+            .await
+            .map_err(|e| format!("Failed to load conversation: {}", e))?
+            .ok_or_else(|| format!("Conversation {} not found", message.conversation_id))?;
+        let config = self
+            .telegram_repo
+            .get_inbox_telegram_config(&conversation.inbox_id)
+            .await
+            .map_err(|e| format!("Failed to load Telegram configuration: {}", e))?;
+        let Some(config) = config else {
+            return self.fallback.deliver(message).await;
+        };
+        if !config.enabled {
+            return Err(format!("Telegram is disabled for inbox {}", config.inbox_id));
+        }
+
+        let chat_id = self
+            .channel_repo
+            .find_channel_external_id(&conversation.inbox_id, &conversation.contact_id)
+            .await
+            .map_err(|e| format!("Failed to load recipient: {}", e))?
+            .ok_or_else(|| {
+                format!(
+                    "Contact {} has no Telegram chat in inbox {}",
+                    conversation.contact_id, conversation.inbox_id
+                )
+            })?;
+
+        let attachments = match &self.attachment_service {
+            Some(attachment_service) => attachment_service
+                .get_message_attachments(&message.id)
+                .await
+                .map_err(|e| format!("Failed to load attachments: {}", e))?,
+            None => Vec::new(),
+        };
+
+        let parts = split_telegram_text(&message.content, TELEGRAM_MAX_MESSAGE_LENGTH);
+        if parts.is_empty() && attachments.is_empty() {
+            return Err("Message is empty".to_string());
+        }
+        for part in &parts {
+            self.bot_api
+                .send_message(&config.bot_token, &chat_id, part)
+                .await?;
+        }
+
+        if let Some(attachment_service) = &self.attachment_service {
+            for attachment in &attachments {
+                self.bot_api
+                    .send_document(
+                        &config.bot_token,
+                        &chat_id,
+                        &attachment_service.signed_download_url(attachment),
+                        Some(&attachment.filename),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "telegram"
+    }
+}
@@ -0,0 +1,149 @@
+use crate::domain::entities::Message;
+use crate::domain::ports::channel_repository::ChannelRepository;
+use crate::domain::ports::conversation_repository::ConversationRepository;
+use crate::domain::ports::sms_config_repository::SmsConfigRepository;
+use crate::domain::services::sms::split_sms;
+use crate::MessageDeliveryProvider;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+
+const TWILIO_API_BASE_URL: &str = "https://api.twilio.com";
+
+/// Delivery provider for SMS inboxes, sending replies through Twilio
+///
+/// Replies longer than the inbox's segment limit are split into several texts.
+/// Inboxes without an SMS configuration are delivered through `fallback`, so
+/// this provider can serve every inbox of the SMS channel type.
+pub struct TwilioDeliveryProvider {
+    conversation_repo: Arc<dyn ConversationRepository>,
+    channel_repo: Arc<dyn ChannelRepository>,
+    sms_repo: Arc<dyn SmsConfigRepository>,
+    fallback: Arc<dyn MessageDeliveryProvider>,
+    api_base_url: String,
+    client: reqwest::Client,
+}
+
+impl TwilioDeliveryProvider {
+    pub fn new(
+        conversation_repo: Arc<dyn ConversationRepository>,
+        channel_repo: Arc<dyn ChannelRepository>,
+        sms_repo: Arc<dyn SmsConfigRepository>,
+        fallback: Arc<dyn MessageDeliveryProvider>,
+    ) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            conversation_repo,
+            channel_repo,
+            sms_repo,
+            fallback,
+            api_base_url: TWILIO_API_BASE_URL.to_string(),
+            client,
+        }
+    }
+
+    /// Send through another Twilio API host (regional edge, test server)
+    pub fn with_api_base_url(mut self, api_base_url: &str) -> Self {
+        self.api_base_url = api_base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    async fn send_text(
+        &self,
+        account_sid: &str,
+        auth_token: &str,
+        from: &str,
+        to: &str,
+        body: &str,
+    ) -> Result<(), String> {
+        let url = format!(
+            "{}/2010-04-01/Accounts/{}/Messages.json",
+            self.api_base_url, account_sid
+        );
+        let response = self
+            .client
+            .post(&url)
+            .basic_auth(account_sid, Some(auth_token))
+            .form(&[("To", to), ("From", from), ("Body", body)])
+            .send()
+            .await
+            .map_err(|e| format!("Twilio request failed: {}", e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let detail = response
+                .json::<serde_json::Value>()
+                .await
+                .ok()
+                .and_then(|body| {
+                    body.get("message")
+                        .and_then(|m| m.as_str())
+                        .map(str::to_string)
+                })
+                .unwrap_or_default();
+            return Err(format!("Twilio returned {}: {}", status, detail));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MessageDeliveryProvider for TwilioDeliveryProvider {
+    async fn deliver(&self, message: &Message) -> Result<(), String> {
+        let conversation = self
+            .conversation_repo
+            .get_conversation_by_id(&message.conversation_id)
+            .await
+            .map_err(|e| format!("Failed to load conversation: {}", e))?
+            .ok_or_else(|| format!("Conversation {} not found", message.conversation_id))?;
+        let config = self
+            .sms_repo
+            .get_inbox_sms_config(&conversation.inbox_id)
+            .await
+            .map_err(|e| format!("Failed to load SMS configuration: {}", e))?;
+        let Some(config) = config else {
+            return self.fallback.deliver(message).await;
+        };
+        if !config.enabled {
+            return Err(format!("SMS is disabled for inbox {}", config.inbox_id));
+        }
+
+        let to = self
+            .channel_repo
+            .find_channel_external_id(&conversation.inbox_id, &conversation.contact_id)
+            .await
+            .map_err(|e| format!("Failed to load recipient: {}", e))?
+            .ok_or_else(|| {
+                format!(
+                    "Contact {} has no phone number in inbox {}",
+                    conversation.contact_id, conversation.inbox_id
+                )
+            })?;
+
+        let parts = split_sms(&message.content, config.max_segments.max(1) as usize);
+        if parts.is_empty() {
+            return Err("Message is empty".to_string());
+        }
+        for part in &parts {
+            self.send_text(
+                &config.account_sid,
+                &config.auth_token,
+                &config.phone_number,
+                &to,
+                part,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "twilio"
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     domain::entities::CreateAgentRequest,
     infrastructure::http::middleware::{AppState, AuthenticatedUser},
+    shared::i18n,
 };
 use askama::Template;
 use axum::{
@@ -197,6 +198,7 @@ struct ConversationData {
     id: String,
     contact_name: String,
     subject: String,
+    summary: String,
     status: String,
     updated_at: String,
 }
@@ -211,6 +213,7 @@ struct ConversationDetailData {
     tags_json: String,
     applied_sla: Option<AppliedSlaData>,
     applied_sla_json: String,
+    pinned_notes: Vec<ContactNoteData>,
 }
 
 #[derive(serde::Serialize, Clone)]
@@ -220,6 +223,11 @@ struct TagData {
     color: String,
 }
 
+struct ContactNoteData {
+    content: String,
+    author_name: Option<String>,
+}
+
 #[derive(serde::Serialize)]
 struct AppliedSlaData {
     policy_name: String,
@@ -236,6 +244,8 @@ struct MessageData {
     content: String,
     is_agent: bool,
     created_at: String,
+    /// Channel the message came in through, shown as a badge
+    channel: Option<String>,
 }
 
 #[derive(Template)]
@@ -355,8 +365,11 @@ pub async fn handle_login(State(state): State<AppState>, Form(form): Form<LoginF
         Ok(result) => result,
         Err(_) => {
             tracing::warn!("Login failed for {}", form.email);
-            return Html("<div class=\"alert alert-error\">Invalid email or password</div>")
-                .into_response();
+            return Html(format!(
+                "<div class=\"alert alert-error\">{}</div>",
+                i18n::t("error-invalid-credentials")
+            ))
+            .into_response();
         }
     };
 
@@ -531,8 +544,11 @@ pub async fn show_agents(
     let agents = match state.agent_service.list_agents_raw(1000, 0).await {
         Ok(agents) => agents,
         Err(_) => {
-            return Html("<div class=\"alert alert-error\">Failed to load agents</div>")
-                .into_response();
+            return Html(format!(
+                "<div class=\"alert alert-error\">{}</div>",
+                i18n::t("web-load-agents-failed")
+            ))
+            .into_response();
         }
     };
 
@@ -664,8 +680,11 @@ pub async fn show_contacts(
     let contacts = match state.contact_service.list_contacts(1, 1000).await {
         Ok(list) => list.contacts,
         Err(_) => {
-            return Html("<div class=\"alert alert-error\">Failed to load contacts</div>")
-                .into_response();
+            return Html(format!(
+                "<div class=\"alert alert-error\">{}</div>",
+                i18n::t("web-load-contacts-failed")
+            ))
+            .into_response();
         }
     };
 
@@ -712,8 +731,11 @@ pub async fn show_roles(
     let roles = match state.role_service.list_roles().await {
         Ok(roles) => roles,
         Err(_) => {
-            return Html("<div class=\"alert alert-error\">Failed to load roles</div>")
-                .into_response();
+            return Html(format!(
+                "<div class=\"alert alert-error\">{}</div>",
+                i18n::t("web-load-roles-failed")
+            ))
+            .into_response();
         }
     };
 
@@ -889,7 +911,7 @@ pub async fn show_inbox(
     // Fetch all conversations first
     let all_conversations = match state
         .conversation_service
-        .list_conversations(&auth_user, 1, 100, None, None, None)
+        .list_conversations(&auth_user, 1, 100, Default::default())
         .await
     {
         Ok(list) => list.conversations,
@@ -960,6 +982,7 @@ pub async fn show_inbox(
             id: conv.id,
             contact_name,
             subject: conv.subject.unwrap_or_default(),
+            summary: conv.summary.unwrap_or_default(),
             status: conv.status.to_string(),
             updated_at: conv.updated_at,
         });
@@ -985,6 +1008,7 @@ pub async fn show_inbox(
         tags_json: "[]".to_string(),
         applied_sla: None,
         applied_sla_json: "null".to_string(),
+        pinned_notes: vec![],
     };
 
     let template = InboxTemplate {
@@ -1064,6 +1088,22 @@ pub async fn show_conversation(
         _ => (None, "null".to_string()),
     };
 
+    // Fetch pinned contact notes
+    let pinned_notes = match state
+        .contact_note_service
+        .list_conversation_pinned_notes(&conversation.id)
+        .await
+    {
+        Ok(notes) => notes
+            .into_iter()
+            .map(|n| ContactNoteData {
+                content: n.content,
+                author_name: n.author_name,
+            })
+            .collect(),
+        Err(_) => vec![],
+    };
+
     // Prepare Detail Data
     let detail_data = ConversationDetailData {
         id: conversation.id.clone(),
@@ -1075,6 +1115,7 @@ pub async fn show_conversation(
         tags_json,
         applied_sla,
         applied_sla_json,
+        pinned_notes,
     };
 
     // Fetch Agents for Dropdown
@@ -1125,7 +1166,7 @@ pub async fn show_conversation(
 
     let mut message_data = Vec::new();
     for msg in messages {
-        let (sender_name, is_agent) = if msg.author_id == conversation.contact_id {
+        let (sender_name, is_agent) = if msg.message_type == crate::domain::entities::MessageType::Incoming {
             ("Contact".to_string(), false)
         } else {
             ("Agent".to_string(), true)
@@ -1137,6 +1178,7 @@ pub async fn show_conversation(
             content: msg.content,
             is_agent,
             created_at: msg.created_at,
+            channel: msg.channel,
         });
     }
 
@@ -1153,7 +1195,7 @@ pub async fn show_conversation(
         // Full page render
         let all_convs = match state
             .conversation_service
-            .list_conversations(&auth_user, 1, 50, None, None, None)
+            .list_conversations(&auth_user, 1, 50, Default::default())
             .await
         {
             Ok(list) => list.conversations,
@@ -1174,6 +1216,7 @@ pub async fn show_conversation(
                 id: conv.id,
                 contact_name: c_name,
                 subject: conv.subject.unwrap_or_default(),
+                summary: conv.summary.unwrap_or_default(),
                 status: conv.status.to_string(),
                 updated_at: conv.updated_at,
             });
@@ -1545,10 +1588,11 @@ pub async fn show_contact_profile(
         .list_conversations(
             &auth_user,
             1,
-            100,              // limit
-            None,             // status
-            None,             // inbox_id
-            Some(id.clone()), // contact_id (user_id)
+            100, // limit
+            crate::domain::entities::ConversationListFilter {
+                contact_id: Some(id.clone()),
+                ..Default::default()
+            },
         )
         .await
     {
@@ -1562,6 +1606,7 @@ pub async fn show_contact_profile(
             id: c.id,
             contact_name: String::new(), // Not needed for profile view of same contact
             subject: c.subject.unwrap_or_default(),
+            summary: c.summary.unwrap_or_default(),
             status: c.status.to_string(),
             updated_at: c.updated_at,
         })
@@ -1745,6 +1790,7 @@ pub async fn show_public_conversation(
         tags_json: "[]".to_string(),
         applied_sla: None,
         applied_sla_json: "null".to_string(),
+        pinned_notes: vec![],
     };
 
     // 3. Fetch messages (Publicly accessible)
@@ -1755,7 +1801,7 @@ pub async fn show_public_conversation(
 
     let mut message_data = Vec::new();
     for msg in messages {
-        let (sender_name, is_agent) = if msg.author_id == conversation.contact_id {
+        let (sender_name, is_agent) = if msg.message_type == crate::domain::entities::MessageType::Incoming {
             ("You".to_string(), false)
         } else {
             ("Oxidesk Support".to_string(), true)
@@ -1767,6 +1813,7 @@ pub async fn show_public_conversation(
             content: msg.content,
             is_agent,
             created_at: msg.created_at,
+            channel: msg.channel,
         });
     }
 
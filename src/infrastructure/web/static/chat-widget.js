@@ -0,0 +1,165 @@
+// Oxidesk web chat widget
+// Embed with: <script src="https://<oxidesk-host>/widget/<inbox-id>/widget.js" async></script>
+// For logged-in users, add data-email and data-identity-signature (hex HMAC-SHA256
+// of the lowercased email with CHAT_WIDGET_IDENTITY_SECRET), and optionally data-name
+(function () {
+  "use strict";
+
+  var INBOX_ID = "__OXIDESK_INBOX_ID__";
+  var STORAGE_KEY = "oxidesk-chat-" + INBOX_ID;
+  var RECONNECT_DELAY_MS = 3000;
+
+  var script = document.currentScript;
+  if (!script || window["__oxideskChat_" + INBOX_ID]) {
+    return;
+  }
+  window["__oxideskChat_" + INBOX_ID] = true;
+  var origin = new URL(script.src).origin;
+  var hostUser = script.dataset.email
+    ? {
+        type: "identify",
+        email: script.dataset.email,
+        name: script.dataset.name || null,
+        identity_signature: script.dataset.identitySignature || null,
+      }
+    : null;
+
+  function loadState() {
+    try {
+      return JSON.parse(window.localStorage.getItem(STORAGE_KEY)) || {};
+    } catch (e) {
+      return {};
+    }
+  }
+
+  function saveState(state) {
+    try {
+      window.localStorage.setItem(STORAGE_KEY, JSON.stringify(state));
+    } catch (e) {
+      // Storage unavailable (private mode); the session lasts for the page only
+    }
+  }
+
+  var state = loadState();
+  var socket = null;
+  var seen = {};
+
+  // UI
+  var root = document.createElement("div");
+  root.style.cssText =
+    "position:fixed;bottom:20px;right:20px;z-index:2147483000;font:14px/1.4 sans-serif;";
+  var panel = document.createElement("div");
+  panel.style.cssText =
+    "display:none;width:320px;height:420px;margin-bottom:10px;background:#fff;border:1px solid #ddd;" +
+    "border-radius:8px;box-shadow:0 4px 16px rgba(0,0,0,.15);flex-direction:column;overflow:hidden;";
+  var log = document.createElement("div");
+  log.style.cssText = "flex:1;overflow-y:auto;padding:10px;";
+  var emailForm = document.createElement("form");
+  emailForm.style.cssText = "display:flex;gap:4px;padding:6px 10px;border-top:1px solid #eee;";
+  emailForm.innerHTML =
+    '<input type="email" required placeholder="Your email, so we can follow up" ' +
+    'style="flex:1;padding:6px;border:1px solid #ccc;border-radius:4px;">' +
+    '<button type="submit" style="padding:6px 10px;">Save</button>';
+  var messageForm = document.createElement("form");
+  messageForm.style.cssText = "display:flex;gap:4px;padding:10px;border-top:1px solid #eee;";
+  messageForm.innerHTML =
+    '<input type="text" required placeholder="Type a message" ' +
+    'style="flex:1;padding:6px;border:1px solid #ccc;border-radius:4px;">' +
+    '<button type="submit" style="padding:6px 10px;">Send</button>';
+  var toggle = document.createElement("button");
+  toggle.type = "button";
+  toggle.textContent = "Chat with us";
+  toggle.style.cssText =
+    "float:right;padding:10px 16px;border:0;border-radius:20px;background:#2563eb;color:#fff;cursor:pointer;";
+
+  panel.appendChild(log);
+  panel.appendChild(emailForm);
+  panel.appendChild(messageForm);
+  root.appendChild(panel);
+  root.appendChild(toggle);
+
+  function render(message) {
+    if (seen[message.id]) {
+      return;
+    }
+    seen[message.id] = true;
+    var bubble = document.createElement("div");
+    bubble.textContent = message.content;
+    bubble.style.cssText =
+      "max-width:80%;margin:4px 0;padding:6px 10px;border-radius:10px;white-space:pre-wrap;" +
+      (message.from_visitor
+        ? "margin-left:auto;background:#2563eb;color:#fff;"
+        : "margin-right:auto;background:#f1f1f1;color:#111;");
+    log.appendChild(bubble);
+    log.scrollTop = log.scrollHeight;
+  }
+
+  function send(frame) {
+    if (socket && socket.readyState === WebSocket.OPEN) {
+      socket.send(JSON.stringify(frame));
+      return true;
+    }
+    return false;
+  }
+
+  function connect() {
+    var url = new URL("/widget/" + encodeURIComponent(INBOX_ID) + "/ws", origin);
+    url.protocol = url.protocol === "https:" ? "wss:" : "ws:";
+    if (state.visitor_token) {
+      url.searchParams.set("visitor_token", state.visitor_token);
+    }
+    if (state.conversation_id) {
+      url.searchParams.set("conversation_id", state.conversation_id);
+    }
+
+    socket = new WebSocket(url.toString());
+    socket.onmessage = function (event) {
+      var frame = JSON.parse(event.data);
+      if (frame.type === "session") {
+        state.visitor_token = frame.visitor_token;
+        state.conversation_id = frame.conversation_id;
+        saveState(state);
+        emailForm.style.display = frame.identified ? "none" : "flex";
+        if (!frame.identified && hostUser) {
+          send(hostUser);
+          hostUser = null;
+        }
+      } else if (frame.type === "history") {
+        frame.messages.forEach(render);
+      } else if (frame.type === "message") {
+        render(frame.message);
+      } else if (frame.type === "error") {
+        console.warn("Oxidesk chat:", frame.error);
+      }
+    };
+    socket.onclose = function () {
+      setTimeout(connect, RECONNECT_DELAY_MS);
+    };
+  }
+
+  toggle.addEventListener("click", function () {
+    panel.style.display = panel.style.display === "none" ? "flex" : "none";
+  });
+  messageForm.addEventListener("submit", function (event) {
+    event.preventDefault();
+    var input = messageForm.querySelector("input");
+    if (input.value.trim() && send({ type: "message", content: input.value })) {
+      input.value = "";
+    }
+  });
+  emailForm.addEventListener("submit", function (event) {
+    event.preventDefault();
+    var input = emailForm.querySelector("input");
+    send({ type: "identify", email: input.value });
+  });
+
+  function start() {
+    document.body.appendChild(root);
+    connect();
+  }
+  if (document.body) {
+    start();
+  } else {
+    document.addEventListener("DOMContentLoaded", start);
+  }
+})();
@@ -0,0 +1,30 @@
+// Apply the workspace branding (product name and logo) to the current page.
+// Colors come from /branding.css; elements opt in with data-brand-name and
+// data-brand-logo.
+(function () {
+    fetch('/api/branding')
+        .then(function (response) {
+            return response.ok ? response.json() : null;
+        })
+        .then(function (branding) {
+            if (!branding) {
+                return;
+            }
+            document.title = document.title.replace(/Oxi[Dd]esk/g, branding.product_name);
+            document.querySelectorAll('[data-brand-name]').forEach(function (el) {
+                el.textContent = branding.product_name;
+            });
+            if (branding.logo_url) {
+                document.querySelectorAll('[data-brand-logo]').forEach(function (el) {
+                    var logo = document.createElement('img');
+                    logo.src = branding.logo_url;
+                    logo.alt = branding.product_name;
+                    logo.className = 'w-8 h-8 mr-3 rounded-lg object-contain';
+                    el.replaceWith(logo);
+                });
+            }
+        })
+        .catch(function () {
+            // Keep the built-in branding
+        });
+})();
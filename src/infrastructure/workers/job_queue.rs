@@ -4,12 +4,26 @@ use serde_json::Value; // serde::{Deserialize, Serialize} removed as Job/JobStat
 use sqlx::Row;
 use uuid::Uuid;
 
-use crate::domain::entities::{Job, JobStatus};
+use crate::domain::entities::{
+    Job, JobFailure, JobFailureKind, JobStatus, MAX_RECORDED_JOB_FAILURES,
+};
 use crate::domain::ports::task_queue::TaskQueue;
 use crate::{
-    infrastructure::http::middleware::error::ApiResult, infrastructure::persistence::Database,
+    infrastructure::http::middleware::error::{ApiError, ApiResult},
+    infrastructure::persistence::Database,
 };
 
+/// How long a worker holds a job before others may reclaim it; running jobs
+/// renew the lock well before it runs out
+pub const JOB_LOCK_DURATION: chrono::Duration = chrono::Duration::minutes(5);
+
+const JOB_COLUMNS: &str = "id, job_type, payload, status,
+    CAST(run_at AS TEXT) as run_at,
+    CAST(created_at AS TEXT) as created_at,
+    CAST(updated_at AS TEXT) as updated_at,
+    attempts, max_attempts, last_error, failures,
+    CAST(dead_at AS TEXT) as dead_at";
+
 /// SQLite implementation of the TaskQueue
 #[derive(Clone)]
 pub struct SqliteTaskQueue {
@@ -64,9 +78,10 @@ impl TaskQueue for SqliteTaskQueue {
     }
 
     async fn fetch_next_job(&self) -> ApiResult<Option<Job>> {
+        self.reclaim_expired_locks().await?;
+
         let now = Utc::now();
-        // 5 minutes lock timeout
-        let lock_timeout = now + chrono::Duration::minutes(5);
+        let lock_timeout = now + JOB_LOCK_DURATION;
 
         // Transaction to ensure atomic fetch-and-lock
         let mut tx = self.db.pool().begin().await?;
@@ -106,57 +121,39 @@ impl TaskQueue for SqliteTaskQueue {
             }
 
             // 3. Fetch full details
-            let job_row = sqlx::query(
-                "SELECT id, job_type, payload, status, 
-                        CAST(run_at AS TEXT) as run_at, 
-                        CAST(created_at AS TEXT) as created_at, 
-                        CAST(updated_at AS TEXT) as updated_at, 
-                        attempts, max_attempts, last_error
-                 FROM jobs WHERE id = ?",
-            )
-            .bind(&id)
-            .fetch_one(&mut *tx)
-            .await?;
+            let job_row = sqlx::query(&format!("SELECT {} FROM jobs WHERE id = ?", JOB_COLUMNS))
+                .bind(&id)
+                .fetch_one(&mut *tx)
+                .await?;
 
             tx.commit().await?;
 
-            let status_str: String = job_row.try_get("status")?;
-            let payload_str: String = job_row.try_get("payload")?;
-            let payload: Value = serde_json::from_str(&payload_str).unwrap_or(Value::Null);
-
-            // Helper to parse string timestamp back to DateTime<Utc>
-            fn parse_date_col(row: &sqlx::any::AnyRow, col: &str) -> ApiResult<DateTime<Utc>> {
-                let s: String = row.try_get(col)?;
-                DateTime::parse_from_rfc3339(&s)
-                    .map(|dt| dt.with_timezone(&Utc))
-                    .map_err(|e| sqlx::Error::Decode(Box::new(e)).into())
-            }
-
-            // Handle optional date
-            let last_error: Option<String> = job_row.try_get("last_error").ok();
-
-            Ok(Some(Job {
-                id: job_row.try_get("id")?,
-                job_type: job_row.try_get("job_type")?,
-                payload,
-                status: JobStatus::from(status_str),
-                run_at: parse_date_col(&job_row, "run_at")?,
-                created_at: parse_date_col(&job_row, "created_at")?,
-                updated_at: parse_date_col(&job_row, "updated_at")?,
-                attempts: job_row.try_get("attempts")?,
-                max_attempts: job_row.try_get("max_attempts")?,
-                last_error,
-            }))
+            Ok(Some(row_to_job(&job_row)?))
         } else {
             Ok(None)
         }
     }
 
+    async fn extend_lock(&self, job_id: &str) -> ApiResult<()> {
+        let now = Utc::now();
+        sqlx::query(
+            "UPDATE jobs
+             SET locked_until = ?
+             WHERE id = ? AND status = 'processing'",
+        )
+        .bind((now + JOB_LOCK_DURATION).to_rfc3339())
+        .bind(job_id)
+        .execute(self.db.pool())
+        .await?;
+
+        Ok(())
+    }
+
     async fn complete_job(&self, job_id: &str) -> ApiResult<()> {
         let now = Utc::now();
         sqlx::query(
             "UPDATE jobs
-             SET status = 'completed', updated_at = ?
+             SET status = 'completed', updated_at = ?, locked_until = NULL
              WHERE id = ?",
         )
         .bind(now.to_rfc3339())
@@ -167,51 +164,200 @@ impl TaskQueue for SqliteTaskQueue {
         Ok(())
     }
 
-    async fn fail_job(&self, job_id: &str, error: &str) -> ApiResult<()> {
-        let now = Utc::now();
+    async fn fail_job(
+        &self,
+        job_id: &str,
+        kind: JobFailureKind,
+        error: &str,
+    ) -> ApiResult<JobStatus> {
+        self.record_failure(job_id, kind, error, None)
+            .await?
+            .ok_or_else(|| ApiError::Conflict(format!("Job {} is not running", job_id)))
+    }
 
-        // Fetch current attempts to decide on retry
-        let row = sqlx::query("SELECT attempts, max_attempts FROM jobs WHERE id = ?")
+    async fn get_job(&self, job_id: &str) -> ApiResult<Option<Job>> {
+        let row = sqlx::query(&format!("SELECT {} FROM jobs WHERE id = ?", JOB_COLUMNS))
             .bind(job_id)
-            .fetch_one(self.db.pool())
+            .fetch_optional(self.db.pool())
             .await?;
 
+        row.as_ref().map(row_to_job).transpose()
+    }
+
+    async fn list_dead_jobs(&self, limit: i64, offset: i64) -> ApiResult<Vec<Job>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM jobs
+             WHERE status = 'dead'
+             ORDER BY dead_at DESC, id ASC
+             LIMIT ? OFFSET ?",
+            JOB_COLUMNS
+        ))
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(self.db.pool())
+        .await?;
+
+        rows.iter().map(row_to_job).collect()
+    }
+
+    async fn requeue_job(&self, job_id: &str) -> ApiResult<bool> {
+        let now = Utc::now();
+        // Failure history and last_error are kept for context
+        let result = sqlx::query(
+            "UPDATE jobs
+             SET status = 'pending', attempts = 0, run_at = ?, updated_at = ?,
+                 dead_at = NULL, locked_until = NULL
+             WHERE id = ? AND status = 'dead'",
+        )
+        .bind(now.to_rfc3339())
+        .bind(now.to_rfc3339())
+        .bind(job_id)
+        .execute(self.db.pool())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+impl SqliteTaskQueue {
+    /// Count jobs whose worker stopped renewing the lock as failed attempts,
+    /// so they are retried (or declared dead) instead of staying stuck
+    async fn reclaim_expired_locks(&self) -> ApiResult<()> {
+        let now = Utc::now().to_rfc3339();
+        let rows = sqlx::query(
+            "SELECT id FROM jobs
+             WHERE status = 'processing' AND locked_until IS NOT NULL AND locked_until < ?",
+        )
+        .bind(&now)
+        .fetch_all(self.db.pool())
+        .await?;
+
+        for row in rows {
+            let id: String = row.try_get("id")?;
+            let status = self
+                .record_failure(
+                    &id,
+                    JobFailureKind::LockExpired,
+                    "Worker stopped before the job finished",
+                    Some(&now),
+                )
+                .await?;
+            if status.is_some() {
+                tracing::warn!("Reclaimed job {} after its lock expired", id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Append a failure to a processing job and reschedule or kill it.
+    /// With `lock_expired_before`, only applies if the lock is still expired.
+    /// Returns None if the job was no longer processing.
+    async fn record_failure(
+        &self,
+        job_id: &str,
+        kind: JobFailureKind,
+        error: &str,
+        lock_expired_before: Option<&str>,
+    ) -> ApiResult<Option<JobStatus>> {
+        let now = Utc::now();
+
+        let row = sqlx::query(
+            "SELECT attempts, max_attempts, failures FROM jobs
+             WHERE id = ? AND status = 'processing'",
+        )
+        .bind(job_id)
+        .fetch_optional(self.db.pool())
+        .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
         let attempts: i32 = row.try_get("attempts")?;
         let max_attempts: i32 = row.try_get("max_attempts")?;
         let new_attempts = attempts + 1;
 
-        if new_attempts < max_attempts {
-            // Exponential backoff: 2^attempts * 30 seconds
-            let backoff_seconds = 30 * (1 << attempts);
-            let next_run = now + chrono::Duration::seconds(backoff_seconds as i64);
+        let mut failures = parse_failures(&row);
+        failures.push(JobFailure {
+            attempt: new_attempts,
+            kind,
+            message: error.to_string(),
+            failed_at: now,
+        });
+        let excess = failures.len().saturating_sub(MAX_RECORDED_JOB_FAILURES);
+        failures.drain(..excess);
+        let failures_json = serde_json::to_string(&failures).unwrap_or_else(|_| "[]".to_string());
 
-            sqlx::query(
-                "UPDATE jobs
-                 SET status = 'pending', attempts = ?, last_error = ?, run_at = ?, updated_at = ?
-                 WHERE id = ?",
+        let (status, run_at, dead_at) = if new_attempts < max_attempts {
+            (
+                JobStatus::Pending,
+                now + Job::retry_delay(new_attempts),
+                None,
             )
-            .bind(new_attempts)
-            .bind(error)
-            .bind(next_run.to_rfc3339())
-            .bind(now.to_rfc3339())
-            .bind(job_id)
-            .execute(self.db.pool())
-            .await?;
         } else {
-            // Permanent failure
-            sqlx::query(
-                "UPDATE jobs
-                 SET status = 'failed', attempts = ?, last_error = ?, updated_at = ?
-                 WHERE id = ?",
-            )
-            .bind(new_attempts)
-            .bind(error)
-            .bind(now.to_rfc3339())
-            .bind(job_id)
-            .execute(self.db.pool())
-            .await?;
-        }
+            (JobStatus::Dead, now, Some(now.to_rfc3339()))
+        };
 
-        Ok(())
+        let result = sqlx::query(
+            "UPDATE jobs
+             SET status = ?, attempts = ?, last_error = ?, failures = ?, run_at = ?,
+                 dead_at = ?, locked_until = NULL, updated_at = ?
+             WHERE id = ? AND status = 'processing'
+               AND (? IS NULL OR locked_until < ?)",
+        )
+        .bind(status.to_string())
+        .bind(new_attempts)
+        .bind(error)
+        .bind(failures_json)
+        .bind(run_at.to_rfc3339())
+        .bind(dead_at)
+        .bind(now.to_rfc3339())
+        .bind(job_id)
+        .bind(lock_expired_before)
+        .bind(lock_expired_before)
+        .execute(self.db.pool())
+        .await?;
+
+        Ok((result.rows_affected() > 0).then_some(status))
     }
 }
+
+fn parse_failures(row: &sqlx::any::AnyRow) -> Vec<JobFailure> {
+    let failures: Option<String> = row.try_get("failures").ok().flatten();
+    failures
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn row_to_job(row: &sqlx::any::AnyRow) -> ApiResult<Job> {
+    // Helper to parse string timestamp back to DateTime<Utc>
+    fn parse_date(s: &str) -> ApiResult<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(s)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)).into())
+    }
+    fn parse_date_col(row: &sqlx::any::AnyRow, col: &str) -> ApiResult<DateTime<Utc>> {
+        let s: String = row.try_get(col)?;
+        parse_date(&s)
+    }
+
+    let status_str: String = row.try_get("status")?;
+    let payload_str: String = row.try_get("payload")?;
+    let payload: Value = serde_json::from_str(&payload_str).unwrap_or(Value::Null);
+    let dead_at: Option<String> = row.try_get("dead_at").ok().flatten();
+
+    Ok(Job {
+        id: row.try_get("id")?,
+        job_type: row.try_get("job_type")?,
+        payload,
+        status: JobStatus::from(status_str),
+        run_at: parse_date_col(row, "run_at")?,
+        created_at: parse_date_col(row, "created_at")?,
+        updated_at: parse_date_col(row, "updated_at")?,
+        attempts: row.try_get("attempts")?,
+        max_attempts: row.try_get("max_attempts")?,
+        last_error: row.try_get("last_error").ok().flatten(),
+        failures: parse_failures(row),
+        dead_at: dead_at.as_deref().map(parse_date).transpose()?,
+    })
+}
@@ -1,28 +1,45 @@
 use chrono::Utc;
+use futures::FutureExt;
 use serde_json::Value;
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::{error, info};
 
-use crate::application::services::{AvailabilityService, SlaService};
-use crate::domain::entities::Job;
+use crate::application::services::{
+    AutomationService, AvailabilityService, ImportService, ResponseReminderService, ShiftService,
+    SlaService, WebhookService, CHECK_RESPONSE_REMINDERS_JOB, RUN_AUTOMATION_WAIT_JOB,
+    RUN_IMPORT_JOB, RUN_WEBHOOK_BACKFILL_JOB,
+};
+use crate::domain::entities::{Job, JobFailureKind, JobStatus};
 use crate::domain::ports::oidc_repository::OidcRepository;
 use crate::domain::ports::task_queue::TaskQueue;
 use crate::domain::ports::webhook_repository::WebhookRepository;
+use crate::shared::maintenance::MaintenanceMode;
 use crate::shared::rate_limiter::AuthRateLimiter;
 
 use crate::domain::ports::time_service::TimeService;
 
+/// How often a running job renews its queue lock (the lock lasts 5 minutes)
+const JOB_LOCK_RENEW_INTERVAL: Duration = Duration::from_secs(60);
+
 pub struct JobProcessor {
     queue: Arc<dyn TaskQueue>,
     oidc_repo: OidcRepository,
     webhook_repo: WebhookRepository,
     rate_limiter: AuthRateLimiter,
     availability_service: AvailabilityService,
+    shift_service: ShiftService,
     sla_service: SlaService,
     session_service: crate::application::services::SessionService,
     http_client: reqwest::Client,
     time_service: Arc<dyn TimeService>,
+    import_service: Option<ImportService>,
+    automation_service: Option<Arc<AutomationService>>,
+    webhook_service: Option<WebhookService>,
+    maintenance_mode: Option<MaintenanceMode>,
+    response_reminder_service: Option<ResponseReminderService>,
 }
 
 impl JobProcessor {
@@ -32,6 +49,7 @@ impl JobProcessor {
         webhook_repo: WebhookRepository,
         rate_limiter: AuthRateLimiter,
         availability_service: AvailabilityService,
+        shift_service: ShiftService,
         sla_service: SlaService,
         session_service: crate::application::services::SessionService,
         time_service: Arc<dyn TimeService>,
@@ -47,16 +65,58 @@ impl JobProcessor {
             webhook_repo,
             rate_limiter,
             availability_service,
+            shift_service,
             sla_service,
             session_service,
             http_client,
             time_service,
+            import_service: None,
+            automation_service: None,
+            webhook_service: None,
+            maintenance_mode: None,
+            response_reminder_service: None,
         }
     }
 
+    /// Run helpdesk imports queued by `ImportService`
+    pub fn set_import_service(&mut self, import_service: ImportService) {
+        self.import_service = Some(import_service);
+    }
+
+    /// Run the follow-up of automation Wait actions when they come due
+    pub fn set_automation_service(&mut self, automation_service: Arc<AutomationService>) {
+        self.automation_service = Some(automation_service);
+    }
+
+    /// Replay webhook backfills a batch at a time
+    pub fn set_webhook_service(&mut self, webhook_service: WebhookService) {
+        self.webhook_service = Some(webhook_service);
+    }
+
+    /// Stop picking up jobs while maintenance mode is on; running jobs finish
+    pub fn set_maintenance_mode(&mut self, maintenance_mode: MaintenanceMode) {
+        self.maintenance_mode = Some(maintenance_mode);
+    }
+
+    /// Remind assignees about conversations waiting for a reply
+    pub fn set_response_reminder_service(&mut self, service: ResponseReminderService) {
+        self.response_reminder_service = Some(service);
+    }
+
     pub async fn run(&self) {
         info!("Starting JobProcessor...");
         loop {
+            if self
+                .maintenance_mode
+                .as_ref()
+                .is_some_and(|mode| mode.is_enabled())
+            {
+                // Paused for maintenance; jobs stay queued
+                self.time_service.sleep(Duration::from_secs(1)).await;
+                continue;
+            }
+
+            let _active_job = self.maintenance_mode.as_ref().map(|mode| mode.begin_job());
             match self.process_next().await {
                 Ok(Some(_)) => {
                     // Job processed, check for next one immediately
@@ -90,33 +150,50 @@ impl JobProcessor {
             metrics::counter!("job_executions_total", "type" => job.job_type.clone()).increment(1);
             let start = std::time::Instant::now();
 
-            // Execute the job logic
-            let result = self.execute_job(&job).await;
+            // Execute the job logic, renewing the lock while it runs
+            let outcome = self.execute_with_lock(&job).await;
             let duration = start.elapsed();
             metrics::histogram!("job_duration_seconds", "type" => job.job_type.clone())
                 .record(duration.as_secs_f64());
 
             // Handle result
-            match result {
-                Ok(_) => {
+            let (kind, e) = match outcome {
+                Ok(Ok(())) => {
                     info!("Job {} completed successfully", job.id);
                     if let Err(e) = self.queue.complete_job(&job.id).await {
                         error!("Failed to mark job {} as completed: {}", job.id, e);
                     }
+                    return Ok(Some(()));
                 }
-                Err(e) => {
-                    metrics::counter!("job_errors_total", "type" => job.job_type.clone())
+                Ok(Err(e)) => (JobFailureKind::Error, e),
+                Err(panic) => (
+                    JobFailureKind::Panic,
+                    format!("Job panicked: {}", panic_message(panic.as_ref())),
+                ),
+            };
+
+            metrics::counter!("job_errors_total", "type" => job.job_type.clone()).increment(1);
+            error!(
+                "Job {} (type: {}) failed on attempt {}/{}: {}. Payload: {}",
+                job.id,
+                job.job_type,
+                job.attempts + 1,
+                job.max_attempts,
+                e,
+                serde_json::to_string(&job.payload).unwrap_or_default()
+            );
+            match self.queue.fail_job(&job.id, kind, &e).await {
+                Ok(JobStatus::Dead) => {
+                    metrics::counter!("job_dead_total", "type" => job.job_type.clone())
                         .increment(1);
                     error!(
-                        "Job {} (type: {}) failed: {}. Payload: {}",
-                        job.id,
-                        job.job_type,
-                        e,
-                        serde_json::to_string(&job.payload).unwrap_or_default()
+                        "Job {} (type: {}) is dead after {} attempts; requeue it from the admin API once fixed",
+                        job.id, job.job_type, job.max_attempts
                     );
-                    if let Err(retry_err) = self.queue.fail_job(&job.id, &e).await {
-                        error!("Failed to mark job {} as failed: {}", job.id, retry_err);
-                    }
+                }
+                Ok(_) => {}
+                Err(retry_err) => {
+                    error!("Failed to mark job {} as failed: {}", job.id, retry_err);
                 }
             }
 
@@ -126,6 +203,28 @@ impl JobProcessor {
         }
     }
 
+    /// Run a job, catching panics and renewing its lock until it finishes
+    async fn execute_with_lock(
+        &self,
+        job: &Job,
+    ) -> Result<Result<(), String>, Box<dyn Any + Send>> {
+        let execution = AssertUnwindSafe(self.execute_job(job)).catch_unwind();
+        tokio::pin!(execution);
+
+        let mut renew = tokio::time::interval(JOB_LOCK_RENEW_INTERVAL);
+        renew.tick().await; // the first tick completes immediately
+        loop {
+            tokio::select! {
+                outcome = &mut execution => return outcome,
+                _ = renew.tick() => {
+                    if let Err(e) = self.queue.extend_lock(&job.id).await {
+                        error!("Failed to renew lock on job {}: {}", job.id, e);
+                    }
+                }
+            }
+        }
+    }
+
     #[tracing::instrument(skip(self, job), fields(job_id = %job.id, job_type = %job.job_type))]
     async fn execute_job(&self, job: &Job) -> Result<(), String> {
         match job.job_type.as_str() {
@@ -138,7 +237,11 @@ impl JobProcessor {
             "cleanup_oidc_states" => self.handle_cleanup_oidc_states().await,
             "check_availability" => self.handle_check_availability().await,
             "check_sla_breaches" => self.handle_check_sla_breaches().await,
+            CHECK_RESPONSE_REMINDERS_JOB => self.handle_check_response_reminders().await,
             "deliver_webhook" => self.handle_deliver_webhook(&job.payload).await,
+            RUN_IMPORT_JOB => self.handle_run_import(&job.payload).await,
+            RUN_AUTOMATION_WAIT_JOB => self.handle_run_automation_wait(&job.payload).await,
+            RUN_WEBHOOK_BACKFILL_JOB => self.handle_run_webhook_backfill(&job.payload).await,
             _ => Err(format!("Unknown job type: {}", job.job_type)),
         }
     }
@@ -203,6 +306,11 @@ impl JobProcessor {
             error!("Failed to check max idle thresholds: {}", e);
         }
 
+        // 3. Apply shift start/end transitions
+        if let Err(e) = self.shift_service.apply_shift_transitions(Utc::now()).await {
+            error!("Failed to apply shift transitions: {}", e);
+        }
+
         // Schedule next run in 30 seconds
         let next_run = Utc::now() + chrono::Duration::seconds(30);
         self.queue
@@ -226,6 +334,74 @@ impl JobProcessor {
         Ok(())
     }
 
+    async fn handle_check_response_reminders(&self) -> Result<(), String> {
+        let service = self
+            .response_reminder_service
+            .as_ref()
+            .ok_or("Response reminder service is not configured")?;
+        if let Err(e) = service.send_due_reminders(Utc::now()).await {
+            error!("Failed to send response reminders: {}", e);
+        }
+
+        // Schedule next run in 60 seconds
+        let next_run = Utc::now() + chrono::Duration::seconds(60);
+        self.queue
+            .enqueue_at(CHECK_RESPONSE_REMINDERS_JOB, Value::Null, next_run, 3)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    async fn handle_run_import(&self, payload: &Value) -> Result<(), String> {
+        let import_id = payload["import_id"]
+            .as_str()
+            .ok_or("Missing 'import_id' in job payload")?;
+        let import_service = self
+            .import_service
+            .as_ref()
+            .ok_or("Import service is not configured")?;
+
+        let import = import_service
+            .run_import(import_id)
+            .await
+            .map_err(|e| format!("Import {} failed: {}", import_id, e))?;
+        info!(
+            "Import {} finished with status {} ({}/{} records)",
+            import.id, import.status, import.processed_records, import.total_records
+        );
+        Ok(())
+    }
+
+    async fn handle_run_automation_wait(&self, payload: &Value) -> Result<(), String> {
+        let pending_action_id = payload["pending_action_id"]
+            .as_str()
+            .ok_or("Missing 'pending_action_id' in job payload")?;
+        let automation_service = self
+            .automation_service
+            .as_ref()
+            .ok_or("Automation service is not configured")?;
+
+        automation_service
+            .run_pending_action(pending_action_id)
+            .await
+    }
+
+    async fn handle_run_webhook_backfill(&self, payload: &Value) -> Result<(), String> {
+        let backfill_id = payload["backfill_id"]
+            .as_str()
+            .ok_or("Missing 'backfill_id' in job payload")?;
+        let webhook_service = self
+            .webhook_service
+            .as_ref()
+            .ok_or("Webhook service is not configured")?;
+
+        webhook_service
+            .run_backfill(backfill_id)
+            .await
+            .map_err(|e| format!("Backfill {} failed: {}", backfill_id, e))?;
+        Ok(())
+    }
+
     async fn handle_deliver_webhook(&self, payload: &Value) -> Result<(), String> {
         // Extract job arguments
         let webhook_id = payload["webhook_id"]
@@ -316,3 +492,14 @@ impl JobProcessor {
         }
     }
 }
+
+/// Text of a caught panic payload
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
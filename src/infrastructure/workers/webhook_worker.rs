@@ -90,9 +90,20 @@ impl WebhookWorker {
             event_type
         );
 
+        // The snapshot is looked up once, and only if some webhook wants it
+        let enriched_payload = if webhooks.iter().any(|w| w.include_conversation_snapshot) {
+            self.with_conversation_snapshot(&payload).await
+        } else {
+            None
+        };
+
         // Queue delivery for each matching webhook
         for webhook in webhooks {
-            if let Err(e) = self.queue_delivery(&webhook, &event_type, &payload).await {
+            let payload = match &enriched_payload {
+                Some(enriched) if webhook.include_conversation_snapshot => enriched,
+                _ => &payload,
+            };
+            if let Err(e) = self.queue_delivery(&webhook, &event_type, payload).await {
                 error!("Failed to queue delivery for webhook {}: {}", webhook.id, e);
                 // Continue with other webhooks even if one fails
             }
@@ -265,6 +276,38 @@ impl WebhookWorker {
                     "timestamp": timestamp,
                 }),
             ),
+            SystemEvent::CsatReceived {
+                csat_response_id,
+                conversation_id,
+                score,
+                comment,
+                timestamp,
+            } => (
+                "csat.received",
+                json!({
+                    "csat_response_id": csat_response_id,
+                    "conversation_id": conversation_id,
+                    "score": score,
+                    "comment": comment,
+                    "timestamp": timestamp,
+                }),
+            ),
+            SystemEvent::SentimentDropped {
+                conversation_id,
+                message_id,
+                previous_score,
+                current_score,
+                timestamp,
+            } => (
+                "conversation.sentiment_dropped",
+                json!({
+                    "conversation_id": conversation_id,
+                    "message_id": message_id,
+                    "previous_score": previous_score,
+                    "current_score": current_score,
+                    "timestamp": timestamp,
+                }),
+            ),
             SystemEvent::AgentAvailabilityChanged {
                 agent_id,
                 old_status,
@@ -317,6 +360,37 @@ impl WebhookWorker {
         Ok((event_type.to_string(), envelope))
     }
 
+    /// Copy of the payload with the conversation's current state added as
+    /// `data.conversation`
+    ///
+    /// Returns None for events that aren't about a conversation, or when the
+    /// snapshot can't be loaded; those webhooks get the plain payload.
+    async fn with_conversation_snapshot(
+        &self,
+        payload: &serde_json::Value,
+    ) -> Option<serde_json::Value> {
+        let conversation_id = payload["data"]["conversation_id"].as_str()?;
+        let snapshot = match self
+            .webhook_repo
+            .get_conversation_snapshot(conversation_id)
+            .await
+        {
+            Ok(Some(snapshot)) => snapshot,
+            Ok(None) => return None,
+            Err(e) => {
+                warn!(
+                    "Failed to load snapshot of conversation {}: {}",
+                    conversation_id, e
+                );
+                return None;
+            }
+        };
+
+        let mut enriched = payload.clone();
+        enriched["data"]["conversation"] = json!(snapshot);
+        Some(enriched)
+    }
+
     /// Queue a webhook delivery for processing
     #[tracing::instrument(skip(self, payload))]
     async fn queue_delivery(
@@ -361,6 +435,7 @@ impl WebhookWorker {
 mod tests {
     use super::*;
     use crate::domain::entities::conversation::ConversationStatus;
+    use crate::domain::entities::{find_webhook_event_type, WEBHOOK_EVENT_TYPES};
     use crate::domain::ports::webhook_repository::WebhookRepository;
     use crate::infrastructure::persistence::Database;
     use crate::infrastructure::workers::SqliteTaskQueue;
@@ -416,4 +491,172 @@ mod tests {
         assert_eq!(payload["data"]["message_id"], "msg-123");
         assert_eq!(payload["data"]["agent_id"], "agent-789");
     }
+
+    #[tokio::test]
+    async fn test_every_payload_matches_event_catalog() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        db.run_migrations().await.unwrap();
+        let event_bus = Arc::new(crate::shared::events::LocalEventBus::default());
+        let task_queue = Arc::new(SqliteTaskQueue::new(db.clone()));
+        let webhook_repo = WebhookRepository::new(db);
+        let worker = WebhookWorker::new(webhook_repo, event_bus, task_queue);
+
+        let id = || "id-1".to_string();
+        let ts = || "2026-01-13T10:00:00Z".to_string();
+        let events = vec![
+            SystemEvent::ConversationCreated {
+                conversation_id: id(),
+                inbox_id: id(),
+                contact_id: id(),
+                status: ConversationStatus::Open,
+                timestamp: ts(),
+            },
+            SystemEvent::ConversationStatusChanged {
+                conversation_id: id(),
+                old_status: ConversationStatus::Open,
+                new_status: ConversationStatus::Resolved,
+                agent_id: None,
+                timestamp: ts(),
+            },
+            SystemEvent::MessageReceived {
+                message_id: id(),
+                conversation_id: id(),
+                contact_id: id(),
+                timestamp: ts(),
+            },
+            SystemEvent::MessageSent {
+                message_id: id(),
+                conversation_id: id(),
+                agent_id: id(),
+                timestamp: ts(),
+            },
+            SystemEvent::MessageFailed {
+                message_id: id(),
+                conversation_id: id(),
+                retry_count: 1,
+                timestamp: ts(),
+            },
+            SystemEvent::ConversationAssigned {
+                conversation_id: id(),
+                assigned_user_id: Some(id()),
+                assigned_team_id: None,
+                assigned_by: id(),
+                timestamp: ts(),
+            },
+            SystemEvent::ConversationUnassigned {
+                conversation_id: id(),
+                previous_assigned_user_id: Some(id()),
+                previous_assigned_team_id: None,
+                unassigned_by: id(),
+                timestamp: ts(),
+            },
+            SystemEvent::ConversationTagsChanged {
+                conversation_id: id(),
+                previous_tags: vec![],
+                new_tags: vec!["billing".to_string()],
+                changed_by: id(),
+                timestamp: ts(),
+            },
+            SystemEvent::ConversationPriorityChanged {
+                conversation_id: id(),
+                previous_priority: None,
+                new_priority: Some("high".to_string()),
+                updated_by: id(),
+                timestamp: ts(),
+            },
+            SystemEvent::AgentAvailabilityChanged {
+                agent_id: id(),
+                old_status: "online".to_string(),
+                new_status: "away".to_string(),
+                timestamp: ts(),
+                reason: "manual".to_string(),
+            },
+            SystemEvent::AgentLoggedIn {
+                agent_id: id(),
+                user_id: id(),
+                timestamp: ts(),
+            },
+            SystemEvent::AgentLoggedOut {
+                agent_id: id(),
+                user_id: id(),
+                timestamp: ts(),
+            },
+            SystemEvent::SlaBreached {
+                event_id: id(),
+                applied_sla_id: id(),
+                conversation_id: id(),
+                event_type: "first_response".to_string(),
+                deadline_at: ts(),
+                breached_at: ts(),
+                timestamp: ts(),
+            },
+            SystemEvent::CsatReceived {
+                csat_response_id: id(),
+                conversation_id: id(),
+                score: 5,
+                comment: None,
+                timestamp: ts(),
+            },
+            SystemEvent::SentimentDropped {
+                conversation_id: id(),
+                message_id: id(),
+                previous_score: 0.5,
+                current_score: -0.5,
+                timestamp: ts(),
+            },
+        ];
+
+        let mut emitted = Vec::new();
+        for event in &events {
+            let (event_type, payload) = worker.construct_payload(event).unwrap();
+            let catalog = find_webhook_event_type(&event_type)
+                .unwrap_or_else(|| panic!("{} is missing from the event catalog", event_type));
+
+            let mut fields: Vec<&str> = payload["data"]
+                .as_object()
+                .unwrap()
+                .keys()
+                .map(String::as_str)
+                .collect();
+            let mut documented: Vec<&str> = catalog.data.iter().map(|(field, _)| *field).collect();
+            fields.sort();
+            documented.sort();
+            assert_eq!(fields, documented, "fields of {}", event_type);
+            emitted.push(event_type);
+        }
+
+        // Nothing in the catalog that is never delivered
+        assert_eq!(emitted.len(), WEBHOOK_EVENT_TYPES.len());
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_only_added_for_known_conversations() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        db.run_migrations().await.unwrap();
+        let event_bus = Arc::new(crate::shared::events::LocalEventBus::default());
+        let task_queue = Arc::new(SqliteTaskQueue::new(db.clone()));
+        let webhook_repo = WebhookRepository::new(db);
+        let worker = WebhookWorker::new(webhook_repo, event_bus, task_queue);
+
+        // Not about a conversation
+        let (_, payload) = worker
+            .construct_payload(&SystemEvent::AgentLoggedIn {
+                agent_id: "agent-1".to_string(),
+                user_id: "user-1".to_string(),
+                timestamp: "2026-01-13T10:00:00Z".to_string(),
+            })
+            .unwrap();
+        assert!(worker.with_conversation_snapshot(&payload).await.is_none());
+
+        // The conversation is gone by the time the event is handled
+        let (_, payload) = worker
+            .construct_payload(&SystemEvent::MessageSent {
+                message_id: "msg-1".to_string(),
+                conversation_id: "conv-missing".to_string(),
+                agent_id: "agent-1".to_string(),
+                timestamp: "2026-01-13T10:00:00Z".to_string(),
+            })
+            .unwrap();
+        assert!(worker.with_conversation_snapshot(&payload).await.is_none());
+    }
 }
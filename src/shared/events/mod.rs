@@ -40,6 +40,10 @@ impl EventBus for LocalEventBus {
             .map(|res| res.map_err(|e| format!("Broadcast channel error: {}", e)));
         Box::pin(stream)
     }
+
+    fn pending_events(&self) -> usize {
+        self.tx.len()
+    }
 }
 
 impl LocalEventBus {
@@ -66,6 +70,26 @@ mod tests {
         assert_eq!(bus.subscriber_count(), 0);
     }
 
+    #[tokio::test]
+    async fn test_pending_events_drain_as_subscribers_receive() {
+        use tokio_stream::StreamExt;
+        let bus = LocalEventBus::new(10);
+        let mut rx = bus.subscribe();
+        let event = || SystemEvent::AgentLoggedOut {
+            agent_id: "agent-id".to_string(),
+            user_id: "user-id".to_string(),
+            timestamp: "2026-01-12T10:00:00Z".to_string(),
+        };
+
+        let _ = bus.publish(event());
+        let _ = bus.publish(event());
+        assert_eq!(bus.pending_events(), 2);
+
+        rx.next().await.unwrap().unwrap();
+        rx.next().await.unwrap().unwrap();
+        assert_eq!(bus.pending_events(), 0);
+    }
+
     #[tokio::test]
     async fn test_event_publish_subscribe() {
         use tokio_stream::StreamExt;
@@ -0,0 +1,572 @@
+//! Localization of API messages, web pages and notification emails
+//!
+//! Catalogs are written in a subset of the Fluent syntax: `id = text`
+//! messages with `{ $name }` placeholders, indented continuation lines and
+//! `#` comments. English and Spanish ship with the binary; more locales (or
+//! overrides of the built-in ones) are read from `<locale>.ftl` files in
+//! `LOCALES_DIR` at startup.
+//!
+//! Server messages are written in English throughout the code base, so the
+//! English catalog doubles as the message index: [`localize`] finds the
+//! entry an English message was rendered from, like a gettext msgid, and
+//! renders the same entry in the request locale. Anything without an entry
+//! stays in English.
+//!
+//! The request locale is negotiated by the locale middleware and carried in
+//! a task-local, so templates and error responses can look it up without it
+//! being threaded through every handler.
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Locale every message exists in
+pub const FALLBACK_LOCALE: &str = "en";
+
+const BUILTIN_CATALOGS: &[(&str, &str)] = &[
+    ("en", include_str!("../../locales/en.ftl")),
+    ("es", include_str!("../../locales/es.ftl")),
+];
+
+tokio::task_local! {
+    static REQUEST_LOCALE: String;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Variable(String),
+}
+
+/// Parsed message text
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Pattern {
+    segments: Vec<Segment>,
+}
+
+impl Pattern {
+    fn parse(source: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut rest = source;
+        while let Some(start) = rest.find('{') {
+            if start > 0 {
+                segments.push(Segment::Text(rest[..start].to_string()));
+            }
+            let inner = rest[start + 1..].trim_start();
+            // `{ "{" }` is how Fluent writes a literal brace
+            let (segment, after) = if let Some(quoted) = inner.strip_prefix('"') {
+                let close = quoted
+                    .find('"')
+                    .ok_or_else(|| "unclosed string literal".to_string())?;
+                let after = quoted[close + 1..]
+                    .trim_start()
+                    .strip_prefix('}')
+                    .ok_or_else(|| "unclosed placeholder".to_string())?;
+                (Segment::Text(quoted[..close].to_string()), after)
+            } else {
+                let end = inner
+                    .find('}')
+                    .ok_or_else(|| "unclosed placeholder".to_string())?;
+                let placeholder = inner[..end].trim();
+                let name = placeholder
+                    .strip_prefix('$')
+                    .filter(|name| is_identifier(name))
+                    .ok_or_else(|| format!("unsupported placeholder '{}'", placeholder))?;
+                (Segment::Variable(name.to_string()), &inner[end + 1..])
+            };
+            segments.push(segment);
+            rest = after;
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Text(rest.to_string()));
+        }
+        Ok(Self { segments })
+    }
+
+    fn format(&self, args: &[(&str, &str)]) -> String {
+        let mut output = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Text(text) => output.push_str(text),
+                Segment::Variable(name) => match args.iter().find(|(key, _)| key == name) {
+                    Some((_, value)) => output.push_str(value),
+                    // Fluent leaves unresolved variables visible
+                    None => {
+                        output.push_str("{$");
+                        output.push_str(name);
+                        output.push('}');
+                    }
+                },
+            }
+        }
+        output
+    }
+
+    fn literal_len(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text.len(),
+                Segment::Variable(_) => 0,
+            })
+            .sum()
+    }
+
+    /// Variable values if `text` is this pattern rendered with some arguments
+    fn capture(&self, text: &str) -> Option<Vec<(String, String)>> {
+        let mut captured = Vec::new();
+        capture_segments(&self.segments, text, &mut captured).then_some(captured)
+    }
+}
+
+fn capture_segments(
+    segments: &[Segment],
+    text: &str,
+    captured: &mut Vec<(String, String)>,
+) -> bool {
+    match segments {
+        [] => text.is_empty(),
+        [Segment::Text(literal), rest @ ..] => text
+            .strip_prefix(literal.as_str())
+            .is_some_and(|text| capture_segments(rest, text, captured)),
+        [Segment::Variable(name)] => {
+            if text.is_empty() {
+                return false;
+            }
+            captured.push((name.clone(), text.to_string()));
+            true
+        }
+        [Segment::Variable(name), Segment::Text(literal), rest @ ..] => {
+            // Try every place the following text could start
+            for (index, _) in text.match_indices(literal.as_str()).filter(|(i, _)| *i > 0) {
+                let mark = captured.len();
+                captured.push((name.clone(), text[..index].to_string()));
+                if capture_segments(&rest_with(literal, rest), &text[index..], captured) {
+                    return true;
+                }
+                captured.truncate(mark);
+            }
+            false
+        }
+        // Adjacent variables can't be told apart
+        [Segment::Variable(_), Segment::Variable(_), ..] => false,
+    }
+}
+
+fn rest_with(literal: &str, rest: &[Segment]) -> Vec<Segment> {
+    let mut segments = Vec::with_capacity(rest.len() + 1);
+    segments.push(Segment::Text(literal.to_string()));
+    segments.extend_from_slice(rest);
+    segments
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Canonical form of a language tag: `pt_br` and `PT-BR` become `pt-BR`
+pub fn normalize_locale(tag: &str) -> Option<String> {
+    let mut parts = tag.trim().split(['-', '_']);
+    let language = parts.next()?.to_ascii_lowercase();
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let mut normalized = language;
+    for part in parts {
+        if part.is_empty() || part.len() > 8 || !part.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        normalized.push('-');
+        if part.len() == 2 {
+            normalized.push_str(&part.to_ascii_uppercase());
+        } else {
+            normalized.push_str(&part.to_ascii_lowercase());
+        }
+    }
+    Some(normalized)
+}
+
+/// Language part of a tag, e.g. `pt` for `pt-BR`
+fn primary_language(locale: &str) -> &str {
+    locale.split('-').next().unwrap_or(locale)
+}
+
+/// Messages of every available locale
+#[derive(Debug, Clone)]
+pub struct Catalog {
+    locales: HashMap<String, HashMap<String, Pattern>>,
+    default_locale: String,
+    /// English entries, most specific first, for [`Catalog::localize`]
+    index: Vec<(String, Pattern)>,
+}
+
+impl Catalog {
+    /// Catalog of the locales that ship with the binary
+    pub fn builtin() -> Self {
+        let mut catalog = Self {
+            locales: HashMap::new(),
+            default_locale: FALLBACK_LOCALE.to_string(),
+            index: Vec::new(),
+        };
+        for (locale, source) in BUILTIN_CATALOGS {
+            catalog
+                .add_resource(locale, source)
+                .unwrap_or_else(|e| panic!("built-in {} catalog is invalid: {}", locale, e));
+        }
+        catalog
+    }
+
+    /// Built-in catalog plus `LOCALES_DIR`, using `DEFAULT_LOCALE` for
+    /// requests that don't ask for a supported locale
+    pub fn from_env() -> Self {
+        let mut catalog = Self::builtin();
+        if let Ok(dir) = std::env::var("LOCALES_DIR") {
+            if let Err(e) = catalog.load_dir(Path::new(&dir)) {
+                tracing::error!("Failed to load locales from {}: {}", dir, e);
+            }
+        }
+        if let Ok(locale) = std::env::var("DEFAULT_LOCALE") {
+            if !catalog.set_default_locale(&locale) {
+                tracing::warn!(
+                    "DEFAULT_LOCALE '{}' has no catalog; using '{}'",
+                    locale,
+                    catalog.default_locale
+                );
+            }
+        }
+        catalog
+    }
+
+    /// Add the messages of one `.ftl` resource, replacing existing ones
+    pub fn add_resource(&mut self, locale: &str, source: &str) -> Result<(), String> {
+        let locale =
+            normalize_locale(locale).ok_or_else(|| format!("invalid locale '{}'", locale))?;
+        let messages = parse_resource(source)?;
+        self.locales
+            .entry(locale.clone())
+            .or_default()
+            .extend(messages);
+        if locale == FALLBACK_LOCALE {
+            self.rebuild_index();
+        }
+        Ok(())
+    }
+
+    /// Load every `<locale>.ftl` file in `dir`
+    pub fn load_dir(&mut self, dir: &Path) -> Result<(), String> {
+        let entries = std::fs::read_dir(dir).map_err(|e| e.to_string())?;
+        for entry in entries {
+            let path = entry.map_err(|e| e.to_string())?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("ftl") {
+                continue;
+            }
+            let Some(locale) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let source = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+            self.add_resource(locale, &source)
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+            tracing::info!("Loaded locale {} from {}", locale, path.display());
+        }
+        Ok(())
+    }
+
+    /// Returns false (and keeps the current default) for unknown locales
+    pub fn set_default_locale(&mut self, locale: &str) -> bool {
+        match normalize_locale(locale).filter(|l| self.locales.contains_key(l)) {
+            Some(locale) => {
+                self.default_locale = locale;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn default_locale(&self) -> &str {
+        &self.default_locale
+    }
+
+    /// Available locales, sorted
+    pub fn locales(&self) -> Vec<String> {
+        let mut locales: Vec<String> = self.locales.keys().cloned().collect();
+        locales.sort();
+        locales
+    }
+
+    /// The available locale `tag` resolves to: itself, or its language
+    /// when only that is available (`es-MX` to `es`)
+    pub fn resolve(&self, tag: &str) -> Option<String> {
+        let locale = normalize_locale(tag)?;
+        if self.locales.contains_key(&locale) {
+            return Some(locale);
+        }
+        let language = primary_language(&locale);
+        self.locales
+            .contains_key(language)
+            .then(|| language.to_string())
+    }
+
+    /// Pick the response locale: a supported user preference wins, then the
+    /// best supported `Accept-Language` entry, then the default locale
+    pub fn negotiate(&self, accept_language: Option<&str>, preferred: Option<&str>) -> String {
+        if let Some(locale) = preferred.and_then(|p| self.resolve(p)) {
+            return locale;
+        }
+        accept_language
+            .map(parse_accept_language)
+            .unwrap_or_default()
+            .into_iter()
+            .find_map(|tag| self.resolve(&tag))
+            .unwrap_or_else(|| self.default_locale.clone())
+    }
+
+    /// Message `id` in `locale`, falling back to English and then to the id
+    pub fn translate(&self, locale: &str, id: &str, args: &[(&str, &str)]) -> String {
+        [locale, primary_language(locale), FALLBACK_LOCALE]
+            .iter()
+            .find_map(|locale| self.locales.get(*locale)?.get(id))
+            .map(|pattern| pattern.format(args))
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    /// Render an English message in `locale` if the English catalog has it
+    pub fn localize(&self, locale: &str, message: &str) -> String {
+        if locale == FALLBACK_LOCALE {
+            return message.to_string();
+        }
+        for (id, pattern) in &self.index {
+            if let Some(captured) = pattern.capture(message) {
+                let args: Vec<(&str, &str)> = captured
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_str()))
+                    .collect();
+                return self.translate(locale, id, &args);
+            }
+        }
+        message.to_string()
+    }
+
+    fn rebuild_index(&mut self) {
+        let mut index: Vec<(String, Pattern)> = self
+            .locales
+            .get(FALLBACK_LOCALE)
+            .map(|messages| {
+                messages
+                    .iter()
+                    .filter(|(_, pattern)| pattern.literal_len() > 0)
+                    .map(|(id, pattern)| (id.clone(), pattern.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        // Longest literal text first, so "is required when snoozing" isn't
+        // taken for "{ $field } is required"
+        index.sort_by(|(a_id, a), (b_id, b)| {
+            b.literal_len()
+                .cmp(&a.literal_len())
+                .then_with(|| a_id.cmp(b_id))
+        });
+        self.index = index;
+    }
+}
+
+/// Messages of a Fluent resource; only plain messages are supported
+fn parse_resource(source: &str) -> Result<HashMap<String, Pattern>, String> {
+    let mut entries: Vec<(String, Vec<String>, usize)> = Vec::new();
+    for (number, line) in source.lines().enumerate() {
+        let number = number + 1;
+        if line.trim().is_empty() {
+            // Blank lines inside a multiline message are kept; trailing
+            // ones are dropped below
+            if let Some((_, lines, _)) = entries.last_mut() {
+                if !lines.is_empty() {
+                    lines.push(String::new());
+                }
+            }
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with([' ', '\t']) {
+            match entries.last_mut() {
+                Some((_, lines, _)) => lines.push(line.trim().to_string()),
+                None => return Err(format!("line {}: continuation without a message", number)),
+            }
+            continue;
+        }
+        let (id, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected 'id = text'", number))?;
+        let id = id.trim();
+        if !is_identifier(id) {
+            return Err(format!("line {}: invalid message id '{}'", number, id));
+        }
+        let value = value.trim();
+        let lines = if value.is_empty() {
+            Vec::new()
+        } else {
+            vec![value.to_string()]
+        };
+        entries.push((id.to_string(), lines, number));
+    }
+
+    let mut messages = HashMap::new();
+    for (id, mut lines, number) in entries {
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        if lines.is_empty() {
+            return Err(format!("line {}: message '{}' has no text", number, id));
+        }
+        let pattern =
+            Pattern::parse(&lines.join("\n")).map_err(|e| format!("line {}: {}", number, e))?;
+        messages.insert(id, pattern);
+    }
+    Ok(messages)
+}
+
+/// Language tags of an `Accept-Language` header, most preferred first
+pub fn parse_accept_language(header: &str) -> Vec<String> {
+    let mut tags: Vec<(String, f32, usize)> = header
+        .split(',')
+        .enumerate()
+        .filter_map(|(position, entry)| {
+            let mut parts = entry.split(';');
+            let tag = parts.next()?.trim();
+            if tag.is_empty() || tag == "*" {
+                return None;
+            }
+            let quality = parts
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .map(|q| q.trim().parse::<f32>().unwrap_or(0.0))
+                .unwrap_or(1.0);
+            (quality > 0.0).then(|| (tag.to_string(), quality, position))
+        })
+        .collect();
+    tags.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.2.cmp(&b.2)));
+    tags.into_iter().map(|(tag, _, _)| tag).collect()
+}
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+/// The process-wide catalog, loaded with [`Catalog::from_env`] on first use
+pub fn catalog() -> &'static Catalog {
+    CATALOG.get_or_init(Catalog::from_env)
+}
+
+/// Run `future` with `locale` as the request locale
+pub async fn with_locale<F: Future>(locale: String, future: F) -> F::Output {
+    REQUEST_LOCALE.scope(locale, future).await
+}
+
+/// Locale of the request being handled, or the default locale outside one
+pub fn current_locale() -> String {
+    REQUEST_LOCALE
+        .try_with(|locale| locale.clone())
+        .unwrap_or_else(|_| catalog().default_locale().to_string())
+}
+
+/// Message `id` in the request locale
+pub fn t(id: &str) -> String {
+    t_args(id, &[])
+}
+
+/// Message `id` in the request locale, with placeholder values
+pub fn t_args(id: &str, args: &[(&str, &str)]) -> String {
+    catalog().translate(&current_locale(), id, args)
+}
+
+/// English `message` in the request locale
+pub fn localize(message: &str) -> String {
+    catalog().localize(&current_locale(), message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate() {
+        let catalog = Catalog::builtin();
+        assert_eq!(
+            catalog.negotiate(Some("es-MX,es;q=0.9,en;q=0.8"), None),
+            "es"
+        );
+        assert_eq!(catalog.negotiate(Some("fr-FR, de;q=0.5"), None), "en");
+        assert_eq!(catalog.negotiate(Some("fr;q=1, es;q=0.4"), None), "es");
+        assert_eq!(catalog.negotiate(Some("es;q=0"), None), "en");
+        assert_eq!(catalog.negotiate(Some("es"), Some("en")), "en");
+        assert_eq!(catalog.negotiate(Some("es"), Some("xx")), "es");
+        assert_eq!(catalog.negotiate(None, None), "en");
+    }
+
+    #[test]
+    fn test_translate_falls_back_to_english() {
+        let mut catalog = Catalog::builtin();
+        catalog
+            .add_resource("en", "only-english = Hello { $name }")
+            .unwrap();
+        assert_eq!(
+            catalog.translate("es", "only-english", &[("name", "Ana")]),
+            "Hello Ana"
+        );
+        assert_eq!(
+            catalog.translate("es", "no-such-message", &[]),
+            "no-such-message"
+        );
+        assert_eq!(
+            catalog.translate("en", "error-unauthorized", &[]),
+            "Unauthorized"
+        );
+        assert_eq!(
+            catalog.translate("es-AR", "error-unauthorized", &[]),
+            "No autorizado"
+        );
+    }
+
+    #[test]
+    fn test_localize_english_message() {
+        let catalog = Catalog::builtin();
+        assert_eq!(
+            catalog.localize("es", "must be at most 50 characters"),
+            "debe tener como máximo 50 caracteres"
+        );
+        assert_eq!(catalog.localize("es", "is required"), "es obligatorio");
+        assert_eq!(
+            catalog.localize("es", "is required when snoozing"),
+            "es obligatorio al posponer"
+        );
+        assert_eq!(
+            catalog.localize("es", "Something nobody translated"),
+            "Something nobody translated"
+        );
+        assert_eq!(catalog.localize("en", "is required"), "is required");
+    }
+
+    #[test]
+    fn test_parse_resource() {
+        let messages = parse_resource(
+            "# comment\nwelcome = Hi { $name },\n\n    welcome back\n\nbrace = { \"{\" }x{ \"}\" }\n",
+        )
+        .unwrap();
+        assert_eq!(
+            messages["welcome"].format(&[("name", "Sam")]),
+            "Hi Sam,\n\nwelcome back"
+        );
+        assert_eq!(messages["brace"].format(&[]), "{x}");
+        assert!(parse_resource("bad id = x").is_err());
+        assert!(parse_resource("x = { $ }").is_err());
+        assert!(parse_resource("  orphan").is_err());
+    }
+
+    #[test]
+    fn test_normalize_locale() {
+        assert_eq!(normalize_locale("pt_br").as_deref(), Some("pt-BR"));
+        assert_eq!(normalize_locale("EN").as_deref(), Some("en"));
+        assert_eq!(normalize_locale("zh-Hant").as_deref(), Some("zh-hant"));
+        assert_eq!(normalize_locale("e"), None);
+        assert_eq!(normalize_locale("en-"), None);
+    }
+}
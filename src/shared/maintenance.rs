@@ -0,0 +1,93 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// In-memory maintenance switch shared by the HTTP layer and background workers
+///
+/// `MaintenanceService` persists the switch and keeps this copy in sync, so the
+/// per-request check is a single atomic load. Workers register running jobs
+/// with `begin_job` so readiness can report when they have drained.
+#[derive(Clone, Default)]
+pub struct MaintenanceMode {
+    enabled: Arc<AtomicBool>,
+    message: Arc<RwLock<Option<String>>>,
+    active_jobs: Arc<AtomicUsize>,
+}
+
+impl MaintenanceMode {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Message shown to users while maintenance is on
+    pub fn message(&self) -> Option<String> {
+        self.message
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    pub fn set(&self, enabled: bool, message: Option<String>) {
+        *self
+            .message
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = message;
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Jobs that started before maintenance and are still running
+    pub fn active_jobs(&self) -> usize {
+        self.active_jobs.load(Ordering::Relaxed)
+    }
+
+    /// Track a running job until the returned guard is dropped
+    pub fn begin_job(&self) -> ActiveJob {
+        self.active_jobs.fetch_add(1, Ordering::Relaxed);
+        ActiveJob {
+            active_jobs: self.active_jobs.clone(),
+        }
+    }
+}
+
+/// Guard returned by `MaintenanceMode::begin_job`
+pub struct ActiveJob {
+    active_jobs: Arc<AtomicUsize>,
+}
+
+impl Drop for ActiveJob {
+    fn drop(&mut self) {
+        self.active_jobs.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_state() {
+        let mode = MaintenanceMode::new();
+        let clone = mode.clone();
+        assert!(!clone.is_enabled());
+
+        mode.set(true, Some("Upgrading".to_string()));
+        assert!(clone.is_enabled());
+        assert_eq!(clone.message().as_deref(), Some("Upgrading"));
+    }
+
+    #[test]
+    fn test_active_jobs_are_counted_until_dropped() {
+        let mode = MaintenanceMode::new();
+        let first = mode.begin_job();
+        let second = mode.clone().begin_job();
+        assert_eq!(mode.active_jobs(), 2);
+
+        drop(first);
+        assert_eq!(mode.active_jobs(), 1);
+        drop(second);
+        assert_eq!(mode.active_jobs(), 0);
+    }
+}
@@ -1,9 +1,16 @@
 pub mod csrf;
 pub mod events;
+pub mod i18n;
+pub mod maintenance;
 pub mod rate_limiter;
+pub mod trusted_proxies;
 pub mod utils;
+pub mod validation;
 
 pub use csrf::*;
 pub use events::*;
+pub use maintenance::*;
 pub use rate_limiter::*;
+pub use trusted_proxies::*;
 pub use utils::*;
+pub use validation::*;
@@ -5,23 +5,30 @@ use governor::{
     Quota, RateLimiter as GovernorRateLimiter,
 };
 use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::{num::NonZeroU32, sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 
+/// How many times the per-email quota a single client address gets, so
+/// guessing across many accounts from one address is slowed down without
+/// locking out offices behind a shared address
+const CLIENT_ATTEMPT_MULTIPLIER: u32 = 10;
+
 /// Rate limiter for authentication attempts
 ///
-/// Tracks failed login attempts per email address using a sliding window.
-/// Default: 5 attempts per 15 minutes.
+/// Tracks failed login attempts per email address, and per client address,
+/// using a sliding window. Default: 5 attempts per 15 minutes per email.
 #[derive(Clone)]
 pub struct AuthRateLimiter {
     /// Map of email -> rate limiter instance
     limiters: Arc<
         RwLock<HashMap<String, Arc<GovernorRateLimiter<NotKeyed, InMemoryState, DefaultClock>>>>,
     >,
-    /// Maximum attempts allowed
-    max_attempts: u32,
+    /// Maximum attempts allowed (shared so clones see `reconfigure`)
+    max_attempts: Arc<AtomicU32>,
     /// Time window in minutes
-    window_minutes: u64,
+    window_minutes: Arc<AtomicU64>,
 }
 
 impl AuthRateLimiter {
@@ -34,28 +41,65 @@ impl AuthRateLimiter {
     pub fn with_config(max_attempts: u32, window_minutes: u64) -> Self {
         Self {
             limiters: Arc::new(RwLock::new(HashMap::new())),
-            max_attempts,
-            window_minutes,
+            max_attempts: Arc::new(AtomicU32::new(max_attempts.max(1))),
+            window_minutes: Arc::new(AtomicU64::new(window_minutes.max(1))),
         }
     }
 
+    /// Change the limits at runtime
+    ///
+    /// Existing per-email state is dropped so the new quota applies immediately.
+    pub async fn reconfigure(&self, max_attempts: u32, window_minutes: u64) {
+        let mut limiters = self.limiters.write().await;
+        self.max_attempts
+            .store(max_attempts.max(1), Ordering::Relaxed);
+        self.window_minutes
+            .store(window_minutes.max(1), Ordering::Relaxed);
+        limiters.clear();
+    }
+
+    /// Current (max_attempts, window_minutes)
+    pub fn limits(&self) -> (u32, u64) {
+        (
+            self.max_attempts.load(Ordering::Relaxed),
+            self.window_minutes.load(Ordering::Relaxed),
+        )
+    }
+
     /// Check if an email is rate limited
     ///
     /// Returns Ok(()) if the request is allowed, Err(duration) if rate limited.
     /// The duration indicates how long to wait before retrying.
     pub async fn check(&self, email: &str) -> Result<(), Duration> {
-        let email = email.to_lowercase();
+        self.check_key(email.to_lowercase(), 1).await
+    }
 
-        // Get or create limiter for this email
+    /// Check if a client address is rate limited
+    ///
+    /// Clients get `CLIENT_ATTEMPT_MULTIPLIER` times the per-email quota.
+    pub async fn check_client(&self, ip: IpAddr) -> Result<(), Duration> {
+        self.check_key(client_key(ip), CLIENT_ATTEMPT_MULTIPLIER)
+            .await
+    }
+
+    /// Record a failed authentication attempt from a client address
+    pub async fn record_client_failure(&self, ip: IpAddr) -> Result<(), Duration> {
+        self.check_client(ip).await
+    }
+
+    async fn check_key(&self, key: String, multiplier: u32) -> Result<(), Duration> {
+        // Get or create limiter for this key
         let limiter = {
             let mut limiters = self.limiters.write().await;
 
             limiters
-                .entry(email.clone())
+                .entry(key)
                 .or_insert_with(|| {
-                    let quota = Quota::with_period(Duration::from_secs(self.window_minutes * 60))
+                    let (max_attempts, window_minutes) = self.limits();
+                    let burst = max_attempts.saturating_mul(multiplier);
+                    let quota = Quota::with_period(Duration::from_secs(window_minutes * 60))
                         .unwrap()
-                        .allow_burst(NonZeroU32::new(self.max_attempts).unwrap());
+                        .allow_burst(NonZeroU32::new(burst).unwrap());
 
                     Arc::new(GovernorRateLimiter::direct(quota))
                 })
@@ -97,11 +141,11 @@ impl AuthRateLimiter {
                 // We just check if the limiter would allow a request
                 drop(limiters);
                 match self.check(&email).await {
-                    Ok(_) => self.max_attempts,
+                    Ok(_) => self.limits().0,
                     Err(_) => 0,
                 }
             }
-            None => self.max_attempts,
+            None => self.limits().0,
         }
     }
 
@@ -122,6 +166,11 @@ impl AuthRateLimiter {
     }
 }
 
+/// Limiter key of a client address, kept apart from email keys
+fn client_key(ip: IpAddr) -> String {
+    format!("client:{}", ip)
+}
+
 impl Default for AuthRateLimiter {
     fn default() -> Self {
         Self::new()
@@ -177,6 +226,22 @@ mod tests {
         assert!(limiter.record_failure(email).await.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_rate_limiter_reconfigure() {
+        let limiter = AuthRateLimiter::with_config(1, 1);
+        let email = "test@example.com";
+        assert!(limiter.record_failure(email).await.is_ok());
+        assert!(limiter.record_failure(email).await.is_err());
+
+        // Clones share the new limits, and existing state is dropped
+        limiter.clone().reconfigure(3, 5).await;
+        assert_eq!(limiter.limits(), (3, 5));
+        assert!(limiter.record_failure(email).await.is_ok());
+        assert!(limiter.record_failure(email).await.is_ok());
+        assert!(limiter.record_failure(email).await.is_ok());
+        assert!(limiter.record_failure(email).await.is_err());
+    }
+
     #[tokio::test]
     async fn test_rate_limiter_case_insensitive() {
         let limiter = AuthRateLimiter::with_config(2, 1);
@@ -201,6 +266,23 @@ mod tests {
         assert!(limiter.record_failure("user2@example.com").await.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_rate_limiter_client_quota() {
+        let limiter = AuthRateLimiter::with_config(1, 1);
+        let ip: IpAddr = "198.51.100.7".parse().unwrap();
+
+        // Clients get a larger quota, kept apart from email limits
+        for _ in 0..CLIENT_ATTEMPT_MULTIPLIER {
+            assert!(limiter.record_client_failure(ip).await.is_ok());
+        }
+        assert!(limiter.record_client_failure(ip).await.is_err());
+        assert!(limiter
+            .record_client_failure("198.51.100.8".parse().unwrap())
+            .await
+            .is_ok());
+        assert!(limiter.record_failure("user@example.com").await.is_ok());
+    }
+
     #[tokio::test]
     async fn test_cleanup_removes_expired_limiters() {
         let limiter = AuthRateLimiter::with_config(1, 1);
@@ -0,0 +1,283 @@
+use axum::http::HeaderMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+/// An IP network in CIDR notation (`10.0.0.0/8`, `::1/128`); a bare address
+/// is a single-host network
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpCidr {
+    network: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpCidr {
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let (address, prefix_len) = match value.split_once('/') {
+            Some((address, prefix_len)) => (address, Some(prefix_len.parse::<u8>().ok()?)),
+            None => (value, None),
+        };
+        let network: IpAddr = address.parse().ok()?;
+        let max_len = if network.is_ipv4() { 32 } else { 128 };
+        let prefix_len = prefix_len.unwrap_or(max_len);
+        if prefix_len > max_len {
+            return None;
+        }
+
+        Some(Self {
+            network,
+            prefix_len,
+        })
+    }
+
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, canonical_ip(ip)) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - self.prefix_len as u32)
+                    .unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Reverse proxies allowed to report the client address
+///
+/// Forwarding headers are only believed when the connection comes from one of
+/// these networks. With none configured, the peer address is always used.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies {
+    networks: Arc<Vec<IpCidr>>,
+}
+
+impl TrustedProxies {
+    pub fn new(networks: Vec<IpCidr>) -> Self {
+        Self {
+            networks: Arc::new(networks),
+        }
+    }
+
+    /// Comma-separated CIDRs from `TRUSTED_PROXIES`; invalid entries are
+    /// logged and skipped
+    pub fn from_env() -> Self {
+        let value = std::env::var("TRUSTED_PROXIES").unwrap_or_default();
+        let networks = value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .filter_map(|entry| {
+                let network = IpCidr::parse(entry);
+                if network.is_none() {
+                    tracing::warn!("Ignoring invalid TRUSTED_PROXIES entry: {}", entry);
+                }
+                network
+            })
+            .collect();
+        Self::new(networks)
+    }
+
+    pub fn is_trusted(&self, ip: IpAddr) -> bool {
+        self.networks.iter().any(|network| network.contains(ip))
+    }
+
+    /// Real client address of a request arriving from `peer`
+    ///
+    /// When the peer is a trusted proxy, the `Forwarded` chain (or
+    /// `X-Forwarded-For`, then `X-Real-IP`) is walked from the nearest hop
+    /// back, and the first address that is not itself a trusted proxy wins.
+    /// Without a known peer nothing can be trusted and `None` is returned.
+    pub fn client_ip(&self, peer: Option<SocketAddr>, headers: &HeaderMap) -> Option<IpAddr> {
+        let mut client = canonical_ip(peer?.ip());
+        if !self.is_trusted(client) {
+            return Some(client);
+        }
+
+        for hop in forwarded_chain(headers).into_iter().rev() {
+            // Obfuscated or malformed hops end the chain at the last proxy
+            let Some(hop) = hop else {
+                break;
+            };
+            client = hop;
+            if !self.is_trusted(hop) {
+                break;
+            }
+        }
+        Some(client)
+    }
+}
+
+/// Addresses in the forwarding headers, client first
+///
+/// `None` marks hops that are hidden (`for=unknown`) or unparseable.
+fn forwarded_chain(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    let header_values = |name: &str| -> Vec<String> {
+        headers
+            .get_all(name)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect()
+    };
+
+    let forwarded = header_values("forwarded");
+    if !forwarded.is_empty() {
+        return forwarded
+            .iter()
+            .map(|element| {
+                element.split(';').find_map(|pair| {
+                    let (key, value) = pair.split_once('=')?;
+                    key.trim()
+                        .eq_ignore_ascii_case("for")
+                        .then(|| parse_node(value))
+                })?
+            })
+            .collect();
+    }
+
+    let forwarded_for = header_values("x-forwarded-for");
+    if !forwarded_for.is_empty() {
+        return forwarded_for
+            .iter()
+            .map(|value| parse_node(value))
+            .collect();
+    }
+
+    header_values("x-real-ip")
+        .iter()
+        .map(|value| parse_node(value))
+        .collect()
+}
+
+/// Parse `192.0.2.1`, `192.0.2.1:443`, `"[2001:db8::1]:443"` or `2001:db8::1`
+fn parse_node(value: &str) -> Option<IpAddr> {
+    let value = value.trim().trim_matches('"');
+    if let Ok(ip) = value.parse::<IpAddr>() {
+        return Some(canonical_ip(ip));
+    }
+    if let Ok(addr) = value.parse::<SocketAddr>() {
+        return Some(canonical_ip(addr.ip()));
+    }
+    value
+        .strip_prefix('[')
+        .and_then(|rest| rest.split_once(']'))
+        .and_then(|(ip, _)| ip.parse::<IpAddr>().ok())
+        .map(canonical_ip)
+}
+
+/// IPv4-mapped IPv6 addresses (`::ffff:10.0.0.1`) as plain IPv4
+fn canonical_ip(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => v6
+            .to_ipv4_mapped()
+            .map(IpAddr::V4)
+            .unwrap_or(IpAddr::V6(v6)),
+        v4 => v4,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proxies(networks: &[&str]) -> TrustedProxies {
+        TrustedProxies::new(
+            networks
+                .iter()
+                .map(|network| IpCidr::parse(network).unwrap())
+                .collect(),
+        )
+    }
+
+    fn headers(pairs: &[(&str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(
+                axum::http::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                value.parse().unwrap(),
+            );
+        }
+        headers
+    }
+
+    fn peer(ip: &str) -> Option<SocketAddr> {
+        Some(SocketAddr::new(ip.parse().unwrap(), 40000))
+    }
+
+    #[test]
+    fn test_cidr_matching() {
+        let network = IpCidr::parse("10.0.0.0/8").unwrap();
+        assert!(network.contains("10.20.30.40".parse().unwrap()));
+        assert!(network.contains("::ffff:10.1.1.1".parse().unwrap()));
+        assert!(!network.contains("11.0.0.1".parse().unwrap()));
+
+        let host = IpCidr::parse("2001:db8::1").unwrap();
+        assert!(host.contains("2001:db8::1".parse().unwrap()));
+        assert!(!host.contains("2001:db8::2".parse().unwrap()));
+        assert!(IpCidr::parse("0.0.0.0/0")
+            .unwrap()
+            .contains("203.0.113.9".parse().unwrap()));
+
+        assert_eq!(IpCidr::parse("10.0.0.0/33"), None);
+        assert_eq!(IpCidr::parse("not-an-ip"), None);
+    }
+
+    #[test]
+    fn test_untrusted_peer_ignores_forwarding_headers() {
+        let headers = headers(&[("x-forwarded-for", "198.51.100.7")]);
+        assert_eq!(
+            proxies(&[]).client_ip(peer("203.0.113.9"), &headers),
+            Some("203.0.113.9".parse().unwrap())
+        );
+        assert_eq!(proxies(&["10.0.0.0/8"]).client_ip(None, &headers), None);
+    }
+
+    #[test]
+    fn test_forwarded_for_walks_back_past_trusted_hops() {
+        let proxies = proxies(&["10.0.0.0/8"]);
+        // A spoofed leftmost entry is ignored; the first untrusted hop wins
+        let forwarded_for = headers(&[("x-forwarded-for", "1.2.3.4, 198.51.100.7, 10.0.0.2")]);
+        assert_eq!(
+            proxies.client_ip(peer("10.0.0.1"), &forwarded_for),
+            Some("198.51.100.7".parse().unwrap())
+        );
+
+        let real_ip = headers(&[("x-real-ip", "198.51.100.8")]);
+        assert_eq!(
+            proxies.client_ip(peer("10.0.0.1"), &real_ip),
+            Some("198.51.100.8".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_forwarded_header_takes_precedence() {
+        let proxies = proxies(&["10.0.0.0/8"]);
+        let forwarded = headers(&[
+            (
+                "forwarded",
+                "for=\"[2001:db8:cafe::17]:4711\";proto=https, for=10.0.0.3",
+            ),
+            ("x-forwarded-for", "198.51.100.7"),
+        ]);
+        assert_eq!(
+            proxies.client_ip(peer("10.0.0.1"), &forwarded),
+            Some("2001:db8:cafe::17".parse().unwrap())
+        );
+
+        // Hidden hops stop the walk at the last known proxy
+        let hidden = headers(&[("forwarded", "for=unknown, for=10.0.0.3")]);
+        assert_eq!(
+            proxies.client_ip(peer("10.0.0.1"), &hidden),
+            Some("10.0.0.3".parse().unwrap())
+        );
+    }
+}
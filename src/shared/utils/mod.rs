@@ -29,6 +29,14 @@ pub fn generate_reset_token() -> String {
         .collect()
 }
 
+/// Generate a random 256-bit secret, hex-encoded (64 characters)
+///
+/// Used for webhook secrets and link-signing keys.
+pub fn generate_secret() -> String {
+    let bytes: [u8; 32] = rand::thread_rng().gen();
+    hex::encode(bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,6 +66,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_secret_is_hex() {
+        let secret = generate_secret();
+        assert_eq!(secret.len(), 64);
+        assert!(secret.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(secret, generate_secret());
+    }
+
     #[test]
     fn test_generate_reset_token_no_special_chars() {
         let token = generate_reset_token();
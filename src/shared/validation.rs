@@ -0,0 +1,303 @@
+//! Field-level validation of request bodies
+//!
+//! Request DTOs implement [`Validate`] to list every constraint a body breaks;
+//! the `ValidatedJson` extractor runs it before the handler and answers
+//! `422 Unprocessable Entity` with one entry per failed field.
+use serde::Serialize;
+use std::fmt;
+
+/// A constraint a request field failed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    /// Path of the field in the body, e.g. `name` or `actions[1].action_type`
+    pub field: String,
+    pub message: String,
+}
+
+/// Every field error found in a request body
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct ValidationErrors {
+    errors: Vec<FieldError>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Single-field error
+    pub fn field(field: impl Into<String>, message: impl Into<String>) -> Self {
+        let mut errors = Self::new();
+        errors.add(field, message);
+        errors
+    }
+
+    pub fn add(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            field: field.into(),
+            message: message.into(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    /// Messages recorded for `field`
+    pub fn messages_for(&self, field: &str) -> Vec<&str> {
+        self.errors
+            .iter()
+            .filter(|error| error.field == field)
+            .map(|error| error.message.as_str())
+            .collect()
+    }
+
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Validate a nested value, prefixing its field paths with `prefix`
+    pub fn nested<T: Validate + ?Sized>(&mut self, prefix: &str, value: &T) {
+        let mut nested = ValidationErrors::new();
+        value.validate_fields(&mut nested);
+        for error in nested.errors {
+            let field = if error.field.is_empty() {
+                prefix.to_string()
+            } else {
+                format!("{}.{}", prefix, error.field)
+            };
+            self.add(field, error.message);
+        }
+    }
+
+    /// Required text of `min..=max` characters (surrounding whitespace ignored)
+    pub fn length(&mut self, field: &str, value: &str, min: usize, max: usize) {
+        let length = value.trim().chars().count();
+        if length == 0 {
+            self.add(field, "is required");
+        } else if length > max {
+            self.add(field, format!("must be at most {} characters", max));
+        } else if length < min {
+            self.add(field, format!("must be at least {} characters", min));
+        }
+    }
+
+    /// Like [`length`](Self::length), for fields that may be left out
+    pub fn optional_length(&mut self, field: &str, value: Option<&str>, min: usize, max: usize) {
+        if let Some(value) = value {
+            self.length(field, value, min, max);
+        }
+    }
+
+    /// Free text that may be empty but not longer than `max` characters
+    pub fn max_length(&mut self, field: &str, value: Option<&str>, max: usize) {
+        if value.is_some_and(|value| value.chars().count() > max) {
+            self.add(field, format!("must be at most {} characters", max));
+        }
+    }
+
+    pub fn email(&mut self, field: &str, value: &str) {
+        if !email_address::EmailAddress::is_valid(value.trim()) {
+            self.add(field, "must be a valid email address");
+        }
+    }
+
+    /// Absolute `http://` or `https://` URL of at most `max` characters
+    pub fn http_url(&mut self, field: &str, value: &str, max: usize) {
+        let value = value.trim();
+        if value.is_empty() {
+            self.add(field, "is required");
+        } else if value.len() > max {
+            self.add(field, format!("must be at most {} characters", max));
+        } else if !is_http_url(value) {
+            self.add(field, "must be an HTTP or HTTPS URL");
+        }
+    }
+
+    /// Colour in `#RRGGBB` form
+    pub fn hex_color(&mut self, field: &str, value: &str) {
+        let valid = value.len() == 7
+            && value.starts_with('#')
+            && value[1..].chars().all(|c| c.is_ascii_hexdigit());
+        if !valid {
+            self.add(field, "must be a hex colour (#RRGGBB)");
+        }
+    }
+
+    pub fn one_of(&mut self, field: &str, value: &str, allowed: &[&str]) {
+        if !allowed.contains(&value) {
+            self.add(field, format!("must be one of: {}", allowed.join(", ")));
+        }
+    }
+
+    pub fn range<T>(&mut self, field: &str, value: T, min: T, max: T)
+    where
+        T: PartialOrd + fmt::Display,
+    {
+        if value < min || value > max {
+            self.add(field, format!("must be between {} and {}", min, max));
+        }
+    }
+
+    /// List of at least `min` entries, each 1 to `max_item_length` characters
+    pub fn items(&mut self, field: &str, values: &[String], min: usize, max_item_length: usize) {
+        if values.len() < min {
+            if min == 1 {
+                self.add(field, "must not be empty");
+            } else {
+                self.add(field, format!("must have at least {} entries", min));
+            }
+        }
+        for (index, value) in values.iter().enumerate() {
+            self.length(&format!("{}[{}]", field, index), value, 1, max_item_length);
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self
+            .errors
+            .iter()
+            .map(|error| format!("{} {}", error.field, error.message))
+            .collect();
+        write!(f, "{}", messages.join("; "))
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// Field constraints of a request body
+pub trait Validate {
+    /// Record every constraint the value breaks in `errors`
+    fn validate_fields(&self, errors: &mut ValidationErrors);
+
+    /// Check the value, returning all field errors at once
+    fn check(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        self.validate_fields(&mut errors);
+        errors.into_result()
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_fields(&self, errors: &mut ValidationErrors) {
+        if let Some(value) = self {
+            value.validate_fields(errors);
+        }
+    }
+}
+
+fn is_http_url(value: &str) -> bool {
+    let rest = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"));
+    rest.is_some_and(|rest| {
+        let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+        !host.is_empty() && !host.contains(char::is_whitespace)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Item {
+        name: String,
+    }
+
+    impl Validate for Item {
+        fn validate_fields(&self, errors: &mut ValidationErrors) {
+            errors.length("name", &self.name, 1, 5);
+        }
+    }
+
+    #[test]
+    fn test_length() {
+        let mut errors = ValidationErrors::new();
+        errors.length("a", "  ", 1, 10);
+        errors.length("b", "abcdef", 1, 5);
+        errors.length("c", "ab", 3, 5);
+        errors.length("d", " ok ", 1, 2);
+
+        assert_eq!(errors.messages_for("a"), vec!["is required"]);
+        assert_eq!(
+            errors.messages_for("b"),
+            vec!["must be at most 5 characters"]
+        );
+        assert_eq!(
+            errors.messages_for("c"),
+            vec!["must be at least 3 characters"]
+        );
+        assert!(errors.messages_for("d").is_empty());
+    }
+
+    #[test]
+    fn test_formats() {
+        let mut errors = ValidationErrors::new();
+        errors.email("email", "not-an-email");
+        errors.http_url("url", "ftp://example.com", 2048);
+        errors.http_url("url_ok", "https://example.com/hook?x=1", 2048);
+        errors.hex_color("color", "#12345G");
+        errors.hex_color("color_ok", "#A1b2C3");
+        errors.one_of("mode", "sometimes", &["all", "restricted"]);
+
+        let fields: Vec<&str> = errors.errors().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["email", "url", "color", "mode"]);
+        assert_eq!(
+            errors.messages_for("mode"),
+            vec!["must be one of: all, restricted"]
+        );
+    }
+
+    #[test]
+    fn test_items_and_nested_paths() {
+        let mut errors = ValidationErrors::new();
+        errors.items("events", &["ok".to_string(), "".to_string()], 1, 10);
+        errors.nested(
+            "items[2]",
+            &Item {
+                name: "toolong".to_string(),
+            },
+        );
+
+        assert_eq!(errors.messages_for("events[1]"), vec!["is required"]);
+        assert_eq!(
+            errors.messages_for("items[2].name"),
+            vec!["must be at most 5 characters"]
+        );
+
+        let mut errors = ValidationErrors::new();
+        errors.items("events", &[], 1, 10);
+        assert_eq!(errors.messages_for("events"), vec!["must not be empty"]);
+    }
+
+    #[test]
+    fn test_check_collects_all_errors() {
+        assert!(Item {
+            name: "ok".to_string()
+        }
+        .check()
+        .is_ok());
+
+        let errors = Item {
+            name: String::new(),
+        }
+        .check()
+        .unwrap_err();
+        assert_eq!(errors.to_string(), "name is required");
+        assert_eq!(
+            serde_json::to_value(&errors).unwrap(),
+            serde_json::json!([{ "field": "name", "message": "is required" }])
+        );
+    }
+}
@@ -1,8 +1,8 @@
 #![allow(dead_code)]
 use chrono::{DateTime, Utc};
 use oxidesk::{
-    infrastructure::persistence::Database,
     domain::entities::{Agent, AgentAvailability},
+    infrastructure::persistence::Database,
 };
 use sqlx::Row;
 
@@ -1,12 +1,12 @@
 #![allow(dead_code)]
-use oxidesk::infrastructure::http::middleware::AuthenticatedUser;
-use oxidesk::infrastructure::persistence::Database;
-use oxidesk::domain::ports::agent_repository::AgentRepository;
-use oxidesk::domain::ports::contact_repository::ContactRepository;
-use oxidesk::domain::ports::user_repository::UserRepository;
 use oxidesk::domain::entities::conversation::{Conversation, ConversationStatus};
 use oxidesk::domain::entities::{Agent, Role};
 use oxidesk::domain::entities::{Contact, User, UserType};
+use oxidesk::domain::ports::agent_repository::AgentRepository;
+use oxidesk::domain::ports::contact_repository::ContactRepository;
+use oxidesk::domain::ports::user_repository::UserRepository;
+use oxidesk::infrastructure::http::middleware::AuthenticatedUser;
+use oxidesk::infrastructure::persistence::Database;
 use oxidesk::shared::utils::email_validator::validate_and_normalize_email;
 use sqlx::Row;
 use uuid::Uuid;
@@ -129,6 +129,11 @@ pub async fn create_test_conversation(
         version: row.try_get("version").unwrap(),
         tags: None,
         priority: None,
+        contact_email_verified: None,
+        csat_score: None,
+        sentiment_score: None,
+        sentiment_trend: None,
+        summary: None,
     }
 }
 
@@ -193,6 +198,11 @@ pub async fn create_snoozed_conversation(
         version: row.try_get("version").unwrap(),
         tags: None,
         priority: None,
+        contact_email_verified: None,
+        csat_score: None,
+        sentiment_score: None,
+        sentiment_trend: None,
+        summary: None,
     }
 }
 
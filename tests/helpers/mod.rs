@@ -1,6 +1,7 @@
 #![allow(unused_imports)]
 pub mod availability_helpers;
 pub mod conversation_helpers;
+pub mod password_reset_helpers;
 pub mod rbac_helpers;
 pub mod sla_helpers;
 pub mod tag_helpers;
@@ -8,6 +9,7 @@ pub mod test_db;
 
 pub use availability_helpers::*;
 pub use conversation_helpers::*;
+pub use password_reset_helpers::*;
 pub use sla_helpers::*;
 pub use tag_helpers::*;
 pub use test_db::*;
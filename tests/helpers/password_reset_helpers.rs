@@ -0,0 +1,87 @@
+#![allow(dead_code)]
+use async_trait::async_trait;
+use oxidesk::application::services::PasswordResetService;
+use oxidesk::domain::ports::email_sender::{EmailSender, OutgoingEmail};
+use oxidesk::domain::ports::password_reset_repository::PasswordResetRepository;
+use oxidesk::infrastructure::persistence::Database;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Email sender that hands every message to a `Mailbox` instead of delivering it
+pub struct RecordingEmailSender {
+    tx: mpsc::UnboundedSender<OutgoingEmail>,
+}
+
+#[async_trait]
+impl EmailSender for RecordingEmailSender {
+    async fn send(&self, email: &OutgoingEmail) -> Result<(), String> {
+        self.tx.send(email.clone()).map_err(|e| e.to_string())
+    }
+}
+
+/// Emails captured by a `RecordingEmailSender`
+pub struct Mailbox {
+    rx: mpsc::UnboundedReceiver<OutgoingEmail>,
+}
+
+impl Mailbox {
+    /// Wait for the next email; reset emails are sent in the background
+    pub async fn next_email(&mut self) -> OutgoingEmail {
+        tokio::time::timeout(Duration::from_secs(5), self.rx.recv())
+            .await
+            .expect("Timed out waiting for email")
+            .expect("Email sender dropped")
+    }
+
+    /// Wait for the next reset email and return the plaintext token from its link
+    pub async fn next_token(&mut self) -> String {
+        let email = self.next_email().await;
+        extract_reset_token(&email.text_body).expect("Reset email has no token")
+    }
+
+    /// Whether an email arrives within a short grace period
+    pub async fn received_email(&mut self) -> bool {
+        tokio::time::timeout(Duration::from_millis(200), self.rx.recv())
+            .await
+            .map(|email| email.is_some())
+            .unwrap_or(false)
+    }
+}
+
+/// Pull the `token=` query parameter out of an email body
+pub fn extract_reset_token(body: &str) -> Option<String> {
+    let start = body.find("token=")? + "token=".len();
+    let token: String = body[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect();
+    (!token.is_empty()).then_some(token)
+}
+
+/// Email sender whose messages are captured in the returned mailbox
+pub fn recording_email_sender() -> (Arc<dyn EmailSender>, Mailbox) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    (Arc::new(RecordingEmailSender { tx }), Mailbox { rx })
+}
+
+/// Password reset service whose emails are captured in the returned mailbox
+pub fn password_reset_service_with_mailbox(db: &Database) -> (PasswordResetService, Mailbox) {
+    let (email_sender, mailbox) = recording_email_sender();
+    let mut service = PasswordResetService::new(
+        PasswordResetRepository::new(db.clone()),
+        Arc::new(db.clone()),
+    );
+    service.set_email_sender(email_sender);
+    (service, mailbox)
+}
+
+/// Request a reset for `email` and return the plaintext token that was emailed
+pub async fn request_reset_token(db: &Database, email: &str) -> String {
+    let (service, mut mailbox) = password_reset_service_with_mailbox(db);
+    service
+        .request_password_reset(email)
+        .await
+        .expect("Failed to request password reset");
+    mailbox.next_token().await
+}
@@ -1,9 +1,9 @@
 #![allow(dead_code)]
-use oxidesk::infrastructure::http::middleware::AuthenticatedUser;
-use oxidesk::infrastructure::persistence::Database;
+use oxidesk::domain::entities::{Agent, Role, User, UserType};
 use oxidesk::domain::ports::agent_repository::AgentRepository;
 use oxidesk::domain::ports::user_repository::UserRepository;
-use oxidesk::domain::entities::{Agent, Role, User, UserType};
+use oxidesk::infrastructure::http::middleware::AuthenticatedUser;
+use oxidesk::infrastructure::persistence::Database;
 use sqlx::Row;
 use uuid::Uuid;
 
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
-use oxidesk::infrastructure::persistence::Database;
 use oxidesk::domain::entities::Tag;
+use oxidesk::infrastructure::persistence::Database;
 
 /// Create a test tag
 pub async fn create_test_tag(
@@ -36,6 +36,41 @@ impl Drop for TestDatabase {
 }
 
 pub async fn setup_test_db() -> TestDatabase {
+    let test_db = connect_test_db().await;
+
+    // Run migrations from migrations/sqlite directory
+    run_migrations(&test_db.db).await;
+
+    test_db
+}
+
+/// Test database migrated only up to and including `version`, for seeding data
+/// that a later migration must carry over. Call `run_migrations` to finish.
+pub async fn setup_test_db_at_version(version: i64) -> TestDatabase {
+    let test_db = connect_test_db().await;
+
+    let migrator = sqlx::migrate!("./migrations/sqlite");
+    let partial = sqlx::migrate::Migrator {
+        migrations: std::borrow::Cow::Owned(
+            migrator
+                .migrations
+                .iter()
+                .filter(|migration| migration.version <= version)
+                .cloned()
+                .collect(),
+        ),
+        ignore_missing: false,
+        locking: true,
+    };
+    partial
+        .run(test_db.db.pool())
+        .await
+        .expect("Failed to run migrations");
+
+    test_db
+}
+
+async fn connect_test_db() -> TestDatabase {
     // Install drivers for AnyPool (required for tests)
     sqlx::any::install_default_drivers();
 
@@ -61,13 +96,10 @@ pub async fn setup_test_db() -> TestDatabase {
         .await
         .expect("Failed to connect to test database");
 
-    // Run migrations from migrations/sqlite directory
-    run_migrations(&db).await;
-
     TestDatabase { db, db_file }
 }
 
-async fn run_migrations(db: &Database) {
+pub async fn run_migrations(db: &Database) {
     let pool = db.pool();
 
     // Run all SQLite migrations
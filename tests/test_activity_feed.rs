@@ -0,0 +1,213 @@
+// Integration tests for the admin activity feed
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use oxidesk::application::services::ActivityService;
+use oxidesk::domain::entities::{ActivityType, ConversationStatus, SlaEventType};
+use oxidesk::domain::errors::ActivityError;
+use oxidesk::infrastructure::persistence::Database;
+
+mod helpers;
+use helpers::rbac_helpers::{create_auth_user_with_roles, ensure_admin_role};
+use helpers::*;
+
+fn service(db: &Database) -> ActivityService {
+    ActivityService::new(Arc::new(db.clone()))
+}
+
+fn minutes_ago(minutes: i64) -> String {
+    (Utc::now() - Duration::minutes(minutes)).to_rfc3339()
+}
+
+async fn conversation_created_at(db: &Database, minutes: i64) -> String {
+    let contact = create_test_contact(db, &format!("customer{}@example.com", minutes)).await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    sqlx::query("UPDATE conversations SET created_at = ? WHERE id = ?")
+        .bind(minutes_ago(minutes))
+        .bind(&conversation.id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    conversation.id
+}
+
+/// One of each activity type: conversation 40 min ago, SLA breach 30, rule run 20, webhook failure 10
+async fn seed_activity(db: &Database, user_id: &str) -> String {
+    let conversation_id = conversation_created_at(db, 40).await;
+
+    let policy = create_test_sla_policy(db, "Standard", "1h", "24h", "4h").await;
+    let applied = create_test_applied_sla(
+        db,
+        &conversation_id,
+        &policy.id,
+        Utc::now() - Duration::minutes(30),
+        Utc::now() + Duration::hours(24),
+    )
+    .await;
+    let event = create_test_sla_event(
+        db,
+        &applied.id,
+        SlaEventType::FirstResponse,
+        Utc::now() - Duration::minutes(30),
+    )
+    .await;
+    sqlx::query("UPDATE sla_events SET status = 'breached', breached_at = ? WHERE id = ?")
+        .bind(minutes_ago(30))
+        .bind(&event.id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+
+    let now = Utc::now().to_rfc3339();
+    sqlx::query(
+        "INSERT INTO automation_rules (id, name, rule_type, event_subscription, condition, action, created_at, updated_at)
+         VALUES ('rule-1', 'Escalate VIPs', 'conversation_update', '[]', '{}', '{}', ?, ?)",
+    )
+    .bind(&now)
+    .bind(&now)
+    .execute(db.pool())
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO rule_evaluation_logs (id, rule_id, rule_name, event_type, conversation_id, matched,
+             condition_result, action_executed, action_result, evaluation_time_ms, evaluated_at)
+         VALUES ('log-1', 'rule-1', 'Escalate VIPs', 'conversation.created', ?, 1, 'true', 1, 'success', 3, ?),
+                ('log-2', 'rule-1', 'Escalate VIPs', 'conversation.created', ?, 0, 'false', 0, 'skipped', 2, ?)",
+    )
+    .bind(&conversation_id)
+    .bind(minutes_ago(20))
+    .bind(&conversation_id)
+    .bind(minutes_ago(15))
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    sqlx::query(
+        "INSERT INTO webhooks (id, name, url, subscribed_events, secret, created_at, updated_at, created_by)
+         VALUES ('hook-1', 'CRM sync', 'https://crm.example.com/hook', '[]', 'secret', ?, ?, ?)",
+    )
+    .bind(&now)
+    .bind(&now)
+    .bind(user_id)
+    .execute(db.pool())
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO webhook_deliveries (id, webhook_id, event_type, payload, signature, status,
+             http_status_code, retry_count, attempted_at, completed_at, error_message)
+         VALUES ('delivery-1', 'hook-1', 'conversation.created', '{}', 'sha256=x', 'failed', 500, 5, ?, ?, 'HTTP 500'),
+                ('delivery-2', 'hook-1', 'conversation.created', '{}', 'sha256=x', 'success', 200, 0, ?, ?, NULL)",
+    )
+    .bind(minutes_ago(10))
+    .bind(minutes_ago(10))
+    .bind(minutes_ago(5))
+    .bind(minutes_ago(5))
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    conversation_id
+}
+
+#[tokio::test]
+async fn test_feed_combines_activity_newest_first() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin_role = ensure_admin_role(db).await;
+    let admin =
+        create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![admin_role]).await;
+    let conversation_id = seed_activity(db, &admin.user.id).await;
+
+    let feed = service(db)
+        .list_activity(&admin, None, None, 50)
+        .await
+        .unwrap();
+    let types: Vec<ActivityType> = feed.items.iter().map(|i| i.activity_type).collect();
+    assert_eq!(
+        types,
+        vec![
+            ActivityType::WebhookFailed,
+            ActivityType::RuleExecuted,
+            ActivityType::SlaBreached,
+            ActivityType::ConversationCreated,
+        ]
+    );
+    assert_eq!(feed.next_before, None);
+
+    assert_eq!(
+        feed.items[0].summary,
+        "Webhook CRM sync (conversation.created) delivery failed: HTTP 500"
+    );
+    assert_eq!(feed.items[0].conversation_id, None);
+    assert_eq!(
+        feed.items[1].summary,
+        "Automation rule 'Escalate VIPs' ran: success"
+    );
+    assert_eq!(
+        feed.items[2].summary,
+        "SLA first response target breached (Standard)"
+    );
+    assert_eq!(
+        feed.items[3].conversation_id.as_deref(),
+        Some(conversation_id.as_str())
+    );
+
+    let json = serde_json::to_value(&feed).unwrap();
+    assert_eq!(json["items"][0]["type"], "webhook_failed");
+}
+
+#[tokio::test]
+async fn test_feed_filters_by_type_and_paginates() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin_role = ensure_admin_role(db).await;
+    let admin =
+        create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![admin_role]).await;
+    seed_activity(db, &admin.user.id).await;
+    let service = service(db);
+
+    let feed = service
+        .list_activity(&admin, Some("sla_breached, webhook_failed"), None, 50)
+        .await
+        .unwrap();
+    let types: Vec<ActivityType> = feed.items.iter().map(|i| i.activity_type).collect();
+    assert_eq!(
+        types,
+        vec![ActivityType::WebhookFailed, ActivityType::SlaBreached]
+    );
+
+    let first = service.list_activity(&admin, None, None, 3).await.unwrap();
+    assert_eq!(first.items.len(), 3);
+    let before = first.next_before.expect("a full page has a cursor");
+    let second = service
+        .list_activity(&admin, None, Some(&before), 3)
+        .await
+        .unwrap();
+    assert_eq!(second.items.len(), 1);
+    assert_eq!(
+        second.items[0].activity_type,
+        ActivityType::ConversationCreated
+    );
+    assert_eq!(second.next_before, None);
+
+    let result = service
+        .list_activity(&admin, Some("conversation_created,logins"), None, 50)
+        .await;
+    assert!(matches!(result, Err(ActivityError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_feed_requires_admin() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_auth_user_with_roles(db, "agent@example.com", "Agent", vec![]).await;
+
+    let result = service(db).list_activity(&agent, None, None, 50).await;
+    assert!(matches!(result, Err(ActivityError::Forbidden(_))));
+}
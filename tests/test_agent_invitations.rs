@@ -0,0 +1,183 @@
+// Integration tests for bulk agent invitations and invitation acceptance
+use oxidesk::{
+    application::services::auth::verify_password,
+    application::services::*,
+    domain::entities::*,
+    domain::ports::{
+        agent_repository::AgentRepository, role_repository::RoleRepository,
+        team_repository::TeamRepository, user_repository::UserRepository,
+    },
+    infrastructure::http::middleware::ApiError,
+    infrastructure::persistence::Database,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::{create_auth_user_with_roles, create_test_team};
+use helpers::*;
+
+const ADMIN_ROLE_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+fn onboarding_service(db: &Database) -> (AgentOnboardingService, Mailbox) {
+    let agent_service = AgentService::new(
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        SessionService::new(Arc::new(db.clone())),
+    );
+    let (password_reset_service, mailbox) = password_reset_service_with_mailbox(db);
+    let service = AgentOnboardingService::new(
+        agent_service,
+        TeamService::new(Arc::new(db.clone())),
+        password_reset_service,
+    );
+    (service, mailbox)
+}
+
+fn bulk_request(
+    emails: &[&str],
+    role_id: Option<&str>,
+    team_id: Option<&str>,
+) -> BulkCreateAgentsRequest {
+    BulkCreateAgentsRequest {
+        emails: emails.iter().map(|e| e.to_string()).collect(),
+        role_id: role_id.map(str::to_string),
+        team_id: team_id.map(str::to_string),
+    }
+}
+
+#[tokio::test]
+async fn test_bulk_invite_reports_each_row_and_accepts_invitation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let team = create_test_team(db, "Support").await;
+    create_test_agent(db, "existing@example.com", "Existing").await;
+
+    let (service, mut mailbox) = onboarding_service(db);
+    let response = service
+        .bulk_invite_agents(
+            &admin,
+            bulk_request(
+                &[
+                    "New.Agent@Example.com",
+                    "not-an-email",
+                    "existing@example.com",
+                    "new.agent@example.com",
+                    "second@example.com",
+                ],
+                None,
+                Some(&team),
+            ),
+        )
+        .await
+        .unwrap();
+
+    let statuses: Vec<BulkAgentStatus> = response.results.iter().map(|r| r.status).collect();
+    assert_eq!(
+        statuses,
+        vec![
+            BulkAgentStatus::Invited,
+            BulkAgentStatus::Failed,
+            BulkAgentStatus::Failed,
+            BulkAgentStatus::Failed,
+            BulkAgentStatus::Invited,
+        ]
+    );
+    assert_eq!((response.invited, response.failed), (2, 3));
+    assert_eq!(response.results[0].email, "new.agent@example.com");
+    assert!(response.results[0].invitation_sent);
+    assert_eq!(
+        response.results[3].error.as_deref(),
+        Some("Duplicate email in request")
+    );
+    assert!(response.results[2].agent_id.is_none());
+
+    // Invited agents get the default role and join the team
+    let user_id = response.results[0].user_id.clone().unwrap();
+    let roles = db.get_user_roles(&user_id).await.unwrap();
+    assert_eq!(roles.len(), 1);
+    assert_eq!(roles[0].name, "Agent");
+    assert!(db.is_team_member(&team, &user_id).await.unwrap());
+
+    // Each invitee gets a link; accepting it sets the chosen password
+    let mut emails = vec![mailbox.next_email().await, mailbox.next_email().await];
+    emails.sort_by(|a, b| a.to.cmp(&b.to));
+    assert_eq!(emails[0].to, "new.agent@example.com");
+    assert!(emails[0].text_body.contains("/accept-invitation?token="));
+    let token = extract_reset_token(&emails[0].text_body).unwrap();
+
+    let (password_reset_service, _) = password_reset_service_with_mailbox(db);
+    let result = password_reset_service
+        .accept_invitation(&token, "short")
+        .await;
+    assert!(result.is_err());
+
+    password_reset_service
+        .accept_invitation(&token, "NewAgentPass123!")
+        .await
+        .unwrap();
+    let agent = db.get_agent_by_user_id(&user_id).await.unwrap().unwrap();
+    assert!(verify_password("NewAgentPass123!", &agent.password_hash).unwrap());
+
+    // Invitation links are single use
+    let result = password_reset_service
+        .accept_invitation(&token, "AnotherPass123!")
+        .await;
+    match result {
+        Err(ApiError::BadRequest(msg)) => assert_eq!(msg, "Invalid or expired invitation"),
+        other => panic!(
+            "Expected invalid invitation, got {:?}",
+            other.map(|r| r.message)
+        ),
+    }
+}
+
+#[tokio::test]
+async fn test_bulk_invite_checks_role_team_and_permission() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let (service, _mailbox) = onboarding_service(db);
+
+    let result = service
+        .bulk_invite_agents(
+            &admin,
+            bulk_request(&["a@example.com"], Some("no-such-role"), None),
+        )
+        .await;
+    assert!(matches!(result, Err(ApiError::BadRequest(_))));
+
+    let result = service
+        .bulk_invite_agents(
+            &admin,
+            bulk_request(&["a@example.com"], None, Some("no-such-team")),
+        )
+        .await;
+    assert!(matches!(result, Err(ApiError::NotFound(_))));
+    assert!(db
+        .get_user_by_email_and_type("a@example.com", &UserType::Agent)
+        .await
+        .unwrap()
+        .is_none());
+
+    let response = service
+        .bulk_invite_agents(
+            &admin,
+            bulk_request(&["lead@example.com"], Some(ADMIN_ROLE_ID), None),
+        )
+        .await
+        .unwrap();
+    let user_id = response.results[0].user_id.clone().unwrap();
+    assert_eq!(
+        db.get_user_roles(&user_id).await.unwrap()[0].id,
+        ADMIN_ROLE_ID
+    );
+
+    let non_admin = create_auth_user_with_roles(db, "plain@example.com", "Plain", vec![]).await;
+    let result = service
+        .bulk_invite_agents(&non_admin, bulk_request(&["b@example.com"], None, None))
+        .await;
+    assert!(matches!(result, Err(ApiError::Forbidden(_))));
+}
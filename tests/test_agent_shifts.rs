@@ -0,0 +1,224 @@
+// Integration tests for agent shift schedules and coverage reporting
+use chrono::{TimeZone, Utc};
+use oxidesk::{
+    application::services::*, domain::entities::*, infrastructure::persistence::Database,
+    LocalEventBus,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+const WEEKDAYS_9_TO_5: &str = r#"{"timezone": "UTC", "schedule": [
+    {"day": "Monday", "start": "09:00", "end": "17:00"},
+    {"day": "Tuesday", "start": "09:00", "end": "17:00"},
+    {"day": "Wednesday", "start": "09:00", "end": "17:00"},
+    {"day": "Thursday", "start": "09:00", "end": "17:00"},
+    {"day": "Friday", "start": "09:00", "end": "17:00"}
+]}"#;
+
+fn availability_service(db: &Database) -> AvailabilityService {
+    let repo = Arc::new(db.clone());
+    AvailabilityService::new(
+        repo.clone(),
+        repo.clone(),
+        repo,
+        Arc::new(LocalEventBus::new(10)),
+    )
+}
+
+fn shift_service(db: &Database) -> ShiftService {
+    let repo = Arc::new(db.clone());
+    ShiftService::new(repo.clone(), repo, availability_service(db))
+}
+
+async fn create_team_with_member(db: &Database, user_id: &str) -> Team {
+    let mut team = Team::new("Support".to_string(), None);
+    team.business_hours = Some(WEEKDAYS_9_TO_5.to_string());
+    db.create_team(&team).await.expect("Failed to create team");
+    db.add_team_member(&team.id, user_id, TeamMemberRole::Member)
+        .await
+        .expect("Failed to add member");
+    team
+}
+
+fn shift_request(user_id: &str, day: &str, start: &str, end: &str) -> CreateShiftRequest {
+    CreateShiftRequest {
+        user_id: user_id.to_string(),
+        day: day.to_string(),
+        start_time: start.to_string(),
+        end_time: end.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_shift_validation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let outsider = create_test_agent(db, "outsider@example.com", "Outsider").await;
+    let team = create_team_with_member(db, &agent.user_id).await;
+    let service = shift_service(db);
+
+    let shift = service
+        .create_shift(
+            &team.id,
+            shift_request(&agent.user_id, "mon", "09:00", "13:00"),
+        )
+        .await
+        .expect("Failed to create shift");
+    assert_eq!(shift.day, "Monday");
+
+    let result = service
+        .create_shift(
+            &team.id,
+            shift_request(&agent.user_id, "Monday", "12:00", "15:00"),
+        )
+        .await;
+    assert!(matches!(result, Err(ShiftError::Validation(_))));
+
+    let result = service
+        .create_shift(
+            &team.id,
+            shift_request(&agent.user_id, "Tuesday", "15:00", "09:00"),
+        )
+        .await;
+    assert!(matches!(result, Err(ShiftError::Validation(_))));
+
+    let result = service
+        .create_shift(
+            &team.id,
+            shift_request(&outsider.user_id, "Monday", "09:00", "17:00"),
+        )
+        .await;
+    assert!(matches!(result, Err(ShiftError::Validation(_))));
+
+    let result = service
+        .create_shift(
+            "no-such-team",
+            shift_request(&agent.user_id, "Monday", "09:00", "17:00"),
+        )
+        .await;
+    assert!(matches!(result, Err(ShiftError::NotFound(_))));
+
+    let updated = service
+        .update_shift(
+            &team.id,
+            &shift.id,
+            UpdateShiftRequest {
+                day: None,
+                start_time: None,
+                end_time: Some("17:00".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.end_time, "17:00");
+
+    service.delete_shift(&team.id, &shift.id).await.unwrap();
+    assert!(service.list_shifts(&team.id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_coverage_report_shows_gaps() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let alice = create_test_agent(db, "alice@example.com", "Alice").await;
+    let bob = create_test_agent(db, "bob@example.com", "Bob").await;
+    let team = create_team_with_member(db, &alice.user_id).await;
+    db.add_team_member(&team.id, &bob.user_id, TeamMemberRole::Member)
+        .await
+        .unwrap();
+    let service = shift_service(db);
+
+    for day in ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday"] {
+        service
+            .create_shift(
+                &team.id,
+                shift_request(&alice.user_id, day, "09:00", "13:00"),
+            )
+            .await
+            .unwrap();
+        service
+            .create_shift(&team.id, shift_request(&bob.user_id, day, "13:00", "17:00"))
+            .await
+            .unwrap();
+    }
+
+    let report = service.coverage_report(&team.id).await.unwrap();
+    assert!(!report.has_gaps);
+    assert_eq!(report.timezone, "UTC");
+    assert_eq!(report.days.len(), 7);
+    assert_eq!(report.days[0].scheduled_agents.len(), 2);
+    // Weekends are outside business hours
+    assert!(report.days[5].required.is_none());
+
+    // Remove Bob's Wednesday shift to open an afternoon gap
+    let wednesday_shift = service
+        .list_shifts(&team.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|s| s.day == "Wednesday" && s.user_id == bob.user_id)
+        .unwrap();
+    service
+        .delete_shift(&team.id, &wednesday_shift.id)
+        .await
+        .unwrap();
+
+    let report = service.coverage_report(&team.id).await.unwrap();
+    assert!(report.has_gaps);
+    assert_eq!(
+        report.days[2].gaps,
+        vec![TimeRange {
+            start: "13:00".to_string(),
+            end: "17:00".to_string(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_availability_follows_shift_boundaries() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let team = create_team_with_member(db, &agent.user_id).await;
+    let service = shift_service(db);
+    let availability = availability_service(db);
+
+    service
+        .create_shift(
+            &team.id,
+            shift_request(&agent.user_id, "Monday", "09:00", "17:00"),
+        )
+        .await
+        .unwrap();
+
+    // 2025-01-06 is a Monday; the first run only records the state
+    let before = Utc.with_ymd_and_hms(2025, 1, 6, 8, 0, 0).unwrap();
+    assert!(service
+        .apply_shift_transitions(before)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let start = Utc.with_ymd_and_hms(2025, 1, 6, 9, 0, 0).unwrap();
+    let changed = service.apply_shift_transitions(start).await.unwrap();
+    assert_eq!(changed, vec![agent.user_id.clone()]);
+    let status = availability.get_availability(&agent.user_id).await.unwrap();
+    assert_eq!(status.availability_status, AgentAvailability::Online);
+
+    // No further change while the shift is running
+    let during = Utc.with_ymd_and_hms(2025, 1, 6, 12, 0, 0).unwrap();
+    assert!(service
+        .apply_shift_transitions(during)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let end = Utc.with_ymd_and_hms(2025, 1, 6, 17, 0, 0).unwrap();
+    let changed = service.apply_shift_transitions(end).await.unwrap();
+    assert_eq!(changed, vec![agent.user_id.clone()]);
+    let status = availability.get_availability(&agent.user_id).await.unwrap();
+    assert_eq!(status.availability_status, AgentAvailability::Offline);
+}
@@ -0,0 +1,190 @@
+// Integration tests for per-key API usage counters
+use oxidesk::application::services::ApiKeyUsageService;
+use oxidesk::domain::errors::ApiKeyUsageError;
+use oxidesk::domain::ports::api_key_repository::ApiKeyRepository;
+use oxidesk::infrastructure::http::middleware::AuthenticatedUser;
+use oxidesk::infrastructure::persistence::Database;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::{create_auth_user_with_roles, create_test_role};
+use helpers::*;
+
+const OWNER_KEY: &str = "ownerkey0123456789abcdefghijklmn";
+const OTHER_KEY: &str = "otherkey0123456789abcdefghijklmn";
+
+fn usage_service(db: &Database) -> ApiKeyUsageService {
+    let repo = Arc::new(db.clone());
+    ApiKeyUsageService::new(repo.clone(), repo)
+}
+
+async fn agent_with_key(db: &Database, email: &str, api_key: &str) -> AuthenticatedUser {
+    let user = create_auth_user_with_roles(db, email, "Agent", vec![]).await;
+    db.create_api_key(&user.agent.id, api_key, "hash", Some("CI sync".to_string()))
+        .await
+        .unwrap();
+    user
+}
+
+async fn user_admin(db: &Database) -> AuthenticatedUser {
+    let role = create_test_role(db, "User Admin", None, vec!["users:read".to_string()]).await;
+    create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![role]).await
+}
+
+#[tokio::test]
+async fn test_owner_sees_request_and_error_counts() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let owner = agent_with_key(db, "owner@example.com", OWNER_KEY).await;
+    let service = usage_service(db);
+
+    for status in [200, 201, 404, 500] {
+        service
+            .record_request(OWNER_KEY, &owner.agent.id, status, Some("203.0.113.7"))
+            .await
+            .unwrap();
+    }
+
+    let usage = service
+        .get_key_usage(&owner, OWNER_KEY, None)
+        .await
+        .unwrap();
+    assert_eq!(usage.window_hours, 24);
+    assert_eq!(usage.agent_id, owner.agent.id);
+    assert_eq!(usage.request_count, 4);
+    assert_eq!(usage.error_count, 2);
+    assert!((usage.error_rate - 0.5).abs() < f64::EPSILON);
+    assert_eq!(usage.last_status, Some(500));
+    assert_eq!(usage.last_ip.as_deref(), Some("203.0.113.7"));
+    assert!(usage.last_seen_at.is_some());
+    assert_eq!(usage.hourly.len(), 1);
+    assert_eq!(usage.hourly[0].request_count, 4);
+}
+
+#[tokio::test]
+async fn test_usage_is_private_to_owner_and_admins() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let owner = agent_with_key(db, "owner@example.com", OWNER_KEY).await;
+    let other = agent_with_key(db, "other@example.com", OTHER_KEY).await;
+    let admin = user_admin(db).await;
+    let service = usage_service(db);
+
+    service
+        .record_request(OWNER_KEY, &owner.agent.id, 200, None)
+        .await
+        .unwrap();
+
+    let err = service
+        .get_key_usage(&other, OWNER_KEY, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiKeyUsageError::Forbidden(_)));
+
+    let usage = service
+        .get_key_usage(&admin, OWNER_KEY, None)
+        .await
+        .unwrap();
+    assert_eq!(usage.request_count, 1);
+
+    let err = service
+        .get_key_usage(&admin, "missingkey0123456789abcdefghijkl", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiKeyUsageError::NotFound(_)));
+
+    let err = service
+        .get_key_usage(&owner, OWNER_KEY, Some(0))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiKeyUsageError::Validation(_)));
+}
+
+#[tokio::test]
+async fn test_usage_survives_key_revocation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let owner = agent_with_key(db, "owner@example.com", OWNER_KEY).await;
+    let service = usage_service(db);
+
+    service
+        .record_request(OWNER_KEY, &owner.agent.id, 401, None)
+        .await
+        .unwrap();
+    db.revoke_api_key(&owner.agent.id).await.unwrap();
+
+    let usage = service
+        .get_key_usage(&owner, OWNER_KEY, None)
+        .await
+        .unwrap();
+    assert_eq!(usage.error_count, 1);
+}
+
+#[tokio::test]
+async fn test_admin_overview_lists_busiest_keys_first() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let owner = agent_with_key(db, "owner@example.com", OWNER_KEY).await;
+    let other = agent_with_key(db, "other@example.com", OTHER_KEY).await;
+    let admin = user_admin(db).await;
+    let service = usage_service(db);
+
+    service
+        .record_request(OWNER_KEY, &owner.agent.id, 200, None)
+        .await
+        .unwrap();
+    for status in [200, 429, 200] {
+        service
+            .record_request(OTHER_KEY, &other.agent.id, status, None)
+            .await
+            .unwrap();
+    }
+
+    let err = service.usage_overview(&owner, None).await.unwrap_err();
+    assert!(matches!(err, ApiKeyUsageError::Forbidden(_)));
+
+    let overview = service.usage_overview(&admin, Some(48)).await.unwrap();
+    assert_eq!(overview.window_hours, 48);
+    assert_eq!(overview.request_count, 4);
+    assert_eq!(overview.error_count, 1);
+    assert_eq!(overview.keys.len(), 2);
+    assert_eq!(overview.keys[0].api_key, OTHER_KEY);
+    assert_eq!(overview.keys[0].agent_id, other.agent.id);
+    assert_eq!(overview.keys[1].api_key, OWNER_KEY);
+}
+
+#[tokio::test]
+async fn test_old_usage_is_pruned_and_outside_window() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let owner = agent_with_key(db, "owner@example.com", OWNER_KEY).await;
+    let service = usage_service(db);
+
+    sqlx::query(
+        "INSERT INTO api_key_usage
+         (api_key, agent_id, hour, request_count, error_count, last_seen_at, last_status)
+         VALUES (?, ?, '2020-01-01T00:00:00Z', 10, 0, '2020-01-01T00:30:00+00:00', 200)",
+    )
+    .bind(OWNER_KEY)
+    .bind(&owner.agent.id)
+    .execute(db.pool())
+    .await
+    .unwrap();
+    service
+        .record_request(OWNER_KEY, &owner.agent.id, 200, None)
+        .await
+        .unwrap();
+
+    let usage = service
+        .get_key_usage(&owner, OWNER_KEY, None)
+        .await
+        .unwrap();
+    assert_eq!(usage.request_count, 1);
+
+    assert_eq!(service.prune_old_usage().await.unwrap(), 1);
+    let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM api_key_usage")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(remaining, 1);
+}
@@ -0,0 +1,247 @@
+// Integration tests for tagging and routing conversations by attachment type
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    domain::errors::AttachmentRuleError,
+    infrastructure::{
+        persistence::Database, providers::connection_manager::InMemoryConnectionManager,
+    },
+    LocalEventBus,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::create_test_team;
+use helpers::*;
+
+fn assignment_service(db: &Database) -> AssignmentService {
+    let repo = Arc::new(db.clone());
+    AssignmentService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo,
+        Arc::new(LocalEventBus::new(10)),
+        NotificationService::new(Some(Arc::new(db.clone()))),
+        Arc::new(InMemoryConnectionManager::new()),
+    )
+}
+
+fn attachment_rule_service(db: &Database) -> AttachmentRuleService {
+    let repo = Arc::new(db.clone());
+    AttachmentRuleService::new(
+        repo.clone(),
+        repo.clone(),
+        oxidesk::domain::ports::tag_repository::TagRepository::new(db.clone()),
+        repo.clone(),
+        repo,
+        assignment_service(db),
+        Arc::new(LocalEventBus::new(10)),
+    )
+}
+
+fn rule_request(pattern: &str, tag_id: Option<&str>, team_id: Option<&str>) -> CreateAttachmentRuleRequest {
+    CreateAttachmentRuleRequest {
+        pattern: pattern.to_string(),
+        tag_id: tag_id.map(str::to_string),
+        team_id: team_id.map(str::to_string),
+    }
+}
+
+fn attachment(filename: &str, content_type: &str) -> MessageAttachment {
+    MessageAttachment::new(
+        "message-1".to_string(),
+        filename.to_string(),
+        Some(content_type.to_string()),
+        1024,
+        format!("messages/message-1/{}", filename),
+    )
+}
+
+async fn new_conversation(db: &Database) -> Conversation {
+    let contact = create_test_contact(db, "customer@example.com").await;
+    create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id,
+        ConversationStatus::Open,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_attachments_tag_and_route_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_agent(db, "admin@example.com", "Admin").await;
+    let invoices = create_test_tag(db, "Invoices", None, None).await;
+    let logs = create_test_tag(db, "Logs", None, None).await;
+    let billing = create_test_team(db, "Billing").await;
+    let engineering = create_test_team(db, "Engineering").await;
+    let conversation = new_conversation(db).await;
+
+    let service = attachment_rule_service(db);
+    let pdf_rule = service
+        .create_rule(
+            "inbox-001",
+            rule_request("application/pdf", Some(&invoices.id), Some(&billing)),
+            &admin.user_id,
+        )
+        .await
+        .unwrap();
+    service
+        .create_rule(
+            "inbox-001",
+            rule_request(".ZIP", Some(&logs.id), Some(&engineering)),
+            &admin.user_id,
+        )
+        .await
+        .unwrap();
+
+    let outcome = service
+        .apply_to_attachments(
+            &conversation.id,
+            "inbox-001",
+            &[attachment("notes.txt", "text/plain")],
+        )
+        .await
+        .unwrap();
+    assert_eq!(outcome, AttachmentRuleOutcome::default());
+
+    let outcome = service
+        .apply_to_attachments(
+            &conversation.id,
+            "inbox-001",
+            &[
+                attachment("crash-logs.zip", "application/octet-stream"),
+                attachment("invoice-42.pdf", "application/pdf"),
+            ],
+        )
+        .await
+        .unwrap();
+    // Both rules tag; the older rule decides the team
+    assert_eq!(outcome.tags_applied, vec![invoices.id.clone(), logs.id.clone()]);
+    assert_eq!(outcome.routed_team_id.as_deref(), Some(billing.as_str()));
+
+    let updated = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(updated.assigned_team_id.as_deref(), Some(billing.as_str()));
+    let tags = db.get_conversation_tags(&conversation.id).await.unwrap();
+    assert_eq!(tags.len(), 2);
+
+    let history = assignment_service(db)
+        .get_assignment_history(&conversation.id)
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].source, AssignmentSource::Rule);
+    assert_eq!(history[0].rule_id.as_deref(), Some(pdf_rule.id.as_str()));
+    assert_eq!(
+        history[0].reason.as_deref(),
+        Some("Attachment 'invoice-42.pdf' matched 'application/pdf'")
+    );
+
+    // Already assigned and already tagged: nothing changes
+    let outcome = service
+        .apply_to_attachments(
+            &conversation.id,
+            "inbox-001",
+            &[attachment("more-logs.zip", "application/zip")],
+        )
+        .await
+        .unwrap();
+    assert_eq!(outcome, AttachmentRuleOutcome::default());
+    let updated = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(updated.assigned_team_id.as_deref(), Some(billing.as_str()));
+}
+
+#[tokio::test]
+async fn test_rule_validation_and_disabled_rules() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_agent(db, "admin@example.com", "Admin").await;
+    let tag = create_test_tag(db, "Screenshots", None, None).await;
+    let conversation = new_conversation(db).await;
+    let service = attachment_rule_service(db);
+
+    for request in [
+        rule_request("pdf", Some(&tag.id), None),
+        rule_request("image/*", None, None),
+    ] {
+        let result = service
+            .create_rule("inbox-001", request, &admin.user_id)
+            .await;
+        assert!(matches!(result, Err(AttachmentRuleError::Validation(_))));
+    }
+    for (inbox_id, request) in [
+        ("no-such-inbox", rule_request("image/*", Some(&tag.id), None)),
+        ("inbox-001", rule_request("image/*", Some("no-such-tag"), None)),
+        ("inbox-001", rule_request("image/*", None, Some("no-such-team"))),
+    ] {
+        let result = service
+            .create_rule(inbox_id, request, &admin.user_id)
+            .await;
+        assert!(matches!(result, Err(AttachmentRuleError::NotFound(_))));
+    }
+
+    let rule = service
+        .create_rule(
+            "inbox-001",
+            rule_request("Image/*", Some(&tag.id), None),
+            &admin.user_id,
+        )
+        .await
+        .unwrap();
+    assert_eq!(rule.pattern, "image/*");
+
+    let disabled = service
+        .update_rule(
+            &rule.id,
+            UpdateAttachmentRuleRequest {
+                pattern: None,
+                tag_id: None,
+                team_id: None,
+                enabled: Some(false),
+            },
+        )
+        .await
+        .unwrap();
+    assert!(!disabled.enabled);
+
+    let outcome = service
+        .apply_to_attachments(
+            &conversation.id,
+            "inbox-001",
+            &[attachment("screen.png", "image/png")],
+        )
+        .await
+        .unwrap();
+    assert!(outcome.tags_applied.is_empty());
+
+    // Removing the only action is rejected
+    let result = service
+        .update_rule(
+            &rule.id,
+            UpdateAttachmentRuleRequest {
+                pattern: None,
+                tag_id: Some(String::new()),
+                team_id: None,
+                enabled: None,
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(AttachmentRuleError::Validation(_))));
+
+    service.delete_rule(&rule.id).await.unwrap();
+    assert!(service.list_rules("inbox-001").await.unwrap().is_empty());
+}
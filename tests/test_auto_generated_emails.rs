@@ -0,0 +1,146 @@
+// Integration tests for out-of-office replies and bounces received by email
+use oxidesk::application::services::{AttachmentService, ContactService};
+use oxidesk::domain::entities::*;
+use oxidesk::domain::ports::message_repository::MessageRepository;
+use oxidesk::infrastructure::persistence::Database;
+use oxidesk::infrastructure::providers::email_parser::{EmailParserService, ParsedEmail};
+use oxidesk::infrastructure::providers::EmailReceiverService;
+use oxidesk::infrastructure::storage::local::LocalFileStorage;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+fn receiver(db: &Database) -> EmailReceiverService {
+    let repo = Arc::new(db.clone());
+    let storage_dir = std::env::temp_dir().join(format!("oxidesk-test-{}", uuid::Uuid::new_v4()));
+    EmailReceiverService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        ContactService::new(repo.clone(), repo.clone()),
+        AttachmentService::new(repo, Arc::new(LocalFileStorage::new(storage_dir))),
+    )
+}
+
+fn parse(raw: &str) -> ParsedEmail {
+    EmailParserService::new()
+        .parse_email(raw.replace('\n', "\r\n").as_bytes())
+        .unwrap()
+}
+
+async fn resolved_conversation(db: &Database) -> Conversation {
+    let contact = create_test_contact(db, "customer@example.com").await;
+    create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id,
+        ConversationStatus::Resolved,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_out_of_office_reply_is_recorded_as_system_note() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation = resolved_conversation(db).await;
+
+    let email = parse(&format!(
+        "From: customer@example.com\n\
+         Message-ID: <ooo-1@example.com>\n\
+         Subject: Automatic reply: Re: Help [#{}]\n\
+         Auto-Submitted: auto-replied\n\
+         \n\
+         I'm out of the office until Monday.\n",
+        conversation.reference_number
+    ));
+    let kind = email.auto_generated.unwrap();
+    assert_eq!(kind, AutoGeneratedEmailKind::AutoReply);
+
+    let note = receiver(db)
+        .record_auto_generated_email(kind, &email)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(note.conversation_id, conversation.id);
+    assert_eq!(note.content, "I'm out of the office until Monday.");
+
+    // Not a contact message, and the conversation stays resolved
+    assert_eq!(db.count_messages(&conversation.id).await.unwrap(), 0);
+    let stored = get_conversation_by_id(db, conversation.id.clone())
+        .await
+        .unwrap();
+    assert_eq!(stored.status, ConversationStatus::Resolved);
+
+    let notes = db.list_system_notes(&conversation.id).await.unwrap();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].kind, AutoGeneratedEmailKind::AutoReply);
+    assert_eq!(notes[0].email_message_id, "ooo-1@example.com");
+}
+
+#[tokio::test]
+async fn test_bounce_is_matched_by_quoted_reference() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation = resolved_conversation(db).await;
+
+    let email = parse(&format!(
+        "From: MAILER-DAEMON@mail.example.com\n\
+         Message-ID: <dsn-1@mail.example.com>\n\
+         Subject: Undelivered Mail Returned to Sender\n\
+         MIME-Version: 1.0\n\
+         Content-Type: multipart/report; report-type=delivery-status; boundary=\"b1\"\n\
+         \n\
+         --b1\n\
+         Content-Type: text/plain\n\
+         \n\
+         Your message could not be delivered.\n\
+         Subject: Re: Help [#{}]\n\
+         --b1\n\
+         Content-Type: message/delivery-status\n\
+         \n\
+         Final-Recipient: rfc822; customer@example.com\n\
+         Action: failed\n\
+         Status: 5.1.1\n\
+         --b1--\n",
+        conversation.reference_number
+    ));
+    let kind = email.auto_generated.unwrap();
+    assert_eq!(kind, AutoGeneratedEmailKind::Bounce);
+
+    let note = receiver(db)
+        .record_auto_generated_email(kind, &email)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(note.conversation_id, conversation.id);
+    assert_eq!(note.kind, AutoGeneratedEmailKind::Bounce);
+    assert_eq!(db.count_messages(&conversation.id).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_unmatched_auto_reply_does_not_start_a_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+
+    let email = parse(
+        "From: someone@example.com\n\
+         Message-ID: <ooo-2@example.com>\n\
+         Subject: Out of office\n\
+         X-Autoreply: yes\n\
+         \n\
+         Away this week.\n",
+    );
+    let note = receiver(db)
+        .record_auto_generated_email(email.auto_generated.unwrap(), &email)
+        .await
+        .unwrap();
+    assert!(note.is_none());
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM conversations")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,343 @@
+// Integration tests for inbox keyword auto-tagging
+use oxidesk::{
+    application::services::*, domain::entities::*, domain::ports::event_bus::EventBus,
+    infrastructure::persistence::Database, LocalEventBus, SystemEvent,
+};
+use std::sync::Arc;
+use tokio_stream::StreamExt;
+
+mod helpers;
+use helpers::*;
+
+fn auto_tag_service(db: &Database) -> AutoTagService {
+    auto_tag_service_with_bus(db, Arc::new(LocalEventBus::new(10)))
+}
+
+fn auto_tag_service_with_bus(db: &Database, event_bus: Arc<dyn EventBus>) -> AutoTagService {
+    let repo = Arc::new(db.clone());
+    AutoTagService::new(
+        repo.clone(),
+        repo.clone(),
+        oxidesk::domain::ports::tag_repository::TagRepository::new(db.clone()),
+        repo.clone(),
+        repo.clone(),
+        repo,
+        event_bus,
+    )
+}
+
+fn rule_request(tag_id: &str, pattern: &str) -> CreateAutoTagRuleRequest {
+    CreateAutoTagRuleRequest {
+        tag_id: tag_id.to_string(),
+        pattern: pattern.to_string(),
+        match_field: AutoTagMatchField::Any,
+        match_type: AutoTagMatchType::Contains,
+        case_sensitive: false,
+    }
+}
+
+#[tokio::test]
+async fn test_incoming_message_is_auto_tagged() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+
+    let admin = create_test_agent(db, "admin@example.com", "Admin").await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    let billing = create_test_tag(db, "Billing", None, None).await;
+
+    let service = auto_tag_service(db);
+    service
+        .create_rule(
+            "inbox-001",
+            rule_request(&billing.id, "refund"),
+            &admin.user_id,
+        )
+        .await
+        .expect("Failed to create rule");
+
+    let repo = Arc::new(db.clone());
+    let mut message_service = MessageService::new(repo.clone(), repo);
+    message_service.set_auto_tag_service(service);
+
+    let make_request = |content: &str| IncomingMessageRequest {
+        conversation_id: conversation.id.clone(),
+        content: content.to_string(),
+        contact_id: Some(contact.user_id.clone()),
+        inbox_id: "inbox-001".to_string(),
+        from_header: None,
+        external_id: None,
+        received_at: None,
+        channel: None,
+        channel_metadata: None,
+    };
+
+    message_service
+        .create_incoming_message(make_request("Hello there"))
+        .await
+        .expect("Failed to create message");
+    let tags = db.get_conversation_tags(&conversation.id).await.unwrap();
+    assert!(tags.is_empty());
+
+    message_service
+        .create_incoming_message(make_request("I would like a REFUND"))
+        .await
+        .expect("Failed to create message");
+    let tags = db.get_conversation_tags(&conversation.id).await.unwrap();
+    assert_eq!(tags.len(), 1);
+    assert_eq!(tags[0].id, billing.id);
+}
+
+#[tokio::test]
+async fn test_disabled_rule_and_invalid_regex() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+
+    let admin = create_test_agent(db, "admin@example.com", "Admin").await;
+    let tag = create_test_tag(db, "Bug", None, None).await;
+    let service = auto_tag_service(db);
+
+    let mut invalid = rule_request(&tag.id, "(unclosed");
+    invalid.match_type = AutoTagMatchType::Regex;
+    let result = service
+        .create_rule("inbox-001", invalid, &admin.user_id)
+        .await;
+    assert!(matches!(result, Err(AutoTagError::Validation(_))));
+
+    let result = service
+        .create_rule(
+            "no-such-inbox",
+            rule_request(&tag.id, "bug"),
+            &admin.user_id,
+        )
+        .await;
+    assert!(matches!(result, Err(AutoTagError::NotFound(_))));
+
+    let rule = service
+        .create_rule("inbox-001", rule_request(&tag.id, "crash"), &admin.user_id)
+        .await
+        .unwrap();
+    service
+        .update_rule(
+            &rule.id,
+            UpdateAutoTagRuleRequest {
+                tag_id: None,
+                pattern: None,
+                match_field: None,
+                match_type: None,
+                case_sensitive: None,
+                enabled: Some(false),
+            },
+        )
+        .await
+        .unwrap();
+
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    let applied = service
+        .apply_to_message(&conversation.id, "inbox-001", None, "the app crash again")
+        .await
+        .unwrap();
+    assert!(applied.is_empty());
+}
+
+#[tokio::test]
+async fn test_backfill_tags_existing_conversations() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+
+    let admin = create_test_agent(db, "admin@example.com", "Admin").await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+    // Test conversations are created with subject "Test conversation"
+    for _ in 0..3 {
+        create_test_conversation(
+            db,
+            "inbox-001".to_string(),
+            contact.id.clone(),
+            ConversationStatus::Open,
+        )
+        .await;
+    }
+    let tag = create_test_tag(db, "Legacy", None, None).await;
+
+    let service = auto_tag_service(db);
+    let mut request = rule_request(&tag.id, "test conversation");
+    request.match_field = AutoTagMatchField::Subject;
+    service
+        .create_rule("inbox-001", request, &admin.user_id)
+        .await
+        .unwrap();
+
+    let first = service
+        .backfill(
+            "inbox-001",
+            AutoTagBackfillRequest {
+                limit: 2,
+                offset: 0,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(first.conversations_scanned, 2);
+    assert_eq!(first.tags_applied, 2);
+    assert_eq!(first.next_offset, Some(2));
+
+    let second = service
+        .backfill(
+            "inbox-001",
+            AutoTagBackfillRequest {
+                limit: 2,
+                offset: 2,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(second.conversations_scanned, 1);
+    assert_eq!(second.conversations_tagged, 1);
+    assert_eq!(second.next_offset, None);
+
+    // Re-running is idempotent
+    let rerun = service
+        .backfill(
+            "inbox-001",
+            AutoTagBackfillRequest {
+                limit: 10,
+                offset: 0,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(rerun.conversations_scanned, 3);
+    assert_eq!(rerun.tags_applied, 0);
+}
+
+#[tokio::test]
+async fn test_tag_event_names_the_rule_creator() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+
+    let admin = create_test_agent(db, "admin@example.com", "Admin").await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    let tag = create_test_tag(db, "Outage", None, None).await;
+
+    let event_bus: Arc<dyn EventBus> = Arc::new(LocalEventBus::new(10));
+    let mut events = event_bus.subscribe();
+    let service = auto_tag_service_with_bus(db, event_bus);
+    service
+        .create_rule("inbox-001", rule_request(&tag.id, "down"), &admin.user_id)
+        .await
+        .unwrap();
+
+    let applied = service
+        .apply_to_message(&conversation.id, "inbox-001", None, "The site is down")
+        .await
+        .unwrap();
+    assert_eq!(applied, vec![tag.id.clone()]);
+
+    let (added_by,): (String,) = sqlx::query_as(
+        "SELECT added_by FROM conversation_tags WHERE conversation_id = ? AND tag_id = ?",
+    )
+    .bind(&conversation.id)
+    .bind(&tag.id)
+    .fetch_one(db.pool())
+    .await
+    .unwrap();
+    assert_eq!(added_by, admin.user_id);
+
+    let event = tokio::time::timeout(std::time::Duration::from_secs(1), events.next())
+        .await
+        .expect("Timeout waiting for event")
+        .expect("Event stream ended")
+        .expect("Broadcast error");
+    match event {
+        SystemEvent::ConversationTagsChanged {
+            new_tags,
+            changed_by,
+            ..
+        } => {
+            assert_eq!(new_tags, vec![tag.id]);
+            assert_eq!(changed_by, added_by);
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_updated_regex_takes_effect() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+
+    let admin = create_test_agent(db, "admin@example.com", "Admin").await;
+    let tag = create_test_tag(db, "Orders", None, None).await;
+    let service = auto_tag_service(db);
+
+    let mut request = rule_request(&tag.id, r"order #\d+");
+    request.match_type = AutoTagMatchType::Regex;
+    let rule = service
+        .create_rule("inbox-001", request, &admin.user_id)
+        .await
+        .unwrap();
+
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let new_conversation = || {
+        create_test_conversation(
+            db,
+            "inbox-001".to_string(),
+            contact.id.clone(),
+            ConversationStatus::Open,
+        )
+    };
+
+    let first = new_conversation().await;
+    let applied = service
+        .apply_to_message(&first.id, "inbox-001", None, "Where is ORDER #42?")
+        .await
+        .unwrap();
+    assert_eq!(applied.len(), 1);
+
+    service
+        .update_rule(
+            &rule.id,
+            UpdateAutoTagRuleRequest {
+                tag_id: None,
+                pattern: Some(r"invoice #\d+".to_string()),
+                match_field: None,
+                match_type: None,
+                case_sensitive: None,
+                enabled: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    let second = new_conversation().await;
+    let applied = service
+        .apply_to_message(&second.id, "inbox-001", None, "Where is order #42?")
+        .await
+        .unwrap();
+    assert!(applied.is_empty());
+    let applied = service
+        .apply_to_message(&second.id, "inbox-001", None, "Invoice #7 is wrong")
+        .await
+        .unwrap();
+    assert_eq!(applied.len(), 1);
+}
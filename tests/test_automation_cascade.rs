@@ -0,0 +1,196 @@
+// Integration tests for automation cascade depth and loop suppression
+use oxidesk::{
+    application::services::automation_service::{AutomationConfig, AutomationService},
+    domain::entities::*,
+    domain::ports::{
+        agent_repository::AgentRepository, automation_repository::AutomationRepository,
+        conversation_repository::ConversationRepository,
+        conversation_tag_repository::ConversationTagRepository,
+        notification_repository::NotificationRepository, tag_repository::TagRepository,
+        team_repository::TeamRepository, user_repository::UserRepository,
+    },
+    domain::services::action_executor::ActionExecutor,
+    infrastructure::persistence::Database,
+};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+fn automation_service(db: &Database, config: AutomationConfig) -> AutomationService {
+    let action_executor = ActionExecutor::new(
+        Arc::new(db.clone()) as Arc<dyn ConversationRepository>,
+        Arc::new(db.clone()) as Arc<dyn UserRepository>,
+        Arc::new(db.clone()) as Arc<dyn AgentRepository>,
+        Arc::new(db.clone()) as Arc<dyn TeamRepository>,
+        TagRepository::new(db.clone()),
+        Arc::new(db.clone()) as Arc<dyn ConversationTagRepository>,
+    );
+    let mut service = AutomationService::new(Arc::new(db.clone()), action_executor, config);
+    service.set_cascade_alerts(Arc::new(db.clone()), Arc::new(db.clone()));
+    service
+}
+
+/// A rule that acts on every tag change of an open conversation
+async fn create_self_triggering_rule(db: &Database) -> AutomationRule {
+    let rule = AutomationRule::new(
+        "Escalate on tag change".to_string(),
+        RuleType::ConversationUpdate,
+        vec!["conversation.tags_changed".to_string()],
+        RuleCondition::Simple {
+            attribute: "status".to_string(),
+            comparison: ComparisonOperator::Equals,
+            value: json!("open"),
+        },
+        RuleAction {
+            action_type: ActionType::SetPriority,
+            parameters: HashMap::from([("priority".to_string(), json!("High"))]),
+        },
+    );
+    db.create_automation_rule(&rule).await.unwrap();
+    rule
+}
+
+async fn alerts_for(db: &Database, user_id: &str) -> Vec<UserNotification> {
+    db.list_notifications(user_id, 50, 0)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|n| n.notification_type == NotificationType::Alert)
+        .collect()
+}
+
+async fn suppressed_logs(db: &Database, conversation_id: &str) -> Vec<RuleEvaluationLog> {
+    db.get_rule_evaluation_logs(None, Some(conversation_id), None, None, None)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|log| {
+            log.error_message
+                .as_deref()
+                .is_some_and(|m| m.starts_with("Cascade suppressed"))
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_rule_loop_is_suppressed_and_admins_alerted_once() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    create_self_triggering_rule(db).await;
+
+    let service = automation_service(
+        db,
+        AutomationConfig {
+            cascade_max_depth: 10,
+            cycle_max_runs: 2,
+            ..Default::default()
+        },
+    );
+    for _ in 0..4 {
+        service
+            .handle_conversation_event("conversation.tags_changed", &conversation, "system")
+            .await
+            .unwrap();
+    }
+
+    let logs = db
+        .get_rule_evaluation_logs(None, Some(&conversation.id), None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(logs.iter().filter(|log| log.action_executed).count(), 2);
+
+    let suppressed = suppressed_logs(db, &conversation.id).await;
+    assert_eq!(suppressed.len(), 2);
+    assert!(suppressed
+        .iter()
+        .all(|log| log.action_result == Some(ActionResult::Skipped)));
+    assert!(suppressed[0]
+        .error_message
+        .as_deref()
+        .unwrap()
+        .contains("already ran 2 times"));
+
+    let alerts = alerts_for(db, &admin.user.id).await;
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(
+        alerts[0].conversation_id.as_deref(),
+        Some(conversation.id.as_str())
+    );
+}
+
+#[tokio::test]
+async fn test_cascade_depth_is_inherited_and_enforced() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    create_self_triggering_rule(db).await;
+
+    let service = automation_service(
+        db,
+        AutomationConfig {
+            cascade_max_depth: 1,
+            cycle_max_runs: 10,
+            ..Default::default()
+        },
+    );
+    // Each event follows the rule's own change, so it continues the cascade
+    for _ in 0..3 {
+        service
+            .handle_conversation_event("conversation.tags_changed", &conversation, "system")
+            .await
+            .unwrap();
+    }
+
+    let mut depths: Vec<(u32, bool)> = db
+        .get_rule_evaluation_logs(None, Some(&conversation.id), None, None, None)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|log| (log.cascade_depth, log.action_executed))
+        .collect();
+    depths.sort();
+    assert_eq!(depths, vec![(0, true), (1, true), (2, false)]);
+
+    let suppressed = suppressed_logs(db, &conversation.id).await;
+    assert_eq!(suppressed.len(), 1);
+    assert!(suppressed[0]
+        .error_message
+        .as_deref()
+        .unwrap()
+        .contains("depth 2 exceeds limit 1"));
+    assert_eq!(alerts_for(db, &admin.user.id).await.len(), 1);
+
+    // Another conversation is unaffected
+    let other = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    service
+        .handle_conversation_event("conversation.tags_changed", &other, "system")
+        .await
+        .unwrap();
+    assert!(suppressed_logs(db, &other.id).await.is_empty());
+}
@@ -212,6 +212,7 @@ async fn test_circular_rule_cascade_depth_limit() {
         cascade_max_depth: 2,
         condition_timeout_secs: 5,
         action_timeout_secs: 5,
+        ..Default::default()
     };
     let service = create_automation_service(db, config);
 
@@ -508,6 +509,7 @@ async fn test_condition_timeout_handling() {
         cascade_max_depth: 5,
         condition_timeout_secs: 1, // Short timeout
         action_timeout_secs: 5,
+        ..Default::default()
     };
     let service = create_automation_service(db, config);
 
@@ -562,6 +564,7 @@ async fn test_action_timeout_handling() {
         cascade_max_depth: 5,
         condition_timeout_secs: 5,
         action_timeout_secs: 1, // Short timeout
+        ..Default::default()
     };
     let service = create_automation_service(db, config);
 
@@ -649,6 +652,7 @@ async fn test_cascading_actions() {
         cascade_max_depth: 5,
         condition_timeout_secs: 5,
         action_timeout_secs: 5,
+        ..Default::default()
     };
     let service = create_automation_service(db, config);
 
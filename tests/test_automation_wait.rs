@@ -0,0 +1,303 @@
+// Integration tests for delayed automation actions (Wait steps)
+mod helpers;
+
+use helpers::*;
+use oxidesk::{
+    application::services::automation_service::{
+        AutomationConfig, AutomationService, RUN_AUTOMATION_WAIT_JOB,
+    },
+    domain::entities::{
+        ActionType, AutomationRule, ComparisonOperator, Conversation, ConversationStatus,
+        PendingRuleActionStatus, Priority, RuleAction, RuleCondition, RuleType,
+    },
+    domain::ports::{
+        agent_repository::AgentRepository, automation_repository::AutomationRepository,
+        conversation_repository::ConversationRepository,
+        conversation_tag_repository::ConversationTagRepository, tag_repository::TagRepository,
+        team_repository::TeamRepository, user_repository::UserRepository,
+    },
+    domain::services::action_executor::ActionExecutor,
+    infrastructure::persistence::Database,
+    infrastructure::workers::SqliteTaskQueue,
+};
+use serde_json::json;
+use sqlx::Row;
+use std::sync::Arc;
+
+fn create_automation_service(db: &Database) -> AutomationService {
+    let action_executor = ActionExecutor::new(
+        Arc::new(db.clone()) as Arc<dyn ConversationRepository>,
+        Arc::new(db.clone()) as Arc<dyn UserRepository>,
+        Arc::new(db.clone()) as Arc<dyn AgentRepository>,
+        Arc::new(db.clone()) as Arc<dyn TeamRepository>,
+        TagRepository::new(db.clone()),
+        Arc::new(db.clone()) as Arc<dyn ConversationTagRepository>,
+    );
+    let mut service = AutomationService::new(
+        Arc::new(db.clone()) as Arc<dyn AutomationRepository>,
+        action_executor,
+        AutomationConfig::default(),
+    );
+    service.set_task_queue(Arc::new(SqliteTaskQueue::new(db.clone())));
+    service.set_conversation_repo(Arc::new(db.clone()) as Arc<dyn ConversationRepository>);
+    service
+}
+
+/// "Wait 4 hours, then if still unassigned raise the priority"
+async fn create_escalation_rule(db: &Database) -> AutomationRule {
+    let rule = AutomationRule::new(
+        "Escalate unassigned".to_string(),
+        RuleType::ConversationUpdate,
+        vec!["conversation.created".to_string()],
+        RuleCondition::Simple {
+            attribute: "assigned_user_id".to_string(),
+            comparison: ComparisonOperator::Equals,
+            value: json!(null),
+        },
+        RuleAction {
+            action_type: ActionType::Wait,
+            parameters: serde_json::from_value(json!({
+                "duration": "4h",
+                "then": {
+                    "action_type": "set_priority",
+                    "parameters": { "priority": "High" }
+                }
+            }))
+            .unwrap(),
+        },
+    );
+    rule.validate().unwrap();
+    AutomationRepository::create_automation_rule(db, &rule)
+        .await
+        .unwrap();
+    rule
+}
+
+async fn create_open_conversation(db: &Database) -> Conversation {
+    let contact = create_test_contact(db, "waiting@example.com").await;
+    create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id,
+        ConversationStatus::Open,
+    )
+    .await
+}
+
+async fn reload(db: &Database, conversation_id: &str) -> Conversation {
+    db.get_conversation_by_id(conversation_id)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_wait_schedules_follow_up_job() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let rule = create_escalation_rule(db).await;
+    let conversation = create_open_conversation(db).await;
+    let service = create_automation_service(db);
+
+    service
+        .handle_conversation_event("conversation.created", &conversation, "system")
+        .await
+        .unwrap();
+
+    // Nothing happens yet
+    assert_ne!(
+        reload(db, &conversation.id).await.priority,
+        Some(Priority::High)
+    );
+
+    let pending = db
+        .get_pending_rule_actions_for_conversation(&conversation.id)
+        .await
+        .unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].rule_id, rule.id);
+    assert_eq!(pending[0].action.action_type, ActionType::SetPriority);
+
+    // The follow-up is queued for when the wait elapses
+    let job =
+        sqlx::query("SELECT payload, CAST(run_at AS TEXT) AS run_at FROM jobs WHERE job_type = ?")
+            .bind(RUN_AUTOMATION_WAIT_JOB)
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+    let payload: serde_json::Value =
+        serde_json::from_str(&job.try_get::<String, _>("payload").unwrap()).unwrap();
+    assert_eq!(payload["pending_action_id"], json!(pending[0].id));
+    assert_eq!(
+        job.try_get::<String, _>("run_at").unwrap(),
+        pending[0].run_at
+    );
+    let run_at = chrono::DateTime::parse_from_rfc3339(&pending[0].run_at).unwrap();
+    let delay = run_at.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    assert!(delay > chrono::Duration::minutes(235) && delay <= chrono::Duration::hours(4));
+
+    // A repeated event doesn't restart the wait
+    service
+        .handle_conversation_event("conversation.created", &conversation, "system")
+        .await
+        .unwrap();
+    assert_eq!(
+        db.get_pending_rule_actions_for_conversation(&conversation.id)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_follow_up_runs_when_condition_still_holds() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_escalation_rule(db).await;
+    let conversation = create_open_conversation(db).await;
+    let service = create_automation_service(db);
+
+    service
+        .handle_conversation_event("conversation.created", &conversation, "system")
+        .await
+        .unwrap();
+    let pending_id = db
+        .get_pending_rule_actions_for_conversation(&conversation.id)
+        .await
+        .unwrap()[0]
+        .id
+        .clone();
+
+    service.run_pending_action(&pending_id).await.unwrap();
+
+    assert_eq!(
+        reload(db, &conversation.id).await.priority,
+        Some(Priority::High)
+    );
+    let pending = db
+        .get_pending_rule_action(&pending_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(pending.status, PendingRuleActionStatus::Completed);
+
+    let logs = db
+        .get_rule_evaluation_logs(
+            None,
+            Some(&conversation.id),
+            Some("automation.wait_elapsed"),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(logs.len(), 1);
+    assert!(logs[0].action_executed);
+
+    // A retried job doesn't run the follow-up twice
+    service.run_pending_action(&pending_id).await.unwrap();
+    let logs = db
+        .get_rule_evaluation_logs(
+            None,
+            Some(&conversation.id),
+            Some("automation.wait_elapsed"),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(logs.len(), 1);
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_wait_cancelled_when_condition_becomes_false() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_escalation_rule(db).await;
+    let conversation = create_open_conversation(db).await;
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let service = create_automation_service(db);
+
+    service
+        .handle_conversation_event("conversation.created", &conversation, "system")
+        .await
+        .unwrap();
+    let pending_id = db
+        .get_pending_rule_actions_for_conversation(&conversation.id)
+        .await
+        .unwrap()[0]
+        .id
+        .clone();
+
+    // The conversation gets picked up before the wait elapses
+    db.assign_conversation_to_user(&conversation.id, Some(agent.user_id.clone()), None)
+        .await
+        .unwrap();
+    let assigned = reload(db, &conversation.id).await;
+    service
+        .handle_conversation_event("conversation.assigned", &assigned, "system")
+        .await
+        .unwrap();
+
+    let pending = db
+        .get_pending_rule_action(&pending_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(pending.status, PendingRuleActionStatus::Cancelled);
+
+    // The queued job finds nothing left to do
+    service.run_pending_action(&pending_id).await.unwrap();
+    assert_ne!(
+        reload(db, &conversation.id).await.priority,
+        Some(Priority::High)
+    );
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_follow_up_skipped_when_condition_false_at_run_time() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_escalation_rule(db).await;
+    let conversation = create_open_conversation(db).await;
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let service = create_automation_service(db);
+
+    service
+        .handle_conversation_event("conversation.created", &conversation, "system")
+        .await
+        .unwrap();
+    let pending_id = db
+        .get_pending_rule_actions_for_conversation(&conversation.id)
+        .await
+        .unwrap()[0]
+        .id
+        .clone();
+
+    // Assigned without any event reaching the automation service
+    db.assign_conversation_to_user(&conversation.id, Some(agent.user_id.clone()), None)
+        .await
+        .unwrap();
+
+    service.run_pending_action(&pending_id).await.unwrap();
+
+    assert_ne!(
+        reload(db, &conversation.id).await.priority,
+        Some(Priority::High)
+    );
+    let pending = db
+        .get_pending_rule_action(&pending_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(pending.status, PendingRuleActionStatus::Cancelled);
+
+    teardown_test_db(test_db).await;
+}
@@ -0,0 +1,111 @@
+// Integration tests for workspace branding settings
+use oxidesk::{
+    application::services::BrandingService,
+    domain::entities::*,
+    domain::errors::{DomainError, SystemConfigError},
+    domain::ports::system_config_repository::SystemConfigRepository,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+#[tokio::test]
+async fn test_branding_defaults_when_unset() {
+    let test_db = setup_test_db().await;
+    let service = BrandingService::new(Arc::new(test_db.db().clone()));
+
+    let branding = service.get_branding().await.unwrap();
+    assert_eq!(branding, Branding::default());
+    assert_eq!(branding.product_name, "Oxidesk");
+    assert!(branding.stylesheet().contains("--oxi-accent: #06b6d4;"));
+    assert!(branding
+        .stylesheet()
+        .contains("--oxi-accent-glow: rgba(6, 182, 212, 0.3);"));
+}
+
+#[tokio::test]
+async fn test_update_branding_stores_and_records_changes() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = BrandingService::new(Arc::new(db.clone()));
+    let admin = create_test_auth_user(db).await;
+
+    let branding = service
+        .update_branding(
+            UpdateBrandingRequest {
+                product_name: Some("Acme Support".to_string()),
+                logo_url: Some("https://cdn.example.com/logo.png".to_string()),
+                accent_color: Some("#FF6600".to_string()),
+                email_footer: Some("Acme Inc.\n1 Main St".to_string()),
+            },
+            &admin.user.id,
+        )
+        .await
+        .unwrap();
+    assert_eq!(branding.product_name, "Acme Support");
+    assert_eq!(branding.accent_color, "#ff6600");
+    assert!(branding.stylesheet().contains("rgba(255, 102, 0, 0.3)"));
+
+    let variables = branding.email_variables();
+    assert_eq!(variables["email_footer"], "Acme Inc.<br>1 Main St");
+
+    let changes = db
+        .list_config_changes(Some(BRANDING_ACCENT_COLOR_KEY), 10)
+        .await
+        .unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].new_value, "#ff6600");
+
+    // Omitted fields stay; an empty string clears the logo
+    let branding = service
+        .update_branding(
+            UpdateBrandingRequest {
+                logo_url: Some(String::new()),
+                ..Default::default()
+            },
+            &admin.user.id,
+        )
+        .await
+        .unwrap();
+    assert_eq!(branding.logo_url, None);
+    assert_eq!(branding.product_name, "Acme Support");
+
+    // Unchanged fields are not recorded again
+    let changes = db
+        .list_config_changes(Some(BRANDING_ACCENT_COLOR_KEY), 10)
+        .await
+        .unwrap();
+    assert_eq!(changes.len(), 1);
+}
+
+#[tokio::test]
+async fn test_update_branding_rejects_invalid_values() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = BrandingService::new(Arc::new(db.clone()));
+    let admin = create_test_auth_user(db).await;
+
+    for request in [
+        UpdateBrandingRequest {
+            accent_color: Some("orange".to_string()),
+            ..Default::default()
+        },
+        UpdateBrandingRequest {
+            logo_url: Some("javascript:alert(1)".to_string()),
+            ..Default::default()
+        },
+        UpdateBrandingRequest {
+            product_name: Some("   ".to_string()),
+            ..Default::default()
+        },
+    ] {
+        let result = service.update_branding(request, &admin.user.id).await;
+        assert!(matches!(
+            result,
+            Err(SystemConfigError::Repository(DomainError::Validation(_)))
+        ));
+    }
+
+    assert_eq!(service.get_branding().await.unwrap(), Branding::default());
+}
@@ -0,0 +1,116 @@
+// Integration tests for meeting invites received by email
+use oxidesk::domain::entities::*;
+use oxidesk::domain::ports::message_repository::MessageRepository;
+use oxidesk::infrastructure::providers::email_parser::{EmailParserService, ParsedEmail};
+
+mod helpers;
+use helpers::*;
+
+const INVITE: &str = "BEGIN:VCALENDAR\n\
+                      METHOD:REQUEST\n\
+                      BEGIN:VEVENT\n\
+                      UID:renewal-42@example.com\n\
+                      SUMMARY:Contract renewal\n\
+                      DTSTART:20261020T140000Z\n\
+                      DTEND:20261020T143000Z\n\
+                      LOCATION:https://meet.example.com/renewal\n\
+                      ORGANIZER;CN=Jane Doe:mailto:jane@example.com\n\
+                      ATTENDEE;PARTSTAT=NEEDS-ACTION;RSVP=TRUE:mailto:support@example.com\n\
+                      END:VEVENT\n\
+                      END:VCALENDAR\n";
+
+fn parse(raw: &str) -> ParsedEmail {
+    EmailParserService::new()
+        .parse_email(raw.replace('\n', "\r\n").as_bytes())
+        .unwrap()
+}
+
+#[test]
+fn test_invite_attachment_is_parsed_instead_of_stored() {
+    let email = parse(&format!(
+        "From: Jane Doe <jane@example.com>\n\
+         Message-ID: <invite-1@example.com>\n\
+         Subject: Invitation: Contract renewal\n\
+         MIME-Version: 1.0\n\
+         Content-Type: multipart/mixed; boundary=\"b1\"\n\
+         \n\
+         --b1\n\
+         Content-Type: text/plain\n\
+         \n\
+         Let's go over the renewal.\n\
+         --b1\n\
+         Content-Type: text/calendar; method=REQUEST; charset=UTF-8\n\
+         Content-Disposition: attachment; filename=\"invite.ics\"\n\
+         \n\
+         {}\
+         --b1--\n",
+        INVITE
+    ));
+
+    assert_eq!(
+        email.text_body.as_deref().map(str::trim),
+        Some("Let's go over the renewal.")
+    );
+    assert!(email.attachments.is_empty());
+    let invite = email.calendar_invite.expect("invite should be parsed");
+    assert_eq!(invite.method, CalendarInviteMethod::Request);
+    assert_eq!(invite.title.as_deref(), Some("Contract renewal"));
+    assert_eq!(
+        invite.starts_at.as_deref(),
+        Some("2026-10-20T14:00:00+00:00")
+    );
+    assert_eq!(
+        invite.location.as_deref(),
+        Some("https://meet.example.com/renewal")
+    );
+    assert_eq!(
+        invite.response_of("support@example.com"),
+        Some(AttendeeResponse::NeedsAction)
+    );
+}
+
+#[test]
+fn test_email_without_invite_is_unchanged() {
+    let email = parse(
+        "From: jane@example.com\n\
+         Message-ID: <plain-1@example.com>\n\
+         Subject: Hello\n\
+         \n\
+         Just a question.\n",
+    );
+    assert!(email.calendar_invite.is_none());
+}
+
+#[tokio::test]
+async fn test_invite_is_stored_with_message() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let contact = create_test_contact(db, "jane@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+
+    let invite = oxidesk::domain::services::parse_calendar_invite(INVITE).unwrap();
+    let mut message = Message::new_incoming(
+        conversation.id.clone(),
+        invite.summary.clone(),
+        contact.user_id.clone(),
+    );
+    message.calendar_invite = Some(invite.clone());
+    db.create_message(&message).await.unwrap();
+
+    let stored = db.get_message_by_id(&message.id).await.unwrap().unwrap();
+    assert_eq!(stored.calendar_invite, Some(invite));
+    assert!(stored.content.starts_with("Invitation: Contract renewal"));
+
+    let json = serde_json::to_value(&stored).unwrap();
+    assert_eq!(json["calendar_invite"]["method"], "request");
+    assert_eq!(
+        json["calendar_invite"]["attendees"][0]["response"],
+        "needs_action"
+    );
+}
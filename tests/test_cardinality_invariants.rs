@@ -278,6 +278,8 @@ async fn test_message_must_have_exactly_one_sender() {
         from_header: None,
         external_id: None,
         received_at: None,
+        channel: None,
+        channel_metadata: None,
     };
 
     let result = message_service
@@ -333,6 +335,8 @@ async fn test_message_with_valid_sender_succeeds() {
         from_header: None,
         external_id: None,
         received_at: None,
+        channel: None,
+        channel_metadata: None,
     };
 
     let result = message_service
@@ -367,6 +371,7 @@ async fn test_webhook_must_have_at_least_one_event_on_create() {
         subscribed_events: vec![], // Empty array - violates cardinality
         secret: "secret1234567890abcdef".to_string(),
         is_active: Some(true),
+        include_conversation_snapshot: None,
     };
 
     let result = webhook_service.create_webhook(request, "admin-123").await;
@@ -404,6 +409,7 @@ async fn test_webhook_must_have_at_least_one_event_on_update() {
         subscribed_events: vec!["conversation.created".to_string()],
         secret: "secret1234567890abcdef".to_string(),
         is_active: Some(true),
+        include_conversation_snapshot: None,
     };
 
     let webhook = webhook_service
@@ -418,6 +424,7 @@ async fn test_webhook_must_have_at_least_one_event_on_update() {
         subscribed_events: Some(vec![]), // Empty array - violates cardinality
         secret: None,
         is_active: None,
+        include_conversation_snapshot: None,
     };
 
     let result = webhook_service
@@ -0,0 +1,214 @@
+// Integration tests for messages pushed in by external channels
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    domain::ports::{contact_repository::ContactRepository, message_repository::MessageRepository},
+    infrastructure::persistence::Database,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+fn channel_service(db: &Database) -> ChannelService {
+    let repo = Arc::new(db.clone());
+    ChannelService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        MessageService::new(repo.clone(), repo),
+    )
+}
+
+fn sms(from: &str, content: &str, external_message_id: Option<&str>) -> ChannelMessageRequest {
+    ChannelMessageRequest {
+        sender: ChannelSender {
+            external_id: Some(from.to_string()),
+            email: None,
+            name: Some("SMS Customer".to_string()),
+        },
+        content: content.to_string(),
+        conversation_id: None,
+        new_conversation: false,
+        subject: None,
+        external_message_id: external_message_id.map(str::to_string),
+        channel: None,
+        channel_metadata: None,
+    }
+}
+
+#[tokio::test]
+async fn test_channel_sender_threads_into_open_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = channel_service(db);
+
+    let first = service
+        .receive_message("inbox-001", sms("+15550100", "Is my order shipped?", None))
+        .await
+        .unwrap();
+    assert!(first.created_conversation);
+    assert!(!first.duplicate);
+    assert_eq!(first.message.message_type, MessageType::Incoming);
+
+    // The sender has no email, so the contact gets a reserved placeholder address
+    let placeholder = ChannelSender::placeholder_email("inbox-001", "+15550100");
+    assert!(placeholder.ends_with(CHANNEL_PLACEHOLDER_EMAIL_DOMAIN));
+    let contact = db
+        .get_contact_by_email(&placeholder)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(contact.id, first.contact_id);
+    assert_eq!(first.message.author_id, contact.user_id);
+
+    let second = service
+        .receive_message("inbox-001", sms("+15550100", "Any update?", None))
+        .await
+        .unwrap();
+    assert!(!second.created_conversation);
+    assert_eq!(second.contact_id, first.contact_id);
+    assert_eq!(second.conversation_id, first.conversation_id);
+    assert_eq!(db.count_messages(&first.conversation_id).await.unwrap(), 2);
+
+    // Once resolved, the next message starts a new conversation
+    db.update_conversation_status(&first.conversation_id, ConversationStatus::Resolved)
+        .await
+        .unwrap();
+    let third = service
+        .receive_message("inbox-001", sms("+15550100", "New question", None))
+        .await
+        .unwrap();
+    assert!(third.created_conversation);
+    assert_ne!(third.conversation_id, first.conversation_id);
+
+    // Naming the old conversation reopens it
+    let mut request = sms("+15550100", "Actually, about that order", None);
+    request.conversation_id = Some(first.conversation_id.clone());
+    let reply = service.receive_message("inbox-001", request).await.unwrap();
+    assert_eq!(reply.conversation_id, first.conversation_id);
+    assert_eq!(reply.conversation_status, ConversationStatus::Open);
+    let conversation = db
+        .get_conversation_by_id(&first.conversation_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(conversation.status, ConversationStatus::Open);
+}
+
+#[tokio::test]
+async fn test_channel_message_reopens_snoozed_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = channel_service(db);
+
+    let first = service
+        .receive_message("inbox-001", sms("+15550123", "Hello", None))
+        .await
+        .unwrap();
+    assert_eq!(first.conversation_status, ConversationStatus::Open);
+    let snoozed_until = (chrono::Utc::now() + chrono::Duration::hours(1)).to_rfc3339();
+    db.update_conversation_fields(
+        &first.conversation_id,
+        ConversationStatus::Snoozed,
+        None,
+        None,
+        Some(snoozed_until),
+    )
+    .await
+    .unwrap();
+
+    let reply = service
+        .receive_message("inbox-001", sms("+15550123", "Still there?", None))
+        .await
+        .unwrap();
+    assert!(!reply.created_conversation);
+    assert_eq!(reply.conversation_id, first.conversation_id);
+    assert_eq!(reply.conversation_status, ConversationStatus::Open);
+}
+
+#[tokio::test]
+async fn test_channel_redelivery_is_ignored() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = channel_service(db);
+
+    let first = service
+        .receive_message("inbox-001", sms("visitor-42", "Hello", Some("SM123")))
+        .await
+        .unwrap();
+    let again = service
+        .receive_message("inbox-001", sms("visitor-42", "Hello", Some("SM123")))
+        .await
+        .unwrap();
+    assert!(again.duplicate);
+    assert_eq!(again.message.id, first.message.id);
+    assert_eq!(again.contact_id, first.contact_id);
+    assert_eq!(db.count_messages(&first.conversation_id).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_channel_sender_email_matches_existing_contact() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = channel_service(db);
+    let contact = create_test_contact(db, "customer@example.com").await;
+
+    let request = ChannelMessageRequest {
+        sender: ChannelSender {
+            external_id: Some("widget-visitor-1".to_string()),
+            email: Some("Customer@Example.com".to_string()),
+            name: None,
+        },
+        content: "Hi from the chat widget".to_string(),
+        conversation_id: None,
+        new_conversation: false,
+        subject: Some("Chat".to_string()),
+        external_message_id: None,
+        channel: None,
+        channel_metadata: None,
+    };
+    let response = service.receive_message("inbox-001", request).await.unwrap();
+    assert_eq!(response.contact_id, contact.id);
+
+    // Later messages from the same visitor resolve through the channel identity
+    let response = service
+        .receive_message("inbox-001", sms("widget-visitor-1", "Still there?", None))
+        .await
+        .unwrap();
+    assert_eq!(response.contact_id, contact.id);
+    assert!(!response.created_conversation);
+}
+
+#[tokio::test]
+async fn test_channel_message_validation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = channel_service(db);
+
+    let mut request = sms("+15550100", "Hello", None);
+    request.sender.external_id = None;
+    let result = service.receive_message("inbox-001", request).await;
+    assert!(matches!(result, Err(ChannelError::Validation(_))));
+
+    let result = service
+        .receive_message("missing-inbox", sms("+15550100", "Hello", None))
+        .await;
+    assert!(matches!(result, Err(ChannelError::NotFound(_))));
+
+    // Conversations belonging to another contact can't be targeted
+    let other = create_test_contact(db, "other@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        other.id,
+        ConversationStatus::Open,
+    )
+    .await;
+    let mut request = sms("+15550100", "Hello", None);
+    request.conversation_id = Some(conversation.id);
+    let result = service.receive_message("inbox-001", request).await;
+    assert!(matches!(result, Err(ChannelError::NotFound(_))));
+}
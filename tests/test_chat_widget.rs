@@ -0,0 +1,358 @@
+// Integration tests for the web chat widget channel
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    domain::ports::{contact_repository::ContactRepository, message_repository::MessageRepository},
+    domain::services::sign_widget_identity,
+    infrastructure::{
+        persistence::Database,
+        providers::{
+            connection_manager::{ConnectionManager, InMemoryConnectionManager},
+            ChannelDeliveryProvider, ChatWidgetDeliveryProvider,
+        },
+    },
+};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+mod helpers;
+use helpers::*;
+
+const CHAT_INBOX: &str = "inbox-chat";
+const IDENTITY_SECRET: &str = "host-site-identity-secret";
+
+async fn create_chat_inbox(db: &Database) {
+    sqlx::query(
+        "INSERT INTO inboxes (id, name, channel_type, created_at, updated_at)
+         VALUES (?, 'Website chat', 'chat', datetime('now'), datetime('now'))",
+    )
+    .bind(CHAT_INBOX)
+    .execute(db.pool())
+    .await
+    .unwrap();
+}
+
+fn chat_widget_service(
+    db: &Database,
+    connections: Arc<InMemoryConnectionManager>,
+) -> ChatWidgetService {
+    let repo = Arc::new(db.clone());
+    let channel_service = ChannelService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        MessageService::new(repo.clone(), repo.clone()),
+    );
+    ChatWidgetService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo,
+        channel_service,
+        connections,
+    )
+}
+
+async fn resume(service: &ChatWidgetService, session: &WidgetSession) -> WidgetSession {
+    service
+        .start_session(
+            CHAT_INBOX,
+            &WidgetConnectQuery {
+                visitor_token: Some(session.visitor_token.clone()),
+                conversation_id: session.conversation_id.clone(),
+            },
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_widget_requires_chat_inbox() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_chat_inbox(db).await;
+    let service = chat_widget_service(db, Arc::new(InMemoryConnectionManager::new()));
+
+    assert!(service.widget_inbox(CHAT_INBOX).await.is_ok());
+    let result = service
+        .start_session("inbox-001", &WidgetConnectQuery::default())
+        .await;
+    assert!(matches!(result, Err(ChannelError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_visitor_session_resumes_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_chat_inbox(db).await;
+    let service = chat_widget_service(db, Arc::new(InMemoryConnectionManager::new()));
+
+    // Visitors stay anonymous, without a contact, until they write
+    let mut session = service
+        .start_session(CHAT_INBOX, &WidgetConnectQuery::default())
+        .await
+        .unwrap();
+    assert_eq!(session.visitor_token.len(), 32);
+    assert!(session.contact.is_none());
+    assert!(session.conversation_id.is_none());
+
+    let message = service
+        .send_message(&mut session, "Do you ship to Canada?".to_string())
+        .await
+        .unwrap();
+    assert!(message.from_visitor);
+    let conversation_id = session.conversation_id.clone().unwrap();
+    assert_eq!(message.conversation_id, conversation_id);
+    assert!(session.contact.is_some());
+    assert!(!session.identified);
+
+    let mut resumed = resume(&service, &session).await;
+    assert_eq!(
+        resumed.conversation_id.as_deref(),
+        Some(conversation_id.as_str())
+    );
+    let history = service.history(&resumed).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].id, message.id);
+
+    service
+        .send_message(&mut resumed, "Or to Mexico?".to_string())
+        .await
+        .unwrap();
+    assert_eq!(
+        resumed.conversation_id.as_deref(),
+        Some(conversation_id.as_str())
+    );
+    assert_eq!(db.count_messages(&conversation_id).await.unwrap(), 2);
+
+    // Another visitor can't resume someone else's conversation
+    let stranger = service
+        .start_session(
+            CHAT_INBOX,
+            &WidgetConnectQuery {
+                visitor_token: None,
+                conversation_id: Some(conversation_id.clone()),
+            },
+        )
+        .await
+        .unwrap();
+    assert_ne!(stranger.visitor_token, session.visitor_token);
+    assert!(stranger.conversation_id.is_none());
+    assert!(service.history(&stranger).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_identify_promotes_anonymous_visitor() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_chat_inbox(db).await;
+    let service = chat_widget_service(db, Arc::new(InMemoryConnectionManager::new()));
+
+    let mut session = service
+        .start_session(CHAT_INBOX, &WidgetConnectQuery::default())
+        .await
+        .unwrap();
+    service
+        .send_message(&mut session, "Hello".to_string())
+        .await
+        .unwrap();
+    let visitor = session.contact.clone().unwrap();
+
+    let result = service
+        .identify(&mut session, "not-an-email", None, None)
+        .await;
+    assert!(matches!(result, Err(ChannelError::Validation(_))));
+
+    service
+        .identify(&mut session, "Visitor@Example.com", Some("Vera"), None)
+        .await
+        .unwrap();
+    assert!(session.identified);
+    let contact = db
+        .get_contact_by_email("visitor@example.com")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(contact.id, visitor.id);
+    assert_eq!(contact.first_name.as_deref(), Some("Vera"));
+
+    let resumed = resume(&service, &session).await;
+    assert!(resumed.identified);
+    assert_eq!(resumed.contact.unwrap().id, visitor.id);
+}
+
+#[tokio::test]
+async fn test_identify_merges_into_existing_contact() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_chat_inbox(db).await;
+    let service = chat_widget_service(db, Arc::new(InMemoryConnectionManager::new()))
+        .with_identity_secret(IDENTITY_SECRET);
+    let existing = create_test_contact(db, "customer@example.com").await;
+
+    let mut session = service
+        .start_session(CHAT_INBOX, &WidgetConnectQuery::default())
+        .await
+        .unwrap();
+    let message = service
+        .send_message(&mut session, "Where is my refund?".to_string())
+        .await
+        .unwrap();
+    let placeholder = session.contact.clone().unwrap();
+
+    let signature = sign_widget_identity("customer@example.com", IDENTITY_SECRET);
+    service
+        .identify(&mut session, "Customer@Example.com", None, Some(&signature))
+        .await
+        .unwrap();
+    assert_eq!(session.contact.as_ref().unwrap().id, existing.id);
+
+    // The visitor's conversation and messages now belong to the known contact
+    let conversation = db
+        .get_conversation_by_id(session.conversation_id.as_ref().unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(conversation.contact_id, existing.id);
+    let stored = db.get_message_by_id(&message.id).await.unwrap().unwrap();
+    assert_eq!(stored.author_id, existing.user_id);
+    assert!(db
+        .find_contact_by_user_id(&placeholder.user_id)
+        .await
+        .unwrap()
+        .is_none());
+
+    let resumed = resume(&service, &session).await;
+    assert!(resumed.identified);
+    assert_eq!(resumed.conversation_id, session.conversation_id);
+    assert_eq!(service.history(&resumed).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_unverified_identify_keeps_visitor_separate() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_chat_inbox(db).await;
+    let service = chat_widget_service(db, Arc::new(InMemoryConnectionManager::new()))
+        .with_identity_secret(IDENTITY_SECRET);
+    let existing = create_test_contact(db, "customer@example.com").await;
+    let mut session = service
+        .start_session(CHAT_INBOX, &WidgetConnectQuery::default())
+        .await
+        .unwrap();
+
+    // Claiming the email before sending anything doesn't attach the visitor
+    service
+        .identify(&mut session, "customer@example.com", None, None)
+        .await
+        .unwrap();
+    assert!(session.contact.is_none());
+    assert!(!session.identified);
+
+    service
+        .send_message(&mut session, "Show me my orders".to_string())
+        .await
+        .unwrap();
+    let placeholder = session.contact.clone().unwrap();
+    assert_ne!(placeholder.id, existing.id);
+
+    // Neither a missing, forged nor other-email signature merges the visitor
+    let forged = sign_widget_identity("customer@example.com", "guessed-secret");
+    let other = sign_widget_identity("visitor@example.com", IDENTITY_SECRET);
+    for signature in [None, Some(forged.as_str()), Some(other.as_str())] {
+        service
+            .identify(&mut session, "customer@example.com", None, signature)
+            .await
+            .unwrap();
+        assert_eq!(session.contact.as_ref().unwrap().id, placeholder.id);
+        assert!(!session.identified);
+    }
+
+    let conversation = db
+        .get_conversation_by_id(session.conversation_id.as_ref().unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(conversation.contact_id, placeholder.id);
+    let stranger = resume(&service, &session).await;
+    assert!(!stranger.identified);
+    assert_eq!(stranger.contact.unwrap().id, placeholder.id);
+
+    // Without a configured secret, no signature is accepted
+    let unconfigured = chat_widget_service(db, Arc::new(InMemoryConnectionManager::new()));
+    let signature = sign_widget_identity("customer@example.com", IDENTITY_SECRET);
+    unconfigured
+        .identify(&mut session, "customer@example.com", None, Some(&signature))
+        .await
+        .unwrap();
+    assert_eq!(session.contact.as_ref().unwrap().id, placeholder.id);
+}
+
+#[tokio::test]
+async fn test_agent_reply_reaches_connected_visitor() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_chat_inbox(db).await;
+    let connections = Arc::new(InMemoryConnectionManager::new());
+    let service = chat_widget_service(db, connections.clone());
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+
+    let mut session = service
+        .start_session(CHAT_INBOX, &WidgetConnectQuery::default())
+        .await
+        .unwrap();
+    service
+        .send_message(&mut session, "Anyone there?".to_string())
+        .await
+        .unwrap();
+    let conversation_id = session.conversation_id.clone().unwrap();
+    let (tx, mut rx) = mpsc::channel(10);
+    connections.add_connection(&conversation_id, tx).await;
+
+    // Chat inboxes are routed to the widget; everything else to the default provider
+    let repo = Arc::new(db.clone());
+    let delivery = ChannelDeliveryProvider::new(
+        repo.clone(),
+        repo,
+        Arc::new(MockDeliveryProvider::new_failing()),
+    )
+    .with_provider(
+        CHAT_CHANNEL_TYPE,
+        Arc::new(ChatWidgetDeliveryProvider::new(connections.clone())),
+    );
+    let reply = Message::new_outgoing(
+        conversation_id.clone(),
+        "Yes, how can I help?".to_string(),
+        agent.user_id.clone(),
+    );
+    db.create_message(&reply).await.unwrap();
+    delivery.deliver(&reply).await.unwrap();
+
+    let event = rx.recv().await.unwrap();
+    assert_eq!(event.type_, CHAT_MESSAGE_EVENT_TYPE);
+    let pushed = service
+        .reply_for_visitor(&session, event.message_id.as_deref().unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(pushed.content, "Yes, how can I help?");
+    assert!(!pushed.from_visitor);
+
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let email_conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id,
+        ConversationStatus::Open,
+    )
+    .await;
+    let email_reply = Message::new_outgoing(
+        email_conversation.id,
+        "Thanks for writing in".to_string(),
+        agent.user_id,
+    );
+    assert!(delivery.deliver(&email_reply).await.is_err());
+}
@@ -0,0 +1,95 @@
+// Integration tests for client address resolution behind trusted proxies
+use axum::{
+    body::{to_bytes, Body},
+    extract::connect_info::MockConnectInfo,
+    http::Request,
+    routing::get,
+    Router,
+};
+use oxidesk::{
+    infrastructure::http::middleware::{client_info_middleware, ClientInfo},
+    shared::trusted_proxies::{IpCidr, TrustedProxies},
+};
+use std::net::SocketAddr;
+use tower::ServiceExt;
+
+async fn whoami(client: ClientInfo) -> String {
+    client.ip_address()
+}
+
+async fn client_ip(
+    proxies: Option<&[&str]>,
+    peer: Option<&str>,
+    headers: &[(&str, &str)],
+) -> String {
+    let mut app = Router::new().route("/whoami", get(whoami));
+    if let Some(proxies) = proxies {
+        let proxies = TrustedProxies::new(
+            proxies
+                .iter()
+                .map(|network| IpCidr::parse(network).unwrap())
+                .collect(),
+        );
+        app = app.layer(axum::middleware::from_fn_with_state(
+            proxies,
+            client_info_middleware,
+        ));
+    }
+    if let Some(peer) = peer {
+        let peer: SocketAddr = format!("{}:40000", peer).parse().unwrap();
+        app = app.layer(MockConnectInfo(peer));
+    }
+
+    let mut request = Request::get("/whoami");
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    let response = app
+        .oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn test_forwarded_address_from_trusted_proxy() {
+    let proxies: &[&str] = &["10.0.0.0/8"];
+    let headers = [("x-forwarded-for", "203.0.113.50, 10.0.0.7")];
+
+    assert_eq!(
+        client_ip(Some(proxies), Some("10.0.0.1"), &headers).await,
+        "203.0.113.50"
+    );
+    assert_eq!(
+        client_ip(
+            Some(proxies),
+            Some("10.0.0.1"),
+            &[("forwarded", "for=198.51.100.2;proto=https")]
+        )
+        .await,
+        "198.51.100.2"
+    );
+}
+
+#[tokio::test]
+async fn test_spoofed_headers_are_ignored() {
+    let headers = [("x-forwarded-for", "203.0.113.50")];
+
+    // Direct connections from outside the trusted networks
+    assert_eq!(
+        client_ip(Some(&["10.0.0.0/8"]), Some("198.51.100.9"), &headers).await,
+        "198.51.100.9"
+    );
+    // No trusted proxies configured
+    assert_eq!(
+        client_ip(Some(&[]), Some("10.0.0.1"), &headers).await,
+        "10.0.0.1"
+    );
+    // Without the middleware the extractor still reports the peer only
+    assert_eq!(
+        client_ip(None, Some("10.0.0.1"), &headers).await,
+        "10.0.0.1"
+    );
+    assert_eq!(client_ip(None, None, &headers).await, "unknown");
+}
@@ -378,5 +378,10 @@ fn create_test_conversation_minimal() -> Conversation {
         version: 1,
         tags: None,
         priority: None,
+        contact_email_verified: None,
+        csat_score: None,
+        sentiment_score: None,
+        sentiment_trend: None,
+        summary: None,
     }
 }
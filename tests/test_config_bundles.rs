@@ -0,0 +1,223 @@
+// Integration tests for configuration bundle export/import
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    domain::ports::{
+        macro_repository::MacroRepository, tag_repository::TagRepository,
+        webhook_repository::WebhookRepository,
+    },
+    infrastructure::persistence::Database,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+fn config_bundle_service(db: &Database) -> ConfigBundleService {
+    let repo = Arc::new(db.clone());
+    ConfigBundleService::new(
+        repo.clone(),
+        MacroRepository::new(db.clone()),
+        TagRepository::new(db.clone()),
+        repo,
+        WebhookRepository::new(db.clone()),
+    )
+}
+
+fn sample_bundle() -> ConfigBundle {
+    serde_json::from_value(json!({
+        "version": CONFIG_BUNDLE_VERSION,
+        "tags": [
+            {"name": "VIP", "description": "Key accounts", "color": "#FF0000"}
+        ],
+        "sla_policies": [
+            {
+                "name": "Standard",
+                "description": null,
+                "first_response_time": "2h",
+                "resolution_time": "24h",
+                "next_response_time": "4h"
+            }
+        ],
+        "macros": [
+            {
+                "name": "Close as resolved",
+                "message_content": "Glad we could help!",
+                "access_control": "all",
+                "actions": [{"action_type": "set_status", "action_value": "resolved"}]
+            }
+        ],
+        "automation_rules": [
+            {
+                "name": "Tag urgent",
+                "description": null,
+                "enabled": true,
+                "rule_type": "conversation_update",
+                "event_subscription": ["conversation.priority_changed"],
+                "condition": {
+                    "operator": "simple",
+                    "attribute": "priority",
+                    "comparison": "equals",
+                    "value": "High"
+                },
+                "action": {"action_type": "add_tag", "parameters": {"tag": "VIP"}},
+                "priority": 100
+            }
+        ],
+        "webhooks": [
+            {
+                "name": "CRM sync",
+                "url": "https://crm.example.com/hooks",
+                "subscribed_events": ["conversation.created"],
+                "is_active": true
+            }
+        ]
+    }))
+    .expect("Invalid bundle")
+}
+
+fn import_request(
+    bundle: ConfigBundle,
+    dry_run: bool,
+    on_conflict: ConflictStrategy,
+) -> ImportConfigRequest {
+    ImportConfigRequest {
+        bundle,
+        dry_run,
+        on_conflict,
+    }
+}
+
+#[tokio::test]
+async fn test_import_and_export_round_trip() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_agent(db, "admin@example.com", "Admin").await;
+    let service = config_bundle_service(db);
+
+    let response = service
+        .import(
+            import_request(sample_bundle(), false, ConflictStrategy::Skip),
+            &admin.user_id,
+        )
+        .await
+        .expect("Import failed");
+    assert!(response.applied);
+    assert_eq!(response.created, 5);
+
+    let exported = service.export().await.unwrap();
+    assert_eq!(exported.version, CONFIG_BUNDLE_VERSION);
+    assert_eq!(exported.tags.len(), 1);
+    assert_eq!(exported.macros[0].actions.len(), 1);
+    assert_eq!(exported.automation_rules[0].name, "Tag urgent");
+    // Webhook secrets never leave the system
+    let json = serde_json::to_string(&exported).unwrap();
+    assert!(!json.contains("secret"));
+
+    // Importing the export again changes nothing
+    let response = service
+        .import(
+            import_request(exported, false, ConflictStrategy::Fail),
+            &admin.user_id,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.unchanged, 5);
+    assert_eq!(response.created + response.updated, 0);
+}
+
+#[tokio::test]
+async fn test_conflict_strategies_and_dry_run() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_agent(db, "admin@example.com", "Admin").await;
+    let service = config_bundle_service(db);
+    service
+        .import(
+            import_request(sample_bundle(), false, ConflictStrategy::Skip),
+            &admin.user_id,
+        )
+        .await
+        .unwrap();
+
+    let mut changed = sample_bundle();
+    changed.tags[0].color = Some("#00FF00".to_string());
+    changed.sla_policies[0].resolution_time = "48h".to_string();
+
+    // Dry run reports the diff without writing
+    let preview = service
+        .import(
+            import_request(changed.clone(), true, ConflictStrategy::Overwrite),
+            &admin.user_id,
+        )
+        .await
+        .unwrap();
+    assert!(!preview.applied);
+    assert_eq!(preview.updated, 2);
+    let tag_change = preview.changes.iter().find(|c| c.kind == "tag").unwrap();
+    assert_eq!(tag_change.action, ImportAction::Update);
+    assert_eq!(tag_change.changed_fields, vec!["color".to_string()]);
+    assert_eq!(
+        service.export().await.unwrap().tags[0].color.as_deref(),
+        Some("#FF0000")
+    );
+
+    let result = service
+        .import(
+            import_request(changed.clone(), false, ConflictStrategy::Fail),
+            &admin.user_id,
+        )
+        .await;
+    assert!(matches!(result, Err(ConfigBundleError::Conflict(_))));
+
+    let skipped = service
+        .import(
+            import_request(changed.clone(), false, ConflictStrategy::Skip),
+            &admin.user_id,
+        )
+        .await
+        .unwrap();
+    assert_eq!(skipped.skipped, 2);
+
+    let applied = service
+        .import(
+            import_request(changed, false, ConflictStrategy::Overwrite),
+            &admin.user_id,
+        )
+        .await
+        .unwrap();
+    assert_eq!(applied.updated, 2);
+    let exported = service.export().await.unwrap();
+    assert_eq!(exported.tags[0].color.as_deref(), Some("#00FF00"));
+    assert_eq!(exported.sla_policies[0].resolution_time, "48h");
+}
+
+#[tokio::test]
+async fn test_invalid_bundles_are_rejected() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_agent(db, "admin@example.com", "Admin").await;
+    let service = config_bundle_service(db);
+
+    let mut bundle = sample_bundle();
+    bundle.version = CONFIG_BUNDLE_VERSION + 1;
+    let result = service
+        .import(
+            import_request(bundle, true, ConflictStrategy::Skip),
+            &admin.user_id,
+        )
+        .await;
+    assert!(matches!(result, Err(ConfigBundleError::Validation(_))));
+
+    let mut bundle = sample_bundle();
+    bundle.sla_policies[0].first_response_time = "soon".to_string();
+    let result = service
+        .import(
+            import_request(bundle, false, ConflictStrategy::Skip),
+            &admin.user_id,
+        )
+        .await;
+    assert!(matches!(result, Err(ConfigBundleError::Validation(_))));
+    assert!(service.export().await.unwrap().tags.is_empty());
+}
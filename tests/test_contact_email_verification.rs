@@ -0,0 +1,163 @@
+// Integration tests for contact-facing email address verification
+use oxidesk::{
+    application::services::*, domain::entities::*,
+    domain::ports::contact_repository::ContactRepository, infrastructure::persistence::Database,
+};
+use sqlx::Row;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+fn services(db: &Database) -> (ContactService, ConversationService, Mailbox) {
+    let repo = Arc::new(db.clone());
+    let (email_sender, mailbox) = recording_email_sender();
+    let mut contact_service = ContactService::new(repo.clone(), repo.clone());
+    contact_service.set_email_sender(email_sender, "https://desk.example.com/");
+    (
+        contact_service,
+        ConversationService::new(repo.clone(), repo.clone(), repo.clone(), repo),
+        mailbox,
+    )
+}
+
+async fn stored_token_hash(db: &Database, channel_id: &str) -> String {
+    let row = sqlx::query(
+        "SELECT token_hash FROM contact_email_verifications
+         WHERE channel_id = ? AND used = 0
+         ORDER BY created_at DESC LIMIT 1",
+    )
+    .bind(channel_id)
+    .fetch_one(db.pool())
+    .await
+    .expect("Verification token should exist");
+    row.try_get("token_hash").unwrap()
+}
+
+#[tokio::test]
+async fn test_manual_contact_requires_verification_before_outbound() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let (contact_service, conversation_service, mut mailbox) = services(db);
+
+    let contact = contact_service
+        .create_contact(
+            &admin,
+            CreateContactRequest {
+                email: "new.customer@example.com".to_string(),
+                first_name: Some("New".to_string()),
+                inbox_id: "inbox-001".to_string(),
+            },
+        )
+        .await
+        .expect("Failed to create contact");
+    assert_eq!(contact.channels.len(), 1);
+    let channel = contact.channels[0].clone();
+    assert!(!channel.email_verified);
+    assert!(channel.email_verified_at.is_none());
+
+    let request = CreateConversation {
+        inbox_id: "inbox-001".to_string(),
+        contact_id: contact.id.clone(),
+        subject: Some("Welcome".to_string()),
+    };
+    let result = conversation_service
+        .create_conversation(&admin, request.clone(), None)
+        .await;
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("has not been verified"));
+
+    let email = mailbox.next_email().await;
+    assert_eq!(email.to, "new.customer@example.com");
+    assert!(email
+        .text_body
+        .contains("https://desk.example.com/api/contacts/verify-email?token="));
+    let token = extract_reset_token(&email.text_body).expect("Email has no token");
+
+    // Only the hash of the token is stored
+    let token_hash = stored_token_hash(db, &channel.id).await;
+    assert_ne!(token_hash, token);
+    assert_eq!(token_hash, ContactEmailVerification::hash_token(&token));
+
+    let verified = contact_service
+        .verify_email(&token)
+        .await
+        .expect("Verification should succeed");
+    assert!(verified.email_verified);
+    assert!(verified.email_verified_at.is_some());
+
+    // Tokens are single-use
+    assert!(contact_service.verify_email(&token).await.is_err());
+    assert!(contact_service.verify_email("not-a-token").await.is_err());
+
+    let conversation = conversation_service
+        .create_conversation(&admin, request, None)
+        .await
+        .expect("Verified contact should allow outbound conversations");
+    let fetched = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(fetched.contact_email_verified, Some(true));
+}
+
+#[tokio::test]
+async fn test_inbound_contacts_are_verified() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+
+    let contact_id = db
+        .create_contact_from_message("sender@example.com", Some("Sender"), "inbox-001")
+        .await
+        .unwrap();
+    let channels = db.find_contact_channels(&contact_id).await.unwrap();
+    assert_eq!(channels.len(), 1);
+    assert!(channels[0].email_verified);
+}
+
+#[tokio::test]
+async fn test_resend_and_expired_tokens() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let (contact_service, _, mut mailbox) = services(db);
+
+    let contact = contact_service
+        .create_contact(
+            &admin,
+            CreateContactRequest {
+                email: "later@example.com".to_string(),
+                first_name: None,
+                inbox_id: "inbox-001".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    let channel_id = contact.channels[0].id.clone();
+    mailbox.next_email().await;
+
+    // Expired links are rejected
+    let mut expired = ContactEmailVerification::new(channel_id.clone(), "expired-token");
+    expired.expires_at = (chrono::Utc::now() - chrono::Duration::hours(1)).to_rfc3339();
+    db.create_email_verification(&expired).await.unwrap();
+    let result = contact_service.verify_email("expired-token").await;
+    assert!(result.unwrap_err().to_string().contains("expired"));
+
+    // Re-sending issues a fresh, working link
+    contact_service
+        .resend_email_verification(&admin, &contact.id, &channel_id)
+        .await
+        .expect("Resend should succeed");
+    let token = mailbox.next_token().await;
+    contact_service.verify_email(&token).await.unwrap();
+
+    // Nothing left to verify
+    let result = contact_service
+        .resend_email_verification(&admin, &contact.id, &channel_id)
+        .await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,171 @@
+// Integration tests for contact notes
+use std::sync::Arc;
+
+use oxidesk::application::services::ContactNoteService;
+use oxidesk::domain::entities::{
+    ConversationStatus, CreateContactNoteRequest, UpdateContactNoteRequest,
+};
+use oxidesk::domain::errors::ContactNoteError;
+use oxidesk::infrastructure::persistence::Database;
+
+mod helpers;
+use helpers::rbac_helpers::{create_auth_user_with_roles, ensure_admin_role};
+use helpers::*;
+
+fn service(db: &Database) -> ContactNoteService {
+    ContactNoteService::new(Arc::new(db.clone()), Arc::new(db.clone()))
+}
+
+fn note(content: &str, pinned: bool) -> CreateContactNoteRequest {
+    CreateContactNoteRequest {
+        content: content.to_string(),
+        pinned,
+    }
+}
+
+#[tokio::test]
+async fn test_notes_are_listed_pinned_first_with_author() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = service(db);
+    let contact = create_test_contact(db, "vip@example.com").await;
+    let agent = create_auth_user_with_roles(db, "ana@example.com", "Ana", vec![]).await;
+
+    let first = service
+        .create_note(&agent, &contact.user_id, note("Prefers email", false))
+        .await
+        .unwrap();
+    let vip = service
+        .create_note(&agent, &contact.user_id, note("VIP billing", true))
+        .await
+        .unwrap();
+    assert!(vip.pinned);
+    assert!(vip.pinned_at.is_some());
+    assert_eq!(vip.author_id.as_deref(), Some(agent.user.id.as_str()));
+    assert_eq!(vip.author_name.as_deref(), Some("Ana"));
+
+    let notes = service.list_notes(&contact.user_id).await.unwrap();
+    let ids: Vec<_> = notes.iter().map(|n| n.id.as_str()).collect();
+    assert_eq!(ids, vec![vip.id.as_str(), first.id.as_str()]);
+
+    let pinned = service.list_pinned_notes(&contact.user_id).await.unwrap();
+    assert_eq!(pinned.len(), 1);
+    assert_eq!(pinned[0].content, "VIP billing");
+}
+
+#[tokio::test]
+async fn test_any_agent_can_pin_but_only_author_or_admin_can_edit() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = service(db);
+    let contact = create_test_contact(db, "billing@example.com").await;
+    let author = create_auth_user_with_roles(db, "author@example.com", "Author", vec![]).await;
+    let other = create_auth_user_with_roles(db, "other@example.com", "Other", vec![]).await;
+    let admin_role = ensure_admin_role(db).await;
+    let admin =
+        create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![admin_role]).await;
+
+    let created = service
+        .create_note(&author, &contact.user_id, note("Invoices go to AP", false))
+        .await
+        .unwrap();
+
+    let pin = UpdateContactNoteRequest {
+        content: None,
+        pinned: Some(true),
+    };
+    let pinned = service
+        .update_note(&other, &contact.user_id, &created.id, pin)
+        .await
+        .unwrap();
+    assert!(pinned.pinned);
+
+    let edit = UpdateContactNoteRequest {
+        content: Some("Rewritten".to_string()),
+        pinned: None,
+    };
+    let result = service
+        .update_note(&other, &contact.user_id, &created.id, edit)
+        .await;
+    assert!(matches!(result, Err(ContactNoteError::Forbidden(_))));
+    let result = service
+        .delete_note(&other, &contact.user_id, &created.id)
+        .await;
+    assert!(matches!(result, Err(ContactNoteError::Forbidden(_))));
+
+    let edit = UpdateContactNoteRequest {
+        content: Some("Invoices go to accounts payable".to_string()),
+        pinned: None,
+    };
+    let edited = service
+        .update_note(&admin, &contact.user_id, &created.id, edit)
+        .await
+        .unwrap();
+    assert_eq!(edited.content, "Invoices go to accounts payable");
+    assert!(edited.pinned);
+
+    service
+        .delete_note(&author, &contact.user_id, &created.id)
+        .await
+        .unwrap();
+    let notes = service.list_notes(&contact.user_id).await.unwrap();
+    assert!(notes.is_empty());
+}
+
+#[tokio::test]
+async fn test_notes_are_scoped_to_their_contact() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = service(db);
+    let contact = create_test_contact(db, "one@example.com").await;
+    let other_contact = create_test_contact(db, "two@example.com").await;
+    let agent = create_auth_user_with_roles(db, "agent@example.com", "Agent", vec![]).await;
+
+    let created = service
+        .create_note(&agent, &contact.user_id, note("Only for one", false))
+        .await
+        .unwrap();
+
+    let result = service
+        .delete_note(&agent, &other_contact.user_id, &created.id)
+        .await;
+    assert!(matches!(result, Err(ContactNoteError::NotFound(_))));
+
+    // Agents are users too, but not contacts
+    let result = service
+        .create_note(&agent, &agent.user.id, note("Not a contact", false))
+        .await;
+    assert!(matches!(result, Err(ContactNoteError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_pinned_notes_are_found_from_a_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = service(db);
+    let contact = create_test_contact(db, "sidebar@example.com").await;
+    let agent = create_auth_user_with_roles(db, "agent@example.com", "Agent", vec![]).await;
+    service
+        .create_note(&agent, &contact.user_id, note("Call before 5pm", true))
+        .await
+        .unwrap();
+    service
+        .create_note(&agent, &contact.user_id, note("Not pinned", false))
+        .await
+        .unwrap();
+
+    // Conversations store the contact record id, notes the contact's user id
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    let pinned = service
+        .list_conversation_pinned_notes(&conversation.id)
+        .await
+        .unwrap();
+    assert_eq!(pinned.len(), 1);
+    assert_eq!(pinned[0].content, "Call before 5pm");
+}
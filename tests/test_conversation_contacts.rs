@@ -0,0 +1,283 @@
+// Integration tests for additional contacts on a conversation
+use oxidesk::application::services::{
+    AttachmentService, ContactService, ConversationContactService,
+};
+use oxidesk::domain::entities::*;
+use oxidesk::domain::errors::ConversationContactError;
+use oxidesk::domain::ports::message_repository::MessageRepository;
+use oxidesk::infrastructure::http::middleware::AuthenticatedUser;
+use oxidesk::infrastructure::persistence::Database;
+use oxidesk::infrastructure::providers::email_parser::EmailParserService;
+use oxidesk::infrastructure::providers::EmailReceiverService;
+use oxidesk::infrastructure::storage::local::LocalFileStorage;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::{create_auth_user_with_roles, create_test_role};
+use helpers::*;
+
+fn contact_service(db: &Database) -> ConversationContactService {
+    let repo = Arc::new(db.clone());
+    ConversationContactService::new(repo.clone(), repo.clone(), repo.clone(), repo.clone(), repo)
+}
+
+/// A conversation with the customer as its contact; returns (conversation, contact)
+async fn customer_conversation(db: &Database) -> (Conversation, Contact) {
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    (conversation, contact)
+}
+
+async fn supervisor(db: &Database) -> AuthenticatedUser {
+    let role = create_test_role(
+        db,
+        "Supervisor",
+        None,
+        vec![
+            "conversations:read_all".to_string(),
+            "conversations:update_all".to_string(),
+        ],
+    )
+    .await;
+    create_auth_user_with_roles(db, "lead@example.com", "Lead", vec![role]).await
+}
+
+fn add_request(contact_id: &str) -> AddConversationContactRequest {
+    AddConversationContactRequest {
+        contact_id: contact_id.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_add_and_remove_contact_updates_outbound_email() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let (conversation, customer) = customer_conversation(db).await;
+    db.add_email_participant(&conversation.id, "customer@example.com")
+        .await
+        .unwrap();
+    let colleague = create_test_contact(db, "colleague@example.com").await;
+    let agent = supervisor(db).await;
+    let service = contact_service(db);
+
+    let added = service
+        .add_contact(&agent, &conversation.id, add_request(&colleague.id))
+        .await
+        .unwrap();
+    assert_eq!(added.email, "colleague@example.com");
+    assert!(!added.is_primary);
+    assert_eq!(added.added_by.as_deref(), Some(agent.user.id.as_str()));
+
+    let contacts = service
+        .list_contacts(&agent, &conversation.id)
+        .await
+        .unwrap();
+    assert_eq!(contacts.len(), 2);
+    assert_eq!(contacts[0].contact_id, customer.id);
+    assert!(contacts[0].is_primary);
+    assert_eq!(contacts[1].contact_id, colleague.id);
+
+    // The colleague is copied on replies
+    let participants = db.list_email_participants(&conversation.id).await.unwrap();
+    let recipients = EmailRecipients::for_reply("customer@example.com", &participants);
+    assert_eq!(recipients.cc, vec!["colleague@example.com"]);
+
+    service
+        .remove_contact(&agent, &conversation.id, &colleague.id)
+        .await
+        .unwrap();
+    let contacts = service
+        .list_contacts(&agent, &conversation.id)
+        .await
+        .unwrap();
+    assert_eq!(contacts.len(), 1);
+
+    let participants = db.list_email_participants(&conversation.id).await.unwrap();
+    let recipients = EmailRecipients::for_reply("customer@example.com", &participants);
+    assert!(recipients.cc.is_empty());
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_primary_and_duplicate_contacts_rejected() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let (conversation, customer) = customer_conversation(db).await;
+    let colleague = create_test_contact(db, "colleague@example.com").await;
+    let agent = supervisor(db).await;
+    let service = contact_service(db);
+
+    let result = service
+        .add_contact(&agent, &conversation.id, add_request(&customer.id))
+        .await;
+    assert!(matches!(result, Err(ConversationContactError::Conflict(_))));
+
+    service
+        .add_contact(&agent, &conversation.id, add_request(&colleague.id))
+        .await
+        .unwrap();
+    let result = service
+        .add_contact(&agent, &conversation.id, add_request(&colleague.id))
+        .await;
+    assert!(matches!(result, Err(ConversationContactError::Conflict(_))));
+
+    let result = service
+        .add_contact(&agent, &conversation.id, add_request("missing"))
+        .await;
+    assert!(matches!(result, Err(ConversationContactError::NotFound(_))));
+
+    let result = service
+        .remove_contact(&agent, &conversation.id, &customer.id)
+        .await;
+    assert!(matches!(
+        result,
+        Err(ConversationContactError::Validation(_))
+    ));
+
+    let result = service
+        .remove_contact(&agent, &conversation.id, "missing")
+        .await;
+    assert!(matches!(result, Err(ConversationContactError::NotFound(_))));
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_contact_added_by_reply_without_email_thread() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let (conversation, customer) = customer_conversation(db).await;
+    let colleague = create_test_contact(db, "colleague@example.com").await;
+
+    // As recorded when the colleague replies on the thread
+    assert!(db
+        .add_conversation_contact(&conversation.id, &colleague.id, None)
+        .await
+        .unwrap());
+    assert!(!db
+        .add_conversation_contact(&conversation.id, &colleague.id, None)
+        .await
+        .unwrap());
+    // The primary contact is never stored twice
+    assert!(!db
+        .add_conversation_contact(&conversation.id, &customer.id, None)
+        .await
+        .unwrap());
+
+    let contacts = db
+        .list_conversation_contacts(&conversation.id)
+        .await
+        .unwrap();
+    assert_eq!(contacts.len(), 2);
+    assert!(contacts[1].added_by.is_none());
+
+    // Not an email conversation, so no email participants appear
+    let agent = supervisor(db).await;
+    let other = create_test_contact(db, "other@example.com").await;
+    contact_service(db)
+        .add_contact(&agent, &conversation.id, add_request(&other.id))
+        .await
+        .unwrap();
+    assert!(db
+        .list_email_participants(&conversation.id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_reply_from_cc_colleague_joins_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let (conversation, _) = customer_conversation(db).await;
+    let colleague = create_test_contact(db, "colleague@example.com").await;
+
+    let repo = Arc::new(db.clone());
+    let storage_dir = std::env::temp_dir().join(format!("oxidesk-test-{}", uuid::Uuid::new_v4()));
+    let mut receiver = EmailReceiverService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        ContactService::new(repo.clone(), repo.clone()),
+        AttachmentService::new(repo.clone(), Arc::new(LocalFileStorage::new(storage_dir))),
+    );
+    receiver.set_conversation_contact_repo(repo);
+
+    let raw = format!(
+        "From: colleague@example.com\n\
+         Message-ID: <colleague-reply@example.com>\n\
+         Subject: Re: Invoice [#{}]\n\
+         \n\
+         Adding the PO number here.\n",
+        conversation.reference_number
+    );
+    let parsed = EmailParserService::new()
+        .parse_email(raw.replace('\n', "\r\n").as_bytes())
+        .unwrap();
+    let log = EmailProcessingLog::new(
+        "inbox-001".to_string(),
+        parsed.message_id.clone(),
+        parsed.from_address.clone(),
+        parsed.subject.clone(),
+    );
+    let log = receiver
+        .ingest_email("inbox-001", 1, &parsed, log)
+        .await
+        .unwrap();
+    assert_eq!(
+        log.conversation_id.as_deref(),
+        Some(conversation.id.as_str())
+    );
+
+    // The message is the colleague's, and they now take part
+    let message = db
+        .get_message_by_id(log.message_id.as_deref().unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(message.author_id, colleague.user_id);
+    let contacts = db
+        .list_conversation_contacts(&conversation.id)
+        .await
+        .unwrap();
+    assert_eq!(contacts.len(), 2);
+    assert_eq!(contacts[1].contact_id, colleague.id);
+    assert!(contacts[1].added_by.is_none());
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_contacts_require_conversation_permission() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let (conversation, _) = customer_conversation(db).await;
+    let colleague = create_test_contact(db, "colleague@example.com").await;
+    let user = create_auth_user_with_roles(db, "nobody@example.com", "Nobody", vec![]).await;
+    let service = contact_service(db);
+
+    let result = service.list_contacts(&user, &conversation.id).await;
+    assert!(matches!(
+        result,
+        Err(ConversationContactError::Forbidden(_))
+    ));
+
+    let result = service
+        .add_contact(&user, &conversation.id, add_request(&colleague.id))
+        .await;
+    assert!(matches!(
+        result,
+        Err(ConversationContactError::Forbidden(_))
+    ));
+
+    teardown_test_db(test_db).await;
+}
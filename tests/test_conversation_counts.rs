@@ -0,0 +1,151 @@
+// Integration tests for the conversation sidebar counters
+use chrono::{Duration, Utc};
+use oxidesk::{
+    application::services::ConversationService, domain::entities::*,
+    domain::ports::conversation_repository::ConversationRepository,
+    infrastructure::http::middleware::ApiError,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::{
+    add_user_to_team, create_auth_user_with_roles, create_conversation_assigned_to_team,
+    create_conversation_assigned_to_user, create_test_role, create_test_team,
+};
+use helpers::sla_helpers::{
+    create_test_applied_sla, create_test_sla_event, create_test_sla_policy,
+};
+use helpers::*;
+
+#[tokio::test]
+async fn test_sidebar_counts_for_admin() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let repo = Arc::new(db.clone());
+    let service = ConversationService::new(repo.clone(), repo.clone(), repo.clone(), repo.clone());
+    let admin = create_test_auth_user(db).await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+
+    // Two unassigned in inbox-001, one urgent and breaching within the hour
+    let urgent = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    db.set_conversation_priority(&urgent.id, &Priority::High)
+        .await
+        .unwrap();
+    let policy = create_test_sla_policy(db, "Standard", "1h", "24h", "2h").await;
+    let now = Utc::now();
+    let applied = create_test_applied_sla(
+        db,
+        &urgent.id,
+        &policy.id,
+        now + Duration::minutes(30),
+        now + Duration::hours(24),
+    )
+    .await;
+    create_test_sla_event(
+        db,
+        &applied.id,
+        SlaEventType::FirstResponse,
+        now + Duration::minutes(30),
+    )
+    .await;
+
+    let later = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    let applied = create_test_applied_sla(
+        db,
+        &later.id,
+        &policy.id,
+        now + Duration::hours(5),
+        now + Duration::hours(24),
+    )
+    .await;
+    create_test_sla_event(
+        db,
+        &applied.id,
+        SlaEventType::FirstResponse,
+        now + Duration::hours(5),
+    )
+    .await;
+
+    // Assigned to the admin in another inbox; resolved ones don't count
+    create_conversation_assigned_to_user(db, &contact.id, &admin.user.id).await;
+    create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Resolved,
+    )
+    .await;
+
+    let counts = service.get_sidebar_counts(&admin).await.unwrap();
+    assert_eq!(counts.mine, 1);
+    assert_eq!(counts.unassigned, 2);
+    assert_eq!(counts.urgent, 1);
+    assert_eq!(counts.breaching_soon, 1);
+    assert_eq!(
+        counts.inboxes,
+        vec![
+            InboxConversationCount {
+                inbox_id: "inbox-001".to_string(),
+                open: 2,
+            },
+            InboxConversationCount {
+                inbox_id: "test-inbox-rbac".to_string(),
+                open: 1,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_sidebar_counts_are_scoped_to_assigned_conversations() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let repo = Arc::new(db.clone());
+    let service = ConversationService::new(repo.clone(), repo.clone(), repo.clone(), repo.clone());
+    let role = create_test_role(
+        db,
+        "Support Agent",
+        None,
+        vec!["conversations:read_assigned".to_string()],
+    )
+    .await;
+    let agent = create_auth_user_with_roles(db, "agent@example.com", "Agent", vec![role]).await;
+    let team_id = create_test_team(db, "Billing").await;
+    add_user_to_team(db, &agent.user.id, &team_id).await;
+    let other_team_id = create_test_team(db, "Sales").await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+
+    create_conversation_assigned_to_user(db, &contact.id, &agent.user.id).await;
+    create_conversation_assigned_to_team(db, &contact.id, &team_id).await;
+    create_conversation_assigned_to_team(db, &contact.id, &other_team_id).await;
+    create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+
+    let counts = service.get_sidebar_counts(&agent).await.unwrap();
+    assert_eq!(counts.mine, 1);
+    // Only the team conversation is unassigned within the agent's scope
+    assert_eq!(counts.unassigned, 1);
+    assert_eq!(counts.inboxes.len(), 1);
+    assert_eq!(counts.inboxes[0].open, 2);
+
+    let outsider = create_auth_user_with_roles(db, "viewer@example.com", "Viewer", vec![]).await;
+    let result = service.get_sidebar_counts(&outsider).await;
+    assert!(matches!(result, Err(ApiError::Forbidden(_))));
+}
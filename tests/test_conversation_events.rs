@@ -0,0 +1,461 @@
+// Integration tests for the append-only conversation history
+mod helpers;
+
+use chrono::{Duration, Utc};
+use helpers::rbac_helpers::{create_auth_user_with_roles, create_test_role};
+use helpers::*;
+use oxidesk::application::services::ConversationService;
+use oxidesk::domain::entities::*;
+use oxidesk::infrastructure::http::middleware::ApiError;
+use oxidesk::infrastructure::persistence::Database;
+use std::sync::Arc;
+
+fn conversation_service(db: &Database) -> ConversationService {
+    let repo = Arc::new(db.clone());
+    ConversationService::new(repo.clone(), repo.clone(), repo.clone(), repo)
+}
+
+async fn new_conversation(db: &Database) -> Conversation {
+    let email = format!("history-{}@example.com", uuid::Uuid::new_v4());
+    let contact = create_test_contact(db, &email).await;
+    db.create_conversation(&CreateConversation {
+        inbox_id: "inbox-001".to_string(),
+        contact_id: contact.id,
+        subject: Some("Where is my order?".to_string()),
+    })
+    .await
+    .unwrap()
+}
+
+fn event_types(events: &[ConversationEvent]) -> Vec<ConversationEventType> {
+    events.iter().map(|event| event.event_type).collect()
+}
+
+#[tokio::test]
+async fn test_mutations_recorded_in_order() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation = new_conversation(db).await;
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let team_id = helpers::rbac_helpers::create_test_team(db, "Billing").await;
+    let tags = create_test_tags(db, vec![("billing", None, None), ("vip", None, None)]).await;
+
+    db.assign_conversation_to_user(
+        &conversation.id,
+        Some(agent.user_id.clone()),
+        Some(agent.user_id.clone()),
+    )
+    .await
+    .unwrap();
+    db.assign_conversation_to_team(&conversation.id, Some(team_id.clone()), None)
+        .await
+        .unwrap();
+    db.set_conversation_priority(&conversation.id, &Priority::High)
+        .await
+        .unwrap();
+    db.add_conversation_tag(&conversation.id, &tags[0].id, &agent.user_id)
+        .await
+        .unwrap();
+    db.update_conversation_status(&conversation.id, ConversationStatus::Resolved)
+        .await
+        .unwrap();
+
+    let events = db.get_conversation_events(&conversation.id).await.unwrap();
+    assert_eq!(
+        event_types(&events),
+        vec![
+            ConversationEventType::Created,
+            ConversationEventType::AssigneeChanged,
+            ConversationEventType::TeamChanged,
+            ConversationEventType::PriorityChanged,
+            ConversationEventType::TagAdded,
+            ConversationEventType::StatusChanged,
+        ]
+    );
+    assert!(events.windows(2).all(|pair| pair[0].id < pair[1].id));
+
+    let assigned = &events[1];
+    assert_eq!(assigned.actor_id.as_deref(), Some(agent.user_id.as_str()));
+    assert!(assigned.data["from"].is_null());
+    assert_eq!(assigned.data["to"], agent.user_id.as_str());
+    assert_eq!(events[3].data["to"], "High");
+    assert_eq!(events[4].data["tag"], "billing");
+    assert_eq!(events[5].data["from"], "open");
+    assert_eq!(events[5].data["to"], "resolved");
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_replay_rebuilds_current_state() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation = new_conversation(db).await;
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let tags = create_test_tags(
+        db,
+        vec![
+            ("billing", None, None),
+            ("vip", None, None),
+            ("refund", None, None),
+        ],
+    )
+    .await;
+
+    db.assign_conversation_to_user(&conversation.id, Some(agent.user_id.clone()), None)
+        .await
+        .unwrap();
+    db.unassign_conversation_user(&conversation.id)
+        .await
+        .unwrap();
+    db.set_conversation_priority(&conversation.id, &Priority::Low)
+        .await
+        .unwrap();
+    db.clear_conversation_priority(&conversation.id)
+        .await
+        .unwrap();
+    db.set_conversation_priority(&conversation.id, &Priority::Medium)
+        .await
+        .unwrap();
+    db.add_conversation_tag(&conversation.id, &tags[0].id, &agent.user_id)
+        .await
+        .unwrap();
+    db.add_conversation_tag(&conversation.id, &tags[1].id, &agent.user_id)
+        .await
+        .unwrap();
+    db.replace_conversation_tags(
+        &conversation.id,
+        &[tags[1].id.clone(), tags[2].id.clone()],
+        &agent.user_id,
+    )
+    .await
+    .unwrap();
+    db.update_conversation_fields(
+        &conversation.id,
+        ConversationStatus::Snoozed,
+        None,
+        None,
+        Some((Utc::now() + Duration::hours(2)).to_rfc3339()),
+    )
+    .await
+    .unwrap();
+
+    let events = db.get_conversation_events(&conversation.id).await.unwrap();
+    let state = ConversationStateSnapshot::replay(&events);
+    let current = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    let current_tags: Vec<String> = db
+        .get_conversation_tags(&conversation.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|tag| tag.id)
+        .collect();
+
+    assert_eq!(state.status, Some(current.status));
+    assert_eq!(state.assigned_user_id, current.assigned_user_id);
+    assert_eq!(state.assigned_team_id, current.assigned_team_id);
+    assert_eq!(state.priority, Some(Priority::Medium));
+    assert_eq!(
+        state.tag_ids,
+        current_tags
+            .into_iter()
+            .collect::<std::collections::BTreeSet<_>>()
+    );
+
+    // The replacement dropped "billing" and added "refund"; "vip" was kept
+    let tag_changes: Vec<(ConversationEventType, String)> = events
+        .iter()
+        .filter(|event| {
+            matches!(
+                event.event_type,
+                ConversationEventType::TagAdded | ConversationEventType::TagRemoved
+            )
+        })
+        .map(|event| {
+            (
+                event.event_type,
+                event.data["tag"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        tag_changes,
+        vec![
+            (ConversationEventType::TagAdded, "billing".to_string()),
+            (ConversationEventType::TagAdded, "vip".to_string()),
+            (ConversationEventType::TagRemoved, "billing".to_string()),
+            (ConversationEventType::TagAdded, "refund".to_string()),
+        ]
+    );
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_unchanged_values_not_recorded() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation = new_conversation(db).await;
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let tag = create_test_tag(db, "billing", None, None).await;
+
+    for _ in 0..2 {
+        db.set_conversation_priority(&conversation.id, &Priority::High)
+            .await
+            .unwrap();
+        db.update_conversation_status(&conversation.id, ConversationStatus::Open)
+            .await
+            .unwrap();
+        db.add_conversation_tag(&conversation.id, &tag.id, &agent.user_id)
+            .await
+            .unwrap();
+    }
+    db.remove_conversation_tag(&conversation.id, "no-such-tag")
+        .await
+        .unwrap();
+
+    let events = db.get_conversation_events(&conversation.id).await.unwrap();
+    assert_eq!(
+        event_types(&events),
+        vec![
+            ConversationEventType::Created,
+            ConversationEventType::PriorityChanged,
+            ConversationEventType::TagAdded,
+        ]
+    );
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_bulk_unassign_recorded_per_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let first = new_conversation(db).await;
+    let second = new_conversation(db).await;
+    for conversation in [&first, &second] {
+        db.assign_conversation_to_user(&conversation.id, Some(agent.user_id.clone()), None)
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(
+        db.unassign_agent_open_conversations(&agent.user_id)
+            .await
+            .unwrap(),
+        2
+    );
+
+    for conversation in [&first, &second] {
+        let events = db.get_conversation_events(&conversation.id).await.unwrap();
+        let last = events.last().unwrap();
+        assert_eq!(last.event_type, ConversationEventType::AssigneeChanged);
+        assert_eq!(last.data["from"], agent.user_id.as_str());
+        assert!(last.data["to"].is_null());
+        assert!(ConversationStateSnapshot::replay(&events)
+            .assigned_user_id
+            .is_none());
+    }
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_sla_changes_recorded() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation = new_conversation(db).await;
+    let policy = create_test_sla_policy(db, "Standard", "1h", "24h", "4h").await;
+    let now = Utc::now();
+    let applied = create_test_applied_sla(
+        db,
+        &conversation.id,
+        &policy.id,
+        now + Duration::hours(1),
+        now + Duration::hours(24),
+    )
+    .await;
+    let first_response =
+        create_test_sla_event(db, &applied.id, SlaEventType::FirstResponse, now).await;
+    let resolution = create_test_sla_event(
+        db,
+        &applied.id,
+        SlaEventType::Resolution,
+        now + Duration::hours(24),
+    )
+    .await;
+
+    db.mark_sla_event_breached(&first_response.id, &now.to_rfc3339())
+        .await
+        .unwrap();
+    db.mark_sla_event_met(&resolution.id, &now.to_rfc3339())
+        .await
+        .unwrap();
+    db.update_applied_sla_status(&applied.id, AppliedSlaStatus::Breached)
+        .await
+        .unwrap();
+
+    let events = db.get_conversation_events(&conversation.id).await.unwrap();
+    assert_eq!(
+        event_types(&events),
+        vec![
+            ConversationEventType::Created,
+            ConversationEventType::SlaApplied,
+            ConversationEventType::SlaBreached,
+            ConversationEventType::SlaMet,
+            ConversationEventType::SlaStatusChanged,
+        ]
+    );
+    assert_eq!(events[1].data["sla_policy_id"], policy.id.as_str());
+    assert_eq!(events[2].data["sla_event_type"], "first_response");
+    assert_eq!(events[3].data["sla_event_id"], resolution.id.as_str());
+    assert_eq!(events[4].data["from"], "pending");
+    assert_eq!(events[4].data["to"], "breached");
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_events_cannot_be_rewritten() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation = new_conversation(db).await;
+
+    let result = sqlx::query(
+        "UPDATE conversation_events SET event_type = 'tag_added' WHERE conversation_id = ?",
+    )
+    .bind(&conversation.id)
+    .execute(db.pool())
+    .await;
+    assert!(result.unwrap_err().to_string().contains("append-only"));
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_timeline_requires_access() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation = new_conversation(db).await;
+    let role = create_test_role(
+        db,
+        "Assigned Agent",
+        None,
+        vec!["conversations:read_assigned".to_string()],
+    )
+    .await;
+    let agent = create_auth_user_with_roles(db, "agent@example.com", "Agent", vec![role]).await;
+    let service = conversation_service(db);
+
+    let result = service.get_timeline(&agent, &conversation.id).await;
+    assert!(matches!(result, Err(ApiError::Forbidden(_))));
+
+    db.assign_conversation_to_user(&conversation.id, Some(agent.user.id.clone()), None)
+        .await
+        .unwrap();
+    let timeline = service
+        .get_timeline(&agent, &conversation.id)
+        .await
+        .unwrap();
+    assert_eq!(timeline.conversation_id, conversation.id);
+    assert_eq!(
+        event_types(&timeline.events),
+        vec![
+            ConversationEventType::Created,
+            ConversationEventType::AssigneeChanged,
+        ]
+    );
+    assert_eq!(timeline.state.status, Some(ConversationStatus::Open));
+    assert_eq!(
+        timeline.state.assigned_user_id.as_deref(),
+        Some(agent.user.id.as_str())
+    );
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_contact_merge_recorded() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let placeholder = create_test_contact(db, "visitor-123@chat.invalid").await;
+    let customer = create_test_contact(db, "customer@example.com").await;
+    let conversation = db
+        .create_conversation(&CreateConversation {
+            inbox_id: "inbox-001".to_string(),
+            contact_id: placeholder.id.clone(),
+            subject: None,
+        })
+        .await
+        .unwrap();
+
+    db.merge_channel_contact(&placeholder, &customer)
+        .await
+        .unwrap();
+
+    let events = db.get_conversation_events(&conversation.id).await.unwrap();
+    assert_eq!(
+        event_types(&events),
+        vec![
+            ConversationEventType::Created,
+            ConversationEventType::ContactChanged,
+        ]
+    );
+    assert_eq!(events[0].data["contact_id"], placeholder.id.as_str());
+    assert_eq!(events[1].data["from"], placeholder.id.as_str());
+    assert_eq!(events[1].data["to"], customer.id.as_str());
+    assert_eq!(
+        ConversationStateSnapshot::replay(&events).contact_id,
+        Some(customer.id)
+    );
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_migration_backfills_conversations_without_history() {
+    let test_db = setup_test_db_at_version(104).await;
+    let db = test_db.db();
+    let conversation = new_conversation(db).await;
+    let agent = create_test_agent(db, "importer@example.com", "Importer").await;
+    let tags = create_test_tags(db, vec![("billing", None, None)]).await;
+    // Written like an import used to be: no history at all
+    sqlx::query("DELETE FROM conversation_events WHERE conversation_id = ?")
+        .bind(&conversation.id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO conversation_tags (conversation_id, tag_id, added_by, added_at)
+         VALUES (?, ?, ?, ?)",
+    )
+    .bind(&conversation.id)
+    .bind(&tags[0].id)
+    .bind(&agent.user_id)
+    .bind(Utc::now().to_rfc3339())
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    run_migrations(db).await;
+
+    let events = db.get_conversation_events(&conversation.id).await.unwrap();
+    assert_eq!(
+        event_types(&events),
+        vec![
+            ConversationEventType::Created,
+            ConversationEventType::TagAdded,
+        ]
+    );
+    let state = ConversationStateSnapshot::replay(&events);
+    assert_eq!(state.status, Some(ConversationStatus::Open));
+    assert_eq!(state.contact_id, Some(conversation.contact_id));
+    assert!(state.tag_ids.contains(&tags[0].id));
+
+    teardown_test_db(test_db).await;
+}
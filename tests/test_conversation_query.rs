@@ -0,0 +1,212 @@
+// Integration tests for the conversation search query language and saved views
+use chrono::{Duration, Utc};
+use oxidesk::{
+    application::services::SavedViewService, domain::entities::*, domain::errors::SavedViewError,
+    infrastructure::persistence::Database,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+async fn set_column(db: &Database, conversation_id: &str, column: &str, value: Option<&str>) {
+    sqlx::query(&format!(
+        "UPDATE conversations SET {} = ? WHERE id = ?",
+        column
+    ))
+    .bind(value)
+    .bind(conversation_id)
+    .execute(db.pool())
+    .await
+    .expect("Failed to update conversation");
+}
+
+async fn tag_conversation(db: &Database, conversation_id: &str, tag: &Tag, user_id: &str) {
+    sqlx::query(
+        "INSERT INTO conversation_tags (conversation_id, tag_id, added_by, added_at)
+         VALUES (?, ?, ?, ?)",
+    )
+    .bind(conversation_id)
+    .bind(&tag.id)
+    .bind(user_id)
+    .bind(Utc::now().to_rfc3339())
+    .execute(db.pool())
+    .await
+    .expect("Failed to tag conversation");
+}
+
+async fn search(db: &Database, query: &str, user_id: &str) -> Vec<String> {
+    let conditions =
+        compile_conversation_query(query, &ConversationQueryContext::new(user_id)).unwrap();
+    let filter = ConversationListFilter {
+        conditions,
+        ..Default::default()
+    };
+    let mut ids: Vec<String> = db
+        .list_conversations(50, 0, &filter)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|c| c.id)
+        .collect();
+    assert_eq!(
+        db.count_conversations(&filter).await.unwrap(),
+        ids.len() as i64
+    );
+    ids.sort();
+    ids
+}
+
+fn sorted(ids: &[&str]) -> Vec<String> {
+    let mut ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+    ids.sort();
+    ids
+}
+
+#[tokio::test]
+async fn test_query_filters_conversations() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let billing = create_test_tag(db, "Billing", None, None).await;
+
+    let mut ids = Vec::new();
+    for status in [
+        ConversationStatus::Open,
+        ConversationStatus::Open,
+        ConversationStatus::Resolved,
+        ConversationStatus::Junk,
+    ] {
+        let conversation =
+            create_test_conversation(db, "inbox-001".to_string(), contact.id.clone(), status).await;
+        ids.push(conversation.id);
+    }
+    let (urgent, stale, resolved, junk) = (&ids[0], &ids[1], &ids[2], &ids[3]);
+
+    set_column(db, urgent, "priority", Some("High")).await;
+    set_column(db, urgent, "assigned_user_id", Some(&agent.user_id)).await;
+    set_column(db, urgent, "subject", Some("Refund 100% please")).await;
+    tag_conversation(db, urgent, &billing, &agent.user_id).await;
+    tag_conversation(db, resolved, &billing, &agent.user_id).await;
+    set_column(db, stale, "priority", Some("Low")).await;
+    let old = (Utc::now() - Duration::days(5)).to_rfc3339();
+    set_column(db, stale, "created_at", Some(&old)).await;
+
+    assert_eq!(
+        search(
+            db,
+            "status:open tag:billing priority>=high assignee:me updated<2d",
+            &agent.user_id
+        )
+        .await,
+        sorted(&[urgent])
+    );
+    assert_eq!(
+        search(db, "tag:BILLING", &agent.user_id).await,
+        sorted(&[urgent, resolved])
+    );
+    assert_eq!(
+        search(db, "priority<medium", &agent.user_id).await,
+        sorted(&[stale, resolved])
+    );
+    assert_eq!(
+        search(db, "assignee:none created>2d", &agent.user_id).await,
+        sorted(&[stale])
+    );
+    assert_eq!(
+        search(db, "\"100%\"", &agent.user_id).await,
+        sorted(&[urgent])
+    );
+    assert!(search(db, "50%", &agent.user_id).await.is_empty());
+
+    // Junk stays hidden unless asked for by status
+    assert!(!search(db, "", &agent.user_id).await.contains(junk));
+    assert_eq!(
+        search(db, "status:junk", &agent.user_id).await,
+        sorted(&[junk])
+    );
+}
+
+#[tokio::test]
+async fn test_saved_views_validate_and_stay_personal() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let other = helpers::rbac_helpers::create_auth_user_with_roles(
+        db,
+        "other@example.com",
+        "Other",
+        vec![],
+    )
+    .await;
+    let service = SavedViewService::new(Arc::new(db.clone()));
+
+    let result = service
+        .create_view(
+            &admin,
+            CreateSavedViewRequest {
+                name: "Mine".to_string(),
+                query: "status:open asignee:me".to_string(),
+            },
+        )
+        .await;
+    match result {
+        Err(SavedViewError::InvalidQuery(err)) => {
+            assert_eq!(err.column, 13);
+            assert!(err.message.contains("unknown filter 'asignee'"));
+        }
+        other => panic!("Expected an invalid query, got {:?}", other),
+    }
+
+    let view = service
+        .create_view(
+            &admin,
+            CreateSavedViewRequest {
+                name: " Mine ".to_string(),
+                query: "status:open assignee:me".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(view.name, "Mine");
+
+    let result = service
+        .create_view(
+            &admin,
+            CreateSavedViewRequest {
+                name: "mine".to_string(),
+                query: "status:open".to_string(),
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(SavedViewError::AlreadyExists(_))));
+
+    // The view compiles for whoever runs it
+    let conditions = service.view_conditions(&admin, &view.id).await.unwrap();
+    assert_eq!(
+        conditions[1],
+        ConversationCondition::AssignedUser(Some(admin.user.id.clone()))
+    );
+
+    // Other agents can neither see nor use it
+    assert!(service.list_views(&other).await.unwrap().is_empty());
+    let result = service.view_conditions(&other, &view.id).await;
+    assert!(matches!(result, Err(SavedViewError::NotFound(_))));
+
+    let updated = service
+        .update_view(
+            &admin,
+            &view.id,
+            UpdateSavedViewRequest {
+                name: Some("Mine".to_string()),
+                query: Some("priority>=high".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.query, "priority>=high");
+
+    service.delete_view(&admin, &view.id).await.unwrap();
+    assert!(service.list_views(&admin).await.unwrap().is_empty());
+}
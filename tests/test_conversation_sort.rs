@@ -0,0 +1,182 @@
+// Integration tests for conversation list sort options
+use chrono::{Duration, Utc};
+use oxidesk::domain::entities::{
+    Conversation, ConversationListFilter, ConversationSort, ConversationSortField,
+    ConversationStatus, Priority, SlaEventType, SortDirection,
+};
+use oxidesk::infrastructure::persistence::Database;
+
+mod helpers;
+use helpers::*;
+
+async fn create_conversations(db: &Database, count: usize) -> Vec<Conversation> {
+    let contact = create_test_contact(db, "sort@example.com").await;
+    let mut conversations = Vec::new();
+    for _ in 0..count {
+        conversations.push(
+            create_test_conversation(
+                db,
+                "inbox-001".to_string(),
+                contact.id.clone(),
+                ConversationStatus::Open,
+            )
+            .await,
+        );
+    }
+    conversations
+}
+
+async fn set_column(db: &Database, conversation_id: &str, column: &str, value: Option<&str>) {
+    sqlx::query(&format!(
+        "UPDATE conversations SET {} = ? WHERE id = ?",
+        column
+    ))
+    .bind(value)
+    .bind(conversation_id)
+    .execute(db.pool())
+    .await
+    .expect("Failed to update conversation");
+}
+
+async fn list_ids(
+    db: &Database,
+    field: ConversationSortField,
+    direction: Option<SortDirection>,
+) -> Vec<String> {
+    let filter = ConversationListFilter {
+        sort: ConversationSort::new(field, direction),
+        ..Default::default()
+    };
+    db.list_conversations(20, 0, &filter)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|c| c.id)
+        .collect()
+}
+
+fn ids(conversations: &[&Conversation]) -> Vec<String> {
+    conversations.iter().map(|c| c.id.clone()).collect()
+}
+
+#[test]
+fn test_sort_defaults() {
+    let sort = ConversationSort::default();
+    assert_eq!(sort.field, ConversationSortField::CreatedAt);
+    assert_eq!(sort.direction, SortDirection::Desc);
+
+    let sort = ConversationSort::new(ConversationSortField::SlaTimeToBreach, None);
+    assert_eq!(sort.direction, SortDirection::Asc);
+
+    let sort = ConversationSort::new(ConversationSortField::Priority, Some(SortDirection::Asc));
+    assert_eq!(sort.direction, SortDirection::Asc);
+
+    let field: ConversationSortField = serde_json::from_str("\"last_message_at\"").unwrap();
+    assert_eq!(field, ConversationSortField::LastMessageAt);
+    assert!(serde_json::from_str::<ConversationSortField>("\"subject\"").is_err());
+}
+
+#[tokio::test]
+async fn test_sort_by_last_message_at_puts_silent_conversations_last() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let c = create_conversations(db, 3).await;
+
+    for (conversation, at) in [(&c[0], "2026-01-01T10:00"), (&c[2], "2026-01-02T10:00")] {
+        set_column(db, &conversation.id, "last_message_at", Some(at)).await;
+    }
+
+    let listed = list_ids(db, ConversationSortField::LastMessageAt, None).await;
+    assert_eq!(listed, ids(&[&c[2], &c[0], &c[1]]));
+
+    let listed = list_ids(
+        db,
+        ConversationSortField::LastMessageAt,
+        Some(SortDirection::Asc),
+    )
+    .await;
+    assert_eq!(listed, ids(&[&c[0], &c[2], &c[1]]));
+}
+
+#[tokio::test]
+async fn test_sort_by_priority_breaks_ties_by_creation_time() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let c = create_conversations(db, 4).await;
+
+    for (index, created_at) in ["2026-01-01", "2026-01-02", "2026-01-03", "2026-01-04"]
+        .iter()
+        .enumerate()
+    {
+        set_column(db, &c[index].id, "created_at", Some(created_at)).await;
+    }
+    db.set_conversation_priority(&c[0].id, &Priority::High)
+        .await
+        .unwrap();
+    db.set_conversation_priority(&c[1].id, &Priority::Low)
+        .await
+        .unwrap();
+    db.set_conversation_priority(&c[2].id, &Priority::High)
+        .await
+        .unwrap();
+
+    let listed = list_ids(db, ConversationSortField::Priority, None).await;
+    assert_eq!(listed, ids(&[&c[2], &c[0], &c[1], &c[3]]));
+}
+
+#[tokio::test]
+async fn test_sort_by_sla_time_to_breach() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let c = create_conversations(db, 3).await;
+
+    let now = Utc::now();
+    let policy = create_test_sla_policy(db, "Standard", "1h", "1d", "4h").await;
+    for (conversation, hours) in [(&c[0], 5), (&c[1], 2)] {
+        let applied = create_test_applied_sla(
+            db,
+            &conversation.id,
+            &policy.id,
+            now + Duration::hours(hours),
+            now + Duration::hours(24),
+        )
+        .await;
+        create_test_sla_event(
+            db,
+            &applied.id,
+            SlaEventType::FirstResponse,
+            now + Duration::hours(hours),
+        )
+        .await;
+    }
+
+    let listed = list_ids(db, ConversationSortField::SlaTimeToBreach, None).await;
+    assert_eq!(listed, ids(&[&c[1], &c[0], &c[2]]));
+}
+
+#[tokio::test]
+async fn test_sort_by_reference_number_and_stable_pages() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let c = create_conversations(db, 5).await;
+
+    let listed = list_ids(
+        db,
+        ConversationSortField::ReferenceNumber,
+        Some(SortDirection::Asc),
+    )
+    .await;
+    assert_eq!(listed, ids(&c.iter().collect::<Vec<_>>()));
+
+    // Equal creation times are ordered by id, so pages never overlap
+    let filter = ConversationListFilter::default();
+    let mut paged = Vec::new();
+    for offset in [0, 2, 4] {
+        let page = db.list_conversations(2, offset, &filter).await.unwrap();
+        paged.extend(page.into_iter().map(|c| c.id));
+    }
+    let mut expected = ids(&c.iter().collect::<Vec<_>>());
+    expected.sort();
+    expected.reverse();
+    assert_eq!(paged, expected);
+}
@@ -0,0 +1,142 @@
+// Integration tests for the rolling conversation summary
+use oxidesk::{
+    application::listeners::conversation_summary::run_conversation_summary_listener,
+    application::services::*, domain::entities::*, domain::ports::event_bus::EventBus,
+    infrastructure::persistence::Database, LocalEventBus, SystemEvent,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+mod helpers;
+use helpers::*;
+
+fn summary_service(db: &Database) -> ConversationSummaryService {
+    let repo = Arc::new(db.clone());
+    ConversationSummaryService::new(repo.clone(), repo.clone(), repo)
+}
+
+async fn wait_for_summary(db: &Database, conversation_id: &str) -> Option<String> {
+    for _ in 0..50 {
+        let conversation = db
+            .get_conversation_by_id(conversation_id)
+            .await
+            .unwrap()
+            .unwrap();
+        if conversation.summary.is_some() {
+            return conversation.summary;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    None
+}
+
+#[tokio::test]
+async fn test_summary_follows_messages_and_replies() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let event_bus = Arc::new(LocalEventBus::new(100));
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let (agent_user, _) =
+        helpers::rbac_helpers::create_test_agent(db, "agent@example.com", "Agent").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+
+    let repo = Arc::new(db.clone());
+    let mut service = MessageService::new(repo.clone(), repo);
+    service.set_summary_service(summary_service(db));
+    let listener = tokio::spawn(run_conversation_summary_listener(
+        event_bus.clone(),
+        summary_service(db),
+    ));
+    tokio::task::yield_now().await;
+
+    // Inbound messages are summarized by the listener
+    let message = service
+        .create_incoming_message(IncomingMessageRequest {
+            conversation_id: conversation.id.clone(),
+            content: "Hi there,\n\nI was charged twice for my March invoice. \
+                      Please refund one of the payments.\n\nThanks,\nAna"
+                .to_string(),
+            contact_id: Some(contact.user_id.clone()),
+            inbox_id: conversation.inbox_id.clone(),
+            from_header: None,
+            external_id: None,
+            received_at: None,
+            channel: None,
+            channel_metadata: None,
+        })
+        .await
+        .unwrap();
+    event_bus
+        .publish(SystemEvent::MessageReceived {
+            message_id: message.id.clone(),
+            conversation_id: conversation.id.clone(),
+            contact_id: contact.user_id.clone(),
+            timestamp: message.created_at.clone(),
+        })
+        .unwrap();
+    assert_eq!(
+        wait_for_summary(db, &conversation.id).await.as_deref(),
+        Some("I was charged twice for my March invoice. — Waiting on agent")
+    );
+
+    // Agent replies switch the state without replacing the issue
+    service
+        .send_message(
+            conversation.id.clone(),
+            agent_user.id.clone(),
+            SendMessageRequest {
+                content: "Refund issued, it should show up in 3-5 days.".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    let listed = db
+        .list_conversations(50, 0, &ConversationListFilter::default())
+        .await
+        .unwrap();
+    let listed = listed.iter().find(|c| c.id == conversation.id).unwrap();
+    assert_eq!(
+        listed.summary.as_deref(),
+        Some("I was charged twice for my March invoice. — Waiting on customer")
+    );
+
+    listener.abort();
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_summary_falls_back_to_subject() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Resolved,
+    )
+    .await;
+
+    let summary = summary_service(db).refresh(&conversation.id).await.unwrap();
+    let expected = match conversation.subject.as_deref() {
+        Some(subject) => format!("{} — Resolved", subject),
+        None => "Resolved".to_string(),
+    };
+    assert_eq!(summary, expected);
+    assert_eq!(
+        db.get_conversation_by_id(&conversation.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .summary,
+        Some(expected)
+    );
+
+    teardown_test_db(test_db).await;
+}
@@ -0,0 +1,200 @@
+// Integration tests for conversation transfers with handover notes
+use oxidesk::{
+    application::services::ConversationTransferService,
+    domain::entities::*,
+    infrastructure::{
+        http::middleware::ApiError, persistence::Database,
+        providers::connection_manager::InMemoryConnectionManager,
+    },
+    LocalEventBus,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::{
+    add_user_to_team, create_auth_user_with_roles, create_conversation_assigned_to_user,
+    create_test_team,
+};
+use helpers::*;
+
+fn transfer_service(db: &Database) -> ConversationTransferService {
+    let repo = Arc::new(db.clone());
+    ConversationTransferService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo,
+        Arc::new(LocalEventBus::new(10)),
+        Arc::new(InMemoryConnectionManager::new()),
+    )
+}
+
+fn transfer_request(to_user_id: &str, require_acceptance: bool) -> TransferConversationRequest {
+    TransferConversationRequest {
+        to_user_id: Some(to_user_id.to_string()),
+        to_team_id: None,
+        note: "Customer wants a refund; invoice #42 attached".to_string(),
+        require_acceptance,
+    }
+}
+
+#[tokio::test]
+async fn test_transfer_reassigns_and_records_note() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = transfer_service(db);
+    let sender = create_auth_user_with_roles(db, "sender@example.com", "Sender", vec![]).await;
+    let receiver =
+        create_auth_user_with_roles(db, "receiver@example.com", "Receiver", vec![]).await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation_id =
+        create_conversation_assigned_to_user(db, &contact.id, &sender.user.id).await;
+
+    // The current assignee can hand over without assignee permissions
+    let transfer = service
+        .transfer(
+            &sender,
+            &conversation_id,
+            transfer_request(&receiver.user.id, false),
+        )
+        .await
+        .unwrap();
+    assert_eq!(transfer.status, TransferStatus::Completed);
+    assert_eq!(
+        transfer.from_user_id.as_deref(),
+        Some(sender.user.id.as_str())
+    );
+
+    let conversation = db
+        .get_conversation_by_id(&conversation_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        conversation.assigned_user_id,
+        Some(receiver.user.id.clone())
+    );
+
+    let history = db.get_assignment_history(&conversation_id).await.unwrap();
+    assert_eq!(history[0].assigned_user_id, Some(receiver.user.id.clone()));
+    assert_eq!(history[0].reason.as_deref(), Some(transfer.note.as_str()));
+
+    let notifications = db
+        .list_notifications(&receiver.user.id, 10, 0)
+        .await
+        .unwrap();
+    assert_eq!(notifications.len(), 1);
+    assert_eq!(
+        notifications[0].notification_type,
+        NotificationType::Transfer
+    );
+
+    // Someone else's conversation needs the assignee permission
+    let result = service
+        .transfer(
+            &sender,
+            &conversation_id,
+            transfer_request(&sender.user.id, false),
+        )
+        .await;
+    assert!(matches!(
+        result.map_err(ApiError::from),
+        Err(ApiError::Forbidden(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_transfer_requiring_acceptance() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = transfer_service(db);
+    let sender = create_auth_user_with_roles(db, "sender@example.com", "Sender", vec![]).await;
+    let receiver =
+        create_auth_user_with_roles(db, "receiver@example.com", "Receiver", vec![]).await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation_id =
+        create_conversation_assigned_to_user(db, &contact.id, &sender.user.id).await;
+
+    let declined = service
+        .transfer(
+            &sender,
+            &conversation_id,
+            transfer_request(&receiver.user.id, true),
+        )
+        .await
+        .unwrap();
+    assert_eq!(declined.status, TransferStatus::Pending);
+
+    // Only one pending transfer at a time, and only the receiver answers it
+    let result = service
+        .transfer(
+            &sender,
+            &conversation_id,
+            transfer_request(&receiver.user.id, true),
+        )
+        .await;
+    assert!(matches!(
+        result.map_err(ApiError::from),
+        Err(ApiError::Conflict(_))
+    ));
+    let result = service
+        .accept(&sender, &conversation_id, &declined.id)
+        .await;
+    assert!(matches!(
+        result.map_err(ApiError::from),
+        Err(ApiError::Forbidden(_))
+    ));
+
+    let declined = service
+        .decline(&receiver, &conversation_id, &declined.id)
+        .await
+        .unwrap();
+    assert_eq!(declined.status, TransferStatus::Declined);
+    let conversation = db
+        .get_conversation_by_id(&conversation_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(conversation.assigned_user_id, Some(sender.user.id.clone()));
+    let notifications = db.list_notifications(&sender.user.id, 10, 0).await.unwrap();
+    assert_eq!(notifications.len(), 1);
+
+    // A transfer to the team's member, accepted
+    let team_id = create_test_team(db, "Billing").await;
+    add_user_to_team(db, &receiver.user.id, &team_id).await;
+    let mut request = transfer_request(&receiver.user.id, true);
+    request.to_team_id = Some(team_id.clone());
+    let pending = service
+        .transfer(&sender, &conversation_id, request)
+        .await
+        .unwrap();
+    let accepted = service
+        .accept(&receiver, &conversation_id, &pending.id)
+        .await
+        .unwrap();
+    assert_eq!(accepted.status, TransferStatus::Completed);
+    assert_eq!(accepted.responded_by, Some(receiver.user.id.clone()));
+
+    let conversation = db
+        .get_conversation_by_id(&conversation_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        conversation.assigned_user_id,
+        Some(receiver.user.id.clone())
+    );
+    assert_eq!(conversation.assigned_team_id, Some(team_id));
+
+    let result = service
+        .decline(&receiver, &conversation_id, &pending.id)
+        .await;
+    assert!(matches!(
+        result.map_err(ApiError::from),
+        Err(ApiError::Conflict(_))
+    ));
+
+    let transfers = service.list_transfers(&conversation_id).await.unwrap();
+    assert_eq!(transfers.len(), 2);
+}
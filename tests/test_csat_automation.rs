@@ -0,0 +1,279 @@
+// Integration tests for CSAT-driven automation rules
+use oxidesk::{
+    application::services::automation_service::{AutomationConfig, AutomationService},
+    application::services::*,
+    domain::entities::*,
+    domain::ports::{
+        agent_repository::AgentRepository, automation_repository::AutomationRepository,
+        conversation_repository::ConversationRepository,
+        conversation_tag_repository::ConversationTagRepository,
+        notification_repository::NotificationRepository, tag_repository::TagRepository,
+        team_repository::TeamRepository, user_repository::UserRepository,
+    },
+    domain::services::action_executor::ActionExecutor,
+    infrastructure::persistence::{automation_rules::AutomationRulesRepository, Database},
+    LocalEventBus,
+};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::create_test_team;
+use helpers::*;
+
+fn csat_service(db: &Database) -> CsatService {
+    let repo = Arc::new(db.clone());
+    CsatService::new(repo.clone(), repo, Arc::new(LocalEventBus::new(10)))
+}
+
+fn automation_service(db: &Database) -> AutomationService {
+    let mut action_executor = ActionExecutor::new(
+        Arc::new(db.clone()) as Arc<dyn ConversationRepository>,
+        Arc::new(db.clone()) as Arc<dyn UserRepository>,
+        Arc::new(db.clone()) as Arc<dyn AgentRepository>,
+        Arc::new(db.clone()) as Arc<dyn TeamRepository>,
+        TagRepository::new(db.clone()),
+        Arc::new(db.clone()) as Arc<dyn ConversationTagRepository>,
+    );
+    action_executor.set_notification_repo(Arc::new(db.clone()) as Arc<dyn NotificationRepository>);
+    AutomationService::new(
+        Arc::new(db.clone()) as Arc<dyn AutomationRepository>,
+        action_executor,
+        AutomationConfig::default(),
+    )
+}
+
+fn low_score_rule(name: &str, action: RuleAction) -> AutomationRule {
+    AutomationRule::new(
+        name.to_string(),
+        RuleType::ConversationUpdate,
+        vec!["csat.received".to_string()],
+        RuleCondition::Simple {
+            attribute: "csat_score".to_string(),
+            comparison: ComparisonOperator::LessThanOrEqual,
+            value: json!(2),
+        },
+        action,
+    )
+}
+
+#[tokio::test]
+async fn test_submit_csat_response() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Resolved,
+    )
+    .await;
+
+    let service = csat_service(db);
+    let signature = service.survey_signature(&conversation.id);
+
+    // Only the conversation's own survey link is accepted
+    let other = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Resolved,
+    )
+    .await;
+    for bad_signature in [
+        service.survey_signature(&other.id),
+        "not-hex".to_string(),
+        String::new(),
+    ] {
+        let result = service
+            .submit_response(
+                &conversation.id,
+                &bad_signature,
+                SubmitCsatRequest {
+                    score: 5,
+                    comment: None,
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(CsatError::Forbidden(_))));
+    }
+    assert_eq!(
+        service.survey_url(&conversation.id),
+        format!(
+            "http://localhost:3000/api/csat/{}?signature={}",
+            conversation.id, signature
+        )
+    );
+
+    let result = service
+        .submit_response(
+            &conversation.id,
+            &signature,
+            SubmitCsatRequest {
+                score: 6,
+                comment: None,
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(CsatError::Validation(_))));
+
+    let result = service
+        .submit_response(
+            "no-such-conversation",
+            &service.survey_signature("no-such-conversation"),
+            SubmitCsatRequest {
+                score: 3,
+                comment: None,
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(CsatError::NotFound(_))));
+
+    service
+        .submit_response(
+            &conversation.id,
+            &signature,
+            SubmitCsatRequest {
+                score: 4,
+                comment: Some("  ".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    let latest = service
+        .submit_response(
+            &conversation.id,
+            &signature,
+            SubmitCsatRequest {
+                score: 2,
+                comment: Some("Slow replies".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(latest.comment.as_deref(), Some("Slow replies"));
+
+    let responses = service.list_responses(&conversation.id).await.unwrap();
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0].id, latest.id);
+    assert_eq!(responses[1].comment, None);
+
+    // The latest score is exposed on the conversation for rule conditions
+    let fetched = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(fetched.csat_score, Some(2));
+}
+
+#[tokio::test]
+async fn test_low_csat_reopens_tags_and_alerts_team_lead() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+
+    let lead = create_test_agent(db, "lead@example.com", "Lead").await;
+    let member = create_test_agent(db, "member@example.com", "Member").await;
+    let team_id = create_test_team(db, "Support").await;
+    db.add_team_member(&team_id, &lead.user_id, TeamMemberRole::Lead)
+        .await
+        .unwrap();
+    db.add_team_member(&team_id, &member.user_id, TeamMemberRole::Member)
+        .await
+        .unwrap();
+    create_test_tag(db, "Unhappy", None, None).await;
+
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Resolved,
+    )
+    .await;
+    db.assign_conversation_to_team(&conversation.id, Some(team_id.clone()), None)
+        .await
+        .unwrap();
+
+    for rule in [
+        low_score_rule(
+            "Reopen on low CSAT",
+            RuleAction {
+                action_type: ActionType::ChangeStatus,
+                parameters: HashMap::from([("status".to_string(), json!("open"))]),
+            },
+        ),
+        low_score_rule(
+            "Tag low CSAT",
+            RuleAction {
+                action_type: ActionType::AddTag,
+                parameters: HashMap::from([("tag".to_string(), json!("Unhappy"))]),
+            },
+        ),
+        low_score_rule(
+            "Alert lead on low CSAT",
+            RuleAction {
+                action_type: ActionType::NotifyTeamLead,
+                parameters: HashMap::new(),
+            },
+        ),
+    ] {
+        AutomationRulesRepository::create_automation_rule(db, &rule)
+            .await
+            .unwrap();
+    }
+
+    let service = automation_service(db);
+
+    // A good score does not trigger anything
+    let mut rated = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    rated.csat_score = Some(5);
+    service
+        .handle_conversation_event("csat.received", &rated, &lead.user_id)
+        .await
+        .unwrap();
+    let unchanged = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(unchanged.status, ConversationStatus::Resolved);
+
+    // A low score runs all three rules
+    rated.csat_score = Some(1);
+    service
+        .handle_conversation_event("csat.received", &rated, &lead.user_id)
+        .await
+        .unwrap();
+
+    let updated = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(updated.status, ConversationStatus::Open);
+
+    let tags = db.get_conversation_tags(&conversation.id).await.unwrap();
+    assert_eq!(tags.len(), 1);
+    assert_eq!(tags[0].name, "Unhappy");
+
+    let lead_notifications = db.list_notifications(&lead.user_id, 10, 0).await.unwrap();
+    assert_eq!(lead_notifications.len(), 1);
+    assert_eq!(
+        lead_notifications[0].notification_type,
+        NotificationType::Alert
+    );
+    assert_eq!(
+        lead_notifications[0].conversation_id.as_deref(),
+        Some(conversation.id.as_str())
+    );
+    let member_notifications = db.list_notifications(&member.user_id, 10, 0).await.unwrap();
+    assert!(member_notifications.is_empty());
+}
@@ -0,0 +1,52 @@
+// Integration tests for converting between HTTP and domain errors
+use axum::{http::StatusCode, response::IntoResponse};
+use oxidesk::{
+    domain::errors::{DomainError, TagError},
+    infrastructure::http::middleware::ApiError,
+    shared::validation::ValidationErrors,
+};
+
+fn status(err: ApiError) -> StatusCode {
+    err.into_response().status()
+}
+
+#[test]
+fn test_api_errors_survive_domain_round_trip() {
+    let errors = || {
+        vec![
+            ApiError::NotFound("Tag not found".to_string()),
+            ApiError::BadRequest("Bad tag".to_string()),
+            ApiError::Unauthorized,
+            ApiError::Forbidden("Missing permission".to_string()),
+            ApiError::Internal("Database unavailable".to_string()),
+            ApiError::Conflict("Tag exists".to_string()),
+            ApiError::TooManyRequests("Slow down".to_string()),
+            ApiError::Validation(ValidationErrors::field("name", "is required")),
+        ]
+    };
+
+    for (original, err) in errors().into_iter().zip(errors()) {
+        let expected = status(original);
+        let through_domain = ApiError::from(DomainError::from(err));
+        assert_eq!(status(through_domain), expected);
+    }
+    for (original, err) in errors().into_iter().zip(errors()) {
+        let expected = status(original);
+        let through_service = ApiError::from(TagError::from(err));
+        assert_eq!(status(through_service), expected);
+    }
+}
+
+#[test]
+fn test_field_errors_kept_through_domain() {
+    let mut errors = ValidationErrors::new();
+    errors.add("name", "is required");
+    errors.add("color", "must be a hex colour (#RRGGBB)");
+
+    let ApiError::Validation(round_tripped) =
+        ApiError::from(TagError::from(ApiError::Validation(errors.clone())))
+    else {
+        panic!("expected field errors");
+    };
+    assert_eq!(round_tripped, errors);
+}
@@ -0,0 +1,164 @@
+// Integration tests for SPF/DKIM/DMARC results on inbound email
+use oxidesk::application::services::{AttachmentService, ContactService, JunkService};
+use oxidesk::domain::entities::*;
+use oxidesk::domain::ports::message_repository::MessageRepository;
+use oxidesk::infrastructure::persistence::Database;
+use oxidesk::infrastructure::providers::email_parser::{EmailParserService, ParsedEmail};
+use oxidesk::infrastructure::providers::EmailReceiverService;
+use oxidesk::infrastructure::storage::local::LocalFileStorage;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+const SPOOFED_RESULTS: &str = "mx.example.net; spf=fail smtp.mailfrom=ceo@example.com; \
+                               dkim=none; dmarc=fail (p=reject) header.from=example.com";
+const GENUINE_RESULTS: &str = "mx.example.net; spf=pass smtp.mailfrom=ceo@example.com; \
+                               dkim=pass header.d=example.com; dmarc=pass header.from=example.com";
+
+fn receiver(db: &Database) -> EmailReceiverService {
+    let repo = Arc::new(db.clone());
+    let storage_dir = std::env::temp_dir().join(format!("oxidesk-test-{}", uuid::Uuid::new_v4()));
+    let mut receiver = EmailReceiverService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        ContactService::new(repo.clone(), repo.clone()),
+        AttachmentService::new(repo.clone(), Arc::new(LocalFileStorage::new(storage_dir))),
+    );
+    receiver.set_junk_service(JunkService::new(repo.clone(), repo.clone(), repo));
+    receiver
+}
+
+async fn configure_inbox(db: &Database, policy: EmailAuthFailurePolicy) {
+    let mut config = InboxEmailConfig::new(
+        "inbox-001".to_string(),
+        "imap.example.com".to_string(),
+        993,
+        "support@example.com".to_string(),
+        "password".to_string(),
+        "smtp.example.com".to_string(),
+        587,
+        "support@example.com".to_string(),
+        "password".to_string(),
+        "support@example.com".to_string(),
+        "Support".to_string(),
+        None,
+    );
+    config.auth_failure_policy = policy;
+    db.create_inbox_email_config(&config).await.unwrap();
+}
+
+fn email(message_id: &str, subject: &str, results: &str) -> ParsedEmail {
+    let raw = format!(
+        "Authentication-Results: {}\n\
+         Authentication-Results: forged.example.org; spf=pass; dkim=pass; dmarc=pass\n\
+         From: ceo@example.com\n\
+         Message-ID: <{}@example.com>\n\
+         Subject: {}\n\
+         \n\
+         Please wire the money today.\n",
+        results, message_id, subject
+    );
+    EmailParserService::new()
+        .parse_email(raw.replace('\n', "\r\n").as_bytes())
+        .unwrap()
+}
+
+/// Ingest an email; returns its conversation and message
+async fn ingest(db: &Database, parsed: &ParsedEmail) -> (Conversation, Message) {
+    let log = EmailProcessingLog::new(
+        "inbox-001".to_string(),
+        parsed.message_id.clone(),
+        parsed.from_address.clone(),
+        parsed.subject.clone(),
+    );
+    let log = receiver(db)
+        .ingest_email("inbox-001", 1, parsed, log)
+        .await
+        .expect("Failed to ingest email");
+    let conversation = db
+        .get_conversation_by_id(log.conversation_id.as_deref().unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    let message = db
+        .get_message_by_id(log.message_id.as_deref().unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    (conversation, message)
+}
+
+#[test]
+fn test_topmost_header_is_used() {
+    let parsed = email("spoof-1", "Urgent", SPOOFED_RESULTS);
+    let results = parsed.authentication.unwrap();
+    assert_eq!(results.authserv_id.as_deref(), Some("mx.example.net"));
+    assert_eq!(results.dmarc, Some(EmailAuthResult::Fail));
+    assert!(results.is_failure());
+}
+
+#[tokio::test]
+async fn test_results_recorded_without_policy() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+
+    let (conversation, message) = ingest(db, &email("spoof-1", "Urgent", SPOOFED_RESULTS)).await;
+    assert_eq!(conversation.status, ConversationStatus::Open);
+    let results = message.email_authentication.unwrap();
+    assert_eq!(results.spf, Some(EmailAuthResult::Fail));
+    assert_eq!(results.dkim, Some(EmailAuthResult::None));
+    assert!(!results.flagged);
+
+    // Exposed on the message as returned by the API
+    let json =
+        serde_json::to_value(db.get_message_by_id(&message.id).await.unwrap().unwrap()).unwrap();
+    assert_eq!(json["email_authentication"]["dmarc"], "fail");
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_flag_policy_flags_failures_only() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    configure_inbox(db, EmailAuthFailurePolicy::Flag).await;
+
+    let (conversation, message) = ingest(db, &email("spoof-1", "Urgent", SPOOFED_RESULTS)).await;
+    assert_eq!(conversation.status, ConversationStatus::Open);
+    assert!(message.email_authentication.unwrap().flagged);
+
+    let (_, message) = ingest(db, &email("genuine-1", "Invoice", GENUINE_RESULTS)).await;
+    let results = message.email_authentication.unwrap();
+    assert_eq!(results.dmarc, Some(EmailAuthResult::Pass));
+    assert!(!results.flagged);
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_quarantine_policy() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    configure_inbox(db, EmailAuthFailurePolicy::Quarantine).await;
+
+    // A spoofed email starting a conversation goes to junk
+    let (conversation, message) = ingest(db, &email("spoof-1", "Urgent", SPOOFED_RESULTS)).await;
+    assert_eq!(conversation.status, ConversationStatus::Junk);
+    assert!(message.email_authentication.unwrap().flagged);
+
+    // A spoofed reply doesn't reopen a resolved conversation
+    let (genuine, _) = ingest(db, &email("genuine-1", "Invoice", GENUINE_RESULTS)).await;
+    assert_eq!(genuine.status, ConversationStatus::Open);
+    db.update_conversation_status(&genuine.id, ConversationStatus::Resolved)
+        .await
+        .unwrap();
+    let subject = format!("Re: Invoice [#{}]", genuine.reference_number);
+    let (replied, message) = ingest(db, &email("spoof-2", &subject, SPOOFED_RESULTS)).await;
+    assert_eq!(replied.id, genuine.id);
+    assert_eq!(replied.status, ConversationStatus::Resolved);
+    assert!(message.email_authentication.unwrap().flagged);
+
+    teardown_test_db(test_db).await;
+}
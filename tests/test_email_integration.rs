@@ -9,7 +9,7 @@ mod helpers;
 
 use helpers::conversation_helpers::create_test_conversation;
 use helpers::*;
-use oxidesk::application::services::AttachmentService;
+use oxidesk::application::services::{AttachmentLinkConfig, AttachmentService};
 use oxidesk::domain::entities::{
     Contact, ConversationStatus, CreateConversation, InboxEmailConfig, Message, User, UserType,
 };
@@ -732,6 +732,9 @@ async fn test_email_config_crud() {
         display_name: None,
         poll_interval_seconds: None,
         enabled: Some(false),
+        auth_method: None,
+        oauth_provider: None,
+        auth_failure_policy: None,
     };
 
     let updated = db
@@ -767,3 +770,138 @@ async fn test_last_poll_time_update() {
 
     teardown_test_db(test_db).await;
 }
+
+#[tokio::test]
+async fn test_email_backlog_progress() {
+    let (test_db, inbox_id, _user_id, _contact_id) = setup_email_test_db().await;
+    let db = test_db.db();
+    setup_email_config(db, &inbox_id).await;
+
+    // No progress until the inbox has been polled
+    assert!(db.get_email_backlog(&inbox_id).await.unwrap().is_none());
+
+    let mut backlog = oxidesk::domain::entities::InboxEmailBacklog::new(inbox_id.clone());
+    backlog.messages_fetched = 50;
+    backlog.messages_processed = 48;
+    backlog.messages_failed = 2;
+    backlog.messages_remaining = 950;
+    backlog.total_processed = 48;
+    db.save_email_backlog(&backlog).await.unwrap();
+
+    // The next batch replaces the per-batch counters
+    backlog.messages_fetched = 50;
+    backlog.messages_processed = 50;
+    backlog.messages_failed = 0;
+    backlog.messages_remaining = 900;
+    backlog.total_processed += 50;
+    db.save_email_backlog(&backlog).await.unwrap();
+
+    let saved = db.get_email_backlog(&inbox_id).await.unwrap().unwrap();
+    assert_eq!(saved.messages_fetched, 50);
+    assert_eq!(saved.messages_failed, 0);
+    assert_eq!(saved.messages_remaining, 900);
+    assert_eq!(saved.total_processed, 98);
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_signed_attachment_download_links() {
+    let (test_db, inbox_id, user_id, contact_id) = setup_email_test_db().await;
+    let db = test_db.db();
+
+    let temp_dir = std::env::temp_dir().join(Uuid::new_v4().to_string());
+    std::fs::create_dir_all(&temp_dir).unwrap();
+
+    let attachment_repo = Arc::new(db.clone())
+        as Arc<dyn oxidesk::domain::ports::attachment_repository::AttachmentRepository>;
+    let file_storage =
+        Arc::new(oxidesk::infrastructure::storage::local::LocalFileStorage::new(temp_dir.clone()));
+    let link_config = AttachmentLinkConfig {
+        secret: "test-link-secret".to_string(),
+        lifetime: std::time::Duration::from_secs(3600),
+        base_url: "https://support.example.com".to_string(),
+    };
+    let attachment_service = AttachmentService::new(attachment_repo.clone(), file_storage.clone())
+        .with_link_config(link_config.clone());
+
+    let conversation = create_test_conversation(
+        db,
+        inbox_id.clone(),
+        contact_id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    let message = Message::new_incoming(conversation.id, "Test message".to_string(), user_id);
+    db.create_message(&message).await.unwrap();
+    let attachment = attachment_service
+        .save_attachment(
+            message.id.clone(),
+            "invoice.pdf".to_string(),
+            "application/pdf".to_string(),
+            b"%PDF-1.4".to_vec(),
+        )
+        .await
+        .unwrap();
+
+    // Link carries the attachment ID, expiry and signature
+    let url = attachment_service.signed_download_url(&attachment);
+    let prefix = format!(
+        "https://support.example.com/api/attachments/{}/download?",
+        attachment.id
+    );
+    assert!(url.starts_with(&prefix));
+    let params: std::collections::HashMap<_, _> = url[prefix.len()..]
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .collect();
+    let expires: i64 = params["expires"].parse().unwrap();
+    let signature = params["signature"];
+
+    let (downloaded, content) = attachment_service
+        .open_signed_download(&attachment.id, expires, signature)
+        .await
+        .unwrap();
+    assert_eq!(downloaded.filename, "invoice.pdf");
+    assert_eq!(content, b"%PDF-1.4".to_vec());
+
+    // Tampered expiry, other secrets and garbage signatures are rejected
+    assert!(attachment_service
+        .open_signed_download(&attachment.id, expires + 60, signature)
+        .await
+        .is_err());
+    assert!(attachment_service
+        .open_signed_download(&attachment.id, expires, "not-hex")
+        .await
+        .is_err());
+    let other_service = AttachmentService::new(attachment_repo.clone(), file_storage.clone())
+        .with_link_config(AttachmentLinkConfig {
+            secret: "other-secret".to_string(),
+            ..link_config.clone()
+        });
+    assert!(other_service
+        .open_signed_download(&attachment.id, expires, signature)
+        .await
+        .is_err());
+
+    // Expired links are rejected even with a valid signature
+    let expired_service = AttachmentService::new(attachment_repo, file_storage)
+        .with_link_config(AttachmentLinkConfig {
+            lifetime: std::time::Duration::ZERO,
+            ..link_config
+        });
+    let url = expired_service.signed_download_url(&attachment);
+    let params: std::collections::HashMap<_, _> = url[prefix.len()..]
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .collect();
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    assert!(expired_service
+        .open_signed_download(&attachment.id, params["expires"].parse().unwrap(), params["signature"])
+        .await
+        .is_err());
+
+    // Cleanup
+    std::fs::remove_dir_all(&temp_dir).ok();
+    teardown_test_db(test_db).await;
+}
@@ -0,0 +1,412 @@
+// Integration tests for OAuth2 (XOAUTH2) sign-in of inbox mailboxes
+use base64::Engine;
+use oxidesk::{
+    application::services::*, domain::entities::*, domain::errors::EmailOAuthError,
+    domain::ports::email_oauth_client::EmailOAuthClient, domain::services::xoauth2_sasl_response,
+    infrastructure::persistence::Database, shared::validation::Validate,
+};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+mod helpers;
+use helpers::*;
+
+const REDIRECT_URL: &str = "https://desk.example.com/api/email-oauth/callback";
+
+/// Token endpoint stand-in: records requests and answers from a queue
+#[derive(Default)]
+struct MockTokenEndpoint {
+    requests: Mutex<Vec<(String, HashMap<String, String>)>>,
+    responses: Mutex<VecDeque<Result<OAuthTokenGrant, String>>>,
+}
+
+impl MockTokenEndpoint {
+    fn respond(&self, response: Result<OAuthTokenGrant, String>) {
+        self.responses.lock().unwrap().push_back(response);
+    }
+
+    fn requests(&self) -> Vec<(String, HashMap<String, String>)> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailOAuthClient for MockTokenEndpoint {
+    async fn request_token(
+        &self,
+        token_url: &str,
+        params: &[(&str, &str)],
+    ) -> Result<OAuthTokenGrant, String> {
+        self.requests.lock().unwrap().push((
+            token_url.to_string(),
+            params
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        ));
+        self.responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("unexpected token request")
+    }
+}
+
+fn grant(access_token: &str, refresh_token: Option<&str>) -> OAuthTokenGrant {
+    OAuthTokenGrant {
+        access_token: access_token.to_string(),
+        refresh_token: refresh_token.map(str::to_string),
+        expires_in: Some(3600),
+        scope: Some("https://mail.google.com/".to_string()),
+    }
+}
+
+fn oauth_service(db: &Database, endpoint: Arc<MockTokenEndpoint>) -> EmailOAuthService {
+    let credentials = |id: &str| {
+        Some(EmailOAuthClientCredentials {
+            client_id: id.to_string(),
+            client_secret: format!("{}-secret", id),
+        })
+    };
+    EmailOAuthService::new(Arc::new(db.clone()), Arc::new(db.clone()), endpoint).with_settings(
+        EmailOAuthSettings {
+            google: credentials("google-client"),
+            microsoft: credentials("microsoft-client"),
+            microsoft_tenant: "contoso.onmicrosoft.com".to_string(),
+            redirect_url: REDIRECT_URL.to_string(),
+        },
+    )
+}
+
+/// Inbox whose mailbox signs in with OAuth2 at `provider`
+async fn oauth_inbox(db: &Database, provider: EmailOAuthProvider) -> InboxEmailConfig {
+    let inbox_id = uuid::Uuid::new_v4().to_string();
+    sqlx::query(
+        "INSERT INTO inboxes (id, name, channel_type, created_at, updated_at)
+         VALUES (?, ?, 'email', datetime('now'), datetime('now'))",
+    )
+    .bind(&inbox_id)
+    .bind(format!("Support {}", inbox_id))
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    let mut config = InboxEmailConfig::new(
+        inbox_id,
+        "imap.gmail.com".to_string(),
+        993,
+        "support@example.com".to_string(),
+        String::new(),
+        "smtp.gmail.com".to_string(),
+        587,
+        "support@example.com".to_string(),
+        String::new(),
+        "support@example.com".to_string(),
+        "Support".to_string(),
+        None,
+    );
+    config.auth_method = EmailAuthMethod::OAuth2;
+    config.oauth_provider = Some(provider);
+    db.create_inbox_email_config(&config).await.unwrap()
+}
+
+fn query_params(url: &str) -> HashMap<String, String> {
+    reqwest::Url::parse(url)
+        .unwrap()
+        .query_pairs()
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_authorization_code_flow() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let config = oauth_inbox(db, EmailOAuthProvider::Google).await;
+    let endpoint = Arc::new(MockTokenEndpoint::default());
+    let service = oauth_service(db, endpoint.clone());
+
+    let authorization = service
+        .start_authorization(&config.inbox_id, "admin-user")
+        .await
+        .unwrap();
+    assert!(authorization
+        .authorize_url
+        .starts_with("https://accounts.google.com/o/oauth2/v2/auth?"));
+    let params = query_params(&authorization.authorize_url);
+    assert_eq!(params["client_id"], "google-client");
+    assert_eq!(params["redirect_uri"], REDIRECT_URL);
+    assert_eq!(params["response_type"], "code");
+    assert_eq!(params["scope"], "https://mail.google.com/");
+    assert_eq!(params["code_challenge_method"], "S256");
+    assert_eq!(params["access_type"], "offline");
+    assert_eq!(params["login_hint"], "support@example.com");
+
+    endpoint.respond(Ok(grant("access-1", Some("refresh-1"))));
+    let status = service
+        .complete_authorization(EmailOAuthCallbackParams {
+            code: Some("auth-code".to_string()),
+            state: Some(params["state"].clone()),
+            error: None,
+            error_description: None,
+        })
+        .await
+        .unwrap();
+    assert!(status.authorized);
+    assert_eq!(status.provider, Some(EmailOAuthProvider::Google));
+    assert_eq!(status.authorized_by.as_deref(), Some("admin-user"));
+
+    let requests = endpoint.requests();
+    assert_eq!(requests.len(), 1);
+    let (token_url, form) = &requests[0];
+    assert_eq!(token_url, "https://oauth2.googleapis.com/token");
+    assert_eq!(form["grant_type"], "authorization_code");
+    assert_eq!(form["code"], "auth-code");
+    assert_eq!(form["redirect_uri"], REDIRECT_URL);
+    assert_eq!(form["client_secret"], "google-client-secret");
+    // The verifier sent with the code matches the challenge sent to the consent page
+    let challenge = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .encode(Sha256::digest(form["code_verifier"].as_bytes()));
+    assert_eq!(challenge, params["code_challenge"]);
+
+    // A fresh token is used as is
+    assert_eq!(service.access_token(&config).await.unwrap(), "access-1");
+    assert_eq!(endpoint.requests().len(), 1);
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_state_is_single_use_and_expires() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let config = oauth_inbox(db, EmailOAuthProvider::Google).await;
+    let endpoint = Arc::new(MockTokenEndpoint::default());
+    let service = oauth_service(db, endpoint.clone());
+
+    let authorization = service
+        .start_authorization(&config.inbox_id, "admin-user")
+        .await
+        .unwrap();
+    let state = query_params(&authorization.authorize_url)["state"].clone();
+    let callback = |state: &str| EmailOAuthCallbackParams {
+        code: Some("auth-code".to_string()),
+        state: Some(state.to_string()),
+        error: None,
+        error_description: None,
+    };
+
+    endpoint.respond(Ok(grant("access-1", Some("refresh-1"))));
+    service
+        .complete_authorization(callback(&state))
+        .await
+        .unwrap();
+    let replayed = service.complete_authorization(callback(&state)).await;
+    assert!(matches!(replayed, Err(EmailOAuthError::InvalidState)));
+
+    let now = chrono::Utc::now();
+    db.create_email_oauth_state(&EmailOAuthState {
+        state: "stale-state".to_string(),
+        inbox_id: config.inbox_id.clone(),
+        provider: EmailOAuthProvider::Google,
+        pkce_verifier: "verifier".to_string(),
+        created_by: None,
+        created_at: (now - chrono::Duration::minutes(20)).to_rfc3339(),
+        expires_at: (now - chrono::Duration::minutes(10)).to_rfc3339(),
+    })
+    .await
+    .unwrap();
+    let expired = service
+        .complete_authorization(callback("stale-state"))
+        .await;
+    assert!(matches!(expired, Err(EmailOAuthError::InvalidState)));
+
+    // Neither rejected callback reached the token endpoint
+    assert_eq!(endpoint.requests().len(), 1);
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_expiring_token_refreshed() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let config = oauth_inbox(db, EmailOAuthProvider::Microsoft).await;
+    let endpoint = Arc::new(MockTokenEndpoint::default());
+    let service = oauth_service(db, endpoint.clone());
+
+    let now = chrono::Utc::now();
+    db.save_email_oauth_token(&EmailOAuthToken {
+        inbox_id: config.inbox_id.clone(),
+        provider: EmailOAuthProvider::Microsoft,
+        access_token: "access-1".to_string(),
+        refresh_token: "refresh-1".to_string(),
+        expires_at: (now + chrono::Duration::minutes(1)).to_rfc3339(),
+        scope: None,
+        authorized_by: Some("admin-user".to_string()),
+        created_at: now.to_rfc3339(),
+        updated_at: now.to_rfc3339(),
+    })
+    .await
+    .unwrap();
+
+    // Refresh responses without a new refresh token keep the current one
+    endpoint.respond(Ok(grant("access-2", None)));
+    assert_eq!(service.access_token(&config).await.unwrap(), "access-2");
+    assert_eq!(service.access_token(&config).await.unwrap(), "access-2");
+
+    let requests = endpoint.requests();
+    assert_eq!(requests.len(), 1);
+    let (token_url, form) = &requests[0];
+    assert_eq!(
+        token_url,
+        "https://login.microsoftonline.com/contoso.onmicrosoft.com/oauth2/v2.0/token"
+    );
+    assert_eq!(form["grant_type"], "refresh_token");
+    assert_eq!(form["refresh_token"], "refresh-1");
+    assert!(form["scope"].contains("offline_access"));
+
+    let stored = db
+        .get_email_oauth_token(&config.inbox_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.refresh_token, "refresh-1");
+    assert!(!stored.expires_within(chrono::Duration::minutes(30)));
+    assert_eq!(stored.authorized_by.as_deref(), Some("admin-user"));
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_revoked_refresh_token_needs_reauthorization() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let config = oauth_inbox(db, EmailOAuthProvider::Google).await;
+    let endpoint = Arc::new(MockTokenEndpoint::default());
+    let service = oauth_service(db, endpoint.clone());
+
+    let unauthorized = service.access_token(&config).await;
+    assert!(matches!(
+        unauthorized,
+        Err(EmailOAuthError::NotAuthorized(_))
+    ));
+
+    let now = chrono::Utc::now().to_rfc3339();
+    db.save_email_oauth_token(&EmailOAuthToken {
+        inbox_id: config.inbox_id.clone(),
+        provider: EmailOAuthProvider::Google,
+        access_token: "access-1".to_string(),
+        refresh_token: "refresh-1".to_string(),
+        expires_at: now.clone(),
+        scope: None,
+        authorized_by: None,
+        created_at: now.clone(),
+        updated_at: now,
+    })
+    .await
+    .unwrap();
+    endpoint.respond(Err(
+        "invalid_grant: Token has been expired or revoked.".to_string()
+    ));
+
+    let revoked = service.access_token(&config).await;
+    assert!(matches!(revoked, Err(EmailOAuthError::NotAuthorized(_))));
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_password_inbox_cannot_be_authorized() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let config = oauth_inbox(db, EmailOAuthProvider::Google).await;
+    let endpoint = Arc::new(MockTokenEndpoint::default());
+    let service = oauth_service(db, endpoint);
+
+    let updated = db
+        .update_inbox_email_config(
+            &config.id,
+            &UpdateInboxEmailConfigRequest {
+                imap_host: None,
+                imap_port: None,
+                imap_username: None,
+                imap_password: Some("app-password".to_string()),
+                imap_use_tls: None,
+                imap_folder: None,
+                smtp_host: None,
+                smtp_port: None,
+                smtp_username: None,
+                smtp_password: Some("app-password".to_string()),
+                smtp_use_tls: None,
+                email_address: None,
+                display_name: None,
+                poll_interval_seconds: None,
+                enabled: None,
+                auth_method: Some(EmailAuthMethod::Password),
+                oauth_provider: None,
+                auth_failure_policy: None,
+            },
+        )
+        .await
+        .unwrap();
+    let stored = db
+        .get_inbox_email_config(&config.inbox_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.auth_method, EmailAuthMethod::Password);
+    assert_eq!(stored.imap_password, "app-password");
+
+    let result = service
+        .start_authorization(&updated.inbox_id, "admin-user")
+        .await;
+    assert!(matches!(result, Err(EmailOAuthError::Validation(_))));
+
+    teardown_test_db(test_db).await;
+}
+
+#[test]
+fn test_oauth_config_request_needs_provider_not_passwords() {
+    let request = |body: serde_json::Value| -> CreateInboxEmailConfigRequest {
+        let mut base = serde_json::json!({
+            "imap_host": "outlook.office365.com",
+            "imap_port": 993,
+            "imap_username": "support@example.com",
+            "smtp_host": "smtp.office365.com",
+            "smtp_port": 587,
+            "smtp_username": "support@example.com",
+            "email_address": "support@example.com",
+            "display_name": "Support",
+        });
+        base.as_object_mut()
+            .unwrap()
+            .extend(body.as_object().unwrap().clone());
+        serde_json::from_value(base).unwrap()
+    };
+
+    let oauth = request(serde_json::json!({
+        "auth_method": "oauth2",
+        "oauth_provider": "microsoft",
+    }));
+    assert!(oauth.check().is_ok());
+
+    let errors = request(serde_json::json!({ "auth_method": "oauth2" }))
+        .check()
+        .unwrap_err();
+    assert!(!errors.messages_for("oauth_provider").is_empty());
+
+    // Password sign-in stays the default and still needs both passwords
+    let errors = request(serde_json::json!({})).check().unwrap_err();
+    assert!(!errors.messages_for("imap_password").is_empty());
+    assert!(!errors.messages_for("smtp_password").is_empty());
+}
+
+#[test]
+fn test_xoauth2_sasl_response() {
+    assert_eq!(
+        xoauth2_sasl_response("support@example.com", "ya29.token"),
+        "user=support@example.com\u{1}auth=Bearer ya29.token\u{1}\u{1}"
+    );
+}
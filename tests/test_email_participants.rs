@@ -0,0 +1,255 @@
+// Integration tests for email participants and per-thread suppression
+use oxidesk::application::services::EmailParticipantService;
+use oxidesk::domain::entities::*;
+use oxidesk::domain::errors::EmailParticipantError;
+use oxidesk::infrastructure::http::middleware::AuthenticatedUser;
+use oxidesk::infrastructure::persistence::Database;
+use oxidesk::infrastructure::providers::email_parser::EmailParserService;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::{create_auth_user_with_roles, create_test_role};
+use helpers::*;
+
+fn participant_service(db: &Database) -> EmailParticipantService {
+    let repo = Arc::new(db.clone());
+    EmailParticipantService::new(repo.clone(), repo.clone(), repo)
+}
+
+/// A customer conversation that CCs two colleagues; returns its id
+async fn cc_conversation(db: &Database) -> String {
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id,
+        ConversationStatus::Open,
+    )
+    .await;
+    for email in [
+        "Customer@Example.com",
+        "manager@example.com",
+        "finance@example.com",
+    ] {
+        db.add_email_participant(&conversation.id, email)
+            .await
+            .unwrap();
+    }
+    conversation.id
+}
+
+async fn supervisor(db: &Database) -> AuthenticatedUser {
+    let role = create_test_role(
+        db,
+        "Supervisor",
+        None,
+        vec![
+            "conversations:read_all".to_string(),
+            "conversations:update_all".to_string(),
+        ],
+    )
+    .await;
+    create_auth_user_with_roles(db, "lead@example.com", "Lead", vec![role]).await
+}
+
+fn addresses(participants: &[EmailParticipant]) -> Vec<&str> {
+    participants.iter().map(|p| p.email.as_str()).collect()
+}
+
+#[test]
+fn test_cc_addresses_parsed() {
+    let raw = "From: Customer@Example.com\n\
+               Cc: Manager <manager@example.com>, finance@example.com\n\
+               Message-ID: <cc-thread@example.com>\n\
+               Subject: Invoice question\n\
+               \n\
+               Can you resend the invoice?\n";
+    let parsed = EmailParserService::new()
+        .parse_email(raw.replace('\n', "\r\n").as_bytes())
+        .unwrap();
+    assert_eq!(
+        parsed.cc_addresses,
+        vec!["manager@example.com", "finance@example.com"]
+    );
+}
+
+#[tokio::test]
+async fn test_participants_listed() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation_id = cc_conversation(db).await;
+    let agent = supervisor(db).await;
+
+    let participants = participant_service(db)
+        .list_participants(&agent, &conversation_id)
+        .await
+        .unwrap();
+    let mut listed = addresses(&participants);
+    listed.sort();
+    assert_eq!(
+        listed,
+        vec![
+            "customer@example.com",
+            "finance@example.com",
+            "manager@example.com"
+        ]
+    );
+    assert!(participants.iter().all(|p| !p.suppressed));
+
+    let recipients = EmailRecipients::for_reply("customer@example.com", &participants);
+    assert_eq!(recipients.to.as_deref(), Some("customer@example.com"));
+    assert_eq!(recipients.cc.len(), 2);
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_suppressed_participant_kept_but_left_out_of_replies() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation_id = cc_conversation(db).await;
+    let agent = supervisor(db).await;
+    let service = participant_service(db);
+
+    let updated = service
+        .update_participant(
+            &agent,
+            &conversation_id,
+            "Manager@Example.com",
+            UpdateEmailParticipantRequest { suppressed: true },
+        )
+        .await
+        .unwrap();
+    assert!(updated.suppressed);
+    assert_eq!(
+        updated.suppressed_by.as_deref(),
+        Some(agent.user.id.as_str())
+    );
+    assert!(updated.suppressed_at.is_some());
+
+    // Still part of the thread's history
+    let participants = service
+        .list_participants(&agent, &conversation_id)
+        .await
+        .unwrap();
+    assert_eq!(participants.len(), 3);
+
+    let recipients = EmailRecipients::for_reply("customer@example.com", &participants);
+    assert_eq!(recipients.to.as_deref(), Some("customer@example.com"));
+    assert_eq!(recipients.cc, vec!["finance@example.com"]);
+
+    // Restoring puts them back on replies
+    let restored = service
+        .update_participant(
+            &agent,
+            &conversation_id,
+            "manager@example.com",
+            UpdateEmailParticipantRequest { suppressed: false },
+        )
+        .await
+        .unwrap();
+    assert!(!restored.suppressed);
+    assert!(restored.suppressed_at.is_none());
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_suppressed_contact_leaves_no_to_recipient() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation_id = cc_conversation(db).await;
+    let agent = supervisor(db).await;
+    let service = participant_service(db);
+
+    for email in [
+        "customer@example.com",
+        "manager@example.com",
+        "finance@example.com",
+    ] {
+        service
+            .update_participant(
+                &agent,
+                &conversation_id,
+                email,
+                UpdateEmailParticipantRequest { suppressed: true },
+            )
+            .await
+            .unwrap();
+    }
+
+    let participants = service
+        .list_participants(&agent, &conversation_id)
+        .await
+        .unwrap();
+    let recipients = EmailRecipients::for_reply("customer@example.com", &participants);
+    assert!(recipients.to.is_none());
+    assert!(recipients.is_empty());
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_unknown_participant_not_found() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation_id = cc_conversation(db).await;
+    let agent = supervisor(db).await;
+
+    let result = participant_service(db)
+        .update_participant(
+            &agent,
+            &conversation_id,
+            "stranger@example.com",
+            UpdateEmailParticipantRequest { suppressed: true },
+        )
+        .await;
+    assert!(matches!(result, Err(EmailParticipantError::NotFound(_))));
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_suppression_requires_access_to_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let conversation_id = cc_conversation(db).await;
+    let role = create_test_role(
+        db,
+        "Assigned Agent",
+        None,
+        vec![
+            "conversations:read_assigned".to_string(),
+            "conversations:update_assigned".to_string(),
+        ],
+    )
+    .await;
+    let agent = create_auth_user_with_roles(db, "agent@example.com", "Agent", vec![role]).await;
+    let service = participant_service(db);
+
+    let result = service
+        .update_participant(
+            &agent,
+            &conversation_id,
+            "manager@example.com",
+            UpdateEmailParticipantRequest { suppressed: true },
+        )
+        .await;
+    assert!(matches!(result, Err(EmailParticipantError::Forbidden(_))));
+
+    // Allowed once the conversation is assigned to them
+    db.assign_conversation_to_user(&conversation_id, Some(agent.user.id.clone()), None)
+        .await
+        .unwrap();
+    service
+        .update_participant(
+            &agent,
+            &conversation_id,
+            "manager@example.com",
+            UpdateEmailParticipantRequest { suppressed: true },
+        )
+        .await
+        .unwrap();
+
+    teardown_test_db(test_db).await;
+}
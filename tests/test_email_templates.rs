@@ -0,0 +1,146 @@
+// Integration tests for email template previews and test sends
+use oxidesk::{
+    application::services::EmailTemplateService,
+    domain::entities::*,
+    domain::ports::{
+        email_sender::{InboxEmailSender, OutgoingEmail},
+        template_repository::{Template, TemplateRepository},
+    },
+    infrastructure::http::middleware::{ApiError, ApiResult},
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+mod helpers;
+use helpers::rbac_helpers::create_auth_user_with_roles;
+use helpers::*;
+
+struct StaticTemplates;
+
+#[async_trait::async_trait]
+impl TemplateRepository for StaticTemplates {
+    async fn get_template(&self, name: &str) -> ApiResult<Option<Template>> {
+        Ok(Some(Template {
+            name: name.to_string(),
+            subject: String::new(),
+            body_html: "<p>{{message_content}}</p><p>{{agent_signature}}</p>{{footer}}".to_string(),
+            body_text: String::new(),
+        }))
+    }
+
+    async fn save_template(&self, _template: &Template) -> ApiResult<()> {
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct RecordingSender {
+    sent: Mutex<Vec<(String, OutgoingEmail)>>,
+}
+
+#[async_trait::async_trait]
+impl InboxEmailSender for RecordingSender {
+    async fn send_from_inbox(&self, inbox_id: &str, email: &OutgoingEmail) -> Result<(), String> {
+        if inbox_id == "inbox-without-smtp" {
+            return Err(format!(
+                "No email configuration found for inbox {}",
+                inbox_id
+            ));
+        }
+        self.sent
+            .lock()
+            .unwrap()
+            .push((inbox_id.to_string(), email.clone()));
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_preview_uses_samples_and_given_variables() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = EmailTemplateService::new(
+        Arc::new(StaticTemplates),
+        Arc::new(RecordingSender::default()),
+    );
+    let admin = create_test_auth_user(db).await;
+
+    let request = PreviewEmailTemplateRequest {
+        variables: HashMap::from([("agent_signature".to_string(), "Sam".to_string())]),
+    };
+    let preview = service
+        .preview(&admin, "agent_reply_email.html", request)
+        .await
+        .unwrap();
+    assert!(preview.html.contains("Thanks for reaching out"));
+    assert!(preview.html.contains("<p>Sam</p>"));
+    assert_eq!(preview.unresolved, vec!["footer".to_string()]);
+
+    // Only catalog templates can be rendered
+    let result = service
+        .preview(&admin, "base.html", PreviewEmailTemplateRequest::default())
+        .await;
+    assert!(matches!(
+        result.map_err(ApiError::from),
+        Err(ApiError::NotFound(_))
+    ));
+
+    let agent = create_auth_user_with_roles(db, "agent@example.com", "Agent", vec![]).await;
+    let result = service
+        .preview(
+            &agent,
+            "agent_reply_email.html",
+            PreviewEmailTemplateRequest::default(),
+        )
+        .await;
+    assert!(matches!(
+        result.map_err(ApiError::from),
+        Err(ApiError::Forbidden(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_send_goes_through_the_inbox_sender() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let sender = Arc::new(RecordingSender::default());
+    let service = EmailTemplateService::new(Arc::new(StaticTemplates), sender.clone());
+    let admin = create_test_auth_user(db).await;
+
+    let response = service
+        .test_send(
+            &admin,
+            "password_reset_email.html",
+            TestSendEmailTemplateRequest {
+                inbox_id: "inbox-001".to_string(),
+                to: "qa@example.com".to_string(),
+                variables: HashMap::new(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.subject, "[Test] Password Reset Request");
+
+    let sent = sender.sent.lock().unwrap();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].0, "inbox-001");
+    assert_eq!(sent[0].1.to, "qa@example.com");
+    assert!(sent[0].1.html_body.is_some());
+    drop(sent);
+
+    let result = service
+        .test_send(
+            &admin,
+            "password_reset_email.html",
+            TestSendEmailTemplateRequest {
+                inbox_id: "inbox-without-smtp".to_string(),
+                to: "qa@example.com".to_string(),
+                variables: HashMap::new(),
+            },
+        )
+        .await;
+    assert!(matches!(
+        result.map_err(ApiError::from),
+        Err(ApiError::BadRequest(_))
+    ));
+}
@@ -0,0 +1,214 @@
+// Integration tests for contact GDPR data export and erasure
+use chrono::Utc;
+use oxidesk::{
+    application::services::GdprService,
+    domain::entities::*,
+    domain::errors::GdprError,
+    domain::ports::{contact_repository::ContactRepository, user_repository::UserRepository},
+    infrastructure::persistence::Database,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+async fn add_message(
+    db: &Database,
+    conversation_id: &str,
+    message_type: &str,
+    author_id: &str,
+    content: &str,
+) -> String {
+    let id = uuid::Uuid::new_v4().to_string();
+    let now = Utc::now().to_rfc3339();
+    sqlx::query(
+        "INSERT INTO messages (id, conversation_id, type, status, content, author_id, created_at, updated_at)
+         VALUES (?, ?, ?, 'received', ?, ?, ?, ?)",
+    )
+    .bind(&id)
+    .bind(conversation_id)
+    .bind(message_type)
+    .bind(content)
+    .bind(author_id)
+    .bind(&now)
+    .bind(&now)
+    .execute(db.pool())
+    .await
+    .expect("Failed to create message");
+    id
+}
+
+async fn message_content(db: &Database, message_id: &str) -> String {
+    sqlx::query_scalar("SELECT content FROM messages WHERE id = ?")
+        .bind(message_id)
+        .fetch_one(db.pool())
+        .await
+        .unwrap()
+}
+
+/// A contact with a phone identity, a note and one conversation with a message each way
+async fn seed_contact(db: &Database, agent_user_id: &str) -> (Contact, String, String) {
+    let contact = create_test_contact(db, "ann.lee@example.com").await;
+    let now = Utc::now().to_rfc3339();
+    sqlx::query(
+        "INSERT INTO channel_identities (inbox_id, external_id, contact_id, created_at)
+         VALUES ('inbox-001', '+15551234567', ?, ?)",
+    )
+    .bind(&contact.id)
+    .bind(&now)
+    .execute(db.pool())
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO contact_notes (id, contact_id, author_id, content, created_at, updated_at)
+         VALUES (?, ?, ?, 'Prefers phone calls', ?, ?)",
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(&contact.user_id)
+    .bind(agent_user_id)
+    .bind(&now)
+    .bind(&now)
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    let incoming = add_message(
+        db,
+        &conversation.id,
+        "incoming",
+        &contact.user_id,
+        "Please call me on +15551234567",
+    )
+    .await;
+    let outgoing = add_message(
+        db,
+        &conversation.id,
+        "outgoing",
+        agent_user_id,
+        "We emailed ann.lee@example.com about your refund",
+    )
+    .await;
+    (contact, incoming, outgoing)
+}
+
+#[tokio::test]
+async fn test_export_contains_contact_data_and_is_audited() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let (contact, incoming, _) = seed_contact(db, &admin.user.id).await;
+    let service = GdprService::new(Arc::new(db.clone()));
+
+    let export = service
+        .export_contact(&admin, &contact.user_id)
+        .await
+        .unwrap();
+    assert_eq!(export.contact.email, "ann.lee@example.com");
+    assert_eq!(export.channels.len(), 1);
+    assert_eq!(export.channel_identities[0].external_id, "+15551234567");
+    assert_eq!(export.notes[0].content, "Prefers phone calls");
+    let messages = &export.conversations[0].messages;
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].id, incoming);
+    assert_eq!(messages[0].direction, "incoming");
+
+    let records = service
+        .list_requests(&admin, &contact.user_id)
+        .await
+        .unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].request_type, GdprRequestType::Export);
+    assert_eq!(records[0].summary.as_ref().unwrap().messages, 2);
+
+    let agent = helpers::rbac_helpers::create_auth_user_with_roles(
+        db,
+        "agent@example.com",
+        "Agent",
+        vec![],
+    )
+    .await;
+    let result = service.export_contact(&agent, &contact.user_id).await;
+    assert!(matches!(result, Err(GdprError::Forbidden(_))));
+    let result = service.export_contact(&admin, "no-such-contact").await;
+    assert!(matches!(result, Err(GdprError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_erasure_needs_confirmation_and_anonymizes_contact() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let (contact, incoming, outgoing) = seed_contact(db, &admin.user.id).await;
+    let service = GdprService::new(Arc::new(db.clone()));
+
+    let confirmation = service
+        .request_erasure(&admin, &contact.user_id, MessageRedaction::Full)
+        .await
+        .unwrap();
+    assert_eq!(confirmation.summary.messages, 2);
+
+    // Nothing changes until confirmed
+    let user = db.get_user_by_id(&contact.user_id).await.unwrap().unwrap();
+    assert_eq!(user.email, "ann.lee@example.com");
+
+    let result = service
+        .confirm_erasure(&admin, &contact.user_id, "wrong-token")
+        .await;
+    assert!(matches!(result, Err(GdprError::InvalidConfirmation(_))));
+
+    let record = service
+        .confirm_erasure(&admin, &contact.user_id, &confirmation.confirmation_token)
+        .await
+        .unwrap();
+    assert_eq!(record.status, GdprRequestStatus::Completed);
+    assert_eq!(record.confirmed_by.as_deref(), Some(admin.user.id.as_str()));
+
+    let user = db.get_user_by_id(&contact.user_id).await.unwrap().unwrap();
+    assert_eq!(user.email, erased_contact_email(&contact.user_id));
+    let erased = db
+        .find_contact_by_user_id(&contact.user_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(erased.first_name, None);
+    assert_eq!(message_content(db, &incoming).await, REDACTED_TEXT);
+    assert_eq!(
+        message_content(db, &outgoing).await,
+        "We emailed [redacted] about your refund"
+    );
+
+    let export = service
+        .export_contact(&admin, &contact.user_id)
+        .await
+        .unwrap();
+    assert!(export.notes.is_empty());
+    assert!(export.channel_identities.is_empty());
+    assert_eq!(export.channels[0].email, user.email);
+
+    // The token is single-use and the contact can't be erased twice
+    let result = service
+        .confirm_erasure(&admin, &contact.user_id, &confirmation.confirmation_token)
+        .await;
+    assert!(matches!(result, Err(GdprError::InvalidConfirmation(_))));
+    let result = service
+        .request_erasure(&admin, &contact.user_id, MessageRedaction::Keep)
+        .await;
+    assert!(matches!(result, Err(GdprError::AlreadyErased)));
+
+    let records = service
+        .list_requests(&admin, &contact.user_id)
+        .await
+        .unwrap();
+    assert_eq!(records.len(), 2);
+    assert!(records
+        .iter()
+        .any(|r| r.request_type == GdprRequestType::Erasure
+            && r.message_redaction == Some(MessageRedaction::Full)));
+}
@@ -0,0 +1,332 @@
+// Integration tests for importing Zendesk and Freshdesk exports
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use oxidesk::application::services::{AttachmentService, ImportService};
+use oxidesk::domain::entities::{
+    ConversationEventType, ConversationStateSnapshot, ConversationStatus, CreateImportRequest,
+    ImportFormat, ImportSource, ImportStatus, ImportedEntity, MessageStatus, MessageType, Priority,
+};
+use oxidesk::domain::errors::ImportError;
+use oxidesk::domain::ports::contact_repository::ContactRepository;
+use oxidesk::domain::ports::file_downloader::{DownloadedFile, FileDownloader};
+use oxidesk::domain::ports::message_repository::MessageRepository;
+use oxidesk::domain::ports::tag_repository::TagRepository;
+use oxidesk::infrastructure::persistence::Database;
+use oxidesk::infrastructure::workers::SqliteTaskQueue;
+use uuid::Uuid;
+
+mod helpers;
+use helpers::rbac_helpers::{create_auth_user_with_roles, ensure_admin_role};
+use helpers::*;
+
+const ZENDESK_EXPORT: &str = r#"{
+    "tickets": [{
+        "id": 101,
+        "subject": "Refund for order 42",
+        "status": "solved",
+        "priority": "urgent",
+        "requester_id": 1,
+        "tags": ["billing", "refund"],
+        "created_at": "2023-03-01T09:00:00Z",
+        "updated_at": "2023-03-02T10:00:00Z",
+        "comments": [
+            {"id": 1001, "author_id": 1, "public": true, "plain_body": "I'd like a refund",
+             "created_at": "2023-03-01T09:00:00Z",
+             "attachments": [{"id": 501, "file_name": "receipt.pdf",
+                              "content_type": "application/pdf",
+                              "content_url": "https://example.zendesk.com/attachments/501"}]},
+            {"id": 1002, "author_id": 2, "public": false, "plain_body": "Check the order first",
+             "created_at": "2023-03-01T09:30:00Z"},
+            {"id": 1003, "author_id": 2, "public": true, "plain_body": "Refund issued",
+             "created_at": "2023-03-01T10:00:00Z"}
+        ]
+    }],
+    "users": [
+        {"id": 1, "name": "Ada Lovelace", "email": "ada@example.com", "role": "end-user"},
+        {"id": 2, "name": "Sam", "email": "sam@example.com", "role": "agent"}
+    ]
+}"#;
+
+/// Serves a fixed file and counts downloads
+struct StubDownloader {
+    downloads: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl FileDownloader for StubDownloader {
+    async fn download(&self, _url: &str) -> Result<DownloadedFile, String> {
+        self.downloads.fetch_add(1, Ordering::SeqCst);
+        Ok(DownloadedFile {
+            content: b"%PDF-1.4".to_vec(),
+            content_type: None,
+        })
+    }
+}
+
+fn service(db: &Database) -> ImportService {
+    ImportService::new(
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        TagRepository::new(db.clone()),
+        Arc::new(SqliteTaskQueue::new(db.clone())),
+    )
+}
+
+fn import_request(source: ImportSource, format: ImportFormat, data: &str) -> CreateImportRequest {
+    CreateImportRequest {
+        source,
+        format,
+        inbox_id: "inbox-001".to_string(),
+        data: data.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_import_recreates_tickets_with_original_timestamps() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = service(db);
+    let admin_role = ensure_admin_role(db).await;
+    let admin =
+        create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![admin_role]).await;
+    let agent = create_test_agent(db, "sam@example.com", "Sam").await;
+
+    let import = service
+        .create_import(
+            &admin,
+            import_request(ImportSource::Zendesk, ImportFormat::Json, ZENDESK_EXPORT),
+        )
+        .await
+        .unwrap();
+    assert_eq!(import.status, ImportStatus::Pending);
+    assert_eq!(import.total_records, 1);
+
+    let import = service.run_import(&import.id).await.unwrap();
+    assert_eq!(import.status, ImportStatus::Completed);
+    assert_eq!(import.processed_records, 1);
+    assert_eq!(import.progress_percent(), 100);
+    assert_eq!(import.counts.contacts_created, 1);
+    assert_eq!(import.counts.conversations_created, 1);
+    assert_eq!(import.counts.messages_created, 2);
+    assert_eq!(import.counts.tags_created, 2);
+    assert!(import.errors.is_empty());
+
+    let conversation_id = db
+        .find_imported_record(ImportSource::Zendesk, ImportedEntity::Conversation, "101")
+        .await
+        .unwrap()
+        .expect("ticket should be recorded");
+    let conversation = db
+        .get_conversation_by_id(&conversation_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(conversation.status, ConversationStatus::Resolved);
+    assert_eq!(conversation.priority, Some(Priority::High));
+    assert_eq!(conversation.subject.as_deref(), Some("Refund for order 42"));
+    assert_eq!(conversation.created_at, "2023-03-01T09:00:00+00:00");
+    assert_eq!(conversation.updated_at, "2023-03-02T10:00:00+00:00");
+
+    let mut tags: Vec<String> = db
+        .get_conversation_tags(&conversation_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|tag| tag.name)
+        .collect();
+    tags.sort();
+    assert_eq!(tags, vec!["billing", "refund"]);
+
+    // The history replays to the imported state
+    let events = db.get_conversation_events(&conversation_id).await.unwrap();
+    assert_eq!(events[0].event_type, ConversationEventType::Created);
+    let state = ConversationStateSnapshot::replay(&events);
+    assert_eq!(state.status, Some(ConversationStatus::Resolved));
+    assert_eq!(state.priority, Some(Priority::High));
+    assert_eq!(state.contact_id, Some(conversation.contact_id.clone()));
+    assert_eq!(state.tag_ids.len(), 2);
+
+    // The private note is left out
+    let (mut messages, total) = db.list_messages(&conversation_id, 10, 0).await.unwrap();
+    messages.reverse();
+    assert_eq!(total, 2);
+    let contact = db
+        .get_contact_by_email("ada@example.com")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(messages[0].message_type, MessageType::Incoming);
+    assert_eq!(messages[0].author_id, contact.user_id);
+    assert_eq!(messages[0].created_at, "2023-03-01T09:00:00+00:00");
+    assert_eq!(messages[1].message_type, MessageType::Outgoing);
+    assert_eq!(messages[1].status, MessageStatus::Sent);
+    assert_eq!(messages[1].author_id, agent.user_id);
+    assert_eq!(
+        messages[1].sent_at.as_deref(),
+        Some("2023-03-01T10:00:00+00:00")
+    );
+
+    // Export data is dropped once the import has run
+    let stored = db.get_import(&import.id).await.unwrap().unwrap();
+    assert!(stored.data.is_empty());
+}
+
+#[tokio::test]
+async fn test_rerunning_an_export_does_not_duplicate() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let temp_dir = std::env::temp_dir().join(Uuid::new_v4().to_string());
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    let downloader = Arc::new(StubDownloader {
+        downloads: AtomicUsize::new(0),
+    });
+    let attachment_service = AttachmentService::new(
+        Arc::new(db.clone()),
+        Arc::new(oxidesk::infrastructure::storage::local::LocalFileStorage::new(temp_dir.clone())),
+    );
+    let service = service(db).with_attachments(attachment_service, downloader.clone());
+    let admin_role = ensure_admin_role(db).await;
+    let admin =
+        create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![admin_role]).await;
+
+    let first = service
+        .create_import(
+            &admin,
+            import_request(ImportSource::Zendesk, ImportFormat::Json, ZENDESK_EXPORT),
+        )
+        .await
+        .unwrap();
+    let first = service.run_import(&first.id).await.unwrap();
+    assert_eq!(first.counts.conversations_created, 1);
+    assert_eq!(first.counts.attachments_created, 1);
+
+    let second = service
+        .create_import(
+            &admin,
+            import_request(ImportSource::Zendesk, ImportFormat::Json, ZENDESK_EXPORT),
+        )
+        .await
+        .unwrap();
+    let second = service.run_import(&second.id).await.unwrap();
+    assert_eq!(second.status, ImportStatus::Completed);
+    assert_eq!(second.counts.tickets_skipped, 1);
+    assert_eq!(second.counts.conversations_created, 0);
+    assert_eq!(second.counts.messages_created, 0);
+    assert_eq!(second.counts.contacts_created, 0);
+    assert_eq!(second.counts.tags_created, 0);
+    assert_eq!(second.counts.attachments_created, 0);
+    assert_eq!(downloader.downloads.load(Ordering::SeqCst), 1);
+
+    // A finished import is not run again
+    let rerun = service.run_import(&first.id).await.unwrap();
+    assert_eq!(rerun.counts, first.counts);
+
+    let conversation_id = db
+        .find_imported_record(ImportSource::Zendesk, ImportedEntity::Conversation, "101")
+        .await
+        .unwrap()
+        .unwrap();
+    let (messages, total) = db.list_messages(&conversation_id, 10, 0).await.unwrap();
+    assert_eq!(total, 2);
+    let incoming = messages
+        .iter()
+        .find(|m| m.message_type == MessageType::Incoming)
+        .unwrap();
+    let attachments = db.get_message_attachments(&incoming.id).await.unwrap();
+    assert_eq!(attachments.len(), 1);
+    assert_eq!(attachments[0].filename, "receipt.pdf");
+    assert_eq!(
+        attachments[0].content_type.as_deref(),
+        Some("application/pdf")
+    );
+
+    std::fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[tokio::test]
+async fn test_csv_import_reports_invalid_rows() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = service(db);
+    let admin_role = ensure_admin_role(db).await;
+    let admin =
+        create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![admin_role]).await;
+    let existing = create_test_contact(db, "ed@example.com").await;
+
+    let csv = "Ticket ID,Subject,Description,Status,Priority,Full name,Email,Created time,Last updated time,Tags\n\
+               9,Invoice,Please send the invoice,Closed,Low,Ed,ed@example.com,2023-04-01 12:00:00,2023-04-03 08:00:00,\"billing,invoices\"\n\
+               10,Missing email,Hello,Open,Low,No Email,,2023-04-01 12:00:00,,\n";
+    let import = service
+        .create_import(
+            &admin,
+            import_request(ImportSource::Freshdesk, ImportFormat::Csv, csv),
+        )
+        .await
+        .unwrap();
+    assert_eq!(import.total_records, 2);
+
+    let import = service.run_import(&import.id).await.unwrap();
+    assert_eq!(import.processed_records, 2);
+    assert_eq!(import.counts.conversations_created, 1);
+    assert_eq!(import.counts.tickets_failed, 1);
+    assert_eq!(import.counts.contacts_created, 0);
+    assert_eq!(import.errors, vec!["Ticket 10: missing requester email"]);
+
+    let conversation_id = db
+        .find_imported_record(ImportSource::Freshdesk, ImportedEntity::Conversation, "9")
+        .await
+        .unwrap()
+        .unwrap();
+    let conversation = db
+        .get_conversation_by_id(&conversation_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(conversation.contact_id, existing.id);
+    assert_eq!(conversation.status, ConversationStatus::Closed);
+    assert_eq!(
+        conversation.closed_at.as_deref(),
+        Some("2023-04-03T08:00:00+00:00")
+    );
+}
+
+#[tokio::test]
+async fn test_imports_require_admin_and_a_readable_export() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = service(db);
+    let admin_role = ensure_admin_role(db).await;
+    let admin =
+        create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![admin_role]).await;
+    let agent = create_auth_user_with_roles(db, "agent@example.com", "Agent", vec![]).await;
+
+    let result = service
+        .create_import(
+            &agent,
+            import_request(ImportSource::Zendesk, ImportFormat::Json, ZENDESK_EXPORT),
+        )
+        .await;
+    assert!(matches!(result, Err(ImportError::Forbidden(_))));
+    assert!(matches!(
+        service.list_imports(&agent).await,
+        Err(ImportError::Forbidden(_))
+    ));
+
+    let result = service
+        .create_import(
+            &admin,
+            import_request(ImportSource::Zendesk, ImportFormat::Json, "{not json"),
+        )
+        .await;
+    assert!(matches!(result, Err(ImportError::Validation(_))));
+
+    let mut request = import_request(ImportSource::Zendesk, ImportFormat::Json, ZENDESK_EXPORT);
+    request.inbox_id = "missing-inbox".to_string();
+    let result = service.create_import(&admin, request).await;
+    assert!(matches!(result, Err(ImportError::NotFound(_))));
+
+    assert!(service.list_imports(&admin).await.unwrap().is_empty());
+}
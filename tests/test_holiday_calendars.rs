@@ -0,0 +1,204 @@
+// Integration tests for per-team holiday calendars (Feature 029)
+use oxidesk::{
+    application::services::*, domain::entities::*, infrastructure::persistence::Database,
+    LocalEventBus,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+const UK_ICS: &str = "BEGIN:VCALENDAR\r\n\
+VERSION:2.0\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART;VALUE=DATE:20251225\r\n\
+DTEND;VALUE=DATE:20251226\r\n\
+SUMMARY:Christmas Day\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART;VALUE=DATE:20251226\r\n\
+DTEND;VALUE=DATE:20251227\r\n\
+SUMMARY:Boxing Day\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+const WEEKDAYS_9_TO_5: &str = r#"{"timezone": "UTC", "schedule": [
+    {"day": "Monday", "start": "09:00", "end": "17:00"},
+    {"day": "Tuesday", "start": "09:00", "end": "17:00"},
+    {"day": "Wednesday", "start": "09:00", "end": "17:00"},
+    {"day": "Thursday", "start": "09:00", "end": "17:00"},
+    {"day": "Friday", "start": "09:00", "end": "17:00"}
+]}"#;
+
+fn holiday_calendar_service(db: &Database) -> HolidayCalendarService {
+    let repo = Arc::new(db.clone());
+    HolidayCalendarService::new(repo.clone(), repo)
+}
+
+async fn create_team_with_hours(db: &Database, name: &str) -> Team {
+    let mut team = Team::new(name.to_string(), None);
+    team.business_hours = Some(WEEKDAYS_9_TO_5.to_string());
+    db.create_team(&team).await.expect("Failed to create team");
+    team
+}
+
+async fn create_uk_calendar(service: &HolidayCalendarService) -> HolidayCalendar {
+    service
+        .create_calendar(CreateHolidayCalendarRequest {
+            name: "UK".to_string(),
+            description: Some("UK bank holidays".to_string()),
+        })
+        .await
+        .expect("Failed to create calendar")
+}
+
+#[tokio::test]
+async fn test_ical_import_is_idempotent() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = holiday_calendar_service(db);
+    let calendar = create_uk_calendar(&service).await;
+
+    let result = service
+        .import_ical(
+            &calendar.id,
+            ImportHolidaysRequest {
+                ics: UK_ICS.to_string(),
+                replace: false,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.imported, 2);
+    assert_eq!(result.skipped, 0);
+
+    // Importing the same feed again doesn't duplicate dates
+    let result = service
+        .import_ical(
+            &calendar.id,
+            ImportHolidaysRequest {
+                ics: UK_ICS.to_string(),
+                replace: false,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.imported, 0);
+    assert_eq!(result.skipped, 2);
+
+    let holidays = service.list_holidays(&calendar.id).await.unwrap();
+    assert_eq!(holidays.len(), 2);
+    assert_eq!(holidays[0].date, "2025-12-25");
+    assert_eq!(
+        holidays[0].calendar_id.as_deref(),
+        Some(calendar.id.as_str())
+    );
+
+    let invalid = service
+        .import_ical(
+            &calendar.id,
+            ImportHolidaysRequest {
+                ics: "hello".to_string(),
+                replace: false,
+            },
+        )
+        .await;
+    assert!(matches!(invalid, Err(HolidayCalendarError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_team_working_days_use_linked_calendar() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = holiday_calendar_service(db);
+    let calendar = create_uk_calendar(&service).await;
+    service
+        .add_holiday(
+            &calendar.id,
+            CreateHolidayRequest {
+                name: "Christmas Day".to_string(),
+                date: "2025-12-25".to_string(),
+                recurring: true,
+            },
+        )
+        .await
+        .unwrap();
+
+    let uk_team = create_team_with_hours(db, "UK Support").await;
+    let us_team = create_team_with_hours(db, "US Support").await;
+    service
+        .set_team_calendar(&uk_team.id, Some(&calendar.id))
+        .await
+        .unwrap();
+
+    // 2025-12-25 is a Thursday, 2025-12-27 a Saturday
+    let days = service
+        .team_working_days(&uk_team.id, "2025-12-24", "2025-12-27")
+        .await
+        .unwrap();
+    let working: Vec<bool> = days.days.iter().map(|d| d.is_working_day).collect();
+    assert_eq!(working, vec![true, false, true, false]);
+    assert_eq!(days.days[1].holiday.as_deref(), Some("Christmas Day"));
+
+    // Recurring holidays apply in later years too
+    let next_year = chrono::NaiveDate::from_ymd_opt(2026, 12, 25).unwrap();
+    assert!(!service
+        .is_team_working_day(&uk_team.id, next_year)
+        .await
+        .unwrap());
+
+    // Teams without the calendar are unaffected
+    let us_days = service
+        .team_working_days(&us_team.id, "2025-12-25", "2025-12-25")
+        .await
+        .unwrap();
+    assert!(us_days.days[0].is_working_day);
+
+    // Deleting the calendar unlinks the team
+    service.delete_calendar(&calendar.id).await.unwrap();
+    let team = db.get_team_by_id(&uk_team.id).await.unwrap().unwrap();
+    assert!(team.holiday_calendar_id.is_none());
+}
+
+#[tokio::test]
+async fn test_sla_deadline_skips_team_holidays() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = holiday_calendar_service(db);
+    let calendar = create_uk_calendar(&service).await;
+    service
+        .import_ical(
+            &calendar.id,
+            ImportHolidaysRequest {
+                ics: UK_ICS.to_string(),
+                replace: false,
+            },
+        )
+        .await
+        .unwrap();
+
+    let sla_service = SlaService::new(
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        Arc::new(LocalEventBus::new(10)),
+    );
+    let business_hours = BusinessHours::parse(WEEKDAYS_9_TO_5).unwrap();
+
+    // Two working hours from Wednesday 16:00: one hour on the 24th, the
+    // remainder on the next working day
+    let base = "2025-12-24T16:00:00Z";
+    let without_calendar = sla_service
+        .calculate_deadline_with_business_hours(base, 2 * 3600, &business_hours, None)
+        .await
+        .unwrap();
+    let with_calendar = sla_service
+        .calculate_deadline_with_business_hours(base, 2 * 3600, &business_hours, Some(&calendar.id))
+        .await
+        .unwrap();
+
+    let parse = |s: &str| chrono::DateTime::parse_from_rfc3339(s).unwrap();
+    assert_eq!(parse(&without_calendar), parse("2025-12-25T10:00:00Z"));
+    // Christmas and Boxing Day are skipped, then the weekend
+    assert_eq!(parse(&with_calendar), parse("2025-12-29T10:00:00Z"));
+}
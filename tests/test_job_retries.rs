@@ -0,0 +1,164 @@
+// Integration tests for job retries, dead jobs and requeueing
+use chrono::Utc;
+use oxidesk::{
+    application::services::JobService, domain::entities::*, domain::errors::JobError,
+    domain::ports::task_queue::TaskQueue, infrastructure::workers::SqliteTaskQueue,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+/// Make a scheduled retry due now
+async fn make_due(db: &oxidesk::infrastructure::persistence::Database, job_id: &str) {
+    sqlx::query("UPDATE jobs SET run_at = ? WHERE id = ?")
+        .bind(Utc::now().to_rfc3339())
+        .bind(job_id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_failing_job_is_retried_then_dead() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let queue = Arc::new(SqliteTaskQueue::new(db.clone()));
+
+    let job_id = queue
+        .enqueue("deliver_webhook", json!({"webhook_id": "hook-1"}), 2)
+        .await
+        .unwrap();
+
+    let job = queue.fetch_next_job().await.unwrap().unwrap();
+    assert_eq!(job.id, job_id);
+    let status = queue
+        .fail_job(&job_id, JobFailureKind::Error, "HTTP 500")
+        .await
+        .unwrap();
+    assert_eq!(status, JobStatus::Pending);
+
+    // Backed off, so not picked up again straight away
+    assert!(queue.fetch_next_job().await.unwrap().is_none());
+    let job = queue.get_job(&job_id).await.unwrap().unwrap();
+    assert_eq!(job.attempts, 1);
+    assert!(job.run_at > Utc::now() + chrono::Duration::seconds(20));
+
+    make_due(db, &job_id).await;
+    queue.fetch_next_job().await.unwrap().unwrap();
+    let status = queue
+        .fail_job(&job_id, JobFailureKind::Panic, "Job panicked: boom")
+        .await
+        .unwrap();
+    assert_eq!(status, JobStatus::Dead);
+
+    let job = queue.get_job(&job_id).await.unwrap().unwrap();
+    assert_eq!(job.status, JobStatus::Dead);
+    assert!(job.dead_at.is_some());
+    assert_eq!(job.last_error.as_deref(), Some("Job panicked: boom"));
+    assert_eq!(job.failures.len(), 2);
+    assert_eq!(job.failures[0].attempt, 1);
+    assert_eq!(job.failures[0].kind, JobFailureKind::Error);
+    assert_eq!(job.failures[1].kind, JobFailureKind::Panic);
+
+    // Dead jobs are not picked up
+    make_due(db, &job_id).await;
+    assert!(queue.fetch_next_job().await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_expired_lock_counts_as_failed_attempt() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let queue = Arc::new(SqliteTaskQueue::new(db.clone()));
+
+    let job_id = queue
+        .enqueue("run_import", json!({"import_id": "import-1"}), 3)
+        .await
+        .unwrap();
+    queue.fetch_next_job().await.unwrap().unwrap();
+
+    // A renewed lock keeps the job
+    queue.extend_lock(&job_id).await.unwrap();
+    assert!(queue.fetch_next_job().await.unwrap().is_none());
+    assert_eq!(
+        queue.get_job(&job_id).await.unwrap().unwrap().status,
+        JobStatus::Processing
+    );
+
+    // The worker died: the lock runs out and the job is rescheduled
+    sqlx::query("UPDATE jobs SET locked_until = ? WHERE id = ?")
+        .bind((Utc::now() - chrono::Duration::minutes(1)).to_rfc3339())
+        .bind(&job_id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    assert!(queue.fetch_next_job().await.unwrap().is_none());
+
+    let job = queue.get_job(&job_id).await.unwrap().unwrap();
+    assert_eq!(job.status, JobStatus::Pending);
+    assert_eq!(job.attempts, 1);
+    assert_eq!(job.failures[0].kind, JobFailureKind::LockExpired);
+}
+
+#[tokio::test]
+async fn test_requeue_dead_job() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let queue = Arc::new(SqliteTaskQueue::new(db.clone()));
+    let service = JobService::new(queue.clone());
+
+    let job_id = queue.enqueue("test_job", json!({}), 1).await.unwrap();
+    let pending_id = queue
+        .enqueue_at(
+            "test_job",
+            json!({}),
+            Utc::now() + chrono::Duration::hours(1),
+            1,
+        )
+        .await
+        .unwrap();
+
+    // Only dead jobs can be requeued
+    let result = service.requeue_job(&job_id, "admin").await;
+    assert!(matches!(result, Err(JobError::NotDead(_))));
+
+    queue.fetch_next_job().await.unwrap().unwrap();
+    queue
+        .fail_job(&job_id, JobFailureKind::Error, "Unknown job type")
+        .await
+        .unwrap();
+
+    let dead = service
+        .list_dead_jobs(DeadJobsQuery {
+            limit: 10,
+            offset: 0,
+        })
+        .await
+        .unwrap();
+    assert_eq!(dead.len(), 1);
+    assert_eq!(dead[0].id, job_id);
+    assert!(dead.iter().all(|job| job.id != pending_id));
+
+    let job = service.requeue_job(&job_id, "admin").await.unwrap();
+    assert_eq!(job.status, JobStatus::Pending);
+    assert_eq!(job.attempts, 0);
+    assert!(job.dead_at.is_none());
+    // The failure history stays for context
+    assert_eq!(job.failures.len(), 1);
+
+    let fetched = queue.fetch_next_job().await.unwrap().unwrap();
+    assert_eq!(fetched.id, job_id);
+
+    let result = service.get_job("missing").await;
+    assert!(matches!(result, Err(JobError::NotFound(_))));
+}
+
+#[test]
+fn test_retry_delay_backs_off_and_caps() {
+    assert_eq!(Job::retry_delay(1), chrono::Duration::seconds(30));
+    assert_eq!(Job::retry_delay(2), chrono::Duration::seconds(60));
+    assert_eq!(Job::retry_delay(4), chrono::Duration::seconds(240));
+    assert_eq!(Job::retry_delay(50), chrono::Duration::hours(1));
+}
@@ -0,0 +1,348 @@
+// Integration tests for junk conversations, sender reputation and inbox blocklists
+use oxidesk::application::services::{AttachmentService, ContactService, JunkService};
+use oxidesk::domain::entities::*;
+use oxidesk::domain::errors::JunkError;
+use oxidesk::domain::ports::message_repository::MessageRepository;
+use oxidesk::infrastructure::http::middleware::AuthenticatedUser;
+use oxidesk::infrastructure::persistence::Database;
+use oxidesk::infrastructure::providers::email_parser::{EmailParserService, ParsedEmail};
+use oxidesk::infrastructure::providers::EmailReceiverService;
+use oxidesk::infrastructure::storage::local::LocalFileStorage;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::{create_auth_user_with_roles, create_test_role, ensure_admin_role};
+use helpers::*;
+
+fn junk_service(db: &Database) -> JunkService {
+    let repo = Arc::new(db.clone());
+    JunkService::new(repo.clone(), repo.clone(), repo)
+}
+
+fn receiver(db: &Database) -> EmailReceiverService {
+    let repo = Arc::new(db.clone());
+    let storage_dir = std::env::temp_dir().join(format!("oxidesk-test-{}", uuid::Uuid::new_v4()));
+    let mut receiver = EmailReceiverService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        ContactService::new(repo.clone(), repo.clone()),
+        AttachmentService::new(repo, Arc::new(LocalFileStorage::new(storage_dir))),
+    );
+    receiver.set_junk_service(junk_service(db));
+    receiver
+}
+
+fn email(message_id: &str, subject: &str) -> ParsedEmail {
+    let raw = format!(
+        "From: Spammer@Example.com\n\
+         Message-ID: <{}@example.com>\n\
+         Subject: {}\n\
+         \n\
+         Cheap watches!\n",
+        message_id, subject
+    );
+    EmailParserService::new()
+        .parse_email(raw.replace('\n', "\r\n").as_bytes())
+        .unwrap()
+}
+
+async fn ingest(db: &Database, parsed: &ParsedEmail) -> EmailProcessingLog {
+    let log = EmailProcessingLog::new(
+        "inbox-001".to_string(),
+        parsed.message_id.clone(),
+        parsed.from_address.clone(),
+        parsed.subject.clone(),
+    );
+    receiver(db)
+        .ingest_email("inbox-001", 1, parsed, log)
+        .await
+        .expect("Failed to ingest email")
+}
+
+async fn supervisor(db: &Database) -> AuthenticatedUser {
+    let role = create_test_role(
+        db,
+        "Supervisor",
+        None,
+        vec!["conversations:update_all".to_string()],
+    )
+    .await;
+    create_auth_user_with_roles(db, "lead@example.com", "Lead", vec![role]).await
+}
+
+async fn spam_conversation(db: &Database) -> Conversation {
+    let contact = create_test_contact(db, "spammer@example.com").await;
+    create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id,
+        ConversationStatus::Open,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_mark_and_unmark_junk() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = supervisor(db).await;
+    let conversation = spam_conversation(db).await;
+    let service = junk_service(db);
+
+    let marked = service
+        .mark_junk(&agent, &conversation.id, MarkJunkRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(marked.conversation.status, ConversationStatus::Junk);
+    assert_eq!(marked.sender_email.as_deref(), Some("spammer@example.com"));
+    assert_eq!(marked.junk_count, 1);
+    assert!(!marked.blocked);
+
+    // Junk is left out of default lists but can be asked for by status
+    let listed = db
+        .list_conversations(50, 0, &ConversationListFilter::default())
+        .await
+        .unwrap();
+    assert!(listed.iter().all(|c| c.id != conversation.id));
+    let junk_filter = ConversationListFilter {
+        status: Some(ConversationStatus::Junk),
+        ..Default::default()
+    };
+    let listed = db.list_conversations(50, 0, &junk_filter).await.unwrap();
+    assert_eq!(listed.len(), 1);
+
+    let err = service
+        .mark_junk(&agent, &conversation.id, MarkJunkRequest::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, JunkError::Validation(_)));
+
+    let unmarked = service.unmark_junk(&agent, &conversation.id).await.unwrap();
+    assert_eq!(unmarked.conversation.status, ConversationStatus::Open);
+    assert_eq!(unmarked.junk_count, 0);
+}
+
+#[tokio::test]
+async fn test_marking_junk_requires_update_all_permission() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_auth_user_with_roles(db, "agent@example.com", "Agent", vec![]).await;
+    let conversation = spam_conversation(db).await;
+
+    let err = junk_service(db)
+        .mark_junk(&agent, &conversation.id, MarkJunkRequest::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, JunkError::Forbidden(_)));
+}
+
+#[tokio::test]
+async fn test_confirmed_junk_blocks_sender_on_inbox() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = supervisor(db).await;
+    let admin_role = ensure_admin_role(db).await;
+    let admin =
+        create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![admin_role]).await;
+    let conversation = spam_conversation(db).await;
+    let service = junk_service(db);
+
+    let marked = service
+        .mark_junk(&agent, &conversation.id, MarkJunkRequest { confirm: true })
+        .await
+        .unwrap();
+    assert!(marked.blocked);
+
+    // New emails from the sender are dropped, whatever the case of the address
+    let log = ingest(db, &email("blocked-1", "Great offer")).await;
+    assert_eq!(log.status(), ProcessingStatus::Success);
+    assert!(log.conversation_id.is_none());
+    let count = db
+        .count_conversations(&ConversationListFilter {
+            status: Some(ConversationStatus::Open),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+
+    let err = service
+        .list_blocked_senders(&agent, "inbox-001")
+        .await
+        .unwrap_err();
+    assert!(matches!(err, JunkError::Forbidden(_)));
+
+    let blocked = service
+        .list_blocked_senders(&admin, "inbox-001")
+        .await
+        .unwrap();
+    assert_eq!(blocked.len(), 1);
+    assert_eq!(blocked[0].email, "spammer@example.com");
+    assert_eq!(
+        blocked[0].conversation_id.as_deref(),
+        Some(conversation.id.as_str())
+    );
+
+    service
+        .unblock_sender(&admin, "inbox-001", &blocked[0].id)
+        .await
+        .unwrap();
+    assert!(!service
+        .is_sender_blocked("inbox-001", "spammer@example.com")
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_sender_reaching_threshold_is_junked_automatically() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = supervisor(db).await;
+    let service = junk_service(db);
+    sqlx::query(
+        "INSERT INTO system_config (key, value, description, updated_at)
+         VALUES (?, '2', 'Auto-junk threshold', datetime('now'))",
+    )
+    .bind(AUTO_JUNK_THRESHOLD_KEY)
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    let contact = create_test_contact(db, "spammer@example.com").await;
+    let mut conversations = Vec::new();
+    for _ in 0..3 {
+        conversations.push(
+            create_test_conversation(
+                db,
+                "inbox-001".to_string(),
+                contact.id.clone(),
+                ConversationStatus::Open,
+            )
+            .await,
+        );
+    }
+
+    service
+        .mark_junk(&agent, &conversations[0].id, MarkJunkRequest::default())
+        .await
+        .unwrap();
+    let junked = service
+        .apply_sender_reputation(&conversations[1], "Spammer@Example.com")
+        .await
+        .unwrap();
+    assert!(!junked, "one marking is below the threshold");
+
+    service
+        .mark_junk(&agent, &conversations[1].id, MarkJunkRequest::default())
+        .await
+        .unwrap();
+    let junked = service
+        .apply_sender_reputation(&conversations[2], "Spammer@Example.com")
+        .await
+        .unwrap();
+    assert!(junked);
+    let conversation = db
+        .get_conversation_by_id(&conversations[2].id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(conversation.status, ConversationStatus::Junk);
+
+    // Automatic junking doesn't count as another marking
+    let count: i64 =
+        sqlx::query_scalar("SELECT junk_count FROM sender_reputations WHERE email = ?")
+            .bind("spammer@example.com")
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+    assert_eq!(count, 2);
+}
+
+#[tokio::test]
+async fn test_junk_status_is_allowed_on_fresh_database() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let contact = create_test_contact(db, "spammer@example.com").await;
+    let now = chrono::Utc::now().to_rfc3339();
+
+    sqlx::query(
+        "INSERT INTO conversations (id, reference_number, status, inbox_id, contact_id, created_at, updated_at)
+         VALUES ('junk-conversation', 9001, 'junk', 'inbox-001', ?, ?, ?)",
+    )
+    .bind(&contact.id)
+    .bind(&now)
+    .bind(&now)
+    .execute(db.pool())
+    .await
+    .expect("'junk' should satisfy the status CHECK constraint");
+
+    let conversation = db
+        .get_conversation_by_id("junk-conversation")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(conversation.status, ConversationStatus::Junk);
+
+    // Unknown statuses are still rejected
+    let result =
+        sqlx::query("UPDATE conversations SET status = 'spam' WHERE id = 'junk-conversation'")
+            .execute(db.pool())
+            .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_junk_migration_keeps_conversation_data() {
+    // Seed data on the schema just before the junk status was added
+    let test_db = setup_test_db_at_version(93).await;
+    let db = test_db.db();
+    let now = chrono::Utc::now().to_rfc3339();
+    for sql in [
+        "INSERT INTO users (id, email, user_type, created_at, updated_at)
+         VALUES ('user-1', 'customer@example.com', 'contact', ?1, ?1)",
+        "INSERT INTO contacts (id, user_id, first_name) VALUES ('contact-1', 'user-1', 'Customer')",
+        "INSERT INTO conversations (id, reference_number, status, inbox_id, contact_id, subject, created_at, updated_at)
+         VALUES ('conversation-1', 100, 'open', 'inbox-001', 'contact-1', 'Order', ?1, ?1)",
+        "INSERT INTO messages (id, conversation_id, type, status, content, author_id, created_at, updated_at)
+         VALUES ('message-1', 'conversation-1', 'incoming', 'received', 'Hello', 'user-1', ?1, ?1)",
+        "INSERT INTO tags (id, name, created_at, updated_at) VALUES ('tag-1', 'Orders', ?1, ?1)",
+        "INSERT INTO conversation_tags (conversation_id, tag_id, added_by, added_at)
+         VALUES ('conversation-1', 'tag-1', 'user-1', ?1)",
+    ] {
+        sqlx::query(sql).bind(&now).execute(db.pool()).await.unwrap();
+    }
+
+    run_migrations(db).await;
+
+    let conversation = db
+        .get_conversation_by_id("conversation-1")
+        .await
+        .unwrap()
+        .expect("Conversation should survive the migration");
+    assert_eq!(conversation.subject.as_deref(), Some("Order"));
+    assert_eq!(db.count_messages("conversation-1").await.unwrap(), 1);
+    assert_eq!(
+        db.get_conversation_tags("conversation-1")
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+
+    let violations = sqlx::query("PRAGMA foreign_key_check")
+        .fetch_all(db.pool())
+        .await
+        .unwrap();
+    assert!(violations.is_empty());
+
+    // Foreign keys are enforced again afterwards
+    let result = sqlx::query(
+        "INSERT INTO messages (id, conversation_id, type, status, content, author_id, created_at, updated_at)
+         VALUES ('message-2', 'missing', 'incoming', 'received', 'Hi', 'user-1', ?1, ?1)",
+    )
+    .bind(&now)
+    .execute(db.pool())
+    .await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,321 @@
+// Integration tests for routing conversations to teams by detected language
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    infrastructure::{
+        http::middleware::ApiError, persistence::Database,
+        providers::connection_manager::InMemoryConnectionManager,
+    },
+    shared::validation::Validate,
+    LocalEventBus,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::{add_user_to_team, create_test_team};
+use helpers::*;
+
+fn assignment_service(db: &Database) -> AssignmentService {
+    let repo = Arc::new(db.clone());
+    let mut service = AssignmentService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        Arc::new(LocalEventBus::new(10)),
+        NotificationService::new(Some(Arc::new(db.clone()))),
+        Arc::new(InMemoryConnectionManager::new()),
+    );
+    service.set_language_routing_repo(repo);
+    service
+}
+
+fn message_service(db: &Database) -> MessageService {
+    let repo = Arc::new(db.clone());
+    let mut service = MessageService::new(repo.clone(), repo);
+    service.set_assignment_service(assignment_service(db));
+    service
+}
+
+async fn receive(db: &Database, contact: &Contact, content: &str) -> Conversation {
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+
+    message_service(db)
+        .create_incoming_message(IncomingMessageRequest {
+            conversation_id: conversation.id.clone(),
+            content: content.to_string(),
+            contact_id: Some(contact.user_id.clone()),
+            inbox_id: "inbox-001".to_string(),
+            from_header: None,
+            external_id: None,
+            received_at: None,
+            channel: None,
+            channel_metadata: None,
+        })
+        .await
+        .expect("Failed to create message");
+
+    db.get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+async fn create_rule(
+    service: &AssignmentService,
+    language: Option<&str>,
+    team_id: &str,
+) -> LanguageRoutingRule {
+    service
+        .create_language_rule(CreateLanguageRoutingRuleRequest {
+            language: language.map(str::to_string),
+            team_id: team_id.to_string(),
+        })
+        .await
+        .expect("Failed to create rule")
+}
+
+#[tokio::test]
+async fn test_incoming_message_routes_to_language_capable_team() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = assignment_service(db);
+    let contact = create_test_contact(db, "cliente@example.com").await;
+
+    let spanish = create_test_team(db, "Soporte").await;
+    let general = create_test_team(db, "General").await;
+    let agent = create_test_agent(db, "lucia@example.com", "Lucia").await;
+    add_user_to_team(db, &agent.user_id, &spanish).await;
+    let languages = service
+        .set_member_languages(
+            &spanish,
+            &agent.user_id,
+            UpdateMemberLanguagesRequest {
+                languages: vec!["es".to_string(), "en".to_string(), "es".to_string()],
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(languages.languages, vec!["en", "es"]);
+
+    let rule = create_rule(&service, Some("es"), &spanish).await;
+    create_rule(&service, None, &general).await;
+
+    let conversation = receive(
+        db,
+        &contact,
+        "Hola, no puedo acceder a mi cuenta y el enlace no funciona",
+    )
+    .await;
+    assert_eq!(
+        conversation.assigned_team_id.as_deref(),
+        Some(spanish.as_str())
+    );
+    assert_eq!(conversation.assigned_user_id, None);
+
+    let history = service
+        .get_assignment_history(&conversation.id)
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].source, AssignmentSource::Rule);
+    assert_eq!(history[0].rule_id.as_deref(), Some(rule.id.as_str()));
+    assert_eq!(history[0].assigned_by, "system");
+    assert_eq!(history[0].reason.as_deref(), Some("Detected language 'es'"));
+
+    let (language,): (Option<String>,) =
+        sqlx::query_as("SELECT language FROM conversations WHERE id = ?")
+            .bind(&conversation.id)
+            .fetch_one(db.pool())
+            .await
+            .unwrap();
+    assert_eq!(language.as_deref(), Some("es"));
+}
+
+#[tokio::test]
+async fn test_routing_falls_back_to_default_team() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = assignment_service(db);
+    let contact = create_test_contact(db, "kunde@example.com").await;
+
+    // The German team has no German speakers yet
+    let german = create_test_team(db, "Deutsch").await;
+    let general = create_test_team(db, "General").await;
+    let agent = create_test_agent(db, "sam@example.com", "Sam").await;
+    add_user_to_team(db, &agent.user_id, &german).await;
+    create_rule(&service, Some("de"), &german).await;
+    let default_rule = create_rule(&service, None, &general).await;
+
+    let conversation = receive(
+        db,
+        &contact,
+        "Hallo, ich kann mich nicht anmelden, bitte helfen Sie mir",
+    )
+    .await;
+    assert_eq!(
+        conversation.assigned_team_id.as_deref(),
+        Some(general.as_str())
+    );
+    let history = service
+        .get_assignment_history(&conversation.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        history[0].rule_id.as_deref(),
+        Some(default_rule.id.as_str())
+    );
+    assert!(history[0].reason.as_deref().unwrap().contains("'de'"));
+
+    // Too short to tell
+    let conversation = receive(db, &contact, "Thanks!").await;
+    assert_eq!(
+        conversation.assigned_team_id.as_deref(),
+        Some(general.as_str())
+    );
+
+    // Once a member speaks German the language rule wins
+    service
+        .set_member_languages(
+            &german,
+            &agent.user_id,
+            UpdateMemberLanguagesRequest {
+                languages: vec!["de".to_string()],
+            },
+        )
+        .await
+        .unwrap();
+    let conversation = receive(
+        db,
+        &contact,
+        "Hallo, ich kann mich nicht anmelden, bitte helfen Sie mir",
+    )
+    .await;
+    assert_eq!(
+        conversation.assigned_team_id.as_deref(),
+        Some(german.as_str())
+    );
+}
+
+#[tokio::test]
+async fn test_assigned_conversations_are_not_rerouted() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = assignment_service(db);
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let general = create_test_team(db, "General").await;
+    let billing = create_test_team(db, "Billing").await;
+    create_rule(&service, None, &general).await;
+
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    db.assign_conversation_to_team(&conversation.id, Some(billing.clone()), None)
+        .await
+        .unwrap();
+
+    let message = Message::new_incoming(
+        conversation.id.clone(),
+        "Hello, my invoice is wrong and I need a refund".to_string(),
+        contact.user_id.clone(),
+    );
+    let routed = service.route_by_language(&message).await.unwrap();
+    assert!(routed.is_none());
+
+    let conversation = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        conversation.assigned_team_id.as_deref(),
+        Some(billing.as_str())
+    );
+    assert!(service
+        .get_assignment_history(&conversation.id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_language_rule_management() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = assignment_service(db);
+    let general = create_test_team(db, "General").await;
+    let french = create_test_team(db, "Français").await;
+
+    create_rule(&service, None, &general).await;
+    create_rule(&service, Some("fr"), &french).await;
+
+    // One default team and one rule per language
+    let duplicate = service
+        .create_language_rule(CreateLanguageRoutingRuleRequest {
+            language: None,
+            team_id: french.clone(),
+        })
+        .await;
+    assert!(matches!(duplicate, Err(ApiError::Conflict(_))));
+
+    let missing_team = service
+        .create_language_rule(CreateLanguageRoutingRuleRequest {
+            language: Some("es".to_string()),
+            team_id: "no-such-team".to_string(),
+        })
+        .await;
+    assert!(matches!(missing_team, Err(ApiError::NotFound(_))));
+
+    let rules = service.list_language_rules().await.unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0].language.as_deref(), Some("fr"));
+    assert_eq!(rules[1].language, None);
+
+    service.delete_language_rule(&rules[0].id).await.unwrap();
+    assert!(matches!(
+        service.delete_language_rule(&rules[0].id).await,
+        Err(ApiError::NotFound(_))
+    ));
+
+    // Languages can only be set for existing members
+    let outsider = create_test_agent(db, "outsider@example.com", "Out").await;
+    let result = service
+        .set_member_languages(
+            &general,
+            &outsider.user_id,
+            UpdateMemberLanguagesRequest {
+                languages: vec!["en".to_string()],
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(ApiError::NotFound(_))));
+
+    // Rules only accept languages the detector can report
+    let errors = CreateLanguageRoutingRuleRequest {
+        language: Some("ja".to_string()),
+        team_id: general.clone(),
+    }
+    .check()
+    .unwrap_err();
+    assert_eq!(errors.messages_for("language").len(), 1);
+    let errors = UpdateMemberLanguagesRequest {
+        languages: vec!["ja".to_string(), "English".to_string()],
+    }
+    .check()
+    .unwrap_err();
+    assert_eq!(errors.messages_for("languages[1]").len(), 1);
+    assert!(errors.messages_for("languages[0]").is_empty());
+}
@@ -0,0 +1,157 @@
+// Integration tests for locale negotiation, user locale preferences and
+// localized API errors and emails
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request, StatusCode},
+    routing::post,
+    Json, Router,
+};
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    domain::errors::DomainError,
+    infrastructure::http::middleware::{locale_middleware, ValidatedJson},
+    shared::i18n,
+};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+mod helpers;
+use helpers::*;
+
+async fn create_tag(ValidatedJson(request): ValidatedJson<CreateTagRequest>) -> Json<Value> {
+    Json(json!({ "name": request.name }))
+}
+
+async fn post_invalid_tag(accept_language: Option<&str>) -> (StatusCode, Option<String>, Value) {
+    let app = Router::new()
+        .route("/tags", post(create_tag))
+        .layer(axum::middleware::from_fn(locale_middleware));
+    let mut request = Request::post("/tags").header(header::CONTENT_TYPE, "application/json");
+    if let Some(accept_language) = accept_language {
+        request = request.header(header::ACCEPT_LANGUAGE, accept_language);
+    }
+    let response = app
+        .oneshot(request.body(Body::from(r#"{"name": " "}"#)).unwrap())
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let content_language = response
+        .headers()
+        .get(header::CONTENT_LANGUAGE)
+        .and_then(|h| h.to_str().ok())
+        .map(str::to_string);
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (
+        status,
+        content_language,
+        serde_json::from_slice(&bytes).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn test_validation_errors_follow_accept_language() {
+    let (status, content_language, body) = post_invalid_tag(Some("es-ES,es;q=0.9")).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(content_language.as_deref(), Some("es"));
+    assert_eq!(body["error"], "La validación ha fallado");
+    assert_eq!(body["fields"][0]["field"], "name");
+    assert_eq!(body["fields"][0]["message"], "es obligatorio");
+
+    // Unsupported languages and missing headers fall back to English
+    let (_, content_language, body) = post_invalid_tag(Some("fr-FR")).await;
+    assert_eq!(content_language.as_deref(), Some("en"));
+    assert_eq!(body["error"], "Validation failed");
+    assert_eq!(body["fields"][0]["message"], "is required");
+
+    let (_, _, body) = post_invalid_tag(None).await;
+    assert_eq!(body["fields"][0]["message"], "is required");
+}
+
+#[tokio::test]
+async fn test_user_locale_preference() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let (user, _) = helpers::rbac_helpers::create_test_agent(db, "ana@example.com", "Ana").await;
+    let service = LocaleService::new(Arc::new(db.clone()));
+
+    assert_eq!(service.preferred_locale(&user.id).await, None);
+    let settings = service.get_settings(&user.id).await.unwrap();
+    assert_eq!(settings.locale, None);
+    assert_eq!(settings.effective_locale, "en");
+    assert_eq!(settings.available_locales, vec!["en", "es"]);
+
+    // Regional variants resolve to the available language
+    let settings = service
+        .update_settings(
+            &user.id,
+            UpdateLocaleRequest {
+                locale: Some("es-MX".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(settings.locale.as_deref(), Some("es"));
+    assert_eq!(settings.effective_locale, "es");
+    assert_eq!(
+        service.preferred_locale(&user.id).await.as_deref(),
+        Some("es")
+    );
+
+    let err = service
+        .update_settings(
+            &user.id,
+            UpdateLocaleRequest {
+                locale: Some("xx".to_string()),
+            },
+        )
+        .await
+        .unwrap_err();
+    match err {
+        DomainError::Validation(errors) => assert_eq!(
+            errors.messages_for("locale"),
+            vec!["must be one of the available locales: en, es"]
+        ),
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+
+    service
+        .update_settings(&user.id, UpdateLocaleRequest { locale: None })
+        .await
+        .unwrap();
+    assert_eq!(service.preferred_locale(&user.id).await, None);
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_password_reset_email_uses_recipient_locale() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let (user, _) = helpers::rbac_helpers::create_test_agent(db, "luis@example.com", "Luis").await;
+    db.set_user_locale(&user.id, Some("es")).await.unwrap();
+
+    let (mut service, mut mailbox) = password_reset_service_with_mailbox(db);
+    service.set_user_locale_repo(Arc::new(db.clone()));
+
+    // The request itself is answered in the requester's locale
+    let response = i18n::with_locale(
+        "en".to_string(),
+        service.request_password_reset("luis@example.com"),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        response.message,
+        "If an account exists with that email, you will receive a password reset link."
+    );
+
+    let email = mailbox.next_email().await;
+    assert_eq!(email.subject, "Solicitud para restablecer la contraseña");
+    assert!(email.text_body.contains("Este enlace caducará en 1 hora."));
+    assert!(extract_reset_token(&email.text_body).is_some());
+
+    teardown_test_db(test_db).await;
+}
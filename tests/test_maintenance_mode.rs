@@ -0,0 +1,142 @@
+// Integration tests for the admin maintenance switch
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    infrastructure::persistence::Database,
+    shared::{maintenance::MaintenanceMode, validation::Validate},
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+fn maintenance_service(db: &Database) -> MaintenanceService {
+    MaintenanceService::new(Arc::new(db.clone()), MaintenanceMode::new())
+}
+
+fn request(enabled: bool, message: Option<&str>) -> UpdateMaintenanceModeRequest {
+    UpdateMaintenanceModeRequest {
+        enabled,
+        message: message.map(str::to_string),
+    }
+}
+
+#[tokio::test]
+async fn test_maintenance_mode_is_persisted_and_audited() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = maintenance_service(db);
+
+    let status = service.load().await.unwrap();
+    assert!(!status.enabled);
+    assert!(!service.mode().is_enabled());
+
+    let status = service
+        .set_mode(
+            request(true, Some("  Upgrading to v2, back at 10:00  ")),
+            "admin-1",
+        )
+        .await
+        .unwrap();
+    assert!(status.enabled);
+    assert_eq!(
+        status.message.as_deref(),
+        Some("Upgrading to v2, back at 10:00")
+    );
+    assert_eq!(status.changed_by.as_deref(), Some("admin-1"));
+    assert!(service.mode().is_enabled());
+    assert_eq!(
+        service.mode().message().as_deref(),
+        Some("Upgrading to v2, back at 10:00")
+    );
+
+    // A restarted instance picks the switch back up
+    let restarted = maintenance_service(db);
+    assert!(!restarted.mode().is_enabled());
+    restarted.load().await.unwrap();
+    assert!(restarted.mode().is_enabled());
+
+    // Toggles show up in the config change history
+    service
+        .set_mode(request(false, None), "admin-2")
+        .await
+        .unwrap();
+    assert!(!service.mode().is_enabled());
+    let changes = SystemConfigService::new(Arc::new(db.clone()))
+        .list_changes(Some(MAINTENANCE_MODE_KEY), 10)
+        .await
+        .unwrap();
+    assert_eq!(changes.total, 2);
+    assert_eq!(changes.changes[0].changed_by, "admin-2");
+    assert_eq!(changes.changes[1].old_value, None);
+
+    // The switch is not one of the integer settings
+    let settings = SystemConfigService::new(Arc::new(db.clone()))
+        .get_settings()
+        .await
+        .unwrap();
+    assert!(settings
+        .settings
+        .iter()
+        .all(|s| s.key != MAINTENANCE_MODE_KEY));
+}
+
+#[tokio::test]
+async fn test_default_message_is_used_without_one() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = maintenance_service(db);
+
+    let status = service
+        .set_mode(request(true, Some("   ")), "admin-1")
+        .await
+        .unwrap();
+    assert_eq!(status.message, None);
+    assert_eq!(
+        service.mode().message().as_deref(),
+        Some(DEFAULT_MAINTENANCE_MESSAGE)
+    );
+
+    let errors = request(true, Some(&"x".repeat(MAINTENANCE_MESSAGE_MAX_LENGTH + 1)))
+        .check()
+        .unwrap_err();
+    assert_eq!(errors.messages_for("message").len(), 1);
+}
+
+#[tokio::test]
+async fn test_readiness_reports_maintenance_and_worker_drain() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = maintenance_service(db);
+
+    let readiness = service.readiness().await;
+    assert!(readiness.is_ready());
+    assert!(readiness.database);
+    assert!(!readiness.workers.paused);
+    assert!(!readiness.workers.drained);
+
+    // A job that started before maintenance keeps the workers from draining
+    let running_job = service.mode().begin_job();
+    service
+        .set_mode(request(true, None), "admin-1")
+        .await
+        .unwrap();
+
+    let readiness = service.readiness().await;
+    assert!(!readiness.is_ready());
+    assert_eq!(readiness.status, "maintenance");
+    assert!(readiness.workers.paused);
+    assert_eq!(readiness.workers.active_jobs, 1);
+    assert!(!readiness.workers.drained);
+
+    drop(running_job);
+    let status = service.status().await.unwrap();
+    assert_eq!(status.workers.active_jobs, 0);
+    assert!(status.workers.drained);
+
+    service
+        .set_mode(request(false, None), "admin-1")
+        .await
+        .unwrap();
+    assert!(service.readiness().await.is_ready());
+}
@@ -0,0 +1,124 @@
+// Integration tests for message reactions
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    domain::ports::message_repository::MessageRepository,
+    infrastructure::{
+        persistence::Database,
+        providers::connection_manager::{ConnectionManager, InMemoryConnectionManager},
+    },
+};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+mod helpers;
+use helpers::*;
+
+fn reaction_service(db: &Database) -> MessageReactionService {
+    let repo = Arc::new(db.clone());
+    MessageReactionService::new(repo.clone(), repo.clone(), repo)
+}
+
+async fn create_message(db: &Database, author_id: &str) -> Message {
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    let message = Message::new_outgoing(
+        conversation.id.clone(),
+        "Handing this over, see notes".to_string(),
+        author_id.to_string(),
+    );
+    db.create_message(&message).await.unwrap();
+    message
+}
+
+#[tokio::test]
+async fn test_add_and_remove_reactions() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let alice = create_test_agent(db, "alice@example.com", "Alice").await;
+    let bob = create_test_agent(db, "bob@example.com", "Bob").await;
+    let message = create_message(db, &alice.user_id).await;
+    let service = reaction_service(db);
+
+    service
+        .add_reaction(&message.id, &bob.user_id, "👍")
+        .await
+        .expect("Failed to add reaction");
+    // Same reaction again (by name) is a no-op
+    let reactions = service
+        .add_reaction(&message.id, &bob.user_id, "thumbs_up")
+        .await
+        .unwrap();
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].count, 1);
+
+    let reactions = service
+        .add_reaction(&message.id, &alice.user_id, "✅")
+        .await
+        .unwrap();
+    assert_eq!(reactions.len(), 2);
+
+    let result = service.add_reaction(&message.id, &bob.user_id, "🎉").await;
+    assert!(matches!(result, Err(ReactionError::Validation(_))));
+    let result = service
+        .add_reaction("no-such-message", &bob.user_id, "👍")
+        .await;
+    assert!(matches!(result, Err(ReactionError::NotFound(_))));
+
+    let reactions = service
+        .remove_reaction(&message.id, &bob.user_id, "👍")
+        .await
+        .unwrap();
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].emoji, "✅");
+    let result = service
+        .remove_reaction(&message.id, &bob.user_id, "👍")
+        .await;
+    assert!(matches!(result, Err(ReactionError::NotFound(_))));
+
+    // Reactions are included when messages are read back
+    let (mut messages, _) = db
+        .list_messages(&message.conversation_id, 50, 0)
+        .await
+        .unwrap();
+    service.attach_reactions(&mut messages).await.unwrap();
+    assert_eq!(messages[0].reactions.len(), 1);
+    assert_eq!(
+        messages[0].reactions[0].user_ids,
+        vec![alice.user_id.clone()]
+    );
+}
+
+#[tokio::test]
+async fn test_reaction_is_pushed_to_message_author() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let alice = create_test_agent(db, "alice@example.com", "Alice").await;
+    let bob = create_test_agent(db, "bob@example.com", "Bob").await;
+    let message = create_message(db, &alice.user_id).await;
+
+    let manager = Arc::new(InMemoryConnectionManager::new());
+    let (alice_tx, mut alice_rx) = mpsc::channel(10);
+    let (bob_tx, mut bob_rx) = mpsc::channel(10);
+    manager.add_connection(&alice.user_id, alice_tx).await;
+    manager.add_connection(&bob.user_id, bob_tx).await;
+
+    let service = reaction_service(db).with_connection_manager(manager);
+    service
+        .add_reaction(&message.id, &bob.user_id, "✅")
+        .await
+        .unwrap();
+
+    let event = alice_rx.recv().await.unwrap();
+    assert_eq!(event.type_, "reaction_added");
+    assert_eq!(event.message_id.as_deref(), Some(message.id.as_str()));
+    assert_eq!(event.actor_id.as_deref(), Some(bob.user_id.as_str()));
+    // The reacting agent is not notified of their own reaction
+    assert!(bob_rx.try_recv().is_err());
+}
@@ -146,6 +146,11 @@ async fn create_test_conversation(db: &Database, inbox_id: &str, contact_id: &st
         version: 0,
         tags: None,
         priority: None,
+        contact_email_verified: None,
+        csat_score: None,
+        sentiment_score: None,
+        sentiment_trend: None,
+        summary: None,
     }
 }
 
@@ -563,6 +568,8 @@ async fn test_e2e_incoming_message_flow() {
         from_header: None, // Feature 016: Optional email header for auto contact creation
         external_id: Some("ext_msg_001".to_string()),
         received_at: None,
+        channel: None,
+        channel_metadata: None,
     };
 
     // This would normally be called by webhook endpoint
@@ -0,0 +1,160 @@
+// Integration tests for SSE notification resume (Last-Event-ID)
+use oxidesk::{
+    infrastructure::providers::connection_manager::{
+        ConnectionManager, NotificationEvent, ResumableConnectionManager,
+    },
+    NotificationService, STREAM_REPLAY_PAGE_SIZE,
+};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::StreamExt;
+
+mod helpers;
+use helpers::*;
+
+fn event(id: &str) -> NotificationEvent {
+    NotificationEvent {
+        id: id.to_string(),
+        type_: "mention".to_string(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        is_read: false,
+        conversation_id: None,
+        message_id: None,
+        actor_id: None,
+        sequence: None,
+    }
+}
+
+#[tokio::test]
+async fn test_offline_events_are_queued_for_resume() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+
+    let manager = ResumableConnectionManager::new(Arc::new(db.clone()));
+
+    // Delivered while offline: stored, not an error
+    manager
+        .send_to_user(&agent.user_id, event("notif-1"))
+        .await
+        .expect("Offline delivery should be queued");
+    manager
+        .send_to_user(&agent.user_id, event("notif-2"))
+        .await
+        .expect("Offline delivery should be queued");
+
+    let missed = db
+        .list_stream_events_after(&agent.user_id, 0, 100)
+        .await
+        .unwrap();
+    assert_eq!(missed.len(), 2);
+    assert!(missed[0].sequence < missed[1].sequence);
+    assert!(missed[0].payload.contains("notif-1"));
+
+    // Resuming from the first event only returns the second
+    let service = NotificationService::new(Some(Arc::new(db.clone())));
+    let resumed = service
+        .list_stream_events_after(&agent.user_id, missed[0].sequence)
+        .await
+        .unwrap();
+    assert_eq!(resumed.len(), 1);
+    assert!(resumed[0].payload.contains("notif-2"));
+}
+
+#[tokio::test]
+async fn test_live_events_carry_sequence() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let other = create_test_agent(db, "other@example.com", "Other").await;
+
+    let manager = ResumableConnectionManager::new(Arc::new(db.clone()));
+    let (tx, mut rx) = mpsc::channel(10);
+    manager.add_connection(&agent.user_id, tx).await;
+
+    manager
+        .send_to_user(&agent.user_id, event("notif-1"))
+        .await
+        .unwrap();
+    manager
+        .send_to_user(&other.user_id, event("notif-other"))
+        .await
+        .unwrap();
+
+    let received = rx.recv().await.unwrap();
+    assert_eq!(received.id, "notif-1");
+    let sequence = received
+        .sequence
+        .expect("Live events should have a sequence");
+
+    // Streams are per user
+    let mine = db
+        .list_stream_events_after(&agent.user_id, 0, 100)
+        .await
+        .unwrap();
+    assert_eq!(mine.len(), 1);
+    assert_eq!(mine[0].sequence, sequence);
+}
+
+#[tokio::test]
+async fn test_cleanup_removes_expired_stream_events() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+
+    db.append_stream_event(&agent.user_id, "{}").await.unwrap();
+
+    // Events newer than the retention window are kept
+    let deleted = NotificationService::cleanup_stream_events(db, None)
+        .await
+        .unwrap();
+    assert_eq!(deleted, 0);
+
+    // A zero-hour window expires everything
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    let deleted = NotificationService::cleanup_stream_events(db, Some(0))
+        .await
+        .unwrap();
+    assert_eq!(deleted, 1);
+    assert!(db
+        .list_stream_events_after(&agent.user_id, 0, 100)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_resume_replays_backlog_longer_than_one_page() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+
+    let total = STREAM_REPLAY_PAGE_SIZE as usize * 2 + 3;
+    for i in 0..total {
+        db.append_stream_event(&agent.user_id, &format!("{{\"n\":{}}}", i))
+            .await
+            .unwrap();
+    }
+
+    let service = NotificationService::new(Some(Arc::new(db.clone())));
+    let replayed: Vec<_> = service
+        .stream_events_after(&agent.user_id, 0)
+        .collect()
+        .await;
+    assert_eq!(replayed.len(), total);
+    assert!(replayed
+        .windows(2)
+        .all(|pair| pair[0].sequence < pair[1].sequence));
+    assert_eq!(
+        replayed[total - 1].payload,
+        format!("{{\"n\":{}}}", total - 1)
+    );
+
+    // Resuming partway through only replays what comes after
+    let resumed: Vec<_> = service
+        .stream_events_after(&agent.user_id, replayed[total - 2].sequence)
+        .collect()
+        .await;
+    assert_eq!(resumed.len(), 1);
+    assert_eq!(resumed[0].sequence, replayed[total - 1].sequence);
+}
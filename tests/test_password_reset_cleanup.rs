@@ -31,14 +31,11 @@ async fn test_expired_token_deleted_on_validation_failure() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
+    let token_value = request_reset_token(db, &email).await;
     let tokens = db
         .get_all_password_reset_tokens_for_user(&user.id)
         .await
         .unwrap();
-    let token_value = tokens[0].token.clone();
     let token_id = tokens[0].id.clone();
 
     // Manually expire the token
@@ -92,14 +89,7 @@ async fn test_valid_token_not_deleted_on_validation() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token_value = tokens[0].token.clone();
+    let token_value = request_reset_token(db, &email).await;
 
     // Verify token exists and is valid
     let token_before = db.get_password_reset_token(&token_value).await.unwrap();
@@ -147,9 +137,7 @@ async fn test_multiple_expired_tokens_cleaned_up_individually() {
     // Create multiple tokens and expire them
     let mut expired_tokens = Vec::new();
     for _ in 0..3 {
-        PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-            .await
-            .unwrap();
+        let token_value = request_reset_token(db, &email).await;
         let tokens = db
             .get_all_password_reset_tokens_for_user(&user.id)
             .await
@@ -165,7 +153,7 @@ async fn test_multiple_expired_tokens_cleaned_up_individually() {
             .await
             .unwrap();
 
-        expired_tokens.push((latest_token.token.clone(), latest_token.id.clone()));
+        expired_tokens.push((token_value, latest_token.id.clone()));
     }
 
     // All tokens should exist before cleanup
@@ -216,14 +204,7 @@ async fn test_lazy_cleanup_only_on_expired_not_used() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token_value = tokens[0].token.clone();
+    let token_value = request_reset_token(db, &email).await;
 
     // Use the token successfully
     PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( &token_value, "NewPass123!")
@@ -287,25 +268,15 @@ async fn test_cleanup_does_not_affect_other_users_tokens() {
     db.create_agent(&agent2).await.unwrap();
 
     // Create tokens for both users
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email1)
-        .await
-        .unwrap();
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email2)
-        .await
-        .unwrap();
+    let token1_value = request_reset_token(db, &email1).await;
+    let token2_value = request_reset_token(db, &email2).await;
 
     let tokens1 = db
         .get_all_password_reset_tokens_for_user(&user1.id)
         .await
         .unwrap();
-    let tokens2 = db
-        .get_all_password_reset_tokens_for_user(&user2.id)
-        .await
-        .unwrap();
 
-    let token1_value = tokens1[0].token.clone();
     let token1_id = tokens1[0].id.clone();
-    let token2_value = tokens2[0].token.clone();
 
     // Expire user1's token
     let past_time = chrono::Utc::now() - chrono::Duration::hours(2);
@@ -362,15 +333,12 @@ async fn test_no_background_cleanup_job() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
+    let token_value = request_reset_token(db, &email).await;
     let tokens = db
         .get_all_password_reset_tokens_for_user(&user.id)
         .await
         .unwrap();
     let token_id = tokens[0].id.clone();
-    let token_value = tokens[0].token.clone();
 
     // Expire the token
     let past_time = chrono::Utc::now() - chrono::Duration::hours(2);
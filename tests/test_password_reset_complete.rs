@@ -33,14 +33,7 @@ async fn test_successful_password_reset_with_valid_token() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Reset password with token
     let new_password = "NewPass123!";
@@ -89,14 +82,7 @@ async fn test_password_reset_marks_token_as_used() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Token should not be used initially
     let token_before = db.get_password_reset_token(token).await.unwrap().unwrap();
@@ -201,14 +187,7 @@ async fn test_password_reset_rejects_weak_passwords() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Try various weak passwords
     let weak_passwords = vec![
@@ -265,14 +244,7 @@ async fn test_password_reset_updates_password_hash() {
     assert_eq!(agent_before.password_hash, old_password_hash);
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Reset password
     let new_password = "NewPass123!";
@@ -315,14 +287,7 @@ async fn test_password_reset_returns_success_message() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Reset password
     let result = PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( token, "NewPass123!").await;
@@ -6,7 +6,7 @@ use helpers::*;
 use oxidesk::{
     infrastructure::http::middleware::error::ApiError,
     domain::entities::{Agent, User, UserType},
-    application::services::PasswordResetService,
+    application::services::{PasswordResetConfig, PasswordResetService},
     application::services::auth::hash_password,
     shared::utils::email_validator::validate_and_normalize_email,
 };
@@ -32,14 +32,11 @@ async fn test_expired_token_rejected() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
+    let token_value = request_reset_token(db, &email).await;
     let tokens = db
         .get_all_password_reset_tokens_for_user(&user.id)
         .await
         .unwrap();
-    let token_value = tokens[0].token.clone();
     let token_id = tokens[0].id.clone();
 
     // Manually expire the token (set expires_at to 2 hours ago)
@@ -89,14 +86,11 @@ async fn test_password_unchanged_after_expired_token_rejection() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
+    let token_value = request_reset_token(db, &email).await;
     let tokens = db
         .get_all_password_reset_tokens_for_user(&user.id)
         .await
         .unwrap();
-    let token_value = tokens[0].token.clone();
     let token_id = tokens[0].id.clone();
 
     // Manually expire the token
@@ -143,14 +137,11 @@ async fn test_token_at_exact_expiry_boundary() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
+    let token_value = request_reset_token(db, &email).await;
     let tokens = db
         .get_all_password_reset_tokens_for_user(&user.id)
         .await
         .unwrap();
-    let token_value = tokens[0].token.clone();
     let token_id = tokens[0].id.clone();
 
     // Set token to expire exactly now (boundary condition)
@@ -195,14 +186,11 @@ async fn test_token_just_before_expiry_works() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
+    let token_value = request_reset_token(db, &email).await;
     let tokens = db
         .get_all_password_reset_tokens_for_user(&user.id)
         .await
         .unwrap();
-    let token_value = tokens[0].token.clone();
     let token_id = tokens[0].id.clone();
 
     // Set token to expire in 5 seconds (still valid)
@@ -295,9 +283,7 @@ async fn test_multiple_expired_tokens_all_rejected() {
     // Create multiple tokens and expire them all
     let mut expired_tokens = Vec::new();
     for _ in 0..3 {
-        PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-            .await
-            .unwrap();
+        let token_value = request_reset_token(db, &email).await;
         let tokens = db
             .get_all_password_reset_tokens_for_user(&user.id)
             .await
@@ -313,7 +299,7 @@ async fn test_multiple_expired_tokens_all_rejected() {
             .await
             .unwrap();
 
-        expired_tokens.push(latest_token.token.clone());
+        expired_tokens.push(token_value);
     }
 
     // Try to use each expired token - all should fail
@@ -350,14 +336,11 @@ async fn test_expired_token_error_message_same_as_invalid() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset and expire token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
+    let token_value = request_reset_token(db, &email).await;
     let tokens = db
         .get_all_password_reset_tokens_for_user(&user.id)
         .await
         .unwrap();
-    let token_value = tokens[0].token.clone();
     let token_id = tokens[0].id.clone();
 
     let past_time = chrono::Utc::now() - chrono::Duration::hours(2);
@@ -401,3 +384,47 @@ async fn test_expired_token_error_message_same_as_invalid() {
 
     teardown_test_db(test_db).await;
 }
+
+#[tokio::test]
+async fn test_configured_expiry_is_used_for_token_and_email() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+
+    let email = validate_and_normalize_email("shortlived@example.com").unwrap();
+    let password_hash = hash_password("TestPass123!").unwrap();
+
+    let user = User::new(email.clone(), UserType::Agent);
+    let agent = Agent::new(user.id.clone(), "Short Lived".to_string(), None, password_hash);
+
+    db.create_user(&user).await.unwrap();
+    db.create_agent(&agent).await.unwrap();
+
+    // Request password reset with a 15 minute expiry
+    let (mut service, mut mailbox) = password_reset_service_with_mailbox(db);
+    service.set_config(PasswordResetConfig {
+        token_ttl_seconds: 900,
+        ..PasswordResetConfig::default()
+    });
+    let before_request = chrono::Utc::now();
+    service.request_password_reset(&email).await.unwrap();
+
+    let tokens = db
+        .get_all_password_reset_tokens_for_user(&user.id)
+        .await
+        .unwrap();
+    let token_expires_at = chrono::DateTime::parse_from_rfc3339(&tokens[0].expires_at)
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let lifetime = (token_expires_at - before_request).num_seconds();
+    assert!(
+        (895..=905).contains(&lifetime),
+        "Token should expire after the configured 900 seconds, got {}",
+        lifetime
+    );
+
+    // The email states the configured lifetime
+    let sent = mailbox.next_email().await;
+    assert!(sent.text_body.contains("expire in 15 minutes"));
+
+    teardown_test_db(test_db).await;
+}
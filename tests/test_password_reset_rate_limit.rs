@@ -135,7 +135,7 @@ async fn test_rate_limiting_scenarios() {
 
         // Check that we're counting requests in a 3600 second (1 hour) window
         let count = db
-            .count_recent_reset_requests(&user.id, 3600)
+            .count_recent_reset_requests(&email, 3600)
             .await
             .unwrap();
         assert_eq!(count, 5, "Should count 5 requests in the 1-hour window");
@@ -189,23 +189,30 @@ async fn test_rate_limiting_scenarios() {
         teardown_test_db(test_db).await;
     }
 
-    // 5. Test: Rate limit does not affect non-existent emails
+    // 5. Test: Rate limit applies to non-existent emails too
     {
         let test_db = setup_test_db().await;
         let db = test_db.db();
 
-        // Request password reset for non-existent email multiple times
-        // Should always succeed with generic message (no rate limiting for non-existent users)
-        for i in 1..=10 {
+        // Unknown addresses are limited like real ones so the 429 does not
+        // reveal which accounts exist
+        for i in 1..=5 {
             let result =
                 PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( "nonexistent@example.com").await;
             assert!(
                 result.is_ok(),
-                "Request {} should succeed for non-existent email (no rate limiting)",
+                "Request {} should succeed for non-existent email",
                 i
             );
         }
 
+        let result =
+            PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( "nonexistent@example.com").await;
+        assert!(
+            matches!(result, Err(ApiError::TooManyRequests(_))),
+            "6th request should be rate limited for non-existent email"
+        );
+
         teardown_test_db(test_db).await;
     }
 }
@@ -4,7 +4,7 @@ mod helpers;
 
 use helpers::*;
 use oxidesk::{
-    domain::entities::{Agent, User, UserType},
+    domain::entities::{Agent, PasswordResetToken, User, UserType},
     application::services::auth::hash_password,
     shared::utils::email_validator::validate_and_normalize_email,
     application::services::PasswordResetService,
@@ -26,7 +26,8 @@ async fn test_request_password_reset_registered_email_success() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset
-    let result = PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email).await;
+    let (service, mut mailbox) = password_reset_service_with_mailbox(db);
+    let result = service.request_password_reset(&email).await;
 
     // Should succeed and return generic message
     if let Err(ref e) = result {
@@ -45,9 +46,16 @@ async fn test_request_password_reset_registered_email_success() {
         .await
         .unwrap();
     assert_eq!(tokens.len(), 1);
-    assert_eq!(tokens[0].token.len(), 32);
     assert!(!tokens[0].used);
 
+    // Verify the reset link was emailed to the agent
+    let sent = mailbox.next_email().await;
+    assert_eq!(sent.to, email);
+    assert!(sent.text_body.contains("/reset-password?token="));
+    assert!(sent.text_body.contains("expire in 1 hour"));
+    let token = extract_reset_token(&sent.text_body).unwrap();
+    assert_eq!(tokens[0].token_hash, PasswordResetToken::hash_token(&token));
+
     teardown_test_db(test_db).await;
 }
 
@@ -87,24 +95,25 @@ async fn test_request_password_reset_token_format() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
+    let token = request_reset_token(db, &email).await;
 
     // Verify token format
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    assert_eq!(tokens.len(), 1);
-
-    let token = &tokens[0].token;
     assert_eq!(token.len(), 32, "Token should be 32 characters");
     assert!(
         token.chars().all(|c| c.is_alphanumeric()),
         "Token should be alphanumeric only"
     );
 
+    // Only the SHA-256 hash of the token is stored
+    let tokens = db
+        .get_all_password_reset_tokens_for_user(&user.id)
+        .await
+        .unwrap();
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].token_hash.len(), 64);
+    assert_ne!(tokens[0].token_hash, token);
+    assert_eq!(tokens[0].token_hash, PasswordResetToken::hash_token(&token));
+
     teardown_test_db(test_db).await;
 }
 
@@ -160,14 +169,7 @@ async fn test_request_password_reset_invalidates_previous_tokens() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset twice
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens_after_first = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let first_token = tokens_after_first[0].token.clone();
+    let first_token = request_reset_token(db, &email).await;
 
     PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
         .await
@@ -109,13 +109,17 @@ async fn test_no_token_created_for_nonexistent_email() {
     let db = test_db.db();
 
     // Request password reset for non-existent email
-    let result = PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( "ghost@example.com").await;
+    let (service, mut mailbox) = password_reset_service_with_mailbox(db);
+    let result = service.request_password_reset("ghost@example.com").await;
     assert!(result.is_ok());
 
-    // Verify no tokens were created (since user doesn't exist)
-    // We can't directly query by email since we don't have a user_id,
-    // but we can verify the database has no tokens for this email pattern
-    // This is implicitly tested - if we had a user_id we could check tokens
+    // Verify no tokens were created and nothing was emailed
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM password_reset_tokens")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+    assert!(!mailbox.received_email().await, "No email should be sent");
 
     teardown_test_db(test_db).await;
 }
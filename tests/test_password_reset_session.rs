@@ -50,14 +50,7 @@ async fn test_password_reset_destroys_all_user_sessions() {
     );
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Reset password (should destroy all sessions)
     PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( token, "NewPass123!")
@@ -116,14 +109,7 @@ async fn test_password_reset_only_destroys_user_sessions() {
     db.create_session(&session1_user2).await.unwrap();
 
     // Reset password for user1
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email1)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user1.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email1).await;
     PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( token, "NewPass123!")
         .await
         .unwrap();
@@ -174,14 +160,7 @@ async fn test_password_reset_session_destruction_count() {
     }
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Reset password and check session destruction count
     let result = PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( token, "NewPass123!").await;
@@ -223,14 +202,7 @@ async fn test_password_reset_works_with_no_existing_sessions() {
     assert_eq!(sessions_before.len(), 0, "Should have no sessions");
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Reset password should still succeed (destroying 0 sessions)
     let result = PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( token, "NewPass123!").await;
@@ -267,14 +239,7 @@ async fn test_sessions_destroyed_synchronously() {
     db.create_session(&session).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Reset password
     PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( token, "NewPass123!")
@@ -32,14 +32,7 @@ async fn test_token_cannot_be_reused_after_successful_reset() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = tokens[0].token.clone();
+    let token = request_reset_token(db, &email).await;
 
     // Use token once (should succeed)
     let result1 = PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( &token, "NewPass123!").await;
@@ -80,14 +73,7 @@ async fn test_token_marked_used_after_reset() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = tokens[0].token.clone();
+    let token = request_reset_token(db, &email).await;
 
     // Verify token is not used initially
     let token_before = db.get_password_reset_token(&token).await.unwrap().unwrap();
@@ -129,32 +115,11 @@ async fn test_multiple_tokens_can_be_used_once_each() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset three times (each request invalidates previous tokens)
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens1 = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token1 = tokens1[0].token.clone();
+    let token1 = request_reset_token(db, &email).await;
 
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens2 = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token2 = tokens2.iter().find(|t| !t.used).unwrap().token.clone();
+    let token2 = request_reset_token(db, &email).await;
 
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens3 = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token3 = tokens3.iter().find(|t| !t.used).unwrap().token.clone();
+    let token3 = request_reset_token(db, &email).await;
 
     // Token1 should be invalidated (marked as used when token2 was created)
     let result1 = PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( &token1, "Pass1_123!").await;
@@ -196,14 +161,7 @@ async fn test_new_token_invalidates_previous_unused_tokens() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset (get first token)
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens1 = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let first_token = tokens1[0].token.clone();
+    let first_token = request_reset_token(db, &email).await;
 
     // Verify first token is not used
     let first_token_before = db
@@ -257,14 +215,7 @@ async fn test_token_reuse_returns_same_error_as_expired() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = tokens[0].token.clone();
+    let token = request_reset_token(db, &email).await;
 
     // Use token once
     PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( &token, "FirstPass123!")
@@ -310,14 +261,7 @@ async fn test_concurrent_use_of_same_token() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = tokens[0].token.clone();
+    let token = request_reset_token(db, &email).await;
 
     // Try to use token concurrently (simulate race condition)
     // In practice, only one should succeed due to database atomicity
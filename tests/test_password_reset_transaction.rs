@@ -71,14 +71,7 @@ async fn test_weak_password_does_not_mark_token_as_used() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Try to reset with weak password
     let result = PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( token, "weak").await;
@@ -130,14 +123,7 @@ async fn test_failed_reset_does_not_destroy_sessions() {
     db.create_session(&session2).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Try to reset with weak password (should fail)
     let result = PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( token, "weak").await;
@@ -218,14 +204,7 @@ async fn test_expired_token_does_not_change_password() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token_value = tokens[0].token.clone();
+    let token_value = request_reset_token(db, &email).await;
 
     // Manually update token to be expired (set expires_at to past)
     let expired_token = db
@@ -278,14 +257,7 @@ async fn test_used_token_does_not_change_password_again() {
     db.create_agent(&agent).await.unwrap();
 
     // Request password reset and get token
-    PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).request_password_reset( &email)
-        .await
-        .unwrap();
-    let tokens = db
-        .get_all_password_reset_tokens_for_user(&user.id)
-        .await
-        .unwrap();
-    let token = &tokens[0].token;
+    let token = &request_reset_token(db, &email).await;
 
     // Use token once
     PasswordResetService::new(oxidesk::domain::ports::password_reset_repository::PasswordResetRepository::new(db.clone()), std::sync::Arc::new(db.clone())).reset_password( token, "FirstNewPass123!")
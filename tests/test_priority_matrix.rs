@@ -0,0 +1,254 @@
+// Integration tests for the contact tier × tag priority matrix
+use oxidesk::application::services::{ConversationPriorityService, PriorityMatrixService};
+use oxidesk::domain::entities::*;
+use oxidesk::domain::errors::PriorityMatrixError;
+use oxidesk::domain::ports::event_bus::EventBus;
+use oxidesk::domain::ports::tag_repository::TagRepository;
+use oxidesk::infrastructure::http::middleware::AuthenticatedUser;
+use oxidesk::infrastructure::persistence::Database;
+use oxidesk::{LocalEventBus, SystemEvent};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::{create_auth_user_with_roles, ensure_admin_role};
+use helpers::*;
+
+fn matrix_service(db: &Database) -> PriorityMatrixService {
+    let repo = Arc::new(db.clone());
+    PriorityMatrixService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        TagRepository::new(db.clone()),
+        ConversationPriorityService::new(repo, None),
+    )
+}
+
+async fn admin(db: &Database) -> AuthenticatedUser {
+    let role = ensure_admin_role(db).await;
+    create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![role]).await
+}
+
+fn entry(
+    tier: Option<&str>,
+    tag_id: Option<&str>,
+    priority: Priority,
+) -> CreatePriorityMatrixEntryRequest {
+    CreatePriorityMatrixEntryRequest {
+        tier: tier.map(str::to_string),
+        tag_id: tag_id.map(str::to_string),
+        priority,
+    }
+}
+
+async fn enterprise_conversation(db: &Database, admin: &AuthenticatedUser) -> Conversation {
+    let contact = create_test_contact(db, "cto@bigcorp.example").await;
+    matrix_service(db)
+        .set_contact_tier(
+            admin,
+            &contact.user_id,
+            SetContactTierRequest {
+                tier: Some(" Enterprise ".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id,
+        ConversationStatus::Open,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_manage_matrix_entries() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = admin(db).await;
+    let agent = create_auth_user_with_roles(db, "agent@example.com", "Agent", vec![]).await;
+    let outage = create_test_tag(db, "outage", None, None).await;
+    let service = matrix_service(db);
+
+    let created = service
+        .create_entry(&admin, entry(Some("VIP"), Some(&outage.id), Priority::High))
+        .await
+        .unwrap();
+    assert_eq!(created.tier.as_deref(), Some("vip"));
+
+    let err = service
+        .create_entry(&admin, entry(Some("vip"), Some(&outage.id), Priority::Low))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, PriorityMatrixError::Conflict(_)));
+
+    let err = service
+        .create_entry(&admin, entry(None, Some("no-such-tag"), Priority::Low))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, PriorityMatrixError::Validation(_)));
+
+    let err = service
+        .create_entry(&agent, entry(Some("vip"), None, Priority::Low))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, PriorityMatrixError::Forbidden(_)));
+
+    assert_eq!(service.list_entries(&admin).await.unwrap().len(), 1);
+    service.delete_entry(&admin, &created.id).await.unwrap();
+    let err = service.delete_entry(&admin, &created.id).await.unwrap_err();
+    assert!(matches!(err, PriorityMatrixError::NotFound(_)));
+}
+
+#[tokio::test]
+async fn test_tier_and_tag_raise_priority() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = admin(db).await;
+    let outage = create_test_tag(db, "outage", None, None).await;
+    let service = matrix_service(db);
+    service
+        .create_entry(&admin, entry(Some("enterprise"), None, Priority::Medium))
+        .await
+        .unwrap();
+    service
+        .create_entry(
+            &admin,
+            entry(Some("enterprise"), Some(&outage.id), Priority::High),
+        )
+        .await
+        .unwrap();
+
+    let conversation = enterprise_conversation(db, &admin).await;
+    assert_eq!(
+        service
+            .apply_to_conversation(&conversation.id)
+            .await
+            .unwrap(),
+        Some(Priority::Medium)
+    );
+
+    db.add_conversation_tag(&conversation.id, &outage.id, &admin.user.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        service
+            .apply_to_conversation(&conversation.id)
+            .await
+            .unwrap(),
+        Some(Priority::High)
+    );
+    let updated = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(updated.priority, Some(Priority::High));
+
+    // Removing the tag never lowers a priority
+    db.remove_conversation_tag(&conversation.id, &outage.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        service
+            .apply_to_conversation(&conversation.id)
+            .await
+            .unwrap(),
+        None
+    );
+    let updated = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(updated.priority, Some(Priority::High));
+}
+
+#[tokio::test]
+async fn test_contacts_without_matching_tier_are_untouched() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = admin(db).await;
+    let service = matrix_service(db);
+    service
+        .create_entry(&admin, entry(Some("enterprise"), None, Priority::High))
+        .await
+        .unwrap();
+
+    let contact = create_test_contact(db, "someone@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id,
+        ConversationStatus::Open,
+    )
+    .await;
+    assert_eq!(
+        service
+            .apply_to_conversation(&conversation.id)
+            .await
+            .unwrap(),
+        None
+    );
+
+    let err = service
+        .set_contact_tier(
+            &admin,
+            "missing-contact",
+            SetContactTierRequest {
+                tier: Some("gold".to_string()),
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, PriorityMatrixError::NotFound(_)));
+}
+
+#[tokio::test]
+async fn test_listener_applies_matrix_on_new_conversations() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = admin(db).await;
+    let service = matrix_service(db);
+    service
+        .create_entry(&admin, entry(Some("enterprise"), None, Priority::High))
+        .await
+        .unwrap();
+    let conversation = enterprise_conversation(db, &admin).await;
+
+    let event_bus: Arc<dyn EventBus> = Arc::new(LocalEventBus::new(10));
+    let listener = tokio::spawn(
+        oxidesk::application::listeners::priority_matrix::run_priority_matrix_listener(
+            event_bus.clone(),
+            service,
+        ),
+    );
+    // Give the listener a moment to subscribe
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    event_bus
+        .publish(SystemEvent::ConversationCreated {
+            conversation_id: conversation.id.clone(),
+            inbox_id: conversation.inbox_id.clone(),
+            contact_id: conversation.contact_id.clone(),
+            status: ConversationStatus::Open,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        })
+        .unwrap();
+
+    let mut priority = None;
+    for _ in 0..50 {
+        priority = db
+            .get_conversation_by_id(&conversation.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .priority;
+        if priority.is_some() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    listener.abort();
+    assert_eq!(priority, Some(Priority::High));
+}
@@ -0,0 +1,174 @@
+// Integration tests for read-only reporting tokens
+use std::sync::Arc;
+
+use oxidesk::application::services::ReportingService;
+use oxidesk::domain::entities::{
+    ConversationStatus, CreateReportingTokenRequest, CsatResponse, Priority,
+};
+use oxidesk::domain::errors::ReportingError;
+use oxidesk::infrastructure::persistence::Database;
+
+mod helpers;
+use helpers::rbac_helpers::{create_auth_user_with_roles, ensure_admin_role};
+use helpers::*;
+
+fn service(db: &Database) -> ReportingService {
+    ReportingService::new(Arc::new(db.clone()))
+}
+
+fn token_request(name: &str) -> CreateReportingTokenRequest {
+    CreateReportingTokenRequest {
+        name: name.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_admin_issues_token_that_authenticates() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = service(db);
+    let admin_role = ensure_admin_role(db).await;
+    let admin =
+        create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![admin_role]).await;
+    let agent = create_auth_user_with_roles(db, "agent@example.com", "Agent", vec![]).await;
+
+    let result = service
+        .create_token(&agent, token_request("Metabase"))
+        .await;
+    assert!(matches!(result, Err(ReportingError::Forbidden(_))));
+
+    let created = service
+        .create_token(&admin, token_request("Metabase"))
+        .await
+        .unwrap();
+    assert!(created.plain_token.starts_with("oxr_"));
+    assert!(created.plain_token.starts_with(&created.token.token_prefix));
+
+    // The hash is never serialized, the plain token only on creation
+    let json = serde_json::to_value(&created).unwrap();
+    assert_eq!(json["token"], created.plain_token.as_str());
+    assert!(json.get("token_hash").is_none());
+
+    let authenticated = service
+        .authenticate(&created.plain_token)
+        .await
+        .unwrap()
+        .expect("token should authenticate");
+    assert_eq!(authenticated.id, created.token.id);
+
+    let tokens = service.list_tokens(&admin).await.unwrap();
+    assert_eq!(tokens.len(), 1);
+    assert!(tokens[0].last_used_at.is_some());
+    assert!(matches!(
+        service.list_tokens(&agent).await,
+        Err(ReportingError::Forbidden(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_revoked_and_unknown_tokens_are_rejected() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = service(db);
+    let admin_role = ensure_admin_role(db).await;
+    let admin =
+        create_auth_user_with_roles(db, "admin@example.com", "Admin", vec![admin_role]).await;
+
+    let created = service
+        .create_token(&admin, token_request("Looker"))
+        .await
+        .unwrap();
+    service
+        .revoke_token(&admin, &created.token.id)
+        .await
+        .unwrap();
+
+    assert!(service
+        .authenticate(&created.plain_token)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(service
+        .authenticate("oxr_notarealtoken")
+        .await
+        .unwrap()
+        .is_none());
+
+    let result = service.revoke_token(&admin, "missing").await;
+    assert!(matches!(result, Err(ReportingError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_summary_counts_conversations_and_csat() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = service(db);
+    let contact = create_test_contact(db, "kpi@example.com").await;
+
+    let mut conversations = Vec::new();
+    for status in [
+        ConversationStatus::Open,
+        ConversationStatus::Open,
+        ConversationStatus::Resolved,
+    ] {
+        conversations.push(
+            create_test_conversation(db, "inbox-001".to_string(), contact.id.clone(), status).await,
+        );
+    }
+    db.set_conversation_priority(&conversations[0].id, &Priority::High)
+        .await
+        .unwrap();
+    for (conversation, score) in [(&conversations[1], 4), (&conversations[2], 5)] {
+        let response = CsatResponse::new(conversation.id.clone(), score, None);
+        db.create_csat_response(&response).await.unwrap();
+    }
+
+    let summary = service.summary(None).await.unwrap();
+    assert_eq!(summary.conversations.total, 3);
+    assert_eq!(summary.conversations.by_status["open"], 2);
+    assert_eq!(summary.conversations.by_status["resolved"], 1);
+    assert_eq!(summary.conversations.by_priority["high"], 1);
+    assert_eq!(summary.conversations.by_priority["none"], 2);
+    assert_eq!(summary.csat.responses, 2);
+    assert_eq!(summary.csat.average_score, Some(4.5));
+
+    let summary = service.summary(Some("2999-01-01")).await.unwrap();
+    assert_eq!(summary.conversations.total, 0);
+    assert_eq!(summary.csat.average_score, None);
+}
+
+#[tokio::test]
+async fn test_conversation_export_has_metadata_but_no_content() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = service(db);
+    let contact = create_test_contact(db, "export@example.com").await;
+    let agent = create_test_agent(db, "tagger@example.com", "Tagger").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    for name in ["vip", "billing"] {
+        let tag = create_test_tag(db, name, None, None).await;
+        db.add_conversation_tag(&conversation.id, &tag.id, &agent.user_id)
+            .await
+            .unwrap();
+    }
+
+    let page = service.list_conversations(None, 50, 0).await.unwrap();
+    assert_eq!(page.conversations.len(), 1);
+    let exported = &page.conversations[0];
+    assert_eq!(exported.id, conversation.id);
+    assert_eq!(exported.status, "open");
+    assert_eq!(exported.tags, vec!["billing", "vip"]);
+
+    let json = serde_json::to_value(exported).unwrap();
+    assert!(json.get("subject").is_none());
+    assert!(json.get("contact_id").is_none());
+
+    let page = service.list_conversations(None, 10_000, -5).await.unwrap();
+    assert_eq!((page.limit, page.offset), (500, 0));
+}
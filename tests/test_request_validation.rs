@@ -0,0 +1,232 @@
+// Integration tests for request body validation
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request, StatusCode},
+    routing::post,
+    Json, Router,
+};
+use oxidesk::{
+    domain::entities::*,
+    infrastructure::http::middleware::ValidatedJson,
+    shared::validation::{Validate, ValidationErrors},
+};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+fn parse<T: serde::de::DeserializeOwned>(value: Value) -> T {
+    serde_json::from_value(value).expect("request should deserialize")
+}
+
+fn fields(errors: &ValidationErrors) -> Vec<&str> {
+    errors.errors().iter().map(|e| e.field.as_str()).collect()
+}
+
+async fn create_tag(ValidatedJson(request): ValidatedJson<CreateTagRequest>) -> Json<Value> {
+    Json(json!({ "name": request.name }))
+}
+
+async fn post_json(body: &str) -> (StatusCode, Value) {
+    let app = Router::new().route("/tags", post(create_tag));
+    let response = app
+        .oneshot(
+            Request::post("/tags")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    (status, body)
+}
+
+#[test]
+fn test_valid_requests_pass() {
+    let tag: CreateTagRequest = parse(json!({ "name": "billing", "color": "#1A2B3C" }));
+    assert!(tag.check().is_ok());
+
+    let webhook: CreateWebhookRequest = parse(json!({
+        "name": "CRM sync",
+        "url": "https://crm.example.com/hooks/oxidesk",
+        "subscribed_events": ["conversation.created"],
+        "secret": "0123456789abcdef"
+    }));
+    assert!(webhook.check().is_ok());
+
+    let shift: CreateShiftRequest = parse(json!({
+        "user_id": "user-1",
+        "day": "mon",
+        "start_time": "09:00",
+        "end_time": "17:00"
+    }));
+    assert!(shift.check().is_ok());
+}
+
+#[test]
+fn test_all_field_errors_are_reported() {
+    let webhook: CreateWebhookRequest = parse(json!({
+        "name": " ",
+        "url": "ftp://crm.example.com",
+        "subscribed_events": [],
+        "secret": "short"
+    }));
+
+    let errors = webhook.check().unwrap_err();
+    assert_eq!(
+        fields(&errors),
+        vec!["name", "url", "subscribed_events", "secret"]
+    );
+    assert_eq!(errors.messages_for("name"), vec!["is required"]);
+    assert_eq!(
+        errors.messages_for("secret"),
+        vec!["must be at least 16 characters"]
+    );
+}
+
+#[test]
+fn test_format_and_cross_field_errors() {
+    let agent: CreateAgentRequest = parse(json!({
+        "email": "not-an-email",
+        "first_name": "Ada"
+    }));
+    let errors = agent.check().unwrap_err();
+    assert_eq!(
+        errors.messages_for("email"),
+        vec!["must be a valid email address"]
+    );
+
+    let shift: CreateShiftRequest = parse(json!({
+        "user_id": "user-1",
+        "day": "someday",
+        "start_time": "17:00",
+        "end_time": "09:00"
+    }));
+    let errors = shift.check().unwrap_err();
+    assert_eq!(fields(&errors), vec!["day", "end_time"]);
+    assert_eq!(
+        errors.messages_for("end_time"),
+        vec!["must be after start_time"]
+    );
+
+    let status: UpdateStatusRequest = parse(json!({ "status": "snoozed" }));
+    let errors = status.check().unwrap_err();
+    assert_eq!(
+        errors.messages_for("snooze_duration"),
+        vec!["is required when snoozing"]
+    );
+}
+
+#[test]
+fn test_partial_updates_only_check_present_fields() {
+    let update: UpdateWebhookRequest = parse(json!({}));
+    assert!(update.check().is_ok());
+
+    let update: UpdateWebhookRequest = parse(json!({ "url": "not a url" }));
+    let errors = update.check().unwrap_err();
+    assert_eq!(fields(&errors), vec!["url"]);
+}
+
+#[test]
+fn test_unknown_webhook_events_are_rejected() {
+    let webhook: CreateWebhookRequest = parse(json!({
+        "name": "CRM sync",
+        "url": "https://crm.example.com/hooks/oxidesk",
+        "subscribed_events": ["conversation.created", "conversation.craeted"],
+        "secret": "0123456789abcdef"
+    }));
+    let errors = webhook.check().unwrap_err();
+    assert_eq!(
+        errors.messages_for("subscribed_events"),
+        vec!["unknown event type 'conversation.craeted', see GET /api/webhooks/events"]
+    );
+
+    let update: UpdateWebhookRequest = parse(json!({ "subscribed_events": ["message.recieved"] }));
+    let errors = update.check().unwrap_err();
+    assert_eq!(fields(&errors), vec!["subscribed_events"]);
+}
+
+#[test]
+fn test_action_requests_are_validated() {
+    let tags: AddTagsRequest = parse(json!({ "tag_ids": [] }));
+    assert_eq!(
+        tags.check().unwrap_err().messages_for("tag_ids"),
+        vec!["must not be empty"]
+    );
+    let tags: ReplaceTagsRequest = parse(json!({ "tag_ids": [] }));
+    assert!(tags.check().is_ok());
+
+    let assign: AssignConversationRequest = parse(json!({}));
+    assert_eq!(
+        fields(&assign.check().unwrap_err()),
+        vec!["assigned_user_id"]
+    );
+
+    let reaction: AddReactionRequest = parse(json!({ "emoji": "thumbs_up" }));
+    assert!(reaction.check().is_ok());
+    let reaction: AddReactionRequest = parse(json!({ "emoji": "🎉" }));
+    assert_eq!(fields(&reaction.check().unwrap_err()), vec!["emoji"]);
+
+    let backfill: AutoTagBackfillRequest = parse(json!({ "limit": 0, "offset": -1 }));
+    assert_eq!(
+        fields(&backfill.check().unwrap_err()),
+        vec!["limit", "offset"]
+    );
+
+    let channel: ChannelMessageRequest = parse(json!({
+        "sender": { "email": "not-an-email" },
+        "content": ""
+    }));
+    assert_eq!(
+        fields(&channel.check().unwrap_err()),
+        vec!["sender.email", "content"]
+    );
+
+    let availability: SetAvailabilityRequest = parse(json!({ "status": "away_and_reassigning" }));
+    assert_eq!(fields(&availability.check().unwrap_err()), vec!["status"]);
+
+    let csat: SubmitCsatRequest = parse(json!({ "score": 6 }));
+    assert_eq!(
+        csat.check().unwrap_err().messages_for("score"),
+        vec!["must be between 1 and 5"]
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_body_is_rejected_with_field_errors() {
+    let (status, body) = post_json(r##"{"name": "", "color": "red"}"##).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(body["error"], "Validation failed");
+    assert_eq!(
+        body["fields"],
+        json!([
+            { "field": "name", "message": "is required" },
+            { "field": "color", "message": "must be a hex colour (#RRGGBB)" }
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_missing_field_is_reported_as_field_error() {
+    let (status, body) = post_json(r##"{"color": "#FFFFFF"}"##).await;
+
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(
+        body["fields"],
+        json!([{ "field": "name", "message": "is required" }])
+    );
+}
+
+#[tokio::test]
+async fn test_valid_body_reaches_handler() {
+    let (status, body) = post_json(r##"{"name": "billing", "color": "#00AA00"}"##).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "billing");
+
+    let (status, _) = post_json("{not json").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,193 @@
+// Integration tests for response due reminders to conversation assignees
+use chrono::{DateTime, Duration, Utc};
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    domain::errors::ResponseReminderError,
+    domain::ports::notification_repository::NotificationRepository,
+    infrastructure::{
+        persistence::Database, providers::connection_manager::InMemoryConnectionManager,
+    },
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+fn reminder_service(db: &Database) -> ResponseReminderService {
+    ResponseReminderService::new(
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        Arc::new(InMemoryConnectionManager::new()),
+    )
+}
+
+async fn add_message(
+    db: &Database,
+    conversation_id: &str,
+    message_type: &str,
+    author_id: &str,
+    created_at: DateTime<Utc>,
+) -> String {
+    let id = uuid::Uuid::new_v4().to_string();
+    let created_at = created_at.to_rfc3339();
+    sqlx::query(
+        "INSERT INTO messages (id, conversation_id, type, status, content, author_id, created_at, updated_at)
+         VALUES (?, ?, ?, 'received', 'Hello', ?, ?, ?)",
+    )
+    .bind(&id)
+    .bind(conversation_id)
+    .bind(message_type)
+    .bind(author_id)
+    .bind(&created_at)
+    .bind(&created_at)
+    .execute(db.pool())
+    .await
+    .expect("Failed to create message");
+    id
+}
+
+async fn reminders_for(db: &Database, user_id: &str) -> Vec<UserNotification> {
+    db.list_notifications(user_id, 50, 0)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|n| n.notification_type == NotificationType::Reminder)
+        .collect()
+}
+
+fn settings(
+    enabled: bool,
+    after: i64,
+    repeat: Option<i64>,
+) -> UpdateResponseReminderSettingsRequest {
+    UpdateResponseReminderSettingsRequest {
+        enabled,
+        remind_after_minutes: after,
+        repeat_after_minutes: repeat,
+    }
+}
+
+#[tokio::test]
+async fn test_reminds_assignee_and_repeats_until_reply() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation_id =
+        create_assigned_conversation(db, "inbox-001", &contact.id, &agent.user_id).await;
+
+    let now = Utc::now();
+    let message_id = add_message(
+        db,
+        &conversation_id,
+        "incoming",
+        &contact.user_id,
+        now - Duration::minutes(45),
+    )
+    .await;
+
+    let service = reminder_service(db);
+    service
+        .update_settings(&agent.id, settings(true, 30, Some(60)))
+        .await
+        .unwrap();
+
+    assert_eq!(service.send_due_reminders(now).await.unwrap(), 1);
+    let reminders = reminders_for(db, &agent.user_id).await;
+    assert_eq!(reminders.len(), 1);
+    assert_eq!(
+        reminders[0].conversation_id.as_deref(),
+        Some(conversation_id.as_str())
+    );
+    assert_eq!(
+        reminders[0].message_id.as_deref(),
+        Some(message_id.as_str())
+    );
+
+    // Nothing new until the repeat interval has passed
+    assert_eq!(service.send_due_reminders(now).await.unwrap(), 0);
+    let later = now + Duration::minutes(61);
+    assert_eq!(service.send_due_reminders(later).await.unwrap(), 1);
+    assert_eq!(reminders_for(db, &agent.user_id).await.len(), 2);
+
+    // A reply stops the reminders
+    add_message(
+        db,
+        &conversation_id,
+        "outgoing",
+        &agent.user_id,
+        later + Duration::minutes(1),
+    )
+    .await;
+    let much_later = later + Duration::hours(5);
+    assert_eq!(service.send_due_reminders(much_later).await.unwrap(), 0);
+
+    // A new contact message starts a fresh cycle once it has waited long enough
+    add_message(
+        db,
+        &conversation_id,
+        "incoming",
+        &contact.user_id,
+        later + Duration::minutes(2),
+    )
+    .await;
+    assert_eq!(service.send_due_reminders(much_later).await.unwrap(), 1);
+    assert_eq!(reminders_for(db, &agent.user_id).await.len(), 3);
+}
+
+#[tokio::test]
+async fn test_reminders_are_opt_in_per_agent() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation_id =
+        create_assigned_conversation(db, "inbox-001", &contact.id, &agent.user_id).await;
+    let now = Utc::now();
+    add_message(
+        db,
+        &conversation_id,
+        "incoming",
+        &contact.user_id,
+        now - Duration::hours(3),
+    )
+    .await;
+
+    let service = reminder_service(db);
+    let defaults = service.get_settings(&agent.id).await.unwrap();
+    assert!(!defaults.enabled);
+    assert_eq!(defaults.remind_after_minutes, DEFAULT_REMIND_AFTER_MINUTES);
+    assert_eq!(service.send_due_reminders(now).await.unwrap(), 0);
+
+    // Once only: no repeat interval configured
+    service
+        .update_settings(&agent.id, settings(true, 60, None))
+        .await
+        .unwrap();
+    assert_eq!(service.send_due_reminders(now).await.unwrap(), 1);
+    assert_eq!(
+        service
+            .send_due_reminders(now + Duration::days(1))
+            .await
+            .unwrap(),
+        0
+    );
+
+    service
+        .update_settings(&agent.id, settings(false, 60, Some(30)))
+        .await
+        .unwrap();
+    assert!(!service.get_settings(&agent.id).await.unwrap().enabled);
+    assert_eq!(
+        service
+            .send_due_reminders(now + Duration::days(2))
+            .await
+            .unwrap(),
+        0
+    );
+
+    let result = service.get_settings("no-such-agent").await;
+    assert!(matches!(result, Err(ResponseReminderError::NotFound(_))));
+}
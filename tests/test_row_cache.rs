@@ -0,0 +1,116 @@
+// Integration tests for the repository row cache: cached lookups must reflect writes
+use oxidesk::{
+    domain::entities::*,
+    domain::ports::role_repository::RoleRepository,
+};
+
+mod helpers;
+use helpers::rbac_helpers::{create_test_agent as create_rbac_agent, create_test_role};
+use helpers::*;
+
+#[tokio::test]
+async fn test_conversation_lookup_reflects_writes() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+
+    // Populate the cache, then write through each kind of update
+    let cached = db.get_conversation_by_id(&conversation.id).await.unwrap().unwrap();
+    assert_eq!(cached.status, ConversationStatus::Open);
+    assert!(cached.priority.is_none());
+
+    db.update_conversation_status(&conversation.id, ConversationStatus::Resolved)
+        .await
+        .unwrap();
+    let updated = db.get_conversation_by_id(&conversation.id).await.unwrap().unwrap();
+    assert_eq!(updated.status, ConversationStatus::Resolved);
+
+    db.set_conversation_priority(&conversation.id, &Priority::High)
+        .await
+        .unwrap();
+    let updated = db.get_conversation_by_id(&conversation.id).await.unwrap().unwrap();
+    assert_eq!(updated.priority, Some(Priority::High));
+
+    db.create_csat_response(&CsatResponse::new(conversation.id.clone(), 4, None))
+        .await
+        .unwrap();
+    let updated = db.get_conversation_by_id(&conversation.id).await.unwrap().unwrap();
+    assert_eq!(updated.csat_score, Some(4));
+}
+
+#[tokio::test]
+async fn test_bulk_unassign_invalidates_cached_conversations() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+
+    db.assign_conversation_to_user(&conversation.id, Some(agent.user_id.clone()), None)
+        .await
+        .unwrap();
+    let cached = db.get_conversation_by_id(&conversation.id).await.unwrap().unwrap();
+    assert_eq!(cached.assigned_user_id.as_deref(), Some(agent.user_id.as_str()));
+
+    let unassigned = db.unassign_agent_open_conversations(&agent.user_id).await.unwrap();
+    assert_eq!(unassigned, 1);
+    let updated = db.get_conversation_by_id(&conversation.id).await.unwrap().unwrap();
+    assert!(updated.assigned_user_id.is_none());
+}
+
+#[tokio::test]
+async fn test_role_lookups_reflect_writes() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let (user, _agent) = create_rbac_agent(db, "agent@example.com", "Agent").await;
+    let role = create_test_role(db, "Reviewer", None, vec!["conversations:read".to_string()]).await;
+
+    assert!(RoleRepository::get_user_roles(db, &user.id)
+        .await
+        .unwrap()
+        .iter()
+        .all(|r| r.id != role.id));
+    let cached = RoleRepository::get_role_by_name(db, "Reviewer")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.permissions, vec!["conversations:read".to_string()]);
+
+    RoleRepository::assign_role_to_user(db, &UserRole::new(user.id.clone(), role.id.clone()))
+        .await
+        .unwrap();
+    let roles = RoleRepository::get_user_roles(db, &user.id).await.unwrap();
+    assert!(roles.iter().any(|r| r.id == role.id));
+
+    let permissions = vec!["conversations:read".to_string(), "tags:create".to_string()];
+    RoleRepository::update_role(db, &role.id, None, None, Some(&permissions))
+        .await
+        .unwrap();
+    let updated = RoleRepository::get_role_by_name(db, "Reviewer")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(updated.permissions, permissions);
+    let roles = RoleRepository::get_user_roles(db, &user.id).await.unwrap();
+    let assigned = roles.iter().find(|r| r.id == role.id).unwrap();
+    assert_eq!(assigned.permissions, permissions);
+
+    RoleRepository::remove_user_roles(db, &user.id).await.unwrap();
+    assert!(RoleRepository::get_user_roles(db, &user.id)
+        .await
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,198 @@
+// Integration tests for inbound message sentiment tracking
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    domain::ports::event_bus::EventBus,
+    domain::services::LexiconSentimentAnalyzer,
+    infrastructure::persistence::Database,
+    LocalEventBus,
+};
+use std::sync::Arc;
+use tokio_stream::StreamExt;
+
+mod helpers;
+use helpers::*;
+
+fn message_service(db: &Database, event_bus: Arc<LocalEventBus>) -> MessageService {
+    let repo = Arc::new(db.clone());
+    let mut service = MessageService::new(repo.clone(), repo.clone());
+    service.set_sentiment_service(SentimentService::new(
+        repo.clone(),
+        repo,
+        Arc::new(LexiconSentimentAnalyzer::new()),
+        event_bus,
+    ));
+    service
+}
+
+async fn receive(
+    service: &MessageService,
+    conversation: &Conversation,
+    author_id: &str,
+    content: &str,
+) {
+    service
+        .create_incoming_message(IncomingMessageRequest {
+            conversation_id: conversation.id.clone(),
+            content: content.to_string(),
+            contact_id: Some(author_id.to_string()),
+            inbox_id: conversation.inbox_id.clone(),
+            from_header: None,
+            external_id: None,
+            received_at: None,
+            channel: None,
+            channel_metadata: None,
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_incoming_messages_update_conversation_sentiment() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let event_bus = Arc::new(LocalEventBus::new(100));
+    let mut rx = event_bus.subscribe();
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    let service = message_service(db, event_bus.clone());
+
+    receive(
+        &service,
+        &conversation,
+        &contact.user_id,
+        "Thanks, the new dashboard is great",
+    )
+    .await;
+    let fetched = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    let first_score = fetched.sentiment_score.unwrap();
+    assert!(first_score > POSITIVE_SENTIMENT_THRESHOLD);
+    assert_eq!(fetched.sentiment_trend, Some(SentimentTrend::Stable));
+
+    receive(
+        &service,
+        &conversation,
+        &contact.user_id,
+        "This is unacceptable, it is broken again and I am furious",
+    )
+    .await;
+    let fetched = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    let second_score = fetched.sentiment_score.unwrap();
+    assert!(second_score < first_score);
+    assert_eq!(fetched.sentiment_trend, Some(SentimentTrend::Declining));
+
+    // A sharp drop is published for webhooks and automation rules
+    let event = tokio::time::timeout(std::time::Duration::from_secs(1), rx.next())
+        .await
+        .expect("Timeout waiting for event")
+        .expect("Failed to receive event")
+        .expect("Broadcast error");
+    match event {
+        oxidesk::events::SystemEvent::SentimentDropped {
+            conversation_id,
+            previous_score,
+            current_score,
+            ..
+        } => {
+            assert_eq!(conversation_id, conversation.id);
+            assert_eq!(previous_score, first_score);
+            assert_eq!(current_score, second_score);
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+
+    let sentiment = SentimentService::new(
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        Arc::new(LexiconSentimentAnalyzer::new()),
+        event_bus,
+    )
+    .get_conversation_sentiment(&conversation.id)
+    .await
+    .unwrap();
+    assert_eq!(sentiment.messages.len(), 2);
+    assert!(sentiment.messages[0].score > 0.0);
+    assert!(sentiment.messages[1].score < 0.0);
+    assert_eq!(sentiment.score, Some(second_score));
+}
+
+#[tokio::test]
+async fn test_list_conversations_by_sentiment() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let event_bus = Arc::new(LocalEventBus::new(100));
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let service = message_service(db, event_bus);
+
+    let mut conversations = Vec::new();
+    for status in [
+        ConversationStatus::Open,
+        ConversationStatus::Open,
+        ConversationStatus::Resolved,
+    ] {
+        conversations.push(
+            create_test_conversation(db, "inbox-001".to_string(), contact.id.clone(), status).await,
+        );
+    }
+    receive(
+        &service,
+        &conversations[0],
+        &contact.user_id,
+        "Terrible service, I want a refund",
+    )
+    .await;
+    receive(
+        &service,
+        &conversations[1],
+        &contact.user_id,
+        "Thank you, that was helpful",
+    )
+    .await;
+    receive(
+        &service,
+        &conversations[2],
+        &contact.user_id,
+        "Still broken, this is awful",
+    )
+    .await;
+
+    let negative = ConversationListFilter {
+        sentiment: Some(SentimentLabel::Negative),
+        ..Default::default()
+    };
+    assert_eq!(db.count_conversations(&negative).await.unwrap(), 2);
+
+    let negative_unresolved = ConversationListFilter {
+        unresolved: true,
+        ..negative
+    };
+    let listed = db
+        .list_conversations(20, 0, &negative_unresolved)
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, conversations[0].id);
+    assert!(listed[0].sentiment_score.unwrap() <= NEGATIVE_SENTIMENT_THRESHOLD);
+
+    let positive = ConversationListFilter {
+        sentiment: Some(SentimentLabel::Positive),
+        ..Default::default()
+    };
+    let listed = db.list_conversations(20, 0, &positive).await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, conversations[1].id);
+}
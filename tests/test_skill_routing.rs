@@ -0,0 +1,347 @@
+// Integration tests for the skill catalog and skill-based routing
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    domain::ports::event_bus::EventBus,
+    infrastructure::{
+        http::middleware::ApiError, persistence::Database,
+        providers::connection_manager::InMemoryConnectionManager,
+    },
+    LocalEventBus, SystemEvent,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::{add_user_to_team, create_test_team};
+use helpers::*;
+
+fn assignment_service(db: &Database) -> AssignmentService {
+    let repo = Arc::new(db.clone());
+    let mut service = AssignmentService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        Arc::new(LocalEventBus::new(10)),
+        NotificationService::new(Some(Arc::new(db.clone()))),
+        Arc::new(InMemoryConnectionManager::new()),
+    );
+    service.set_skill_repo(repo);
+    service
+}
+
+async fn create_skill(service: &AssignmentService, name: &str) -> Skill {
+    service
+        .create_skill(CreateSkillRequest {
+            name: name.to_string(),
+            description: None,
+        })
+        .await
+        .expect("Failed to create skill")
+}
+
+/// An online agent with the given skills
+async fn skilled_agent(
+    db: &Database,
+    service: &AssignmentService,
+    email: &str,
+    skills: &[&Skill],
+) -> Agent {
+    let agent = create_test_agent(db, email, "Agent").await;
+    db.update_agent_availability_with_timestamp(&agent.id, AgentAvailability::Online)
+        .await
+        .unwrap();
+    service
+        .set_agent_skills(
+            &agent.user_id,
+            skills.iter().map(|skill| skill.id.clone()).collect(),
+        )
+        .await
+        .unwrap();
+    agent
+}
+
+/// An open conversation carrying the given tags
+async fn tagged_conversation(db: &Database, email: &str, tags: &[&Tag]) -> Conversation {
+    let contact = create_test_contact(db, email).await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id,
+        ConversationStatus::Open,
+    )
+    .await;
+    for tag in tags {
+        db.add_conversation_tag(&conversation.id, &tag.id, &contact.user_id)
+            .await
+            .unwrap();
+    }
+    conversation
+}
+
+#[tokio::test]
+async fn test_skill_catalog_crud_and_validation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = assignment_service(db);
+
+    let billing = service
+        .create_skill(CreateSkillRequest {
+            name: "  Billing ".to_string(),
+            description: Some("Invoices and refunds".to_string()),
+        })
+        .await
+        .unwrap();
+    assert_eq!(billing.name, "Billing");
+    create_skill(&service, "german").await;
+
+    for name in ["", "   ", &"x".repeat(51)] {
+        let err = service
+            .create_skill(CreateSkillRequest {
+                name: name.to_string(),
+                description: None,
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::Validation(_)), "name {:?}", name);
+    }
+
+    let err = service
+        .create_skill(CreateSkillRequest {
+            name: "billing".to_string(),
+            description: None,
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::Conflict(_)));
+
+    let updated = service
+        .update_skill(
+            &billing.id,
+            UpdateSkillRequest {
+                description: Some("Payments".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.description.as_deref(), Some("Payments"));
+
+    let names: Vec<String> = service
+        .list_skills()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|skill| skill.name)
+        .collect();
+    assert_eq!(names, vec!["Billing", "german"]);
+
+    service.delete_skill(&billing.id).await.unwrap();
+    let err = service.delete_skill(&billing.id).await.unwrap_err();
+    assert!(matches!(err, ApiError::NotFound(_)));
+}
+
+#[tokio::test]
+async fn test_agent_skills_are_replaced_and_checked() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = assignment_service(db);
+    let billing = create_skill(&service, "billing").await;
+    let api = create_skill(&service, "api").await;
+    let agent = create_test_agent(db, "maria@example.com", "Maria").await;
+
+    let skills = service
+        .set_agent_skills(
+            &agent.user_id,
+            vec![billing.id.clone(), api.id.clone(), billing.id.clone()],
+        )
+        .await
+        .unwrap();
+    let names: Vec<&str> = skills.iter().map(|skill| skill.name.as_str()).collect();
+    assert_eq!(names, vec!["api", "billing"]);
+
+    service
+        .set_agent_skills(&agent.user_id, vec![api.id.clone()])
+        .await
+        .unwrap();
+    let skills = service.get_agent_skills(&agent.user_id).await.unwrap();
+    assert_eq!(skills.len(), 1);
+    assert_eq!(skills[0].id, api.id);
+
+    let err = service
+        .set_agent_skills(&agent.user_id, vec!["missing-skill".to_string()])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::BadRequest(_)));
+
+    let err = service
+        .set_agent_skills("missing-agent", vec![api.id.clone()])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ApiError::NotFound(_)));
+
+    // Deleting a skill removes it from agents
+    service.delete_skill(&api.id).await.unwrap();
+    assert!(service
+        .get_agent_skills(&agent.user_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_conversation_goes_to_available_agent_with_all_skills() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = assignment_service(db);
+    let billing = create_skill(&service, "billing").await;
+    let german = create_skill(&service, "German").await;
+    let billing_tag = create_test_tag(db, "billing", None, None).await;
+    let german_tag = create_test_tag(db, "german", None, None).await;
+    let other_tag = create_test_tag(db, "vip", None, None).await;
+
+    skilled_agent(db, &service, "billing-only@example.com", &[&billing]).await;
+    let away = skilled_agent(db, &service, "away@example.com", &[&billing, &german]).await;
+    db.update_agent_availability_with_timestamp(&away.id, AgentAvailability::Away)
+        .await
+        .unwrap();
+    let capable = skilled_agent(db, &service, "capable@example.com", &[&billing, &german]).await;
+
+    let conversation = tagged_conversation(
+        db,
+        "kunde@example.com",
+        &[&billing_tag, &german_tag, &other_tag],
+    )
+    .await;
+    let routed = service
+        .route_by_skills(&conversation.id)
+        .await
+        .unwrap()
+        .expect("conversation should be routed");
+    assert_eq!(
+        routed.assigned_user_id.as_deref(),
+        Some(capable.user_id.as_str())
+    );
+
+    let history = service
+        .get_assignment_history(&conversation.id)
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].source, AssignmentSource::Rule);
+    assert_eq!(history[0].assigned_by, "system");
+    assert_eq!(
+        history[0].reason.as_deref(),
+        Some("Has required skills: billing, German")
+    );
+
+    // Already assigned conversations are left alone
+    assert!(service
+        .route_by_skills(&conversation.id)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Nobody online has every skill
+    let api_tag = create_test_tag(db, "api", None, None).await;
+    create_skill(&service, "api").await;
+    let unroutable = tagged_conversation(db, "dev@example.com", &[&api_tag, &billing_tag]).await;
+    assert!(service
+        .route_by_skills(&unroutable.id)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Tags that name no skill don't route
+    let untagged = tagged_conversation(db, "plain@example.com", &[&other_tag]).await;
+    assert!(service
+        .route_by_skills(&untagged.id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_least_loaded_team_member_is_chosen() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = assignment_service(db);
+    let billing = create_skill(&service, "billing").await;
+    let billing_tag = create_test_tag(db, "billing", None, None).await;
+
+    let busy = skilled_agent(db, &service, "busy@example.com", &[&billing]).await;
+    let free = skilled_agent(db, &service, "free@example.com", &[&billing]).await;
+    let outsider = skilled_agent(db, &service, "outsider@example.com", &[&billing]).await;
+    let team = create_test_team(db, "Billing").await;
+    add_user_to_team(db, &busy.user_id, &team).await;
+    add_user_to_team(db, &free.user_id, &team).await;
+
+    let existing = tagged_conversation(db, "first@example.com", &[]).await;
+    db.assign_conversation_to_user(&existing.id, Some(busy.user_id.clone()), None)
+        .await
+        .unwrap();
+
+    let conversation = tagged_conversation(db, "second@example.com", &[&billing_tag]).await;
+    db.assign_conversation_to_team(&conversation.id, Some(team.clone()), None)
+        .await
+        .unwrap();
+    let routed = service
+        .route_by_skills(&conversation.id)
+        .await
+        .unwrap()
+        .expect("conversation should be routed");
+    assert_eq!(
+        routed.assigned_user_id.as_deref(),
+        Some(free.user_id.as_str())
+    );
+    assert_eq!(routed.assigned_team_id.as_deref(), Some(team.as_str()));
+    assert_ne!(routed.assigned_user_id, Some(outsider.user_id));
+}
+
+#[tokio::test]
+async fn test_listener_routes_retagged_conversations() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = assignment_service(db);
+    let api = create_skill(&service, "api").await;
+    let api_tag = create_test_tag(db, "api", None, None).await;
+    let agent = skilled_agent(db, &service, "dev@example.com", &[&api]).await;
+    let conversation = tagged_conversation(db, "customer@example.com", &[&api_tag]).await;
+
+    let event_bus: Arc<dyn EventBus> = Arc::new(LocalEventBus::new(10));
+    let listener = tokio::spawn(
+        oxidesk::application::listeners::skill_routing::run_skill_routing_listener(
+            event_bus.clone(),
+            service,
+        ),
+    );
+    // Give the listener a moment to subscribe
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    event_bus
+        .publish(SystemEvent::ConversationTagsChanged {
+            conversation_id: conversation.id.clone(),
+            previous_tags: vec![],
+            new_tags: vec![api_tag.id.clone()],
+            changed_by: agent.user_id.clone(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        })
+        .unwrap();
+
+    let mut assigned = None;
+    for _ in 0..50 {
+        assigned = db
+            .get_conversation_by_id(&conversation.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .assigned_user_id;
+        if assigned.is_some() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    listener.abort();
+    assert_eq!(assigned, Some(agent.user_id));
+}
@@ -0,0 +1,282 @@
+// Integration tests for simulating a draft SLA policy against past conversations
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use oxidesk::domain::entities::*;
+use oxidesk::domain::ports::message_repository::MessageRepository;
+use oxidesk::infrastructure::persistence::Database;
+use oxidesk::SlaService;
+use uuid::Uuid;
+
+mod helpers;
+use helpers::*;
+
+const WEEKDAY_HOURS: &str = r#"{"timezone": "UTC", "schedule": [
+    {"day": "Monday", "start": "09:00", "end": "17:00"},
+    {"day": "Tuesday", "start": "09:00", "end": "17:00"},
+    {"day": "Wednesday", "start": "09:00", "end": "17:00"},
+    {"day": "Thursday", "start": "09:00", "end": "17:00"},
+    {"day": "Friday", "start": "09:00", "end": "17:00"}
+]}"#;
+
+fn sla_service(db: &Database) -> SlaService {
+    SlaService::new(
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        Arc::new(db.clone()),
+        Arc::new(oxidesk::LocalEventBus::new(100)),
+    )
+}
+
+fn at(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+async fn create_team(db: &Database, name: &str, business_hours: Option<&str>) -> Team {
+    let team = Team::new(name.to_string(), None);
+    db.create_team(&team).await.unwrap();
+    sqlx::query("UPDATE teams SET business_hours = ? WHERE id = ?")
+        .bind(business_hours)
+        .bind(&team.id)
+        .execute(db.pool())
+        .await
+        .unwrap();
+    team
+}
+
+/// Insert a conversation with the given history; messages are
+/// `(created_at, from_agent)`
+async fn insert_conversation(
+    db: &Database,
+    contact: &Contact,
+    team_id: Option<&str>,
+    created_at: &str,
+    resolved_at: Option<&str>,
+    messages: &[(&str, bool)],
+) -> String {
+    let id = Uuid::new_v4().to_string();
+    let status = if resolved_at.is_some() {
+        "resolved"
+    } else {
+        "open"
+    };
+    sqlx::query(
+        "INSERT INTO conversations (
+            id, reference_number, status, inbox_id, contact_id, subject, assigned_team_id,
+            resolved_at, created_at, updated_at
+         ) VALUES (
+            ?, (SELECT COALESCE(MAX(reference_number), 99) + 1 FROM conversations),
+            ?, 'inbox-001', ?, 'Help', ?, ?, ?, ?
+         )",
+    )
+    .bind(&id)
+    .bind(status)
+    .bind(&contact.id)
+    .bind(team_id)
+    .bind(resolved_at)
+    .bind(created_at)
+    .bind(created_at)
+    .execute(db.pool())
+    .await
+    .unwrap();
+
+    for (message_at, from_agent) in messages {
+        let mut message = if *from_agent {
+            Message::new_outgoing(id.clone(), "Reply".to_string(), contact.user_id.clone())
+        } else {
+            Message::new_incoming(id.clone(), "Question".to_string(), contact.user_id.clone())
+        };
+        message.created_at = message_at.to_string();
+        db.create_message(&message).await.unwrap();
+    }
+
+    id
+}
+
+#[tokio::test]
+async fn test_simulation_reports_breach_rates_by_team_and_inbox() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let contact = create_test_contact(db, "sim@example.com").await;
+    let team = create_team(db, "Billing", None).await;
+
+    // Answered in 30 minutes and resolved the same day
+    insert_conversation(
+        db,
+        &contact,
+        Some(&team.id),
+        "2024-03-01T10:00:00Z",
+        Some("2024-03-01T12:00:00Z"),
+        &[
+            ("2024-03-01T10:00:00Z", false),
+            ("2024-03-01T10:30:00Z", true),
+        ],
+    )
+    .await;
+    // Answered after three hours, never resolved
+    insert_conversation(
+        db,
+        &contact,
+        Some(&team.id),
+        "2024-03-02T10:00:00Z",
+        None,
+        &[
+            ("2024-03-02T10:00:00Z", false),
+            ("2024-03-02T13:00:00Z", true),
+        ],
+    )
+    .await;
+    // No team and never answered
+    insert_conversation(
+        db,
+        &contact,
+        None,
+        "2024-03-03T10:00:00Z",
+        None,
+        &[("2024-03-03T10:00:00Z", false)],
+    )
+    .await;
+    // Outside the window
+    insert_conversation(
+        db,
+        &contact,
+        None,
+        "2024-01-15T10:00:00Z",
+        None,
+        &[("2024-01-15T10:00:00Z", false)],
+    )
+    .await;
+
+    let simulation = sla_service(db)
+        .simulate_policy(
+            "1h",
+            "1d",
+            "4h",
+            at("2024-02-01T00:00:00Z"),
+            at("2024-04-01T00:00:00Z"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(simulation.overall.conversations, 3);
+    assert_eq!(simulation.overall.breached_conversations, 2);
+    assert_eq!(simulation.overall.first_response.met, 1);
+    assert_eq!(simulation.overall.first_response.breached, 2);
+    assert_eq!(simulation.overall.resolution.met, 1);
+    assert_eq!(simulation.overall.resolution.breached, 2);
+
+    assert_eq!(simulation.by_team.len(), 2);
+    let billing = &simulation.by_team[0];
+    assert_eq!(billing.id.as_deref(), Some(team.id.as_str()));
+    assert_eq!(billing.name.as_deref(), Some("Billing"));
+    assert_eq!(billing.stats.conversations, 2);
+    assert_eq!(billing.stats.breach_rate, 0.5);
+    assert_eq!(simulation.by_team[1].id, None);
+    assert_eq!(simulation.by_team[1].stats.breach_rate, 1.0);
+
+    assert_eq!(simulation.by_inbox.len(), 1);
+    assert_eq!(simulation.by_inbox[0].id.as_deref(), Some("inbox-001"));
+    assert_eq!(
+        simulation.by_inbox[0].name.as_deref(),
+        Some("Default Inbox")
+    );
+    assert_eq!(simulation.by_inbox[0].stats.conversations, 3);
+
+    // A looser policy would have breached less
+    let loose = sla_service(db)
+        .simulate_policy(
+            "4h",
+            "30d",
+            "4h",
+            at("2024-02-01T00:00:00Z"),
+            at("2024-04-01T00:00:00Z"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(loose.overall.first_response.breached, 1);
+    // Still unresolved long after any deadline
+    assert_eq!(loose.overall.resolution.breached, 2);
+}
+
+#[tokio::test]
+async fn test_simulation_uses_team_business_hours_and_holidays() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let contact = create_test_contact(db, "hours@example.com").await;
+    let team = create_team(db, "Weekdays", Some(WEEKDAY_HOURS)).await;
+
+    // Arrives Friday evening, answered Monday at 11:00
+    insert_conversation(
+        db,
+        &contact,
+        Some(&team.id),
+        "2024-03-01T18:00:00Z",
+        Some("2024-03-04T12:00:00Z"),
+        &[
+            ("2024-03-01T18:00:00Z", false),
+            ("2024-03-04T11:00:00Z", true),
+        ],
+    )
+    .await;
+
+    let simulate = || {
+        let service = sla_service(db);
+        async move {
+            service
+                .simulate_policy(
+                    "1h",
+                    "30d",
+                    "4h",
+                    at("2024-03-01T00:00:00Z"),
+                    at("2024-03-02T00:00:00Z"),
+                )
+                .await
+                .unwrap()
+        }
+    };
+
+    // The hour starts Monday 09:00, so the 11:00 reply is late
+    let simulation = simulate().await;
+    assert_eq!(simulation.overall.first_response.breached, 1);
+
+    // With Monday off the deadline moves to Tuesday 10:00
+    let holiday = Holiday::new("Spring break".to_string(), "2024-03-04".to_string(), false);
+    db.create_holiday(&holiday).await.unwrap();
+    let simulation = simulate().await;
+    assert_eq!(simulation.overall.first_response.met, 1);
+    assert_eq!(simulation.overall.breached_conversations, 0);
+}
+
+#[tokio::test]
+async fn test_simulation_includes_conversations_stored_with_sqlite_timestamps() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let contact = create_test_contact(db, "recent@example.com").await;
+    create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+
+    let now = Utc::now();
+    let simulation = sla_service(db)
+        .simulate_policy(
+            "1h",
+            "1d",
+            "4h",
+            now - chrono::Duration::days(1),
+            now + chrono::Duration::minutes(1),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(simulation.overall.conversations, 1);
+    // Neither deadline has passed yet
+    assert_eq!(simulation.overall.first_response.pending, 1);
+    assert_eq!(simulation.overall.resolution.pending, 1);
+    assert_eq!(simulation.overall.breach_rate, 0.0);
+}
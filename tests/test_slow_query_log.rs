@@ -0,0 +1,118 @@
+// Integration tests for the slow-query diagnostics mode
+mod helpers;
+
+use helpers::*;
+use oxidesk::application::services::DiagnosticsService;
+use oxidesk::infrastructure::persistence::diagnostics::{
+    SlowQueryConfig, SlowQueryLayer, SlowQueryLog,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+
+fn enabled_config(max_entries: usize) -> SlowQueryConfig {
+    SlowQueryConfig {
+        enabled: true,
+        threshold: Duration::from_millis(100),
+        max_entries,
+    }
+}
+
+#[test]
+fn test_slow_queries_aggregated_per_statement() {
+    let log = SlowQueryLog::new(enabled_config(10));
+
+    assert!(log.record(
+        "SELECT * FROM contacts WHERE email = ?",
+        Duration::from_millis(120)
+    ));
+    // Same statement formatted differently
+    assert!(!log.record(
+        "SELECT *\n  FROM contacts\n  WHERE email = ?",
+        Duration::from_millis(300)
+    ));
+    log.record("SELECT * FROM tags", Duration::from_millis(150));
+
+    let report = log.report(10);
+    assert!(report.enabled);
+    assert_eq!(report.threshold_ms, 100);
+    assert_eq!(report.queries.len(), 2);
+
+    let worst = &report.queries[0];
+    assert_eq!(worst.statement, "SELECT * FROM contacts WHERE email = ?");
+    assert_eq!(worst.count, 2);
+    assert_eq!(worst.total_ms, 420);
+    assert_eq!(worst.max_ms, 300);
+    assert_eq!(worst.mean_ms, 210);
+    assert!(worst.plan.is_none());
+
+    assert_eq!(log.report(1).queries.len(), 1);
+}
+
+#[test]
+fn test_cheapest_statement_evicted_when_full() {
+    let log = SlowQueryLog::new(enabled_config(2));
+
+    log.record("SELECT 1", Duration::from_millis(500));
+    log.record("SELECT 2", Duration::from_millis(110));
+    log.record("SELECT 3", Duration::from_millis(200));
+
+    let statements: Vec<String> = log
+        .report(10)
+        .queries
+        .into_iter()
+        .map(|query| query.statement)
+        .collect();
+    assert_eq!(statements, vec!["SELECT 1", "SELECT 3"]);
+}
+
+#[tokio::test]
+async fn test_slow_statements_recorded_with_plan() {
+    let _ = tracing_subscriber::registry()
+        .with(SlowQueryLayer)
+        .try_init();
+    // Every statement counts as slow
+    std::env::set_var("SLOW_QUERY_LOG_ENABLED", "true");
+    std::env::set_var("SLOW_QUERY_THRESHOLD_MS", "0");
+
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let statement = "SELECT COUNT(*) AS total FROM messages WHERE content LIKE ?";
+    sqlx::query(statement)
+        .bind("%refund%")
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+
+    // Statements are recorded and explained in the background
+    let service = DiagnosticsService::new(Arc::new(db.clone()));
+    let mut recorded = None;
+    for _ in 0..50 {
+        recorded = service
+            .slow_queries(100)
+            .queries
+            .into_iter()
+            .find(|query| query.statement == statement && query.plan.is_some());
+        if recorded.is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    let recorded = recorded.expect("slow statement was not recorded");
+    assert_eq!(recorded.count, 1);
+    assert!(recorded.plan.unwrap().contains("SCAN messages"));
+
+    let report = service.slow_queries(1000);
+    assert!(report.enabled);
+    assert_eq!(report.threshold_ms, 0);
+    assert!(report.queries.len() <= 100);
+    // The EXPLAIN runs themselves are left out
+    assert!(report
+        .queries
+        .iter()
+        .all(|query| !query.statement.starts_with("EXPLAIN")));
+
+    teardown_test_db(test_db).await;
+}
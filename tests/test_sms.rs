@@ -0,0 +1,314 @@
+// Integration tests for the Twilio SMS channel
+use axum::{extract::State, http::StatusCode, routing::post, Form, Router};
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    domain::ports::message_repository::MessageRepository,
+    domain::services::sms::{sms_segment_count, twilio_signature},
+    infrastructure::{
+        persistence::Database,
+        providers::{ChannelDeliveryProvider, TwilioDeliveryProvider},
+    },
+};
+use std::sync::{Arc, Mutex};
+
+mod helpers;
+use helpers::*;
+
+const SMS_INBOX: &str = "inbox-sms";
+const ACCOUNT_SID: &str = "AC0123456789abcdef0123456789abcdef";
+const AUTH_TOKEN: &str = "twilio-auth-token";
+const INBOX_NUMBER: &str = "+14155550100";
+const CUSTOMER_NUMBER: &str = "+14155550199";
+
+async fn create_sms_inbox(db: &Database) {
+    sqlx::query(
+        "INSERT INTO inboxes (id, name, channel_type, created_at, updated_at)
+         VALUES (?, 'Support line', 'api', datetime('now'), datetime('now'))",
+    )
+    .bind(SMS_INBOX)
+    .execute(db.pool())
+    .await
+    .unwrap();
+}
+
+fn sms_service(db: &Database) -> SmsService {
+    let repo = Arc::new(db.clone());
+    let channel_service = ChannelService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        MessageService::new(repo.clone(), repo.clone()),
+    );
+    SmsService::new(repo.clone(), repo, channel_service)
+        .with_webhook_base_url("https://desk.example.com/")
+}
+
+fn config_request(max_segments: Option<i32>) -> CreateInboxSmsConfigRequest {
+    CreateInboxSmsConfigRequest {
+        account_sid: ACCOUNT_SID.to_string(),
+        auth_token: AUTH_TOKEN.to_string(),
+        phone_number: INBOX_NUMBER.to_string(),
+        max_segments,
+        enabled: None,
+    }
+}
+
+fn inbound(message_sid: &str, body: &str) -> Vec<(String, String)> {
+    [
+        ("AccountSid", ACCOUNT_SID),
+        ("Body", body),
+        ("From", CUSTOMER_NUMBER),
+        ("MessageSid", message_sid),
+        ("NumMedia", "0"),
+        ("To", INBOX_NUMBER),
+    ]
+    .iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect()
+}
+
+async fn receive(
+    service: &SmsService,
+    params: &[(String, String)],
+) -> SmsResult<ChannelMessageResponse> {
+    let signature = twilio_signature(&service.webhook_url(SMS_INBOX), params, AUTH_TOKEN);
+    service
+        .receive_twilio_message(SMS_INBOX, params, Some(&signature))
+        .await
+}
+
+#[tokio::test]
+async fn test_sms_config_validation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_sms_inbox(db).await;
+    let service = sms_service(db);
+
+    // Only API inboxes can take a Twilio number
+    let result = service
+        .create_config("inbox-001", config_request(None))
+        .await;
+    assert!(matches!(result, Err(SmsError::Validation(_))));
+
+    let mut invalid = config_request(None);
+    invalid.phone_number = "4155550100".to_string();
+    let result = service.create_config(SMS_INBOX, invalid).await;
+    assert!(matches!(result, Err(SmsError::Validation(_))));
+
+    let config = service
+        .create_config(SMS_INBOX, config_request(None))
+        .await
+        .unwrap();
+    assert_eq!(config.max_segments, DEFAULT_SMS_MAX_SEGMENTS);
+    assert!(config.enabled);
+    assert_eq!(
+        service.webhook_url(SMS_INBOX),
+        "https://desk.example.com/webhooks/twilio/inbox-sms"
+    );
+
+    let result = service.create_config(SMS_INBOX, config_request(None)).await;
+    assert!(matches!(result, Err(SmsError::Conflict(_))));
+
+    let result = service
+        .update_config(
+            SMS_INBOX,
+            UpdateInboxSmsConfigRequest {
+                max_segments: Some(MAX_SMS_SEGMENTS + 1),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(SmsError::Validation(_))));
+
+    let updated = service
+        .update_config(
+            SMS_INBOX,
+            UpdateInboxSmsConfigRequest {
+                max_segments: Some(1),
+                enabled: Some(false),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.max_segments, 1);
+    let stored = service.get_config(SMS_INBOX).await.unwrap();
+    assert!(!stored.enabled);
+    assert_eq!(stored.auth_token, AUTH_TOKEN);
+
+    service.delete_config(SMS_INBOX).await.unwrap();
+    assert!(matches!(
+        service.get_config(SMS_INBOX).await,
+        Err(SmsError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_twilio_webhook_creates_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_sms_inbox(db).await;
+    let service = sms_service(db);
+    service
+        .create_config(SMS_INBOX, config_request(None))
+        .await
+        .unwrap();
+
+    let first = receive(&service, &inbound("SM001", "Is my order shipped?"))
+        .await
+        .unwrap();
+    assert!(first.created_conversation);
+    assert_eq!(first.message.content, "Is my order shipped?");
+    assert_eq!(first.message.channel.as_deref(), Some(SMS_CHANNEL));
+
+    // The next text from the same number continues the conversation
+    let second = receive(&service, &inbound("SM002", "Order #1234"))
+        .await
+        .unwrap();
+    assert!(!second.created_conversation);
+    assert_eq!(second.conversation_id, first.conversation_id);
+    assert_eq!(second.contact_id, first.contact_id);
+
+    // Twilio retries are ignored
+    let retry = receive(&service, &inbound("SM002", "Order #1234"))
+        .await
+        .unwrap();
+    assert!(retry.duplicate);
+    assert_eq!(retry.message.id, second.message.id);
+    assert_eq!(db.count_messages(&first.conversation_id).await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn test_twilio_webhook_rejects_bad_signatures() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_sms_inbox(db).await;
+    let service = sms_service(db);
+
+    // No SMS configuration yet
+    let result = receive(&service, &inbound("SM001", "Hello")).await;
+    assert!(matches!(result, Err(SmsError::NotFound(_))));
+
+    service
+        .create_config(SMS_INBOX, config_request(None))
+        .await
+        .unwrap();
+    let params = inbound("SM001", "Hello");
+
+    let result = service
+        .receive_twilio_message(SMS_INBOX, &params, None)
+        .await;
+    assert!(matches!(result, Err(SmsError::InvalidSignature)));
+
+    let forged = twilio_signature(&service.webhook_url(SMS_INBOX), &params, "wrong-token");
+    let result = service
+        .receive_twilio_message(SMS_INBOX, &params, Some(&forged))
+        .await;
+    assert!(matches!(result, Err(SmsError::InvalidSignature)));
+
+    // Signed for another URL
+    let elsewhere = twilio_signature("https://desk.example.com/other", &params, AUTH_TOKEN);
+    let result = service
+        .receive_twilio_message(SMS_INBOX, &params, Some(&elsewhere))
+        .await;
+    assert!(matches!(result, Err(SmsError::InvalidSignature)));
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM conversations WHERE inbox_id = ?")
+        .bind(SMS_INBOX)
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+type SentTexts = Arc<Mutex<Vec<Vec<(String, String)>>>>;
+
+async fn record_text(
+    State(sent): State<SentTexts>,
+    Form(params): Form<Vec<(String, String)>>,
+) -> (StatusCode, &'static str) {
+    sent.lock().unwrap().push(params);
+    (StatusCode::CREATED, "{\"sid\":\"SM-test\"}")
+}
+
+/// Local stand-in for the Twilio Messages API; returns its base URL
+async fn start_mock_twilio(sent: SentTexts) -> String {
+    let app = Router::new()
+        .route(
+            "/2010-04-01/Accounts/:account_sid/Messages.json",
+            post(record_text),
+        )
+        .with_state(sent);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    format!("http://{}", address)
+}
+
+#[tokio::test]
+async fn test_twilio_delivery_splits_long_replies() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_sms_inbox(db).await;
+    let service = sms_service(db);
+    service
+        .create_config(SMS_INBOX, config_request(Some(1)))
+        .await
+        .unwrap();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let incoming = receive(&service, &inbound("SM001", "Hi")).await.unwrap();
+
+    let sent: SentTexts = Arc::new(Mutex::new(Vec::new()));
+    let api_base_url = start_mock_twilio(sent.clone()).await;
+    let repo = Arc::new(db.clone());
+    let twilio = TwilioDeliveryProvider::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        Arc::new(MockDeliveryProvider::new_failing()),
+    )
+    .with_api_base_url(&api_base_url);
+    let delivery = ChannelDeliveryProvider::new(
+        repo.clone(),
+        repo,
+        Arc::new(MockDeliveryProvider::new_failing()),
+    )
+    .with_provider(SMS_INBOX_CHANNEL_TYPE, Arc::new(twilio));
+
+    let content = "Your order shipped today and should arrive within three days. ".repeat(5);
+    let reply = Message::new_outgoing(
+        incoming.conversation_id.clone(),
+        content.clone(),
+        agent.user_id.clone(),
+    );
+    db.create_message(&reply).await.unwrap();
+    delivery.deliver(&reply).await.unwrap();
+
+    let sent = sent.lock().unwrap().clone();
+    assert!(sent.len() > 1);
+    let mut bodies = Vec::new();
+    for params in &sent {
+        let value = |name: &str| {
+            params
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+                .unwrap()
+        };
+        assert_eq!(value("To"), CUSTOMER_NUMBER);
+        assert_eq!(value("From"), INBOX_NUMBER);
+        assert_eq!(sms_segment_count(&value("Body")), 1);
+        bodies.push(value("Body"));
+    }
+    assert_eq!(bodies.join(" "), content.trim());
+
+    // API inboxes without a Twilio number fall back to the default provider
+    service.delete_config(SMS_INBOX).await.unwrap();
+    assert!(delivery.deliver(&reply).await.is_err());
+}
@@ -0,0 +1,160 @@
+// Integration tests for admin-editable system configuration
+use oxidesk::{
+    application::services::*, domain::entities::*, infrastructure::persistence::Database,
+    shared::rate_limiter::AuthRateLimiter,
+};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+fn system_config_service(db: &Database, rate_limiter: AuthRateLimiter) -> SystemConfigService {
+    SystemConfigService::new(Arc::new(db.clone())).with_rate_limiter(rate_limiter)
+}
+
+fn update(settings: serde_json::Value) -> UpdateSystemConfigRequest {
+    UpdateSystemConfigRequest {
+        settings: serde_json::from_value::<BTreeMap<String, serde_json::Value>>(settings).unwrap(),
+    }
+}
+
+#[tokio::test]
+async fn test_get_settings_lists_allow_list_with_defaults() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = system_config_service(db, AuthRateLimiter::new());
+
+    let response = service.get_settings().await.unwrap();
+    assert_eq!(response.settings.len(), SYSTEM_CONFIG_SETTINGS.len());
+
+    // Seeded by migration
+    let inactivity = response
+        .settings
+        .iter()
+        .find(|s| s.key == INACTIVITY_TIMEOUT_KEY)
+        .unwrap();
+    assert_eq!(inactivity.value, 300);
+    assert!(inactivity.updated_at.is_some());
+
+    // Not stored yet, so the default applies
+    let retention = response
+        .settings
+        .iter()
+        .find(|s| s.key == NOTIFICATION_RETENTION_DAYS_KEY)
+        .unwrap();
+    assert_eq!(retention.value, 30);
+    assert!(retention.updated_at.is_none());
+}
+
+#[tokio::test]
+async fn test_update_settings_validates_before_writing() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = system_config_service(db, AuthRateLimiter::new());
+
+    let result = service
+        .update_settings(update(json!({"database.url": "sqlite::memory:"})), "admin")
+        .await;
+    assert!(matches!(result, Err(SystemConfigError::Validation(_))));
+
+    // One bad value rejects the whole request
+    let result = service
+        .update_settings(
+            update(json!({
+                NOTIFICATION_RETENTION_DAYS_KEY: 90,
+                AUTH_RATE_LIMIT_ATTEMPTS_KEY: 0,
+            })),
+            "admin",
+        )
+        .await;
+    assert!(matches!(result, Err(SystemConfigError::Validation(_))));
+    let result = service
+        .update_settings(update(json!({NOTIFICATION_RETENTION_DAYS_KEY: "ninety"})), "admin")
+        .await;
+    assert!(matches!(result, Err(SystemConfigError::Validation(_))));
+    assert_eq!(service.value(NOTIFICATION_RETENTION_DAYS_KEY).await.unwrap(), 30);
+
+    // The reassignment threshold cannot be shorter than the away timeout
+    let result = service
+        .update_settings(update(json!({MAX_IDLE_THRESHOLD_KEY: 120})), "admin")
+        .await;
+    assert!(matches!(result, Err(SystemConfigError::Validation(_))));
+    let response = service
+        .update_settings(
+            update(json!({INACTIVITY_TIMEOUT_KEY: 60, MAX_IDLE_THRESHOLD_KEY: 120})),
+            "admin",
+        )
+        .await
+        .unwrap();
+    let max_idle = response
+        .settings
+        .iter()
+        .find(|s| s.key == MAX_IDLE_THRESHOLD_KEY)
+        .unwrap();
+    assert_eq!(max_idle.value, 120);
+}
+
+#[tokio::test]
+async fn test_update_settings_records_changes() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let service = system_config_service(db, AuthRateLimiter::new());
+
+    service
+        .update_settings(update(json!({NOTIFICATION_RETENTION_DAYS_KEY: "90"})), "admin-1")
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    service
+        .update_settings(
+            update(json!({NOTIFICATION_RETENTION_DAYS_KEY: 60, INACTIVITY_TIMEOUT_KEY: 300})),
+            "admin-2",
+        )
+        .await
+        .unwrap();
+    assert_eq!(service.value(NOTIFICATION_RETENTION_DAYS_KEY).await.unwrap(), 60);
+
+    // Unchanged values are not recorded
+    let history = service.list_changes(None, 50).await.unwrap();
+    assert_eq!(history.total, 2);
+    assert_eq!(history.changes[0].new_value, "60");
+    assert_eq!(history.changes[0].old_value.as_deref(), Some("90"));
+    assert_eq!(history.changes[0].changed_by, "admin-2");
+    assert_eq!(history.changes[1].old_value, None);
+
+    let history = service
+        .list_changes(Some(INACTIVITY_TIMEOUT_KEY), 50)
+        .await
+        .unwrap();
+    assert_eq!(history.total, 0);
+}
+
+#[tokio::test]
+async fn test_rate_limit_settings_apply_without_restart() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let rate_limiter = AuthRateLimiter::new();
+    let service = system_config_service(db, rate_limiter.clone());
+
+    service
+        .update_settings(
+            update(json!({AUTH_RATE_LIMIT_ATTEMPTS_KEY: 2, AUTH_RATE_LIMIT_WINDOW_KEY: 10})),
+            "admin",
+        )
+        .await
+        .unwrap();
+    assert_eq!(rate_limiter.limits(), (2, 10));
+    assert!(rate_limiter.record_failure("agent@example.com").await.is_ok());
+    assert!(rate_limiter.record_failure("agent@example.com").await.is_ok());
+    assert!(rate_limiter.record_failure("agent@example.com").await.is_err());
+
+    // A fresh limiter picks up stored limits at startup
+    let restarted = AuthRateLimiter::new();
+    system_config_service(db, restarted.clone())
+        .apply_runtime_settings()
+        .await
+        .unwrap();
+    assert_eq!(restarted.limits(), (2, 10));
+}
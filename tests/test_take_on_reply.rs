@@ -0,0 +1,211 @@
+// Integration tests for assigning team conversations to the first member who replies
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    infrastructure::{
+        persistence::Database, providers::connection_manager::InMemoryConnectionManager,
+    },
+    LocalEventBus,
+};
+use std::sync::Arc;
+
+mod helpers;
+use helpers::rbac_helpers::{add_user_to_team, create_test_team};
+use helpers::*;
+
+fn assignment_service(db: &Database) -> AssignmentService {
+    let repo = Arc::new(db.clone());
+    AssignmentService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        Arc::new(LocalEventBus::new(10)),
+        NotificationService::new(Some(Arc::new(db.clone()))),
+        Arc::new(InMemoryConnectionManager::new()),
+    )
+}
+
+fn message_service(db: &Database) -> MessageService {
+    let repo = Arc::new(db.clone());
+    let mut service = MessageService::new(repo.clone(), repo);
+    service.set_assignment_service(assignment_service(db));
+    service
+}
+
+async fn team_conversation(db: &Database, team_id: &str) -> Conversation {
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+    db.assign_conversation_to_team(&conversation.id, Some(team_id.to_string()), None)
+        .await
+        .unwrap();
+    conversation
+}
+
+async fn reply(db: &Database, conversation: &Conversation, agent_id: &str) -> Conversation {
+    message_service(db)
+        .send_message(
+            conversation.id.clone(),
+            agent_id.to_string(),
+            SendMessageRequest {
+                content: "Thanks for reaching out, looking into it now".to_string(),
+            },
+        )
+        .await
+        .expect("Failed to send message");
+
+    db.get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_reply_assigns_team_conversation_to_member() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let team_id = create_test_team(db, "Billing").await;
+    let agent = create_test_agent(db, "alex@example.com", "Alex").await;
+    let other = create_test_agent(db, "robin@example.com", "Robin").await;
+    add_user_to_team(db, &agent.user_id, &team_id).await;
+    add_user_to_team(db, &other.user_id, &team_id).await;
+
+    let team_service = TeamService::new(Arc::new(db.clone()));
+    let team = team_service
+        .set_take_on_reply(&team_id, true)
+        .await
+        .unwrap();
+    assert!(team.take_on_reply);
+
+    let conversation = team_conversation(db, &team_id).await;
+    let conversation = reply(db, &conversation, &agent.user_id).await;
+    assert_eq!(
+        conversation.assigned_user_id.as_deref(),
+        Some(agent.user_id.as_str())
+    );
+    assert_eq!(
+        conversation.assigned_team_id.as_deref(),
+        Some(team_id.as_str())
+    );
+
+    let history = db.get_assignment_history(&conversation.id).await.unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].assigned_by, agent.user_id);
+    assert_eq!(
+        history[0].assigned_user_id.as_deref(),
+        Some(agent.user_id.as_str())
+    );
+    assert_eq!(
+        history[0].reason.as_deref(),
+        Some("Replied to an unassigned team conversation")
+    );
+
+    // Later replies by teammates leave the assignee alone
+    let conversation = reply(db, &conversation, &other.user_id).await;
+    assert_eq!(
+        conversation.assigned_user_id.as_deref(),
+        Some(agent.user_id.as_str())
+    );
+    assert_eq!(
+        db.get_assignment_history(&conversation.id)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_reply_without_take_on_reply_leaves_conversation_unassigned() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let team_id = create_test_team(db, "Support").await;
+    let agent = create_test_agent(db, "alex@example.com", "Alex").await;
+    add_user_to_team(db, &agent.user_id, &team_id).await;
+
+    let conversation = team_conversation(db, &team_id).await;
+    let conversation = reply(db, &conversation, &agent.user_id).await;
+    assert_eq!(conversation.assigned_user_id, None);
+    assert!(db
+        .get_assignment_history(&conversation.id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_reply_by_non_member_does_not_take_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let team_id = create_test_team(db, "Support").await;
+    let outsider = create_test_agent(db, "sam@example.com", "Sam").await;
+    TeamService::new(Arc::new(db.clone()))
+        .set_take_on_reply(&team_id, true)
+        .await
+        .unwrap();
+
+    let conversation = team_conversation(db, &team_id).await;
+    let conversation = reply(db, &conversation, &outsider.user_id).await;
+    assert_eq!(conversation.assigned_user_id, None);
+}
+
+#[tokio::test]
+async fn test_concurrent_replies_assign_only_the_first_agent() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let team_id = create_test_team(db, "Billing").await;
+    let first = create_test_agent(db, "alex@example.com", "Alex").await;
+    let second = create_test_agent(db, "robin@example.com", "Robin").await;
+    add_user_to_team(db, &first.user_id, &team_id).await;
+    add_user_to_team(db, &second.user_id, &team_id).await;
+    TeamService::new(Arc::new(db.clone()))
+        .set_take_on_reply(&team_id, true)
+        .await
+        .unwrap();
+
+    // Both replies start from the same unassigned snapshot
+    let conversation = team_conversation(db, &team_id).await;
+    let conversation = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    let service = assignment_service(db);
+
+    let taken = service
+        .take_on_reply(&conversation, &first.user_id)
+        .await
+        .unwrap();
+    assert!(taken.is_some());
+    let taken = service
+        .take_on_reply(&conversation, &second.user_id)
+        .await
+        .unwrap();
+    assert!(taken.is_none());
+
+    let conversation = db
+        .get_conversation_by_id(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        conversation.assigned_user_id.as_deref(),
+        Some(first.user_id.as_str())
+    );
+    assert_eq!(
+        db.get_assignment_history(&conversation.id)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
@@ -0,0 +1,426 @@
+// Integration tests for the Telegram bot channel
+use oxidesk::{
+    application::services::*,
+    domain::entities::*,
+    domain::ports::message_repository::MessageRepository,
+    domain::ports::telegram_bot_api::TelegramBotApi,
+    infrastructure::{
+        persistence::Database,
+        providers::{ChannelDeliveryProvider, TelegramDeliveryProvider},
+    },
+};
+use std::sync::{Arc, Mutex};
+
+mod helpers;
+use helpers::*;
+
+const TELEGRAM_INBOX: &str = "inbox-telegram";
+const BOT_TOKEN: &str = "123456789:AAHfiqksKZ8WmR2zSjiQ7_v4TMAKdiHm9T0";
+const CHAT_ID: i64 = 987654321;
+
+/// Bot API stand-in that records the calls made to it
+#[derive(Default)]
+struct MockBotApi {
+    calls: Mutex<Vec<String>>,
+}
+
+impl MockBotApi {
+    fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    fn record(&self, call: String) {
+        self.calls.lock().unwrap().push(call);
+    }
+}
+
+#[async_trait::async_trait]
+impl TelegramBotApi for MockBotApi {
+    async fn get_me(&self, bot_token: &str) -> Result<TelegramUser, String> {
+        if bot_token != BOT_TOKEN {
+            return Err("Unauthorized".to_string());
+        }
+        Ok(TelegramUser {
+            id: 123456789,
+            is_bot: true,
+            first_name: "Support".to_string(),
+            last_name: None,
+            username: Some("support_bot".to_string()),
+        })
+    }
+
+    async fn set_webhook(
+        &self,
+        _bot_token: &str,
+        url: &str,
+        secret_token: &str,
+    ) -> Result<(), String> {
+        self.record(format!("setWebhook {} {}", url, secret_token));
+        Ok(())
+    }
+
+    async fn delete_webhook(&self, _bot_token: &str) -> Result<(), String> {
+        self.record("deleteWebhook".to_string());
+        Ok(())
+    }
+
+    async fn send_message(
+        &self,
+        _bot_token: &str,
+        chat_id: &str,
+        text: &str,
+    ) -> Result<(), String> {
+        self.record(format!("sendMessage {} {}", chat_id, text));
+        Ok(())
+    }
+
+    async fn send_document(
+        &self,
+        _bot_token: &str,
+        chat_id: &str,
+        document_url: &str,
+        caption: Option<&str>,
+    ) -> Result<(), String> {
+        self.record(format!(
+            "sendDocument {} {} {}",
+            chat_id,
+            document_url,
+            caption.unwrap_or_default()
+        ));
+        Ok(())
+    }
+
+    async fn download_file(&self, _bot_token: &str, file_id: &str) -> Result<Vec<u8>, String> {
+        self.record(format!("getFile {}", file_id));
+        Ok(b"%PDF-1.4 invoice".to_vec())
+    }
+}
+
+async fn create_telegram_inbox(db: &Database) {
+    sqlx::query(
+        "INSERT INTO inboxes (id, name, channel_type, created_at, updated_at)
+         VALUES (?, 'Telegram bot', 'api', datetime('now'), datetime('now'))",
+    )
+    .bind(TELEGRAM_INBOX)
+    .execute(db.pool())
+    .await
+    .unwrap();
+}
+
+fn attachment_service(db: &Database) -> AttachmentService {
+    let temp_dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+    std::fs::create_dir_all(&temp_dir).unwrap();
+    AttachmentService::new(
+        Arc::new(db.clone()),
+        Arc::new(oxidesk::infrastructure::storage::local::LocalFileStorage::new(temp_dir)),
+    )
+}
+
+fn telegram_service(db: &Database, bot_api: Arc<MockBotApi>) -> TelegramService {
+    let repo = Arc::new(db.clone());
+    let channel_service = ChannelService::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        MessageService::new(repo.clone(), repo.clone()),
+    );
+    TelegramService::new(repo.clone(), repo, channel_service, bot_api)
+        .with_attachment_service(attachment_service(db))
+        .with_webhook_base_url("https://desk.example.com/")
+}
+
+fn config_request(bot_token: &str) -> CreateInboxTelegramConfigRequest {
+    CreateInboxTelegramConfigRequest {
+        bot_token: bot_token.to_string(),
+        enabled: None,
+    }
+}
+
+fn text_update(message_id: i64, text: &str) -> TelegramUpdate {
+    serde_json::from_value(serde_json::json!({
+        "update_id": 10000 + message_id,
+        "message": {
+            "message_id": message_id,
+            "from": {
+                "id": CHAT_ID,
+                "is_bot": false,
+                "first_name": "Ada",
+                "last_name": "Lovelace",
+                "username": "ada"
+            },
+            "chat": { "id": CHAT_ID, "type": "private", "username": "ada" },
+            "date": 1700000000,
+            "text": text
+        }
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_telegram_config_sets_webhook() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_telegram_inbox(db).await;
+    let bot_api = Arc::new(MockBotApi::default());
+    let service = telegram_service(db, bot_api.clone());
+
+    // Only API inboxes can take a bot
+    let result = service
+        .create_config("inbox-001", config_request(BOT_TOKEN))
+        .await;
+    assert!(matches!(result, Err(TelegramError::Validation(_))));
+
+    let result = service
+        .create_config(TELEGRAM_INBOX, config_request("not-a-token"))
+        .await;
+    assert!(matches!(result, Err(TelegramError::Validation(_))));
+
+    // Tokens the Bot API rejects are not stored
+    let result = service
+        .create_config(TELEGRAM_INBOX, config_request("42:revoked"))
+        .await;
+    assert!(matches!(result, Err(TelegramError::Api(_))));
+
+    let config = service
+        .create_config(TELEGRAM_INBOX, config_request(BOT_TOKEN))
+        .await
+        .unwrap();
+    assert_eq!(config.bot_username.as_deref(), Some("support_bot"));
+    assert!(config.enabled);
+    assert_eq!(
+        bot_api.calls(),
+        vec![format!(
+            "setWebhook https://desk.example.com/webhooks/telegram/inbox-telegram {}",
+            config.webhook_secret
+        )]
+    );
+
+    let result = service
+        .create_config(TELEGRAM_INBOX, config_request(BOT_TOKEN))
+        .await;
+    assert!(matches!(result, Err(TelegramError::Conflict(_))));
+
+    let stored = service.get_config(TELEGRAM_INBOX).await.unwrap();
+    assert_eq!(stored.bot_token, BOT_TOKEN);
+    assert_eq!(stored.webhook_secret, config.webhook_secret);
+
+    service.delete_config(TELEGRAM_INBOX).await.unwrap();
+    assert_eq!(bot_api.calls().last().unwrap(), "deleteWebhook");
+    assert!(matches!(
+        service.get_config(TELEGRAM_INBOX).await,
+        Err(TelegramError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_telegram_update_creates_conversation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_telegram_inbox(db).await;
+    let service = telegram_service(db, Arc::new(MockBotApi::default()));
+    let config = service
+        .create_config(TELEGRAM_INBOX, config_request(BOT_TOKEN))
+        .await
+        .unwrap();
+    let secret = Some(config.webhook_secret.as_str());
+
+    let first = service
+        .receive_update(
+            TELEGRAM_INBOX,
+            text_update(1, "Where is my parcel?"),
+            secret,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(first.created_conversation);
+    assert_eq!(first.message.content, "Where is my parcel?");
+    assert_eq!(first.message.channel.as_deref(), Some(TELEGRAM_CHANNEL));
+
+    // Channel metadata is stored with the message
+    let stored = db
+        .get_message_by_id(&first.message.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.channel.as_deref(), Some(TELEGRAM_CHANNEL));
+    let metadata = stored.channel_metadata.unwrap();
+    assert_eq!(metadata["chat_id"], CHAT_ID);
+    assert_eq!(metadata["username"], "ada");
+    assert_eq!(metadata["bot_username"], "support_bot");
+
+    // Later messages in the chat continue the conversation
+    let second = service
+        .receive_update(TELEGRAM_INBOX, text_update(2, "Order #1234"), secret)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!second.created_conversation);
+    assert_eq!(second.conversation_id, first.conversation_id);
+
+    // Redelivered updates are ignored
+    let retry = service
+        .receive_update(TELEGRAM_INBOX, text_update(2, "Order #1234"), secret)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(retry.duplicate);
+    assert_eq!(db.count_messages(&first.conversation_id).await.unwrap(), 2);
+
+    // Updates without a message are acknowledged without creating anything
+    let edit: TelegramUpdate = serde_json::from_value(serde_json::json!({
+        "update_id": 20000,
+        "edited_message": { "message_id": 2 }
+    }))
+    .unwrap();
+    assert!(service
+        .receive_update(TELEGRAM_INBOX, edit, secret)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_telegram_update_rejects_bad_secret() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_telegram_inbox(db).await;
+    let service = telegram_service(db, Arc::new(MockBotApi::default()));
+
+    // No Telegram configuration yet
+    let result = service
+        .receive_update(TELEGRAM_INBOX, text_update(1, "Hello"), Some("secret"))
+        .await;
+    assert!(matches!(result, Err(TelegramError::NotFound(_))));
+
+    service
+        .create_config(TELEGRAM_INBOX, config_request(BOT_TOKEN))
+        .await
+        .unwrap();
+
+    let result = service
+        .receive_update(TELEGRAM_INBOX, text_update(1, "Hello"), None)
+        .await;
+    assert!(matches!(result, Err(TelegramError::InvalidSecret)));
+
+    let result = service
+        .receive_update(TELEGRAM_INBOX, text_update(1, "Hello"), Some("guess"))
+        .await;
+    assert!(matches!(result, Err(TelegramError::InvalidSecret)));
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM conversations WHERE inbox_id = ?")
+        .bind(TELEGRAM_INBOX)
+        .fetch_one(db.pool())
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[tokio::test]
+async fn test_telegram_document_is_attached() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_telegram_inbox(db).await;
+    let bot_api = Arc::new(MockBotApi::default());
+    let service = telegram_service(db, bot_api.clone());
+    let config = service
+        .create_config(TELEGRAM_INBOX, config_request(BOT_TOKEN))
+        .await
+        .unwrap();
+
+    let update: TelegramUpdate = serde_json::from_value(serde_json::json!({
+        "update_id": 30000,
+        "message": {
+            "message_id": 7,
+            "from": { "id": CHAT_ID, "is_bot": false, "first_name": "Ada" },
+            "chat": { "id": CHAT_ID, "type": "private" },
+            "date": 1700000000,
+            "document": {
+                "file_id": "doc-file-id",
+                "file_unique_id": "doc-unique",
+                "file_name": "invoice.pdf",
+                "mime_type": "application/pdf"
+            }
+        }
+    }))
+    .unwrap();
+    let response = service
+        .receive_update(TELEGRAM_INBOX, update, Some(&config.webhook_secret))
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(response.message.content, "[Attachment: invoice.pdf]");
+    assert!(bot_api.calls().contains(&"getFile doc-file-id".to_string()));
+    let attachments = attachment_service(db)
+        .get_message_attachments(&response.message.id)
+        .await
+        .unwrap();
+    assert_eq!(attachments.len(), 1);
+    assert_eq!(attachments[0].filename, "invoice.pdf");
+}
+
+#[tokio::test]
+async fn test_telegram_delivery_splits_long_replies() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    create_telegram_inbox(db).await;
+    let bot_api = Arc::new(MockBotApi::default());
+    let service = telegram_service(db, bot_api.clone());
+    let config = service
+        .create_config(TELEGRAM_INBOX, config_request(BOT_TOKEN))
+        .await
+        .unwrap();
+    let agent = create_test_agent(db, "agent@example.com", "Agent").await;
+    let incoming = service
+        .receive_update(
+            TELEGRAM_INBOX,
+            text_update(1, "Hi"),
+            Some(&config.webhook_secret),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+    let repo = Arc::new(db.clone());
+    let telegram = TelegramDeliveryProvider::new(
+        repo.clone(),
+        repo.clone(),
+        repo.clone(),
+        bot_api.clone(),
+        Arc::new(MockDeliveryProvider::new_failing()),
+    );
+    let delivery = ChannelDeliveryProvider::new(
+        repo.clone(),
+        repo,
+        Arc::new(MockDeliveryProvider::new_failing()),
+    )
+    .with_provider(TELEGRAM_INBOX_CHANNEL_TYPE, Arc::new(telegram));
+
+    let content = "Your parcel left our warehouse this morning. ".repeat(100);
+    let reply = Message::new_outgoing(
+        incoming.conversation_id.clone(),
+        content.clone(),
+        agent.user_id.clone(),
+    );
+    db.create_message(&reply).await.unwrap();
+    delivery.deliver(&reply).await.unwrap();
+
+    let prefix = format!("sendMessage {} ", CHAT_ID);
+    let sent: Vec<String> = bot_api
+        .calls()
+        .into_iter()
+        .filter_map(|call| call.strip_prefix(&prefix).map(str::to_string))
+        .collect();
+    assert_eq!(sent.len(), 2);
+    for text in &sent {
+        assert!(text.chars().count() <= TELEGRAM_MAX_MESSAGE_LENGTH);
+    }
+    assert_eq!(sent.join(" "), content.trim());
+
+    // API inboxes without a bot fall back to the default provider
+    service.delete_config(TELEGRAM_INBOX).await.unwrap();
+    assert!(delivery.deliver(&reply).await.is_err());
+}
@@ -0,0 +1,331 @@
+// Integration tests for replaying past events into webhooks
+use chrono::{Duration, Utc};
+use oxidesk::application::services::{WebhookService, RUN_WEBHOOK_BACKFILL_JOB};
+use oxidesk::domain::entities::*;
+use oxidesk::domain::errors::WebhookError;
+use oxidesk::domain::ports::webhook_repository::WebhookRepository;
+use oxidesk::domain::services::webhook_signature::sign_payload;
+use oxidesk::infrastructure::persistence::Database;
+use oxidesk::infrastructure::workers::SqliteTaskQueue;
+use serde_json::json;
+use sqlx::Row;
+use std::sync::Arc;
+
+mod helpers;
+use helpers::*;
+
+const EVENTS: [&str; 2] = ["conversation.created", "message.sent"];
+
+fn webhook_service(db: &Database) -> WebhookService {
+    let mut service = WebhookService::new(WebhookRepository::new(db.clone()));
+    service.set_task_queue(Arc::new(SqliteTaskQueue::new(db.clone())));
+    service
+}
+
+async fn create_webhook(db: &Database, name: &str, created_by: &str) -> Webhook {
+    let webhook = Webhook::new(
+        name.to_string(),
+        format!("https://example.com/{}", name),
+        EVENTS.iter().map(|e| e.to_string()).collect(),
+        "backfill-secret-123456".to_string(),
+        created_by.to_string(),
+    );
+    db.create_webhook(&webhook).await.unwrap();
+    webhook
+}
+
+/// Store a delivery of an event that happened `hours_ago`
+async fn record_delivery(
+    db: &Database,
+    webhook: &Webhook,
+    event_type: &str,
+    conversation_id: &str,
+    hours_ago: i64,
+) -> serde_json::Value {
+    let at = Utc::now() - Duration::hours(hours_ago);
+    let payload = json!({
+        "event_type": event_type,
+        "timestamp": at.to_rfc3339(),
+        "data": { "conversation_id": conversation_id },
+    });
+    store_delivery(db, webhook, event_type, &payload, hours_ago).await;
+    payload
+}
+
+async fn store_delivery(
+    db: &Database,
+    webhook: &Webhook,
+    event_type: &str,
+    payload: &serde_json::Value,
+    hours_ago: i64,
+) {
+    let mut delivery = WebhookDelivery::new(
+        webhook.id.clone(),
+        event_type.to_string(),
+        payload.to_string(),
+        "sha256=old".to_string(),
+    );
+    delivery.attempted_at = Some((Utc::now() - Duration::hours(hours_ago)).to_rfc3339());
+    db.create_webhook_delivery(&delivery).await.unwrap();
+}
+
+fn backfill_request(days: i64, event_types: Option<&[&str]>) -> CreateWebhookBackfillRequest {
+    CreateWebhookBackfillRequest {
+        from: (Utc::now() - Duration::days(days)).to_rfc3339(),
+        to: None,
+        event_types: event_types.map(|types| types.iter().map(|t| t.to_string()).collect()),
+    }
+}
+
+/// Bodies of deliveries queued for a webhook, in queue order
+async fn queued_bodies(db: &Database, webhook_id: &str) -> Vec<serde_json::Value> {
+    let rows =
+        sqlx::query("SELECT payload FROM jobs WHERE job_type = 'deliver_webhook' ORDER BY rowid")
+            .fetch_all(db.pool())
+            .await
+            .unwrap();
+    let mut bodies = Vec::new();
+    for row in rows {
+        let job: serde_json::Value =
+            serde_json::from_str(&row.try_get::<String, _>("payload").unwrap()).unwrap();
+        if job["webhook_id"] == json!(webhook_id) {
+            let body = job["body"].as_str().unwrap();
+            assert_eq!(
+                job["signature"],
+                json!(sign_payload(body, "backfill-secret-123456"))
+            );
+            bodies.push(serde_json::from_str(body).unwrap());
+        }
+    }
+    bodies
+}
+
+async fn backfill_jobs(db: &Database) -> i64 {
+    sqlx::query("SELECT COUNT(*) AS count FROM jobs WHERE job_type = ?")
+        .bind(RUN_WEBHOOK_BACKFILL_JOB)
+        .fetch_one(db.pool())
+        .await
+        .unwrap()
+        .try_get("count")
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_backfill_replays_distinct_events_in_order() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let source = create_webhook(db, "source", &admin.user.id).await;
+    let other = create_webhook(db, "other", &admin.user.id).await;
+
+    let created = record_delivery(db, &source, "conversation.created", "conv-1", 3).await;
+    // A retry of the same event
+    store_delivery(db, &source, "conversation.created", &created, 3).await;
+    let sent = record_delivery(db, &source, "message.sent", "conv-1", 2).await;
+    // The same event delivered to a webhook that includes snapshots
+    let mut with_snapshot = sent.clone();
+    with_snapshot["data"]["conversation"] = json!({ "id": "conv-1" });
+    store_delivery(db, &other, "message.sent", &with_snapshot, 2).await;
+    // Outside the range
+    record_delivery(db, &source, "message.sent", "conv-old", 24 * 10).await;
+
+    let late = create_webhook(db, "late", &admin.user.id).await;
+    let service = webhook_service(db);
+    let backfill = service
+        .create_backfill(&late.id, backfill_request(1, None), &admin.user.id)
+        .await
+        .unwrap();
+    assert_eq!(backfill.backfill.status, WebhookBackfillStatus::Pending);
+    assert_eq!(backfill.backfill.total_events, 2);
+    assert_eq!(backfill.progress_percent, 0);
+    assert_eq!(backfill_jobs(db).await, 1);
+
+    let finished = service.run_backfill(&backfill.backfill.id).await.unwrap();
+    assert_eq!(finished.status, WebhookBackfillStatus::Completed);
+    assert_eq!(finished.processed_events, 2);
+
+    let bodies = queued_bodies(db, &late.id).await;
+    assert_eq!(bodies.len(), 2);
+    assert_eq!(bodies[0]["event_type"], "conversation.created");
+    assert_eq!(bodies[0]["timestamp"], created["timestamp"]);
+    assert_eq!(bodies[1]["event_type"], "message.sent");
+    assert_eq!(bodies[1]["data"], sent["data"]);
+    assert!(bodies.iter().all(|body| body["replayed"] == json!(true)));
+
+    let progress = service
+        .get_backfill(&late.id, &backfill.backfill.id)
+        .await
+        .unwrap();
+    assert_eq!(progress.progress_percent, 100);
+
+    // Running a finished backfill again replays nothing
+    service.run_backfill(&backfill.backfill.id).await.unwrap();
+    assert_eq!(queued_bodies(db, &late.id).await.len(), 2);
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_backfill_filters_event_types() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let source = create_webhook(db, "source", &admin.user.id).await;
+    record_delivery(db, &source, "conversation.created", "conv-1", 3).await;
+    record_delivery(db, &source, "message.sent", "conv-1", 2).await;
+
+    let late = create_webhook(db, "late", &admin.user.id).await;
+    let service = webhook_service(db);
+    let backfill = service
+        .create_backfill(
+            &late.id,
+            backfill_request(1, Some(&["message.sent"])),
+            &admin.user.id,
+        )
+        .await
+        .unwrap();
+    assert_eq!(backfill.backfill.total_events, 1);
+
+    service.run_backfill(&backfill.backfill.id).await.unwrap();
+    let bodies = queued_bodies(db, &late.id).await;
+    assert_eq!(bodies.len(), 1);
+    assert_eq!(bodies[0]["event_type"], "message.sent");
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_backfill_is_throttled_in_batches() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let source = create_webhook(db, "source", &admin.user.id).await;
+    for i in 0..15 {
+        record_delivery(db, &source, "message.sent", &format!("conv-{}", i), 2).await;
+    }
+
+    let late = create_webhook(db, "late", &admin.user.id).await;
+    let service = webhook_service(db);
+    let backfill = service
+        .create_backfill(&late.id, backfill_request(1, None), &admin.user.id)
+        .await
+        .unwrap();
+    assert_eq!(backfill.backfill.total_events, 15);
+
+    let first = service.run_backfill(&backfill.backfill.id).await.unwrap();
+    assert_eq!(first.status, WebhookBackfillStatus::Running);
+    assert_eq!(first.processed_events, 10);
+    assert_eq!(queued_bodies(db, &late.id).await.len(), 10);
+    let progress = service
+        .get_backfill(&late.id, &backfill.backfill.id)
+        .await
+        .unwrap();
+    assert_eq!(progress.progress_percent, 66);
+
+    // The next batch waits for its turn
+    let next_run: String = sqlx::query(
+        "SELECT CAST(run_at AS TEXT) AS run_at FROM jobs WHERE job_type = ? ORDER BY run_at DESC",
+    )
+    .bind(RUN_WEBHOOK_BACKFILL_JOB)
+    .fetch_one(db.pool())
+    .await
+    .unwrap()
+    .try_get("run_at")
+    .unwrap();
+    let next_run = chrono::DateTime::parse_from_rfc3339(&next_run).unwrap();
+    assert!(next_run.with_timezone(&Utc) > Utc::now());
+
+    let second = service.run_backfill(&backfill.backfill.id).await.unwrap();
+    assert_eq!(second.status, WebhookBackfillStatus::Completed);
+    assert_eq!(second.processed_events, 15);
+    assert_eq!(queued_bodies(db, &late.id).await.len(), 15);
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_backfill_validation() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let webhook = create_webhook(db, "late", &admin.user.id).await;
+    let service = webhook_service(db);
+
+    // Not subscribed
+    let result = service
+        .create_backfill(
+            &webhook.id,
+            backfill_request(1, Some(&["csat.received"])),
+            &admin.user.id,
+        )
+        .await;
+    assert!(matches!(result, Err(WebhookError::Validation(_))));
+
+    // Empty range
+    let request = CreateWebhookBackfillRequest {
+        from: Utc::now().to_rfc3339(),
+        to: Some((Utc::now() - Duration::days(1)).to_rfc3339()),
+        event_types: None,
+    };
+    let result = service
+        .create_backfill(&webhook.id, request, &admin.user.id)
+        .await;
+    assert!(matches!(result, Err(WebhookError::Validation(_))));
+
+    let request = CreateWebhookBackfillRequest {
+        from: "yesterday".to_string(),
+        to: None,
+        event_types: None,
+    };
+    let result = service
+        .create_backfill(&webhook.id, request, &admin.user.id)
+        .await;
+    assert!(matches!(result, Err(WebhookError::Validation(_))));
+
+    // Nothing to replay finishes right away
+    let backfill = service
+        .create_backfill(&webhook.id, backfill_request(1, None), &admin.user.id)
+        .await
+        .unwrap();
+    assert_eq!(backfill.backfill.status, WebhookBackfillStatus::Completed);
+    assert_eq!(backfill.progress_percent, 100);
+    assert_eq!(backfill_jobs(db).await, 0);
+
+    // Progress is only visible through its own webhook
+    let other = create_webhook(db, "other", &admin.user.id).await;
+    let result = service.get_backfill(&other.id, &backfill.backfill.id).await;
+    assert!(matches!(result, Err(WebhookError::NotFound(_))));
+
+    // Inactive webhooks can't be backfilled
+    service.toggle_webhook_status(&webhook.id).await.unwrap();
+    let result = service
+        .create_backfill(&webhook.id, backfill_request(1, None), &admin.user.id)
+        .await;
+    assert!(matches!(result, Err(WebhookError::Validation(_))));
+
+    teardown_test_db(test_db).await;
+}
+
+#[tokio::test]
+async fn test_backfill_fails_when_webhook_deactivated() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let source = create_webhook(db, "source", &admin.user.id).await;
+    record_delivery(db, &source, "message.sent", "conv-1", 2).await;
+
+    let late = create_webhook(db, "late", &admin.user.id).await;
+    let service = webhook_service(db);
+    let backfill = service
+        .create_backfill(&late.id, backfill_request(1, None), &admin.user.id)
+        .await
+        .unwrap();
+
+    service.toggle_webhook_status(&late.id).await.unwrap();
+    let result = service.run_backfill(&backfill.backfill.id).await.unwrap();
+    assert_eq!(result.status, WebhookBackfillStatus::Failed);
+    assert!(result.error_message.is_some());
+    assert!(queued_bodies(db, &late.id).await.is_empty());
+
+    teardown_test_db(test_db).await;
+}
@@ -0,0 +1,148 @@
+// Integration tests for conversation snapshots in webhook payloads
+use oxidesk::application::services::WebhookService;
+use oxidesk::domain::entities::*;
+use oxidesk::domain::ports::webhook_repository::WebhookRepository;
+
+mod helpers;
+use helpers::*;
+
+#[tokio::test]
+async fn test_snapshot_reflects_current_conversation_state() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let auth_user = create_test_auth_user(db).await;
+    let repo = WebhookRepository::new(db.clone());
+
+    let contact = create_test_contact(db, "customer@example.com").await;
+    let agent = create_test_agent(db, "agent@example.com", "Dana").await;
+    let conversation = create_test_conversation(
+        db,
+        "inbox-001".to_string(),
+        contact.id.clone(),
+        ConversationStatus::Open,
+    )
+    .await;
+
+    let snapshot = repo
+        .get_conversation_snapshot(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(snapshot.status, "open");
+    assert_eq!(snapshot.priority, None);
+    assert!(snapshot.tags.is_empty());
+    assert_eq!(snapshot.assigned_user_id, None);
+    assert_eq!(snapshot.assigned_user_name, None);
+
+    db.set_conversation_priority(&conversation.id, &Priority::High)
+        .await
+        .unwrap();
+    db.assign_conversation_to_user(
+        &conversation.id,
+        Some(agent.user_id.clone()),
+        Some(auth_user.user.id.clone()),
+    )
+    .await
+    .unwrap();
+    for name in ["vip", "billing"] {
+        let tag = create_test_tag(db, name, None, None).await;
+        db.add_conversation_tag(&conversation.id, &tag.id, &auth_user.user.id)
+            .await
+            .unwrap();
+    }
+
+    let snapshot = repo
+        .get_conversation_snapshot(&conversation.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(snapshot.id, conversation.id);
+    assert_eq!(snapshot.reference_number, conversation.reference_number);
+    assert_eq!(snapshot.priority.as_deref(), Some("High"));
+    assert_eq!(snapshot.tags, vec!["billing", "vip"]);
+    assert_eq!(
+        snapshot.assigned_user_id.as_deref(),
+        Some(agent.user_id.as_str())
+    );
+    assert_eq!(snapshot.assigned_user_name.as_deref(), Some("Dana"));
+    assert_eq!(snapshot.assigned_team_id, None);
+    assert_eq!(snapshot.contact.id, contact.id);
+    assert_eq!(
+        snapshot.contact.email.as_deref(),
+        Some("customer@example.com")
+    );
+    assert_eq!(
+        snapshot.contact.name.as_deref(),
+        Some("Test User customer@example.com")
+    );
+
+    assert!(repo
+        .get_conversation_snapshot("missing-conversation")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_include_setting_is_opt_in_and_updatable() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let service = WebhookService::new(WebhookRepository::new(db.clone()));
+
+    let request = CreateWebhookRequest {
+        name: "CRM sync".to_string(),
+        url: "https://crm.example.com/hooks".to_string(),
+        subscribed_events: vec!["conversation.assigned".to_string()],
+        secret: "0123456789abcdef".to_string(),
+        is_active: None,
+        include_conversation_snapshot: None,
+    };
+    let webhook = service
+        .create_webhook(request, &admin.user.id)
+        .await
+        .unwrap();
+    assert!(!webhook.include_conversation_snapshot);
+
+    let update = UpdateWebhookRequest {
+        name: None,
+        url: None,
+        subscribed_events: None,
+        secret: None,
+        is_active: None,
+        include_conversation_snapshot: Some(true),
+    };
+    service.update_webhook(&webhook.id, update).await.unwrap();
+
+    let stored = service.get_webhook(&webhook.id).await.unwrap();
+    assert!(stored.include_conversation_snapshot);
+    let subscribed = WebhookRepository::new(db.clone())
+        .get_active_webhooks_for_event("conversation.assigned")
+        .await
+        .unwrap();
+    assert!(subscribed[0].include_conversation_snapshot);
+}
+
+#[test]
+fn test_catalog_documents_snapshot_for_conversation_events() {
+    let assigned = find_webhook_event_type("conversation.assigned").unwrap();
+    let data = &assigned.payload_schema()["properties"]["data"];
+    let conversation = &data["properties"]["conversation"];
+    assert_eq!(conversation["properties"]["tags"]["type"], "array");
+    assert_eq!(
+        conversation["properties"]["contact"]["properties"]["email"]["type"],
+        serde_json::json!(["string", "null"])
+    );
+    // Only sent to webhooks that opt in
+    assert!(!data["required"]
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!("conversation")));
+
+    let logged_in = find_webhook_event_type("agent.logged_in").unwrap();
+    assert!(
+        logged_in.payload_schema()["properties"]["data"]["properties"]
+            .get("conversation")
+            .is_none()
+    );
+}
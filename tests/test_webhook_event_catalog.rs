@@ -0,0 +1,97 @@
+// Integration tests for the webhook event catalog and subscription checks
+use oxidesk::application::services::WebhookService;
+use oxidesk::domain::entities::*;
+use oxidesk::domain::errors::WebhookError;
+use oxidesk::domain::ports::webhook_repository::WebhookRepository;
+
+mod helpers;
+use helpers::*;
+
+fn create_request(subscribed_events: &[&str]) -> CreateWebhookRequest {
+    CreateWebhookRequest {
+        name: "CRM sync".to_string(),
+        url: "https://crm.example.com/hooks".to_string(),
+        subscribed_events: subscribed_events.iter().map(|e| e.to_string()).collect(),
+        secret: "0123456789abcdef".to_string(),
+        is_active: None,
+        include_conversation_snapshot: None,
+    }
+}
+
+#[tokio::test]
+async fn test_catalog_lists_event_types_with_schemas() {
+    let test_db = setup_test_db().await;
+    let service = WebhookService::new(WebhookRepository::new(test_db.db().clone()));
+
+    let catalog = service.list_event_types();
+
+    let names: Vec<&str> = catalog.events.iter().map(|e| e.name.as_str()).collect();
+    assert!(names.contains(&"conversation.created"));
+    assert!(names.contains(&"sla.breached"));
+    assert_eq!(names.len(), WEBHOOK_EVENT_TYPES.len());
+
+    let assigned = catalog
+        .events
+        .iter()
+        .find(|e| e.name == "conversation.assigned")
+        .unwrap();
+    assert!(!assigned.description.is_empty());
+    assert_eq!(
+        assigned.schema["properties"]["event_type"]["const"],
+        "conversation.assigned"
+    );
+    let data = &assigned.schema["properties"]["data"];
+    assert_eq!(data["properties"]["conversation_id"]["type"], "string");
+    assert_eq!(
+        data["properties"]["assigned_team_id"]["type"],
+        serde_json::json!(["string", "null"])
+    );
+    assert_eq!(data["properties"]["timestamp"]["format"], "date-time");
+}
+
+#[tokio::test]
+async fn test_unknown_events_are_rejected_on_create_and_update() {
+    let test_db = setup_test_db().await;
+    let db = test_db.db();
+    let admin = create_test_auth_user(db).await;
+    let service = WebhookService::new(WebhookRepository::new(db.clone()));
+
+    let result = service
+        .create_webhook(
+            create_request(&["conversation.created", "conversation.craeted"]),
+            &admin.user.id,
+        )
+        .await;
+    match result {
+        Err(WebhookError::Validation(message)) => {
+            assert!(message.contains("conversation.craeted"), "{}", message)
+        }
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+
+    let webhook = service
+        .create_webhook(
+            create_request(&["conversation.created", "sla.breached"]),
+            &admin.user.id,
+        )
+        .await
+        .unwrap();
+
+    let update = UpdateWebhookRequest {
+        name: None,
+        url: None,
+        subscribed_events: Some(vec!["message.recieved".to_string()]),
+        secret: None,
+        is_active: None,
+        include_conversation_snapshot: None,
+    };
+    let result = service.update_webhook(&webhook.id, update).await;
+    assert!(matches!(result, Err(WebhookError::Validation(_))));
+
+    // The stored subscription is left as it was
+    let stored = service.get_webhook(&webhook.id).await.unwrap();
+    assert_eq!(
+        stored.subscribed_events,
+        vec!["conversation.created", "sla.breached"]
+    );
+}